@@ -87,5 +87,11 @@ echo "  SLACK_APP_TOKEN=xapp-..."
 echo "  GOOGLE_SERVICE_ACCOUNT_KEY=$CONFIG_DIR/service-account.json"
 echo "  RESOURCE_CONFIG=$CONFIG_DIR/resources.toml"
 echo "  IDENTITY_LINKS_FILE=$DATA_DIR/identity_links.json"
+echo "  IDENTITY_LINK_AUDIT_FILE=$DATA_DIR/identity_link_audit.jsonl"
 echo "  GOOGLE_CALENDAR_MAPPINGS_FILE=$DATA_DIR/google_calendar_mappings.json"
+echo "  RESOURCE_FREEZES_FILE=$DATA_DIR/resource_freezes.json"
+echo "  DEVICE_STATUSES_FILE=$DATA_DIR/device_statuses.json"
+echo "  SENT_REMINDERS_FILE=$DATA_DIR/sent_reminders.json"
+echo "  WAITLIST_FILE=$DATA_DIR/waitlist.json"
+echo "  WORKSPACE_TOKENS_FILE=$DATA_DIR/workspace_tokens.json"
 echo "  RUST_LOG=info"
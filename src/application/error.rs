@@ -1,10 +1,21 @@
+use crate::domain::aggregates::device_health::errors::DeviceHealthError;
+use crate::domain::aggregates::group::errors::GroupError;
 use crate::domain::aggregates::identity_link::errors::IdentityLinkError;
+use crate::domain::aggregates::resource_freeze::errors::ResourceFreezeError;
 use crate::domain::aggregates::resource_usage::errors::ResourceUsageError;
+use crate::domain::aggregates::waitlist::errors::WaitlistError;
 use crate::domain::ports::{
-    notifier::NotificationError, repositories::RepositoryError,
+    member_directory::DirectoryError, notifier::NotificationError,
+    power_management::PowerManagementError, repositories::RepositoryError,
+    reservation_export::ExportError, reservation_import::ImportError,
     resource_collection_access::ResourceCollectionAccessError,
 };
-use crate::domain::services::resource_usage::errors::ResourceConflictError;
+use crate::domain::services::resource_usage::booking_window::BookingWindowError;
+use crate::domain::services::resource_usage::errors::{
+    CapacityExceededError, ConflictCheckError, ResourceConflictError,
+};
+use crate::domain::services::resource_usage::preemption::PreemptionError;
+use crate::domain::services::resource_usage::reservation_limit::ReservationLimitError;
 use std::fmt;
 
 /// Application層で発生するエラーの列挙型
@@ -18,11 +29,35 @@ pub enum ApplicationError {
     Notification(NotificationError),
     /// リソースコレクションへのアクセス中に発生したエラー
     ResourceCollectionAccess(ResourceCollectionAccessError),
+    /// 電源管理中に発生したエラー
+    PowerManagement(PowerManagementError),
+    /// ディレクトリサービスとの同期中に発生したエラー
+    Directory(DirectoryError),
+    /// 予約の書き出し中に発生したエラー
+    Export(ExportError),
+    /// 予約の取り込み中に発生したエラー
+    Import(ImportError),
 
     /// リソース使用に関するドメインエラー
     ResourceUsage(ResourceUsageError),
     /// ID紐付けに関するドメインエラー
     IdentityLink(IdentityLinkError),
+    /// 予約停止に関するドメインエラー
+    ResourceFreeze(ResourceFreezeError),
+    /// デバイスの状態に関するドメインエラー
+    DeviceHealth(DeviceHealthError),
+    /// チームに関するドメインエラー
+    Group(GroupError),
+    /// 空き待ちに関するドメインエラー
+    Waitlist(WaitlistError),
+    /// 利用者ごとの同時予約の上限を超えた
+    ReservationLimit(ReservationLimitError),
+    /// リソースの予約の受付期間外
+    BookingWindow(BookingWindowError),
+    /// 競合する予約を横取りできない
+    Preemption(PreemptionError),
+    /// 1件の予約だけでリソースの上限を超える
+    CapacityExceeded(CapacityExceededError),
 
     /// 外部システムが既に紐付けられている
     ExternalSystemAlreadyLinked {
@@ -52,8 +87,20 @@ impl fmt::Display for ApplicationError {
             ApplicationError::ResourceCollectionAccess(e) => {
                 write!(f, "リソースコレクションアクセスエラー: {}", e)
             }
+            ApplicationError::PowerManagement(e) => write!(f, "電源管理エラー: {}", e),
+            ApplicationError::Directory(e) => write!(f, "ディレクトリエラー: {}", e),
+            ApplicationError::Export(e) => write!(f, "書き出しエラー: {}", e),
+            ApplicationError::Import(e) => write!(f, "取り込みエラー: {}", e),
             ApplicationError::ResourceUsage(e) => write!(f, "リソース使用エラー: {}", e),
             ApplicationError::IdentityLink(e) => write!(f, "ID紐付けエラー: {}", e),
+            ApplicationError::ResourceFreeze(e) => write!(f, "予約停止: {}", e),
+            ApplicationError::DeviceHealth(e) => write!(f, "デバイスの状態: {}", e),
+            ApplicationError::Group(e) => write!(f, "チーム: {}", e),
+            ApplicationError::Waitlist(e) => write!(f, "空き待ちエラー: {}", e),
+            ApplicationError::ReservationLimit(e) => write!(f, "予約の上限: {}", e),
+            ApplicationError::BookingWindow(e) => write!(f, "予約の受付期間: {}", e),
+            ApplicationError::Preemption(e) => write!(f, "予約の横取り: {}", e),
+            ApplicationError::CapacityExceeded(e) => write!(f, "リソースの上限: {}", e),
             ApplicationError::ExternalSystemAlreadyLinked {
                 email,
                 external_system,
@@ -87,8 +134,20 @@ impl std::error::Error for ApplicationError {
             ApplicationError::Repository(e) => Some(e),
             ApplicationError::Notification(e) => Some(e),
             ApplicationError::ResourceCollectionAccess(e) => Some(e),
+            ApplicationError::PowerManagement(e) => Some(e),
+            ApplicationError::Directory(e) => Some(e),
+            ApplicationError::Export(e) => Some(e),
+            ApplicationError::Import(e) => Some(e),
             ApplicationError::ResourceUsage(e) => Some(e),
             ApplicationError::IdentityLink(e) => Some(e),
+            ApplicationError::ResourceFreeze(e) => Some(e),
+            ApplicationError::DeviceHealth(e) => Some(e),
+            ApplicationError::Group(e) => Some(e),
+            ApplicationError::Waitlist(e) => Some(e),
+            ApplicationError::ReservationLimit(e) => Some(e),
+            ApplicationError::BookingWindow(e) => Some(e),
+            ApplicationError::Preemption(e) => Some(e),
+            ApplicationError::CapacityExceeded(e) => Some(e),
             ApplicationError::ExternalSystemAlreadyLinked { .. } => None,
             ApplicationError::ResourceConflict { .. } => None,
             ApplicationError::Unauthorized(_) => None,
@@ -114,15 +173,73 @@ impl From<IdentityLinkError> for ApplicationError {
     }
 }
 
+impl From<ResourceFreezeError> for ApplicationError {
+    fn from(e: ResourceFreezeError) -> Self {
+        ApplicationError::ResourceFreeze(e)
+    }
+}
+
+impl From<DeviceHealthError> for ApplicationError {
+    fn from(e: DeviceHealthError) -> Self {
+        ApplicationError::DeviceHealth(e)
+    }
+}
+
+impl From<GroupError> for ApplicationError {
+    fn from(e: GroupError) -> Self {
+        ApplicationError::Group(e)
+    }
+}
+
+impl From<WaitlistError> for ApplicationError {
+    fn from(e: WaitlistError) -> Self {
+        ApplicationError::Waitlist(e)
+    }
+}
+
 impl From<ResourceConflictError> for ApplicationError {
     fn from(e: ResourceConflictError) -> Self {
         ApplicationError::ResourceConflict {
-            resource_description: e.resource_description,
-            conflicting_usage_id: e.conflicting_usage_id.as_str().to_string(),
+            resource_description: e.resource_description(),
+            conflicting_usage_id: e.conflicting_usage_id(),
+        }
+    }
+}
+
+impl From<ConflictCheckError> for ApplicationError {
+    fn from(e: ConflictCheckError) -> Self {
+        match e {
+            ConflictCheckError::Conflict(conflict) => conflict.into(),
+            ConflictCheckError::CapacityExceeded(e) => ApplicationError::CapacityExceeded(e),
+            ConflictCheckError::Repository(e) => ApplicationError::Repository(e),
         }
     }
 }
 
+impl From<CapacityExceededError> for ApplicationError {
+    fn from(e: CapacityExceededError) -> Self {
+        ApplicationError::CapacityExceeded(e)
+    }
+}
+
+impl From<ReservationLimitError> for ApplicationError {
+    fn from(e: ReservationLimitError) -> Self {
+        ApplicationError::ReservationLimit(e)
+    }
+}
+
+impl From<BookingWindowError> for ApplicationError {
+    fn from(e: BookingWindowError) -> Self {
+        ApplicationError::BookingWindow(e)
+    }
+}
+
+impl From<PreemptionError> for ApplicationError {
+    fn from(e: PreemptionError) -> Self {
+        ApplicationError::Preemption(e)
+    }
+}
+
 impl From<NotificationError> for ApplicationError {
     fn from(e: NotificationError) -> Self {
         ApplicationError::Notification(e)
@@ -134,3 +251,27 @@ impl From<ResourceCollectionAccessError> for ApplicationError {
         ApplicationError::ResourceCollectionAccess(e)
     }
 }
+
+impl From<PowerManagementError> for ApplicationError {
+    fn from(e: PowerManagementError) -> Self {
+        ApplicationError::PowerManagement(e)
+    }
+}
+
+impl From<DirectoryError> for ApplicationError {
+    fn from(e: DirectoryError) -> Self {
+        ApplicationError::Directory(e)
+    }
+}
+
+impl From<ExportError> for ApplicationError {
+    fn from(e: ExportError) -> Self {
+        ApplicationError::Export(e)
+    }
+}
+
+impl From<ImportError> for ApplicationError {
+    fn from(e: ImportError) -> Self {
+        ApplicationError::Import(e)
+    }
+}
@@ -14,6 +14,8 @@
 
 /// Application層で発生するエラーの定義
 pub mod error;
+/// CQRSのクエリ側で使う読み取りモデル
+pub mod read_model;
 pub mod usecases;
 
 pub use error::ApplicationError;
@@ -0,0 +1,13 @@
+//! # Read Model（読み取りモデル）
+//!
+//! CQRSにおけるクエリ側のモデル。
+//! リポジトリから定期的に再構築したプロジェクションを保持し、
+//! ダッシュボード・タイムライン・空き状況の問い合わせに外部APIを介さずに応答する。
+//!
+//! 書き込み（予約の作成・更新・削除）は引き続きユースケース経由でリポジトリに対して行い、
+//! 読み取りモデルには次回の再構築で反映される（結果整合性）。
+
+/// 予約のプロジェクション
+pub mod reservation_projection;
+
+pub use reservation_projection::{ReservationProjection, ReservationReadModel, ReservationView};
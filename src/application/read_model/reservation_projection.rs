@@ -0,0 +1,401 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    ReservationMetadata, Resource, TimePeriod, UsageId,
+};
+use crate::domain::common::EmailAddress;
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use chrono_tz::Tz;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+/// 予約の読み取り専用プロジェクション
+///
+/// 予約を「リソース × 日付」で索引付けしたスナップショット。
+/// 複数日にまたがる予約は、期間に含まれる各日付に登録される。
+/// 日付の区切りは構築時に指定したタイムゾーン（未指定ならシステムのローカルタイムゾーン）で判定する。
+#[derive(Debug, Clone)]
+pub struct ReservationProjection {
+    by_resource_and_day: HashMap<(Resource, NaiveDate), Vec<Arc<ResourceUsage>>>,
+    timezone: Option<Tz>,
+    built_at: DateTime<Utc>,
+}
+
+impl Default for ReservationProjection {
+    fn default() -> Self {
+        Self {
+            by_resource_and_day: HashMap::new(),
+            timezone: None,
+            built_at: DateTime::<Utc>::MIN_UTC,
+        }
+    }
+}
+
+impl ReservationProjection {
+    /// 予約一覧からプロジェクションを構築
+    ///
+    /// # Arguments
+    /// * `usages` - 予約一覧
+    /// * `timezone` - 日付の区切りに使うタイムゾーン（IANA形式）
+    pub fn build(usages: Vec<ResourceUsage>, timezone: Option<&str>) -> Self {
+        let mut projection = Self {
+            by_resource_and_day: HashMap::new(),
+            timezone: timezone.and_then(|tz| tz.parse::<Tz>().ok()),
+            built_at: Utc::now(),
+        };
+
+        for usage in usages {
+            let days = projection.days_of(usage.time_period());
+            let usage = Arc::new(usage);
+            for resource in usage.resources() {
+                for day in &days {
+                    projection
+                        .by_resource_and_day
+                        .entry((resource.clone(), *day))
+                        .or_default()
+                        .push(usage.clone());
+                }
+            }
+        }
+
+        for usages in projection.by_resource_and_day.values_mut() {
+            usages.sort_by_key(|usage| usage.time_period().start());
+        }
+
+        projection
+    }
+
+    /// プロジェクションを構築した時刻
+    pub fn built_at(&self) -> DateTime<Utc> {
+        self.built_at
+    }
+
+    /// 指定したリソースの指定日の予約を取得（開始時刻順）
+    pub fn reservations_on(&self, resource: &Resource, date: NaiveDate) -> &[Arc<ResourceUsage>] {
+        self.by_resource_and_day
+            .get(&(resource.clone(), date))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// 指定したリソースの指定日の予約を、閲覧者に応じて詳細を伏せた形で取得（開始時刻順）
+    ///
+    /// # Arguments
+    /// * `viewer` - 閲覧者のメールアドレス（不特定多数に向けた表示の場合は `None`）
+    /// * `is_admin` - 閲覧者が管理者かどうか
+    pub fn views_on(
+        &self,
+        resource: &Resource,
+        date: NaiveDate,
+        viewer: Option<&EmailAddress>,
+        is_admin: bool,
+    ) -> Vec<ReservationView> {
+        self.reservations_on(resource, date)
+            .iter()
+            .map(|usage| ReservationView::new(usage.clone(), viewer, is_admin))
+            .collect()
+    }
+
+    /// 指定日のタイムライン（リソースごとの予約一覧）を取得
+    ///
+    /// # Returns
+    /// 予約のあるリソースと、その日の予約一覧（リソースの表示名順）
+    pub fn timeline(&self, date: NaiveDate) -> Vec<(&Resource, &[Arc<ResourceUsage>])> {
+        let mut timeline: Vec<(&Resource, &[Arc<ResourceUsage>])> = self
+            .by_resource_and_day
+            .iter()
+            .filter(|((_, day), _)| *day == date)
+            .map(|((resource, _), usages)| (resource, usages.as_slice()))
+            .collect();
+        timeline.sort_by_key(|(resource, _)| resource.to_string());
+        timeline
+    }
+
+    /// 指定したリソースで、指定期間と重複する予約を取得（開始時刻順）
+    pub fn overlapping(
+        &self,
+        resource: &Resource,
+        time_period: &TimePeriod,
+    ) -> Vec<Arc<ResourceUsage>> {
+        let mut seen = HashSet::new();
+        let mut result: Vec<Arc<ResourceUsage>> = self
+            .days_of(time_period)
+            .into_iter()
+            .flat_map(|day| self.reservations_on(resource, day))
+            .filter(|usage| usage.time_period().overlaps_with(time_period))
+            .filter(|usage| seen.insert(usage.id().clone()))
+            .cloned()
+            .collect();
+        result.sort_by_key(|usage| usage.time_period().start());
+        result
+    }
+
+    /// 指定したリソースが指定期間に空いているかどうか
+    pub fn is_available(&self, resource: &Resource, time_period: &TimePeriod) -> bool {
+        self.overlapping(resource, time_period).is_empty()
+    }
+
+    /// 期間に含まれる日付の一覧を取得
+    fn days_of(&self, time_period: &TimePeriod) -> Vec<NaiveDate> {
+        let first = self.local_date(time_period.start());
+        // 終了時刻は排他的なので、ちょうど0:00に終わる予約は翌日に含めない
+        let last = self.local_date(time_period.end() - Duration::nanoseconds(1));
+        first.iter_days().take_while(|day| *day <= last).collect()
+    }
+
+    fn local_date(&self, date_time: DateTime<Utc>) -> NaiveDate {
+        match self.timezone {
+            Some(tz) => date_time.with_timezone(&tz).date_naive(),
+            None => date_time.with_timezone(&Local).date_naive(),
+        }
+    }
+}
+
+/// 閲覧者に応じて詳細を伏せた予約の表示用ビュー
+///
+/// 非公開の予約は、予約者本人と管理者以外には予約者・備考・メタデータを返さない。
+/// ダッシュボード等の表示では `ResourceUsage` を直接参照せず、このビューを経由する。
+#[derive(Debug, Clone)]
+pub struct ReservationView {
+    usage: Arc<ResourceUsage>,
+    show_details: bool,
+}
+
+impl ReservationView {
+    /// 閲覧者に応じたビューを作成
+    ///
+    /// # Arguments
+    /// * `usage` - 予約
+    /// * `viewer` - 閲覧者のメールアドレス（不特定多数に向けた表示の場合は `None`）
+    /// * `is_admin` - 閲覧者が管理者かどうか
+    pub fn new(usage: Arc<ResourceUsage>, viewer: Option<&EmailAddress>, is_admin: bool) -> Self {
+        let show_details = usage.details_visible_to(viewer, is_admin);
+        Self {
+            usage,
+            show_details,
+        }
+    }
+
+    /// 予約ID
+    pub fn id(&self) -> &UsageId {
+        self.usage.id()
+    }
+
+    /// 使用期間
+    pub fn time_period(&self) -> &TimePeriod {
+        self.usage.time_period()
+    }
+
+    /// 使用するリソース
+    pub fn resources(&self) -> &[Resource] {
+        self.usage.resources()
+    }
+
+    /// 予約者のメールアドレス（伏せられている場合は `None`）
+    pub fn owner_email(&self) -> Option<&EmailAddress> {
+        self.show_details.then(|| self.usage.owner_email())
+    }
+
+    /// 備考（伏せられている場合は `None`）
+    pub fn notes(&self) -> Option<&String> {
+        self.usage.notes().filter(|_| self.show_details)
+    }
+
+    /// メタデータ（伏せられている場合は `None`）
+    pub fn metadata(&self) -> Option<&ReservationMetadata> {
+        self.show_details.then(|| self.usage.metadata())
+    }
+
+    /// 詳細が伏せられているかどうか
+    pub fn is_redacted(&self) -> bool {
+        !self.show_details
+    }
+}
+
+/// 予約の読み取りモデル
+///
+/// 定期的に再構築される `ReservationProjection` を保持し、
+/// 問い合わせのたびに外部のカレンダーAPIへアクセスせずに予約状況を参照できるようにする。
+#[derive(Debug, Default)]
+pub struct ReservationReadModel {
+    projection: RwLock<Arc<ReservationProjection>>,
+}
+
+impl ReservationReadModel {
+    /// 空の読み取りモデルを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 現在のプロジェクションを取得
+    ///
+    /// 返されたスナップショットは再構築の影響を受けない。
+    pub fn snapshot(&self) -> Arc<ReservationProjection> {
+        self.projection
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// プロジェクションを置き換える
+    pub fn replace(&self, projection: ReservationProjection) {
+        *self
+            .projection
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Arc::new(projection);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Visibility};
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    fn gpu(device: u32) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()))
+    }
+
+    fn usage(start: DateTime<Utc>, end: DateTime<Utc>, resources: Vec<Resource>) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, end).unwrap(),
+            resources,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_views_hide_private_details_from_others() {
+        let owner = EmailAddress::new("user@example.com".to_string()).unwrap();
+        let other = EmailAddress::new("other@example.com".to_string()).unwrap();
+        let private = ResourceUsage::new(
+            owner.clone(),
+            TimePeriod::new(at(15, 10), at(15, 12)).unwrap(),
+            vec![gpu(0)],
+            Some("面接".to_string()),
+        )
+        .unwrap()
+        .with_visibility(Visibility::Private);
+        let projection = ReservationProjection::build(
+            vec![private, usage(at(15, 13), at(15, 14), vec![gpu(0)])],
+            Some("UTC"),
+        );
+
+        let for_other = projection.views_on(&gpu(0), date(15), Some(&other), false);
+        assert!(for_other[0].is_redacted());
+        assert_eq!(for_other[0].owner_email(), None);
+        assert_eq!(for_other[0].notes(), None);
+        assert_eq!(for_other[1].owner_email(), Some(&owner));
+
+        let for_owner = projection.views_on(&gpu(0), date(15), Some(&owner), false);
+        assert_eq!(for_owner[0].notes().map(String::as_str), Some("面接"));
+
+        let for_admin = projection.views_on(&gpu(0), date(15), None, true);
+        assert_eq!(for_admin[0].owner_email(), Some(&owner));
+    }
+
+    #[test]
+    fn test_indexes_by_resource_and_day() {
+        let projection = ReservationProjection::build(
+            vec![usage(at(15, 10), at(15, 12), vec![gpu(0), gpu(1)])],
+            Some("UTC"),
+        );
+
+        assert_eq!(projection.reservations_on(&gpu(0), date(15)).len(), 1);
+        assert_eq!(projection.reservations_on(&gpu(1), date(15)).len(), 1);
+        assert!(projection.reservations_on(&gpu(2), date(15)).is_empty());
+        assert!(projection.reservations_on(&gpu(0), date(16)).is_empty());
+    }
+
+    #[test]
+    fn test_multi_day_usage_is_registered_on_each_day() {
+        // 15日10:00から17日0:00まで（17日は含まない）
+        let projection = ReservationProjection::build(
+            vec![usage(at(15, 10), at(17, 0), vec![gpu(0)])],
+            Some("UTC"),
+        );
+
+        assert_eq!(projection.reservations_on(&gpu(0), date(15)).len(), 1);
+        assert_eq!(projection.reservations_on(&gpu(0), date(16)).len(), 1);
+        assert!(projection.reservations_on(&gpu(0), date(17)).is_empty());
+    }
+
+    #[test]
+    fn test_days_follow_configured_timezone() {
+        // UTC 15日20:00 は JST 16日5:00
+        let projection = ReservationProjection::build(
+            vec![usage(at(15, 20), at(15, 22), vec![gpu(0)])],
+            Some("Asia/Tokyo"),
+        );
+
+        assert!(projection.reservations_on(&gpu(0), date(15)).is_empty());
+        assert_eq!(projection.reservations_on(&gpu(0), date(16)).len(), 1);
+    }
+
+    #[test]
+    fn test_overlapping_and_availability() {
+        let projection = ReservationProjection::build(
+            vec![usage(at(15, 10), at(16, 12), vec![gpu(0)])],
+            Some("UTC"),
+        );
+
+        let period = TimePeriod::new(at(15, 9), at(16, 18)).unwrap();
+        // 複数日に登録されていても重複して返さない
+        assert_eq!(projection.overlapping(&gpu(0), &period).len(), 1);
+        assert!(!projection.is_available(&gpu(0), &period));
+        assert!(projection.is_available(&gpu(1), &period));
+
+        let after = TimePeriod::new(at(16, 12), at(16, 18)).unwrap();
+        assert!(projection.is_available(&gpu(0), &after));
+    }
+
+    #[test]
+    fn test_timeline_lists_resources_of_the_day() {
+        let projection = ReservationProjection::build(
+            vec![
+                usage(at(15, 10), at(15, 12), vec![gpu(1)]),
+                usage(at(15, 13), at(15, 14), vec![gpu(0)]),
+            ],
+            Some("UTC"),
+        );
+
+        let timeline = projection.timeline(date(15));
+
+        assert_eq!(
+            timeline
+                .iter()
+                .map(|(r, _)| (*r).clone())
+                .collect::<Vec<_>>(),
+            vec![gpu(0), gpu(1)]
+        );
+    }
+
+    #[test]
+    fn test_read_model_replace_keeps_old_snapshot() {
+        let read_model = ReservationReadModel::new();
+        let before = read_model.snapshot();
+
+        read_model.replace(ReservationProjection::build(
+            vec![usage(at(15, 10), at(15, 12), vec![gpu(0)])],
+            Some("UTC"),
+        ));
+
+        assert!(before.reservations_on(&gpu(0), date(15)).is_empty());
+        assert_eq!(
+            read_model
+                .snapshot()
+                .reservations_on(&gpu(0), date(15))
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::{
+    entity::ResourceUsage,
+    errors::ResourceUsageError,
+    value_objects::{Resource, UsageId},
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 承認待ちの予約を承認・却下するユースケース
+///
+/// 承認するとカレンダー上の予定が確定し、却下すると予約が削除される。
+/// 予約者には通常の更新・削除通知で結果が共有される。
+/// 承認・却下できるのは、予約した部屋の承認者と管理者だけ。
+pub struct ApproveReservationUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    approvers: HashMap<String, Vec<EmailAddress>>,
+    admins: Vec<EmailAddress>,
+}
+
+impl<R: ResourceUsageRepository> ApproveReservationUseCase<R> {
+    /// 新しいApproveReservationUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    pub fn new(repository: Arc<R>) -> Self {
+        Self {
+            repository,
+            approvers: HashMap::new(),
+            admins: Vec::new(),
+        }
+    }
+
+    /// 部屋ごとの承認者を設定
+    ///
+    /// # Arguments
+    /// * `approvers` - 部屋名から、その部屋の予約を承認・却下できるユーザーへのマップ
+    pub fn with_approvers(mut self, approvers: HashMap<String, Vec<EmailAddress>>) -> Self {
+        self.approvers = approvers;
+        self
+    }
+
+    /// 管理者を設定
+    ///
+    /// 管理者はどの部屋の予約も承認・却下できる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.admins = admins;
+        self
+    }
+
+    /// 承認待ちの予約を承認する
+    ///
+    /// # Arguments
+    /// * `id` - 使用予定ID
+    /// * `approver` - 操作したユーザーのメールアドレス（権限チェック用）
+    ///
+    /// # Returns
+    /// 承認した予約
+    ///
+    /// # Errors
+    /// - 指定されたIDの予約が見つからない場合
+    /// - 予約が承認待ちでない場合
+    /// - 予約した部屋の承認者でも管理者でもない場合
+    /// - リポジトリエラー
+    pub async fn approve(
+        &self,
+        id: &UsageId,
+        approver: &EmailAddress,
+    ) -> Result<ResourceUsage, ApplicationError> {
+        let mut usage = self.find_pending(id, approver).await?;
+
+        usage.approve()?;
+        self.repository.save(&usage).await?;
+
+        Ok(usage)
+    }
+
+    /// 承認待ちの予約を却下する（予約は削除される）
+    ///
+    /// # Arguments
+    /// * `id` - 使用予定ID
+    /// * `approver` - 操作したユーザーのメールアドレス（権限チェック用）
+    ///
+    /// # Returns
+    /// 却下した予約
+    ///
+    /// # Errors
+    /// - 指定されたIDの予約が見つからない場合
+    /// - 予約が承認待ちでない場合
+    /// - 予約した部屋の承認者でも管理者でもない場合
+    /// - リポジトリエラー
+    pub async fn reject(
+        &self,
+        id: &UsageId,
+        approver: &EmailAddress,
+    ) -> Result<ResourceUsage, ApplicationError> {
+        let usage = self.find_pending(id, approver).await?;
+
+        self.repository.delete(id).await?;
+
+        Ok(usage)
+    }
+
+    /// 承認待ちの予約を取得（操作したユーザーが承認・却下できない場合はエラー）
+    async fn find_pending(
+        &self,
+        id: &UsageId,
+        approver: &EmailAddress,
+    ) -> Result<ResourceUsage, ApplicationError> {
+        let usage = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::Repository(RepositoryError::NotFound))?;
+
+        if !self.can_approve(&usage, approver) {
+            return Err(ApplicationError::Unauthorized(format!(
+                "{} はこの予約の承認者ではありません",
+                approver.as_str()
+            )));
+        }
+
+        if !usage.approval_status().is_pending() {
+            return Err(ResourceUsageError::NotPendingApproval.into());
+        }
+
+        Ok(usage)
+    }
+
+    /// 予約を承認・却下できるかどうか
+    ///
+    /// 管理者はすべての予約、それ以外は予約したすべての承認が必要な部屋の承認者の場合に限る。
+    fn can_approve(&self, usage: &ResourceUsage, approver: &EmailAddress) -> bool {
+        let listed = |emails: &[EmailAddress]| {
+            emails
+                .iter()
+                .any(|email| email.as_str().eq_ignore_ascii_case(approver.as_str()))
+        };
+        if listed(&self.admins) {
+            return true;
+        }
+        let mut rooms = usage
+            .resources()
+            .iter()
+            .filter_map(|resource| match resource {
+                Resource::Room { name } => self.approvers.get(name),
+                _ => None,
+            })
+            .peekable();
+        rooms.peek().is_some() && rooms.all(|approvers| listed(approvers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{ApprovalStatus, TimePeriod};
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use chrono::{Duration, Utc};
+
+    fn email(address: &str) -> EmailAddress {
+        EmailAddress::new(address.to_string()).unwrap()
+    }
+
+    async fn pending_usage(repository: &MockUsageRepository, room: &str) -> UsageId {
+        let start = Utc::now() + Duration::days(1);
+        let usage = ResourceUsage::new(
+            email("student@example.com"),
+            TimePeriod::new(start, start + Duration::hours(2)).unwrap(),
+            vec![Resource::Room {
+                name: room.to_string(),
+            }],
+            None,
+        )
+        .unwrap()
+        .with_approval_status(ApprovalStatus::Pending);
+        repository.save(&usage).await.unwrap();
+        usage.id().clone()
+    }
+
+    fn usecase(
+        repository: &Arc<MockUsageRepository>,
+    ) -> ApproveReservationUseCase<MockUsageRepository> {
+        ApproveReservationUseCase::new(repository.clone())
+            .with_approvers(HashMap::from([(
+                "ゼミ室".to_string(),
+                vec![email("prof@example.com")],
+            )]))
+            .with_admins(vec![email("admin@example.com")])
+    }
+
+    #[tokio::test]
+    async fn test_room_approver_can_approve() {
+        let repository = Arc::new(MockUsageRepository::new());
+        let id = pending_usage(&repository, "ゼミ室").await;
+
+        let approved = usecase(&repository)
+            .approve(&id, &email("Prof@example.com"))
+            .await
+            .unwrap();
+
+        assert!(!approved.approval_status().is_pending());
+        let saved = repository.find_by_id(&id).await.unwrap().unwrap();
+        assert!(!saved.approval_status().is_pending());
+    }
+
+    #[tokio::test]
+    async fn test_admin_can_reject() {
+        let repository = Arc::new(MockUsageRepository::new());
+        let id = pending_usage(&repository, "ゼミ室").await;
+
+        usecase(&repository)
+            .reject(&id, &email("admin@example.com"))
+            .await
+            .unwrap();
+
+        assert!(repository.find_by_id(&id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_other_user_cannot_approve_or_reject() {
+        let repository = Arc::new(MockUsageRepository::new());
+        let id = pending_usage(&repository, "ゼミ室").await;
+        let usecase = usecase(&repository);
+
+        let approved = usecase.approve(&id, &email("student@example.com")).await;
+        assert!(matches!(approved, Err(ApplicationError::Unauthorized(_))));
+        let rejected = usecase.reject(&id, &email("student@example.com")).await;
+        assert!(matches!(rejected, Err(ApplicationError::Unauthorized(_))));
+
+        let saved = repository.find_by_id(&id).await.unwrap().unwrap();
+        assert!(saved.approval_status().is_pending());
+    }
+
+    #[tokio::test]
+    async fn test_approver_of_another_room_cannot_approve() {
+        let repository = Arc::new(MockUsageRepository::new());
+        let id = pending_usage(&repository, "会議室A").await;
+
+        let result = usecase(&repository)
+            .approve(&id, &email("prof@example.com"))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Unauthorized(_))));
+    }
+}
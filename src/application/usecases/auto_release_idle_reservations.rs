@@ -0,0 +1,494 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod, UsageId};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::gpu_monitor::GpuProcessMonitor;
+use crate::domain::ports::notifier::IdleReservationNotifier;
+use crate::domain::ports::repositories::{
+    IdentityLinkRepository, RepositoryError, ResourceUsageRepository,
+};
+use crate::domain::services::resource_usage::{IdleReleaseAction, IdleReleasePolicy};
+use crate::domain::services::{AuthorizationPolicy, ResourceUsageAuthorizationPolicy};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// 使用中の予約ごとの、GPUの使用状況の記録
+#[derive(Debug, Default)]
+struct IdleState {
+    /// GPUが使われていない状態になった時刻
+    idle_since: HashMap<UsageId, DateTime<Utc>>,
+    /// 予約者に確認した時刻
+    warned_at: HashMap<UsageId, DateTime<Utc>>,
+    /// 予約期間中に一度でもGPUが使われた予約
+    active_seen: HashSet<UsageId>,
+    /// 予約者が使用を続けると応答した予約
+    kept: HashSet<UsageId>,
+}
+
+/// GPUが使われていない予約を予約者に確認し、応答がなければ解放するユースケース
+///
+/// 使用中の予約について、予約したGPUのいずれでもプロセスが実行されていない状態が続いたら
+/// 予約者にダイレクトメッセージで確認する。応答がなく使われないまま猶予を過ぎたら、
+/// 予約期間中に一度でも使われた予約は終了時刻を現在時刻に切り詰め、一度も使われなかった予約は取り消す。
+/// 予約者がSlackと紐付いていない予約は、確認できないため解放しない。
+pub struct AutoReleaseIdleReservationsUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    monitor: Arc<dyn GpuProcessMonitor>,
+    identity_repo: Arc<dyn IdentityLinkRepository>,
+    notifier: Arc<dyn IdleReservationNotifier>,
+    policy: IdleReleasePolicy,
+    authorization_policy: ResourceUsageAuthorizationPolicy,
+    state: tokio::sync::Mutex<IdleState>,
+}
+
+impl<R: ResourceUsageRepository> AutoReleaseIdleReservationsUseCase<R> {
+    /// 新しいAutoReleaseIdleReservationsUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `monitor` - GPUの使用状況の取得サービス
+    /// * `identity_repo` - IdentityLinkリポジトリ
+    /// * `notifier` - 予約者への確認・解放の通知の送信先
+    /// * `policy` - 確認・解放までの時間
+    pub fn new(
+        repository: Arc<R>,
+        monitor: Arc<dyn GpuProcessMonitor>,
+        identity_repo: Arc<dyn IdentityLinkRepository>,
+        notifier: Arc<dyn IdleReservationNotifier>,
+        policy: IdleReleasePolicy,
+    ) -> Self {
+        Self {
+            repository,
+            monitor,
+            identity_repo,
+            notifier,
+            policy,
+            authorization_policy: ResourceUsageAuthorizationPolicy::new(),
+            state: tokio::sync::Mutex::new(IdleState::default()),
+        }
+    }
+
+    /// 予約者が使用を続けると応答した予約を、この予約期間中は解放しないようにする
+    ///
+    /// # Arguments
+    /// * `id` - 使用予定ID
+    /// * `owner_email` - 所有者のメールアドレス（権限チェック用）
+    ///
+    /// # Errors
+    /// - 指定されたIDの予約が見つからない場合
+    /// - 操作する権限がない場合
+    /// - リポジトリエラー
+    pub async fn keep(
+        &self,
+        id: &UsageId,
+        owner_email: &EmailAddress,
+    ) -> Result<(), ApplicationError> {
+        let usage = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::Repository(RepositoryError::NotFound))?;
+        self.authorization_policy
+            .authorize_update(owner_email, &usage)
+            .map_err(|e| ApplicationError::Unauthorized(e.to_string()))?;
+
+        let mut state = self.state.lock().await;
+        state.warned_at.remove(id);
+        state.kept.insert(id.clone());
+        Ok(())
+    }
+
+    /// 使われていない予約を確認・解放する
+    ///
+    /// 個々のサーバーの使用状況の取得や通知の送信に失敗しても残りの処理は継続し、次回の実行で再試行する。
+    ///
+    /// # Returns
+    /// 確認・解放した予約のIDと行った操作の一覧
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self) -> Result<Vec<(UsageId, IdleReleaseAction)>, ApplicationError> {
+        let now = Utc::now();
+        let instant = TimePeriod::new(now, now + Duration::seconds(1))?;
+        let in_progress: Vec<ResourceUsage> = self
+            .repository
+            .find_overlapping(&instant)
+            .await?
+            .into_iter()
+            .filter(|usage| usage.time_period().start() <= now)
+            .collect();
+
+        // 使用状況を取得できたサーバーの、使われているデバイス
+        let mut busy: HashMap<String, HashSet<u32>> = HashMap::new();
+        for server in self.monitor.monitored_servers() {
+            match self.monitor.processes(&server).await {
+                Ok(processes) => {
+                    let devices = processes.iter().map(|p| p.device_number).collect();
+                    busy.insert(server, devices);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to query GPU processes on '{}': {}", server, e);
+                }
+            }
+        }
+
+        let mut state = self.state.lock().await;
+        // 終了・削除した予約の記録は破棄
+        let current_ids: HashSet<&UsageId> = in_progress.iter().map(|u| u.id()).collect();
+        state.idle_since.retain(|id, _| current_ids.contains(id));
+        state.warned_at.retain(|id, _| current_ids.contains(id));
+        state.active_seen.retain(|id| current_ids.contains(id));
+        state.kept.retain(|id| current_ids.contains(id));
+
+        let mut handled = Vec::new();
+        for usage in &in_progress {
+            let Some(active) = is_active(usage, &busy) else {
+                continue;
+            };
+            let id = usage.id().clone();
+            if active {
+                state.idle_since.remove(&id);
+                state.warned_at.remove(&id);
+                state.active_seen.insert(id);
+                continue;
+            }
+            if state.kept.contains(&id) {
+                continue;
+            }
+
+            let idle_since = *state.idle_since.entry(id.clone()).or_insert(now);
+            let warned_at = state.warned_at.get(&id).copied();
+            let action = self.policy.decide(idle_since, warned_at, now);
+            if action == IdleReleaseAction::None {
+                continue;
+            }
+            let Some(user_id) = self.slack_user_id(usage).await? else {
+                continue;
+            };
+
+            let result = match action {
+                IdleReleaseAction::Warn => self
+                    .notifier
+                    .notify_idle(&user_id, usage, now + self.policy.grace())
+                    .await
+                    .map_err(ApplicationError::from)
+                    .map(|()| {
+                        state.warned_at.insert(id.clone(), now);
+                    }),
+                IdleReleaseAction::Release => {
+                    let cancelled = !state.active_seen.contains(&id);
+                    self.release(usage, now, cancelled).await?;
+                    state.idle_since.remove(&id);
+                    state.warned_at.remove(&id);
+                    self.notifier
+                        .notify_released(&user_id, usage, cancelled)
+                        .await
+                        .map_err(ApplicationError::from)
+                }
+                IdleReleaseAction::None => Ok(()),
+            };
+            match result {
+                Ok(()) => handled.push((id, action)),
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to notify owner of idle usage '{}': {}",
+                        id.as_str(),
+                        e
+                    );
+                }
+            }
+        }
+
+        Ok(handled)
+    }
+
+    /// 予約を終了する（一度も使われていない場合は取り消す）
+    async fn release(
+        &self,
+        usage: &ResourceUsage,
+        now: DateTime<Utc>,
+        cancelled: bool,
+    ) -> Result<(), ApplicationError> {
+        if cancelled {
+            self.repository.delete(usage.id()).await?;
+        } else {
+            let mut usage = usage.clone();
+            usage.truncate_at(now)?;
+            self.repository.save(&usage).await?;
+        }
+        Ok(())
+    }
+
+    /// 予約者のSlackユーザーID（Slackと紐付いていない場合は `None`）
+    async fn slack_user_id(
+        &self,
+        usage: &ResourceUsage,
+    ) -> Result<Option<String>, ApplicationError> {
+        let Some(identity_link) = self
+            .identity_repo
+            .find_by_email(usage.owner_email())
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(identity_link
+            .get_identity_for_system(&ExternalSystem::Slack)
+            .map(|identity| identity.user_id().to_string()))
+    }
+}
+
+/// 予約したGPUのいずれかが使われているか
+///
+/// 使用状況を取得していないサーバーのGPUを含む予約、GPUを含まない予約は判定できないため `None`。
+fn is_active(usage: &ResourceUsage, busy: &HashMap<String, HashSet<u32>>) -> Option<bool> {
+    let mut gpus = usage
+        .resources()
+        .iter()
+        .filter_map(|resource| match resource {
+            Resource::Gpu(gpu) => Some(gpu),
+            _ => None,
+        })
+        .peekable();
+    gpus.peek()?;
+
+    let mut active = false;
+    for gpu in gpus {
+        let devices = busy.get(gpu.server())?;
+        active |= devices.contains(&gpu.device_number());
+    }
+    Some(active)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::identity_link::entity::IdentityLink;
+    use crate::domain::aggregates::identity_link::value_objects::ExternalIdentity;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::ports::NotificationError;
+    use crate::domain::ports::gpu_monitor::{GpuMonitorError, GpuProcess};
+    use crate::infrastructure::repositories::identity_link::JsonFileIdentityLinkRepository;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// サーバーごとに、プロセスが実行されているデバイスを返す
+    #[derive(Default)]
+    struct FakeMonitor {
+        busy: Mutex<HashMap<String, Vec<u32>>>,
+    }
+
+    impl FakeMonitor {
+        fn set_busy(&self, server: &str, devices: Vec<u32>) {
+            self.busy
+                .lock()
+                .unwrap()
+                .insert(server.to_string(), devices);
+        }
+    }
+
+    #[async_trait]
+    impl GpuProcessMonitor for FakeMonitor {
+        fn monitored_servers(&self) -> Vec<String> {
+            self.busy.lock().unwrap().keys().cloned().collect()
+        }
+
+        async fn processes(&self, server: &str) -> Result<Vec<GpuProcess>, GpuMonitorError> {
+            let busy = self.busy.lock().unwrap();
+            let devices = busy
+                .get(server)
+                .ok_or_else(|| GpuMonitorError::NotConfigured(server.to_string()))?;
+            Ok(devices
+                .iter()
+                .map(|&device_number| GpuProcess {
+                    device_number,
+                    pid: 1000,
+                    unix_user: "alice".to_string(),
+                })
+                .collect())
+        }
+    }
+
+    /// 確認・解放の通知を記録する（解放の通知は取り消したかどうかを記録する）
+    #[derive(Default)]
+    struct RecordingIdleNotifier {
+        idle: Mutex<Vec<String>>,
+        released: Mutex<Vec<(String, bool)>>,
+    }
+
+    #[async_trait]
+    impl IdleReservationNotifier for RecordingIdleNotifier {
+        async fn notify_idle(
+            &self,
+            user_id: &str,
+            _usage: &ResourceUsage,
+            _release_at: DateTime<Utc>,
+        ) -> Result<(), NotificationError> {
+            self.idle.lock().unwrap().push(user_id.to_string());
+            Ok(())
+        }
+
+        async fn notify_released(
+            &self,
+            user_id: &str,
+            _usage: &ResourceUsage,
+            cancelled: bool,
+        ) -> Result<(), NotificationError> {
+            self.released
+                .lock()
+                .unwrap()
+                .push((user_id.to_string(), cancelled));
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        usages: Arc<MockUsageRepository>,
+        monitor: Arc<FakeMonitor>,
+        notifier: Arc<RecordingIdleNotifier>,
+        use_case: AutoReleaseIdleReservationsUseCase<MockUsageRepository>,
+    }
+
+    /// aliceだけがSlackと紐付いていて、使われていなければすぐに確認・解放する環境
+    async fn fixture() -> Fixture {
+        let dir = std::env::temp_dir().join(format!("lrm_idle_{}", uuid::Uuid::new_v4()));
+        let identities = Arc::new(JsonFileIdentityLinkRepository::new(
+            dir.join("identity_links.json"),
+        ));
+        let mut link = IdentityLink::new(email("alice"));
+        link.link_external_identity(ExternalIdentity::new(
+            ExternalSystem::Slack,
+            "U_ALICE".to_string(),
+        ))
+        .unwrap();
+        identities.save(link).await.unwrap();
+
+        let usages = Arc::new(MockUsageRepository::new());
+        let monitor = Arc::new(FakeMonitor::default());
+        monitor.set_busy("Thalys", vec![]);
+        let notifier = Arc::new(RecordingIdleNotifier::default());
+        let use_case = AutoReleaseIdleReservationsUseCase::new(
+            usages.clone(),
+            monitor.clone(),
+            identities,
+            notifier.clone(),
+            IdleReleasePolicy::new(Duration::zero(), Duration::zero()),
+        );
+        Fixture {
+            usages,
+            monitor,
+            notifier,
+            use_case,
+        }
+    }
+
+    fn email(name: &str) -> EmailAddress {
+        EmailAddress::new(format!("{}@example.com", name)).unwrap()
+    }
+
+    /// 1時間前に始まり1時間後に終わるGPUの予約を保存する
+    async fn in_progress(fixture: &Fixture, owner: &str, server: &str) -> ResourceUsage {
+        let now = Utc::now();
+        let usage = ResourceUsage::new(
+            email(owner),
+            TimePeriod::new(now - Duration::hours(1), now + Duration::hours(1)).unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                server.to_string(),
+                0,
+                "A100".to_string(),
+            ))],
+            None,
+        )
+        .unwrap();
+        fixture.usages.save(&usage).await.unwrap();
+        usage
+    }
+
+    #[tokio::test]
+    async fn test_cancels_reservation_never_used() {
+        let fixture = fixture().await;
+        let usage = in_progress(&fixture, "alice", "Thalys").await;
+
+        assert_eq!(
+            fixture.use_case.execute().await.unwrap(),
+            vec![(usage.id().clone(), IdleReleaseAction::Warn)]
+        );
+        assert_eq!(*fixture.notifier.idle.lock().unwrap(), vec!["U_ALICE"]);
+
+        assert_eq!(
+            fixture.use_case.execute().await.unwrap(),
+            vec![(usage.id().clone(), IdleReleaseAction::Release)]
+        );
+        assert_eq!(
+            *fixture.notifier.released.lock().unwrap(),
+            vec![("U_ALICE".to_string(), true)]
+        );
+        assert!(
+            fixture
+                .usages
+                .find_by_id(usage.id())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_truncates_reservation_used_earlier() {
+        let fixture = fixture().await;
+        let usage = in_progress(&fixture, "alice", "Thalys").await;
+        fixture.monitor.set_busy("Thalys", vec![0]);
+        assert!(fixture.use_case.execute().await.unwrap().is_empty());
+
+        fixture.monitor.set_busy("Thalys", vec![]);
+        fixture.use_case.execute().await.unwrap();
+        assert_eq!(
+            fixture.use_case.execute().await.unwrap(),
+            vec![(usage.id().clone(), IdleReleaseAction::Release)]
+        );
+        assert_eq!(
+            *fixture.notifier.released.lock().unwrap(),
+            vec![("U_ALICE".to_string(), false)]
+        );
+        let truncated = fixture
+            .usages
+            .find_by_id(usage.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(truncated.time_period().end() <= Utc::now());
+    }
+
+    #[tokio::test]
+    async fn test_keeps_reservation_owner_asked_to_keep() {
+        let fixture = fixture().await;
+        let usage = in_progress(&fixture, "alice", "Thalys").await;
+        fixture.use_case.execute().await.unwrap();
+
+        assert!(matches!(
+            fixture.use_case.keep(usage.id(), &email("bob")).await,
+            Err(ApplicationError::Unauthorized(_))
+        ));
+        fixture
+            .use_case
+            .keep(usage.id(), &email("alice"))
+            .await
+            .unwrap();
+
+        assert!(fixture.use_case.execute().await.unwrap().is_empty());
+        assert!(fixture.notifier.released.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_skips_unlinked_owner_and_unmonitored_server() {
+        let fixture = fixture().await;
+        in_progress(&fixture, "bob", "Thalys").await;
+        in_progress(&fixture, "alice", "Freccia").await;
+
+        assert!(fixture.use_case.execute().await.unwrap().is_empty());
+        assert!(fixture.use_case.execute().await.unwrap().is_empty());
+        assert!(fixture.notifier.idle.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{
+    RepositoryError, ResourceUsageRepository, UsageQuery, UsageStatus,
+};
+use crate::domain::services::{AuthorizationPolicy, ResourceUsageAuthorizationPolicy};
+use std::sync::Arc;
+
+/// 複数のリソース使用予定をまとめて削除するユースケース
+pub struct BulkDeleteResourceUsagesUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    authorization_policy: ResourceUsageAuthorizationPolicy,
+}
+
+impl<R: ResourceUsageRepository> BulkDeleteResourceUsagesUseCase<R> {
+    /// 新しいBulkDeleteResourceUsagesUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    pub fn new(repository: Arc<R>) -> Self {
+        Self {
+            repository,
+            authorization_policy: ResourceUsageAuthorizationPolicy::new(),
+        }
+    }
+
+    /// 管理者を設定
+    ///
+    /// 管理者は他のユーザーの予約も削除できる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_admins(admins);
+        self
+    }
+
+    /// モデレーターを設定
+    ///
+    /// モデレーターは他のユーザーの予約も削除できる。
+    pub fn with_moderators(mut self, moderators: Vec<EmailAddress>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_moderators(moderators);
+        self
+    }
+
+    /// ユーザーの開始前のリソース使用予定を取得
+    ///
+    /// # Arguments
+    /// * `owner_email` - 所有者のメールアドレス
+    ///
+    /// # Returns
+    /// 開始前のリソース使用予定（開始時刻の早い順）
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn list_upcoming(
+        &self,
+        owner_email: &EmailAddress,
+    ) -> Result<Vec<ResourceUsage>, ApplicationError> {
+        let query = UsageQuery::new()
+            .with_owner(owner_email.clone())
+            .with_status(UsageStatus::Upcoming);
+        Ok(self.repository.query(&query).await?)
+    }
+
+    /// 指定したリソース使用予定をまとめて削除
+    ///
+    /// 一部だけ削除されることがないよう、すべての予約の存在と権限を確認してから削除する。
+    ///
+    /// # Arguments
+    /// * `ids` - 削除する使用予定ID
+    /// * `actor_email` - 操作するユーザーのメールアドレス（権限チェック用）
+    ///
+    /// # Returns
+    /// 削除したリソース使用予定（開始時刻の早い順）
+    ///
+    /// # Errors
+    /// - 指定されたIDの予約が1件でも見つからない場合（何も削除しない）
+    /// - 操作するユーザーに削除する権限がない予約がある場合（何も削除しない）
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        ids: &[UsageId],
+        actor_email: &EmailAddress,
+    ) -> Result<Vec<ResourceUsage>, ApplicationError> {
+        let mut usages = Vec::with_capacity(ids.len());
+        for id in ids {
+            let usage = self
+                .repository
+                .find_by_id(id)
+                .await?
+                .ok_or(ApplicationError::Repository(RepositoryError::NotFound))?;
+            self.authorization_policy
+                .authorize_delete(actor_email, &usage)
+                .map_err(|e| ApplicationError::Unauthorized(e.to_string()))?;
+            usages.push(usage);
+        }
+
+        for usage in &usages {
+            self.repository.delete(usage.id()).await?;
+        }
+
+        usages.sort_by_key(|usage| usage.time_period().start());
+        Ok(usages)
+    }
+}
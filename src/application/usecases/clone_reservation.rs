@@ -0,0 +1,71 @@
+use crate::application::error::ApplicationError;
+use crate::application::usecases::find_next_available_slot::FindNextAvailableSlotUseCase;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod, UsageId};
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+/// 複製した予約の内容（新しい予約の入力の初期値）
+#[derive(Debug, Clone)]
+pub struct ClonedReservation {
+    /// 元の予約と同じリソース
+    pub resources: Vec<Resource>,
+    /// 元の予約と同じ長さ
+    pub duration: Duration,
+    /// 同じリソースが同じ長さだけ空いている次の時間帯（見つからない場合は `None`）
+    pub period: Option<TimePeriod>,
+}
+
+/// 予約を複製するユースケース
+///
+/// 過去や終了間近の予約と同じリソース・同じ長さで、次に空いている時間帯を求める。
+/// 予約は作成せず、予約フォームの初期値として使う。備考やプロジェクト情報は複製しない。
+pub struct CloneReservationUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    next_slot: Arc<FindNextAvailableSlotUseCase<R>>,
+}
+
+impl<R: ResourceUsageRepository> CloneReservationUseCase<R> {
+    /// 新しいCloneReservationUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `next_slot` - 空いている時間帯を探すユースケース
+    pub fn new(repository: Arc<R>, next_slot: Arc<FindNextAvailableSlotUseCase<R>>) -> Self {
+        Self {
+            repository,
+            next_slot,
+        }
+    }
+
+    /// 予約を複製する
+    ///
+    /// # Arguments
+    /// * `id` - 複製する予約のID
+    /// * `now` - 現在時刻（この時刻以降の時間帯を探す）
+    ///
+    /// # Errors
+    /// - 指定されたIDの予約が見つからない場合
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        id: &UsageId,
+        now: DateTime<Utc>,
+    ) -> Result<ClonedReservation, ApplicationError> {
+        let usage = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::Repository(RepositoryError::NotFound))?;
+
+        let resources = usage.resources().clone();
+        let duration = usage.time_period().end() - usage.time_period().start();
+        let period = self.next_slot.execute(&resources, duration, now).await?;
+
+        Ok(ClonedReservation {
+            resources,
+            duration,
+            period,
+        })
+    }
+}
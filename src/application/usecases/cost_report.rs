@@ -0,0 +1,319 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::{
+    entity::ResourceUsage,
+    value_objects::{Resource, TimePeriod},
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::services::resource_usage::CostModel;
+use chrono::Duration;
+use std::sync::Arc;
+
+/// ユーザーごとの費用の合計
+#[derive(Debug, Clone)]
+pub struct UserCostTotal {
+    owner_email: EmailAddress,
+    gpu_time: Duration,
+    cost: f64,
+}
+
+impl UserCostTotal {
+    /// 予約者のメールアドレス
+    pub fn owner_email(&self) -> &EmailAddress {
+        &self.owner_email
+    }
+
+    /// GPUの予約時間の合計（GPU 1台につき1時間で1時間）
+    pub fn gpu_time(&self) -> Duration {
+        self.gpu_time
+    }
+
+    /// 費用の合計
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+}
+
+/// プロジェクトごとの費用の合計
+#[derive(Debug, Clone)]
+pub struct ProjectCostTotal {
+    project: Option<String>,
+    gpu_time: Duration,
+    cost: f64,
+}
+
+impl ProjectCostTotal {
+    /// プロジェクト名（プロジェクト名のない予約の合計の場合は `None`）
+    pub fn project(&self) -> Option<&str> {
+        self.project.as_deref()
+    }
+
+    /// GPUの予約時間の合計（GPU 1台につき1時間で1時間）
+    pub fn gpu_time(&self) -> Duration {
+        self.gpu_time
+    }
+
+    /// 費用の合計
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+}
+
+/// 期間内の費用の集計結果
+#[derive(Debug, Clone)]
+pub struct CostReport {
+    period: TimePeriod,
+    currency: String,
+    users: Vec<UserCostTotal>,
+    projects: Vec<ProjectCostTotal>,
+}
+
+impl CostReport {
+    /// 集計した期間
+    pub fn period(&self) -> &TimePeriod {
+        &self.period
+    }
+
+    /// 通貨の表記
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// ユーザーごとの合計（費用の多い順、GPUを予約していないユーザーは含まない）
+    pub fn users(&self) -> &[UserCostTotal] {
+        &self.users
+    }
+
+    /// プロジェクトごとの合計（費用の多い順、プロジェクト名のない予約の合計は最後）
+    pub fn projects(&self) -> &[ProjectCostTotal] {
+        &self.projects
+    }
+
+    /// 費用の総額
+    pub fn total_cost(&self) -> f64 {
+        self.users.iter().map(UserCostTotal::cost).sum()
+    }
+
+    /// 予約を集計する
+    ///
+    /// 期間の外にはみ出した部分は数えない。
+    fn aggregate(period: TimePeriod, cost_model: &CostModel, usages: &[ResourceUsage]) -> Self {
+        let mut users: Vec<UserCostTotal> = Vec::new();
+        let mut projects: Vec<ProjectCostTotal> = Vec::new();
+
+        for usage in usages {
+            let start = usage.time_period().start().max(period.start());
+            let end = usage.time_period().end().min(period.end());
+            if end <= start {
+                continue;
+            }
+            let gpus = usage
+                .resources()
+                .iter()
+                .filter(|r| matches!(r, Resource::Gpu(_)))
+                .count() as i32;
+            if gpus == 0 {
+                continue;
+            }
+            let gpu_time = (end - start) * gpus;
+            let cost = cost_model.cost_of(usage, &period);
+
+            match users
+                .iter_mut()
+                .find(|u| &u.owner_email == usage.owner_email())
+            {
+                Some(total) => {
+                    total.gpu_time += gpu_time;
+                    total.cost += cost;
+                }
+                None => users.push(UserCostTotal {
+                    owner_email: usage.owner_email().clone(),
+                    gpu_time,
+                    cost,
+                }),
+            }
+
+            let project = usage.metadata().project();
+            match projects
+                .iter_mut()
+                .find(|p| p.project.as_deref() == project)
+            {
+                Some(total) => {
+                    total.gpu_time += gpu_time;
+                    total.cost += cost;
+                }
+                None => projects.push(ProjectCostTotal {
+                    project: project.map(str::to_string),
+                    gpu_time,
+                    cost,
+                }),
+            }
+        }
+
+        users.sort_by(|a, b| {
+            b.cost
+                .total_cmp(&a.cost)
+                .then_with(|| a.owner_email.as_str().cmp(b.owner_email.as_str()))
+        });
+        projects.sort_by(|a, b| {
+            a.project
+                .is_none()
+                .cmp(&b.project.is_none())
+                .then_with(|| b.cost.total_cmp(&a.cost))
+                .then_with(|| a.project.cmp(&b.project))
+        });
+
+        Self {
+            period,
+            currency: cost_model.currency().to_string(),
+            users,
+            projects,
+        }
+    }
+}
+
+/// 期間内のGPUの予約の費用をユーザー・プロジェクトごとに集計するユースケース
+///
+/// 計算機の利用料を研究費に按分するために使う。費用はGPUのモデルごとの料金から計算する。
+/// 非公開の予約も集計に含める。
+pub struct CostReportUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    cost_model: CostModel,
+}
+
+impl<R: ResourceUsageRepository> CostReportUseCase<R> {
+    /// 新しいCostReportUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `cost_model` - GPUのモデルごとの料金
+    pub fn new(repository: Arc<R>, cost_model: CostModel) -> Self {
+        Self {
+            repository,
+            cost_model,
+        }
+    }
+
+    /// 指定期間の費用を集計
+    ///
+    /// # Arguments
+    /// * `period` - 集計する期間
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self, period: &TimePeriod) -> Result<CostReport, ApplicationError> {
+        let usages = self.repository.find_overlapping(period).await?;
+        Ok(CostReport::aggregate(
+            period.clone(),
+            &self.cost_model,
+            &usages,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, ReservationMetadata};
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use chrono::{TimeZone, Utc};
+
+    fn period(start: u32, end: u32) -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 1, 15, start, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 15, end, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn gpu(device: u32, model: &str) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, model.to_string()))
+    }
+
+    async fn save(
+        repo: &MockUsageRepository,
+        owner: &str,
+        (start, end): (u32, u32),
+        resources: Vec<Resource>,
+        project: Option<&str>,
+    ) {
+        let usage = ResourceUsage::new(
+            EmailAddress::new(format!("{}@example.com", owner)).unwrap(),
+            period(start, end),
+            resources,
+            None,
+        )
+        .unwrap()
+        .with_metadata(ReservationMetadata::new(project.map(str::to_string), None, None).unwrap());
+        repo.save(&usage).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_costs_per_user_and_project() {
+        let repo = MockUsageRepository::new();
+        save(
+            &repo,
+            "alice",
+            (9, 11),
+            vec![gpu(0, "A100"), gpu(1, "A100")],
+            Some("llm"),
+        )
+        .await;
+        // 集計期間の外にはみ出した部分は数えない
+        save(&repo, "bob", (18, 22), vec![gpu(2, "V100")], None).await;
+        save(&repo, "bob", (10, 11), vec![gpu(0, "A100")], Some("vision")).await;
+        save(&repo, "dave", (12, 13), vec![gpu(0, "A100")], Some("llm")).await;
+        // GPUを含まない予約は集計しない
+        save(
+            &repo,
+            "carol",
+            (9, 12),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            Some("llm"),
+        )
+        .await;
+        let cost_model = CostModel::new("円")
+            .with_hourly_rate("A100", 300.0)
+            .with_hourly_rate("V100", 100.0);
+
+        let report = CostReportUseCase::new(Arc::new(repo), cost_model)
+            .execute(&period(8, 20))
+            .await
+            .unwrap();
+
+        assert_eq!(report.period(), &period(8, 20));
+        assert_eq!(report.currency(), "円");
+        assert_eq!(report.total_cost(), 2000.0);
+
+        let users: Vec<(&str, i64, f64)> = report
+            .users()
+            .iter()
+            .map(|u| (u.owner_email().as_str(), u.gpu_time().num_hours(), u.cost()))
+            .collect();
+        assert_eq!(
+            users,
+            vec![
+                ("alice@example.com", 4, 1200.0),
+                ("bob@example.com", 3, 500.0),
+                ("dave@example.com", 1, 300.0),
+            ]
+        );
+
+        // プロジェクト名のない予約の合計は最後
+        let projects: Vec<(Option<&str>, i64, f64)> = report
+            .projects()
+            .iter()
+            .map(|p| (p.project(), p.gpu_time().num_hours(), p.cost()))
+            .collect();
+        assert_eq!(
+            projects,
+            vec![
+                (Some("llm"), 5, 1500.0),
+                (Some("vision"), 1, 300.0),
+                (None, 2, 200.0),
+            ]
+        );
+    }
+}
@@ -1,17 +1,55 @@
 use crate::application::error::ApplicationError;
+use crate::application::usecases::find_next_available_slot::SEARCH_DAYS;
+use crate::domain::aggregates::group::{Group, GroupError, GroupId};
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
 use crate::domain::aggregates::resource_usage::{
     entity::ResourceUsage,
-    value_objects::{Resource, TimePeriod, UsageId},
+    value_objects::{
+        ApprovalStatus, Priority, RecurrenceRule, ReservationMetadata, Resource, SeriesId,
+        TimePeriod, UsageId, Visibility,
+    },
 };
 use crate::domain::common::EmailAddress;
-use crate::domain::ports::repositories::ResourceUsageRepository;
-use crate::domain::services::ResourceConflictChecker;
+use crate::domain::ports::notifier::{ApprovalRequestSender, PreemptionNotifier};
+use crate::domain::ports::repositories::{
+    DeviceHealthRepository, IdentityLinkRepository, ResourceFreezeRepository,
+    ResourceUsageRepository, UsageQuery, UsageStatus,
+};
+use crate::domain::services::resource_usage::{
+    BookingWindowError, BookingWindowPolicy, FairShareAdvice, FairSharePolicy, PreemptionError,
+    PreemptionPolicy, ReservationLimitPolicy, ResourceConflict, ResourceConflictError,
+    SplitProposal,
+};
+use crate::domain::services::{ResourceAllocationService, ResourceConflictChecker};
+use chrono::{Duration, Utc};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// リソース使用予定を作成するユースケース
 pub struct CreateResourceUsageUseCase<R: ResourceUsageRepository> {
     repository: Arc<R>,
     conflict_checker: ResourceConflictChecker,
+    allocation_service: ResourceAllocationService,
+    freeze_repository: Option<Arc<dyn ResourceFreezeRepository>>,
+    device_health_repository: Option<Arc<dyn DeviceHealthRepository>>,
+    approval_required_rooms: HashSet<String>,
+    approval_request_sender: Option<Arc<dyn ApprovalRequestSender>>,
+    reservation_limits: ReservationLimitPolicy,
+    booking_windows: BookingWindowPolicy,
+    fair_share: Option<FairSharePolicy>,
+    admins: Vec<EmailAddress>,
+    groups: Vec<Group>,
+    identity_repo: Option<Arc<dyn IdentityLinkRepository>>,
+    preemption_notifier: Option<Arc<dyn PreemptionNotifier>>,
+}
+
+/// 横取りして作成した予約
+#[derive(Debug, Clone)]
+pub struct PreemptiveReservation {
+    /// 作成された予約のID
+    pub usage_id: UsageId,
+    /// 取り消した予約
+    pub preempted: Vec<ResourceUsage>,
 }
 
 impl<R: ResourceUsageRepository> CreateResourceUsageUseCase<R> {
@@ -24,53 +62,900 @@ impl<R: ResourceUsageRepository> CreateResourceUsageUseCase<R> {
         Self {
             repository,
             conflict_checker,
+            allocation_service: ResourceAllocationService::default(),
+            freeze_repository: None,
+            device_health_repository: None,
+            approval_required_rooms: HashSet::new(),
+            approval_request_sender: None,
+            reservation_limits: ReservationLimitPolicy::default(),
+            booking_windows: BookingWindowPolicy::default(),
+            fair_share: None,
+            admins: Vec::new(),
+            groups: Vec::new(),
+            identity_repo: None,
+            preemption_notifier: None,
         }
     }
 
+    /// 予約停止リポジトリを設定
+    ///
+    /// 設定した場合、予約停止中のリソースを停止開始以降に予約できなくなる。
+    pub fn with_freeze_repository(
+        mut self,
+        freeze_repository: Arc<dyn ResourceFreezeRepository>,
+    ) -> Self {
+        self.freeze_repository = Some(freeze_repository);
+        self
+    }
+
+    /// デバイスの状態のリポジトリを設定
+    ///
+    /// 設定した場合、使用停止中のデバイスを予約できなくなる。
+    pub fn with_device_health_repository(
+        mut self,
+        device_health_repository: Arc<dyn DeviceHealthRepository>,
+    ) -> Self {
+        self.device_health_repository = Some(device_health_repository);
+        self
+    }
+
+    /// ストレージのボリュームごとの容量（GB）を設定
+    ///
+    /// 設定した場合、同じボリュームの予約の確保容量の合計が容量を超える予約を競合とみなす。
+    pub fn with_storage_capacities(mut self, storage_capacities: HashMap<String, u64>) -> Self {
+        self.conflict_checker = self
+            .conflict_checker
+            .with_storage_capacities(storage_capacities);
+        self
+    }
+
+    /// ライセンスごとの席数を設定
+    ///
+    /// 設定した場合、同じライセンスの予約の確保席数の合計が席数を超える予約を競合とみなす。
+    pub fn with_license_seats(mut self, license_seats: HashMap<String, u32>) -> Self {
+        self.conflict_checker = self.conflict_checker.with_license_seats(license_seats);
+        self
+    }
+
+    /// 利用者ごとの同時予約の上限を設定
+    ///
+    /// 設定した場合、上限を超える予約を作成できなくなる。
+    pub fn with_reservation_limits(mut self, reservation_limits: ReservationLimitPolicy) -> Self {
+        self.reservation_limits = reservation_limits;
+        self
+    }
+
+    /// リソースごとの予約の受付期間を設定
+    ///
+    /// 設定した場合、開始が近すぎる予約や先すぎる予約を作成できなくなる。
+    pub fn with_booking_windows(mut self, booking_windows: BookingWindowPolicy) -> Self {
+        self.booking_windows = booking_windows;
+        self
+    }
+
+    /// 予約がリソースの受付期間内か確認
+    ///
+    /// 予約フォームの送信時に、日時の入力欄にエラーを表示するために使う。
+    ///
+    /// # Errors
+    /// いずれかのリソースの受付期間外の場合
+    pub fn check_booking_window(
+        &self,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Result<(), BookingWindowError> {
+        self.booking_windows
+            .check(time_period, resources, Utc::now())
+    }
+
+    /// 公平な利用のための助言ポリシーを設定
+    ///
+    /// 設定した場合、`fair_share_advice` で直近の利用実績に基づく助言を作成できる。
+    pub fn with_fair_share(mut self, fair_share: FairSharePolicy) -> Self {
+        self.fair_share = Some(fair_share);
+        self
+    }
+
+    /// 管理者を設定
+    ///
+    /// 管理者は優先度にかかわらず、重なる予約を横取りして予約できる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.admins = admins;
+        self
+    }
+
+    /// 予約を所有できるチームを設定
+    ///
+    /// メンバーは、所属するチームを所有者とする予約を作成できる。
+    pub fn with_groups(mut self, groups: Vec<Group>) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// 指定したユーザーが所属するチーム
+    pub fn groups_of(&self, email: &EmailAddress) -> Vec<&Group> {
+        self.groups
+            .iter()
+            .filter(|group| group.is_member(email))
+            .collect()
+    }
+
+    /// 指定したIDのチーム
+    pub fn group(&self, id: &GroupId) -> Option<&Group> {
+        self.groups.iter().find(|group| group.id() == id)
+    }
+
+    /// 横取りされた予約の予約者への通知の送信先を設定
+    ///
+    /// 設定した場合、予約を横取りしたときに、取り消した予約の予約者にSlackで知らせる。
+    pub fn with_preemption_notifier(
+        mut self,
+        identity_repo: Arc<dyn IdentityLinkRepository>,
+        preemption_notifier: Arc<dyn PreemptionNotifier>,
+    ) -> Self {
+        self.identity_repo = Some(identity_repo);
+        self.preemption_notifier = Some(preemption_notifier);
+        self
+    }
+
+    /// 承認が必要な部屋と、承認依頼の送信先を設定
+    ///
+    /// 設定した部屋を含む予約は承認待ちとして作成され、承認者に承認依頼が送られる。
+    pub fn with_approval(
+        mut self,
+        approval_required_rooms: HashSet<String>,
+        approval_request_sender: Arc<dyn ApprovalRequestSender>,
+    ) -> Self {
+        self.approval_required_rooms = approval_required_rooms;
+        self.approval_request_sender = Some(approval_request_sender);
+        self
+    }
+
+    /// 指定したリソースの予約に承認が必要かどうか
+    pub fn requires_approval(&self, resources: &[Resource]) -> bool {
+        resources.iter().any(|resource| match resource {
+            Resource::Room { name } => self.approval_required_rooms.contains(name),
+            Resource::Gpu(_)
+            | Resource::Instrument { .. }
+            | Resource::Custom { .. }
+            | Resource::Storage { .. }
+            | Resource::License { .. } => false,
+        })
+    }
+
     /// リソース使用予定を作成
     ///
+    /// 承認が必要な部屋を含む場合は承認待ちとして作成し、承認者に承認依頼を送る。
+    ///
     /// # Arguments
     /// * `owner_email` - 所有者のメールアドレス
     /// * `time_period` - 使用期間
     /// * `resources` - 使用するリソースのリスト
     /// * `notes` - 備考（オプション）
+    /// * `metadata` - メタデータ（プロジェクト名・実験IDなど）
+    /// * `visibility` - 公開範囲
+    /// * `priority` - 優先度
+    /// * `group` - 予約を所有するチーム（予約者個人の予約の場合は `None`）
     ///
     /// # Returns
     /// 作成されたResourceUsageのID
     ///
     /// # Errors
+    /// - 予約者が所属していないチームを指定した場合
+    /// - リソースの予約の受付期間外の場合
+    /// - 予約停止中のリソースを停止開始以降に予約しようとした場合
+    /// - 使用停止中のデバイスを予約しようとした場合
     /// - 指定期間と重複するリソース使用がある場合
+    /// - 利用者ごとの同時予約の上限を超える場合
     /// - リポジトリエラー
+    #[allow(clippy::too_many_arguments)]
     pub async fn execute(
         &self,
         owner_email: EmailAddress,
         time_period: TimePeriod,
         resources: Vec<Resource>,
         notes: Option<String>,
+        metadata: ReservationMetadata,
+        visibility: Visibility,
+        priority: Priority,
+        group: Option<GroupId>,
     ) -> Result<UsageId, ApplicationError> {
-        // 競合チェック
-        self.conflict_checker
-            .check_conflicts(self.repository.as_ref(), &time_period, &resources, None)
-            .await
-            .map_err(|e| match e {
-                crate::domain::services::resource_usage::errors::ConflictCheckError::Conflict(
-                    conflict_err,
-                ) => ApplicationError::ResourceConflict {
-                    resource_description: conflict_err.resource_description.clone(),
-                    conflicting_usage_id: conflict_err.conflicting_usage_id.as_str().to_string(),
-                },
-                crate::domain::services::resource_usage::errors::ConflictCheckError::Repository(
-                    repo_err,
-                ) => ApplicationError::Repository(repo_err),
-            })?;
+        self.ensure_group_member(&owner_email, group.as_ref())?;
+        self.check_booking_window(&time_period, &resources)?;
+        self.ensure_available(&time_period, &resources).await?;
+        let held = self.held_usages(&owner_email).await?;
+        self.reservation_limits
+            .check(&held, &time_period, &resources)?;
 
         // 新しいResourceUsageを作成（UUID自動生成）
-        let usage = ResourceUsage::new(owner_email, time_period, resources, notes)?;
+        let approval_status = self.approval_status_for(&resources);
+        let usage = ResourceUsage::new(owner_email, time_period, resources, notes)?
+            .with_visibility(visibility)
+            .with_approval_status(approval_status)
+            .with_priority(priority)
+            .with_group(group)
+            .with_metadata(metadata);
 
         // 保存
         self.repository.save(&usage).await?;
+        self.request_approval_if_pending(&usage).await;
 
         // 生成されたIDを返す
         Ok(usage.id().clone())
     }
+
+    /// 競合する予約を取り消して（横取りして）リソース使用予定を作成
+    ///
+    /// 競合する予約がすべて新しい予約より優先度が低く開始前の場合（予約者が管理者の場合は常に）、
+    /// 新しい予約を保存してから、それらを取り消す。取り消しに失敗した場合は、新しい予約を削除し、
+    /// 取り消し済みの予約を元に戻す。取り消した予約の予約者には、
+    /// 同じリソース・長さで新しい予約の終了後に空いている時間帯を添えて知らせる。
+    ///
+    /// # Arguments
+    /// * `owner_email` - 所有者のメールアドレス
+    /// * `time_period` - 使用期間
+    /// * `resources` - 使用するリソースのリスト
+    /// * `notes` - 備考（オプション）
+    /// * `metadata` - メタデータ（プロジェクト名・実験IDなど）
+    /// * `visibility` - 公開範囲
+    /// * `priority` - 優先度
+    /// * `group` - 予約を所有するチーム（予約者個人の予約の場合は `None`）
+    ///
+    /// # Returns
+    /// 作成された予約のIDと、取り消した予約
+    ///
+    /// # Errors
+    /// - 予約者が所属していないチームを指定した場合
+    /// - リソースの予約の受付期間外の場合
+    /// - 予約停止中のリソースを停止開始以降に予約しようとした場合
+    /// - 使用停止中のデバイスを予約しようとした場合
+    /// - 横取りできない予約と競合する場合
+    /// - 利用者ごとの同時予約の上限を超える場合
+    /// - リポジトリエラー
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_preempting(
+        &self,
+        owner_email: EmailAddress,
+        time_period: TimePeriod,
+        resources: Vec<Resource>,
+        notes: Option<String>,
+        metadata: ReservationMetadata,
+        visibility: Visibility,
+        priority: Priority,
+        group: Option<GroupId>,
+    ) -> Result<PreemptiveReservation, ApplicationError> {
+        self.ensure_group_member(&owner_email, group.as_ref())?;
+        self.check_booking_window(&time_period, &resources)?;
+        self.ensure_not_frozen(&time_period, &resources).await?;
+        // 予約を横取りしても上限を超える場合は予約できない
+        self.conflict_checker.check_capacity(&resources)?;
+        let conflicts = self.find_conflicts(&time_period, &resources).await?;
+        let preempted = self.preemptable(&owner_email, priority, &conflicts)?;
+
+        let held: Vec<ResourceUsage> = self
+            .held_usages(&owner_email)
+            .await?
+            .into_iter()
+            .filter(|usage| !preempted.iter().any(|p| p.id() == usage.id()))
+            .collect();
+        self.reservation_limits
+            .check(&held, &time_period, &resources)?;
+
+        // 横取りする予約を取り消せば競合しないことを、取り消す前に確認する
+        let excluded: Vec<UsageId> = preempted.iter().map(|usage| usage.id().clone()).collect();
+        let remaining = self
+            .conflict_checker
+            .find_conflicts_excluding(
+                self.repository.as_ref(),
+                &time_period,
+                &resources,
+                &excluded,
+            )
+            .await?;
+        if let Some(conflict) = ResourceConflictError::from_conflicts(&remaining) {
+            return Err(conflict.into());
+        }
+
+        let approval_status = self.approval_status_for(&resources);
+        let usage = ResourceUsage::new(owner_email, time_period, resources, notes)?
+            .with_visibility(visibility)
+            .with_approval_status(approval_status)
+            .with_priority(priority)
+            .with_group(group)
+            .with_metadata(metadata);
+        self.repository.save(&usage).await?;
+
+        // 新しい予約を保存できてから取り消す
+        for (index, preempted_usage) in preempted.iter().enumerate() {
+            if let Err(e) = self.repository.delete(preempted_usage.id()).await {
+                self.rollback_preemption(&usage, &preempted[..index]).await;
+                return Err(e.into());
+            }
+        }
+        self.request_approval_if_pending(&usage).await;
+
+        for preempted_usage in &preempted {
+            self.notify_preempted(preempted_usage, &usage).await;
+        }
+
+        Ok(PreemptiveReservation {
+            usage_id: usage.id().clone(),
+            preempted,
+        })
+    }
+
+    /// 競合する予約をすべて横取りできるか判定
+    ///
+    /// # Returns
+    /// 取り消すことになる予約
+    ///
+    /// # Errors
+    /// 横取りできない予約がある場合
+    pub fn preemptable(
+        &self,
+        owner_email: &EmailAddress,
+        priority: Priority,
+        conflicts: &[ResourceConflict],
+    ) -> Result<Vec<ResourceUsage>, PreemptionError> {
+        let is_admin = self
+            .admins
+            .iter()
+            .any(|admin| admin.as_str().eq_ignore_ascii_case(owner_email.as_str()));
+        PreemptionPolicy::preemptable(priority, is_admin, conflicts, Utc::now())
+    }
+
+    /// 繰り返し予約を作成
+    ///
+    /// 最初の使用期間から繰り返し規則に従って、同じシリーズIDを持つリソース使用予定をまとめて作成する。
+    /// いずれかの回が予約できない場合は、どの回も作成しない。
+    ///
+    /// # Arguments
+    /// * `owner_email` - 所有者のメールアドレス
+    /// * `first_period` - 最初の回の使用期間
+    /// * `recurrence` - 繰り返し規則
+    /// * `resources` - 使用するリソースのリスト
+    /// * `notes` - 備考（オプション）
+    /// * `metadata` - メタデータ（プロジェクト名・実験IDなど）
+    /// * `visibility` - 公開範囲
+    /// * `priority` - 優先度
+    /// * `group` - 予約を所有するチーム（予約者個人の予約の場合は `None`）
+    ///
+    /// # Returns
+    /// 作成されたResourceUsageのID（開始時刻の早い順）
+    ///
+    /// # Errors
+    /// - 予約者が所属していないチームを指定した場合
+    /// - 繰り返し規則が不正な場合
+    /// - いずれかの回がリソースの予約の受付期間外の場合
+    /// - いずれかの回で予約停止中・使用停止中・既存の予約と競合する場合
+    /// - 各回を順に加えていったときに利用者ごとの同時予約の上限を超える場合
+    /// - リポジトリエラー
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_series(
+        &self,
+        owner_email: EmailAddress,
+        first_period: TimePeriod,
+        recurrence: &RecurrenceRule,
+        resources: Vec<Resource>,
+        notes: Option<String>,
+        metadata: ReservationMetadata,
+        visibility: Visibility,
+        priority: Priority,
+        group: Option<GroupId>,
+    ) -> Result<Vec<UsageId>, ApplicationError> {
+        self.ensure_group_member(&owner_email, group.as_ref())?;
+        let periods = recurrence.occurrences(&first_period)?;
+        self.conflict_checker.check_capacity(&resources)?;
+
+        // すべての回を先にチェックしてから作成する
+        for period in &periods {
+            self.check_booking_window(period, &resources)?;
+            self.ensure_not_frozen(period, &resources).await?;
+        }
+        let conflicts = self
+            .conflict_checker
+            .find_series_conflicts(self.repository.as_ref(), &periods, &resources, None)
+            .await?;
+        if let Some(conflict) = ResourceConflictError::from_conflicts(&conflicts) {
+            return Err(conflict.into());
+        }
+        let mut held = self.held_usages(&owner_email).await?;
+        for period in &periods {
+            self.reservation_limits.check(&held, period, &resources)?;
+            held.push(ResourceUsage::new(
+                owner_email.clone(),
+                period.clone(),
+                resources.clone(),
+                None,
+            )?);
+        }
+
+        let series_id = SeriesId::new();
+        let approval_status = self.approval_status_for(&resources);
+        let mut usage_ids = Vec::with_capacity(periods.len());
+        for period in periods {
+            let usage = ResourceUsage::new(
+                owner_email.clone(),
+                period,
+                resources.clone(),
+                notes.clone(),
+            )?
+            .with_visibility(visibility)
+            .with_series_id(Some(series_id.clone()))
+            .with_recurrence(Some(recurrence.clone()))
+            .with_approval_status(approval_status)
+            .with_priority(priority)
+            .with_group(group.clone())
+            .with_metadata(metadata.clone());
+
+            self.repository.save(&usage).await?;
+            self.request_approval_if_pending(&usage).await;
+            usage_ids.push(usage.id().clone());
+        }
+
+        Ok(usage_ids)
+    }
+
+    /// 予約者が予約を所有するチームのメンバーであることを確認する
+    fn ensure_group_member(
+        &self,
+        owner_email: &EmailAddress,
+        group_id: Option<&GroupId>,
+    ) -> Result<(), GroupError> {
+        let Some(group_id) = group_id else {
+            return Ok(());
+        };
+        let group = self.group(group_id).ok_or_else(|| GroupError::NotFound {
+            group_id: group_id.as_str().to_string(),
+        })?;
+        if !group.is_member(owner_email) {
+            return Err(GroupError::NotMember {
+                group_name: group.name().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// 作成する予約の承認状態
+    fn approval_status_for(&self, resources: &[Resource]) -> ApprovalStatus {
+        if self.requires_approval(resources) {
+            ApprovalStatus::Pending
+        } else {
+            ApprovalStatus::Approved
+        }
+    }
+
+    /// 承認待ちの予約について承認依頼を送る
+    ///
+    /// 予約自体は作成済みのため、送信に失敗しても警告を記録するだけにとどめる。
+    async fn request_approval_if_pending(&self, usage: &ResourceUsage) {
+        if !usage.approval_status().is_pending() {
+            return;
+        }
+        if let Some(sender) = &self.approval_request_sender
+            && let Err(e) = sender.request_approval(usage).await
+        {
+            tracing::warn!(
+                "Failed to request approval for usage {}: {}",
+                usage.id().as_str(),
+                e
+            );
+        }
+    }
+
+    /// 横取りの途中で失敗した場合に、新しい予約を削除し、取り消し済みの予約を元に戻す
+    ///
+    /// 元のエラーを返すため、元に戻せなかった予約はエラーとして記録するだけにとどめる。
+    async fn rollback_preemption(&self, usage: &ResourceUsage, deleted: &[ResourceUsage]) {
+        if let Err(e) = self.repository.delete(usage.id()).await {
+            tracing::error!(
+                "Failed to remove usage {} after a failed preemption: {}",
+                usage.id().as_str(),
+                e
+            );
+        }
+        for preempted in deleted {
+            if let Err(e) = self.repository.save(preempted).await {
+                tracing::error!(
+                    "Failed to restore preempted usage {}: {}",
+                    preempted.id().as_str(),
+                    e
+                );
+            }
+        }
+    }
+
+    /// 横取りされた予約の予約者に知らせる
+    ///
+    /// 予約の横取り自体は完了しているため、送信に失敗しても警告を記録するだけにとどめる。
+    async fn notify_preempted(&self, preempted: &ResourceUsage, preempting: &ResourceUsage) {
+        let (Some(identity_repo), Some(notifier)) =
+            (&self.identity_repo, &self.preemption_notifier)
+        else {
+            return;
+        };
+        let user_id = match identity_repo.find_by_email(preempted.owner_email()).await {
+            Ok(link) => link.and_then(|link| {
+                link.get_identity_for_system(&ExternalSystem::Slack)
+                    .map(|identity| identity.user_id().to_string())
+            }),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to look up owner of preempted usage {}: {}",
+                    preempted.id().as_str(),
+                    e
+                );
+                None
+            }
+        };
+        // Slackと紐付いていない予約者には知らせる手段がない
+        let Some(user_id) = user_id else {
+            return;
+        };
+
+        let suggestion = self.reschedule_suggestion(preempted, preempting).await;
+        if let Err(e) = notifier
+            .notify_preempted(&user_id, preempted, preempting, suggestion.as_ref())
+            .await
+        {
+            tracing::warn!(
+                "Failed to notify owner of preempted usage {}: {}",
+                preempted.id().as_str(),
+                e
+            );
+        }
+    }
+
+    /// 横取りされた予約の代わりに、横取りした予約の終了後で同じリソース・長さの空いている時間帯を探す
+    async fn reschedule_suggestion(
+        &self,
+        preempted: &ResourceUsage,
+        preempting: &ResourceUsage,
+    ) -> Option<TimePeriod> {
+        let period = preempted.time_period();
+        let start = preempting.time_period().end().max(Utc::now());
+        let search_range = TimePeriod::new(start, start + Duration::days(SEARCH_DAYS)).ok()?;
+        self.allocation_service
+            .find_next_slot(
+                self.repository.as_ref(),
+                &search_range,
+                preempted.resources(),
+                period.end() - period.start(),
+            )
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to find a slot for preempted usage: {}", e);
+                None
+            })
+    }
+
+    /// 予約停止中のリソースを停止開始以降に予約しようとしておらず、
+    /// 使用停止中のデバイスも含まないことを確認
+    async fn ensure_not_frozen(
+        &self,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Result<(), ApplicationError> {
+        if let Some(freeze_repository) = &self.freeze_repository {
+            for freeze in freeze_repository.find_all().await? {
+                freeze.check(time_period, resources)?;
+            }
+        }
+        if let Some(device_health_repository) = &self.device_health_repository {
+            for health in device_health_repository.find_all().await? {
+                health.check(resources)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 予約停止中・使用停止中でなく、既存の予約とも競合しないことを確認
+    async fn ensure_available(
+        &self,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Result<(), ApplicationError> {
+        // 予約停止・使用停止チェック
+        self.ensure_not_frozen(time_period, resources).await?;
+
+        // 競合チェック
+        self.conflict_checker
+            .check_conflicts(self.repository.as_ref(), time_period, resources, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// 上限の計算に数える利用者の予約（終了していないもの）を取得
+    ///
+    /// 上限が設定されていない場合はリポジトリを参照しない。
+    async fn held_usages(
+        &self,
+        owner: &EmailAddress,
+    ) -> Result<Vec<ResourceUsage>, ApplicationError> {
+        if self.reservation_limits.is_unlimited() {
+            return Ok(Vec::new());
+        }
+        let now = Utc::now();
+        let usages = self
+            .repository
+            .query(&UsageQuery::new().with_owner(owner.clone()))
+            .await?;
+        Ok(usages
+            .into_iter()
+            .filter(|usage| UsageStatus::of(usage, now) != UsageStatus::Ended)
+            .collect())
+    }
+
+    /// 希望した時間帯・リソースと競合する既存の予約を取得
+    ///
+    /// 予約フォームの送信時に、競合するリソースごとに予約者と時間帯を示すために使う。
+    ///
+    /// # Arguments
+    /// * `time_period` - 希望する使用期間
+    /// * `resources` - 希望するリソースのリスト
+    ///
+    /// # Returns
+    /// 競合したリソースと既存の予約の組。競合がない場合は空
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn find_conflicts(
+        &self,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Result<Vec<ResourceConflict>, ApplicationError> {
+        Ok(self
+            .conflict_checker
+            .find_conflicts(self.repository.as_ref(), time_period, resources, None)
+            .await?)
+    }
+
+    /// 繰り返し予約のすべての回について、既存の予約との競合を取得
+    ///
+    /// 予約フォームの送信時に、どの回がどの予約と競合するかをまとめて示すために使う。
+    ///
+    /// # Arguments
+    /// * `first_period` - 最初の回の使用期間
+    /// * `recurrence` - 繰り返し規則
+    /// * `resources` - 希望するリソースのリスト
+    ///
+    /// # Returns
+    /// 競合したリソースと既存の予約の組（回の順）。競合がない場合は空
+    ///
+    /// # Errors
+    /// - 繰り返し規則が不正な場合
+    /// - リポジトリエラー
+    pub async fn find_series_conflicts(
+        &self,
+        first_period: &TimePeriod,
+        recurrence: &RecurrenceRule,
+        resources: &[Resource],
+    ) -> Result<Vec<ResourceConflict>, ApplicationError> {
+        let periods = recurrence.occurrences(first_period)?;
+        Ok(self
+            .conflict_checker
+            .find_series_conflicts(self.repository.as_ref(), &periods, resources, None)
+            .await?)
+    }
+
+    /// 直近の利用実績に基づく公平な利用のための助言を作成
+    ///
+    /// 予約者が同じモデルのGPUを公平な配分より多く使っている場合に、
+    /// 同じ時間帯に空いている別のサーバーの提案とあわせて返す。予約の可否には影響しない。
+    ///
+    /// # Arguments
+    /// * `owner_email` - 予約者のメールアドレス
+    /// * `time_period` - 予約する期間
+    /// * `resources` - 予約するリソースのリスト
+    ///
+    /// # Returns
+    /// 助言ポリシーが未設定の場合や、助言がない場合は `None`
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn fair_share_advice(
+        &self,
+        owner_email: &EmailAddress,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Result<Option<FairShareAdvice>, ApplicationError> {
+        let Some(policy) = &self.fair_share else {
+            return Ok(None);
+        };
+        let now = Utc::now();
+        let Some(history_period) = policy.history_period(now) else {
+            return Ok(None);
+        };
+        let history = self.repository.find_overlapping(&history_period).await?;
+        let busy = self.repository.find_overlapping(time_period).await?;
+        Ok(policy.advise(owner_email, now, &history, &busy, time_period, resources))
+    }
+
+    /// 競合している予約の分割案を計算
+    ///
+    /// 希望した時間帯とリソースの一部だけが既存の予約と競合している場合に、
+    /// 空いている部分だけを予約する分割案を返します。
+    /// 分割案の各区画は `execute` でそれぞれ予約できます。
+    ///
+    /// # Arguments
+    /// * `time_period` - 希望する使用期間
+    /// * `resources` - 希望するリソースのリスト
+    ///
+    /// # Returns
+    /// 競合がない場合は `None`
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn propose_split(
+        &self,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Result<Option<SplitProposal>, ApplicationError> {
+        Ok(self
+            .allocation_service
+            .propose_split(self.repository.as_ref(), time_period, resources, None)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+
+    fn email(address: &str) -> EmailAddress {
+        EmailAddress::new(address.to_string()).unwrap()
+    }
+
+    fn gpu(device: u32) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()))
+    }
+
+    fn tomorrow(start_hour: i64, end_hour: i64) -> TimePeriod {
+        let base = Utc::now() + Duration::days(1);
+        TimePeriod::new(
+            base + Duration::hours(start_hour),
+            base + Duration::hours(end_hour),
+        )
+        .unwrap()
+    }
+
+    async fn save_background(repo: &MockUsageRepository, device: u32) -> ResourceUsage {
+        let usage = ResourceUsage::new(
+            email("bob@example.com"),
+            tomorrow(0, 2),
+            vec![gpu(device)],
+            None,
+        )
+        .unwrap()
+        .with_priority(Priority::Background);
+        repo.save(&usage).await.unwrap();
+        usage
+    }
+
+    async fn preempt(
+        usecase: &CreateResourceUsageUseCase<MockUsageRepository>,
+        resources: Vec<Resource>,
+    ) -> Result<PreemptiveReservation, ApplicationError> {
+        usecase
+            .execute_preempting(
+                email("alice@example.com"),
+                tomorrow(0, 2),
+                resources,
+                None,
+                ReservationMetadata::default(),
+                Visibility::Public,
+                Priority::Deadline,
+                None,
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_storage_larger_than_volume() {
+        let repo = MockUsageRepository::new();
+        let usecase = CreateResourceUsageUseCase::new(Arc::new(repo.clone()))
+            .with_storage_capacities(HashMap::from([("scratch".to_string(), 1000)]));
+        let storage = Resource::Storage {
+            volume: "scratch".to_string(),
+            gigabytes: 2000,
+        };
+
+        let result = usecase
+            .execute(
+                email("alice@example.com"),
+                tomorrow(0, 2),
+                vec![storage],
+                None,
+                ReservationMetadata::default(),
+                Visibility::Public,
+                Priority::Normal,
+                None,
+            )
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::CapacityExceeded(_))));
+        assert!(repo.find_future().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_preempting_replaces_lower_priority_usages() {
+        let repo = MockUsageRepository::new();
+        let first = save_background(&repo, 0).await;
+        let second = save_background(&repo, 1).await;
+        let usecase = CreateResourceUsageUseCase::new(Arc::new(repo.clone()));
+
+        let reservation = preempt(&usecase, vec![gpu(0), gpu(1)]).await.unwrap();
+
+        assert_eq!(reservation.preempted.len(), 2);
+        let remaining = repo.find_future().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id(), &reservation.usage_id);
+        assert!(repo.find_by_id(first.id()).await.unwrap().is_none());
+        assert!(repo.find_by_id(second.id()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_preempting_keeps_usages_when_save_fails() {
+        let repo = MockUsageRepository::new();
+        let existing = save_background(&repo, 0).await;
+        repo.fail_saves(true);
+        let usecase = CreateResourceUsageUseCase::new(Arc::new(repo.clone()));
+
+        let result = preempt(&usecase, vec![gpu(0)]).await;
+
+        assert!(matches!(result, Err(ApplicationError::Repository(_))));
+        let remaining = repo.find_future().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id(), existing.id());
+    }
+
+    #[tokio::test]
+    async fn test_preempting_restores_usages_when_delete_fails() {
+        let repo = MockUsageRepository::new();
+        let first = save_background(&repo, 0).await;
+        let second = save_background(&repo, 1).await;
+        let usecase = CreateResourceUsageUseCase::new(Arc::new(repo.clone()));
+        // 取り消しの順は競合の順（リソースの順）
+        repo.fail_delete_of(second.id());
+
+        let result = preempt(&usecase, vec![gpu(0), gpu(1)]).await;
+
+        assert!(matches!(result, Err(ApplicationError::Repository(_))));
+        let mut remaining: Vec<UsageId> = repo
+            .find_future()
+            .await
+            .unwrap()
+            .iter()
+            .map(|usage| usage.id().clone())
+            .collect();
+        remaining.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let mut expected = vec![first.id().clone(), second.id().clone()];
+        expected.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        assert_eq!(remaining, expected);
+    }
+
+    #[tokio::test]
+    async fn test_preempting_rejects_conflicts_that_cannot_be_preempted() {
+        let repo = MockUsageRepository::new();
+        let existing = save_background(&repo, 0).await;
+        let same_priority = ResourceUsage::new(
+            email("carol@example.com"),
+            tomorrow(0, 2),
+            vec![gpu(1)],
+            None,
+        )
+        .unwrap()
+        .with_priority(Priority::Deadline);
+        repo.save(&same_priority).await.unwrap();
+        let usecase = CreateResourceUsageUseCase::new(Arc::new(repo.clone()));
+
+        let result = preempt(&usecase, vec![gpu(0), gpu(1)]).await;
+
+        assert!(matches!(result, Err(ApplicationError::Preemption(_))));
+        assert!(repo.find_by_id(existing.id()).await.unwrap().is_some());
+        assert_eq!(repo.find_future().await.unwrap().len(), 2);
+    }
 }
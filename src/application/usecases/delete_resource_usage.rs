@@ -1,8 +1,13 @@
 use crate::application::error::ApplicationError;
+use crate::domain::aggregates::group::Group;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
 use crate::domain::aggregates::resource_usage::value_objects::UsageId;
 use crate::domain::common::EmailAddress;
-use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::domain::ports::repositories::{
+    RepositoryError, ResourceUsageRepository, UsageQuery, UsageStatus,
+};
 use crate::domain::services::{AuthorizationPolicy, ResourceUsageAuthorizationPolicy};
+use chrono::Utc;
 use std::sync::Arc;
 
 /// リソース使用予定を削除するユースケース
@@ -24,6 +29,30 @@ impl<R: ResourceUsageRepository> DeleteResourceUsageUseCase<R> {
         }
     }
 
+    /// 管理者を設定
+    ///
+    /// 管理者は他のユーザーの予約も削除できる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_admins(admins);
+        self
+    }
+
+    /// モデレーターを設定
+    ///
+    /// モデレーターは他のユーザーの予約も削除できる。
+    pub fn with_moderators(mut self, moderators: Vec<EmailAddress>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_moderators(moderators);
+        self
+    }
+
+    /// チームを設定
+    ///
+    /// チームが所有する予約は、そのチームのメンバーも削除できる。
+    pub fn with_groups(mut self, groups: Vec<Group>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_groups(groups);
+        self
+    }
+
     /// リソース使用予定を削除
     ///
     /// # Arguments
@@ -35,7 +64,7 @@ impl<R: ResourceUsageRepository> DeleteResourceUsageUseCase<R> {
     ///
     /// # Errors
     /// - 指定されたIDの予約が見つからない場合
-    /// - 所有者が一致しない場合
+    /// - 操作する権限がない場合
     /// - リポジトリエラー
     pub async fn execute(
         &self,
@@ -59,4 +88,44 @@ impl<R: ResourceUsageRepository> DeleteResourceUsageUseCase<R> {
 
         Ok(())
     }
+
+    /// 指定したユーザーの終了していないリソース使用予定をすべて削除
+    ///
+    /// # Arguments
+    /// * `owner_email` - 削除する予約の所有者
+    /// * `actor_email` - 操作するユーザーのメールアドレス（権限チェック用）
+    ///
+    /// # Returns
+    /// 削除したリソース使用予定（開始時刻の早い順）
+    ///
+    /// # Errors
+    /// - 操作するユーザーに削除する権限がない場合（何も削除しない）
+    /// - リポジトリエラー
+    pub async fn execute_for_owner(
+        &self,
+        owner_email: &EmailAddress,
+        actor_email: &EmailAddress,
+    ) -> Result<Vec<ResourceUsage>, ApplicationError> {
+        let now = Utc::now();
+        let usages: Vec<ResourceUsage> = self
+            .repository
+            .query(&UsageQuery::new().with_owner(owner_email.clone()))
+            .await?
+            .into_iter()
+            .filter(|usage| UsageStatus::of(usage, now) != UsageStatus::Ended)
+            .collect();
+
+        // 一部だけ削除されることがないよう、先にすべての権限を確認する
+        for usage in &usages {
+            self.authorization_policy
+                .authorize_delete(actor_email, usage)
+                .map_err(|e| ApplicationError::Unauthorized(e.to_string()))?;
+        }
+
+        for usage in &usages {
+            self.repository.delete(usage.id()).await?;
+        }
+
+        Ok(usages)
+    }
 }
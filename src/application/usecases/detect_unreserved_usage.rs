@@ -0,0 +1,256 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::ports::gpu_monitor::{GpuProcessMonitor, UnreservedGpuUsage};
+use crate::domain::ports::notifier::UnreservedUsageNotifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use chrono::{Duration, Utc};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+/// 予約なしでのGPUの使用を検出して警告するユースケース
+///
+/// サーバーのGPUで実行中のプロセスと現在の予約を突き合わせ、
+/// 予約に含まれないGPUでプロセスを実行しているUNIXユーザーを警告する。
+/// 同じデバイス・ユーザーの使用は、使用が続く間は一度だけ警告する。
+pub struct DetectUnreservedUsageUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    monitor: Arc<dyn GpuProcessMonitor>,
+    notifier: Arc<dyn UnreservedUsageNotifier>,
+    /// 警告済みの（サーバー名, デバイス番号, UNIXユーザー名）
+    warned: tokio::sync::Mutex<HashSet<(String, u32, String)>>,
+}
+
+impl<R: ResourceUsageRepository> DetectUnreservedUsageUseCase<R> {
+    /// 新しいDetectUnreservedUsageUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `monitor` - GPUの使用状況の取得サービス
+    /// * `notifier` - 警告の送信先
+    pub fn new(
+        repository: Arc<R>,
+        monitor: Arc<dyn GpuProcessMonitor>,
+        notifier: Arc<dyn UnreservedUsageNotifier>,
+    ) -> Self {
+        Self {
+            repository,
+            monitor,
+            notifier,
+            warned: tokio::sync::Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 予約なしでのGPUの使用を検出して警告する
+    ///
+    /// 個々のサーバーの使用状況の取得や警告の送信に失敗しても残りの処理は継続し、次回の実行で再試行する。
+    ///
+    /// # Returns
+    /// 新たに警告した使用の一覧
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self) -> Result<Vec<UnreservedGpuUsage>, ApplicationError> {
+        let now = Utc::now();
+        let instant = TimePeriod::new(now, now + Duration::seconds(1))?;
+        let reserved: HashSet<(String, u32)> = self
+            .repository
+            .find_overlapping(&instant)
+            .await?
+            .iter()
+            .filter(|usage| usage.time_period().start() <= now)
+            .flat_map(|usage| usage.resources())
+            .filter_map(|resource| match resource {
+                Resource::Gpu(gpu) => Some((gpu.server().to_string(), gpu.device_number())),
+                _ => None,
+            })
+            .collect();
+
+        let mut warned = self.warned.lock().await;
+        let mut notified = Vec::new();
+        for server in self.monitor.monitored_servers() {
+            let processes = match self.monitor.processes(&server).await {
+                Ok(processes) => processes,
+                Err(e) => {
+                    tracing::warn!("Failed to query GPU processes on '{}': {}", server, e);
+                    continue;
+                }
+            };
+
+            // デバイス・ユーザーごとにプロセスをまとめる
+            let mut unreserved: BTreeMap<(u32, String), Vec<u32>> = BTreeMap::new();
+            for process in processes {
+                if reserved.contains(&(server.clone(), process.device_number)) {
+                    continue;
+                }
+                unreserved
+                    .entry((process.device_number, process.unix_user))
+                    .or_default()
+                    .push(process.pid);
+            }
+
+            // 使用が終わった（または予約された）ものの記録は破棄し、再び使われたら警告する
+            warned.retain(|(warned_server, device_number, unix_user)| {
+                *warned_server != server
+                    || unreserved.contains_key(&(*device_number, unix_user.clone()))
+            });
+
+            for ((device_number, unix_user), mut pids) in unreserved {
+                let key = (server.clone(), device_number, unix_user.clone());
+                if warned.contains(&key) {
+                    continue;
+                }
+                pids.sort_unstable();
+                let usage = UnreservedGpuUsage {
+                    server: server.clone(),
+                    device_number,
+                    unix_user,
+                    pids,
+                };
+                match self.notifier.notify_unreserved(&usage).await {
+                    Ok(()) => {
+                        warned.insert(key);
+                        notified.push(usage);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to send unreserved GPU usage warning: {}", e);
+                    }
+                }
+            }
+        }
+
+        Ok(notified)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::common::EmailAddress;
+    use crate::domain::ports::NotificationError;
+    use crate::domain::ports::gpu_monitor::{GpuMonitorError, GpuProcess};
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Thalysで実行中のプロセスを返す
+    #[derive(Default)]
+    struct FakeMonitor {
+        processes: Mutex<Vec<GpuProcess>>,
+    }
+
+    impl FakeMonitor {
+        fn run(&self, processes: &[(u32, u32, &str)]) {
+            *self.processes.lock().unwrap() = processes
+                .iter()
+                .map(|&(device_number, pid, unix_user)| GpuProcess {
+                    device_number,
+                    pid,
+                    unix_user: unix_user.to_string(),
+                })
+                .collect();
+        }
+    }
+
+    #[async_trait]
+    impl GpuProcessMonitor for FakeMonitor {
+        fn monitored_servers(&self) -> Vec<String> {
+            vec!["Thalys".to_string()]
+        }
+
+        async fn processes(&self, _server: &str) -> Result<Vec<GpuProcess>, GpuMonitorError> {
+            Ok(self.processes.lock().unwrap().clone())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        warned: Mutex<Vec<UnreservedGpuUsage>>,
+    }
+
+    #[async_trait]
+    impl UnreservedUsageNotifier for RecordingNotifier {
+        async fn notify_unreserved(
+            &self,
+            usage: &UnreservedGpuUsage,
+        ) -> Result<(), NotificationError> {
+            self.warned.lock().unwrap().push(usage.clone());
+            Ok(())
+        }
+    }
+
+    /// 今から `start_hours` 時間後に始まる、ThalysのGPU 0の2時間の予約を保存する
+    async fn reserve(repo: &MockUsageRepository, start_hours: i64) {
+        let start = Utc::now() + Duration::hours(start_hours);
+        let usage = ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, start + Duration::hours(2)).unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                0,
+                "A100".to_string(),
+            ))],
+            None,
+        )
+        .unwrap();
+        repo.save(&usage).await.unwrap();
+    }
+
+    fn use_case(
+        repo: MockUsageRepository,
+        monitor: &Arc<FakeMonitor>,
+        notifier: &Arc<RecordingNotifier>,
+    ) -> DetectUnreservedUsageUseCase<MockUsageRepository> {
+        DetectUnreservedUsageUseCase::new(Arc::new(repo), monitor.clone(), notifier.clone())
+    }
+
+    fn unreserved(device_number: u32, unix_user: &str, pids: Vec<u32>) -> UnreservedGpuUsage {
+        UnreservedGpuUsage {
+            server: "Thalys".to_string(),
+            device_number,
+            unix_user: unix_user.to_string(),
+            pids,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warns_once_per_device_and_user_while_running() {
+        let repo = MockUsageRepository::new();
+        reserve(&repo, -1).await;
+        let monitor = Arc::new(FakeMonitor::default());
+        let notifier = Arc::new(RecordingNotifier::default());
+        let use_case = use_case(repo, &monitor, &notifier);
+        monitor.run(&[(0, 10, "alice"), (1, 30, "bob"), (1, 20, "bob")]);
+
+        assert_eq!(
+            use_case.execute().await.unwrap(),
+            vec![unreserved(1, "bob", vec![20, 30])]
+        );
+        assert!(use_case.execute().await.unwrap().is_empty());
+
+        // 使用が終わった後に再び使われたら改めて警告する
+        monitor.run(&[]);
+        assert!(use_case.execute().await.unwrap().is_empty());
+        monitor.run(&[(1, 40, "bob")]);
+        assert_eq!(
+            use_case.execute().await.unwrap(),
+            vec![unreserved(1, "bob", vec![40])]
+        );
+        assert_eq!(notifier.warned.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_future_reservation_does_not_cover_current_use() {
+        let repo = MockUsageRepository::new();
+        reserve(&repo, 1).await;
+        let monitor = Arc::new(FakeMonitor::default());
+        let notifier = Arc::new(RecordingNotifier::default());
+        monitor.run(&[(0, 10, "alice")]);
+
+        assert_eq!(
+            use_case(repo, &monitor, &notifier).execute().await.unwrap(),
+            vec![unreserved(0, "alice", vec![10])]
+        );
+    }
+}
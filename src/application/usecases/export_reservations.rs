@@ -0,0 +1,50 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::ports::reservation_export::{ExportFormat, ReservationExporter};
+use std::sync::Arc;
+
+/// 期間内の予約を書き出すユースケース
+///
+/// 学期末の利用報告などのために、期間と重なるすべての予約を開始時刻順に書き出す。
+pub struct ExportReservationsUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    exporter: Arc<dyn ReservationExporter>,
+}
+
+impl<R: ResourceUsageRepository> ExportReservationsUseCase<R> {
+    /// 新しいExportReservationsUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `exporter` - 予約の書き出し先
+    pub fn new(repository: Arc<R>, exporter: Arc<dyn ReservationExporter>) -> Self {
+        Self {
+            repository,
+            exporter,
+        }
+    }
+
+    /// 指定期間の予約を書き出す
+    ///
+    /// # Arguments
+    /// * `period` - 書き出す期間
+    /// * `format` - 書き出し形式
+    ///
+    /// # Returns
+    /// 書き出した予約の件数
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    /// - 書き出しエラー
+    pub async fn execute(
+        &self,
+        period: &TimePeriod,
+        format: ExportFormat,
+    ) -> Result<usize, ApplicationError> {
+        let mut usages = self.repository.find_overlapping(period).await?;
+        usages.sort_by_key(|usage| usage.time_period().start());
+        self.exporter.export(&usages, format).await?;
+        Ok(usages.len())
+    }
+}
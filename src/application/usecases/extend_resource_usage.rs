@@ -0,0 +1,487 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::group::Group;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, UsageId};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{
+    DeviceHealthRepository, RepositoryError, ResourceFreezeRepository, ResourceUsageRepository,
+    UsageQuery, UsageStatus,
+};
+use crate::domain::services::resource_usage::{BookingWindowPolicy, ReservationLimitPolicy};
+use crate::domain::services::{
+    AuthorizationPolicy, ResourceConflictChecker, ResourceUsageAuthorizationPolicy,
+};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// リソース使用予定の終了時刻を延長するユースケース
+///
+/// Slackの延長ボタン・延長フォームのほか、CLIやAPIなど終了時刻を直接指定する呼び出し元からも使える。
+pub struct ExtendResourceUsageUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    authorization_policy: ResourceUsageAuthorizationPolicy,
+    conflict_checker: ResourceConflictChecker,
+    freeze_repository: Option<Arc<dyn ResourceFreezeRepository>>,
+    device_health_repository: Option<Arc<dyn DeviceHealthRepository>>,
+    reservation_limits: ReservationLimitPolicy,
+    booking_windows: BookingWindowPolicy,
+}
+
+impl<R: ResourceUsageRepository> ExtendResourceUsageUseCase<R> {
+    /// 新しいExtendResourceUsageUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    pub fn new(repository: Arc<R>) -> Self {
+        Self {
+            repository,
+            authorization_policy: ResourceUsageAuthorizationPolicy::new(),
+            conflict_checker: ResourceConflictChecker::new(),
+            freeze_repository: None,
+            device_health_repository: None,
+            reservation_limits: ReservationLimitPolicy::default(),
+            booking_windows: BookingWindowPolicy::default(),
+        }
+    }
+
+    /// 予約停止リポジトリを設定
+    ///
+    /// 設定した場合、予約停止中のリソースの予約を停止開始以降に延長できなくなる。
+    pub fn with_freeze_repository(
+        mut self,
+        freeze_repository: Arc<dyn ResourceFreezeRepository>,
+    ) -> Self {
+        self.freeze_repository = Some(freeze_repository);
+        self
+    }
+
+    /// デバイスの状態のリポジトリを設定
+    ///
+    /// 設定した場合、使用停止中のデバイスを含む予約を延長できなくなる。
+    pub fn with_device_health_repository(
+        mut self,
+        device_health_repository: Arc<dyn DeviceHealthRepository>,
+    ) -> Self {
+        self.device_health_repository = Some(device_health_repository);
+        self
+    }
+
+    /// ストレージのボリュームごとの容量（GB）を設定
+    ///
+    /// 設定した場合、同じボリュームの予約の確保容量の合計が容量を超える予約を競合とみなす。
+    pub fn with_storage_capacities(mut self, storage_capacities: HashMap<String, u64>) -> Self {
+        self.conflict_checker = self
+            .conflict_checker
+            .with_storage_capacities(storage_capacities);
+        self
+    }
+
+    /// 利用者ごとの同時予約の上限を設定
+    ///
+    /// 設定した場合、予約者の上限を超えるように延長できなくなる。
+    pub fn with_reservation_limits(mut self, reservation_limits: ReservationLimitPolicy) -> Self {
+        self.reservation_limits = reservation_limits;
+        self
+    }
+
+    /// リソースごとの予約の受付期間を設定
+    ///
+    /// 設定した場合、延長分の時間帯を新しい予約とみなし、その開始時刻（現在の終了時刻）が
+    /// 受付期間外になる延長はできなくなる。
+    pub fn with_booking_windows(mut self, booking_windows: BookingWindowPolicy) -> Self {
+        self.booking_windows = booking_windows;
+        self
+    }
+
+    /// 管理者を設定
+    ///
+    /// 管理者は他のユーザーの予約も延長できる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_admins(admins);
+        self
+    }
+
+    /// チームを設定
+    ///
+    /// チームが所有する予約は、そのチームのメンバーも延長できる。
+    pub fn with_groups(mut self, groups: Vec<Group>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_groups(groups);
+        self
+    }
+
+    /// ライセンスごとの席数を設定
+    ///
+    /// 設定した場合、同じライセンスの予約の確保席数の合計が席数を超える予約を競合とみなす。
+    pub fn with_license_seats(mut self, license_seats: HashMap<String, u32>) -> Self {
+        self.conflict_checker = self.conflict_checker.with_license_seats(license_seats);
+        self
+    }
+
+    /// リソース使用予定の終了時刻を延長
+    ///
+    /// # Arguments
+    /// * `id` - 使用予定ID
+    /// * `owner_email` - 所有者のメールアドレス（権限チェック用）
+    /// * `extension` - 延長する時間
+    ///
+    /// # Returns
+    /// 延長後の使用期間
+    ///
+    /// # Errors
+    /// `extend_to` と同じ
+    pub async fn execute(
+        &self,
+        id: &UsageId,
+        owner_email: &EmailAddress,
+        extension: Duration,
+    ) -> Result<TimePeriod, ApplicationError> {
+        let usage = self.find_authorized(id, owner_email).await?;
+        let new_end = usage.time_period().end() + extension;
+        self.extend(usage, new_end).await
+    }
+
+    /// リソース使用予定の終了時刻を指定した時刻まで延長
+    ///
+    /// 延長する時間帯（現在の終了時刻から新しい終了時刻まで）が、
+    /// 後に続く他の予約と競合しないことを確認してから更新する。
+    ///
+    /// # Arguments
+    /// * `id` - 使用予定ID
+    /// * `owner_email` - 所有者のメールアドレス（権限チェック用）
+    /// * `new_end` - 新しい終了時刻
+    ///
+    /// # Returns
+    /// 延長後の使用期間
+    ///
+    /// # Errors
+    /// - 新しい終了時刻が現在の終了時刻以前の場合
+    /// - 指定されたIDの予約が見つからない場合
+    /// - 操作する権限がない場合
+    /// - 延長する時間帯がリソースの予約の受付期間外の場合
+    /// - 延長する時間帯が予約停止の開始以降にかかる場合
+    /// - 使用停止中のデバイスを含む場合
+    /// - 延長する時間帯が他の予約と競合する場合
+    /// - 延長後の期間で予約者の同時予約の上限を超える場合
+    /// - リポジトリエラー
+    pub async fn extend_to(
+        &self,
+        id: &UsageId,
+        owner_email: &EmailAddress,
+        new_end: DateTime<Utc>,
+    ) -> Result<TimePeriod, ApplicationError> {
+        let usage = self.find_authorized(id, owner_email).await?;
+        self.extend(usage, new_end).await
+    }
+
+    /// 予約を取得し、操作する権限を確認する
+    async fn find_authorized(
+        &self,
+        id: &UsageId,
+        owner_email: &EmailAddress,
+    ) -> Result<ResourceUsage, ApplicationError> {
+        let usage = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::Repository(RepositoryError::NotFound))?;
+
+        self.authorization_policy
+            .authorize_update(owner_email, &usage)
+            .map_err(|e| ApplicationError::Unauthorized(e.to_string()))?;
+
+        Ok(usage)
+    }
+
+    /// 延長できることを確認してから終了時刻を更新する
+    async fn extend(
+        &self,
+        mut usage: ResourceUsage,
+        new_end: DateTime<Utc>,
+    ) -> Result<TimePeriod, ApplicationError> {
+        let current = usage.time_period().clone();
+        // 新しい終了時刻が現在の終了時刻以前の場合は、延長分の時間帯を作れないためここでエラーになる
+        let added = TimePeriod::new(current.end(), new_end)?;
+        let extended = TimePeriod::new(current.start(), new_end)?;
+
+        // 受付期間チェック（延長分を新しい予約とみなす）
+        self.booking_windows
+            .check(&added, usage.resources(), Utc::now())?;
+
+        // 予約停止チェック
+        if let Some(freeze_repository) = &self.freeze_repository {
+            for freeze in freeze_repository.find_all().await? {
+                freeze.check(&added, usage.resources())?;
+            }
+        }
+
+        // 使用停止チェック
+        if let Some(device_health_repository) = &self.device_health_repository {
+            for health in device_health_repository.find_all().await? {
+                health.check(usage.resources())?;
+            }
+        }
+
+        // 延長分の時間帯について競合チェック（自分自身を除外）
+        self.conflict_checker
+            .check_conflicts(
+                self.repository.as_ref(),
+                &added,
+                usage.resources(),
+                Some(usage.id()),
+            )
+            .await?;
+
+        // 同時予約の上限チェック（予約者の他の予約と合わせて数える）
+        if !self.reservation_limits.is_unlimited() {
+            let now = Utc::now();
+            let held: Vec<_> = self
+                .repository
+                .query(&UsageQuery::new().with_owner(usage.owner_email().clone()))
+                .await?
+                .into_iter()
+                .filter(|other| {
+                    other.id() != usage.id() && UsageStatus::of(other, now) != UsageStatus::Ended
+                })
+                .collect();
+            self.reservation_limits
+                .check(&held, &extended, usage.resources())?;
+        }
+
+        usage.update_time_period(extended.clone());
+        self.repository.save(&usage).await?;
+
+        Ok(extended)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::device_health::{DeviceHealth, DeviceStatus};
+    use crate::domain::aggregates::resource_freeze::ResourceFreeze;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource};
+    use crate::domain::services::resource_usage::{BookingWindow, ReservationLimit};
+    use crate::infrastructure::repositories::device_health::JsonFileDeviceHealthRepository;
+    use crate::infrastructure::repositories::resource_freeze::JsonFileResourceFreezeRepository;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use std::path::PathBuf;
+
+    fn email(address: &str) -> EmailAddress {
+        EmailAddress::new(address.to_string()).unwrap()
+    }
+
+    fn gpu(device: u32) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()))
+    }
+
+    fn tomorrow(start_hour: i64, end_hour: i64) -> TimePeriod {
+        let base = Utc::now() + Duration::days(1);
+        TimePeriod::new(
+            base + Duration::hours(start_hour),
+            base + Duration::hours(end_hour),
+        )
+        .unwrap()
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("lrm_extend_{}", uuid::Uuid::new_v4()))
+            .join(name)
+    }
+
+    async fn save(
+        repo: &MockUsageRepository,
+        owner: &str,
+        period: TimePeriod,
+        device: u32,
+    ) -> ResourceUsage {
+        let usage = ResourceUsage::new(email(owner), period, vec![gpu(device)], None).unwrap();
+        repo.save(&usage).await.unwrap();
+        usage
+    }
+
+    async fn end_of(repo: &MockUsageRepository, usage: &ResourceUsage) -> DateTime<Utc> {
+        repo.find_by_id(usage.id())
+            .await
+            .unwrap()
+            .unwrap()
+            .time_period()
+            .end()
+    }
+
+    #[tokio::test]
+    async fn test_execute_extends_the_end_time() {
+        let repo = MockUsageRepository::new();
+        let usage = save(&repo, "alice@example.com", tomorrow(0, 2), 0).await;
+        let usecase = ExtendResourceUsageUseCase::new(Arc::new(repo.clone()));
+
+        let extended = usecase
+            .execute(usage.id(), &email("alice@example.com"), Duration::hours(1))
+            .await
+            .unwrap();
+
+        assert_eq!(extended.start(), usage.time_period().start());
+        assert_eq!(
+            extended.end(),
+            usage.time_period().end() + Duration::hours(1)
+        );
+        assert_eq!(end_of(&repo, &usage).await, extended.end());
+    }
+
+    #[tokio::test]
+    async fn test_extend_to_sets_the_given_end_time() {
+        let repo = MockUsageRepository::new();
+        let usage = save(&repo, "alice@example.com", tomorrow(0, 2), 0).await;
+        let usecase = ExtendResourceUsageUseCase::new(Arc::new(repo.clone()));
+        let new_end = usage.time_period().end() + Duration::minutes(90);
+
+        let extended = usecase
+            .extend_to(usage.id(), &email("alice@example.com"), new_end)
+            .await
+            .unwrap();
+        assert_eq!(extended.end(), new_end);
+
+        // 現在の終了時刻以前は延長にならない
+        let result = usecase
+            .extend_to(
+                usage.id(),
+                &email("alice@example.com"),
+                usage.time_period().start(),
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(end_of(&repo, &usage).await, new_end);
+    }
+
+    #[tokio::test]
+    async fn test_extend_rejects_other_users() {
+        let repo = MockUsageRepository::new();
+        let usage = save(&repo, "alice@example.com", tomorrow(0, 2), 0).await;
+        let usecase = ExtendResourceUsageUseCase::new(Arc::new(repo.clone()));
+
+        let result = usecase
+            .execute(usage.id(), &email("bob@example.com"), Duration::hours(1))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Unauthorized(_))));
+        assert_eq!(end_of(&repo, &usage).await, usage.time_period().end());
+    }
+
+    #[tokio::test]
+    async fn test_extend_rejects_conflicts_with_the_next_usage() {
+        let repo = MockUsageRepository::new();
+        let usage = save(&repo, "alice@example.com", tomorrow(0, 2), 0).await;
+        save(&repo, "bob@example.com", tomorrow(2, 4), 0).await;
+        // 別のデバイスの予約は競合しない
+        save(&repo, "bob@example.com", tomorrow(2, 4), 1).await;
+        let usecase = ExtendResourceUsageUseCase::new(Arc::new(repo.clone()));
+
+        let result = usecase
+            .execute(usage.id(), &email("alice@example.com"), Duration::hours(1))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ResourceConflict { .. })
+        ));
+        assert_eq!(end_of(&repo, &usage).await, usage.time_period().end());
+    }
+
+    #[tokio::test]
+    async fn test_extend_rejects_periods_after_a_freeze() {
+        let repo = MockUsageRepository::new();
+        let usage = save(&repo, "alice@example.com", tomorrow(0, 2), 0).await;
+        let freeze_repo = Arc::new(JsonFileResourceFreezeRepository::new(temp_file(
+            "resource_freezes.json",
+        )));
+        freeze_repo
+            .save(
+                ResourceFreeze::new(
+                    "Thalys".to_string(),
+                    usage.time_period().end() + Duration::minutes(30),
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let usecase = ExtendResourceUsageUseCase::new(Arc::new(repo.clone()))
+            .with_freeze_repository(freeze_repo);
+
+        let result = usecase
+            .execute(usage.id(), &email("alice@example.com"), Duration::hours(1))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::ResourceFreeze(_))));
+        assert_eq!(end_of(&repo, &usage).await, usage.time_period().end());
+    }
+
+    #[tokio::test]
+    async fn test_extend_rejects_out_of_service_devices() {
+        let repo = MockUsageRepository::new();
+        let usage = save(&repo, "alice@example.com", tomorrow(0, 2), 0).await;
+        let health_repo = Arc::new(JsonFileDeviceHealthRepository::new(temp_file(
+            "device_statuses.json",
+        )));
+        health_repo
+            .save(DeviceHealth::new(
+                "Thalys".to_string(),
+                0,
+                DeviceStatus::OutOfService,
+                None,
+            ))
+            .await
+            .unwrap();
+        let usecase = ExtendResourceUsageUseCase::new(Arc::new(repo.clone()))
+            .with_device_health_repository(health_repo);
+
+        let result = usecase
+            .execute(usage.id(), &email("alice@example.com"), Duration::hours(1))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::DeviceHealth(_))));
+        assert_eq!(end_of(&repo, &usage).await, usage.time_period().end());
+    }
+
+    #[tokio::test]
+    async fn test_extend_rejects_extensions_outside_the_booking_window() {
+        let repo = MockUsageRepository::new();
+        let usage = save(&repo, "alice@example.com", tomorrow(0, 2), 0).await;
+        let usecase = ExtendResourceUsageUseCase::new(Arc::new(repo.clone())).with_booking_windows(
+            BookingWindowPolicy::new().with_window(
+                "Thalys",
+                BookingWindow {
+                    min_notice: None,
+                    max_advance: Some(Duration::hours(12)),
+                },
+            ),
+        );
+
+        let result = usecase
+            .execute(usage.id(), &email("alice@example.com"), Duration::hours(1))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::BookingWindow(_))));
+        assert_eq!(end_of(&repo, &usage).await, usage.time_period().end());
+    }
+
+    #[tokio::test]
+    async fn test_extend_rejects_exceeding_the_device_limit() {
+        let repo = MockUsageRepository::new();
+        let usage = save(&repo, "alice@example.com", tomorrow(0, 2), 0).await;
+        save(&repo, "alice@example.com", tomorrow(2, 4), 1).await;
+        let usecase = ExtendResourceUsageUseCase::new(Arc::new(repo.clone()))
+            .with_reservation_limits(ReservationLimitPolicy::new(ReservationLimit {
+                max_reservations: None,
+                max_devices: Some(1),
+            }));
+
+        let result = usecase
+            .execute(usage.id(), &email("alice@example.com"), Duration::hours(1))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::ReservationLimit(_))));
+        assert_eq!(end_of(&repo, &usage).await, usage.time_period().end());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::ports::repositories::{ResourceFreezeRepository, ResourceUsageRepository};
+use crate::domain::services::ResourceAllocationService;
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use std::sync::Arc;
+
+/// 空いている時間帯を探す範囲（日数）
+pub const SEARCH_DAYS: i64 = 14;
+
+/// 提案する時間帯の開始時刻の刻み（分）
+const SLOT_STEP_MINUTES: i64 = 15;
+
+/// 次に空いている時間帯を探すユースケース
+///
+/// 既存の予約を調べ、指定したリソースをすべて、指定した長さだけ続けて使える最も早い時間帯を求める。
+pub struct FindNextAvailableSlotUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    allocation_service: ResourceAllocationService,
+    freeze_repository: Option<Arc<dyn ResourceFreezeRepository>>,
+}
+
+impl<R: ResourceUsageRepository> FindNextAvailableSlotUseCase<R> {
+    /// 新しいFindNextAvailableSlotUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    pub fn new(repository: Arc<R>) -> Self {
+        Self {
+            repository,
+            allocation_service: ResourceAllocationService::default(),
+            freeze_repository: None,
+        }
+    }
+
+    /// 予約停止リポジトリを設定
+    ///
+    /// 設定した場合、予約停止の開始以降にかかる時間帯は提案しない。
+    pub fn with_freeze_repository(
+        mut self,
+        freeze_repository: Arc<dyn ResourceFreezeRepository>,
+    ) -> Self {
+        self.freeze_repository = Some(freeze_repository);
+        self
+    }
+
+    /// 次に空いている時間帯を探す
+    ///
+    /// `not_before` を15分単位に切り上げた時刻から `SEARCH_DAYS` 日先までを探す。
+    ///
+    /// # Arguments
+    /// * `resources` - 使用するリソース
+    /// * `duration` - 必要な時間の長さ
+    /// * `not_before` - この時刻以降の時間帯を探す
+    ///
+    /// # Returns
+    /// 探す範囲に空いている時間帯がない場合は `None`
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        resources: &[Resource],
+        duration: Duration,
+        not_before: DateTime<Utc>,
+    ) -> Result<Option<TimePeriod>, ApplicationError> {
+        let start = round_up(not_before);
+        let mut end = start + Duration::days(SEARCH_DAYS);
+
+        // 予約停止の開始以降は予約できないため、探す範囲をそこまでに狭める
+        if let Some(freeze_repository) = &self.freeze_repository {
+            for freeze in freeze_repository.find_all().await? {
+                if resources.iter().any(|resource| freeze.applies_to(resource)) {
+                    end = end.min(freeze.starts_at());
+                }
+            }
+        }
+
+        let Ok(search_range) = TimePeriod::new(start, end) else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .allocation_service
+            .find_next_slot(self.repository.as_ref(), &search_range, resources, duration)
+            .await?)
+    }
+}
+
+/// 開始時刻の刻みに切り上げる
+fn round_up(date_time: DateTime<Utc>) -> DateTime<Utc> {
+    let step = Duration::minutes(SLOT_STEP_MINUTES);
+    match date_time.duration_trunc(step) {
+        Ok(truncated) if truncated < date_time => truncated + step,
+        Ok(truncated) => truncated,
+        Err(_) => date_time,
+    }
+}
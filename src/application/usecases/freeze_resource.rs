@@ -0,0 +1,72 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_freeze::ResourceFreeze;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::ports::repositories::{ResourceFreezeRepository, ResourceUsageRepository};
+use std::sync::Arc;
+
+/// リソースの予約を停止するユースケース
+///
+/// クラスタ移行などに備えて、指定時刻以降のリソースの新規予約を停止する。
+/// 予約停止の登録時には、影響を受ける既存の予約を返す。
+pub struct FreezeResourceUseCase<R: ResourceUsageRepository> {
+    usage_repository: Arc<R>,
+    freeze_repository: Arc<dyn ResourceFreezeRepository>,
+}
+
+impl<R: ResourceUsageRepository> FreezeResourceUseCase<R> {
+    /// 新しいFreezeResourceUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `usage_repository` - ResourceUsageリポジトリ
+    /// * `freeze_repository` - ResourceFreezeリポジトリ
+    pub fn new(
+        usage_repository: Arc<R>,
+        freeze_repository: Arc<dyn ResourceFreezeRepository>,
+    ) -> Self {
+        Self {
+            usage_repository,
+            freeze_repository,
+        }
+    }
+
+    /// 予約停止を登録する
+    ///
+    /// 同じリソースの予約停止が既にある場合は置き換える。
+    ///
+    /// # Returns
+    /// 予約停止の開始以降にかかる既存の予約（開始時刻順）
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        freeze: ResourceFreeze,
+    ) -> Result<Vec<ResourceUsage>, ApplicationError> {
+        let mut affected: Vec<ResourceUsage> = self
+            .usage_repository
+            .find_future()
+            .await?
+            .into_iter()
+            .filter(|usage| freeze.affects(usage))
+            .collect();
+        affected.sort_by_key(|usage| usage.time_period().start());
+
+        self.freeze_repository.save(freeze).await?;
+        Ok(affected)
+    }
+
+    /// 予約停止を解除する
+    ///
+    /// # Returns
+    /// 解除した場合は `true`、予約停止が無かった場合は `false`
+    pub async fn unfreeze(&self, resource_name: &str) -> Result<bool, ApplicationError> {
+        Ok(self.freeze_repository.delete(resource_name).await?)
+    }
+
+    /// 登録されている予約停止の一覧を取得する（開始時刻順）
+    pub async fn list(&self) -> Result<Vec<ResourceFreeze>, ApplicationError> {
+        let mut freezes = self.freeze_repository.find_all().await?;
+        freezes.sort_by_key(|freeze| freeze.starts_at());
+        Ok(freezes)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+/// リソースを現在使用中の予約を取得するユースケース
+pub struct GetCurrentOccupantsUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+}
+
+impl<R: ResourceUsageRepository> GetCurrentOccupantsUseCase<R> {
+    /// 新しいGetCurrentOccupantsUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 指定したサーバー・部屋を指定時刻に使用中の予約を取得
+    ///
+    /// # Arguments
+    /// * `resource_name` - サーバー名または部屋名
+    /// * `now` - 基準の時刻
+    ///
+    /// # Returns
+    /// 使用中の予約（終了時刻順）
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        resource_name: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ResourceUsage>, ApplicationError> {
+        let instant = TimePeriod::new(now, now + Duration::seconds(1))?;
+        let mut usages: Vec<ResourceUsage> = self
+            .repository
+            .find_overlapping(&instant)
+            .await?
+            .into_iter()
+            .filter(|usage| usage.time_period().start() <= now)
+            .filter(|usage| {
+                usage
+                    .resources()
+                    .iter()
+                    .any(|resource| resource.name() == resource_name)
+            })
+            .collect();
+        usages.sort_by_key(|usage| usage.time_period().end());
+        Ok(usages)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::identity_link::value_objects::{
+    ExternalSystem, IdentityLinkAuditEntry,
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::IdentityLinkAuditRepository;
+use std::sync::Arc;
+
+/// IdentityLinkの監査記録を取得するユースケース
+///
+/// 誰が・いつ・どのメールアドレスを紐付け（解除）したかの履歴を返す。
+pub struct GetIdentityLinkHistoryUseCase {
+    audit_repo: Arc<dyn IdentityLinkAuditRepository>,
+}
+
+impl GetIdentityLinkHistoryUseCase {
+    /// 新しいGetIdentityLinkHistoryUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `audit_repo` - 監査記録リポジトリ
+    pub fn new(audit_repo: Arc<dyn IdentityLinkAuditRepository>) -> Self {
+        Self { audit_repo }
+    }
+
+    /// 外部システムのユーザーの履歴を古い順に取得
+    ///
+    /// # Arguments
+    /// * `system` - 外部システム
+    /// * `user_id` - 外部システム上のユーザーID
+    pub async fn by_external_user(
+        &self,
+        system: &ExternalSystem,
+        user_id: &str,
+    ) -> Result<Vec<IdentityLinkAuditEntry>, ApplicationError> {
+        Ok(self
+            .audit_repo
+            .find_by_external_user_id(system, user_id)
+            .await?)
+    }
+
+    /// メールアドレスの履歴を古い順に取得
+    ///
+    /// # Arguments
+    /// * `email` - メールアドレス
+    pub async fn by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Vec<IdentityLinkAuditEntry>, ApplicationError> {
+        Ok(self.audit_repo.find_by_email(email).await?)
+    }
+}
@@ -0,0 +1,391 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// リソースの空き状況
+#[derive(Debug, Clone)]
+pub struct ResourceAvailability {
+    resource: Resource,
+    busy_periods: Vec<TimePeriod>,
+    free_periods: Vec<TimePeriod>,
+}
+
+impl ResourceAvailability {
+    /// 対象のリソース
+    pub fn resource(&self) -> &Resource {
+        &self.resource
+    }
+
+    /// 問い合わせた期間内で予約されている期間（開始時刻順、問い合わせた期間で切り詰め、重なりはまとめる）
+    pub fn busy_periods(&self) -> &[TimePeriod] {
+        &self.busy_periods
+    }
+
+    /// 問い合わせた期間内で空いている期間（開始時刻順）
+    pub fn free_periods(&self) -> &[TimePeriod] {
+        &self.free_periods
+    }
+
+    /// 問い合わせた期間を通して空いているか
+    pub fn is_free(&self) -> bool {
+        self.busy_periods.is_empty()
+    }
+
+    /// 指定期間と重なる予約があるか
+    pub fn is_busy_during(&self, time_period: &TimePeriod) -> bool {
+        self.busy_periods
+            .iter()
+            .any(|busy| busy.overlaps_with(time_period))
+    }
+}
+
+/// 指定期間の空き状況
+///
+/// Slackの表示や外部のAPIなど、表示方法によらず使えるようにリソースごとの予約・空きの期間をまとめたもの。
+#[derive(Debug, Clone)]
+pub struct AvailabilityReport {
+    period: TimePeriod,
+    resources: Vec<ResourceAvailability>,
+}
+
+impl AvailabilityReport {
+    /// 問い合わせた期間
+    pub fn period(&self) -> &TimePeriod {
+        &self.period
+    }
+
+    /// リソースごとの空き状況（設定の順）
+    pub fn resources(&self) -> &[ResourceAvailability] {
+        &self.resources
+    }
+
+    /// 問い合わせた期間を通して空いているリソースの数
+    pub fn free_count(&self) -> usize {
+        self.resources.iter().filter(|r| r.is_free()).count()
+    }
+
+    /// 指定したリソースの空き状況
+    pub fn get(&self, resource: &Resource) -> Option<&ResourceAvailability> {
+        self.resources.iter().find(|r| r.resource() == resource)
+    }
+}
+
+/// リソースの空き状況を取得するユースケース
+///
+/// 指定期間と重複する予約から、GPU・部屋ごとに予約されている期間を求める。
+/// リソース名またはタグ（例: "a100"）で対象を絞り込める。
+pub struct GetResourceAvailabilityUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    /// 空き状況の対象となるすべてのリソース
+    resources: Vec<Resource>,
+    /// リソースごとのタグ
+    tags: HashMap<Resource, Vec<String>>,
+}
+
+impl<R: ResourceUsageRepository> GetResourceAvailabilityUseCase<R> {
+    /// 新しいGetResourceAvailabilityUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `resources` - 空き状況の対象となるすべてのリソース（表示順）
+    pub fn new(repository: Arc<R>, resources: Vec<Resource>) -> Self {
+        Self {
+            repository,
+            resources,
+            tags: HashMap::new(),
+        }
+    }
+
+    /// リソースのタグを設定
+    ///
+    /// # Arguments
+    /// * `tags` - タグの付いたリソースとそのタグ
+    pub fn with_tags(mut self, tags: Vec<(Resource, Vec<String>)>) -> Self {
+        self.tags = tags.into_iter().collect();
+        self
+    }
+
+    /// リソースに指定したタグが付いているかどうか（大文字小文字を区別しない）
+    fn has_tag(&self, resource: &Resource, tag: &str) -> bool {
+        self.tags
+            .get(resource)
+            .is_some_and(|tags| tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+    }
+
+    /// 指定期間の空き状況を取得
+    ///
+    /// # Arguments
+    /// * `time_period` - 問い合わせる期間
+    /// * `resource_name` - サーバー名または部屋名で絞り込む場合に指定
+    /// * `tag` - タグで絞り込む場合に指定
+    ///
+    /// # Returns
+    /// リソースごとの空き状況（`new` で渡したリソースの順）
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        time_period: &TimePeriod,
+        resource_name: Option<&str>,
+        tag: Option<&str>,
+    ) -> Result<AvailabilityReport, ApplicationError> {
+        let overlapping = self.repository.find_overlapping(time_period).await?;
+
+        let resources = self
+            .resources
+            .iter()
+            .filter(|resource| resource_name.is_none_or(|name| resource.name() == name))
+            .filter(|resource| tag.is_none_or(|tag| self.has_tag(resource, tag)))
+            .map(|resource| {
+                let busy = overlapping
+                    .iter()
+                    .filter(|usage| {
+                        usage
+                            .resources()
+                            .iter()
+                            .any(|used| used.conflicts_with(resource))
+                    })
+                    .map(|usage| usage.time_period());
+                let busy_periods = busy_within(time_period, busy);
+                ResourceAvailability {
+                    resource: resource.clone(),
+                    free_periods: free_within(time_period, &busy_periods),
+                    busy_periods,
+                }
+            })
+            .collect();
+
+        Ok(AvailabilityReport {
+            period: time_period.clone(),
+            resources,
+        })
+    }
+}
+
+/// 予約の期間を問い合わせた期間で切り詰め、重なる期間をまとめる
+fn busy_within<'a>(
+    window: &TimePeriod,
+    periods: impl Iterator<Item = &'a TimePeriod>,
+) -> Vec<TimePeriod> {
+    let mut clipped: Vec<(DateTime<Utc>, DateTime<Utc>)> = periods
+        .map(|p| (p.start().max(window.start()), p.end().min(window.end())))
+        .filter(|(start, end)| start < end)
+        .collect();
+    clipped.sort();
+
+    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+    for (start, end) in clipped {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+        .into_iter()
+        .filter_map(|(start, end)| TimePeriod::new(start, end).ok())
+        .collect()
+}
+
+/// 問い合わせた期間のうち予約されていない期間
+fn free_within(window: &TimePeriod, busy_periods: &[TimePeriod]) -> Vec<TimePeriod> {
+    let mut free = Vec::new();
+    let mut cursor = window.start();
+    for busy in busy_periods {
+        if let Ok(gap) = TimePeriod::new(cursor, busy.start()) {
+            free.push(gap);
+        }
+        cursor = cursor.max(busy.end());
+    }
+    if let Ok(gap) = TimePeriod::new(cursor, window.end()) {
+        free.push(gap);
+    }
+    free
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::common::EmailAddress;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use chrono::Duration;
+
+    fn gpu(device: u32) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()))
+    }
+
+    fn room() -> Resource {
+        Resource::Room {
+            name: "会議室A".to_string(),
+        }
+    }
+
+    /// 基準時刻からの時間で表した期間
+    fn period(base: DateTime<Utc>, start_hour: i64, end_hour: i64) -> TimePeriod {
+        TimePeriod::new(
+            base + Duration::hours(start_hour),
+            base + Duration::hours(end_hour),
+        )
+        .unwrap()
+    }
+
+    async fn save(repo: &MockUsageRepository, period: TimePeriod, resources: Vec<Resource>) {
+        let usage = ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            period,
+            resources,
+            None,
+        )
+        .unwrap();
+        repo.save(&usage).await.unwrap();
+    }
+
+    fn use_case(repo: &MockUsageRepository) -> GetResourceAvailabilityUseCase<MockUsageRepository> {
+        GetResourceAvailabilityUseCase::new(Arc::new(repo.clone()), vec![gpu(0), gpu(1), room()])
+            .with_tags(vec![(gpu(1), vec!["A100".to_string()])])
+    }
+
+    #[tokio::test]
+    async fn test_busy_periods_are_clipped_and_merged() {
+        let base = Utc::now() + Duration::days(1);
+        let repo = MockUsageRepository::new();
+        save(&repo, period(base, -1, 1), vec![gpu(0)]).await;
+        save(&repo, period(base, 2, 4), vec![gpu(0)]).await;
+        save(&repo, period(base, 3, 6), vec![gpu(0), room()]).await;
+
+        let day = period(base, 0, 24);
+        let report = use_case(&repo).execute(&day, None, None).await.unwrap();
+
+        assert_eq!(report.period(), &day);
+        assert_eq!(
+            report
+                .resources()
+                .iter()
+                .map(|r| r.resource().clone())
+                .collect::<Vec<_>>(),
+            vec![gpu(0), gpu(1), room()]
+        );
+        assert_eq!(report.free_count(), 1);
+
+        let gpu0 = report.get(&gpu(0)).unwrap();
+        assert_eq!(
+            gpu0.busy_periods(),
+            &[period(base, 0, 1), period(base, 2, 6)]
+        );
+        assert_eq!(
+            gpu0.free_periods(),
+            &[period(base, 1, 2), period(base, 6, 24)]
+        );
+        assert!(gpu0.is_busy_during(&period(base, 5, 7)));
+        assert!(!gpu0.is_busy_during(&period(base, 1, 2)));
+
+        let gpu1 = report.get(&gpu(1)).unwrap();
+        assert!(gpu1.is_free());
+        assert_eq!(gpu1.free_periods(), std::slice::from_ref(&day));
+
+        assert_eq!(
+            report.get(&room()).unwrap().busy_periods(),
+            &[period(base, 3, 6)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_filters_by_resource_name_and_tag() {
+        let base = Utc::now() + Duration::days(1);
+        let repo = MockUsageRepository::new();
+        let day = period(base, 0, 24);
+        let use_case = use_case(&repo);
+
+        let report = use_case.execute(&day, Some("会議室A"), None).await.unwrap();
+        assert_eq!(report.resources().len(), 1);
+        assert_eq!(report.resources()[0].resource(), &room());
+
+        let report = use_case.execute(&day, Some("Thalys"), None).await.unwrap();
+        assert_eq!(report.resources().len(), 2);
+
+        // タグは大文字小文字を区別しない
+        let report = use_case
+            .execute(&day, Some("Thalys"), Some("a100"))
+            .await
+            .unwrap();
+        assert_eq!(report.resources().len(), 1);
+        assert_eq!(report.resources()[0].resource(), &gpu(1));
+
+        let report = use_case.execute(&day, None, Some("h100")).await.unwrap();
+        assert!(report.resources().is_empty());
+    }
+
+    fn base() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-04-07T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_busy_within_merges_touching_and_nested_periods() {
+        let window = period(base(), 0, 10);
+        let periods = [
+            period(base(), 5, 8),
+            period(base(), 1, 3),
+            // 終了と開始が接する期間はまとめる
+            period(base(), 3, 4),
+            // 他の期間に含まれる期間
+            period(base(), 6, 7),
+            period(base(), 11, 12),
+        ];
+
+        let busy = busy_within(&window, periods.iter());
+
+        assert_eq!(busy, vec![period(base(), 1, 4), period(base(), 5, 8)]);
+    }
+
+    #[test]
+    fn test_busy_within_ignores_periods_outside_window() {
+        let window = period(base(), 2, 4);
+        let periods = [period(base(), 0, 2), period(base(), 4, 6)];
+
+        assert!(busy_within(&window, periods.iter()).is_empty());
+    }
+
+    #[test]
+    fn test_free_within_returns_gaps_between_busy_periods() {
+        let window = period(base(), 0, 10);
+
+        assert_eq!(
+            free_within(&window, &[period(base(), 0, 2), period(base(), 4, 10)]),
+            vec![period(base(), 2, 4)]
+        );
+        assert!(free_within(&window, std::slice::from_ref(&window)).is_empty());
+        assert_eq!(free_within(&window, &[]), vec![window.clone()]);
+    }
+
+    #[test]
+    fn test_report_counts_free_resources_and_finds_by_resource() {
+        let window = period(base(), 0, 10);
+        let availability = |resource: Resource, busy: Vec<TimePeriod>| ResourceAvailability {
+            resource,
+            free_periods: free_within(&window, &busy),
+            busy_periods: busy,
+        };
+        let report = AvailabilityReport {
+            period: window.clone(),
+            resources: vec![
+                availability(gpu(0), vec![period(base(), 2, 3)]),
+                availability(gpu(1), vec![]),
+            ],
+        };
+
+        assert_eq!(report.free_count(), 1);
+        assert!(report.get(&gpu(1)).unwrap().is_free());
+        assert!(report.get(&room()).is_none());
+        let gpu0 = report.get(&gpu(0)).unwrap();
+        assert!(gpu0.is_busy_during(&period(base(), 1, 3)));
+        // 終了時刻ちょうどから始まる期間とは重ならない
+        assert!(!gpu0.is_busy_during(&period(base(), 3, 4)));
+    }
+}
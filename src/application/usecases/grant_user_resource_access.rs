@@ -1,10 +1,10 @@
 use crate::application::error::ApplicationError;
 use crate::domain::aggregates::identity_link::{
     entity::IdentityLink,
-    value_objects::{ExternalIdentity, ExternalSystem},
+    value_objects::{ExternalIdentity, ExternalSystem, IdentityLinkAction, IdentityLinkAuditEntry},
 };
 use crate::domain::common::EmailAddress;
-use crate::domain::ports::repositories::IdentityLinkRepository;
+use crate::domain::ports::repositories::{IdentityLinkAuditRepository, IdentityLinkRepository};
 use crate::domain::ports::resource_collection_access::{
     ResourceCollectionAccessError, ResourceCollectionAccessService,
 };
@@ -18,6 +18,7 @@ pub struct GrantUserResourceAccessUseCase {
     collection_access: Arc<dyn ResourceCollectionAccessService>,
     /// アクセス権を付与するコレクションIDのリスト
     collection_ids: Vec<String>,
+    audit_repo: Option<Arc<dyn IdentityLinkAuditRepository>>,
 }
 
 impl GrantUserResourceAccessUseCase {
@@ -36,15 +37,29 @@ impl GrantUserResourceAccessUseCase {
             identity_repo,
             collection_access,
             collection_ids,
+            audit_repo: None,
         }
     }
 
+    /// 監査記録リポジトリを設定
+    ///
+    /// 設定した場合、紐付けのたびに監査記録を追記する。
+    pub fn with_audit_repository(
+        mut self,
+        audit_repo: Arc<dyn IdentityLinkAuditRepository>,
+    ) -> Self {
+        self.audit_repo = Some(audit_repo);
+        self
+    }
+
     /// ユーザーにリソースアクセス権を付与する
     ///
     /// # Arguments
     /// * `external_system` - 外部システム（例: Slack）
     /// * `external_user_id` - 外部システム上のユーザーID
+    /// * `workspace_id` - 外部システム上のワークスペースID（Slack Enterprise GridのTeam IDなど）
     /// * `email` - ユーザーのメールアドレス
+    /// * `actor_id` - 操作した外部システム上のユーザーID（名簿同期などの自動処理の場合は `None`）
     ///
     /// # Errors
     /// * `ApplicationError::ExternalSystemAlreadyLinked` - 既に同じ外部システムに紐付けられている場合
@@ -53,19 +68,44 @@ impl GrantUserResourceAccessUseCase {
         &self,
         external_system: ExternalSystem,
         external_user_id: String,
+        workspace_id: Option<String>,
         email: EmailAddress,
+        actor_id: Option<String>,
     ) -> Result<(), ApplicationError> {
         let mut identity = self.resolve_or_create_identity_link(&email).await?;
-        self.link_external_identity(&mut identity, external_system, external_user_id)?;
+        self.link_external_identity(
+            &mut identity,
+            external_system.clone(),
+            external_user_id.clone(),
+            workspace_id,
+        )?;
 
         // アクセス権付与を先に実行（全て成功した場合のみIdentityLinkを保存）
         self.grant_access_to_all_resources(&email).await?;
 
         // 成功した場合のみIdentityLinkを保存
         self.save_identity_link(identity).await?;
+
+        self.record_audit(IdentityLinkAuditEntry::new(
+            IdentityLinkAction::Linked,
+            email,
+            external_system,
+            external_user_id,
+            actor_id,
+        ))
+        .await;
         Ok(())
     }
 
+    /// 監査記録を追記する（紐付け自体は完了しているため、失敗しても警告のみ）
+    async fn record_audit(&self, entry: IdentityLinkAuditEntry) {
+        if let Some(audit_repo) = &self.audit_repo
+            && let Err(e) = audit_repo.append(entry).await
+        {
+            tracing::warn!("Failed to record identity link audit entry: {}", e);
+        }
+    }
+
     async fn resolve_or_create_identity_link(
         &self,
         email: &EmailAddress,
@@ -81,16 +121,20 @@ impl GrantUserResourceAccessUseCase {
         identity: &mut IdentityLink,
         external_system: ExternalSystem,
         external_user_id: String,
+        workspace_id: Option<String>,
     ) -> Result<(), ApplicationError> {
-        // 既に指定された外部システムと紐付いているかチェック
-        if identity.has_identity_for_system(&external_system) {
+        // 既に指定された外部システム（同じワークスペース）と紐付いているかチェック
+        if identity.has_identity_for_workspace(&external_system, workspace_id.as_deref()) {
             return Err(ApplicationError::ExternalSystemAlreadyLinked {
                 email: identity.email().as_str().to_string(),
                 external_system: external_system.as_str().to_string(),
             });
         }
 
-        let external_identity = ExternalIdentity::new(external_system, external_user_id);
+        let mut external_identity = ExternalIdentity::new(external_system, external_user_id);
+        if let Some(workspace_id) = workspace_id {
+            external_identity = external_identity.with_workspace_id(workspace_id);
+        }
         identity.link_external_identity(external_identity)?;
         Ok(())
     }
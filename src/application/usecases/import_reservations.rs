@@ -0,0 +1,82 @@
+use crate::application::error::ApplicationError;
+use crate::application::usecases::create_resource_usage::CreateResourceUsageUseCase;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    Priority, ReservationMetadata, UsageId, Visibility,
+};
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::ports::reservation_import::ReservationSource;
+use std::sync::Arc;
+
+/// 取り込んだ1行の結果
+#[derive(Debug)]
+pub struct ImportRowResult {
+    /// 取り込み元での行番号
+    pub line: usize,
+    /// 作成した予約のID、または作成できなかった理由
+    pub result: Result<UsageId, String>,
+}
+
+/// 予約をまとめて取り込むユースケース
+///
+/// 取り込み元の行ごとに、通常の予約の作成と同じ競合・上限・受付期間などの確認を行って予約を作成する。
+/// 作成できない行があっても残りの行の取り込みは継続する。
+/// 行は上から順に作成するため、同じ取り込み元の中で重なる予約は後の行が競合となる。
+pub struct ImportReservationsUseCase<R: ResourceUsageRepository> {
+    create_usecase: Arc<CreateResourceUsageUseCase<R>>,
+    source: Arc<dyn ReservationSource>,
+}
+
+impl<R: ResourceUsageRepository> ImportReservationsUseCase<R> {
+    /// 新しいImportReservationsUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `create_usecase` - 予約の作成ユースケース
+    /// * `source` - 取り込む予約の読み込み元
+    pub fn new(
+        create_usecase: Arc<CreateResourceUsageUseCase<R>>,
+        source: Arc<dyn ReservationSource>,
+    ) -> Self {
+        Self {
+            create_usecase,
+            source,
+        }
+    }
+
+    /// 予約を取り込む
+    ///
+    /// # Returns
+    /// 行ごとの結果（取り込み元の順）
+    ///
+    /// # Errors
+    /// - 取り込み元を読み込めない場合
+    pub async fn execute(&self) -> Result<Vec<ImportRowResult>, ApplicationError> {
+        let rows = self.source.read().await?;
+
+        let mut results = Vec::with_capacity(rows.len());
+        for row in rows {
+            let result = match row.draft {
+                Ok(draft) => self
+                    .create_usecase
+                    .execute(
+                        draft.owner_email,
+                        draft.time_period,
+                        draft.resources,
+                        draft.notes,
+                        ReservationMetadata::default(),
+                        Visibility::default(),
+                        Priority::default(),
+                        None,
+                    )
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(reason) => Err(reason),
+            };
+            results.push(ImportRowResult {
+                line: row.line,
+                result,
+            });
+        }
+
+        Ok(results)
+    }
+}
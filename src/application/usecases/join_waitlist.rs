@@ -0,0 +1,43 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::waitlist::WaitlistEntry;
+use crate::domain::ports::repositories::WaitlistRepository;
+use std::sync::Arc;
+
+/// 空き待ちに登録するユースケース
+///
+/// 既存の予約と競合して予約できなかった時間帯・リソースを記録し、
+/// 空きが出たときに`NotifyWaitlistUseCase`から知らせられるようにする。
+pub struct JoinWaitlistUseCase {
+    waitlist_repository: Arc<dyn WaitlistRepository>,
+}
+
+impl JoinWaitlistUseCase {
+    /// 新しいJoinWaitlistUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `waitlist_repository` - Waitlistリポジトリ
+    pub fn new(waitlist_repository: Arc<dyn WaitlistRepository>) -> Self {
+        Self {
+            waitlist_repository,
+        }
+    }
+
+    /// 空き待ちに登録する
+    ///
+    /// 同じユーザーが同じ時間帯・リソースで既に登録している場合は、登録順を保つため何もしない。
+    ///
+    /// # Returns
+    /// 新たに登録した場合は `true`、既に登録済みだった場合は `false`
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self, entry: WaitlistEntry) -> Result<bool, ApplicationError> {
+        let entries = self.waitlist_repository.find_all().await?;
+        if entries.iter().any(|e| e.is_same_request(&entry)) {
+            return Ok(false);
+        }
+
+        self.waitlist_repository.save(entry).await?;
+        Ok(true)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::application::error::ApplicationError;
 use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
-use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::ports::repositories::{ResourceUsageRepository, UsageQuery};
 use std::sync::Arc;
 
 /// 全ての未来のリソース使用予定を取得するユースケース
@@ -25,11 +25,17 @@ impl<R: ResourceUsageRepository> ListAllFutureResourceUsagesUseCase<R> {
     /// # Errors
     /// - リポジトリエラー
     pub async fn execute(&self) -> Result<Vec<ResourceUsage>, ApplicationError> {
-        let mut usages = self.repository.find_future().await?;
-
-        // 開始時刻でソート
-        usages.sort_by_key(|a| a.time_period().start());
+        self.query(&UsageQuery::new()).await
+    }
 
-        Ok(usages)
+    /// 検索条件に合う未来のリソース使用予定を取得
+    ///
+    /// # Arguments
+    /// * `query` - 検索条件
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn query(&self, query: &UsageQuery) -> Result<Vec<ResourceUsage>, ApplicationError> {
+        Ok(self.repository.query(query).await?)
     }
 }
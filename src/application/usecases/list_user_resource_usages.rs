@@ -1,7 +1,7 @@
 use crate::application::error::ApplicationError;
 use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
 use crate::domain::common::EmailAddress;
-use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::ports::repositories::{ResourceUsageRepository, UsageQuery};
 use std::sync::Arc;
 
 /// ユーザーのリソース使用予定一覧を取得するユースケース
@@ -32,11 +32,7 @@ impl<R: ResourceUsageRepository> ListUserResourceUsagesUseCase<R> {
         &self,
         owner_email: &EmailAddress,
     ) -> Result<Vec<ResourceUsage>, ApplicationError> {
-        let mut usages = self.repository.find_by_owner(owner_email).await?;
-
-        // 開始時刻でソート
-        usages.sort_by_key(|a| a.time_period().start());
-
-        Ok(usages)
+        let query = UsageQuery::new().with_owner(owner_email.clone());
+        Ok(self.repository.query(&query).await?)
     }
 }
@@ -41,28 +41,114 @@
 //! ### 4. Thin Application Layer
 //! Application層は薄く保ち、ドメインロジックをDomain層に配置する。
 
+/// 承認待ちの予約を承認・却下するユースケース
+pub mod approve_reservation;
+/// GPUが使われていない予約を確認・解放するユースケース
+pub mod auto_release_idle_reservations;
+/// 複数のリソース使用予定をまとめて削除するユースケース
+pub mod bulk_delete_resource_usages;
+/// 予約と同じリソース・長さで次に空いている時間帯を求めるユースケース
+pub mod clone_reservation;
+/// 期間内のGPUの予約の費用を集計するユースケース
+pub mod cost_report;
 /// リソース使用予定を作成するユースケース
 pub mod create_resource_usage;
 /// リソース使用予定を削除するユースケース
 pub mod delete_resource_usage;
+/// 予約なしでのGPUの使用を検出して警告するユースケース
+pub mod detect_unreserved_usage;
+/// 期間内の予約を書き出すユースケース
+pub mod export_reservations;
+/// リソース使用予定の終了時刻を延長するユースケース
+pub mod extend_resource_usage;
+/// 次に空いている時間帯を探すユースケース
+pub mod find_next_available_slot;
+/// リソースの予約を停止するユースケース
+pub mod freeze_resource;
+/// リソースを現在使用中の予約を取得するユースケース
+pub mod get_current_occupants;
+/// IdentityLinkの監査記録を取得するユースケース
+pub mod get_identity_link_history;
+/// リソースの空き状況を取得するユースケース
+pub mod get_resource_availability;
 /// IDでリソース使用予定を取得するユースケース
 pub mod get_resource_usage_by_id;
 /// ユーザーにリソースアクセス権を付与するユースケース
 pub mod grant_user_resource_access;
+/// 予約をまとめて取り込むユースケース
+pub mod import_reservations;
+/// 空き待ちに登録するユースケース
+pub mod join_waitlist;
 /// 全ての未来のリソース使用予定を取得するユースケース
 pub mod list_all_future_resource_usages;
 /// ユーザーのリソース使用予定一覧を取得するユースケース
 pub mod list_user_resource_usages;
 /// 未来のリソース使用変更を監視して通知するユースケース
 pub mod notify_future_resource_usage_changes;
+/// 空き待ちしているユーザーに空きを知らせるユースケース
+pub mod notify_waitlist;
+/// 予約の読み取りモデルを再構築するユースケース
+pub mod rebuild_reservation_read_model;
+/// 予約IDとカレンダーのイベントの対応を照合・修復するユースケース
+pub mod reconcile_mappings;
+/// 使用中のリソース使用予定を早期終了するユースケース
+pub mod release_resource_usage;
+/// ユーザーのリソースアクセス権を解除するユースケース
+pub mod revoke_user_resource_access;
+/// 予約開始前に予約者へリマインダーを送るユースケース
+pub mod send_upcoming_reminders;
+/// デバイスの状態を設定するユースケース
+pub mod set_device_status;
+/// 研究室の名簿からID紐付けを同期するユースケース
+pub mod sync_directory_members;
+/// 予約を別のユーザーに引き継ぐユースケース
+pub mod transfer_ownership;
 /// リソース使用予定を更新するユースケース
 pub mod update_resource_usage;
+/// 期間内の予約時間を集計するユースケース
+pub mod usage_report;
+/// 予約開始前にサーバーの電源を入れるユースケース
+pub mod wake_reserved_servers;
+/// 週に一度、利用状況のまとめをチャンネルに投稿するユースケース
+pub mod weekly_digest;
 
+pub use approve_reservation::ApproveReservationUseCase;
+pub use auto_release_idle_reservations::AutoReleaseIdleReservationsUseCase;
+pub use bulk_delete_resource_usages::BulkDeleteResourceUsagesUseCase;
+pub use clone_reservation::{CloneReservationUseCase, ClonedReservation};
+pub use cost_report::{CostReport, CostReportUseCase, ProjectCostTotal, UserCostTotal};
 pub use create_resource_usage::CreateResourceUsageUseCase;
 pub use delete_resource_usage::DeleteResourceUsageUseCase;
+pub use detect_unreserved_usage::DetectUnreservedUsageUseCase;
+pub use export_reservations::ExportReservationsUseCase;
+pub use extend_resource_usage::ExtendResourceUsageUseCase;
+pub use find_next_available_slot::FindNextAvailableSlotUseCase;
+pub use freeze_resource::FreezeResourceUseCase;
+pub use get_current_occupants::GetCurrentOccupantsUseCase;
+pub use get_identity_link_history::GetIdentityLinkHistoryUseCase;
+pub use get_resource_availability::{
+    AvailabilityReport, GetResourceAvailabilityUseCase, ResourceAvailability,
+};
 pub use get_resource_usage_by_id::GetResourceUsageByIdUseCase;
 pub use grant_user_resource_access::GrantUserResourceAccessUseCase;
+pub use import_reservations::{ImportReservationsUseCase, ImportRowResult};
+pub use join_waitlist::JoinWaitlistUseCase;
 pub use list_all_future_resource_usages::ListAllFutureResourceUsagesUseCase;
 pub use list_user_resource_usages::ListUserResourceUsagesUseCase;
 pub use notify_future_resource_usage_changes::NotifyFutureResourceUsageChangesUseCase;
+pub use notify_waitlist::NotifyWaitlistUseCase;
+pub use rebuild_reservation_read_model::RebuildReservationReadModelUseCase;
+pub use reconcile_mappings::{ReconcileMappingsUseCase, ReconcileResult};
+pub use release_resource_usage::ReleaseResourceUsageUseCase;
+pub use revoke_user_resource_access::RevokeUserResourceAccessUseCase;
+pub use send_upcoming_reminders::SendUpcomingRemindersUseCase;
+pub use set_device_status::SetDeviceStatusUseCase;
+pub use sync_directory_members::SyncDirectoryMembersUseCase;
+pub use transfer_ownership::TransferOwnershipUseCase;
 pub use update_resource_usage::UpdateResourceUsageUseCase;
+pub use usage_report::{
+    PeriodUsageTotal, ProjectUsageTotal, ResourceUsageTotal, UsageReport, UsageReportUseCase,
+    UserUsageTotal,
+};
+pub use wake_reserved_servers::WakeReservedServersUseCase;
+pub use weekly_digest::{DigestChannel, WeeklyDigestUseCase};
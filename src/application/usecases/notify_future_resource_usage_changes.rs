@@ -1,20 +1,37 @@
 use crate::application::ApplicationError;
 use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
-use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, SeriesId, UsageId};
+use crate::domain::ports::repositories::{
+    ResourceUsageRepository, UsageSnapshotEntry, UsageSnapshotRepository,
+};
 use crate::domain::ports::{NotificationEvent, Notifier};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::json;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::warn;
 
 /// 未来および進行中のリソース使用状況の変更を監視し、通知するユースケース
 ///
 /// このユースケースは以下の変更を検知して通知します:
-/// - 新規作成: 新しいリソース使用予約が追加された
+/// - 新規作成: 新しいリソース使用予約が追加された（繰り返し予約はシリーズごとに1回）
 /// - 更新: 既存の予約内容が変更された
 /// - 削除: **未来の予約**がキャンセル/削除された
+/// - 使用開始: 予約の開始時刻を迎えた
+/// - 使用終了: 予約の終了時刻を迎えた（解放・短縮によって早く終了した場合を含む）
+///
+/// 使用開始・終了は、前回のポーリングから今回のポーリングまでの間に開始・終了時刻を迎えた予約を通知します。
+/// 承認待ちの予約は通知しません。
 ///
 /// # スコープ
 /// このユースケースは「未来および進行中」のリソース使用のみを監視対象とします。
 /// 予約期間が終了したリソースは自然に監視対象外となり、削除通知は送信されません。
+///
+/// # 再起動
+/// 状態のリポジトリを指定した場合（[`Self::with_snapshot`]）、ポーリングごとに確認した予約を記録し、
+/// 起動時には記録との差分（停止中に作成・更新・削除された予約）だけを通知します。
+/// 削除は、記録した予約のうちまだ終了していないものが見つからなくなった場合に通知します。
 pub struct NotifyFutureResourceUsageChangesUseCase<R, N>
 where
     R: ResourceUsageRepository,
@@ -23,6 +40,8 @@ where
     repository: Arc<R>,
     notifier: N,
     previous_state: tokio::sync::Mutex<HashMap<String, ResourceUsage>>,
+    previous_polled_at: tokio::sync::Mutex<DateTime<Utc>>,
+    snapshots: Option<Arc<dyn UsageSnapshotRepository>>,
 }
 
 impl<R, N> NotifyFutureResourceUsageChangesUseCase<R, N>
@@ -39,37 +58,191 @@ where
     /// # Errors
     /// リポジトリから初期状態の取得に失敗した場合
     pub async fn new(repository: Arc<R>, notifier: N) -> Result<Self, ApplicationError> {
+        Self::initialize(repository, notifier, None).await
+    }
+
+    /// 前回確認した状態を引き継いでインスタンスを作成する
+    ///
+    /// 記録がある場合は、記録にない予約を作成、内容が変わった予約を更新、
+    /// 記録にあってまだ終了していないのに見つからない予約を削除として通知する。
+    /// 記録がない場合（初回起動時）は `new` と同じく現在の状態を初期状態とする。
+    ///
+    /// # Arguments
+    /// * `repository` - リソース使用リポジトリ（Arc で共有）
+    /// * `notifier` - 通知サービス
+    /// * `snapshots` - 確認した状態のリポジトリ
+    ///
+    /// # Errors
+    /// リポジトリから初期状態または記録の取得に失敗した場合
+    pub async fn with_snapshot(
+        repository: Arc<R>,
+        notifier: N,
+        snapshots: Arc<dyn UsageSnapshotRepository>,
+    ) -> Result<Self, ApplicationError> {
+        Self::initialize(repository, notifier, Some(snapshots)).await
+    }
+
+    async fn initialize(
+        repository: Arc<R>,
+        notifier: N,
+        snapshots: Option<Arc<dyn UsageSnapshotRepository>>,
+    ) -> Result<Self, ApplicationError> {
         let instance = Self {
             repository,
             notifier,
             previous_state: tokio::sync::Mutex::new(HashMap::new()),
+            previous_polled_at: tokio::sync::Mutex::new(Utc::now()),
+            snapshots,
         };
 
         let current_usages = instance.fetch_current_usages().await?;
+        if let Some(snapshots) = &instance.snapshots {
+            if let Some(saved) = snapshots.load().await? {
+                // 起動を止めないよう、通知の失敗は記録するだけにする
+                if let Err(e) = instance
+                    .notify_changes_since_snapshot(&saved, &current_usages)
+                    .await
+                {
+                    warn!("⚠️  停止中の変更の通知に失敗しました: {}", e);
+                }
+            }
+            instance.save_snapshot(&current_usages).await?;
+        }
         *instance.previous_state.lock().await = current_usages;
 
         Ok(instance)
     }
 
+    /// 記録した状態から変わった予約（作成・更新・削除）を通知する
+    ///
+    /// フィンガープリントの形式が異なる記録（以前のバージョンで記録したもの）は、内容を比較できないため
+    /// 更新として通知しない。
+    async fn notify_changes_since_snapshot(
+        &self,
+        saved: &HashMap<UsageId, UsageSnapshotEntry>,
+        current: &HashMap<String, ResourceUsage>,
+    ) -> Result<(), ApplicationError> {
+        let known: HashMap<String, ResourceUsage> = current
+            .iter()
+            .filter(|(_, usage)| saved.contains_key(usage.id()))
+            .map(|(id, usage)| (id.clone(), usage.clone()))
+            .collect();
+        self.detect_and_notify_created_usages(&known, current)
+            .await?;
+
+        for usage in known.values() {
+            let Some(entry) = saved.get(usage.id()) else {
+                continue;
+            };
+            if is_current_fingerprint(&entry.fingerprint) && entry.fingerprint != fingerprint(usage)
+            {
+                self.notify_updated(usage.clone()).await?;
+            }
+        }
+
+        let previous: HashMap<String, ResourceUsage> = saved
+            .iter()
+            .map(|(id, entry)| (id.as_str().to_string(), entry.usage.clone()))
+            .collect();
+        let ended = self.resolve_ended_usages(&previous, current).await?;
+        self.detect_and_notify_deleted_usages(&previous, current, &ended, Utc::now())
+            .await
+    }
+
+    async fn save_snapshot(
+        &self,
+        usages: &HashMap<String, ResourceUsage>,
+    ) -> Result<(), ApplicationError> {
+        let Some(snapshots) = &self.snapshots else {
+            return Ok(());
+        };
+        let snapshot = usages
+            .values()
+            .map(|usage| {
+                let entry = UsageSnapshotEntry {
+                    fingerprint: fingerprint(usage),
+                    usage: usage.clone(),
+                };
+                (usage.id().clone(), entry)
+            })
+            .collect();
+        snapshots.save(&snapshot).await?;
+        Ok(())
+    }
+
     /// 一度だけポーリングを実行し、変更を検知して通知する
     ///
-    /// 前回の状態と現在の状態を比較し、作成・更新・削除された予約を検知して通知します。
+    /// 前回の状態と現在の状態を比較し、作成・更新・削除された予約と、使用が開始・終了した予約を検知して通知します。
+    ///
+    /// # Returns
+    /// 取得した終了していない予約の件数
     ///
     /// # Errors
     /// リポジトリアクセスまたは通知送信に失敗した場合
-    pub async fn poll_once(&self) -> Result<(), ApplicationError> {
+    pub async fn poll_once(&self) -> Result<usize, ApplicationError> {
         let current_usages = self.fetch_current_usages().await?;
+        let now = Utc::now();
         let mut previous_usages = self.previous_state.lock().await;
+        let mut previous_polled_at = self.previous_polled_at.lock().await;
+        let ended_usages = self
+            .resolve_ended_usages(&previous_usages, &current_usages)
+            .await?;
 
         self.detect_and_notify_created_usages(&previous_usages, &current_usages)
             .await?;
-        self.detect_and_notify_updated_usages(&previous_usages, &current_usages)
-            .await?;
-        self.detect_and_notify_deleted_usages(&previous_usages, &current_usages)
+        self.detect_and_notify_updated_usages(&previous_usages, &current_usages, &ended_usages)
             .await?;
+        self.detect_and_notify_deleted_usages(
+            &previous_usages,
+            &current_usages,
+            &ended_usages,
+            now,
+        )
+        .await?;
+        self.detect_and_notify_started_usages(
+            &previous_usages,
+            &current_usages,
+            *previous_polled_at,
+            now,
+        )
+        .await?;
+        self.detect_and_notify_ended_usages(
+            &previous_usages,
+            &current_usages,
+            &ended_usages,
+            *previous_polled_at,
+            now,
+        )
+        .await?;
 
+        if *previous_usages != current_usages {
+            self.save_snapshot(&current_usages).await?;
+        }
+
+        let fetched = current_usages.len();
         *previous_usages = current_usages;
+        *previous_polled_at = now;
+
+        Ok(fetched)
+    }
+
+    /// 通知せずに現在の状態を取り込む
+    ///
+    /// 複数インスタンスで運用する場合の待機中のインスタンスが呼び出す。
+    /// リーダーを引き継いだときに、引き継ぐ前の変更を重ねて通知しないようにする。
+    ///
+    /// # Errors
+    /// リポジトリアクセスに失敗した場合
+    pub async fn sync_state(&self) -> Result<(), ApplicationError> {
+        let current_usages = self.fetch_current_usages().await?;
+        let mut previous_usages = self.previous_state.lock().await;
+
+        if *previous_usages != current_usages {
+            self.save_snapshot(&current_usages).await?;
+        }
 
+        *previous_usages = current_usages;
+        *self.previous_polled_at.lock().await = Utc::now();
         Ok(())
     }
 
@@ -83,15 +256,59 @@ where
             .collect())
     }
 
+    /// 前回確認した予約のうち、今回取得できなかった予約の現在の内容をリポジトリから取得する
+    ///
+    /// 終了時刻を過ぎた予約は未来の予約として取得されないため、期間どおりに終了した予約や、
+    /// 解放・短縮によって早く終了した予約もここで見つかる。見つからない予約（削除された予約）は含まない。
+    async fn resolve_ended_usages(
+        &self,
+        previous: &HashMap<String, ResourceUsage>,
+        current: &HashMap<String, ResourceUsage>,
+    ) -> Result<HashMap<String, ResourceUsage>, ApplicationError> {
+        let mut ended = HashMap::new();
+        for (id, usage) in previous {
+            if current.contains_key(id) {
+                continue;
+            }
+            if let Some(found) = self.repository.find_by_id(usage.id()).await? {
+                ended.insert(id.clone(), found);
+            }
+        }
+        Ok(ended)
+    }
+
     async fn detect_and_notify_created_usages(
         &self,
         previous: &HashMap<String, ResourceUsage>,
         current: &HashMap<String, ResourceUsage>,
     ) -> Result<(), ApplicationError> {
+        // 繰り返し予約はシリーズごとにまとめて1回だけ通知する
+        let mut series: HashMap<&SeriesId, Vec<&ResourceUsage>> = HashMap::new();
         for (id, usage) in current {
-            if !previous.contains_key(id) {
-                self.notify_created(usage.clone()).await?;
+            if previous.contains_key(id) {
+                continue;
+            }
+            match usage.series_id() {
+                Some(series_id) => series.entry(series_id).or_default().push(usage),
+                None => self.notify_created(usage.clone()).await?,
+            }
+        }
+
+        for mut usages in series.into_values() {
+            usages.sort_by_key(|usage| usage.time_period().start());
+            if let [usage] = usages.as_slice() {
+                self.notify_created((*usage).clone()).await?;
+                continue;
             }
+            let event = NotificationEvent::ResourceUsageSeriesCreated {
+                first: usages[0].clone(),
+                periods: usages
+                    .iter()
+                    .map(|usage| usage.time_period().clone())
+                    .collect(),
+                ids: usages.iter().map(|usage| usage.id().clone()).collect(),
+            };
+            self.notifier.notify(event).await?;
         }
         Ok(())
     }
@@ -100,8 +317,10 @@ where
         &self,
         previous: &HashMap<String, ResourceUsage>,
         current: &HashMap<String, ResourceUsage>,
+        ended: &HashMap<String, ResourceUsage>,
     ) -> Result<(), ApplicationError> {
-        for (id, current_usage) in current {
+        // 解放・短縮によって終了した予約は取得できなくなるため、リポジトリの内容と比較する
+        for (id, current_usage) in current.iter().chain(ended) {
             if let Some(previous_usage) = previous.get(id)
                 && previous_usage != current_usage
             {
@@ -115,9 +334,9 @@ where
         &self,
         previous: &HashMap<String, ResourceUsage>,
         current: &HashMap<String, ResourceUsage>,
+        ended: &HashMap<String, ResourceUsage>,
+        now: DateTime<Utc>,
     ) -> Result<(), ApplicationError> {
-        let now = chrono::Utc::now();
-
         // previousを現在時刻基準で「まだ未来」のものだけに絞る
         // (currentと同じ時間軸に合わせることで、自然な期限切れを削除と誤検知しない)
         let previous_still_future: HashMap<_, _> = previous
@@ -126,14 +345,60 @@ where
             .collect();
 
         // フィルタリング後のpreviousとcurrentを比較
+        // 解放・短縮によって早く終了した予約はリポジトリに残っているため、削除としない
         for (id, usage) in previous_still_future {
-            if !current.contains_key(id) {
+            if !current.contains_key(id) && !ended.contains_key(id) {
                 self.notify_deleted(usage.clone()).await?;
             }
         }
         Ok(())
     }
 
+    async fn detect_and_notify_started_usages(
+        &self,
+        previous: &HashMap<String, ResourceUsage>,
+        current: &HashMap<String, ResourceUsage>,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), ApplicationError> {
+        // 新規作成された予約は作成の通知で期間を知らせるため、前回から存在した予約のみ対象にする
+        for (id, usage) in current {
+            let start = usage.time_period().start();
+            if previous.contains_key(id)
+                && since < start
+                && start <= now
+                && !usage.approval_status().is_pending()
+            {
+                let event = NotificationEvent::ResourceUsageStarted(usage.clone());
+                self.notifier.notify(event).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn detect_and_notify_ended_usages(
+        &self,
+        previous: &HashMap<String, ResourceUsage>,
+        current: &HashMap<String, ResourceUsage>,
+        ended: &HashMap<String, ResourceUsage>,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), ApplicationError> {
+        for id in previous.keys() {
+            // 前回の終了時刻ではなく、延長・解放・短縮を反映した現在の終了時刻で判定する
+            // （どちらにも無い予約は削除されている）
+            let Some(usage) = current.get(id).or_else(|| ended.get(id)) else {
+                continue;
+            };
+            let end = usage.time_period().end();
+            if since < end && end <= now && !usage.approval_status().is_pending() {
+                let event = NotificationEvent::ResourceUsageEnded(usage.clone());
+                self.notifier.notify(event).await?;
+            }
+        }
+        Ok(())
+    }
+
     async fn notify_created(&self, usage: ResourceUsage) -> Result<(), ApplicationError> {
         let event = NotificationEvent::ResourceUsageCreated(usage);
         self.notifier.notify(event).await?;
@@ -152,3 +417,362 @@ where
         Ok(())
     }
 }
+
+/// フィンガープリントの形式のバージョン
+///
+/// 対象の項目や表現を変えた場合は上げる。形式の異なる記録は比較しない。
+const FINGERPRINT_VERSION: &str = "v1";
+
+/// 記録したフィンガープリントが現在の形式かどうか
+fn is_current_fingerprint(value: &str) -> bool {
+    value
+        .split_once(':')
+        .is_some_and(|(version, _)| version == FINGERPRINT_VERSION)
+}
+
+/// 予約内容のフィンガープリント（内容が同じ予約は同じ値になる）
+///
+/// 通知に関わる項目を決まった順序で並べたJSONのハッシュに、形式のバージョンを付ける。
+fn fingerprint(usage: &ResourceUsage) -> String {
+    let time = |value: DateTime<Utc>| value.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let metadata = usage.metadata();
+    let resources: Vec<String> = usage.resources().iter().map(resource_key).collect();
+    let fields = json!([
+        ["owner_email", usage.owner_email().as_str()],
+        ["start", time(usage.time_period().start())],
+        ["end", time(usage.time_period().end())],
+        ["resources", resources],
+        ["notes", usage.notes()],
+        ["project", metadata.project()],
+        ["experiment_id", metadata.experiment_id()],
+        ["expected_utilization", metadata.expected_utilization()],
+        ["private", usage.visibility().is_private()],
+        ["pending_approval", usage.approval_status().is_pending()],
+        ["priority", usage.priority().as_str()],
+        ["series_id", usage.series_id().map(|id| id.as_str())],
+        ["recurrence", usage.recurrence().map(|rule| rule.to_rrule())],
+        ["group", usage.group().map(|group| group.as_str())],
+    ]);
+    format!(
+        "{}:{}",
+        FINGERPRINT_VERSION,
+        hex::encode(Sha256::digest(fields.to_string().as_bytes()))
+    )
+}
+
+/// フィンガープリントに使うリソースの表現
+fn resource_key(resource: &Resource) -> String {
+    match resource {
+        Resource::Gpu(gpu) => format!(
+            "gpu/{}/{}/{}",
+            gpu.server(),
+            gpu.device_number(),
+            gpu.model()
+        ),
+        Resource::Room { name } => format!("room/{}", name),
+        Resource::Instrument { name } => format!("instrument/{}", name),
+        Resource::Custom { kind, name } => format!("custom/{}/{}", kind, name),
+        Resource::Storage { volume, gigabytes } => format!("storage/{}/{}", volume, gigabytes),
+        Resource::License { name, seats } => format!("license/{}/{}", name, seats),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::usecases::release_resource_usage::ReleaseResourceUsageUseCase;
+    use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+    use crate::domain::common::EmailAddress;
+    use crate::domain::ports::NotificationError;
+    use crate::domain::ports::repositories::RepositoryError;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use async_trait::async_trait;
+    use chrono::Duration;
+    use std::sync::Mutex;
+
+    #[derive(Clone, Default)]
+    struct RecordingNotifier {
+        events: Arc<Mutex<Vec<NotificationEvent>>>,
+    }
+
+    #[async_trait]
+    impl Notifier for RecordingNotifier {
+        async fn notify(&self, event: NotificationEvent) -> Result<(), NotificationError> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MemorySnapshots {
+        saved: Mutex<Option<HashMap<UsageId, UsageSnapshotEntry>>>,
+    }
+
+    #[async_trait]
+    impl UsageSnapshotRepository for MemorySnapshots {
+        async fn load(
+            &self,
+        ) -> Result<Option<HashMap<UsageId, UsageSnapshotEntry>>, RepositoryError> {
+            Ok(self.saved.lock().unwrap().clone())
+        }
+
+        async fn save(
+            &self,
+            snapshot: &HashMap<UsageId, UsageSnapshotEntry>,
+        ) -> Result<(), RepositoryError> {
+            *self.saved.lock().unwrap() = Some(snapshot.clone());
+            Ok(())
+        }
+    }
+
+    fn usage(start_in_hours: i64, notes: &str) -> ResourceUsage {
+        let start = Utc::now() + Duration::hours(start_in_hours);
+        ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, start + Duration::hours(2)).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            Some(notes.to_string()),
+        )
+        .unwrap()
+    }
+
+    fn snapshots_of(entries: Vec<(ResourceUsage, String)>) -> Arc<MemorySnapshots> {
+        let saved = entries
+            .into_iter()
+            .map(|(usage, fingerprint)| {
+                (
+                    usage.id().clone(),
+                    UsageSnapshotEntry { fingerprint, usage },
+                )
+            })
+            .collect();
+        Arc::new(MemorySnapshots {
+            saved: Mutex::new(Some(saved)),
+        })
+    }
+
+    async fn restart(
+        current: Vec<ResourceUsage>,
+        snapshots: Arc<MemorySnapshots>,
+    ) -> Vec<NotificationEvent> {
+        let repository = Arc::new(MockUsageRepository::new());
+        for usage in &current {
+            repository.save(usage).await.unwrap();
+        }
+        let notifier = RecordingNotifier::default();
+        NotifyFutureResourceUsageChangesUseCase::with_snapshot(
+            repository,
+            notifier.clone(),
+            snapshots,
+        )
+        .await
+        .unwrap();
+        notifier.events.lock().unwrap().clone()
+    }
+
+    fn in_progress_usage(ends_in: Duration) -> ResourceUsage {
+        let now = Utc::now();
+        ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(now - Duration::hours(1), now + ends_in).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            None,
+        )
+        .unwrap()
+    }
+
+    async fn watch(
+        usages: &[ResourceUsage],
+    ) -> (
+        Arc<MockUsageRepository>,
+        RecordingNotifier,
+        NotifyFutureResourceUsageChangesUseCase<MockUsageRepository, RecordingNotifier>,
+    ) {
+        let repository = Arc::new(MockUsageRepository::new());
+        for usage in usages {
+            repository.save(usage).await.unwrap();
+        }
+        let notifier = RecordingNotifier::default();
+        let use_case =
+            NotifyFutureResourceUsageChangesUseCase::new(repository.clone(), notifier.clone())
+                .await
+                .unwrap();
+        (repository, notifier, use_case)
+    }
+
+    #[tokio::test]
+    async fn test_poll_reports_released_usage_as_updated_not_deleted() {
+        let usage = in_progress_usage(Duration::hours(1));
+        let (repository, notifier, use_case) = watch(std::slice::from_ref(&usage)).await;
+
+        let released = ReleaseResourceUsageUseCase::new(repository.clone())
+            .execute(usage.id(), usage.owner_email())
+            .await
+            .unwrap();
+        use_case.poll_once().await.unwrap();
+
+        let events = notifier.events.lock().unwrap().clone();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            NotificationEvent::ResourceUsageUpdated(updated)
+                if updated.id() == usage.id() && updated.time_period() == &released
+        )));
+        assert!(
+            !events
+                .iter()
+                .any(|event| matches!(event, NotificationEvent::ResourceUsageDeleted(_)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_reports_deleted_in_progress_usage() {
+        let usage = in_progress_usage(Duration::hours(1));
+        let (repository, notifier, use_case) = watch(std::slice::from_ref(&usage)).await;
+
+        repository.delete(usage.id()).await.unwrap();
+        use_case.poll_once().await.unwrap();
+
+        let events = notifier.events.lock().unwrap().clone();
+        assert!(matches!(
+            events.as_slice(),
+            [NotificationEvent::ResourceUsageDeleted(deleted)] if deleted == &usage
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_poll_reports_released_usage_as_ended() {
+        let usage = in_progress_usage(Duration::hours(1));
+        let (repository, notifier, use_case) = watch(std::slice::from_ref(&usage)).await;
+
+        ReleaseResourceUsageUseCase::new(repository)
+            .execute(usage.id(), usage.owner_email())
+            .await
+            .unwrap();
+        use_case.poll_once().await.unwrap();
+
+        let events = notifier.events.lock().unwrap().clone();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            NotificationEvent::ResourceUsageEnded(ended)
+                if ended.id() == usage.id() && ended.time_period().end() < usage.time_period().end()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_poll_reports_usage_ending_on_schedule_as_ended_only() {
+        let usage = in_progress_usage(Duration::milliseconds(50));
+        let (_repository, notifier, use_case) = watch(std::slice::from_ref(&usage)).await;
+
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        use_case.poll_once().await.unwrap();
+
+        let events = notifier.events.lock().unwrap().clone();
+        assert!(matches!(
+            events.as_slice(),
+            [NotificationEvent::ResourceUsageEnded(ended)] if ended == &usage
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_poll_does_not_report_extended_usage_as_ended() {
+        let usage = in_progress_usage(Duration::milliseconds(50));
+        let (repository, notifier, use_case) = watch(std::slice::from_ref(&usage)).await;
+
+        let mut extended = usage.clone();
+        extended.update_time_period(
+            TimePeriod::new(usage.time_period().start(), Utc::now() + Duration::hours(1)).unwrap(),
+        );
+        repository.save(&extended).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        use_case.poll_once().await.unwrap();
+
+        let events = notifier.events.lock().unwrap().clone();
+        assert!(matches!(
+            events.as_slice(),
+            [NotificationEvent::ResourceUsageUpdated(updated)] if updated == &extended
+        ));
+    }
+
+    #[test]
+    fn test_fingerprint_is_versioned_and_follows_content() {
+        let original = usage(24, "ゼミ");
+        let same = original.clone();
+        let edited = ResourceUsage::reconstruct(
+            original.id().clone(),
+            original.owner_email().clone(),
+            original.time_period().clone(),
+            original.resources().clone(),
+            Some("輪講".to_string()),
+        )
+        .unwrap();
+
+        assert!(fingerprint(&original).starts_with("v1:"));
+        assert!(is_current_fingerprint(&fingerprint(&original)));
+        assert!(!is_current_fingerprint("9f86d081884c7d65"));
+        assert_eq!(fingerprint(&original), fingerprint(&same));
+        assert_ne!(fingerprint(&original), fingerprint(&edited));
+    }
+
+    #[tokio::test]
+    async fn test_restart_reports_usages_deleted_while_stopped() {
+        let deleted = usage(24, "ゼミ");
+        let snapshots = snapshots_of(vec![(deleted.clone(), fingerprint(&deleted))]);
+
+        let events = restart(Vec::new(), snapshots.clone()).await;
+
+        assert!(matches!(
+            events.as_slice(),
+            [NotificationEvent::ResourceUsageDeleted(usage)] if usage == &deleted
+        ));
+        assert_eq!(snapshots.load().await.unwrap(), Some(HashMap::new()));
+    }
+
+    #[tokio::test]
+    async fn test_restart_does_not_report_ended_usages_as_deleted() {
+        let ended = usage(-5, "ゼミ");
+        let snapshots = snapshots_of(vec![(ended.clone(), fingerprint(&ended))]);
+
+        assert!(restart(Vec::new(), snapshots).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restart_reports_created_and_updated_usages_only() {
+        let unchanged = usage(24, "ゼミ");
+        let before = usage(48, "ゼミ");
+        let after = ResourceUsage::reconstruct(
+            before.id().clone(),
+            before.owner_email().clone(),
+            before.time_period().clone(),
+            before.resources().clone(),
+            Some("輪講".to_string()),
+        )
+        .unwrap();
+        let created = usage(72, "面談");
+        let snapshots = snapshots_of(vec![
+            (unchanged.clone(), fingerprint(&unchanged)),
+            (before.clone(), fingerprint(&before)),
+        ]);
+
+        let events = restart(vec![unchanged, after.clone(), created.clone()], snapshots).await;
+
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().any(
+            |event| matches!(event, NotificationEvent::ResourceUsageCreated(usage) if usage == &created)
+        ));
+        assert!(events.iter().any(
+            |event| matches!(event, NotificationEvent::ResourceUsageUpdated(usage) if usage == &after)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_restart_does_not_compare_fingerprints_of_another_version() {
+        let usage = usage(24, "ゼミ");
+        let snapshots = snapshots_of(vec![(usage.clone(), "9f86d081884c7d65".to_string())]);
+
+        assert!(restart(vec![usage], snapshots).await.is_empty());
+    }
+}
@@ -0,0 +1,342 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::aggregates::waitlist::WaitlistEntry;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::WaitlistNotifier;
+use crate::domain::ports::repositories::{
+    IdentityLinkRepository, ResourceUsageRepository, WaitlistRepository,
+};
+use crate::domain::services::resource_usage::ResourceConflictChecker;
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 空きを知らせた希望者に与える手番の長さ（分）
+///
+/// この間は、重なる時間帯・リソースを待っている後続の希望者には知らせない。
+pub const WAITLIST_TURN_MINUTES: i64 = 15;
+
+/// 空き待ちしているユーザーに空きを知らせるユースケース
+///
+/// 競合していた予約がキャンセル・短縮されるなどして、希望する時間帯・リソースのすべてが
+/// 空いた空き待ちについて、ID紐付けから希望者のSlackユーザーを特定し、
+/// ダイレクトメッセージで知らせる。
+/// 同じ枠を複数人が待っている場合は登録順に1人ずつ知らせ、手番の間に予約されなければ次の人に知らせる。
+pub struct NotifyWaitlistUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    waitlist_repository: Arc<dyn WaitlistRepository>,
+    identity_repo: Arc<dyn IdentityLinkRepository>,
+    waitlist_notifier: Arc<dyn WaitlistNotifier>,
+    conflict_checker: ResourceConflictChecker,
+}
+
+impl<R: ResourceUsageRepository> NotifyWaitlistUseCase<R> {
+    /// 新しいNotifyWaitlistUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `waitlist_repository` - Waitlistリポジトリ
+    /// * `identity_repo` - IdentityLinkリポジトリ
+    /// * `waitlist_notifier` - 空き通知の送信手段
+    pub fn new(
+        repository: Arc<R>,
+        waitlist_repository: Arc<dyn WaitlistRepository>,
+        identity_repo: Arc<dyn IdentityLinkRepository>,
+        waitlist_notifier: Arc<dyn WaitlistNotifier>,
+    ) -> Self {
+        Self {
+            repository,
+            waitlist_repository,
+            identity_repo,
+            waitlist_notifier,
+            conflict_checker: ResourceConflictChecker::new(),
+        }
+    }
+
+    /// ストレージのボリュームごとの容量（GB）を設定
+    ///
+    /// 設定した場合、確保容量の合計が容量を超える間は、ストレージの空き待ちに空きを知らせない。
+    pub fn with_storage_capacities(mut self, storage_capacities: HashMap<String, u64>) -> Self {
+        self.conflict_checker = self
+            .conflict_checker
+            .with_storage_capacities(storage_capacities);
+        self
+    }
+
+    /// ライセンスごとの席数を設定
+    ///
+    /// 設定した場合、同じライセンスの予約の確保席数の合計が席数を超える予約を競合とみなす。
+    pub fn with_license_seats(mut self, license_seats: HashMap<String, u32>) -> Self {
+        self.conflict_checker = self.conflict_checker.with_license_seats(license_seats);
+        self
+    }
+
+    /// 空きが出た空き待ちの希望者に知らせる
+    ///
+    /// 希望する時間帯が終わった空き待ちと、手番が過ぎた空き待ちは削除する。
+    /// 個々の送信に失敗しても残りの空き待ちの処理は継続し、次回の実行で再試行する。
+    ///
+    /// # Returns
+    /// 空きを知らせた希望者のメールアドレスの一覧（登録順）
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self) -> Result<Vec<EmailAddress>, ApplicationError> {
+        let now = Utc::now();
+        let turn = Duration::minutes(WAITLIST_TURN_MINUTES);
+
+        let mut holding: Vec<WaitlistEntry> = Vec::new();
+        let mut notified = Vec::new();
+        for mut entry in self.waitlist_repository.find_all().await? {
+            if entry.is_expired(now) {
+                self.waitlist_repository.delete(entry.id()).await?;
+                continue;
+            }
+            if entry.notified_at().is_some() {
+                if entry.holds_turn(now, turn) {
+                    holding.push(entry);
+                } else {
+                    self.waitlist_repository.delete(entry.id()).await?;
+                }
+                continue;
+            }
+            // 先に登録した希望者の手番の間は待つ
+            if holding.iter().any(|h| h.overlaps_with(&entry)) {
+                continue;
+            }
+
+            let conflicts = self
+                .conflict_checker
+                .find_conflicts(
+                    self.repository.as_ref(),
+                    entry.time_period(),
+                    entry.resources(),
+                    None,
+                )
+                .await?;
+            if !conflicts.is_empty() {
+                continue;
+            }
+
+            match self.notify(&entry).await {
+                Ok(true) => {
+                    entry.mark_notified(now);
+                    self.waitlist_repository.save(entry.clone()).await?;
+                    notified.push(entry.owner_email().clone());
+                    holding.push(entry);
+                }
+                Ok(false) => {
+                    // Slackと紐付いていない希望者には知らせる手段がない
+                    self.waitlist_repository.delete(entry.id()).await?;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to notify waitlist entry '{}': {}", entry.id(), e);
+                }
+            }
+        }
+
+        Ok(notified)
+    }
+
+    /// 希望者のSlackユーザーに空きを知らせる
+    ///
+    /// # Returns
+    /// 送信した場合は `true`、希望者がSlackと紐付いていない場合は `false`
+    async fn notify(&self, entry: &WaitlistEntry) -> Result<bool, ApplicationError> {
+        let Some(identity_link) = self
+            .identity_repo
+            .find_by_email(entry.owner_email())
+            .await?
+        else {
+            return Ok(false);
+        };
+        let Some(identity) = identity_link.get_identity_for_system(&ExternalSystem::Slack) else {
+            return Ok(false);
+        };
+
+        self.waitlist_notifier
+            .notify_available(identity.user_id(), entry)
+            .await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::identity_link::entity::IdentityLink;
+    use crate::domain::aggregates::identity_link::value_objects::ExternalIdentity;
+    use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource, TimePeriod};
+    use crate::domain::ports::NotificationError;
+    use crate::infrastructure::repositories::identity_link::JsonFileIdentityLinkRepository;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use crate::infrastructure::repositories::waitlist::JsonFileWaitlistRepository;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// 送信先のユーザーIDを記録する（`failing` の間は送信に失敗する）
+    #[derive(Default)]
+    struct RecordingWaitlistNotifier {
+        sent: Mutex<Vec<String>>,
+        failing: AtomicBool,
+    }
+
+    #[async_trait]
+    impl WaitlistNotifier for RecordingWaitlistNotifier {
+        async fn notify_available(
+            &self,
+            user_id: &str,
+            _entry: &WaitlistEntry,
+        ) -> Result<(), NotificationError> {
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(NotificationError::SendFailure(
+                    "送信の失敗（テスト用）".to_string(),
+                ));
+            }
+            self.sent.lock().unwrap().push(user_id.to_string());
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        usages: Arc<MockUsageRepository>,
+        waitlist: Arc<JsonFileWaitlistRepository>,
+        notifier: Arc<RecordingWaitlistNotifier>,
+        use_case: NotifyWaitlistUseCase<MockUsageRepository>,
+    }
+
+    /// aliceとbobだけがSlackと紐付いている環境
+    async fn fixture() -> Fixture {
+        let dir = std::env::temp_dir().join(format!("lrm_waitlist_{}", uuid::Uuid::new_v4()));
+        let identities = Arc::new(JsonFileIdentityLinkRepository::new(
+            dir.join("identity_links.json"),
+        ));
+        for (name, user_id) in [("alice", "U_ALICE"), ("bob", "U_BOB")] {
+            let mut link = IdentityLink::new(email(name));
+            link.link_external_identity(ExternalIdentity::new(
+                ExternalSystem::Slack,
+                user_id.to_string(),
+            ))
+            .unwrap();
+            identities.save(link).await.unwrap();
+        }
+
+        let usages = Arc::new(MockUsageRepository::new());
+        let waitlist = Arc::new(JsonFileWaitlistRepository::new(dir.join("waitlist.json")));
+        let notifier = Arc::new(RecordingWaitlistNotifier::default());
+        let use_case = NotifyWaitlistUseCase::new(
+            usages.clone(),
+            waitlist.clone(),
+            identities,
+            notifier.clone(),
+        );
+        Fixture {
+            usages,
+            waitlist,
+            notifier,
+            use_case,
+        }
+    }
+
+    fn email(name: &str) -> EmailAddress {
+        EmailAddress::new(format!("{}@example.com", name)).unwrap()
+    }
+
+    fn gpu(device: u32) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()))
+    }
+
+    fn tomorrow() -> TimePeriod {
+        let start = Utc::now() + Duration::days(1);
+        TimePeriod::new(start, start + Duration::hours(2)).unwrap()
+    }
+
+    /// 登録順を固定するため、`minutes_ago` 分前に登録した空き待ちとして保存する
+    async fn join(
+        fixture: &Fixture,
+        name: &str,
+        device: u32,
+        minutes_ago: i64,
+        notified_minutes_ago: Option<i64>,
+    ) -> WaitlistEntry {
+        let now = Utc::now();
+        let entry = WaitlistEntry::reconstitute(
+            uuid::Uuid::new_v4().to_string(),
+            email(name),
+            tomorrow(),
+            vec![gpu(device)],
+            now - Duration::minutes(minutes_ago),
+            notified_minutes_ago.map(|minutes| now - Duration::minutes(minutes)),
+        );
+        fixture.waitlist.save(entry.clone()).await.unwrap();
+        entry
+    }
+
+    async fn remaining(fixture: &Fixture) -> Vec<(EmailAddress, bool)> {
+        fixture
+            .waitlist
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| (entry.owner_email().clone(), entry.notified_at().is_some()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_notifies_first_waiting_user_of_free_slot() {
+        let fixture = fixture().await;
+        let taken = ResourceUsage::new(email("dave"), tomorrow(), vec![gpu(2)], None).unwrap();
+        fixture.usages.save(&taken).await.unwrap();
+        join(&fixture, "alice", 0, 30, None).await;
+        join(&fixture, "bob", 0, 20, None).await;
+        join(&fixture, "carol", 1, 10, None).await;
+        join(&fixture, "bob", 2, 5, None).await;
+
+        let notified = fixture.use_case.execute().await.unwrap();
+
+        // bobはaliceの手番の間は待ち、紐付いていないcarolの空き待ちは削除し、
+        // 予約の残っているGPU 2の空き待ちは残す
+        assert_eq!(notified, vec![email("alice")]);
+        assert_eq!(*fixture.notifier.sent.lock().unwrap(), vec!["U_ALICE"]);
+        assert_eq!(
+            remaining(&fixture).await,
+            vec![
+                (email("alice"), true),
+                (email("bob"), false),
+                (email("bob"), false)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_passes_turn_to_next_user_after_it_expires() {
+        let fixture = fixture().await;
+        join(&fixture, "alice", 0, 60, Some(WAITLIST_TURN_MINUTES + 5)).await;
+        join(&fixture, "bob", 0, 30, None).await;
+
+        let notified = fixture.use_case.execute().await.unwrap();
+
+        assert_eq!(notified, vec![email("bob")]);
+        assert_eq!(remaining(&fixture).await, vec![(email("bob"), true)]);
+    }
+
+    #[tokio::test]
+    async fn test_keeps_entry_when_sending_fails() {
+        let fixture = fixture().await;
+        join(&fixture, "alice", 0, 30, None).await;
+        fixture.notifier.failing.store(true, Ordering::SeqCst);
+
+        assert!(fixture.use_case.execute().await.unwrap().is_empty());
+        assert_eq!(remaining(&fixture).await, vec![(email("alice"), false)]);
+
+        // 次回の実行で再試行する
+        fixture.notifier.failing.store(false, Ordering::SeqCst);
+        assert_eq!(
+            fixture.use_case.execute().await.unwrap(),
+            vec![email("alice")]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::application::error::ApplicationError;
+use crate::application::read_model::{ReservationProjection, ReservationReadModel};
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use std::sync::Arc;
+
+/// 予約の読み取りモデルを再構築するユースケース
+pub struct RebuildReservationReadModelUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    read_model: Arc<ReservationReadModel>,
+    timezone: Option<String>,
+}
+
+impl<R: ResourceUsageRepository> RebuildReservationReadModelUseCase<R> {
+    /// 新しいRebuildReservationReadModelUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `read_model` - 再構築対象の読み取りモデル
+    /// * `timezone` - 日付の区切りに使うタイムゾーン（IANA形式）
+    pub fn new(
+        repository: Arc<R>,
+        read_model: Arc<ReservationReadModel>,
+        timezone: Option<String>,
+    ) -> Self {
+        Self {
+            repository,
+            read_model,
+            timezone,
+        }
+    }
+
+    /// 再構築対象の読み取りモデルを取得
+    pub fn read_model(&self) -> Arc<ReservationReadModel> {
+        self.read_model.clone()
+    }
+
+    /// 未来の予約からプロジェクションを構築し、読み取りモデルを置き換える
+    ///
+    /// 取得に失敗した場合は以前のプロジェクションを保持する。
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self) -> Result<(), ApplicationError> {
+        let usages = self.repository.find_future().await?;
+        let projection = ReservationProjection::build(usages, self.timezone.as_deref());
+        self.read_model.replace(projection);
+        Ok(())
+    }
+}
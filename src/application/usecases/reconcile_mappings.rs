@@ -0,0 +1,77 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::ports::mapping_reconciliation::{MappingDiscrepancy, MappingReconciler};
+use std::sync::Arc;
+
+/// 修復した食い違い1件の結果
+#[derive(Debug)]
+pub struct ReconcileResult {
+    /// 検出した食い違い
+    pub discrepancy: MappingDiscrepancy,
+    /// 修復の結果、または修復できなかった理由
+    pub result: Result<(), String>,
+}
+
+/// 予約IDとカレンダーのイベントの対応を照合・修復するユースケース
+///
+/// 手動で削除されたイベントへの対応を削除し、対応の記録がないイベントの対応を補完する。
+/// 修復できない食い違いがあっても残りの修復は継続する。
+pub struct ReconcileMappingsUseCase {
+    reconciler: Arc<dyn MappingReconciler>,
+}
+
+impl ReconcileMappingsUseCase {
+    /// 新しいReconcileMappingsUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `reconciler` - 対応の照合サービス
+    pub fn new(reconciler: Arc<dyn MappingReconciler>) -> Self {
+        Self { reconciler }
+    }
+
+    /// 食い違いを検出する（修復はしない）
+    ///
+    /// # Arguments
+    /// * `period` - 対応のないイベントを探す期間
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn find(
+        &self,
+        period: &TimePeriod,
+    ) -> Result<Vec<MappingDiscrepancy>, ApplicationError> {
+        Ok(self.reconciler.find_discrepancies(period).await?)
+    }
+
+    /// 食い違いを検出して修復する
+    ///
+    /// # Arguments
+    /// * `period` - 対応のないイベントを探す期間
+    ///
+    /// # Returns
+    /// 食い違いごとの修復の結果
+    ///
+    /// # Errors
+    /// - 食い違いの検出に失敗した場合
+    pub async fn execute(
+        &self,
+        period: &TimePeriod,
+    ) -> Result<Vec<ReconcileResult>, ApplicationError> {
+        let discrepancies = self.reconciler.find_discrepancies(period).await?;
+
+        let mut results = Vec::with_capacity(discrepancies.len());
+        for discrepancy in discrepancies {
+            let result = self
+                .reconciler
+                .resolve(&discrepancy)
+                .await
+                .map_err(|e| e.to_string());
+            results.push(ReconcileResult {
+                discrepancy,
+                result,
+            });
+        }
+
+        Ok(results)
+    }
+}
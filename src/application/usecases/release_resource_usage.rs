@@ -0,0 +1,78 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::group::Group;
+use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, UsageId};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::domain::services::{AuthorizationPolicy, ResourceUsageAuthorizationPolicy};
+use chrono::Utc;
+use std::sync::Arc;
+
+/// 使用中のリソース使用予定を早期終了し、リソースを解放するユースケース
+pub struct ReleaseResourceUsageUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    authorization_policy: ResourceUsageAuthorizationPolicy,
+}
+
+impl<R: ResourceUsageRepository> ReleaseResourceUsageUseCase<R> {
+    /// 新しいReleaseResourceUsageUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    pub fn new(repository: Arc<R>) -> Self {
+        Self {
+            repository,
+            authorization_policy: ResourceUsageAuthorizationPolicy::new(),
+        }
+    }
+
+    /// 管理者を設定
+    ///
+    /// 管理者は他のユーザーの予約も早期終了できる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_admins(admins);
+        self
+    }
+
+    /// チームを設定
+    ///
+    /// チームが所有する予約は、そのチームのメンバーも早期終了できる。
+    pub fn with_groups(mut self, groups: Vec<Group>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_groups(groups);
+        self
+    }
+
+    /// 使用中のリソース使用予定の終了時刻を現在時刻に切り詰める
+    ///
+    /// # Arguments
+    /// * `id` - 使用予定ID
+    /// * `owner_email` - 所有者のメールアドレス（権限チェック用）
+    ///
+    /// # Returns
+    /// 切り詰めた後の使用期間
+    ///
+    /// # Errors
+    /// - 指定されたIDの予約が見つからない場合
+    /// - 操作する権限がない場合
+    /// - 予約が使用中でない（開始前または終了済み）場合
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        id: &UsageId,
+        owner_email: &EmailAddress,
+    ) -> Result<TimePeriod, ApplicationError> {
+        let mut usage = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::Repository(RepositoryError::NotFound))?;
+
+        self.authorization_policy
+            .authorize_update(owner_email, &usage)
+            .map_err(|e| ApplicationError::Unauthorized(e.to_string()))?;
+
+        usage.truncate_at(Utc::now())?;
+        self.repository.save(&usage).await?;
+
+        Ok(usage.time_period().clone())
+    }
+}
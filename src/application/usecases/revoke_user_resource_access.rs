@@ -0,0 +1,138 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::identity_link::{
+    errors::IdentityLinkError,
+    value_objects::{ExternalSystem, IdentityLinkAction, IdentityLinkAuditEntry},
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{IdentityLinkAuditRepository, IdentityLinkRepository};
+use crate::domain::ports::resource_collection_access::ResourceCollectionAccessService;
+use std::sync::Arc;
+
+/// ユーザーのリソースアクセス権を解除するUseCase
+///
+/// 外部システムのユーザーとメールアドレスの紐付けを解除する。
+/// どの外部システムとも紐付かなくなった場合はIdentityLinkを削除し、
+/// すべてのリソースコレクションへのアクセス権を解除する。
+pub struct RevokeUserResourceAccessUseCase {
+    identity_repo: Arc<dyn IdentityLinkRepository>,
+    collection_access: Arc<dyn ResourceCollectionAccessService>,
+    /// アクセス権を解除するコレクションIDのリスト
+    collection_ids: Vec<String>,
+    audit_repo: Option<Arc<dyn IdentityLinkAuditRepository>>,
+}
+
+impl RevokeUserResourceAccessUseCase {
+    /// 新しいインスタンスを作成する
+    ///
+    /// # Arguments
+    /// * `identity_repo` - ID紐付けリポジトリ
+    /// * `collection_access` - リソースコレクションアクセスサービス
+    /// * `collection_ids` - アクセス権を解除するコレクションIDのリスト
+    pub fn new(
+        identity_repo: Arc<dyn IdentityLinkRepository>,
+        collection_access: Arc<dyn ResourceCollectionAccessService>,
+        collection_ids: Vec<String>,
+    ) -> Self {
+        Self {
+            identity_repo,
+            collection_access,
+            collection_ids,
+            audit_repo: None,
+        }
+    }
+
+    /// 監査記録リポジトリを設定
+    ///
+    /// 設定した場合、紐付けの解除のたびに監査記録を追記する。
+    pub fn with_audit_repository(
+        mut self,
+        audit_repo: Arc<dyn IdentityLinkAuditRepository>,
+    ) -> Self {
+        self.audit_repo = Some(audit_repo);
+        self
+    }
+
+    /// ユーザーの紐付けを解除する
+    ///
+    /// 他の外部システムとの紐付けが残っている場合、アクセス権はそのまま残す。
+    ///
+    /// # Arguments
+    /// * `external_system` - 外部システム（例: Slack）
+    /// * `external_user_id` - 外部システム上のユーザーID
+    /// * `actor_id` - 操作した外部システム上のユーザーID
+    ///
+    /// # Returns
+    /// 紐付けを解除したメールアドレス
+    ///
+    /// # Errors
+    /// * `ApplicationError::IdentityLink` - ユーザーが紐付けられていない場合
+    /// * その他のリポジトリエラー
+    pub async fn execute(
+        &self,
+        external_system: ExternalSystem,
+        external_user_id: &str,
+        actor_id: Option<String>,
+    ) -> Result<EmailAddress, ApplicationError> {
+        let mut identity = self
+            .identity_repo
+            .find_by_external_user_id(&external_system, external_user_id)
+            .await?
+            .ok_or_else(|| IdentityLinkError::IdentityNotFound {
+                system: external_system.clone(),
+            })?;
+        let email = identity.email().clone();
+        let audit_entries: Vec<IdentityLinkAuditEntry> = identity
+            .identities_for_system(&external_system)
+            .map(|unlinked| {
+                IdentityLinkAuditEntry::new(
+                    IdentityLinkAction::Unlinked,
+                    email.clone(),
+                    external_system.clone(),
+                    unlinked.user_id().to_string(),
+                    actor_id.clone(),
+                )
+            })
+            .collect();
+        identity.unlink_external_identity(&external_system)?;
+
+        if identity.is_linked_to_any_system() {
+            self.identity_repo.save(identity).await?;
+        } else {
+            // アクセス権の解除を先に実行（失敗しても紐付けが残るため再実行できる）
+            self.revoke_access_to_all_resources(&email).await;
+            self.identity_repo.delete(&email).await?;
+        }
+
+        for entry in audit_entries {
+            self.record_audit(entry).await;
+        }
+        Ok(email)
+    }
+
+    /// 監査記録を追記する（解除自体は完了しているため、失敗しても警告のみ）
+    async fn record_audit(&self, entry: IdentityLinkAuditEntry) {
+        if let Some(audit_repo) = &self.audit_repo
+            && let Err(e) = audit_repo.append(entry).await
+        {
+            tracing::warn!("Failed to record identity link audit entry: {}", e);
+        }
+    }
+
+    async fn revoke_access_to_all_resources(&self, email: &EmailAddress) {
+        for collection_id in &self.collection_ids {
+            if let Err(e) = self
+                .collection_access
+                .revoke_access(collection_id, email)
+                .await
+            {
+                // アクセス権が既に無い場合も含め、警告を出して処理は継続
+                tracing::warn!(
+                    "Failed to revoke access to collection '{}' for {}: {}",
+                    collection_id,
+                    email.as_str(),
+                    e
+                );
+            }
+        }
+    }
+}
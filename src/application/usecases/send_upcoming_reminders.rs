@@ -0,0 +1,318 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{
+    IdentityLinkRepository, ResourceUsageRepository, SentReminderRepository,
+};
+use crate::domain::ports::{ReminderKind, ReminderSender};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// 予約の開始前・終了前に予約者へリマインダーを送るユースケース
+///
+/// 予約の開始（または終了）のリードタイム以内に入った予約について、
+/// ID紐付けから予約者のSlackユーザーを特定し、ダイレクトメッセージでリマインダーを送る。
+/// 送信済みの記録は永続化し、再起動後も同じ予約に重複して送らない。
+/// 予約の開始・終了時刻が変更（延長など）された場合は、新しい時刻に合わせて改めて送る。
+pub struct SendUpcomingRemindersUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    identity_repo: Arc<dyn IdentityLinkRepository>,
+    reminder_sender: Arc<dyn ReminderSender>,
+    sent_reminders: Arc<dyn SentReminderRepository>,
+    /// リソース名（サーバー名・部屋名）ごとの開始前リマインダーのリードタイム
+    remind_before: HashMap<String, Duration>,
+    /// リソース名（サーバー名・部屋名）ごとの終了前リマインダーのリードタイム
+    remind_before_end: HashMap<String, Duration>,
+}
+
+impl<R: ResourceUsageRepository> SendUpcomingRemindersUseCase<R> {
+    /// 新しいSendUpcomingRemindersUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `identity_repo` - IdentityLinkリポジトリ
+    /// * `reminder_sender` - リマインダーの送信手段
+    /// * `sent_reminders` - 送信済みリマインダーのリポジトリ
+    /// * `remind_before` - リソース名ごとの開始前リマインダーのリードタイム
+    pub fn new(
+        repository: Arc<R>,
+        identity_repo: Arc<dyn IdentityLinkRepository>,
+        reminder_sender: Arc<dyn ReminderSender>,
+        sent_reminders: Arc<dyn SentReminderRepository>,
+        remind_before: HashMap<String, Duration>,
+    ) -> Self {
+        Self {
+            repository,
+            identity_repo,
+            reminder_sender,
+            sent_reminders,
+            remind_before,
+            remind_before_end: HashMap::new(),
+        }
+    }
+
+    /// 終了前リマインダーのリードタイムを設定
+    ///
+    /// 設定したリソースの予約は、終了の少し前に予約者へ延長・解放ボタン付きのリマインダーを送る。
+    pub fn with_end_reminders(mut self, remind_before_end: HashMap<String, Duration>) -> Self {
+        self.remind_before_end = remind_before_end;
+        self
+    }
+
+    /// 開始・終了が近い予約の予約者にリマインダーを送る
+    ///
+    /// 個々の送信に失敗しても残りの予約の処理は継続し、次回の実行で再試行する。
+    ///
+    /// # Returns
+    /// リマインダーを送った予約者のメールアドレスと、リマインダーの種類の一覧
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self) -> Result<Vec<(EmailAddress, ReminderKind)>, ApplicationError> {
+        let now = Utc::now();
+        let usages = self.repository.find_future().await?;
+
+        // 削除・終了した予約の記録は破棄
+        let current_ids: HashSet<UsageId> = usages.iter().map(|u| u.id().clone()).collect();
+        self.sent_reminders.retain(&current_ids).await?;
+
+        let mut reminded = Vec::new();
+        for kind in [ReminderKind::Start, ReminderKind::End] {
+            let sent = self.sent_reminders.find_all(kind).await?;
+            for usage in &usages {
+                let Some(lead_time) = self.lead_time(usage, kind) else {
+                    continue;
+                };
+                let at = reference_time(usage, kind);
+                let in_window = now >= at - lead_time && now < at;
+                // 終了前リマインダーは使用中の予約にだけ送る
+                let started = kind == ReminderKind::Start || now >= usage.time_period().start();
+                if !in_window || !started {
+                    continue;
+                }
+                if sent.get(usage.id()) == Some(&at) {
+                    continue;
+                }
+
+                match self.remind(usage, kind).await {
+                    Ok(sent) => {
+                        self.sent_reminders.save(kind, usage.id(), at).await?;
+                        if sent {
+                            reminded.push((usage.owner_email().clone(), kind));
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to send {:?} reminder for usage '{}': {}",
+                            kind,
+                            usage.id().as_str(),
+                            e
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(reminded)
+    }
+
+    /// 予約のリソースに設定されたリードタイムのうち最長のもの
+    fn lead_time(&self, usage: &ResourceUsage, kind: ReminderKind) -> Option<Duration> {
+        let lead_times = match kind {
+            ReminderKind::Start => &self.remind_before,
+            ReminderKind::End => &self.remind_before_end,
+        };
+        usage
+            .resources()
+            .iter()
+            .filter_map(|r| lead_times.get(r.name()).copied())
+            .max()
+    }
+
+    /// 予約者のSlackユーザーにリマインダーを送る
+    ///
+    /// # Returns
+    /// 送信した場合は `true`、予約者がSlackと紐付いていない場合は `false`
+    async fn remind(
+        &self,
+        usage: &ResourceUsage,
+        kind: ReminderKind,
+    ) -> Result<bool, ApplicationError> {
+        let Some(identity_link) = self
+            .identity_repo
+            .find_by_email(usage.owner_email())
+            .await?
+        else {
+            return Ok(false);
+        };
+        let Some(identity) = identity_link.get_identity_for_system(&ExternalSystem::Slack) else {
+            return Ok(false);
+        };
+
+        self.reminder_sender
+            .send_reminder(identity.user_id(), usage, kind)
+            .await?;
+        Ok(true)
+    }
+}
+
+/// リマインダーの基準時刻（開始前なら開始時刻、終了前なら終了時刻）
+fn reference_time(usage: &ResourceUsage, kind: ReminderKind) -> DateTime<Utc> {
+    match kind {
+        ReminderKind::Start => usage.time_period().start(),
+        ReminderKind::End => usage.time_period().end(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::identity_link::entity::IdentityLink;
+    use crate::domain::aggregates::identity_link::value_objects::ExternalIdentity;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource, TimePeriod};
+    use crate::domain::ports::NotificationError;
+    use crate::infrastructure::repositories::identity_link::JsonFileIdentityLinkRepository;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use crate::infrastructure::repositories::sent_reminder::JsonFileSentReminderRepository;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// 送信したリマインダーの送信先と種類を記録する
+    #[derive(Default)]
+    struct RecordingReminderSender {
+        sent: Mutex<Vec<(String, ReminderKind)>>,
+    }
+
+    #[async_trait]
+    impl ReminderSender for RecordingReminderSender {
+        async fn send_reminder(
+            &self,
+            user_id: &str,
+            _usage: &ResourceUsage,
+            kind: ReminderKind,
+        ) -> Result<(), NotificationError> {
+            self.sent.lock().unwrap().push((user_id.to_string(), kind));
+            Ok(())
+        }
+    }
+
+    fn email(name: &str) -> EmailAddress {
+        EmailAddress::new(format!("{}@example.com", name)).unwrap()
+    }
+
+    /// 今から `start_minutes` 分後に始まり `end_minutes` 分後に終わるThalysのGPUの予約
+    fn usage(owner: &str, start_minutes: i64, end_minutes: i64) -> ResourceUsage {
+        let now = Utc::now();
+        ResourceUsage::new(
+            email(owner),
+            TimePeriod::new(
+                now + Duration::minutes(start_minutes),
+                now + Duration::minutes(end_minutes),
+            )
+            .unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                0,
+                "A100".to_string(),
+            ))],
+            None,
+        )
+        .unwrap()
+    }
+
+    /// aliceだけがSlackと紐付いている環境で、Thalysの開始・終了の15分前に送るユースケースを作る
+    async fn use_case(
+        usages: &Arc<MockUsageRepository>,
+        sender: &Arc<RecordingReminderSender>,
+    ) -> SendUpcomingRemindersUseCase<MockUsageRepository> {
+        let dir = std::env::temp_dir().join(format!("lrm_reminders_{}", uuid::Uuid::new_v4()));
+        let identities = Arc::new(JsonFileIdentityLinkRepository::new(
+            dir.join("identity_links.json"),
+        ));
+        let mut link = IdentityLink::new(email("alice"));
+        link.link_external_identity(ExternalIdentity::new(
+            ExternalSystem::Slack,
+            "U_ALICE".to_string(),
+        ))
+        .unwrap();
+        identities.save(link).await.unwrap();
+
+        let lead_times = HashMap::from([("Thalys".to_string(), Duration::minutes(15))]);
+        SendUpcomingRemindersUseCase::new(
+            usages.clone(),
+            identities,
+            sender.clone(),
+            Arc::new(JsonFileSentReminderRepository::new(
+                dir.join("sent_reminders.json"),
+            )),
+            lead_times.clone(),
+        )
+        .with_end_reminders(lead_times)
+    }
+
+    #[tokio::test]
+    async fn test_sends_start_reminder_once_within_lead_time() {
+        let usages = Arc::new(MockUsageRepository::new());
+        let sender = Arc::new(RecordingReminderSender::default());
+        let use_case = use_case(&usages, &sender).await;
+        usages.save(&usage("alice", 10, 120)).await.unwrap();
+        usages.save(&usage("alice", 60, 120)).await.unwrap();
+        usages.save(&usage("bob", 10, 120)).await.unwrap();
+
+        // 紐付いていないbobには送らない
+        assert_eq!(
+            use_case.execute().await.unwrap(),
+            vec![(email("alice"), ReminderKind::Start)]
+        );
+        assert_eq!(
+            *sender.sent.lock().unwrap(),
+            vec![("U_ALICE".to_string(), ReminderKind::Start)]
+        );
+
+        assert!(use_case.execute().await.unwrap().is_empty());
+        assert_eq!(sender.sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resends_start_reminder_after_start_time_changes() {
+        let usages = Arc::new(MockUsageRepository::new());
+        let sender = Arc::new(RecordingReminderSender::default());
+        let use_case = use_case(&usages, &sender).await;
+        let mut reserved = usage("alice", 10, 120);
+        usages.save(&reserved).await.unwrap();
+        assert_eq!(use_case.execute().await.unwrap().len(), 1);
+
+        let now = Utc::now();
+        reserved.update_time_period(
+            TimePeriod::new(now + Duration::minutes(5), now + Duration::minutes(120)).unwrap(),
+        );
+        usages.save(&reserved).await.unwrap();
+
+        assert_eq!(
+            use_case.execute().await.unwrap(),
+            vec![(email("alice"), ReminderKind::Start)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sends_end_reminder_only_for_started_usage() {
+        let usages = Arc::new(MockUsageRepository::new());
+        let sender = Arc::new(RecordingReminderSender::default());
+        let use_case = use_case(&usages, &sender).await;
+        usages.save(&usage("alice", -60, 10)).await.unwrap();
+        // 終了が近くてもまだ始まっていない予約には開始前リマインダーだけを送る
+        usages.save(&usage("alice", 2, 10)).await.unwrap();
+
+        assert_eq!(
+            use_case.execute().await.unwrap(),
+            vec![
+                (email("alice"), ReminderKind::Start),
+                (email("alice"), ReminderKind::End)
+            ]
+        );
+        assert!(use_case.execute().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::device_health::{DeviceHealth, DeviceStatus};
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::ports::repositories::{DeviceHealthRepository, ResourceUsageRepository};
+use std::sync::Arc;
+
+/// デバイスの状態を設定するユースケース
+///
+/// 故障や性能低下に合わせて、デバイスごとの状態（使用可能・性能低下・使用停止）を設定する。
+/// 使用停止にした場合は、そのデバイスを含む今後の予約を返す。
+pub struct SetDeviceStatusUseCase<R: ResourceUsageRepository> {
+    usage_repository: Arc<R>,
+    device_health_repository: Arc<dyn DeviceHealthRepository>,
+}
+
+impl<R: ResourceUsageRepository> SetDeviceStatusUseCase<R> {
+    /// 新しいSetDeviceStatusUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `usage_repository` - ResourceUsageリポジトリ
+    /// * `device_health_repository` - DeviceHealthリポジトリ
+    pub fn new(
+        usage_repository: Arc<R>,
+        device_health_repository: Arc<dyn DeviceHealthRepository>,
+    ) -> Self {
+        Self {
+            usage_repository,
+            device_health_repository,
+        }
+    }
+
+    /// デバイスの状態を設定する
+    ///
+    /// 使用可能に戻す場合は、登録されている状態を削除する。
+    ///
+    /// # Returns
+    /// 使用停止にした場合は、そのデバイスを含む今後の予約（開始時刻順）。それ以外は空
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        health: DeviceHealth,
+    ) -> Result<Vec<ResourceUsage>, ApplicationError> {
+        if health.status() == DeviceStatus::Available {
+            self.device_health_repository
+                .delete(health.server_name(), health.device_id())
+                .await?;
+            return Ok(Vec::new());
+        }
+
+        let mut affected: Vec<ResourceUsage> = if health.status() == DeviceStatus::OutOfService {
+            self.usage_repository
+                .find_future()
+                .await?
+                .into_iter()
+                .filter(|usage| usage.resources().iter().any(|r| health.applies_to(r)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        affected.sort_by_key(|usage| usage.time_period().start());
+
+        self.device_health_repository.save(health).await?;
+        Ok(affected)
+    }
+
+    /// 状態が登録されているデバイスの一覧を取得する（サーバー名・デバイス番号順）
+    pub async fn list(&self) -> Result<Vec<DeviceHealth>, ApplicationError> {
+        let mut healths = self.device_health_repository.find_all().await?;
+        healths.sort_by(|a, b| {
+            (a.server_name(), a.device_id()).cmp(&(b.server_name(), b.device_id()))
+        });
+        Ok(healths)
+    }
+}
@@ -0,0 +1,98 @@
+use crate::application::error::ApplicationError;
+use crate::application::usecases::grant_user_resource_access::GrantUserResourceAccessUseCase;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::member_directory::{ExternalUserDirectory, MemberDirectory};
+use crate::domain::ports::repositories::IdentityLinkRepository;
+use std::sync::Arc;
+
+/// 研究室の名簿からID紐付けを同期するユースケース
+///
+/// 名簿（LDAP等）に登録されたメンバーのうち、まだ外部システムに紐付けられていない人を
+/// メールアドレスで外部システムのユーザーと突き合わせ、紐付けとリソースへのアクセス権付与を行う。
+/// 名簿から外れたメンバーの紐付けは解除しない。
+pub struct SyncDirectoryMembersUseCase {
+    member_directory: Arc<dyn MemberDirectory>,
+    user_directory: Arc<dyn ExternalUserDirectory>,
+    identity_repo: Arc<dyn IdentityLinkRepository>,
+    grant_access_usecase: Arc<GrantUserResourceAccessUseCase>,
+}
+
+impl SyncDirectoryMembersUseCase {
+    /// 新しいSyncDirectoryMembersUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `member_directory` - 研究室メンバーの名簿
+    /// * `user_directory` - 外部システムのユーザーディレクトリ
+    /// * `identity_repo` - ID紐付けリポジトリ
+    /// * `grant_access_usecase` - アクセス権付与ユースケース
+    pub fn new(
+        member_directory: Arc<dyn MemberDirectory>,
+        user_directory: Arc<dyn ExternalUserDirectory>,
+        identity_repo: Arc<dyn IdentityLinkRepository>,
+        grant_access_usecase: Arc<GrantUserResourceAccessUseCase>,
+    ) -> Self {
+        Self {
+            member_directory,
+            user_directory,
+            identity_repo,
+            grant_access_usecase,
+        }
+    }
+
+    /// 名簿を同期する
+    ///
+    /// 個々のメンバーの処理に失敗しても残りのメンバーの処理は継続し、次回の同期で再試行する。
+    ///
+    /// # Returns
+    /// 新たに紐付けたメンバーのメールアドレス一覧
+    ///
+    /// # Errors
+    /// - 名簿の取得エラー
+    pub async fn execute(&self) -> Result<Vec<EmailAddress>, ApplicationError> {
+        let system = self.user_directory.system();
+        let members = self.member_directory.list_member_emails().await?;
+
+        let mut linked = Vec::new();
+        for email in members {
+            match self.identity_repo.find_by_email(&email).await {
+                Ok(Some(identity)) if identity.has_identity_for_system(&system) => continue,
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        "⚠️ {} の紐付け状況を取得できませんでした: {}",
+                        email.as_str(),
+                        e
+                    );
+                    continue;
+                }
+            }
+
+            let user_id = match self.user_directory.find_user_id_by_email(&email).await {
+                Ok(Some(user_id)) => user_id,
+                Ok(None) => {
+                    tracing::debug!(
+                        "{} に対応する{:?}ユーザーがいません",
+                        email.as_str(),
+                        system
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!("⚠️ {} のユーザー検索に失敗: {}", email.as_str(), e);
+                    continue;
+                }
+            };
+
+            match self
+                .grant_access_usecase
+                .execute(system.clone(), user_id, None, email.clone(), None)
+                .await
+            {
+                Ok(()) => linked.push(email),
+                Err(e) => tracing::warn!("⚠️ {} の紐付けに失敗: {}", email.as_str(), e),
+            }
+        }
+
+        Ok(linked)
+    }
+}
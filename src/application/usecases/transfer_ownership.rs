@@ -0,0 +1,102 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{
+    RepositoryError, ResourceUsageRepository, UsageQuery, UsageStatus,
+};
+use crate::domain::services::ResourceUsageAuthorizationPolicy;
+use crate::domain::services::resource_usage::ReservationLimitPolicy;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// 予約を別のユーザーに引き継ぐユースケース
+///
+/// 卒業などで使えなくなった予約を研究室の別のメンバーに移すために使う。
+/// 引き継げるのは予約者本人と管理者のみ。予約の期間・リソース・備考などはそのまま引き継ぐ。
+pub struct TransferOwnershipUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    authorization_policy: ResourceUsageAuthorizationPolicy,
+    reservation_limits: ReservationLimitPolicy,
+}
+
+impl<R: ResourceUsageRepository> TransferOwnershipUseCase<R> {
+    /// 新しいTransferOwnershipUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    pub fn new(repository: Arc<R>) -> Self {
+        Self {
+            repository,
+            authorization_policy: ResourceUsageAuthorizationPolicy::new(),
+            reservation_limits: ReservationLimitPolicy::default(),
+        }
+    }
+
+    /// 管理者を設定
+    ///
+    /// 管理者は他のユーザーの予約も引き継がせることができる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_admins(admins);
+        self
+    }
+
+    /// 利用者ごとの同時予約の上限を設定
+    ///
+    /// 設定した場合、引き継ぎ先の上限を超える引き継ぎはできなくなる。
+    pub fn with_reservation_limits(mut self, reservation_limits: ReservationLimitPolicy) -> Self {
+        self.reservation_limits = reservation_limits;
+        self
+    }
+
+    /// 予約を引き継ぐ
+    ///
+    /// # Arguments
+    /// * `id` - 使用予定ID
+    /// * `actor` - 操作するユーザーのメールアドレス（権限チェック用）
+    /// * `new_owner` - 引き継ぎ先のメールアドレス
+    ///
+    /// # Errors
+    /// - 指定されたIDの予約が見つからない場合
+    /// - 操作する権限がない場合
+    /// - 引き継ぎ先が現在の予約者と同じ場合
+    /// - 引き継ぎ先の同時予約の上限を超える場合
+    /// - リポジトリエラー
+    pub async fn execute(
+        &self,
+        id: &UsageId,
+        actor: &EmailAddress,
+        new_owner: EmailAddress,
+    ) -> Result<(), ApplicationError> {
+        let mut usage = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::Repository(RepositoryError::NotFound))?;
+
+        self.authorization_policy
+            .authorize_transfer(actor, &usage)
+            .map_err(|e| ApplicationError::Unauthorized(e.to_string()))?;
+
+        usage.transfer_to(new_owner)?;
+
+        // 同時予約の上限チェック（引き継ぎ先の他の予約と合わせて数える）
+        if !self.reservation_limits.is_unlimited() {
+            let now = Utc::now();
+            let held: Vec<_> = self
+                .repository
+                .query(&UsageQuery::new().with_owner(usage.owner_email().clone()))
+                .await?
+                .into_iter()
+                .filter(|other| {
+                    other.id() != usage.id() && UsageStatus::of(other, now) != UsageStatus::Ended
+                })
+                .collect();
+            self.reservation_limits
+                .check(&held, usage.time_period(), usage.resources())?;
+        }
+
+        self.repository.save(&usage).await?;
+
+        Ok(())
+    }
+}
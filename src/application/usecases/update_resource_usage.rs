@@ -1,10 +1,18 @@
 use crate::application::error::ApplicationError;
-use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, UsageId};
+use crate::domain::aggregates::group::Group;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    ReservationMetadata, TimePeriod, UsageId, Visibility,
+};
 use crate::domain::common::EmailAddress;
-use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::domain::ports::repositories::{
+    RepositoryError, ResourceFreezeRepository, ResourceUsageRepository, UsageQuery, UsageStatus,
+};
+use crate::domain::services::resource_usage::{BookingWindowPolicy, ReservationLimitPolicy};
 use crate::domain::services::{
     AuthorizationPolicy, ResourceConflictChecker, ResourceUsageAuthorizationPolicy,
 };
+use chrono::Utc;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// リソース使用予定を更新するユースケース
@@ -12,6 +20,9 @@ pub struct UpdateResourceUsageUseCase<R: ResourceUsageRepository> {
     repository: Arc<R>,
     authorization_policy: ResourceUsageAuthorizationPolicy,
     conflict_checker: ResourceConflictChecker,
+    freeze_repository: Option<Arc<dyn ResourceFreezeRepository>>,
+    reservation_limits: ReservationLimitPolicy,
+    booking_windows: BookingWindowPolicy,
 }
 
 impl<R: ResourceUsageRepository> UpdateResourceUsageUseCase<R> {
@@ -26,9 +37,73 @@ impl<R: ResourceUsageRepository> UpdateResourceUsageUseCase<R> {
             repository,
             authorization_policy,
             conflict_checker,
+            freeze_repository: None,
+            reservation_limits: ReservationLimitPolicy::default(),
+            booking_windows: BookingWindowPolicy::default(),
         }
     }
 
+    /// 予約停止リポジトリを設定
+    ///
+    /// 設定した場合、予約停止中のリソースの予約を停止開始以降に延ばせなくなる。
+    pub fn with_freeze_repository(
+        mut self,
+        freeze_repository: Arc<dyn ResourceFreezeRepository>,
+    ) -> Self {
+        self.freeze_repository = Some(freeze_repository);
+        self
+    }
+
+    /// ストレージのボリュームごとの容量（GB）を設定
+    ///
+    /// 設定した場合、同じボリュームの予約の確保容量の合計が容量を超える予約を競合とみなす。
+    pub fn with_storage_capacities(mut self, storage_capacities: HashMap<String, u64>) -> Self {
+        self.conflict_checker = self
+            .conflict_checker
+            .with_storage_capacities(storage_capacities);
+        self
+    }
+
+    /// ライセンスごとの席数を設定
+    ///
+    /// 設定した場合、同じライセンスの予約の確保席数の合計が席数を超える予約を競合とみなす。
+    pub fn with_license_seats(mut self, license_seats: HashMap<String, u32>) -> Self {
+        self.conflict_checker = self.conflict_checker.with_license_seats(license_seats);
+        self
+    }
+
+    /// 利用者ごとの同時予約の上限を設定
+    ///
+    /// 設定した場合、予約者の上限を超えるように時間枠を変更できなくなる。
+    pub fn with_reservation_limits(mut self, reservation_limits: ReservationLimitPolicy) -> Self {
+        self.reservation_limits = reservation_limits;
+        self
+    }
+
+    /// リソースごとの予約の受付期間を設定
+    ///
+    /// 設定した場合、開始時刻を受付期間外に変更できなくなる（開始時刻を変えない変更は制限しない）。
+    pub fn with_booking_windows(mut self, booking_windows: BookingWindowPolicy) -> Self {
+        self.booking_windows = booking_windows;
+        self
+    }
+
+    /// 管理者を設定
+    ///
+    /// 管理者は他のユーザーの予約も更新できる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_admins(admins);
+        self
+    }
+
+    /// チームを設定
+    ///
+    /// チームが所有する予約は、そのチームのメンバーも更新できる。
+    pub fn with_groups(mut self, groups: Vec<Group>) -> Self {
+        self.authorization_policy = self.authorization_policy.with_groups(groups);
+        self
+    }
+
     /// リソース使用予定を更新
     ///
     /// # Arguments
@@ -36,14 +111,19 @@ impl<R: ResourceUsageRepository> UpdateResourceUsageUseCase<R> {
     /// * `owner_email` - 所有者のメールアドレス（権限チェック用）
     /// * `new_time_period` - 新しい使用期間（Noneの場合は変更なし）
     /// * `new_notes` - 新しい備考（Noneの場合は変更なし）
+    /// * `new_metadata` - 新しいメタデータ（Noneの場合は変更なし）
+    /// * `new_visibility` - 新しい公開範囲（Noneの場合は変更なし）
     ///
     /// # Returns
     /// 更新成功
     ///
     /// # Errors
     /// - 指定されたIDの予約が見つからない場合
-    /// - 所有者が一致しない場合
+    /// - 操作する権限がない場合
+    /// - 新しい開始時刻がリソースの予約の受付期間外の場合
+    /// - 新しい時間枠が予約停止の開始以降にかかる場合
     /// - 新しい時間枠が競合する場合
+    /// - 新しい時間枠で予約者の同時予約の上限を超える場合
     /// - リポジトリエラー
     pub async fn execute(
         &self,
@@ -51,6 +131,8 @@ impl<R: ResourceUsageRepository> UpdateResourceUsageUseCase<R> {
         owner_email: &EmailAddress,
         new_time_period: Option<TimePeriod>,
         new_notes: Option<String>,
+        new_metadata: Option<ReservationMetadata>,
+        new_visibility: Option<Visibility>,
     ) -> Result<(), ApplicationError> {
         // 既存の予約を取得
         let mut usage = self
@@ -66,6 +148,19 @@ impl<R: ResourceUsageRepository> UpdateResourceUsageUseCase<R> {
 
         // 時間枠の更新と競合チェック
         if let Some(new_period) = new_time_period {
+            // 受付期間チェック（開始時刻を変える場合のみ）
+            if new_period.start() != usage.time_period().start() {
+                self.booking_windows
+                    .check(&new_period, usage.resources(), Utc::now())?;
+            }
+
+            // 予約停止チェック
+            if let Some(freeze_repository) = &self.freeze_repository {
+                for freeze in freeze_repository.find_all().await? {
+                    freeze.check(&new_period, usage.resources())?;
+                }
+            }
+
             // 競合チェック（自分自身を除外）
             self.conflict_checker
                 .check_conflicts(
@@ -74,18 +169,24 @@ impl<R: ResourceUsageRepository> UpdateResourceUsageUseCase<R> {
                     usage.resources(),
                     Some(usage.id()),
                 )
-                .await
-                .map_err(|e| match e {
-                    crate::domain::services::resource_usage::errors::ConflictCheckError::Conflict(
-                        conflict_err,
-                    ) => ApplicationError::ResourceConflict {
-                        resource_description: conflict_err.resource_description.clone(),
-                        conflicting_usage_id: conflict_err.conflicting_usage_id.as_str().to_string(),
-                    },
-                    crate::domain::services::resource_usage::errors::ConflictCheckError::Repository(
-                        repo_err,
-                    ) => ApplicationError::Repository(repo_err),
-                })?;
+                .await?;
+
+            // 同時予約の上限チェック（予約者の他の予約と合わせて数える）
+            if !self.reservation_limits.is_unlimited() {
+                let now = Utc::now();
+                let held: Vec<_> = self
+                    .repository
+                    .query(&UsageQuery::new().with_owner(usage.owner_email().clone()))
+                    .await?
+                    .into_iter()
+                    .filter(|other| {
+                        other.id() != usage.id()
+                            && UsageStatus::of(other, now) != UsageStatus::Ended
+                    })
+                    .collect();
+                self.reservation_limits
+                    .check(&held, &new_period, usage.resources())?;
+            }
 
             usage.update_time_period(new_period);
         }
@@ -95,6 +196,16 @@ impl<R: ResourceUsageRepository> UpdateResourceUsageUseCase<R> {
             usage.update_notes(notes);
         }
 
+        // メタデータの更新
+        if let Some(metadata) = new_metadata {
+            usage.update_metadata(metadata);
+        }
+
+        // 公開範囲の更新
+        if let Some(visibility) = new_visibility {
+            usage.update_visibility(visibility);
+        }
+
         // 更新
         self.repository.save(&usage).await?;
 
@@ -0,0 +1,721 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::{
+    entity::ResourceUsage,
+    value_objects::{Resource, TimePeriod},
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use chrono::{DateTime, Datelike, Days, Duration, Local, Months, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::sync::Arc;
+
+/// ユーザーごとの予約時間の合計
+#[derive(Debug, Clone)]
+pub struct UserUsageTotal {
+    owner_email: EmailAddress,
+    gpu_time: Duration,
+    room_time: Duration,
+}
+
+impl UserUsageTotal {
+    /// 予約者のメールアドレス
+    pub fn owner_email(&self) -> &EmailAddress {
+        &self.owner_email
+    }
+
+    /// GPUの予約時間の合計（GPU 1台につき1時間で1時間）
+    pub fn gpu_time(&self) -> Duration {
+        self.gpu_time
+    }
+
+    /// 部屋の予約時間の合計
+    pub fn room_time(&self) -> Duration {
+        self.room_time
+    }
+}
+
+/// リソース（GPU・部屋）ごとの予約時間の合計
+#[derive(Debug, Clone)]
+pub struct ResourceUsageTotal {
+    resource: Resource,
+    time: Duration,
+}
+
+impl ResourceUsageTotal {
+    /// 対象のリソース
+    pub fn resource(&self) -> &Resource {
+        &self.resource
+    }
+
+    /// 予約時間の合計
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+}
+
+/// プロジェクトごとの予約時間の合計
+///
+/// 予約のメタデータにプロジェクト名が指定されたものだけを集計する。
+#[derive(Debug, Clone)]
+pub struct ProjectUsageTotal {
+    project: String,
+    gpu_time: Duration,
+    room_time: Duration,
+    /// 想定使用率（%）×GPUの予約時間（分）の合計
+    weighted_utilization: i64,
+    /// 想定使用率が指定された予約のGPUの予約時間（分）の合計
+    utilization_minutes: i64,
+}
+
+impl ProjectUsageTotal {
+    /// プロジェクト名
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+
+    /// GPUの予約時間の合計（GPU 1台につき1時間で1時間）
+    pub fn gpu_time(&self) -> Duration {
+        self.gpu_time
+    }
+
+    /// 部屋の予約時間の合計
+    pub fn room_time(&self) -> Duration {
+        self.room_time
+    }
+
+    /// 想定使用率（%）のGPUの予約時間による加重平均
+    ///
+    /// 想定使用率が指定された予約がない場合は `None`
+    pub fn expected_utilization(&self) -> Option<u8> {
+        (self.utilization_minutes > 0)
+            .then(|| (self.weighted_utilization / self.utilization_minutes) as u8)
+    }
+}
+
+/// 週・月ごとの予約時間の合計
+#[derive(Debug, Clone)]
+pub struct PeriodUsageTotal {
+    period: TimePeriod,
+    gpu_time: Duration,
+    room_time: Duration,
+}
+
+impl PeriodUsageTotal {
+    /// 集計した期間（集計期間の最初と最後の週・月は集計期間で切り詰める）
+    pub fn period(&self) -> &TimePeriod {
+        &self.period
+    }
+
+    /// GPUの予約時間の合計（GPU 1台につき1時間で1時間）
+    pub fn gpu_time(&self) -> Duration {
+        self.gpu_time
+    }
+
+    /// 部屋の予約時間の合計
+    pub fn room_time(&self) -> Duration {
+        self.room_time
+    }
+}
+
+/// 期間内の予約時間の集計結果
+///
+/// Slackのメッセージのほか、エクスポートやAPIでそのまま使えるようにシリアライズできる。
+/// 時間は時間単位の小数、時刻はRFC 3339の文字列として出力する。
+#[derive(Debug, Clone)]
+pub struct UsageReport {
+    period: TimePeriod,
+    users: Vec<UserUsageTotal>,
+    resources: Vec<ResourceUsageTotal>,
+    projects: Vec<ProjectUsageTotal>,
+    weeks: Vec<PeriodUsageTotal>,
+    months: Vec<PeriodUsageTotal>,
+}
+
+impl UsageReport {
+    /// 集計した期間
+    pub fn period(&self) -> &TimePeriod {
+        &self.period
+    }
+
+    /// ユーザーごとの合計（GPUの予約時間、部屋の予約時間の多い順）
+    pub fn users(&self) -> &[UserUsageTotal] {
+        &self.users
+    }
+
+    /// GPU・部屋ごとの合計（予約時間の多い順、予約のないリソースは含まない）
+    pub fn resources(&self) -> &[ResourceUsageTotal] {
+        &self.resources
+    }
+
+    /// プロジェクトごとの合計（GPUの予約時間、部屋の予約時間の多い順、プロジェクト名のない予約は含まない）
+    pub fn projects(&self) -> &[ProjectUsageTotal] {
+        &self.projects
+    }
+
+    /// 週（月曜始まり、ローカル時刻）ごとの合計（古い順、予約のない週も含む）
+    pub fn weeks(&self) -> &[PeriodUsageTotal] {
+        &self.weeks
+    }
+
+    /// 月（ローカル時刻）ごとの合計（古い順、予約のない月も含む）
+    pub fn months(&self) -> &[PeriodUsageTotal] {
+        &self.months
+    }
+
+    /// サーバーごとのGPUの予約時間の合計（多い順）
+    pub fn servers(&self) -> Vec<(String, Duration)> {
+        let mut servers: Vec<(String, Duration)> = Vec::new();
+        for total in &self.resources {
+            let Resource::Gpu(gpu) = total.resource() else {
+                continue;
+            };
+            match servers.iter_mut().find(|(name, _)| name == gpu.server()) {
+                Some((_, time)) => *time += total.time(),
+                None => servers.push((gpu.server().to_string(), total.time())),
+            }
+        }
+        servers.sort_by_key(|(_, time)| std::cmp::Reverse(*time));
+        servers
+    }
+
+    /// 予約を集計する
+    ///
+    /// 期間の外にはみ出した部分は数えない。週・月は `timezone` の暦で区切る（未設定ならローカル時刻）。
+    fn aggregate(period: TimePeriod, usages: &[ResourceUsage], timezone: Option<Tz>) -> Self {
+        let mut users: Vec<UserUsageTotal> = Vec::new();
+        let mut resources: Vec<ResourceUsageTotal> = Vec::new();
+        let mut projects: Vec<ProjectUsageTotal> = Vec::new();
+        let next_week = |date: NaiveDate| date.checked_add_days(Days::new(7));
+        let next_month = |date: NaiveDate| date.checked_add_months(Months::new(1));
+        let (mut weeks, mut months) = match timezone {
+            Some(tz) => (
+                calendar_totals(&period, &tz, week_start, next_week),
+                calendar_totals(&period, &tz, month_start, next_month),
+            ),
+            None => (
+                calendar_totals(&period, &Local, week_start, next_week),
+                calendar_totals(&period, &Local, month_start, next_month),
+            ),
+        };
+
+        for usage in usages {
+            let start = usage.time_period().start().max(period.start());
+            let end = usage.time_period().end().min(period.end());
+            if end <= start {
+                continue;
+            }
+            let time = end - start;
+
+            for bucket in weeks.iter_mut().chain(months.iter_mut()) {
+                bucket.add(usage);
+            }
+
+            if let Some(project) = usage.metadata().project() {
+                let total = match projects.iter().position(|p| p.project == project) {
+                    Some(index) => &mut projects[index],
+                    None => {
+                        projects.push(ProjectUsageTotal {
+                            project: project.to_string(),
+                            gpu_time: Duration::zero(),
+                            room_time: Duration::zero(),
+                            weighted_utilization: 0,
+                            utilization_minutes: 0,
+                        });
+                        projects.last_mut().expect("pushed above")
+                    }
+                };
+                let gpus = usage
+                    .resources()
+                    .iter()
+                    .filter(|r| matches!(r, Resource::Gpu(_)))
+                    .count() as i32;
+                let rooms = usage
+                    .resources()
+                    .iter()
+                    .filter(|r| matches!(r, Resource::Room { .. }))
+                    .count() as i32;
+                total.gpu_time += time * gpus;
+                total.room_time += time * rooms;
+                if let Some(utilization) = usage.metadata().expected_utilization() {
+                    let minutes = (time * gpus).num_minutes();
+                    total.weighted_utilization += i64::from(utilization) * minutes;
+                    total.utilization_minutes += minutes;
+                }
+            }
+
+            let user = match users
+                .iter_mut()
+                .position(|u| &u.owner_email == usage.owner_email())
+            {
+                Some(index) => &mut users[index],
+                None => {
+                    users.push(UserUsageTotal {
+                        owner_email: usage.owner_email().clone(),
+                        gpu_time: Duration::zero(),
+                        room_time: Duration::zero(),
+                    });
+                    users.last_mut().expect("pushed above")
+                }
+            };
+            for resource in usage.resources() {
+                match resource {
+                    Resource::Gpu(_) => user.gpu_time += time,
+                    Resource::Room { .. } => user.room_time += time,
+                    // 機器と設定で定義したリソースはリソースごとの集計にのみ含める
+                    Resource::Instrument { .. } | Resource::Custom { .. } => {}
+                    // ストレージとライセンスは容量・席数の確保であり、使用時間の集計には含めない
+                    Resource::Storage { .. } | Resource::License { .. } => continue,
+                }
+                match resources.iter_mut().find(|r| &r.resource == resource) {
+                    Some(total) => total.time += time,
+                    None => resources.push(ResourceUsageTotal {
+                        resource: resource.clone(),
+                        time,
+                    }),
+                }
+            }
+        }
+
+        users.sort_by(|a, b| {
+            (b.gpu_time, b.room_time)
+                .cmp(&(a.gpu_time, a.room_time))
+                .then_with(|| a.owner_email.as_str().cmp(b.owner_email.as_str()))
+        });
+        resources.sort_by_key(|total| std::cmp::Reverse(total.time));
+        projects.sort_by(|a, b| {
+            (b.gpu_time, b.room_time)
+                .cmp(&(a.gpu_time, a.room_time))
+                .then_with(|| a.project.cmp(&b.project))
+        });
+
+        Self {
+            period,
+            users,
+            resources,
+            projects,
+            weeks,
+            months,
+        }
+    }
+}
+
+impl PeriodUsageTotal {
+    /// 予約のうちこの期間に含まれる部分の時間を加える
+    fn add(&mut self, usage: &ResourceUsage) {
+        let start = usage.time_period().start().max(self.period.start());
+        let end = usage.time_period().end().min(self.period.end());
+        if end <= start {
+            return;
+        }
+        for resource in usage.resources() {
+            match resource {
+                Resource::Gpu(_) => self.gpu_time += end - start,
+                Resource::Room { .. } => self.room_time += end - start,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// 集計期間を週・月に区切った、予約時間が0の合計の一覧
+///
+/// # Arguments
+/// * `period` - 集計期間
+/// * `timezone` - 週・月を区切るタイムゾーン
+/// * `first` - 日付を含む週・月の初日
+/// * `next` - 週・月の初日から次の週・月の初日
+fn calendar_totals<Z: TimeZone>(
+    period: &TimePeriod,
+    timezone: &Z,
+    first: fn(NaiveDate) -> NaiveDate,
+    next: impl Fn(NaiveDate) -> Option<NaiveDate>,
+) -> Vec<PeriodUsageTotal> {
+    let mut totals = Vec::new();
+    let mut date = first(period.start().with_timezone(timezone).date_naive());
+    while let Some(following) = next(date) {
+        let (Some(start), Some(end)) = (
+            local_midnight(timezone, date),
+            local_midnight(timezone, following),
+        ) else {
+            break;
+        };
+        if start >= period.end() {
+            break;
+        }
+        if let Ok(bucket) = TimePeriod::new(start.max(period.start()), end.min(period.end())) {
+            totals.push(PeriodUsageTotal {
+                period: bucket,
+                gpu_time: Duration::zero(),
+                room_time: Duration::zero(),
+            });
+        }
+        date = following;
+    }
+    totals
+}
+
+/// 日付を含む週の月曜日
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+/// 日付を含む月の1日
+fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// タイムゾーンでの日付の0:00
+fn local_midnight<Z: TimeZone>(timezone: &Z, date: NaiveDate) -> Option<DateTime<Utc>> {
+    timezone
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+/// 時間を時間単位の小数に変換する（シリアライズ用）
+fn hours(time: Duration) -> f64 {
+    time.num_seconds() as f64 / 3600.0
+}
+
+impl Serialize for UserUsageTotal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("UserUsageTotal", 3)?;
+        state.serialize_field("owner_email", self.owner_email.as_str())?;
+        state.serialize_field("gpu_hours", &hours(self.gpu_time))?;
+        state.serialize_field("room_hours", &hours(self.room_time))?;
+        state.end()
+    }
+}
+
+impl Serialize for ResourceUsageTotal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ResourceUsageTotal", 3)?;
+        state.serialize_field("name", self.resource.name())?;
+        state.serialize_field("resource", &self.resource.to_string())?;
+        state.serialize_field("hours", &hours(self.time))?;
+        state.end()
+    }
+}
+
+impl Serialize for ProjectUsageTotal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ProjectUsageTotal", 4)?;
+        state.serialize_field("project", &self.project)?;
+        state.serialize_field("gpu_hours", &hours(self.gpu_time))?;
+        state.serialize_field("room_hours", &hours(self.room_time))?;
+        state.serialize_field("expected_utilization", &self.expected_utilization())?;
+        state.end()
+    }
+}
+
+impl Serialize for PeriodUsageTotal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("PeriodUsageTotal", 4)?;
+        state.serialize_field("start", &self.period.start().to_rfc3339())?;
+        state.serialize_field("end", &self.period.end().to_rfc3339())?;
+        state.serialize_field("gpu_hours", &hours(self.gpu_time))?;
+        state.serialize_field("room_hours", &hours(self.room_time))?;
+        state.end()
+    }
+}
+
+impl Serialize for UsageReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let servers: Vec<ServerHours> = self
+            .servers()
+            .into_iter()
+            .map(|(server, time)| ServerHours {
+                server,
+                gpu_hours: hours(time),
+            })
+            .collect();
+
+        let mut state = serializer.serialize_struct("UsageReport", 8)?;
+        state.serialize_field("start", &self.period.start().to_rfc3339())?;
+        state.serialize_field("end", &self.period.end().to_rfc3339())?;
+        state.serialize_field("users", &self.users)?;
+        state.serialize_field("servers", &servers)?;
+        state.serialize_field("resources", &self.resources)?;
+        state.serialize_field("projects", &self.projects)?;
+        state.serialize_field("weeks", &self.weeks)?;
+        state.serialize_field("months", &self.months)?;
+        state.end()
+    }
+}
+
+/// サーバーごとのGPUの予約時間（シリアライズ用）
+#[derive(serde::Serialize)]
+struct ServerHours {
+    server: String,
+    gpu_hours: f64,
+}
+
+/// 期間内の予約時間をユーザー・サーバー・デバイス・プロジェクト・週・月ごとに集計するユースケース
+///
+/// 誰がどのリソースをどれだけ、どのプロジェクトのために予約しているかを把握するために使う。
+/// 非公開の予約も、予約時間としては集計に含める。
+pub struct UsageReportUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    timezone: Option<Tz>,
+}
+
+impl<R: ResourceUsageRepository> UsageReportUseCase<R> {
+    /// 新しいUsageReportUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    pub fn new(repository: Arc<R>) -> Self {
+        Self {
+            repository,
+            timezone: None,
+        }
+    }
+
+    /// 週・月の合計を区切るタイムゾーン（IANA名）を設定
+    ///
+    /// 未設定の場合はローカル時刻で区切る。
+    pub fn with_timezone(mut self, timezone: Option<&str>) -> Self {
+        self.timezone = timezone.and_then(|tz| tz.parse::<Tz>().ok());
+        self
+    }
+
+    /// 指定期間の予約時間を集計
+    ///
+    /// # Arguments
+    /// * `period` - 集計する期間
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self, period: &TimePeriod) -> Result<UsageReport, ApplicationError> {
+        let usages = self.repository.find_overlapping(period).await?;
+        Ok(UsageReport::aggregate(
+            period.clone(),
+            &usages,
+            self.timezone,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, ReservationMetadata};
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use chrono_tz::Asia::Tokyo;
+
+    /// 2025年4月の日時（日本時間）
+    fn april(day: u32, hour: u32) -> DateTime<Utc> {
+        Tokyo
+            .with_ymd_and_hms(2025, 4, day, hour, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn gpu(server: &str, device: u32) -> Resource {
+        Resource::Gpu(Gpu::new(server.to_string(), device, "A100".to_string()))
+    }
+
+    fn room() -> Resource {
+        Resource::Room {
+            name: "会議室A".to_string(),
+        }
+    }
+
+    async fn save(
+        repo: &MockUsageRepository,
+        owner: &str,
+        period: (DateTime<Utc>, DateTime<Utc>),
+        resources: Vec<Resource>,
+        project: Option<(&str, u8)>,
+    ) {
+        let mut usage = ResourceUsage::new(
+            EmailAddress::new(owner.to_string()).unwrap(),
+            TimePeriod::new(period.0, period.1).unwrap(),
+            resources,
+            None,
+        )
+        .unwrap();
+        if let Some((project, utilization)) = project {
+            usage = usage.with_metadata(
+                ReservationMetadata::new(Some(project.to_string()), None, Some(utilization))
+                    .unwrap(),
+            );
+        }
+        repo.save(&usage).await.unwrap();
+    }
+
+    /// 2025/4/7（月）から2週間の予約を集計する
+    async fn report() -> UsageReport {
+        let repo = MockUsageRepository::new();
+        save(
+            &repo,
+            "alice@example.com",
+            (april(8, 10), april(8, 12)),
+            vec![gpu("Thalys", 0), gpu("Thalys", 1)],
+            Some(("llm", 80)),
+        )
+        .await;
+        // 集計期間の終わりをまたぐ予約は期間内の2時間だけ数える
+        save(
+            &repo,
+            "bob@example.com",
+            (april(20, 22), april(21, 2)),
+            vec![gpu("Freccia", 0)],
+            Some(("llm", 50)),
+        )
+        .await;
+        save(
+            &repo,
+            "bob@example.com",
+            (april(15, 9), april(15, 12)),
+            vec![room()],
+            None,
+        )
+        .await;
+        save(
+            &repo,
+            "carol@example.com",
+            (april(16, 9), april(16, 10)),
+            vec![
+                Resource::Instrument {
+                    name: "顕微鏡".to_string(),
+                },
+                Resource::Storage {
+                    volume: "scratch".to_string(),
+                    gigabytes: 100,
+                },
+            ],
+            None,
+        )
+        .await;
+
+        UsageReportUseCase::new(Arc::new(repo))
+            .with_timezone(Some("Asia/Tokyo"))
+            .execute(&TimePeriod::new(april(7, 0), april(21, 0)).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_totals_per_user_resource_and_project() {
+        let report = report().await;
+
+        let users: Vec<(&str, i64, i64)> = report
+            .users()
+            .iter()
+            .map(|u| {
+                (
+                    u.owner_email().as_str(),
+                    u.gpu_time().num_hours(),
+                    u.room_time().num_hours(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            users,
+            vec![
+                ("alice@example.com", 4, 0),
+                ("bob@example.com", 2, 3),
+                ("carol@example.com", 0, 0),
+            ]
+        );
+
+        let time_of = |resource: &Resource| {
+            report
+                .resources()
+                .iter()
+                .find(|total| total.resource() == resource)
+                .map(|total| total.time().num_hours())
+        };
+        assert_eq!(report.resources()[0].resource(), &room());
+        assert_eq!(time_of(&gpu("Thalys", 1)), Some(2));
+        assert_eq!(time_of(&gpu("Freccia", 0)), Some(2));
+        assert_eq!(
+            time_of(&Resource::Instrument {
+                name: "顕微鏡".to_string()
+            }),
+            Some(1)
+        );
+        // ストレージは使用時間として集計しない
+        assert_eq!(report.resources().len(), 5);
+
+        assert_eq!(
+            report.servers(),
+            vec![
+                ("Thalys".to_string(), Duration::hours(4)),
+                ("Freccia".to_string(), Duration::hours(2)),
+            ]
+        );
+
+        let [project] = report.projects() else {
+            panic!("プロジェクトは1件のはず: {:?}", report.projects());
+        };
+        assert_eq!(project.project(), "llm");
+        assert_eq!(project.gpu_time(), Duration::hours(6));
+        // 80%×4時間と50%×2時間の加重平均
+        assert_eq!(project.expected_utilization(), Some(70));
+    }
+
+    #[tokio::test]
+    async fn test_totals_per_week_and_month() {
+        let report = report().await;
+
+        let weeks: Vec<(DateTime<Utc>, i64, i64)> = report
+            .weeks()
+            .iter()
+            .map(|w| {
+                (
+                    w.period().start(),
+                    w.gpu_time().num_hours(),
+                    w.room_time().num_hours(),
+                )
+            })
+            .collect();
+        assert_eq!(weeks, vec![(april(7, 0), 4, 0), (april(14, 0), 2, 3)]);
+
+        let [month] = report.months() else {
+            panic!("月は1件のはず: {:?}", report.months());
+        };
+        // 月の合計は集計期間で切り詰める
+        assert_eq!(month.period().start(), april(7, 0));
+        assert_eq!(month.period().end(), april(21, 0));
+        assert_eq!(month.gpu_time(), Duration::hours(6));
+        assert_eq!(month.room_time(), Duration::hours(3));
+    }
+
+    #[test]
+    fn test_weeks_are_cut_in_configured_timezone() {
+        // 日本時間の月曜1時はUTCではまだ日曜だが、日本時間の週に数える
+        let usage = ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(april(14, 1), april(14, 3)).unwrap(),
+            vec![gpu("Thalys", 0)],
+            None,
+        )
+        .unwrap();
+        let period = TimePeriod::new(april(7, 0), april(21, 0)).unwrap();
+
+        let report = UsageReport::aggregate(period, &[usage], Some(Tokyo));
+
+        let weeks: Vec<(DateTime<Utc>, i64)> = report
+            .weeks()
+            .iter()
+            .map(|w| (w.period().start(), w.gpu_time().num_hours()))
+            .collect();
+        assert_eq!(weeks, vec![(april(7, 0), 0), (april(14, 0), 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_serializes_hours_as_decimals() {
+        let json = serde_json::to_value(report().await).unwrap();
+
+        assert_eq!(json["users"][0]["owner_email"], "alice@example.com");
+        assert_eq!(json["users"][0]["gpu_hours"], 4.0);
+        assert_eq!(json["servers"][1]["server"], "Freccia");
+        assert_eq!(json["servers"][1]["gpu_hours"], 2.0);
+        assert_eq!(json["projects"][0]["expected_utilization"], 70);
+        assert_eq!(json["weeks"].as_array().unwrap().len(), 2);
+        assert_eq!(json["start"], april(7, 0).to_rfc3339());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, UsageId};
+use crate::domain::ports::power_management::{PowerManagementService, PowerState};
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use chrono::{Duration, Utc};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+/// 予約開始前にサーバーの電源を入れるユースケース
+///
+/// 予約開始のリードタイム以内（または予約期間中）に入った予約について、
+/// サーバーの電源がオフであれば電源を入れる。
+/// 同じ予約に対しては一度だけ処理し、起動後にユーザーが電源を切っても再度起動しない。
+pub struct WakeReservedServersUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    power_management: Arc<dyn PowerManagementService>,
+    /// サーバー名ごとの起動リードタイム
+    wake_before: HashMap<String, Duration>,
+    /// 処理済みの（予約ID, サーバー名）
+    handled: tokio::sync::Mutex<HashSet<(UsageId, String)>>,
+}
+
+impl<R: ResourceUsageRepository> WakeReservedServersUseCase<R> {
+    /// 新しいWakeReservedServersUseCaseインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `power_management` - 電源管理サービス
+    /// * `wake_before` - サーバー名ごとの起動リードタイム
+    pub fn new(
+        repository: Arc<R>,
+        power_management: Arc<dyn PowerManagementService>,
+        wake_before: HashMap<String, Duration>,
+    ) -> Self {
+        Self {
+            repository,
+            power_management,
+            wake_before,
+            handled: tokio::sync::Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 起動が必要なサーバーの電源を入れる
+    ///
+    /// 個々のサーバーの操作に失敗しても残りのサーバーの処理は継続し、次回の実行で再試行する。
+    ///
+    /// # Returns
+    /// 電源を入れたサーバー名の一覧
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self) -> Result<Vec<String>, ApplicationError> {
+        let now = Utc::now();
+        let usages = self.repository.find_future().await?;
+        let mut handled = self.handled.lock().await;
+
+        // 削除・終了した予約の記録は破棄
+        let current_ids: HashSet<&UsageId> = usages.iter().map(|u| u.id()).collect();
+        handled.retain(|(id, _)| current_ids.contains(id));
+
+        let mut woken = Vec::new();
+        for usage in &usages {
+            let servers: BTreeSet<&str> = usage
+                .resources()
+                .iter()
+                .filter_map(|r| match r {
+                    Resource::Gpu(gpu) => Some(gpu.server()),
+                    Resource::Room { .. }
+                    | Resource::Instrument { .. }
+                    | Resource::Custom { .. }
+                    | Resource::Storage { .. }
+                    | Resource::License { .. } => None,
+                })
+                .collect();
+
+            for server in servers {
+                let Some(lead_time) = self.wake_before.get(server) else {
+                    continue;
+                };
+                let period = usage.time_period();
+                if now < period.start() - *lead_time || now >= period.end() {
+                    continue;
+                }
+                let key = (usage.id().clone(), server.to_string());
+                if handled.contains(&key) {
+                    continue;
+                }
+
+                match self.wake(server).await {
+                    Ok(true) => {
+                        woken.push(server.to_string());
+                        handled.insert(key);
+                    }
+                    Ok(false) => {
+                        handled.insert(key);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to wake server '{}': {}", server, e);
+                    }
+                }
+            }
+        }
+
+        Ok(woken)
+    }
+
+    /// 電源がオフであれば電源を入れる
+    ///
+    /// # Returns
+    /// 電源を入れた場合は `true`
+    async fn wake(&self, server: &str) -> Result<bool, ApplicationError> {
+        match self.power_management.power_state(server).await? {
+            PowerState::Off => {
+                self.power_management.power_on(server).await?;
+                Ok(true)
+            }
+            PowerState::On | PowerState::Unknown => Ok(false),
+        }
+    }
+}
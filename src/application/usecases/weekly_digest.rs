@@ -0,0 +1,490 @@
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::ports::weekly_digest::{
+    DeviceUsageTime, ServerCapacity, WeeklyDigest, WeeklyDigestSender,
+};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+/// 投稿時刻を過ぎてから投稿を試みる時間（再起動時に過去の分をまとめて投稿しないため）
+const SEND_WINDOW_MINUTES: i64 = 60;
+
+/// まとめに載せる最も予約されたデバイスの数
+const BUSIEST_DEVICE_COUNT: usize = 3;
+
+/// まとめを投稿するチャンネル
+#[derive(Debug, Clone)]
+pub struct DigestChannel {
+    /// 投稿先のSlackのチャンネルID
+    pub channel_id: String,
+    /// まとめに含めるサーバーとそのデバイス数
+    pub servers: Vec<(String, u32)>,
+}
+
+/// 週に一度、利用状況のまとめをチャンネルに投稿するユースケース
+///
+/// 先週予約されたGPU時間の合計と最も予約されたデバイス、今週開始する大きな予約、今週の空きをまとめる。
+/// チャンネルごとに、そのチャンネルに通知するサーバーだけを集計する。
+/// 投稿に失敗したチャンネルは、投稿時刻から1時間以内であれば次回の実行で再試行する。
+pub struct WeeklyDigestUseCase<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    sender: Arc<dyn WeeklyDigestSender>,
+    channels: Vec<DigestChannel>,
+    weekday: Weekday,
+    hour: u32,
+    timezone: Option<Tz>,
+    big_booking_gpu_time: Duration,
+    /// チャンネルIDごとの最後に投稿した投稿時刻
+    sent: tokio::sync::Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl<R: ResourceUsageRepository> WeeklyDigestUseCase<R> {
+    /// 大きな予約とみなすGPU時間のデフォルト
+    pub const DEFAULT_BIG_BOOKING_GPU_HOURS: u32 = 24;
+
+    /// 新しいWeeklyDigestUseCaseインスタンスを作成
+    ///
+    /// 投稿時刻は月曜日の9時（ローカルタイムゾーン）。
+    ///
+    /// # Arguments
+    /// * `repository` - ResourceUsageリポジトリ
+    /// * `sender` - まとめの送信先
+    /// * `channels` - まとめを投稿するチャンネル
+    pub fn new(
+        repository: Arc<R>,
+        sender: Arc<dyn WeeklyDigestSender>,
+        channels: Vec<DigestChannel>,
+    ) -> Self {
+        Self {
+            repository,
+            sender,
+            channels,
+            weekday: Weekday::Mon,
+            hour: 9,
+            timezone: None,
+            big_booking_gpu_time: Duration::hours(i64::from(Self::DEFAULT_BIG_BOOKING_GPU_HOURS)),
+            sent: tokio::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 投稿する曜日と時刻を設定
+    pub fn with_schedule(mut self, weekday: Weekday, hour: u32) -> Self {
+        self.weekday = weekday;
+        self.hour = hour;
+        self
+    }
+
+    /// 投稿時刻と週の区切りに使うタイムゾーン（IANA形式）を設定
+    ///
+    /// 指定しない場合、または不正な場合はシステムのローカルタイムゾーンを使用する。
+    pub fn with_timezone(mut self, timezone: Option<&str>) -> Self {
+        self.timezone = timezone.and_then(|tz| tz.parse::<Tz>().ok());
+        self
+    }
+
+    /// 大きな予約とみなすGPU時間（GPU 1台につき1時間で1時間）を設定
+    pub fn with_big_booking_gpu_time(mut self, big_booking_gpu_time: Duration) -> Self {
+        self.big_booking_gpu_time = big_booking_gpu_time;
+        self
+    }
+
+    /// 投稿時刻を過ぎていれば、まだ投稿していないチャンネルにまとめを投稿する
+    ///
+    /// 個々のチャンネルへの投稿に失敗しても残りのチャンネルへの投稿は継続する。
+    ///
+    /// # Arguments
+    /// * `now` - 現在時刻
+    ///
+    /// # Returns
+    /// まとめを投稿したチャンネルIDの一覧
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn execute(&self, now: DateTime<Utc>) -> Result<Vec<String>, ApplicationError> {
+        let Some((due, this_week_start)) = self.latest_schedule(now) else {
+            return Ok(Vec::new());
+        };
+        if now - due > Duration::minutes(SEND_WINDOW_MINUTES) {
+            return Ok(Vec::new());
+        }
+
+        let mut sent = self.sent.lock().await;
+        let pending: Vec<&DigestChannel> = self
+            .channels
+            .iter()
+            .filter(|channel| sent.get(&channel.channel_id) != Some(&due))
+            .collect();
+        if pending.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let last_week = TimePeriod::new(this_week_start - Duration::days(7), this_week_start)?;
+        let this_week = TimePeriod::new(this_week_start, this_week_start + Duration::days(7))?;
+        let usages = self
+            .repository
+            .find_overlapping(&TimePeriod::new(last_week.start(), this_week.end())?)
+            .await?;
+
+        let mut posted = Vec::new();
+        for channel in pending {
+            let digest = self.digest(channel, &usages, &last_week, &this_week);
+            match self.sender.send_digest(&channel.channel_id, &digest).await {
+                Ok(()) => {
+                    sent.insert(channel.channel_id.clone(), due);
+                    posted.push(channel.channel_id.clone());
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to post weekly digest to '{}': {}",
+                        channel.channel_id,
+                        e
+                    );
+                }
+            }
+        }
+
+        Ok(posted)
+    }
+
+    /// 現在時刻以前で最も新しい投稿時刻と、その日の0:00（今週の始まり）
+    fn latest_schedule(&self, now: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let today = match self.timezone {
+            Some(tz) => now.with_timezone(&tz).date_naive(),
+            None => now.with_timezone(&Local).date_naive(),
+        };
+        let days_since =
+            (7 + today.weekday().num_days_from_monday() - self.weekday.num_days_from_monday()) % 7;
+        let mut date = today - Duration::days(i64::from(days_since));
+        let mut due = self.local_time(date, self.hour)?;
+        if due > now {
+            date -= Duration::days(7);
+            due = self.local_time(date, self.hour)?;
+        }
+        Some((due, self.local_time(date, 0)?))
+    }
+
+    /// 設定したタイムゾーンでの日付と時刻
+    fn local_time(&self, date: NaiveDate, hour: u32) -> Option<DateTime<Utc>> {
+        let naive = date.and_hms_opt(hour, 0, 0)?;
+        match self.timezone {
+            Some(tz) => tz
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|time| time.with_timezone(&Utc)),
+            None => Local
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|time| time.with_timezone(&Utc)),
+        }
+    }
+
+    /// チャンネルのサーバーの予約からまとめを作成
+    fn digest(
+        &self,
+        channel: &DigestChannel,
+        usages: &[ResourceUsage],
+        last_week: &TimePeriod,
+        this_week: &TimePeriod,
+    ) -> WeeklyDigest {
+        let in_channel = |server: &str| channel.servers.iter().any(|(name, _)| name == server);
+
+        let mut device_times: BTreeMap<(String, u32), Duration> = BTreeMap::new();
+        let mut reserved_this_week: HashMap<&str, Duration> = HashMap::new();
+        let mut big_bookings = Vec::new();
+        for usage in usages {
+            let gpus: Vec<_> = usage
+                .resources()
+                .iter()
+                .filter_map(|resource| match resource {
+                    Resource::Gpu(gpu) if in_channel(gpu.server()) => Some(gpu),
+                    _ => None,
+                })
+                .collect();
+            if gpus.is_empty() {
+                continue;
+            }
+
+            let last_week_time = overlap(usage.time_period(), last_week);
+            let this_week_time = overlap(usage.time_period(), this_week);
+            for gpu in &gpus {
+                if last_week_time > Duration::zero() {
+                    *device_times
+                        .entry((gpu.server().to_string(), gpu.device_number()))
+                        .or_insert_with(Duration::zero) += last_week_time;
+                }
+                *reserved_this_week
+                    .entry(gpu.server())
+                    .or_insert_with(Duration::zero) += this_week_time;
+            }
+
+            let period = usage.time_period();
+            let gpu_time = (period.end() - period.start()) * gpus.len() as i32;
+            if period.start() >= this_week.start()
+                && period.start() < this_week.end()
+                && gpu_time >= self.big_booking_gpu_time
+            {
+                big_bookings.push(usage.clone());
+            }
+        }
+        big_bookings.sort_by_key(|usage| usage.time_period().start());
+
+        let reserved_gpu_time = device_times
+            .values()
+            .fold(Duration::zero(), |total, time| total + *time);
+        let mut busiest_devices: Vec<DeviceUsageTime> = device_times
+            .into_iter()
+            .map(|((server, device_number), time)| DeviceUsageTime {
+                server,
+                device_number,
+                time,
+            })
+            .collect();
+        busiest_devices.sort_by_key(|device| std::cmp::Reverse(device.time));
+        busiest_devices.truncate(BUSIEST_DEVICE_COUNT);
+
+        let week_length = this_week.end() - this_week.start();
+        let capacities = channel
+            .servers
+            .iter()
+            .map(|(server, devices)| ServerCapacity {
+                server: server.clone(),
+                total: week_length * *devices as i32,
+                reserved: reserved_this_week
+                    .get(server.as_str())
+                    .copied()
+                    .unwrap_or_else(Duration::zero),
+            })
+            .collect();
+
+        WeeklyDigest {
+            last_week: last_week.clone(),
+            this_week: this_week.clone(),
+            reserved_gpu_time,
+            busiest_devices,
+            big_bookings,
+            capacities,
+        }
+    }
+}
+
+/// 2つの期間が重なる時間（重ならない場合は0）
+fn overlap(period: &TimePeriod, other: &TimePeriod) -> Duration {
+    let start = period.start().max(other.start());
+    let end = period.end().min(other.end());
+    (end - start).max(Duration::zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::common::EmailAddress;
+    use crate::domain::ports::NotificationError;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use async_trait::async_trait;
+    use chrono_tz::Asia::Tokyo;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    /// 投稿したまとめを記録する（`failing` のチャンネルへの投稿は失敗する）
+    #[derive(Default)]
+    struct RecordingDigestSender {
+        sent: Mutex<Vec<(String, WeeklyDigest)>>,
+        failing: Mutex<HashSet<String>>,
+    }
+
+    #[async_trait]
+    impl WeeklyDigestSender for RecordingDigestSender {
+        async fn send_digest(
+            &self,
+            channel_id: &str,
+            digest: &WeeklyDigest,
+        ) -> Result<(), NotificationError> {
+            if self.failing.lock().unwrap().contains(channel_id) {
+                return Err(NotificationError::SendFailure(
+                    "投稿の失敗（テスト用）".to_string(),
+                ));
+            }
+            self.sent
+                .lock()
+                .unwrap()
+                .push((channel_id.to_string(), digest.clone()));
+            Ok(())
+        }
+    }
+
+    /// 2025年4月の日時（東京）
+    fn april(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Tokyo
+            .with_ymd_and_hms(2025, 4, day, hour, minute, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn gpu(server: &str, device: u32) -> Resource {
+        Resource::Gpu(Gpu::new(server.to_string(), device, "A100".to_string()))
+    }
+
+    async fn save(
+        repo: &MockUsageRepository,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        resources: Vec<Resource>,
+    ) {
+        let usage = ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, end).unwrap(),
+            resources,
+            None,
+        )
+        .unwrap();
+        repo.save(&usage).await.unwrap();
+    }
+
+    /// 2025/4/7（月）の週の前後の予約と、ThalysとFrecciaのチャンネルに投稿するユースケース
+    async fn weekly_digest(
+        sender: &Arc<RecordingDigestSender>,
+    ) -> WeeklyDigestUseCase<MockUsageRepository> {
+        let repo = MockUsageRepository::new();
+        save(
+            &repo,
+            april(1, 10, 0),
+            april(1, 14, 0),
+            vec![gpu("Thalys", 0)],
+        )
+        .await;
+        save(
+            &repo,
+            april(2, 10, 0),
+            april(2, 12, 0),
+            vec![gpu("Thalys", 1)],
+        )
+        .await;
+        // 週の区切りをまたぐ予約は先週と今週に分けて数える
+        save(
+            &repo,
+            april(6, 22, 0),
+            april(7, 4, 0),
+            vec![gpu("Thalys", 0)],
+        )
+        .await;
+        save(
+            &repo,
+            april(8, 0, 0),
+            april(9, 0, 0),
+            vec![gpu("Thalys", 0), gpu("Thalys", 1)],
+        )
+        .await;
+        save(
+            &repo,
+            april(3, 9, 0),
+            april(3, 12, 0),
+            vec![gpu("Freccia", 0)],
+        )
+        .await;
+
+        WeeklyDigestUseCase::new(
+            Arc::new(repo),
+            sender.clone(),
+            vec![
+                DigestChannel {
+                    channel_id: "C_THALYS".to_string(),
+                    servers: vec![("Thalys".to_string(), 2)],
+                },
+                DigestChannel {
+                    channel_id: "C_FRECCIA".to_string(),
+                    servers: vec![("Freccia".to_string(), 1)],
+                },
+            ],
+        )
+        .with_timezone(Some("Asia/Tokyo"))
+    }
+
+    #[tokio::test]
+    async fn test_posts_once_within_window_after_schedule() {
+        let sender = Arc::new(RecordingDigestSender::default());
+        let use_case = weekly_digest(&sender).await;
+
+        assert!(use_case.execute(april(7, 8, 59)).await.unwrap().is_empty());
+        assert_eq!(
+            use_case.execute(april(7, 9, 30)).await.unwrap(),
+            vec!["C_THALYS", "C_FRECCIA"]
+        );
+        assert!(use_case.execute(april(7, 9, 40)).await.unwrap().is_empty());
+
+        // 投稿時刻から1時間を過ぎた後の起動では投稿しない
+        let late = weekly_digest(&sender).await;
+        assert!(late.execute(april(7, 10, 30)).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_digest_counts_only_channel_servers() {
+        let sender = Arc::new(RecordingDigestSender::default());
+        weekly_digest(&sender)
+            .await
+            .execute(april(7, 9, 0))
+            .await
+            .unwrap();
+
+        let sent = sender.sent.lock().unwrap();
+        let (_, thalys) = &sent[0];
+        assert_eq!(thalys.last_week.start(), april(7, 0, 0) - Duration::days(7));
+        assert_eq!(thalys.this_week.start(), april(7, 0, 0));
+        assert_eq!(thalys.reserved_gpu_time, Duration::hours(8));
+        assert_eq!(
+            thalys.busiest_devices,
+            vec![
+                DeviceUsageTime {
+                    server: "Thalys".to_string(),
+                    device_number: 0,
+                    time: Duration::hours(6),
+                },
+                DeviceUsageTime {
+                    server: "Thalys".to_string(),
+                    device_number: 1,
+                    time: Duration::hours(2),
+                },
+            ]
+        );
+        assert_eq!(thalys.big_bookings.len(), 1);
+        assert_eq!(thalys.big_bookings[0].time_period().start(), april(8, 0, 0));
+        assert_eq!(
+            thalys.capacities,
+            vec![ServerCapacity {
+                server: "Thalys".to_string(),
+                total: Duration::hours(7 * 24 * 2),
+                reserved: Duration::hours(52),
+            }]
+        );
+
+        let (_, freccia) = &sent[1];
+        assert_eq!(freccia.reserved_gpu_time, Duration::hours(3));
+        assert!(freccia.big_bookings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retries_channel_that_failed() {
+        let sender = Arc::new(RecordingDigestSender::default());
+        let use_case = weekly_digest(&sender).await;
+        sender
+            .failing
+            .lock()
+            .unwrap()
+            .insert("C_FRECCIA".to_string());
+
+        assert_eq!(
+            use_case.execute(april(7, 9, 0)).await.unwrap(),
+            vec!["C_THALYS"]
+        );
+
+        sender.failing.lock().unwrap().clear();
+        assert_eq!(
+            use_case.execute(april(7, 9, 10)).await.unwrap(),
+            vec!["C_FRECCIA"]
+        );
+    }
+}
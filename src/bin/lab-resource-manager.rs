@@ -3,28 +3,397 @@
 //! このバイナリは、ユーザーがGmailアカウントを登録し、
 //! 共有リソースカレンダーへのアクセス権を取得できるSlack Botを実行します。
 
+use chrono::{DateTime, Days, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use clap::{Parser, Subcommand};
+use google_calendar3::yup_oauth2;
+#[cfg(feature = "chaos")]
+use lab_resource_manager::infrastructure::chaos::{
+    FaultInjectingNotifier, FaultInjectingRepository, FaultInjectionConfig,
+};
+#[cfg(feature = "metrics")]
+use lab_resource_manager::infrastructure::metrics::{self, MetricsConfig};
 use lab_resource_manager::{
-    application::usecases::{
-        create_resource_usage::CreateResourceUsageUseCase,
-        delete_resource_usage::DeleteResourceUsageUseCase,
-        grant_user_resource_access::GrantUserResourceAccessUseCase,
-        notify_future_resource_usage_changes::NotifyFutureResourceUsageChangesUseCase,
-        update_resource_usage::UpdateResourceUsageUseCase,
-    },
-    infrastructure::{
-        config::{load_config, load_from_env},
-        notifier::NotificationRouter,
-        repositories::{
-            identity_link::JsonFileIdentityLinkRepository,
-            resource_usage::google_calendar::GoogleCalendarUsageRepository,
-        },
-        resource_collection_access::GoogleCalendarAccessService,
-    },
-    interface::slack::SlackApp,
+    LabResourceManagerBuilder,
+    application::usecases::{ExportReservationsUseCase, ReconcileMappingsUseCase},
+    domain::aggregates::resource_usage::entity::ResourceUsage,
+    domain::aggregates::resource_usage::value_objects::{
+        Priority, ReservationMetadata, TimePeriod, UsageId, Visibility,
+    },
+    domain::common::EmailAddress,
+    domain::ports::ExportFormat,
+    domain::ports::repositories::UsageQuery,
+    infrastructure::backup,
+    infrastructure::config::{ResourceConfig, ResourceStyle, defaults, load_config},
+    infrastructure::export::FileReservationExporter,
+    infrastructure::google_auth,
+    infrastructure::import::CsvReservationSource,
+    infrastructure::logging::{self, LogFormat},
+    infrastructure::notifier::formatter::format_resources_styled,
+    infrastructure::repositories::identity_link::SqliteIdentityLinkRepository,
+    interface::http_api,
 };
 use slack_morphism::prelude::*;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// JSONファイルのID紐付けをSQLiteデータベースへ取り込む
+    ImportIdentityLinks {
+        /// 取り込むJSONファイル（IDENTITY_LINKS_FILEの形式）
+        json: PathBuf,
+        /// 取り込み先のSQLiteデータベース
+        database: PathBuf,
+    },
+    /// 期間内の予約をCSV・JSON・iCalendarで書き出す
+    ExportReservations {
+        /// 期間の初日（YYYY-MM-DD、リソース設定のタイムゾーン）
+        #[arg(long)]
+        from: NaiveDate,
+        /// 期間の最終日（YYYY-MM-DD、この日を含む）
+        #[arg(long)]
+        to: NaiveDate,
+        /// 書き出し形式（csv, json, ics）
+        #[arg(long, default_value = "csv")]
+        format: ExportFormat,
+        /// 書き出し先のファイル（省略時は reservations.<拡張子>）
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// CSVの予約をまとめて取り込み、行ごとの結果を表示する
+    ImportReservations {
+        /// 取り込むCSVファイル（owner_email, resource, start, end, notes の列）
+        csv: PathBuf,
+    },
+    /// 予約IDとカレンダーのイベントの対応を照合し、食い違いを修復する
+    ReconcileMappings {
+        /// 対応のないイベントを探す期間の初日（省略時は90日前）
+        #[arg(long)]
+        from: Option<NaiveDate>,
+        /// 対応のないイベントを探す期間の最終日（省略時は365日後）
+        #[arg(long)]
+        to: Option<NaiveDate>,
+        /// 食い違いを表示するだけで修復しない
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// ID紐付け、IDマッピングなどのデータファイルを日時付きのアーカイブにバックアップする
+    Backup {
+        /// アーカイブの保存先
+        #[arg(long, default_value = defaults::BACKUP_DIR)]
+        dir: PathBuf,
+        /// 残すバックアップの数（指定した場合、古いものから削除する）
+        #[arg(long)]
+        keep: Option<usize>,
+    },
+    /// バックアップのアーカイブからデータファイルを復元する（Botを停止してから実行する）
+    Restore {
+        /// 復元するアーカイブ
+        archive: PathBuf,
+    },
+    /// Google Calendar APIをOAuthで認証し、トークンを保存する（GOOGLE_AUTH=oauth の場合）
+    GoogleAuth {
+        /// 同意後のリダイレクトを受け取るポート（http://localhost:<port>）
+        #[arg(long, default_value_t = 8085)]
+        port: u16,
+    },
+    /// HTTP API・MCPサーバーのトークンを発行し、API_TOKENS_FILE に追記する（トークンは一度だけ表示する）
+    ApiToken {
+        /// トークンで操作する利用者のメールアドレス
+        email: String,
+    },
+    /// 標準入出力でMCPサーバーを実行し、AIアシスタントに予約のツールを公開する
+    ///
+    /// 操作する利用者は環境変数 LRM_API_TOKEN のトークン（api-token で発行）で決まる。
+    Mcp,
+    /// 端末にデバイスごとの予約のタイムライン・通知ログ・ポーリングの状態を表示する（q + Enter で終了）
+    Tui {
+        /// タイムラインに表示する時間数
+        #[arg(long, default_value_t = 24)]
+        hours: u32,
+    },
+    /// 今後の予約を一覧表示する
+    List {
+        /// このサーバーの予約のみを表示する
+        #[arg(long)]
+        server: Option<String>,
+        /// このリソース（部屋・機器など）の予約のみを表示する
+        #[arg(long, conflicts_with = "server")]
+        resource: Option<String>,
+        /// このタグの付いたデバイスの予約のみを表示する
+        #[arg(long)]
+        tag: Option<String>,
+        /// この予約者の予約のみを表示する
+        #[arg(long)]
+        owner: Option<String>,
+    },
+    /// 予約を作成する（Slackの /reserve と同じく、競合・上限・受付期間・予約停止を確認する）
+    Reserve {
+        /// 予約者のメールアドレス
+        #[arg(long)]
+        owner: String,
+        /// 予約するサーバー（サーバーが1台だけの場合は省略できる）
+        #[arg(long)]
+        server: Option<String>,
+        /// 予約するGPUの番号（例: 0-1、省略時はサーバーのすべてのGPU）
+        #[arg(long)]
+        gpu: Option<String>,
+        /// 予約するリソースの指定（例: "Thalys:0-1; 会議室A"、CSVの取り込みと同じ形式）
+        #[arg(long, conflicts_with_all = ["server", "gpu"])]
+        resources: Option<String>,
+        /// 開始（YYYY-MM-DD HH:MM はリソース設定のタイムゾーン、またはRFC 3339）
+        #[arg(long)]
+        from: String,
+        /// 終了（--from と同じ形式）
+        #[arg(long)]
+        to: String,
+        /// 備考
+        #[arg(long)]
+        notes: Option<String>,
+        /// 他の利用者に詳細を見せない
+        #[arg(long)]
+        private: bool,
+    },
+    /// 予約を取り消す
+    Cancel {
+        /// 予約ID
+        id: String,
+        /// 取り消す利用者のメールアドレス（省略時は予約者本人として取り消す）
+        #[arg(long = "as")]
+        actor: Option<String>,
+    },
+    /// 期間内のリソースごとの使用中・空きの期間を表示する
+    Availability {
+        /// 期間の開始（--from と同じ形式、省略時は現在時刻）
+        #[arg(long)]
+        from: Option<String>,
+        /// 期間の終了（省略時は開始の24時間後）
+        #[arg(long)]
+        to: Option<String>,
+        /// リソース名で絞り込む
+        #[arg(long)]
+        resource: Option<String>,
+        /// タグで絞り込む
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// リソース設定を検証する
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// リソース設定ファイルを読み込み、設定の食い違いを表示する（外部サービスには接続しない）
+    Validate {
+        /// リソース設定ファイル（省略時は RESOURCE_CONFIG またはデフォルトのパス）
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// 設定の検証に加えて、Slackのトークンとカレンダーへのアクセスを確認する
+    Doctor,
+}
+
+/// 初日の0時から最終日の翌日0時までの期間（タイムゾーン未指定の場合はUTC）
+fn export_period(
+    from: NaiveDate,
+    to: NaiveDate,
+    timezone: Option<Tz>,
+) -> Result<TimePeriod, Box<dyn std::error::Error>> {
+    let end_date = to
+        .checked_add_days(Days::new(1))
+        .ok_or("期間の最終日が不正です")?;
+    let (start, end) = match timezone {
+        Some(tz) => (
+            tz.from_local_datetime(&from.and_time(Default::default()))
+                .earliest()
+                .ok_or("期間の初日が不正です")?
+                .with_timezone(&Utc),
+            tz.from_local_datetime(&end_date.and_time(Default::default()))
+                .earliest()
+                .ok_or("期間の最終日が不正です")?
+                .with_timezone(&Utc),
+        ),
+        None => (
+            from.and_time(Default::default()).and_utc(),
+            end_date.and_time(Default::default()).and_utc(),
+        ),
+    };
+    Ok(TimePeriod::new(start, end)?)
+}
+
+/// コマンドラインで指定した時刻を解釈する
+///
+/// RFC 3339に加えて、タイムゾーンを省略した `YYYY-MM-DD HH:MM`（または `T` 区切り）を受け付け、
+/// リソース設定のタイムゾーン（未指定の場合はシステムのローカルタイムゾーン）の時刻として扱う。
+fn parse_cli_time(value: &str, timezone: Option<Tz>) -> Result<DateTime<Utc>, String> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    let invalid = || {
+        format!(
+            "時刻は YYYY-MM-DD HH:MM またはRFC 3339形式で指定してください: {}",
+            value
+        )
+    };
+    let naive = ["%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .ok_or_else(invalid)?;
+    match timezone {
+        Some(tz) => tz
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|datetime| datetime.with_timezone(&Utc)),
+        None => Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|datetime| datetime.with_timezone(&Utc)),
+    }
+    .ok_or_else(invalid)
+}
+
+/// 時刻をリソース設定のタイムゾーン（未指定の場合はシステムのローカルタイムゾーン）で表示する
+fn format_cli_time(datetime: DateTime<Utc>, timezone: Option<Tz>) -> String {
+    const FORMAT: &str = "%Y-%m-%d %H:%M";
+    match timezone {
+        Some(tz) => datetime.with_timezone(&tz).format(FORMAT).to_string(),
+        None => datetime.with_timezone(&Local).format(FORMAT).to_string(),
+    }
+}
+
+/// `reserve` のオプションからリソースの指定（`;` 区切り）を作る
+fn reserve_spec(
+    config: &ResourceConfig,
+    server: Option<String>,
+    gpu: Option<String>,
+    resources: Option<String>,
+) -> Result<String, String> {
+    if let Some(resources) = resources {
+        return Ok(resources);
+    }
+    let server = match (server, config.servers.as_slice()) {
+        (Some(server), _) => server,
+        (None, [only]) => only.name.clone(),
+        (None, _) => {
+            return Err(
+                "--server でサーバーを指定するか、--resources でリソースを指定してください"
+                    .to_string(),
+            );
+        }
+    };
+    Ok(match gpu {
+        Some(gpu) => format!("{}:{}", server, gpu),
+        None => server,
+    })
+}
+
+/// `list` のオプションから予約の検索条件を作る
+fn list_query(
+    config: &ResourceConfig,
+    resource: Option<String>,
+    tag: Option<String>,
+    owner: Option<EmailAddress>,
+) -> UsageQuery {
+    let mut query = UsageQuery::new();
+    if let Some(resource) = resource {
+        query = query.with_resource(resource);
+    }
+    if let Some(tag) = tag {
+        query = query.with_tag(&tag, &config.resource_tags());
+    }
+    if let Some(owner) = owner {
+        query = query.with_owner(owner);
+    }
+    query
+}
+
+/// 予約を1行で表示する
+fn print_usage(usage: &ResourceUsage, timezone: Option<Tz>) {
+    println!("{}", usage_line(usage, timezone));
+}
+
+/// 予約を表示する1行（ID・期間・予約者・リソース・備考）
+fn usage_line(usage: &ResourceUsage, timezone: Option<Tz>) -> String {
+    let period = usage.time_period();
+    let mut line = format!(
+        "{}  {} - {}  {}  {}",
+        usage.id().as_str(),
+        format_cli_time(period.start(), timezone),
+        format_cli_time(period.end(), timezone),
+        usage.owner_email().as_str(),
+        format_resources_styled(usage.resources(), ResourceStyle::Compact)
+    );
+    if let Some(notes) = usage.notes() {
+        line.push_str(&format!("  {}", notes));
+    }
+    line
+}
+
+/// 設定の問題を表示し、問題の件数を返す
+fn report_config_issues(path: &std::path::Path, config: &ResourceConfig) -> usize {
+    let issues = config.validate();
+    if issues.is_empty() {
+        println!("✅ リソース設定に問題はありません: {}", path.display());
+    }
+    for issue in &issues {
+        println!("❌ {}", issue);
+    }
+    issues.len()
+}
+
+/// Botトークンとアプリトークンが有効か確認し、問題の件数を返す
+async fn check_slack_tokens(bot_token: &str, app_token: &str) -> usize {
+    let client = SlackClient::new(
+        SlackClientHyperConnector::new().expect("Failed to initialize Slack HTTP connector"),
+    );
+    let mut problems = 0;
+
+    let bot_token = SlackApiToken::new(bot_token.to_string().into());
+    match client.open_session(&bot_token).auth_test().await {
+        Ok(response) => println!(
+            "✅ SLACK_BOT_TOKEN: ワークスペース {} のBotとして認証できました",
+            response.team
+        ),
+        Err(e) => {
+            problems += 1;
+            println!(
+                "❌ SLACK_BOT_TOKEN: 認証に失敗しました（xoxb- から始まるBot User OAuth Tokenか確認してください）: {}",
+                e
+            );
+        }
+    }
+
+    let app_token = SlackApiToken::new(app_token.to_string().into());
+    match client
+        .open_session(&app_token)
+        .apps_connections_open(&SlackApiAppsConnectionOpenRequest::new())
+        .await
+    {
+        Ok(_) => println!("✅ SLACK_APP_TOKEN: Socket Modeで接続できます"),
+        Err(e) => {
+            problems += 1;
+            println!(
+                "❌ SLACK_APP_TOKEN: Socket Modeの接続に失敗しました（connections:write スコープを持つ xapp- から始まるApp-Level Tokenか、Socket Modeが有効か確認してください）: {}",
+                e
+            );
+        }
+    }
+
+    problems
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // rustls暗号化プロバイダの初期化
@@ -32,86 +401,657 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .install_default()
         .ok();
 
-    // ===========================================
-    // 設定の読み込み
-    // ===========================================
-    let app_config = load_from_env()?;
-    let resource_config = Arc::new(load_config(&app_config.resource_config_path)?);
+    let cli = Cli::parse();
 
-    let service_account_key = app_config
-        .google_service_account_key_path
-        .to_str()
-        .ok_or("サービスアカウントキーパスが不正なUTF-8です")?;
+    // ログ出力の初期化（RUST_LOG, LOG_FORMAT）
+    // MCPサーバーとダッシュボードは標準出力を使うため、ログを標準エラー出力に書く
+    match cli.command {
+        Some(Command::Mcp | Command::Tui { .. }) => logging::init_stderr(LogFormat::from_env()?),
+        _ => logging::init(LogFormat::from_env()?),
+    }
 
-    // ===========================================
-    // 依存の組み立て（コンポジションルート）
-    // ===========================================
+    match cli.command {
+        Some(Command::ImportIdentityLinks { json, database }) => {
+            let repository = SqliteIdentityLinkRepository::open(database.clone()).await?;
+            let imported = repository.import_json(&json).await?;
+            println!(
+                "✅ {}件のID紐付けを取り込みました: {} → {}",
+                imported,
+                json.display(),
+                database.display()
+            );
+            return Ok(());
+        }
+        Some(Command::ExportReservations {
+            from,
+            to,
+            format,
+            output,
+        }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let period = export_period(from, to, builder.resource_config().tz())?;
+            let output = output
+                .unwrap_or_else(|| PathBuf::from(format!("reservations.{}", format.extension())));
+            let usecase = ExportReservationsUseCase::new(
+                Arc::new(builder.google_calendar_repository().await?),
+                Arc::new(FileReservationExporter::new(output.clone())),
+            );
+            let exported = usecase.execute(&period, format).await?;
+            println!(
+                "✅ {}件の予約を書き出しました: {}",
+                exported,
+                output.display()
+            );
+            return Ok(());
+        }
+        Some(Command::ImportReservations { csv }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let source = CsvReservationSource::new(csv.clone(), builder.resource_config().clone());
+            let usecase = builder
+                .import_reservations_usecase(
+                    builder.google_calendar_repository().await?,
+                    Arc::new(source),
+                )
+                .await?;
+            let results = usecase.execute().await?;
+            let mut failed = 0;
+            for row in &results {
+                match &row.result {
+                    Ok(id) => println!("✅ {}行目: 作成しました ({})", row.line, id.as_str()),
+                    Err(reason) => {
+                        failed += 1;
+                        println!("❌ {}行目: {}", row.line, reason);
+                    }
+                }
+            }
+            println!(
+                "{}件中{}件の予約を取り込みました: {}",
+                results.len(),
+                results.len() - failed,
+                csv.display()
+            );
+            if failed > 0 {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::ReconcileMappings { from, to, dry_run }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let today = Local::now().date_naive();
+            let from = from
+                .or_else(|| today.checked_sub_days(Days::new(90)))
+                .ok_or("期間の初日が不正です")?;
+            let to = to
+                .or_else(|| today.checked_add_days(Days::new(365)))
+                .ok_or("期間の最終日が不正です")?;
+            let period = export_period(from, to, builder.resource_config().tz())?;
+            let usecase = ReconcileMappingsUseCase::new(Arc::new(
+                builder.google_calendar_repository().await?,
+            ));
 
-    // リポジトリ
-    let identity_repo = Arc::new(JsonFileIdentityLinkRepository::new(
-        app_config.identity_links_file.clone(),
-    ));
+            if dry_run {
+                let discrepancies = usecase.find(&period).await?;
+                for d in &discrepancies {
+                    println!(
+                        "🔍 {}: {} ({} / {})",
+                        d.kind,
+                        d.usage_id.as_str(),
+                        d.calendar_id,
+                        d.event_id
+                    );
+                }
+                println!("{}件の食い違いが見つかりました", discrepancies.len());
+                return Ok(());
+            }
 
-    let calendar_access_service =
-        Arc::new(GoogleCalendarAccessService::new(service_account_key).await?);
-
-    let resource_usage_repo = Arc::new(
-        GoogleCalendarUsageRepository::new(
-            service_account_key,
-            resource_config.as_ref().clone(),
-            app_config.calendar_mappings_file.clone(),
-        )
-        .await?,
-    );
+            let results = usecase.execute(&period).await?;
+            let mut failed = 0;
+            for r in &results {
+                let d = &r.discrepancy;
+                match &r.result {
+                    Ok(()) => println!(
+                        "✅ {}を修復しました: {} ({} / {})",
+                        d.kind,
+                        d.usage_id.as_str(),
+                        d.calendar_id,
+                        d.event_id
+                    ),
+                    Err(reason) => {
+                        failed += 1;
+                        println!("❌ {}: {} ({})", d.kind, d.usage_id.as_str(), reason);
+                    }
+                }
+            }
+            println!(
+                "{}件中{}件の食い違いを修復しました",
+                results.len(),
+                results.len() - failed
+            );
+            if failed > 0 {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Backup { dir, keep }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let files = backup::data_files(builder.app_config());
+            let archive = backup::create_backup(&files, &dir, Utc::now()).await?;
+            println!("✅ バックアップを作成しました: {}", archive.display());
+            if let Some(keep) = keep {
+                for removed in backup::rotate(&dir, keep)? {
+                    println!("🗑️  古いバックアップを削除しました: {}", removed.display());
+                }
+            }
+            return Ok(());
+        }
+        Some(Command::Restore { archive }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let files = backup::data_files(builder.app_config());
+            for file in backup::restore_backup(&archive, &files).await? {
+                println!("✅ {} を復元しました: {}", file.key, file.path.display());
+            }
+            return Ok(());
+        }
+        Some(Command::GoogleAuth { port }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let oauth = builder
+                .app_config()
+                .google_oauth
+                .as_ref()
+                .ok_or("GOOGLE_AUTH=oauth が設定されていません")?;
+            let secret = yup_oauth2::read_application_secret(&oauth.client_secret_file).await?;
+            google_auth::authorize(secret, &oauth.token_cache_file, port).await?;
+            println!(
+                "✅ トークンを保存しました: {}",
+                oauth.token_cache_file.display()
+            );
+            return Ok(());
+        }
+        Some(Command::ApiToken { email }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let tokens_file = &builder.app_config().api_tokens_file;
+            let email = EmailAddress::new(email)?;
+            let token = http_api::auth::issue_token(tokens_file, &email)?;
+            println!(
+                "✅ {} のトークンを発行しました（{} に追記しました）",
+                email.as_str(),
+                tokens_file.display()
+            );
+            println!("{}", token);
+            println!("⚠️  トークンは再表示できません。安全な場所に保存してください");
+            return Ok(());
+        }
+        Some(Command::Mcp) => {
+            let token = std::env::var("LRM_API_TOKEN")
+                .map_err(|_| "LRM_API_TOKEN にAPIトークンを設定してください")?;
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let tokens =
+                http_api::auth::ApiTokens::load(&builder.app_config().api_tokens_file).await?;
+            let actor = tokens
+                .owner_of(token.trim())
+                .cloned()
+                .ok_or("LRM_API_TOKEN のトークンが無効です")?;
+            let server = builder
+                .mcp_server(builder.google_calendar_repository().await?, actor.clone())
+                .await?;
+            tracing::info!("🤖 MCPサーバーを起動しました: {}", actor.as_str());
+            server.serve_stdio().await?;
+            return Ok(());
+        }
+        Some(Command::Tui { hours }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let dashboard =
+                builder.tui_dashboard(builder.google_calendar_repository().await?, hours.max(1));
+            dashboard.run().await?;
+            return Ok(());
+        }
+        Some(Command::List {
+            server,
+            resource,
+            tag,
+            owner,
+        }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let usecases = builder
+                .reservation_usecases(builder.google_calendar_repository().await?)
+                .await?;
+            let query = list_query(
+                builder.resource_config(),
+                server.or(resource),
+                tag,
+                owner.map(EmailAddress::new).transpose()?,
+            );
+            let usages = usecases.list.query(&query).await?;
+            let timezone = builder.resource_config().tz();
+            for usage in &usages {
+                print_usage(usage, timezone);
+            }
+            println!("{}件の予約があります", usages.len());
+            return Ok(());
+        }
+        Some(Command::Reserve {
+            owner,
+            server,
+            gpu,
+            resources,
+            from,
+            to,
+            notes,
+            private,
+        }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let usecases = builder
+                .reservation_usecases(builder.google_calendar_repository().await?)
+                .await?;
+            let timezone = builder.resource_config().tz();
+            let owner = EmailAddress::new(owner)?;
+            let period = TimePeriod::new(
+                parse_cli_time(&from, timezone)?,
+                parse_cli_time(&to, timezone)?,
+            )?;
+            let spec = reserve_spec(&usecases.resource_config, server, gpu, resources)?;
+            let resources = usecases.resource_config.resolve_resources(&spec)?;
+            let visibility = if private {
+                Visibility::Private
+            } else {
+                Visibility::default()
+            };
+            let id = usecases
+                .create
+                .execute(
+                    owner,
+                    period,
+                    resources,
+                    notes.filter(|notes| !notes.trim().is_empty()),
+                    ReservationMetadata::default(),
+                    visibility,
+                    Priority::default(),
+                    None,
+                )
+                .await?;
+            let usage = usecases.get.execute(&id).await?;
+            println!("✅ 予約を作成しました");
+            print_usage(&usage, timezone);
+            return Ok(());
+        }
+        Some(Command::Cancel { id, actor }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let usecases = builder
+                .reservation_usecases(builder.google_calendar_repository().await?)
+                .await?;
+            let id = UsageId::from_string(id);
+            let usage = usecases.get.execute(&id).await?;
+            let actor = match actor {
+                Some(actor) => EmailAddress::new(actor)?,
+                None => usage.owner_email().clone(),
+            };
+            usecases.delete.execute(&id, &actor).await?;
+            println!("🗑️  予約を取り消しました");
+            print_usage(&usage, builder.resource_config().tz());
+            return Ok(());
+        }
+        Some(Command::Availability {
+            from,
+            to,
+            resource,
+            tag,
+        }) => {
+            let builder = LabResourceManagerBuilder::from_env().await?;
+            let usecases = builder
+                .reservation_usecases(builder.google_calendar_repository().await?)
+                .await?;
+            let timezone = builder.resource_config().tz();
+            let start = match from {
+                Some(from) => parse_cli_time(&from, timezone)?,
+                None => Utc::now(),
+            };
+            let end = match to {
+                Some(to) => parse_cli_time(&to, timezone)?,
+                None => start + Duration::hours(24),
+            };
+            let report = usecases
+                .availability
+                .execute(
+                    &TimePeriod::new(start, end)?,
+                    resource.as_deref(),
+                    tag.as_deref(),
+                )
+                .await?;
+            let format_periods = |periods: &[TimePeriod]| {
+                if periods.is_empty() {
+                    return "なし".to_string();
+                }
+                periods
+                    .iter()
+                    .map(|period| {
+                        format!(
+                            "{} - {}",
+                            format_cli_time(period.start(), timezone),
+                            format_cli_time(period.end(), timezone)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            for availability in report.resources() {
+                println!("{}", availability.resource());
+                println!("  使用中: {}", format_periods(availability.busy_periods()));
+                println!("  空き: {}", format_periods(availability.free_periods()));
+            }
+            return Ok(());
+        }
+        Some(Command::Config {
+            command: ConfigCommand::Validate { config },
+        }) => {
+            let path = config
+                .or_else(|| std::env::var("RESOURCE_CONFIG").ok().map(PathBuf::from))
+                .unwrap_or_else(|| PathBuf::from(defaults::RESOURCE_CONFIG_PATH));
+            let resource_config = match load_config(&path) {
+                Ok(resource_config) => resource_config,
+                Err(e) => {
+                    println!("❌ {} を読み込めません: {}", path.display(), e);
+                    std::process::exit(1);
+                }
+            };
+            if report_config_issues(&path, &resource_config) > 0 {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Config {
+            command: ConfigCommand::Doctor,
+        }) => {
+            let builder = match LabResourceManagerBuilder::from_env().await {
+                Ok(builder) => builder,
+                Err(e) => {
+                    println!("❌ 設定を読み込めません: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            let app_config = builder.app_config();
+            let mut problems =
+                report_config_issues(&app_config.resource_config_path, builder.resource_config());
+            problems +=
+                check_slack_tokens(&app_config.slack_bot_token, &app_config.slack_app_token).await;
 
-    // UseCases
-    let collection_ids: Vec<String> = resource_config
-        .servers
-        .iter()
-        .map(|s| s.calendar_id.clone())
-        .chain(resource_config.rooms.iter().map(|r| r.calendar_id.clone()))
-        .collect();
-
-    let grant_access_usecase = Arc::new(GrantUserResourceAccessUseCase::new(
-        identity_repo.clone(),
-        calendar_access_service,
-        collection_ids,
-    ));
-
-    let create_usecase = Arc::new(CreateResourceUsageUseCase::new(resource_usage_repo.clone()));
-    let update_usecase = Arc::new(UpdateResourceUsageUseCase::new(resource_usage_repo.clone()));
-    let delete_usecase = Arc::new(DeleteResourceUsageUseCase::new(resource_usage_repo.clone()));
-
-    let notifier = NotificationRouter::new(resource_config.as_ref().clone(), identity_repo.clone());
-    let notify_usecase = Arc::new(
-        NotifyFutureResourceUsageChangesUseCase::new(resource_usage_repo, notifier)
-            .await
-            .map_err(|e| format!("通知UseCaseの初期化に失敗: {}", e))?,
-    );
+            match builder.google_calendar_repository().await {
+                Ok(repository) => {
+                    for calendar_id in builder.resource_config().calendar_ids() {
+                        match repository.check_calendar_access(&calendar_id).await {
+                            Ok(()) => println!("✅ カレンダー {} を参照できます", calendar_id),
+                            Err(e) => {
+                                problems += 1;
+                                println!("❌ {}", e);
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    problems += 1;
+                    if app_config.google_oauth.is_some() {
+                        println!(
+                            "❌ OAuthで認証できません（GOOGLE_OAUTH_CLIENT_SECRET_FILE と、google-auth で保存したトークンを確認してください）: {}",
+                            e
+                        );
+                    } else {
+                        println!(
+                            "❌ サービスアカウントで認証できません（GOOGLE_SERVICE_ACCOUNT_KEY のパスと鍵の内容を確認してください）: {}",
+                            e
+                        );
+                    }
+                }
+            }
 
-    // Slackインフラ
-    let slack_client = Arc::new(SlackClient::new(SlackClientHyperConnector::new()?));
-    let bot_token = SlackApiToken::new(app_config.slack_bot_token.clone().into());
+            if problems > 0 {
+                println!("{}件の問題が見つかりました", problems);
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        None => {}
+    }
 
     // ===========================================
-    // アプリケーションの組み立てと実行
+    // 依存の組み立て（コンポジションルート）
     // ===========================================
-    let app = Arc::new(SlackApp::new(
-        app_config,
-        resource_config,
-        identity_repo,
-        grant_access_usecase,
-        create_usecase,
-        update_usecase,
-        delete_usecase,
-        notify_usecase,
-        slack_client,
-        bot_token,
-    ));
+    let builder = LabResourceManagerBuilder::from_env().await?;
+
+    // 障害注入（chaosフィーチャー有効時のみ）
+    #[cfg(feature = "chaos")]
+    let app = {
+        let fault_injection = FaultInjectionConfig::from_env()?;
+        if fault_injection.is_enabled() {
+            tracing::warn!("⚠️  障害注入が有効です: {:?}", fault_injection);
+        }
+        let repository = FaultInjectingRepository::new(
+            builder.google_calendar_repository().await?,
+            fault_injection.clone(),
+        );
+        builder
+            .build_with(repository, |notifier| {
+                FaultInjectingNotifier::new(notifier, fault_injection)
+            })
+            .await?
+    };
+    #[cfg(not(feature = "chaos"))]
+    let app = builder.build().await?;
+
+    // メトリクスの公開（metricsフィーチャー有効時のみ）
+    #[cfg(feature = "metrics")]
+    {
+        let metrics_config = MetricsConfig::from_env();
+        tracing::info!(
+            "📈 メトリクスのHTTPサーバーを起動します: {}{}",
+            metrics_config.listen_addr,
+            metrics::METRICS_PATH
+        );
+        tokio::spawn(async move {
+            if let Err(e) = metrics::serve(&metrics_config).await {
+                tracing::error!("❌ メトリクスのHTTPサーバーのエラー: {}", e);
+            }
+        });
+    }
 
+    // ===========================================
+    // アプリケーションの実行
+    // ===========================================
+    let app = Arc::new(app);
     app.run()
         .await
         .map_err(|e| -> Box<dyn std::error::Error> { e })?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lab_resource_manager::domain::aggregates::resource_usage::value_objects::{Gpu, Resource};
+
+    fn config() -> ResourceConfig {
+        toml::from_str(
+            r#"
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+tags = ["a100"]
+
+[[servers.devices]]
+id = 1
+model = "RTX 4090"
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+notifications = []
+"#,
+        )
+        .unwrap()
+    }
+
+    fn gpu_usage(owner: &str, device: u32) -> ResourceUsage {
+        let start = Utc::now() + Duration::hours(1);
+        ResourceUsage::new(
+            EmailAddress::new(owner.to_string()).unwrap(),
+            TimePeriod::new(start, start + Duration::hours(2)).unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                device,
+                if device == 0 { "A100" } else { "RTX 4090" }.to_string(),
+            ))],
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_list_query_combines_server_tag_and_owner() {
+        let usages = vec![
+            gpu_usage("alice@example.com", 0),
+            gpu_usage("alice@example.com", 1),
+            gpu_usage("bob@example.com", 0),
+        ];
+
+        let query = list_query(
+            &config(),
+            Some("thalys".to_string()),
+            Some("A100".to_string()),
+            Some(EmailAddress::new("alice@example.com".to_string()).unwrap()),
+        );
+        let result = query.apply(usages.clone(), Utc::now());
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id(), usages[0].id());
+        assert_eq!(
+            list_query(&config(), None, None, None)
+                .apply(usages, Utc::now())
+                .len(),
+            3
+        );
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_cli_time_in_configured_timezone() {
+        let tokyo = Some(Tz::Asia__Tokyo);
+
+        assert_eq!(
+            parse_cli_time("2025-04-01 09:00", tokyo),
+            Ok(utc("2025-04-01T00:00:00Z"))
+        );
+        assert_eq!(
+            parse_cli_time(" 2025-04-01T09:30 ", tokyo),
+            Ok(utc("2025-04-01T00:30:00Z"))
+        );
+        // オフセットを指定した時刻は設定のタイムゾーンによらない
+        assert_eq!(
+            parse_cli_time("2025-04-01T09:00:00-04:00", tokyo),
+            Ok(utc("2025-04-01T13:00:00Z"))
+        );
+        assert!(
+            parse_cli_time("04/01 09:00", tokyo)
+                .unwrap_err()
+                .contains("YYYY-MM-DD HH:MM")
+        );
+    }
+
+    #[test]
+    fn test_reserve_spec_defaults_to_only_server() {
+        let config = config();
+
+        assert_eq!(
+            reserve_spec(&config, None, Some("0-1".to_string()), None),
+            Ok("Thalys:0-1".to_string())
+        );
+        assert_eq!(
+            reserve_spec(&config, None, None, None),
+            Ok("Thalys".to_string())
+        );
+        assert_eq!(
+            reserve_spec(
+                &config,
+                Some("Thalys".to_string()),
+                Some("1".to_string()),
+                None
+            ),
+            Ok("Thalys:1".to_string())
+        );
+        // --resources はそのまま使う
+        assert_eq!(
+            reserve_spec(
+                &config,
+                None,
+                Some("0".to_string()),
+                Some("会議室A".to_string())
+            ),
+            Ok("会議室A".to_string())
+        );
+    }
+
+    #[test]
+    fn test_reserve_spec_requires_server_when_several() {
+        let config: ResourceConfig = toml::from_str(
+            r#"
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+devices = [{ id = 0, model = "A100" }]
+
+[[servers]]
+name = "Freccia"
+calendar_id = "freccia@example.com"
+notifications = []
+devices = [{ id = 0, model = "A100" }]
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+notifications = []
+"#,
+        )
+        .unwrap();
+
+        assert!(
+            reserve_spec(&config, None, Some("0".to_string()), None)
+                .unwrap_err()
+                .contains("--server")
+        );
+    }
+
+    #[test]
+    fn test_usage_line_shows_times_in_configured_timezone() {
+        let usage = ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(utc("2025-04-01T00:00:00Z"), utc("2025-04-01T02:30:00Z")).unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                0,
+                "A100".to_string(),
+            ))],
+            Some("学習ジョブ".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            usage_line(&usage, Some(Tz::Asia__Tokyo)),
+            format!(
+                "{}  2025-04-01 09:00 - 2025-04-01 11:30  alice@example.com  {}  学習ジョブ",
+                usage.id().as_str(),
+                format_resources_styled(usage.resources(), ResourceStyle::Compact)
+            )
+        );
+        assert_eq!(
+            format_cli_time(utc("2025-04-01T00:00:00Z"), Some(Tz::UTC)),
+            "2025-04-01 00:00"
+        );
+    }
+}
@@ -0,0 +1,1321 @@
+//! システム全体の組み立て
+//!
+//! `ResourceConfig` と `AppConfig` から、リポジトリ・通知・電源管理・ユースケースを
+//! 組み立てて `SlackApp` を構築する。
+//! 各依存にはデフォルト実装（Google Calendar、JSON/SQLiteファイル等）が使われ、
+//! `with_*` メソッドで差し替えることができる。
+
+use crate::application::read_model::ReservationReadModel;
+use crate::application::usecases::{
+    ApproveReservationUseCase, AutoReleaseIdleReservationsUseCase, BulkDeleteResourceUsagesUseCase,
+    CloneReservationUseCase, CostReportUseCase, CreateResourceUsageUseCase,
+    DeleteResourceUsageUseCase, DetectUnreservedUsageUseCase, DigestChannel,
+    ExtendResourceUsageUseCase, FindNextAvailableSlotUseCase, FreezeResourceUseCase,
+    GetCurrentOccupantsUseCase, GetIdentityLinkHistoryUseCase, GetResourceAvailabilityUseCase,
+    GetResourceUsageByIdUseCase, GrantUserResourceAccessUseCase, ImportReservationsUseCase,
+    JoinWaitlistUseCase, ListAllFutureResourceUsagesUseCase, ListUserResourceUsagesUseCase,
+    NotifyFutureResourceUsageChangesUseCase, NotifyWaitlistUseCase,
+    RebuildReservationReadModelUseCase, ReleaseResourceUsageUseCase,
+    RevokeUserResourceAccessUseCase, SendUpcomingRemindersUseCase, SetDeviceStatusUseCase,
+    SyncDirectoryMembersUseCase, TransferOwnershipUseCase, UpdateResourceUsageUseCase,
+    UsageReportUseCase, WakeReservedServersUseCase, WeeklyDigestUseCase,
+};
+use crate::domain::aggregates::group::{Group, GroupId};
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::gpu_monitor::GpuProcessMonitor;
+use crate::domain::ports::leader_election::LeaderElection;
+use crate::domain::ports::member_directory::MemberDirectory;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::power_management::PowerManagementService;
+use crate::domain::ports::repositories::{
+    DeviceHealthRepository, IdentityLinkAuditRepository, IdentityLinkRepository,
+    ResourceFreezeRepository, ResourceUsageRepository, WaitlistRepository,
+    WorkspaceTokenRepository,
+};
+use crate::domain::ports::reservation_import::ReservationSource;
+use crate::domain::ports::resource_collection_access::ResourceCollectionAccessService;
+use crate::infrastructure::config::{
+    AppConfig, LeaderElectionConfig, ResourceConfig, defaults, load_config, load_with_secrets,
+};
+use crate::infrastructure::directory::{LdapMemberDirectory, SlackUserDirectory};
+use crate::infrastructure::export::{GoogleSheetsExporter, NotionSync};
+use crate::infrastructure::google_auth::GoogleCredentials;
+use crate::infrastructure::gpu_monitor::GpuMonitorRouter;
+use crate::infrastructure::leader_election::{FileLockLeaderElection, RedisLeaderElection};
+use crate::infrastructure::logging::ops_alert;
+use crate::infrastructure::notifier::{
+    NotificationRouter, SlackApprovalRequestSender, SlackIdleReservationNotifier,
+    SlackPreemptionNotifier, SlackReminderSender, SlackUnreservedUsageNotifier,
+    SlackWaitlistNotifier, SlackWeeklyDigestSender,
+};
+use crate::infrastructure::power_management::PowerManagementRouter;
+use crate::infrastructure::repositories::device_health::JsonFileDeviceHealthRepository;
+use crate::infrastructure::repositories::identity_link::{
+    self, JsonLinesIdentityLinkAuditRepository,
+};
+use crate::infrastructure::repositories::resource_freeze::JsonFileResourceFreezeRepository;
+use crate::infrastructure::repositories::resource_usage::google_calendar::GoogleCalendarUsageRepository;
+use crate::infrastructure::repositories::sent_reminder::JsonFileSentReminderRepository;
+use crate::infrastructure::repositories::usage_snapshot::JsonFileUsageSnapshotRepository;
+use crate::infrastructure::repositories::waitlist::JsonFileWaitlistRepository;
+use crate::infrastructure::repositories::workspace_token::JsonFileWorkspaceTokenRepository;
+use crate::infrastructure::resource_collection_access::GoogleCalendarAccessService;
+use crate::infrastructure::secrets;
+use crate::interface::http_api::{ApiServer, events};
+use crate::interface::mcp::McpServer;
+use crate::interface::slack::SlackApp;
+use crate::interface::tui::TuiDashboard;
+use google_calendar3::yup_oauth2;
+use slack_morphism::prelude::*;
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// 組み立て時の結果型
+type BuildResult<T> = Result<T, Box<dyn Error>>;
+
+/// 予約を直接操作するためのユースケース一式（[`LabResourceManagerBuilder::reservation_usecases`] で組み立てる）
+pub struct ReservationUseCases<R: ResourceUsageRepository> {
+    /// ユーザーグループを展開したリソース設定
+    pub resource_config: Arc<ResourceConfig>,
+    pub create: Arc<CreateResourceUsageUseCase<R>>,
+    pub delete: Arc<DeleteResourceUsageUseCase<R>>,
+    pub get: Arc<GetResourceUsageByIdUseCase<R>>,
+    pub availability: Arc<GetResourceAvailabilityUseCase<R>>,
+    pub list: Arc<ListAllFutureResourceUsagesUseCase<R>>,
+    pub list_user: Arc<ListUserResourceUsagesUseCase<R>>,
+}
+
+/// lab-resource-managerの組み立てを行うビルダー
+///
+/// 差し替えなかった依存は以下のデフォルト実装で組み立てる。
+///
+/// | 依存 | デフォルト |
+/// |------|-----------|
+/// | ID紐付けリポジトリ | `IDENTITY_LINKS_FILE`（拡張子によりJSON/SQLite） |
+/// | リソースコレクションへのアクセス権付与 | Google Calendar |
+/// | 電源管理 | サーバーの `power` 設定（未設定なら無効） |
+/// | GPUの使用状況の取得 | サーバーの `gpu_monitor` 設定（未設定なら無効） |
+/// | 研究室メンバーの名簿 | `LDAP_URL` が設定されていればLDAP（未設定なら無効） |
+pub struct LabResourceManagerBuilder {
+    app_config: AppConfig,
+    resource_config: Arc<ResourceConfig>,
+    identity_repo: Option<Arc<dyn IdentityLinkRepository>>,
+    collection_access: Option<Arc<dyn ResourceCollectionAccessService>>,
+    power_management: Option<Arc<dyn PowerManagementService>>,
+    gpu_monitor: Option<Arc<dyn GpuProcessMonitor>>,
+    member_directory: Option<Arc<dyn MemberDirectory>>,
+}
+
+impl LabResourceManagerBuilder {
+    /// 設定から新しいビルダーを作成
+    pub fn new(app_config: AppConfig, resource_config: ResourceConfig) -> Self {
+        Self {
+            app_config,
+            resource_config: Arc::new(resource_config),
+            identity_repo: None,
+            collection_access: None,
+            power_management: None,
+            gpu_monitor: None,
+            member_directory: None,
+        }
+    }
+
+    /// 環境変数とリソース設定ファイルからビルダーを作成
+    ///
+    /// トークンとサービスアカウントキーは `SECRET_PROVIDER` で選んだ取得元から読み込む。
+    pub async fn from_env() -> BuildResult<Self> {
+        let app_config = load_with_secrets(secrets::provider_from_env()?.as_ref()).await?;
+        let resource_config = load_config(&app_config.resource_config_path)?;
+        Ok(Self::new(app_config, resource_config))
+    }
+
+    /// アプリケーション設定
+    pub fn app_config(&self) -> &AppConfig {
+        &self.app_config
+    }
+
+    /// リソース設定
+    pub fn resource_config(&self) -> &ResourceConfig {
+        &self.resource_config
+    }
+
+    /// ID紐付けリポジトリを差し替える
+    pub fn with_identity_repository(
+        mut self,
+        identity_repo: Arc<dyn IdentityLinkRepository>,
+    ) -> Self {
+        self.identity_repo = Some(identity_repo);
+        self
+    }
+
+    /// リソースコレクションへのアクセス権付与サービスを差し替える
+    pub fn with_resource_collection_access(
+        mut self,
+        collection_access: Arc<dyn ResourceCollectionAccessService>,
+    ) -> Self {
+        self.collection_access = Some(collection_access);
+        self
+    }
+
+    /// 電源管理サービスを差し替える
+    pub fn with_power_management(
+        mut self,
+        power_management: Arc<dyn PowerManagementService>,
+    ) -> Self {
+        self.power_management = Some(power_management);
+        self
+    }
+
+    /// GPUの使用状況の取得サービスを差し替える
+    pub fn with_gpu_monitor(mut self, gpu_monitor: Arc<dyn GpuProcessMonitor>) -> Self {
+        self.gpu_monitor = Some(gpu_monitor);
+        self
+    }
+
+    /// 研究室メンバーの名簿を差し替える
+    pub fn with_member_directory(mut self, member_directory: Arc<dyn MemberDirectory>) -> Self {
+        self.member_directory = Some(member_directory);
+        self
+    }
+
+    /// デフォルトのリソース使用予定リポジトリ（Google Calendar）を作成
+    ///
+    /// `build_with` に渡す前にリポジトリをラップしたい場合に使う。
+    pub async fn google_calendar_repository(&self) -> BuildResult<GoogleCalendarUsageRepository> {
+        GoogleCalendarUsageRepository::from_credentials(
+            self.google_credentials().await?,
+            self.resource_config.as_ref().clone(),
+            self.app_config.calendar_mappings_file.clone(),
+        )
+        .await
+    }
+
+    /// 予約をまとめて取り込むユースケースを組み立てる
+    ///
+    /// 予約フォームと同じ競合・上限・受付期間などの確認を行う。
+    ///
+    /// # Arguments
+    /// * `repository` - リソース使用予定リポジトリ
+    /// * `source` - 取り込む予約の読み込み元
+    pub async fn import_reservations_usecase<R>(
+        &self,
+        repository: R,
+        source: Arc<dyn ReservationSource>,
+    ) -> BuildResult<ImportReservationsUseCase<R>>
+    where
+        R: ResourceUsageRepository + Send + Sync + 'static,
+    {
+        let repository = Arc::new(repository);
+        let resource_config = expand_user_groups(
+            self.resource_config.as_ref().clone(),
+            &self.app_config.slack_bot_token,
+        )
+        .await;
+        let identity_repo = match self.identity_repo.clone() {
+            Some(identity_repo) => identity_repo,
+            None => identity_link::open(self.app_config.identity_links_file.clone()).await?,
+        };
+        let freeze_repo: Arc<dyn ResourceFreezeRepository> = Arc::new(
+            JsonFileResourceFreezeRepository::new(self.app_config.resource_freezes_file.clone()),
+        );
+        let device_health_repo: Arc<dyn DeviceHealthRepository> = Arc::new(
+            JsonFileDeviceHealthRepository::new(self.app_config.device_statuses_file.clone()),
+        );
+        let admins = resolve_emails(
+            resource_config.admin_emails(),
+            resource_config.admin_user_ids(),
+            "Admin",
+            identity_repo.as_ref(),
+        )
+        .await;
+        let groups = resolve_groups(&resource_config, identity_repo.as_ref()).await;
+
+        let create_usecase = self.create_usecase(
+            &repository,
+            &resource_config,
+            &identity_repo,
+            &freeze_repo,
+            &device_health_repo,
+            admins,
+            groups,
+        );
+        Ok(ImportReservationsUseCase::new(
+            Arc::new(create_usecase),
+            source,
+        ))
+    }
+
+    /// 予約の一覧・空き状況の確認・作成・取り消しのユースケースを組み立てる
+    ///
+    /// Slackアプリと同じ確認（競合・上限・受付期間・予約停止）と権限で予約を作成・取り消す。
+    /// MCPサーバーとCLIのサブコマンドが使う。
+    pub async fn reservation_usecases<R>(
+        &self,
+        repository: R,
+    ) -> BuildResult<ReservationUseCases<R>>
+    where
+        R: ResourceUsageRepository + Send + Sync + 'static,
+    {
+        let repository = Arc::new(repository);
+        let resource_config = expand_user_groups(
+            self.resource_config.as_ref().clone(),
+            &self.app_config.slack_bot_token,
+        )
+        .await;
+        let identity_repo = match self.identity_repo.clone() {
+            Some(identity_repo) => identity_repo,
+            None => identity_link::open(self.app_config.identity_links_file.clone()).await?,
+        };
+        let freeze_repo: Arc<dyn ResourceFreezeRepository> = Arc::new(
+            JsonFileResourceFreezeRepository::new(self.app_config.resource_freezes_file.clone()),
+        );
+        let device_health_repo: Arc<dyn DeviceHealthRepository> = Arc::new(
+            JsonFileDeviceHealthRepository::new(self.app_config.device_statuses_file.clone()),
+        );
+        let admins = resolve_emails(
+            resource_config.admin_emails(),
+            resource_config.admin_user_ids(),
+            "Admin",
+            identity_repo.as_ref(),
+        )
+        .await;
+        let moderators = resolve_emails(
+            resource_config.moderator_emails(),
+            resource_config.moderator_user_ids(),
+            "Moderator",
+            identity_repo.as_ref(),
+        )
+        .await;
+        let groups = resolve_groups(&resource_config, identity_repo.as_ref()).await;
+
+        let create_usecase = self.create_usecase(
+            &repository,
+            &resource_config,
+            &identity_repo,
+            &freeze_repo,
+            &device_health_repo,
+            admins.clone(),
+            groups.clone(),
+        );
+        let delete_usecase = DeleteResourceUsageUseCase::new(repository.clone())
+            .with_admins(admins)
+            .with_moderators(moderators)
+            .with_groups(groups);
+        let availability_usecase =
+            GetResourceAvailabilityUseCase::new(repository.clone(), resource_config.resources())
+                .with_tags(resource_config.resource_tags());
+        Ok(ReservationUseCases {
+            resource_config: Arc::new(resource_config),
+            create: Arc::new(create_usecase),
+            delete: Arc::new(delete_usecase),
+            get: Arc::new(GetResourceUsageByIdUseCase::new(repository.clone())),
+            availability: Arc::new(availability_usecase),
+            list: Arc::new(ListAllFutureResourceUsagesUseCase::new(repository.clone())),
+            list_user: Arc::new(ListUserResourceUsagesUseCase::new(repository)),
+        })
+    }
+
+    /// 指定した利用者として予約を操作するMCPサーバーを組み立てる
+    pub async fn mcp_server<R>(
+        &self,
+        repository: R,
+        actor: EmailAddress,
+    ) -> BuildResult<McpServer<R>>
+    where
+        R: ResourceUsageRepository + Send + Sync + 'static,
+    {
+        let usecases = self.reservation_usecases(repository).await?;
+        Ok(McpServer::new(
+            actor,
+            usecases.resource_config,
+            usecases.create,
+            usecases.delete,
+            usecases.get,
+            usecases.availability,
+            usecases.list,
+            usecases.list_user,
+        ))
+    }
+
+    /// 端末のダッシュボードを組み立てる（`POLLING_INTERVAL` ごとに予約を取得し直す）
+    ///
+    /// # Arguments
+    /// * `hours` - タイムラインに表示する時間数
+    pub fn tui_dashboard<R>(&self, repository: R, hours: u32) -> TuiDashboard<R>
+    where
+        R: ResourceUsageRepository + Send + Sync + 'static,
+    {
+        TuiDashboard::new(
+            Arc::new(repository),
+            &self.resource_config,
+            Duration::from_secs(self.app_config.polling_interval_secs),
+            hours,
+        )
+    }
+
+    /// デフォルトの実装でSlackアプリケーションを組み立てる
+    pub async fn build(
+        self,
+    ) -> BuildResult<SlackApp<GoogleCalendarUsageRepository, NotificationRouter>> {
+        let repository = self.google_calendar_repository().await?;
+        self.build_with(repository, |notifier| notifier).await
+    }
+
+    /// 指定したリポジトリでSlackアプリケーションを組み立てる
+    ///
+    /// # Arguments
+    /// * `repository` - リソース使用予定リポジトリ
+    /// * `wrap_notifier` - 設定から組み立てた `NotificationRouter` をラップする関数
+    pub async fn build_with<R, N>(
+        self,
+        repository: R,
+        wrap_notifier: impl FnOnce(NotificationRouter) -> N,
+    ) -> BuildResult<SlackApp<R, N>>
+    where
+        R: ResourceUsageRepository + Send + Sync + 'static,
+        N: Notifier + Send + Sync + 'static,
+    {
+        let repository = Arc::new(repository);
+        let resource_config = Arc::new(
+            expand_user_groups(
+                self.resource_config.as_ref().clone(),
+                &self.app_config.slack_bot_token,
+            )
+            .await,
+        );
+
+        // 運用エラーの投稿
+        if let Some(channel_id) = &resource_config.ops_channel_id {
+            ops_alert::start(self.app_config.slack_bot_token.clone(), channel_id.clone());
+        }
+
+        // リポジトリ・外部サービス
+        let identity_repo = match self.identity_repo.clone() {
+            Some(identity_repo) => identity_repo,
+            None => identity_link::open(self.app_config.identity_links_file.clone()).await?,
+        };
+        let collection_access: Arc<dyn ResourceCollectionAccessService> =
+            match self.collection_access.clone() {
+                Some(collection_access) => collection_access,
+                None => Arc::new(
+                    GoogleCalendarAccessService::from_credentials(self.google_credentials().await?)
+                        .await?,
+                ),
+            };
+        let audit_repo: Arc<dyn IdentityLinkAuditRepository> =
+            Arc::new(JsonLinesIdentityLinkAuditRepository::new(
+                self.app_config.identity_link_audit_file.clone(),
+            ));
+        let freeze_repo: Arc<dyn ResourceFreezeRepository> = Arc::new(
+            JsonFileResourceFreezeRepository::new(self.app_config.resource_freezes_file.clone()),
+        );
+        let device_health_repo: Arc<dyn DeviceHealthRepository> = Arc::new(
+            JsonFileDeviceHealthRepository::new(self.app_config.device_statuses_file.clone()),
+        );
+        let workspace_token_repo: Arc<dyn WorkspaceTokenRepository> = Arc::new(
+            JsonFileWorkspaceTokenRepository::new(self.app_config.workspace_tokens_file.clone()),
+        );
+        let power_management = match self.power_management.clone() {
+            Some(power_management) => Some(power_management),
+            None => {
+                let router = PowerManagementRouter::new(&resource_config)?;
+                (!router.is_empty()).then(|| Arc::new(router) as Arc<dyn PowerManagementService>)
+            }
+        };
+
+        // UseCases
+        let grant_access_usecase = Arc::new(
+            GrantUserResourceAccessUseCase::new(
+                identity_repo.clone(),
+                collection_access.clone(),
+                resource_config.calendar_ids(),
+            )
+            .with_audit_repository(audit_repo.clone()),
+        );
+        let revoke_access_usecase = Arc::new(
+            RevokeUserResourceAccessUseCase::new(
+                identity_repo.clone(),
+                collection_access,
+                resource_config.calendar_ids(),
+            )
+            .with_audit_repository(audit_repo.clone()),
+        );
+        let link_history_usecase = Arc::new(GetIdentityLinkHistoryUseCase::new(audit_repo));
+        let storage_capacities = resource_config.storage_capacities();
+        let license_seats = resource_config.license_seats();
+        let reservation_limits = resource_config.reservation_limit_policy();
+        let booking_windows = resource_config.booking_window_policy();
+        let admins = resolve_emails(
+            resource_config.admin_emails(),
+            resource_config.admin_user_ids(),
+            "Admin",
+            identity_repo.as_ref(),
+        )
+        .await;
+        let moderators = resolve_emails(
+            resource_config.moderator_emails(),
+            resource_config.moderator_user_ids(),
+            "Moderator",
+            identity_repo.as_ref(),
+        )
+        .await;
+        let groups = resolve_groups(&resource_config, identity_repo.as_ref()).await;
+        let create_usecase = Arc::new(self.create_usecase(
+            &repository,
+            &resource_config,
+            &identity_repo,
+            &freeze_repo,
+            &device_health_repo,
+            admins.clone(),
+            groups.clone(),
+        ));
+        let update_usecase = Arc::new(
+            UpdateResourceUsageUseCase::new(repository.clone())
+                .with_freeze_repository(freeze_repo.clone())
+                .with_storage_capacities(storage_capacities.clone())
+                .with_license_seats(license_seats.clone())
+                .with_reservation_limits(reservation_limits.clone())
+                .with_booking_windows(booking_windows.clone())
+                .with_admins(admins.clone())
+                .with_groups(groups.clone()),
+        );
+        let extend_usecase = Arc::new(
+            ExtendResourceUsageUseCase::new(repository.clone())
+                .with_freeze_repository(freeze_repo.clone())
+                .with_device_health_repository(device_health_repo.clone())
+                .with_storage_capacities(storage_capacities.clone())
+                .with_license_seats(license_seats.clone())
+                .with_reservation_limits(reservation_limits.clone())
+                .with_booking_windows(booking_windows)
+                .with_admins(admins.clone())
+                .with_groups(groups.clone()),
+        );
+        let release_usecase = Arc::new(
+            ReleaseResourceUsageUseCase::new(repository.clone())
+                .with_admins(admins.clone())
+                .with_groups(groups.clone()),
+        );
+        let transfer_usecase = Arc::new(
+            TransferOwnershipUseCase::new(repository.clone())
+                .with_admins(admins.clone())
+                .with_reservation_limits(reservation_limits),
+        );
+        let mut room_approvers = HashMap::new();
+        for room in resource_config.approval_required_room_configs() {
+            let approvers = resolve_emails(
+                room.approver_emails(),
+                room.approver_user_ids(),
+                "Approver",
+                identity_repo.as_ref(),
+            )
+            .await;
+            room_approvers.insert(room.name.clone(), approvers);
+        }
+        let approve_usecase = Arc::new(
+            ApproveReservationUseCase::new(repository.clone())
+                .with_approvers(room_approvers)
+                .with_admins(admins.clone()),
+        );
+        let get_usage_usecase = Arc::new(GetResourceUsageByIdUseCase::new(repository.clone()));
+        let next_slot_usecase = Arc::new(
+            FindNextAvailableSlotUseCase::new(repository.clone())
+                .with_freeze_repository(freeze_repo.clone()),
+        );
+        let clone_reservation_usecase = Arc::new(CloneReservationUseCase::new(
+            repository.clone(),
+            next_slot_usecase.clone(),
+        ));
+        let freeze_usecase = Arc::new(FreezeResourceUseCase::new(repository.clone(), freeze_repo));
+        let device_status_usecase = Arc::new(SetDeviceStatusUseCase::new(
+            repository.clone(),
+            device_health_repo,
+        ));
+        let availability_usecase = Arc::new(
+            GetResourceAvailabilityUseCase::new(repository.clone(), resource_config.resources())
+                .with_tags(resource_config.resource_tags()),
+        );
+        let current_occupants_usecase =
+            Arc::new(GetCurrentOccupantsUseCase::new(repository.clone()));
+        let usage_report_usecase = Arc::new(
+            UsageReportUseCase::new(repository.clone())
+                .with_timezone(resource_config.timezone.as_deref()),
+        );
+        let cost_report_usecase = resource_config
+            .cost_model()
+            .map(|cost_model| Arc::new(CostReportUseCase::new(repository.clone(), cost_model)));
+        let delete_usecase = Arc::new(
+            DeleteResourceUsageUseCase::new(repository.clone())
+                .with_admins(admins.clone())
+                .with_moderators(moderators.clone())
+                .with_groups(groups),
+        );
+        let bulk_delete_usecase = Arc::new(
+            BulkDeleteResourceUsagesUseCase::new(repository.clone())
+                .with_admins(admins)
+                .with_moderators(moderators),
+        );
+        let rebuild_read_model_usecase = Arc::new(RebuildReservationReadModelUseCase::new(
+            repository.clone(),
+            Arc::new(ReservationReadModel::new()),
+            resource_config.timezone.clone(),
+        ));
+        let wake_servers_usecase = power_management.as_ref().and_then(|power_management| {
+            let wake_before: HashMap<String, chrono::Duration> = resource_config
+                .wake_before_minutes()
+                .into_iter()
+                .map(|(server, minutes)| (server, chrono::Duration::minutes(minutes.into())))
+                .collect();
+            (!wake_before.is_empty()).then(|| {
+                Arc::new(WakeReservedServersUseCase::new(
+                    repository.clone(),
+                    power_management.clone(),
+                    wake_before,
+                ))
+            })
+        });
+        let detect_unreserved_usecase = self.detect_unreserved_usecase(&repository);
+        let auto_release_usecase = self.auto_release_usecase(&repository, &identity_repo);
+        let weekly_digest_usecase = self.weekly_digest_usecase(&repository);
+        let reminders_usecase = {
+            let to_durations =
+                |lead_times: HashMap<String, u32>| -> HashMap<String, chrono::Duration> {
+                    lead_times
+                        .into_iter()
+                        .map(|(resource, minutes)| {
+                            (resource, chrono::Duration::minutes(minutes.into()))
+                        })
+                        .collect()
+                };
+            let remind_before = to_durations(resource_config.remind_before_minutes());
+            let remind_before_end = to_durations(resource_config.remind_before_end_minutes());
+            (!remind_before.is_empty() || !remind_before_end.is_empty()).then(|| {
+                Arc::new(
+                    SendUpcomingRemindersUseCase::new(
+                        repository.clone(),
+                        identity_repo.clone(),
+                        Arc::new(SlackReminderSender::new(
+                            self.app_config.slack_bot_token.clone(),
+                            resource_config.timezone.clone(),
+                        )),
+                        Arc::new(JsonFileSentReminderRepository::new(
+                            self.app_config.sent_reminders_file.clone(),
+                        )),
+                        remind_before,
+                    )
+                    .with_end_reminders(remind_before_end),
+                )
+            })
+        };
+
+        let waitlist_repo: Arc<dyn WaitlistRepository> = Arc::new(JsonFileWaitlistRepository::new(
+            self.app_config.waitlist_file.clone(),
+        ));
+        let join_waitlist_usecase = Arc::new(JoinWaitlistUseCase::new(waitlist_repo.clone()));
+        let notify_waitlist_usecase = Arc::new(
+            NotifyWaitlistUseCase::new(
+                repository.clone(),
+                waitlist_repo,
+                identity_repo.clone(),
+                Arc::new(SlackWaitlistNotifier::new(
+                    self.app_config.slack_bot_token.clone(),
+                    resource_config.timezone.clone(),
+                    resource_config.i18n.clone(),
+                )),
+            )
+            .with_storage_capacities(storage_capacities)
+            .with_license_seats(license_seats),
+        );
+
+        let notifier =
+            NotificationRouter::new(resource_config.as_ref().clone(), identity_repo.clone())
+                .with_workspace_token_repository(workspace_token_repo.clone());
+        let notifier = match &power_management {
+            Some(power_management) => notifier.with_power_management(power_management.clone()),
+            None => notifier,
+        };
+        // 予約の変更をGoogleスプレッドシートに追記する
+        let notifier = match &self.app_config.google_sheets_export {
+            Some(config) => {
+                let auth = self
+                    .google_credentials()
+                    .await?
+                    .service_account_authenticator()
+                    .await?;
+                notifier.with_sheets_exporter(Arc::new(GoogleSheetsExporter::new(auth, config)))
+            }
+            None => notifier,
+        };
+        // 予約の変更をNotionデータベースに同期する
+        let notifier = match &self.app_config.notion_sync {
+            Some(config) => notifier.with_notion_sync(Arc::new(NotionSync::new(config))),
+            None => notifier,
+        };
+        // HTTP APIのイベントストリームへ、通知と同じ変更イベントを配信する
+        let event_stream = self
+            .app_config
+            .http_api
+            .as_ref()
+            .map(|_| broadcast::channel(events::EVENT_BUFFER).0);
+        let notifier = match &event_stream {
+            Some(event_stream) => notifier.with_event_stream(event_stream.clone()),
+            None => notifier,
+        };
+        let notify_usecase = Arc::new(
+            NotifyFutureResourceUsageChangesUseCase::with_snapshot(
+                repository.clone(),
+                wrap_notifier(notifier),
+                Arc::new(JsonFileUsageSnapshotRepository::new(
+                    self.app_config.usage_snapshot_file.clone(),
+                )),
+            )
+            .await
+            .map_err(|e| format!("通知UseCaseの初期化に失敗: {}", e))?,
+        );
+
+        let sync_members = self.sync_members_usecase(&identity_repo, &grant_access_usecase);
+        let leader_election = self.leader_election()?;
+
+        // 予約を操作するHTTP API
+        let api_server = self.app_config.http_api.clone().map(|config| {
+            let api_server = ApiServer::new(
+                config,
+                self.app_config.api_tokens_file.clone(),
+                resource_config.clone(),
+                create_usecase.clone(),
+                update_usecase.clone(),
+                delete_usecase.clone(),
+                get_usage_usecase.clone(),
+                availability_usecase.clone(),
+                Arc::new(ListAllFutureResourceUsagesUseCase::new(repository.clone())),
+                Arc::new(ListUserResourceUsagesUseCase::new(repository.clone())),
+            );
+            Arc::new(match event_stream {
+                Some(event_stream) => api_server.with_event_stream(event_stream),
+                None => api_server,
+            })
+        });
+
+        // Slackインフラ
+        let slack_client = Arc::new(SlackClient::new(SlackClientHyperConnector::new()?));
+        let bot_token = SlackApiToken::new(self.app_config.slack_bot_token.clone().into());
+
+        let app = SlackApp::new(
+            self.app_config,
+            resource_config,
+            identity_repo,
+            grant_access_usecase,
+            revoke_access_usecase,
+            link_history_usecase,
+            create_usecase,
+            update_usecase,
+            extend_usecase,
+            release_usecase,
+            transfer_usecase,
+            approve_usecase,
+            get_usage_usecase,
+            delete_usecase,
+            bulk_delete_usecase,
+            freeze_usecase,
+            device_status_usecase,
+            availability_usecase,
+            next_slot_usecase,
+            clone_reservation_usecase,
+            current_occupants_usecase,
+            usage_report_usecase,
+            notify_usecase,
+            rebuild_read_model_usecase,
+            slack_client,
+            bot_token,
+        )
+        .with_workspace_token_repository(workspace_token_repo)
+        .with_waitlist_usecases(join_waitlist_usecase, notify_waitlist_usecase);
+        let app = match wake_servers_usecase {
+            Some(wake_servers_usecase) => app.with_wake_servers_usecase(wake_servers_usecase),
+            None => app,
+        };
+        let app = match detect_unreserved_usecase {
+            Some(detect_unreserved_usecase) => {
+                app.with_detect_unreserved_usecase(detect_unreserved_usecase)
+            }
+            None => app,
+        };
+        let app = match auto_release_usecase {
+            Some(auto_release_usecase) => app.with_auto_release_usecase(auto_release_usecase),
+            None => app,
+        };
+        let app = match cost_report_usecase {
+            Some(cost_report_usecase) => app.with_cost_report_usecase(cost_report_usecase),
+            None => app,
+        };
+        let app = match reminders_usecase {
+            Some(reminders_usecase) => app.with_reminders_usecase(reminders_usecase),
+            None => app,
+        };
+        let app = match weekly_digest_usecase {
+            Some(weekly_digest_usecase) => app.with_weekly_digest_usecase(weekly_digest_usecase),
+            None => app,
+        };
+        let app = match leader_election {
+            Some(leader_election) => app.with_leader_election(leader_election),
+            None => app,
+        };
+        let app = match api_server {
+            Some(api_server) => app.with_api_server(api_server),
+            None => app,
+        };
+        Ok(match sync_members {
+            Some((sync_members_usecase, interval)) => {
+                app.with_sync_members_usecase(sync_members_usecase, interval)
+            }
+            None => app,
+        })
+    }
+
+    /// 承認が必要な部屋があれば、予約作成ユースケースに承認依頼の送信先を設定する
+    /// 予約の作成ユースケースを組み立てる
+    ///
+    /// 予約フォームとCSVの取り込みで、同じ競合・上限・受付期間などの確認を行うために共有する。
+    #[allow(clippy::too_many_arguments)]
+    fn create_usecase<R: ResourceUsageRepository>(
+        &self,
+        repository: &Arc<R>,
+        resource_config: &ResourceConfig,
+        identity_repo: &Arc<dyn IdentityLinkRepository>,
+        freeze_repo: &Arc<dyn ResourceFreezeRepository>,
+        device_health_repo: &Arc<dyn DeviceHealthRepository>,
+        admins: Vec<EmailAddress>,
+        groups: Vec<Group>,
+    ) -> CreateResourceUsageUseCase<R> {
+        let create_usecase = CreateResourceUsageUseCase::new(repository.clone())
+            .with_freeze_repository(freeze_repo.clone())
+            .with_device_health_repository(device_health_repo.clone())
+            .with_storage_capacities(resource_config.storage_capacities())
+            .with_license_seats(resource_config.license_seats())
+            .with_reservation_limits(resource_config.reservation_limit_policy())
+            .with_booking_windows(resource_config.booking_window_policy())
+            .with_admins(admins)
+            .with_groups(groups)
+            .with_preemption_notifier(
+                identity_repo.clone(),
+                Arc::new(SlackPreemptionNotifier::new(
+                    self.app_config.slack_bot_token.clone(),
+                    resource_config.timezone.clone(),
+                    resource_config.i18n.clone(),
+                )),
+            );
+        let create_usecase = match resource_config.fair_share_policy() {
+            Some(policy) => create_usecase.with_fair_share(policy),
+            None => create_usecase,
+        };
+        self.with_approval(create_usecase)
+    }
+
+    fn with_approval<R: ResourceUsageRepository>(
+        &self,
+        create_usecase: CreateResourceUsageUseCase<R>,
+    ) -> CreateResourceUsageUseCase<R> {
+        let rooms = self.resource_config.approval_required_rooms();
+        if rooms.is_empty() {
+            return create_usecase;
+        }
+        let Some(channel_id) = &self.resource_config.approvers_channel_id else {
+            tracing::warn!(
+                "Rooms {:?} require approval, but approvers_channel_id is not set; approval is disabled",
+                rooms
+            );
+            return create_usecase;
+        };
+        create_usecase.with_approval(
+            rooms,
+            Arc::new(SlackApprovalRequestSender::new(
+                self.app_config.slack_bot_token.clone(),
+                channel_id.clone(),
+                self.resource_config.timezone.clone(),
+            )),
+        )
+    }
+
+    /// GPUの使用状況の取得サービスを組み立てる（使用状況を取得するサーバーが無い場合は `None`）
+    fn gpu_process_monitor(&self) -> Option<Arc<dyn GpuProcessMonitor>> {
+        if let Some(monitor) = &self.gpu_monitor {
+            return Some(monitor.clone());
+        }
+        let router = GpuMonitorRouter::new(&self.resource_config);
+        (!router.is_empty()).then(|| Arc::new(router) as Arc<dyn GpuProcessMonitor>)
+    }
+
+    /// 使われていない予約を確認・解放するユースケースを組み立てる
+    ///
+    /// 自動解放のポリシーとGPUの使用状況を取得するサーバーの両方が設定されている場合のみ有効にする。
+    fn auto_release_usecase<R: ResourceUsageRepository>(
+        &self,
+        repository: &Arc<R>,
+        identity_repo: &Arc<dyn IdentityLinkRepository>,
+    ) -> Option<Arc<AutoReleaseIdleReservationsUseCase<R>>> {
+        let policy = self.resource_config.idle_release_policy()?;
+        let Some(monitor) = self.gpu_process_monitor() else {
+            tracing::warn!(
+                "idle_release is configured, but no server has gpu_monitor; idle reservations will not be released"
+            );
+            return None;
+        };
+        Some(Arc::new(AutoReleaseIdleReservationsUseCase::new(
+            repository.clone(),
+            monitor,
+            identity_repo.clone(),
+            Arc::new(SlackIdleReservationNotifier::new(
+                self.app_config.slack_bot_token.clone(),
+                self.resource_config.timezone.clone(),
+            )),
+            policy,
+        )))
+    }
+
+    /// 予約なしでのGPUの使用を検出するユースケースを組み立てる
+    ///
+    /// GPUの使用状況を取得するサーバーと警告の投稿先チャンネルの両方が設定されている場合のみ有効にする。
+    fn detect_unreserved_usecase<R: ResourceUsageRepository>(
+        &self,
+        repository: &Arc<R>,
+    ) -> Option<Arc<DetectUnreservedUsageUseCase<R>>> {
+        let monitor = self.gpu_process_monitor()?;
+        let Some(channel_id) = &self.resource_config.unreserved_usage_channel_id else {
+            tracing::warn!(
+                "GPU monitoring is configured, but unreserved_usage_channel_id is not set; unreserved usage detection is disabled"
+            );
+            return None;
+        };
+        Some(Arc::new(DetectUnreservedUsageUseCase::new(
+            repository.clone(),
+            monitor,
+            Arc::new(SlackUnreservedUsageNotifier::new(
+                self.app_config.slack_bot_token.clone(),
+                channel_id.clone(),
+            )),
+        )))
+    }
+
+    /// 週間の利用状況のまとめを投稿するユースケースを組み立てる
+    ///
+    /// `weekly_digest` が設定され、Slackに通知するサーバーがある場合のみ有効にする。
+    fn weekly_digest_usecase<R: ResourceUsageRepository>(
+        &self,
+        repository: &Arc<R>,
+    ) -> Option<Arc<WeeklyDigestUseCase<R>>> {
+        let config = self.resource_config.weekly_digest.as_ref()?;
+        let channels: Vec<DigestChannel> = self
+            .resource_config
+            .servers_by_slack_channel()
+            .into_iter()
+            .map(|(channel_id, servers)| DigestChannel {
+                channel_id,
+                servers: servers
+                    .iter()
+                    .map(|server| (server.name.clone(), server.devices.len() as u32))
+                    .collect(),
+            })
+            .collect();
+        if channels.is_empty() {
+            tracing::warn!(
+                "weekly_digest is configured, but no server notifies a Slack channel; the weekly digest is disabled"
+            );
+            return None;
+        }
+        Some(Arc::new(
+            WeeklyDigestUseCase::new(
+                repository.clone(),
+                Arc::new(SlackWeeklyDigestSender::new(
+                    self.app_config.slack_bot_token.clone(),
+                    self.resource_config.timezone.clone(),
+                )),
+                channels,
+            )
+            .with_schedule(config.weekday, config.hour)
+            .with_timezone(self.resource_config.timezone.as_deref())
+            .with_big_booking_gpu_time(chrono::Duration::hours(i64::from(
+                config.big_booking_gpu_hours,
+            ))),
+        ))
+    }
+
+    /// 名簿同期ユースケースと同期間隔を組み立てる（名簿が無い場合は `None`）
+    fn sync_members_usecase(
+        &self,
+        identity_repo: &Arc<dyn IdentityLinkRepository>,
+        grant_access_usecase: &Arc<GrantUserResourceAccessUseCase>,
+    ) -> Option<(Arc<SyncDirectoryMembersUseCase>, Duration)> {
+        let ldap_sync = self.app_config.ldap_sync.as_ref();
+        let member_directory: Arc<dyn MemberDirectory> = match &self.member_directory {
+            Some(member_directory) => member_directory.clone(),
+            None => Arc::new(LdapMemberDirectory::new(ldap_sync?.clone())),
+        };
+        let interval_secs = ldap_sync
+            .map(|ldap_sync| ldap_sync.sync_interval_secs)
+            .unwrap_or(defaults::LDAP_SYNC_INTERVAL_SECS);
+
+        let usecase = Arc::new(SyncDirectoryMembersUseCase::new(
+            member_directory,
+            Arc::new(SlackUserDirectory::new(&self.app_config.slack_bot_token)),
+            identity_repo.clone(),
+            grant_access_usecase.clone(),
+        ));
+        Some((usecase, Duration::from_secs(interval_secs)))
+    }
+
+    /// リーダー選出を組み立てる（設定が無い場合は `None`）
+    fn leader_election(&self) -> BuildResult<Option<Arc<dyn LeaderElection>>> {
+        let Some(config) = &self.app_config.leader_election else {
+            return Ok(None);
+        };
+        Ok(Some(match config {
+            LeaderElectionConfig::File { lock_file } => {
+                Arc::new(FileLockLeaderElection::new(lock_file.clone()))
+            }
+            LeaderElectionConfig::Redis { url, key } => {
+                // ポーリングが数回続けて失敗するまではリーダーを維持する
+                let lease = Duration::from_secs(self.app_config.polling_interval_secs * 3)
+                    .max(Duration::from_secs(30));
+                Arc::new(RedisLeaderElection::new(url, key, lease)?)
+            }
+        }))
+    }
+
+    /// Google Calendar APIの認証情報を読み込む
+    ///
+    /// OAuthが設定されていなければサービスアカウントキーを読み込む（キーの内容が設定されていればファイルより優先する）。
+    /// 代理で操作するユーザーが設定されていれば、ドメイン全体の委任で認証する。
+    async fn google_credentials(&self) -> BuildResult<GoogleCredentials> {
+        if let Some(oauth) = &self.app_config.google_oauth {
+            return Ok(GoogleCredentials::OAuth {
+                secret: yup_oauth2::read_application_secret(&oauth.client_secret_file).await?,
+                token_cache_file: oauth.token_cache_file.clone(),
+            });
+        }
+        let key = match &self.app_config.google_service_account_key {
+            Some(key) => yup_oauth2::parse_service_account_key(key)?,
+            None => {
+                yup_oauth2::read_service_account_key(
+                    &self.app_config.google_service_account_key_path,
+                )
+                .await?
+            }
+        };
+        Ok(match &self.app_config.google_delegated_user {
+            Some(subject) => GoogleCredentials::DomainWideDelegation {
+                key,
+                subject: subject.clone(),
+            },
+            None => GoogleCredentials::ServiceAccount(key),
+        })
+    }
+}
+
+/// 認可ポリシーに渡すメールアドレスを求める
+///
+/// SlackユーザーIDで登録されたユーザーは、起動時点のID紐付けからメールアドレスを求める。
+/// 紐付けが無い場合、そのユーザーは予約の更新・削除の権限を持たない（管理者用コマンドは実行できる）。
+///
+/// # Arguments
+/// * `emails` - メールアドレスで登録されたユーザー
+/// * `user_ids` - SlackユーザーIDで登録されたユーザー
+/// * `label` - ログに表示する登録先（例: `admin`）
+async fn resolve_emails(
+    mut emails: Vec<EmailAddress>,
+    user_ids: impl Iterator<Item = &str>,
+    label: &str,
+    identity_repo: &dyn IdentityLinkRepository,
+) -> Vec<EmailAddress> {
+    for user_id in user_ids {
+        match identity_repo
+            .find_by_external_user_id(&ExternalSystem::Slack, user_id)
+            .await
+        {
+            Ok(Some(identity_link)) => emails.push(identity_link.email().clone()),
+            Ok(None) => tracing::warn!(
+                "{} '{}' is not linked to an email address; skipping for reservation authorization",
+                label,
+                user_id
+            ),
+            Err(e) => tracing::warn!("Failed to resolve {} '{}': {}", label, user_id, e),
+        }
+    }
+    emails
+}
+
+/// 予約を所有できるチームを求める
+///
+/// `members` にSlackユーザーIDで登録されたメンバーは、管理者と同様に起動時点のID紐付けからメールアドレスを求める。
+async fn resolve_groups(
+    resource_config: &ResourceConfig,
+    identity_repo: &dyn IdentityLinkRepository,
+) -> Vec<Group> {
+    let mut groups = Vec::with_capacity(resource_config.groups.len());
+    for group in &resource_config.groups {
+        let members = resolve_emails(
+            group.member_emails(),
+            group.member_user_ids(),
+            &format!("Member of group '{}'", group.id),
+            identity_repo,
+        )
+        .await;
+        groups.push(Group::new(
+            GroupId::new(group.id.clone()),
+            group.name.clone(),
+            members,
+        ));
+    }
+    groups
+}
+
+/// 管理者・モデレーターに指定されたSlackユーザーグループを、起動時点のメンバーに展開する
+///
+/// メンバーを取得できなかったユーザーグループは、誰にも役割を与えない。
+async fn expand_user_groups(
+    mut resource_config: ResourceConfig,
+    bot_token: &str,
+) -> ResourceConfig {
+    let user_group_ids = resource_config.user_group_ids();
+    if user_group_ids.is_empty() {
+        return resource_config;
+    }
+
+    let directory = SlackUserDirectory::new(bot_token);
+    let mut members = HashMap::new();
+    for user_group_id in user_group_ids {
+        match directory.user_group_members(&user_group_id).await {
+            Ok(user_ids) => {
+                members.insert(user_group_id, user_ids);
+            }
+            Err(e) => tracing::warn!("{}", e),
+        }
+    }
+    resource_config.expand_user_groups(&members);
+    resource_config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::error::ApplicationError;
+    use crate::domain::aggregates::identity_link::IdentityLink;
+    use crate::domain::aggregates::identity_link::value_objects::ExternalIdentity;
+    use crate::domain::aggregates::resource_usage::value_objects::{
+        Gpu, Priority, ReservationMetadata, Resource, TimePeriod, Visibility,
+    };
+    use crate::domain::ports::gpu_monitor::{GpuMonitorError, GpuProcess};
+    use crate::infrastructure::repositories::identity_link::JsonFileIdentityLinkRepository;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use async_trait::async_trait;
+    use std::path::{Path, PathBuf};
+
+    const CONFIG: &str = r#"
+admins = ["U_ALICE"]
+rooms = []
+
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[groups]]
+id = "vision"
+name = "Vision"
+members = ["carol@example.com", "U_BOB", "U_UNLINKED"]
+"#;
+
+    struct NoProcesses;
+
+    #[async_trait]
+    impl GpuProcessMonitor for NoProcesses {
+        fn monitored_servers(&self) -> Vec<String> {
+            vec!["Thalys".to_string()]
+        }
+
+        async fn processes(&self, _server: &str) -> Result<Vec<GpuProcess>, GpuMonitorError> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("lrm_builder_{}", uuid::Uuid::new_v4()))
+    }
+
+    /// 状態ファイルをすべて `dir` に置く設定
+    fn app_config(dir: &Path) -> AppConfig {
+        AppConfig {
+            google_service_account_key_path: dir.join("service-account.json"),
+            google_service_account_key: None,
+            google_delegated_user: None,
+            google_oauth: None,
+            slack_bot_token: "xoxb-test".to_string(),
+            slack_app_token: "xapp-test".to_string(),
+            slack_enterprise_grid: false,
+            resource_config_path: dir.join("resources.toml"),
+            identity_links_file: dir.join("identity_links.json"),
+            identity_link_audit_file: dir.join("identity_link_audit.jsonl"),
+            calendar_mappings_file: dir.join("calendar_mappings.json"),
+            resource_freezes_file: dir.join("resource_freezes.json"),
+            device_statuses_file: dir.join("device_statuses.json"),
+            sent_reminders_file: dir.join("sent_reminders.json"),
+            usage_snapshot_file: dir.join("usage_snapshot.json"),
+            waitlist_file: dir.join("waitlist.json"),
+            workspace_tokens_file: dir.join("workspace_tokens.json"),
+            api_tokens_file: dir.join("api_tokens"),
+            polling_interval_secs: 60,
+            ldap_sync: None,
+            slack_oauth: None,
+            leader_election: None,
+            http_api: None,
+            google_sheets_export: None,
+            notion_sync: None,
+        }
+    }
+
+    fn email(name: &str) -> EmailAddress {
+        EmailAddress::new(format!("{}@example.com", name)).unwrap()
+    }
+
+    /// aliceとbobがSlackと紐付いているID紐付けリポジトリ
+    async fn identity_repo(dir: &Path) -> Arc<dyn IdentityLinkRepository> {
+        let repo = JsonFileIdentityLinkRepository::new(dir.join("identity_links.json"));
+        for (name, user_id) in [("alice", "U_ALICE"), ("bob", "U_BOB")] {
+            let mut link = IdentityLink::new(email(name));
+            link.link_external_identity(ExternalIdentity::new(
+                ExternalSystem::Slack,
+                user_id.to_string(),
+            ))
+            .unwrap();
+            repo.save(link).await.unwrap();
+        }
+        Arc::new(repo)
+    }
+
+    fn builder(dir: &Path, config: &str) -> LabResourceManagerBuilder {
+        // Slackのクライアントの作成に必要
+        rustls::crypto::ring::default_provider()
+            .install_default()
+            .ok();
+        LabResourceManagerBuilder::new(app_config(dir), toml::from_str(config).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_resolves_slack_user_ids_to_emails() {
+        let dir = temp_dir();
+        let identity_repo = identity_repo(&dir).await;
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+
+        let admins = resolve_emails(
+            config.admin_emails(),
+            config.admin_user_ids(),
+            "Admin",
+            identity_repo.as_ref(),
+        )
+        .await;
+        assert_eq!(admins, vec![email("alice")]);
+
+        // 紐付けの無いユーザーIDは除く
+        let groups = resolve_groups(&config, identity_repo.as_ref()).await;
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].id(), &GroupId::new("vision".to_string()));
+        assert_eq!(groups[0].members(), &[email("carol"), email("bob")]);
+    }
+
+    #[tokio::test]
+    async fn test_reservation_usecases_apply_resolved_roles() {
+        let dir = temp_dir();
+        let usecases = builder(&dir, CONFIG)
+            .with_identity_repository(identity_repo(&dir).await)
+            .reservation_usecases(MockUsageRepository::new())
+            .await
+            .unwrap();
+        let start = chrono::Utc::now() + chrono::Duration::days(1);
+        let period = TimePeriod::new(start, start + chrono::Duration::hours(2)).unwrap();
+        let gpu = Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string()));
+
+        let id = usecases
+            .create
+            .execute(
+                email("dave"),
+                period.clone(),
+                vec![gpu.clone()],
+                None,
+                ReservationMetadata::default(),
+                Visibility::default(),
+                Priority::default(),
+                None,
+            )
+            .await
+            .unwrap();
+        let report = usecases
+            .availability
+            .execute(&period, None, None)
+            .await
+            .unwrap();
+        assert!(!report.get(&gpu).unwrap().is_free());
+
+        // SlackユーザーIDで登録した管理者は他人の予約を取り消せる
+        assert!(matches!(
+            usecases.delete.execute(&id, &email("bob")).await,
+            Err(ApplicationError::Unauthorized(_))
+        ));
+        usecases.delete.execute(&id, &email("alice")).await.unwrap();
+        assert!(usecases.get.execute(&id).await.is_err());
+    }
+
+    #[test]
+    fn test_optional_usecases_require_configuration() {
+        let dir = temp_dir();
+        let repository = Arc::new(MockUsageRepository::new());
+        let identity_repo: Arc<dyn IdentityLinkRepository> = Arc::new(
+            JsonFileIdentityLinkRepository::new(dir.join("identity_links.json")),
+        );
+
+        let unconfigured = builder(&dir, CONFIG);
+        assert!(unconfigured.gpu_process_monitor().is_none());
+        assert!(
+            unconfigured
+                .auto_release_usecase(&repository, &identity_repo)
+                .is_none()
+        );
+        assert!(
+            unconfigured
+                .detect_unreserved_usecase(&repository)
+                .is_none()
+        );
+        assert!(unconfigured.weekly_digest_usecase(&repository).is_none());
+        assert!(unconfigured.leader_election().unwrap().is_none());
+
+        // GPUの使用状況を取得できても、解放のポリシーと警告の投稿先が無ければ有効にしない
+        let monitored = builder(&dir, CONFIG).with_gpu_monitor(Arc::new(NoProcesses));
+        assert!(monitored.gpu_process_monitor().is_some());
+        assert!(
+            monitored
+                .auto_release_usecase(&repository, &identity_repo)
+                .is_none()
+        );
+        assert!(monitored.detect_unreserved_usecase(&repository).is_none());
+
+        let configured = builder(
+            &dir,
+            &format!(
+                "unreserved_usage_channel_id = \"C_OPS\"\n{}\n[idle_release]\nidle_minutes = 30\n",
+                CONFIG
+            ),
+        )
+        .with_gpu_monitor(Arc::new(NoProcesses));
+        assert!(
+            configured
+                .auto_release_usecase(&repository, &identity_repo)
+                .is_some()
+        );
+        assert!(configured.detect_unreserved_usecase(&repository).is_some());
+    }
+}
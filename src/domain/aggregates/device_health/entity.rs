@@ -0,0 +1,172 @@
+use super::errors::DeviceHealthError;
+use crate::domain::aggregates::resource_usage::value_objects::Resource;
+use std::fmt;
+use std::str::FromStr;
+
+/// デバイスの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DeviceStatus {
+    /// 通常どおり使用できる
+    #[default]
+    Available,
+    /// 使用できるが、性能低下などの問題がある
+    Degraded,
+    /// 故障などで使用できない（予約を受け付けない）
+    OutOfService,
+}
+
+impl DeviceStatus {
+    /// すべての状態
+    pub const ALL: [DeviceStatus; 3] = [Self::Available, Self::Degraded, Self::OutOfService];
+
+    /// 保存・コマンドの引数に使う識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Available => "available",
+            Self::Degraded => "degraded",
+            Self::OutOfService => "out-of-service",
+        }
+    }
+}
+
+impl FromStr for DeviceStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or(())
+    }
+}
+
+impl fmt::Display for DeviceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Available => write!(f, "使用可能"),
+            Self::Degraded => write!(f, "性能低下"),
+            Self::OutOfService => write!(f, "使用停止"),
+        }
+    }
+}
+
+/// デバイスの状態を管理する集約ルート
+///
+/// サーバー名とデバイス番号を識別子とする。
+/// 使用停止中のデバイスは予約モーダルに表示されず、新しい予約も受け付けない。
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceHealth {
+    /// サーバー名
+    server_name: String,
+    /// デバイス番号
+    device_id: u32,
+    /// デバイスの状態
+    status: DeviceStatus,
+    /// 状態の補足（故障の内容など）
+    note: Option<String>,
+}
+
+impl DeviceHealth {
+    /// 新しいデバイスの状態を作成
+    ///
+    /// # Arguments
+    /// * `server_name` - サーバー名
+    /// * `device_id` - デバイス番号
+    /// * `status` - デバイスの状態
+    /// * `note` - 状態の補足
+    pub fn new(
+        server_name: String,
+        device_id: u32,
+        status: DeviceStatus,
+        note: Option<String>,
+    ) -> Self {
+        Self {
+            server_name,
+            device_id,
+            status,
+            note,
+        }
+    }
+
+    /// 指定したリソースがこのデバイスかどうか
+    pub fn applies_to(&self, resource: &Resource) -> bool {
+        matches!(
+            resource,
+            Resource::Gpu(gpu)
+                if gpu.server() == self.server_name && gpu.device_number() == self.device_id
+        )
+    }
+
+    /// 指定したリソースを予約できるかを確認する
+    ///
+    /// # Errors
+    /// * `DeviceHealthError::OutOfService` - 使用停止中で、リソースにこのデバイスを含む場合
+    pub fn check(&self, resources: &[Resource]) -> Result<(), DeviceHealthError> {
+        if self.status == DeviceStatus::OutOfService && resources.iter().any(|r| self.applies_to(r))
+        {
+            return Err(DeviceHealthError::OutOfService {
+                server_name: self.server_name.clone(),
+                device_id: self.device_id,
+                note: self.note.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// サーバー名を取得
+    pub fn server_name(&self) -> &str {
+        &self.server_name
+    }
+
+    /// デバイス番号を取得
+    pub fn device_id(&self) -> u32 {
+        self.device_id
+    }
+
+    /// デバイスの状態を取得
+    pub fn status(&self) -> DeviceStatus {
+        self.status
+    }
+
+    /// 状態の補足を取得
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+
+    fn gpu(server: &str, device: u32) -> Resource {
+        Resource::Gpu(Gpu::new(server.to_string(), device, "A100".to_string()))
+    }
+
+    #[test]
+    fn test_check_rejects_only_out_of_service_device() {
+        let broken = DeviceHealth::new(
+            "Thalys".to_string(),
+            1,
+            DeviceStatus::OutOfService,
+            Some("ファン故障".to_string()),
+        );
+
+        assert!(broken.check(&[gpu("Thalys", 0)]).is_ok());
+        assert!(broken.check(&[gpu("Freccia", 1)]).is_ok());
+        assert!(matches!(
+            broken.check(&[gpu("Thalys", 0), gpu("Thalys", 1)]),
+            Err(DeviceHealthError::OutOfService { device_id: 1, .. })
+        ));
+
+        let degraded = DeviceHealth::new("Thalys".to_string(), 1, DeviceStatus::Degraded, None);
+        assert!(degraded.check(&[gpu("Thalys", 1)]).is_ok());
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!("out-of-service".parse(), Ok(DeviceStatus::OutOfService));
+        assert_eq!(" Degraded ".parse(), Ok(DeviceStatus::Degraded));
+        assert_eq!("broken".parse::<DeviceStatus>(), Err(()));
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt;
+
+/// DeviceHealth集約のドメインエラー型
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeviceHealthError {
+    /// 使用停止中のデバイスを予約しようとした
+    OutOfService {
+        /// サーバー名
+        server_name: String,
+        /// デバイス番号
+        device_id: u32,
+        /// 使用停止の理由
+        note: Option<String>,
+    },
+}
+
+impl fmt::Display for DeviceHealthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfService {
+                server_name,
+                device_id,
+                note,
+            } => {
+                write!(
+                    f,
+                    "{} のデバイス {} は使用停止中のため予約できません",
+                    server_name, device_id
+                )?;
+                if let Some(note) = note {
+                    write!(f, "（理由: {}）", note)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeviceHealthError {}
+
+impl crate::domain::errors::DomainError for DeviceHealthError {}
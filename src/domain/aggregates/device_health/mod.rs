@@ -0,0 +1,15 @@
+//! # DeviceHealth集約
+//!
+//! GPUの故障や性能低下など、デバイスごとの状態を管理する集約です。
+//!
+//! ## 集約ルート
+//!
+//! `DeviceHealth`エンティティが集約ルートとして機能し、1つのデバイスの状態を表します。
+
+/// DeviceHealth集約のエンティティ定義
+pub mod entity;
+/// DeviceHealth集約のエラー型
+pub mod errors;
+
+pub use entity::{DeviceHealth, DeviceStatus};
+pub use errors::DeviceHealthError;
@@ -0,0 +1,90 @@
+use crate::domain::common::EmailAddress;
+
+/// チームの識別子
+///
+/// 設定ファイルで指定する短い名前（例: `nlp`）をそのまま使う。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GroupId(String);
+
+impl GroupId {
+    /// ID文字列からGroupIdを作成
+    ///
+    /// # Arguments
+    /// * `id` - ID文字列
+    pub fn new(id: String) -> Self {
+        Self(id)
+    }
+
+    /// 文字列表現を取得
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// 研究室内のチームを表す集約ルート
+///
+/// チームが所有する予約は、メンバーの誰でも更新・削除できる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    /// チームの識別子
+    id: GroupId,
+    /// 表示名（例: 「NLPグループ」）
+    name: String,
+    /// メンバーのメールアドレス
+    members: Vec<EmailAddress>,
+}
+
+impl Group {
+    /// 新しいチームを作成
+    ///
+    /// # Arguments
+    /// * `id` - チームの識別子
+    /// * `name` - 表示名
+    /// * `members` - メンバーのメールアドレス
+    pub fn new(id: GroupId, name: String, members: Vec<EmailAddress>) -> Self {
+        Self { id, name, members }
+    }
+
+    /// チームの識別子を取得
+    pub fn id(&self) -> &GroupId {
+        &self.id
+    }
+
+    /// 表示名を取得
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// メンバーのメールアドレスを取得
+    pub fn members(&self) -> &[EmailAddress] {
+        &self.members
+    }
+
+    /// 指定したユーザーがメンバーかどうか（大文字小文字は区別しない）
+    pub fn is_member(&self, email: &EmailAddress) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.as_str().eq_ignore_ascii_case(email.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn email(s: &str) -> EmailAddress {
+        EmailAddress::new(s.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_is_member_ignores_case() {
+        let group = Group::new(
+            GroupId::new("nlp".to_string()),
+            "NLPグループ".to_string(),
+            vec![email("Alice@Example.com")],
+        );
+
+        assert!(group.is_member(&email("alice@example.com")));
+        assert!(!group.is_member(&email("bob@example.com")));
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// Group集約のドメインエラー型
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupError {
+    /// 存在しないチームを指定した
+    NotFound {
+        /// チームID
+        group_id: String,
+    },
+    /// メンバーでないチームを予約の所有者にしようとした
+    NotMember {
+        /// チーム名
+        group_name: String,
+    },
+}
+
+impl fmt::Display for GroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { group_id } => write!(f, "チーム {} が見つかりません", group_id),
+            Self::NotMember { group_name } => write!(
+                f,
+                "{} のメンバーではないため、このチームの予約にはできません",
+                group_name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GroupError {}
+
+impl crate::domain::errors::DomainError for GroupError {}
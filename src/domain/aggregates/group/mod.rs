@@ -0,0 +1,16 @@
+//! # Group集約
+//!
+//! 「NLPグループ」などの研究室内のチームと、そのメンバーを管理する集約です。
+//!
+//! ## 集約ルート
+//!
+//! `Group`エンティティが集約ルートとして機能し、1つのチームを表します。
+//! チームが所有する予約は、メンバーの誰でも更新・削除できます。
+
+/// Group集約のエンティティ定義
+pub mod entity;
+/// Group集約のエラー型
+pub mod errors;
+
+pub use entity::{Group, GroupId};
+pub use errors::GroupError;
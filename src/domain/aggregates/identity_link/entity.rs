@@ -63,11 +63,13 @@ impl IdentityLink {
     }
 
     /// 外部システムの識別情報を追加
+    ///
+    /// 同じ外部システムでも、ワークスペースが異なれば複数の識別情報を紐付けられる。
     pub fn link_external_identity(
         &mut self,
         identity: ExternalIdentity,
     ) -> Result<(), IdentityLinkError> {
-        if self.has_identity_for_system(identity.system()) {
+        if self.has_identity_for_workspace(identity.system(), identity.workspace_id()) {
             return Err(IdentityLinkError::IdentityAlreadyExists {
                 system: identity.system().clone(),
             });
@@ -103,6 +105,46 @@ impl IdentityLink {
             .find(|id| id.system() == system)
     }
 
+    /// 特定の外部システムでの識別情報をすべて取得
+    ///
+    /// ワークスペースごとに紐付けている場合は複数になる。
+    pub fn identities_for_system<'a>(
+        &'a self,
+        system: &'a ExternalSystem,
+    ) -> impl Iterator<Item = &'a ExternalIdentity> {
+        self.external_identities
+            .iter()
+            .filter(move |id| id.system() == system)
+    }
+
+    /// 特定の外部システムの、指定ワークスペースで有効な識別情報を取得
+    ///
+    /// ワークスペースが一致する識別情報を優先し、なければワークスペース未指定のものを返す。
+    pub fn get_identity_for_workspace(
+        &self,
+        system: &ExternalSystem,
+        workspace_id: Option<&str>,
+    ) -> Option<&ExternalIdentity> {
+        let candidates = || {
+            self.external_identities
+                .iter()
+                .filter(move |id| id.system() == system && id.is_valid_in(workspace_id))
+        };
+        candidates()
+            .find(|id| workspace_id.is_some() && id.workspace_id() == workspace_id)
+            .or_else(|| candidates().next())
+    }
+
+    /// 特定の外部システムの、指定ワークスペースで有効な識別情報と紐付けられているか
+    pub fn has_identity_for_workspace(
+        &self,
+        system: &ExternalSystem,
+        workspace_id: Option<&str>,
+    ) -> bool {
+        self.get_identity_for_workspace(system, workspace_id)
+            .is_some()
+    }
+
     /// 特定の外部システムと紐付けられているか
     pub fn has_identity_for_system(&self, system: &ExternalSystem) -> bool {
         self.external_identities
@@ -163,6 +205,42 @@ mod tests {
         assert!(identity.has_identity_for_system(&ExternalSystem::Slack));
     }
 
+    #[test]
+    fn test_link_multiple_systems() {
+        let email = EmailAddress::new("user@example.com".to_string()).unwrap();
+        let mut identity = IdentityLink::new(email);
+
+        for (system, user_id) in [
+            (ExternalSystem::Slack, "U12345678"),
+            (ExternalSystem::Discord, "123456789012345678"),
+            (ExternalSystem::GitHub, "octocat"),
+        ] {
+            identity
+                .link_external_identity(ExternalIdentity::new(system, user_id.to_string()))
+                .unwrap();
+        }
+
+        assert_eq!(identity.external_identities().len(), 3);
+        assert_eq!(
+            identity
+                .get_identity_for_system(&ExternalSystem::GitHub)
+                .map(|id| id.user_id()),
+            Some("octocat")
+        );
+
+        identity
+            .unlink_external_identity(&ExternalSystem::Discord)
+            .unwrap();
+        assert!(!identity.has_identity_for_system(&ExternalSystem::Discord));
+        assert!(identity.has_identity_for_system(&ExternalSystem::Slack));
+        assert_eq!(
+            identity
+                .identities_for_system(&ExternalSystem::Slack)
+                .count(),
+            1
+        );
+    }
+
     #[test]
     fn test_link_duplicate_system() {
         let email = EmailAddress::new("user@example.com".to_string()).unwrap();
@@ -196,6 +274,64 @@ mod tests {
         assert!(!identity.has_identity_for_system(&ExternalSystem::Slack));
     }
 
+    #[test]
+    fn test_link_same_system_in_different_workspaces() {
+        let email = EmailAddress::new("user@example.com".to_string()).unwrap();
+        let mut identity = IdentityLink::new(email);
+
+        identity
+            .link_external_identity(
+                ExternalIdentity::new(ExternalSystem::Slack, "U111".to_string())
+                    .with_workspace_id("T111"),
+            )
+            .unwrap();
+        identity
+            .link_external_identity(
+                ExternalIdentity::new(ExternalSystem::Slack, "U222".to_string())
+                    .with_workspace_id("T222"),
+            )
+            .unwrap();
+
+        let duplicate = identity.link_external_identity(
+            ExternalIdentity::new(ExternalSystem::Slack, "U333".to_string())
+                .with_workspace_id("T111"),
+        );
+        assert!(duplicate.is_err());
+
+        let found = identity
+            .get_identity_for_workspace(&ExternalSystem::Slack, Some("T222"))
+            .unwrap();
+        assert_eq!(found.user_id(), "U222");
+        assert!(
+            identity
+                .get_identity_for_workspace(&ExternalSystem::Slack, Some("T999"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_identity_without_workspace_is_valid_everywhere() {
+        let email = EmailAddress::new("user@example.com".to_string()).unwrap();
+        let mut identity = IdentityLink::new(email);
+
+        identity
+            .link_external_identity(ExternalIdentity::new(
+                ExternalSystem::Slack,
+                "U111".to_string(),
+            ))
+            .unwrap();
+
+        let found = identity.get_identity_for_workspace(&ExternalSystem::Slack, Some("T111"));
+        assert_eq!(found.map(|id| id.user_id()), Some("U111"));
+
+        // ワークスペース未指定の識別情報があれば、別のワークスペースでの追加は重複になる
+        let result = identity.link_external_identity(
+            ExternalIdentity::new(ExternalSystem::Slack, "U222".to_string())
+                .with_workspace_id("T222"),
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_get_identity_for_system() {
         let email = EmailAddress::new("user@example.com".to_string()).unwrap();
@@ -0,0 +1,135 @@
+use super::ExternalSystem;
+use crate::domain::common::EmailAddress;
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// 紐付けの操作の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentityLinkAction {
+    /// 紐付け
+    Linked,
+    /// 紐付けの解除
+    Unlinked,
+}
+
+impl IdentityLinkAction {
+    /// 文字列表現を取得
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Linked => "linked",
+            Self::Unlinked => "unlinked",
+        }
+    }
+}
+
+impl std::str::FromStr for IdentityLinkAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linked" => Ok(Self::Linked),
+            "unlinked" => Ok(Self::Unlinked),
+            _ => Err(format!("Unknown identity link action: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for IdentityLinkAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linked => write!(f, "紐付け"),
+            Self::Unlinked => write!(f, "紐付け解除"),
+        }
+    }
+}
+
+/// IdentityLinkの監査記録の1件
+///
+/// 誰が・いつ・どのメールアドレスと外部システムのユーザーを紐付け（解除）したかを表す。
+/// 記録は追記のみで、変更・削除されない。
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentityLinkAuditEntry {
+    action: IdentityLinkAction,
+    email: EmailAddress,
+    system: ExternalSystem,
+    external_user_id: String,
+    /// 操作した外部システム上のユーザーID（`None` の場合は名簿同期などによる自動処理）
+    actor_id: Option<String>,
+    occurred_at: DateTime<Utc>,
+}
+
+impl IdentityLinkAuditEntry {
+    /// 現在時刻の監査記録を作成
+    ///
+    /// # Arguments
+    /// * `action` - 操作の種類
+    /// * `email` - 対象のメールアドレス
+    /// * `system` - 対象の外部システム
+    /// * `external_user_id` - 対象の外部システム上のユーザーID
+    /// * `actor_id` - 操作した外部システム上のユーザーID（自動処理の場合は `None`）
+    pub fn new(
+        action: IdentityLinkAction,
+        email: EmailAddress,
+        system: ExternalSystem,
+        external_user_id: String,
+        actor_id: Option<String>,
+    ) -> Self {
+        Self {
+            action,
+            email,
+            system,
+            external_user_id,
+            actor_id,
+            occurred_at: Utc::now(),
+        }
+    }
+
+    /// 永続化層からの復元用
+    pub(crate) fn reconstitute(
+        action: IdentityLinkAction,
+        email: EmailAddress,
+        system: ExternalSystem,
+        external_user_id: String,
+        actor_id: Option<String>,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            action,
+            email,
+            system,
+            external_user_id,
+            actor_id,
+            occurred_at,
+        }
+    }
+
+    /// 操作の種類を取得
+    pub fn action(&self) -> IdentityLinkAction {
+        self.action
+    }
+
+    /// 対象のメールアドレスを取得
+    pub fn email(&self) -> &EmailAddress {
+        &self.email
+    }
+
+    /// 対象の外部システムを取得
+    pub fn system(&self) -> &ExternalSystem {
+        &self.system
+    }
+
+    /// 対象の外部システム上のユーザーIDを取得
+    pub fn external_user_id(&self) -> &str {
+        &self.external_user_id
+    }
+
+    /// 操作した外部システム上のユーザーIDを取得
+    pub fn actor_id(&self) -> Option<&str> {
+        self.actor_id.as_deref()
+    }
+
+    /// 操作日時を取得
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+}
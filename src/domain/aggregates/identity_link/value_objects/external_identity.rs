@@ -9,6 +9,11 @@ pub struct ExternalIdentity {
     system: ExternalSystem,
     /// 外部システムでのユーザーID
     user_id: String,
+    /// 外部システム上のワークスペースID（SlackのTeam IDなど）
+    ///
+    /// `None` の場合はワークスペースを問わず有効。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    workspace_id: Option<String>,
     /// 紐付けた日時
     linked_at: DateTime<Utc>,
 }
@@ -23,19 +28,30 @@ impl ExternalIdentity {
         Self {
             system,
             user_id,
+            workspace_id: None,
             linked_at: Utc::now(),
         }
     }
 
+    /// ワークスペースに限定した識別情報にする
+    ///
+    /// Slack Enterprise Gridのように、1つの外部システムに複数のワークスペースがある場合に使用する。
+    pub fn with_workspace_id(mut self, workspace_id: impl Into<String>) -> Self {
+        self.workspace_id = Some(workspace_id.into());
+        self
+    }
+
     /// 永続化層からの復元用
     pub(crate) fn reconstitute(
         system: ExternalSystem,
         user_id: String,
+        workspace_id: Option<String>,
         linked_at: DateTime<Utc>,
     ) -> Self {
         Self {
             system,
             user_id,
+            workspace_id,
             linked_at,
         }
     }
@@ -50,6 +66,21 @@ impl ExternalIdentity {
         &self.user_id
     }
 
+    /// ワークスペースIDを取得
+    pub fn workspace_id(&self) -> Option<&str> {
+        self.workspace_id.as_deref()
+    }
+
+    /// 指定したワークスペースで有効な識別情報かどうか
+    ///
+    /// どちらかのワークスペースが未指定の場合は有効とみなす。
+    pub fn is_valid_in(&self, workspace_id: Option<&str>) -> bool {
+        match (self.workspace_id.as_deref(), workspace_id) {
+            (Some(own), Some(other)) => own == other,
+            _ => true,
+        }
+    }
+
     /// 紐付けた日時を取得
     pub fn linked_at(&self) -> DateTime<Utc> {
         self.linked_at
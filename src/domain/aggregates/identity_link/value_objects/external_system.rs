@@ -7,6 +7,11 @@ use std::str::FromStr;
 pub enum ExternalSystem {
     /// Slack
     Slack,
+    /// Discord
+    Discord,
+    /// GitHub
+    #[serde(rename = "github")]
+    GitHub,
 }
 
 impl ExternalSystem {
@@ -14,6 +19,8 @@ impl ExternalSystem {
     pub fn as_str(&self) -> &str {
         match self {
             ExternalSystem::Slack => "slack",
+            ExternalSystem::Discord => "discord",
+            ExternalSystem::GitHub => "github",
         }
     }
 }
@@ -24,7 +31,37 @@ impl FromStr for ExternalSystem {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "slack" => Ok(ExternalSystem::Slack),
+            "discord" => Ok(ExternalSystem::Discord),
+            "github" => Ok(ExternalSystem::GitHub),
             _ => Err(format!("Unknown external system: {}", s)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_str_round_trip() {
+        for system in [
+            ExternalSystem::Slack,
+            ExternalSystem::Discord,
+            ExternalSystem::GitHub,
+        ] {
+            assert_eq!(
+                ExternalSystem::from_str(system.as_str()),
+                Ok(system.clone())
+            );
+            assert_eq!(
+                serde_json::to_string(&system).unwrap(),
+                format!("\"{}\"", system.as_str())
+            );
+        }
+        assert_eq!(
+            ExternalSystem::from_str("GitHub"),
+            Ok(ExternalSystem::GitHub)
+        );
+        assert!(ExternalSystem::from_str("teams").is_err());
+    }
+}
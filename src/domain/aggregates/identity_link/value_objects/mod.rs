@@ -1,5 +1,7 @@
+mod audit_entry;
 mod external_identity;
 mod external_system;
 
+pub use audit_entry::{IdentityLinkAction, IdentityLinkAuditEntry};
 pub use external_identity::ExternalIdentity;
 pub use external_system::ExternalSystem;
@@ -10,5 +10,9 @@
 //!
 //! 各サブディレクトリは一つの集約を表します。
 //! それぞれ集約に関連するエンティティ、値オブジェクト、リポジトリインターフェース、ドメインサービスを含みます。
+pub mod device_health;
+pub mod group;
 pub mod identity_link;
+pub mod resource_freeze;
 pub mod resource_usage;
+pub mod waitlist;
@@ -0,0 +1,169 @@
+use super::errors::ResourceFreezeError;
+use crate::domain::aggregates::resource_usage::{
+    entity::ResourceUsage,
+    value_objects::{Resource, TimePeriod},
+};
+use chrono::{DateTime, Utc};
+
+/// リソースの予約停止を管理する集約ルート
+///
+/// リソース名（サーバー名または部屋名）を識別子とし、
+/// 開始時刻以降にかかる予約を受け付けないようにする。
+/// サーバーを停止した場合は、そのサーバーのすべてのGPUが対象になる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceFreeze {
+    /// 予約を停止するリソース名（サーバー名または部屋名）
+    resource_name: String,
+    /// 予約停止の開始時刻
+    starts_at: DateTime<Utc>,
+    /// 予約停止の理由
+    reason: Option<String>,
+}
+
+impl ResourceFreeze {
+    /// 新しい予約停止を作成
+    ///
+    /// # Arguments
+    /// * `resource_name` - サーバー名または部屋名
+    /// * `starts_at` - 予約停止の開始時刻（未来の時刻）
+    /// * `reason` - 予約停止の理由
+    ///
+    /// # Errors
+    /// * `ResourceFreezeError::StartsInPast` - 開始時刻が過去の場合
+    pub fn new(
+        resource_name: String,
+        starts_at: DateTime<Utc>,
+        reason: Option<String>,
+    ) -> Result<Self, ResourceFreezeError> {
+        if starts_at <= Utc::now() {
+            return Err(ResourceFreezeError::StartsInPast { starts_at });
+        }
+
+        Ok(Self {
+            resource_name,
+            starts_at,
+            reason,
+        })
+    }
+
+    /// 永続化層からの復元
+    ///
+    /// **Repository実装専用**。開始時刻が過去になっていてもそのまま復元する。
+    pub(crate) fn reconstitute(
+        resource_name: String,
+        starts_at: DateTime<Utc>,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            resource_name,
+            starts_at,
+            reason,
+        }
+    }
+
+    /// 指定したリソースが予約停止の対象かどうか
+    pub fn applies_to(&self, resource: &Resource) -> bool {
+        resource.name() == self.resource_name
+    }
+
+    /// 指定した期間・リソースで予約できるかを確認する
+    ///
+    /// # Errors
+    /// * `ResourceFreezeError::Frozen` - 期間が開始時刻以降にかかり、対象のリソースを含む場合
+    pub fn check(
+        &self,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Result<(), ResourceFreezeError> {
+        if time_period.end() > self.starts_at && resources.iter().any(|r| self.applies_to(r)) {
+            return Err(ResourceFreezeError::Frozen {
+                resource_name: self.resource_name.clone(),
+                starts_at: self.starts_at,
+                reason: self.reason.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// 既存の予約が予約停止の影響を受けるかどうか
+    pub fn affects(&self, usage: &ResourceUsage) -> bool {
+        self.check(usage.time_period(), usage.resources()).is_err()
+    }
+
+    /// リソース名を取得
+    pub fn resource_name(&self) -> &str {
+        &self.resource_name
+    }
+
+    /// 予約停止の開始時刻を取得
+    pub fn starts_at(&self) -> DateTime<Utc> {
+        self.starts_at
+    }
+
+    /// 予約停止の理由を取得
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use chrono::Duration;
+
+    fn gpu(server: &str) -> Resource {
+        Resource::Gpu(Gpu::new(server.to_string(), 0, "A100".to_string()))
+    }
+
+    #[test]
+    fn test_new_rejects_past_start() {
+        let result =
+            ResourceFreeze::new("Thalys".to_string(), Utc::now() - Duration::hours(1), None);
+
+        assert!(matches!(
+            result,
+            Err(ResourceFreezeError::StartsInPast { .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_rejects_periods_past_freeze() {
+        let starts_at = Utc::now() + Duration::days(7);
+        let freeze = ResourceFreeze::new(
+            "Thalys".to_string(),
+            starts_at,
+            Some("クラスタ移行".to_string()),
+        )
+        .unwrap();
+
+        let before = TimePeriod::new(starts_at - Duration::hours(2), starts_at).unwrap();
+        let across = TimePeriod::new(
+            starts_at - Duration::hours(1),
+            starts_at + Duration::hours(1),
+        )
+        .unwrap();
+
+        assert!(freeze.check(&before, &[gpu("Thalys")]).is_ok());
+        assert!(freeze.check(&across, &[gpu("Freccia")]).is_ok());
+        assert!(matches!(
+            freeze.check(&across, &[gpu("Freccia"), gpu("Thalys")]),
+            Err(ResourceFreezeError::Frozen { .. })
+        ));
+    }
+
+    #[test]
+    fn test_applies_to_room() {
+        let freeze =
+            ResourceFreeze::new("会議室A".to_string(), Utc::now() + Duration::days(1), None)
+                .unwrap();
+
+        assert!(freeze.applies_to(&Resource::Room {
+            name: "会議室A".to_string()
+        }));
+        assert!(!freeze.applies_to(&Resource::Room {
+            name: "会議室B".to_string()
+        }));
+        assert!(!freeze.applies_to(&gpu("Thalys")));
+    }
+}
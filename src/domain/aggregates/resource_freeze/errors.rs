@@ -0,0 +1,62 @@
+use chrono::{DateTime, Local, Utc};
+use std::fmt;
+
+/// ResourceFreeze集約のドメインエラー型
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourceFreezeError {
+    /// 予約停止の開始時刻が過去
+    StartsInPast {
+        /// 指定された開始時刻
+        starts_at: DateTime<Utc>,
+    },
+    /// 予約停止中のリソースを停止開始以降に使用しようとした
+    Frozen {
+        /// 予約停止中のリソース名
+        resource_name: String,
+        /// 予約停止の開始時刻
+        starts_at: DateTime<Utc>,
+        /// 予約停止の理由
+        reason: Option<String>,
+    },
+}
+
+impl fmt::Display for ResourceFreezeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StartsInPast { starts_at } => {
+                write!(
+                    f,
+                    "予約停止の開始時刻 {} は過去の時刻です",
+                    format_timestamp(starts_at)
+                )
+            }
+            Self::Frozen {
+                resource_name,
+                starts_at,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "{} は {} 以降の予約を受け付けていません",
+                    resource_name,
+                    format_timestamp(starts_at)
+                )?;
+                if let Some(reason) = reason {
+                    write!(f, "（理由: {}）", reason)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+impl std::error::Error for ResourceFreezeError {}
+
+impl crate::domain::errors::DomainError for ResourceFreezeError {}
@@ -0,0 +1,15 @@
+//! # ResourceFreeze集約
+//!
+//! クラスタ移行などに備えて、リソースの新規予約を指定時刻以降停止する設定を管理する集約です。
+//!
+//! ## 集約ルート
+//!
+//! `ResourceFreeze`エンティティが集約ルートとして機能し、1つのリソースの予約停止を表します。
+
+/// ResourceFreeze集約のエンティティ定義
+pub mod entity;
+/// ResourceFreeze集約のエラー型
+pub mod errors;
+
+pub use entity::ResourceFreeze;
+pub use errors::ResourceFreezeError;
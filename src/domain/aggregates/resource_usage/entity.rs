@@ -1,6 +1,8 @@
 use super::errors::ResourceUsageError;
 use super::value_objects::*;
+use crate::domain::aggregates::group::GroupId;
 use crate::domain::common::EmailAddress;
+use chrono::{DateTime, Utc};
 
 /// リソース使用予定を表す集約ルート
 ///
@@ -12,6 +14,13 @@ pub struct ResourceUsage {
     time_period: TimePeriod,
     resources: Vec<Resource>,
     notes: Option<String>,
+    metadata: ReservationMetadata,
+    visibility: Visibility,
+    series_id: Option<SeriesId>,
+    recurrence: Option<RecurrenceRule>,
+    approval_status: ApprovalStatus,
+    priority: Priority,
+    group: Option<GroupId>,
 }
 
 impl ResourceUsage {
@@ -41,6 +50,13 @@ impl ResourceUsage {
             time_period,
             resources,
             notes,
+            metadata: ReservationMetadata::default(),
+            visibility: Visibility::default(),
+            series_id: None,
+            recurrence: None,
+            approval_status: ApprovalStatus::default(),
+            priority: Priority::default(),
+            group: None,
         })
     }
 
@@ -72,6 +88,13 @@ impl ResourceUsage {
             time_period,
             resources,
             notes,
+            metadata: ReservationMetadata::default(),
+            visibility: Visibility::default(),
+            series_id: None,
+            recurrence: None,
+            approval_status: ApprovalStatus::default(),
+            priority: Priority::default(),
+            group: None,
         })
     }
 
@@ -100,13 +123,247 @@ impl ResourceUsage {
         self.notes.as_ref()
     }
 
+    /// メタデータ（プロジェクト名・実験IDなど）を取得
+    pub fn metadata(&self) -> &ReservationMetadata {
+        &self.metadata
+    }
+
+    /// 公開範囲を取得
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    /// 繰り返し予約のシリーズIDを取得
+    ///
+    /// 繰り返し予約から作成されたものでない場合は `None`
+    pub fn series_id(&self) -> Option<&SeriesId> {
+        self.series_id.as_ref()
+    }
+
+    /// 繰り返し予約の規則を取得
+    ///
+    /// 繰り返し予約から作成されたものでない場合は `None`
+    pub fn recurrence(&self) -> Option<&RecurrenceRule> {
+        self.recurrence.as_ref()
+    }
+
+    /// 承認状態を取得
+    pub fn approval_status(&self) -> ApprovalStatus {
+        self.approval_status
+    }
+
+    /// 優先度を取得
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// 予約を所有するチームを取得
+    ///
+    /// 予約者個人の予約の場合は `None`
+    pub fn group(&self) -> Option<&GroupId> {
+        self.group.as_ref()
+    }
+
+    /// 公開範囲を指定する
+    ///
+    /// 作成・再構築時は公開（`Visibility::Public`）となるため、非公開にする場合に使う。
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// メタデータを指定する
+    ///
+    /// 作成・再構築時は未指定となるため、プロジェクト名などを記録する場合に使う。
+    pub fn with_metadata(mut self, metadata: ReservationMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// 詳細（予約者・備考）を閲覧できるかどうか
+    ///
+    /// 公開の予約は誰でも、非公開の予約は予約者本人と管理者のみが閲覧できる。
+    ///
+    /// # Arguments
+    /// * `viewer` - 閲覧者のメールアドレス（不特定多数に向けた表示の場合は `None`）
+    /// * `is_admin` - 閲覧者が管理者かどうか
+    pub fn details_visible_to(&self, viewer: Option<&EmailAddress>, is_admin: bool) -> bool {
+        !self.visibility.is_private() || is_admin || viewer == Some(&self.owner_email)
+    }
+
+    /// 繰り返し予約のシリーズIDを指定する
+    ///
+    /// 作成・再構築時はシリーズに属さないため、繰り返し予約の一部とする場合に使う。
+    pub fn with_series_id(mut self, series_id: Option<SeriesId>) -> Self {
+        self.series_id = series_id;
+        self
+    }
+
+    /// 繰り返し予約の規則を指定する
+    ///
+    /// 作成・再構築時は繰り返し予約でないため、繰り返し予約の一部とする場合にシリーズIDとあわせて使う。
+    pub fn with_recurrence(mut self, recurrence: Option<RecurrenceRule>) -> Self {
+        self.recurrence = recurrence;
+        self
+    }
+
+    /// 承認状態を指定する
+    ///
+    /// 作成・再構築時は承認済み（`ApprovalStatus::Approved`）となるため、承認待ちにする場合に使う。
+    pub fn with_approval_status(mut self, approval_status: ApprovalStatus) -> Self {
+        self.approval_status = approval_status;
+        self
+    }
+
+    /// 優先度を指定する
+    ///
+    /// 作成・再構築時は通常（`Priority::Normal`）となるため、それ以外にする場合に使う。
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// 予約を所有するチームを指定する
+    ///
+    /// 作成・再構築時は予約者個人の予約となるため、チームの予約にする場合に使う。
+    pub fn with_group(mut self, group: Option<GroupId>) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// 承認待ちの予約を承認する
+    ///
+    /// # Errors
+    /// 承認待ちでない場合、`ResourceUsageError::NotPendingApproval`を返す
+    pub fn approve(&mut self) -> Result<(), ResourceUsageError> {
+        if !self.approval_status.is_pending() {
+            return Err(ResourceUsageError::NotPendingApproval);
+        }
+        self.approval_status = ApprovalStatus::Approved;
+        Ok(())
+    }
+
     /// 使用期間を更新する
     pub fn update_time_period(&mut self, new_time_period: TimePeriod) {
         self.time_period = new_time_period;
     }
 
+    /// 使用中の予約を指定時刻で終了させる（早期解放）
+    ///
+    /// # Errors
+    /// 指定時刻に使用中でない（開始前または終了済み）場合、`ResourceUsageError::NotInProgress`を返す
+    pub fn truncate_at(&mut self, now: DateTime<Utc>) -> Result<(), ResourceUsageError> {
+        let period = &self.time_period;
+        if now <= period.start() || now >= period.end() {
+            return Err(ResourceUsageError::NotInProgress);
+        }
+        self.time_period = TimePeriod::new(period.start(), now)?;
+        Ok(())
+    }
+
     /// 備考を更新する
     pub fn update_notes(&mut self, notes: String) {
         self.notes = Some(notes);
     }
+
+    /// メタデータを更新する
+    pub fn update_metadata(&mut self, metadata: ReservationMetadata) {
+        self.metadata = metadata;
+    }
+
+    /// 公開範囲を更新する
+    pub fn update_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    /// 予約者を変更する（予約の引き継ぎ）
+    ///
+    /// # Errors
+    /// 引き継ぎ先が現在の予約者と同じ場合、`ResourceUsageError::SameOwner`を返す
+    pub fn transfer_to(&mut self, new_owner: EmailAddress) -> Result<(), ResourceUsageError> {
+        if self
+            .owner_email
+            .as_str()
+            .eq_ignore_ascii_case(new_owner.as_str())
+        {
+            return Err(ResourceUsageError::SameOwner);
+        }
+        self.owner_email = new_owner;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn usage() -> ResourceUsage {
+        let start = Utc.with_ymd_and_hms(2025, 4, 1, 10, 0, 0).unwrap();
+        ResourceUsage::new(
+            EmailAddress::new("owner@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, start + Duration::hours(4)).unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                0,
+                "A100".to_string(),
+            ))],
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_truncate_at_ends_ongoing_usage() {
+        let mut usage = usage();
+        let now = usage.time_period().start() + Duration::hours(1);
+
+        usage.truncate_at(now).unwrap();
+
+        assert_eq!(usage.time_period().end(), now);
+    }
+
+    #[test]
+    fn test_truncate_at_rejects_usage_not_in_progress() {
+        let mut usage = usage();
+        let start = usage.time_period().start();
+        let end = usage.time_period().end();
+
+        assert!(matches!(
+            usage.truncate_at(start - Duration::minutes(1)),
+            Err(ResourceUsageError::NotInProgress)
+        ));
+        assert!(matches!(
+            usage.truncate_at(end),
+            Err(ResourceUsageError::NotInProgress)
+        ));
+        assert_eq!(usage.time_period().end(), end);
+    }
+
+    #[test]
+    fn test_approve_pending_usage() {
+        let mut usage = usage().with_approval_status(ApprovalStatus::Pending);
+
+        usage.approve().unwrap();
+
+        assert_eq!(usage.approval_status(), ApprovalStatus::Approved);
+        assert!(matches!(
+            usage.approve(),
+            Err(ResourceUsageError::NotPendingApproval)
+        ));
+    }
+
+    #[test]
+    fn test_transfer_to_changes_owner() {
+        let mut usage = usage();
+        let labmate = EmailAddress::new("labmate@example.com".to_string()).unwrap();
+
+        usage.transfer_to(labmate.clone()).unwrap();
+
+        assert_eq!(usage.owner_email(), &labmate);
+        assert!(matches!(
+            usage.transfer_to(EmailAddress::new("LABMATE@example.com".to_string()).unwrap()),
+            Err(ResourceUsageError::SameOwner)
+        ));
+    }
 }
@@ -13,6 +13,16 @@ pub enum ResourceUsageError {
     },
     /// リソース項目が空
     NoResourceItems,
+    /// 無効な繰り返し規則
+    InvalidRecurrence(String),
+    /// 無効なメタデータ
+    InvalidMetadata(String),
+    /// 使用中でない（開始前または終了済みの）ため、早期終了できない
+    NotInProgress,
+    /// 承認待ちでないため、承認・却下できない
+    NotPendingApproval,
+    /// 引き継ぎ先が現在の予約者と同じ
+    SameOwner,
     /// リソース使用の競合
     UsageConflict {
         /// 競合しているリソース名
@@ -36,6 +46,21 @@ impl fmt::Display for ResourceUsageError {
             ResourceUsageError::NoResourceItems => {
                 write!(f, "資源項目エラー: 少なくとも1つの資源項目が必要です")
             }
+            ResourceUsageError::InvalidRecurrence(reason) => {
+                write!(f, "繰り返しエラー: {}", reason)
+            }
+            ResourceUsageError::InvalidMetadata(reason) => {
+                write!(f, "メタデータエラー: {}", reason)
+            }
+            ResourceUsageError::NotInProgress => {
+                write!(f, "使用中の予約ではないため、早期終了できません")
+            }
+            ResourceUsageError::NotPendingApproval => {
+                write!(f, "承認待ちの予約ではないため、承認・却下できません")
+            }
+            ResourceUsageError::SameOwner => {
+                write!(f, "引き継ぎ先が現在の予約者と同じです")
+            }
             ResourceUsageError::UsageConflict {
                 resource,
                 conflicting_user,
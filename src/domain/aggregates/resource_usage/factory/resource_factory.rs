@@ -45,6 +45,84 @@ impl ResourceFactory {
         Ok(resources)
     }
 
+    /// ストレージ指定記法から Resource を生成
+    ///
+    /// # 記法
+    /// - `"scratch:500G"` / `"scratch:500GB"` → scratch の 500GB
+    /// - `"scratch:2T"` / `"scratch:2TB"` → scratch の 2000GB（1TB = 1000GB）
+    ///
+    /// 単位の大文字・小文字は区別しない。
+    ///
+    /// # Arguments
+    /// * `spec` - ストレージ指定文字列
+    ///
+    /// # Returns
+    /// ストレージのResource
+    ///
+    /// # Errors
+    /// - ボリューム名・容量・単位が不正な場合
+    /// - 容量が0の場合
+    pub fn create_storage_from_spec(spec: &str) -> Result<Resource, ResourceFactoryError> {
+        let invalid = || ResourceFactoryError::InvalidStorageSpec(spec.to_string());
+
+        let (volume, size) = spec.trim().split_once(':').ok_or_else(invalid)?;
+        let volume = volume.trim();
+        let size = size.trim().to_ascii_uppercase();
+        if volume.is_empty() {
+            return Err(invalid());
+        }
+
+        let digits_end = size
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let (amount, unit) = size.split_at(digits_end);
+        let amount: u64 = amount.parse().map_err(|_| invalid())?;
+        let gigabytes = match unit {
+            "G" | "GB" => Some(amount),
+            "T" | "TB" => amount.checked_mul(1000),
+            _ => None,
+        }
+        .filter(|gigabytes| *gigabytes > 0)
+        .ok_or_else(invalid)?;
+
+        Ok(Resource::Storage {
+            volume: volume.to_string(),
+            gigabytes,
+        })
+    }
+
+    /// ライセンス指定記法から Resource を生成
+    ///
+    /// # 記法
+    /// - `"matlab"` → matlab の 1席
+    /// - `"matlab:3"` → matlab の 3席
+    ///
+    /// # Arguments
+    /// * `spec` - ライセンス指定文字列
+    ///
+    /// # Returns
+    /// ライセンスのResource
+    ///
+    /// # Errors
+    /// - ライセンス名・席数が不正な場合
+    /// - 席数が0の場合
+    pub fn create_license_from_spec(spec: &str) -> Result<Resource, ResourceFactoryError> {
+        let invalid = || ResourceFactoryError::InvalidLicenseSpec(spec.to_string());
+
+        let (name, seats) = match spec.trim().split_once(':') {
+            Some((name, seats)) => (name.trim(), seats.trim().parse().map_err(|_| invalid())?),
+            None => (spec.trim(), 1),
+        };
+        if name.is_empty() || seats == 0 {
+            return Err(invalid());
+        }
+
+        Ok(Resource::License {
+            name: name.to_string(),
+            seats,
+        })
+    }
+
     /// デバイス番号のパース（内部ヘルパー）
     ///
     /// "0-2,5,7-9" のような記法をパースして、個別のデバイス番号のリストに展開します。
@@ -129,6 +207,10 @@ pub enum ResourceFactoryError {
         /// デバイスID
         device_id: u32,
     },
+    /// 無効なストレージ指定
+    InvalidStorageSpec(String),
+    /// 無効なライセンス指定
+    InvalidLicenseSpec(String),
 }
 
 impl std::fmt::Display for ResourceFactoryError {
@@ -143,6 +225,12 @@ impl std::fmt::Display for ResourceFactoryError {
             ResourceFactoryError::DeviceNotFound { server, device_id } => {
                 write!(f, "デバイス{}が{}に存在しません", device_id, server)
             }
+            ResourceFactoryError::InvalidStorageSpec(s) => {
+                write!(f, "無効なストレージ指定: {}（例: scratch:500G）", s)
+            }
+            ResourceFactoryError::InvalidLicenseSpec(s) => {
+                write!(f, "無効なライセンス指定: {}（例: matlab:2）", s)
+            }
         }
     }
 }
@@ -237,4 +325,73 @@ mod tests {
             Err(ResourceFactoryError::DeviceNotFound { .. })
         ));
     }
+
+    #[test]
+    fn test_create_storage_from_spec() {
+        let storage = |volume: &str, gigabytes| Resource::Storage {
+            volume: volume.to_string(),
+            gigabytes,
+        };
+
+        assert_eq!(
+            ResourceFactory::create_storage_from_spec("scratch:500G").unwrap(),
+            storage("scratch", 500)
+        );
+        assert_eq!(
+            ResourceFactory::create_storage_from_spec(" scratch : 500gb ").unwrap(),
+            storage("scratch", 500)
+        );
+        assert_eq!(
+            ResourceFactory::create_storage_from_spec("archive:2T").unwrap(),
+            storage("archive", 2000)
+        );
+    }
+
+    #[test]
+    fn test_create_storage_from_invalid_spec() {
+        for spec in [
+            "scratch",
+            ":500G",
+            "scratch:500",
+            "scratch:G",
+            "scratch:0G",
+            "scratch:5P",
+        ] {
+            assert!(
+                matches!(
+                    ResourceFactory::create_storage_from_spec(spec),
+                    Err(ResourceFactoryError::InvalidStorageSpec(_))
+                ),
+                "{}",
+                spec
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_license_from_spec() {
+        let license = |name: &str, seats| Resource::License {
+            name: name.to_string(),
+            seats,
+        };
+
+        assert_eq!(
+            ResourceFactory::create_license_from_spec("matlab").unwrap(),
+            license("matlab", 1)
+        );
+        assert_eq!(
+            ResourceFactory::create_license_from_spec(" matlab : 3 ").unwrap(),
+            license("matlab", 3)
+        );
+        for spec in ["", ":2", "matlab:0", "matlab:x", "matlab:-1"] {
+            assert!(
+                matches!(
+                    ResourceFactory::create_license_from_spec(spec),
+                    Err(ResourceFactoryError::InvalidLicenseSpec(_))
+                ),
+                "{}",
+                spec
+            );
+        }
+    }
 }
@@ -75,7 +75,11 @@ pub fn format_resource_item(item: &Resource) -> String {
                 spec.device_number()
             )
         }
-        Resource::Room { name } => name.clone(),
+        Resource::Room { name } | Resource::Instrument { name } | Resource::Custom { name, .. } => {
+            name.clone()
+        }
+        Resource::Storage { volume, gigabytes } => format!("{} / {}GB", volume, gigabytes),
+        Resource::License { name, seats } => format!("{} / {}席", name, seats),
     }
 }
 
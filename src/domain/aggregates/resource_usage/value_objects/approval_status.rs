@@ -0,0 +1,30 @@
+use std::fmt;
+
+/// リソース使用予定の承認状態
+///
+/// 承認が必要な部屋の予約は、承認者が承認するまで「承認待ち」となり、
+/// カレンダー上では仮の予定として扱われる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ApprovalStatus {
+    /// 承認済み（承認が不要な予約を含む）
+    #[default]
+    Approved,
+    /// 承認待ち
+    Pending,
+}
+
+impl ApprovalStatus {
+    /// 承認待ちかどうか
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+}
+
+impl fmt::Display for ApprovalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Approved => write!(f, "承認済み"),
+            Self::Pending => write!(f, "承認待ち"),
+        }
+    }
+}
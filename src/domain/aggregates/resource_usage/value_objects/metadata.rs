@@ -0,0 +1,104 @@
+use crate::domain::aggregates::resource_usage::errors::ResourceUsageError;
+
+/// 予約のメタデータ（プロジェクト名・実験ID・想定使用率）
+///
+/// 備考と異なり項目ごとに保存するため、プロジェクト別の集計などに使える。
+/// 各項目は1行の文字列に正規化し、空の項目は未指定として扱う。
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReservationMetadata {
+    project: Option<String>,
+    experiment_id: Option<String>,
+    expected_utilization: Option<u8>,
+}
+
+impl ReservationMetadata {
+    /// 想定使用率の上限（%）
+    pub const MAX_UTILIZATION: u8 = 100;
+
+    /// 新しいメタデータを作成する
+    ///
+    /// # Arguments
+    /// * `project` - プロジェクト名
+    /// * `experiment_id` - 実験ID
+    /// * `expected_utilization` - 想定使用率（%）
+    ///
+    /// # Errors
+    /// 想定使用率が100%を超える場合、`ResourceUsageError::InvalidMetadata`を返す
+    pub fn new(
+        project: Option<String>,
+        experiment_id: Option<String>,
+        expected_utilization: Option<u8>,
+    ) -> Result<Self, ResourceUsageError> {
+        if let Some(utilization) = expected_utilization
+            && utilization > Self::MAX_UTILIZATION
+        {
+            return Err(ResourceUsageError::InvalidMetadata(format!(
+                "想定使用率は0〜{}%で指定してください",
+                Self::MAX_UTILIZATION
+            )));
+        }
+        Ok(Self {
+            project: normalize(project),
+            experiment_id: normalize(experiment_id),
+            expected_utilization,
+        })
+    }
+
+    /// プロジェクト名を取得
+    pub fn project(&self) -> Option<&str> {
+        self.project.as_deref()
+    }
+
+    /// 実験IDを取得
+    pub fn experiment_id(&self) -> Option<&str> {
+        self.experiment_id.as_deref()
+    }
+
+    /// 想定使用率（%）を取得
+    pub fn expected_utilization(&self) -> Option<u8> {
+        self.expected_utilization
+    }
+
+    /// いずれの項目も指定されていないかどうか
+    pub fn is_empty(&self) -> bool {
+        self.project.is_none()
+            && self.experiment_id.is_none()
+            && self.expected_utilization.is_none()
+    }
+}
+
+/// 前後の空白と改行を除いて1行にする（空の場合は `None`）
+fn normalize(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_normalizes_fields() {
+        let metadata = ReservationMetadata::new(
+            Some("  llm\n pretraining ".to_string()),
+            Some("   ".to_string()),
+            Some(80),
+        )
+        .unwrap();
+
+        assert_eq!(metadata.project(), Some("llm pretraining"));
+        assert_eq!(metadata.experiment_id(), None);
+        assert_eq!(metadata.expected_utilization(), Some(80));
+        assert!(!metadata.is_empty());
+        assert!(ReservationMetadata::default().is_empty());
+    }
+
+    #[test]
+    fn test_metadata_rejects_utilization_over_100() {
+        assert!(matches!(
+            ReservationMetadata::new(None, None, Some(101)),
+            Err(ResourceUsageError::InvalidMetadata(_))
+        ));
+    }
+}
@@ -9,13 +9,31 @@
 //! - **自己検証**: 生成時に不正な値を拒否し、常に有効な状態を保つ
 //! - **副作用なし**: メソッドは新しい値オブジェクトを返し、自身を変更しない
 
+/// 承認状態の値オブジェクト
+pub mod approval_status;
+/// 予約のメタデータの値オブジェクト
+pub mod metadata;
+/// 優先度の値オブジェクト
+pub mod priority;
+/// 繰り返し予約の規則の値オブジェクト
+pub mod recurrence;
 /// リソース（GPU、部屋など）の値オブジェクト
 pub mod resource;
+/// 繰り返し予約のシリーズIDの値オブジェクト
+pub mod series_id;
 /// 時間枠の値オブジェクト
 pub mod time_period;
 /// 使用予定IDの値オブジェクト
 pub mod usage_id;
+/// 公開範囲の値オブジェクト
+pub mod visibility;
 
+pub use approval_status::ApprovalStatus;
+pub use metadata::ReservationMetadata;
+pub use priority::Priority;
+pub use recurrence::{RecurrenceEnd, RecurrenceFrequency, RecurrenceRule};
 pub use resource::{Gpu, Resource};
+pub use series_id::SeriesId;
 pub use time_period::TimePeriod;
 pub use usage_id::UsageId;
+pub use visibility::Visibility;
@@ -0,0 +1,77 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// リソース使用予定の優先度
+///
+/// 優先度の高い予約は、重なる優先度の低い予約を取り消して（横取りして）予約できる。
+/// 比較は `Background < Normal < Deadline` の順。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Priority {
+    /// 急がない処理（空いていれば使う）
+    Background,
+    /// 通常
+    #[default]
+    Normal,
+    /// 論文の締切前など、急ぎの利用
+    Deadline,
+}
+
+impl Priority {
+    /// すべての優先度（低い順）
+    pub const ALL: [Priority; 3] = [Self::Background, Self::Normal, Self::Deadline];
+
+    /// 保存・フォームの値に使う識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Background => "background",
+            Self::Normal => "normal",
+            Self::Deadline => "deadline",
+        }
+    }
+
+    /// 指定した優先度の予約を横取りできるかどうか（自分より低い優先度のみ）
+    pub fn outranks(&self, other: Priority) -> bool {
+        *self > other
+    }
+}
+
+impl FromStr for Priority {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|priority| priority.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or(())
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Background => write!(f, "バックグラウンド"),
+            Self::Normal => write!(f, "通常"),
+            Self::Deadline => write!(f, "締切前"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_order() {
+        assert!(Priority::Deadline.outranks(Priority::Normal));
+        assert!(Priority::Normal.outranks(Priority::Background));
+        assert!(!Priority::Normal.outranks(Priority::Normal));
+        assert!(!Priority::Background.outranks(Priority::Deadline));
+    }
+
+    #[test]
+    fn test_parse_priority() {
+        assert_eq!("deadline".parse(), Ok(Priority::Deadline));
+        assert_eq!(" Background ".parse(), Ok(Priority::Background));
+        assert_eq!("urgent".parse::<Priority>(), Err(()));
+    }
+}
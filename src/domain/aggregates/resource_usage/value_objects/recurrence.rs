@@ -0,0 +1,310 @@
+use super::TimePeriod;
+use crate::domain::aggregates::resource_usage::errors::ResourceUsageError;
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use std::fmt;
+
+/// 繰り返しの頻度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecurrenceFrequency {
+    /// 毎週
+    Weekly,
+    /// 隔週
+    Biweekly,
+}
+
+impl RecurrenceFrequency {
+    /// 繰り返しの間隔
+    pub fn interval(&self) -> Duration {
+        match self {
+            Self::Weekly => Duration::weeks(1),
+            Self::Biweekly => Duration::weeks(2),
+        }
+    }
+
+    /// 文字列表現（フォームの値などに使う）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Weekly => "weekly",
+            Self::Biweekly => "biweekly",
+        }
+    }
+
+    /// 文字列表現から頻度を取得
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "weekly" => Some(Self::Weekly),
+            "biweekly" => Some(Self::Biweekly),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RecurrenceFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Weekly => write!(f, "毎週"),
+            Self::Biweekly => write!(f, "隔週"),
+        }
+    }
+}
+
+/// 繰り返しの終わり方
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceEnd {
+    /// この日時以前に開始する回まで繰り返す
+    Until(DateTime<Utc>),
+    /// 最初の回を含めて指定した回数だけ繰り返す
+    Count(u32),
+}
+
+/// 繰り返し予約の規則
+///
+/// 最初の使用期間から一定の間隔で、終了日時まで、または指定した回数だけ繰り返す。
+/// 繰り返し予約から作成した各回のリソース使用予定は、同じ規則とシリーズIDを持つ。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    frequency: RecurrenceFrequency,
+    end: RecurrenceEnd,
+}
+
+impl RecurrenceRule {
+    /// 1つの繰り返し予約で作成できる最大回数
+    pub const MAX_OCCURRENCES: usize = 52;
+
+    /// 終了日時までの繰り返し規則を作成
+    ///
+    /// # Arguments
+    /// * `frequency` - 繰り返しの頻度
+    /// * `until` - 繰り返しの終了日時（この時刻以前に開始するものまで作成する）
+    pub fn until(frequency: RecurrenceFrequency, until: DateTime<Utc>) -> Self {
+        Self {
+            frequency,
+            end: RecurrenceEnd::Until(until),
+        }
+    }
+
+    /// 回数を指定した繰り返し規則を作成
+    ///
+    /// # Arguments
+    /// * `frequency` - 繰り返しの頻度
+    /// * `count` - 最初の回を含めた回数
+    pub fn count(frequency: RecurrenceFrequency, count: u32) -> Self {
+        Self {
+            frequency,
+            end: RecurrenceEnd::Count(count),
+        }
+    }
+
+    /// 繰り返しの頻度を取得
+    pub fn frequency(&self) -> RecurrenceFrequency {
+        self.frequency
+    }
+
+    /// 繰り返しの終わり方を取得
+    pub fn end(&self) -> RecurrenceEnd {
+        self.end
+    }
+
+    /// 最初の使用期間から、繰り返しのすべての使用期間を求める
+    ///
+    /// # Errors
+    /// - 1回の使用期間が繰り返しの間隔より長い場合（同じシリーズの回どうしが重なるため）
+    /// - 終了日時が最初の開始時刻より前の場合、または回数が0の場合
+    /// - 回数が `MAX_OCCURRENCES` を超える場合
+    pub fn occurrences(&self, first: &TimePeriod) -> Result<Vec<TimePeriod>, ResourceUsageError> {
+        let interval = self.frequency.interval();
+        if first.end() - first.start() > interval {
+            return Err(ResourceUsageError::InvalidRecurrence(format!(
+                "{}の繰り返しでは、1回の使用期間を{}日以内にしてください",
+                self.frequency,
+                interval.num_days()
+            )));
+        }
+        let count = match self.end {
+            RecurrenceEnd::Until(until) if until < first.start() => {
+                return Err(ResourceUsageError::InvalidRecurrence(
+                    "繰り返しの終了日は開始日以降にしてください".to_string(),
+                ));
+            }
+            RecurrenceEnd::Until(until) => {
+                ((until - first.start()).num_seconds() / interval.num_seconds()) as usize + 1
+            }
+            RecurrenceEnd::Count(0) => {
+                return Err(ResourceUsageError::InvalidRecurrence(
+                    "繰り返しの回数は1回以上にしてください".to_string(),
+                ));
+            }
+            RecurrenceEnd::Count(count) => count as usize,
+        };
+        if count > Self::MAX_OCCURRENCES {
+            return Err(ResourceUsageError::InvalidRecurrence(format!(
+                "繰り返しは最大{}回までです",
+                Self::MAX_OCCURRENCES
+            )));
+        }
+
+        (0..count as i32)
+            .map(|i| TimePeriod::new(first.start() + interval * i, first.end() + interval * i))
+            .collect()
+    }
+
+    /// iCalendar（RFC 5545）のRRULE形式の文字列にする（例: `FREQ=WEEKLY;INTERVAL=2;COUNT=4`）
+    ///
+    /// 予約の保存先に規則を記録するために使う。
+    pub fn to_rrule(&self) -> String {
+        let mut rule = "FREQ=WEEKLY".to_string();
+        if self.frequency == RecurrenceFrequency::Biweekly {
+            rule.push_str(";INTERVAL=2");
+        }
+        match self.end {
+            RecurrenceEnd::Until(until) => {
+                rule.push_str(&format!(";UNTIL={}", until.format("%Y%m%dT%H%M%SZ")))
+            }
+            RecurrenceEnd::Count(count) => rule.push_str(&format!(";COUNT={}", count)),
+        }
+        rule
+    }
+
+    /// `to_rrule` で作成した文字列から繰り返し規則を復元する
+    ///
+    /// 毎週・隔週以外の規則や、終了日時・回数のないものは `None` を返す。
+    pub fn from_rrule(value: &str) -> Option<Self> {
+        let mut weekly = false;
+        let mut frequency = RecurrenceFrequency::Weekly;
+        let mut end = None;
+        for part in value.trim().trim_start_matches("RRULE:").split(';') {
+            let (key, value) = part.split_once('=')?;
+            match key {
+                "FREQ" => weekly = value == "WEEKLY",
+                "INTERVAL" => {
+                    frequency = match value {
+                        "1" => RecurrenceFrequency::Weekly,
+                        "2" => RecurrenceFrequency::Biweekly,
+                        _ => return None,
+                    }
+                }
+                "UNTIL" => {
+                    let until = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ").ok()?;
+                    end = Some(RecurrenceEnd::Until(until.and_utc()));
+                }
+                "COUNT" => end = Some(RecurrenceEnd::Count(value.parse().ok()?)),
+                _ => return None,
+            }
+        }
+        weekly.then_some(Self {
+            frequency,
+            end: end?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn first() -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2025, 4, 1, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 4, 1, 12, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_weekly_occurrences_include_until_day() {
+        let recurrence = RecurrenceRule::until(
+            RecurrenceFrequency::Weekly,
+            Utc.with_ymd_and_hms(2025, 4, 22, 23, 59, 0).unwrap(),
+        );
+
+        let occurrences = recurrence.occurrences(&first()).unwrap();
+
+        assert_eq!(occurrences.len(), 4);
+        assert_eq!(
+            occurrences[3].start(),
+            Utc.with_ymd_and_hms(2025, 4, 22, 10, 0, 0).unwrap()
+        );
+        assert_eq!(
+            occurrences[3].end(),
+            Utc.with_ymd_and_hms(2025, 4, 22, 12, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_biweekly_occurrences() {
+        let recurrence = RecurrenceRule::until(
+            RecurrenceFrequency::Biweekly,
+            Utc.with_ymd_and_hms(2025, 4, 29, 0, 0, 0).unwrap(),
+        );
+
+        let occurrences = recurrence.occurrences(&first()).unwrap();
+
+        assert_eq!(occurrences.len(), 2);
+        assert_eq!(
+            occurrences[1].start(),
+            Utc.with_ymd_and_hms(2025, 4, 15, 10, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_occurrences_rejects_until_before_start_and_too_many() {
+        let before = RecurrenceRule::until(
+            RecurrenceFrequency::Weekly,
+            Utc.with_ymd_and_hms(2025, 3, 31, 0, 0, 0).unwrap(),
+        );
+        assert!(before.occurrences(&first()).is_err());
+
+        let too_long = RecurrenceRule::until(
+            RecurrenceFrequency::Weekly,
+            Utc.with_ymd_and_hms(2027, 4, 1, 0, 0, 0).unwrap(),
+        );
+        assert!(too_long.occurrences(&first()).is_err());
+    }
+
+    #[test]
+    fn test_count_occurrences_and_overlong_period() {
+        let recurrence = RecurrenceRule::count(RecurrenceFrequency::Biweekly, 3);
+        let occurrences = recurrence.occurrences(&first()).unwrap();
+        assert_eq!(occurrences.len(), 3);
+        assert_eq!(
+            occurrences[2].start(),
+            Utc.with_ymd_and_hms(2025, 4, 29, 10, 0, 0).unwrap()
+        );
+
+        assert!(
+            RecurrenceRule::count(RecurrenceFrequency::Weekly, 0)
+                .occurrences(&first())
+                .is_err()
+        );
+
+        // 1週間を超える使用期間を毎週繰り返すと、同じシリーズの回どうしが重なる
+        let eight_days =
+            TimePeriod::new(first().start(), first().start() + Duration::days(8)).unwrap();
+        assert!(
+            RecurrenceRule::count(RecurrenceFrequency::Weekly, 2)
+                .occurrences(&eight_days)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_rrule_round_trip() {
+        let until = RecurrenceRule::until(
+            RecurrenceFrequency::Biweekly,
+            Utc.with_ymd_and_hms(2025, 4, 22, 23, 59, 0).unwrap(),
+        );
+        assert_eq!(
+            until.to_rrule(),
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=20250422T235900Z"
+        );
+        assert_eq!(RecurrenceRule::from_rrule(&until.to_rrule()), Some(until));
+
+        let count = RecurrenceRule::count(RecurrenceFrequency::Weekly, 4);
+        assert_eq!(count.to_rrule(), "FREQ=WEEKLY;COUNT=4");
+        assert_eq!(RecurrenceRule::from_rrule(&count.to_rrule()), Some(count));
+
+        assert_eq!(RecurrenceRule::from_rrule("FREQ=DAILY;COUNT=4"), None);
+        assert_eq!(RecurrenceRule::from_rrule("FREQ=WEEKLY"), None);
+    }
+}
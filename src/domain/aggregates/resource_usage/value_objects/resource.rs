@@ -37,6 +37,13 @@ impl Gpu {
     pub fn model(&self) -> &str {
         &self.model
     }
+
+    /// 同じデバイスかどうか（サーバー名とデバイス番号で判定する）
+    ///
+    /// モデル名は表示用のため、設定の変更などで異なっていても同じデバイスとみなす。
+    pub fn is_same_device(&self, other: &Gpu) -> bool {
+        self.server == other.server && self.device_number == other.device_number
+    }
 }
 
 impl fmt::Display for Gpu {
@@ -59,14 +66,69 @@ pub enum Resource {
         /// 部屋名
         name: String,
     },
+    /// 実験機器（オシロスコープ、3Dプリンターなど）
+    Instrument {
+        /// 機器名
+        name: String,
+    },
+    /// 設定ファイルで定義した種別のリソース
+    Custom {
+        /// リソース種別のID
+        kind: String,
+        /// リソース名
+        name: String,
+    },
+    /// 共有ストレージ（NASのスクラッチ領域など）の容量
+    Storage {
+        /// ボリューム名
+        volume: String,
+        /// 確保する容量（GB）
+        gigabytes: u64,
+    },
+    /// フローティングライセンス
+    License {
+        /// ライセンス名
+        name: String,
+        /// 使用する席数
+        seats: u32,
+    },
 }
 
 impl Resource {
+    /// リソース名（GPUの場合はサーバー名、部屋・機器・設定で定義したリソースの場合はその名前、
+    /// ストレージの場合はボリューム名、ライセンスの場合はライセンス名）
+    pub fn name(&self) -> &str {
+        match self {
+            Resource::Gpu(gpu) => gpu.server(),
+            Resource::Room { name } => name,
+            Resource::Instrument { name } => name,
+            Resource::Custom { name, .. } => name,
+            Resource::Storage { volume, .. } => volume,
+            Resource::License { name, .. } => name,
+        }
+    }
+
     /// この資源が他の資源と競合するか（同じ資源を指すか）
+    ///
+    /// ストレージとライセンスは複数の予約で分け合うため、1対1では競合しない。
+    /// 容量・席数を超えるかどうかは `ResourceConflictChecker` が合計で判定する。
     pub fn conflicts_with(&self, other: &Resource) -> bool {
         match (self, other) {
-            (Resource::Gpu(id1), Resource::Gpu(id2)) => id1 == id2,
+            (Resource::Gpu(gpu1), Resource::Gpu(gpu2)) => gpu1.is_same_device(gpu2),
             (Resource::Room { name: name1 }, Resource::Room { name: name2 }) => name1 == name2,
+            (Resource::Instrument { name: name1 }, Resource::Instrument { name: name2 }) => {
+                name1 == name2
+            }
+            (
+                Resource::Custom {
+                    kind: kind1,
+                    name: name1,
+                },
+                Resource::Custom {
+                    kind: kind2,
+                    name: name2,
+                },
+            ) => kind1 == kind2 && name1 == name2,
             _ => false,
         }
     }
@@ -77,6 +139,12 @@ impl fmt::Display for Resource {
         match self {
             Resource::Gpu(gpu) => write!(f, "{}", gpu),
             Resource::Room { name } => write!(f, "部屋: {}", name),
+            Resource::Instrument { name } => write!(f, "機器: {}", name),
+            Resource::Custom { kind, name } => write!(f, "{}: {}", kind, name),
+            Resource::Storage { volume, gigabytes } => {
+                write!(f, "ストレージ: {} {}GB", volume, gigabytes)
+            }
+            Resource::License { name, seats } => write!(f, "ライセンス: {} {}席", name, seats),
         }
     }
 }
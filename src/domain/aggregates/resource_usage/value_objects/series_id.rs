@@ -0,0 +1,31 @@
+/// 繰り返し予約のシリーズの識別子
+///
+/// 同じ繰り返し予約から作成されたリソース使用予定は、同じシリーズIDを持つ。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeriesId(String);
+
+impl Default for SeriesId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SeriesId {
+    /// 新しいSeriesIdを生成（UUID v4）
+    pub fn new() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+
+    /// 既存のID文字列からSeriesIdを再構築
+    ///
+    /// # Arguments
+    /// * `id` - ID文字列
+    pub fn from_string(id: String) -> Self {
+        Self(id)
+    }
+
+    /// 文字列表現を取得
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt;
+
+/// リソース使用予定の公開範囲
+///
+/// 非公開の予約（例: 会議室での面接）は、予約者本人と管理者以外には
+/// 「予約済み」であることだけが示され、予約者や備考は表示されない。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Visibility {
+    /// 誰でも詳細を閲覧できる
+    #[default]
+    Public,
+    /// 予約者本人と管理者のみ詳細を閲覧できる
+    Private,
+}
+
+impl Visibility {
+    /// 非公開かどうか
+    pub fn is_private(&self) -> bool {
+        matches!(self, Self::Private)
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Public => write!(f, "公開"),
+            Self::Private => write!(f, "非公開"),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use super::errors::WaitlistError;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::common::EmailAddress;
+use chrono::{DateTime, Duration, Utc};
+
+/// 空き待ちを管理する集約ルート
+///
+/// 希望する時間帯・リソースのすべてが空いたときに、登録順に希望者へ知らせる。
+/// 知らせた希望者には一定時間の手番を与え、その間は重なる空き待ちの希望者には知らせない。
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaitlistEntry {
+    /// 空き待ちの識別子
+    id: String,
+    /// 空き待ちしているユーザーのメールアドレス
+    owner_email: EmailAddress,
+    /// 希望する時間帯
+    time_period: TimePeriod,
+    /// 希望するリソース
+    resources: Vec<Resource>,
+    /// 空き待ちに登録した時刻
+    joined_at: DateTime<Utc>,
+    /// 空きを知らせた時刻（まだ知らせていない場合は `None`）
+    notified_at: Option<DateTime<Utc>>,
+}
+
+impl WaitlistEntry {
+    /// 新しい空き待ちを作成
+    ///
+    /// # Arguments
+    /// * `owner_email` - 空き待ちするユーザーのメールアドレス
+    /// * `time_period` - 希望する時間帯
+    /// * `resources` - 希望するリソース
+    ///
+    /// # Errors
+    /// * `WaitlistError::NoResources` - リソースが空の場合
+    /// * `WaitlistError::PeriodEnded` - 希望する時間帯が既に終了している場合
+    pub fn new(
+        owner_email: EmailAddress,
+        time_period: TimePeriod,
+        resources: Vec<Resource>,
+    ) -> Result<Self, WaitlistError> {
+        if resources.is_empty() {
+            return Err(WaitlistError::NoResources);
+        }
+        let now = Utc::now();
+        if time_period.end() <= now {
+            return Err(WaitlistError::PeriodEnded);
+        }
+
+        Ok(Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            owner_email,
+            time_period,
+            resources,
+            joined_at: now,
+            notified_at: None,
+        })
+    }
+
+    /// 永続化層からの復元
+    ///
+    /// **Repository実装専用**。時間帯が終了していてもそのまま復元する。
+    pub(crate) fn reconstitute(
+        id: String,
+        owner_email: EmailAddress,
+        time_period: TimePeriod,
+        resources: Vec<Resource>,
+        joined_at: DateTime<Utc>,
+        notified_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            owner_email,
+            time_period,
+            resources,
+            joined_at,
+            notified_at,
+        }
+    }
+
+    /// 希望する時間帯が終了し、空き待ちが不要になったかどうか
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.time_period.end() <= now
+    }
+
+    /// 希望する時間帯・リソースが別の空き待ちと重なるかどうか
+    pub fn overlaps_with(&self, other: &WaitlistEntry) -> bool {
+        self.time_period.overlaps_with(&other.time_period)
+            && self
+                .resources
+                .iter()
+                .any(|r| other.resources.iter().any(|o| r.conflicts_with(o)))
+    }
+
+    /// 空きを知らせたことを記録
+    pub fn mark_notified(&mut self, now: DateTime<Utc>) {
+        self.notified_at = Some(now);
+    }
+
+    /// 空きを知らせてから `turn` が経過しておらず、まだ希望者の手番かどうか
+    pub fn holds_turn(&self, now: DateTime<Utc>, turn: Duration) -> bool {
+        self.notified_at.is_some_and(|at| now < at + turn)
+    }
+
+    /// 同じユーザーが同じ時間帯・リソースで既に空き待ちしているかどうか
+    pub fn is_same_request(&self, other: &WaitlistEntry) -> bool {
+        self.owner_email == other.owner_email
+            && self.time_period == other.time_period
+            && self.resources == other.resources
+    }
+
+    /// 空き待ちの識別子を取得
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// 空き待ちしているユーザーのメールアドレスを取得
+    pub fn owner_email(&self) -> &EmailAddress {
+        &self.owner_email
+    }
+
+    /// 希望する時間帯を取得
+    pub fn time_period(&self) -> &TimePeriod {
+        &self.time_period
+    }
+
+    /// 希望するリソースを取得
+    pub fn resources(&self) -> &[Resource] {
+        &self.resources
+    }
+
+    /// 空き待ちに登録した時刻を取得
+    pub fn joined_at(&self) -> DateTime<Utc> {
+        self.joined_at
+    }
+
+    /// 空きを知らせた時刻を取得
+    pub fn notified_at(&self) -> Option<DateTime<Utc>> {
+        self.notified_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+
+    fn email() -> EmailAddress {
+        EmailAddress::new("user@example.com".to_string()).unwrap()
+    }
+
+    fn gpu(device: u32) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()))
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_requests() {
+        let now = Utc::now();
+        let future = TimePeriod::new(now + Duration::hours(1), now + Duration::hours(2)).unwrap();
+        let past = TimePeriod::new(now - Duration::hours(2), now - Duration::hours(1)).unwrap();
+
+        assert_eq!(
+            WaitlistEntry::new(email(), future, vec![]),
+            Err(WaitlistError::NoResources)
+        );
+        assert_eq!(
+            WaitlistEntry::new(email(), past, vec![gpu(0)]),
+            Err(WaitlistError::PeriodEnded)
+        );
+    }
+
+    #[test]
+    fn test_is_same_request_and_expiry() {
+        let now = Utc::now();
+        let period = TimePeriod::new(now + Duration::hours(1), now + Duration::hours(2)).unwrap();
+        let entry = WaitlistEntry::new(email(), period.clone(), vec![gpu(0)]).unwrap();
+
+        assert!(
+            entry.is_same_request(
+                &WaitlistEntry::new(email(), period.clone(), vec![gpu(0)]).unwrap()
+            )
+        );
+        assert!(
+            !entry.is_same_request(&WaitlistEntry::new(email(), period, vec![gpu(1)]).unwrap())
+        );
+        assert!(!entry.is_expired(now));
+        assert!(entry.is_expired(now + Duration::hours(2)));
+    }
+
+    #[test]
+    fn test_turn_after_notification() {
+        let now = Utc::now();
+        let period = TimePeriod::new(now + Duration::hours(1), now + Duration::hours(2)).unwrap();
+        let mut entry = WaitlistEntry::new(email(), period.clone(), vec![gpu(0)]).unwrap();
+        let other = WaitlistEntry::new(email(), period, vec![gpu(0), gpu(1)]).unwrap();
+        assert!(entry.overlaps_with(&other));
+        assert!(!entry.holds_turn(now, Duration::minutes(15)));
+
+        entry.mark_notified(now);
+        assert!(entry.holds_turn(now + Duration::minutes(10), Duration::minutes(15)));
+        assert!(!entry.holds_turn(now + Duration::minutes(15), Duration::minutes(15)));
+    }
+}
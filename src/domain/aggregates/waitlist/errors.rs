@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// Waitlist集約のドメインエラー型
+#[derive(Debug, Clone, PartialEq)]
+pub enum WaitlistError {
+    /// 空き待ちするリソースが指定されていない
+    NoResources,
+    /// 希望する時間帯が既に終了している
+    PeriodEnded,
+}
+
+impl fmt::Display for WaitlistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoResources => write!(f, "空き待ちするリソースが指定されていません"),
+            Self::PeriodEnded => write!(f, "希望する時間帯は既に終了しています"),
+        }
+    }
+}
+
+impl std::error::Error for WaitlistError {}
+
+impl crate::domain::errors::DomainError for WaitlistError {}
@@ -0,0 +1,16 @@
+//! # Waitlist集約
+//!
+//! 既存の予約と競合して予約できなかった時間帯・リソースについて、
+//! 空きが出たら知らせてほしいという希望（空き待ち）を管理する集約です。
+//!
+//! ## 集約ルート
+//!
+//! `WaitlistEntry`エンティティが集約ルートとして機能し、1人のユーザーの1件の空き待ちを表します。
+
+/// Waitlist集約のエンティティ定義
+pub mod entity;
+/// Waitlist集約のエラー型
+pub mod errors;
+
+pub use entity::WaitlistEntry;
+pub use errors::WaitlistError;
@@ -0,0 +1,76 @@
+use crate::domain::{errors::DomainError, ports::PortError};
+use async_trait::async_trait;
+use std::fmt;
+
+/// GPUで実行中のプロセス
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuProcess {
+    /// GPUのデバイス番号
+    pub device_number: u32,
+    /// プロセスID
+    pub pid: u32,
+    /// プロセスを実行しているUNIXユーザー名
+    pub unix_user: String,
+}
+
+/// 予約なしでのGPUの使用
+///
+/// 現在の予約に含まれないGPUで、プロセスが実行されていることを表す。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreservedGpuUsage {
+    /// サーバー名
+    pub server: String,
+    /// GPUのデバイス番号
+    pub device_number: u32,
+    /// プロセスを実行しているUNIXユーザー名
+    pub unix_user: String,
+    /// 実行中のプロセスID（昇順）
+    pub pids: Vec<u32>,
+}
+
+/// GPUの使用状況の取得のエラー型
+#[derive(Debug, Clone)]
+pub enum GpuMonitorError {
+    /// 使用状況の取得が設定されていないサーバー
+    NotConfigured(String),
+    /// サーバーとの通信エラー
+    ConnectionError(String),
+    /// 出力を解釈できない
+    InvalidOutput(String),
+}
+
+impl fmt::Display for GpuMonitorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotConfigured(server) => {
+                write!(f, "GPUの使用状況の取得が設定されていません: {}", server)
+            }
+            Self::ConnectionError(msg) => write!(f, "サーバー通信エラー: {}", msg),
+            Self::InvalidOutput(msg) => write!(f, "GPUの使用状況を解釈できません: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for GpuMonitorError {}
+impl DomainError for GpuMonitorError {}
+impl PortError for GpuMonitorError {}
+
+/// GPUの実際の使用状況を取得するサービスのインターフェース
+///
+/// `nvidia-smi` などを通じて、サーバーのGPUで実行中のプロセスを取得する。
+/// 予約なしでGPUを使っている利用者を見つけるために使う。
+#[async_trait]
+pub trait GpuProcessMonitor: Send + Sync {
+    /// 使用状況を取得できるサーバー名の一覧
+    fn monitored_servers(&self) -> Vec<String>;
+
+    /// サーバーのGPUで実行中のプロセスを取得する
+    ///
+    /// # 引数
+    /// * `server` - サーバー名
+    ///
+    /// # エラー
+    /// - 使用状況の取得が設定されていない場合
+    /// - サーバーとの通信エラー
+    async fn processes(&self, server: &str) -> Result<Vec<GpuProcess>, GpuMonitorError>;
+}
@@ -0,0 +1,39 @@
+use crate::domain::{errors::DomainError, ports::PortError};
+use async_trait::async_trait;
+use std::fmt;
+
+/// リーダー選出のエラー型
+#[derive(Debug, Clone)]
+pub enum LeaderElectionError {
+    /// ロックの保管先との通信エラー
+    ConnectionError(String),
+    /// 保管先の応答や設定が不正
+    Invalid(String),
+}
+
+impl fmt::Display for LeaderElectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConnectionError(msg) => write!(f, "リーダーのロックを確認できません: {}", msg),
+            Self::Invalid(msg) => write!(f, "リーダー選出の設定が不正です: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LeaderElectionError {}
+impl DomainError for LeaderElectionError {}
+impl PortError for LeaderElectionError {}
+
+/// 複数のインスタンスからポーリングと通知を担うリーダーを1つ選ぶインターフェース
+///
+/// リーダー以外のインスタンスは待機し、リーダーが停止するとロックを引き継ぐ。
+#[async_trait]
+pub trait LeaderElection: Send + Sync {
+    /// リーダーのロックを取得または更新し、このインスタンスがリーダーかどうかを返す
+    ///
+    /// ポーリングのたびに呼び出す。リーダーはこの呼び出しでロックの期限を延長する。
+    async fn try_acquire(&self) -> Result<bool, LeaderElectionError>;
+
+    /// リーダーのロックを手放す（停止時に呼び出す）
+    async fn release(&self) -> Result<(), LeaderElectionError>;
+}
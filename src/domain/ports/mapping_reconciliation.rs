@@ -0,0 +1,53 @@
+use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, UsageId};
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+use std::fmt;
+
+/// 予約IDと外部のイベントの対応の食い違いの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscrepancyKind {
+    /// 対応するイベントが削除されている（手動での削除など）
+    StaleMapping,
+    /// イベントに対応する予約IDが記録されていない
+    UnmappedEvent,
+}
+
+impl fmt::Display for DiscrepancyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StaleMapping => write!(f, "イベントが存在しない対応"),
+            Self::UnmappedEvent => write!(f, "対応のないイベント"),
+        }
+    }
+}
+
+/// 予約IDと外部のイベントの対応の食い違い
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingDiscrepancy {
+    /// 食い違いの種類
+    pub kind: DiscrepancyKind,
+    /// 予約ID（対応のないイベントの場合は補完に使うID）
+    pub usage_id: UsageId,
+    /// イベントのカレンダーID
+    pub calendar_id: String,
+    /// イベントID
+    pub event_id: String,
+}
+
+/// 予約IDと外部のイベントの対応を照合・修復するサービスのインターフェース
+///
+/// 対応の記録とカレンダーの両方を突き合わせ、イベントが削除された対応の削除と、
+/// 記録のないイベントの対応の補完を行う。
+#[async_trait]
+pub trait MappingReconciler: Send + Sync {
+    /// 対応の食い違いを検出する
+    ///
+    /// イベントが存在しない対応はすべての対応から、対応のないイベントは指定期間と重なるイベントから検出する。
+    async fn find_discrepancies(
+        &self,
+        period: &TimePeriod,
+    ) -> Result<Vec<MappingDiscrepancy>, RepositoryError>;
+
+    /// 食い違いを修復する（対応の削除または補完）
+    async fn resolve(&self, discrepancy: &MappingDiscrepancy) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,60 @@
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::common::EmailAddress;
+use crate::domain::{errors::DomainError, ports::PortError};
+use async_trait::async_trait;
+use std::fmt;
+
+/// ディレクトリサービスのエラー型
+#[derive(Debug, Clone)]
+pub enum DirectoryError {
+    /// ディレクトリサービスとの通信エラー
+    ConnectionError(String),
+    /// その他のエラー
+    Unknown(String),
+}
+
+impl fmt::Display for DirectoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConnectionError(msg) => write!(f, "ディレクトリ通信エラー: {}", msg),
+            Self::Unknown(msg) => write!(f, "不明なエラー: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DirectoryError {}
+impl DomainError for DirectoryError {}
+impl PortError for DirectoryError {}
+
+/// 研究室メンバーの名簿のインターフェース
+///
+/// LDAPやActive Directoryなど、研究室のメンバーを管理している外部の名簿を表す。
+#[async_trait]
+pub trait MemberDirectory: Send + Sync {
+    /// 名簿に登録されているメンバーのメールアドレスを取得する
+    ///
+    /// # エラー
+    /// - 名簿との通信エラー
+    async fn list_member_emails(&self) -> Result<Vec<EmailAddress>, DirectoryError>;
+}
+
+/// 外部システムのユーザーディレクトリのインターフェース
+///
+/// メールアドレスから外部システム（例: Slack）上のユーザーを検索する。
+#[async_trait]
+pub trait ExternalUserDirectory: Send + Sync {
+    /// 対象の外部システム
+    fn system(&self) -> ExternalSystem;
+
+    /// メールアドレスから外部システム上のユーザーIDを検索する
+    ///
+    /// # 引数
+    /// * `email` - 検索するメールアドレス
+    ///
+    /// # 戻り値
+    /// ユーザーが見つからない場合は `None`
+    async fn find_user_id_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Option<String>, DirectoryError>;
+}
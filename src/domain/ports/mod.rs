@@ -20,15 +20,45 @@
 
 /// ポート共通のエラー定義
 pub mod error;
+/// GPUの使用状況の取得サービスポート
+pub mod gpu_monitor;
+/// リーダー選出ポート
+pub mod leader_election;
+/// 予約IDと外部のイベントの対応の照合サービスポート
+pub mod mapping_reconciliation;
+/// 研究室メンバー名簿・外部ユーザーディレクトリポート
+pub mod member_directory;
 /// 通知サービスポート
 pub mod notifier;
+/// 電源管理サービスポート
+pub mod power_management;
 /// リポジトリポート
 pub mod repositories;
+/// 予約の書き出しサービスポート
+pub mod reservation_export;
+/// 予約の取り込み元ポート
+pub mod reservation_import;
 /// リソースコレクションアクセスサービスポート
 pub mod resource_collection_access;
+/// シークレットの取得元ポート
+pub mod secret_provider;
+/// 週間の利用状況のまとめの送信ポート
+pub mod weekly_digest;
 
 pub use error::PortError;
-pub use notifier::{NotificationError, NotificationEvent, Notifier};
+pub use gpu_monitor::{GpuMonitorError, GpuProcess, GpuProcessMonitor, UnreservedGpuUsage};
+pub use leader_election::{LeaderElection, LeaderElectionError};
+pub use mapping_reconciliation::{DiscrepancyKind, MappingDiscrepancy, MappingReconciler};
+pub use member_directory::{DirectoryError, ExternalUserDirectory, MemberDirectory};
+pub use notifier::{
+    ApprovalRequestSender, IdleReservationNotifier, NotificationError, NotificationEvent, Notifier,
+    PreemptionNotifier, ReminderKind, ReminderSender, UnreservedUsageNotifier, WaitlistNotifier,
+};
+pub use power_management::{PowerManagementError, PowerManagementService, PowerState};
+pub use reservation_export::{ExportError, ExportFormat, ReservationExporter};
+pub use reservation_import::{ImportError, ImportRow, ReservationDraft, ReservationSource};
 pub use resource_collection_access::{
     ResourceCollectionAccessError, ResourceCollectionAccessService,
 };
+pub use secret_provider::{SecretError, SecretProvider};
+pub use weekly_digest::{DeviceUsageTime, ServerCapacity, WeeklyDigest, WeeklyDigestSender};
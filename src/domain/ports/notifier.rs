@@ -1,8 +1,15 @@
 // NOTE: これ以上肥大化するようであればnotifierディレクトリを作成してその中に適宜分割する
 use crate::domain::{
-    aggregates::resource_usage::entity::ResourceUsage, errors::DomainError, ports::PortError,
+    aggregates::resource_usage::{
+        entity::ResourceUsage,
+        value_objects::{TimePeriod, UsageId},
+    },
+    aggregates::waitlist::WaitlistEntry,
+    errors::DomainError,
+    ports::{PortError, gpu_monitor::UnreservedGpuUsage},
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::fmt;
 
 /// 通知イベントの種類
@@ -14,6 +21,35 @@ pub enum NotificationEvent {
     ResourceUsageUpdated(ResourceUsage),
     /// リソース使用予定が削除された
     ResourceUsageDeleted(ResourceUsage),
+    /// 繰り返し予約がまとめて作成された
+    ///
+    /// シリーズの各回を個別に通知せず、1つの通知にまとめるために使う。
+    ResourceUsageSeriesCreated {
+        /// 最初の回
+        first: ResourceUsage,
+        /// すべての回の使用期間（開始時刻の早い順）
+        periods: Vec<TimePeriod>,
+        /// すべての回のID（`periods` と同じ順）
+        ids: Vec<UsageId>,
+    },
+    /// リソース使用予定の開始時刻を迎え、使用中になった
+    ResourceUsageStarted(ResourceUsage),
+    /// リソース使用予定の終了時刻を迎え、リソースが空いた
+    ResourceUsageEnded(ResourceUsage),
+}
+
+impl NotificationEvent {
+    /// 通知の対象となるリソース使用予定（繰り返し予約の場合は最初の回）
+    pub fn usage(&self) -> &ResourceUsage {
+        match self {
+            Self::ResourceUsageCreated(usage)
+            | Self::ResourceUsageUpdated(usage)
+            | Self::ResourceUsageDeleted(usage)
+            | Self::ResourceUsageSeriesCreated { first: usage, .. }
+            | Self::ResourceUsageStarted(usage)
+            | Self::ResourceUsageEnded(usage) => usage,
+        }
+    }
 }
 
 /// 通知サービスのポート
@@ -23,6 +59,98 @@ pub trait Notifier: Send + Sync {
     async fn notify(&self, event: NotificationEvent) -> Result<(), NotificationError>;
 }
 
+/// リマインダーの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReminderKind {
+    /// 予約の開始前
+    Start,
+    /// 予約の終了前
+    End,
+}
+
+/// 予約者個人へのリマインダー送信ポート
+#[async_trait]
+pub trait ReminderSender: Send + Sync {
+    /// 予約の開始・終了が近いことを予約者に直接知らせる
+    ///
+    /// `user_id` は送信先となるSlackのユーザーID。
+    async fn send_reminder(
+        &self,
+        user_id: &str,
+        usage: &ResourceUsage,
+        kind: ReminderKind,
+    ) -> Result<(), NotificationError>;
+}
+
+/// 承認が必要な予約の承認依頼の送信ポート
+#[async_trait]
+pub trait ApprovalRequestSender: Send + Sync {
+    /// 承認待ちの予約について、承認者に承認・却下を依頼する
+    async fn request_approval(&self, usage: &ResourceUsage) -> Result<(), NotificationError>;
+}
+
+/// 空き待ちしているユーザーへの空き通知の送信ポート
+#[async_trait]
+pub trait WaitlistNotifier: Send + Sync {
+    /// 空き待ちしていた時間帯・リソースが空いたことを希望者に直接知らせる
+    ///
+    /// `user_id` は送信先となるSlackのユーザーID。
+    async fn notify_available(
+        &self,
+        user_id: &str,
+        entry: &WaitlistEntry,
+    ) -> Result<(), NotificationError>;
+}
+
+/// 横取りされた予約の予約者への通知の送信ポート
+#[async_trait]
+pub trait PreemptionNotifier: Send + Sync {
+    /// 予約が優先度の高い予約に横取りされて取り消されたことを予約者に直接知らせる
+    ///
+    /// `user_id` は送信先となるSlackのユーザーID。
+    /// `suggestion` は取り消された予約と同じリソース・長さで次に空いている時間帯（見つからない場合は `None`）。
+    async fn notify_preempted(
+        &self,
+        user_id: &str,
+        preempted: &ResourceUsage,
+        preempting: &ResourceUsage,
+        suggestion: Option<&TimePeriod>,
+    ) -> Result<(), NotificationError>;
+}
+
+/// GPUが使われていない予約の予約者への確認・解放の通知の送信ポート
+#[async_trait]
+pub trait IdleReservationNotifier: Send + Sync {
+    /// 予約したGPUが使われていないことを予約者に直接知らせ、使用を続けるか確認する
+    ///
+    /// `user_id` は送信先となるSlackのユーザーID。
+    /// `release_at` は応答がない場合に予約を解放する時刻。
+    async fn notify_idle(
+        &self,
+        user_id: &str,
+        usage: &ResourceUsage,
+        release_at: DateTime<Utc>,
+    ) -> Result<(), NotificationError>;
+
+    /// 使われていない予約を解放したことを予約者に直接知らせる
+    ///
+    /// `cancelled` は予約を取り消した（予約期間中に一度も使われなかった）場合に `true`、
+    /// 終了時刻を切り詰めた場合に `false`。
+    async fn notify_released(
+        &self,
+        user_id: &str,
+        usage: &ResourceUsage,
+        cancelled: bool,
+    ) -> Result<(), NotificationError>;
+}
+
+/// 予約なしでのGPUの使用の警告の送信ポート
+#[async_trait]
+pub trait UnreservedUsageNotifier: Send + Sync {
+    /// 予約なしでGPUが使われていることを管理者・利用者に知らせる
+    async fn notify_unreserved(&self, usage: &UnreservedGpuUsage) -> Result<(), NotificationError>;
+}
+
 /// 通知エラー
 #[derive(Debug)]
 pub enum NotificationError {
@@ -0,0 +1,77 @@
+use crate::domain::{errors::DomainError, ports::PortError};
+use async_trait::async_trait;
+use std::fmt;
+
+/// サーバーの電源状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerState {
+    /// 電源オン
+    On,
+    /// 電源オフ
+    Off,
+    /// 状態を判定できない（起動中・停止中を含む）
+    Unknown,
+}
+
+impl fmt::Display for PowerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerState::On => write!(f, "オン"),
+            PowerState::Off => write!(f, "オフ"),
+            PowerState::Unknown => write!(f, "不明"),
+        }
+    }
+}
+
+/// 電源管理のエラー型
+#[derive(Debug, Clone)]
+pub enum PowerManagementError {
+    /// 電源管理が設定されていないサーバー
+    NotConfigured(String),
+    /// BMCとの通信エラー
+    ConnectionError(String),
+    /// その他のエラー
+    Unknown(String),
+}
+
+impl fmt::Display for PowerManagementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotConfigured(server) => {
+                write!(f, "電源管理が設定されていません: {}", server)
+            }
+            Self::ConnectionError(msg) => write!(f, "BMC通信エラー: {}", msg),
+            Self::Unknown(msg) => write!(f, "不明なエラー: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PowerManagementError {}
+impl DomainError for PowerManagementError {}
+impl PortError for PowerManagementError {}
+
+/// サーバーの電源管理サービスのインターフェース
+///
+/// IPMIやRedfishなどのBMC（Baseboard Management Controller）を通じて、
+/// 予約されたサーバーの電源状態の確認と起動を行う。
+#[async_trait]
+pub trait PowerManagementService: Send + Sync {
+    /// 電源管理の対象となっているサーバーかどうか
+    fn is_managed(&self, server: &str) -> bool;
+
+    /// サーバーの電源状態を取得する
+    ///
+    /// # 引数
+    /// * `server` - サーバー名
+    ///
+    /// # エラー
+    /// - 電源管理が設定されていない場合
+    /// - BMCとの通信エラー
+    async fn power_state(&self, server: &str) -> Result<PowerState, PowerManagementError>;
+
+    /// サーバーの電源を入れる
+    ///
+    /// # 引数
+    /// * `server` - サーバー名
+    async fn power_on(&self, server: &str) -> Result<(), PowerManagementError>;
+}
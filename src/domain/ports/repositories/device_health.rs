@@ -0,0 +1,23 @@
+use crate::domain::aggregates::device_health::DeviceHealth;
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+
+/// DeviceHealth集約のリポジトリポート
+#[async_trait]
+pub trait DeviceHealthRepository: Send + Sync {
+    /// 状態が登録されているすべてのデバイスを取得
+    ///
+    /// 登録されていないデバイスは使用可能として扱う。
+    async fn find_all(&self) -> Result<Vec<DeviceHealth>, RepositoryError>;
+
+    /// デバイスの状態を保存
+    ///
+    /// 同じデバイスの状態が既にある場合は上書きする。
+    async fn save(&self, health: DeviceHealth) -> Result<(), RepositoryError>;
+
+    /// デバイスの状態を削除（使用可能に戻す）
+    ///
+    /// # Returns
+    /// 削除した場合は `true`、状態が登録されていなかった場合は `false`
+    async fn delete(&self, server_name: &str, device_id: u32) -> Result<bool, RepositoryError>;
+}
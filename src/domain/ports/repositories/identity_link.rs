@@ -9,12 +9,16 @@ use async_trait::async_trait;
 #[async_trait]
 pub trait IdentityLinkRepository: Send + Sync {
     /// メールアドレスでIdentityLinkを検索
+    ///
+    /// 見つかったIdentityLinkには、すべての外部システムの識別情報が含まれる。
     async fn find_by_email(
         &self,
         email: &EmailAddress,
     ) -> Result<Option<IdentityLink>, RepositoryError>;
 
     /// 外部システムのユーザーIDでIdentityLinkを検索
+    ///
+    /// ユーザーIDは外部システムごとに独立しているため、`system` と組で検索する。
     async fn find_by_external_user_id(
         &self,
         system: &ExternalSystem,
@@ -23,4 +27,9 @@ pub trait IdentityLinkRepository: Send + Sync {
 
     /// IdentityLinkを保存
     async fn save(&self, identity_link: IdentityLink) -> Result<(), RepositoryError>;
+
+    /// メールアドレスのIdentityLinkを削除
+    ///
+    /// 存在しない場合は何もしない。
+    async fn delete(&self, email: &EmailAddress) -> Result<(), RepositoryError>;
 }
@@ -0,0 +1,29 @@
+use crate::domain::aggregates::identity_link::value_objects::{
+    ExternalSystem, IdentityLinkAuditEntry,
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+
+/// IdentityLinkの監査記録のリポジトリポート
+///
+/// 記録は追記のみで、変更・削除の操作は提供しない。
+/// IdentityLinkが削除された後も記録は残る。
+#[async_trait]
+pub trait IdentityLinkAuditRepository: Send + Sync {
+    /// 監査記録を追記
+    async fn append(&self, entry: IdentityLinkAuditEntry) -> Result<(), RepositoryError>;
+
+    /// メールアドレスの監査記録を古い順に取得
+    async fn find_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Vec<IdentityLinkAuditEntry>, RepositoryError>;
+
+    /// 外部システムのユーザーの監査記録を古い順に取得
+    async fn find_by_external_user_id(
+        &self,
+        system: &ExternalSystem,
+        user_id: &str,
+    ) -> Result<Vec<IdentityLinkAuditEntry>, RepositoryError>;
+}
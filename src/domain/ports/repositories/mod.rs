@@ -31,13 +31,37 @@
 //! Repositoryは**集約ルート**に対して1つ定義する。
 //! 集約内部の値オブジェクトには個別のRepositoryを作らない。
 
+/// DeviceHealthリポジトリポート
+pub mod device_health;
 /// リポジトリのエラー型
 pub mod errors;
 /// IdentityLinkリポジトリポート
 pub mod identity_link;
+/// IdentityLinkの監査記録リポジトリポート
+pub mod identity_link_audit;
+/// ResourceFreezeリポジトリポート
+pub mod resource_freeze;
 /// ResourceUsageリポジトリポート
 pub mod resource_usage;
+/// 送信済みリマインダーのリポジトリポート
+pub mod sent_reminder;
+/// ResourceUsageの検索条件
+pub mod usage_query;
+/// 変更検知で最後に確認した予約の状態のリポジトリポート
+pub mod usage_snapshot;
+/// Waitlistリポジトリポート
+pub mod waitlist;
+/// ワークスペースごとのBot Tokenのリポジトリポート
+pub mod workspace_token;
 
+pub use device_health::DeviceHealthRepository;
 pub use errors::RepositoryError;
 pub use identity_link::IdentityLinkRepository;
+pub use identity_link_audit::IdentityLinkAuditRepository;
+pub use resource_freeze::ResourceFreezeRepository;
 pub use resource_usage::ResourceUsageRepository;
+pub use sent_reminder::SentReminderRepository;
+pub use usage_query::{UsageQuery, UsageSort, UsageStatus};
+pub use usage_snapshot::{UsageSnapshotEntry, UsageSnapshotRepository};
+pub use waitlist::WaitlistRepository;
+pub use workspace_token::WorkspaceTokenRepository;
@@ -0,0 +1,21 @@
+use crate::domain::aggregates::resource_freeze::ResourceFreeze;
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+
+/// ResourceFreeze集約のリポジトリポート
+#[async_trait]
+pub trait ResourceFreezeRepository: Send + Sync {
+    /// すべての予約停止を取得
+    async fn find_all(&self) -> Result<Vec<ResourceFreeze>, RepositoryError>;
+
+    /// 予約停止を保存
+    ///
+    /// 同じリソースの予約停止が既にある場合は上書きする。
+    async fn save(&self, freeze: ResourceFreeze) -> Result<(), RepositoryError>;
+
+    /// リソースの予約停止を削除
+    ///
+    /// # Returns
+    /// 削除した場合は `true`、予約停止が無かった場合は `false`
+    async fn delete(&self, resource_name: &str) -> Result<bool, RepositoryError>;
+}
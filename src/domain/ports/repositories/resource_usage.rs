@@ -4,13 +4,14 @@ use crate::domain::{
         value_objects::{TimePeriod, UsageId},
     },
     common::EmailAddress,
-    ports::repositories::RepositoryError,
+    ports::repositories::{RepositoryError, UsageQuery},
 };
 use async_trait::async_trait;
+use chrono::Utc;
 
 /// ResourceUsage集約のリポジトリポート
 #[async_trait]
-pub trait ResourceUsageRepository {
+pub trait ResourceUsageRepository: Send + Sync {
     /// IDでResourceUsageを検索
     async fn find_by_id(&self, id: &UsageId) -> Result<Option<ResourceUsage>, RepositoryError>;
 
@@ -37,6 +38,23 @@ pub trait ResourceUsageRepository {
         owner_email: &EmailAddress,
     ) -> Result<Vec<ResourceUsage>, RepositoryError>;
 
+    /// 検索条件に一致するResourceUsageを取得
+    ///
+    /// 絞り込み・並べ替え・ページングは `UsageQuery::apply` で行う。
+    /// デフォルト実装は、期間の条件があれば `find_overlapping`、なければ `find_future` で
+    /// 候補を取得するため、`find_future` が終了済みの予約を返さない実装では
+    /// 終了済みの予約は結果に含まれない。
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    async fn query(&self, query: &UsageQuery) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        let candidates = match query.time_period() {
+            Some(time_period) => self.find_overlapping(time_period).await?,
+            None => self.find_future().await?,
+        };
+        Ok(query.apply(candidates, Utc::now()))
+    }
+
     /// ResourceUsageを保存（新規作成または更新）
     ///
     /// Domain ID (UUID) を持つResourceUsageを保存します。
@@ -0,0 +1,33 @@
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::ports::notifier::ReminderKind;
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+
+/// 送信済みリマインダーのリポジトリポート
+///
+/// 予約とリマインダーの種類ごとに、リマインダーを送ったときの基準時刻
+/// （開始前なら開始時刻、終了前なら終了時刻）を記録する。
+/// 再起動後も同じ予約に重複してリマインダーを送らないために使う。
+#[async_trait]
+pub trait SentReminderRepository: Send + Sync {
+    /// 指定した種類の送信済みの記録を取得（予約ID → 送信時の基準時刻）
+    async fn find_all(
+        &self,
+        kind: ReminderKind,
+    ) -> Result<HashMap<UsageId, DateTime<Utc>>, RepositoryError>;
+
+    /// リマインダーを送信済みとして記録
+    ///
+    /// 同じ予約・種類の記録が既にある場合は上書きする。
+    async fn save(
+        &self,
+        kind: ReminderKind,
+        usage_id: &UsageId,
+        at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+
+    /// 指定した予約以外の記録を削除（すべての種類が対象）
+    async fn retain(&self, usage_ids: &HashSet<UsageId>) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,349 @@
+use crate::domain::aggregates::resource_usage::{
+    entity::ResourceUsage,
+    value_objects::{Resource, TimePeriod},
+};
+use crate::domain::common::EmailAddress;
+use chrono::{DateTime, Utc};
+
+/// リソース使用予定の進行状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageStatus {
+    /// 開始前
+    Upcoming,
+    /// 使用中
+    Ongoing,
+    /// 終了済み
+    Ended,
+}
+
+impl UsageStatus {
+    /// 指定時刻におけるリソース使用予定の進行状況を判定
+    pub fn of(usage: &ResourceUsage, now: DateTime<Utc>) -> Self {
+        let period = usage.time_period();
+        if now < period.start() {
+            Self::Upcoming
+        } else if now < period.end() {
+            Self::Ongoing
+        } else {
+            Self::Ended
+        }
+    }
+}
+
+/// リソース使用予定の並び順
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UsageSort {
+    /// 開始時刻の早い順
+    #[default]
+    StartAscending,
+    /// 開始時刻の遅い順
+    StartDescending,
+}
+
+/// リソース使用予定の検索条件
+///
+/// 指定した条件はすべて満たすもの（AND）に絞り込む。
+/// `ResourceUsageRepository::query` に渡して使う。
+///
+/// ```rust,ignore
+/// let query = UsageQuery::new()
+///     .with_owner(email)
+///     .with_resource("Thalys")
+///     .with_tag("a100", &resource_config.resource_tags())
+///     .with_status(UsageStatus::Upcoming)
+///     .with_limit(10);
+/// let usages = repository.query(&query).await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UsageQuery {
+    owner: Option<EmailAddress>,
+    resource_name: Option<String>,
+    tagged_resources: Option<Vec<Resource>>,
+    time_period: Option<TimePeriod>,
+    status: Option<UsageStatus>,
+    sort: UsageSort,
+    offset: usize,
+    limit: Option<usize>,
+}
+
+impl UsageQuery {
+    /// 条件なし（すべて・開始時刻の早い順）の検索条件を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 予約者で絞り込む
+    pub fn with_owner(mut self, owner: EmailAddress) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// リソース名（サーバー名または部屋名）で絞り込む
+    ///
+    /// サーバー名を指定した場合、そのサーバーのいずれかのGPUを含む予約が対象になる。
+    /// 大文字小文字は区別しない。
+    pub fn with_resource(mut self, resource_name: impl Into<String>) -> Self {
+        self.resource_name = Some(resource_name.into());
+        self
+    }
+
+    /// タグで絞り込む
+    ///
+    /// タグはリソース設定でサーバー・デバイスに付けるため、タグの付いたリソースとそのタグ
+    /// （`ResourceConfig::resource_tags`）から、指定したタグの付いたリソースを解決する。
+    /// タグの大文字小文字は区別しない。タグの付いたリソースを1つでも含む予約が対象になる。
+    ///
+    /// # Arguments
+    /// * `tag` - 絞り込むタグ
+    /// * `resource_tags` - タグの付いたリソースとそのタグ
+    pub fn with_tag(mut self, tag: &str, resource_tags: &[(Resource, Vec<String>)]) -> Self {
+        self.tagged_resources = Some(
+            resource_tags
+                .iter()
+                .filter(|(_, tags)| tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+                .map(|(resource, _)| resource.clone())
+                .collect(),
+        );
+        self
+    }
+
+    /// 指定期間と重複するもので絞り込む
+    pub fn with_time_period(mut self, time_period: TimePeriod) -> Self {
+        self.time_period = Some(time_period);
+        self
+    }
+
+    /// 進行状況で絞り込む
+    pub fn with_status(mut self, status: UsageStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// 並び順を指定
+    pub fn with_sort(mut self, sort: UsageSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// 先頭から読み飛ばす件数を指定
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// 取得する最大件数を指定
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// 期間の条件
+    pub fn time_period(&self) -> Option<&TimePeriod> {
+        self.time_period.as_ref()
+    }
+
+    /// リソース使用予定が絞り込み条件を満たすか
+    ///
+    /// # Arguments
+    /// * `usage` - 判定するリソース使用予定
+    /// * `now` - 進行状況の判定に使う現在時刻
+    pub fn matches(&self, usage: &ResourceUsage, now: DateTime<Utc>) -> bool {
+        self.owner
+            .as_ref()
+            .is_none_or(|owner| usage.owner_email() == owner)
+            && self.resource_name.as_deref().is_none_or(|name| {
+                usage
+                    .resources()
+                    .iter()
+                    .any(|resource| resource.name().eq_ignore_ascii_case(name))
+            })
+            && self.tagged_resources.as_ref().is_none_or(|tagged| {
+                usage
+                    .resources()
+                    .iter()
+                    .any(|resource| tagged.contains(resource))
+            })
+            && self
+                .time_period
+                .as_ref()
+                .is_none_or(|period| usage.time_period().overlaps_with(period))
+            && self
+                .status
+                .is_none_or(|status| UsageStatus::of(usage, now) == status)
+    }
+
+    /// 絞り込み・並べ替え・ページングを適用する
+    ///
+    /// リポジトリの実装は、取得したリソース使用予定にこれを適用して `query` の結果とする。
+    ///
+    /// # Arguments
+    /// * `usages` - 候補のリソース使用予定
+    /// * `now` - 進行状況の判定に使う現在時刻
+    pub fn apply(&self, usages: Vec<ResourceUsage>, now: DateTime<Utc>) -> Vec<ResourceUsage> {
+        let mut usages: Vec<ResourceUsage> = usages
+            .into_iter()
+            .filter(|usage| self.matches(usage, now))
+            .collect();
+
+        match self.sort {
+            UsageSort::StartAscending => usages.sort_by_key(|u| u.time_period().start()),
+            UsageSort::StartDescending => {
+                usages.sort_by_key(|u| std::cmp::Reverse(u.time_period().start()))
+            }
+        }
+
+        usages
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource};
+    use chrono::{Duration, TimeZone};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 4, 1, 12, 0, 0).unwrap()
+    }
+
+    fn usage(owner: &str, resource: Resource, start_hours: i64) -> ResourceUsage {
+        let start = now() + Duration::hours(start_hours);
+        ResourceUsage::new(
+            EmailAddress::new(owner.to_string()).unwrap(),
+            TimePeriod::new(start, start + Duration::hours(2)).unwrap(),
+            vec![resource],
+            None,
+        )
+        .unwrap()
+    }
+
+    fn gpu(server: &str) -> Resource {
+        Resource::Gpu(Gpu::new(server.to_string(), 0, "A100".to_string()))
+    }
+
+    fn room(name: &str) -> Resource {
+        Resource::Room {
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_usage_status() {
+        assert_eq!(
+            UsageStatus::of(&usage("a@example.com", gpu("Thalys"), 1), now()),
+            UsageStatus::Upcoming
+        );
+        assert_eq!(
+            UsageStatus::of(&usage("a@example.com", gpu("Thalys"), -1), now()),
+            UsageStatus::Ongoing
+        );
+        assert_eq!(
+            UsageStatus::of(&usage("a@example.com", gpu("Thalys"), -3), now()),
+            UsageStatus::Ended
+        );
+    }
+
+    #[test]
+    fn test_filters_are_combined() {
+        let usages = vec![
+            usage("alice@example.com", gpu("Thalys"), 1),
+            usage("alice@example.com", room("会議室"), 1),
+            usage("bob@example.com", gpu("Thalys"), 1),
+            usage("alice@example.com", gpu("Thalys"), -1),
+        ];
+
+        let query = UsageQuery::new()
+            .with_owner(EmailAddress::new("alice@example.com".to_string()).unwrap())
+            .with_resource("Thalys")
+            .with_status(UsageStatus::Upcoming);
+        let result = query.apply(usages.clone(), now());
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id(), usages[0].id());
+    }
+
+    #[test]
+    fn test_tag_filter_resolves_tagged_resources() {
+        let gpu_on = |server: &str, device| {
+            Resource::Gpu(Gpu::new(server.to_string(), device, "A100".to_string()))
+        };
+        let usages = vec![
+            usage("a@example.com", gpu_on("Thalys", 0), 1),
+            usage("a@example.com", gpu_on("Thalys", 1), 1),
+            usage("a@example.com", gpu_on("Luna", 0), 1),
+        ];
+        let resource_tags = vec![
+            (gpu_on("Thalys", 0), vec!["A100".to_string()]),
+            (
+                gpu_on("Luna", 0),
+                vec!["a100".to_string(), "teaching-only".to_string()],
+            ),
+        ];
+
+        let result = UsageQuery::new()
+            .with_tag("a100", &resource_tags)
+            .apply(usages.clone(), now());
+        let ids: Vec<_> = result.iter().map(|u| u.id().clone()).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(usages[0].id()));
+        assert!(ids.contains(usages[2].id()));
+
+        let untagged = UsageQuery::new()
+            .with_tag("large-memory", &resource_tags)
+            .apply(usages, now());
+        assert!(untagged.is_empty());
+    }
+
+    #[test]
+    fn test_resource_filter_ignores_case() {
+        let usages = vec![usage("a@example.com", gpu("Thalys"), 1)];
+
+        let result = UsageQuery::new()
+            .with_resource("thalys")
+            .apply(usages, now());
+
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn test_time_period_filter() {
+        let usages = vec![
+            usage("a@example.com", gpu("Thalys"), 1),
+            usage("a@example.com", gpu("Thalys"), 10),
+        ];
+        let period = TimePeriod::new(now(), now() + Duration::hours(5)).unwrap();
+
+        let result = UsageQuery::new()
+            .with_time_period(period)
+            .apply(usages.clone(), now());
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id(), usages[0].id());
+    }
+
+    #[test]
+    fn test_sort_and_paging() {
+        let usages: Vec<ResourceUsage> = [3, 1, 4, 2]
+            .into_iter()
+            .map(|h| usage("a@example.com", gpu("Thalys"), h))
+            .collect();
+        let starts = |result: Vec<ResourceUsage>| -> Vec<i64> {
+            result
+                .iter()
+                .map(|u| (u.time_period().start() - now()).num_hours())
+                .collect()
+        };
+
+        let ascending = UsageQuery::new().with_offset(1).with_limit(2);
+        assert_eq!(starts(ascending.apply(usages.clone(), now())), vec![2, 3]);
+
+        let descending = UsageQuery::new()
+            .with_sort(UsageSort::StartDescending)
+            .with_limit(3);
+        assert_eq!(starts(descending.apply(usages, now())), vec![4, 3, 2]);
+    }
+}
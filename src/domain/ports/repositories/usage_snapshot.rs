@@ -0,0 +1,30 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// 変更検知で確認した予約1件の記録
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageSnapshotEntry {
+    /// 予約内容のフィンガープリント
+    pub fingerprint: String,
+    /// 確認した時点の予約（停止中に削除された予約の通知に使う）
+    pub usage: ResourceUsage,
+}
+
+/// 変更検知で最後に確認した予約の状態のリポジトリポート
+///
+/// 予約ID → 予約内容とそのフィンガープリントを記録する。
+/// 再起動後に、停止中に作成・更新・削除された予約だけを通知するために使う。
+#[async_trait]
+pub trait UsageSnapshotRepository: Send + Sync {
+    /// 記録した状態を取得（まだ記録がない場合は `None`）
+    async fn load(&self) -> Result<Option<HashMap<UsageId, UsageSnapshotEntry>>, RepositoryError>;
+
+    /// 記録した状態を置き換える
+    async fn save(
+        &self,
+        snapshot: &HashMap<UsageId, UsageSnapshotEntry>,
+    ) -> Result<(), RepositoryError>;
+}
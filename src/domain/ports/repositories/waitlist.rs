@@ -0,0 +1,21 @@
+use crate::domain::aggregates::waitlist::WaitlistEntry;
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+
+/// Waitlist集約のリポジトリポート
+#[async_trait]
+pub trait WaitlistRepository: Send + Sync {
+    /// すべての空き待ちを登録の古い順に取得
+    async fn find_all(&self) -> Result<Vec<WaitlistEntry>, RepositoryError>;
+
+    /// 空き待ちを保存
+    ///
+    /// 同じ識別子の空き待ちが既にある場合は上書きする。
+    async fn save(&self, entry: WaitlistEntry) -> Result<(), RepositoryError>;
+
+    /// 空き待ちを削除
+    ///
+    /// # Returns
+    /// 削除した場合は `true`、空き待ちが無かった場合は `false`
+    async fn delete(&self, id: &str) -> Result<bool, RepositoryError>;
+}
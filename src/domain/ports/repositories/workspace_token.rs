@@ -0,0 +1,17 @@
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+
+/// ワークスペースごとのBot Tokenのリポジトリポート
+///
+/// 複数のSlackワークスペースにBotをインストールした場合に、
+/// インストール時に発行されたBot Token（xoxb-...）をワークスペース（Team ID）ごとに保持する。
+#[async_trait]
+pub trait WorkspaceTokenRepository: Send + Sync {
+    /// ワークスペースのBot Tokenを取得（インストールされていない場合は `None`）
+    async fn find_by_team_id(&self, team_id: &str) -> Result<Option<String>, RepositoryError>;
+
+    /// ワークスペースのBot Tokenを保存
+    ///
+    /// 同じワークスペースのBot Tokenが既にある場合は上書きする（再インストール）。
+    async fn save(&self, team_id: &str, bot_token: &str) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,90 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::{errors::DomainError, ports::PortError};
+use async_trait::async_trait;
+use std::fmt;
+use std::str::FromStr;
+
+/// 予約の書き出し形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// CSV（1行に1件の予約）
+    Csv,
+    /// JSON（予約の配列）
+    Json,
+    /// iCalendar（すべての予約を1つの .ics にまとめる）
+    ICalendar,
+}
+
+impl ExportFormat {
+    /// 形式に対応するファイルの拡張子
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::ICalendar => "ics",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            "ics" | "ical" | "icalendar" => Ok(Self::ICalendar),
+            _ => Err(format!(
+                "不明な書き出し形式です: {}（csv, json, ics のいずれか）",
+                s
+            )),
+        }
+    }
+}
+
+/// 予約の書き出しのエラー型
+#[derive(Debug, Clone)]
+pub enum ExportError {
+    /// 書き出し先への書き込みエラー
+    WriteFailure(String),
+    /// 予約を書き出し形式に変換できない
+    SerializationFailure(String),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WriteFailure(msg) => write!(f, "書き出しに失敗しました: {}", msg),
+            Self::SerializationFailure(msg) => write!(f, "予約を変換できません: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+impl DomainError for ExportError {}
+impl PortError for ExportError {}
+
+/// 予約の一覧を外部に書き出すサービスのインターフェース
+///
+/// 学期末の利用報告などのために、予約をCSV・JSON・iCalendarで書き出す。
+#[async_trait]
+pub trait ReservationExporter: Send + Sync {
+    /// 予約の一覧を指定した形式で書き出す
+    async fn export(
+        &self,
+        usages: &[ResourceUsage],
+        format: ExportFormat,
+    ) -> Result<(), ExportError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_export_format() {
+        assert_eq!("CSV".parse::<ExportFormat>(), Ok(ExportFormat::Csv));
+        assert_eq!("ics".parse::<ExportFormat>(), Ok(ExportFormat::ICalendar));
+        assert!("xlsx".parse::<ExportFormat>().is_err());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::common::EmailAddress;
+use crate::domain::{errors::DomainError, ports::PortError};
+use async_trait::async_trait;
+use std::fmt;
+
+/// 取り込む予約1件分の内容
+#[derive(Debug, Clone)]
+pub struct ReservationDraft {
+    /// 予約者のメールアドレス
+    pub owner_email: EmailAddress,
+    /// 予約期間
+    pub time_period: TimePeriod,
+    /// 予約するリソース
+    pub resources: Vec<Resource>,
+    /// 備考
+    pub notes: Option<String>,
+}
+
+/// 取り込み元の1行
+///
+/// 行の内容を解釈できない場合は、`draft` に理由を持つ。
+#[derive(Debug, Clone)]
+pub struct ImportRow {
+    /// 取り込み元での行番号（1始まり、ヘッダーを含む）
+    pub line: usize,
+    /// 行から読み取った予約の内容
+    pub draft: Result<ReservationDraft, String>,
+}
+
+/// 予約の取り込みのエラー型
+#[derive(Debug, Clone)]
+pub enum ImportError {
+    /// 取り込み元を読み込めない
+    ReadFailure(String),
+    /// 取り込み元の形式が不正（ヘッダーが無いなど）
+    InvalidFormat(String),
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReadFailure(msg) => write!(f, "読み込みに失敗しました: {}", msg),
+            Self::InvalidFormat(msg) => write!(f, "形式が不正です: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+impl DomainError for ImportError {}
+impl PortError for ImportError {}
+
+/// 取り込む予約の読み込み元のインターフェース
+///
+/// 講義の部屋の予約など、表計算ソフトで計画した予約をまとめて取り込むために使う。
+#[async_trait]
+pub trait ReservationSource: Send + Sync {
+    /// 取り込む予約をすべて読み込む
+    async fn read(&self) -> Result<Vec<ImportRow>, ImportError>;
+}
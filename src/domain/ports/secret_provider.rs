@@ -0,0 +1,39 @@
+use crate::domain::{errors::DomainError, ports::PortError};
+use async_trait::async_trait;
+use std::fmt;
+
+/// シークレット取得のエラー型
+#[derive(Debug, Clone)]
+pub enum SecretError {
+    /// シークレットが登録されていない
+    NotFound(String),
+    /// シークレットの保管先との通信エラー
+    ConnectionError(String),
+    /// 保管先の応答や設定が不正
+    Invalid(String),
+}
+
+impl fmt::Display for SecretError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(name) => write!(f, "シークレットが見つかりません: {}", name),
+            Self::ConnectionError(msg) => write!(f, "シークレットの取得に失敗しました: {}", msg),
+            Self::Invalid(msg) => write!(f, "シークレットを読み取れません: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SecretError {}
+impl DomainError for SecretError {}
+impl PortError for SecretError {}
+
+/// シークレット（トークンや鍵）の取得元のインターフェース
+///
+/// Slackのトークンやサービスアカウントキーを、環境変数・ファイル・Vaultなどの保管先から取得する。
+#[async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// 名前（例: `SLACK_BOT_TOKEN`）を指定してシークレットを取得する
+    ///
+    /// 登録されていない場合は `SecretError::NotFound` を返す。
+    async fn get_secret(&self, name: &str) -> Result<String, SecretError>;
+}
@@ -0,0 +1,64 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::ports::notifier::NotificationError;
+use async_trait::async_trait;
+use chrono::Duration;
+
+/// デバイスごとの予約時間
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceUsageTime {
+    /// サーバー名
+    pub server: String,
+    /// デバイス番号
+    pub device_number: u32,
+    /// 予約時間の合計
+    pub time: Duration,
+}
+
+/// サーバーごとの今週の空き
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerCapacity {
+    /// サーバー名
+    pub server: String,
+    /// すべてのデバイスの今週の時間の合計（デバイス数×1週間）
+    pub total: Duration,
+    /// 今週すでに予約されている時間の合計
+    pub reserved: Duration,
+}
+
+impl ServerCapacity {
+    /// 予約されていない時間
+    pub fn free(&self) -> Duration {
+        (self.total - self.reserved).max(Duration::zero())
+    }
+}
+
+/// 週間の利用状況のまとめ
+#[derive(Debug, Clone)]
+pub struct WeeklyDigest {
+    /// 先週の期間
+    pub last_week: TimePeriod,
+    /// 今週の期間
+    pub this_week: TimePeriod,
+    /// 先週予約されたGPU時間の合計（GPU 1台につき1時間で1時間）
+    pub reserved_gpu_time: Duration,
+    /// 先週最も予約されたデバイス（予約時間の長い順）
+    pub busiest_devices: Vec<DeviceUsageTime>,
+    /// 今週開始する大きな予約（開始の早い順）
+    pub big_bookings: Vec<ResourceUsage>,
+    /// サーバーごとの今週の空き
+    pub capacities: Vec<ServerCapacity>,
+}
+
+/// 週間の利用状況のまとめの送信ポート
+#[async_trait]
+pub trait WeeklyDigestSender: Send + Sync {
+    /// まとめをチャンネルに投稿する
+    ///
+    /// `channel_id` は投稿先となるSlackのチャンネルID。
+    async fn send_digest(
+        &self,
+        channel_id: &str,
+        digest: &WeeklyDigest,
+    ) -> Result<(), NotificationError>;
+}
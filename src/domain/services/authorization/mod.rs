@@ -11,9 +11,12 @@
 //!
 //! - `policy` - 認可ポリシーの基本トレイトとエラー型
 //! - `resource_usage_policy` - リソース使用予定の認可ポリシー実装
+//! - `role` - 利用者の役割（管理者・モデレーター・一般メンバー）
 
 pub mod policy;
 pub mod resource_usage_policy;
+pub mod role;
 
 pub use policy::{AuthorizationError, AuthorizationPolicy};
 pub use resource_usage_policy::ResourceUsageAuthorizationPolicy;
+pub use role::Role;
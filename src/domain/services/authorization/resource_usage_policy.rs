@@ -1,21 +1,107 @@
 use super::policy::{AuthorizationError, AuthorizationPolicy};
+use super::role::Role;
+use crate::domain::aggregates::group::Group;
 use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
 use crate::domain::common::EmailAddress;
 
-/// ResourceUsageの認可ポリシー
+/// 役割に基づくResourceUsageの認可ポリシー
 ///
-/// 所有者（owner）のみが更新・削除できるシンプルなポリシー
+/// 所有者（owner）と予約を所有するチームのメンバーは、自分たちの予約を更新・削除できる。
+/// 管理者はすべての予約を更新・削除でき、モデレーターはすべての予約を削除できる。
+/// メールアドレスは、所有者・チーム・役割のいずれの判定でも大文字小文字を区別しない。
 #[derive(Debug, Clone, Default)]
-pub struct ResourceUsageAuthorizationPolicy;
+pub struct ResourceUsageAuthorizationPolicy {
+    /// 管理者のメールアドレス
+    admins: Vec<EmailAddress>,
+    /// モデレーターのメールアドレス
+    moderators: Vec<EmailAddress>,
+    /// 予約を所有できるチーム
+    groups: Vec<Group>,
+}
 
 impl ResourceUsageAuthorizationPolicy {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// 管理者を設定
+    ///
+    /// 管理者は他のユーザーの予約も更新・削除できる。
+    pub fn with_admins(mut self, admins: Vec<EmailAddress>) -> Self {
+        self.admins = admins;
+        self
     }
 
-    /// 所有者かどうかをチェック
+    /// モデレーターを設定
+    ///
+    /// モデレーターは他のユーザーの予約も削除できる（更新はできない）。
+    pub fn with_moderators(mut self, moderators: Vec<EmailAddress>) -> Self {
+        self.moderators = moderators;
+        self
+    }
+
+    /// チームを設定
+    ///
+    /// チームが所有する予約は、そのチームのメンバーも更新・削除できる。
+    pub fn with_groups(mut self, groups: Vec<Group>) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// 所有者かどうかをチェック（大文字小文字は区別しない）
     fn is_owner(&self, actor: &EmailAddress, resource: &ResourceUsage) -> bool {
-        resource.owner_email() == actor
+        resource
+            .owner_email()
+            .as_str()
+            .eq_ignore_ascii_case(actor.as_str())
+    }
+
+    /// 役割を求める（大文字小文字は区別しない）
+    fn role(&self, actor: &EmailAddress) -> Role {
+        let listed = |emails: &[EmailAddress]| {
+            emails
+                .iter()
+                .any(|email| email.as_str().eq_ignore_ascii_case(actor.as_str()))
+        };
+        if listed(&self.admins) {
+            Role::Admin
+        } else if listed(&self.moderators) {
+            Role::Moderator
+        } else {
+            Role::Member
+        }
+    }
+
+    /// 予約を所有するチームのメンバーかどうかをチェック
+    fn is_group_member(&self, actor: &EmailAddress, resource: &ResourceUsage) -> bool {
+        resource.group().is_some_and(|group_id| {
+            self.groups
+                .iter()
+                .any(|group| group.id() == group_id && group.is_member(actor))
+        })
+    }
+
+    /// 自分たち（本人またはチーム）の予約かどうか
+    fn is_own(&self, actor: &EmailAddress, resource: &ResourceUsage) -> bool {
+        self.is_owner(actor, resource) || self.is_group_member(actor, resource)
+    }
+
+    /// 予約者の変更（引き継ぎ）の権限をチェック
+    ///
+    /// 引き継げるのは予約者本人と管理者のみ（チームのメンバーは引き継げない）。
+    pub fn authorize_transfer(
+        &self,
+        actor: &EmailAddress,
+        resource: &ResourceUsage,
+    ) -> Result<(), AuthorizationError> {
+        if !self.is_owner(actor, resource) && !self.role(actor).can_update_others() {
+            return Err(AuthorizationError::Forbidden {
+                actor: actor.clone(),
+                action: "transfer".to_string(),
+                resource: format!("ResourceUsage({})", resource.id().as_str()),
+            });
+        }
+        Ok(())
     }
 }
 
@@ -25,7 +111,7 @@ impl AuthorizationPolicy<ResourceUsage> for ResourceUsageAuthorizationPolicy {
         actor: &EmailAddress,
         resource: &ResourceUsage,
     ) -> Result<(), AuthorizationError> {
-        if !self.is_owner(actor, resource) {
+        if !self.is_own(actor, resource) && !self.role(actor).can_update_others() {
             return Err(AuthorizationError::Forbidden {
                 actor: actor.clone(),
                 action: "update".to_string(),
@@ -40,7 +126,7 @@ impl AuthorizationPolicy<ResourceUsage> for ResourceUsageAuthorizationPolicy {
         actor: &EmailAddress,
         resource: &ResourceUsage,
     ) -> Result<(), AuthorizationError> {
-        if !self.is_owner(actor, resource) {
+        if !self.is_own(actor, resource) && !self.role(actor).can_cancel_others() {
             return Err(AuthorizationError::Forbidden {
                 actor: actor.clone(),
                 action: "delete".to_string(),
@@ -50,3 +136,176 @@ impl AuthorizationPolicy<ResourceUsage> for ResourceUsageAuthorizationPolicy {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::group::GroupId;
+    use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+    use chrono::{Duration, Utc};
+
+    fn email(s: &str) -> EmailAddress {
+        EmailAddress::new(s.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_owner_and_admins_can_delete() {
+        let start = Utc::now();
+        let usage = ResourceUsage::new(
+            email("owner@example.com"),
+            TimePeriod::new(start, start + Duration::hours(1)).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            None,
+        )
+        .unwrap();
+        let policy =
+            ResourceUsageAuthorizationPolicy::new().with_admins(vec![email("Admin@Example.com")]);
+
+        assert!(
+            policy
+                .authorize_delete(&email("owner@example.com"), &usage)
+                .is_ok()
+        );
+        // 所有者も管理者と同じく大文字小文字を区別しない
+        assert!(
+            policy
+                .authorize_delete(&email("Owner@Example.com"), &usage)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .authorize_transfer(&email("OWNER@example.com"), &usage)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .authorize_delete(&email("admin@example.com"), &usage)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .authorize_delete(&email("other@example.com"), &usage)
+                .is_err()
+        );
+        assert!(
+            ResourceUsageAuthorizationPolicy::new()
+                .authorize_delete(&email("admin@example.com"), &usage)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_group_members_can_update_group_reservation() {
+        let start = Utc::now();
+        let usage = ResourceUsage::new(
+            email("owner@example.com"),
+            TimePeriod::new(start, start + Duration::hours(1)).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            None,
+        )
+        .unwrap();
+        let nlp = GroupId::new("nlp".to_string());
+        let policy = ResourceUsageAuthorizationPolicy::new().with_groups(vec![Group::new(
+            nlp.clone(),
+            "NLPグループ".to_string(),
+            vec![email("member@example.com")],
+        )]);
+
+        assert!(
+            policy
+                .authorize_update(&email("member@example.com"), &usage)
+                .is_err()
+        );
+
+        let usage = usage.with_group(Some(nlp));
+        assert!(
+            policy
+                .authorize_update(&email("member@example.com"), &usage)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .authorize_delete(&email("member@example.com"), &usage)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .authorize_update(&email("other@example.com"), &usage)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_moderators_can_delete_but_not_update() {
+        let start = Utc::now();
+        let usage = ResourceUsage::new(
+            email("owner@example.com"),
+            TimePeriod::new(start, start + Duration::hours(1)).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            None,
+        )
+        .unwrap();
+        let policy = ResourceUsageAuthorizationPolicy::new()
+            .with_admins(vec![email("admin@example.com")])
+            .with_moderators(vec![email("Moderator@Example.com")]);
+
+        let moderator = email("moderator@example.com");
+        assert!(policy.authorize_delete(&moderator, &usage).is_ok());
+        assert!(policy.authorize_update(&moderator, &usage).is_err());
+
+        let admin = email("admin@example.com");
+        assert!(policy.authorize_delete(&admin, &usage).is_ok());
+        assert!(policy.authorize_update(&admin, &usage).is_ok());
+    }
+
+    #[test]
+    fn test_only_owner_and_admins_can_transfer() {
+        let start = Utc::now();
+        let nlp = GroupId::new("nlp".to_string());
+        let usage = ResourceUsage::new(
+            email("owner@example.com"),
+            TimePeriod::new(start, start + Duration::hours(1)).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            None,
+        )
+        .unwrap()
+        .with_group(Some(nlp.clone()));
+        let policy = ResourceUsageAuthorizationPolicy::new()
+            .with_admins(vec![email("admin@example.com")])
+            .with_moderators(vec![email("moderator@example.com")])
+            .with_groups(vec![Group::new(
+                nlp,
+                "NLPグループ".to_string(),
+                vec![email("member@example.com")],
+            )]);
+
+        assert!(
+            policy
+                .authorize_transfer(&email("owner@example.com"), &usage)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .authorize_transfer(&email("admin@example.com"), &usage)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .authorize_transfer(&email("member@example.com"), &usage)
+                .is_err()
+        );
+        assert!(
+            policy
+                .authorize_transfer(&email("moderator@example.com"), &usage)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! 利用者の役割
+
+/// 利用者の役割
+///
+/// 役割によって、他の利用者の予約に対してできる操作が決まる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Role {
+    /// 一般メンバー（自分の予約だけを操作できる）
+    #[default]
+    Member,
+    /// モデレーター（他の利用者の予約もキャンセルできる）
+    Moderator,
+    /// 管理者（他の利用者の予約も更新・キャンセルでき、管理者用コマンドを実行できる）
+    Admin,
+}
+
+impl Role {
+    /// 他の利用者の予約を更新できるかどうか
+    pub fn can_update_others(self) -> bool {
+        self == Self::Admin
+    }
+
+    /// 他の利用者の予約をキャンセルできるかどうか
+    pub fn can_cancel_others(self) -> bool {
+        self >= Self::Moderator
+    }
+}
@@ -11,6 +11,6 @@ pub mod authorization;
 pub mod resource_usage;
 
 pub use authorization::{
-    AuthorizationError, AuthorizationPolicy, ResourceUsageAuthorizationPolicy,
+    AuthorizationError, AuthorizationPolicy, ResourceUsageAuthorizationPolicy, Role,
 };
-pub use resource_usage::ResourceConflictChecker;
+pub use resource_usage::{ResourceAllocationService, ResourceConflictChecker};
@@ -0,0 +1,392 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod, UsageId};
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use chrono::{DateTime, Duration, Utc};
+
+/// 分割予約の1区画（同じ時間帯にまとめて予約できるリソース群）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceAllocation {
+    /// 予約する時間帯
+    pub time_period: TimePeriod,
+    /// この時間帯に予約するリソース
+    pub resources: Vec<Resource>,
+}
+
+/// 部分的に競合する予約に対する分割案
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SplitProposal {
+    /// 空いている区画（開始時刻順）
+    pub allocations: Vec<ResourceAllocation>,
+    /// 希望期間中に一切空きがないリソース
+    pub unavailable: Vec<Resource>,
+}
+
+impl SplitProposal {
+    /// 予約可能な区画が1つもないかどうか
+    pub fn is_empty(&self) -> bool {
+        self.allocations.is_empty()
+    }
+}
+
+/// リソース割り当てサービス
+///
+/// 希望した時間帯とリソースが既存の予約と部分的に競合する場合に、
+/// 空いている部分だけを予約する分割案を計算する。
+/// （例: デバイス0-1は全期間、デバイス2は15:00以降のみ）
+#[derive(Debug, Clone)]
+pub struct ResourceAllocationService {
+    /// これより短い空き時間は分割案に含めない
+    min_segment: Duration,
+}
+
+impl Default for ResourceAllocationService {
+    fn default() -> Self {
+        Self::new(Duration::minutes(15))
+    }
+}
+
+impl ResourceAllocationService {
+    /// 新しいサービスを作成
+    ///
+    /// # Arguments
+    /// * `min_segment` - 分割案に含める空き時間の最小長
+    pub fn new(min_segment: Duration) -> Self {
+        Self { min_segment }
+    }
+
+    /// リポジトリの予約状況から分割案を計算
+    ///
+    /// # Arguments
+    /// * `repository` - リソース使用リポジトリ
+    /// * `time_period` - 希望する時間帯
+    /// * `resources` - 希望するリソース
+    /// * `exclude_usage_id` - 計算から除外するUsageID（更新時に自分自身を除外するため）
+    ///
+    /// # Returns
+    /// 競合がなく分割の必要がない場合は `None`
+    pub async fn propose_split<R: ResourceUsageRepository>(
+        &self,
+        repository: &R,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+        exclude_usage_id: Option<&UsageId>,
+    ) -> Result<Option<SplitProposal>, RepositoryError> {
+        let overlapping = repository.find_overlapping(time_period).await?;
+        let existing: Vec<&ResourceUsage> = overlapping
+            .iter()
+            .filter(|usage| exclude_usage_id != Some(usage.id()))
+            .collect();
+
+        Ok(self.split(time_period, resources, &existing))
+    }
+
+    /// 既存の予約から分割案を計算
+    ///
+    /// # Returns
+    /// 競合がなく分割の必要がない場合は `None`
+    pub fn split(
+        &self,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+        existing: &[&ResourceUsage],
+    ) -> Option<SplitProposal> {
+        let mut has_conflict = false;
+        let mut proposal = SplitProposal::default();
+
+        for resource in resources {
+            let busy = Self::busy_intervals(time_period, resource, existing);
+            has_conflict |= !busy.is_empty();
+
+            let free = self.free_intervals(time_period, &busy);
+            if free.is_empty() {
+                proposal.unavailable.push(resource.clone());
+                continue;
+            }
+
+            for period in free {
+                match proposal
+                    .allocations
+                    .iter_mut()
+                    .find(|a| a.time_period == period)
+                {
+                    Some(allocation) => allocation.resources.push(resource.clone()),
+                    None => proposal.allocations.push(ResourceAllocation {
+                        time_period: period,
+                        resources: vec![resource.clone()],
+                    }),
+                }
+            }
+        }
+
+        if !has_conflict {
+            return None;
+        }
+
+        proposal.allocations.sort_by_key(|a| a.time_period.start());
+        Some(proposal)
+    }
+
+    /// リポジトリの予約状況から、すべてのリソースが続けて空いている最も早い時間帯を探す
+    ///
+    /// # Arguments
+    /// * `repository` - リソース使用リポジトリ
+    /// * `search_range` - 探す範囲（この範囲に収まる時間帯だけを返す）
+    /// * `resources` - 使用するリソース
+    /// * `duration` - 必要な時間の長さ
+    ///
+    /// # Returns
+    /// 範囲内に空いている時間帯がない場合は `None`
+    pub async fn find_next_slot<R: ResourceUsageRepository>(
+        &self,
+        repository: &R,
+        search_range: &TimePeriod,
+        resources: &[Resource],
+        duration: Duration,
+    ) -> Result<Option<TimePeriod>, RepositoryError> {
+        let overlapping = repository.find_overlapping(search_range).await?;
+        let existing: Vec<&ResourceUsage> = overlapping.iter().collect();
+
+        Ok(self.next_slot(search_range, resources, &existing, duration))
+    }
+
+    /// 既存の予約から、すべてのリソースが続けて空いている最も早い時間帯を計算
+    ///
+    /// 時間帯は探す範囲の開始時刻か、既存の予約の終了時刻から始まる。
+    ///
+    /// # Returns
+    /// 範囲内に空いている時間帯がない場合は `None`
+    pub fn next_slot(
+        &self,
+        search_range: &TimePeriod,
+        resources: &[Resource],
+        existing: &[&ResourceUsage],
+        duration: Duration,
+    ) -> Option<TimePeriod> {
+        let mut busy: Vec<(DateTime<Utc>, DateTime<Utc>)> = resources
+            .iter()
+            .flat_map(|resource| Self::busy_intervals(search_range, resource, existing))
+            .collect();
+        busy.sort();
+
+        let mut cursor = search_range.start();
+        for (start, end) in busy
+            .into_iter()
+            .chain(std::iter::once((search_range.end(), search_range.end())))
+        {
+            if start - cursor >= duration {
+                return TimePeriod::new(cursor, cursor + duration).ok();
+            }
+            cursor = cursor.max(end);
+        }
+
+        None
+    }
+
+    /// 希望期間内でリソースが使用中の時間帯を取得（開始時刻順にマージ済み）
+    fn busy_intervals(
+        time_period: &TimePeriod,
+        resource: &Resource,
+        existing: &[&ResourceUsage],
+    ) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        let mut intervals: Vec<(DateTime<Utc>, DateTime<Utc>)> = existing
+            .iter()
+            .filter(|usage| usage.time_period().overlaps_with(time_period))
+            .filter(|usage| usage.resources().iter().any(|r| r.conflicts_with(resource)))
+            .map(|usage| {
+                (
+                    usage.time_period().start().max(time_period.start()),
+                    usage.time_period().end().min(time_period.end()),
+                )
+            })
+            .collect();
+
+        intervals.sort();
+
+        let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+        for (start, end) in intervals {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+
+    /// 使用中の時間帯を除いた空き時間帯を取得
+    fn free_intervals(
+        &self,
+        time_period: &TimePeriod,
+        busy: &[(DateTime<Utc>, DateTime<Utc>)],
+    ) -> Vec<TimePeriod> {
+        let mut free = Vec::new();
+        let mut cursor = time_period.start();
+
+        for (start, end) in busy
+            .iter()
+            .copied()
+            .chain(std::iter::once((time_period.end(), time_period.end())))
+        {
+            if start - cursor >= self.min_segment
+                && let Ok(period) = TimePeriod::new(cursor, start)
+            {
+                free.push(period);
+            }
+            cursor = cursor.max(end);
+        }
+
+        free
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::common::EmailAddress;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, hour, 0, 0).unwrap()
+    }
+
+    fn period(start: u32, end: u32) -> TimePeriod {
+        TimePeriod::new(at(start), at(end)).unwrap()
+    }
+
+    fn gpu(device: u32) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()))
+    }
+
+    fn usage(start: u32, end: u32, resources: Vec<Resource>) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("other@example.com".to_string()).unwrap(),
+            period(start, end),
+            resources,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_no_conflict_returns_none() {
+        let service = ResourceAllocationService::default();
+        let existing = usage(9, 10, vec![gpu(0)]);
+
+        let result = service.split(&period(10, 18), &[gpu(0), gpu(1)], &[&existing]);
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_split_by_device_and_time() {
+        let service = ResourceAllocationService::default();
+        let existing = usage(9, 15, vec![gpu(2)]);
+
+        let proposal = service
+            .split(&period(10, 18), &[gpu(0), gpu(1), gpu(2)], &[&existing])
+            .unwrap();
+
+        assert_eq!(
+            proposal.allocations,
+            vec![
+                ResourceAllocation {
+                    time_period: period(10, 18),
+                    resources: vec![gpu(0), gpu(1)],
+                },
+                ResourceAllocation {
+                    time_period: period(15, 18),
+                    resources: vec![gpu(2)],
+                },
+            ]
+        );
+        assert!(proposal.unavailable.is_empty());
+    }
+
+    #[test]
+    fn test_gap_in_middle_produces_two_segments() {
+        let service = ResourceAllocationService::default();
+        let existing = usage(12, 14, vec![gpu(0)]);
+
+        let proposal = service
+            .split(&period(10, 18), &[gpu(0)], &[&existing])
+            .unwrap();
+
+        assert_eq!(
+            proposal
+                .allocations
+                .iter()
+                .map(|a| a.time_period.clone())
+                .collect::<Vec<_>>(),
+            vec![period(10, 12), period(14, 18)]
+        );
+    }
+
+    #[test]
+    fn test_fully_blocked_resource_is_unavailable() {
+        let service = ResourceAllocationService::default();
+        let first = usage(9, 13, vec![gpu(0)]);
+        let second = usage(12, 19, vec![gpu(0)]);
+
+        let proposal = service
+            .split(&period(10, 18), &[gpu(0)], &[&first, &second])
+            .unwrap();
+
+        assert!(proposal.is_empty());
+        assert_eq!(proposal.unavailable, vec![gpu(0)]);
+    }
+
+    #[test]
+    fn test_short_segments_are_dropped() {
+        let service = ResourceAllocationService::new(Duration::hours(2));
+        let existing = usage(11, 17, vec![gpu(0)]);
+
+        let proposal = service
+            .split(&period(10, 18), &[gpu(0)], &[&existing])
+            .unwrap();
+
+        assert!(proposal.is_empty());
+    }
+
+    #[test]
+    fn test_next_slot_waits_for_every_resource() {
+        let service = ResourceAllocationService::default();
+        let first = usage(9, 12, vec![gpu(0)]);
+        let second = usage(11, 14, vec![gpu(1)]);
+        let later = usage(16, 18, vec![gpu(0)]);
+
+        let slot = service.next_slot(
+            &period(10, 20),
+            &[gpu(0), gpu(1)],
+            &[&first, &second, &later],
+            Duration::hours(2),
+        );
+
+        assert_eq!(slot, Some(period(14, 16)));
+    }
+
+    #[test]
+    fn test_next_slot_skips_short_gaps() {
+        let service = ResourceAllocationService::default();
+        let first = usage(9, 12, vec![gpu(0)]);
+        let second = usage(13, 15, vec![gpu(0)]);
+
+        let slot = service.next_slot(
+            &period(10, 20),
+            &[gpu(0)],
+            &[&first, &second],
+            Duration::hours(2),
+        );
+
+        assert_eq!(slot, Some(period(15, 17)));
+    }
+
+    #[test]
+    fn test_next_slot_must_fit_in_range() {
+        let service = ResourceAllocationService::default();
+        let existing = usage(9, 17, vec![gpu(0)]);
+
+        let slot = service.next_slot(&period(10, 18), &[gpu(0)], &[&existing], Duration::hours(2));
+
+        assert!(slot.is_none());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::errors::DomainError;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::fmt;
+
+/// リソースごとの予約を受け付ける期間
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BookingWindow {
+    /// 開始時刻のどれだけ前までに予約する必要があるか
+    pub min_notice: Option<Duration>,
+    /// 開始時刻をどれだけ先まで予約できるか
+    pub max_advance: Option<Duration>,
+}
+
+/// 受付期間の違反の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookingWindowViolation {
+    /// 開始までの時間が短すぎる（必要な時間）
+    NoticeTooShort(Duration),
+    /// 開始時刻が先すぎる（予約できる期間）
+    TooFarAhead(Duration),
+}
+
+/// リソースごとの予約の受付期間ポリシー
+///
+/// 「部屋は1時間前までに予約する」「GPUは30日先までしか予約できない」といった規則を、
+/// リソース名（GPUの場合はサーバー名）ごとに判定する。受付期間は開始時刻で判定する。
+#[derive(Debug, Clone, Default)]
+pub struct BookingWindowPolicy {
+    windows: HashMap<String, BookingWindow>,
+}
+
+impl BookingWindowPolicy {
+    /// 受付期間の制限がないポリシーを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// リソースの受付期間を追加
+    pub fn with_window(mut self, resource_name: impl Into<String>, window: BookingWindow) -> Self {
+        self.windows.insert(resource_name.into(), window);
+        self
+    }
+
+    /// 受付期間が1つも設定されていないかどうか
+    pub fn is_unrestricted(&self) -> bool {
+        self.windows
+            .values()
+            .all(|window| *window == BookingWindow::default())
+    }
+
+    /// 予約が受付期間内か確認
+    ///
+    /// # Arguments
+    /// * `time_period` - 予約の期間
+    /// * `resources` - 予約のリソース
+    /// * `now` - 現在時刻
+    ///
+    /// # Errors
+    /// いずれかのリソースの受付期間外の場合
+    pub fn check(
+        &self,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+        now: DateTime<Utc>,
+    ) -> Result<(), BookingWindowError> {
+        let lead_time = time_period.start() - now;
+        for resource in resources {
+            let Some(window) = self.windows.get(resource.name()) else {
+                continue;
+            };
+            if let Some(min_notice) = window.min_notice
+                && lead_time < min_notice
+            {
+                return Err(BookingWindowError {
+                    resource: resource.name().to_string(),
+                    violation: BookingWindowViolation::NoticeTooShort(min_notice),
+                });
+            }
+            if let Some(max_advance) = window.max_advance
+                && lead_time > max_advance
+            {
+                return Err(BookingWindowError {
+                    resource: resource.name().to_string(),
+                    violation: BookingWindowViolation::TooFarAhead(max_advance),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 予約が受付期間外のエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookingWindowError {
+    /// 受付期間外のリソース名（GPUの場合はサーバー名）
+    pub resource: String,
+    /// 違反の種類
+    pub violation: BookingWindowViolation,
+}
+
+impl fmt::Display for BookingWindowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.violation {
+            BookingWindowViolation::NoticeTooShort(min_notice) => write!(
+                f,
+                "{}は開始の{}分前までに予約してください",
+                self.resource,
+                min_notice.num_minutes()
+            ),
+            BookingWindowViolation::TooFarAhead(max_advance) => write!(
+                f,
+                "{}は{}日先までしか予約できません",
+                self.resource,
+                max_advance.num_days()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BookingWindowError {}
+
+impl DomainError for BookingWindowError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap()
+    }
+
+    fn starting_in(lead_time: Duration) -> TimePeriod {
+        TimePeriod::new(now() + lead_time, now() + lead_time + Duration::hours(1)).unwrap()
+    }
+
+    fn policy() -> BookingWindowPolicy {
+        BookingWindowPolicy::new()
+            .with_window(
+                "会議室A",
+                BookingWindow {
+                    min_notice: Some(Duration::hours(1)),
+                    max_advance: None,
+                },
+            )
+            .with_window(
+                "Thalys",
+                BookingWindow {
+                    min_notice: None,
+                    max_advance: Some(Duration::days(30)),
+                },
+            )
+    }
+
+    fn room() -> Resource {
+        Resource::Room {
+            name: "会議室A".to_string(),
+        }
+    }
+
+    fn gpu(server: &str) -> Resource {
+        Resource::Gpu(Gpu::new(server.to_string(), 0, "A100".to_string()))
+    }
+
+    #[test]
+    fn test_min_notice() {
+        let policy = policy();
+
+        assert!(
+            policy
+                .check(&starting_in(Duration::hours(1)), &[room()], now())
+                .is_ok()
+        );
+        let err = policy
+            .check(&starting_in(Duration::minutes(30)), &[room()], now())
+            .unwrap_err();
+        assert_eq!(err.resource, "会議室A");
+        assert_eq!(
+            err.violation,
+            BookingWindowViolation::NoticeTooShort(Duration::hours(1))
+        );
+    }
+
+    #[test]
+    fn test_max_advance_applies_only_to_configured_resources() {
+        let policy = policy();
+
+        assert!(
+            policy
+                .check(&starting_in(Duration::days(30)), &[gpu("Thalys")], now())
+                .is_ok()
+        );
+        let err = policy
+            .check(&starting_in(Duration::days(31)), &[gpu("Thalys")], now())
+            .unwrap_err();
+        assert_eq!(
+            err.violation,
+            BookingWindowViolation::TooFarAhead(Duration::days(30))
+        );
+
+        // 受付期間のないサーバーは制限しない
+        assert!(
+            policy
+                .check(&starting_in(Duration::days(60)), &[gpu("Freccia")], now())
+                .is_ok()
+        );
+    }
+}
@@ -1,16 +1,148 @@
-use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod, UsageId};
-use crate::domain::ports::repositories::ResourceUsageRepository;
-use crate::domain::services::resource_usage::errors::{ConflictCheckError, ResourceConflictError};
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    Resource, SeriesId, TimePeriod, UsageId,
+};
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::domain::services::resource_usage::errors::{
+    CapacityExceededError, ConflictCheckError, ResourceConflictError,
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// 既存の予約との競合
+#[derive(Debug, Clone)]
+pub struct ResourceConflict {
+    /// 競合したリソース
+    pub resource: Resource,
+    /// 競合している既存の予約
+    pub existing_usage: ResourceUsage,
+}
 
 /// リソース競合チェックサービス
 ///
-/// 指定された時間帯とリソースが既存の予約と競合しないかをチェックする
+/// 指定された時間帯とリソースが既存の予約と競合しないかをチェックする。
+/// ストレージは、同じボリュームの予約の確保容量の合計が容量を超える場合に競合とみなす。
+/// ライセンスも同様に、同じライセンスの予約の確保席数の合計が席数を超える場合に競合とみなす。
 #[derive(Debug, Clone, Default)]
-pub struct ResourceConflictChecker;
+pub struct ResourceConflictChecker {
+    /// ストレージのボリューム名と容量（GB）
+    storage_capacities: HashMap<String, u64>,
+    /// ライセンス名と席数
+    license_seats: HashMap<String, u32>,
+}
 
 impl ResourceConflictChecker {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// ストレージのボリュームごとの容量（GB）を設定
+    ///
+    /// 容量が設定されていないボリュームは、容量の上限なしとして扱う。
+    pub fn with_storage_capacities(mut self, storage_capacities: HashMap<String, u64>) -> Self {
+        self.storage_capacities = storage_capacities;
+        self
+    }
+
+    /// ライセンスごとの席数を設定
+    ///
+    /// 席数が設定されていないライセンスは、席数の上限なしとして扱う。
+    pub fn with_license_seats(mut self, license_seats: HashMap<String, u32>) -> Self {
+        self.license_seats = license_seats;
+        self
+    }
+
+    /// 既存の予約と競合するリソースをすべて取得
+    ///
+    /// # Arguments
+    /// * `repository` - リソース使用リポジトリ
+    /// * `time_period` - チェック対象の時間帯
+    /// * `resources` - チェック対象のリソースリスト
+    /// * `exclude_usage_id` - チェックから除外するUsageID（更新時に自分自身を除外するため）
+    ///
+    /// # Returns
+    /// 競合したリソースと既存の予約の組（`resources` の順）。競合がない場合は空
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn find_conflicts<R: ResourceUsageRepository>(
+        &self,
+        repository: &R,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+        exclude_usage_id: Option<&UsageId>,
+    ) -> Result<Vec<ResourceConflict>, RepositoryError> {
+        // 指定期間と重複する予約を検索
+        let overlapping = repository.find_overlapping(time_period).await?;
+        Ok(self.conflicts_among(&overlapping, time_period, resources, exclude_usage_id))
+    }
+
+    /// 指定した予約がないものとして、既存の予約と競合するリソースをすべて取得
+    ///
+    /// 予約を横取りする前に、横取りする予約を取り消せば予約できるかを確かめるために使う。
+    /// ストレージとライセンスの確保量の合計にも、除外した予約は数えない。
+    ///
+    /// # Arguments
+    /// * `repository` - リソース使用リポジトリ
+    /// * `time_period` - チェック対象の時間帯
+    /// * `resources` - チェック対象のリソースリスト
+    /// * `excluded` - ないものとして扱う予約のID
+    ///
+    /// # Returns
+    /// 競合したリソースと既存の予約の組（`resources` の順）。競合がない場合は空
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn find_conflicts_excluding<R: ResourceUsageRepository>(
+        &self,
+        repository: &R,
+        time_period: &TimePeriod,
+        resources: &[Resource],
+        excluded: &[UsageId],
+    ) -> Result<Vec<ResourceConflict>, RepositoryError> {
+        let overlapping: Vec<ResourceUsage> = repository
+            .find_overlapping(time_period)
+            .await?
+            .into_iter()
+            .filter(|usage| !excluded.contains(usage.id()))
+            .collect();
+        Ok(self.conflicts_among(&overlapping, time_period, resources, None))
+    }
+
+    /// 繰り返し予約のすべての回について、既存の予約と競合するリソースを取得
+    ///
+    /// 期間全体と重なる予約を一度だけ検索し、回ごとに競合を判定する。
+    /// `exclude_series` を指定した場合、そのシリーズの予約（変更前の同じ繰り返し予約）は競合とみなさない。
+    ///
+    /// # Arguments
+    /// * `repository` - リソース使用リポジトリ
+    /// * `occurrences` - 繰り返し予約の各回の使用期間（開始時刻順）
+    /// * `resources` - チェック対象のリソースリスト
+    /// * `exclude_series` - チェックから除外するシリーズID
+    ///
+    /// # Returns
+    /// 競合したリソースと既存の予約の組（回の順、回の中では `resources` の順）。競合がない場合は空
+    ///
+    /// # Errors
+    /// - リポジトリエラー
+    pub async fn find_series_conflicts<R: ResourceUsageRepository>(
+        &self,
+        repository: &R,
+        occurrences: &[TimePeriod],
+        resources: &[Resource],
+        exclude_series: Option<&SeriesId>,
+    ) -> Result<Vec<ResourceConflict>, RepositoryError> {
+        let (Some(first), Some(last)) = (occurrences.first(), occurrences.last()) else {
+            return Ok(Vec::new());
+        };
+        let span = TimePeriod::new(first.start(), last.end())?;
+        let candidates: Vec<ResourceUsage> = repository
+            .find_overlapping(&span)
+            .await?
+            .into_iter()
+            .filter(|usage| exclude_series.is_none() || usage.series_id() != exclude_series)
+            .collect();
+        Ok(self.series_conflicts_among(&candidates, occurrences, resources))
     }
 
     /// リソース競合をチェック
@@ -22,7 +154,7 @@ impl ResourceConflictChecker {
     /// * `exclude_usage_id` - チェックから除外するUsageID（更新時に自分自身を除外するため）
     ///
     /// # Returns
-    /// 競合がない場合はOk(())、競合がある場合はエラー
+    /// 競合がない場合はOk(())、競合がある場合は競合したすべてのリソースを含むエラー
     ///
     /// # Errors
     /// - 競合するリソースがある場合
@@ -34,31 +166,524 @@ impl ResourceConflictChecker {
         resources: &[Resource],
         exclude_usage_id: Option<&UsageId>,
     ) -> Result<(), ConflictCheckError> {
-        // 指定期間と重複する予約を検索
-        let overlapping = repository.find_overlapping(time_period).await?;
+        self.check_capacity(resources)?;
+        let conflicts = self
+            .find_conflicts(repository, time_period, resources, exclude_usage_id)
+            .await?;
+
+        match ResourceConflictError::from_conflicts(&conflicts) {
+            Some(conflict) => Err(ConflictCheckError::Conflict(conflict)),
+            None => Ok(()),
+        }
+    }
+
+    /// 1件の予約だけでストレージの容量・ライセンスの席数を超えないことを確認
+    ///
+    /// 重なる予約がなくても予約できないため、既存の予約との競合とは別に確認する。
+    /// 容量・席数が設定されていないボリューム・ライセンスは確認しない。
+    ///
+    /// # Errors
+    /// 確保量が上限を超えるリソースがある場合
+    pub fn check_capacity(&self, resources: &[Resource]) -> Result<(), CapacityExceededError> {
+        for resource in resources {
+            if let Some((Some(limit), requested)) = self.pooled_limit(resource)
+                && requested > limit
+            {
+                return Err(CapacityExceededError {
+                    resource: resource.clone(),
+                    limit,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// ストレージ・ライセンスの上限（設定されていない場合は `None`）と確保量
+    ///
+    /// 上限と確保量の合計で競合を判定しないリソースの場合は `None` を返す。
+    fn pooled_limit(&self, resource: &Resource) -> Option<(Option<u64>, u64)> {
+        match resource {
+            Resource::Storage { volume, gigabytes } => {
+                Some((self.storage_capacities.get(volume).copied(), *gigabytes))
+            }
+            Resource::License { name, seats } => Some((
+                self.license_seats.get(name).map(|seats| u64::from(*seats)),
+                u64::from(*seats),
+            )),
+            Resource::Gpu(_)
+            | Resource::Room { .. }
+            | Resource::Instrument { .. }
+            | Resource::Custom { .. } => None,
+        }
+    }
+
+    /// 繰り返し予約の回ごとに、その回と重なる予約の中から競合する予約を探す
+    fn series_conflicts_among(
+        &self,
+        candidates: &[ResourceUsage],
+        occurrences: &[TimePeriod],
+        resources: &[Resource],
+    ) -> Vec<ResourceConflict> {
+        occurrences
+            .iter()
+            .flat_map(|period| {
+                let overlapping: Vec<ResourceUsage> = candidates
+                    .iter()
+                    .filter(|usage| usage.time_period().overlaps_with(period))
+                    .cloned()
+                    .collect();
+                self.conflicts_among(&overlapping, period, resources, None)
+            })
+            .collect()
+    }
 
-        // リソースの競合チェック
+    /// 重複する予約の中から、リソースごとに競合する予約を探す
+    fn conflicts_among(
+        &self,
+        overlapping: &[ResourceUsage],
+        time_period: &TimePeriod,
+        resources: &[Resource],
+        exclude_usage_id: Option<&UsageId>,
+    ) -> Vec<ResourceConflict> {
+        let mut conflicts = Vec::new();
         for new_resource in resources {
-            for existing_usage in &overlapping {
+            // ストレージとライセンスは、上限（容量・席数）と確保量の合計で競合を判定する
+            if let Some((limit, requested)) = self.pooled_limit(new_resource) {
+                if let Some(existing_usage) = limit.and_then(|limit| {
+                    pooled_conflict(
+                        overlapping,
+                        time_period,
+                        new_resource,
+                        limit,
+                        requested,
+                        exclude_usage_id,
+                    )
+                }) {
+                    conflicts.push(ResourceConflict {
+                        resource: new_resource.clone(),
+                        existing_usage: existing_usage.clone(),
+                    });
+                }
+                continue;
+            }
+
+            for existing_usage in overlapping {
                 // 除外対象の場合はスキップ
-                if let Some(exclude_id) = exclude_usage_id
-                    && existing_usage.id() == exclude_id
-                {
+                if exclude_usage_id.is_some_and(|exclude_id| existing_usage.id() == exclude_id) {
                     continue;
                 }
 
                 // 既存予約のリソースと競合チェック
-                for existing_resource in existing_usage.resources() {
-                    if new_resource.conflicts_with(existing_resource) {
-                        return Err(ConflictCheckError::Conflict(ResourceConflictError::new(
-                            new_resource.to_string(),
-                            existing_usage.id().clone(),
-                        )));
-                    }
+                if existing_usage
+                    .resources()
+                    .iter()
+                    .any(|existing_resource| new_resource.conflicts_with(existing_resource))
+                {
+                    conflicts.push(ResourceConflict {
+                        resource: new_resource.clone(),
+                        existing_usage: existing_usage.clone(),
+                    });
                 }
             }
         }
+        conflicts
+    }
+}
 
-        Ok(())
+/// 同じストレージ・ライセンスの確保量の合計が上限を超える場合に、原因となる既存の予約を探す
+///
+/// 期間内で既存の予約の確保量の合計が最大になる時点を調べ、
+/// そこに `requested` を加えると `limit` を超える場合は、その時点で最も多く確保している予約を返す。
+fn pooled_conflict<'a>(
+    overlapping: &'a [ResourceUsage],
+    time_period: &TimePeriod,
+    resource: &Resource,
+    limit: u64,
+    requested: u64,
+    exclude_usage_id: Option<&UsageId>,
+) -> Option<&'a ResourceUsage> {
+    // 同じストレージ・ライセンスを確保している予約と、その確保量
+    let allocations: Vec<(&ResourceUsage, u64)> = overlapping
+        .iter()
+        .filter(|usage| exclude_usage_id != Some(usage.id()))
+        .filter(|usage| usage.time_period().overlaps_with(time_period))
+        .map(|usage| {
+            let allocated = usage
+                .resources()
+                .iter()
+                .filter_map(|existing| pooled_amount(resource, existing))
+                .sum::<u64>();
+            (usage, allocated)
+        })
+        .filter(|(_, allocated)| *allocated > 0)
+        .collect();
+
+    // 期間内の開始・終了を時刻順に走査する（同時刻では終了を先に処理する）
+    let mut events: Vec<(DateTime<Utc>, bool, usize)> = Vec::new();
+    for (index, (usage, _)) in allocations.iter().enumerate() {
+        events.push((
+            usage.time_period().start().max(time_period.start()),
+            true,
+            index,
+        ));
+        events.push((
+            usage.time_period().end().min(time_period.end()),
+            false,
+            index,
+        ));
+    }
+    events.sort_by_key(|(at, is_start, _)| (*at, *is_start));
+
+    let mut active: Vec<usize> = Vec::new();
+    let mut used = 0u64;
+    for (_, is_start, index) in events {
+        if !is_start {
+            active.retain(|i| *i != index);
+            used -= allocations[index].1;
+            continue;
+        }
+        active.push(index);
+        used += allocations[index].1;
+        if used.saturating_add(requested) > limit {
+            return active
+                .iter()
+                .map(|i| allocations[*i])
+                .max_by_key(|(_, allocated)| *allocated)
+                .map(|(usage, _)| usage);
+        }
+    }
+    None
+}
+
+/// `existing` が `resource` と同じストレージ・ライセンスであれば、その確保量を返す
+fn pooled_amount(resource: &Resource, existing: &Resource) -> Option<u64> {
+    match (resource, existing) {
+        (
+            Resource::Storage { volume, .. },
+            Resource::Storage {
+                volume: other,
+                gigabytes,
+            },
+        ) if volume == other => Some(*gigabytes),
+        (Resource::License { name, .. }, Resource::License { name: other, seats })
+            if name == other =>
+        {
+            Some(u64::from(*seats))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::common::EmailAddress;
+    use chrono::{TimeZone, Utc};
+
+    fn period(start: u32, end: u32) -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 1, 15, start, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 15, end, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn gpu(device: u32) -> Resource {
+        Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()))
+    }
+
+    fn usage(resources: Vec<Resource>) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("other@example.com".to_string()).unwrap(),
+            period(10, 12),
+            resources,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_conflicts_among_lists_each_conflicting_resource() {
+        let first = usage(vec![gpu(0)]);
+        let second = usage(vec![gpu(1), gpu(2)]);
+        let overlapping = vec![first.clone(), second.clone()];
+
+        let conflicts = ResourceConflictChecker::new().conflicts_among(
+            &overlapping,
+            &period(9, 13),
+            &[gpu(0), gpu(2), gpu(3)],
+            None,
+        );
+
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].resource, gpu(0));
+        assert_eq!(conflicts[0].existing_usage.id(), first.id());
+        assert_eq!(conflicts[1].resource, gpu(2));
+        assert_eq!(conflicts[1].existing_usage.id(), second.id());
+    }
+
+    #[test]
+    fn test_gpus_conflict_per_device_regardless_of_model() {
+        let existing = usage(vec![gpu(0)]);
+        let renamed = Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100 80GB".to_string()));
+
+        let checker = ResourceConflictChecker::new();
+        let overlapping = std::slice::from_ref(&existing);
+        assert!(
+            checker
+                .conflicts_among(overlapping, &period(9, 13), &[gpu(1)], None)
+                .is_empty()
+        );
+        assert_eq!(
+            checker
+                .conflicts_among(overlapping, &period(9, 13), &[renamed], None)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_conflict_error_lists_every_conflicting_resource() {
+        let first = usage(vec![gpu(0)]);
+        let second = usage(vec![gpu(1), gpu(2)]);
+
+        let conflicts = ResourceConflictChecker::new().conflicts_among(
+            &[first.clone(), second.clone()],
+            &period(9, 13),
+            &[gpu(0), gpu(1), gpu(2), gpu(3)],
+            None,
+        );
+        let error = ResourceConflictError::from_conflicts(&conflicts).unwrap();
+
+        assert_eq!(
+            error.resource_descriptions,
+            vec![gpu(0).to_string(), gpu(1).to_string(), gpu(2).to_string()]
+        );
+        assert_eq!(
+            error.conflicting_usage_ids,
+            vec![first.id().clone(), second.id().clone()]
+        );
+        assert!(ResourceConflictError::from_conflicts(&[]).is_none());
+    }
+
+    #[test]
+    fn test_conflicts_among_skips_excluded_usage() {
+        let existing = usage(vec![gpu(0)]);
+
+        let conflicts = ResourceConflictChecker::new().conflicts_among(
+            std::slice::from_ref(&existing),
+            &period(9, 13),
+            &[gpu(0)],
+            Some(existing.id()),
+        );
+
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_series_conflicts_only_for_overlapping_occurrences() {
+        let week = chrono::Duration::weeks(1);
+        let existing = usage(vec![gpu(0)]);
+        let next_week = ResourceUsage::new(
+            EmailAddress::new("other@example.com".to_string()).unwrap(),
+            TimePeriod::new(period(10, 12).start() + week, period(10, 12).end() + week).unwrap(),
+            vec![gpu(1)],
+            None,
+        )
+        .unwrap();
+        let occurrences: Vec<TimePeriod> = (0..3)
+            .map(|i| {
+                TimePeriod::new(
+                    period(11, 13).start() + week * i,
+                    period(11, 13).end() + week * i,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let conflicts = ResourceConflictChecker::new().series_conflicts_among(
+            &[existing.clone(), next_week.clone()],
+            &occurrences,
+            &[gpu(0), gpu(1)],
+        );
+
+        // 1回目はGPU 0、2回目はGPU 1と競合し、3回目は競合しない
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].existing_usage.id(), existing.id());
+        assert_eq!(conflicts[0].resource, gpu(0));
+        assert_eq!(conflicts[1].existing_usage.id(), next_week.id());
+        assert_eq!(conflicts[1].resource, gpu(1));
+    }
+
+    fn custom(kind: &str, name: &str) -> Resource {
+        Resource::Custom {
+            kind: kind.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_custom_resources_conflict_by_kind_and_name() {
+        let existing = usage(vec![custom("camera", "EOS R5")]);
+        let checker = ResourceConflictChecker::new();
+        let overlapping = std::slice::from_ref(&existing);
+
+        let conflicts = checker.conflicts_among(
+            overlapping,
+            &period(9, 13),
+            &[custom("camera", "EOS R5")],
+            None,
+        );
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].resource, custom("camera", "EOS R5"));
+
+        assert!(
+            checker
+                .conflicts_among(
+                    overlapping,
+                    &period(9, 13),
+                    &[custom("camera", "α7 IV"), custom("lens", "EOS R5")],
+                    None,
+                )
+                .is_empty()
+        );
+    }
+
+    fn storage(gigabytes: u64) -> Resource {
+        Resource::Storage {
+            volume: "scratch".to_string(),
+            gigabytes,
+        }
+    }
+
+    fn storage_usage(start: u32, end: u32, gigabytes: u64) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("other@example.com".to_string()).unwrap(),
+            period(start, end),
+            vec![storage(gigabytes)],
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_storage_conflicts_when_summed_capacity_is_exceeded() {
+        let checker = ResourceConflictChecker::new()
+            .with_storage_capacities(HashMap::from([("scratch".to_string(), 1000)]));
+        let small = storage_usage(10, 12, 300);
+        let large = storage_usage(11, 13, 500);
+        let overlapping = vec![small.clone(), large.clone()];
+
+        // 11時〜12時は合計800GB確保されているため、300GBは入らない
+        let conflicts =
+            checker.conflicts_among(&overlapping, &period(10, 13), &[storage(300)], None);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].existing_usage.id(), large.id());
+
+        // 200GBなら合計1000GBに収まる
+        assert!(
+            checker
+                .conflicts_among(&overlapping, &period(10, 13), &[storage(200)], None)
+                .is_empty()
+        );
+
+        // 12時以降は500GBのみのため、500GBまで確保できる
+        assert!(
+            checker
+                .conflicts_among(&overlapping, &period(12, 13), &[storage(500)], None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_storage_request_larger_than_volume_is_rejected() {
+        let checker = ResourceConflictChecker::new()
+            .with_storage_capacities(HashMap::from([("scratch".to_string(), 1000)]));
+
+        // 重なる予約がなくても、1件で容量を超える場合は予約できない
+        let error = checker.check_capacity(&[storage(2000)]).unwrap_err();
+        assert_eq!(error.resource, storage(2000));
+        assert_eq!(error.limit, 1000);
+
+        // 容量ちょうどまでは確保できる
+        assert!(checker.check_capacity(&[storage(1000)]).is_ok());
+    }
+
+    #[test]
+    fn test_storage_without_capacity_never_conflicts() {
+        let overlapping = vec![storage_usage(10, 12, 5000)];
+
+        assert!(
+            ResourceConflictChecker::new()
+                .conflicts_among(&overlapping, &period(10, 12), &[storage(5000)], None)
+                .is_empty()
+        );
+    }
+
+    fn license_usage(start: u32, end: u32, seats: u32) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("other@example.com".to_string()).unwrap(),
+            period(start, end),
+            vec![Resource::License {
+                name: "matlab".to_string(),
+                seats,
+            }],
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_license_conflicts_when_seats_are_exhausted() {
+        let checker = ResourceConflictChecker::new()
+            .with_license_seats(HashMap::from([("matlab".to_string(), 3)]));
+        let overlapping = vec![license_usage(10, 12, 1), license_usage(11, 13, 1)];
+        let license = |seats| Resource::License {
+            name: "matlab".to_string(),
+            seats,
+        };
+
+        // 11時〜12時は2席使われているため、残りは1席
+        assert!(
+            checker
+                .conflicts_among(&overlapping, &period(10, 13), &[license(1)], None)
+                .is_empty()
+        );
+        assert_eq!(
+            checker
+                .conflicts_among(&overlapping, &period(10, 13), &[license(2)], None)
+                .len(),
+            1
+        );
+
+        // 別のライセンスの予約は席数に数えない
+        let other = Resource::License {
+            name: "ansys".to_string(),
+            seats: 3,
+        };
+        assert!(
+            checker
+                .conflicts_among(&overlapping, &period(10, 13), &[other], None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_license_request_above_seat_count_is_rejected() {
+        let checker = ResourceConflictChecker::new()
+            .with_license_seats(HashMap::from([("matlab".to_string(), 3)]));
+        let license = |seats| Resource::License {
+            name: "matlab".to_string(),
+            seats,
+        };
+
+        // 重なる予約がなくても、1件で席数を超える場合は予約できない
+        let error = checker.check_capacity(&[license(4)]).unwrap_err();
+        assert_eq!(error.resource, license(4));
+        assert_eq!(error.limit, 3);
+        assert!(error.to_string().contains("3席"));
+
+        assert!(checker.check_capacity(&[license(3)]).is_ok());
     }
 }
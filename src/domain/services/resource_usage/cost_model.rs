@@ -0,0 +1,122 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use std::collections::HashMap;
+
+/// GPUの使用料金のモデル
+///
+/// GPUのモデルごとの1時間あたりの料金から、予約の費用を計算する。
+/// 研究費などに計算機の利用料を按分する研究室向け。料金を設定していないモデルのGPUは無料として扱う。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CostModel {
+    currency: String,
+    hourly_rates: HashMap<String, f64>,
+}
+
+impl CostModel {
+    /// 新しい料金モデルを作成
+    ///
+    /// # Arguments
+    /// * `currency` - 通貨の表記（例: "¥"）
+    pub fn new(currency: impl Into<String>) -> Self {
+        Self {
+            currency: currency.into(),
+            hourly_rates: HashMap::new(),
+        }
+    }
+
+    /// GPUのモデルの1時間あたりの料金を追加
+    pub fn with_hourly_rate(mut self, model: impl Into<String>, rate: f64) -> Self {
+        self.hourly_rates.insert(model.into(), rate);
+        self
+    }
+
+    /// 通貨の表記
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// GPUのモデルの1時間あたりの料金（設定していない場合は `None`）
+    pub fn hourly_rate(&self, model: &str) -> Option<f64> {
+        self.hourly_rates.get(model).copied()
+    }
+
+    /// 予約のうち `period` に含まれる部分の費用
+    ///
+    /// GPU 1台ごとに、使用時間とそのモデルの料金から計算する。
+    pub fn cost_of(&self, usage: &ResourceUsage, period: &TimePeriod) -> f64 {
+        let start = usage.time_period().start().max(period.start());
+        let end = usage.time_period().end().min(period.end());
+        if end <= start {
+            return 0.0;
+        }
+        let hours = (end - start).num_seconds() as f64 / 3600.0;
+
+        usage
+            .resources()
+            .iter()
+            .filter_map(|resource| match resource {
+                Resource::Gpu(gpu) => self.hourly_rate(gpu.model()),
+                _ => None,
+            })
+            .map(|rate| rate * hours)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::common::EmailAddress;
+    use chrono::{TimeZone, Utc};
+
+    fn period(start: u32, end: u32) -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 1, 15, start, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 15, end, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn usage(start: u32, end: u32, models: &[&str]) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            period(start, end),
+            models
+                .iter()
+                .enumerate()
+                .map(|(i, model)| {
+                    Resource::Gpu(Gpu::new("Thalys".to_string(), i as u32, model.to_string()))
+                })
+                .collect(),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cost_sums_each_device_and_skips_unpriced_models() {
+        let model = CostModel::new("¥")
+            .with_hourly_rate("A100", 300.0)
+            .with_hourly_rate("RTX", 100.0);
+
+        // 2時間 × (300 + 100)、料金のないモデルは無料
+        let cost = model.cost_of(&usage(10, 12, &["A100", "RTX", "V100"]), &period(0, 23));
+
+        assert_eq!(cost, 800.0);
+    }
+
+    #[test]
+    fn test_cost_counts_only_time_within_period() {
+        let model = CostModel::new("¥").with_hourly_rate("A100", 300.0);
+
+        assert_eq!(
+            model.cost_of(&usage(10, 14, &["A100"]), &period(12, 23)),
+            600.0
+        );
+        assert_eq!(
+            model.cost_of(&usage(10, 12, &["A100"]), &period(12, 23)),
+            0.0
+        );
+    }
+}
@@ -1,25 +1,59 @@
 //! リソース使用ドメインサービスのエラー
 
-use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, UsageId};
 use crate::domain::errors::DomainError;
 use crate::domain::ports::repositories::RepositoryError;
+use crate::domain::services::resource_usage::conflict_checker::ResourceConflict;
 use std::fmt;
 
 /// リソース競合エラー
+///
+/// 競合したすべてのリソースと、競合相手の予約を持つ。
 #[derive(Debug)]
 pub struct ResourceConflictError {
-    /// 競合しているリソースの説明
-    pub resource_description: String,
-    /// 競合している既存の使用予定ID
-    pub conflicting_usage_id: UsageId,
+    /// 競合しているリソースの説明（重複を除き、チェックしたリソースの順）
+    pub resource_descriptions: Vec<String>,
+    /// 競合している既存の使用予定ID（重複を除く）
+    pub conflicting_usage_ids: Vec<UsageId>,
 }
 
 impl ResourceConflictError {
-    pub fn new(resource_description: impl Into<String>, conflicting_usage_id: UsageId) -> Self {
-        Self {
-            resource_description: resource_description.into(),
-            conflicting_usage_id,
+    /// 競合の一覧からエラーを作成
+    ///
+    /// 競合がない場合は `None`
+    pub fn from_conflicts(conflicts: &[ResourceConflict]) -> Option<Self> {
+        if conflicts.is_empty() {
+            return None;
         }
+        let mut resource_descriptions: Vec<String> = Vec::new();
+        let mut conflicting_usage_ids: Vec<UsageId> = Vec::new();
+        for conflict in conflicts {
+            let description = conflict.resource.to_string();
+            if !resource_descriptions.contains(&description) {
+                resource_descriptions.push(description);
+            }
+            if !conflicting_usage_ids.contains(conflict.existing_usage.id()) {
+                conflicting_usage_ids.push(conflict.existing_usage.id().clone());
+            }
+        }
+        Some(Self {
+            resource_descriptions,
+            conflicting_usage_ids,
+        })
+    }
+
+    /// 競合しているリソースの説明（カンマ区切り）
+    pub fn resource_description(&self) -> String {
+        self.resource_descriptions.join(", ")
+    }
+
+    /// 競合している既存の使用予定ID（カンマ区切り）
+    pub fn conflicting_usage_id(&self) -> String {
+        self.conflicting_usage_ids
+            .iter()
+            .map(UsageId::as_str)
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 }
 
@@ -28,8 +62,8 @@ impl fmt::Display for ResourceConflictError {
         write!(
             f,
             "リソース競合: {} (競合する予約ID: {})",
-            self.resource_description,
-            self.conflicting_usage_id.as_str()
+            self.resource_description(),
+            self.conflicting_usage_id()
         )
     }
 }
@@ -38,11 +72,43 @@ impl std::error::Error for ResourceConflictError {}
 
 impl DomainError for ResourceConflictError {}
 
+/// 1件の予約だけでストレージの容量・ライセンスの席数を超えるエラー
+#[derive(Debug, Clone)]
+pub struct CapacityExceededError {
+    /// 上限を超えたリソース
+    pub resource: Resource,
+    /// 上限（ストレージは容量（GB）、ライセンスは席数）
+    pub limit: u64,
+}
+
+impl fmt::Display for CapacityExceededError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.resource {
+            Resource::License { .. } => write!(
+                f,
+                "{} は席数（{}席）を超えるため予約できません",
+                self.resource, self.limit
+            ),
+            _ => write!(
+                f,
+                "{} は容量（{}GB）を超えるため予約できません",
+                self.resource, self.limit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CapacityExceededError {}
+
+impl DomainError for CapacityExceededError {}
+
 /// 競合チェックで発生するエラー
 #[derive(Debug)]
 pub enum ConflictCheckError {
     /// リソース競合
     Conflict(ResourceConflictError),
+    /// 1件の予約だけで上限を超える
+    CapacityExceeded(CapacityExceededError),
     /// リポジトリエラー
     Repository(RepositoryError),
 }
@@ -51,6 +117,7 @@ impl fmt::Display for ConflictCheckError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ConflictCheckError::Conflict(e) => write!(f, "{}", e),
+            ConflictCheckError::CapacityExceeded(e) => write!(f, "{}", e),
             ConflictCheckError::Repository(e) => write!(f, "{}", e),
         }
     }
@@ -60,6 +127,7 @@ impl std::error::Error for ConflictCheckError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             ConflictCheckError::Conflict(e) => Some(e),
+            ConflictCheckError::CapacityExceeded(e) => Some(e),
             ConflictCheckError::Repository(e) => Some(e),
         }
     }
@@ -71,4 +139,10 @@ impl From<RepositoryError> for ConflictCheckError {
     }
 }
 
+impl From<CapacityExceededError> for ConflictCheckError {
+    fn from(e: CapacityExceededError) -> Self {
+        ConflictCheckError::CapacityExceeded(e)
+    }
+}
+
 impl DomainError for ConflictCheckError {}
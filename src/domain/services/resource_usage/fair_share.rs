@@ -0,0 +1,366 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource, TimePeriod};
+use crate::domain::common::EmailAddress;
+use chrono::{DateTime, Duration, Utc};
+
+/// 公平性の評価と代替案
+#[derive(Debug, Clone, PartialEq)]
+pub struct FairShareAdvice {
+    /// 評価したGPUのモデル
+    pub model: String,
+    /// 利用実績を集計した期間の長さ
+    pub window: Duration,
+    /// 集計期間に予約者が使用したGPU時間（GPUの台数×時間）
+    pub used: Duration,
+    /// 公平な配分に対する使用量の比（1.0で公平な配分と同じ）
+    pub score: f64,
+    /// 代わりに予約を検討できるサーバー
+    pub alternative: Option<String>,
+}
+
+/// 公平な利用のための助言ポリシー
+///
+/// 直近の一定期間の利用実績から、予約者が同じモデルのGPUを公平な配分
+/// （そのモデルの総使用量を利用者数で割った量）の何倍使っているかを評価する。
+/// しきい値を超える場合に、同じ時間帯に空いている別のサーバーを提案する。
+/// 予約を拒否するためのものではなく、確認メッセージで注意を促すために使う。
+#[derive(Debug, Clone)]
+pub struct FairSharePolicy {
+    window: Duration,
+    threshold: f64,
+    gpus: Vec<Gpu>,
+}
+
+impl FairSharePolicy {
+    /// 集計期間の既定値（日）
+    pub const DEFAULT_WINDOW_DAYS: u32 = 7;
+    /// 助言するしきい値の既定値（公平な配分の何倍か）
+    pub const DEFAULT_THRESHOLD: f64 = 1.5;
+
+    /// 新しいポリシーを作成
+    ///
+    /// # Arguments
+    /// * `window` - 利用実績を集計する期間の長さ
+    /// * `threshold` - 助言するしきい値（公平な配分の何倍か）
+    pub fn new(window: Duration, threshold: f64) -> Self {
+        Self {
+            window,
+            threshold,
+            gpus: Vec::new(),
+        }
+    }
+
+    /// 代替案の候補とするGPUを設定（設定の順に提案する）
+    pub fn with_gpus(mut self, gpus: Vec<Gpu>) -> Self {
+        self.gpus = gpus;
+        self
+    }
+
+    /// 利用実績を集計する期間の長さ
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// `now` までの集計期間
+    pub fn history_period(&self, now: DateTime<Utc>) -> Option<TimePeriod> {
+        TimePeriod::new(now - self.window, now).ok()
+    }
+
+    /// 予約者の使用量を評価する
+    ///
+    /// # Arguments
+    /// * `owner` - 予約者
+    /// * `now` - 現在時刻（集計期間の終わり）
+    /// * `history` - 集計期間と重なる予約（期間の外にはみ出した部分は数えない）
+    /// * `model` - 評価するGPUのモデル
+    ///
+    /// # Returns
+    /// 予約者の使用量と、公平な配分に対する使用量の比
+    pub fn score(
+        &self,
+        owner: &EmailAddress,
+        now: DateTime<Utc>,
+        history: &[ResourceUsage],
+        model: &str,
+    ) -> (Duration, f64) {
+        let mut totals: Vec<(&EmailAddress, Duration)> = vec![(owner, Duration::zero())];
+        for usage in history {
+            let start = usage.time_period().start().max(now - self.window);
+            let end = usage.time_period().end().min(now);
+            if end <= start {
+                continue;
+            }
+            let devices = usage
+                .resources()
+                .iter()
+                .filter(|r| matches!(r, Resource::Gpu(gpu) if gpu.model() == model))
+                .count() as i32;
+            if devices == 0 {
+                continue;
+            }
+            let time = (end - start) * devices;
+            match totals.iter_mut().find(|(e, _)| *e == usage.owner_email()) {
+                Some((_, total)) => *total += time,
+                None => totals.push((usage.owner_email(), time)),
+            }
+        }
+
+        let used = totals[0].1;
+        let total = totals
+            .iter()
+            .fold(Duration::zero(), |sum, (_, time)| sum + *time);
+        if total.is_zero() {
+            return (used, 0.0);
+        }
+        let fair_share = total.num_seconds() as f64 / totals.len() as f64;
+        (used, used.num_seconds() as f64 / fair_share)
+    }
+
+    /// 予約への助言を作成する
+    ///
+    /// 予約するGPUのうち最も台数の多いモデルについて評価し、しきい値以上の場合に助言を返す。
+    /// 代替案は、予約するサーバー以外で、予約者の評価がしきい値未満のモデルのGPUが
+    /// 同じ時間帯に同じ台数以上空いているサーバーから選ぶ。
+    ///
+    /// # Arguments
+    /// * `owner` - 予約者
+    /// * `now` - 現在時刻（集計期間の終わり）
+    /// * `history` - 集計期間と重なる予約
+    /// * `busy` - 予約する期間と重なる予約
+    /// * `time_period` - 予約する期間
+    /// * `resources` - 予約するリソース
+    ///
+    /// # Returns
+    /// GPUを含まない予約や、評価がしきい値未満の場合は `None`
+    pub fn advise(
+        &self,
+        owner: &EmailAddress,
+        now: DateTime<Utc>,
+        history: &[ResourceUsage],
+        busy: &[ResourceUsage],
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Option<FairShareAdvice> {
+        let requested: Vec<&Gpu> = resources
+            .iter()
+            .filter_map(|resource| match resource {
+                Resource::Gpu(gpu) => Some(gpu),
+                _ => None,
+            })
+            .collect();
+        let mut models: Vec<(&str, usize)> = Vec::new();
+        for gpu in &requested {
+            match models.iter_mut().find(|(model, _)| *model == gpu.model()) {
+                Some((_, count)) => *count += 1,
+                None => models.push((gpu.model(), 1)),
+            }
+        }
+        // 台数が同じ場合は先に予約したモデルを評価する
+        let (model, count) = models
+            .iter()
+            .rev()
+            .max_by_key(|(_, count)| *count)
+            .copied()?;
+
+        let (used, score) = self.score(owner, now, history, model);
+        if used.is_zero() || score < self.threshold {
+            return None;
+        }
+
+        Some(FairShareAdvice {
+            model: model.to_string(),
+            window: self.window,
+            used,
+            score,
+            alternative: self.alternative(
+                owner,
+                now,
+                history,
+                busy,
+                time_period,
+                &requested,
+                count,
+            ),
+        })
+    }
+
+    /// 代わりに予約を検討できるサーバーを探す
+    #[allow(clippy::too_many_arguments)]
+    fn alternative(
+        &self,
+        owner: &EmailAddress,
+        now: DateTime<Utc>,
+        history: &[ResourceUsage],
+        busy: &[ResourceUsage],
+        time_period: &TimePeriod,
+        requested: &[&Gpu],
+        count: usize,
+    ) -> Option<String> {
+        let mut servers: Vec<&str> = Vec::new();
+        for gpu in &self.gpus {
+            if !servers.contains(&gpu.server())
+                && !requested.iter().any(|r| r.server() == gpu.server())
+            {
+                servers.push(gpu.server());
+            }
+        }
+
+        servers
+            .into_iter()
+            .find(|server| {
+                self.gpus
+                    .iter()
+                    .filter(|gpu| gpu.server() == *server)
+                    .filter(|gpu| self.score(owner, now, history, gpu.model()).1 < self.threshold)
+                    .filter(|gpu| !is_busy(gpu, busy, time_period))
+                    .count()
+                    >= count
+            })
+            .map(str::to_string)
+    }
+}
+
+/// GPUが期間中に予約されているかどうか
+fn is_busy(gpu: &Gpu, busy: &[ResourceUsage], time_period: &TimePeriod) -> bool {
+    busy.iter().any(|usage| {
+        usage.time_period().overlaps_with(time_period)
+            && usage
+                .resources()
+                .iter()
+                .any(|r| matches!(r, Resource::Gpu(other) if other.is_same_device(gpu)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap()
+    }
+
+    fn period(start_day: u32, end_day: u32) -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 1, start_day, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, end_day, 0, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn email(name: &str) -> EmailAddress {
+        EmailAddress::new(format!("{}@example.com", name)).unwrap()
+    }
+
+    fn gpu(server: &str, device: u32, model: &str) -> Gpu {
+        Gpu::new(server.to_string(), device, model.to_string())
+    }
+
+    fn usage(owner: &str, time_period: TimePeriod, gpus: &[Gpu]) -> ResourceUsage {
+        ResourceUsage::new(
+            email(owner),
+            time_period,
+            gpus.iter().cloned().map(Resource::Gpu).collect(),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn policy() -> FairSharePolicy {
+        FairSharePolicy::new(Duration::days(7), 1.5).with_gpus(vec![
+            gpu("Thalys", 0, "A100"),
+            gpu("Thalys", 1, "A100"),
+            gpu("Freccia", 0, "RTX"),
+            gpu("Freccia", 1, "RTX"),
+        ])
+    }
+
+    #[test]
+    fn test_score_compares_with_fair_share() {
+        // 集計期間（1/8〜1/15）にaliceが3日、bobが1日使用
+        let history = vec![
+            usage("alice", period(9, 12), &[gpu("Thalys", 0, "A100")]),
+            usage("bob", period(12, 13), &[gpu("Thalys", 1, "A100")]),
+            // 集計期間より前の部分は数えない
+            usage("bob", period(1, 9), &[gpu("Thalys", 1, "A100")]),
+        ];
+
+        let (used, score) = policy().score(&email("alice"), now(), &history, "A100");
+
+        assert_eq!(used, Duration::days(3));
+        // 公平な配分は (3 + 1 + 1) / 2 = 2.5日
+        assert!((score - 1.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_advice_below_threshold() {
+        let history = vec![
+            usage("alice", period(9, 11), &[gpu("Thalys", 0, "A100")]),
+            usage("bob", period(9, 11), &[gpu("Thalys", 1, "A100")]),
+        ];
+
+        let advice = policy().advise(
+            &email("alice"),
+            now(),
+            &history,
+            &[],
+            &period(16, 17),
+            &[Resource::Gpu(gpu("Thalys", 0, "A100"))],
+        );
+
+        assert!(advice.is_none());
+    }
+
+    #[test]
+    fn test_advice_suggests_free_server_with_other_model() {
+        let history = vec![
+            usage(
+                "alice",
+                period(8, 14),
+                &[gpu("Thalys", 0, "A100"), gpu("Thalys", 1, "A100")],
+            ),
+            usage("bob", period(13, 14), &[gpu("Thalys", 1, "A100")]),
+        ];
+
+        let advice = policy()
+            .advise(
+                &email("alice"),
+                now(),
+                &history,
+                &[],
+                &period(16, 17),
+                &[Resource::Gpu(gpu("Thalys", 0, "A100"))],
+            )
+            .unwrap();
+
+        assert_eq!(advice.model, "A100");
+        assert_eq!(advice.used, Duration::days(12));
+        assert_eq!(advice.alternative.as_deref(), Some("Freccia"));
+    }
+
+    #[test]
+    fn test_busy_server_is_not_suggested() {
+        let history = vec![
+            usage("alice", period(8, 14), &[gpu("Thalys", 0, "A100")]),
+            usage("bob", period(13, 14), &[gpu("Thalys", 1, "A100")]),
+        ];
+        let busy = vec![usage(
+            "bob",
+            period(16, 17),
+            &[gpu("Freccia", 0, "RTX"), gpu("Freccia", 1, "RTX")],
+        )];
+
+        let advice = policy()
+            .advise(
+                &email("alice"),
+                now(),
+                &history,
+                &busy,
+                &period(16, 17),
+                &[Resource::Gpu(gpu("Thalys", 0, "A100"))],
+            )
+            .unwrap();
+
+        assert_eq!(advice.alternative, None);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc, Weekday};
+use chrono_tz::Tz;
+
+/// 研究室の休業期間（学年暦の休業日など）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holiday {
+    /// 休業日の名前（例: 年末年始休業）
+    pub name: String,
+    /// 開始日
+    pub start: NaiveDate,
+    /// 終了日（この日を含む）
+    pub end: NaiveDate,
+}
+
+/// 休業日の理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClosureReason {
+    /// 定休日の曜日
+    Weekend(Weekday),
+    /// 学年暦などで定められた休業日
+    Holiday(String),
+}
+
+/// 予約期間に含まれる休業日
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosedDay {
+    /// 日付
+    pub date: NaiveDate,
+    /// 休業の理由
+    pub reason: ClosureReason,
+}
+
+/// 休業日の注意喚起ポリシー
+///
+/// 予約期間が週末や休業日にかかる場合に、予約を拒否せずに注意を促すための判定を行う。
+/// 日付の区切りは指定したタイムゾーン（未指定ならシステムのローカルタイムゾーン）で判定する。
+#[derive(Debug, Clone, Default)]
+pub struct HolidayAdvisoryPolicy {
+    closed_weekdays: Vec<Weekday>,
+    holidays: Vec<Holiday>,
+    timezone: Option<Tz>,
+}
+
+impl HolidayAdvisoryPolicy {
+    /// 新しいポリシーを作成
+    ///
+    /// # Arguments
+    /// * `closed_weekdays` - 定休日の曜日
+    /// * `holidays` - 休業期間の一覧
+    /// * `timezone` - 日付の区切りに使うタイムゾーン（IANA形式）
+    pub fn new(
+        closed_weekdays: Vec<Weekday>,
+        holidays: Vec<Holiday>,
+        timezone: Option<&str>,
+    ) -> Self {
+        Self {
+            closed_weekdays,
+            holidays,
+            timezone: timezone.and_then(|tz| tz.parse::<Tz>().ok()),
+        }
+    }
+
+    /// 予約期間に含まれる休業日を取得（日付順）
+    ///
+    /// 休業期間と定休日の両方に該当する日は、休業期間として扱う。
+    pub fn closed_days(&self, time_period: &TimePeriod) -> Vec<ClosedDay> {
+        let first = self.local_date(time_period.start());
+        // 終了時刻は排他的なので、ちょうど0:00に終わる予約は翌日を含めない
+        let last = self.local_date(time_period.end() - Duration::nanoseconds(1));
+
+        first
+            .iter_days()
+            .take_while(|day| *day <= last)
+            .filter_map(|date| {
+                self.reason_for(date)
+                    .map(|reason| ClosedDay { date, reason })
+            })
+            .collect()
+    }
+
+    fn reason_for(&self, date: NaiveDate) -> Option<ClosureReason> {
+        if let Some(holiday) = self
+            .holidays
+            .iter()
+            .find(|h| h.start <= date && date <= h.end)
+        {
+            return Some(ClosureReason::Holiday(holiday.name.clone()));
+        }
+        self.closed_weekdays
+            .contains(&date.weekday())
+            .then(|| ClosureReason::Weekend(date.weekday()))
+    }
+
+    fn local_date(&self, date_time: DateTime<Utc>) -> NaiveDate {
+        match self.timezone {
+            Some(tz) => date_time.with_timezone(&tz).date_naive(),
+            None => date_time.with_timezone(&Local).date_naive(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn period(start: (u32, u32), end: (u32, u32)) -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 12, start.0, start.1, 0, 0)
+                .unwrap(),
+            Utc.with_ymd_and_hms(2024, 12, end.0, end.1, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 12, day).unwrap()
+    }
+
+    fn policy() -> HolidayAdvisoryPolicy {
+        HolidayAdvisoryPolicy::new(
+            vec![Weekday::Sat, Weekday::Sun],
+            vec![Holiday {
+                name: "年末年始休業".to_string(),
+                start: date(28),
+                end: date(31),
+            }],
+            Some("UTC"),
+        )
+    }
+
+    #[test]
+    fn test_weekday_reservation_has_no_closed_days() {
+        // 2024-12-16（月）〜 2024-12-18（水）
+        assert!(policy().closed_days(&period((16, 10), (18, 10))).is_empty());
+    }
+
+    #[test]
+    fn test_detects_weekend() {
+        // 2024-12-20（金）〜 2024-12-23（月）0:00 は土日を含む
+        let closed = policy().closed_days(&period((20, 10), (23, 0)));
+
+        assert_eq!(
+            closed,
+            vec![
+                ClosedDay {
+                    date: date(21),
+                    reason: ClosureReason::Weekend(Weekday::Sat),
+                },
+                ClosedDay {
+                    date: date(22),
+                    reason: ClosureReason::Weekend(Weekday::Sun),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_holiday_takes_precedence_over_weekend() {
+        // 2024-12-28（土）は休業期間
+        let closed = policy().closed_days(&period((27, 10), (28, 12)));
+
+        assert_eq!(
+            closed,
+            vec![ClosedDay {
+                date: date(28),
+                reason: ClosureReason::Holiday("年末年始休業".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_days_follow_configured_timezone() {
+        // UTC 2024-12-20（金）20:00 は JST 2024-12-21（土）5:00
+        let policy = HolidayAdvisoryPolicy::new(vec![Weekday::Sat], Vec::new(), Some("Asia/Tokyo"));
+
+        let closed = policy.closed_days(&period((20, 20), (20, 22)));
+
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].date, date(21));
+    }
+}
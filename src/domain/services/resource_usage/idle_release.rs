@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// 使われていない予約に対して行う操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleReleaseAction {
+    /// 何もしない
+    None,
+    /// 予約者に確認する
+    Warn,
+    /// 予約を終了してGPUを解放する
+    Release,
+}
+
+/// 使われていない予約の自動解放ポリシー
+///
+/// 予約したGPUでプロセスが実行されていない状態が `idle_after` 続いたら予約者に確認し、
+/// 確認から `grace` の間に応答がなく使われないままであれば予約を終了する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleReleasePolicy {
+    idle_after: Duration,
+    grace: Duration,
+}
+
+impl IdleReleasePolicy {
+    /// 確認するまでの時間の既定値（分）
+    pub const DEFAULT_IDLE_MINUTES: u32 = 60;
+    /// 確認から解放までの猶予の既定値（分）
+    pub const DEFAULT_GRACE_MINUTES: u32 = 30;
+
+    /// 新しいポリシーを作成
+    ///
+    /// # Arguments
+    /// * `idle_after` - 使われていない状態がどれだけ続いたら予約者に確認するか
+    /// * `grace` - 確認からどれだけ待って解放するか
+    pub fn new(idle_after: Duration, grace: Duration) -> Self {
+        Self { idle_after, grace }
+    }
+
+    /// 確認から解放までの猶予
+    pub fn grace(&self) -> Duration {
+        self.grace
+    }
+
+    /// 使われていない予約に対して行う操作を決める
+    ///
+    /// # Arguments
+    /// * `idle_since` - GPUが使われていない状態になった時刻
+    /// * `warned_at` - 予約者に確認した時刻（未確認の場合は `None`）
+    /// * `now` - 現在時刻
+    pub fn decide(
+        &self,
+        idle_since: DateTime<Utc>,
+        warned_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> IdleReleaseAction {
+        match warned_at {
+            Some(warned_at) if now - warned_at >= self.grace => IdleReleaseAction::Release,
+            Some(_) => IdleReleaseAction::None,
+            None if now - idle_since >= self.idle_after => IdleReleaseAction::Warn,
+            None => IdleReleaseAction::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, 10, minute, 0).unwrap()
+    }
+
+    fn policy() -> IdleReleasePolicy {
+        IdleReleasePolicy::new(Duration::minutes(30), Duration::minutes(15))
+    }
+
+    #[test]
+    fn test_warns_after_idle_period() {
+        assert_eq!(
+            policy().decide(at(0), None, at(29)),
+            IdleReleaseAction::None
+        );
+        assert_eq!(
+            policy().decide(at(0), None, at(30)),
+            IdleReleaseAction::Warn
+        );
+    }
+
+    #[test]
+    fn test_releases_after_grace_period() {
+        assert_eq!(
+            policy().decide(at(0), Some(at(30)), at(44)),
+            IdleReleaseAction::None
+        );
+        assert_eq!(
+            policy().decide(at(0), Some(at(30)), at(45)),
+            IdleReleaseAction::Release
+        );
+    }
+}
@@ -9,11 +9,39 @@
 //!
 //! # モジュール
 //!
+//! - `allocation` - 部分的な競合に対する分割予約案や、次に空いている時間帯を計算
+//! - `booking_window` - リソースごとの予約の受付期間（何分前まで・何日先まで）
 //! - `conflict_checker` - リソースの時間的競合をチェック
+//! - `cost_model` - GPUのモデルごとの料金による予約の費用の計算
 //! - `errors` - サービス層のエラー型定義
+//! - `fair_share` - 直近の利用実績に基づく公平な利用のための助言
+//! - `holiday_advisory` - 週末・休業日にかかる予約への注意喚起
+//! - `idle_release` - GPUが使われていない予約の確認と自動解放
+//! - `preemption` - 優先度の高い予約による、優先度の低い予約の横取りの可否
+//! - `reservation_limit` - 利用者ごとの同時予約の上限
 
+pub mod allocation;
+pub mod booking_window;
 pub mod conflict_checker;
+pub mod cost_model;
 pub mod errors;
+pub mod fair_share;
+pub mod holiday_advisory;
+pub mod idle_release;
+pub mod preemption;
+pub mod reservation_limit;
 
-pub use conflict_checker::ResourceConflictChecker;
-pub use errors::ResourceConflictError;
+pub use allocation::{ResourceAllocation, ResourceAllocationService, SplitProposal};
+pub use booking_window::{
+    BookingWindow, BookingWindowError, BookingWindowPolicy, BookingWindowViolation,
+};
+pub use conflict_checker::{ResourceConflict, ResourceConflictChecker};
+pub use cost_model::CostModel;
+pub use errors::{CapacityExceededError, ResourceConflictError};
+pub use fair_share::{FairShareAdvice, FairSharePolicy};
+pub use holiday_advisory::{ClosedDay, ClosureReason, Holiday, HolidayAdvisoryPolicy};
+pub use idle_release::{IdleReleaseAction, IdleReleasePolicy};
+pub use preemption::{NotPreemptableReason, PreemptionError, PreemptionPolicy};
+pub use reservation_limit::{
+    LimitKind, ReservationLimit, ReservationLimitError, ReservationLimitPolicy,
+};
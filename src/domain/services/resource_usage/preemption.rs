@@ -0,0 +1,159 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::Priority;
+use crate::domain::errors::DomainError;
+use crate::domain::services::resource_usage::ResourceConflict;
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// 予約の横取りの可否を判定するポリシー
+///
+/// 優先度の高い予約は、重なる優先度の低い開始前の予約を取り消して予約できる。
+/// 管理者は優先度や開始済みかどうかにかかわらず、重なる予約を取り消して予約できる。
+pub struct PreemptionPolicy;
+
+impl PreemptionPolicy {
+    /// 競合する予約をすべて横取りできるか判定
+    ///
+    /// # Arguments
+    /// * `priority` - 新しい予約の優先度
+    /// * `is_admin` - 予約者が管理者かどうか
+    /// * `conflicts` - 新しい予約と競合する既存の予約
+    /// * `now` - 現在時刻（開始済みの予約の判定に使う）
+    ///
+    /// # Returns
+    /// 取り消す予約（重複なし、競合の順）
+    ///
+    /// # Errors
+    /// 1つでも横取りできない予約がある場合
+    pub fn preemptable(
+        priority: Priority,
+        is_admin: bool,
+        conflicts: &[ResourceConflict],
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ResourceUsage>, PreemptionError> {
+        let mut preempted: Vec<ResourceUsage> = Vec::new();
+        for conflict in conflicts {
+            let existing = &conflict.existing_usage;
+            if preempted.iter().any(|usage| usage.id() == existing.id()) {
+                continue;
+            }
+            if !is_admin {
+                let reason = if !priority.outranks(existing.priority()) {
+                    Some(NotPreemptableReason::NotLowerPriority(existing.priority()))
+                } else if existing.time_period().start() <= now {
+                    Some(NotPreemptableReason::AlreadyStarted)
+                } else {
+                    None
+                };
+                if let Some(reason) = reason {
+                    return Err(PreemptionError {
+                        resource_description: conflict.resource.to_string(),
+                        usage_id: existing.id().as_str().to_string(),
+                        reason,
+                    });
+                }
+            }
+            preempted.push(existing.clone());
+        }
+        Ok(preempted)
+    }
+}
+
+/// 横取りできない理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotPreemptableReason {
+    /// 既存の予約の優先度が同じか高い
+    NotLowerPriority(Priority),
+    /// 既存の予約が開始済み
+    AlreadyStarted,
+}
+
+/// 競合する予約を横取りできないエラー
+#[derive(Debug, Clone)]
+pub struct PreemptionError {
+    /// 競合したリソースの説明
+    pub resource_description: String,
+    /// 横取りできない予約のID
+    pub usage_id: String,
+    /// 横取りできない理由
+    pub reason: NotPreemptableReason,
+}
+
+impl fmt::Display for PreemptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.reason {
+            NotPreemptableReason::NotLowerPriority(priority) => write!(
+                f,
+                "{} の予約 {} は優先度（{}）が同じか高いため取り消せません",
+                self.resource_description, self.usage_id, priority
+            ),
+            NotPreemptableReason::AlreadyStarted => write!(
+                f,
+                "{} の予約 {} は開始済みのため取り消せません",
+                self.resource_description, self.usage_id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PreemptionError {}
+
+impl DomainError for PreemptionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource, TimePeriod};
+    use crate::domain::common::EmailAddress;
+    use chrono::{Duration, TimeZone};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap()
+    }
+
+    fn conflict(start_hour: u32, priority: Priority) -> ResourceConflict {
+        let resource = Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string()));
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, start_hour, 0, 0).unwrap();
+        let usage = ResourceUsage::new(
+            EmailAddress::new("other@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, start + Duration::hours(2)).unwrap(),
+            vec![resource.clone()],
+            None,
+        )
+        .unwrap()
+        .with_priority(priority);
+        ResourceConflict {
+            resource,
+            existing_usage: usage,
+        }
+    }
+
+    #[test]
+    fn test_preempts_only_lower_priority() {
+        let conflicts = vec![conflict(10, Priority::Background)];
+
+        let preempted =
+            PreemptionPolicy::preemptable(Priority::Normal, false, &conflicts, now()).unwrap();
+        assert_eq!(preempted.len(), 1);
+
+        let err = PreemptionPolicy::preemptable(Priority::Background, false, &conflicts, now())
+            .unwrap_err();
+        assert_eq!(
+            err.reason,
+            NotPreemptableReason::NotLowerPriority(Priority::Background)
+        );
+    }
+
+    #[test]
+    fn test_does_not_preempt_started_usage_unless_admin() {
+        let conflicts = vec![conflict(8, Priority::Background)];
+
+        let err = PreemptionPolicy::preemptable(Priority::Deadline, false, &conflicts, now())
+            .unwrap_err();
+        assert_eq!(err.reason, NotPreemptableReason::AlreadyStarted);
+
+        let preempted =
+            PreemptionPolicy::preemptable(Priority::Normal, true, &conflicts, now()).unwrap();
+        assert_eq!(preempted.len(), 1);
+    }
+}
@@ -0,0 +1,325 @@
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::errors::DomainError;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::fmt;
+
+/// 利用者1人あたりの予約の上限
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReservationLimit {
+    /// 終了していない予約の件数の上限
+    pub max_reservations: Option<u32>,
+    /// 同時に確保できるGPUの数の上限
+    pub max_devices: Option<u32>,
+}
+
+/// 上限の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitKind {
+    /// 終了していない予約の件数
+    Reservations,
+    /// 同時に確保しているGPUの数
+    Devices,
+}
+
+/// 利用者ごとの同時予約の上限ポリシー
+///
+/// 研究室全体の上限と、サーバーごとの上限を組み合わせて判定する。
+/// サーバーごとの上限は、そのサーバーのGPUを含む予約・そのサーバーのGPUの数だけを数える。
+#[derive(Debug, Clone, Default)]
+pub struct ReservationLimitPolicy {
+    global: ReservationLimit,
+    per_server: HashMap<String, ReservationLimit>,
+}
+
+impl ReservationLimitPolicy {
+    /// 研究室全体の上限を指定してポリシーを作成
+    pub fn new(global: ReservationLimit) -> Self {
+        Self {
+            global,
+            per_server: HashMap::new(),
+        }
+    }
+
+    /// サーバーごとの上限を追加
+    pub fn with_server_limit(mut self, server: impl Into<String>, limit: ReservationLimit) -> Self {
+        self.per_server.insert(server.into(), limit);
+        self
+    }
+
+    /// 上限が1つも設定されていないかどうか
+    pub fn is_unlimited(&self) -> bool {
+        self.global == ReservationLimit::default()
+            && self
+                .per_server
+                .values()
+                .all(|limit| *limit == ReservationLimit::default())
+    }
+
+    /// 予約を追加した場合に上限を超えないか確認
+    ///
+    /// # Arguments
+    /// * `held` - 利用者の終了していない予約（更新の場合は更新対象の予約を除く）
+    /// * `time_period` - 追加する予約の期間
+    /// * `resources` - 追加する予約のリソース
+    ///
+    /// # Errors
+    /// いずれかの上限を超える場合。エラーには上限の計算に数えた利用者の予約を含める
+    pub fn check(
+        &self,
+        held: &[ResourceUsage],
+        time_period: &TimePeriod,
+        resources: &[Resource],
+    ) -> Result<(), ReservationLimitError> {
+        check_limit(&self.global, None, held, time_period, resources)?;
+
+        let mut servers: Vec<&str> = resources
+            .iter()
+            .filter_map(|resource| match resource {
+                Resource::Gpu(gpu) => Some(gpu.server()),
+                _ => None,
+            })
+            .collect();
+        servers.sort_unstable();
+        servers.dedup();
+        for server in servers {
+            if let Some(limit) = self.per_server.get(server) {
+                check_limit(limit, Some(server), held, time_period, resources)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 1つの上限について確認する（`server` を指定した場合はそのサーバーのGPUだけを数える）
+fn check_limit(
+    limit: &ReservationLimit,
+    server: Option<&str>,
+    held: &[ResourceUsage],
+    time_period: &TimePeriod,
+    resources: &[Resource],
+) -> Result<(), ReservationLimitError> {
+    let devices_of = |resources: &[Resource]| {
+        resources
+            .iter()
+            .filter(|resource| match (resource, server) {
+                (Resource::Gpu(gpu), Some(server)) => gpu.server() == server,
+                (Resource::Gpu(_), None) => true,
+                _ => false,
+            })
+            .count() as u32
+    };
+    let relevant: Vec<&ResourceUsage> = match server {
+        Some(_) => held
+            .iter()
+            .filter(|usage| devices_of(usage.resources()) > 0)
+            .collect(),
+        None => held.iter().collect(),
+    };
+
+    if let Some(max) = limit.max_reservations {
+        let current = relevant.len() as u32;
+        if current + 1 > max {
+            return Err(ReservationLimitError {
+                kind: LimitKind::Reservations,
+                server: server.map(str::to_string),
+                limit: max,
+                current,
+                holdings: relevant.into_iter().cloned().collect(),
+            });
+        }
+    }
+
+    if let Some(max) = limit.max_devices {
+        let requested = devices_of(resources);
+        if requested == 0 {
+            return Ok(());
+        }
+        let overlapping: Vec<(&ResourceUsage, u32)> = relevant
+            .into_iter()
+            .filter(|usage| usage.time_period().overlaps_with(time_period))
+            .map(|usage| (usage, devices_of(usage.resources())))
+            .filter(|(_, devices)| *devices > 0)
+            .collect();
+        let current = peak_devices(&overlapping, time_period);
+        if current + requested > max {
+            return Err(ReservationLimitError {
+                kind: LimitKind::Devices,
+                server: server.map(str::to_string),
+                limit: max,
+                current,
+                holdings: overlapping
+                    .into_iter()
+                    .map(|(usage, _)| usage.clone())
+                    .collect(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// 期間内で同時に確保しているGPUの数の最大値
+fn peak_devices(held: &[(&ResourceUsage, u32)], time_period: &TimePeriod) -> u32 {
+    // 期間内の開始・終了を時刻順に走査する（同時刻では終了を先に処理する）
+    let mut events: Vec<(DateTime<Utc>, bool, u32)> = Vec::new();
+    for (usage, devices) in held {
+        events.push((
+            usage.time_period().start().max(time_period.start()),
+            true,
+            *devices,
+        ));
+        events.push((
+            usage.time_period().end().min(time_period.end()),
+            false,
+            *devices,
+        ));
+    }
+    events.sort_by_key(|(at, is_start, _)| (*at, *is_start));
+
+    let mut current = 0u32;
+    let mut peak = 0u32;
+    for (_, is_start, devices) in events {
+        if is_start {
+            current += devices;
+            peak = peak.max(current);
+        } else {
+            current -= devices;
+        }
+    }
+    peak
+}
+
+/// 予約の上限を超えるエラー
+#[derive(Debug, Clone)]
+pub struct ReservationLimitError {
+    /// 超えた上限の種類
+    pub kind: LimitKind,
+    /// サーバーごとの上限の場合はサーバー名（研究室全体の上限の場合は `None`）
+    pub server: Option<String>,
+    /// 上限
+    pub limit: u32,
+    /// 現在の数（予約の件数、または新しい予約の期間中に同時に確保しているGPUの最大数）
+    pub current: u32,
+    /// 上限の計算に数えた利用者の予約
+    pub holdings: Vec<ResourceUsage>,
+}
+
+impl fmt::Display for ReservationLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scope = match &self.server {
+            Some(server) => format!("{}で", server),
+            None => String::new(),
+        };
+        match self.kind {
+            LimitKind::Reservations => write!(
+                f,
+                "{}同時に持てる予約は{}件までです（現在{}件）",
+                scope, self.limit, self.current
+            ),
+            LimitKind::Devices => write!(
+                f,
+                "{}同時に確保できるGPUは{}台までです（この期間に確保済み{}台）",
+                scope, self.limit, self.current
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReservationLimitError {}
+
+impl DomainError for ReservationLimitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::common::EmailAddress;
+    use chrono::TimeZone;
+
+    fn period(start: u32, end: u32) -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 1, 15, start, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 15, end, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn gpus(server: &str, devices: &[u32]) -> Vec<Resource> {
+        devices
+            .iter()
+            .map(|d| Resource::Gpu(Gpu::new(server.to_string(), *d, "A100".to_string())))
+            .collect()
+    }
+
+    fn usage(start: u32, end: u32, resources: Vec<Resource>) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            period(start, end),
+            resources,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_max_reservations() {
+        let policy = ReservationLimitPolicy::new(ReservationLimit {
+            max_reservations: Some(2),
+            max_devices: None,
+        });
+        let held = vec![
+            usage(10, 11, gpus("Thalys", &[0])),
+            usage(12, 13, gpus("Thalys", &[0])),
+        ];
+
+        assert!(
+            policy
+                .check(&held[..1], &period(14, 15), &gpus("Thalys", &[0]))
+                .is_ok()
+        );
+        let err = policy
+            .check(&held, &period(14, 15), &gpus("Thalys", &[0]))
+            .unwrap_err();
+        assert_eq!(err.kind, LimitKind::Reservations);
+        assert_eq!(err.current, 2);
+        assert_eq!(err.holdings.len(), 2);
+    }
+
+    #[test]
+    fn test_max_devices_counts_only_overlapping_reservations() {
+        let policy = ReservationLimitPolicy::new(ReservationLimit::default()).with_server_limit(
+            "Thalys",
+            ReservationLimit {
+                max_reservations: None,
+                max_devices: Some(3),
+            },
+        );
+        let held = vec![
+            usage(10, 12, gpus("Thalys", &[0, 1])),
+            usage(14, 16, gpus("Thalys", &[2, 3])),
+            usage(10, 12, gpus("Freccia", &[0, 1])),
+        ];
+
+        // 11時〜13時はThalysのGPUを2台確保済みのため、1台まで
+        assert!(
+            policy
+                .check(&held, &period(11, 13), &gpus("Thalys", &[4]))
+                .is_ok()
+        );
+        let err = policy
+            .check(&held, &period(11, 13), &gpus("Thalys", &[4, 5]))
+            .unwrap_err();
+        assert_eq!(err.kind, LimitKind::Devices);
+        assert_eq!(err.server.as_deref(), Some("Thalys"));
+        assert_eq!(err.current, 2);
+        assert_eq!(err.holdings.len(), 1);
+
+        // 上限のないサーバーは数えない
+        assert!(
+            policy
+                .check(&held, &period(11, 13), &gpus("Freccia", &[2, 3, 4]))
+                .is_ok()
+        );
+    }
+}
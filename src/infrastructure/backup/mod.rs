@@ -0,0 +1,390 @@
+//! # Backup
+//!
+//! ID紐付け、IDマッピング、その他のローカルの状態ファイルをまとめて、
+//! 日時付きのアーカイブ（tar形式）にバックアップ・復元する。
+//!
+//! - アーカイブ内のパスは `<環境変数名>/<ファイル名>`（例: `IDENTITY_LINKS_FILE/identity_links.json`）。
+//!   復元時は環境変数名で現在の設定のパスに対応付けるため、パスを変更していても復元できる
+//! - JSONファイルはBotと同じロック（`<ファイル名>.lock`）を取ってから読み書きする
+//! - SQLiteデータベースは `VACUUM INTO` で整合性のとれた複製を作ってからアーカイブに含める
+//! - 存在しないファイル（使っていない機能の状態ファイル）はアーカイブに含めない
+
+pub mod tar;
+
+use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::repositories::file_store;
+use crate::infrastructure::repositories::identity_link::is_sqlite_path;
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// バックアップのファイル名の接頭辞
+pub const ARCHIVE_PREFIX: &str = "lab-resource-manager-";
+
+/// バックアップのファイル名の拡張子
+pub const ARCHIVE_EXTENSION: &str = "tar";
+
+/// SQLiteデータベースのファイルの先頭
+const SQLITE_HEADER: &[u8] = b"SQLite format 3\0";
+
+/// バックアップ・復元のエラー
+#[derive(Debug, Error)]
+pub enum BackupError {
+    #[error("{path} の読み書きに失敗: {reason}")]
+    Io { path: String, reason: String },
+    #[error("SQLiteデータベース {path} の複製に失敗: {reason}")]
+    Sqlite { path: String, reason: String },
+    #[error("アーカイブ {path} が不正です: {reason}")]
+    InvalidArchive { path: String, reason: String },
+}
+
+impl BackupError {
+    fn io(path: &Path, e: impl std::fmt::Display) -> Self {
+        Self::Io {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        }
+    }
+}
+
+/// バックアップの対象のファイル
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataFile {
+    /// パスを指定する環境変数名（アーカイブ内のディレクトリ名）
+    pub key: &'static str,
+    /// 現在の設定でのパス
+    pub path: PathBuf,
+}
+
+impl DataFile {
+    fn entry_name(&self) -> String {
+        let file_name = self
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "data".to_string());
+        format!("{}/{}", self.key, file_name)
+    }
+}
+
+/// 設定からバックアップの対象のファイルを列挙する
+pub fn data_files(config: &AppConfig) -> Vec<DataFile> {
+    [
+        ("IDENTITY_LINKS_FILE", &config.identity_links_file),
+        ("IDENTITY_LINK_AUDIT_FILE", &config.identity_link_audit_file),
+        (
+            "GOOGLE_CALENDAR_MAPPINGS_FILE",
+            &config.calendar_mappings_file,
+        ),
+        ("RESOURCE_FREEZES_FILE", &config.resource_freezes_file),
+        ("DEVICE_STATUSES_FILE", &config.device_statuses_file),
+        ("SENT_REMINDERS_FILE", &config.sent_reminders_file),
+        ("USAGE_SNAPSHOT_FILE", &config.usage_snapshot_file),
+        ("WAITLIST_FILE", &config.waitlist_file),
+        ("WORKSPACE_TOKENS_FILE", &config.workspace_tokens_file),
+    ]
+    .into_iter()
+    .chain(
+        config
+            .google_oauth
+            .as_ref()
+            .map(|oauth| ("GOOGLE_OAUTH_TOKEN_CACHE", &oauth.token_cache_file)),
+    )
+    .map(|(key, path)| DataFile {
+        key,
+        path: path.clone(),
+    })
+    .collect()
+}
+
+/// バックアップのアーカイブを作成し、そのパスを返す
+///
+/// # Arguments
+/// * `files` - バックアップの対象のファイル
+/// * `dir` - アーカイブを保存するディレクトリ
+/// * `now` - アーカイブのファイル名に使う日時
+pub async fn create_backup(
+    files: &[DataFile],
+    dir: &Path,
+    now: DateTime<Utc>,
+) -> Result<PathBuf, BackupError> {
+    let mut entries = Vec::new();
+    for file in files {
+        if let Some(data) = snapshot(&file.path).await? {
+            entries.push(tar::Entry {
+                name: file.entry_name(),
+                data,
+            });
+        }
+    }
+
+    fs::create_dir_all(dir).map_err(|e| BackupError::io(dir, e))?;
+    let archive_path = dir.join(format!(
+        "{}{}.{}",
+        ARCHIVE_PREFIX,
+        now.format("%Y%m%dT%H%M%SZ"),
+        ARCHIVE_EXTENSION
+    ));
+    let mut archive = Vec::new();
+    tar::write(&mut archive, &entries, now.timestamp().max(0) as u64)
+        .map_err(|e| BackupError::io(&archive_path, e))?;
+    file_store::write_atomic(&archive_path, archive)
+        .await
+        .map_err(|e| BackupError::io(&archive_path, e))?;
+    Ok(archive_path)
+}
+
+/// アーカイブからファイルを復元し、復元したファイルを返す
+///
+/// 書き込む前にアーカイブ全体を検証し、不正な場合は何も書き込まない。
+/// アーカイブに含まれないファイルはそのまま残す。
+///
+/// # Arguments
+/// * `archive` - 復元するアーカイブ
+/// * `files` - 復元先のファイル（現在の設定）
+pub async fn restore_backup(
+    archive: &Path,
+    files: &[DataFile],
+) -> Result<Vec<DataFile>, BackupError> {
+    let invalid = |reason: String| BackupError::InvalidArchive {
+        path: archive.display().to_string(),
+        reason,
+    };
+
+    let mut reader = fs::File::open(archive).map_err(|e| BackupError::io(archive, e))?;
+    let entries = tar::read(&mut reader).map_err(|e| invalid(e.to_string()))?;
+
+    let mut restores = Vec::new();
+    for entry in entries {
+        let key = entry.name.split('/').next().unwrap_or_default();
+        let file = files
+            .iter()
+            .find(|file| file.key == key)
+            .ok_or_else(|| invalid(format!("不明なファイル {} が含まれています", entry.name)))?;
+        if is_sqlite_path(&file.path) != entry.data.starts_with(SQLITE_HEADER) {
+            return Err(invalid(format!(
+                "{} の形式が {} と一致しません（SQLiteとJSONの設定を確認してください）",
+                entry.name,
+                file.path.display()
+            )));
+        }
+        restores.push((file.clone(), entry.data));
+    }
+
+    for (file, data) in &restores {
+        let _guard = file_store::lock(&file.path)
+            .await
+            .map_err(|e| BackupError::io(&file.path, e))?;
+        file_store::write_atomic(&file.path, data)
+            .await
+            .map_err(|e| BackupError::io(&file.path, e))?;
+        if is_sqlite_path(&file.path) {
+            // 復元前のデータベースのジャーナルが適用されないよう削除する
+            for suffix in ["-wal", "-shm"] {
+                let mut journal = file.path.clone().into_os_string();
+                journal.push(suffix);
+                remove_if_exists(Path::new(&journal))?;
+            }
+        }
+    }
+    Ok(restores.into_iter().map(|(file, _)| file).collect())
+}
+
+/// 古いバックアップを削除し、削除したファイルを返す
+///
+/// # Arguments
+/// * `dir` - アーカイブを保存するディレクトリ
+/// * `keep` - 残すバックアップの数（新しいものから残す）
+pub fn rotate(dir: &Path, keep: usize) -> Result<Vec<PathBuf>, BackupError> {
+    let mut archives: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| BackupError::io(dir, e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(ARCHIVE_PREFIX)
+                        && name.ends_with(&format!(".{}", ARCHIVE_EXTENSION))
+                })
+        })
+        .collect();
+    // ファイル名の日時の順（古い順）
+    archives.sort();
+
+    let removed: Vec<PathBuf> = archives
+        .drain(..archives.len().saturating_sub(keep))
+        .collect();
+    for path in &removed {
+        remove_if_exists(path)?;
+    }
+    Ok(removed)
+}
+
+/// ファイルの現在の内容を読み込む（ファイルが無い場合は `None`）
+async fn snapshot(path: &Path) -> Result<Option<Vec<u8>>, BackupError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    if is_sqlite_path(path) {
+        return snapshot_sqlite(path).await.map(Some);
+    }
+
+    let _guard = file_store::lock(path)
+        .await
+        .map_err(|e| BackupError::io(path, e))?;
+    match fs::read(path) {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(BackupError::io(path, e)),
+    }
+}
+
+/// 書き込み中のBotがいても整合性のとれたSQLiteデータベースの複製を読み込む
+async fn snapshot_sqlite(path: &Path) -> Result<Vec<u8>, BackupError> {
+    let sqlite_error = |e: rusqlite::Error| BackupError::Sqlite {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    };
+    let copy_path = std::env::temp_dir().join(format!(
+        "lab-resource-manager-backup-{}.db",
+        uuid::Uuid::new_v4()
+    ));
+
+    let source = path.to_path_buf();
+    let destination = copy_path.clone();
+    tokio::task::spawn_blocking(move || {
+        let connection = rusqlite::Connection::open_with_flags(
+            &source,
+            rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY,
+        )?;
+        connection.execute("VACUUM INTO ?1", [destination.to_string_lossy()])?;
+        Ok::<_, rusqlite::Error>(())
+    })
+    .await
+    .map_err(|e| BackupError::io(path, e))?
+    .map_err(sqlite_error)?;
+
+    let data = fs::read(&copy_path).map_err(|e| BackupError::io(&copy_path, e));
+    remove_if_exists(&copy_path)?;
+    data
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), BackupError> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(BackupError::io(path, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("lrm_backup_{}", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn test_backup_and_restore_round_trip() {
+        let dir = temp_dir();
+        let files = vec![
+            DataFile {
+                key: "IDENTITY_LINKS_FILE",
+                path: dir.join("data/identity_links.json"),
+            },
+            DataFile {
+                key: "GOOGLE_CALENDAR_MAPPINGS_FILE",
+                path: dir.join("data/mappings.db"),
+            },
+            DataFile {
+                key: "WAITLIST_FILE",
+                path: dir.join("data/waitlist.json"),
+            },
+        ];
+        fs::create_dir_all(dir.join("data")).unwrap();
+        fs::write(&files[0].path, "{\"links\": 1}").unwrap();
+        rusqlite::Connection::open(&files[1].path)
+            .unwrap()
+            .execute_batch("CREATE TABLE t (v INTEGER); INSERT INTO t VALUES (42);")
+            .unwrap();
+
+        let now = Utc.with_ymd_and_hms(2026, 4, 1, 3, 0, 0).unwrap();
+        let archive = create_backup(&files, &dir.join("backups"), now)
+            .await
+            .unwrap();
+        assert_eq!(
+            archive.file_name().unwrap(),
+            "lab-resource-manager-20260401T030000Z.tar"
+        );
+
+        fs::write(&files[0].path, "{\"links\": 2}").unwrap();
+        fs::remove_file(&files[1].path).unwrap();
+
+        let restored = restore_backup(&archive, &files).await.unwrap();
+
+        assert_eq!(restored.len(), 2);
+        assert_eq!(
+            fs::read_to_string(&files[0].path).unwrap(),
+            "{\"links\": 1}"
+        );
+        let value: i64 = rusqlite::Connection::open(&files[1].path)
+            .unwrap()
+            .query_row("SELECT v FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(value, 42);
+        assert!(!files[2].path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_unknown_entries() {
+        let dir = temp_dir();
+        let file = DataFile {
+            key: "WAITLIST_FILE",
+            path: dir.join("waitlist.json"),
+        };
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&file.path, "[]").unwrap();
+        let archive = create_backup(std::slice::from_ref(&file), &dir, Utc::now())
+            .await
+            .unwrap();
+
+        let other = DataFile {
+            key: "SENT_REMINDERS_FILE",
+            path: dir.join("sent_reminders.json"),
+        };
+        assert!(matches!(
+            restore_backup(&archive, &[other]).await,
+            Err(BackupError::InvalidArchive { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rotate_keeps_newest_archives() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "lab-resource-manager-20260101T000000Z.tar",
+            "lab-resource-manager-20260103T000000Z.tar",
+            "lab-resource-manager-20260102T000000Z.tar",
+            "notes.tar",
+        ] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let removed = rotate(&dir, 2).unwrap();
+
+        assert_eq!(
+            removed,
+            vec![dir.join("lab-resource-manager-20260101T000000Z.tar")]
+        );
+        assert!(
+            dir.join("lab-resource-manager-20260102T000000Z.tar")
+                .exists()
+        );
+        assert!(dir.join("notes.tar").exists());
+    }
+}
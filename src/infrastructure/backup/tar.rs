@@ -0,0 +1,179 @@
+//! tar形式（ustar）のアーカイブの読み書き
+//!
+//! バックアップに必要な、通常のファイルだけを含むアーカイブに限って扱う。
+
+use std::io::{self, Read, Write};
+
+const BLOCK_SIZE: usize = 512;
+
+/// アーカイブ内のファイル
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// アーカイブ内のパス（100バイトまで）
+    pub name: String,
+    /// ファイルの内容
+    pub data: Vec<u8>,
+}
+
+/// ファイルをtar形式で書き出す
+///
+/// # Arguments
+/// * `writer` - 書き出し先
+/// * `entries` - 書き出すファイル
+/// * `mtime` - ファイルの更新時刻（UNIX時間）
+pub fn write(writer: &mut impl Write, entries: &[Entry], mtime: u64) -> io::Result<()> {
+    for entry in entries {
+        writer.write_all(&header(entry, mtime)?)?;
+        writer.write_all(&entry.data)?;
+        writer.write_all(&vec![0; padding(entry.data.len())])?;
+    }
+    // アーカイブの終わりは空のブロック2つ
+    writer.write_all(&[0; BLOCK_SIZE * 2])?;
+    Ok(())
+}
+
+/// tar形式のアーカイブからファイルを読み込む（通常のファイル以外は読み飛ばす）
+pub fn read(reader: &mut impl Read) -> io::Result<Vec<Entry>> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+    let mut entries = Vec::new();
+    let mut block = [0u8; BLOCK_SIZE];
+
+    loop {
+        reader.read_exact(&mut block)?;
+        if block.iter().all(|b| *b == 0) {
+            break;
+        }
+        if checksum(&block)
+            != parse_octal(&block[148..156]).ok_or_else(|| invalid("チェックサムが不正です"))?
+        {
+            return Err(invalid("チェックサムが一致しません"));
+        }
+
+        let name = field_str(&block[0..100]);
+        let prefix = field_str(&block[345..500]);
+        let size =
+            parse_octal(&block[124..136]).ok_or_else(|| invalid("サイズが不正です"))? as usize;
+        let typeflag = block[156];
+
+        let mut data = vec![0; size];
+        reader.read_exact(&mut data)?;
+        io::copy(&mut reader.take(padding(size) as u64), &mut io::sink())?;
+
+        if typeflag == b'0' || typeflag == 0 {
+            let name = if prefix.is_empty() {
+                name
+            } else {
+                format!("{}/{}", prefix, name)
+            };
+            entries.push(Entry { name, data });
+        }
+    }
+    Ok(entries)
+}
+
+fn header(entry: &Entry, mtime: u64) -> io::Result<[u8; BLOCK_SIZE]> {
+    if entry.name.len() > 100 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("ファイル名が長すぎます: {}", entry.name),
+        ));
+    }
+
+    let mut block = [0u8; BLOCK_SIZE];
+    block[..entry.name.len()].copy_from_slice(entry.name.as_bytes());
+    write_octal(&mut block[100..108], 0o600);
+    write_octal(&mut block[108..116], 0);
+    write_octal(&mut block[116..124], 0);
+    write_octal(&mut block[124..136], entry.data.len() as u64);
+    write_octal(&mut block[136..148], mtime);
+    block[156] = b'0';
+    block[257..263].copy_from_slice(b"ustar\0");
+    block[263..265].copy_from_slice(b"00");
+
+    let sum = checksum(&block);
+    block[148..154].copy_from_slice(format!("{:06o}", sum).as_bytes());
+    block[154] = 0;
+    block[155] = b' ';
+    Ok(block)
+}
+
+/// ヘッダーのチェックサム（チェックサム欄は空白として数える）
+fn checksum(block: &[u8; BLOCK_SIZE]) -> u64 {
+    block
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            if (148..156).contains(&i) {
+                u64::from(b' ')
+            } else {
+                u64::from(*b)
+            }
+        })
+        .sum()
+}
+
+fn write_octal(field: &mut [u8], value: u64) {
+    let digits = format!("{:0width$o}", value, width = field.len() - 1);
+    field[..digits.len()].copy_from_slice(digits.as_bytes());
+    field[digits.len()] = 0;
+}
+
+fn parse_octal(field: &[u8]) -> Option<u64> {
+    let text = field_str(field);
+    let text = text.trim();
+    if text.is_empty() {
+        return Some(0);
+    }
+    u64::from_str_radix(text, 8).ok()
+}
+
+fn field_str(field: &[u8]) -> String {
+    let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+fn padding(size: usize) -> usize {
+    (BLOCK_SIZE - size % BLOCK_SIZE) % BLOCK_SIZE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_and_read_round_trip() {
+        let entries = vec![
+            Entry {
+                name: "IDENTITY_LINKS_FILE/identity_links.json".to_string(),
+                data: b"{}".to_vec(),
+            },
+            Entry {
+                name: "GOOGLE_CALENDAR_MAPPINGS_FILE/mappings.db".to_string(),
+                data: vec![0xff; 1000],
+            },
+        ];
+
+        let mut archive = Vec::new();
+        write(&mut archive, &entries, 1_700_000_000).unwrap();
+
+        assert_eq!(archive.len() % BLOCK_SIZE, 0);
+        assert_eq!(read(&mut archive.as_slice()).unwrap(), entries);
+    }
+
+    #[test]
+    fn test_read_rejects_corrupted_header() {
+        let mut archive = Vec::new();
+        write(
+            &mut archive,
+            &[Entry {
+                name: "a.json".to_string(),
+                data: b"{}".to_vec(),
+            }],
+            0,
+        )
+        .unwrap();
+        archive[0] = b'b';
+
+        assert!(read(&mut archive.as_slice()).is_err());
+    }
+}
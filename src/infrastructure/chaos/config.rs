@@ -0,0 +1,96 @@
+//! 障害注入の設定
+
+use crate::infrastructure::config::ConfigLoadError;
+use rand::Rng;
+use std::env;
+use std::time::Duration;
+
+/// 障害注入の設定
+///
+/// 確率は 0.0〜1.0 の範囲で指定する。0.0 の場合は一切障害を注入しない。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FaultInjectionConfig {
+    /// リポジトリ操作をエラーにする確率
+    pub repository_error_rate: f64,
+    /// 通知送信をエラーにする確率
+    pub notifier_error_rate: f64,
+    /// 各操作の前に挿入する遅延の最大値（0〜この値の範囲でランダム）
+    pub max_latency: Duration,
+}
+
+impl FaultInjectionConfig {
+    /// 環境変数から障害注入の設定を読み込む
+    ///
+    /// - `CHAOS_REPOSITORY_ERROR_RATE`: リポジトリのエラー発生確率（デフォルト: 0.0）
+    /// - `CHAOS_NOTIFIER_ERROR_RATE`: 通知のエラー発生確率（デフォルト: 0.0）
+    /// - `CHAOS_MAX_LATENCY_MS`: 遅延の最大値（ミリ秒、デフォルト: 0）
+    pub fn from_env() -> Result<Self, ConfigLoadError> {
+        Ok(Self {
+            repository_error_rate: Self::rate_from_env("CHAOS_REPOSITORY_ERROR_RATE")?,
+            notifier_error_rate: Self::rate_from_env("CHAOS_NOTIFIER_ERROR_RATE")?,
+            max_latency: env::var("CHAOS_MAX_LATENCY_MS")
+                .ok()
+                .map(|s| {
+                    s.parse::<u64>()
+                        .map_err(|_| ConfigLoadError::InvalidEnvVar {
+                            name: "CHAOS_MAX_LATENCY_MS",
+                            reason: "0以上の整数である必要があります".to_string(),
+                        })
+                })
+                .transpose()?
+                .map(Duration::from_millis)
+                .unwrap_or_default(),
+        })
+    }
+
+    /// いずれかの障害注入が有効かどうか
+    pub fn is_enabled(&self) -> bool {
+        self.repository_error_rate > 0.0
+            || self.notifier_error_rate > 0.0
+            || !self.max_latency.is_zero()
+    }
+
+    /// 設定された範囲でランダムな遅延を挿入する
+    pub(super) async fn inject_latency(&self) {
+        if self.max_latency.is_zero() {
+            return;
+        }
+        let millis = rand::rng().random_range(0..=self.max_latency.as_millis() as u64);
+        tokio::time::sleep(Duration::from_millis(millis)).await;
+    }
+
+    /// 指定確率で障害を発生させるかどうかを判定する
+    pub(super) fn should_fail(rate: f64) -> bool {
+        rate > 0.0 && rand::rng().random_bool(rate.min(1.0))
+    }
+
+    fn rate_from_env(name: &'static str) -> Result<f64, ConfigLoadError> {
+        let Ok(value) = env::var(name) else {
+            return Ok(0.0);
+        };
+
+        match value.parse::<f64>() {
+            Ok(rate) if (0.0..=1.0).contains(&rate) => Ok(rate),
+            _ => Err(ConfigLoadError::InvalidEnvVar {
+                name,
+                reason: "0.0〜1.0の数値である必要があります".to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_disabled() {
+        assert!(!FaultInjectionConfig::default().is_enabled());
+    }
+
+    #[test]
+    fn test_should_fail_boundaries() {
+        assert!(!FaultInjectionConfig::should_fail(0.0));
+        assert!(FaultInjectionConfig::should_fail(1.0));
+    }
+}
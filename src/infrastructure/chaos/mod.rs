@@ -0,0 +1,19 @@
+//! # Fault Injection
+//!
+//! ステージング環境で障害時の挙動を検証するための障害注入レイヤーを提供します。
+//! `chaos` フィーチャーを有効にした場合のみコンパイルされます。
+//!
+//! - `config`: 障害注入の設定（エラー発生確率・人工的な遅延）
+//! - `repository`: `ResourceUsageRepository` をラップして障害を注入
+//! - `notifier`: `Notifier` をラップして障害を注入
+
+/// 障害注入の設定
+pub mod config;
+/// 障害を注入する通知サービスのラッパー
+pub mod notifier;
+/// 障害を注入するリポジトリのラッパー
+pub mod repository;
+
+pub use config::FaultInjectionConfig;
+pub use notifier::FaultInjectingNotifier;
+pub use repository::FaultInjectingRepository;
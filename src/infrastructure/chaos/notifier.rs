@@ -0,0 +1,36 @@
+//! 障害を注入する通知サービスのラッパー
+
+use super::config::FaultInjectionConfig;
+use crate::domain::ports::notifier::{NotificationError, NotificationEvent, Notifier};
+use async_trait::async_trait;
+
+/// 設定に従って遅延とエラーを注入する `Notifier` のラッパー
+pub struct FaultInjectingNotifier<N> {
+    inner: N,
+    config: FaultInjectionConfig,
+}
+
+impl<N> FaultInjectingNotifier<N> {
+    /// 新しいラッパーを作成
+    ///
+    /// # Arguments
+    /// * `inner` - ラップする通知サービス
+    /// * `config` - 障害注入の設定
+    pub fn new(inner: N, config: FaultInjectionConfig) -> Self {
+        Self { inner, config }
+    }
+}
+
+#[async_trait]
+impl<N: Notifier> Notifier for FaultInjectingNotifier<N> {
+    async fn notify(&self, event: NotificationEvent) -> Result<(), NotificationError> {
+        self.config.inject_latency().await;
+        if FaultInjectionConfig::should_fail(self.config.notifier_error_rate) {
+            tracing::warn!("💥 障害注入: 通知送信を失敗させます");
+            return Err(NotificationError::SendFailure(
+                "障害注入による通知送信の失敗".to_string(),
+            ));
+        }
+        self.inner.notify(event).await
+    }
+}
@@ -0,0 +1,115 @@
+//! 障害を注入するリポジトリのラッパー
+
+use super::config::FaultInjectionConfig;
+use crate::domain::aggregates::resource_usage::{
+    entity::ResourceUsage,
+    value_objects::{TimePeriod, UsageId},
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository, UsageQuery};
+use async_trait::async_trait;
+
+/// 設定に従って遅延とエラーを注入する `ResourceUsageRepository` のラッパー
+pub struct FaultInjectingRepository<R> {
+    inner: R,
+    config: FaultInjectionConfig,
+}
+
+impl<R> FaultInjectingRepository<R> {
+    /// 新しいラッパーを作成
+    ///
+    /// # Arguments
+    /// * `inner` - ラップするリポジトリ
+    /// * `config` - 障害注入の設定
+    pub fn new(inner: R, config: FaultInjectionConfig) -> Self {
+        Self { inner, config }
+    }
+
+    /// 遅延を挿入し、確率に応じて注入エラーを返す
+    async fn maybe_fail(&self, operation: &str) -> Result<(), RepositoryError> {
+        self.config.inject_latency().await;
+        if FaultInjectionConfig::should_fail(self.config.repository_error_rate) {
+            tracing::warn!("💥 障害注入: リポジトリ操作 {} を失敗させます", operation);
+            return Err(RepositoryError::ConnectionError(format!(
+                "障害注入による {} の失敗",
+                operation
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R> ResourceUsageRepository for FaultInjectingRepository<R>
+where
+    R: ResourceUsageRepository + Send + Sync,
+{
+    async fn find_by_id(&self, id: &UsageId) -> Result<Option<ResourceUsage>, RepositoryError> {
+        self.maybe_fail("find_by_id").await?;
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_future(&self) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        self.maybe_fail("find_future").await?;
+        self.inner.find_future().await
+    }
+
+    async fn find_overlapping(
+        &self,
+        time_period: &TimePeriod,
+    ) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        self.maybe_fail("find_overlapping").await?;
+        self.inner.find_overlapping(time_period).await
+    }
+
+    async fn find_by_owner(
+        &self,
+        owner_email: &EmailAddress,
+    ) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        self.maybe_fail("find_by_owner").await?;
+        self.inner.find_by_owner(owner_email).await
+    }
+
+    async fn query(&self, query: &UsageQuery) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        self.maybe_fail("query").await?;
+        self.inner.query(query).await
+    }
+
+    async fn save(&self, usage: &ResourceUsage) -> Result<(), RepositoryError> {
+        self.maybe_fail("save").await?;
+        self.inner.save(usage).await
+    }
+
+    async fn delete(&self, id: &UsageId) -> Result<(), RepositoryError> {
+        self.maybe_fail("delete").await?;
+        self.inner.delete(id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+
+    #[tokio::test]
+    async fn test_always_fails_with_rate_one() {
+        let repo = FaultInjectingRepository::new(
+            MockUsageRepository::new(),
+            FaultInjectionConfig {
+                repository_error_rate: 1.0,
+                ..Default::default()
+            },
+        );
+
+        let result = repo.find_future().await;
+        assert!(matches!(result, Err(RepositoryError::ConnectionError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_passes_through_with_rate_zero() {
+        let repo = FaultInjectingRepository::new(MockUsageRepository::new(), Default::default());
+
+        let result = repo.find_future().await;
+        assert!(result.unwrap().is_empty());
+    }
+}
@@ -10,16 +10,151 @@ use std::path::PathBuf;
 pub struct AppConfig {
     /// Google サービスアカウントJSONキーのパス
     pub google_service_account_key_path: PathBuf,
+    /// Google サービスアカウントJSONキーの内容（指定した場合はパスより優先）
+    ///
+    /// シークレットの取得元や環境変数 `GOOGLE_SERVICE_ACCOUNT_KEY_JSON` から読み込む。
+    pub google_service_account_key: Option<String>,
+    /// ドメイン全体の委任（Domain-Wide Delegation）でサービスアカウントが代理で操作するユーザー
+    ///
+    /// 設定した場合、予約者をイベントの参加者として追加する。
+    pub google_delegated_user: Option<String>,
+    /// Google Calendar APIをOAuth（ユーザーの同意）で認証する設定（未設定の場合はサービスアカウントで認証する）
+    pub google_oauth: Option<GoogleOAuthConfig>,
     /// Slack Bot User OAuth Token (xoxb-...)
     pub slack_bot_token: String,
     /// Socket Mode用のSlack App-Level Token (xapp-...)
     pub slack_app_token: String,
+    /// Slack Enterprise Gridで運用するか
+    ///
+    /// 有効な場合、ID紐付けはワークスペース（Team ID）ごとに管理される。
+    pub slack_enterprise_grid: bool,
     /// リソース設定ファイルのパス
     pub resource_config_path: PathBuf,
     /// ID紐付けファイルのパス
     pub identity_links_file: PathBuf,
+    /// ID紐付けの監査記録ファイルのパス（JSON Lines）
+    pub identity_link_audit_file: PathBuf,
     /// カレンダーIDマッピングファイルのパス
     pub calendar_mappings_file: PathBuf,
+    /// 予約停止ファイルのパス
+    pub resource_freezes_file: PathBuf,
+    /// デバイスの状態ファイルのパス
+    pub device_statuses_file: PathBuf,
+    /// 送信済みリマインダーの記録ファイルのパス
+    pub sent_reminders_file: PathBuf,
+    /// 変更検知の状態ファイルのパス
+    pub usage_snapshot_file: PathBuf,
+    /// 空き待ちリストファイルのパス
+    pub waitlist_file: PathBuf,
+    /// ワークスペースごとのBot Tokenの保存ファイルのパス
+    pub workspace_tokens_file: PathBuf,
+    /// APIトークンのファイルのパス（1行に1つ、`<メールアドレス> <トークンのSHA-256>`）
+    ///
+    /// HTTP APIとMCPサーバーで共通。
+    pub api_tokens_file: PathBuf,
     /// ポーリング間隔（秒）
     pub polling_interval_secs: u64,
+    /// LDAP名簿との同期設定（未設定の場合は同期しない）
+    pub ldap_sync: Option<LdapSyncConfig>,
+    /// 複数ワークスペースへのインストール（OAuth）の設定（未設定の場合は受け付けない）
+    pub slack_oauth: Option<SlackOAuthConfig>,
+    /// 複数インスタンスで運用する場合のリーダー選出の設定（未設定の場合は常にポーリングする）
+    pub leader_election: Option<LeaderElectionConfig>,
+    /// 予約を操作するHTTP APIの設定（未設定の場合はAPIを提供しない）
+    pub http_api: Option<HttpApiConfig>,
+    /// 予約の変更を追記するGoogleスプレッドシートの設定（未設定の場合は書き出さない）
+    pub google_sheets_export: Option<GoogleSheetsExportConfig>,
+    /// 予約を同期するNotionデータベースの設定（未設定の場合は同期しない）
+    pub notion_sync: Option<NotionSyncConfig>,
+}
+
+/// Google Calendar APIをOAuth（インストール型アプリのフロー）で認証する設定
+///
+/// サービスアカウントからのカレンダーの参照が禁止されているGoogle Workspace向け。
+#[derive(Debug, Clone)]
+pub struct GoogleOAuthConfig {
+    /// OAuthクライアント（デスクトップアプリ）のシークレットJSONファイルのパス
+    pub client_secret_file: PathBuf,
+    /// アクセストークンとリフレッシュトークンのキャッシュファイルのパス
+    pub token_cache_file: PathBuf,
+}
+
+/// 複数インスタンスで運用する場合のリーダー選出の設定
+///
+/// リーダーのインスタンスだけがポーリングと通知を行い、他のインスタンスは待機する。
+#[derive(Debug, Clone)]
+pub enum LeaderElectionConfig {
+    /// ファイルロックで選ぶ（同じホストのインスタンス向け）
+    File {
+        /// ロックファイルのパス
+        lock_file: PathBuf,
+    },
+    /// Redisのキーで選ぶ（別々のホストのインスタンス向け）
+    Redis {
+        /// RedisのURL（例: redis://:password@redis.example.ac.jp:6379/0）
+        url: String,
+        /// ロックに使うキー
+        key: String,
+    },
+}
+
+/// 複数のSlackワークスペースへBotをインストールするためのOAuth設定
+#[derive(Debug, Clone)]
+pub struct SlackOAuthConfig {
+    /// SlackアプリのClient ID
+    pub client_id: String,
+    /// SlackアプリのClient Secret
+    pub client_secret: String,
+    /// OAuthのリダイレクトURL（Slackアプリに登録した公開URL）
+    ///
+    /// このURLのパスでインストール完了のコールバックを受け付ける。
+    pub redirect_url: String,
+    /// インストール用のHTTPサーバーの待ち受けアドレス
+    pub listen_addr: String,
+    /// インストール時に要求するBotのスコープ（カンマ区切り）
+    pub scopes: String,
+}
+
+/// 予約を操作するHTTP APIの設定
+#[derive(Debug, Clone)]
+pub struct HttpApiConfig {
+    /// APIサーバーの待ち受けアドレス
+    pub listen_addr: String,
+}
+
+/// 予約の変更を追記するGoogleスプレッドシートの設定
+#[derive(Debug, Clone)]
+pub struct GoogleSheetsExportConfig {
+    /// スプレッドシートのID（URLの `/spreadsheets/d/` と `/edit` の間）
+    pub spreadsheet_id: String,
+    /// 追記するシートの名前
+    pub sheet: String,
+}
+
+/// 予約を同期するNotionデータベースの設定
+#[derive(Debug, Clone)]
+pub struct NotionSyncConfig {
+    /// Notionのインテグレーションのトークン
+    pub token: String,
+    /// データベースのID（URLの末尾の32桁の英数字）
+    pub database_id: String,
+}
+
+/// LDAP / Active Directory 名簿との同期設定
+#[derive(Debug, Clone)]
+pub struct LdapSyncConfig {
+    /// LDAPサーバーのURL（例: ldaps://ldap.example.ac.jp）
+    pub url: String,
+    /// 検索ベースDN
+    pub base_dn: String,
+    /// バインドDN（未設定の場合は匿名バインド）
+    pub bind_dn: Option<String>,
+    /// バインドパスワードを記載したファイルのパス
+    pub bind_password_file: Option<PathBuf>,
+    /// メンバーを抽出する検索フィルタ
+    pub filter: String,
+    /// メールアドレスを保持する属性名
+    pub mail_attribute: String,
+    /// 同期間隔（秒）
+    pub sync_interval_secs: u64,
 }
@@ -5,15 +5,76 @@
 /// Google サービスアカウントJSONキーのデフォルトパス
 pub const GOOGLE_SERVICE_ACCOUNT_KEY_PATH: &str = "/etc/lab-resource-manager/service-account.json";
 
+/// OAuthクライアントのシークレットJSONファイルのデフォルトパス
+pub const GOOGLE_OAUTH_CLIENT_SECRET_FILE: &str = "/etc/lab-resource-manager/oauth-client.json";
+
+/// OAuthのトークンキャッシュのデフォルトパス
+pub const GOOGLE_OAUTH_TOKEN_CACHE: &str = "/var/lib/lab-resource-manager/google_oauth_tokens.json";
+
+/// 予約の変更を追記するGoogleスプレッドシートのシート名のデフォルト
+pub const GOOGLE_SHEETS_SHEET: &str = "Sheet1";
+
 /// リソース設定ファイルのデフォルトパス
 pub const RESOURCE_CONFIG_PATH: &str = "/etc/lab-resource-manager/resources.toml";
 
 /// ID紐付けファイルのデフォルトパス
 pub const IDENTITY_LINKS_FILE: &str = "/var/lib/lab-resource-manager/identity_links.json";
 
+/// ID紐付けの監査記録ファイルのデフォルトパス
+pub const IDENTITY_LINK_AUDIT_FILE: &str =
+    "/var/lib/lab-resource-manager/identity_link_audit.jsonl";
+
 /// カレンダーIDマッピングファイルのデフォルトパス
 pub const CALENDAR_MAPPINGS_FILE: &str =
     "/var/lib/lab-resource-manager/google_calendar_mappings.json";
 
+/// 予約停止ファイルのデフォルトパス
+pub const RESOURCE_FREEZES_FILE: &str = "/var/lib/lab-resource-manager/resource_freezes.json";
+
+/// デバイスの状態ファイルのデフォルトパス
+pub const DEVICE_STATUSES_FILE: &str = "/var/lib/lab-resource-manager/device_statuses.json";
+
+/// 送信済みリマインダーの記録ファイルのデフォルトパス
+pub const SENT_REMINDERS_FILE: &str = "/var/lib/lab-resource-manager/sent_reminders.json";
+
+/// 変更検知の状態ファイルのデフォルトパス
+pub const USAGE_SNAPSHOT_FILE: &str = "/var/lib/lab-resource-manager/usage_snapshot.json";
+
+/// バックアップの保存先のデフォルト
+pub const BACKUP_DIR: &str = "/var/lib/lab-resource-manager/backups";
+
+/// リーダー選出のロックファイルのデフォルトパス
+pub const LEADER_LOCK_FILE: &str = "/var/lib/lab-resource-manager/leader.lock";
+
+/// リーダー選出に使うRedisのキーのデフォルト
+pub const LEADER_REDIS_KEY: &str = "lab-resource-manager:leader";
+
+/// 空き待ちリストファイルのデフォルトパス
+pub const WAITLIST_FILE: &str = "/var/lib/lab-resource-manager/waitlist.json";
+
+/// ワークスペースごとのBot Tokenの保存ファイルのデフォルトパス
+pub const WORKSPACE_TOKENS_FILE: &str = "/var/lib/lab-resource-manager/workspace_tokens.json";
+
 /// ポーリング間隔のデフォルト値（秒）
 pub const POLLING_INTERVAL_SECS: u64 = 60;
+
+/// LDAP検索フィルタのデフォルト値
+pub const LDAP_FILTER: &str = "(objectClass=person)";
+
+/// メールアドレス属性名のデフォルト値
+pub const LDAP_MAIL_ATTRIBUTE: &str = "mail";
+
+/// LDAP同期間隔のデフォルト値（秒）
+pub const LDAP_SYNC_INTERVAL_SECS: u64 = 3600;
+
+/// OAuthインストール用HTTPサーバーの待ち受けアドレスのデフォルト値
+pub const SLACK_OAUTH_LISTEN_ADDR: &str = "0.0.0.0:3000";
+
+/// OAuthインストール時に要求するBotのスコープのデフォルト値
+pub const SLACK_OAUTH_SCOPES: &str = "commands,chat:write,chat:write.public,channels:read,channels:join,groups:read,im:write,users:read,users:read.email";
+
+/// HTTP APIのトークンファイルのデフォルトパス
+pub const API_TOKENS_FILE: &str = "/etc/lab-resource-manager/api_tokens";
+
+/// ファイルからシークレットを読み込む場合のディレクトリのデフォルトパス
+pub const SECRETS_DIR: &str = "/run/secrets";
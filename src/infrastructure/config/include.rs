@@ -0,0 +1,296 @@
+//! リソース設定ファイルの分割
+//!
+//! トップレベルの `include = ["servers/*.toml", "rooms.toml"]` で指定したファイルを読み込み、
+//! 1つの設定にマージする。パスは指定したファイルのあるディレクトリからの相対パスで、
+//! ファイル名には `*` と `?` のワイルドカードを使える（一致したファイルは名前順に読み込む）。
+//! 読み込んだファイルもさらに `include` を持てる。
+//!
+//! マージの規則:
+//! - テーブルの配列（`[[servers]]` など）は、指定したファイルの後に読み込み順で連結する。
+//!   連結した結果に同じ `name`（`name` がない場合は `id`）の要素があればエラー
+//! - テーブル（`[weekly_digest]` など）はキーごとにマージする
+//! - それ以外の値（`timezone`、`admins` など）は1つのファイルでしか定義できない
+
+use super::loader::ConfigLoadError;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// 読み込むファイルを指定するキー
+pub const INCLUDE_KEY: &str = "include";
+
+/// 設定ファイルを読み込み、`include` で指定したファイルをマージする
+pub fn load_with_includes(path: &Path) -> Result<Table, ConfigLoadError> {
+    load_file(path, &mut Vec::new())
+}
+
+fn load_file(path: &Path, visiting: &mut Vec<PathBuf>) -> Result<Table, ConfigLoadError> {
+    let invalid = |reason: String| ConfigLoadError::InvalidFile {
+        path: path.display().to_string(),
+        reason,
+    };
+
+    let canonical = path.canonicalize().map_err(|e| invalid(e.to_string()))?;
+    if visiting.contains(&canonical) {
+        return Err(invalid("include が循環しています".to_string()));
+    }
+
+    let content = fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+    let mut table: Table = toml::from_str(&content).map_err(|e| invalid(e.to_string()))?;
+    let patterns = match table.remove(INCLUDE_KEY) {
+        None => Vec::new(),
+        Some(Value::Array(items)) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::String(pattern) => Ok(pattern),
+                _ => Err(invalid(
+                    "include はファイルパスの配列である必要があります".to_string(),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(_) => {
+            return Err(invalid(
+                "include はファイルパスの配列である必要があります".to_string(),
+            ));
+        }
+    };
+
+    visiting.push(canonical);
+    let base_dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    for pattern in patterns {
+        for file in expand(base_dir, &pattern).map_err(invalid)? {
+            let included = load_file(&file, visiting)?;
+            merge(&mut table, included, "", &file)?;
+        }
+    }
+    visiting.pop();
+
+    Ok(table)
+}
+
+/// `include` のパスを読み込むファイルのリストに展開する
+///
+/// ワイルドカードを含まないパスはそのまま返す（存在しない場合は読み込み時にエラーになる）。
+fn expand(base_dir: &Path, pattern: &str) -> Result<Vec<PathBuf>, String> {
+    let path = base_dir.join(pattern);
+    let file_pattern = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| format!("include のパス {} が不正です", pattern))?;
+    if !has_wildcard(file_pattern) {
+        return Ok(vec![path]);
+    }
+
+    let dir = path.parent().unwrap_or(base_dir);
+    if has_wildcard(&dir.to_string_lossy()) {
+        return Err(format!(
+            "include のパス {} が不正です（ワイルドカードはファイル名にのみ使えます）",
+            pattern
+        ));
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| format!("{} を読み込めません: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|file| file.is_file())
+        .filter(|file| {
+            file.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| wildcard_match(file_pattern, name))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn has_wildcard(s: &str) -> bool {
+    s.contains(['*', '?'])
+}
+
+/// `*`（0文字以上）と `?`（1文字）のワイルドカードでファイル名を照合する
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// 読み込んだファイルの設定をマージする
+fn merge(base: &mut Table, other: Table, prefix: &str, path: &Path) -> Result<(), ConfigLoadError> {
+    for (key, value) in other {
+        let location = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match (base.get_mut(&key), value) {
+            (None, value) => {
+                base.insert(key, value);
+            }
+            (Some(Value::Table(existing)), Value::Table(value)) => {
+                merge(existing, value, &location, path)?;
+            }
+            (Some(Value::Array(existing)), Value::Array(items))
+                if is_array_of_tables(existing) && is_array_of_tables(&items) =>
+            {
+                let names: HashSet<&str> = existing.iter().filter_map(entry_name).collect();
+                if let Some(name) = items
+                    .iter()
+                    .filter_map(entry_name)
+                    .find(|n| names.contains(n))
+                {
+                    return Err(ConfigLoadError::DuplicateEntry {
+                        path: path.display().to_string(),
+                        key: location,
+                        name: name.to_string(),
+                    });
+                }
+                existing.extend(items);
+            }
+            _ => {
+                return Err(ConfigLoadError::DuplicateKey {
+                    path: path.display().to_string(),
+                    key: location,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_array_of_tables(items: &[Value]) -> bool {
+    items.iter().all(Value::is_table)
+}
+
+/// テーブルの配列の要素を識別する名前（`name`、なければ `id`）
+fn entry_name(item: &Value) -> Option<&str> {
+    item.get("name")
+        .or_else(|| item.get("id"))
+        .and_then(Value::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_files(files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("lrm_include_{}", uuid::Uuid::new_v4()));
+        for (name, content) in files {
+            let path = dir.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.toml", "gpu.toml"));
+        assert!(wildcard_match("gpu-?.toml", "gpu-1.toml"));
+        assert!(wildcard_match("*-*.toml", "gpu-a-b.toml"));
+        assert!(!wildcard_match("*.toml", "gpu.toml.bak"));
+        assert!(!wildcard_match("gpu-?.toml", "gpu-10.toml"));
+    }
+
+    #[test]
+    fn test_load_with_includes_merges_files() {
+        let dir = write_files(&[
+            (
+                "resources.toml",
+                r#"
+include = ["servers/*.toml", "digest.toml"]
+timezone = "Asia/Tokyo"
+
+[[servers]]
+name = "Main"
+calendar_id = "main@example.com"
+
+[weekly_digest]
+channel_id = "C001"
+"#,
+            ),
+            (
+                "servers/b.toml",
+                "[[servers]]\nname = \"B\"\ncalendar_id = \"b@example.com\"\n",
+            ),
+            (
+                "servers/a.toml",
+                "[[servers]]\nname = \"A\"\ncalendar_id = \"a@example.com\"\n",
+            ),
+            ("servers/notes.txt", "not toml"),
+            ("digest.toml", "[weekly_digest]\nhour = 9\n"),
+        ]);
+
+        let table = load_with_includes(&dir.join("resources.toml")).unwrap();
+
+        let names: Vec<&str> = table["servers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Main", "A", "B"]);
+        assert_eq!(table["weekly_digest"]["channel_id"].as_str(), Some("C001"));
+        assert_eq!(table["weekly_digest"]["hour"].as_integer(), Some(9));
+        assert!(!table.contains_key(INCLUDE_KEY));
+    }
+
+    #[test]
+    fn test_load_with_includes_rejects_duplicates() {
+        let dir = write_files(&[
+            (
+                "resources.toml",
+                "include = [\"rooms.toml\"]\n[[rooms]]\nname = \"会議室A\"\ncalendar_id = \"a@example.com\"\n",
+            ),
+            (
+                "rooms.toml",
+                "[[rooms]]\nname = \"会議室A\"\ncalendar_id = \"b@example.com\"\n",
+            ),
+            ("timezone.toml", "timezone = \"UTC\"\n"),
+            (
+                "main.toml",
+                "include = [\"timezone.toml\"]\ntimezone = \"Asia/Tokyo\"\n",
+            ),
+            ("loop.toml", "include = [\"loop.toml\"]\n"),
+        ]);
+
+        assert!(matches!(
+            load_with_includes(&dir.join("resources.toml")),
+            Err(ConfigLoadError::DuplicateEntry { ref key, ref name, .. })
+                if key == "rooms" && name == "会議室A"
+        ));
+        assert!(matches!(
+            load_with_includes(&dir.join("main.toml")),
+            Err(ConfigLoadError::DuplicateKey { ref key, .. }) if key == "timezone"
+        ));
+        assert!(matches!(
+            load_with_includes(&dir.join("loop.toml")),
+            Err(ConfigLoadError::InvalidFile { .. })
+        ));
+    }
+}
@@ -0,0 +1,260 @@
+//! リソース設定での環境変数の展開と上書き
+//!
+//! トークンやチャンネルIDなどの秘密情報を設定ファイルに直接書かずに済むよう、
+//! 読み込んだTOMLに次の2つを適用する。
+//!
+//! - 文字列の値に含まれる `${ENV_VAR}` を環境変数の値に置き換える（`$${` は `${` のまま残す）
+//! - `LRM_` から始まる環境変数で任意のキーを上書きする。キーの階層は `__` で区切り、
+//!   配列の要素は添字で指定する（例: `LRM_WEEKLY_DIGEST__HOUR=9`、`LRM_SERVERS__0__CALENDAR_ID=...`）
+
+use super::loader::ConfigLoadError;
+use toml::{Table, Value};
+
+/// 設定を上書きする環境変数の接頭辞
+pub const OVERRIDE_PREFIX: &str = "LRM_";
+
+/// 文字列の値に含まれる `${ENV_VAR}` を展開する
+///
+/// # Arguments
+/// * `table` - 読み込んだTOML
+/// * `lookup` - 環境変数の値を返す関数
+pub fn interpolate(
+    table: &mut Table,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<(), ConfigLoadError> {
+    for (key, value) in table.iter_mut() {
+        interpolate_value(value, key, lookup)?;
+    }
+    Ok(())
+}
+
+fn interpolate_value(
+    value: &mut Value,
+    location: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<(), ConfigLoadError> {
+    match value {
+        Value::String(s) => *s = interpolate_str(s, location, lookup)?,
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                interpolate_value(item, &format!("{}[{}]", location, index), lookup)?;
+            }
+        }
+        Value::Table(table) => {
+            for (key, item) in table.iter_mut() {
+                interpolate_value(item, &format!("{}.{}", location, key), lookup)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn interpolate_str(
+    s: &str,
+    location: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<String, ConfigLoadError> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('$') {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        if let Some(escaped) = tail.strip_prefix("$${") {
+            out.push_str("${");
+            rest = escaped;
+        } else if let Some(reference) = tail.strip_prefix("${") {
+            let end = reference
+                .find('}')
+                .ok_or_else(|| ConfigLoadError::InvalidInterpolation {
+                    location: location.to_string(),
+                    reason: "`${` に対応する `}` がありません".to_string(),
+                })?;
+            let name = &reference[..end];
+            let value = lookup(name).ok_or_else(|| ConfigLoadError::UndefinedEnvVar {
+                location: location.to_string(),
+                name: name.to_string(),
+            })?;
+            out.push_str(&value);
+            rest = &reference[end + 1..];
+        } else {
+            out.push('$');
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// `LRM_` から始まる環境変数で設定を上書きする
+///
+/// 値はTOMLの値（数値、真偽値、配列など）として解釈し、解釈できない場合は文字列として扱う。
+/// 存在しないテーブルは作成するが、存在しない配列の要素は指定できない。
+///
+/// # Arguments
+/// * `table` - 読み込んだTOML
+/// * `vars` - 環境変数の名前と値
+pub fn apply_overrides(
+    table: &mut Table,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<(), ConfigLoadError> {
+    for (name, raw) in vars {
+        let Some(key) = name.strip_prefix(OVERRIDE_PREFIX) else {
+            continue;
+        };
+        let path: Vec<String> = key.split("__").map(str::to_lowercase).collect();
+        if path.iter().any(String::is_empty) {
+            return Err(ConfigLoadError::InvalidOverride {
+                name,
+                reason: "キーが空です".to_string(),
+            });
+        }
+        set_path(table, &path, parse_override(&raw))
+            .map_err(|reason| ConfigLoadError::InvalidOverride { name, reason })?;
+    }
+    Ok(())
+}
+
+/// 上書きする値をTOMLの値として解釈する
+fn parse_override(raw: &str) -> Value {
+    toml::from_str::<Table>(&format!("value = {}", raw))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+fn set_path(table: &mut Table, path: &[String], value: Value) -> Result<(), String> {
+    let (key, rest) = path.split_first().expect("path is not empty");
+    if rest.is_empty() {
+        table.insert(key.clone(), value);
+        return Ok(());
+    }
+    let child = table
+        .entry(key.clone())
+        .or_insert_with(|| Value::Table(Table::new()));
+    set_value_path(child, key, rest, value)
+}
+
+fn set_value_path(
+    current: &mut Value,
+    key: &str,
+    path: &[String],
+    value: Value,
+) -> Result<(), String> {
+    match current {
+        Value::Table(table) => set_path(table, path, value),
+        Value::Array(items) => {
+            let (index, rest) = path.split_first().expect("path is not empty");
+            let item = index
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| items.get_mut(i))
+                .ok_or_else(|| format!("{} に {} 番目の要素がありません", key, index))?;
+            if rest.is_empty() {
+                *item = value;
+                Ok(())
+            } else {
+                set_value_path(item, index, rest, value)
+            }
+        }
+        _ => Err(format!("{} はテーブルではありません", key)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "SLACK_BOT_TOKEN" => Some("xoxb-secret".to_string()),
+            "CHANNEL" => Some("C001".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_interpolate_strings() {
+        let mut table: Table = toml::from_str(
+            r#"
+# bot_token = "${NOT_SET}"
+literal = "$${CHANNEL} costs $5"
+
+[[servers.notifications]]
+type = "slack"
+bot_token = "${SLACK_BOT_TOKEN}"
+channel_id = "${CHANNEL}"
+"#,
+        )
+        .unwrap();
+
+        interpolate(&mut table, &lookup).unwrap();
+
+        let notification = &table["servers"]["notifications"][0];
+        assert_eq!(notification["bot_token"].as_str(), Some("xoxb-secret"));
+        assert_eq!(notification["channel_id"].as_str(), Some("C001"));
+        assert_eq!(table["literal"].as_str(), Some("${CHANNEL} costs $5"));
+    }
+
+    #[test]
+    fn test_interpolate_reports_undefined_variable() {
+        let mut table: Table = toml::from_str(r#"rooms = [{ calendar_id = "${ROOM}" }]"#).unwrap();
+
+        let error = interpolate(&mut table, &lookup).unwrap_err();
+
+        assert!(matches!(
+            error,
+            ConfigLoadError::UndefinedEnvVar { ref location, ref name }
+                if location == "rooms[0].calendar_id" && name == "ROOM"
+        ));
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let mut table: Table = toml::from_str(
+            r#"
+timezone = "UTC"
+
+[[servers]]
+name = "Thalys"
+calendar_id = "old@example.com"
+"#,
+        )
+        .unwrap();
+
+        apply_overrides(
+            &mut table,
+            [
+                ("LRM_TIMEZONE", "Asia/Tokyo"),
+                ("LRM_SERVERS__0__CALENDAR_ID", "new@example.com"),
+                ("LRM_WEEKLY_DIGEST__HOUR", "9"),
+                ("LRM_ADMINS", r#"["admin@example.com"]"#),
+                ("SLACK_BOT_TOKEN", "ignored"),
+            ]
+            .map(|(k, v)| (k.to_string(), v.to_string())),
+        )
+        .unwrap();
+
+        assert_eq!(table["timezone"].as_str(), Some("Asia/Tokyo"));
+        assert_eq!(
+            table["servers"][0]["calendar_id"].as_str(),
+            Some("new@example.com")
+        );
+        assert_eq!(table["weekly_digest"]["hour"].as_integer(), Some(9));
+        assert_eq!(table["admins"][0].as_str(), Some("admin@example.com"));
+        assert!(!table.contains_key("slack_bot_token"));
+    }
+
+    #[test]
+    fn test_apply_overrides_rejects_missing_array_element() {
+        let mut table: Table = toml::from_str(r#"servers = [{ name = "Thalys" }]"#).unwrap();
+
+        let error = apply_overrides(
+            &mut table,
+            [("LRM_SERVERS__1__NAME".to_string(), "Other".to_string())],
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, ConfigLoadError::InvalidOverride { .. }));
+    }
+}
@@ -2,9 +2,17 @@
 //!
 //! 環境変数から設定を読み込むロジックを担当する。
 //! 構造やデフォルト値の知識は別モジュールから取得する。
+//!
+//! 各環境変数は `LRM_` を付けた名前（例: `LRM_SLACK_BOT_TOKEN`）でも指定でき、両方ある場合はそちらを優先する。
 
-use super::app_config::AppConfig;
+use super::app_config::{
+    AppConfig, GoogleOAuthConfig, GoogleSheetsExportConfig, HttpApiConfig, LdapSyncConfig,
+    LeaderElectionConfig, NotionSyncConfig, SlackOAuthConfig,
+};
 use super::defaults;
+use super::interpolation::OVERRIDE_PREFIX;
+use crate::domain::ports::secret_provider::{SecretError, SecretProvider};
+use std::collections::HashMap;
 use std::env;
 use std::path::PathBuf;
 use thiserror::Error;
@@ -18,33 +26,169 @@ pub enum ConfigLoadError {
     /// 環境変数の値が不正
     #[error("環境変数 {name} の値が不正です: {reason}")]
     InvalidEnvVar { name: &'static str, reason: String },
+    /// シークレットの取得元から読み込めない
+    #[error("シークレット {name} を取得できません: {reason}")]
+    SecretUnavailable { name: &'static str, reason: String },
+    /// リソース設定で参照している環境変数が設定されていない
+    #[error("{location} で参照している環境変数 {name} が設定されていません")]
+    UndefinedEnvVar { location: String, name: String },
+    /// リソース設定の `${...}` の書式が不正
+    #[error("{location} の環境変数の参照が不正です: {reason}")]
+    InvalidInterpolation { location: String, reason: String },
+    /// 設定を上書きする環境変数が不正
+    #[error("環境変数 {name} で設定を上書きできません: {reason}")]
+    InvalidOverride { name: String, reason: String },
+    /// リソース設定ファイルを読み込めない
+    #[error("設定ファイル {path} を読み込めません: {reason}")]
+    InvalidFile { path: String, reason: String },
+    /// 複数の設定ファイルで同じキーを定義している
+    #[error("{path} の {key} は他の設定ファイルでも定義されています")]
+    DuplicateKey { path: String, key: String },
+    /// 複数の設定ファイルで同じ名前のリソースなどを定義している
+    #[error("{path} の {key} の {name} は他の設定ファイルでも定義されています")]
+    DuplicateEntry {
+        path: String,
+        key: String,
+        name: String,
+    },
+}
+
+/// 環境変数を読み込む（`LRM_` を付けた変数があればそちらを優先する）
+fn var(name: &str) -> Result<String, env::VarError> {
+    env::var(format!("{}{}", OVERRIDE_PREFIX, name)).or_else(|_| env::var(name))
+}
+
+/// シークレットの取得元から読み込むシークレットの名前
+pub const SECRET_NAMES: &[&str] = &[
+    "SLACK_BOT_TOKEN",
+    "SLACK_APP_TOKEN",
+    "SLACK_CLIENT_SECRET",
+    "GOOGLE_SERVICE_ACCOUNT_KEY_JSON",
+    "LEADER_REDIS_URL",
+    "NOTION_TOKEN",
+];
+
+/// 取得元から読み込んだシークレット
+type Secrets = HashMap<&'static str, String>;
+
+/// シークレットを読み込む（取得元にない場合は環境変数を使う）
+fn secret(secrets: &Secrets, name: &'static str) -> Option<String> {
+    secrets.get(name).cloned().or_else(|| var(name).ok())
 }
 
 /// 環境変数から設定を読み込む
 pub fn load_from_env() -> Result<AppConfig, ConfigLoadError> {
-    let google_service_account_key_path = env::var("GOOGLE_SERVICE_ACCOUNT_KEY")
+    load(&Secrets::new())
+}
+
+/// シークレットの取得元と環境変数から設定を読み込む
+///
+/// `SECRET_NAMES` のシークレットは取得元を優先し、取得元にないものは環境変数から読み込む。
+pub async fn load_with_secrets(
+    provider: &dyn SecretProvider,
+) -> Result<AppConfig, ConfigLoadError> {
+    let mut secrets = Secrets::new();
+    for name in SECRET_NAMES {
+        match provider.get_secret(name).await {
+            Ok(value) => {
+                secrets.insert(name, value);
+            }
+            Err(SecretError::NotFound(_)) => {}
+            Err(e) => {
+                return Err(ConfigLoadError::SecretUnavailable {
+                    name,
+                    reason: e.to_string(),
+                });
+            }
+        }
+    }
+    load(&secrets)
+}
+
+fn load(secrets: &Secrets) -> Result<AppConfig, ConfigLoadError> {
+    let google_service_account_key_path = var("GOOGLE_SERVICE_ACCOUNT_KEY")
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from(defaults::GOOGLE_SERVICE_ACCOUNT_KEY_PATH));
 
-    let slack_bot_token = env::var("SLACK_BOT_TOKEN")
-        .map_err(|_| ConfigLoadError::MissingEnvVar("SLACK_BOT_TOKEN"))?;
+    let google_service_account_key = secret(secrets, "GOOGLE_SERVICE_ACCOUNT_KEY_JSON");
+
+    let google_oauth = load_google_oauth_from_env()?;
+
+    let google_delegated_user = var("GOOGLE_DELEGATED_USER")
+        .ok()
+        .filter(|user| !user.trim().is_empty());
+    if google_delegated_user.is_some() && google_oauth.is_some() {
+        return Err(ConfigLoadError::InvalidEnvVar {
+            name: "GOOGLE_DELEGATED_USER",
+            reason:
+                "GOOGLE_AUTH=oauth とは同時に設定できません（サービスアカウントでのみ使えます）"
+                    .to_string(),
+        });
+    }
+
+    let slack_bot_token = secret(secrets, "SLACK_BOT_TOKEN")
+        .ok_or(ConfigLoadError::MissingEnvVar("SLACK_BOT_TOKEN"))?;
 
-    let slack_app_token = env::var("SLACK_APP_TOKEN")
-        .map_err(|_| ConfigLoadError::MissingEnvVar("SLACK_APP_TOKEN"))?;
+    let slack_app_token = secret(secrets, "SLACK_APP_TOKEN")
+        .ok_or(ConfigLoadError::MissingEnvVar("SLACK_APP_TOKEN"))?;
 
-    let resource_config_path = env::var("RESOURCE_CONFIG")
+    let slack_enterprise_grid = var("SLACK_ENTERPRISE_GRID")
+        .ok()
+        .map(|s| match s.trim().to_lowercase().as_str() {
+            "1" | "true" | "yes" => Ok(true),
+            "0" | "false" | "no" | "" => Ok(false),
+            _ => Err(ConfigLoadError::InvalidEnvVar {
+                name: "SLACK_ENTERPRISE_GRID",
+                reason: "true または false である必要があります".to_string(),
+            }),
+        })
+        .transpose()?
+        .unwrap_or(false);
+
+    let resource_config_path = var("RESOURCE_CONFIG")
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from(defaults::RESOURCE_CONFIG_PATH));
 
-    let identity_links_file = env::var("IDENTITY_LINKS_FILE")
+    let identity_links_file = var("IDENTITY_LINKS_FILE")
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from(defaults::IDENTITY_LINKS_FILE));
 
-    let calendar_mappings_file = env::var("GOOGLE_CALENDAR_MAPPINGS_FILE")
+    let identity_link_audit_file = var("IDENTITY_LINK_AUDIT_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(defaults::IDENTITY_LINK_AUDIT_FILE));
+
+    let calendar_mappings_file = var("GOOGLE_CALENDAR_MAPPINGS_FILE")
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from(defaults::CALENDAR_MAPPINGS_FILE));
 
-    let polling_interval_secs = env::var("POLLING_INTERVAL")
+    let resource_freezes_file = var("RESOURCE_FREEZES_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(defaults::RESOURCE_FREEZES_FILE));
+
+    let device_statuses_file = var("DEVICE_STATUSES_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(defaults::DEVICE_STATUSES_FILE));
+
+    let sent_reminders_file = var("SENT_REMINDERS_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(defaults::SENT_REMINDERS_FILE));
+
+    let usage_snapshot_file = var("USAGE_SNAPSHOT_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(defaults::USAGE_SNAPSHOT_FILE));
+
+    let waitlist_file = var("WAITLIST_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(defaults::WAITLIST_FILE));
+
+    let api_tokens_file = var("API_TOKENS_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(defaults::API_TOKENS_FILE));
+    let workspace_tokens_file = var("WORKSPACE_TOKENS_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(defaults::WORKSPACE_TOKENS_FILE));
+
+    let polling_interval_secs = var("POLLING_INTERVAL")
         .ok()
         .map(|s| {
             s.parse::<u64>()
@@ -56,13 +200,186 @@ pub fn load_from_env() -> Result<AppConfig, ConfigLoadError> {
         .transpose()?
         .unwrap_or(defaults::POLLING_INTERVAL_SECS);
 
+    let ldap_sync = load_ldap_sync_from_env()?;
+    let slack_oauth = load_slack_oauth_from_env(secrets)?;
+    let leader_election = load_leader_election_from_env(secrets)?;
+    let http_api = load_http_api_from_env();
+    let google_sheets_export = load_google_sheets_export_from_env();
+    let notion_sync = load_notion_sync_from_env(secrets)?;
+
     Ok(AppConfig {
         google_service_account_key_path,
+        google_service_account_key,
+        google_delegated_user,
+        google_oauth,
         slack_bot_token,
         slack_app_token,
+        slack_enterprise_grid,
         resource_config_path,
         identity_links_file,
+        identity_link_audit_file,
         calendar_mappings_file,
+        resource_freezes_file,
+        device_statuses_file,
+        sent_reminders_file,
+        usage_snapshot_file,
+        waitlist_file,
+        workspace_tokens_file,
+        api_tokens_file,
         polling_interval_secs,
+        ldap_sync,
+        slack_oauth,
+        leader_election,
+        http_api,
+        google_sheets_export,
+        notion_sync,
     })
 }
+
+/// Google Calendar APIのOAuthの設定を環境変数から読み込む
+///
+/// `GOOGLE_AUTH=oauth` の場合のみOAuthで認証する（`service_account` または未設定の場合はサービスアカウント）。
+fn load_google_oauth_from_env() -> Result<Option<GoogleOAuthConfig>, ConfigLoadError> {
+    let method = var("GOOGLE_AUTH").unwrap_or_default();
+    match method.trim().to_lowercase().as_str() {
+        "" | "service_account" => Ok(None),
+        "oauth" => Ok(Some(GoogleOAuthConfig {
+            client_secret_file: var("GOOGLE_OAUTH_CLIENT_SECRET_FILE")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from(defaults::GOOGLE_OAUTH_CLIENT_SECRET_FILE)),
+            token_cache_file: var("GOOGLE_OAUTH_TOKEN_CACHE")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from(defaults::GOOGLE_OAUTH_TOKEN_CACHE)),
+        })),
+        _ => Err(ConfigLoadError::InvalidEnvVar {
+            name: "GOOGLE_AUTH",
+            reason: "service_account または oauth である必要があります".to_string(),
+        }),
+    }
+}
+
+/// LDAP同期設定を環境変数から読み込む
+///
+/// `LDAP_URL` が設定されている場合のみ同期を有効にする。
+fn load_ldap_sync_from_env() -> Result<Option<LdapSyncConfig>, ConfigLoadError> {
+    let Ok(url) = var("LDAP_URL") else {
+        return Ok(None);
+    };
+
+    let base_dn =
+        var("LDAP_BASE_DN").map_err(|_| ConfigLoadError::MissingEnvVar("LDAP_BASE_DN"))?;
+
+    let sync_interval_secs = var("LDAP_SYNC_INTERVAL")
+        .ok()
+        .map(|s| match s.parse::<u64>() {
+            Ok(secs) if secs > 0 => Ok(secs),
+            _ => Err(ConfigLoadError::InvalidEnvVar {
+                name: "LDAP_SYNC_INTERVAL",
+                reason: "正の整数である必要があります".to_string(),
+            }),
+        })
+        .transpose()?
+        .unwrap_or(defaults::LDAP_SYNC_INTERVAL_SECS);
+
+    Ok(Some(LdapSyncConfig {
+        url,
+        base_dn,
+        bind_dn: var("LDAP_BIND_DN").ok(),
+        bind_password_file: var("LDAP_BIND_PASSWORD_FILE").ok().map(PathBuf::from),
+        filter: var("LDAP_FILTER").unwrap_or_else(|_| defaults::LDAP_FILTER.to_string()),
+        mail_attribute: var("LDAP_MAIL_ATTRIBUTE")
+            .unwrap_or_else(|_| defaults::LDAP_MAIL_ATTRIBUTE.to_string()),
+        sync_interval_secs,
+    }))
+}
+
+/// 複数ワークスペースへのインストール（OAuth）の設定を環境変数から読み込む
+///
+/// `SLACK_CLIENT_ID` が設定されている場合のみインストールを受け付ける。
+fn load_slack_oauth_from_env(
+    secrets: &Secrets,
+) -> Result<Option<SlackOAuthConfig>, ConfigLoadError> {
+    let Ok(client_id) = var("SLACK_CLIENT_ID") else {
+        return Ok(None);
+    };
+
+    let client_secret = secret(secrets, "SLACK_CLIENT_SECRET")
+        .ok_or(ConfigLoadError::MissingEnvVar("SLACK_CLIENT_SECRET"))?;
+
+    let redirect_url = var("SLACK_OAUTH_REDIRECT_URL")
+        .map_err(|_| ConfigLoadError::MissingEnvVar("SLACK_OAUTH_REDIRECT_URL"))?;
+    reqwest::Url::parse(&redirect_url).map_err(|e| ConfigLoadError::InvalidEnvVar {
+        name: "SLACK_OAUTH_REDIRECT_URL",
+        reason: e.to_string(),
+    })?;
+
+    Ok(Some(SlackOAuthConfig {
+        client_id,
+        client_secret,
+        redirect_url,
+        listen_addr: var("SLACK_OAUTH_LISTEN_ADDR")
+            .unwrap_or_else(|_| defaults::SLACK_OAUTH_LISTEN_ADDR.to_string()),
+        scopes: var("SLACK_OAUTH_SCOPES")
+            .unwrap_or_else(|_| defaults::SLACK_OAUTH_SCOPES.to_string()),
+    }))
+}
+
+/// HTTP APIの設定を環境変数から読み込む
+///
+/// `API_LISTEN_ADDR` が設定されている場合のみAPIを提供する。
+fn load_http_api_from_env() -> Option<HttpApiConfig> {
+    let listen_addr = var("API_LISTEN_ADDR").ok()?;
+    Some(HttpApiConfig { listen_addr })
+}
+
+/// Googleスプレッドシートへの書き出しの設定を環境変数から読み込む
+///
+/// `GOOGLE_SHEETS_SPREADSHEET_ID` が設定されている場合のみ書き出す。
+fn load_google_sheets_export_from_env() -> Option<GoogleSheetsExportConfig> {
+    let spreadsheet_id = var("GOOGLE_SHEETS_SPREADSHEET_ID").ok()?;
+    Some(GoogleSheetsExportConfig {
+        spreadsheet_id,
+        sheet: var("GOOGLE_SHEETS_SHEET")
+            .unwrap_or_else(|_| defaults::GOOGLE_SHEETS_SHEET.to_string()),
+    })
+}
+
+/// Notionデータベースへの同期の設定を環境変数から読み込む
+///
+/// `NOTION_DATABASE_ID` が設定されている場合のみ同期する。
+fn load_notion_sync_from_env(
+    secrets: &Secrets,
+) -> Result<Option<NotionSyncConfig>, ConfigLoadError> {
+    let Ok(database_id) = var("NOTION_DATABASE_ID") else {
+        return Ok(None);
+    };
+    let token =
+        secret(secrets, "NOTION_TOKEN").ok_or(ConfigLoadError::MissingEnvVar("NOTION_TOKEN"))?;
+    Ok(Some(NotionSyncConfig { token, database_id }))
+}
+
+/// リーダー選出の設定を環境変数から読み込む
+///
+/// `LEADER_ELECTION`（file または redis）が設定されている場合のみリーダー選出を行う。
+fn load_leader_election_from_env(
+    secrets: &Secrets,
+) -> Result<Option<LeaderElectionConfig>, ConfigLoadError> {
+    let kind = var("LEADER_ELECTION").unwrap_or_default();
+    match kind.trim().to_lowercase().as_str() {
+        "" => Ok(None),
+        "file" => Ok(Some(LeaderElectionConfig::File {
+            lock_file: var("LEADER_LOCK_FILE")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from(defaults::LEADER_LOCK_FILE)),
+        })),
+        "redis" => Ok(Some(LeaderElectionConfig::Redis {
+            url: secret(secrets, "LEADER_REDIS_URL")
+                .ok_or(ConfigLoadError::MissingEnvVar("LEADER_REDIS_URL"))?,
+            key: var("LEADER_REDIS_KEY").unwrap_or_else(|_| defaults::LEADER_REDIS_KEY.to_string()),
+        })),
+        _ => Err(ConfigLoadError::InvalidEnvVar {
+            name: "LEADER_ELECTION",
+            reason: "file または redis である必要があります".to_string(),
+        }),
+    }
+}
@@ -11,18 +11,30 @@
 pub mod app_config;
 /// 設定のデフォルト値
 pub mod defaults;
+/// リソース設定ファイルの分割
+pub mod include;
+/// リソース設定での環境変数の展開と上書き
+pub mod interpolation;
 /// 設定の読み込み
 pub mod loader;
 /// 通知フォーマット設定
 pub mod notification_format;
 /// リソース設定の定義と読み込み
 pub mod resource_config;
+/// リソース設定の検証
+pub mod validation;
 
-pub use app_config::AppConfig;
-pub use loader::{ConfigLoadError, load_from_env};
+pub use app_config::{
+    AppConfig, GoogleOAuthConfig, GoogleSheetsExportConfig, HttpApiConfig, LdapSyncConfig,
+    LeaderElectionConfig, NotionSyncConfig, SlackOAuthConfig,
+};
+pub use loader::{ConfigLoadError, SECRET_NAMES, load_from_env, load_with_secrets};
 pub use notification_format::{
     DateFormat, FormatConfig, NotificationCustomization, ResourceStyle, TemplateConfig, TimeStyle,
 };
 pub use resource_config::{
-    DeviceConfig, NotificationConfig, ResourceConfig, RoomConfig, ServerConfig, load_config,
+    CustomResourceConfig, DeviceConfig, GpuMonitorConfig, GroupConfig, HolidayConfig, I18nConfig,
+    IdleReleaseConfig, LabCalendarConfig, NotificationConfig, PowerConfig, ReservationLimitConfig,
+    ResourceConfig, ResourceTypeConfig, RoomConfig, ServerConfig, WeeklyDigestConfig, load_config,
 };
+pub use validation::ConfigIssue;
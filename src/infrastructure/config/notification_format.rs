@@ -6,7 +6,7 @@ use serde::Deserialize;
 
 /// メッセージテンプレート設定
 ///
-/// 各イベントタイプ（作成・更新・削除）のメッセージテンプレートを定義。
+/// 各イベントタイプ（作成・更新・削除・使用開始・使用終了）のメッセージテンプレートを定義。
 /// プレースホルダー: `{user}`, `{resource}`, `{time}`, `{notes}`, `{resource_label}`
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq, Hash, Default)]
 pub struct TemplateConfig {
@@ -21,6 +21,14 @@ pub struct TemplateConfig {
     /// 予約削除時のテンプレート
     #[serde(default)]
     pub deleted: Option<String>,
+
+    /// 予約の使用開始時のテンプレート
+    #[serde(default)]
+    pub started: Option<String>,
+
+    /// 予約の使用終了時のテンプレート
+    #[serde(default)]
+    pub ended: Option<String>,
 }
 
 /// リソース表示スタイル
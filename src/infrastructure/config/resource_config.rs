@@ -1,10 +1,20 @@
-use crate::domain::aggregates::resource_usage::value_objects::Resource;
+use crate::domain::aggregates::resource_usage::factory::ResourceFactory;
+use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource};
+use crate::domain::common::EmailAddress;
+use crate::domain::services::Role;
+use crate::domain::services::resource_usage::{
+    BookingWindow, BookingWindowPolicy, CostModel, FairSharePolicy, Holiday, HolidayAdvisoryPolicy,
+    IdleReleasePolicy, ReservationLimit, ReservationLimitPolicy,
+};
 use crate::infrastructure::config::notification_format::{
     FormatConfig, NotificationCustomization, TemplateConfig,
 };
-use serde::Deserialize;
-use std::collections::HashMap;
-use std::fs;
+use crate::infrastructure::config::{include, interpolation};
+use crate::infrastructure::i18n::Locale;
+use chrono::{Duration, NaiveDate, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// 通知設定の種類と設定値
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq, Hash)]
@@ -13,9 +23,43 @@ pub enum NotificationConfig {
     /// Slack通知設定
     Slack {
         /// Bot Token (xoxb-...)
+        ///
+        /// 省略した場合は `team_id` のワークスペースにOAuthでインストールされたトークンを使う。
+        #[serde(default)]
         bot_token: String,
-        /// チャンネルID (C01234567...)
+        /// チャンネルID (C01234567...) または `#` から始まるチャンネル名
+        ///
+        /// チャンネル名の場合は起動後の初回送信時にIDへ解決する。
+        /// プライベートチャンネルはBotが招待されている場合のみ解決できる。
         channel_id: String,
+        /// Enterprise GridのワークスペースID (T01234567...)（オプション）
+        ///
+        /// チャンネル名の解決と、通知でメンションするユーザーの選択に使用する。
+        #[serde(default)]
+        team_id: Option<String>,
+        /// タイムゾーン（オプション）
+        #[serde(default)]
+        timezone: Option<String>,
+        /// メッセージテンプレート（オプション）
+        #[serde(default)]
+        templates: Option<TemplateConfig>,
+        /// フォーマット設定（オプション）
+        #[serde(default)]
+        format: Option<FormatConfig>,
+        /// 送信失敗時の再試行回数（オプション、デフォルトは0）
+        #[serde(default)]
+        retries: u32,
+        /// 再試行しても送信できなかった場合の代替通知先（オプション、先頭から順に試す）
+        #[serde(default)]
+        fallback: Vec<NotificationConfig>,
+    },
+    /// 予約者へのメール通知設定（sendmail経由）
+    Email {
+        /// 送信元メールアドレス
+        from: String,
+        /// sendmailコマンドのパス（オプション、デフォルトは `/usr/sbin/sendmail`）
+        #[serde(default)]
+        sendmail_path: Option<String>,
         /// タイムゾーン（オプション）
         #[serde(default)]
         timezone: Option<String>,
@@ -25,6 +69,12 @@ pub enum NotificationConfig {
         /// フォーマット設定（オプション）
         #[serde(default)]
         format: Option<FormatConfig>,
+        /// 送信失敗時の再試行回数（オプション、デフォルトは0）
+        #[serde(default)]
+        retries: u32,
+        /// 再試行しても送信できなかった場合の代替通知先（オプション、先頭から順に試す）
+        #[serde(default)]
+        fallback: Vec<NotificationConfig>,
     },
     /// テスト/開発用モック通知設定
     Mock {
@@ -41,10 +91,20 @@ pub enum NotificationConfig {
 }
 
 impl NotificationConfig {
+    /// 通知手段の名前（設定ファイルの `type`）を取得
+    pub fn kind(&self) -> &'static str {
+        match self {
+            NotificationConfig::Slack { .. } => "slack",
+            NotificationConfig::Email { .. } => "email",
+            NotificationConfig::Mock { .. } => "mock",
+        }
+    }
+
     /// タイムゾーン文字列を取得
     pub fn timezone(&self) -> Option<&str> {
         match self {
             NotificationConfig::Slack { timezone, .. } => timezone.as_deref(),
+            NotificationConfig::Email { timezone, .. } => timezone.as_deref(),
             NotificationConfig::Mock { timezone, .. } => timezone.as_deref(),
         }
     }
@@ -55,6 +115,9 @@ impl NotificationConfig {
             NotificationConfig::Slack {
                 templates, format, ..
             }
+            | NotificationConfig::Email {
+                templates, format, ..
+            }
             | NotificationConfig::Mock {
                 templates, format, ..
             } => NotificationCustomization {
@@ -63,6 +126,24 @@ impl NotificationConfig {
             },
         }
     }
+
+    /// 送信失敗時の再試行回数を取得
+    pub fn retries(&self) -> u32 {
+        match self {
+            NotificationConfig::Slack { retries, .. }
+            | NotificationConfig::Email { retries, .. } => *retries,
+            NotificationConfig::Mock { .. } => 0,
+        }
+    }
+
+    /// 代替通知先を取得
+    pub fn fallback(&self) -> &[NotificationConfig] {
+        match self {
+            NotificationConfig::Slack { fallback, .. }
+            | NotificationConfig::Email { fallback, .. } => fallback,
+            NotificationConfig::Mock { .. } => &[],
+        }
+    }
 }
 
 /// リソース全体の設定
@@ -72,6 +153,308 @@ pub struct ResourceConfig {
     pub servers: Vec<ServerConfig>,
     /// 部屋の設定リスト
     pub rooms: Vec<RoomConfig>,
+    /// 実験機器の設定リスト（オプション）
+    #[serde(default)]
+    pub instruments: Vec<InstrumentConfig>,
+    /// 専用のカレンダーを持たない機器の予約を登録する共有カレンダーID（オプション）
+    ///
+    /// 共有カレンダーの予定は、タイトルに書かれた機器名で機器を判別する。
+    #[serde(default)]
+    pub instrument_calendar_id: Option<String>,
+    /// 設定で定義するリソース種別のリスト（オプション）
+    ///
+    /// コードを変更せずに、部屋と同様に1件ずつ予約するリソースの種別を追加できる。
+    #[serde(default)]
+    pub resource_types: Vec<ResourceTypeConfig>,
+    /// 共有ストレージのボリュームの設定リスト（オプション）
+    ///
+    /// 同じボリュームの予約は、重なる時間帯の確保容量の合計が `capacity_gb` を超えない範囲で共存できる。
+    #[serde(default)]
+    pub storage: Vec<StorageConfig>,
+    /// フローティングライセンスの設定リスト（オプション）
+    ///
+    /// 同じライセンスの予約は、重なる時間帯の確保席数の合計が `seats` を超えない範囲で共存できる。
+    #[serde(default)]
+    pub licenses: Vec<LicenseConfig>,
+    /// 終日イベントの解釈に使うタイムゾーン（オプション、IANA形式）
+    ///
+    /// 指定しない場合はシステムのローカルタイムゾーンを使用する。不正な値は読み込み時に拒否する。
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// 休業日の設定（オプション）
+    ///
+    /// 指定した場合、週末や休業日にかかる予約の確認メッセージに注意書きを表示する。
+    #[serde(default)]
+    pub lab_calendar: Option<LabCalendarConfig>,
+    /// 利用者1人あたりの同時予約の上限（オプション）
+    ///
+    /// サーバーごとの上限は `servers` の `max_reservations_per_user` と `max_devices_per_user` で指定する。
+    #[serde(default)]
+    pub reservation_limits: ReservationLimitConfig,
+    /// 公平な利用のための助言の設定（オプション）
+    ///
+    /// 指定した場合、直近の利用実績が公平な配分を超える利用者の予約の確認メッセージに、
+    /// 使用量と空いている別のサーバーを添える。
+    #[serde(default)]
+    pub fair_share: Option<FairShareConfig>,
+    /// GPUが使われていない予約の自動解放の設定（オプション）
+    ///
+    /// 指定した場合、`gpu_monitor` を設定したサーバーの予約で、GPUが使われていない状態が続くと
+    /// 予約者に確認し、応答がなければ予約を解放する。
+    #[serde(default)]
+    pub idle_release: Option<IdleReleaseConfig>,
+    /// GPUの使用料金の設定（オプション）
+    ///
+    /// 指定した場合、管理者が `/cost-report` でユーザー・プロジェクトごとの費用を集計できる。
+    #[serde(default)]
+    pub cost_model: Option<CostModelConfig>,
+    /// 管理者のメールアドレス、SlackユーザーIDまたはSlackユーザーグループID（オプション）
+    ///
+    /// 管理者は非公開の予約の詳細（予約者・備考）も閲覧でき、他のユーザーの予約を更新・削除できる。
+    /// また、`/unlink-user` や `/link-history`、`/freeze-resource`、`/device-status`、`/admin-cancel` などの
+    /// 管理者用コマンドを実行できる。
+    #[serde(default)]
+    pub admins: Vec<String>,
+    /// モデレーターのメールアドレス、SlackユーザーIDまたはSlackユーザーグループID（オプション）
+    ///
+    /// モデレーターは他のユーザーの予約をキャンセルできる（`/admin-cancel` を含む）が、更新はできない。
+    /// 管理者と一般メンバーの中間の役割で、管理者用のその他のコマンドは実行できない。
+    #[serde(default)]
+    pub moderators: Vec<String>,
+    /// 予約を所有できるチームの設定リスト（オプション）
+    ///
+    /// チームを所有者とした予約は、予約者本人に加えてそのチームのメンバーも更新・削除できる。
+    #[serde(default)]
+    pub groups: Vec<GroupConfig>,
+    /// 承認依頼を投稿するSlackチャンネルID（オプション）
+    ///
+    /// `requires_approval` を指定した部屋の予約は、このチャンネルに承認・却下ボタン付きで投稿される。
+    #[serde(default)]
+    pub approvers_channel_id: Option<String>,
+    /// 予約なしでのGPUの使用を警告するSlackチャンネルID（オプション）
+    ///
+    /// `gpu_monitor` を設定したサーバーで、予約のないGPUでプロセスが実行されているとこのチャンネルに投稿される。
+    #[serde(default)]
+    pub unreserved_usage_channel_id: Option<String>,
+    /// 運用エラーを投稿するSlackチャンネルID（オプション）
+    ///
+    /// 指定した場合、ポーリングの失敗やファイルの破損、外部APIの失敗などのエラーをこのチャンネルに投稿する。
+    /// 同じエラーは1時間に1回だけ投稿する。
+    #[serde(default, alias = "ops_channel")]
+    pub ops_channel_id: Option<String>,
+    /// 週間の利用状況のまとめの設定（オプション）
+    ///
+    /// 指定した場合、サーバーの通知先のSlackチャンネルに、そのチャンネルに通知するサーバーの
+    /// 先週の予約時間・今週の空き・今週の大きな予約のまとめを週に一度投稿する。
+    #[serde(default)]
+    pub weekly_digest: Option<WeeklyDigestConfig>,
+    /// 表示言語の設定（オプション）
+    #[serde(default)]
+    pub i18n: I18nConfig,
+}
+
+/// 週間の利用状況のまとめの設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct WeeklyDigestConfig {
+    /// 投稿する曜日（デフォルト: "Mon"）
+    #[serde(
+        default = "default_digest_weekday",
+        deserialize_with = "deserialize_weekday"
+    )]
+    pub weekday: Weekday,
+    /// 投稿する時刻（時、`timezone` のタイムゾーン、デフォルト: 9）
+    #[serde(default = "default_digest_hour")]
+    pub hour: u32,
+    /// 大きな予約とみなすGPU時間（GPU 1台につき1時間で1時間、デフォルト: 24）
+    #[serde(default = "default_big_booking_gpu_hours")]
+    pub big_booking_gpu_hours: u32,
+}
+
+fn default_digest_weekday() -> Weekday {
+    Weekday::Mon
+}
+
+fn default_digest_hour() -> u32 {
+    9
+}
+
+fn default_big_booking_gpu_hours() -> u32 {
+    24
+}
+
+fn deserialize_weekday<'de, D>(deserializer: D) -> Result<Weekday, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse::<Weekday>()
+        .map_err(|_| serde::de::Error::custom(format!("不明な曜日: {}", s)))
+}
+
+/// チームの設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct GroupConfig {
+    /// チームの識別子（予約に記録されるため、後から変更しないこと）
+    pub id: String,
+    /// 表示名
+    pub name: String,
+    /// メンバーのメールアドレスまたはSlackユーザーID
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+impl GroupConfig {
+    /// メールアドレスで登録されたメンバー
+    pub fn member_emails(&self) -> Vec<EmailAddress> {
+        self.members
+            .iter()
+            .filter_map(|member| EmailAddress::new(member.trim().to_string()).ok())
+            .collect()
+    }
+
+    /// SlackユーザーIDで登録されたメンバー
+    pub fn member_user_ids(&self) -> impl Iterator<Item = &str> {
+        self.members
+            .iter()
+            .map(|member| member.trim())
+            .filter(|member| !member.contains('@'))
+    }
+}
+
+/// 表示言語の設定
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct I18nConfig {
+    /// Slackの画面・通知の表示言語（デフォルトは日本語）
+    #[serde(default)]
+    pub locale: Locale,
+    /// Slackの画面を利用者ごとのSlackの言語設定で表示するか
+    ///
+    /// 有効にした場合、モーダルやコマンドの返信は利用者の言語設定に従い、
+    /// 対応していない言語の場合は `locale` を使う。チャンネルへの通知は常に `locale` で表示する。
+    #[serde(default)]
+    pub use_slack_locale: bool,
+}
+
+/// 利用者1人あたりの同時予約の上限の設定
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ReservationLimitConfig {
+    /// 終了していない予約の件数の上限
+    #[serde(default)]
+    pub max_reservations: Option<u32>,
+    /// 同時に確保できるGPUの数の上限
+    #[serde(default)]
+    pub max_devices: Option<u32>,
+}
+
+/// GPUの使用料金の設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct CostModelConfig {
+    /// 通貨の表記（デフォルト: "¥"）
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    /// GPUのモデルごとの1時間あたりの料金（キーは `servers.devices` の `model`）
+    #[serde(default)]
+    pub hourly_rates: HashMap<String, f64>,
+}
+
+fn default_currency() -> String {
+    "¥".to_string()
+}
+
+/// 公平な利用のための助言の設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct FairShareConfig {
+    /// 利用実績を集計する日数（デフォルト: 7）
+    #[serde(default = "default_fair_share_window_days")]
+    pub window_days: u32,
+    /// 助言するしきい値（公平な配分の何倍か、デフォルト: 1.5）
+    #[serde(default = "default_fair_share_threshold")]
+    pub threshold: f64,
+}
+
+/// GPUが使われていない予約の自動解放の設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct IdleReleaseConfig {
+    /// GPUが使われていない状態が何分続いたら予約者に確認するか（デフォルト: 60）
+    #[serde(default = "default_idle_minutes")]
+    pub idle_minutes: u32,
+    /// 確認から何分応答がなければ解放するか（デフォルト: 30）
+    #[serde(default = "default_idle_grace_minutes")]
+    pub grace_minutes: u32,
+}
+
+fn default_idle_minutes() -> u32 {
+    IdleReleasePolicy::DEFAULT_IDLE_MINUTES
+}
+
+fn default_idle_grace_minutes() -> u32 {
+    IdleReleasePolicy::DEFAULT_GRACE_MINUTES
+}
+
+fn default_fair_share_window_days() -> u32 {
+    FairSharePolicy::DEFAULT_WINDOW_DAYS
+}
+
+fn default_fair_share_threshold() -> f64 {
+    FairSharePolicy::DEFAULT_THRESHOLD
+}
+
+/// 休業日の設定（学年暦など）
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct LabCalendarConfig {
+    /// 定休日の曜日（例: `["Sat", "Sun"]`）
+    #[serde(default, deserialize_with = "deserialize_weekdays")]
+    pub closed_weekdays: Vec<Weekday>,
+    /// 休業日のリスト
+    #[serde(default)]
+    pub holidays: Vec<HolidayConfig>,
+}
+
+/// 休業日の設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct HolidayConfig {
+    /// 休業日の名前
+    pub name: String,
+    /// 日付（YYYY-MM-DD）
+    #[serde(deserialize_with = "deserialize_date")]
+    pub date: NaiveDate,
+    /// 複数日にわたる場合の最終日（YYYY-MM-DD、この日を含む）
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<NaiveDate>,
+}
+
+fn deserialize_weekdays<'de, D>(deserializer: D) -> Result<Vec<Weekday>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|s| {
+            s.parse::<Weekday>()
+                .map_err(|_| serde::de::Error::custom(format!("不明な曜日: {}", s)))
+        })
+        .collect()
+}
+
+fn parse_date<E: serde::de::Error>(s: &str) -> Result<NaiveDate, E> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| E::custom(format!("日付の形式が不正です（YYYY-MM-DD）: {}", s)))
+}
+
+fn deserialize_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_date(&String::deserialize(deserializer)?)
+}
+
+fn deserialize_optional_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| parse_date(&s))
+        .transpose()
 }
 
 /// サーバー（GPU）の設定
@@ -85,6 +468,103 @@ pub struct ServerConfig {
     pub devices: Vec<DeviceConfig>,
     /// 通知設定のリスト
     pub notifications: Vec<NotificationConfig>,
+    /// 電源管理の設定（オプション）
+    #[serde(default)]
+    pub power: Option<PowerConfig>,
+    /// GPUの実際の使用状況の取得の設定（オプション）
+    #[serde(default)]
+    pub gpu_monitor: Option<GpuMonitorConfig>,
+    /// 予約開始の何分前に予約者へリマインダーを送るか（オプション）
+    #[serde(default)]
+    pub remind_before_minutes: Option<u32>,
+    /// 予約終了の何分前に予約者へリマインダー（延長・解放ボタン付き）を送るか（オプション）
+    #[serde(default)]
+    pub remind_before_end_minutes: Option<u32>,
+    /// 利用者1人あたりのこのサーバーの終了していない予約の件数の上限（オプション）
+    #[serde(default)]
+    pub max_reservations_per_user: Option<u32>,
+    /// 利用者1人あたりの同時に確保できるこのサーバーのGPUの数の上限（オプション）
+    #[serde(default)]
+    pub max_devices_per_user: Option<u32>,
+    /// 開始の何分前までに予約する必要があるか（オプション）
+    #[serde(default)]
+    pub min_notice_minutes: Option<u32>,
+    /// 開始時刻を何日先まで予約できるか（オプション）
+    #[serde(default)]
+    pub max_advance_days: Option<u32>,
+    /// サーバーのすべてのデバイスに付けるタグ（例: "a100", "large-memory"）
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// サーバーの電源管理（BMC）の設定
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum PowerConfig {
+    /// Redfish API
+    Redfish {
+        /// BMCのURL（例: https://bmc-thalys.example.com）
+        address: String,
+        /// BMCのユーザー名
+        username: String,
+        /// BMCのパスワード
+        password: String,
+        /// RedfishのシステムID
+        #[serde(default = "default_redfish_system_id")]
+        system_id: String,
+        /// 自己署名証明書を許可するかどうか
+        #[serde(default)]
+        accept_invalid_certs: bool,
+        /// 予約開始の何分前に電源を入れるか（オプション）
+        #[serde(default)]
+        wake_before_minutes: Option<u32>,
+    },
+    /// IPMI（ipmitool経由）
+    Ipmi {
+        /// BMCのホスト名またはIPアドレス
+        host: String,
+        /// BMCのユーザー名
+        username: String,
+        /// BMCのパスワード
+        password: String,
+        /// 予約開始の何分前に電源を入れるか（オプション）
+        #[serde(default)]
+        wake_before_minutes: Option<u32>,
+    },
+}
+
+/// GPUの実際の使用状況の取得の設定
+///
+/// SSHでサーバーに接続し、`nvidia-smi` の出力からGPUで実行中のプロセスを取得する。
+#[derive(Debug, Deserialize, Clone)]
+pub struct GpuMonitorConfig {
+    /// SSHの接続先のホスト名（`~/.ssh/config` のホスト名も使える）
+    pub ssh_host: String,
+    /// SSHのユーザー名（オプション、省略時はSSHの設定に従う）
+    #[serde(default)]
+    pub ssh_user: Option<String>,
+}
+
+fn default_redfish_system_id() -> String {
+    "1".to_string()
+}
+
+impl PowerConfig {
+    /// 予約開始の何分前に電源を入れるか
+    ///
+    /// 指定されていない場合は自動起動しない。
+    pub fn wake_before_minutes(&self) -> Option<u32> {
+        match self {
+            PowerConfig::Redfish {
+                wake_before_minutes,
+                ..
+            }
+            | PowerConfig::Ipmi {
+                wake_before_minutes,
+                ..
+            } => *wake_before_minutes,
+        }
+    }
 }
 
 /// デバイス（GPU）の設定
@@ -94,6 +574,57 @@ pub struct DeviceConfig {
     pub id: u32,
     /// モデル名
     pub model: String,
+    /// デバイス専用のカレンダーID（オプション）
+    ///
+    /// 指定した場合、このデバイスの予約はサーバーのカレンダーではなくこのカレンダーに登録される。
+    #[serde(default)]
+    pub calendar_id: Option<String>,
+    /// デバイスに付けるタグ（サーバーのタグに加えて付く）
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl ServerConfig {
+    /// デバイスのタグ（サーバーのタグとデバイスのタグ、重複を除く）
+    pub fn device_tags<'a>(&'a self, device: &'a DeviceConfig) -> Vec<&'a str> {
+        let mut tags: Vec<&str> = Vec::new();
+        for tag in self.tags.iter().chain(&device.tags) {
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+
+    /// デバイスに指定したタグが付いているかどうか（大文字小文字を区別しない）
+    pub fn device_has_tag(&self, device: &DeviceConfig, tag: &str) -> bool {
+        self.device_tags(device)
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// 指定したタグが付いたデバイスを持つかどうか
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.devices
+            .iter()
+            .any(|device| self.device_has_tag(device, tag))
+    }
+
+    /// デバイスの予約を登録するカレンダーIDを取得
+    ///
+    /// デバイス専用のカレンダーがなければサーバーのカレンダーIDを返す。
+    pub fn calendar_id_for_device(&self, device_id: u32) -> &str {
+        self.devices
+            .iter()
+            .find(|d| d.id == device_id)
+            .and_then(|d| d.calendar_id.as_deref())
+            .unwrap_or(&self.calendar_id)
+    }
+
+    /// デバイス専用のカレンダーを持つかどうか
+    pub fn has_device_calendars(&self) -> bool {
+        self.devices.iter().any(|d| d.calendar_id.is_some())
+    }
 }
 
 /// 部屋の設定
@@ -105,9 +636,208 @@ pub struct RoomConfig {
     pub calendar_id: String,
     /// 通知設定のリスト
     pub notifications: Vec<NotificationConfig>,
+    /// 予約開始の何分前に予約者へリマインダーを送るか（オプション）
+    #[serde(default)]
+    pub remind_before_minutes: Option<u32>,
+    /// 予約終了の何分前に予約者へリマインダー（延長・解放ボタン付き）を送るか（オプション）
+    #[serde(default)]
+    pub remind_before_end_minutes: Option<u32>,
+    /// 予約に承認者の承認が必要かどうか（オプション、デフォルトはfalse）
+    #[serde(default)]
+    pub requires_approval: bool,
+    /// 予約を承認・却下できるユーザー（メールアドレスまたはSlackユーザーID）
+    ///
+    /// 管理者は指定しなくても承認・却下できる。
+    #[serde(default)]
+    pub approvers: Vec<String>,
+    /// 開始の何分前までに予約する必要があるか（オプション）
+    #[serde(default)]
+    pub min_notice_minutes: Option<u32>,
+    /// 開始時刻を何日先まで予約できるか（オプション）
+    #[serde(default)]
+    pub max_advance_days: Option<u32>,
+}
+
+impl RoomConfig {
+    /// 承認者として登録されたメールアドレス
+    pub fn approver_emails(&self) -> Vec<EmailAddress> {
+        listed_emails(&self.approvers)
+    }
+
+    /// 承認者として登録されたSlackユーザーID（`@` を含まない指定）
+    pub fn approver_user_ids(&self) -> impl Iterator<Item = &str> {
+        listed_user_ids(&self.approvers)
+    }
+}
+
+/// 実験機器の設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct InstrumentConfig {
+    /// 機器名
+    pub name: String,
+    /// 機器専用のカレンダーID（オプション）
+    ///
+    /// 指定しない場合は `instrument_calendar_id` の共有カレンダーに登録される。
+    #[serde(default)]
+    pub calendar_id: Option<String>,
+    /// 通知設定のリスト
+    pub notifications: Vec<NotificationConfig>,
+    /// 開始の何分前までに予約する必要があるか（オプション）
+    #[serde(default)]
+    pub min_notice_minutes: Option<u32>,
+    /// 開始時刻を何日先まで予約できるか（オプション）
+    #[serde(default)]
+    pub max_advance_days: Option<u32>,
+}
+
+/// 設定で定義するリソース種別（カメラ、公用車など）
+#[derive(Debug, Deserialize, Clone)]
+pub struct ResourceTypeConfig {
+    /// 種別のID（予約データとSlackのフォームで使う）
+    pub id: String,
+    /// 予約フォームに表示する種別名
+    pub label: String,
+    /// この種別のリソースのリスト
+    pub resources: Vec<CustomResourceConfig>,
+}
+
+/// 設定で定義した種別のリソースの設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct CustomResourceConfig {
+    /// リソース名
+    pub name: String,
+    /// カレンダーID
+    pub calendar_id: String,
+    /// 通知設定のリスト
+    pub notifications: Vec<NotificationConfig>,
+}
+
+/// 共有ストレージのボリュームの設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct StorageConfig {
+    /// ボリューム名（予定のタイトルの `scratch:500G` の `scratch` の部分）
+    pub name: String,
+    /// カレンダーID
+    pub calendar_id: String,
+    /// ボリュームの容量（GB）
+    pub capacity_gb: u64,
+    /// 通知設定のリスト
+    pub notifications: Vec<NotificationConfig>,
+}
+
+/// フローティングライセンスの設定
+#[derive(Debug, Deserialize, Clone)]
+pub struct LicenseConfig {
+    /// ライセンス名（予定のタイトルの `matlab:2` の `matlab` の部分）
+    pub name: String,
+    /// カレンダーID
+    pub calendar_id: String,
+    /// 同時に利用できる席数
+    pub seats: u32,
+    /// 通知設定のリスト
+    pub notifications: Vec<NotificationConfig>,
 }
 
 impl ResourceConfig {
+    /// `timezone` を解釈したタイムゾーン（未指定の場合は `None`）
+    ///
+    /// 不正な値は [`load_config`] が拒否するため、読み込んだ設定では指定した値が無視されることはない。
+    pub fn tz(&self) -> Option<Tz> {
+        self.timezone.as_deref().and_then(|tz| tz.parse().ok())
+    }
+
+    /// 指定したメールアドレスが管理者かどうか（大文字小文字は区別しない）
+    pub fn is_admin(&self, email: &EmailAddress) -> bool {
+        self.role_of(email) == Role::Admin
+    }
+
+    /// 指定したSlackユーザーIDが管理者として登録されているかどうか
+    pub fn is_admin_user_id(&self, user_id: &str) -> bool {
+        self.role_of_user_id(user_id) == Role::Admin
+    }
+
+    /// 管理者として登録されたメールアドレス
+    pub fn admin_emails(&self) -> Vec<EmailAddress> {
+        listed_emails(&self.admins)
+    }
+
+    /// 管理者として登録されたSlackユーザーID（`@` を含まない指定）
+    pub fn admin_user_ids(&self) -> impl Iterator<Item = &str> {
+        listed_user_ids(&self.admins)
+    }
+
+    /// モデレーターとして登録されたメールアドレス
+    pub fn moderator_emails(&self) -> Vec<EmailAddress> {
+        listed_emails(&self.moderators)
+    }
+
+    /// モデレーターとして登録されたSlackユーザーID（`@` を含まない指定）
+    pub fn moderator_user_ids(&self) -> impl Iterator<Item = &str> {
+        listed_user_ids(&self.moderators)
+    }
+
+    /// 指定したメールアドレスのユーザーの役割（大文字小文字は区別しない）
+    ///
+    /// 管理者とモデレーターの両方に登録されている場合は管理者とする。
+    pub fn role_of(&self, email: &EmailAddress) -> Role {
+        let listed = |entries: &[String]| {
+            entries
+                .iter()
+                .any(|entry| entry.trim().eq_ignore_ascii_case(email.as_str()))
+        };
+        if listed(&self.admins) {
+            Role::Admin
+        } else if listed(&self.moderators) {
+            Role::Moderator
+        } else {
+            Role::Member
+        }
+    }
+
+    /// 指定したSlackユーザーIDで登録されたユーザーの役割
+    pub fn role_of_user_id(&self, user_id: &str) -> Role {
+        if listed_user_ids(&self.admins).any(|admin| admin == user_id) {
+            Role::Admin
+        } else if listed_user_ids(&self.moderators).any(|moderator| moderator == user_id) {
+            Role::Moderator
+        } else {
+            Role::Member
+        }
+    }
+
+    /// 管理者・モデレーターとして登録されたSlackユーザーグループID（`S` で始まる指定）
+    pub fn user_group_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .admins
+            .iter()
+            .chain(&self.moderators)
+            .map(|entry| entry.trim())
+            .filter(|entry| is_user_group_id(entry))
+            .map(str::to_string)
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// 管理者・モデレーターのSlackユーザーグループIDを、そのメンバーのSlackユーザーIDに置き換える
+    ///
+    /// # Arguments
+    /// * `members` - ユーザーグループIDごとのメンバーのSlackユーザーID（含まれないグループはそのまま残す）
+    pub fn expand_user_groups(&mut self, members: &HashMap<String, Vec<String>>) {
+        let expand = |entries: &mut Vec<String>| {
+            *entries = entries
+                .drain(..)
+                .flat_map(|entry| match members.get(entry.trim()) {
+                    Some(user_ids) => user_ids.clone(),
+                    None => vec![entry],
+                })
+                .collect();
+        };
+        expand(&mut self.admins);
+        expand(&mut self.moderators);
+    }
+
     /// カレンダーIDからサーバー名へのマッピングを取得
     pub fn calendar_to_server_map(&self) -> HashMap<String, String> {
         self.servers
@@ -116,11 +846,556 @@ impl ResourceConfig {
             .collect()
     }
 
+    /// サーバー・デバイスに付けられたすべてのタグ（名前順、大文字小文字を区別せず重複を除く）
+    pub fn tags(&self) -> Vec<&str> {
+        let mut tags: Vec<&str> = Vec::new();
+        for server in &self.servers {
+            for device in &server.devices {
+                for tag in server.device_tags(device) {
+                    if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                        tags.push(tag);
+                    }
+                }
+            }
+        }
+        tags.sort_unstable_by_key(|tag| tag.to_lowercase());
+        tags
+    }
+
+    /// タグの付いたリソースとそのタグ（`resources` の順）
+    pub fn resource_tags(&self) -> Vec<(Resource, Vec<String>)> {
+        self.servers
+            .iter()
+            .flat_map(|s| {
+                s.devices.iter().map(move |d| {
+                    (
+                        Resource::Gpu(Gpu::new(s.name.clone(), d.id, d.model.clone())),
+                        s.device_tags(d).into_iter().map(str::to_string).collect(),
+                    )
+                })
+            })
+            .filter(|(_, tags): &(Resource, Vec<String>)| !tags.is_empty())
+            .collect()
+    }
+
     /// サーバー設定を名前で検索
     pub fn get_server(&self, name: &str) -> Option<&ServerConfig> {
         self.servers.iter().find(|s| s.name == name)
     }
 
+    /// 機器設定を名前で検索
+    pub fn get_instrument(&self, name: &str) -> Option<&InstrumentConfig> {
+        self.instruments.iter().find(|i| i.name == name)
+    }
+
+    /// 機器の予約を登録するカレンダーIDを取得
+    ///
+    /// 機器専用のカレンダーがなければ共有カレンダーIDを返す。どちらもなければ `None`。
+    pub fn calendar_id_for_instrument<'a>(
+        &'a self,
+        instrument: &'a InstrumentConfig,
+    ) -> Option<&'a str> {
+        instrument
+            .calendar_id
+            .as_deref()
+            .or(self.instrument_calendar_id.as_deref())
+    }
+
+    /// 指定したカレンダーに予約を登録する機器を取得
+    pub fn instruments_in_calendar(&self, calendar_id: &str) -> Vec<&InstrumentConfig> {
+        self.instruments
+            .iter()
+            .filter(|i| self.calendar_id_for_instrument(i) == Some(calendar_id))
+            .collect()
+    }
+
+    /// 設定で定義したリソース種別をIDで検索
+    pub fn get_resource_type(&self, id: &str) -> Option<&ResourceTypeConfig> {
+        self.resource_types.iter().find(|t| t.id == id)
+    }
+
+    /// リソース種別の表示名（設定にない種別はIDをそのまま返す）
+    pub fn resource_type_label<'a>(&'a self, kind: &'a str) -> &'a str {
+        self.get_resource_type(kind)
+            .map_or(kind, |resource_type| resource_type.label.as_str())
+    }
+
+    /// 設定で定義した種別のリソースを種別IDと名前で検索
+    pub fn get_custom_resource(&self, kind: &str, name: &str) -> Option<&CustomResourceConfig> {
+        self.get_resource_type(kind)?
+            .resources
+            .iter()
+            .find(|r| r.name == name)
+    }
+
+    /// 設定で定義した種別のリソースが指定した名前で存在するかどうか
+    pub fn has_custom_resource(&self, name: &str) -> bool {
+        self.resource_types
+            .iter()
+            .any(|t| t.resources.iter().any(|r| r.name == name))
+    }
+
+    /// カレンダーIDから設定で定義した種別のリソースを検索
+    pub fn custom_resource_for_calendar(
+        &self,
+        calendar_id: &str,
+    ) -> Option<(&ResourceTypeConfig, &CustomResourceConfig)> {
+        self.resource_types.iter().find_map(|t| {
+            t.resources
+                .iter()
+                .find(|r| r.calendar_id == calendar_id)
+                .map(|r| (t, r))
+        })
+    }
+
+    /// ストレージのボリューム設定を名前で検索
+    pub fn get_storage(&self, name: &str) -> Option<&StorageConfig> {
+        self.storage.iter().find(|s| s.name == name)
+    }
+
+    /// ストレージのボリューム名と容量（GB）の対応を取得
+    pub fn storage_capacities(&self) -> HashMap<String, u64> {
+        self.storage
+            .iter()
+            .map(|s| (s.name.clone(), s.capacity_gb))
+            .collect()
+    }
+
+    /// ライセンス設定を名前で検索
+    pub fn get_license(&self, name: &str) -> Option<&LicenseConfig> {
+        self.licenses.iter().find(|l| l.name == name)
+    }
+
+    /// ライセンス名と席数の対応を取得
+    pub fn license_seats(&self) -> HashMap<String, u32> {
+        self.licenses
+            .iter()
+            .map(|l| (l.name.clone(), l.seats))
+            .collect()
+    }
+
+    /// 設定されているすべてのカレンダーIDを取得（重複なし）
+    ///
+    /// サーバー、デバイス専用、部屋、機器、設定で定義したリソース、ストレージ、ライセンスのカレンダーの順に並ぶ。
+    pub fn calendar_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = Vec::new();
+        let candidates = self
+            .servers
+            .iter()
+            .flat_map(|s| {
+                std::iter::once(&s.calendar_id)
+                    .chain(s.devices.iter().filter_map(|d| d.calendar_id.as_ref()))
+            })
+            .chain(self.rooms.iter().map(|r| &r.calendar_id))
+            .chain(
+                self.instruments
+                    .iter()
+                    .filter_map(|i| i.calendar_id.as_ref())
+                    .chain(
+                        self.instrument_calendar_id
+                            .as_ref()
+                            .filter(|_| self.instruments.iter().any(|i| i.calendar_id.is_none())),
+                    ),
+            )
+            .chain(
+                self.resource_types
+                    .iter()
+                    .flat_map(|t| t.resources.iter().map(|r| &r.calendar_id)),
+            )
+            .chain(self.storage.iter().map(|s| &s.calendar_id))
+            .chain(self.licenses.iter().map(|l| &l.calendar_id));
+        for id in candidates {
+            if !ids.contains(id) {
+                ids.push(id.clone());
+            }
+        }
+        ids
+    }
+
+    /// GPUの予約が登録されうるカレンダーID（サーバーとデバイス専用のカレンダー）を取得（重複なし）
+    pub fn gpu_calendar_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = Vec::new();
+        let candidates = self.servers.iter().flat_map(|s| {
+            std::iter::once(&s.calendar_id)
+                .chain(s.devices.iter().filter_map(|d| d.calendar_id.as_ref()))
+        });
+        for id in candidates {
+            if !ids.contains(id) {
+                ids.push(id.clone());
+            }
+        }
+        ids
+    }
+
+    /// 設定されているすべてのリソース（サーバーのGPU、部屋、機器、設定で定義したリソースの順）を取得
+    ///
+    /// ストレージとライセンスは容量・席数を分け合うリソースのため含まない。
+    pub fn resources(&self) -> Vec<Resource> {
+        self.servers
+            .iter()
+            .flat_map(|s| {
+                s.devices
+                    .iter()
+                    .map(|d| Resource::Gpu(Gpu::new(s.name.clone(), d.id, d.model.clone())))
+            })
+            .chain(self.rooms.iter().map(|r| Resource::Room {
+                name: r.name.clone(),
+            }))
+            .chain(self.instruments.iter().map(|i| Resource::Instrument {
+                name: i.name.clone(),
+            }))
+            .chain(self.resource_types.iter().flat_map(|t| {
+                t.resources.iter().map(|r| Resource::Custom {
+                    kind: t.id.clone(),
+                    name: r.name.clone(),
+                })
+            }))
+            .collect()
+    }
+
+    /// リソースの指定（`;` 区切り）を予約するリソースに変換する
+    ///
+    /// リソース名は大文字・小文字を区別しない。サーバーは `Thalys:0-1` のようにデバイスを指定でき、
+    /// 省略するとすべてのデバイスを予約する。ストレージは `scratch:500G`、ライセンスは `matlab:2` のように指定する。
+    pub fn resolve_resources(&self, spec: &str) -> Result<Vec<Resource>, String> {
+        let mut resources = Vec::new();
+        for item in spec
+            .split(';')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            resources.extend(self.resolve_resource(item)?);
+        }
+        if resources.is_empty() {
+            return Err("リソースが指定されていません".to_string());
+        }
+        Ok(resources)
+    }
+
+    fn resolve_resource(&self, item: &str) -> Result<Vec<Resource>, String> {
+        let (name, detail) = match item.split_once(':') {
+            Some((name, detail)) => (name.trim(), Some(detail.trim())),
+            None => (item, None),
+        };
+
+        if let Some(server) = self
+            .servers
+            .iter()
+            .find(|server| server.name.eq_ignore_ascii_case(name))
+        {
+            return match detail {
+                Some(devices) => {
+                    ResourceFactory::create_gpus_from_spec(devices, &server.name, |id| {
+                        server
+                            .devices
+                            .iter()
+                            .find(|device| device.id == id)
+                            .map(|device| device.model.clone())
+                    })
+                    .map_err(|e| e.to_string())
+                }
+                None => Ok(server
+                    .devices
+                    .iter()
+                    .map(|device| {
+                        Resource::Gpu(Gpu::new(
+                            server.name.clone(),
+                            device.id,
+                            device.model.clone(),
+                        ))
+                    })
+                    .collect()),
+            };
+        }
+        if let Some(storage) = self
+            .storage
+            .iter()
+            .find(|storage| storage.name.eq_ignore_ascii_case(name))
+        {
+            let size =
+                detail.ok_or_else(|| format!("{} の容量を指定してください", storage.name))?;
+            return ResourceFactory::create_storage_from_spec(&format!(
+                "{}:{}",
+                storage.name, size
+            ))
+            .map(|resource| vec![resource])
+            .map_err(|e| e.to_string());
+        }
+        if let Some(license) = self
+            .licenses
+            .iter()
+            .find(|license| license.name.eq_ignore_ascii_case(name))
+        {
+            let spec = match detail {
+                Some(seats) => format!("{}:{}", license.name, seats),
+                None => license.name.clone(),
+            };
+            return ResourceFactory::create_license_from_spec(&spec)
+                .map(|resource| vec![resource])
+                .map_err(|e| e.to_string());
+        }
+
+        if detail.is_some() {
+            return Err(format!("{} にはデバイスを指定できません", name));
+        }
+        if let Some(room) = self
+            .rooms
+            .iter()
+            .find(|room| room.name.eq_ignore_ascii_case(name))
+        {
+            return Ok(vec![Resource::Room {
+                name: room.name.clone(),
+            }]);
+        }
+        if let Some(instrument) = self
+            .instruments
+            .iter()
+            .find(|instrument| instrument.name.eq_ignore_ascii_case(name))
+        {
+            return Ok(vec![Resource::Instrument {
+                name: instrument.name.clone(),
+            }]);
+        }
+        for resource_type in &self.resource_types {
+            if let Some(resource) = resource_type
+                .resources
+                .iter()
+                .find(|resource| resource.name.eq_ignore_ascii_case(name))
+            {
+                return Ok(vec![Resource::Custom {
+                    kind: resource_type.id.clone(),
+                    name: resource.name.clone(),
+                }]);
+            }
+        }
+
+        Err(format!("不明なリソースです: {}", name))
+    }
+
+    /// リソースが登録されているカレンダーIDを取得
+    pub fn get_calendar_id_for_resource(&self, resource: &Resource) -> Option<&str> {
+        match resource {
+            Resource::Gpu(gpu) => self
+                .get_server(gpu.server())
+                .map(|s| s.calendar_id_for_device(gpu.device_number())),
+            Resource::Room { name } => self
+                .rooms
+                .iter()
+                .find(|r| r.name == *name)
+                .map(|r| r.calendar_id.as_str()),
+            Resource::Instrument { name } => self
+                .get_instrument(name)
+                .and_then(|i| self.calendar_id_for_instrument(i)),
+            Resource::Custom { kind, name } => self
+                .get_custom_resource(kind, name)
+                .map(|r| r.calendar_id.as_str()),
+            Resource::Storage { volume, .. } => {
+                self.get_storage(volume).map(|s| s.calendar_id.as_str())
+            }
+            Resource::License { name, .. } => {
+                self.get_license(name).map(|l| l.calendar_id.as_str())
+            }
+        }
+    }
+
+    /// 予約開始前に電源を入れるサーバーと、その起動リードタイム（分）を取得
+    pub fn wake_before_minutes(&self) -> HashMap<String, u32> {
+        self.servers
+            .iter()
+            .filter_map(|s| {
+                s.power
+                    .as_ref()
+                    .and_then(PowerConfig::wake_before_minutes)
+                    .map(|minutes| (s.name.clone(), minutes))
+            })
+            .collect()
+    }
+
+    /// GPUの使用状況を取得するサーバーがあるかどうか
+    pub fn has_gpu_monitor(&self) -> bool {
+        self.servers.iter().any(|s| s.gpu_monitor.is_some())
+    }
+
+    /// サーバーの通知先のSlackチャンネルごとに、そのチャンネルに通知するサーバーを取得
+    ///
+    /// 代替通知先（`fallback`）のチャンネルは含めない。
+    pub fn servers_by_slack_channel(&self) -> BTreeMap<String, Vec<&ServerConfig>> {
+        let mut channels: BTreeMap<String, Vec<&ServerConfig>> = BTreeMap::new();
+        for server in &self.servers {
+            for notification in &server.notifications {
+                if let NotificationConfig::Slack { channel_id, .. } = notification {
+                    let servers = channels.entry(channel_id.clone()).or_default();
+                    if !servers.iter().any(|s| s.name == server.name) {
+                        servers.push(server);
+                    }
+                }
+            }
+        }
+        channels
+    }
+
+    /// 予約開始前にリマインダーを送るリソース（サーバー名・部屋名）と、そのリードタイム（分）を取得
+    pub fn remind_before_minutes(&self) -> HashMap<String, u32> {
+        self.servers
+            .iter()
+            .filter_map(|s| {
+                s.remind_before_minutes
+                    .map(|minutes| (s.name.clone(), minutes))
+            })
+            .chain(self.rooms.iter().filter_map(|r| {
+                r.remind_before_minutes
+                    .map(|minutes| (r.name.clone(), minutes))
+            }))
+            .collect()
+    }
+
+    /// 予約終了前にリマインダーを送るリソース（サーバー名・部屋名）と、そのリードタイム（分）を取得
+    pub fn remind_before_end_minutes(&self) -> HashMap<String, u32> {
+        self.servers
+            .iter()
+            .filter_map(|s| {
+                s.remind_before_end_minutes
+                    .map(|minutes| (s.name.clone(), minutes))
+            })
+            .chain(self.rooms.iter().filter_map(|r| {
+                r.remind_before_end_minutes
+                    .map(|minutes| (r.name.clone(), minutes))
+            }))
+            .collect()
+    }
+
+    /// 予約に承認が必要な部屋名を取得
+    pub fn approval_required_rooms(&self) -> HashSet<String> {
+        self.rooms
+            .iter()
+            .filter(|r| r.requires_approval)
+            .map(|r| r.name.clone())
+            .collect()
+    }
+
+    /// 予約に承認が必要な部屋
+    pub fn approval_required_room_configs(&self) -> impl Iterator<Item = &RoomConfig> {
+        self.rooms.iter().filter(|r| r.requires_approval)
+    }
+
+    /// 休業日の注意喚起ポリシーを取得
+    ///
+    /// 休業日の設定がない場合は `None` を返す。
+    pub fn holiday_advisory_policy(&self) -> Option<HolidayAdvisoryPolicy> {
+        self.lab_calendar.as_ref().map(|calendar| {
+            HolidayAdvisoryPolicy::new(
+                calendar.closed_weekdays.clone(),
+                calendar
+                    .holidays
+                    .iter()
+                    .map(|h| Holiday {
+                        name: h.name.clone(),
+                        start: h.date,
+                        end: h.end_date.unwrap_or(h.date),
+                    })
+                    .collect(),
+                self.timezone.as_deref(),
+            )
+        })
+    }
+
+    /// 利用者ごとの同時予約の上限ポリシーを取得
+    ///
+    /// 上限を設定していない場合は、何も制限しないポリシーになる。
+    pub fn reservation_limit_policy(&self) -> ReservationLimitPolicy {
+        let global = ReservationLimit {
+            max_reservations: self.reservation_limits.max_reservations,
+            max_devices: self.reservation_limits.max_devices,
+        };
+        self.servers
+            .iter()
+            .filter(|s| s.max_reservations_per_user.is_some() || s.max_devices_per_user.is_some())
+            .fold(ReservationLimitPolicy::new(global), |policy, s| {
+                policy.with_server_limit(
+                    s.name.clone(),
+                    ReservationLimit {
+                        max_reservations: s.max_reservations_per_user,
+                        max_devices: s.max_devices_per_user,
+                    },
+                )
+            })
+    }
+
+    /// 公平な利用のための助言ポリシーを取得
+    ///
+    /// 設定がない場合は `None` を返す。代替案の候補はすべてのサーバーのGPU。
+    pub fn fair_share_policy(&self) -> Option<FairSharePolicy> {
+        self.fair_share.as_ref().map(|fair_share| {
+            FairSharePolicy::new(
+                Duration::days(i64::from(fair_share.window_days)),
+                fair_share.threshold,
+            )
+            .with_gpus(
+                self.servers
+                    .iter()
+                    .flat_map(|s| {
+                        s.devices
+                            .iter()
+                            .map(|d| Gpu::new(s.name.clone(), d.id, d.model.clone()))
+                    })
+                    .collect(),
+            )
+        })
+    }
+
+    /// GPUが使われていない予約の自動解放ポリシーを取得
+    ///
+    /// `[idle_release]` を設定していない場合は `None`
+    pub fn idle_release_policy(&self) -> Option<IdleReleasePolicy> {
+        self.idle_release.as_ref().map(|idle_release| {
+            IdleReleasePolicy::new(
+                Duration::minutes(i64::from(idle_release.idle_minutes)),
+                Duration::minutes(i64::from(idle_release.grace_minutes)),
+            )
+        })
+    }
+
+    /// GPUの使用料金のモデルを取得
+    ///
+    /// 設定がない場合は `None` を返す。
+    pub fn cost_model(&self) -> Option<CostModel> {
+        self.cost_model.as_ref().map(|cost_model| {
+            cost_model.hourly_rates.iter().fold(
+                CostModel::new(cost_model.currency.clone()),
+                |model, (gpu_model, rate)| model.with_hourly_rate(gpu_model.clone(), *rate),
+            )
+        })
+    }
+
+    /// リソースごとの予約の受付期間ポリシーを取得
+    ///
+    /// サーバー・部屋・機器の `min_notice_minutes` と `max_advance_days` から作成する。
+    /// どちらも指定していないリソースは制限しない。
+    pub fn booking_window_policy(&self) -> BookingWindowPolicy {
+        let window =
+            |min_notice_minutes: Option<u32>, max_advance_days: Option<u32>| BookingWindow {
+                min_notice: min_notice_minutes.map(|minutes| Duration::minutes(i64::from(minutes))),
+                max_advance: max_advance_days.map(|days| Duration::days(i64::from(days))),
+            };
+        self.servers
+            .iter()
+            .map(|s| (&s.name, window(s.min_notice_minutes, s.max_advance_days)))
+            .chain(
+                self.rooms
+                    .iter()
+                    .map(|r| (&r.name, window(r.min_notice_minutes, r.max_advance_days))),
+            )
+            .chain(
+                self.instruments
+                    .iter()
+                    .map(|i| (&i.name, window(i.min_notice_minutes, i.max_advance_days))),
+            )
+            .filter(|(_, window)| *window != BookingWindow::default())
+            .fold(BookingWindowPolicy::new(), |policy, (name, window)| {
+                policy.with_window(name.clone(), window)
+            })
+    }
+
     /// リソースに対する通知設定を取得
     pub fn get_notifications_for_resource(&self, resource: &Resource) -> Vec<NotificationConfig> {
         match resource {
@@ -136,15 +1411,848 @@ impl ResourceConfig {
                 .find(|r| r.name == *name)
                 .map(|r| r.notifications.clone())
                 .unwrap_or_default(),
+            Resource::Instrument { name } => self
+                .get_instrument(name)
+                .map(|i| i.notifications.clone())
+                .unwrap_or_default(),
+            Resource::Custom { kind, name } => self
+                .get_custom_resource(kind, name)
+                .map(|r| r.notifications.clone())
+                .unwrap_or_default(),
+            Resource::Storage { volume, .. } => self
+                .get_storage(volume)
+                .map(|s| s.notifications.clone())
+                .unwrap_or_default(),
+            Resource::License { name, .. } => self
+                .get_license(name)
+                .map(|l| l.notifications.clone())
+                .unwrap_or_default(),
         }
     }
 }
 
 /// TOMLファイルからリソース設定を読み込む
+/// メールアドレスで登録されたユーザー
+fn listed_emails(entries: &[String]) -> Vec<EmailAddress> {
+    entries
+        .iter()
+        .filter_map(|entry| EmailAddress::new(entry.trim().to_string()).ok())
+        .collect()
+}
+
+/// SlackユーザーIDで登録されたユーザー（メールアドレスとユーザーグループIDを除く）
+fn listed_user_ids(entries: &[String]) -> impl Iterator<Item = &str> {
+    entries
+        .iter()
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.contains('@') && !is_user_group_id(entry))
+}
+
+/// SlackユーザーグループIDかどうか（SlackユーザーIDは `U` か `W`、ユーザーグループIDは `S` で始まる）
+fn is_user_group_id(entry: &str) -> bool {
+    entry.starts_with('S') && !entry.contains('@')
+}
+
+/// リソース設定ファイルを読み込む
+///
+/// `include` で指定したファイルをマージしてから、文字列の値の `${ENV_VAR}` を展開し、`LRM_` から始まる環境変数による上書きを適用する。
+/// `timezone` が不正な場合は、時刻がシステムのタイムゾーンで解釈されないようエラーにする。
 pub fn load_config(
     path: impl AsRef<std::path::Path>,
 ) -> Result<ResourceConfig, Box<dyn std::error::Error>> {
-    let content = fs::read_to_string(path)?;
-    let config: ResourceConfig = toml::from_str(&content)?;
+    let mut table = include::load_with_includes(path.as_ref())?;
+    interpolation::interpolate(&mut table, &|name| std::env::var(name).ok())?;
+    interpolation::apply_overrides(&mut table, std::env::vars())?;
+    let config: ResourceConfig = toml::Value::Table(table).try_into()?;
+    if let Some(issue) = config.timezone_issue() {
+        return Err(issue.into());
+    }
     Ok(config)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+
+    const CONFIG: &str = r#"
+[[servers]]
+name = "Thalys"
+calendar_id = "server@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers.devices]]
+id = 1
+model = "A100"
+calendar_id = "gpu1@example.com"
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+notifications = []
+"#;
+
+    #[test]
+    fn test_load_config_rejects_unknown_timezone() {
+        let dir = std::env::temp_dir().join(format!("lrm_timezone_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("resources.toml");
+
+        std::fs::write(&path, format!("timezone = \"Asia/Tokio\"\n{}", CONFIG)).unwrap();
+        let error = load_config(&path).unwrap_err().to_string();
+        assert!(error.starts_with("timezone: "), "{}", error);
+
+        std::fs::write(&path, format!("timezone = \"Asia/Tokyo\"\n{}", CONFIG)).unwrap();
+        assert_eq!(load_config(&path).unwrap().tz(), Some(Tz::Asia__Tokyo));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_calendar_id_for_device_falls_back_to_server() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        let server = config.get_server("Thalys").unwrap();
+
+        assert!(server.has_device_calendars());
+        assert_eq!(server.calendar_id_for_device(0), "server@example.com");
+        assert_eq!(server.calendar_id_for_device(1), "gpu1@example.com");
+    }
+
+    #[test]
+    fn test_calendar_ids_include_device_calendars() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+
+        assert_eq!(
+            config.calendar_ids(),
+            vec![
+                "server@example.com".to_string(),
+                "gpu1@example.com".to_string(),
+                "room@example.com".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gpu_calendar_ids_exclude_rooms() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+
+        assert_eq!(
+            config.gpu_calendar_ids(),
+            vec![
+                "server@example.com".to_string(),
+                "gpu1@example.com".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resources_list_gpus_then_rooms() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+
+        let names: Vec<String> = config.resources().iter().map(|r| r.to_string()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Thalys GPU#0 (A100)".to_string(),
+                "Thalys GPU#1 (A100)".to_string(),
+                "部屋: 会議室A".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_device_tags_combine_server_and_device_tags() {
+        let config: ResourceConfig = toml::from_str(
+            r#"
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+tags = ["a100"]
+
+[[servers.devices]]
+id = 0
+model = "A100"
+tags = ["large-memory", "A100"]
+
+[[servers.devices]]
+id = 1
+model = "A100"
+
+[[servers]]
+name = "Freccia"
+calendar_id = "freccia@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "RTX 4090"
+tags = ["teaching-only"]
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+notifications = []
+"#,
+        )
+        .unwrap();
+        let thalys = config.get_server("Thalys").unwrap();
+
+        assert_eq!(
+            thalys.device_tags(&thalys.devices[0]),
+            vec!["a100", "large-memory"]
+        );
+        assert!(thalys.device_has_tag(&thalys.devices[1], "A100"));
+        assert!(!thalys.device_has_tag(&thalys.devices[1], "large-memory"));
+        assert!(!config.get_server("Freccia").unwrap().has_tag("a100"));
+        assert_eq!(config.tags(), vec!["a100", "large-memory", "teaching-only"]);
+        assert_eq!(config.resource_tags().len(), 3);
+    }
+
+    #[test]
+    fn test_instrument_calendar_falls_back_to_shared_calendar() {
+        let content = format!(
+            r#"instrument_calendar_id = "instruments@example.com"
+{}
+[[instruments]]
+name = "オシロスコープ"
+notifications = []
+
+[[instruments]]
+name = "3Dプリンター"
+calendar_id = "printer@example.com"
+notifications = []
+"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let instrument = |name: &str| Resource::Instrument {
+            name: name.to_string(),
+        };
+
+        assert_eq!(
+            config.get_calendar_id_for_resource(&instrument("オシロスコープ")),
+            Some("instruments@example.com")
+        );
+        assert_eq!(
+            config.get_calendar_id_for_resource(&instrument("3Dプリンター")),
+            Some("printer@example.com")
+        );
+        assert_eq!(
+            config
+                .instruments_in_calendar("instruments@example.com")
+                .iter()
+                .map(|i| i.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["オシロスコープ"]
+        );
+        assert!(
+            config
+                .calendar_ids()
+                .contains(&"instruments@example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_resource_types() {
+        let content = format!(
+            r#"{}
+[[resource_types]]
+id = "camera"
+label = "カメラ"
+
+[[resource_types.resources]]
+name = "EOS R5"
+calendar_id = "camera@example.com"
+notifications = []
+"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let camera = Resource::Custom {
+            kind: "camera".to_string(),
+            name: "EOS R5".to_string(),
+        };
+
+        assert_eq!(
+            config.get_calendar_id_for_resource(&camera),
+            Some("camera@example.com")
+        );
+        assert_eq!(
+            config
+                .custom_resource_for_calendar("camera@example.com")
+                .map(|(t, r)| (t.label.as_str(), r.name.as_str())),
+            Some(("カメラ", "EOS R5"))
+        );
+        assert_eq!(config.resources().last(), Some(&camera));
+        assert_eq!(config.resource_type_label("camera"), "カメラ");
+        assert_eq!(config.resource_type_label("drone"), "drone");
+    }
+
+    #[test]
+    fn test_parse_storage() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        assert!(config.storage.is_empty());
+
+        let content = format!(
+            r#"{}
+[[storage]]
+name = "scratch"
+calendar_id = "scratch@example.com"
+capacity_gb = 2000
+notifications = []
+"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let storage = Resource::Storage {
+            volume: "scratch".to_string(),
+            gigabytes: 500,
+        };
+
+        assert_eq!(
+            config.storage_capacities(),
+            HashMap::from([("scratch".to_string(), 2000)])
+        );
+        assert_eq!(
+            config.get_calendar_id_for_resource(&storage),
+            Some("scratch@example.com")
+        );
+        assert_eq!(
+            config.calendar_ids().last().map(String::as_str),
+            Some("scratch@example.com")
+        );
+    }
+
+    #[test]
+    fn test_parse_licenses() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        assert!(config.licenses.is_empty());
+
+        let content = format!(
+            r#"{}
+[[licenses]]
+name = "matlab"
+calendar_id = "matlab@example.com"
+seats = 5
+notifications = []
+"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let license = Resource::License {
+            name: "matlab".to_string(),
+            seats: 2,
+        };
+
+        assert_eq!(
+            config.license_seats(),
+            HashMap::from([("matlab".to_string(), 5)])
+        );
+        assert_eq!(
+            config.get_calendar_id_for_resource(&license),
+            Some("matlab@example.com")
+        );
+    }
+
+    #[test]
+    fn test_lab_calendar_is_optional() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+
+        assert!(config.holiday_advisory_policy().is_none());
+    }
+
+    #[test]
+    fn test_parse_lab_calendar() {
+        let content = format!(
+            r#"
+[lab_calendar]
+closed_weekdays = ["Sat", "Sun"]
+
+[[lab_calendar.holidays]]
+name = "年末年始休業"
+date = "2024-12-28"
+end_date = "2025-01-03"
+{}"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let calendar = config.lab_calendar.as_ref().unwrap();
+
+        assert_eq!(calendar.closed_weekdays, vec![Weekday::Sat, Weekday::Sun]);
+        assert_eq!(
+            calendar.holidays[0].end_date,
+            NaiveDate::from_ymd_opt(2025, 1, 3)
+        );
+        assert!(config.holiday_advisory_policy().is_some());
+    }
+
+    #[test]
+    fn test_booking_window_policy() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        assert!(config.booking_window_policy().is_unrestricted());
+
+        let content = r#"
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+max_advance_days = 30
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+min_notice_minutes = 60
+notifications = []
+"#;
+        let config: ResourceConfig = toml::from_str(content).unwrap();
+        let policy = config.booking_window_policy();
+        let now = chrono::Utc::now();
+        let soon =
+            TimePeriod::new(now + Duration::minutes(30), now + Duration::minutes(90)).unwrap();
+        let room = Resource::Room {
+            name: "会議室A".to_string(),
+        };
+        let gpu = Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string()));
+
+        assert!(policy.check(&soon, &[gpu], now).is_ok());
+        assert!(policy.check(&soon, &[room], now).is_err());
+    }
+
+    #[test]
+    fn test_parse_cost_model() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        assert!(config.cost_model().is_none());
+
+        let content = format!(
+            r#"
+[cost_model.hourly_rates]
+"A100 80GB PCIe" = 300
+{}"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let cost_model = config.cost_model().unwrap();
+
+        assert_eq!(cost_model.currency(), "¥");
+        assert_eq!(cost_model.hourly_rate("A100 80GB PCIe"), Some(300.0));
+        assert_eq!(cost_model.hourly_rate("RTX"), None);
+    }
+
+    #[test]
+    fn test_parse_idle_release() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        assert!(config.idle_release_policy().is_none());
+
+        let content = format!("[idle_release]\nidle_minutes = 45\n{}", CONFIG);
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+
+        assert_eq!(
+            config.idle_release_policy(),
+            Some(IdleReleasePolicy::new(
+                Duration::minutes(45),
+                Duration::minutes(30)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_fair_share() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        assert!(config.fair_share_policy().is_none());
+
+        let content = format!(
+            r#"
+[fair_share]
+threshold = 2.0
+{}"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let fair_share = config.fair_share.as_ref().unwrap();
+
+        assert_eq!(fair_share.window_days, FairSharePolicy::DEFAULT_WINDOW_DAYS);
+        assert_eq!(fair_share.threshold, 2.0);
+        assert_eq!(
+            config.fair_share_policy().unwrap().window(),
+            Duration::days(7)
+        );
+    }
+
+    #[test]
+    fn test_parse_reservation_limits() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        assert!(config.reservation_limit_policy().is_unlimited());
+
+        let content = format!(
+            r#"
+[reservation_limits]
+max_reservations = 5
+{}"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+
+        assert_eq!(config.reservation_limits.max_reservations, Some(5));
+        assert_eq!(config.reservation_limits.max_devices, None);
+        assert!(!config.reservation_limit_policy().is_unlimited());
+    }
+
+    #[test]
+    fn test_parse_i18n() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        assert_eq!(config.i18n.locale, Locale::Ja);
+        assert!(!config.i18n.use_slack_locale);
+
+        let content = format!(
+            r#"
+[i18n]
+locale = "en"
+use_slack_locale = true
+{}"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+
+        assert_eq!(config.i18n.locale, Locale::En);
+        assert!(config.i18n.use_slack_locale);
+    }
+
+    #[test]
+    fn test_is_admin() {
+        let content = format!("admins = [\"Prof@Example.ac.jp\"]\n{}", CONFIG);
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let email = |s: &str| EmailAddress::new(s.to_string()).unwrap();
+
+        assert!(config.is_admin(&email("prof@example.ac.jp")));
+        assert!(!config.is_admin(&email("student@example.ac.jp")));
+        assert!(
+            !toml::from_str::<ResourceConfig>(CONFIG)
+                .unwrap()
+                .is_admin(&email("prof@example.ac.jp"))
+        );
+    }
+
+    #[test]
+    fn test_admins_accept_slack_user_ids() {
+        let content = format!(
+            "admins = [\"prof@example.ac.jp\", \"U01234567\"]\n{}",
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+
+        assert!(config.is_admin_user_id("U01234567"));
+        assert!(!config.is_admin_user_id("U07654321"));
+        assert_eq!(
+            config.admin_emails(),
+            vec![EmailAddress::new("prof@example.ac.jp".to_string()).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_invalid_weekday_is_rejected() {
+        let content = format!("[lab_calendar]\nclosed_weekdays = [\"Funday\"]\n{}", CONFIG);
+
+        assert!(toml::from_str::<ResourceConfig>(&content).is_err());
+    }
+
+    #[test]
+    fn test_parse_power_config() {
+        let content = r#"
+rooms = []
+
+[[servers]]
+name = "Thalys"
+calendar_id = "server@example.com"
+notifications = []
+devices = []
+
+[servers.power]
+type = "redfish"
+address = "https://bmc-thalys.example.com"
+username = "admin"
+password = "secret"
+wake_before_minutes = 10
+
+[[servers]]
+name = "Freccia"
+calendar_id = "freccia@example.com"
+notifications = []
+devices = []
+
+[servers.power]
+type = "ipmi"
+host = "bmc-freccia.example.com"
+username = "admin"
+password = "secret"
+
+[[servers]]
+name = "Italo"
+calendar_id = "italo@example.com"
+notifications = []
+devices = []
+"#;
+        let config: ResourceConfig = toml::from_str(content).unwrap();
+
+        match config.get_server("Thalys").unwrap().power.as_ref().unwrap() {
+            PowerConfig::Redfish { system_id, .. } => assert_eq!(system_id, "1"),
+            other => panic!("unexpected power config: {:?}", other),
+        }
+        assert!(config.get_server("Italo").unwrap().power.is_none());
+        assert_eq!(
+            config.wake_before_minutes(),
+            HashMap::from([("Thalys".to_string(), 10)])
+        );
+    }
+
+    #[test]
+    fn test_parse_weekly_digest_config() {
+        let content = r#"
+rooms = []
+
+[weekly_digest]
+weekday = "Fri"
+big_booking_gpu_hours = 48
+
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+
+[[servers.notifications]]
+type = "slack"
+channel_id = "C001"
+
+[[servers.notifications]]
+type = "slack"
+channel_id = "C002"
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers]]
+name = "Italo"
+calendar_id = "italo@example.com"
+
+[[servers.notifications]]
+type = "slack"
+channel_id = "C001"
+
+[[servers.devices]]
+id = 0
+model = "RTX 4090"
+"#;
+        let config: ResourceConfig = toml::from_str(content).unwrap();
+
+        let digest = config.weekly_digest.as_ref().unwrap();
+        assert_eq!(digest.weekday, Weekday::Fri);
+        assert_eq!(digest.hour, 9);
+        assert_eq!(digest.big_booking_gpu_hours, 48);
+
+        let channels = config.servers_by_slack_channel();
+        let names = |channel: &str| -> Vec<&str> {
+            channels[channel].iter().map(|s| s.name.as_str()).collect()
+        };
+        assert_eq!(names("C001"), vec!["Thalys", "Italo"]);
+        assert_eq!(names("C002"), vec!["Thalys"]);
+    }
+
+    #[test]
+    fn test_parse_gpu_monitor_config() {
+        let content = r#"
+rooms = []
+unreserved_usage_channel_id = "C0123456789"
+
+[[servers]]
+name = "Thalys"
+calendar_id = "server@example.com"
+notifications = []
+devices = []
+
+[servers.gpu_monitor]
+ssh_host = "thalys.example.com"
+ssh_user = "monitor"
+
+[[servers]]
+name = "Italo"
+calendar_id = "italo@example.com"
+notifications = []
+devices = []
+"#;
+        let config: ResourceConfig = toml::from_str(content).unwrap();
+
+        let monitor = config.get_server("Thalys").unwrap().gpu_monitor.as_ref();
+        assert_eq!(monitor.unwrap().ssh_host, "thalys.example.com");
+        assert_eq!(monitor.unwrap().ssh_user.as_deref(), Some("monitor"));
+        assert!(config.get_server("Italo").unwrap().gpu_monitor.is_none());
+        assert!(config.has_gpu_monitor());
+        assert_eq!(
+            config.unreserved_usage_channel_id.as_deref(),
+            Some("C0123456789")
+        );
+    }
+
+    #[test]
+    fn test_remind_before_minutes() {
+        let content = r#"
+[[servers]]
+name = "Thalys"
+calendar_id = "server@example.com"
+notifications = []
+devices = []
+remind_before_minutes = 15
+
+[[servers]]
+name = "Italo"
+calendar_id = "italo@example.com"
+notifications = []
+devices = []
+remind_before_end_minutes = 10
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+notifications = []
+remind_before_minutes = 5
+"#;
+        let config: ResourceConfig = toml::from_str(content).unwrap();
+
+        assert_eq!(
+            config.remind_before_minutes(),
+            HashMap::from([("Thalys".to_string(), 15), ("会議室A".to_string(), 5)])
+        );
+        assert_eq!(
+            config.remind_before_end_minutes(),
+            HashMap::from([("Italo".to_string(), 10)])
+        );
+    }
+
+    #[test]
+    fn test_roles_from_admins_moderators_and_user_groups() {
+        let content = format!(
+            r#"admins = ["prof@example.ac.jp", "S0ADMINS"]
+moderators = ["ta@example.ac.jp", "U07654321", "S0ADMINS"]
+{}"#,
+            CONFIG
+        );
+        let mut config: ResourceConfig = toml::from_str(&content).unwrap();
+        let email = |s: &str| EmailAddress::new(s.to_string()).unwrap();
+
+        assert_eq!(config.role_of(&email("prof@example.ac.jp")), Role::Admin);
+        assert_eq!(config.role_of(&email("TA@example.ac.jp")), Role::Moderator);
+        assert_eq!(
+            config.role_of(&email("student@example.ac.jp")),
+            Role::Member
+        );
+        assert_eq!(config.role_of_user_id("U07654321"), Role::Moderator);
+        assert_eq!(config.user_group_ids(), vec!["S0ADMINS".to_string()]);
+        assert_eq!(config.role_of_user_id("S0ADMINS"), Role::Member);
+
+        config.expand_user_groups(&HashMap::from([(
+            "S0ADMINS".to_string(),
+            vec!["U01234567".to_string()],
+        )]));
+        assert_eq!(config.role_of_user_id("U01234567"), Role::Admin);
+        assert!(config.user_group_ids().is_empty());
+    }
+
+    #[test]
+    fn test_groups_accept_emails_and_slack_user_ids() {
+        let content = format!(
+            r#"{}
+[[groups]]
+id = "nlp"
+name = "NLPグループ"
+members = ["alice@example.ac.jp", "U01234567"]
+"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let group = &config.groups[0];
+
+        assert_eq!(
+            group.member_emails(),
+            vec![EmailAddress::new("alice@example.ac.jp".to_string()).unwrap()]
+        );
+        assert_eq!(
+            group.member_user_ids().collect::<Vec<_>>(),
+            vec!["U01234567"]
+        );
+    }
+
+    #[test]
+    fn test_approval_required_rooms() {
+        let content = r#"
+approvers_channel_id = "C0123456789"
+servers = []
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room-a@example.com"
+notifications = []
+requires_approval = true
+approvers = ["prof@example.ac.jp", "U01234567"]
+
+[[rooms]]
+name = "会議室B"
+calendar_id = "room-b@example.com"
+notifications = []
+"#;
+        let config: ResourceConfig = toml::from_str(content).unwrap();
+
+        assert_eq!(config.approvers_channel_id.as_deref(), Some("C0123456789"));
+        assert_eq!(
+            config.approval_required_rooms(),
+            HashSet::from(["会議室A".to_string()])
+        );
+        let rooms: Vec<&RoomConfig> = config.approval_required_room_configs().collect();
+        assert_eq!(rooms.len(), 1);
+        assert_eq!(
+            rooms[0].approver_emails(),
+            vec![EmailAddress::new("prof@example.ac.jp".to_string()).unwrap()]
+        );
+        assert_eq!(
+            rooms[0].approver_user_ids().collect::<Vec<_>>(),
+            vec!["U01234567"]
+        );
+        assert!(config.rooms[1].approvers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_notification_fallback() {
+        let content = r#"
+rooms = []
+
+[[servers]]
+name = "Thalys"
+calendar_id = "server@example.com"
+devices = []
+
+[[servers.notifications]]
+type = "slack"
+bot_token = "xoxb-test"
+channel_id = "C01234567"
+retries = 2
+
+[[servers.notifications.fallback]]
+type = "email"
+from = "lab-bot@example.com"
+"#;
+        let config: ResourceConfig = toml::from_str(content).unwrap();
+        let notification = &config.get_server("Thalys").unwrap().notifications[0];
+
+        assert_eq!(notification.retries(), 2);
+        assert!(matches!(
+            notification.fallback(),
+            [NotificationConfig::Email { from, retries: 0, .. }] if from == "lab-bot@example.com"
+        ));
+    }
+}
@@ -0,0 +1,365 @@
+//! リソース設定の検証
+//!
+//! 読み込めた設定の中で、実行時に問題になる食い違い（デバイスIDの重複、不正なタイムゾーン、
+//! 複数のリソースで共有されたカレンダーIDなど）を検出する。
+
+use super::resource_config::{NotificationConfig, ResourceConfig};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::fmt;
+
+/// 設定の問題
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// 問題のある設定の場所（例: `servers[Thalys].devices`）
+    pub location: String,
+    /// 問題と対処方法
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new(location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+impl std::error::Error for ConfigIssue {}
+
+impl ResourceConfig {
+    /// 設定の問題をすべて検出する
+    ///
+    /// 問題がなければ空のリストを返す。
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues: Vec<ConfigIssue> = self.timezone_issue().into_iter().collect();
+
+        check_unique_names(
+            &mut issues,
+            "servers",
+            self.servers.iter().map(|s| s.name.as_str()),
+        );
+        check_unique_names(
+            &mut issues,
+            "rooms",
+            self.rooms.iter().map(|r| r.name.as_str()),
+        );
+        check_unique_names(
+            &mut issues,
+            "instruments",
+            self.instruments.iter().map(|i| i.name.as_str()),
+        );
+        check_unique_names(
+            &mut issues,
+            "storage",
+            self.storage.iter().map(|s| s.name.as_str()),
+        );
+        check_unique_names(
+            &mut issues,
+            "licenses",
+            self.licenses.iter().map(|l| l.name.as_str()),
+        );
+        for resource_type in &self.resource_types {
+            check_unique_names(
+                &mut issues,
+                &format!("resource_types[{}].resources", resource_type.id),
+                resource_type.resources.iter().map(|r| r.name.as_str()),
+            );
+        }
+
+        for server in &self.servers {
+            let mut seen = HashMap::new();
+            for device in &server.devices {
+                if seen.insert(device.id, ()).is_some() {
+                    issues.push(ConfigIssue::new(
+                        format!("servers[{}].devices", server.name),
+                        format!(
+                            "デバイスID {} が重複しています。各デバイスに異なる id を指定してください",
+                            device.id
+                        ),
+                    ));
+                }
+            }
+        }
+
+        self.check_calendar_ids(&mut issues);
+
+        for (location, notifications) in self.notification_lists() {
+            for (index, notification) in notifications.iter().enumerate() {
+                check_notification(
+                    &mut issues,
+                    &format!("{}.notifications[{}]", location, index),
+                    notification,
+                );
+            }
+        }
+
+        if let Some(digest) = &self.weekly_digest
+            && digest.hour > 23
+        {
+            issues.push(ConfigIssue::new(
+                "weekly_digest.hour",
+                format!(
+                    "{} は時刻として不正です。0〜23 を指定してください",
+                    digest.hour
+                ),
+            ));
+        }
+
+        issues
+    }
+
+    /// `timezone` の問題（読み込み時にも確認する）
+    pub(super) fn timezone_issue(&self) -> Option<ConfigIssue> {
+        let mut issues = Vec::new();
+        if let Some(timezone) = &self.timezone {
+            check_timezone(&mut issues, "timezone", timezone);
+        }
+        issues.pop()
+    }
+
+    /// 空のカレンダーIDと、複数のリソースで共有されたカレンダーIDを検出する
+    ///
+    /// 機器のカレンダーは複数の機器で共有できるため対象外。
+    fn check_calendar_ids(&self, issues: &mut Vec<ConfigIssue>) {
+        let mut owners: Vec<(String, &str)> = Vec::new();
+        for server in &self.servers {
+            owners.push((format!("servers[{}]", server.name), &server.calendar_id));
+            for device in &server.devices {
+                if let Some(calendar_id) = &device.calendar_id {
+                    owners.push((
+                        format!("servers[{}].devices[{}]", server.name, device.id),
+                        calendar_id,
+                    ));
+                }
+            }
+        }
+        for room in &self.rooms {
+            owners.push((format!("rooms[{}]", room.name), &room.calendar_id));
+        }
+        for resource_type in &self.resource_types {
+            for resource in &resource_type.resources {
+                owners.push((
+                    format!(
+                        "resource_types[{}].resources[{}]",
+                        resource_type.id, resource.name
+                    ),
+                    &resource.calendar_id,
+                ));
+            }
+        }
+        for volume in &self.storage {
+            owners.push((format!("storage[{}]", volume.name), &volume.calendar_id));
+        }
+        for license in &self.licenses {
+            owners.push((format!("licenses[{}]", license.name), &license.calendar_id));
+        }
+
+        let mut first_owner: HashMap<&str, &str> = HashMap::new();
+        for (location, calendar_id) in &owners {
+            if calendar_id.trim().is_empty() {
+                issues.push(ConfigIssue::new(
+                    format!("{}.calendar_id", location),
+                    "カレンダーIDが空です。Googleカレンダーの設定にあるカレンダーIDを指定してください",
+                ));
+                continue;
+            }
+            match first_owner.get(calendar_id) {
+                Some(first) => issues.push(ConfigIssue::new(
+                    format!("{}.calendar_id", location),
+                    format!(
+                        "カレンダーID {} は {} でも使われています。リソースごとに別のカレンダーを指定してください",
+                        calendar_id, first
+                    ),
+                )),
+                None => {
+                    first_owner.insert(calendar_id, location);
+                }
+            }
+        }
+    }
+
+    /// リソースごとの通知設定のリスト（場所の表示名付き）
+    fn notification_lists(&self) -> Vec<(String, &[NotificationConfig])> {
+        let mut lists: Vec<(String, &[NotificationConfig])> = Vec::new();
+        for server in &self.servers {
+            lists.push((format!("servers[{}]", server.name), &server.notifications));
+        }
+        for room in &self.rooms {
+            lists.push((format!("rooms[{}]", room.name), &room.notifications));
+        }
+        for instrument in &self.instruments {
+            lists.push((
+                format!("instruments[{}]", instrument.name),
+                &instrument.notifications,
+            ));
+        }
+        for resource_type in &self.resource_types {
+            for resource in &resource_type.resources {
+                lists.push((
+                    format!(
+                        "resource_types[{}].resources[{}]",
+                        resource_type.id, resource.name
+                    ),
+                    &resource.notifications,
+                ));
+            }
+        }
+        for volume in &self.storage {
+            lists.push((format!("storage[{}]", volume.name), &volume.notifications));
+        }
+        for license in &self.licenses {
+            lists.push((
+                format!("licenses[{}]", license.name),
+                &license.notifications,
+            ));
+        }
+        lists
+    }
+}
+
+/// 通知設定（代替通知先を含む）のタイムゾーンを検証する
+fn check_notification(
+    issues: &mut Vec<ConfigIssue>,
+    location: &str,
+    notification: &NotificationConfig,
+) {
+    if let Some(timezone) = notification.timezone() {
+        check_timezone(issues, &format!("{}.timezone", location), timezone);
+    }
+    for (index, fallback) in notification.fallback().iter().enumerate() {
+        check_notification(
+            issues,
+            &format!("{}.fallback[{}]", location, index),
+            fallback,
+        );
+    }
+}
+
+fn check_timezone(issues: &mut Vec<ConfigIssue>, location: &str, timezone: &str) {
+    if timezone.parse::<Tz>().is_err() {
+        issues.push(ConfigIssue::new(
+            location,
+            format!(
+                "タイムゾーン {} が不明です。IANA形式（例: \"Asia/Tokyo\"）で指定してください",
+                timezone
+            ),
+        ));
+    }
+}
+
+fn check_unique_names<'a>(
+    issues: &mut Vec<ConfigIssue>,
+    location: &str,
+    names: impl Iterator<Item = &'a str>,
+) {
+    let mut seen = HashMap::new();
+    for name in names {
+        if seen.insert(name, ()).is_some() {
+            issues.push(ConfigIssue::new(
+                location,
+                format!(
+                    "名前 {} が重複しています。リソースごとに異なる name を指定してください",
+                    name
+                ),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_config_has_no_issues() {
+        let config: ResourceConfig = toml::from_str(
+            r#"
+timezone = "Asia/Tokyo"
+
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+
+[[servers.notifications]]
+type = "slack"
+channel_id = "C001"
+timezone = "Asia/Tokyo"
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers.devices]]
+id = 1
+model = "A100"
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+notifications = []
+"#,
+        )
+        .unwrap();
+
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn test_detects_duplicates_and_bad_timezones() {
+        let config: ResourceConfig = toml::from_str(
+            r#"
+timezone = "Asia/Tokio"
+
+[[servers]]
+name = "Thalys"
+calendar_id = "shared@example.com"
+
+[[servers.notifications]]
+type = "slack"
+channel_id = "C001"
+
+[[servers.notifications.fallback]]
+type = "mock"
+timezone = "Mars/Olympus"
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "shared@example.com"
+notifications = []
+"#,
+        )
+        .unwrap();
+
+        let issues: Vec<String> = config
+            .validate()
+            .iter()
+            .map(|issue| issue.location.clone())
+            .collect();
+
+        assert_eq!(
+            issues,
+            vec![
+                "timezone",
+                "servers[Thalys].devices",
+                "rooms[会議室A].calendar_id",
+                "servers[Thalys].notifications[0].fallback[0].timezone",
+            ]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::member_directory::{DirectoryError, MemberDirectory};
+use crate::infrastructure::config::LdapSyncConfig;
+use async_trait::async_trait;
+use tokio::process::Command;
+
+/// ldapsearchを使用したLDAP / Active Directory名簿
+///
+/// バインドパスワードはプロセス一覧に表示されないよう、ファイル経由（`-y`）で渡す。
+pub struct LdapMemberDirectory {
+    config: LdapSyncConfig,
+}
+
+impl LdapMemberDirectory {
+    /// 新しい名簿を作成
+    pub fn new(config: LdapSyncConfig) -> Self {
+        Self { config }
+    }
+
+    /// ldapsearchのコマンドライン引数を構築
+    fn build_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "-LLL".to_string(),
+            "-x".to_string(),
+            "-o".to_string(),
+            "ldif-wrap=no".to_string(),
+            "-H".to_string(),
+            self.config.url.clone(),
+            "-b".to_string(),
+            self.config.base_dn.clone(),
+        ];
+        if let Some(bind_dn) = &self.config.bind_dn {
+            args.extend(["-D".to_string(), bind_dn.clone()]);
+        }
+        if let Some(password_file) = &self.config.bind_password_file {
+            args.extend([
+                "-y".to_string(),
+                password_file.to_string_lossy().into_owned(),
+            ]);
+        }
+        args.extend([
+            self.config.filter.clone(),
+            self.config.mail_attribute.clone(),
+        ]);
+        args
+    }
+}
+
+#[async_trait]
+impl MemberDirectory for LdapMemberDirectory {
+    async fn list_member_emails(&self) -> Result<Vec<EmailAddress>, DirectoryError> {
+        let output = Command::new("ldapsearch")
+            .args(self.build_args())
+            .output()
+            .await
+            .map_err(|e| DirectoryError::Unknown(format!("ldapsearchの実行に失敗: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(DirectoryError::ConnectionError(format!(
+                "ldapsearchが失敗しました: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(parse_ldif_emails(
+            &String::from_utf8_lossy(&output.stdout),
+            &self.config.mail_attribute,
+        ))
+    }
+}
+
+/// LDIF出力から指定属性の値をメールアドレスとして抽出する
+///
+/// 属性名は大文字小文字を区別しない。Base64エンコードされた値（`attr:: ...`）や
+/// メールアドレスとして不正な値は読み飛ばし、重複は除外する。
+fn parse_ldif_emails(ldif: &str, attribute: &str) -> Vec<EmailAddress> {
+    let mut emails: Vec<EmailAddress> = Vec::new();
+    for line in ldif.lines() {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        if !name.eq_ignore_ascii_case(attribute) || value.starts_with(':') {
+            continue;
+        }
+        match EmailAddress::new(value.trim().to_lowercase()) {
+            Ok(email) if !emails.contains(&email) => emails.push(email),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("⚠️ LDAPのメールアドレスを読み飛ばしました: {}", e),
+        }
+    }
+    emails
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn config() -> LdapSyncConfig {
+        LdapSyncConfig {
+            url: "ldaps://ldap.example.ac.jp".to_string(),
+            base_dn: "ou=people,dc=example,dc=ac,dc=jp".to_string(),
+            bind_dn: Some("cn=reader,dc=example,dc=ac,dc=jp".to_string()),
+            bind_password_file: Some(PathBuf::from("/etc/lab-resource-manager/ldap.secret")),
+            filter: "(objectClass=person)".to_string(),
+            mail_attribute: "mail".to_string(),
+            sync_interval_secs: 3600,
+        }
+    }
+
+    #[test]
+    fn test_parse_ldif_emails() {
+        let ldif = "dn: uid=alice,ou=people,dc=example,dc=ac,dc=jp\n\
+                    mail: alice@example.ac.jp\n\
+                    \n\
+                    dn: uid=bob,ou=people,dc=example,dc=ac,dc=jp\n\
+                    Mail: Bob@Example.ac.jp\n\
+                    mail: alice@example.ac.jp\n\
+                    \n\
+                    dn: uid=carol,ou=people,dc=example,dc=ac,dc=jp\n\
+                    mail:: Y2Fyb2xAZXhhbXBsZS5hYy5qcA==\n\
+                    mail: not-an-email\n";
+
+        let emails = parse_ldif_emails(ldif, "mail");
+
+        assert_eq!(
+            emails.iter().map(|e| e.as_str()).collect::<Vec<_>>(),
+            vec!["alice@example.ac.jp", "bob@example.ac.jp"]
+        );
+    }
+
+    #[test]
+    fn test_build_args_with_bind_credentials() {
+        let args = LdapMemberDirectory::new(config()).build_args();
+
+        assert_eq!(
+            args,
+            vec![
+                "-LLL",
+                "-x",
+                "-o",
+                "ldif-wrap=no",
+                "-H",
+                "ldaps://ldap.example.ac.jp",
+                "-b",
+                "ou=people,dc=example,dc=ac,dc=jp",
+                "-D",
+                "cn=reader,dc=example,dc=ac,dc=jp",
+                "-y",
+                "/etc/lab-resource-manager/ldap.secret",
+                "(objectClass=person)",
+                "mail",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_args_anonymous_bind() {
+        let mut config = config();
+        config.bind_dn = None;
+        config.bind_password_file = None;
+
+        let args = LdapMemberDirectory::new(config).build_args();
+
+        assert!(!args.contains(&"-D".to_string()));
+        assert!(!args.contains(&"-y".to_string()));
+    }
+}
@@ -0,0 +1,14 @@
+//! # Directory Implementations
+//!
+//! MemberDirectory / ExternalUserDirectoryポートの具象実装を提供します。
+//!
+//! - `ldap`: ldapsearchを使用したLDAP / Active Directory名簿
+//! - `slack`: Slack APIを使用したユーザーディレクトリ
+
+/// ldapsearchを使用したLDAP / Active Directory名簿
+pub mod ldap;
+/// Slack APIを使用したユーザーディレクトリ
+pub mod slack;
+
+pub use ldap::LdapMemberDirectory;
+pub use slack::SlackUserDirectory;
@@ -0,0 +1,81 @@
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::member_directory::{DirectoryError, ExternalUserDirectory};
+use async_trait::async_trait;
+use slack_morphism::errors::SlackClientError;
+use slack_morphism::prelude::*;
+
+/// Slack APIを使用したユーザーディレクトリ
+///
+/// `users.lookupByEmail` でメールアドレスからSlackユーザーを検索する。
+/// Botトークンに `users:read.email` スコープが必要。
+pub struct SlackUserDirectory {
+    slack_client: SlackClient<SlackClientHyperHttpsConnector>,
+    bot_token: SlackApiToken,
+}
+
+impl SlackUserDirectory {
+    /// 新しいディレクトリを作成
+    ///
+    /// # Arguments
+    /// * `bot_token` - Slack Bot User OAuth Token
+    pub fn new(bot_token: &str) -> Self {
+        Self {
+            slack_client: SlackClient::new(
+                SlackClientHyperConnector::new()
+                    .expect("Failed to initialize Slack HTTP connector"),
+            ),
+            bot_token: SlackApiToken::new(bot_token.to_string().into()),
+        }
+    }
+
+    /// Slackユーザーグループのメンバーを取得
+    ///
+    /// `usergroups.users.list` を呼び出す。Botトークンに `usergroups:read` スコープが必要。
+    ///
+    /// # Returns
+    /// メンバーのSlackユーザーID
+    pub async fn user_group_members(
+        &self,
+        user_group_id: &str,
+    ) -> Result<Vec<String>, DirectoryError> {
+        let session = self.slack_client.open_session(&self.bot_token);
+        let request = SlackApiUserGroupsUsersListRequest::new(user_group_id.into());
+
+        session
+            .usergroups_users_list(&request)
+            .await
+            .map(|response| response.users.iter().map(ToString::to_string).collect())
+            .map_err(|e| {
+                DirectoryError::ConnectionError(format!(
+                    "Slackユーザーグループ {} のメンバーの取得に失敗: {}",
+                    user_group_id, e
+                ))
+            })
+    }
+}
+
+#[async_trait]
+impl ExternalUserDirectory for SlackUserDirectory {
+    fn system(&self) -> ExternalSystem {
+        ExternalSystem::Slack
+    }
+
+    async fn find_user_id_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Option<String>, DirectoryError> {
+        let session = self.slack_client.open_session(&self.bot_token);
+        let request = SlackApiUsersLookupByEmailRequest::new(email.as_str().into());
+
+        match session.users_lookup_by_email(&request).await {
+            Ok(response) if response.user.deleted == Some(true) => Ok(None),
+            Ok(response) => Ok(Some(response.user.id.to_string())),
+            Err(SlackClientError::ApiError(e)) if e.code == "users_not_found" => Ok(None),
+            Err(e) => Err(DirectoryError::ConnectionError(format!(
+                "Slackユーザーの検索に失敗: {}",
+                e
+            ))),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! 予約のファイルへの書き出し
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::ports::reservation_export::{ExportError, ExportFormat, ReservationExporter};
+use crate::infrastructure::export::formats;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::path::PathBuf;
+
+/// 予約を1つのファイルに書き出す
+///
+/// 既にファイルがある場合は上書きする。
+pub struct FileReservationExporter {
+    path: PathBuf,
+}
+
+impl FileReservationExporter {
+    /// 新しいFileReservationExporterを作成
+    ///
+    /// # Arguments
+    /// * `path` - 書き出し先のファイル
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl ReservationExporter for FileReservationExporter {
+    async fn export(
+        &self,
+        usages: &[ResourceUsage],
+        format: ExportFormat,
+    ) -> Result<(), ExportError> {
+        let content = match format {
+            ExportFormat::Csv => formats::to_csv(usages),
+            ExportFormat::Json => formats::to_json(usages)?,
+            ExportFormat::ICalendar => formats::to_icalendar(usages, Utc::now()),
+        };
+        tokio::fs::write(&self.path, content)
+            .await
+            .map_err(|e| ExportError::WriteFailure(format!("{}: {}", self.path.display(), e)))
+    }
+}
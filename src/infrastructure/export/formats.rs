@@ -0,0 +1,333 @@
+//! 予約の書き出し形式への変換
+//!
+//! CSV・JSON・iCalendarのいずれも、同じ項目（`ExportedReservation`）を書き出します。
+//! 時刻はUTC（RFC 3339）で書き出し、表示側のタイムゾーンに依存しないようにします。
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::service::format_resource_item;
+use crate::domain::aggregates::resource_usage::value_objects::Visibility;
+use crate::domain::ports::reservation_export::ExportError;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+
+/// 書き出す予約1件分の項目
+#[derive(Debug, Serialize)]
+struct ExportedReservation {
+    id: String,
+    owner_email: String,
+    start: String,
+    end: String,
+    resources: Vec<String>,
+    notes: Option<String>,
+    project: Option<String>,
+    experiment_id: Option<String>,
+    expected_utilization: Option<u8>,
+    private: bool,
+    pending_approval: bool,
+    priority: String,
+    group: Option<String>,
+}
+
+impl ExportedReservation {
+    fn from_usage(usage: &ResourceUsage) -> Self {
+        let metadata = usage.metadata();
+        Self {
+            id: usage.id().as_str().to_string(),
+            owner_email: usage.owner_email().as_str().to_string(),
+            start: rfc3339(usage.time_period().start()),
+            end: rfc3339(usage.time_period().end()),
+            resources: usage.resources().iter().map(format_resource_item).collect(),
+            notes: usage.notes().cloned(),
+            project: metadata.project().map(str::to_string),
+            experiment_id: metadata.experiment_id().map(str::to_string),
+            expected_utilization: metadata.expected_utilization(),
+            private: usage.visibility() == Visibility::Private,
+            pending_approval: usage.approval_status().is_pending(),
+            priority: usage.priority().as_str().to_string(),
+            group: usage.group().map(|group| group.as_str().to_string()),
+        }
+    }
+}
+
+fn rfc3339(datetime: DateTime<Utc>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// CSVのヘッダー行（[`fields`] の項目名）
+pub const CSV_HEADER: [&str; 13] = [
+    "id",
+    "owner_email",
+    "start",
+    "end",
+    "resources",
+    "notes",
+    "project",
+    "experiment_id",
+    "expected_utilization",
+    "private",
+    "pending_approval",
+    "priority",
+    "group",
+];
+
+/// 予約の一覧をCSVに変換する
+///
+/// 1行に1件の予約を書き出す。複数のリソースは `; ` で区切って1つの列にまとめる。
+pub fn to_csv(usages: &[ResourceUsage]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push_str("\r\n");
+    for usage in usages {
+        let line = fields(usage)
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push_str(&line);
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// 予約1件をCSVの1行分の項目（[`CSV_HEADER`] の順）に変換する
+///
+/// 複数のリソースは `; ` で区切って1つの項目にまとめる。
+pub fn fields(usage: &ResourceUsage) -> [String; 13] {
+    let record = ExportedReservation::from_usage(usage);
+    [
+        record.id,
+        record.owner_email,
+        record.start,
+        record.end,
+        record.resources.join("; "),
+        record.notes.unwrap_or_default(),
+        record.project.unwrap_or_default(),
+        record.experiment_id.unwrap_or_default(),
+        record
+            .expected_utilization
+            .map(|u| u.to_string())
+            .unwrap_or_default(),
+        record.private.to_string(),
+        record.pending_approval.to_string(),
+        record.priority,
+        record.group.unwrap_or_default(),
+    ]
+}
+
+/// CSVの1項目を、必要に応じて引用符で囲む（RFC 4180）
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 予約の一覧をJSONの配列に変換する
+pub fn to_json(usages: &[ResourceUsage]) -> Result<String, ExportError> {
+    let records: Vec<ExportedReservation> =
+        usages.iter().map(ExportedReservation::from_usage).collect();
+    serde_json::to_string_pretty(&records)
+        .map_err(|e| ExportError::SerializationFailure(e.to_string()))
+}
+
+/// 予約の一覧を1つのiCalendar（RFC 5545）にまとめる
+///
+/// 予約ごとに `VEVENT` を書き出す。件名はリソースと予約者、説明は備考とメタデータ。
+pub fn to_icalendar(usages: &[ResourceUsage], generated_at: DateTime<Utc>) -> String {
+    build_icalendar(usages, generated_at, None, |_| true)
+}
+
+/// 予約の一覧を購読用のiCalendarフィードにまとめる
+///
+/// `to_icalendar` と同じ形式に、カレンダーアプリで表示する名前（`X-WR-CALNAME`）を加える。
+/// `show_details` が `false` を返す予約は、件名をリソースのみにし、予約者・説明を書き出さない。
+pub fn to_icalendar_feed(
+    usages: &[ResourceUsage],
+    generated_at: DateTime<Utc>,
+    calendar_name: &str,
+    show_details: impl Fn(&ResourceUsage) -> bool,
+) -> String {
+    build_icalendar(usages, generated_at, Some(calendar_name), show_details)
+}
+
+fn build_icalendar(
+    usages: &[ResourceUsage],
+    generated_at: DateTime<Utc>,
+    calendar_name: Option<&str>,
+    show_details: impl Fn(&ResourceUsage) -> bool,
+) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//kano-lab//lab-resource-manager//JA".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    if let Some(calendar_name) = calendar_name {
+        lines.push(format!("X-WR-CALNAME:{}", ical_text(calendar_name)));
+    }
+    for usage in usages {
+        let record = ExportedReservation::from_usage(usage);
+        let show_details = show_details(usage);
+        let mut description = Vec::new();
+        if show_details {
+            if let Some(notes) = &record.notes {
+                description.push(notes.clone());
+            }
+            if let Some(project) = &record.project {
+                description.push(format!("project: {}", project));
+            }
+            if let Some(experiment_id) = &record.experiment_id {
+                description.push(format!("experiment_id: {}", experiment_id));
+            }
+        }
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@lab-resource-manager", record.id));
+        lines.push(format!("DTSTAMP:{}", ical_datetime(generated_at)));
+        lines.push(format!(
+            "DTSTART:{}",
+            ical_datetime(usage.time_period().start())
+        ));
+        lines.push(format!(
+            "DTEND:{}",
+            ical_datetime(usage.time_period().end())
+        ));
+        let summary = if show_details {
+            format!("{} ({})", record.resources.join(", "), record.owner_email)
+        } else {
+            record.resources.join(", ")
+        };
+        lines.push(format!("SUMMARY:{}", ical_text(&summary)));
+        if !description.is_empty() {
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                ical_text(&description.join("\n"))
+            ));
+        }
+        if record.pending_approval {
+            lines.push("STATUS:TENTATIVE".to_string());
+        }
+        if record.private {
+            lines.push("CLASS:PRIVATE".to_string());
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+/// iCalendarのUTC日時（例: 20240115T100000Z）
+fn ical_datetime(datetime: DateTime<Utc>) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// iCalendarのテキスト値のエスケープ
+fn ical_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// 1行を75オクテット以内に折り返し、CRLFを付ける
+///
+/// マルチバイト文字の途中では折り返さない。
+fn fold_line(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+    let mut folded = String::new();
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_OCTETS {
+            folded.push_str("\r\n ");
+            // 継続行の先頭の空白も1オクテットに数える
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource, TimePeriod};
+    use crate::domain::common::EmailAddress;
+    use chrono::TimeZone;
+
+    fn usage(notes: Option<&str>) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string())),
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 1, "A100".to_string())),
+            ],
+            notes.map(str::to_string),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_to_csv_quotes_fields() {
+        let csv = to_csv(&[usage(Some("学習, \"評価\""))]);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+
+        assert_eq!(lines[0], CSV_HEADER.join(","));
+        assert!(lines[1].contains(
+            ",user@example.com,2024-01-15T10:00:00Z,2024-01-15T12:00:00Z,Thalys / A100 / GPU:0; Thalys / A100 / GPU:1,\"学習, \"\"評価\"\"\","
+        ));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = to_json(&[usage(None)]).unwrap();
+        let records: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(records[0]["owner_email"], "user@example.com");
+        assert_eq!(records[0]["start"], "2024-01-15T10:00:00Z");
+        assert_eq!(records[0]["resources"][1], "Thalys / A100 / GPU:1");
+        assert!(records[0]["notes"].is_null());
+    }
+
+    #[test]
+    fn test_to_icalendar() {
+        let generated_at = Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap();
+        let ics = to_icalendar(&[usage(Some("学習; 評価"))], generated_at);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTART:20240115T100000Z\r\n"));
+        assert!(ics.contains("DTEND:20240115T120000Z\r\n"));
+        assert!(ics.contains("DESCRIPTION:学習\\; 評価\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    }
+
+    #[test]
+    fn test_to_icalendar_feed_hides_details() {
+        let generated_at = Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap();
+        let usages = [usage(Some("学習"))];
+
+        let shown = to_icalendar_feed(&usages, generated_at, "Thalys", |_| true);
+        assert!(shown.contains("X-WR-CALNAME:Thalys\r\n"));
+        assert!(shown.contains("user@example.com"));
+        assert!(shown.contains("DESCRIPTION:学習\r\n"));
+
+        let hidden = to_icalendar_feed(&usages, generated_at, "Thalys", |_| false);
+        assert!(!hidden.contains("user@example.com"));
+        assert!(!hidden.contains("DESCRIPTION"));
+        assert!(hidden.contains("SUMMARY:Thalys / A100 / GPU:0\\, Thalys / A100 / GPU:1\r\n"));
+    }
+}
@@ -0,0 +1,203 @@
+//! 予約の変更のGoogleスプレッドシートへの追記
+//!
+//! 予約の作成・更新・削除を検知するたびに、Google Sheets APIでシートの末尾に1行ずつ追記する。
+//! 各行は記録した時刻とイベントの種類に続けて、CSVの書き出しと同じ項目（[`formats::CSV_HEADER`]）を並べる。
+
+use crate::domain::ports::notifier::NotificationEvent;
+use crate::domain::ports::reservation_export::ExportError;
+use crate::infrastructure::config::GoogleSheetsExportConfig;
+use crate::infrastructure::export::formats;
+use crate::infrastructure::google_auth::{GoogleAuthenticator, SPREADSHEETS_SCOPE};
+use chrono::{DateTime, SecondsFormat, Utc};
+use reqwest::Url;
+use serde_json::json;
+
+/// Google Sheets APIのベースURL
+const SHEETS_API: &str = "https://sheets.googleapis.com/v4/spreadsheets";
+
+/// 予約の変更をGoogleスプレッドシートに追記する
+pub struct GoogleSheetsExporter {
+    client: reqwest::Client,
+    auth: GoogleAuthenticator,
+    append_url: Url,
+}
+
+impl GoogleSheetsExporter {
+    /// 新しいGoogleSheetsExporterを作成
+    ///
+    /// # Arguments
+    /// * `auth` - サービスアカウントの認証器（シートはサービスアカウントのメールアドレスに編集者として共有しておく）
+    /// * `config` - 追記するスプレッドシートとシート
+    pub fn new(auth: GoogleAuthenticator, config: &GoogleSheetsExportConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            auth,
+            append_url: append_url(config),
+        }
+    }
+
+    /// イベントを行として追記する（開始・終了のイベントは追記しない）
+    pub async fn append(
+        &self,
+        event: &NotificationEvent,
+        recorded_at: DateTime<Utc>,
+    ) -> Result<(), ExportError> {
+        let rows = rows(event, recorded_at);
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let token =
+            self.auth.token(&[SPREADSHEETS_SCOPE]).await.map_err(|e| {
+                ExportError::WriteFailure(format!("トークンを取得できません: {}", e))
+            })?;
+        let token = token
+            .token()
+            .ok_or_else(|| ExportError::WriteFailure("アクセストークンがありません".to_string()))?;
+        let response = self
+            .client
+            .post(self.append_url.clone())
+            .bearer_auth(token)
+            .json(&json!({ "values": rows }))
+            .send()
+            .await
+            .map_err(|e| ExportError::WriteFailure(e.to_string()))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(ExportError::WriteFailure(format!(
+                "Google Sheets APIのエラー（{}）: {}",
+                status, body
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// `values.append` のURL（シートの末尾に行を挿入し、値は入力どおりに書き込む）
+fn append_url(config: &GoogleSheetsExportConfig) -> Url {
+    let mut url = Url::parse(SHEETS_API).expect("valid URL");
+    // シート名は引用符で囲むと、空白や記号を含んでいてもA1表記の範囲として解釈される
+    let range = format!("'{}'", config.sheet.replace('\'', "''"));
+    url.path_segments_mut()
+        .expect("base URL")
+        .push(&config.spreadsheet_id)
+        .push("values")
+        .push(&format!("{}:append", range));
+    url.query_pairs_mut()
+        .append_pair("valueInputOption", "RAW")
+        .append_pair("insertDataOption", "INSERT_ROWS");
+    url
+}
+
+/// イベントを追記する行に変換する
+///
+/// 繰り返し予約は回ごとに1行にする。開始・終了は予約の内容が変わらないため、行にしない。
+fn rows(event: &NotificationEvent, recorded_at: DateTime<Utc>) -> Vec<Vec<String>> {
+    let row = |kind: &str, fields: [String; 13]| {
+        let mut row = vec![
+            recorded_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            kind.to_string(),
+        ];
+        row.extend(fields);
+        row
+    };
+    match event {
+        NotificationEvent::ResourceUsageCreated(usage) => {
+            vec![row("created", formats::fields(usage))]
+        }
+        NotificationEvent::ResourceUsageUpdated(usage) => {
+            vec![row("updated", formats::fields(usage))]
+        }
+        NotificationEvent::ResourceUsageDeleted(usage) => {
+            vec![row("deleted", formats::fields(usage))]
+        }
+        NotificationEvent::ResourceUsageSeriesCreated {
+            first,
+            periods,
+            ids,
+        } => periods
+            .iter()
+            .zip(ids)
+            .map(|(period, id)| {
+                let mut fields = formats::fields(first);
+                fields[0] = id.as_str().to_string();
+                fields[2] = period.start().to_rfc3339_opts(SecondsFormat::Secs, true);
+                fields[3] = period.end().to_rfc3339_opts(SecondsFormat::Secs, true);
+                row("created", fields)
+            })
+            .collect(),
+        NotificationEvent::ResourceUsageStarted(_) | NotificationEvent::ResourceUsageEnded(_) => {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+    use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod, UsageId};
+    use crate::domain::common::EmailAddress;
+    use chrono::TimeZone;
+
+    fn period(day: u32) -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2025, 4, day, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 4, day, 12, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rows() {
+        let usage = ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            period(1),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            Some("ゼミ".to_string()),
+        )
+        .unwrap();
+        let recorded_at = Utc.with_ymd_and_hms(2025, 3, 31, 0, 0, 0).unwrap();
+
+        let created = rows(
+            &NotificationEvent::ResourceUsageCreated(usage.clone()),
+            recorded_at,
+        );
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].len(), 2 + formats::CSV_HEADER.len());
+        assert_eq!(created[0][..2], ["2025-03-31T00:00:00Z", "created"]);
+        assert_eq!(created[0][3], "alice@example.com");
+        assert_eq!(created[0][7], "ゼミ");
+
+        let series = rows(
+            &NotificationEvent::ResourceUsageSeriesCreated {
+                first: usage.clone(),
+                periods: vec![period(1), period(8)],
+                ids: vec![
+                    usage.id().clone(),
+                    UsageId::from_string("second".to_string()),
+                ],
+            },
+            recorded_at,
+        );
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[1][2], "second");
+        assert_eq!(series[1][4], "2025-04-08T09:00:00Z");
+
+        assert!(rows(&NotificationEvent::ResourceUsageStarted(usage), recorded_at).is_empty());
+    }
+
+    #[test]
+    fn test_append_url_quotes_sheet_name() {
+        let url = append_url(&GoogleSheetsExportConfig {
+            spreadsheet_id: "abc123".to_string(),
+            sheet: "予約 ログ".to_string(),
+        });
+        assert_eq!(
+            url.as_str(),
+            "https://sheets.googleapis.com/v4/spreadsheets/abc123/values/'%E4%BA%88%E7%B4%84%20%E3%83%AD%E3%82%B0':append?valueInputOption=RAW&insertDataOption=INSERT_ROWS"
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! # ReservationExporter Implementations
+//!
+//! ReservationExporterポートの具象実装を提供します。
+//!
+//! - `file`: 予約を1つのファイルに書き出す実装
+//! - `formats`: CSV・JSON・iCalendarへの変換
+//! - `google_sheets`: 予約の変更をGoogleスプレッドシートに追記する実装
+//! - `notion`: 予約の変更をNotionデータベースに同期する実装
+
+/// 予約のファイルへの書き出し実装
+pub mod file;
+/// 予約の書き出し形式への変換
+pub mod formats;
+/// 予約の変更のGoogleスプレッドシートへの追記
+pub mod google_sheets;
+/// 予約のNotionデータベースへの同期
+pub mod notion;
+
+pub use file::FileReservationExporter;
+pub use google_sheets::GoogleSheetsExporter;
+pub use notion::NotionSync;
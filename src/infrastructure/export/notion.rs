@@ -0,0 +1,326 @@
+//! 予約のNotionデータベースへの同期
+//!
+//! 予約の作成・更新・削除を検知するたびに、Notion APIでデータベースのページを作成・更新・アーカイブする。
+//! 予約1件につき1ページで、ページは `ID` プロパティの予約IDで探すため、同期の状態は保存しない。
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::service::format_resource_item;
+use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, Visibility};
+use crate::domain::ports::notifier::NotificationEvent;
+use crate::domain::ports::reservation_export::ExportError;
+use crate::infrastructure::config::NotionSyncConfig;
+use chrono::SecondsFormat;
+use reqwest::Method;
+use serde_json::{Map, Value, json};
+
+/// Notion APIのベースURL
+const NOTION_API: &str = "https://api.notion.com/v1";
+
+/// 使用するNotion APIのバージョン
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// リッチテキストの1要素に書ける最大の文字数
+const MAX_TEXT_CHARS: usize = 2000;
+
+/// 予約IDを書くプロパティ（ページを探すのに使う）
+const ID_PROPERTY: &str = "ID";
+
+/// 予約の変更をNotionデータベースに同期する
+pub struct NotionSync {
+    client: reqwest::Client,
+    token: String,
+    database_id: String,
+}
+
+/// 予約1件分の同期内容
+#[derive(Debug, PartialEq)]
+enum Change {
+    /// ページを作成するか、既存のページのプロパティを置き換える
+    Upsert { id: String, properties: Value },
+    /// ページをアーカイブする
+    Archive { id: String },
+}
+
+impl NotionSync {
+    /// 新しいNotionSyncを作成
+    ///
+    /// # Arguments
+    /// * `config` - インテグレーションのトークンと同期先のデータベース
+    ///   （データベースはインテグレーションに共有しておく）
+    pub fn new(config: &NotionSyncConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            token: config.token.clone(),
+            database_id: config.database_id.clone(),
+        }
+    }
+
+    /// イベントをデータベースに反映する（開始・終了のイベントは反映しない）
+    pub async fn sync(&self, event: &NotificationEvent) -> Result<(), ExportError> {
+        for change in changes(event) {
+            match change {
+                Change::Upsert { id, properties } => match self.find_page(&id).await? {
+                    Some(page_id) => {
+                        self.request(
+                            Method::PATCH,
+                            &format!("pages/{}", page_id),
+                            json!({ "properties": properties }),
+                        )
+                        .await?;
+                    }
+                    None => {
+                        self.request(
+                            Method::POST,
+                            "pages",
+                            json!({
+                                "parent": { "database_id": self.database_id },
+                                "properties": properties,
+                            }),
+                        )
+                        .await?;
+                    }
+                },
+                Change::Archive { id } => {
+                    if let Some(page_id) = self.find_page(&id).await? {
+                        self.request(
+                            Method::PATCH,
+                            &format!("pages/{}", page_id),
+                            json!({ "archived": true }),
+                        )
+                        .await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 予約IDのページを探す
+    async fn find_page(&self, id: &str) -> Result<Option<String>, ExportError> {
+        let response = self
+            .request(
+                Method::POST,
+                &format!("databases/{}/query", self.database_id),
+                json!({
+                    "filter": { "property": ID_PROPERTY, "rich_text": { "equals": id } },
+                    "page_size": 1,
+                }),
+            )
+            .await?;
+        Ok(response["results"][0]["id"].as_str().map(str::to_string))
+    }
+
+    async fn request(&self, method: Method, path: &str, body: Value) -> Result<Value, ExportError> {
+        let response = self
+            .client
+            .request(method, format!("{}/{}", NOTION_API, path))
+            .bearer_auth(&self.token)
+            .header("Notion-Version", NOTION_VERSION)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| ExportError::WriteFailure(e.to_string()))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(ExportError::WriteFailure(format!(
+                "Notion APIのエラー（{}）: {}",
+                status, body
+            )));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| ExportError::WriteFailure(e.to_string()))
+    }
+}
+
+/// イベントを予約ごとの同期内容に変換する
+///
+/// 繰り返し予約は回ごとに1ページにする。開始・終了は予約の内容が変わらないため、同期しない。
+fn changes(event: &NotificationEvent) -> Vec<Change> {
+    let upsert = |usage: &ResourceUsage, period: &TimePeriod, id: &str| Change::Upsert {
+        id: id.to_string(),
+        properties: properties(usage, period, id),
+    };
+    match event {
+        NotificationEvent::ResourceUsageCreated(usage)
+        | NotificationEvent::ResourceUsageUpdated(usage) => {
+            vec![upsert(usage, usage.time_period(), usage.id().as_str())]
+        }
+        NotificationEvent::ResourceUsageDeleted(usage) => vec![Change::Archive {
+            id: usage.id().as_str().to_string(),
+        }],
+        NotificationEvent::ResourceUsageSeriesCreated {
+            first,
+            periods,
+            ids,
+        } => periods
+            .iter()
+            .zip(ids)
+            .map(|(period, id)| upsert(first, period, id.as_str()))
+            .collect(),
+        NotificationEvent::ResourceUsageStarted(_) | NotificationEvent::ResourceUsageEnded(_) => {
+            Vec::new()
+        }
+    }
+}
+
+/// ページのプロパティ
+///
+/// データベースには `Name`（タイトル）、`ID`・`Notes`・`Project`・`Group`（テキスト）、`Owner`（メール）、
+/// `Period`（日付）、`Private`・`Pending approval`（チェックボックス）、`Priority`（セレクト）のプロパティが必要。
+fn properties(usage: &ResourceUsage, period: &TimePeriod, id: &str) -> Value {
+    let resources: Vec<String> = usage.resources().iter().map(format_resource_item).collect();
+    let mut properties = Map::new();
+    properties.insert(
+        "Name".to_string(),
+        json!({ "title": rich_text(&resources.join(", ")) }),
+    );
+    properties.insert(
+        ID_PROPERTY.to_string(),
+        json!({ "rich_text": rich_text(id) }),
+    );
+    properties.insert(
+        "Owner".to_string(),
+        json!({ "email": usage.owner_email().as_str() }),
+    );
+    properties.insert(
+        "Period".to_string(),
+        json!({ "date": {
+            "start": period.start().to_rfc3339_opts(SecondsFormat::Secs, true),
+            "end": period.end().to_rfc3339_opts(SecondsFormat::Secs, true),
+        } }),
+    );
+    let text_properties = [
+        ("Notes", usage.notes().map(String::as_str)),
+        ("Project", usage.metadata().project()),
+        ("Group", usage.group().map(|group| group.as_str())),
+    ];
+    for (name, value) in text_properties {
+        properties.insert(
+            name.to_string(),
+            json!({ "rich_text": rich_text(value.unwrap_or_default()) }),
+        );
+    }
+    properties.insert(
+        "Private".to_string(),
+        json!({ "checkbox": usage.visibility() == Visibility::Private }),
+    );
+    properties.insert(
+        "Pending approval".to_string(),
+        json!({ "checkbox": usage.approval_status().is_pending() }),
+    );
+    properties.insert(
+        "Priority".to_string(),
+        json!({ "select": { "name": usage.priority().as_str() } }),
+    );
+    Value::Object(properties)
+}
+
+/// リッチテキストの値（空の場合は空の配列、長すぎる場合は切り詰める）
+fn rich_text(value: &str) -> Value {
+    if value.is_empty() {
+        return json!([]);
+    }
+    let content: String = value.chars().take(MAX_TEXT_CHARS).collect();
+    json!([{ "type": "text", "text": { "content": content } }])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Resource, UsageId};
+    use crate::domain::common::EmailAddress;
+    use chrono::{TimeZone, Utc};
+
+    fn period(day: u32) -> TimePeriod {
+        TimePeriod::new(
+            Utc.with_ymd_and_hms(2025, 4, day, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 4, day, 12, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn usage() -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            period(1),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            Some("ゼミ".to_string()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_changes() {
+        let usage = usage();
+        let id = usage.id().as_str().to_string();
+
+        let created = changes(&NotificationEvent::ResourceUsageCreated(usage.clone()));
+        let [
+            Change::Upsert {
+                id: page_id,
+                properties,
+            },
+        ] = created.as_slice()
+        else {
+            panic!("1件の作成になるはず: {:?}", created);
+        };
+        assert_eq!(page_id, &id);
+        assert_eq!(properties["Owner"]["email"], "alice@example.com");
+        assert_eq!(
+            properties["Notes"]["rich_text"][0]["text"]["content"],
+            "ゼミ"
+        );
+        assert_eq!(properties["Project"]["rich_text"], json!([]));
+        assert_eq!(
+            properties["Period"]["date"]["start"],
+            "2025-04-01T09:00:00Z"
+        );
+        assert_eq!(properties["Private"]["checkbox"], false);
+
+        let series = changes(&NotificationEvent::ResourceUsageSeriesCreated {
+            first: usage.clone(),
+            periods: vec![period(1), period(8)],
+            ids: vec![
+                usage.id().clone(),
+                UsageId::from_string("second".to_string()),
+            ],
+        });
+        assert_eq!(series.len(), 2);
+        let Change::Upsert { id, properties } = &series[1] else {
+            panic!("作成になるはず: {:?}", series[1]);
+        };
+        assert_eq!(id, "second");
+        assert_eq!(
+            properties["ID"]["rich_text"][0]["text"]["content"],
+            "second"
+        );
+        assert_eq!(properties["Period"]["date"]["end"], "2025-04-08T12:00:00Z");
+
+        assert_eq!(
+            changes(&NotificationEvent::ResourceUsageDeleted(usage.clone())),
+            vec![Change::Archive {
+                id: usage.id().as_str().to_string()
+            }]
+        );
+        assert!(changes(&NotificationEvent::ResourceUsageEnded(usage)).is_empty());
+    }
+
+    #[test]
+    fn test_rich_text_truncates_long_values() {
+        let long = "あ".repeat(MAX_TEXT_CHARS + 10);
+        let value = rich_text(&long);
+        assert_eq!(
+            value[0]["text"]["content"]
+                .as_str()
+                .unwrap()
+                .chars()
+                .count(),
+            MAX_TEXT_CHARS
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! # Google Auth
+//!
+//! Google Calendar API（と、予約の記録を追記するGoogle Sheets API）の認証を提供します。
+//!
+//! - サービスアカウント（デフォルト）
+//! - ドメイン全体の委任（Domain-Wide Delegation）: サービスアカウントがWorkspaceのユーザーとして振る舞う。
+//!   予約者をイベントの実際の参加者として追加できる
+//! - OAuth（インストール型アプリのフロー）: Google Workspaceの設定でサービスアカウントからのカレンダーの
+//!   参照が禁止されている場合に、研究室の管理者などのユーザーアカウントの同意で認証する。
+//!   `lab-resource-manager google-auth` で一度だけ同意し、リフレッシュトークンを含むトークンをキャッシュに保存する。
+//!   Botはキャッシュのトークンを使い、期限が切れると自動で更新してキャッシュに書き戻す
+
+use google_calendar3::{
+    CalendarHub,
+    common::GetToken,
+    hyper_rustls::{HttpsConnector, HttpsConnectorBuilder},
+    hyper_util::{
+        client::legacy::{Client, connect::HttpConnector},
+        rt::TokioExecutor,
+    },
+    yup_oauth2::{
+        self, ApplicationSecret, InstalledFlowAuthenticator, InstalledFlowReturnMethod,
+        ServiceAccountKey, authenticator::Authenticator,
+        authenticator_delegate::InstalledFlowDelegate,
+    },
+};
+use std::error::Error;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+/// OAuthで同意を求めるスコープ（カレンダーとACLの読み書き）
+pub const CALENDAR_SCOPE: &str = "https://www.googleapis.com/auth/calendar";
+
+/// Google Sheets APIの読み書きのスコープ
+pub const SPREADSHEETS_SCOPE: &str = "https://www.googleapis.com/auth/spreadsheets";
+
+/// サービスアカウントのトークンを取得する認証器
+pub type GoogleAuthenticator = Authenticator<HttpsConnector<HttpConnector>>;
+
+/// Google Calendar APIのクライアント
+pub type GoogleCalendarHub = CalendarHub<HttpsConnector<HttpConnector>>;
+
+/// Google Calendar APIの認証情報
+#[derive(Clone)]
+pub enum GoogleCredentials {
+    /// サービスアカウントキー
+    ServiceAccount(ServiceAccountKey),
+    /// ドメイン全体の委任を許可したサービスアカウントキーと、代理で操作するユーザー
+    DomainWideDelegation {
+        /// サービスアカウントキー
+        key: ServiceAccountKey,
+        /// 代理で操作するユーザーのメールアドレス
+        subject: String,
+    },
+    /// OAuthクライアント（インストール型アプリ）とトークンキャッシュ
+    OAuth {
+        /// OAuthクライアントのシークレット
+        secret: ApplicationSecret,
+        /// トークンキャッシュのパス
+        token_cache_file: PathBuf,
+    },
+}
+
+impl GoogleCredentials {
+    /// 予約者をイベントの参加者として追加できるか（ドメイン全体の委任の場合のみ）
+    pub fn can_invite_attendees(&self) -> bool {
+        matches!(self, Self::DomainWideDelegation { .. })
+    }
+
+    /// 認証情報でGoogle Calendar APIのクライアントを作成
+    ///
+    /// OAuthの場合、トークンキャッシュが無ければ `google-auth` の実行を促すエラーを返す。
+    pub async fn calendar_hub(&self) -> Result<GoogleCalendarHub, Box<dyn Error>> {
+        if let Self::OAuth {
+            token_cache_file, ..
+        } = self
+            && !token_cache_file.exists()
+        {
+            return Err(format!(
+                "OAuthのトークンキャッシュ {} がありません。`lab-resource-manager google-auth` で認証してください",
+                token_cache_file.display()
+            )
+            .into());
+        }
+
+        let connector = HttpsConnectorBuilder::new()
+            .with_native_roots()?
+            .https_or_http()
+            .enable_http1()
+            .build();
+        let client = Client::builder(TokioExecutor::new()).build(connector);
+
+        match self {
+            Self::ServiceAccount(secret) => {
+                let auth = yup_oauth2::ServiceAccountAuthenticator::builder(secret.clone())
+                    .build()
+                    .await?;
+                Ok(CalendarHub::new(client, auth))
+            }
+            Self::DomainWideDelegation { key, subject } => {
+                let auth = yup_oauth2::ServiceAccountAuthenticator::builder(key.clone())
+                    .subject(subject.clone())
+                    .build()
+                    .await?;
+                Ok(CalendarHub::new(client, auth))
+            }
+            Self::OAuth {
+                secret,
+                token_cache_file,
+            } => {
+                // Botの実行中は同意の画面を出せないため、トークンを更新できない場合はエラーにする
+                let auth = InstalledFlowAuthenticator::builder(
+                    secret.clone(),
+                    InstalledFlowReturnMethod::Interactive,
+                )
+                .persist_tokens_to_disk(token_cache_file)
+                .flow_delegate(Box::new(NonInteractiveDelegate))
+                .build()
+                .await?;
+                Ok(CalendarHub::new(client, CalendarScopeToken(auth)))
+            }
+        }
+    }
+
+    /// サービスアカウント自身として、任意のスコープのトークンを取得する認証器を作成
+    ///
+    /// ドメイン全体の委任の場合も、代理で操作するユーザーではなくサービスアカウントとして認証する。
+    /// OAuthは同意を得たスコープがカレンダーのみのため、エラーを返す。
+    pub async fn service_account_authenticator(
+        &self,
+    ) -> Result<GoogleAuthenticator, Box<dyn Error>> {
+        match self {
+            Self::ServiceAccount(key) | Self::DomainWideDelegation { key, .. } => Ok(
+                yup_oauth2::ServiceAccountAuthenticator::builder(key.clone())
+                    .build()
+                    .await?,
+            ),
+            Self::OAuth { .. } => Err(
+                "GOOGLE_AUTH=oauth の場合はGoogleスプレッドシートに書き出せません（サービスアカウントキーを設定してください）"
+                    .into(),
+            ),
+        }
+    }
+
+    /// 認証したアカウント（イベントの作成者になるアカウント）のメールアドレス
+    ///
+    /// ドメイン全体の委任の場合は代理で操作するユーザー、OAuthの場合は同意したユーザーのメインカレンダーのIDを使う。
+    pub async fn account_email(&self, hub: &GoogleCalendarHub) -> Result<String, Box<dyn Error>> {
+        match self {
+            Self::ServiceAccount(secret) => Ok(secret.client_email.clone()),
+            Self::DomainWideDelegation { subject, .. } => Ok(subject.clone()),
+            Self::OAuth { .. } => {
+                let (_, calendar) = hub.calendars().get("primary").doit().await?;
+                calendar.id.ok_or_else(|| {
+                    "OAuthで認証したアカウントのメールアドレスを取得できません".into()
+                })
+            }
+        }
+    }
+}
+
+/// OAuthの同意画面をブラウザで開いてもらい、トークンをキャッシュに保存する
+///
+/// 同意後のリダイレクトは `http://localhost:<port>` で受け取る。
+/// サーバーで実行する場合は、SSHのポートフォワーディングなどで手元のブラウザからこのポートに届くようにする。
+///
+/// # Arguments
+/// * `secret` - OAuthクライアントのシークレット
+/// * `token_cache_file` - トークンキャッシュのパス
+/// * `port` - リダイレクトを受け取るポート
+pub async fn authorize(
+    secret: ApplicationSecret,
+    token_cache_file: &Path,
+    port: u16,
+) -> Result<(), Box<dyn Error>> {
+    if let Some(parent) = token_cache_file.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let auth = InstalledFlowAuthenticator::builder(
+        secret,
+        InstalledFlowReturnMethod::HTTPPortRedirect(port),
+    )
+    .persist_tokens_to_disk(token_cache_file)
+    .flow_delegate(Box::new(PrintUrlDelegate))
+    .build()
+    .await?;
+    auth.token(&[CALENDAR_SCOPE]).await?;
+    Ok(())
+}
+
+/// API呼び出しごとのスコープによらず、同意を得たスコープのトークンを使う
+///
+/// google-calendar3は呼び出しごとに異なるスコープ（読み取り専用など）を要求するが、
+/// スコープごとに同意を求めることはできないため、[`CALENDAR_SCOPE`] のトークンで代用する。
+#[derive(Clone)]
+struct CalendarScopeToken(GoogleAuthenticator);
+
+impl GetToken for CalendarScopeToken {
+    fn get_token<'a>(
+        &'a self,
+        _scopes: &'a [&str],
+    ) -> Pin<
+        Box<dyn Future<Output = Result<Option<String>, Box<dyn Error + Send + Sync>>> + Send + 'a>,
+    > {
+        Box::pin(async move {
+            let token = self.0.token(&[CALENDAR_SCOPE]).await?;
+            Ok(token.token().map(str::to_string))
+        })
+    }
+}
+
+/// Botの実行中に同意が必要になった場合はエラーにする
+struct NonInteractiveDelegate;
+
+impl InstalledFlowDelegate for NonInteractiveDelegate {
+    fn present_user_url<'a>(
+        &'a self,
+        _url: &'a str,
+        _need_code: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<String, String>> + Send + 'a>> {
+        Box::pin(async {
+            Err("OAuthのトークンを更新できません。`lab-resource-manager google-auth` で再度認証してください"
+                .to_string())
+        })
+    }
+}
+
+/// 同意画面のURLを表示する
+struct PrintUrlDelegate;
+
+impl InstalledFlowDelegate for PrintUrlDelegate {
+    fn present_user_url<'a>(
+        &'a self,
+        url: &'a str,
+        _need_code: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<String, String>> + Send + 'a>> {
+        Box::pin(async move {
+            println!(
+                "次のURLをブラウザで開き、カレンダーへのアクセスを許可してください:\n\n{}\n",
+                url
+            );
+            Ok(String::new())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_oauth_requires_token_cache() {
+        let credentials = GoogleCredentials::OAuth {
+            secret: ApplicationSecret::default(),
+            token_cache_file: std::env::temp_dir()
+                .join(format!("lrm_google_auth_{}", uuid::Uuid::new_v4()))
+                .join("tokens.json"),
+        };
+
+        let error = credentials.calendar_hub().await.err().unwrap();
+
+        assert!(error.to_string().contains("google-auth"));
+    }
+}
@@ -0,0 +1,14 @@
+//! # GpuProcessMonitor Implementations
+//!
+//! GpuProcessMonitorポートの具象実装を提供します。
+//!
+//! - `nvidia_smi`: SSH経由で `nvidia-smi` を実行するクライアント
+//! - `router`: サーバーごとにクライアントを振り分ける実装
+
+/// SSH経由で `nvidia-smi` を実行するクライアント
+pub mod nvidia_smi;
+/// サーバーごとにクライアントを振り分けるGPUの使用状況の取得サービス実装
+pub mod router;
+
+pub use nvidia_smi::NvidiaSmiClient;
+pub use router::GpuMonitorRouter;
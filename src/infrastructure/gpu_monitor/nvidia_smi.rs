@@ -0,0 +1,141 @@
+use crate::domain::ports::gpu_monitor::{GpuMonitorError, GpuProcess};
+use std::collections::HashMap;
+use tokio::process::Command;
+
+/// GPUの一覧とプロセスの一覧の区切り
+const SECTION_SEPARATOR: &str = "---";
+
+/// サーバーで実行するスクリプト
+///
+/// GPUの番号とUUIDの一覧、区切り、プロセスごとの「GPUのUUID, PID, UNIXユーザー名」を出力する。
+/// `nvidia-smi` はプロセスのユーザー名を出力しないため、`ps` で補う。
+const QUERY_SCRIPT: &str = "nvidia-smi --query-gpu=index,uuid --format=csv,noheader \
+&& echo --- \
+&& nvidia-smi --query-compute-apps=gpu_uuid,pid --format=csv,noheader \
+| while IFS=', ' read -r uuid pid; do echo \"$uuid, $pid, $(ps -o user= -p \"$pid\")\"; done";
+
+/// SSH経由で `nvidia-smi` を実行するクライアント
+///
+/// 公開鍵認証で接続できるよう、Botを実行するユーザーのSSHの設定を済ませておく必要がある。
+pub struct NvidiaSmiClient {
+    host: String,
+    user: Option<String>,
+}
+
+impl NvidiaSmiClient {
+    /// 新しいクライアントを作成
+    ///
+    /// # Arguments
+    /// * `host` - SSHの接続先のホスト名
+    /// * `user` - SSHのユーザー名（省略時はSSHの設定に従う）
+    pub fn new(host: &str, user: Option<&str>) -> Self {
+        Self {
+            host: host.to_string(),
+            user: user.map(str::to_string),
+        }
+    }
+
+    /// GPUで実行中のプロセスを取得
+    pub async fn processes(&self) -> Result<Vec<GpuProcess>, GpuMonitorError> {
+        let destination = match &self.user {
+            Some(user) => format!("{}@{}", user, self.host),
+            None => self.host.clone(),
+        };
+        let output = Command::new("ssh")
+            .args(["-o", "BatchMode=yes", "-o", "ConnectTimeout=10"])
+            .arg(&destination)
+            .arg(QUERY_SCRIPT)
+            .output()
+            .await
+            .map_err(|e| GpuMonitorError::ConnectionError(format!("sshの実行に失敗: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(GpuMonitorError::ConnectionError(format!(
+                "{} でのnvidia-smiの実行に失敗しました: {}",
+                self.host,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        parse_processes(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// スクリプトの出力をプロセスの一覧に変換
+///
+/// 取得中に終了したプロセス（ユーザー名が空）は含めない。
+fn parse_processes(output: &str) -> Result<Vec<GpuProcess>, GpuMonitorError> {
+    let (gpus, apps) = output
+        .split_once(SECTION_SEPARATOR)
+        .ok_or_else(|| GpuMonitorError::InvalidOutput("区切りがありません".to_string()))?;
+
+    let mut devices: HashMap<&str, u32> = HashMap::new();
+    for line in gpus.lines().filter(|line| !line.trim().is_empty()) {
+        let (index, uuid) = line
+            .split_once(',')
+            .ok_or_else(|| GpuMonitorError::InvalidOutput(line.to_string()))?;
+        let index = index
+            .trim()
+            .parse()
+            .map_err(|_| GpuMonitorError::InvalidOutput(line.to_string()))?;
+        devices.insert(uuid.trim(), index);
+    }
+
+    let mut processes = Vec::new();
+    for line in apps.lines().filter(|line| !line.trim().is_empty()) {
+        let mut fields = line.split(',').map(str::trim);
+        let (Some(uuid), Some(pid), user) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(GpuMonitorError::InvalidOutput(line.to_string()));
+        };
+        let Some(&device_number) = devices.get(uuid) else {
+            continue;
+        };
+        let unix_user = user.unwrap_or_default();
+        if unix_user.is_empty() {
+            continue;
+        }
+        processes.push(GpuProcess {
+            device_number,
+            pid: pid
+                .parse()
+                .map_err(|_| GpuMonitorError::InvalidOutput(line.to_string()))?,
+            unix_user: unix_user.to_string(),
+        });
+    }
+    Ok(processes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_processes() {
+        let output = "\
+0, GPU-aaaa
+1, GPU-bbbb
+---
+GPU-bbbb, 4242, alice
+GPU-aaaa, 4343, bob
+GPU-aaaa, 4444,
+";
+
+        assert_eq!(
+            parse_processes(output).unwrap(),
+            vec![
+                GpuProcess {
+                    device_number: 1,
+                    pid: 4242,
+                    unix_user: "alice".to_string(),
+                },
+                GpuProcess {
+                    device_number: 0,
+                    pid: 4343,
+                    unix_user: "bob".to_string(),
+                },
+            ]
+        );
+        assert_eq!(parse_processes("0, GPU-aaaa\n---\n").unwrap(), vec![]);
+        assert!(parse_processes("NVIDIA-SMI has failed").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use crate::domain::ports::gpu_monitor::{GpuMonitorError, GpuProcess, GpuProcessMonitor};
+use crate::infrastructure::config::ResourceConfig;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+use super::NvidiaSmiClient;
+
+/// サーバー名に基づいて適切なクライアントに使用状況の取得を振り分ける
+///
+/// リソース設定で `gpu_monitor` が設定されているサーバーのみを対象とする。
+pub struct GpuMonitorRouter {
+    clients: BTreeMap<String, NvidiaSmiClient>,
+}
+
+impl GpuMonitorRouter {
+    /// リソース設定からルーターを作成
+    ///
+    /// # Arguments
+    /// * `config` - リソース設定
+    pub fn new(config: &ResourceConfig) -> Self {
+        let clients = config
+            .servers
+            .iter()
+            .filter_map(|server| {
+                let monitor = server.gpu_monitor.as_ref()?;
+                Some((
+                    server.name.clone(),
+                    NvidiaSmiClient::new(&monitor.ssh_host, monitor.ssh_user.as_deref()),
+                ))
+            })
+            .collect();
+        Self { clients }
+    }
+
+    /// 使用状況を取得するサーバーがあるかどうか
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+}
+
+#[async_trait]
+impl GpuProcessMonitor for GpuMonitorRouter {
+    fn monitored_servers(&self) -> Vec<String> {
+        self.clients.keys().cloned().collect()
+    }
+
+    async fn processes(&self, server: &str) -> Result<Vec<GpuProcess>, GpuMonitorError> {
+        self.clients
+            .get(server)
+            .ok_or_else(|| GpuMonitorError::NotConfigured(server.to_string()))?
+            .processes()
+            .await
+    }
+}
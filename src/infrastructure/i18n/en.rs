@@ -0,0 +1,327 @@
+//! 英語のメッセージカタログ
+
+use super::Messages;
+
+pub(super) static MESSAGES: Messages = Messages {
+    template_created: "🔔 New reservation\n👤 {user}\n\n📅 When\n{time}\n\n{resource_label}\n{resource}{notes}{power_state}",
+    template_updated: "🔄 Reservation updated\n👤 {user}\n\n📅 When\n{time}\n\n{resource_label}\n{resource}{notes}{power_state}",
+    template_deleted: "🗑️ Reservation cancelled\n👤 {user}\n\n📅 When\n{time}\n\n{resource_label}\n{resource}{notes}",
+    template_started: "▶️ Now in use\n👤 In use by {user}\n\n📅 When\n{time}\n\n{resource_label}\n{resource}",
+    template_ended: "⏹️ Now free\n👤 {user}'s reservation has ended\n\n{resource_label}\n{resource}",
+    notes_label: "📝 Notes",
+    metadata_label: "🗂️ Project",
+    power_label: "⚡ Power",
+    power_states: ["On", "Off", "Unknown"],
+    device_statuses: ["Available", "Degraded", "Out of service"],
+    link_actions: ["Linked", "Unlinked"],
+    redacted_owner: "Reserved",
+    series_header: "🔁 Recurring reservation ({count} occurrences)",
+    resource_label_gpu: "💻 GPUs",
+    resource_label_room: "🏢 Room",
+    resource_label_instrument: "🔬 Instrument",
+    resource_label_storage: "💾 Storage",
+    resource_label_license: "🔑 Licenses",
+    resource_label_other: "📦 Resources",
+    relative_days: ["Yesterday", "Today", "Tomorrow", "In 2 days"],
+    weekdays: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    frequencies: ["Weekly", "Every 2 weeks"],
+    priorities: ["Background", "Normal", "Deadline"],
+
+    reserve_title: "Reserve a Resource",
+    reserve_submit: "Reserve",
+    update_title: "Update Reservation",
+    update_submit: "Update",
+    cancel: "Cancel",
+    resource_type: "Resource type",
+    select_server: "Select a server",
+    server_hint: "Selecting a server adds a device picker for it. If you select no devices, every device on the selected servers is reserved",
+    tag_filter: "Filter by tag",
+    tag_any: "All",
+    tag_filter_hint: "Only devices with the selected tag are shown. If you select no devices, only the tagged devices are reserved",
+    no_servers: "⚠️ No servers are configured. Please contact an administrator.",
+    select_room: "Select a room",
+    no_rooms: "⚠️ No rooms are configured. Please contact an administrator.",
+    select_instrument: "Select an instrument",
+    select_custom: "Select {type}",
+    repeat: "Repeat",
+    repeat_none: "Does not repeat",
+    repeat_until: "Repeat until",
+    repeat_until_hint: "When repeating, the same weekday and time are reserved up to this date (at most {max} times)",
+    start_date: "Start date",
+    start_time: "Start time",
+    end_date: "End date",
+    end_time: "End time",
+    timezone_hint: "🌐 Enter dates and times in {timezone} (your Slack profile time zone)",
+    notes: "Notes",
+    project: "Project",
+    experiment_id: "Experiment ID",
+    utilization: "Expected utilization (%)",
+    utilization_hint: "How much of the GPUs you expect to use, from 0 to 100 (used in usage reports)",
+    invalid_utilization: "Enter the expected utilization as a whole number from 0 to 100",
+    visibility: "Visibility",
+    make_private: "Make private",
+    private_hint: "Private reservations show only \"Reserved\" in notifications, hiding who booked and the notes",
+    priority: "Priority",
+    priority_hint: "Use \"Deadline\" for urgent work such as a paper deadline, and \"Background\" for jobs that can run whenever resources are free",
+    group: "Team",
+    group_hint: "Members of the selected team can also edit or cancel this reservation (leave empty for a personal reservation)",
+    group_not_member: "You are not a member of {group}, so the reservation cannot belong to it",
+    preemption: "Preemption",
+    preempt: "Cancel overlapping lower-priority reservations",
+    preempt_hint: "Owners of cancelled reservations are notified by DM with the next free time slot (reservations that have already started cannot be cancelled)",
+    device_busy: "{device} — busy until {until} by {owner}",
+    device_busy_private: "{device} — busy until {until}",
+    device_degraded: "{device} ⚠️ degraded",
+
+    extend_title: "Extend Reservation",
+    extend_submit: "Extend",
+    current_end: "Current end time: *{end}*",
+    extend_duration: "Extend by",
+    plus_one_hour: "+1 hour",
+    plus_two_hours: "+2 hours",
+    other: "Other",
+    extend_minutes: "Minutes to extend",
+    extend_minutes_hint: "Fill in when you choose \"Other\"",
+
+    transfer_title: "Transfer reservation",
+    transfer_submit: "Transfer",
+    current_owner: "Current owner: *{owner}*",
+    transfer_new_owner: "New owner",
+    transfer_new_owner_hint: "The selected member becomes the owner. The time and resources stay the same",
+
+    reserved: "✅ Your reservation is confirmed\nReservation ID: {usage_id}",
+    reserve_failed: "❌ Failed to create the reservation\n\n{error}",
+    series_reserved: "✅ Your recurring reservation is confirmed ({frequency}, {count} occurrences)\nFirst reservation ID: {usage_id}",
+    series_failed: "❌ Failed to create the recurring reservation\n\n{error}",
+    updated: "✅ Your reservation has been updated",
+    update_failed: "❌ Failed to update the reservation: {error}",
+    update_forbidden: "❌ You are not allowed to update this reservation.",
+    extended: "✅ Your reservation has been extended (ends: {end})",
+    extend_failed: "❌ Failed to extend the reservation: {error}",
+    extend_forbidden: "❌ You are not allowed to extend this reservation.",
+    extend_conflict: "❌ Could not extend: {resource} has another reservation in the extended time.",
+    extend_conflict_field: "{resource} has another reservation in the extended time",
+    transferred: "✅ Transferred the reservation to {owner}",
+    transfer_failed: "❌ Failed to transfer the reservation: {error}",
+    transfer_forbidden: "❌ You are not allowed to transfer this reservation.",
+    transfer_not_linked: "This user has not registered an email address, so the reservation cannot be transferred",
+    transfer_same_owner: "This user already owns the reservation",
+    usage_not_found: "❌ Sorry, this reservation has already been deleted or could not be found.",
+    time_slot_taken: "❌ The selected time slot is already reserved.",
+    limit_reservations: "You can hold up to {limit} reservations at a time (currently {current})",
+    limit_reservations_on_server: "You can hold up to {limit} reservations on {server} at a time (currently {current})",
+    limit_devices: "You can hold up to {limit} GPUs at a time ({current} already held during this time)",
+    limit_devices_on_server: "You can hold up to {limit} GPUs on {server} at a time ({current} already held during this time)",
+    limit_holdings: "*Your current reservations:*",
+    booking_notice_required: "{resource} must be reserved at least {minutes} minutes before it starts",
+    booking_too_far_ahead: "{resource} can only be reserved up to {days} days ahead",
+    approval_pending: "⏳ Reservations of this room need approval. Until approved, it shows as a tentative event on the calendar.",
+    closure_header: "⚠️ Note: this reservation falls on days the lab is closed",
+    closed_weekday: "Closed",
+    fair_share_advisory: "⚖️ You've used {hours}h of {model} in the last {days} days ({ratio}× your fair share)",
+    fair_share_alternative: "{server} is free at the same time; consider it instead",
+
+    waitlist_offer: "⚠️ The selected time slot is already reserved\n{reasons}\n\nJoin the waitlist and you will get a DM, in the order people joined, when the slot frees up.",
+    join_waitlist: "🔔 Notify me when it frees up",
+    waitlist_joined: "🔔 You joined the waitlist. You will get a DM when the slot frees up.",
+    waitlist_already_joined: "ℹ️ You are already on the waitlist for this time slot and resources.",
+    waitlist_join_failed: "❌ Failed to join the waitlist: {error}",
+    waitlist_available: "🔔 A time slot you were waiting for is now free\n\n*Resources*\n{resources}\n\n*When*\n{time}\n\nIt is your turn for the next {minutes} minutes. Reserve it with /reserve.",
+
+    preempted_others: "⚡ Cancelled these lower-priority reservations:",
+    preempted_notice: "⚡ Your reservation was cancelled for a higher-priority reservation ({priority})\n\n*Resources*\n{resources}\n\n*When*\n{time}",
+    preempted_suggestion: "The same resources are free at {time}. Reserve again with /reserve.",
+    preempted_no_suggestion: "No time slot of the same length was found for the same resources within two weeks.",
+
+    suggest_time: "💡 Suggest a free time",
+    suggest_time_found: "💡 {time} is free, so it has been filled in",
+    suggest_time_none: "⚠️ The selected resources are not free for that long within the next {days} days",
+    next_free_usage: "Usage: /next-free <server or room name> [devices] <hours>\nExample: /next-free Thalys 0-1 4",
+    next_free_found: "💡 Next free time on {resource}\n{time}",
+    next_free_reserve_hint: "To reserve it: `{command}`",
+    next_free_none: "⚠️ {resource} is not free for {hours} hours in a row within the next {days} days",
+    next_free_failed: "❌ Failed to look for a free time: {error}",
+
+    whois_using_usage: "Usage: /whois-using <server or room name>",
+    whois_using_header: "👥 Currently using {resource}",
+    whois_using_entry: "• {owner}{devices} (until {until})",
+    whois_using_none: "✅ {resource} is free right now",
+    whois_using_failed: "❌ Failed to look up who is using it: {error}",
+
+    cancel_all_title: "Cancel Reservations",
+    cancel_all_submit: "Cancel selected",
+    cancel_all_intro: "Select the upcoming reservations to cancel. They are all cancelled at once.",
+    cancel_all_truncated: "Showing the first {count} by start time. Run /cancel-all again afterwards for the rest.",
+    cancel_all_label: "Upcoming reservations",
+    cancel_all_none: "You have no upcoming reservations to cancel",
+    cancel_all_nothing_selected: "Select the reservations to cancel",
+    cancel_all_done: "🗑️ Cancelled {count} reservation(s):",
+    cancel_all_not_found: "❌ Some of the selected reservations were already deleted, so nothing was cancelled. Run /cancel-all again",
+    cancel_all_failed: "❌ Failed to cancel the reservations: {error}",
+
+    quick_reserve_usage: "Usage: /reserve [today|tomorrow|YYYY-MM-DD] HH:MM-HH:MM <server or room name> [devices]\nExample: /reserve tomorrow 14:00-18:00 Thalys 0-1",
+    end_before_start: "❌ The end time must be after the start time",
+    room_has_no_devices: "Devices cannot be specified for room {room}",
+    instrument_has_no_devices: "Devices cannot be specified for instrument {instrument}",
+    custom_has_no_devices: "Devices cannot be specified for {type} {name}",
+    unknown_resource: "Resource {name} is not configured",
+    unknown_command: "Unknown command: {command}",
+
+    session_expired: "Your session has expired. Please run the command again.",
+    session_expired_button: "Your session has expired. Please press the button again.",
+    invalid_request: "❌ The request could not be processed. Please try again.",
+    email_not_registered: "❌ Register your email address with /register-calendar first",
+    resource_type_required: "Select a resource type",
+    server_required: "Select a server",
+    server_not_found: "Server {server} was not found",
+    device_not_found: "Device {device} was not found on {server}",
+    no_tagged_devices: "{server} has no devices tagged {tag}",
+    room_required: "Select a room",
+    instrument_required: "Select an instrument",
+    custom_required: "Select a {type}",
+    unknown_resource_type: "Unknown resource type: {type}",
+    start_date_required: "Select a start date",
+    start_time_required: "Select a start time",
+    end_date_required: "Select an end date",
+    end_time_required: "Select an end time",
+    end_not_after_start: "The end must be after the start",
+    invalid_datetime: "Invalid date and time: {datetime} (it may fall in a daylight saving time change)",
+    unknown_repeat: "Unknown repeat: {repeat}",
+    repeat_until_required: "Select the date the repetition ends",
+    extend_duration_required: "Select how long to extend",
+    extend_minutes_required: "Enter the minutes to extend",
+    positive_integer_required: "Enter a whole number of 1 or more",
+    unknown_extend_preset: "Unknown extension: {preset}",
+    transfer_owner_required: "Select the new owner",
+    user_required: "Select a user",
+    email_required: "Enter an email address",
+    conflict_with_owner: "{resource} is reserved by {owner} for {time}",
+    conflict_private: "{resource} has a private reservation for {time}",
+    invalid_submission: "The submission could not be processed",
+
+    cancelled: "✅ Reservation cancelled",
+    cancel_forbidden: "❌ You don't have permission to cancel this reservation.",
+    cancel_failed: "❌ Failed to cancel the reservation: {error}",
+    approval_email_required: "❌ Register your email address before approving or rejecting reservations.",
+    approved_by: "✅ {approver} approved the reservation of {resources} (owner: {owner})",
+    rejected_by: "🚫 {approver} rejected the reservation of {resources} (owner: {owner})",
+    already_approved: "❌ This reservation has already been approved.",
+    approval_not_found: "❌ This reservation has already been rejected or deleted, or could not be found.",
+    approval_forbidden: "❌ Only the room's approvers and admins can approve or reject this reservation.",
+    approval_failed: "❌ Failed to approve or reject the reservation: {error}",
+    released: "✅ Reservation ended and resources released (ended: {end})",
+    release_not_in_use: "❌ This reservation is not in progress, so it can't be released now. Cancel it instead if it hasn't started.",
+    release_forbidden: "❌ You don't have permission to end this reservation.",
+    release_failed: "❌ Failed to end the reservation: {error}",
+    kept_idle: "✅ Keeping the reservation. It won't be released automatically during this period.",
+    keep_idle_forbidden: "❌ You don't have permission to change this reservation.",
+    keep_idle_failed: "❌ Failed to keep the reservation: {error}",
+    split_result_header: "Split reservation results",
+    split_reserved: "✅ {resources} (reservation ID: {usage_id})",
+    split_failed: "❌ {resources}: {error}",
+    email_registered: "✅ Registered the email address {email}",
+    email_registered_retry: "✅ Registered the email address {email}. Please run /reserve again",
+    register_failed: "❌ Registration failed: {error}",
+    unlinked: "✅ Unlinked the email address {email}",
+    unlink_failed: "❌ Failed to unlink: {error}",
+    processing: "⏳ Processing...",
+    command_failed: "Error: {error}",
+    check_input: "⚠️ Please check your input",
+    close: "Close",
+
+    linked: "✅ Linked {user} to the email address {email}",
+    link_failed: "❌ Failed to link: {error}",
+
+    admin_only: "❌ Only admins can run this command",
+    admin_cancel_forbidden: "❌ Only admins and moderators can cancel other users' reservations",
+    admin_cancel_usage: "Usage:\n• /admin-cancel <reservation ID> - cancel the reservation\n• /admin-cancel <@user> - cancel all of the user's reservations that haven't ended",
+    reservation_not_found: "❌ Reservation {usage_id} was not found",
+    reservation_target: "reservation {usage_id}",
+    user_not_linked: "❌ {user} is not linked to an email address",
+    admin_cancel_none: "{target} has no reservations that can be cancelled",
+    admin_cancel_done: "🗑️ Cancelled {count} reservation(s) of {target}:",
+    admin_cancel_notice: "The owners will be notified of the cancellation.",
+    availability_usage: "Usage: /availability [server or room name] [tag:TAG] [YYYY-MM-DD]",
+    unknown_tag: "No devices are tagged {tag}",
+    availability_failed: "❌ Failed to get availability: {error}",
+    availability_header: "Availability on {date}: free all day {free} / {total}",
+    availability_legend: "{busy} reserved　{free} free",
+    kind_room: "Rooms",
+    kind_instrument: "Instruments",
+    kind_storage: "Storage",
+    kind_license: "Licenses",
+    window_week: "Last 7 days",
+    window_month: "Last 30 days",
+    usage_stats_usage: "Usage: /usage-stats [week|month]",
+    usage_stats_failed: "❌ Failed to aggregate reserved hours: {error}",
+    usage_stats_header: "Reserved hours ({window}: {start} - {end})",
+    usage_stats_none: "No reservations in this period",
+    usage_stats_room: " (rooms {hours})",
+    usage_stats_utilization: " (expected utilization {utilization}%)",
+    by_user: "By user",
+    by_server: "By server (GPU)",
+    by_resource: "By device and room",
+    by_project: "By project",
+    by_week: "By week",
+    cost_report_usage: "Usage: /cost-report [week|month]",
+    cost_model_missing: "❌ GPU rates are not configured ([cost_model] in resources.toml)",
+    cost_report_failed: "❌ Failed to aggregate costs: {error}",
+    cost_report_header: "GPU reservation costs ({window}: {start} - {end})",
+    cost_report_none: "No GPU reservations in this period",
+    cost_report_total: "Total: {total}",
+    no_project: "(no project)",
+    device_status_usage: "Usage:\n• /device-status <server> <device number> <available|degraded|out-of-service> [note] - set the device status\n• /device-status list - list devices with a registered status",
+    unknown_device: "❌ Device {device} on {server} is not configured",
+    device_status_list_failed: "❌ Failed to get device statuses: {error}",
+    device_status_set_failed: "❌ Failed to set the device status: {error}",
+    device_status_entry: "{server} device {device}: {status}",
+    device_status_none: "All devices are available",
+    device_status_header: "*Devices with a registered status:*",
+    affected_none: "No existing reservations are affected",
+    affected_header: "*Affected existing reservations ({count}):*",
+    affected_notice: "Existing reservations are not cancelled automatically. Contact the owners if needed.",
+    freeze_usage: "Usage:\n• /freeze-resource <resource> <YYYY-MM-DD> [HH:MM] [reason] - stop reservations from the given time\n• /freeze-resource cancel <resource> - lift the freeze\n• /freeze-resource list - list freezes",
+    unfrozen: "✅ Lifted the freeze on {resource}",
+    not_frozen: "{resource} is not frozen",
+    freeze_list_failed: "❌ Failed to get freezes: {error}",
+    unfreeze_failed: "❌ Failed to lift the freeze: {error}",
+    freeze_failed: "❌ Failed to freeze the resource: {error}",
+    freeze_starts_in_past: "❌ The freeze start time {time} is in the past",
+    frozen_entry: "{resource} stops taking reservations from {time}",
+    freeze_reason: " (reason: {reason})",
+    freeze_none: "No resources are frozen",
+    freeze_header: "*Frozen resources:*",
+    link_history_usage: "Usage: /link-history <@slack_user|email>",
+    link_history_failed: "❌ Failed to get the link history: {error}",
+    link_history_none: "{subject} has no link history",
+    link_history_header: "*Link history of {subject}:*",
+    link_history_entry: "• {time} {action} {email} ⇔ {user} (by {actor})",
+    link_history_automatic: "automatic",
+    unlink_usage: "Usage: /unlink-user [<@slack_user>]",
+    not_linked_yet: "You haven't linked an email address yet",
+    unlink_others_forbidden: "❌ Only admins can unlink other users",
+    user_unlinked: "✅ Unlinked {user} from the email address {email}",
+    unlink_confirm: "Unlink the email address *{email}*?\nIf no other service is linked, calendar access is removed as well.",
+    unlink_button: "Unlink",
+
+    profile_email_offer: "You haven't linked Google Calendar yet.\nLink the email address *{email}* from your Slack profile?\nThis address will be given access to the calendars.",
+    link_profile_email: "Use this address",
+    enter_email_manually: "Enter another address",
+    register_title: "Register email address",
+    register_intro: "Register the email address to link with Google Calendar.\nThe address will be given access to the calendars automatically.",
+    register_submit: "Register",
+    email_address: "Email address",
+    link_user_title: "Link a user to an email",
+    link_user_intro: "Link another user to a Google Calendar email address.\nThe user will be given access to the calendars automatically.",
+    link_user_target: "User to link",
+    select_user: "Select a user",
+    link_user_submit: "Link",
+    split_partial_conflict: "⚠️ Part of the requested reservation overlaps existing reservations\n{reasons}\n",
+    split_available: "*You can reserve just the free parts:*",
+    split_unavailable: "\nResources with no free time in the period: {resources}",
+    split_reserve: "✂️ Reserve the split",
+    split_too_large: "The split is too large to reserve automatically. Reserve each part from /reserve.",
+    install_completed: "Installation complete. Return to Slack to get started.",
+    install_cancelled: "Installation was cancelled.",
+    install_failed: "Installation failed. Please contact your administrator.",
+};
@@ -0,0 +1,327 @@
+//! 日本語のメッセージカタログ
+
+use super::Messages;
+
+pub(super) static MESSAGES: Messages = Messages {
+    template_created: "🔔 新規予約\n👤 {user}\n\n📅 期間\n{time}\n\n{resource_label}\n{resource}{notes}{power_state}",
+    template_updated: "🔄 予約更新\n👤 {user}\n\n📅 期間\n{time}\n\n{resource_label}\n{resource}{notes}{power_state}",
+    template_deleted: "🗑️ 予約削除\n👤 {user}\n\n📅 期間\n{time}\n\n{resource_label}\n{resource}{notes}",
+    template_started: "▶️ 使用開始\n👤 {user} が使用中です\n\n📅 期間\n{time}\n\n{resource_label}\n{resource}",
+    template_ended: "⏹️ 使用終了\n👤 {user} の予約が終了し、空きました\n\n{resource_label}\n{resource}",
+    notes_label: "📝 備考",
+    metadata_label: "🗂️ プロジェクト情報",
+    power_label: "⚡ 電源",
+    power_states: ["オン", "オフ", "不明"],
+    device_statuses: ["使用可能", "性能低下", "使用停止"],
+    link_actions: ["紐付け", "紐付け解除"],
+    redacted_owner: "予約済み",
+    series_header: "🔁 繰り返し予約（全{count}回）",
+    resource_label_gpu: "💻 予約GPU",
+    resource_label_room: "🏢 予約部屋",
+    resource_label_instrument: "🔬 予約機器",
+    resource_label_storage: "💾 予約ストレージ",
+    resource_label_license: "🔑 予約ライセンス",
+    resource_label_other: "📦 予約リソース",
+    relative_days: ["昨日", "今日", "明日", "明後日"],
+    weekdays: ["月", "火", "水", "木", "金", "土", "日"],
+    frequencies: ["毎週", "隔週"],
+    priorities: ["バックグラウンド", "通常", "締切前"],
+
+    reserve_title: "リソース予約",
+    reserve_submit: "予約する",
+    update_title: "予約更新",
+    update_submit: "更新",
+    cancel: "キャンセル",
+    resource_type: "リソースタイプ",
+    select_server: "サーバーを選択",
+    server_hint: "サーバーを選ぶとデバイスの選択欄が追加されます。デバイスを選択しない場合は、選択中のサーバーのすべてのデバイスを予約します",
+    tag_filter: "タグで絞り込む",
+    tag_any: "すべて",
+    tag_filter_hint: "選んだタグの付いたデバイスだけを表示します。デバイスを選択しない場合も、タグの付いたデバイスだけを予約します",
+    no_servers: "⚠️ サーバー設定が見つかりません。管理者に問い合わせてください。",
+    select_room: "部屋を選択",
+    no_rooms: "⚠️ 部屋設定が見つかりません。管理者に問い合わせてください。",
+    select_instrument: "機器を選択",
+    select_custom: "{type}を選択",
+    repeat: "繰り返し",
+    repeat_none: "繰り返さない",
+    repeat_until: "繰り返しの終了日",
+    repeat_until_hint: "繰り返す場合は、この日までの同じ曜日・時刻に予約します（最大{max}回）",
+    start_date: "開始日",
+    start_time: "開始時刻",
+    end_date: "終了日",
+    end_time: "終了時刻",
+    timezone_hint: "🌐 日時は {timezone} の時刻で入力してください（Slackプロフィールのタイムゾーン）",
+    notes: "備考",
+    project: "プロジェクト",
+    experiment_id: "実験ID",
+    utilization: "想定使用率（%）",
+    utilization_hint: "GPUをどの程度使う見込みかを0〜100で入力してください（利用状況の集計に使います）",
+    invalid_utilization: "想定使用率は0〜100の整数で入力してください",
+    visibility: "公開範囲",
+    make_private: "非公開にする",
+    private_hint: "非公開にすると、通知では「予約済み」とだけ表示し、予約者と備考を伏せます",
+    priority: "優先度",
+    priority_hint: "論文の締切前など急ぎの場合は「締切前」、空いていれば使う処理は「バックグラウンド」にしてください",
+    group: "チーム",
+    group_hint: "チームを選ぶと、チームのメンバーも予約を変更・キャンセルできます（選ばなければ個人の予約になります）",
+    group_not_member: "{group} のメンバーではないため、このチームの予約にはできません",
+    preemption: "横取り",
+    preempt: "重なる優先度の低い予約を取り消して予約する",
+    preempt_hint: "取り消された予約の予約者には、次に空いている時間帯を添えてDMでお知らせします（開始済みの予約は取り消せません）",
+    device_busy: "{device} — 使用中（{until}まで・{owner}）",
+    device_busy_private: "{device} — 使用中（{until}まで）",
+    device_degraded: "{device} ⚠️ 性能低下",
+
+    extend_title: "予約延長",
+    extend_submit: "延長する",
+    current_end: "現在の終了時刻: *{end}*",
+    extend_duration: "延長する時間",
+    plus_one_hour: "+1時間",
+    plus_two_hours: "+2時間",
+    other: "その他",
+    extend_minutes: "延長する時間（分）",
+    extend_minutes_hint: "「その他」を選んだ場合に入力してください",
+
+    transfer_title: "予約の引き継ぎ",
+    transfer_submit: "引き継ぐ",
+    current_owner: "現在の予約者: *{owner}*",
+    transfer_new_owner: "引き継ぎ先",
+    transfer_new_owner_hint: "選んだメンバーが予約者になります。期間やリソースは変わりません",
+
+    reserved: "✅ リソースの予約が完了しました\n予約ID: {usage_id}",
+    reserve_failed: "❌ 予約の作成に失敗しました\n\n{error}",
+    series_reserved: "✅ 繰り返し予約が完了しました（{frequency}・全{count}回）\n初回の予約ID: {usage_id}",
+    series_failed: "❌ 繰り返し予約の作成に失敗しました\n\n{error}",
+    updated: "✅ 予約を更新しました",
+    update_failed: "❌ 予約の更新に失敗しました: {error}",
+    update_forbidden: "❌ この予約を更新する権限がありません。",
+    extended: "✅ 予約を延長しました（終了: {end}）",
+    extend_failed: "❌ 予約の延長に失敗しました: {error}",
+    extend_forbidden: "❌ この予約を延長する権限がありません。",
+    extend_conflict: "❌ 延長する時間帯に {resource} の別の予約があるため、延長できませんでした。",
+    extend_conflict_field: "延長する時間帯に {resource} の別の予約があります",
+    transferred: "✅ 予約を {owner} に引き継ぎました",
+    transfer_failed: "❌ 予約の引き継ぎに失敗しました: {error}",
+    transfer_forbidden: "❌ この予約を引き継ぐ権限がありません。",
+    transfer_not_linked: "このユーザーはメールアドレスを登録していないため、引き継げません",
+    transfer_same_owner: "現在の予約者と同じユーザーです",
+    usage_not_found: "❌ 申し訳ございません。この予約は既に削除されているか、見つかりませんでした。",
+    time_slot_taken: "❌ 指定された時間帯は既に予約されています。",
+    limit_reservations: "同時に持てる予約は{limit}件までです（現在{current}件）",
+    limit_reservations_on_server: "{server}で同時に持てる予約は{limit}件までです（現在{current}件）",
+    limit_devices: "同時に確保できるGPUは{limit}台までです（この期間に確保済み{current}台）",
+    limit_devices_on_server: "{server}で同時に確保できるGPUは{limit}台までです（この期間に確保済み{current}台）",
+    limit_holdings: "*現在の予約:*",
+    booking_notice_required: "{resource} は開始の{minutes}分前までに予約してください",
+    booking_too_far_ahead: "{resource} は{days}日先までしか予約できません",
+    approval_pending: "⏳ この部屋の予約には承認が必要です。承認されるまで、カレンダー上では仮の予定として表示されます。",
+    closure_header: "⚠️ 注意: この予約は休業日にかかっています（研究室は閉まっています）",
+    closed_weekday: "定休日",
+    fair_share_advisory: "⚖️ 直近{days}日間に {model} を{hours}時間使用しています（公平な配分の{ratio}倍）",
+    fair_share_alternative: "{server} は同じ時間帯に空いているので、そちらも検討してください",
+
+    waitlist_offer: "⚠️ 指定された時間帯は既に予約されています\n{reasons}\n\n空き待ちに登録すると、キャンセルなどで空いたときに登録順にDMでお知らせします。",
+    join_waitlist: "🔔 空いたら知らせる",
+    waitlist_joined: "🔔 空き待ちに登録しました。空きが出たらDMでお知らせします。",
+    waitlist_already_joined: "ℹ️ この時間帯・リソースの空き待ちには既に登録しています。",
+    waitlist_join_failed: "❌ 空き待ちへの登録に失敗しました: {error}",
+    waitlist_available: "🔔 空き待ちしていた時間帯が空きました\n\n*リソース*\n{resources}\n\n*期間*\n{time}\n\nこれから{minutes}分間はあなたの順番です。/reserve から予約してください。",
+
+    preempted_others: "⚡ 優先度の低い次の予約を取り消しました:",
+    preempted_notice: "⚡ あなたの予約は、優先度の高い予約（{priority}）のために取り消されました\n\n*リソース*\n{resources}\n\n*期間*\n{time}",
+    preempted_suggestion: "同じリソースは {time} に空いています。/reserve から予約し直してください。",
+    preempted_no_suggestion: "2週間以内に同じリソースが同じ長さだけ空いている時間帯は見つかりませんでした。",
+
+    suggest_time: "💡 空いている時間を提案",
+    suggest_time_found: "💡 {time} が空いているので、日時に入力しました",
+    suggest_time_none: "⚠️ {days}日以内に、選択したリソースが続けて空いている時間は見つかりませんでした",
+    next_free_usage: "使い方: /next-free <サーバー名または部屋名> [デバイス指定] <時間数>\n例: /next-free Thalys 0-1 4",
+    next_free_found: "💡 {resource} の次の空き\n{time}",
+    next_free_reserve_hint: "予約するには: `{command}`",
+    next_free_none: "⚠️ {resource} には、{days}日以内に{hours}時間続けて空いている時間がありません",
+    next_free_failed: "❌ 空いている時間の検索に失敗しました: {error}",
+
+    whois_using_usage: "使い方: /whois-using <サーバー名または部屋名>",
+    whois_using_header: "👥 {resource} を使用中",
+    whois_using_entry: "• {owner}{devices}（{until}まで）",
+    whois_using_none: "✅ {resource} は現在空いています",
+    whois_using_failed: "❌ 使用状況の取得に失敗しました: {error}",
+
+    cancel_all_title: "予約の一括キャンセル",
+    cancel_all_submit: "キャンセルする",
+    cancel_all_intro: "キャンセルする開始前の予約を選んでください。選んだ予約はまとめてキャンセルされます。",
+    cancel_all_truncated: "開始が早い{count}件を表示しています。残りはキャンセル後にもう一度 /cancel-all を実行してください。",
+    cancel_all_label: "開始前の予約",
+    cancel_all_none: "キャンセルできる開始前の予約はありません",
+    cancel_all_nothing_selected: "キャンセルする予約を選択してください",
+    cancel_all_done: "🗑️ 予約を{count}件キャンセルしました:",
+    cancel_all_not_found: "❌ 選択した予約の一部が既に削除されていたため、何もキャンセルしませんでした。もう一度 /cancel-all を実行してください",
+    cancel_all_failed: "❌ 予約のキャンセルに失敗しました: {error}",
+
+    quick_reserve_usage: "使い方: /reserve [today|tomorrow|YYYY-MM-DD] HH:MM-HH:MM <サーバー名または部屋名> [デバイス指定]\n例: /reserve tomorrow 14:00-18:00 Thalys 0-1",
+    end_before_start: "❌ 終了時刻は開始時刻より後にしてください",
+    room_has_no_devices: "部屋 {room} にはデバイスを指定できません",
+    instrument_has_no_devices: "機器 {instrument} にはデバイスを指定できません",
+    custom_has_no_devices: "{type} {name} にはデバイスを指定できません",
+    unknown_resource: "リソース {name} は設定されていません",
+    unknown_command: "不明なコマンド: {command}",
+
+    session_expired: "セッションの有効期限が切れました。もう一度コマンドを実行してください。",
+    session_expired_button: "セッションの有効期限が切れました。もう一度ボタンを押してください。",
+    invalid_request: "❌ リクエストを処理できませんでした。もう一度お試しください。",
+    email_not_registered: "❌ 先に /register-calendar でメールアドレスを登録してください",
+    resource_type_required: "リソースタイプを選択してください",
+    server_required: "サーバーを選択してください",
+    server_not_found: "サーバー {server} が見つかりません",
+    device_not_found: "デバイス {device} が {server} に見つかりません",
+    no_tagged_devices: "{server} にはタグ {tag} の付いたデバイスがありません",
+    room_required: "部屋を選択してください",
+    instrument_required: "機器を選択してください",
+    custom_required: "{type}を選択してください",
+    unknown_resource_type: "不明なリソースタイプ: {type}",
+    start_date_required: "開始日を選択してください",
+    start_time_required: "開始時刻を選択してください",
+    end_date_required: "終了日を選択してください",
+    end_time_required: "終了時刻を選択してください",
+    end_not_after_start: "終了日時は開始日時より後にしてください",
+    invalid_datetime: "無効な日時です: {datetime}（夏時間の切り替え時刻の可能性があります）",
+    unknown_repeat: "不明な繰り返し: {repeat}",
+    repeat_until_required: "繰り返しの終了日を選択してください",
+    extend_duration_required: "延長する時間を選択してください",
+    extend_minutes_required: "延長する時間（分）を入力してください",
+    positive_integer_required: "1以上の整数を入力してください",
+    unknown_extend_preset: "不明な延長時間: {preset}",
+    transfer_owner_required: "引き継ぎ先のユーザーを選択してください",
+    user_required: "ユーザーを選択してください",
+    email_required: "メールアドレスを入力してください",
+    conflict_with_owner: "{resource} は {owner} が {time} に予約しています",
+    conflict_private: "{resource} は {time} に非公開の予約があります",
+    invalid_submission: "送信内容を処理できませんでした",
+
+    cancelled: "✅ 予約をキャンセルしました",
+    cancel_forbidden: "❌ この予約を削除する権限がありません。",
+    cancel_failed: "❌ 予約の削除に失敗しました: {error}",
+    approval_email_required: "❌ 承認・却下するには、先にメールアドレスを登録してください。",
+    approved_by: "✅ {approver} が {resources} の予約（予約者: {owner}）を承認しました",
+    rejected_by: "🚫 {approver} が {resources} の予約（予約者: {owner}）を却下しました",
+    already_approved: "❌ この予約は既に承認されています。",
+    approval_not_found: "❌ この予約は既に却下・削除されているか、見つかりませんでした。",
+    approval_forbidden: "❌ この予約を承認・却下できるのは、部屋の承認者と管理者だけです。",
+    approval_failed: "❌ 予約の承認・却下に失敗しました: {error}",
+    released: "✅ 予約を終了し、リソースを解放しました（終了: {end}）",
+    release_not_in_use: "❌ この予約は使用中ではないため、今すぐ解放できません。開始前の予約はキャンセルしてください。",
+    release_forbidden: "❌ この予約を終了する権限がありません。",
+    release_failed: "❌ 予約の終了に失敗しました: {error}",
+    kept_idle: "✅ 予約を継続します。この予約期間中は自動で解放しません。",
+    keep_idle_forbidden: "❌ この予約を操作する権限がありません。",
+    keep_idle_failed: "❌ 予約の継続に失敗しました: {error}",
+    split_result_header: "分割予約の結果",
+    split_reserved: "✅ {resources} (予約ID: {usage_id})",
+    split_failed: "❌ {resources}: {error}",
+    email_registered: "✅ メールアドレス {email} を登録しました",
+    email_registered_retry: "✅ メールアドレス {email} を登録しました。もう一度 /reserve を実行してください",
+    register_failed: "❌ 登録に失敗しました: {error}",
+    unlinked: "✅ メールアドレス {email} との連携を解除しました",
+    unlink_failed: "❌ 連携の解除に失敗しました: {error}",
+    processing: "⏳ 処理中...",
+    command_failed: "エラー: {error}",
+    check_input: "⚠️ 入力内容を確認してください",
+    close: "閉じる",
+
+    linked: "✅ ユーザー {user} をメールアドレス {email} に紐付けました",
+    link_failed: "❌ 紐付けに失敗しました: {error}",
+
+    admin_only: "❌ このコマンドを実行できるのは管理者のみです",
+    admin_cancel_forbidden: "❌ 他のユーザーの予約をキャンセルできるのは管理者とモデレーターのみです",
+    admin_cancel_usage: "使い方:\n• /admin-cancel <予約ID> - 指定した予約をキャンセル\n• /admin-cancel <@ユーザー> - ユーザーの終了していない予約をすべてキャンセル",
+    reservation_not_found: "❌ 予約 {usage_id} が見つかりませんでした",
+    reservation_target: "予約 {usage_id}",
+    user_not_linked: "❌ {user} はメールアドレスと紐付けられていません",
+    admin_cancel_none: "{target} にキャンセルできる予約はありません",
+    admin_cancel_done: "🗑️ {target} の予約を{count}件キャンセルしました:",
+    admin_cancel_notice: "予約者にはキャンセルの通知が送られます。",
+    availability_usage: "使い方: /availability [サーバー名または部屋名] [tag:タグ] [YYYY-MM-DD]",
+    unknown_tag: "タグ {tag} の付いたデバイスはありません",
+    availability_failed: "❌ 空き状況の取得に失敗しました: {error}",
+    availability_header: "{date} の空き状況: 終日空き {free} / {total}",
+    availability_legend: "{busy} 予約あり　{free} 空き",
+    kind_room: "部屋",
+    kind_instrument: "機器",
+    kind_storage: "ストレージ",
+    kind_license: "ライセンス",
+    window_week: "過去7日間",
+    window_month: "過去30日間",
+    usage_stats_usage: "使い方: /usage-stats [week|month]",
+    usage_stats_failed: "❌ 予約時間の集計に失敗しました: {error}",
+    usage_stats_header: "予約時間の集計（{window}: {start} 〜 {end}）",
+    usage_stats_none: "期間内の予約はありません",
+    usage_stats_room: "（部屋 {hours}）",
+    usage_stats_utilization: "（想定使用率 {utilization}%）",
+    by_user: "ユーザー別",
+    by_server: "サーバー別（GPU）",
+    by_resource: "デバイス・部屋別",
+    by_project: "プロジェクト別",
+    by_week: "週別",
+    cost_report_usage: "使い方: /cost-report [week|month]",
+    cost_model_missing: "❌ GPUの料金が設定されていません（resources.toml の [cost_model]）",
+    cost_report_failed: "❌ 費用の集計に失敗しました: {error}",
+    cost_report_header: "GPUの予約の費用（{window}: {start} 〜 {end}）",
+    cost_report_none: "期間内のGPUの予約はありません",
+    cost_report_total: "合計: {total}",
+    no_project: "（プロジェクト未指定）",
+    device_status_usage: "使い方:\n• /device-status <サーバー名> <デバイス番号> <available|degraded|out-of-service> [メモ] - デバイスの状態を設定\n• /device-status list - 状態が登録されているデバイスの一覧",
+    unknown_device: "❌ {server} のデバイス {device} は設定されていません",
+    device_status_list_failed: "❌ デバイスの状態の取得に失敗しました: {error}",
+    device_status_set_failed: "❌ デバイスの状態の設定に失敗しました: {error}",
+    device_status_entry: "{server} のデバイス {device}: {status}",
+    device_status_none: "すべてのデバイスが使用可能です",
+    device_status_header: "*状態が登録されているデバイス:*",
+    affected_none: "影響を受ける既存の予約はありません",
+    affected_header: "*影響を受ける既存の予約（{count}件）:*",
+    affected_notice: "既存の予約は自動では取り消されません。必要に応じて予約者に連絡してください。",
+    freeze_usage: "使い方:\n• /freeze-resource <リソース名> <YYYY-MM-DD> [HH:MM] [理由] - 指定時刻以降の予約を停止\n• /freeze-resource cancel <リソース名> - 予約停止を解除\n• /freeze-resource list - 予約停止の一覧",
+    unfrozen: "✅ {resource} の予約停止を解除しました",
+    not_frozen: "{resource} は予約停止されていません",
+    freeze_list_failed: "❌ 予約停止の取得に失敗しました: {error}",
+    unfreeze_failed: "❌ 予約停止の解除に失敗しました: {error}",
+    freeze_failed: "❌ 予約停止の登録に失敗しました: {error}",
+    freeze_starts_in_past: "❌ 予約停止の開始時刻 {time} は過去の時刻です",
+    frozen_entry: "{resource} は {time} 以降の予約を停止します",
+    freeze_reason: "（理由: {reason}）",
+    freeze_none: "予約停止中のリソースはありません",
+    freeze_header: "*予約停止中のリソース:*",
+    link_history_usage: "使い方: /link-history <@slack_user|email>",
+    link_history_failed: "❌ 紐付けの履歴の取得に失敗しました: {error}",
+    link_history_none: "{subject} の紐付けの履歴はありません",
+    link_history_header: "*{subject} の紐付けの履歴:*",
+    link_history_entry: "• {time} {action} {email} ⇔ {user}（操作: {actor}）",
+    link_history_automatic: "自動",
+    unlink_usage: "使い方: /unlink-user [<@slack_user>]",
+    not_linked_yet: "メールアドレスとの連携はまだ行われていません",
+    unlink_others_forbidden: "❌ 他のユーザーの連携を解除できるのは管理者のみです",
+    user_unlinked: "✅ ユーザー {user} とメールアドレス {email} の連携を解除しました",
+    unlink_confirm: "メールアドレス *{email}* との連携を解除しますか？\n他に連携しているサービスがなければ、カレンダーへのアクセス権も削除されます。",
+    unlink_button: "連携を解除する",
+
+    profile_email_offer: "Googleカレンダーとの連携がまだ完了していません。\nSlackプロフィールのメールアドレス *{email}* で連携しますか？\n連携すると、このアドレスにカレンダーへのアクセス権が付与されます。",
+    link_profile_email: "このアドレスで連携",
+    enter_email_manually: "別のアドレスを入力",
+    register_title: "メールアドレスの登録",
+    register_intro: "Googleカレンダーと連携するためのメールアドレスを登録してください。\n登録されたアドレスに、カレンダーへのアクセス権が自動的に付与されます。",
+    register_submit: "登録",
+    email_address: "メールアドレス",
+    link_user_title: "ユーザーをメールアドレスに紐付け",
+    link_user_intro: "他のユーザーをGoogleカレンダーのメールアドレスに紐付けます。\n紐付けられたユーザーに、カレンダーへのアクセス権が自動的に付与されます。",
+    link_user_target: "紐付けるユーザー",
+    select_user: "ユーザーを選択",
+    link_user_submit: "紐付け",
+    split_partial_conflict: "⚠️ 希望した予約は一部が既存の予約と重なっています\n{reasons}\n",
+    split_available: "*空いている部分だけを予約できます:*",
+    split_unavailable: "\n期間中に空きがないリソース: {resources}",
+    split_reserve: "✂️ 分割して予約する",
+    split_too_large: "分割案が大きすぎるため自動予約できません。/reserve から個別に予約してください。",
+    install_completed: "インストールが完了しました。Slackに戻ってご利用ください。",
+    install_cancelled: "インストールは取り消されました。",
+    install_failed: "インストールに失敗しました。管理者に連絡してください。",
+};
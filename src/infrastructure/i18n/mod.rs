@@ -0,0 +1,822 @@
+//! 表示言語（ロケール）とメッセージカタログ
+//!
+//! Slackのモーダル・コマンドの返信・通知に表示する文言を言語ごとにまとめる。
+//! 文言中の `{name}` 形式のプレースホルダーは [`fill`] で置換する。
+
+mod en;
+mod ja;
+
+use crate::domain::aggregates::device_health::DeviceStatus;
+use crate::domain::aggregates::identity_link::value_objects::IdentityLinkAction;
+use crate::domain::aggregates::resource_usage::value_objects::{Priority, RecurrenceFrequency};
+use crate::domain::ports::power_management::PowerState;
+use chrono::Weekday;
+use serde::Deserialize;
+
+/// 表示言語
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// 日本語
+    #[default]
+    Ja,
+    /// 英語
+    En,
+}
+
+impl Locale {
+    /// Slackのロケール（`ja-JP`、`en-US` 等）から表示言語を決定
+    ///
+    /// 対応していない言語の場合は `None`
+    pub fn from_slack_locale(locale: &str) -> Option<Self> {
+        match locale
+            .split(['-', '_'])
+            .next()?
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "ja" => Some(Locale::Ja),
+            "en" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    /// この言語のメッセージカタログを取得
+    pub fn messages(self) -> &'static Messages {
+        match self {
+            Locale::Ja => &ja::MESSAGES,
+            Locale::En => &en::MESSAGES,
+        }
+    }
+}
+
+/// メッセージカタログ
+///
+/// プレースホルダーを含む文言は、名前をコメントに記載している。
+#[derive(Debug)]
+pub struct Messages {
+    // 通知
+    /// 予約作成時のデフォルトテンプレート
+    pub template_created: &'static str,
+    /// 予約更新時のデフォルトテンプレート
+    pub template_updated: &'static str,
+    /// 予約削除時のデフォルトテンプレート
+    pub template_deleted: &'static str,
+    /// 予約の使用開始時のデフォルトテンプレート
+    pub template_started: &'static str,
+    /// 予約の使用終了時のデフォルトテンプレート
+    pub template_ended: &'static str,
+    /// 備考の見出し
+    pub notes_label: &'static str,
+    /// メタデータ（プロジェクト名・実験ID・想定使用率）の見出し
+    pub metadata_label: &'static str,
+    /// 電源状態の見出し
+    pub power_label: &'static str,
+    /// 電源状態（オン・オフ・不明）
+    pub power_states: [&'static str; 3],
+    /// デバイスの状態（使用可能・性能低下・使用停止）
+    pub device_statuses: [&'static str; 3],
+    /// 紐付けの履歴の操作（紐付け・紐付け解除）
+    pub link_actions: [&'static str; 2],
+    /// 非公開の予約で予約者の代わりに表示する文言
+    pub redacted_owner: &'static str,
+    /// 繰り返し予約の見出し（`{count}`）
+    pub series_header: &'static str,
+    /// GPUのみの予約のリソースラベル
+    pub resource_label_gpu: &'static str,
+    /// 部屋のみの予約のリソースラベル
+    pub resource_label_room: &'static str,
+    /// 機器のみの予約のリソースラベル
+    pub resource_label_instrument: &'static str,
+    /// ストレージのみの予約のリソースラベル
+    pub resource_label_storage: &'static str,
+    /// ライセンスのみの予約のリソースラベル
+    pub resource_label_license: &'static str,
+    /// その他の予約のリソースラベル
+    pub resource_label_other: &'static str,
+    /// 相対日付（昨日・今日・明日・明後日）
+    pub relative_days: [&'static str; 4],
+    /// 曜日の略称（月曜日から）
+    pub weekdays: [&'static str; 7],
+    /// 繰り返しの頻度（毎週・隔週）
+    pub frequencies: [&'static str; 2],
+    /// 優先度（バックグラウンド・通常・締切前）
+    pub priorities: [&'static str; 3],
+
+    // 予約モーダル
+    /// 新規予約モーダルのタイトル
+    pub reserve_title: &'static str,
+    /// 新規予約モーダルの送信ボタン
+    pub reserve_submit: &'static str,
+    /// 予約更新モーダルのタイトル
+    pub update_title: &'static str,
+    /// 予約更新モーダルの送信ボタン
+    pub update_submit: &'static str,
+    /// モーダルのキャンセルボタン
+    pub cancel: &'static str,
+    /// リソースタイプの入力欄
+    pub resource_type: &'static str,
+    /// サーバー選択のプレースホルダー
+    pub select_server: &'static str,
+    /// サーバー選択のヒント
+    pub server_hint: &'static str,
+    /// タグで絞り込む入力欄
+    pub tag_filter: &'static str,
+    /// タグで絞り込まない選択肢
+    pub tag_any: &'static str,
+    /// タグで絞り込む入力欄のヒント
+    pub tag_filter_hint: &'static str,
+    /// サーバー設定が無い場合の表示
+    pub no_servers: &'static str,
+    /// 部屋選択のプレースホルダー
+    pub select_room: &'static str,
+    /// 部屋設定が無い場合の表示
+    pub no_rooms: &'static str,
+    /// 機器選択のプレースホルダー
+    pub select_instrument: &'static str,
+    /// 設定で定義した種別のリソース選択のプレースホルダー（`{type}`）
+    pub select_custom: &'static str,
+    /// 繰り返しの入力欄
+    pub repeat: &'static str,
+    /// 繰り返さない場合の選択肢
+    pub repeat_none: &'static str,
+    /// 繰り返しの終了日の入力欄
+    pub repeat_until: &'static str,
+    /// 繰り返しの終了日のヒント（`{max}`）
+    pub repeat_until_hint: &'static str,
+    /// 開始日の入力欄
+    pub start_date: &'static str,
+    /// 開始時刻の入力欄
+    pub start_time: &'static str,
+    /// 終了日の入力欄
+    pub end_date: &'static str,
+    /// 終了時刻の入力欄
+    pub end_time: &'static str,
+    /// 日時を入力するタイムゾーンの案内（`{timezone}`）
+    pub timezone_hint: &'static str,
+    /// 備考の入力欄
+    pub notes: &'static str,
+    /// プロジェクト名の入力欄・表示
+    pub project: &'static str,
+    /// 実験IDの入力欄・表示
+    pub experiment_id: &'static str,
+    /// 想定使用率の入力欄・表示
+    pub utilization: &'static str,
+    /// 想定使用率のヒント
+    pub utilization_hint: &'static str,
+    /// 想定使用率が不正な場合のエラー
+    pub invalid_utilization: &'static str,
+    /// 公開範囲の入力欄
+    pub visibility: &'static str,
+    /// 非公開にする選択肢
+    pub make_private: &'static str,
+    /// 公開範囲のヒント
+    pub private_hint: &'static str,
+    /// 優先度の入力欄
+    pub priority: &'static str,
+    /// 優先度のヒント
+    pub priority_hint: &'static str,
+    /// 予約を所有するチームの入力欄
+    pub group: &'static str,
+    /// 予約を所有するチームのヒント
+    pub group_hint: &'static str,
+    /// メンバーでないチームを選んだ場合のエラー（`{group}`）
+    pub group_not_member: &'static str,
+    /// 横取りの入力欄
+    pub preemption: &'static str,
+    /// 優先度の低い予約を取り消して予約する選択肢
+    pub preempt: &'static str,
+    /// 横取りのヒント
+    pub preempt_hint: &'static str,
+    /// 使用中のデバイス（`{device}`、`{until}`、`{owner}`）
+    pub device_busy: &'static str,
+    /// 予約者を伏せた使用中のデバイス（`{device}`、`{until}`）
+    pub device_busy_private: &'static str,
+    /// 性能低下中のデバイス（`{device}`）
+    pub device_degraded: &'static str,
+
+    // 延長モーダル
+    /// 延長モーダルのタイトル
+    pub extend_title: &'static str,
+    /// 延長モーダルの送信ボタン
+    pub extend_submit: &'static str,
+    /// 現在の終了時刻（`{end}`）
+    pub current_end: &'static str,
+    /// 延長する時間の入力欄
+    pub extend_duration: &'static str,
+    /// +1時間の選択肢
+    pub plus_one_hour: &'static str,
+    /// +2時間の選択肢
+    pub plus_two_hours: &'static str,
+    /// その他の選択肢
+    pub other: &'static str,
+    /// 延長する時間（分）の入力欄
+    pub extend_minutes: &'static str,
+    /// 延長する時間（分）のヒント
+    pub extend_minutes_hint: &'static str,
+
+    // 引き継ぎモーダル
+    /// 引き継ぎモーダルのタイトル
+    pub transfer_title: &'static str,
+    /// 引き継ぎモーダルの送信ボタン
+    pub transfer_submit: &'static str,
+    /// 現在の予約者（`{owner}`）
+    pub current_owner: &'static str,
+    /// 引き継ぎ先の入力欄
+    pub transfer_new_owner: &'static str,
+    /// 引き継ぎ先のヒント
+    pub transfer_new_owner_hint: &'static str,
+
+    // 予約結果
+    /// 予約の完了（`{usage_id}`）
+    pub reserved: &'static str,
+    /// 予約の失敗（`{error}`）
+    pub reserve_failed: &'static str,
+    /// 繰り返し予約の完了（`{frequency}`、`{count}`、`{usage_id}`）
+    pub series_reserved: &'static str,
+    /// 繰り返し予約の失敗（`{error}`）
+    pub series_failed: &'static str,
+    /// 予約の更新
+    pub updated: &'static str,
+    /// 予約の更新の失敗（`{error}`）
+    pub update_failed: &'static str,
+    /// 予約の更新の権限が無い
+    pub update_forbidden: &'static str,
+    /// 予約の延長（`{end}`）
+    pub extended: &'static str,
+    /// 予約の延長の失敗（`{error}`）
+    pub extend_failed: &'static str,
+    /// 予約の延長の権限が無い
+    pub extend_forbidden: &'static str,
+    /// 延長する時間帯が別の予約と重なる（`{resource}`）
+    pub extend_conflict: &'static str,
+    /// 延長する時間帯が別の予約と重なる（入力欄のエラー、`{resource}`）
+    pub extend_conflict_field: &'static str,
+    /// 予約の引き継ぎ（`{owner}`）
+    pub transferred: &'static str,
+    /// 予約の引き継ぎの失敗（`{error}`）
+    pub transfer_failed: &'static str,
+    /// 予約の引き継ぎの権限が無い
+    pub transfer_forbidden: &'static str,
+    /// 引き継ぎ先がメールアドレスを登録していない（入力欄のエラー）
+    pub transfer_not_linked: &'static str,
+    /// 引き継ぎ先が現在の予約者と同じ（入力欄のエラー）
+    pub transfer_same_owner: &'static str,
+    /// 予約が見つからない
+    pub usage_not_found: &'static str,
+    /// 指定された時間帯が既に予約されている
+    pub time_slot_taken: &'static str,
+    /// 同時に持てる予約の件数の上限を超える（`{limit}`、`{current}`）
+    pub limit_reservations: &'static str,
+    /// サーバーごとの同時に持てる予約の件数の上限を超える（`{server}`、`{limit}`、`{current}`）
+    pub limit_reservations_on_server: &'static str,
+    /// 同時に確保できるGPUの数の上限を超える（`{limit}`、`{current}`）
+    pub limit_devices: &'static str,
+    /// サーバーごとの同時に確保できるGPUの数の上限を超える（`{server}`、`{limit}`、`{current}`）
+    pub limit_devices_on_server: &'static str,
+    /// 上限の計算に数えた予約の一覧の見出し
+    pub limit_holdings: &'static str,
+    /// 開始までの時間が受付期間より短い（`{resource}`、`{minutes}`）
+    pub booking_notice_required: &'static str,
+    /// 開始時刻が受付期間より先（`{resource}`、`{days}`）
+    pub booking_too_far_ahead: &'static str,
+    /// 承認が必要な部屋の予約への注意書き
+    pub approval_pending: &'static str,
+    /// 休業日にかかる予約への注意書きの見出し
+    pub closure_header: &'static str,
+    /// 定休日
+    pub closed_weekday: &'static str,
+    /// 直近の使用量が公平な配分を超えている場合の注意書き（`{days}`、`{model}`、`{hours}`、`{ratio}`）
+    pub fair_share_advisory: &'static str,
+    /// 代わりに検討できるサーバーの提案（`{server}`）
+    pub fair_share_alternative: &'static str,
+
+    // 空き待ち
+    /// 予約が競合した場合の空き待ちの案内（`{reasons}`）
+    pub waitlist_offer: &'static str,
+    /// 空き待ちへの登録ボタン
+    pub join_waitlist: &'static str,
+    /// 空き待ちへの登録の完了
+    pub waitlist_joined: &'static str,
+    /// 既に空き待ちに登録済み
+    pub waitlist_already_joined: &'static str,
+    /// 空き待ちへの登録の失敗（`{error}`）
+    pub waitlist_join_failed: &'static str,
+    /// 空き待ちしていた枠が空いたことの通知（`{resources}`、`{time}`、`{minutes}`）
+    pub waitlist_available: &'static str,
+
+    // 予約の横取り
+    /// 横取りして予約したときに取り消した予約の見出し
+    pub preempted_others: &'static str,
+    /// 予約が横取りされたことの通知（`{priority}`、`{resources}`、`{time}`）
+    pub preempted_notice: &'static str,
+    /// 横取りされた予約の代わりに空いている時間帯の提案（`{time}`）
+    pub preempted_suggestion: &'static str,
+    /// 横取りされた予約の代わりに空いている時間帯が見つからない
+    pub preempted_no_suggestion: &'static str,
+
+    // 空いている時間帯の提案
+    /// 予約モーダルの空いている時間を提案するボタン
+    pub suggest_time: &'static str,
+    /// 提案した時間帯を日時に入力した（`{time}`）
+    pub suggest_time_found: &'static str,
+    /// 提案できる時間帯がない（`{days}`）
+    pub suggest_time_none: &'static str,
+    /// `/next-free` の使い方
+    pub next_free_usage: &'static str,
+    /// 次に空いている時間帯（`{resource}`、`{time}`）
+    pub next_free_found: &'static str,
+    /// 見つかった時間帯を予約するコマンドの案内（`{command}`）
+    pub next_free_reserve_hint: &'static str,
+    /// 空いている時間帯がない（`{resource}`、`{hours}`、`{days}`）
+    pub next_free_none: &'static str,
+    /// 空いている時間帯の検索の失敗（`{error}`）
+    pub next_free_failed: &'static str,
+
+    // 使用中のユーザー
+    /// `/whois-using` の使い方
+    pub whois_using_usage: &'static str,
+    /// 使用中のユーザーの見出し（`{resource}`）
+    pub whois_using_header: &'static str,
+    /// 使用中のユーザー1人分（`{owner}`、`{devices}`、`{until}`）
+    pub whois_using_entry: &'static str,
+    /// 使用中のユーザーがいない（`{resource}`）
+    pub whois_using_none: &'static str,
+    /// 使用状況の取得の失敗（`{error}`）
+    pub whois_using_failed: &'static str,
+
+    // 予約の一括キャンセル
+    /// 一括キャンセルモーダルのタイトル
+    pub cancel_all_title: &'static str,
+    /// 一括キャンセルモーダルの送信ボタン
+    pub cancel_all_submit: &'static str,
+    /// 一括キャンセルモーダルの説明
+    pub cancel_all_intro: &'static str,
+    /// 表示しきれない予約がある（`{count}`）
+    pub cancel_all_truncated: &'static str,
+    /// 予約のチェックボックスの見出し
+    pub cancel_all_label: &'static str,
+    /// キャンセルできる予約がない
+    pub cancel_all_none: &'static str,
+    /// 予約が選択されていない
+    pub cancel_all_nothing_selected: &'static str,
+    /// キャンセルした予約の見出し（`{count}`）
+    pub cancel_all_done: &'static str,
+    /// 選択した予約が既に削除されていた
+    pub cancel_all_not_found: &'static str,
+    /// 一括キャンセルの失敗（`{error}`）
+    pub cancel_all_failed: &'static str,
+
+    // スラッシュコマンド
+    /// 引数付きの `/reserve` の使い方
+    pub quick_reserve_usage: &'static str,
+    /// 終了時刻が開始時刻より前
+    pub end_before_start: &'static str,
+    /// 部屋にデバイスを指定した（`{room}`）
+    pub room_has_no_devices: &'static str,
+    /// 機器にデバイスを指定した（`{instrument}`）
+    pub instrument_has_no_devices: &'static str,
+    /// 設定で定義した種別のリソースにデバイスを指定した（`{type}`, `{name}`）
+    pub custom_has_no_devices: &'static str,
+    /// 設定されていないリソース（`{name}`）
+    pub unknown_resource: &'static str,
+    /// 不明なコマンド（`{command}`）
+    pub unknown_command: &'static str,
+
+    // フォームの入力検証
+    /// セッションの有効期限切れ（コマンドから開いたモーダル）
+    pub session_expired: &'static str,
+    /// セッションの有効期限切れ（ボタンから開いたモーダル）
+    pub session_expired_button: &'static str,
+    /// モーダル・ボタンから必要な情報を取得できない
+    pub invalid_request: &'static str,
+    /// 操作したユーザーがメールアドレスを登録していない
+    pub email_not_registered: &'static str,
+    /// リソースタイプが未選択
+    pub resource_type_required: &'static str,
+    /// サーバーが未選択
+    pub server_required: &'static str,
+    /// 設定にないサーバー（`{server}`）
+    pub server_not_found: &'static str,
+    /// 設定にないデバイス（`{device}`、`{server}`）
+    pub device_not_found: &'static str,
+    /// タグの付いたデバイスがない（`{server}`、`{tag}`）
+    pub no_tagged_devices: &'static str,
+    /// 部屋が未選択
+    pub room_required: &'static str,
+    /// 機器が未選択
+    pub instrument_required: &'static str,
+    /// 設定で定義した種別のリソースが未選択（`{type}`）
+    pub custom_required: &'static str,
+    /// 不明なリソースタイプ（`{type}`）
+    pub unknown_resource_type: &'static str,
+    /// 開始日が未選択
+    pub start_date_required: &'static str,
+    /// 開始時刻が未選択
+    pub start_time_required: &'static str,
+    /// 終了日が未選択
+    pub end_date_required: &'static str,
+    /// 終了時刻が未選択
+    pub end_time_required: &'static str,
+    /// 終了日時が開始日時以前（入力欄のエラー）
+    pub end_not_after_start: &'static str,
+    /// 存在しない日時（`{datetime}`）
+    pub invalid_datetime: &'static str,
+    /// 不明な繰り返し（`{repeat}`）
+    pub unknown_repeat: &'static str,
+    /// 繰り返しの終了日が未選択
+    pub repeat_until_required: &'static str,
+    /// 延長する時間が未選択
+    pub extend_duration_required: &'static str,
+    /// 延長する時間（分）が未入力
+    pub extend_minutes_required: &'static str,
+    /// 1以上の整数でない
+    pub positive_integer_required: &'static str,
+    /// 不明な延長時間（`{preset}`）
+    pub unknown_extend_preset: &'static str,
+    /// 引き継ぎ先が未選択
+    pub transfer_owner_required: &'static str,
+    /// ユーザーが未選択
+    pub user_required: &'static str,
+    /// メールアドレスが未入力
+    pub email_required: &'static str,
+    /// 既存の予約との競合（`{resource}`、`{owner}`、`{time}`）
+    pub conflict_with_owner: &'static str,
+    /// 予約者を伏せた既存の予約との競合（`{resource}`、`{time}`）
+    pub conflict_private: &'static str,
+    /// モーダルの送信内容を処理できない
+    pub invalid_submission: &'static str,
+
+    // ボタン操作の結果
+    /// 予約をキャンセルした
+    pub cancelled: &'static str,
+    /// 予約をキャンセルする権限がない
+    pub cancel_forbidden: &'static str,
+    /// 予約のキャンセルに失敗（`{error}`）
+    pub cancel_failed: &'static str,
+    /// 承認者がメールアドレスを登録していない
+    pub approval_email_required: &'static str,
+    /// 予約を承認した（`{approver}`、`{resources}`、`{owner}`）
+    pub approved_by: &'static str,
+    /// 予約を却下した（`{approver}`、`{resources}`、`{owner}`）
+    pub rejected_by: &'static str,
+    /// 予約が承認済み
+    pub already_approved: &'static str,
+    /// 承認待ちの予約が見つからない
+    pub approval_not_found: &'static str,
+    /// 予約を承認・却下する権限がない
+    pub approval_forbidden: &'static str,
+    /// 予約の承認・却下に失敗（`{error}`）
+    pub approval_failed: &'static str,
+    /// 予約を早期終了した（`{end}`）
+    pub released: &'static str,
+    /// 使用中でない予約を早期終了しようとした
+    pub release_not_in_use: &'static str,
+    /// 予約を早期終了する権限がない
+    pub release_forbidden: &'static str,
+    /// 予約の早期終了に失敗（`{error}`）
+    pub release_failed: &'static str,
+    /// 使われていない予約を継続する
+    pub kept_idle: &'static str,
+    /// 使われていない予約を継続する権限がない
+    pub keep_idle_forbidden: &'static str,
+    /// 使われていない予約の継続に失敗（`{error}`）
+    pub keep_idle_failed: &'static str,
+    /// 分割予約の結果の見出し
+    pub split_result_header: &'static str,
+    /// 分割予約の1区画を予約した（`{resources}`、`{usage_id}`）
+    pub split_reserved: &'static str,
+    /// 分割予約の1区画の予約に失敗（`{resources}`、`{error}`）
+    pub split_failed: &'static str,
+    /// メールアドレスを登録した（`{email}`）
+    pub email_registered: &'static str,
+    /// メールアドレスを登録したが予約モーダルを開けなかった（`{email}`）
+    pub email_registered_retry: &'static str,
+    /// メールアドレスの登録に失敗（`{error}`）
+    pub register_failed: &'static str,
+    /// 自分の連携を解除した（`{email}`）
+    pub unlinked: &'static str,
+    /// 連携の解除に失敗（`{error}`）
+    pub unlink_failed: &'static str,
+    /// 時間のかかる処理を受け付けた
+    pub processing: &'static str,
+    /// コマンドの処理に失敗（`{error}`）
+    pub command_failed: &'static str,
+    /// 入力欄のエラーをまとめたメッセージの見出し
+    pub check_input: &'static str,
+    /// モーダルを閉じるボタン
+    pub close: &'static str,
+
+    // メールアドレスの紐付け
+    /// ユーザーをメールアドレスに紐付けた（`{user}`、`{email}`）
+    pub linked: &'static str,
+    /// 紐付けに失敗（`{error}`）
+    pub link_failed: &'static str,
+
+    // 管理・集計コマンド
+    /// 管理者だけが実行できるコマンドを管理者以外が実行した
+    pub admin_only: &'static str,
+    /// 管理者・モデレーター以外が他のユーザーの予約をキャンセルしようとした
+    pub admin_cancel_forbidden: &'static str,
+    /// /admin-cancel の使い方
+    pub admin_cancel_usage: &'static str,
+    /// 指定した予約IDの予約がない（`{usage_id}`）
+    pub reservation_not_found: &'static str,
+    /// キャンセルの対象の予約（`{usage_id}`）
+    pub reservation_target: &'static str,
+    /// ユーザーがメールアドレスと紐付けられていない（`{user}`）
+    pub user_not_linked: &'static str,
+    /// キャンセルできる予約がない（`{target}`）
+    pub admin_cancel_none: &'static str,
+    /// 予約をキャンセルした（`{target}`、`{count}`）
+    pub admin_cancel_done: &'static str,
+    /// 予約者にキャンセルが通知されることの説明
+    pub admin_cancel_notice: &'static str,
+    /// /availability の使い方
+    pub availability_usage: &'static str,
+    /// タグの付いたデバイスがない（`{tag}`）
+    pub unknown_tag: &'static str,
+    /// 空き状況の取得に失敗（`{error}`）
+    pub availability_failed: &'static str,
+    /// 空き状況の見出し（`{date}`、`{free}`、`{total}`）
+    pub availability_header: &'static str,
+    /// 空き状況のグリッドの凡例（`{busy}`、`{free}`）
+    pub availability_legend: &'static str,
+    /// 空き状況でまとめて表示する部屋の見出し
+    pub kind_room: &'static str,
+    /// 空き状況でまとめて表示する機器の見出し
+    pub kind_instrument: &'static str,
+    /// 空き状況でまとめて表示するストレージの見出し
+    pub kind_storage: &'static str,
+    /// 空き状況でまとめて表示するライセンスの見出し
+    pub kind_license: &'static str,
+    /// 集計期間（過去7日間）
+    pub window_week: &'static str,
+    /// 集計期間（過去30日間）
+    pub window_month: &'static str,
+    /// /usage-stats の使い方
+    pub usage_stats_usage: &'static str,
+    /// 予約時間の集計に失敗（`{error}`）
+    pub usage_stats_failed: &'static str,
+    /// 予約時間の集計の見出し（`{window}`、`{start}`、`{end}`）
+    pub usage_stats_header: &'static str,
+    /// 期間内の予約がない
+    pub usage_stats_none: &'static str,
+    /// ユーザー・プロジェクトの部屋の予約時間（`{hours}`）
+    pub usage_stats_room: &'static str,
+    /// プロジェクトの想定使用率（`{utilization}`）
+    pub usage_stats_utilization: &'static str,
+    /// ユーザー別の表の見出し
+    pub by_user: &'static str,
+    /// サーバー別の表の見出し
+    pub by_server: &'static str,
+    /// デバイス・部屋別の表の見出し
+    pub by_resource: &'static str,
+    /// プロジェクト別の表の見出し
+    pub by_project: &'static str,
+    /// 週別の表の見出し
+    pub by_week: &'static str,
+    /// /cost-report の使い方
+    pub cost_report_usage: &'static str,
+    /// GPUの料金が設定されていない
+    pub cost_model_missing: &'static str,
+    /// 費用の集計に失敗（`{error}`）
+    pub cost_report_failed: &'static str,
+    /// 費用の集計の見出し（`{window}`、`{start}`、`{end}`）
+    pub cost_report_header: &'static str,
+    /// 期間内のGPUの予約がない
+    pub cost_report_none: &'static str,
+    /// 費用の合計（`{total}`）
+    pub cost_report_total: &'static str,
+    /// プロジェクトを指定していない予約
+    pub no_project: &'static str,
+    /// /device-status の使い方
+    pub device_status_usage: &'static str,
+    /// 設定にないデバイス（`{server}`、`{device}`）
+    pub unknown_device: &'static str,
+    /// デバイスの状態の取得に失敗（`{error}`）
+    pub device_status_list_failed: &'static str,
+    /// デバイスの状態の設定に失敗（`{error}`）
+    pub device_status_set_failed: &'static str,
+    /// デバイスの状態（`{server}`、`{device}`、`{status}`）
+    pub device_status_entry: &'static str,
+    /// 状態が登録されているデバイスがない
+    pub device_status_none: &'static str,
+    /// 状態が登録されているデバイスの一覧の見出し
+    pub device_status_header: &'static str,
+    /// 影響を受ける既存の予約がない
+    pub affected_none: &'static str,
+    /// 影響を受ける既存の予約の見出し（`{count}`）
+    pub affected_header: &'static str,
+    /// 影響を受ける既存の予約が取り消されないことの説明
+    pub affected_notice: &'static str,
+    /// /freeze-resource の使い方
+    pub freeze_usage: &'static str,
+    /// 予約停止を解除した（`{resource}`）
+    pub unfrozen: &'static str,
+    /// 予約停止されていない（`{resource}`）
+    pub not_frozen: &'static str,
+    /// 予約停止の取得に失敗（`{error}`）
+    pub freeze_list_failed: &'static str,
+    /// 予約停止の解除に失敗（`{error}`）
+    pub unfreeze_failed: &'static str,
+    /// 予約停止の登録に失敗（`{error}`）
+    pub freeze_failed: &'static str,
+    /// 予約停止の開始時刻が過去（`{time}`）
+    pub freeze_starts_in_past: &'static str,
+    /// 予約停止（`{resource}`、`{time}`）
+    pub frozen_entry: &'static str,
+    /// 予約停止の理由（`{reason}`）
+    pub freeze_reason: &'static str,
+    /// 予約停止中のリソースがない
+    pub freeze_none: &'static str,
+    /// 予約停止中のリソースの一覧の見出し
+    pub freeze_header: &'static str,
+    /// /link-history の使い方
+    pub link_history_usage: &'static str,
+    /// 紐付けの履歴の取得に失敗（`{error}`）
+    pub link_history_failed: &'static str,
+    /// 紐付けの履歴がない（`{subject}`）
+    pub link_history_none: &'static str,
+    /// 紐付けの履歴の見出し（`{subject}`）
+    pub link_history_header: &'static str,
+    /// 紐付けの履歴の1件（`{time}`、`{action}`、`{email}`、`{user}`、`{actor}`）
+    pub link_history_entry: &'static str,
+    /// 紐付けの履歴で操作したユーザーがいない場合の表示
+    pub link_history_automatic: &'static str,
+    /// /unlink-user の使い方
+    pub unlink_usage: &'static str,
+    /// 自分の連携を解除しようとしたがまだ連携していない
+    pub not_linked_yet: &'static str,
+    /// 管理者以外が他のユーザーの連携を解除しようとした
+    pub unlink_others_forbidden: &'static str,
+    /// 他のユーザーの連携を解除した（`{user}`、`{email}`）
+    pub user_unlinked: &'static str,
+    /// 自分の連携の解除の確認（`{email}`）
+    pub unlink_confirm: &'static str,
+    /// 連携を解除するボタン
+    pub unlink_button: &'static str,
+
+    // メールアドレスの登録・分割予約・インストール
+    /// プロフィールのメールアドレスでの連携の提案（`{email}`）
+    pub profile_email_offer: &'static str,
+    /// プロフィールのメールアドレスで連携するボタン
+    pub link_profile_email: &'static str,
+    /// 別のメールアドレスを入力するボタン
+    pub enter_email_manually: &'static str,
+    /// メールアドレス登録モーダルのタイトル
+    pub register_title: &'static str,
+    /// メールアドレス登録モーダルの説明
+    pub register_intro: &'static str,
+    /// メールアドレス登録モーダルの送信ボタン
+    pub register_submit: &'static str,
+    /// メールアドレスの入力欄
+    pub email_address: &'static str,
+    /// ユーザー紐付けモーダルのタイトル
+    pub link_user_title: &'static str,
+    /// ユーザー紐付けモーダルの説明
+    pub link_user_intro: &'static str,
+    /// 紐付けるユーザーの入力欄
+    pub link_user_target: &'static str,
+    /// ユーザーの選択欄のプレースホルダー
+    pub select_user: &'static str,
+    /// ユーザー紐付けモーダルの送信ボタン
+    pub link_user_submit: &'static str,
+    /// 希望した予約の一部が既存の予約と重なる（`{reasons}`）
+    pub split_partial_conflict: &'static str,
+    /// 分割案の見出し
+    pub split_available: &'static str,
+    /// 期間中に空きがないリソース（`{resources}`）
+    pub split_unavailable: &'static str,
+    /// 分割して予約するボタン
+    pub split_reserve: &'static str,
+    /// 分割案が大きすぎてボタンにできない
+    pub split_too_large: &'static str,
+    /// インストール完了のページ
+    pub install_completed: &'static str,
+    /// インストール取り消しのページ
+    pub install_cancelled: &'static str,
+    /// インストール失敗のページ
+    pub install_failed: &'static str,
+}
+
+impl Messages {
+    /// 電源状態の表示
+    pub fn power_state(&self, state: PowerState) -> &'static str {
+        match state {
+            PowerState::On => self.power_states[0],
+            PowerState::Off => self.power_states[1],
+            PowerState::Unknown => self.power_states[2],
+        }
+    }
+
+    /// デバイスの状態の表示
+    pub fn device_status(&self, status: DeviceStatus) -> &'static str {
+        match status {
+            DeviceStatus::Available => self.device_statuses[0],
+            DeviceStatus::Degraded => self.device_statuses[1],
+            DeviceStatus::OutOfService => self.device_statuses[2],
+        }
+    }
+
+    /// 紐付けの履歴の操作の表示
+    pub fn link_action(&self, action: IdentityLinkAction) -> &'static str {
+        match action {
+            IdentityLinkAction::Linked => self.link_actions[0],
+            IdentityLinkAction::Unlinked => self.link_actions[1],
+        }
+    }
+
+    /// 今日から `days` 日後の相対日付（昨日〜明後日のみ）
+    pub fn relative_day(&self, days: i64) -> Option<&'static str> {
+        usize::try_from(days + 1)
+            .ok()
+            .and_then(|index| self.relative_days.get(index).copied())
+    }
+
+    /// 曜日の略称
+    pub fn weekday(&self, weekday: Weekday) -> &'static str {
+        self.weekdays[weekday.num_days_from_monday() as usize]
+    }
+
+    /// 繰り返しの頻度の表示
+    pub fn frequency(&self, frequency: RecurrenceFrequency) -> &'static str {
+        match frequency {
+            RecurrenceFrequency::Weekly => self.frequencies[0],
+            RecurrenceFrequency::Biweekly => self.frequencies[1],
+        }
+    }
+
+    /// 優先度の表示
+    pub fn priority(&self, priority: Priority) -> &'static str {
+        match priority {
+            Priority::Background => self.priorities[0],
+            Priority::Normal => self.priorities[1],
+            Priority::Deadline => self.priorities[2],
+        }
+    }
+}
+
+/// 文言中の `{name}` 形式のプレースホルダーを置換
+///
+/// 置換後の値に含まれるプレースホルダーは置換しない。
+pub fn fill(template: &str, args: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        result.push_str(&rest[..open]);
+        let after = &rest[open..];
+        match args.iter().find(|(name, _)| {
+            after
+                .strip_prefix('{')
+                .and_then(|s| s.strip_prefix(name))
+                .is_some_and(|s| s.starts_with('}'))
+        }) {
+            Some((name, value)) => {
+                result.push_str(value);
+                rest = &after[name.len() + 2..];
+            }
+            None => {
+                result.push('{');
+                rest = &after[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_slack_locale() {
+        assert_eq!(Locale::from_slack_locale("ja-JP"), Some(Locale::Ja));
+        assert_eq!(Locale::from_slack_locale("en-US"), Some(Locale::En));
+        assert_eq!(Locale::from_slack_locale("en_GB"), Some(Locale::En));
+        assert_eq!(Locale::from_slack_locale("fr-FR"), None);
+    }
+
+    #[test]
+    fn test_fill_replaces_named_placeholders_once() {
+        assert_eq!(
+            fill(
+                "{count} of {name} ({unknown})",
+                &[("name", "{count}"), ("count", "3")]
+            ),
+            "3 of {count} ({unknown})"
+        );
+    }
+
+    #[test]
+    fn test_relative_day() {
+        let messages = Locale::En.messages();
+        assert_eq!(messages.relative_day(-1), Some("Yesterday"));
+        assert_eq!(messages.relative_day(2), Some("In 2 days"));
+        assert_eq!(messages.relative_day(-2), None);
+        assert_eq!(messages.relative_day(3), None);
+    }
+}
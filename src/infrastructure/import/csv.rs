@@ -0,0 +1,254 @@
+//! CSVからの予約の読み込み
+//!
+//! 1行目はヘッダーで、`owner_email`, `resource`, `start`, `end`, `notes` の列を読み込みます（`notes` は省略可、列の順序は問わない）。
+//!
+//! - `resource`: リソース名（大文字・小文字は区別しない）。サーバーは `Thalys:0-1` のようにデバイスを指定でき、
+//!   省略するとすべてのデバイスを予約する。ストレージは `scratch:500G`、ライセンスは `matlab:2` のように指定する。
+//!   複数のリソースは `;` で区切る。
+//! - `start` / `end`: `YYYY-MM-DD HH:MM`（リソース設定のタイムゾーン）またはRFC 3339形式
+
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::reservation_import::{
+    ImportError, ImportRow, ReservationDraft, ReservationSource,
+};
+use crate::infrastructure::config::ResourceConfig;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// CSVファイルから取り込む予約を読み込む
+pub struct CsvReservationSource {
+    path: PathBuf,
+    config: ResourceConfig,
+}
+
+impl CsvReservationSource {
+    /// 新しいCsvReservationSourceを作成
+    ///
+    /// # Arguments
+    /// * `path` - 読み込むCSVファイル
+    /// * `config` - リソース名の解決と時刻の解釈に使うリソース設定
+    pub fn new(path: PathBuf, config: ResourceConfig) -> Self {
+        Self { path, config }
+    }
+}
+
+#[async_trait]
+impl ReservationSource for CsvReservationSource {
+    async fn read(&self) -> Result<Vec<ImportRow>, ImportError> {
+        let content = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(|e| ImportError::ReadFailure(format!("{}: {}", self.path.display(), e)))?;
+        parse_rows(&content, &self.config)
+    }
+}
+
+/// CSVの内容を行ごとの予約に変換する
+fn parse_rows(content: &str, config: &ResourceConfig) -> Result<Vec<ImportRow>, ImportError> {
+    let mut records = parse_csv(content.trim_start_matches('\u{feff}'))?.into_iter();
+    let (_, header) = records
+        .next()
+        .ok_or_else(|| ImportError::InvalidFormat("ヘッダー行がありません".to_string()))?;
+    let columns: HashMap<String, usize> = header
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (name.trim().to_ascii_lowercase(), index))
+        .collect();
+    let column = |name: &str| {
+        columns
+            .get(name)
+            .copied()
+            .ok_or_else(|| ImportError::InvalidFormat(format!("{} 列がありません", name)))
+    };
+    let owner_email = column("owner_email")?;
+    let resource = column("resource")?;
+    let start = column("start")?;
+    let end = column("end")?;
+    let notes = columns.get("notes").copied();
+
+    let timezone = config
+        .timezone
+        .as_deref()
+        .and_then(|tz| tz.parse::<Tz>().ok());
+    Ok(records
+        .filter(|(_, fields)| fields.iter().any(|field| !field.trim().is_empty()))
+        .map(|(line, fields)| {
+            let field = |index: usize| fields.get(index).map(|f| f.trim()).unwrap_or_default();
+            let draft = (|| {
+                let owner_email =
+                    EmailAddress::new(field(owner_email).to_string()).map_err(|e| e.to_string())?;
+                let resources = config.resolve_resources(field(resource))?;
+                let time_period = TimePeriod::new(
+                    parse_time(field(start), timezone)?,
+                    parse_time(field(end), timezone)?,
+                )
+                .map_err(|e| e.to_string())?;
+                let notes = notes
+                    .map(field)
+                    .filter(|notes| !notes.is_empty())
+                    .map(str::to_string);
+                Ok(ReservationDraft {
+                    owner_email,
+                    time_period,
+                    resources,
+                    notes,
+                })
+            })();
+            ImportRow { line, draft }
+        })
+        .collect())
+}
+
+/// CSVをレコードに分割する（RFC 4180）
+///
+/// 各レコードの開始行番号（1始まり）とフィールドを返す。引用符で囲んだフィールドには改行を含められる。
+fn parse_csv(content: &str) -> Result<Vec<(usize, Vec<String>)>, ImportError> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut fields)));
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(ImportError::InvalidFormat(format!(
+            "{}行目の引用符が閉じられていません",
+            record_line
+        )));
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        records.push((record_line, fields));
+    }
+
+    Ok(records)
+}
+
+/// 日時を解釈する（`YYYY-MM-DD HH:MM` はリソース設定のタイムゾーン、未設定の場合はUTC）
+fn parse_time(value: &str, timezone: Option<Tz>) -> Result<DateTime<Utc>, String> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M")
+        .map_err(|_| format!("日時を解釈できません: {}", value))?;
+    let datetime = match timezone {
+        Some(tz) => tz
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|datetime| datetime.with_timezone(&Utc)),
+        None => Some(naive.and_utc()),
+    };
+    datetime.ok_or_else(|| format!("存在しない日時です: {}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource};
+
+    const CONFIG: &str = r#"
+timezone = "Asia/Tokyo"
+
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@group.calendar.google.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers.devices]]
+id = 1
+model = "A100"
+
+[[rooms]]
+name = "Lecture Room"
+calendar_id = "room@group.calendar.google.com"
+notifications = []
+"#;
+
+    #[test]
+    fn test_parse_rows() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        let content = "owner_email,resource,start,end,notes\n\
+            teacher@example.com,lecture room,2025-04-08 09:00,2025-04-08 10:30,\"Lecture 1, intro\"\n\
+            student@example.com,Thalys:1,2025-04-08T00:00:00Z,2025-04-08T02:00:00Z,\n\
+            nobody@example.com,Unknown,2025-04-08 09:00,2025-04-08 10:00,\n";
+
+        let rows = parse_rows(content, &config).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        let lecture = rows[0].draft.as_ref().unwrap();
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(
+            lecture.resources,
+            vec![Resource::Room {
+                name: "Lecture Room".to_string()
+            }]
+        );
+        assert_eq!(
+            lecture.time_period.start(),
+            Utc.with_ymd_and_hms(2025, 4, 8, 0, 0, 0).unwrap()
+        );
+        assert_eq!(lecture.notes.as_deref(), Some("Lecture 1, intro"));
+
+        let gpu = rows[1].draft.as_ref().unwrap();
+        assert_eq!(
+            gpu.resources,
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                1,
+                "A100".to_string()
+            ))]
+        );
+        assert!(gpu.notes.is_none());
+
+        assert_eq!(rows[2].line, 4);
+        assert!(rows[2].draft.as_ref().unwrap_err().contains("Unknown"));
+    }
+
+    #[test]
+    fn test_parse_rows_requires_columns() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+
+        assert!(matches!(
+            parse_rows("owner_email,start,end\n", &config),
+            Err(ImportError::InvalidFormat(_))
+        ));
+    }
+}
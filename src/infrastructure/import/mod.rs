@@ -0,0 +1,10 @@
+//! # ReservationSource Implementations
+//!
+//! ReservationSourceポートの具象実装を提供します。
+//!
+//! - `csv`: CSVファイルから取り込む予約を読み込む実装
+
+/// CSVからの予約の読み込み実装
+pub mod csv;
+
+pub use csv::CsvReservationSource;
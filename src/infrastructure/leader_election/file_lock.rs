@@ -0,0 +1,91 @@
+use crate::domain::ports::leader_election::{LeaderElection, LeaderElectionError};
+use async_trait::async_trait;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// ファイルロックでリーダーを選ぶ実装
+///
+/// 同じホスト（または排他ロックに対応した共有ファイルシステム）で動くインスタンス向け。
+/// ロックを取得したインスタンスは、プロセスが終了するかロックを手放すまでリーダーであり続ける。
+pub struct FileLockLeaderElection {
+    path: PathBuf,
+    /// ロックを保持しているファイル（リーダーの間だけ開いておく）
+    locked: Mutex<Option<File>>,
+}
+
+impl FileLockLeaderElection {
+    /// 新しいインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `path` - ロックファイルのパス（すべてのインスタンスで同じパスを指定する）
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            locked: Mutex::new(None),
+        }
+    }
+}
+
+#[async_trait]
+impl LeaderElection for FileLockLeaderElection {
+    async fn try_acquire(&self) -> Result<bool, LeaderElectionError> {
+        let mut locked = self.locked.lock().expect("lock poisoned");
+        if locked.is_some() {
+            return Ok(true);
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                LeaderElectionError::ConnectionError(format!("{}: {}", parent.display(), e))
+            })?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&self.path)
+            .map_err(|e| {
+                LeaderElectionError::ConnectionError(format!("{}: {}", self.path.display(), e))
+            })?;
+
+        match file.try_lock() {
+            Ok(()) => {
+                *locked = Some(file);
+                Ok(true)
+            }
+            Err(TryLockError::WouldBlock) => Ok(false),
+            Err(TryLockError::Error(e)) => Err(LeaderElectionError::ConnectionError(format!(
+                "{}: {}",
+                self.path.display(),
+                e
+            ))),
+        }
+    }
+
+    async fn release(&self) -> Result<(), LeaderElectionError> {
+        // ファイルを閉じるとロックも解放される
+        self.locked.lock().expect("lock poisoned").take();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_only_one_instance_holds_the_lock() {
+        let path = std::env::temp_dir().join(format!("lrm_leader_{}.lock", uuid::Uuid::new_v4()));
+        let first = FileLockLeaderElection::new(path.clone());
+        let second = FileLockLeaderElection::new(path);
+
+        assert!(first.try_acquire().await.unwrap());
+        assert!(first.try_acquire().await.unwrap());
+        assert!(!second.try_acquire().await.unwrap());
+
+        first.release().await.unwrap();
+        assert!(second.try_acquire().await.unwrap());
+        assert!(!first.try_acquire().await.unwrap());
+    }
+}
@@ -0,0 +1,16 @@
+//! # Leader Election Implementations
+//!
+//! LeaderElectionポートの具象実装を提供します。
+//!
+//! - `file_lock`: ファイルロックで選ぶ実装（同じホストのインスタンス向け）
+//! - `redis`: Redisのキーの有効期限で選ぶ実装（別々のホストのインスタンス向け）
+//!
+//! どの実装を使うかは環境変数 `LEADER_ELECTION` で選ぶ（未設定の場合はリーダー選出を行わない）。
+
+/// ファイルロックで選ぶ実装
+pub mod file_lock;
+/// Redisで選ぶ実装
+pub mod redis;
+
+pub use file_lock::FileLockLeaderElection;
+pub use redis::RedisLeaderElection;
@@ -0,0 +1,251 @@
+use crate::domain::ports::leader_election::{LeaderElection, LeaderElectionError};
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+/// Redisとの1回のやり取りのタイムアウト
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// ロックが空いていれば取得し、自分が保持していれば期限を延長する
+const ACQUIRE_SCRIPT: &str = r#"
+local holder = redis.call('get', KEYS[1])
+if holder == false then
+  redis.call('set', KEYS[1], ARGV[1], 'PX', ARGV[2])
+  return 1
+end
+if holder == ARGV[1] then
+  redis.call('pexpire', KEYS[1], ARGV[2])
+  return 1
+end
+return 0
+"#;
+
+/// 自分が保持している場合だけロックを削除する
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('get', KEYS[1]) == ARGV[1] then
+  return redis.call('del', KEYS[1])
+end
+return 0
+"#;
+
+/// Redisのキーの有効期限でリーダーを選ぶ実装
+///
+/// 別々のホストで動くインスタンス向け。リーダーはポーリングのたびにキーの期限を延長し、
+/// リーダーが停止して期限が切れると、待機中のインスタンスがキーを取得してリーダーになる。
+/// 呼び出しごとに接続する（TLS接続には対応しない）。
+pub struct RedisLeaderElection {
+    address: String,
+    username: Option<String>,
+    password: Option<String>,
+    database: Option<u32>,
+    key: String,
+    lease: Duration,
+    /// このインスタンスを識別する値（キーの値として保存する）
+    holder_id: String,
+}
+
+impl RedisLeaderElection {
+    /// 新しいインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `url` - RedisのURL（例: `redis://:password@redis.example.ac.jp:6379/0`）
+    /// * `key` - ロックに使うキー（すべてのインスタンスで同じキーを指定する）
+    /// * `lease` - ロックの有効期限（ポーリング間隔より長くする）
+    pub fn new(url: &str, key: &str, lease: Duration) -> Result<Self, LeaderElectionError> {
+        let invalid = |reason: &str| LeaderElectionError::Invalid(format!("{}: {}", url, reason));
+        let parsed = reqwest::Url::parse(url).map_err(|e| invalid(&e.to_string()))?;
+        if parsed.scheme() != "redis" {
+            return Err(invalid("redis:// から始まるURLを指定してください"));
+        }
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| invalid("ホスト名がありません"))?;
+        let database = match parsed.path().trim_start_matches('/') {
+            "" => None,
+            db => Some(
+                db.parse::<u32>()
+                    .map_err(|_| invalid("データベース番号が不正です"))?,
+            ),
+        };
+
+        Ok(Self {
+            address: format!("{}:{}", host, parsed.port().unwrap_or(6379)),
+            username: Some(parsed.username().to_string()).filter(|u| !u.is_empty()),
+            password: parsed.password().map(str::to_string),
+            database,
+            key: key.to_string(),
+            lease,
+            holder_id: uuid::Uuid::new_v4().simple().to_string(),
+        })
+    }
+
+    /// スクリプトを実行して整数の結果を返す
+    async fn eval(&self, script: &str, args: &[&str]) -> Result<i64, LeaderElectionError> {
+        let run = async {
+            let stream = TcpStream::connect(&self.address).await?;
+            let mut stream = BufReader::new(stream);
+
+            if let Some(password) = &self.password {
+                let mut auth = vec!["AUTH"];
+                if let Some(username) = &self.username {
+                    auth.push(username);
+                }
+                auth.push(password);
+                command(&mut stream, &auth).await?;
+            }
+            if let Some(database) = self.database {
+                command(&mut stream, &["SELECT", &database.to_string()]).await?;
+            }
+
+            let mut eval = vec!["EVAL", script, "1", &self.key];
+            eval.extend_from_slice(args);
+            command(&mut stream, &eval).await
+        };
+
+        let reply = tokio::time::timeout(TIMEOUT, run)
+            .await
+            .map_err(|_| {
+                LeaderElectionError::ConnectionError(format!(
+                    "Redis ({}) の応答がタイムアウトしました",
+                    self.address
+                ))
+            })?
+            .map_err(|e| {
+                LeaderElectionError::ConnectionError(format!("Redis ({}): {}", self.address, e))
+            })?;
+
+        match reply {
+            Reply::Integer(value) => Ok(value),
+            other => Err(LeaderElectionError::Invalid(format!(
+                "Redisの応答を解釈できません: {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl LeaderElection for RedisLeaderElection {
+    async fn try_acquire(&self) -> Result<bool, LeaderElectionError> {
+        let lease_ms = self.lease.as_millis().to_string();
+        let acquired = self
+            .eval(ACQUIRE_SCRIPT, &[&self.holder_id, &lease_ms])
+            .await?;
+        Ok(acquired == 1)
+    }
+
+    async fn release(&self) -> Result<(), LeaderElectionError> {
+        self.eval(RELEASE_SCRIPT, &[&self.holder_id]).await?;
+        Ok(())
+    }
+}
+
+/// Redisの応答（このモジュールで使う型のみ）
+#[derive(Debug, PartialEq)]
+enum Reply {
+    Simple(String),
+    Integer(i64),
+    Bulk(Option<Vec<u8>>),
+}
+
+/// コマンドを送信し、応答を読む（エラー応答は `Err` にする）
+async fn command(stream: &mut BufReader<TcpStream>, args: &[&str]) -> std::io::Result<Reply> {
+    stream.get_mut().write_all(&encode(args)).await?;
+    read_reply(stream).await
+}
+
+/// コマンドをRESPの配列にエンコードする
+fn encode(args: &[&str]) -> Vec<u8> {
+    let mut out = format!("*{}\r\n", args.len()).into_bytes();
+    for arg in args {
+        out.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+        out.extend_from_slice(arg.as_bytes());
+        out.extend_from_slice(b"\r\n");
+    }
+    out
+}
+
+async fn read_reply(reader: &mut (impl AsyncBufRead + Unpin)) -> std::io::Result<Reply> {
+    let invalid = |msg: String| std::io::Error::new(std::io::ErrorKind::InvalidData, msg);
+
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "接続が閉じられました",
+        ));
+    }
+    let line = line.trim_end_matches("\r\n");
+    let (kind, body) = line.split_at(line.len().min(1));
+
+    match kind {
+        "+" => Ok(Reply::Simple(body.to_string())),
+        "-" => Err(std::io::Error::other(body.to_string())),
+        ":" => body
+            .parse()
+            .map(Reply::Integer)
+            .map_err(|_| invalid(format!("整数の応答が不正です: {}", line))),
+        "_" => Ok(Reply::Bulk(None)),
+        "$" => {
+            let len: i64 = body
+                .parse()
+                .map_err(|_| invalid(format!("応答の長さが不正です: {}", line)))?;
+            if len < 0 {
+                return Ok(Reply::Bulk(None));
+            }
+            let mut data = vec![0; len as usize + 2];
+            reader.read_exact(&mut data).await?;
+            data.truncate(len as usize);
+            Ok(Reply::Bulk(Some(data)))
+        }
+        _ => Err(invalid(format!("未対応の応答です: {}", line))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_command() {
+        assert_eq!(
+            encode(&["SELECT", "0"]),
+            b"*2\r\n$6\r\nSELECT\r\n$1\r\n0\r\n".to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_reply() {
+        let mut input: &[u8] = b"+OK\r\n:1\r\n$5\r\nhello\r\n$-1\r\n-ERR wrong password\r\n";
+        let mut reader = BufReader::new(&mut input);
+
+        assert_eq!(
+            read_reply(&mut reader).await.unwrap(),
+            Reply::Simple("OK".to_string())
+        );
+        assert_eq!(read_reply(&mut reader).await.unwrap(), Reply::Integer(1));
+        assert_eq!(
+            read_reply(&mut reader).await.unwrap(),
+            Reply::Bulk(Some(b"hello".to_vec()))
+        );
+        assert_eq!(read_reply(&mut reader).await.unwrap(), Reply::Bulk(None));
+        assert!(read_reply(&mut reader).await.is_err());
+    }
+
+    #[test]
+    fn test_new_parses_url() {
+        let election = RedisLeaderElection::new(
+            "redis://:secret@redis.example.ac.jp/2",
+            "lrm:leader",
+            Duration::from_secs(30),
+        )
+        .unwrap();
+
+        assert_eq!(election.address, "redis.example.ac.jp:6379");
+        assert_eq!(election.username, None);
+        assert_eq!(election.password.as_deref(), Some("secret"));
+        assert_eq!(election.database, Some(2));
+        assert!(RedisLeaderElection::new("http://redis", "k", Duration::from_secs(1)).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! JSON形式のログ出力
+//!
+//! 1件のログを1行のJSONオブジェクトとして出力する。
+//! イベントのフィールドに加えて、イベントを囲むスパンのフィールド（`correlation_id` など）も
+//! 同じオブジェクトに展開する。
+
+use chrono::{SecondsFormat, Utc};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::io::Write;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// スパンに記録されたフィールド
+#[derive(Default)]
+struct SpanFields(Map<String, Value>);
+
+/// 1行1オブジェクトのJSON形式でログを出力するレイヤー
+pub struct JsonLayer<W = fn() -> std::io::Stdout> {
+    make_writer: W,
+}
+
+impl JsonLayer {
+    /// 標準出力に書き出すレイヤーを作成
+    pub fn new() -> Self {
+        Self {
+            make_writer: std::io::stdout,
+        }
+    }
+}
+
+impl Default for JsonLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> JsonLayer<W>
+where
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    /// 出力先を指定してレイヤーを作成
+    pub fn with_writer(make_writer: W) -> Self {
+        Self { make_writer }
+    }
+}
+
+impl<S, W> Layer<S> for JsonLayer<W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = SpanFields::default();
+        attrs.record(&mut JsonVisitor(&mut fields.0));
+        span.extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(fields) = extensions.get_mut::<SpanFields>() {
+            values.record(&mut JsonVisitor(&mut fields.0));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut line = Map::new();
+        line.insert(
+            "timestamp".to_string(),
+            Value::String(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)),
+        );
+        line.insert(
+            "level".to_string(),
+            Value::String(metadata.level().to_string()),
+        );
+        line.insert(
+            "target".to_string(),
+            Value::String(metadata.target().to_string()),
+        );
+
+        if let Some(scope) = ctx.event_scope(event) {
+            let mut spans = Vec::new();
+            for span in scope.from_root() {
+                spans.push(Value::String(span.name().to_string()));
+                if let Some(fields) = span.extensions().get::<SpanFields>() {
+                    line.extend(fields.0.clone());
+                }
+            }
+            line.insert("spans".to_string(), Value::Array(spans));
+        }
+
+        event.record(&mut JsonVisitor(&mut line));
+
+        let mut writer = self.make_writer.make_writer();
+        let _ = writeln!(writer, "{}", Value::Object(line));
+    }
+}
+
+/// フィールドをJSONの値として記録する
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0
+            .insert(field.name().to_string(), Value::from(value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(
+            field.name().to_string(),
+            Value::from(format!("{:?}", value)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_event_includes_span_fields() {
+        let buffer = Buffer::default();
+        let subscriber =
+            tracing_subscriber::registry().with(JsonLayer::with_writer(buffer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("poll", correlation_id = "abc123");
+            let _guard = span.enter();
+            tracing::warn!(usage_id = "u1", "通知の送信に失敗しました");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let line: Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(line["level"], "WARN");
+        assert_eq!(line["message"], "通知の送信に失敗しました");
+        assert_eq!(line["correlation_id"], "abc123");
+        assert_eq!(line["usage_id"], "u1");
+        assert_eq!(line["spans"], serde_json::json!(["poll"]));
+    }
+}
@@ -0,0 +1,90 @@
+//! # Logging
+//!
+//! `tracing` のログ出力を初期化します。
+//!
+//! - `json`: 1行1オブジェクトのJSON形式でログを出力するレイヤー
+//! - `ops_alert`: ERRORレベルのログを運用エラーとしてSlackチャンネルに投稿するレイヤー
+//!
+//! ログの出力先は標準出力（MCPサーバーでは標準エラー出力）で、出力するレベルは `RUST_LOG` で指定する（デフォルト: info）。
+//! ポーリング1回やSlackのインタラクション1件ごとのスパンに `correlation_id` を付けるため、
+//! 一連の処理のログを `correlation_id` で検索できる。
+
+/// JSON形式のログ出力
+pub mod json;
+/// 運用エラーのSlackチャンネルへの通知
+pub mod ops_alert;
+
+pub use json::JsonLayer;
+pub use ops_alert::OpsAlertLayer;
+
+use crate::infrastructure::config::ConfigLoadError;
+use std::env;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// ログの形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// 人が読むためのテキスト形式
+    #[default]
+    Text,
+    /// 1行1オブジェクトのJSON形式（ログ収集基盤向け）
+    Json,
+}
+
+impl LogFormat {
+    /// 環境変数 `LOG_FORMAT`（text または json）からログの形式を読み込む
+    pub fn from_env() -> Result<Self, ConfigLoadError> {
+        let Ok(value) = env::var("LOG_FORMAT") else {
+            return Ok(Self::default());
+        };
+
+        match value.trim().to_lowercase().as_str() {
+            "text" | "" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(ConfigLoadError::InvalidEnvVar {
+                name: "LOG_FORMAT",
+                reason: "text または json である必要があります".to_string(),
+            }),
+        }
+    }
+}
+
+/// ログ出力を初期化する
+///
+/// 既に初期化されている場合は何もしない。
+/// 運用エラーの投稿は、設定を読み込んだ後に [`ops_alert::start`] で開始する。
+pub fn init(format: LogFormat) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let registry = tracing_subscriber::registry()
+        .with(filter)
+        .with(OpsAlertLayer::new());
+    let _ = match format {
+        LogFormat::Text => registry.with(tracing_subscriber::fmt::layer()).try_init(),
+        LogFormat::Json => registry.with(JsonLayer::new()).try_init(),
+    };
+}
+
+/// ログ出力を標準エラー出力に初期化する
+///
+/// 標準出力をプロトコルに使うMCPサーバー向け。それ以外は [`init`] と同じ。
+pub fn init_stderr(format: LogFormat) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let registry = tracing_subscriber::registry()
+        .with(filter)
+        .with(OpsAlertLayer::new());
+    let _ = match format {
+        LogFormat::Text => registry
+            .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+            .try_init(),
+        LogFormat::Json => registry
+            .with(JsonLayer::with_writer(std::io::stderr))
+            .try_init(),
+    };
+}
+
+/// 一連の処理を結び付けるための相関ID
+pub fn correlation_id() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
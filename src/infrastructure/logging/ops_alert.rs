@@ -0,0 +1,242 @@
+//! 運用エラーのSlackチャンネルへの通知
+//!
+//! ERRORレベルのログ（設定の読み込み失敗、マッピングファイルの破損、外部APIの失敗など）を
+//! `ops_channel_id` のSlackチャンネルに投稿し、サーバーのログを見なくても管理者が問題に気付けるようにする。
+//!
+//! 同じエラー（数字を除いて同じ文面）は1時間に1回だけ投稿し、その間に繰り返した回数を次の投稿に添える。
+
+use crate::infrastructure::rate_limit::RateLimiter;
+use slack_morphism::prelude::*;
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber, warn};
+use tracing_subscriber::layer::{Context, Layer};
+
+/// 同じエラーを再び投稿するまでの間隔
+const DEDUP_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// 投稿待ちのエラーの上限（超えた分は捨てる）
+const QUEUE_CAPACITY: usize = 100;
+
+/// 投稿するエラーの送り先（`start` を呼ぶまでは未設定で、エラーは捨てる）
+static ALERTS: OnceLock<mpsc::Sender<Alert>> = OnceLock::new();
+
+/// 投稿するエラー
+#[derive(Debug)]
+struct Alert {
+    target: String,
+    message: String,
+}
+
+/// 運用エラーの投稿を開始する
+///
+/// 2回目以降の呼び出しは何もしない。
+///
+/// # Arguments
+/// * `bot_token` - Bot User OAuth Token (xoxb-...)
+/// * `channel_id` - エラーを投稿するチャンネルID
+pub fn start(bot_token: String, channel_id: String) {
+    let (sender, mut receiver) = mpsc::channel::<Alert>(QUEUE_CAPACITY);
+    if ALERTS.set(sender).is_err() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let slack_client = match SlackClientHyperConnector::new() {
+            Ok(connector) => SlackClient::new(connector),
+            Err(e) => {
+                warn!("⚠️  運用エラーの投稿を開始できません: {}", e);
+                return;
+            }
+        };
+        let bot_token = SlackApiToken::new(bot_token.into());
+        let mut throttle = AlertThrottle::new(DEDUP_WINDOW);
+
+        while let Some(alert) = receiver.recv().await {
+            let Some(repeated) = throttle.admit(&dedup_key(&alert), Instant::now()) else {
+                continue;
+            };
+            let request = SlackApiChatPostMessageRequest::new(
+                SlackChannelId::new(channel_id.clone()),
+                SlackMessageContent::new().with_text(alert_message(&alert, repeated)),
+            );
+            let session = slack_client.open_session(&bot_token);
+            // ERRORで記録すると再び投稿の対象になるため、WARNで記録する
+            if let Err(e) = RateLimiter::slack()
+                .call(|| session.chat_post_message(&request))
+                .await
+            {
+                warn!("⚠️  運用エラーの投稿に失敗しました: {}", e);
+            }
+        }
+    });
+}
+
+/// ERRORレベルのログを運用エラーとして投稿するレイヤー
+///
+/// このクレートのログだけを対象にする。
+#[derive(Default)]
+pub struct OpsAlertLayer;
+
+impl OpsAlertLayer {
+    /// 新しいレイヤーを作成
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S: Subscriber> Layer<S> for OpsAlertLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if *metadata.level() != Level::ERROR
+            || !metadata.target().starts_with(env!("CARGO_CRATE_NAME"))
+        {
+            return;
+        }
+        let Some(alerts) = ALERTS.get() else {
+            return;
+        };
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let _ = alerts.try_send(Alert {
+            target: metadata.target().to_string(),
+            message: visitor.message,
+        });
+    }
+}
+
+/// ログのメッセージと、その他のフィールド（`key=value`）を1行にまとめる
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.message);
+            let _ = write!(self.message, "{:?}{}", value, fields);
+        } else {
+            let _ = write!(self.message, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// 同じエラーの投稿を間引く
+struct AlertThrottle {
+    window: Duration,
+    /// エラーごとの最後の投稿時刻と、その後に投稿しなかった回数
+    sent: HashMap<String, (Instant, u32)>,
+}
+
+impl AlertThrottle {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            sent: HashMap::new(),
+        }
+    }
+
+    /// 投稿する場合は、前回の投稿から投稿しなかった回数を返す
+    fn admit(&mut self, key: &str, now: Instant) -> Option<u32> {
+        self.sent
+            .retain(|_, (sent_at, _)| now.duration_since(*sent_at) < self.window * 2);
+
+        match self.sent.get_mut(key) {
+            Some((sent_at, suppressed)) if now.duration_since(*sent_at) < self.window => {
+                *suppressed += 1;
+                None
+            }
+            Some((sent_at, suppressed)) => {
+                let repeated = *suppressed;
+                *sent_at = now;
+                *suppressed = 0;
+                Some(repeated)
+            }
+            None => {
+                self.sent.insert(key.to_string(), (now, 0));
+                Some(0)
+            }
+        }
+    }
+}
+
+/// 同じエラーとみなすためのキー（IDや時刻などの数字の違いは無視する）
+fn dedup_key(alert: &Alert) -> String {
+    let mut key = format!("{}:", alert.target);
+    let mut in_digits = false;
+    for c in alert.message.chars() {
+        if c.is_ascii_digit() {
+            if !in_digits {
+                key.push('#');
+            }
+            in_digits = true;
+        } else {
+            key.push(c);
+            in_digits = false;
+        }
+    }
+    key
+}
+
+/// 投稿の本文を作成
+fn alert_message(alert: &Alert, repeated: u32) -> String {
+    let mut message = format!(
+        "🚨 *運用エラー*\n```{}```\n発生元: `{}`",
+        alert.message, alert.target
+    );
+    if repeated > 0 {
+        let _ = write!(
+            message,
+            "\n（前回の投稿以降、同じエラーが {} 回発生しました）",
+            repeated
+        );
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttle_suppresses_repeats_within_window() {
+        let mut throttle = AlertThrottle::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert_eq!(throttle.admit("a", start), Some(0));
+        assert_eq!(throttle.admit("a", start + Duration::from_secs(10)), None);
+        assert_eq!(
+            throttle.admit("b", start + Duration::from_secs(10)),
+            Some(0)
+        );
+        assert_eq!(throttle.admit("a", start + Duration::from_secs(30)), None);
+        assert_eq!(
+            throttle.admit("a", start + Duration::from_secs(61)),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_dedup_key_ignores_numbers() {
+        let alert = |message: &str| Alert {
+            target: "lab_resource_manager::interface".to_string(),
+            message: message.to_string(),
+        };
+
+        assert_eq!(
+            dedup_key(&alert("Google API 503: retry 1")),
+            dedup_key(&alert("Google API 502: retry 12"))
+        );
+        assert_ne!(
+            dedup_key(&alert("Google API 503")),
+            dedup_key(&alert("Slack API 503"))
+        );
+    }
+}
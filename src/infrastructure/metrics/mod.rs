@@ -0,0 +1,15 @@
+//! # Metrics
+//!
+//! 監視ループとBotの動作状況をPrometheusのテキスト形式で公開します。
+//! `metrics` フィーチャーを有効にした場合のみコンパイルされます。
+//!
+//! - `registry`: カウンター・ヒストグラムの集計とテキスト形式への変換
+//! - `server`: `/metrics` を返すHTTPサーバー
+
+/// メトリクスの集計
+pub mod registry;
+/// メトリクスを公開するHTTPサーバー
+pub mod server;
+
+pub use registry::{Metrics, metrics};
+pub use server::{METRICS_PATH, MetricsConfig, serve};
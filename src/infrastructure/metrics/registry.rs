@@ -0,0 +1,317 @@
+//! メトリクスの集計
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// メトリクス名の接頭辞
+const PREFIX: &str = "lab_resource_manager";
+
+/// ポーリング時間のヒストグラムのバケット（秒）
+const POLL_DURATION_BUCKETS: &[f64] = &[0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+/// スラッシュコマンドの処理時間のヒストグラムのバケット（秒）
+///
+/// Slackはコマンドへの応答を3秒まで待つため、その前後を細かく区切る。
+const COMMAND_DURATION_BUCKETS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.0, 3.0, 5.0];
+
+/// プロセス全体で共有するメトリクス
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::new)
+}
+
+/// 監視ループとBotのメトリクス
+pub struct Metrics {
+    poll_duration: Mutex<Histogram>,
+    poll_failures: AtomicU64,
+    events_fetched: AtomicU64,
+    reservations: AtomicU64,
+    /// 送信手段と結果（sent/failed）ごとの通知の件数
+    notifications: Mutex<BTreeMap<(String, &'static str), u64>>,
+    /// コマンドごとの処理時間
+    command_durations: Mutex<BTreeMap<String, Histogram>>,
+}
+
+impl Metrics {
+    /// 空のメトリクスを作成
+    pub fn new() -> Self {
+        Self {
+            poll_duration: Mutex::new(Histogram::new(POLL_DURATION_BUCKETS)),
+            poll_failures: AtomicU64::new(0),
+            events_fetched: AtomicU64::new(0),
+            reservations: AtomicU64::new(0),
+            notifications: Mutex::new(BTreeMap::new()),
+            command_durations: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// ポーリング1回の結果を記録
+    ///
+    /// 成功した場合は取得した予約の件数を、取得したイベントの累計と現在の予約数に反映する。
+    pub fn record_poll(&self, duration: Duration, fetched: Option<usize>) {
+        self.poll_duration.lock().unwrap().observe(duration);
+        match fetched {
+            Some(fetched) => {
+                self.events_fetched
+                    .fetch_add(fetched as u64, Ordering::Relaxed);
+                self.reservations.store(fetched as u64, Ordering::Relaxed);
+            }
+            None => {
+                self.poll_failures.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// 通知の送信結果を記録
+    ///
+    /// `sender` は送信手段の名前（slack, email, mock）。
+    pub fn record_notification(&self, sender: &str, succeeded: bool) {
+        let result = if succeeded { "sent" } else { "failed" };
+        *self
+            .notifications
+            .lock()
+            .unwrap()
+            .entry((sender.to_string(), result))
+            .or_insert(0) += 1;
+    }
+
+    /// スラッシュコマンドの処理時間を記録
+    pub fn record_command(&self, command: &str, duration: Duration) {
+        self.command_durations
+            .lock()
+            .unwrap()
+            .entry(command.to_string())
+            .or_insert_with(|| Histogram::new(COMMAND_DURATION_BUCKETS))
+            .observe(duration);
+    }
+
+    /// Prometheusのテキスト形式で出力
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        write_header(
+            &mut out,
+            "poll_duration_seconds",
+            "histogram",
+            "カレンダーのポーリング1回にかかった時間",
+        );
+        self.poll_duration
+            .lock()
+            .unwrap()
+            .render(&mut out, "poll_duration_seconds", "");
+
+        write_header(
+            &mut out,
+            "poll_failures_total",
+            "counter",
+            "失敗したポーリングの回数",
+        );
+        write_sample(
+            &mut out,
+            "poll_failures_total",
+            "",
+            self.poll_failures.load(Ordering::Relaxed),
+        );
+
+        write_header(
+            &mut out,
+            "events_fetched_total",
+            "counter",
+            "ポーリングで取得した予約の累計",
+        );
+        write_sample(
+            &mut out,
+            "events_fetched_total",
+            "",
+            self.events_fetched.load(Ordering::Relaxed),
+        );
+
+        write_header(
+            &mut out,
+            "reservations",
+            "gauge",
+            "直近のポーリングで取得した終了していない予約の件数",
+        );
+        write_sample(
+            &mut out,
+            "reservations",
+            "",
+            self.reservations.load(Ordering::Relaxed),
+        );
+
+        write_header(
+            &mut out,
+            "notifications_total",
+            "counter",
+            "送信手段と結果ごとの通知の件数",
+        );
+        for ((sender, result), count) in self.notifications.lock().unwrap().iter() {
+            write_sample(
+                &mut out,
+                "notifications_total",
+                &format!("sender=\"{}\",result=\"{}\"", escape(sender), result),
+                *count,
+            );
+        }
+
+        write_header(
+            &mut out,
+            "slack_command_duration_seconds",
+            "histogram",
+            "スラッシュコマンドの処理にかかった時間",
+        );
+        for (command, histogram) in self.command_durations.lock().unwrap().iter() {
+            histogram.render(
+                &mut out,
+                "slack_command_duration_seconds",
+                &format!("command=\"{}\"", escape(command)),
+            );
+        }
+
+        out
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 累積バケットを持つヒストグラム
+struct Histogram {
+    buckets: &'static [f64],
+    /// 各バケットの上限以下の観測数（`buckets` と同じ順）
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(buckets: &'static [f64]) -> Self {
+        Self {
+            buckets,
+            counts: vec![0; buckets.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        for (bound, count) in self.buckets.iter().zip(self.counts.iter_mut()) {
+            if seconds <= *bound {
+                *count += 1;
+            }
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let separator = if labels.is_empty() { "" } else { "," };
+        for (bound, count) in self.buckets.iter().zip(&self.counts) {
+            write_sample(
+                out,
+                &format!("{}_bucket", name),
+                &format!("{}{}le=\"{}\"", labels, separator, bound),
+                *count,
+            );
+        }
+        write_sample(
+            out,
+            &format!("{}_bucket", name),
+            &format!("{}{}le=\"+Inf\"", labels, separator),
+            self.count,
+        );
+        let _ = writeln!(
+            out,
+            "{}_{}_sum{} {}",
+            PREFIX,
+            name,
+            braces(labels),
+            self.sum
+        );
+        write_sample(out, &format!("{}_count", name), labels, self.count);
+    }
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {}_{} {}", PREFIX, name, help);
+    let _ = writeln!(out, "# TYPE {}_{} {}", PREFIX, name, kind);
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &str, value: u64) {
+    let _ = writeln!(out, "{}_{}{} {}", PREFIX, name, braces(labels), value);
+}
+
+fn braces(labels: &str) -> String {
+    if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    }
+}
+
+/// ラベルの値をエスケープ
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_poll_and_notifications() {
+        let metrics = Metrics::new();
+        metrics.record_poll(Duration::from_millis(300), Some(12));
+        metrics.record_poll(Duration::from_secs(3), None);
+        metrics.record_notification("slack", true);
+        metrics.record_notification("slack", true);
+        metrics.record_notification("email", false);
+
+        let text = metrics.render();
+
+        assert!(text.contains("# TYPE lab_resource_manager_poll_duration_seconds histogram"));
+        assert!(text.contains("lab_resource_manager_poll_duration_seconds_bucket{le=\"0.25\"} 0"));
+        assert!(text.contains("lab_resource_manager_poll_duration_seconds_bucket{le=\"0.5\"} 1"));
+        assert!(text.contains("lab_resource_manager_poll_duration_seconds_bucket{le=\"5\"} 2"));
+        assert!(text.contains("lab_resource_manager_poll_duration_seconds_bucket{le=\"+Inf\"} 2"));
+        assert!(text.contains("lab_resource_manager_poll_duration_seconds_count 2"));
+        assert!(text.contains("lab_resource_manager_poll_failures_total 1"));
+        assert!(text.contains("lab_resource_manager_events_fetched_total 12"));
+        assert!(text.contains("lab_resource_manager_reservations 12"));
+        assert!(text.contains(
+            "lab_resource_manager_notifications_total{sender=\"slack\",result=\"sent\"} 2"
+        ));
+        assert!(text.contains(
+            "lab_resource_manager_notifications_total{sender=\"email\",result=\"failed\"} 1"
+        ));
+    }
+
+    #[test]
+    fn test_render_command_durations() {
+        let metrics = Metrics::new();
+        metrics.record_command("/reserve", Duration::from_millis(80));
+        metrics.record_command("/reserve", Duration::from_millis(1500));
+
+        let text = metrics.render();
+
+        assert!(text.contains(
+            "lab_resource_manager_slack_command_duration_seconds_bucket{command=\"/reserve\",le=\"0.1\"} 1"
+        ));
+        assert!(text.contains(
+            "lab_resource_manager_slack_command_duration_seconds_bucket{command=\"/reserve\",le=\"2\"} 2"
+        ));
+        assert!(text.contains(
+            "lab_resource_manager_slack_command_duration_seconds_count{command=\"/reserve\"} 2"
+        ));
+    }
+}
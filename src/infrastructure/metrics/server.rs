@@ -0,0 +1,79 @@
+//! メトリクスを公開するHTTPサーバー
+
+use super::registry::metrics;
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::env;
+use tokio::net::TcpListener;
+use tracing::{error, info};
+
+/// メトリクスを返すパス
+pub const METRICS_PATH: &str = "/metrics";
+
+/// 待ち受けアドレスのデフォルト値
+const DEFAULT_LISTEN_ADDR: &str = "0.0.0.0:9898";
+
+/// Prometheusのテキスト形式のContent-Type
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// メトリクスのHTTPサーバーの設定
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsConfig {
+    /// 待ち受けアドレス
+    pub listen_addr: String,
+}
+
+impl MetricsConfig {
+    /// 環境変数からメトリクスの設定を読み込む
+    ///
+    /// - `METRICS_LISTEN_ADDR`: 待ち受けアドレス（デフォルト: 0.0.0.0:9898）
+    pub fn from_env() -> Self {
+        Self {
+            listen_addr: env::var("METRICS_LISTEN_ADDR")
+                .unwrap_or_else(|_| DEFAULT_LISTEN_ADDR.to_string()),
+        }
+    }
+}
+
+/// メトリクスのHTTPサーバーを実行
+///
+/// 待ち受けに失敗した場合のみ終了する。
+pub async fn serve(config: &MetricsConfig) -> Result<(), BoxError> {
+    let listener = TcpListener::bind(&config.listen_addr).await?;
+    info!(
+        "📈 メトリクスのHTTPサーバーを起動しました: {}{}",
+        config.listen_addr, METRICS_PATH
+    );
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service_fn(respond))
+                .await
+            {
+                error!("❌ メトリクスのHTTP接続のエラー: {}", e);
+            }
+        });
+    }
+}
+
+/// `/metrics` へのリクエストにメトリクスを返す
+async fn respond(req: Request<Incoming>) -> Result<Response<Full<Bytes>>, hyper::http::Error> {
+    if req.uri().path() != METRICS_PATH {
+        return Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Full::new(Bytes::from_static(b"Not Found")));
+    }
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(hyper::header::CONTENT_TYPE, CONTENT_TYPE)
+        .body(Full::new(Bytes::from(metrics().render())))
+}
@@ -11,7 +11,23 @@
 //!
 //! Infrastructure層はDomain層とApplication層に依存できる。
 //! 外部サービス（GoogleカレンダーAPI、Slack等）との統合を担当する。
+pub mod backup;
+#[cfg(feature = "chaos")]
+pub mod chaos;
 pub mod config;
+pub mod directory;
+pub mod export;
+pub mod google_auth;
+pub mod gpu_monitor;
+pub mod i18n;
+pub mod import;
+pub mod leader_election;
+pub mod logging;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod notifier;
+pub mod power_management;
+pub mod rate_limit;
 pub mod repositories;
 pub mod resource_collection_access;
+pub mod secrets;
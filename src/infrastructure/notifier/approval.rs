@@ -0,0 +1,125 @@
+//! 承認依頼の送信
+//!
+//! 承認が必要な部屋の予約を、承認・却下ボタン付きで承認者のSlackチャンネルに投稿します。
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::service::format_time_period;
+use crate::domain::ports::notifier::{ApprovalRequestSender, NotificationError};
+use crate::infrastructure::config::ResourceStyle;
+use crate::infrastructure::notifier::formatter::format_resources_styled;
+use crate::interface::slack::constants::{ACTION_APPROVE_RESERVATION, ACTION_REJECT_RESERVATION};
+use crate::interface::slack::slack_client::{self, messages};
+use async_trait::async_trait;
+use slack_morphism::prelude::*;
+
+/// Slackチャンネルに承認依頼を投稿する（Bot Token方式）
+pub struct SlackApprovalRequestSender {
+    bot_token: SlackApiToken,
+    /// 承認依頼を投稿するチャンネルID
+    channel_id: String,
+    /// 時刻の表示に使うタイムゾーン（IANA形式、未指定の場合はローカルタイムゾーン）
+    timezone: Option<String>,
+}
+
+impl SlackApprovalRequestSender {
+    /// 新しいSlackApprovalRequestSenderを作成
+    ///
+    /// # Arguments
+    /// * `bot_token` - Bot User OAuth Token (xoxb-...)
+    /// * `channel_id` - 承認依頼を投稿するチャンネルID
+    /// * `timezone` - 時刻の表示に使うタイムゾーン
+    pub fn new(bot_token: String, channel_id: String, timezone: Option<String>) -> Self {
+        Self {
+            bot_token: SlackApiToken::new(bot_token.into()),
+            channel_id,
+            timezone,
+        }
+    }
+}
+
+#[async_trait]
+impl ApprovalRequestSender for SlackApprovalRequestSender {
+    async fn request_approval(&self, usage: &ResourceUsage) -> Result<(), NotificationError> {
+        let session = slack_client::shared().open_session(&self.bot_token);
+
+        let message = approval_request_message(usage, self.timezone.as_deref());
+        let content = SlackMessageContent::new()
+            .with_text(message.clone())
+            .with_blocks(approval_request_blocks(usage, message));
+
+        messages::send_message(
+            &session,
+            SlackChannelId::new(self.channel_id.clone()),
+            content,
+        )
+        .await
+        .map_err(|e| NotificationError::SendFailure(format!("Slack API送信失敗: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// 承認依頼の本文を作成
+fn approval_request_message(usage: &ResourceUsage, timezone: Option<&str>) -> String {
+    let mut message = format!(
+        "📝 予約の承認依頼\n\n*予約者*\n{}\n\n*リソース*\n{}\n\n*期間*\n{}",
+        usage.owner_email().as_str(),
+        format_resources_styled(usage.resources(), ResourceStyle::Full),
+        format_time_period(usage.time_period(), timezone)
+    );
+    if let Some(project) = usage.metadata().project() {
+        message.push_str(&format!("\n\n*プロジェクト*\n{}", project));
+    }
+    if let Some(notes) = usage.notes() {
+        message.push_str(&format!("\n\n*備考*\n{}", notes));
+    }
+    message
+}
+
+/// 承認依頼のブロック（本文と承認・却下ボタン）を作成
+fn approval_request_blocks(usage: &ResourceUsage, message: String) -> Vec<SlackBlock> {
+    let usage_id = usage.id().as_str().to_string();
+    vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(message))),
+        SlackBlock::Actions(SlackActionsBlock::new(vec![
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(ACTION_APPROVE_RESERVATION.into(), pt!("✅ 承認"))
+                    .with_style("primary".to_string())
+                    .with_value(usage_id.clone()),
+            ),
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(ACTION_REJECT_RESERVATION.into(), pt!("❌ 却下"))
+                    .with_style("danger".to_string())
+                    .with_value(usage_id),
+            ),
+        ])),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+    use crate::domain::common::EmailAddress;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_approval_request_message() {
+        let start = Utc.with_ymd_and_hms(2025, 4, 1, 1, 0, 0).unwrap();
+        let usage = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, start + chrono::Duration::hours(1)).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            Some("面接".to_string()),
+        )
+        .unwrap();
+
+        let message = approval_request_message(&usage, Some("Asia/Tokyo"));
+
+        assert!(message.contains("user@example.com"));
+        assert!(message.contains("会議室A"));
+        assert!(message.ends_with("*備考*\n面接"));
+    }
+}
@@ -4,6 +4,7 @@
 
 use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
 use crate::infrastructure::config::{DateFormat, ResourceStyle, TimeStyle};
+use crate::infrastructure::i18n::Locale;
 use chrono::{Local, Utc};
 use chrono_tz::Tz;
 use std::collections::BTreeMap;
@@ -26,6 +27,7 @@ fn format_resources_full(resources: &[Resource]) -> String {
 fn format_resources_compact(resources: &[Resource]) -> String {
     let mut servers: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
     let mut rooms: Vec<&str> = Vec::new();
+    let mut pooled: Vec<String> = Vec::new();
 
     for resource in resources {
         match resource {
@@ -35,9 +37,17 @@ fn format_resources_compact(resources: &[Resource]) -> String {
                     .or_default()
                     .push(gpu.device_number());
             }
-            Resource::Room { name } => {
+            Resource::Room { name }
+            | Resource::Instrument { name }
+            | Resource::Custom { name, .. } => {
                 rooms.push(name);
             }
+            Resource::Storage { volume, gigabytes } => {
+                pooled.push(format!("{} {}GB", volume, gigabytes));
+            }
+            Resource::License { name, seats } => {
+                pooled.push(format!("{} x{}", name, seats));
+            }
         }
     }
 
@@ -57,6 +67,8 @@ fn format_resources_compact(resources: &[Resource]) -> String {
         parts.push(room.to_string());
     }
 
+    parts.extend(pooled);
+
     parts.join("\n")
 }
 
@@ -66,21 +78,31 @@ fn format_resources_server_only(resources: &[Resource]) -> String {
 
     let mut servers: BTreeSet<&str> = BTreeSet::new();
     let mut rooms: BTreeSet<&str> = BTreeSet::new();
+    let mut pooled: BTreeSet<&str> = BTreeSet::new();
 
     for resource in resources {
         match resource {
             Resource::Gpu(gpu) => {
                 servers.insert(gpu.server());
             }
-            Resource::Room { name } => {
+            Resource::Room { name }
+            | Resource::Instrument { name }
+            | Resource::Custom { name, .. } => {
                 rooms.insert(name);
             }
+            Resource::Storage { volume, .. } => {
+                pooled.insert(volume);
+            }
+            Resource::License { name, .. } => {
+                pooled.insert(name);
+            }
         }
     }
 
     servers
         .into_iter()
         .chain(rooms)
+        .chain(pooled)
         .collect::<Vec<_>>()
         .join("\n")
 }
@@ -91,11 +113,12 @@ pub fn format_time_styled(
     timezone_str: Option<&str>,
     style: TimeStyle,
     date_format: DateFormat,
+    locale: Locale,
 ) -> String {
     match style {
         TimeStyle::Full => format_time_full(period, timezone_str),
         TimeStyle::Smart => format_time_smart(period, timezone_str, date_format),
-        TimeStyle::Relative => format_time_relative(period, timezone_str, date_format),
+        TimeStyle::Relative => format_time_relative(period, timezone_str, date_format, locale),
     }
 }
 
@@ -139,6 +162,7 @@ fn format_time_relative(
     period: &TimePeriod,
     timezone_str: Option<&str>,
     date_format: DateFormat,
+    locale: Locale,
 ) -> String {
     let (start, end) = convert_to_timezone(period, timezone_str);
     let now = Utc::now();
@@ -157,12 +181,9 @@ fn format_time_relative(
     let start_date = start.date_naive();
     let days_diff = start_date.signed_duration_since(now_date).num_days();
 
-    let date_str = match days_diff {
-        0 => "今日".to_string(),
-        1 => "明日".to_string(),
-        2 => "明後日".to_string(),
-        -1 => "昨日".to_string(),
-        _ => start.format(date_format_string(date_format)).to_string(),
+    let date_str = match locale.messages().relative_day(days_diff) {
+        Some(relative) => relative.to_string(),
+        None => start.format(date_format_string(date_format)).to_string(),
     };
 
     if start.date_naive() == end.date_naive() {
@@ -265,6 +286,17 @@ mod tests {
         assert_eq!(result, "Thalys 0\n会議室A");
     }
 
+    #[test]
+    fn test_format_resources_compact_with_storage() {
+        let resources = vec![Resource::Storage {
+            volume: "scratch".to_string(),
+            gigabytes: 500,
+        }];
+
+        assert_eq!(format_resources_compact(&resources), "scratch 500GB");
+        assert_eq!(format_resources_server_only(&resources), "scratch");
+    }
+
     #[test]
     fn test_format_resources_server_only() {
         let resources = vec![
@@ -332,7 +364,7 @@ mod tests {
         // UTC 10:00-12:00 = JST 19:00-21:00
         let period = create_test_period(10, 12);
 
-        let result = format_time_relative(&period, Some("Asia/Tokyo"), DateFormat::Md);
+        let result = format_time_relative(&period, Some("Asia/Tokyo"), DateFormat::Md, Locale::Ja);
         // The relative format includes spaces around the hyphen
         assert!(
             result.contains(" - "),
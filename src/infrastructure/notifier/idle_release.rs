@@ -0,0 +1,157 @@
+//! 使われていない予約の確認・解放の通知
+//!
+//! 予約したGPUが使われていないことを、Slackのダイレクトメッセージで予約者本人に確認します。
+//! 確認には、使用を続けるボタンとその場で解放するボタンを付けます。
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::ports::notifier::{IdleReservationNotifier, NotificationError};
+use crate::infrastructure::config::ResourceStyle;
+use crate::infrastructure::notifier::formatter::format_resources_styled;
+use crate::interface::slack::constants::{
+    ACTION_KEEP_IDLE_RESERVATION, ACTION_RELEASE_RESERVATION,
+};
+use crate::interface::slack::slack_client::{self, messages};
+use crate::interface::slack::utility::datetime_parser::to_user_time;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use slack_morphism::prelude::*;
+
+/// Slackのダイレクトメッセージで使われていない予約を確認・通知する（Bot Token方式）
+pub struct SlackIdleReservationNotifier {
+    bot_token: SlackApiToken,
+    /// 解放時刻の表示に使うタイムゾーン（IANA形式、未指定の場合はローカルタイムゾーン）
+    timezone: Option<String>,
+}
+
+impl SlackIdleReservationNotifier {
+    /// 新しいSlackIdleReservationNotifierを作成
+    ///
+    /// # Arguments
+    /// * `bot_token` - Bot User OAuth Token (xoxb-...)
+    /// * `timezone` - 解放時刻の表示に使うタイムゾーン
+    pub fn new(bot_token: String, timezone: Option<String>) -> Self {
+        Self {
+            bot_token: SlackApiToken::new(bot_token.into()),
+            timezone,
+        }
+    }
+
+    /// 予約者とのDMに投稿する
+    async fn post(
+        &self,
+        user_id: &str,
+        content: SlackMessageContent,
+    ) -> Result<(), NotificationError> {
+        let session = slack_client::shared().open_session(&self.bot_token);
+        messages::send_direct_message(&session, &SlackUserId::new(user_id.to_string()), content)
+            .await
+            .map_err(|e| NotificationError::SendFailure(format!("Slack API送信失敗: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl IdleReservationNotifier for SlackIdleReservationNotifier {
+    async fn notify_idle(
+        &self,
+        user_id: &str,
+        usage: &ResourceUsage,
+        release_at: DateTime<Utc>,
+    ) -> Result<(), NotificationError> {
+        let timezone = self
+            .timezone
+            .as_deref()
+            .and_then(|tz| tz.parse::<Tz>().ok());
+        let message = idle_message(usage, release_at, timezone);
+        let content = SlackMessageContent::new()
+            .with_text(message.clone())
+            .with_blocks(idle_blocks(usage, message));
+        self.post(user_id, content).await
+    }
+
+    async fn notify_released(
+        &self,
+        user_id: &str,
+        usage: &ResourceUsage,
+        cancelled: bool,
+    ) -> Result<(), NotificationError> {
+        let content = SlackMessageContent::new().with_text(released_message(usage, cancelled));
+        self.post(user_id, content).await
+    }
+}
+
+/// 確認の本文を作成
+fn idle_message(usage: &ResourceUsage, release_at: DateTime<Utc>, timezone: Option<Tz>) -> String {
+    format!(
+        "💤 予約中のGPUが使われていません\n\n*リソース*\n{}\n\n使用を続ける場合は「使用中」を押してください。応答がない場合は {} に予約を解放します。",
+        format_resources_styled(usage.resources(), ResourceStyle::Full),
+        to_user_time(release_at, timezone).format("%Y-%m-%d %H:%M")
+    )
+}
+
+/// 確認のブロック（本文と使用中・解放ボタン）を作成
+fn idle_blocks(usage: &ResourceUsage, message: String) -> Vec<SlackBlock> {
+    let usage_id = usage.id().as_str().to_string();
+    vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(message))),
+        SlackBlock::Actions(SlackActionsBlock::new(vec![
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(ACTION_KEEP_IDLE_RESERVATION.into(), pt!("▶️ 使用中"))
+                    .with_value(usage_id.clone()),
+            ),
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(
+                    ACTION_RELEASE_RESERVATION.into(),
+                    pt!("⏹ 今すぐ解放"),
+                )
+                .with_value(usage_id),
+            ),
+        ])),
+    ]
+}
+
+/// 解放の通知の本文を作成
+fn released_message(usage: &ResourceUsage, cancelled: bool) -> String {
+    let action = if cancelled {
+        "予約を取り消しました"
+    } else {
+        "予約を終了しました"
+    };
+    format!(
+        "⏹ GPUが使われないままだったため、{}\n\n*リソース*\n{}",
+        action,
+        format_resources_styled(usage.resources(), ResourceStyle::Full)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource, TimePeriod};
+    use crate::domain::common::EmailAddress;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_idle_message() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 15, 1, 0, 0).unwrap();
+        let usage = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(now - Duration::hours(1), now + Duration::hours(2)).unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                0,
+                "A100".to_string(),
+            ))],
+            None,
+        )
+        .unwrap();
+
+        let message = idle_message(&usage, now + Duration::minutes(30), Some(Tz::Asia__Tokyo));
+
+        assert!(message.contains("Thalys"));
+        assert!(message.contains("2024-01-15 10:30 に予約を解放します"));
+        assert!(released_message(&usage, true).contains("予約を取り消しました"));
+    }
+}
@@ -2,18 +2,46 @@
 //!
 //! Notifierポートの具象実装を提供します。
 //!
+//! - `approval`: 承認が必要な予約の承認依頼の送信（Slackチャンネル）
+//! - `idle_release`: 使われていない予約の予約者への確認・解放の通知（SlackのDM）
+//! - `preemption`: 横取りされた予約の予約者への通知（SlackのDM）
 //! - `router`: リソース設定に基づいて複数の通知手段をオーケストレート
+//! - `reminder`: 予約者へのリマインダー送信（SlackのDM）
 //! - `senders`: 個別の送信手段の実装（Slack, Mock, Discord, Email等）
+//! - `unreserved_usage`: 予約なしでのGPUの使用の警告（Slackチャンネル）
+//! - `waitlist`: 空き待ちの希望者への空き通知（SlackのDM）
+//! - `weekly_digest`: 週間の利用状況のまとめの投稿（Slackチャンネル）
 //! - `formatter`: スタイル別フォーマット関数
 //! - `template_renderer`: テンプレートレンダリング
 
+/// 承認依頼送信実装
+pub mod approval;
 /// スタイル別フォーマット関数
 pub mod formatter;
+/// 使われていない予約の確認・解放の通知送信実装
+pub mod idle_release;
+/// 横取り通知送信実装
+pub mod preemption;
+/// リマインダー送信実装
+pub mod reminder;
 /// 通知ルーター実装
 pub mod router;
 /// 通知送信実装
 pub mod senders;
 /// テンプレートレンダリング
 pub mod template_renderer;
+/// 予約なしでのGPUの使用の警告送信実装
+pub mod unreserved_usage;
+/// 空き通知送信実装
+pub mod waitlist;
+/// 週間の利用状況のまとめ投稿実装
+pub mod weekly_digest;
 
+pub use approval::SlackApprovalRequestSender;
+pub use idle_release::SlackIdleReservationNotifier;
+pub use preemption::SlackPreemptionNotifier;
+pub use reminder::SlackReminderSender;
 pub use router::NotificationRouter;
+pub use unreserved_usage::SlackUnreservedUsageNotifier;
+pub use waitlist::SlackWaitlistNotifier;
+pub use weekly_digest::SlackWeeklyDigestSender;
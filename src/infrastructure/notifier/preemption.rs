@@ -0,0 +1,114 @@
+//! 横取りされた予約の予約者への通知
+//!
+//! 予約が優先度の高い予約のために取り消されたことを、次に空いている時間帯の提案を添えて
+//! Slackのダイレクトメッセージで予約者本人に知らせます。
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::service::format_time_period;
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::ports::notifier::{NotificationError, PreemptionNotifier};
+use crate::infrastructure::config::{I18nConfig, ResourceStyle};
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::infrastructure::notifier::formatter::format_resources_styled;
+use crate::interface::slack::slack_client::{self, messages};
+use crate::interface::slack::utility::user_resolver;
+use async_trait::async_trait;
+use slack_morphism::prelude::*;
+
+/// Slackのダイレクトメッセージで予約の横取りを知らせる（Bot Token方式）
+pub struct SlackPreemptionNotifier {
+    bot_token: SlackApiToken,
+    /// 時刻の表示に使うタイムゾーン（IANA形式、未指定の場合はローカルタイムゾーン）
+    ///
+    /// 予約者のSlackプロフィールにタイムゾーンが設定されている場合はそちらを優先する。
+    timezone: Option<String>,
+    /// 表示言語の設定
+    i18n: I18nConfig,
+}
+
+impl SlackPreemptionNotifier {
+    /// 新しいSlackPreemptionNotifierを作成
+    ///
+    /// # Arguments
+    /// * `bot_token` - Bot User OAuth Token (xoxb-...)
+    /// * `timezone` - 時刻の表示に使うタイムゾーン
+    /// * `i18n` - 表示言語の設定
+    pub fn new(bot_token: String, timezone: Option<String>, i18n: I18nConfig) -> Self {
+        Self {
+            bot_token: SlackApiToken::new(bot_token.into()),
+            timezone,
+            i18n,
+        }
+    }
+}
+
+#[async_trait]
+impl PreemptionNotifier for SlackPreemptionNotifier {
+    async fn notify_preempted(
+        &self,
+        user_id: &str,
+        preempted: &ResourceUsage,
+        preempting: &ResourceUsage,
+        suggestion: Option<&TimePeriod>,
+    ) -> Result<(), NotificationError> {
+        let user_id = SlackUserId::new(user_id.to_string());
+        let preferences = user_resolver::fetch_user_preferences(
+            slack_client::shared(),
+            &self.bot_token,
+            &user_id,
+            &self.i18n,
+        )
+        .await;
+        let timezone = preferences
+            .timezone
+            .map(|tz| tz.name().to_string())
+            .or_else(|| self.timezone.clone());
+
+        let content = SlackMessageContent::new().with_text(preempted_message(
+            preferences.messages(),
+            preempted,
+            preempting,
+            suggestion,
+            timezone.as_deref(),
+        ));
+
+        let session = slack_client::shared().open_session(&self.bot_token);
+        messages::send_direct_message(&session, &user_id, content)
+            .await
+            .map_err(|e| NotificationError::SendFailure(format!("Slack API送信失敗: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// 横取りの通知の本文を作成
+fn preempted_message(
+    messages: &Messages,
+    preempted: &ResourceUsage,
+    preempting: &ResourceUsage,
+    suggestion: Option<&TimePeriod>,
+    timezone: Option<&str>,
+) -> String {
+    let notice = fill(
+        messages.preempted_notice,
+        &[
+            ("priority", messages.priority(preempting.priority())),
+            (
+                "resources",
+                &format_resources_styled(preempted.resources(), ResourceStyle::Full),
+            ),
+            (
+                "time",
+                &format_time_period(preempted.time_period(), timezone),
+            ),
+        ],
+    );
+    let next = match suggestion {
+        Some(period) => fill(
+            messages.preempted_suggestion,
+            &[("time", &format_time_period(period, timezone))],
+        ),
+        None => messages.preempted_no_suggestion.to_string(),
+    };
+    format!("{}\n\n{}", notice, next)
+}
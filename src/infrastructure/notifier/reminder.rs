@@ -0,0 +1,178 @@
+//! 予約者へのリマインダー送信
+//!
+//! 予約の開始前・終了前のリマインダーを、Slackのダイレクトメッセージで予約者本人に送ります。
+//! 終了前のリマインダーには、その場で延長・解放・もう一度予約できるボタンを付けます。
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::service::format_time_period;
+use crate::domain::ports::notifier::{NotificationError, ReminderKind, ReminderSender};
+use crate::infrastructure::config::ResourceStyle;
+use crate::infrastructure::notifier::formatter::format_resources_styled;
+use crate::infrastructure::rate_limit::RateLimiter;
+use crate::interface::slack::constants::{
+    ACTION_CLONE_RESERVATION, ACTION_QUICK_EXTEND_RESERVATION, ACTION_RELEASE_RESERVATION,
+    QUICK_EXTEND_MINUTES,
+};
+use crate::interface::slack::slack_client::{self, messages};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use slack_morphism::prelude::*;
+use tracing::warn;
+
+/// Slackのダイレクトメッセージでリマインダーを送る（Bot Token方式）
+pub struct SlackReminderSender {
+    bot_token: SlackApiToken,
+    /// 時刻の表示に使うタイムゾーン（IANA形式、未指定の場合はローカルタイムゾーン）
+    ///
+    /// 予約者のSlackプロフィールにタイムゾーンが設定されている場合はそちらを優先する。
+    timezone: Option<String>,
+}
+
+impl SlackReminderSender {
+    /// 新しいSlackReminderSenderを作成
+    ///
+    /// # Arguments
+    /// * `bot_token` - Bot User OAuth Token (xoxb-...)
+    /// * `timezone` - 時刻の表示に使うタイムゾーン
+    pub fn new(bot_token: String, timezone: Option<String>) -> Self {
+        Self {
+            bot_token: SlackApiToken::new(bot_token.into()),
+            timezone,
+        }
+    }
+
+    /// 予約者のSlackプロフィールに設定されたタイムゾーン（IANA形式）を取得
+    ///
+    /// 取得できない場合は `None` を返す。
+    async fn user_timezone(
+        &self,
+        session: &SlackClientSession<'_, SlackClientHyperHttpsConnector>,
+        user_id: &str,
+    ) -> Option<String> {
+        let request = SlackApiUsersInfoRequest::new(SlackUserId::new(user_id.to_string()));
+        match RateLimiter::slack()
+            .call(|| session.users_info(&request))
+            .await
+        {
+            Ok(response) => response.user.tz,
+            Err(e) => {
+                warn!("⚠️ Slackプロフィールのタイムゾーン取得に失敗: {}", e);
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ReminderSender for SlackReminderSender {
+    async fn send_reminder(
+        &self,
+        user_id: &str,
+        usage: &ResourceUsage,
+        kind: ReminderKind,
+    ) -> Result<(), NotificationError> {
+        let session = slack_client::shared().open_session(&self.bot_token);
+
+        let content = match kind {
+            ReminderKind::Start => {
+                let timezone = self
+                    .user_timezone(&session, user_id)
+                    .await
+                    .or_else(|| self.timezone.clone());
+                SlackMessageContent::new()
+                    .with_text(start_reminder_message(usage, timezone.as_deref()))
+            }
+            ReminderKind::End => {
+                let message = end_reminder_message(usage, Utc::now());
+                SlackMessageContent::new()
+                    .with_text(message.clone())
+                    .with_blocks(end_reminder_blocks(usage, message))
+            }
+        };
+
+        messages::send_direct_message(&session, &SlackUserId::new(user_id.to_string()), content)
+            .await
+            .map_err(|e| NotificationError::SendFailure(format!("Slack API送信失敗: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// 開始前リマインダーの本文を作成
+fn start_reminder_message(usage: &ResourceUsage, timezone: Option<&str>) -> String {
+    format!(
+        "⏰ まもなく予約の開始時刻です\n\n*リソース*\n{}\n\n*期間*\n{}",
+        format_resources_styled(usage.resources(), ResourceStyle::Full),
+        format_time_period(usage.time_period(), timezone)
+    )
+}
+
+/// 終了前リマインダーの本文を作成（例: "⏰ Thalys 0,1 の予約はあと15分で終了します"）
+fn end_reminder_message(usage: &ResourceUsage, now: DateTime<Utc>) -> String {
+    // 端数は切り上げ、「あと0分」と表示しないようにする
+    let remaining_minutes = ((usage.time_period().end() - now).num_seconds() + 59) / 60;
+    format!(
+        "⏰ {} の予約はあと{}分で終了します",
+        format_resources_styled(usage.resources(), ResourceStyle::Compact).replace('\n', ", "),
+        remaining_minutes.max(1)
+    )
+}
+
+/// 終了前リマインダーのブロック（本文と延長・解放・もう一度予約ボタン）を作成
+fn end_reminder_blocks(usage: &ResourceUsage, message: String) -> Vec<SlackBlock> {
+    let usage_id = usage.id().as_str().to_string();
+    vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(message))),
+        SlackBlock::Actions(SlackActionsBlock::new(vec![
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(
+                    ACTION_QUICK_EXTEND_RESERVATION.into(),
+                    pt!(format!("⏩ {}分延長", QUICK_EXTEND_MINUTES)),
+                )
+                .with_value(usage_id.clone()),
+            ),
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(
+                    ACTION_RELEASE_RESERVATION.into(),
+                    pt!("⏹ 今すぐ解放"),
+                )
+                .with_value(usage_id.clone()),
+            ),
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(
+                    ACTION_CLONE_RESERVATION.into(),
+                    pt!("🔁 もう一度予約"),
+                )
+                .with_value(usage_id),
+            ),
+        ])),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource, TimePeriod};
+    use crate::domain::common::EmailAddress;
+    use chrono::Duration;
+
+    #[test]
+    fn test_end_reminder_message() {
+        let now = Utc::now();
+        let usage = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(now - Duration::hours(1), now + Duration::minutes(15)).unwrap(),
+            vec![
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string())),
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 1, "A100".to_string())),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            end_reminder_message(&usage, now),
+            "⏰ Thalys 0,1 の予約はあと15分で終了します"
+        );
+    }
+}
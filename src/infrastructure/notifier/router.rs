@@ -1,24 +1,45 @@
+use crate::domain::aggregates::resource_usage::value_objects::Resource;
 use crate::domain::ports::notifier::{NotificationError, NotificationEvent, Notifier};
-use crate::domain::ports::repositories::IdentityLinkRepository;
+use crate::domain::ports::power_management::{PowerManagementService, PowerState};
+use crate::domain::ports::repositories::{IdentityLinkRepository, WorkspaceTokenRepository};
 use crate::infrastructure::config::{NotificationConfig, ResourceConfig};
+use crate::infrastructure::export::{GoogleSheetsExporter, NotionSync};
+#[cfg(feature = "metrics")]
+use crate::infrastructure::metrics::metrics;
 use async_trait::async_trait;
+use chrono::Utc;
 use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{error, warn};
 
 use super::senders::{
-    MockSender, SlackSender,
+    EmailSender, MockSender, SlackSender,
+    email::EmailNotificationConfig,
     sender::{NotificationContext, Sender},
     slack::SlackNotificationConfig,
 };
 
+/// 再試行の待機時間の基準値（n回目の再試行ではn倍待つ）
+const RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
 /// 複数の通知手段をオーケストレートし、リソースに基づいて適切な通知先にルーティングする
 ///
 /// 各種Sender（Slack, Mock等）を保持し、通知設定の種類に応じて適切なSenderに委譲します。
 pub struct NotificationRouter {
     config: ResourceConfig,
     slack_sender: SlackSender,
+    email_sender: EmailSender,
     mock_sender: MockSender,
     identity_repo: Arc<dyn IdentityLinkRepository>,
+    power_management: Option<Arc<dyn PowerManagementService>>,
+    /// すべての通知イベントを配信するチャンネル（HTTP APIのイベントストリーム用）
+    event_stream: Option<broadcast::Sender<NotificationEvent>>,
+    sheets_exporter: Option<Arc<GoogleSheetsExporter>>,
+    notion_sync: Option<Arc<NotionSync>>,
 }
 
 impl NotificationRouter {
@@ -31,17 +52,93 @@ impl NotificationRouter {
         Self {
             config,
             slack_sender: SlackSender::new(),
+            email_sender: EmailSender::new(),
             mock_sender: MockSender::new(),
             identity_repo,
+            power_management: None,
+            event_stream: None,
+            sheets_exporter: None,
+            notion_sync: None,
+        }
+    }
+
+    /// 通知にサーバーの電源状態を含めるための電源管理サービスを設定
+    pub fn with_power_management(
+        mut self,
+        power_management: Arc<dyn PowerManagementService>,
+    ) -> Self {
+        self.power_management = Some(power_management);
+        self
+    }
+
+    /// 通知イベントを配信するチャンネルを設定
+    ///
+    /// 設定した場合、通知先の設定にかかわらず、すべてのイベントをチャンネルにも送る。
+    pub fn with_event_stream(mut self, event_stream: broadcast::Sender<NotificationEvent>) -> Self {
+        self.event_stream = Some(event_stream);
+        self
+    }
+
+    /// 予約の作成・更新・削除を追記するGoogleスプレッドシートを設定
+    ///
+    /// 設定した場合、通知先の設定にかかわらず、すべての予約の変更を追記する。
+    pub fn with_sheets_exporter(mut self, sheets_exporter: Arc<GoogleSheetsExporter>) -> Self {
+        self.sheets_exporter = Some(sheets_exporter);
+        self
+    }
+
+    /// 予約を同期するNotionデータベースを設定
+    ///
+    /// 設定した場合、通知先の設定にかかわらず、すべての予約の変更を同期する。
+    pub fn with_notion_sync(mut self, notion_sync: Arc<NotionSync>) -> Self {
+        self.notion_sync = Some(notion_sync);
+        self
+    }
+
+    /// Slack通知でワークスペースごとのBotトークンを使うためのリポジトリを設定
+    ///
+    /// `bot_token` を省略した通知先には、`team_id` のワークスペースに
+    /// インストールされたときのトークンで送信する。
+    pub fn with_workspace_token_repository(
+        mut self,
+        workspace_token_repo: Arc<dyn WorkspaceTokenRepository>,
+    ) -> Self {
+        self.slack_sender = self
+            .slack_sender
+            .with_workspace_token_repository(workspace_token_repo);
+        self
+    }
+
+    /// 予約されたサーバーの電源状態を取得
+    ///
+    /// 削除・使用終了のイベントや電源管理の対象外のサーバーの場合は `None` を返す。
+    /// 取得に失敗した場合も通知自体は行うため、`PowerState::Unknown` として扱う。
+    async fn fetch_power_state(&self, event: &NotificationEvent) -> Option<PowerState> {
+        let power_management = self.power_management.as_ref()?;
+        if matches!(
+            event,
+            NotificationEvent::ResourceUsageDeleted(_) | NotificationEvent::ResourceUsageEnded(_)
+        ) {
+            return None;
+        }
+        let usage = event.usage();
+
+        let server = usage.resources().iter().find_map(|r| match r {
+            Resource::Gpu(gpu) if power_management.is_managed(gpu.server()) => Some(gpu.server()),
+            _ => None,
+        })?;
+
+        match power_management.power_state(server).await {
+            Ok(state) => Some(state),
+            Err(e) => {
+                warn!("⚠️ 電源状態の取得に失敗: {}", e);
+                Some(PowerState::Unknown)
+            }
         }
     }
 
     fn collect_notification_configs(&self, event: &NotificationEvent) -> Vec<NotificationConfig> {
-        let resources = match event {
-            NotificationEvent::ResourceUsageCreated(usage) => usage.resources(),
-            NotificationEvent::ResourceUsageUpdated(usage) => usage.resources(),
-            NotificationEvent::ResourceUsageDeleted(usage) => usage.resources(),
-        };
+        let resources = event.usage().resources();
 
         let mut configs = HashSet::new();
         for resource in resources {
@@ -56,12 +153,9 @@ impl NotificationRouter {
         &self,
         config: &NotificationConfig,
         event: &NotificationEvent,
+        power_state: Option<PowerState>,
     ) -> Result<(), NotificationError> {
-        let usage = match event {
-            NotificationEvent::ResourceUsageCreated(u) => u,
-            NotificationEvent::ResourceUsageUpdated(u) => u,
-            NotificationEvent::ResourceUsageDeleted(u) => u,
-        };
+        let usage = event.usage();
 
         let user_email = usage.owner_email();
 
@@ -85,29 +179,112 @@ impl NotificationRouter {
             event,
             identity_link: identity_link.as_ref(),
             timezone: config.timezone(),
+            calendar_id: usage
+                .resources()
+                .first()
+                .and_then(|r| self.config.get_calendar_id_for_resource(r)),
+            power_state,
             customization: config.customization(),
+            locale: self.config.i18n.locale,
         };
 
-        match config {
+        let result = match config {
             NotificationConfig::Slack {
                 bot_token,
                 channel_id,
+                team_id,
                 ..
             } => {
                 let slack_config = SlackNotificationConfig {
                     bot_token: bot_token.clone(),
                     channel_id: channel_id.clone(),
+                    team_id: team_id.clone(),
                 };
                 self.slack_sender.send(&slack_config, context).await
             }
+            NotificationConfig::Email {
+                from,
+                sendmail_path,
+                ..
+            } => {
+                let email_config = EmailNotificationConfig {
+                    from: from.clone(),
+                    sendmail_path: sendmail_path.clone(),
+                };
+                self.email_sender.send(&email_config, context).await
+            }
             NotificationConfig::Mock { .. } => self.mock_sender.send(&(), context).await,
-        }
+        };
+        #[cfg(feature = "metrics")]
+        metrics().record_notification(config.kind(), result.is_ok());
+        result
+    }
+
+    /// 再試行と代替通知先を含めて送信
+    ///
+    /// 設定された回数だけ再試行しても失敗した場合は、代替通知先を先頭から順に試す。
+    /// いずれかに送信できれば成功とし、すべて失敗した場合は元の通知先のエラーを返す。
+    fn deliver<'a>(
+        &'a self,
+        config: &'a NotificationConfig,
+        event: &'a NotificationEvent,
+        power_state: Option<PowerState>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), NotificationError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut result = self.send_to_destination(config, event, power_state).await;
+            for attempt in 1..=config.retries() {
+                let Err(e) = &result else {
+                    break;
+                };
+                warn!(
+                    "⚠️ 通知送信エラー（{}/{}回目の再試行を行います）: {}",
+                    attempt,
+                    config.retries(),
+                    e
+                );
+                tokio::time::sleep(RETRY_BACKOFF * attempt).await;
+                result = self.send_to_destination(config, event, power_state).await;
+            }
+
+            let Err(error) = result else {
+                return Ok(());
+            };
+
+            for fallback in config.fallback() {
+                match self.deliver(fallback, event, power_state).await {
+                    Ok(()) => {
+                        warn!("↪️ 代替通知先に送信しました（元のエラー: {}）", error);
+                        return Ok(());
+                    }
+                    Err(e) => warn!("⚠️ 代替通知先への送信エラー: {}", e),
+                }
+            }
+
+            Err(error)
+        })
     }
 }
 
 #[async_trait]
 impl Notifier for NotificationRouter {
     async fn notify(&self, event: NotificationEvent) -> Result<(), NotificationError> {
+        // 受信者がいない場合は送信に失敗するが、通知には影響しない
+        if let Some(event_stream) = &self.event_stream {
+            let _ = event_stream.send(event.clone());
+        }
+
+        if let Some(sheets_exporter) = &self.sheets_exporter
+            && let Err(e) = sheets_exporter.append(&event, Utc::now()).await
+        {
+            error!("❌ Googleスプレッドシートへの追記エラー: {}", e);
+        }
+
+        if let Some(notion_sync) = &self.notion_sync
+            && let Err(e) = notion_sync.sync(&event).await
+        {
+            error!("❌ Notionへの同期エラー: {}", e);
+        }
+
         let notification_configs = self.collect_notification_configs(&event);
 
         if notification_configs.is_empty() {
@@ -115,12 +292,14 @@ impl Notifier for NotificationRouter {
             return Ok(());
         }
 
+        let power_state = self.fetch_power_state(&event).await;
+
         let mut errors = Vec::new();
 
         // 各通知設定に対して送信（ベストエフォート）
         for config in &notification_configs {
-            if let Err(e) = self.send_to_destination(config, &event).await {
-                eprintln!("⚠️  通知送信エラー: {}", e); // TODO: エラーハンドリングの改善
+            if let Err(e) = self.deliver(config, &event, power_state).await {
+                error!("❌ 通知送信エラー: {}", e); // TODO: エラーハンドリングの改善
                 errors.push(e);
             }
         }
@@ -0,0 +1,204 @@
+//! メール通知送信モジュール
+
+use async_trait::async_trait;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::domain::ports::notifier::{NotificationError, NotificationEvent};
+use crate::infrastructure::notifier::senders::sender::{NotificationContext, Sender};
+use crate::infrastructure::notifier::template_renderer::TemplateRenderer;
+
+/// sendmailコマンドのデフォルトパス
+const DEFAULT_SENDMAIL_PATH: &str = "/usr/sbin/sendmail";
+
+/// メール通知設定
+pub struct EmailNotificationConfig {
+    /// 送信元メールアドレス
+    pub from: String,
+    /// sendmailコマンドのパス
+    pub sendmail_path: Option<String>,
+}
+
+/// 予約者にメールでメッセージを送信する（sendmail経由）
+///
+/// 宛先は予約者のメールアドレス。
+/// ホストのMTA（Postfix等）にsendmail互換のコマンドで引き渡す。
+pub struct EmailSender;
+
+impl Default for EmailSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmailSender {
+    /// 新しいEmailSenderを作成
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// イベントからメールの件名と本文を構築
+    fn format_message(context: &NotificationContext) -> (String, String) {
+        let subject = match context.event {
+            NotificationEvent::ResourceUsageCreated(_) => "予約が作成されました",
+            NotificationEvent::ResourceUsageUpdated(_) => "予約が更新されました",
+            NotificationEvent::ResourceUsageDeleted(_) => "予約が削除されました",
+            NotificationEvent::ResourceUsageSeriesCreated { .. } => "繰り返し予約が作成されました",
+            NotificationEvent::ResourceUsageStarted(_) => "予約の使用が始まりました",
+            NotificationEvent::ResourceUsageEnded(_) => "予約の使用が終わりました",
+        };
+        let usage = context.event.usage();
+
+        let user = usage.owner_email().as_str();
+
+        let renderer = TemplateRenderer::new(
+            &context.customization.templates,
+            &context.customization.format,
+            context.timezone,
+        )
+        .with_calendar_id(context.calendar_id)
+        .with_power_state(context.power_state)
+        .with_locale(context.locale)
+        // 宛先は予約者本人のため、非公開の予約でも詳細を表示する
+        .with_private_details(true);
+
+        let body = match context.event {
+            NotificationEvent::ResourceUsageCreated(_) => renderer.render_created(usage, user),
+            NotificationEvent::ResourceUsageUpdated(_) => renderer.render_updated(usage, user),
+            NotificationEvent::ResourceUsageDeleted(_) => renderer.render_deleted(usage, user),
+            NotificationEvent::ResourceUsageSeriesCreated { periods, .. } => {
+                renderer.render_series_created(usage, periods, user)
+            }
+            NotificationEvent::ResourceUsageStarted(_) => renderer.render_started(usage, user),
+            NotificationEvent::ResourceUsageEnded(_) => renderer.render_ended(usage, user),
+        };
+
+        (format!("[lab-resource-manager] {}", subject), body)
+    }
+
+    /// RFC 5322形式のメッセージを構築
+    fn build_mail(from: &str, to: &str, subject: &str, body: &str) -> String {
+        format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nMIME-Version: 1.0\r\n\
+             Content-Type: text/plain; charset=UTF-8\r\nContent-Transfer-Encoding: 8bit\r\n\r\n{}\r\n",
+            from,
+            to,
+            encode_header(subject),
+            body.replace('\n', "\r\n")
+        )
+    }
+}
+
+/// ヘッダー値をRFC 2047のBエンコーディングで符号化（ASCIIのみの場合はそのまま）
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_string();
+    }
+    format!("=?UTF-8?B?{}?=", base64_encode(value.as_bytes()))
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, b)| acc | (u32::from(*b) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(TABLE[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[async_trait]
+impl Sender for EmailSender {
+    type Config = EmailNotificationConfig;
+
+    async fn send(
+        &self,
+        config: &EmailNotificationConfig,
+        context: NotificationContext<'_>,
+    ) -> Result<(), NotificationError> {
+        // 使用開始・終了はリソースの空き状況をチャンネルに知らせるためのもので、予約者へのメールでは知らせない
+        if matches!(
+            context.event,
+            NotificationEvent::ResourceUsageStarted(_) | NotificationEvent::ResourceUsageEnded(_)
+        ) {
+            return Ok(());
+        }
+
+        let usage = context.event.usage();
+        let (subject, body) = Self::format_message(&context);
+        let mail = Self::build_mail(&config.from, usage.owner_email().as_str(), &subject, &body);
+
+        let mut child = Command::new(
+            config
+                .sendmail_path
+                .as_deref()
+                .unwrap_or(DEFAULT_SENDMAIL_PATH),
+        )
+        .args(["-t", "-oi"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| NotificationError::SendFailure(format!("sendmailの起動に失敗: {}", e)))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(mail.as_bytes()).await.map_err(|e| {
+                NotificationError::SendFailure(format!("sendmailへの書き込みに失敗: {}", e))
+            })?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|e| NotificationError::SendFailure(format!("sendmailの実行に失敗: {}", e)))?;
+        if !output.status.success() {
+            return Err(NotificationError::SendFailure(format!(
+                "sendmailが失敗しました: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_encode_header() {
+        assert_eq!(encode_header("Reservation"), "Reservation");
+        assert_eq!(encode_header("予約"), "=?UTF-8?B?5LqI57SE?=");
+    }
+
+    #[test]
+    fn test_build_mail_uses_crlf() {
+        let mail = EmailSender::build_mail("bot@example.com", "user@example.com", "Hi", "a\nb");
+
+        assert!(
+            mail.starts_with("From: bot@example.com\r\nTo: user@example.com\r\nSubject: Hi\r\n")
+        );
+        assert!(mail.ends_with("\r\n\r\na\r\nb\r\n"));
+    }
+}
@@ -22,11 +22,7 @@ impl MockSender {
     /// イベントからテンプレートレンダラーを用いてメッセージを構築
     /// （Slack送信時と同等のフォーマット出力）
     fn format_message(&self, context: &NotificationContext) -> String {
-        let usage = match context.event {
-            NotificationEvent::ResourceUsageCreated(u) => u,
-            NotificationEvent::ResourceUsageUpdated(u) => u,
-            NotificationEvent::ResourceUsageDeleted(u) => u,
-        };
+        let usage = context.event.usage();
 
         let user = usage.owner_email().as_str();
 
@@ -34,12 +30,20 @@ impl MockSender {
             &context.customization.templates,
             &context.customization.format,
             context.timezone,
-        );
+        )
+        .with_calendar_id(context.calendar_id)
+        .with_power_state(context.power_state)
+        .with_locale(context.locale);
 
         match context.event {
             NotificationEvent::ResourceUsageCreated(_) => renderer.render_created(usage, user),
             NotificationEvent::ResourceUsageUpdated(_) => renderer.render_updated(usage, user),
             NotificationEvent::ResourceUsageDeleted(_) => renderer.render_deleted(usage, user),
+            NotificationEvent::ResourceUsageSeriesCreated { periods, .. } => {
+                renderer.render_series_created(usage, periods, user)
+            }
+            NotificationEvent::ResourceUsageStarted(_) => renderer.render_started(usage, user),
+            NotificationEvent::ResourceUsageEnded(_) => renderer.render_ended(usage, user),
         }
     }
 }
@@ -54,9 +58,7 @@ impl Sender for MockSender {
         context: NotificationContext<'_>,
     ) -> Result<(), NotificationError> {
         let message = self.format_message(&context);
-        println!("📤 [MockSender]");
-        println!("{}", message);
-        println!();
+        tracing::info!("📤 [MockSender]\n{}", message);
         Ok(())
     }
 }
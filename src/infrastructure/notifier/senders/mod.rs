@@ -4,8 +4,11 @@
 //!
 //! - `sender`: 送信手段の共通トレイト定義
 //! - `slack`: Slack Bot Token経由の通知送信
+//! - `email`: sendmail経由の予約者へのメール送信
 //! - `mock`: テスト/開発用のモック送信実装
 
+/// メール通知送信実装
+pub mod email;
 /// モック通知送信実装
 pub mod mock;
 /// 通知送信の共通トレイト
@@ -13,6 +16,7 @@ pub mod sender;
 /// Slack通知送信実装
 pub mod slack;
 
+pub use email::EmailSender;
 pub use mock::MockSender;
 pub use sender::Sender;
 pub use slack::SlackSender;
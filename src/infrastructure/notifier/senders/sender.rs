@@ -1,6 +1,8 @@
 use crate::domain::aggregates::identity_link::entity::IdentityLink;
 use crate::domain::ports::notifier::{NotificationError, NotificationEvent};
+use crate::domain::ports::power_management::PowerState;
 use crate::infrastructure::config::NotificationCustomization;
+use crate::infrastructure::i18n::Locale;
 use async_trait::async_trait;
 
 /// 通知送信に必要なコンテキスト情報
@@ -11,8 +13,14 @@ pub struct NotificationContext<'a> {
     pub identity_link: Option<&'a IdentityLink>,
     /// タイムゾーン（オプション）
     pub timezone: Option<&'a str>,
+    /// 予約が登録されているカレンダーID（オプション）
+    pub calendar_id: Option<&'a str>,
+    /// 予約されたサーバーの電源状態（電源管理が設定されている場合のみ）
+    pub power_state: Option<PowerState>,
     /// カスタマイズ設定
     pub customization: NotificationCustomization,
+    /// 表示言語
+    pub locale: Locale,
 }
 
 /// 通知メッセージを送信する機能を提供するtrait
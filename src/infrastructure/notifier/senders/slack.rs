@@ -3,25 +3,41 @@
 use async_trait::async_trait;
 use serde_json::json;
 use slack_morphism::prelude::*;
-use tracing::error;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{error, info};
 
 use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
-use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
 use crate::domain::common::EmailAddress;
 use crate::domain::ports::notifier::{NotificationError, NotificationEvent};
+use crate::domain::ports::repositories::WorkspaceTokenRepository;
 use crate::infrastructure::notifier::senders::sender::{NotificationContext, Sender};
 use crate::infrastructure::notifier::template_renderer::TemplateRenderer;
-use crate::interface::slack::constants::{ACTION_CANCEL_RESERVATION, ACTION_EDIT_RESERVATION};
+use crate::infrastructure::rate_limit::RateLimiter;
+use crate::interface::slack::constants::{
+    ACTION_CANCEL_RESERVATION, ACTION_CLONE_RESERVATION, ACTION_EDIT_RESERVATION,
+    ACTION_EXTEND_RESERVATION, ACTION_RELEASE_RESERVATION, ACTION_TRANSFER_RESERVATION,
+};
+use crate::interface::slack::slack_client;
 
 /// Slack通知設定
 pub struct SlackNotificationConfig {
+    /// Bot Token。空の場合は `team_id` のワークスペースにインストールされたトークンを使う
     pub bot_token: String,
+    /// チャンネルID、または `#` から始まるチャンネル名
     pub channel_id: String,
+    /// Enterprise GridのワークスペースID
+    pub team_id: Option<String>,
 }
 
+/// チャンネル名解決のキャッシュキー（ワークスペースID, チャンネル名）
+type ChannelCacheKey = (Option<String>, String);
+
 /// Slack経由でメッセージを送信する（Bot Token方式）
 pub struct SlackSender {
-    slack_client: SlackClient<SlackClientHyperHttpsConnector>,
+    channel_cache: RwLock<HashMap<ChannelCacheKey, SlackChannelId>>,
+    workspace_token_repo: Option<Arc<dyn WorkspaceTokenRepository>>,
 }
 
 impl Default for SlackSender {
@@ -34,46 +50,194 @@ impl SlackSender {
     /// 新しいSlackSenderを作成
     pub fn new() -> Self {
         Self {
-            slack_client: SlackClient::new(
-                SlackClientHyperConnector::new()
-                    .expect("Failed to initialize Slack HTTP connector"),
-            ),
+            channel_cache: RwLock::new(HashMap::new()),
+            workspace_token_repo: None,
+        }
+    }
+
+    /// ワークスペースごとのBotトークンを保持するリポジトリを設定
+    pub fn with_workspace_token_repository(
+        mut self,
+        workspace_token_repo: Arc<dyn WorkspaceTokenRepository>,
+    ) -> Self {
+        self.workspace_token_repo = Some(workspace_token_repo);
+        self
+    }
+
+    /// 送信に使うBotトークンを決定
+    ///
+    /// 設定にトークンがなければ、`team_id` のワークスペースにOAuthで
+    /// インストールされたときのトークンを使う。
+    async fn resolve_token(
+        &self,
+        config: &SlackNotificationConfig,
+    ) -> Result<SlackApiToken, NotificationError> {
+        if !config.bot_token.is_empty() {
+            return Ok(SlackApiToken::new(config.bot_token.clone().into()));
+        }
+
+        let (Some(repo), Some(team_id)) = (&self.workspace_token_repo, &config.team_id) else {
+            return Err(NotificationError::SendFailure(
+                "bot_tokenが未設定です（省略する場合はteam_idを指定してください）".to_string(),
+            ));
+        };
+
+        match repo.find_by_team_id(team_id).await {
+            Ok(Some(token)) => Ok(SlackApiToken::new(token.into())),
+            Ok(None) => Err(NotificationError::SendFailure(format!(
+                "ワークスペース {} にアプリがインストールされていません",
+                team_id
+            ))),
+            Err(e) => Err(NotificationError::SendFailure(format!(
+                "ワークスペースのトークン取得に失敗: {}",
+                e
+            ))),
         }
     }
 
     /// Bot Token方式でメッセージを送信
+    ///
+    /// Botが参加していない公開チャンネルの場合は、チャンネルに参加してから再送する。
     async fn send_via_bot_token(
         &self,
-        bot_token: &str,
-        channel_id: &str,
+        config: &SlackNotificationConfig,
         message: String,
         blocks: Vec<SlackBlock>,
     ) -> Result<(), NotificationError> {
-        let token = SlackApiToken::new(bot_token.into());
-        let session = self.slack_client.open_session(&token);
+        let token = self.resolve_token(config).await?;
+        let session = slack_client::shared().open_session(&token);
+
+        let channel_id = self
+            .resolve_channel(&session, &config.channel_id, config.team_id.as_deref())
+            .await?;
 
         let post_chat_req = SlackApiChatPostMessageRequest::new(
-            channel_id.into(),
+            channel_id.clone(),
             SlackMessageContent::new()
                 .with_text(message)
                 .with_blocks(blocks),
         );
 
-        session
-            .chat_post_message(&post_chat_req)
+        let limiter = RateLimiter::slack();
+        match limiter
+            .call(|| session.chat_post_message(&post_chat_req))
             .await
-            .map_err(|e| NotificationError::SendFailure(format!("Slack API送信失敗: {}", e)))?;
+        {
+            Ok(_) => {}
+            Err(slack_morphism::errors::SlackClientError::ApiError(e))
+                if e.code == "not_in_channel" =>
+            {
+                info!("📥 チャンネルに参加して再送します: {}", channel_id);
+                let join_req = SlackApiConversationsJoinRequest::new(channel_id);
+                limiter
+                    .call(|| session.conversations_join(&join_req))
+                    .await
+                    .map_err(|e| {
+                        NotificationError::SendFailure(format!(
+                            "チャンネルへの参加に失敗（プライベートチャンネルの場合はBotを招待してください）: {}",
+                            e
+                        ))
+                    })?;
+                limiter
+                    .call(|| session.chat_post_message(&post_chat_req))
+                    .await
+                    .map_err(|e| {
+                        NotificationError::SendFailure(format!("Slack API送信失敗: {}", e))
+                    })?;
+            }
+            Err(e) => {
+                return Err(NotificationError::SendFailure(format!(
+                    "Slack API送信失敗: {}",
+                    e
+                )));
+            }
+        }
 
         Ok(())
     }
 
+    /// 通知先のチャンネルIDを解決
+    ///
+    /// `#` から始まる場合はチャンネル名として扱い、Botから見えるチャンネル
+    /// （公開チャンネルと、Botが招待されているプライベートチャンネル）から検索する。
+    /// 解決結果はキャッシュする。
+    async fn resolve_channel(
+        &self,
+        session: &SlackClientSession<'_, SlackClientHyperHttpsConnector>,
+        channel: &str,
+        team_id: Option<&str>,
+    ) -> Result<SlackChannelId, NotificationError> {
+        let Some(name) = channel.strip_prefix('#') else {
+            return Ok(SlackChannelId::new(channel.to_string()));
+        };
+
+        let key = (team_id.map(str::to_string), name.to_string());
+        if let Some(channel_id) = self.channel_cache.read().await.get(&key) {
+            return Ok(channel_id.clone());
+        }
+
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut params = vec![
+                ("types", Some("public_channel,private_channel".to_string())),
+                ("exclude_archived", Some("true".to_string())),
+                ("limit", Some("200".to_string())),
+                ("team_id", team_id.map(str::to_string)),
+            ];
+            if let Some(cursor) = &cursor {
+                params.push(("cursor", Some(cursor.clone())));
+            }
+
+            let response: SlackApiConversationsListResponse = RateLimiter::slack()
+                .call(|| {
+                    session
+                        .http_session_api
+                        .http_get("conversations.list", &params, None)
+                })
+                .await
+                .map_err(|e| {
+                    NotificationError::SendFailure(format!("チャンネル一覧の取得に失敗: {}", e))
+                })?;
+
+            if let Some(found) = response
+                .channels
+                .iter()
+                .find(|c| c.name.as_deref() == Some(name))
+            {
+                self.channel_cache
+                    .write()
+                    .await
+                    .insert(key, found.id.clone());
+                return Ok(found.id.clone());
+            }
+
+            cursor = response
+                .response_metadata
+                .and_then(|m| m.next_cursor)
+                .map(|c| c.to_string())
+                .filter(|c| !c.is_empty());
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Err(NotificationError::SendFailure(format!(
+            "チャンネルが見つかりません（プライベートチャンネルの場合はBotを招待してください）: #{}",
+            name
+        )))
+    }
+
     /// ユーザー表示名をフォーマット（Slackメンション or メールアドレス）
+    ///
+    /// Enterprise Gridでは、通知先ワークスペースで有効なSlackユーザーをメンションする。
     fn format_user(
         email: &EmailAddress,
         identity_link: Option<&crate::domain::aggregates::identity_link::entity::IdentityLink>,
+        team_id: Option<&str>,
     ) -> String {
         if let Some(identity) = identity_link
-            && let Some(slack_identity) = identity.get_identity_for_system(&ExternalSystem::Slack)
+            && let Some(slack_identity) =
+                identity.get_identity_for_workspace(&ExternalSystem::Slack, team_id)
         {
             return format!("<@{}>", slack_identity.user_id());
         }
@@ -81,15 +245,18 @@ impl SlackSender {
     }
 
     /// イベントからSlack用のメッセージを構築（テンプレートレンダラー使用）
-    fn format_message(context: &NotificationContext) -> String {
-        let usage = Self::extract_usage_from_event(context.event);
-        let user_display = Self::format_user(usage.owner_email(), context.identity_link);
+    fn format_message(context: &NotificationContext, team_id: Option<&str>) -> String {
+        let usage = context.event.usage();
+        let user_display = Self::format_user(usage.owner_email(), context.identity_link, team_id);
 
         let renderer = TemplateRenderer::new(
             &context.customization.templates,
             &context.customization.format,
             context.timezone,
-        );
+        )
+        .with_calendar_id(context.calendar_id)
+        .with_power_state(context.power_state)
+        .with_locale(context.locale);
 
         match context.event {
             NotificationEvent::ResourceUsageCreated(_) => {
@@ -101,25 +268,23 @@ impl SlackSender {
             NotificationEvent::ResourceUsageDeleted(_) => {
                 renderer.render_deleted(usage, &user_display)
             }
-        }
-    }
-
-    /// イベントからResourceUsageを抽出
-    fn extract_usage_from_event(event: &NotificationEvent) -> &ResourceUsage {
-        match event {
-            NotificationEvent::ResourceUsageCreated(u) => u,
-            NotificationEvent::ResourceUsageUpdated(u) => u,
-            NotificationEvent::ResourceUsageDeleted(u) => u,
+            NotificationEvent::ResourceUsageSeriesCreated { periods, .. } => {
+                renderer.render_series_created(usage, periods, &user_display)
+            }
+            NotificationEvent::ResourceUsageStarted(_) => {
+                renderer.render_started(usage, &user_display)
+            }
+            NotificationEvent::ResourceUsageEnded(_) => renderer.render_ended(usage, &user_display),
         }
     }
 
     /// メッセージブロックを構築（イベントに応じてボタンを追加）
     fn build_message_blocks(message: &str, context: &NotificationContext) -> Vec<SlackBlock> {
-        let usage = Self::extract_usage_from_event(context.event);
+        let usage = context.event.usage();
         let usage_id = usage.id().as_str();
         tracing::info!("🔔 通知ボタン作成: usage_id={}", usage_id);
 
-        // Deleted イベントの場合はボタンなし
+        // Deleted・使用開始・使用終了イベントと繰り返し予約（ボタンでは1回分しか操作できないため）の場合は操作ボタンなし
         let should_add_buttons = matches!(
             context.event,
             NotificationEvent::ResourceUsageCreated(_) | NotificationEvent::ResourceUsageUpdated(_)
@@ -148,6 +313,33 @@ impl SlackSender {
                             "action_id": ACTION_EDIT_RESERVATION,
                             "value": usage_id
                         },
+                        {
+                            "type": "button",
+                            "text": {
+                                "type": "plain_text",
+                                "text": "⏩ 延長"
+                            },
+                            "action_id": ACTION_EXTEND_RESERVATION,
+                            "value": usage_id
+                        },
+                        {
+                            "type": "button",
+                            "text": {
+                                "type": "plain_text",
+                                "text": "⏹ 今すぐ解放"
+                            },
+                            "action_id": ACTION_RELEASE_RESERVATION,
+                            "value": usage_id
+                        },
+                        {
+                            "type": "button",
+                            "text": {
+                                "type": "plain_text",
+                                "text": "👥 引き継ぐ"
+                            },
+                            "action_id": ACTION_TRANSFER_RESERVATION,
+                            "value": usage_id
+                        },
                         {
                             "type": "button",
                             "text": {
@@ -161,8 +353,33 @@ impl SlackSender {
                     ]
                 }
             ])
+        } else if matches!(context.event, NotificationEvent::ResourceUsageEnded(_)) {
+            // 使用終了イベントは同じリソースをもう一度予約するボタンのみ
+            json!([
+                {
+                    "type": "section",
+                    "text": {
+                        "type": "mrkdwn",
+                        "text": message
+                    }
+                },
+                {
+                    "type": "actions",
+                    "elements": [
+                        {
+                            "type": "button",
+                            "text": {
+                                "type": "plain_text",
+                                "text": "🔁 もう一度予約"
+                            },
+                            "action_id": ACTION_CLONE_RESERVATION,
+                            "value": usage_id
+                        }
+                    ]
+                }
+            ])
         } else {
-            // シンプルなブロック（Deletedイベント用）
+            // シンプルなブロック（Deleted・使用開始イベント・繰り返し予約用）
             json!([
                 {
                     "type": "section",
@@ -191,11 +408,10 @@ impl Sender for SlackSender {
         context: NotificationContext<'_>,
     ) -> Result<(), NotificationError> {
         // メッセージとブロックを構築
-        let message = Self::format_message(&context);
+        let message = Self::format_message(&context, config.team_id.as_deref());
         let blocks = Self::build_message_blocks(&message, &context);
 
         // Bot Token方式
-        self.send_via_bot_token(&config.bot_token, &config.channel_id, message, blocks)
-            .await
+        self.send_via_bot_token(config, message, blocks).await
     }
 }
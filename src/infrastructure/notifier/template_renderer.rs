@@ -3,8 +3,12 @@
 //! 通知メッセージのテンプレートとプレースホルダー置換を処理します。
 
 use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
-use crate::domain::aggregates::resource_usage::value_objects::Resource;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    ReservationMetadata, Resource, TimePeriod,
+};
+use crate::domain::ports::power_management::PowerState;
 use crate::infrastructure::config::{FormatConfig, TemplateConfig};
+use crate::infrastructure::i18n::{Locale, Messages, fill};
 use crate::infrastructure::notifier::formatter::{format_resources_styled, format_time_styled};
 
 /// プレースホルダー定義
@@ -15,23 +19,22 @@ pub mod placeholders {
     pub const RESOURCE: &str = "{resource}";
     /// 時刻情報
     pub const TIME: &str = "{time}";
-    /// 備考
+    /// 備考（プロジェクト名などのメタデータを含む）
     pub const NOTES: &str = "{notes}";
     /// リソースラベル（💻 予約GPU等）
     pub const RESOURCE_LABEL: &str = "{resource_label}";
-}
-
-/// デフォルトテンプレート（現在のハードコード値と同等）
-pub mod defaults {
-    /// 予約作成時のデフォルトテンプレート
-    pub const CREATED: &str =
-        "🔔 新規予約\n👤 {user}\n\n📅 期間\n{time}\n\n{resource_label}\n{resource}{notes}";
-    /// 予約更新時のデフォルトテンプレート
-    pub const UPDATED: &str =
-        "🔄 予約更新\n👤 {user}\n\n📅 期間\n{time}\n\n{resource_label}\n{resource}{notes}";
-    /// 予約削除時のデフォルトテンプレート
-    pub const DELETED: &str =
-        "🗑️ 予約削除\n👤 {user}\n\n📅 期間\n{time}\n\n{resource_label}\n{resource}{notes}";
+    /// 予約ID
+    pub const USAGE_ID: &str = "{usage_id}";
+    /// 予約が登録されているカレンダーへのリンク
+    pub const CALENDAR_LINK: &str = "{calendar_link}";
+    /// 予約者のメールアドレス
+    pub const OWNER_EMAIL: &str = "{owner_email}";
+    /// サーバー名（部屋の場合は部屋名）
+    pub const SERVER: &str = "{server}";
+    /// 予約GPU台数
+    pub const DEVICE_COUNT: &str = "{device_count}";
+    /// サーバーの電源状態（電源管理が設定されている場合のみ）
+    pub const POWER_STATE: &str = "{power_state}";
 }
 
 /// テンプレートレンダラー
@@ -39,6 +42,10 @@ pub struct TemplateRenderer<'a> {
     templates: &'a TemplateConfig,
     format: &'a FormatConfig,
     timezone: Option<&'a str>,
+    calendar_id: Option<&'a str>,
+    power_state: Option<PowerState>,
+    show_private_details: bool,
+    locale: Locale,
 }
 
 impl<'a> TemplateRenderer<'a> {
@@ -52,16 +59,46 @@ impl<'a> TemplateRenderer<'a> {
             templates,
             format,
             timezone,
+            calendar_id: None,
+            power_state: None,
+            show_private_details: false,
+            locale: Locale::default(),
         }
     }
 
+    /// デフォルトテンプレートや見出しの表示言語を設定
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// `{calendar_link}` の生成に使うカレンダーIDを設定
+    pub fn with_calendar_id(mut self, calendar_id: Option<&'a str>) -> Self {
+        self.calendar_id = calendar_id;
+        self
+    }
+
+    /// `{power_state}` に表示するサーバーの電源状態を設定
+    pub fn with_power_state(mut self, power_state: Option<PowerState>) -> Self {
+        self.power_state = power_state;
+        self
+    }
+
+    /// 非公開の予約でも予約者・備考を表示するかを設定
+    ///
+    /// 通知の宛先が予約者本人の場合など、詳細を見せてよい場合にのみ有効にする。
+    pub fn with_private_details(mut self, show_private_details: bool) -> Self {
+        self.show_private_details = show_private_details;
+        self
+    }
+
     /// 予約作成メッセージをレンダリング
     pub fn render_created(&self, usage: &ResourceUsage, user_display: &str) -> String {
         let template = self
             .templates
             .created
             .as_deref()
-            .unwrap_or(defaults::CREATED);
+            .unwrap_or(self.messages().template_created);
         self.render(template, usage, user_display)
     }
 
@@ -71,7 +108,7 @@ impl<'a> TemplateRenderer<'a> {
             .templates
             .updated
             .as_deref()
-            .unwrap_or(defaults::UPDATED);
+            .unwrap_or(self.messages().template_updated);
         self.render(template, usage, user_display)
     }
 
@@ -81,15 +118,90 @@ impl<'a> TemplateRenderer<'a> {
             .templates
             .deleted
             .as_deref()
-            .unwrap_or(defaults::DELETED);
+            .unwrap_or(self.messages().template_deleted);
         self.render(template, usage, user_display)
     }
 
+    /// 予約の使用開始メッセージをレンダリング
+    pub fn render_started(&self, usage: &ResourceUsage, user_display: &str) -> String {
+        let template = self
+            .templates
+            .started
+            .as_deref()
+            .unwrap_or(self.messages().template_started);
+        self.render(template, usage, user_display)
+    }
+
+    /// 予約の使用終了メッセージをレンダリング
+    pub fn render_ended(&self, usage: &ResourceUsage, user_display: &str) -> String {
+        let template = self
+            .templates
+            .ended
+            .as_deref()
+            .unwrap_or(self.messages().template_ended);
+        self.render(template, usage, user_display)
+    }
+
+    /// 繰り返し予約の作成メッセージをレンダリング
+    ///
+    /// 最初の回を予約作成のテンプレートでレンダリングし、すべての回の日時を続けて示す。
+    pub fn render_series_created(
+        &self,
+        first: &ResourceUsage,
+        periods: &[TimePeriod],
+        user_display: &str,
+    ) -> String {
+        let schedule = periods
+            .iter()
+            .map(|period| {
+                format!(
+                    "• {}",
+                    format_time_styled(
+                        period,
+                        self.timezone,
+                        self.format.time_style,
+                        self.format.date_format,
+                        self.locale,
+                    )
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "{}\n\n{}\n{}",
+            self.render_created(first, user_display),
+            fill(
+                self.messages().series_header,
+                &[("count", &periods.len().to_string())]
+            ),
+            schedule
+        )
+    }
+
+    /// 表示言語のメッセージカタログ
+    fn messages(&self) -> &'static Messages {
+        self.locale.messages()
+    }
+
     /// テンプレートをレンダリング（シングルパス方式）
     ///
     /// チェーン式の`replace`だと置換後の値にプレースホルダーが含まれる場合に
     /// 誤置換が発生する可能性があるため、シングルパスで処理する。
     fn render(&self, template: &str, usage: &ResourceUsage, user_display: &str) -> String {
+        // 非公開の予約は予約者・備考を伏せる
+        let redacted = usage.visibility().is_private() && !self.show_private_details;
+        let messages = self.messages();
+        let user_display = if redacted {
+            messages.redacted_owner
+        } else {
+            user_display
+        };
+        let owner_email = if redacted {
+            messages.redacted_owner
+        } else {
+            usage.owner_email().as_str()
+        };
+
         let resources_formatted =
             format_resources_styled(usage.resources(), self.format.resource_style);
 
@@ -98,72 +210,146 @@ impl<'a> TemplateRenderer<'a> {
             self.timezone,
             self.format.time_style,
             self.format.date_format,
+            self.locale,
         );
 
-        let notes_formatted = usage
-            .notes()
-            .filter(|n| !n.is_empty())
-            .map(|n| format!("\n\n📝 備考\n{}", n))
+        let mut notes_formatted = String::new();
+        if !redacted {
+            if let Some(metadata) = Self::format_metadata(messages, usage.metadata()) {
+                notes_formatted.push_str(&format!("\n\n{}\n{}", messages.metadata_label, metadata));
+            }
+            if let Some(notes) = usage.notes().filter(|n| !n.is_empty()) {
+                notes_formatted.push_str(&format!("\n\n{}\n{}", messages.notes_label, notes));
+            }
+        }
+
+        let resource_label = Self::get_resource_label(messages, usage.resources());
+
+        let calendar_link = self
+            .calendar_id
+            .map(Self::calendar_link)
             .unwrap_or_default();
 
-        let resource_label = Self::get_resource_label(usage.resources());
+        let server = usage
+            .resources()
+            .first()
+            .map(Resource::name)
+            .unwrap_or_default();
+
+        let device_count = usage
+            .resources()
+            .iter()
+            .filter(|r| matches!(r, Resource::Gpu(_)))
+            .count()
+            .to_string();
+
+        let power_state = self
+            .power_state
+            .map(|state| {
+                format!(
+                    "\n\n{}: {}",
+                    messages.power_label,
+                    messages.power_state(state)
+                )
+            })
+            .unwrap_or_default();
+
+        // 長いプレースホルダーから順にチェック（{resource_label}と{resource}の順序に注意）
+        let replacements: [(&str, &str); 11] = [
+            (placeholders::RESOURCE_LABEL, resource_label),
+            (placeholders::RESOURCE, &resources_formatted),
+            (placeholders::USER, user_display),
+            (placeholders::TIME, &time_formatted),
+            (placeholders::NOTES, &notes_formatted),
+            (placeholders::USAGE_ID, usage.id().as_str()),
+            (placeholders::CALENDAR_LINK, &calendar_link),
+            (placeholders::OWNER_EMAIL, owner_email),
+            (placeholders::SERVER, server),
+            (placeholders::DEVICE_COUNT, &device_count),
+            (placeholders::POWER_STATE, &power_state),
+        ];
 
         // シングルパスでテンプレートを走査し、プレースホルダーのみ置換する
         // プレースホルダーはすべてASCIIなので .len() で文字数を取得可能
         let mut result = String::with_capacity(template.len() + resources_formatted.len() * 2);
-        let mut chars = template.char_indices().peekable();
+        let mut chars = template.char_indices();
 
         while let Some((i, ch)) = chars.next() {
             let rest = &template[i..];
 
-            // 長いプレースホルダーから順にチェック（{resource_label}と{resource}の順序に注意）
-            if rest.starts_with(placeholders::RESOURCE_LABEL) {
-                result.push_str(resource_label);
-                // プレースホルダー分の文字をスキップ（先頭1文字は既に読み込み済み）
-                for _ in 1..placeholders::RESOURCE_LABEL.len() {
-                    chars.next();
-                }
-            } else if rest.starts_with(placeholders::RESOURCE) {
-                result.push_str(&resources_formatted);
-                for _ in 1..placeholders::RESOURCE.len() {
-                    chars.next();
-                }
-            } else if rest.starts_with(placeholders::USER) {
-                result.push_str(user_display);
-                for _ in 1..placeholders::USER.len() {
-                    chars.next();
-                }
-            } else if rest.starts_with(placeholders::TIME) {
-                result.push_str(&time_formatted);
-                for _ in 1..placeholders::TIME.len() {
-                    chars.next();
-                }
-            } else if rest.starts_with(placeholders::NOTES) {
-                result.push_str(&notes_formatted);
-                for _ in 1..placeholders::NOTES.len() {
-                    chars.next();
+            match replacements
+                .iter()
+                .find(|(placeholder, _)| rest.starts_with(placeholder))
+            {
+                Some((placeholder, value)) => {
+                    result.push_str(value);
+                    // プレースホルダー分の文字をスキップ（先頭1文字は既に読み込み済み）
+                    for _ in 1..placeholder.len() {
+                        chars.next();
+                    }
                 }
-            } else {
-                result.push(ch);
+                None => result.push(ch),
             }
         }
 
         result
     }
 
+    /// カレンダーIDからGoogle Calendarの閲覧用URLを生成
+    fn calendar_link(calendar_id: &str) -> String {
+        let encoded: String = calendar_id
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    (b as char).to_string()
+                }
+                _ => format!("%{:02X}", b),
+            })
+            .collect();
+        format!("https://calendar.google.com/calendar/embed?src={}", encoded)
+    }
+
+    /// メタデータを1項目1行の「見出し: 値」にする（指定された項目がない場合は `None`）
+    fn format_metadata(messages: &Messages, metadata: &ReservationMetadata) -> Option<String> {
+        let utilization = metadata.expected_utilization().map(|u| u.to_string());
+        let lines: Vec<String> = [
+            (messages.project, metadata.project()),
+            (messages.experiment_id, metadata.experiment_id()),
+            (messages.utilization, utilization.as_deref()),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| value.map(|value| format!("{}: {}", label, value)))
+        .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
     /// リソースタイプに応じたラベルを取得
-    fn get_resource_label(resources: &[Resource]) -> &'static str {
+    fn get_resource_label(messages: &'static Messages, resources: &[Resource]) -> &'static str {
         if resources.is_empty() {
-            return "📦 予約リソース";
+            return messages.resource_label_other;
         }
 
-        let has_gpu = resources.iter().any(|r| matches!(r, Resource::Gpu(_)));
-        let has_room = resources.iter().any(|r| matches!(r, Resource::Room { .. }));
-
-        match (has_gpu, has_room) {
-            (true, false) => "💻 予約GPU",
-            (false, true) => "🏢 予約部屋",
-            _ => "📦 予約リソース",
+        if resources.iter().all(|r| matches!(r, Resource::Gpu(_))) {
+            messages.resource_label_gpu
+        } else if resources.iter().all(|r| matches!(r, Resource::Room { .. })) {
+            messages.resource_label_room
+        } else if resources
+            .iter()
+            .all(|r| matches!(r, Resource::Instrument { .. }))
+        {
+            messages.resource_label_instrument
+        } else if resources
+            .iter()
+            .all(|r| matches!(r, Resource::Storage { .. }))
+        {
+            messages.resource_label_storage
+        } else if resources
+            .iter()
+            .all(|r| matches!(r, Resource::License { .. }))
+        {
+            messages.resource_label_license
+        } else {
+            messages.resource_label_other
         }
     }
 }
@@ -171,7 +357,7 @@ impl<'a> TemplateRenderer<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, TimePeriod};
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Visibility};
     use crate::domain::common::EmailAddress;
     use crate::infrastructure::config::{DateFormat, ResourceStyle, TimeStyle};
     use chrono::{TimeZone, Utc};
@@ -195,6 +381,29 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn test_render_series_created_lists_every_occurrence() {
+        let templates = TemplateConfig::default();
+        let format = FormatConfig::default();
+
+        let renderer = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"));
+        let usage = create_test_usage();
+        let periods: Vec<TimePeriod> = (0..3)
+            .map(|week| {
+                let start = Utc
+                    .with_ymd_and_hms(2024, 1, 15 + 7 * week, 10, 0, 0)
+                    .unwrap();
+                TimePeriod::new(start, start + chrono::Duration::hours(2)).unwrap()
+            })
+            .collect();
+
+        let result = renderer.render_series_created(&usage, &periods, "<@U12345>");
+
+        assert!(result.starts_with("🔔 新規予約"));
+        assert!(result.contains("🔁 繰り返し予約（全3回）"));
+        assert_eq!(result.matches("\n• ").count(), 3);
+    }
+
     #[test]
     fn test_render_with_default_template() {
         let templates = TemplateConfig::default();
@@ -212,12 +421,118 @@ mod tests {
         assert!(result.contains("テスト用予約"));
     }
 
+    #[test]
+    fn test_render_power_state() {
+        let templates = TemplateConfig::default();
+        let format = FormatConfig::default();
+        let usage = create_test_usage();
+
+        let without_state = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"))
+            .render_created(&usage, "<@U12345>");
+        assert!(!without_state.contains("⚡ 電源"));
+
+        let with_state = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"))
+            .with_power_state(Some(PowerState::Off))
+            .render_created(&usage, "<@U12345>");
+        assert!(with_state.ends_with("\n\n⚡ 電源: オフ"));
+    }
+
+    #[test]
+    fn test_render_private_usage_hides_owner_and_notes() {
+        let templates = TemplateConfig {
+            created: Some("{user} {owner_email}{notes}".to_string()),
+            updated: None,
+            deleted: None,
+            started: None,
+            ended: None,
+        };
+        let format = FormatConfig::default();
+        let usage = create_test_usage().with_visibility(Visibility::Private);
+
+        let redacted = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"))
+            .render_created(&usage, "<@U12345>");
+        assert_eq!(redacted, "予約済み 予約済み");
+
+        let revealed = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"))
+            .with_private_details(true)
+            .render_created(&usage, "<@U12345>");
+        assert_eq!(
+            revealed,
+            "<@U12345> test@example.com\n\n📝 備考\nテスト用予約"
+        );
+    }
+
+    #[test]
+    fn test_render_metadata_before_notes() {
+        let templates = TemplateConfig {
+            created: Some("{notes}".to_string()),
+            updated: None,
+            deleted: None,
+            started: None,
+            ended: None,
+        };
+        let format = FormatConfig::default();
+        let usage = create_test_usage().with_metadata(
+            ReservationMetadata::new(Some("LLM".to_string()), None, Some(80)).unwrap(),
+        );
+
+        let rendered = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"))
+            .render_created(&usage, "<@U12345>");
+
+        assert_eq!(
+            rendered,
+            "\n\n🗂️ プロジェクト情報\nプロジェクト: LLM\n想定使用率（%）: 80\n\n📝 備考\nテスト用予約"
+        );
+    }
+
+    #[test]
+    fn test_render_started_and_ended() {
+        let templates = TemplateConfig {
+            started: Some("{resource}: {user}".to_string()),
+            ..Default::default()
+        };
+        let format = FormatConfig {
+            resource_style: ResourceStyle::Compact,
+            ..Default::default()
+        };
+        let usage = create_test_usage();
+        let renderer = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"));
+
+        assert_eq!(
+            renderer.render_started(&usage, "<@U12345>"),
+            "Thalys 0,1: <@U12345>"
+        );
+        assert!(
+            renderer
+                .render_ended(&usage, "<@U12345>")
+                .starts_with("⏹️ 使用終了\n👤 <@U12345> の予約が終了し、空きました\n")
+        );
+    }
+
+    #[test]
+    fn test_render_default_template_in_english() {
+        let templates = TemplateConfig::default();
+        let format = FormatConfig::default();
+        let usage = create_test_usage().with_visibility(Visibility::Private);
+
+        let rendered = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"))
+            .with_locale(Locale::En)
+            .with_power_state(Some(PowerState::On))
+            .render_created(&usage, "<@U12345>");
+
+        assert!(rendered.starts_with("🔔 New reservation\n👤 Reserved\n"));
+        assert!(rendered.contains("\n\n💻 GPUs\n"));
+        assert!(rendered.ends_with("\n\n⚡ Power: On"));
+    }
+
     #[test]
     fn test_render_with_custom_template() {
         let templates = TemplateConfig {
             created: Some("{user}が{resource}を{time}使います".to_string()),
             updated: None,
             deleted: None,
+            started: None,
+            ended: None,
         };
         let format = FormatConfig {
             resource_style: ResourceStyle::Compact,
@@ -265,7 +580,7 @@ mod tests {
             "A100".to_string(),
         ))];
         assert_eq!(
-            TemplateRenderer::get_resource_label(&resources),
+            TemplateRenderer::get_resource_label(Locale::Ja.messages(), &resources),
             "💻 予約GPU"
         );
     }
@@ -276,11 +591,23 @@ mod tests {
             name: "会議室A".to_string(),
         }];
         assert_eq!(
-            TemplateRenderer::get_resource_label(&resources),
+            TemplateRenderer::get_resource_label(Locale::Ja.messages(), &resources),
             "🏢 予約部屋"
         );
     }
 
+    #[test]
+    fn test_get_resource_label_storage() {
+        let resources = vec![Resource::Storage {
+            volume: "scratch".to_string(),
+            gigabytes: 500,
+        }];
+        assert_eq!(
+            TemplateRenderer::get_resource_label(Locale::Ja.messages(), &resources),
+            "💾 予約ストレージ"
+        );
+    }
+
     #[test]
     fn test_get_resource_label_mixed() {
         let resources = vec![
@@ -290,11 +617,56 @@ mod tests {
             },
         ];
         assert_eq!(
-            TemplateRenderer::get_resource_label(&resources),
+            TemplateRenderer::get_resource_label(Locale::Ja.messages(), &resources),
             "📦 予約リソース"
         );
     }
 
+    #[test]
+    fn test_render_extended_placeholders() {
+        let templates = TemplateConfig {
+            created: Some(
+                "{usage_id}|{owner_email}|{server}|{device_count}|{calendar_link}".to_string(),
+            ),
+            updated: None,
+            deleted: None,
+            started: None,
+            ended: None,
+        };
+        let format = FormatConfig::default();
+
+        let renderer = TemplateRenderer::new(&templates, &format, Some("Asia/Tokyo"))
+            .with_calendar_id(Some("abc@group.calendar.google.com"));
+        let usage = create_test_usage();
+
+        let result = renderer.render_created(&usage, "user");
+
+        assert_eq!(
+            result,
+            format!(
+                "{}|test@example.com|Thalys|2|https://calendar.google.com/calendar/embed?src=abc%40group.calendar.google.com",
+                usage.id().as_str()
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_calendar_link_without_calendar_id() {
+        let templates = TemplateConfig {
+            created: Some("[{calendar_link}]".to_string()),
+            updated: None,
+            deleted: None,
+            started: None,
+            ended: None,
+        };
+        let format = FormatConfig::default();
+
+        let renderer = TemplateRenderer::new(&templates, &format, None);
+        let result = renderer.render_created(&create_test_usage(), "user");
+
+        assert_eq!(result, "[]");
+    }
+
     #[test]
     fn test_render_without_notes() {
         let templates = TemplateConfig::default();
@@ -0,0 +1,79 @@
+//! 予約なしでのGPUの使用の警告
+//!
+//! 予約のないGPUでプロセスが実行されていることを、サーバー・デバイスとUNIXユーザー名を添えてSlackチャンネルに投稿します。
+
+use crate::domain::ports::gpu_monitor::UnreservedGpuUsage;
+use crate::domain::ports::notifier::{NotificationError, UnreservedUsageNotifier};
+use crate::interface::slack::slack_client::{self, messages};
+use async_trait::async_trait;
+use slack_morphism::prelude::*;
+
+/// Slackチャンネルに予約なしでのGPUの使用を投稿する（Bot Token方式）
+pub struct SlackUnreservedUsageNotifier {
+    bot_token: SlackApiToken,
+    /// 警告を投稿するチャンネルID
+    channel_id: String,
+}
+
+impl SlackUnreservedUsageNotifier {
+    /// 新しいSlackUnreservedUsageNotifierを作成
+    ///
+    /// # Arguments
+    /// * `bot_token` - Bot User OAuth Token (xoxb-...)
+    /// * `channel_id` - 警告を投稿するチャンネルID
+    pub fn new(bot_token: String, channel_id: String) -> Self {
+        Self {
+            bot_token: SlackApiToken::new(bot_token.into()),
+            channel_id,
+        }
+    }
+}
+
+#[async_trait]
+impl UnreservedUsageNotifier for SlackUnreservedUsageNotifier {
+    async fn notify_unreserved(&self, usage: &UnreservedGpuUsage) -> Result<(), NotificationError> {
+        let session = slack_client::shared().open_session(&self.bot_token);
+        messages::send_message(
+            &session,
+            SlackChannelId::new(self.channel_id.clone()),
+            SlackMessageContent::new().with_text(unreserved_usage_message(usage)),
+        )
+        .await
+        .map_err(|e| NotificationError::SendFailure(format!("Slack API送信失敗: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// 警告の本文を作成
+fn unreserved_usage_message(usage: &UnreservedGpuUsage) -> String {
+    let pids = usage
+        .pids
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "⚠️ 予約なしでGPUが使用されています\n\n*デバイス*\n{} / GPU:{}\n\n*UNIXユーザー*\n{}\n\n*プロセスID*\n{}\n\n使用を続ける場合は `/reserve` で予約してください。",
+        usage.server, usage.device_number, usage.unix_user, pids
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unreserved_usage_message() {
+        let message = unreserved_usage_message(&UnreservedGpuUsage {
+            server: "Thalys".to_string(),
+            device_number: 1,
+            unix_user: "alice".to_string(),
+            pids: vec![4242, 4343],
+        });
+
+        assert!(message.contains("Thalys / GPU:1"));
+        assert!(message.contains("*UNIXユーザー*\nalice"));
+        assert!(message.contains("4242, 4343"));
+    }
+}
@@ -0,0 +1,92 @@
+//! 空き待ちの希望者への空き通知
+//!
+//! 空き待ちしていた時間帯・リソースが空いたことを、Slackのダイレクトメッセージで希望者本人に知らせます。
+
+use crate::application::usecases::notify_waitlist::WAITLIST_TURN_MINUTES;
+use crate::domain::aggregates::resource_usage::service::format_time_period;
+use crate::domain::aggregates::waitlist::WaitlistEntry;
+use crate::domain::ports::notifier::{NotificationError, WaitlistNotifier};
+use crate::infrastructure::config::{I18nConfig, ResourceStyle};
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::infrastructure::notifier::formatter::format_resources_styled;
+use crate::interface::slack::slack_client::{self, messages};
+use crate::interface::slack::utility::user_resolver;
+use async_trait::async_trait;
+use slack_morphism::prelude::*;
+
+/// Slackのダイレクトメッセージで空きを知らせる（Bot Token方式）
+pub struct SlackWaitlistNotifier {
+    bot_token: SlackApiToken,
+    /// 時刻の表示に使うタイムゾーン（IANA形式、未指定の場合はローカルタイムゾーン）
+    ///
+    /// 希望者のSlackプロフィールにタイムゾーンが設定されている場合はそちらを優先する。
+    timezone: Option<String>,
+    /// 表示言語の設定
+    i18n: I18nConfig,
+}
+
+impl SlackWaitlistNotifier {
+    /// 新しいSlackWaitlistNotifierを作成
+    ///
+    /// # Arguments
+    /// * `bot_token` - Bot User OAuth Token (xoxb-...)
+    /// * `timezone` - 時刻の表示に使うタイムゾーン
+    /// * `i18n` - 表示言語の設定
+    pub fn new(bot_token: String, timezone: Option<String>, i18n: I18nConfig) -> Self {
+        Self {
+            bot_token: SlackApiToken::new(bot_token.into()),
+            timezone,
+            i18n,
+        }
+    }
+}
+
+#[async_trait]
+impl WaitlistNotifier for SlackWaitlistNotifier {
+    async fn notify_available(
+        &self,
+        user_id: &str,
+        entry: &WaitlistEntry,
+    ) -> Result<(), NotificationError> {
+        let user_id = SlackUserId::new(user_id.to_string());
+        let preferences = user_resolver::fetch_user_preferences(
+            slack_client::shared(),
+            &self.bot_token,
+            &user_id,
+            &self.i18n,
+        )
+        .await;
+        let timezone = preferences
+            .timezone
+            .map(|tz| tz.name().to_string())
+            .or_else(|| self.timezone.clone());
+
+        let content = SlackMessageContent::new().with_text(available_message(
+            preferences.messages(),
+            entry,
+            timezone.as_deref(),
+        ));
+
+        let session = slack_client::shared().open_session(&self.bot_token);
+        messages::send_direct_message(&session, &user_id, content)
+            .await
+            .map_err(|e| NotificationError::SendFailure(format!("Slack API送信失敗: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// 空き通知の本文を作成
+fn available_message(messages: &Messages, entry: &WaitlistEntry, timezone: Option<&str>) -> String {
+    fill(
+        messages.waitlist_available,
+        &[
+            (
+                "resources",
+                &format_resources_styled(entry.resources(), ResourceStyle::Full),
+            ),
+            ("time", &format_time_period(entry.time_period(), timezone)),
+            ("minutes", &WAITLIST_TURN_MINUTES.to_string()),
+        ],
+    )
+}
@@ -0,0 +1,251 @@
+//! 週間の利用状況のまとめの投稿
+//!
+//! 先週予約されたGPU時間、よく使われたデバイス、今週の空き、今週開始する大きな予約を
+//! Block Kitのセクション・フィールドでSlackチャンネルに投稿します。
+
+use crate::domain::aggregates::resource_usage::service::format_time_period;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::ports::notifier::NotificationError;
+use crate::domain::ports::weekly_digest::{WeeklyDigest, WeeklyDigestSender};
+use crate::infrastructure::config::ResourceStyle;
+use crate::infrastructure::notifier::formatter::format_resources_styled;
+use crate::interface::slack::slack_client::{self, messages};
+use async_trait::async_trait;
+use chrono::{Duration, Local};
+use chrono_tz::Tz;
+use slack_morphism::prelude::*;
+
+/// 1つのセクションに並べられるフィールドの数（Slackの上限）
+const MAX_FIELDS: usize = 10;
+
+/// Slackチャンネルに週間の利用状況のまとめを投稿する（Bot Token方式）
+pub struct SlackWeeklyDigestSender {
+    bot_token: SlackApiToken,
+    /// 日時の表示に使うタイムゾーン（IANA形式、未指定の場合はローカルタイムゾーン）
+    timezone: Option<String>,
+}
+
+impl SlackWeeklyDigestSender {
+    /// 新しいSlackWeeklyDigestSenderを作成
+    ///
+    /// # Arguments
+    /// * `bot_token` - Bot User OAuth Token (xoxb-...)
+    /// * `timezone` - 日時の表示に使うタイムゾーン
+    pub fn new(bot_token: String, timezone: Option<String>) -> Self {
+        Self {
+            bot_token: SlackApiToken::new(bot_token.into()),
+            timezone,
+        }
+    }
+}
+
+#[async_trait]
+impl WeeklyDigestSender for SlackWeeklyDigestSender {
+    async fn send_digest(
+        &self,
+        channel_id: &str,
+        digest: &WeeklyDigest,
+    ) -> Result<(), NotificationError> {
+        let session = slack_client::shared().open_session(&self.bot_token);
+        messages::send_message(
+            &session,
+            SlackChannelId::new(channel_id.to_string()),
+            SlackMessageContent::new()
+                .with_text("📊 週間の利用状況".to_string())
+                .with_blocks(digest_blocks(digest, self.timezone.as_deref())),
+        )
+        .await
+        .map_err(|e| NotificationError::SendFailure(format!("Slack API送信失敗: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// まとめのブロックを作成
+fn digest_blocks(digest: &WeeklyDigest, timezone: Option<&str>) -> Vec<SlackBlock> {
+    let busiest = if digest.busiest_devices.is_empty() {
+        "なし".to_string()
+    } else {
+        digest
+            .busiest_devices
+            .iter()
+            .enumerate()
+            .map(|(rank, device)| {
+                format!(
+                    "{}. {} / GPU:{}（{}）",
+                    rank + 1,
+                    device.server,
+                    device.device_number,
+                    format_hours(device.time)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let capacities: Vec<SlackBlockText> = digest
+        .capacities
+        .iter()
+        .take(MAX_FIELDS)
+        .map(|capacity| {
+            let percent = if capacity.total > Duration::zero() {
+                capacity.free().num_minutes() * 100 / capacity.total.num_minutes()
+            } else {
+                0
+            };
+            md!(
+                "*{}*\n空き {} / {}（{}%）",
+                capacity.server,
+                format_hours(capacity.free()),
+                format_hours(capacity.total),
+                percent
+            )
+        })
+        .collect();
+
+    let big_bookings = if digest.big_bookings.is_empty() {
+        "なし".to_string()
+    } else {
+        digest
+            .big_bookings
+            .iter()
+            .map(|usage| {
+                let gpus = usage
+                    .resources()
+                    .iter()
+                    .filter(|resource| matches!(resource, Resource::Gpu(_)))
+                    .count();
+                let period = usage.time_period();
+                format!(
+                    "• {} {}（{} GPU時間）",
+                    format_resources_styled(usage.resources(), ResourceStyle::Compact)
+                        .replace('\n', ", "),
+                    format_time_period(period, timezone),
+                    (period.end() - period.start()).num_hours() * gpus as i64
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    vec![
+        SlackBlock::Header(SlackHeaderBlock::new(pt!(
+            "📊 週間の利用状況（{}）",
+            format_week(&digest.this_week, timezone)
+        ))),
+        SlackBlock::Section(
+            SlackSectionBlock::new()
+                .with_text(md!(
+                    "*先週*（{}）",
+                    format_week(&digest.last_week, timezone)
+                ))
+                .with_fields(vec![
+                    md!(
+                        "*予約されたGPU時間*\n{}",
+                        format_hours(digest.reserved_gpu_time)
+                    ),
+                    md!("*よく予約されたデバイス*\n{}", busiest),
+                ]),
+        ),
+        SlackBlock::Divider(SlackDividerBlock::new()),
+        if capacities.is_empty() {
+            SlackBlock::Section(SlackSectionBlock::new().with_text(md!("*今週の空き*\nなし")))
+        } else {
+            SlackBlock::Section(
+                SlackSectionBlock::new()
+                    .with_text(md!("*今週の空き*"))
+                    .with_fields(capacities),
+            )
+        },
+        SlackBlock::Section(
+            SlackSectionBlock::new().with_text(md!("*今週の大きな予約*\n{}", big_bookings)),
+        ),
+    ]
+}
+
+/// 週の期間を表示用に整形（例: "03/09 - 03/15"、最終日を含む）
+fn format_week(week: &TimePeriod, timezone: Option<&str>) -> String {
+    let last_day = week.end() - Duration::seconds(1);
+    match timezone.and_then(|tz| tz.parse::<Tz>().ok()) {
+        Some(tz) => format!(
+            "{} - {}",
+            week.start().with_timezone(&tz).format("%m/%d"),
+            last_day.with_timezone(&tz).format("%m/%d")
+        ),
+        None => format!(
+            "{} - {}",
+            week.start().with_timezone(&Local).format("%m/%d"),
+            last_day.with_timezone(&Local).format("%m/%d")
+        ),
+    }
+}
+
+/// 時間を時間単位で表示（端数は切り捨て）
+fn format_hours(time: Duration) -> String {
+    format!("{}時間", time.num_hours())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use crate::domain::common::EmailAddress;
+    use crate::domain::ports::weekly_digest::{DeviceUsageTime, ServerCapacity};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_digest_blocks() {
+        let this_week_start = Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap();
+        let last_week =
+            TimePeriod::new(this_week_start - Duration::days(7), this_week_start).unwrap();
+        let this_week =
+            TimePeriod::new(this_week_start, this_week_start + Duration::days(7)).unwrap();
+        let big_booking = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                this_week_start + Duration::hours(9),
+                this_week_start + Duration::hours(33),
+            )
+            .unwrap(),
+            vec![
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string())),
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 1, "A100".to_string())),
+            ],
+            None,
+        )
+        .unwrap();
+        let digest = WeeklyDigest {
+            last_week,
+            this_week,
+            reserved_gpu_time: Duration::hours(52),
+            busiest_devices: vec![
+                DeviceUsageTime {
+                    server: "Thalys".to_string(),
+                    device_number: 1,
+                    time: Duration::hours(40),
+                },
+                DeviceUsageTime {
+                    server: "Thalys".to_string(),
+                    device_number: 0,
+                    time: Duration::hours(12),
+                },
+            ],
+            big_bookings: vec![big_booking],
+            capacities: vec![ServerCapacity {
+                server: "Thalys".to_string(),
+                total: Duration::hours(336),
+                reserved: Duration::hours(48),
+            }],
+        };
+
+        let json = serde_json::to_string(&digest_blocks(&digest, Some("UTC"))).unwrap();
+
+        assert!(json.contains("週間の利用状況（03/10 - 03/16）"));
+        assert!(json.contains("*先週*（03/03 - 03/09）"));
+        assert!(json.contains("*予約されたGPU時間*\\n52時間"));
+        assert!(json.contains("1. Thalys / GPU:1（40時間）\\n2. Thalys / GPU:0（12時間）"));
+        assert!(json.contains("*Thalys*\\n空き 288時間 / 336時間（85%）"));
+        assert!(json.contains("（48 GPU時間）"));
+    }
+}
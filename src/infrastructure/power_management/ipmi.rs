@@ -0,0 +1,91 @@
+use crate::domain::ports::power_management::{PowerManagementError, PowerState};
+use tokio::process::Command;
+
+/// ipmitoolを使用したIPMIクライアント
+///
+/// パスワードはプロセス一覧に表示されないよう、環境変数 `IPMI_PASSWORD` 経由（`-E`）で渡す。
+pub struct IpmiClient {
+    host: String,
+    username: String,
+    password: String,
+}
+
+impl IpmiClient {
+    /// 新しいクライアントを作成
+    ///
+    /// # Arguments
+    /// * `host` - BMCのホスト名またはIPアドレス
+    /// * `username` - BMCのユーザー名
+    /// * `password` - BMCのパスワード
+    pub fn new(host: &str, username: &str, password: &str) -> Self {
+        Self {
+            host: host.to_string(),
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    /// 電源状態を取得
+    pub async fn power_state(&self) -> Result<PowerState, PowerManagementError> {
+        let output = self.chassis_power("status").await?;
+        Ok(parse_power_status(&output))
+    }
+
+    /// 電源を入れる
+    pub async fn power_on(&self) -> Result<(), PowerManagementError> {
+        self.chassis_power("on").await.map(|_| ())
+    }
+
+    /// `ipmitool chassis power <command>` を実行し、標準出力を返す
+    async fn chassis_power(&self, command: &str) -> Result<String, PowerManagementError> {
+        let output = Command::new("ipmitool")
+            .args([
+                "-I",
+                "lanplus",
+                "-H",
+                &self.host,
+                "-U",
+                &self.username,
+                "-E",
+            ])
+            .args(["chassis", "power", command])
+            .env("IPMI_PASSWORD", &self.password)
+            .output()
+            .await
+            .map_err(|e| PowerManagementError::Unknown(format!("ipmitoolの実行に失敗: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(PowerManagementError::ConnectionError(format!(
+                "ipmitool chassis power {} が失敗しました: {}",
+                command,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// `chassis power status` の出力（例: "Chassis Power is on"）を電源状態に変換
+fn parse_power_status(output: &str) -> PowerState {
+    match output.trim().rsplit(' ').next() {
+        Some("on") => PowerState::On,
+        Some("off") => PowerState::Off,
+        _ => PowerState::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_power_status() {
+        assert_eq!(parse_power_status("Chassis Power is on\n"), PowerState::On);
+        assert_eq!(
+            parse_power_status("Chassis Power is off\n"),
+            PowerState::Off
+        );
+        assert_eq!(parse_power_status(""), PowerState::Unknown);
+    }
+}
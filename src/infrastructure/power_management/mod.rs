@@ -0,0 +1,18 @@
+//! # PowerManagement Service Implementations
+//!
+//! PowerManagementServiceポートの具象実装を提供します。
+//!
+//! - `redfish`: Redfish APIを使用したBMCクライアント
+//! - `ipmi`: ipmitoolを使用したBMCクライアント
+//! - `router`: サーバーごとにBMCクライアントを振り分ける実装
+
+/// ipmitoolを使用したBMCクライアント
+pub mod ipmi;
+/// Redfish APIを使用したBMCクライアント
+pub mod redfish;
+/// サーバーごとにBMCクライアントを振り分ける電源管理サービス実装
+pub mod router;
+
+pub use ipmi::IpmiClient;
+pub use redfish::RedfishClient;
+pub use router::PowerManagementRouter;
@@ -0,0 +1,123 @@
+use crate::domain::ports::power_management::{PowerManagementError, PowerState};
+use serde::Deserialize;
+use serde_json::json;
+
+/// Redfish APIを使用したBMCクライアント
+///
+/// `/redfish/v1/Systems/{system_id}` の `PowerState` で電源状態を取得し、
+/// `ComputerSystem.Reset` アクションで電源を入れる。
+pub struct RedfishClient {
+    http_client: reqwest::Client,
+    system_url: String,
+    username: String,
+    password: String,
+}
+
+#[derive(Deserialize)]
+struct ComputerSystem {
+    #[serde(rename = "PowerState")]
+    power_state: Option<String>,
+}
+
+impl RedfishClient {
+    /// 新しいクライアントを作成
+    ///
+    /// # Arguments
+    /// * `address` - BMCのURL
+    /// * `system_id` - RedfishのシステムID
+    /// * `username` - BMCのユーザー名
+    /// * `password` - BMCのパスワード
+    /// * `accept_invalid_certs` - 自己署名証明書を許可するかどうか
+    pub fn new(
+        address: &str,
+        system_id: &str,
+        username: &str,
+        password: &str,
+        accept_invalid_certs: bool,
+    ) -> Result<Self, PowerManagementError> {
+        let http_client = reqwest::Client::builder()
+            .danger_accept_invalid_certs(accept_invalid_certs)
+            .build()
+            .map_err(|e| {
+                PowerManagementError::Unknown(format!("HTTPクライアントの作成に失敗: {}", e))
+            })?;
+
+        Ok(Self {
+            http_client,
+            system_url: format!(
+                "{}/redfish/v1/Systems/{}",
+                address.trim_end_matches('/'),
+                system_id
+            ),
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    /// 電源状態を取得
+    pub async fn power_state(&self) -> Result<PowerState, PowerManagementError> {
+        let system: ComputerSystem = self
+            .http_client
+            .get(&self.system_url)
+            .basic_auth(&self.username, Some(&self.password))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                PowerManagementError::ConnectionError(format!("電源状態の取得に失敗: {}", e))
+            })?
+            .json()
+            .await
+            .map_err(|e| {
+                PowerManagementError::Unknown(format!("Redfishの応答を解釈できません: {}", e))
+            })?;
+
+        Ok(parse_power_state(system.power_state.as_deref()))
+    }
+
+    /// 電源を入れる
+    pub async fn power_on(&self) -> Result<(), PowerManagementError> {
+        self.http_client
+            .post(format!("{}/Actions/ComputerSystem.Reset", self.system_url))
+            .basic_auth(&self.username, Some(&self.password))
+            .json(&json!({ "ResetType": "On" }))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| PowerManagementError::ConnectionError(format!("電源投入に失敗: {}", e)))?;
+        Ok(())
+    }
+}
+
+/// Redfishの `PowerState` を電源状態に変換
+fn parse_power_state(value: Option<&str>) -> PowerState {
+    match value {
+        Some("On") => PowerState::On,
+        Some("Off") => PowerState::Off,
+        _ => PowerState::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_power_state() {
+        assert_eq!(parse_power_state(Some("On")), PowerState::On);
+        assert_eq!(parse_power_state(Some("Off")), PowerState::Off);
+        assert_eq!(parse_power_state(Some("PoweringOn")), PowerState::Unknown);
+        assert_eq!(parse_power_state(None), PowerState::Unknown);
+    }
+
+    #[test]
+    fn test_system_url_ignores_trailing_slash() {
+        let client =
+            RedfishClient::new("https://bmc.example.com/", "1", "admin", "secret", false).unwrap();
+
+        assert_eq!(
+            client.system_url,
+            "https://bmc.example.com/redfish/v1/Systems/1"
+        );
+    }
+}
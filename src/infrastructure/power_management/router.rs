@@ -0,0 +1,92 @@
+use crate::domain::ports::power_management::{
+    PowerManagementError, PowerManagementService, PowerState,
+};
+use crate::infrastructure::config::{PowerConfig, ResourceConfig};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use super::{IpmiClient, RedfishClient};
+
+/// サーバーごとのBMCクライアント
+enum PowerController {
+    Redfish(RedfishClient),
+    Ipmi(IpmiClient),
+}
+
+/// サーバー名に基づいて適切なBMCクライアントに電源操作を振り分ける
+///
+/// リソース設定で `power` が設定されているサーバーのみを管理対象とする。
+pub struct PowerManagementRouter {
+    controllers: HashMap<String, PowerController>,
+}
+
+impl PowerManagementRouter {
+    /// リソース設定から電源管理ルーターを作成
+    ///
+    /// # Arguments
+    /// * `config` - リソース設定
+    pub fn new(config: &ResourceConfig) -> Result<Self, PowerManagementError> {
+        let mut controllers = HashMap::new();
+        for server in &config.servers {
+            let Some(power) = &server.power else {
+                continue;
+            };
+            let controller = match power {
+                PowerConfig::Redfish {
+                    address,
+                    username,
+                    password,
+                    system_id,
+                    accept_invalid_certs,
+                    ..
+                } => PowerController::Redfish(RedfishClient::new(
+                    address,
+                    system_id,
+                    username,
+                    password,
+                    *accept_invalid_certs,
+                )?),
+                PowerConfig::Ipmi {
+                    host,
+                    username,
+                    password,
+                    ..
+                } => PowerController::Ipmi(IpmiClient::new(host, username, password)),
+            };
+            controllers.insert(server.name.clone(), controller);
+        }
+        Ok(Self { controllers })
+    }
+
+    /// 電源管理の対象となるサーバーがあるかどうか
+    pub fn is_empty(&self) -> bool {
+        self.controllers.is_empty()
+    }
+
+    fn controller(&self, server: &str) -> Result<&PowerController, PowerManagementError> {
+        self.controllers
+            .get(server)
+            .ok_or_else(|| PowerManagementError::NotConfigured(server.to_string()))
+    }
+}
+
+#[async_trait]
+impl PowerManagementService for PowerManagementRouter {
+    fn is_managed(&self, server: &str) -> bool {
+        self.controllers.contains_key(server)
+    }
+
+    async fn power_state(&self, server: &str) -> Result<PowerState, PowerManagementError> {
+        match self.controller(server)? {
+            PowerController::Redfish(client) => client.power_state().await,
+            PowerController::Ipmi(client) => client.power_state().await,
+        }
+    }
+
+    async fn power_on(&self, server: &str) -> Result<(), PowerManagementError> {
+        match self.controller(server)? {
+            PowerController::Redfish(client) => client.power_on().await,
+            PowerController::Ipmi(client) => client.power_on().await,
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! # Rate Limit
+//!
+//! Slack APIの呼び出し回数を制限するトークンバケットを提供します。
+//!
+//! 通知の送信（`SlackSender` と、`slack_client::messages` を通してDM・チャンネルに投稿する各通知）と
+//! Slackのインタラクションへの応答は [`RateLimiter::slack`] の同じバケットを共有する。トークンがない間、呼び出しは到着順に待たされる。
+//! Slackが429（Too Many Requests）を返した場合は、`Retry-After` の間すべての呼び出しを止めてから再試行する。
+
+use slack_morphism::errors::SlackClientError;
+use slack_morphism::prelude::ClientResult;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::warn;
+
+/// 連続して呼び出せる回数（バケットの容量）
+const SLACK_BURST: u32 = 5;
+
+/// 1秒あたりに補充する呼び出し回数
+const SLACK_REFILL_PER_SEC: f64 = 1.0;
+
+/// 429を受けたときに再試行する回数
+const MAX_RETRIES: u32 = 3;
+
+/// `Retry-After` が返されなかった場合の待ち時間
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// トークンバケット方式のレートリミッター
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    /// 待っている呼び出しはこのロックの順番待ちになる（tokioのMutexは到着順）
+    bucket: tokio::sync::Mutex<Bucket>,
+    paused_until: Mutex<Option<Instant>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// 新しいレートリミッターを作成
+    ///
+    /// # Arguments
+    /// * `capacity` - 連続して呼び出せる回数
+    /// * `refill_per_sec` - 1秒あたりに補充する回数
+    pub fn new(capacity: u32, refill_per_sec: f64) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_sec,
+            bucket: tokio::sync::Mutex::new(Bucket {
+                tokens: f64::from(capacity),
+                refilled_at: Instant::now(),
+            }),
+            paused_until: Mutex::new(None),
+        }
+    }
+
+    /// Slack APIの呼び出しで共有するレートリミッター
+    pub fn slack() -> &'static RateLimiter {
+        static SLACK: OnceLock<RateLimiter> = OnceLock::new();
+        SLACK.get_or_init(|| RateLimiter::new(SLACK_BURST, SLACK_REFILL_PER_SEC))
+    }
+
+    /// 呼び出しを1回分確保する（トークンがなければ補充されるまで待つ）
+    pub async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        loop {
+            let paused_until = *self.paused_until.lock().expect("lock poisoned");
+            if let Some(until) = paused_until
+                && until > Instant::now()
+            {
+                tokio::time::sleep_until(until).await;
+                continue;
+            }
+
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+            bucket.refilled_at = now;
+
+            if bucket.tokens >= 1.0 {
+                bucket.tokens -= 1.0;
+                return;
+            }
+            let wait = (1.0 - bucket.tokens) / self.refill_per_sec;
+            tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+        }
+    }
+
+    /// 指定した時間、すべての呼び出しを止める
+    pub fn pause(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut paused_until = self.paused_until.lock().expect("lock poisoned");
+        if paused_until.is_none_or(|current| current < until) {
+            *paused_until = Some(until);
+        }
+    }
+
+    /// レート制限に従ってSlack APIを呼び出す
+    ///
+    /// 429を受けた場合は `Retry-After` の間待ってから、最大 `MAX_RETRIES` 回再試行する。
+    pub async fn call<T, F, Fut>(&self, mut request: F) -> ClientResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = ClientResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            self.acquire().await;
+            match request().await {
+                Err(SlackClientError::RateLimitError(e)) if attempt < MAX_RETRIES => {
+                    attempt += 1;
+                    let retry_after = e.retry_after.unwrap_or(DEFAULT_RETRY_AFTER);
+                    warn!(
+                        "⏳ Slack APIのレート制限に達しました。{:?} 後に再試行します（{}/{}）",
+                        retry_after, attempt, MAX_RETRIES
+                    );
+                    self.pause(retry_after);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// レート制限に従ってHTTPリクエストを送信する（response URLへの送信用）
+    ///
+    /// 429を受けた場合は `Retry-After` ヘッダーの秒数だけ待ってから、最大 `MAX_RETRIES` 回再試行する。
+    pub async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let mut attempt = 0;
+        loop {
+            self.acquire().await;
+            let Some(current) = request.try_clone() else {
+                return request.send().await;
+            };
+            let response = current.send().await?;
+            if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS || attempt >= MAX_RETRIES
+            {
+                return Ok(response);
+            }
+
+            attempt += 1;
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_RETRY_AFTER);
+            warn!(
+                "⏳ Slackのレート制限に達しました。{:?} 後に再試行します（{}/{}）",
+                retry_after, attempt, MAX_RETRIES
+            );
+            self.pause(retry_after);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use slack_morphism::errors::SlackRateLimitError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn test_acquire_waits_when_bucket_is_empty() {
+        let limiter = RateLimiter::new(2, 20.0);
+        let started = Instant::now();
+
+        for _ in 0..4 {
+            limiter.acquire().await;
+        }
+
+        // 2回は即座に、残り2回は補充（50msごと）を待つ
+        assert!(started.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn test_call_retries_after_rate_limit() {
+        let limiter = RateLimiter::new(5, 100.0);
+        let calls = AtomicU32::new(0);
+        let started = Instant::now();
+
+        let result = limiter
+            .call(|| async {
+                if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(SlackClientError::RateLimitError(
+                        SlackRateLimitError::new().with_retry_after(Duration::from_millis(100)),
+                    ))
+                } else {
+                    Ok("sent")
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), "sent");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(started.elapsed() >= Duration::from_millis(100));
+    }
+}
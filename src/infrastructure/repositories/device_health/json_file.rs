@@ -0,0 +1,186 @@
+use crate::domain::aggregates::device_health::{DeviceHealth, DeviceStatus};
+use crate::domain::ports::repositories::{DeviceHealthRepository, RepositoryError};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+/// サーバー名 → デバイス番号 → 状態
+type DeviceHealthData = BTreeMap<String, BTreeMap<u32, DeviceHealthDto>>;
+
+/// JSON file storage for DeviceHealth
+///
+/// ファイルフォーマット:
+/// ```json
+/// {
+///   "Thalys": {
+///     "1": {
+///       "status": "out-of-service",
+///       "note": "ファン故障"
+///     }
+///   }
+/// }
+/// ```
+pub struct JsonFileDeviceHealthRepository {
+    file_path: PathBuf,
+    /// サーバー名・デバイス番号をキーとした状態（未読み込みの場合は `None`）
+    cache: RwLock<Option<DeviceHealthData>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeviceHealthDto {
+    status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+impl JsonFileDeviceHealthRepository {
+    /// 新しいJSONファイルベースのリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `file_path` - JSONファイルのパス
+    pub fn new(file_path: PathBuf) -> Self {
+        Self {
+            file_path,
+            cache: RwLock::new(None),
+        }
+    }
+
+    async fn load(&self) -> Result<DeviceHealthData, RepositoryError> {
+        let content = match tokio::fs::read_to_string(&self.file_path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // ファイルが存在しない場合はすべて使用可能として扱う
+                return Ok(BTreeMap::new());
+            }
+            Err(e) => {
+                return Err(RepositoryError::Unknown(format!(
+                    "ファイルの読み込みに失敗: {}",
+                    e
+                )));
+            }
+        };
+
+        serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))
+    }
+
+    /// キャッシュが未読み込みの場合、ファイルから読み込む
+    async fn ensure_loaded(&self) -> Result<(), RepositoryError> {
+        if self.cache.read().await.is_some() {
+            return Ok(());
+        }
+        let data = self.load().await?;
+        self.cache.write().await.get_or_insert(data);
+        Ok(())
+    }
+
+    async fn save_to_file(&self, data: &DeviceHealthData) -> Result<(), RepositoryError> {
+        let content = serde_json::to_string_pretty(data)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
+
+        // 親ディレクトリが存在しない場合は作成
+        if let Some(parent) = self.file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::Unknown(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        tokio::fs::write(&self.file_path, content)
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))
+    }
+}
+
+#[async_trait]
+impl DeviceHealthRepository for JsonFileDeviceHealthRepository {
+    async fn find_all(&self) -> Result<Vec<DeviceHealth>, RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let cache = self.cache.read().await;
+        let mut healths = Vec::new();
+        for (server_name, devices) in cache.iter().flatten() {
+            for (device_id, dto) in devices {
+                let status: DeviceStatus = dto.status.parse().map_err(|_| {
+                    RepositoryError::Unknown(format!(
+                        "{} のデバイス {} の状態が不正です: {}",
+                        server_name, device_id, dto.status
+                    ))
+                })?;
+                healths.push(DeviceHealth::new(
+                    server_name.clone(),
+                    *device_id,
+                    status,
+                    dto.note.clone(),
+                ));
+            }
+        }
+        Ok(healths)
+    }
+
+    async fn save(&self, health: DeviceHealth) -> Result<(), RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(BTreeMap::new);
+        data.entry(health.server_name().to_string())
+            .or_default()
+            .insert(
+                health.device_id(),
+                DeviceHealthDto {
+                    status: health.status().as_str().to_string(),
+                    note: health.note().map(str::to_string),
+                },
+            );
+        self.save_to_file(data).await
+    }
+
+    async fn delete(&self, server_name: &str, device_id: u32) -> Result<bool, RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(BTreeMap::new);
+        let Some(devices) = data.get_mut(server_name) else {
+            return Ok(false);
+        };
+        if devices.remove(&device_id).is_none() {
+            return Ok(false);
+        }
+        if devices.is_empty() {
+            data.remove(server_name);
+        }
+        self.save_to_file(data).await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_find_and_delete() {
+        let file_path = std::env::temp_dir()
+            .join(format!("lrm_device_health_{}", uuid::Uuid::new_v4()))
+            .join("device_statuses.json");
+        let repo = JsonFileDeviceHealthRepository::new(file_path.clone());
+        assert!(repo.find_all().await.unwrap().is_empty());
+
+        let health = DeviceHealth::new(
+            "Thalys".to_string(),
+            1,
+            DeviceStatus::OutOfService,
+            Some("ファン故障".to_string()),
+        );
+        repo.save(health.clone()).await.unwrap();
+
+        // 別のインスタンスからも読み込める
+        let reopened = JsonFileDeviceHealthRepository::new(file_path);
+        assert_eq!(reopened.find_all().await.unwrap(), vec![health]);
+
+        assert!(reopened.delete("Thalys", 1).await.unwrap());
+        assert!(!reopened.delete("Thalys", 1).await.unwrap());
+        assert!(reopened.find_all().await.unwrap().is_empty());
+    }
+}
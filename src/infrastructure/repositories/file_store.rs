@@ -0,0 +1,237 @@
+//! JSONデータファイルの安全な読み書き
+//!
+//! - 書き込みは一時ファイル（`<ファイル名>.tmp`）に書いてから置き換えるため、書き込み途中でプロセスが
+//!   停止しても元のファイルが残る。置き換える前の内容は `<ファイル名>.bak` に残す
+//! - 書き込む側は `<ファイル名>.lock` の排他ロック（アドバイザリロック）を取る。手作業のスクリプトも
+//!   同じロックを取れば（例: `flock identity_links.json.lock ...`）、Botと同時に書き込んでも壊れない
+//! - 読み込み時に本体が壊れている場合は、一時ファイル、`.bak` の順に読めるものから復旧する。
+//!   復旧した内容は次の書き込みで本体に保存される
+
+use crate::domain::ports::repositories::RepositoryError;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::ffi::OsStr;
+use std::fs::{File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+/// データファイルの排他ロック（破棄すると解放される）
+pub struct FileLockGuard {
+    _file: File,
+}
+
+/// データファイルの排他ロックを取る（他のプロセスが持っている間は待つ）
+pub async fn lock(path: &Path) -> Result<FileLockGuard, RepositoryError> {
+    let lock_path = sibling(path, "lock");
+    create_parent_dir(path).await?;
+
+    let file = tokio::task::spawn_blocking(move || {
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)?;
+        file.lock()?;
+        Ok::<_, std::io::Error>(file)
+    })
+    .await
+    .map_err(|e| RepositoryError::Unknown(format!("ファイルロックの取得に失敗: {}", e)))?
+    .map_err(|e| {
+        RepositoryError::ConnectionError(format!(
+            "ファイルロックの取得に失敗（{}）: {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    Ok(FileLockGuard { _file: file })
+}
+
+/// JSONファイルを読み込む（ファイルが無い場合は `None`）
+///
+/// 本体が壊れている場合は一時ファイル、`.bak` の順に復旧を試みる。
+/// 本体が無い場合は、最初の書き込みの途中で停止した一時ファイルのみ復旧の対象にする。
+pub async fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, RepositoryError> {
+    let parse_error = match tokio::fs::read_to_string(path).await {
+        Ok(content) => match serde_json::from_str(&content) {
+            Ok(value) => return Ok(Some(value)),
+            Err(e) => Some(e),
+        },
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => {
+            return Err(RepositoryError::ConnectionError(format!(
+                "ファイルの読み込みに失敗（{}）: {}",
+                path.display(),
+                e
+            )));
+        }
+    };
+
+    let mut candidates = vec![sibling(path, "tmp")];
+    if parse_error.is_some() {
+        candidates.push(sibling(path, "bak"));
+    }
+    for candidate in candidates {
+        let Ok(content) = tokio::fs::read_to_string(&candidate).await else {
+            continue;
+        };
+        if let Ok(value) = serde_json::from_str(&content) {
+            warn!(
+                "⚠️  {} を読み込めないため、{} から復旧しました",
+                path.display(),
+                candidate.display()
+            );
+            return Ok(Some(value));
+        }
+    }
+
+    match parse_error {
+        Some(e) => Err(RepositoryError::Unknown(format!(
+            "JSONのパースに失敗（{}）: {}",
+            path.display(),
+            e
+        ))),
+        None => Ok(None),
+    }
+}
+
+/// 値をJSONとしてファイルに書き込む
+///
+/// 他のプロセスと同時に書き込む可能性がある場合は、呼び出し側で [`lock`] を取っておく。
+pub async fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), RepositoryError> {
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
+    write_atomic(path, &content).await
+}
+
+/// 一時ファイル経由でファイルを置き換える
+///
+/// 他のプロセスと同時に書き込む可能性がある場合は、呼び出し側で [`lock`] を取っておく。
+pub async fn write_atomic(path: &Path, content: impl AsRef<[u8]>) -> Result<(), RepositoryError> {
+    let write_error = |e: std::io::Error| {
+        RepositoryError::ConnectionError(format!(
+            "ファイルの書き込みに失敗（{}）: {}",
+            path.display(),
+            e
+        ))
+    };
+    create_parent_dir(path).await?;
+
+    let tmp_path = sibling(path, "tmp");
+    let mut file = tokio::fs::File::create(&tmp_path)
+        .await
+        .map_err(write_error)?;
+    file.write_all(content.as_ref())
+        .await
+        .map_err(write_error)?;
+    file.sync_all().await.map_err(write_error)?;
+    drop(file);
+
+    // 置き換える前の内容を残す（本体は常に存在するよう、ハードリンクで残す）
+    let bak_path = sibling(path, "bak");
+    match tokio::fs::remove_file(&bak_path).await {
+        Ok(()) => {}
+        Err(e) if e.kind() == ErrorKind::NotFound => {}
+        Err(e) => return Err(write_error(e)),
+    }
+    if tokio::fs::hard_link(path, &bak_path).await.is_err() && path.exists() {
+        tokio::fs::copy(path, &bak_path)
+            .await
+            .map_err(write_error)?;
+    }
+
+    tokio::fs::rename(&tmp_path, path)
+        .await
+        .map_err(write_error)?;
+    Ok(())
+}
+
+async fn create_parent_dir(path: &Path) -> Result<(), RepositoryError> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        tokio::fs::create_dir_all(parent).await.map_err(|e| {
+            RepositoryError::ConnectionError(format!("ディレクトリの作成に失敗: {}", e))
+        })?;
+    }
+    Ok(())
+}
+
+/// 同じディレクトリの `<ファイル名>.<extension>` のパス
+fn sibling(path: &Path, extension: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or(OsStr::new("data")).to_owned();
+    name.push(".");
+    name.push(extension);
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn temp_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("lrm_file_store_{}", uuid::Uuid::new_v4()))
+            .join("data.json")
+    }
+
+    fn data(value: u32) -> HashMap<String, u32> {
+        HashMap::from([("key".to_string(), value)])
+    }
+
+    #[tokio::test]
+    async fn test_recovers_from_backup_when_file_is_corrupted() {
+        let path = temp_path();
+        write_json(&path, &data(1)).await.unwrap();
+        write_json(&path, &data(2)).await.unwrap();
+        assert_eq!(read_json(&path).await.unwrap(), Some(data(2)));
+
+        // 本体が途中まで書き込まれた状態
+        std::fs::write(&path, "{\"key\": ").unwrap();
+
+        assert_eq!(read_json(&path).await.unwrap(), Some(data(1)));
+    }
+
+    #[tokio::test]
+    async fn test_recovers_unfinished_first_write() {
+        let path = temp_path();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(sibling(&path, "tmp"), "{\"key\": 3}").unwrap();
+
+        assert_eq!(read_json(&path).await.unwrap(), Some(data(3)));
+    }
+
+    #[tokio::test]
+    async fn test_corrupted_file_without_backup_is_an_error() {
+        let path = temp_path();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not json").unwrap();
+
+        assert!(read_json::<HashMap<String, u32>>(&path).await.is_err());
+        assert_eq!(
+            read_json::<HashMap<String, u32>>(&temp_path())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_lock_is_exclusive() {
+        let path = temp_path();
+        let guard = lock(&path).await.unwrap();
+
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), lock(&path))
+                .await
+                .is_err()
+        );
+
+        drop(guard);
+        assert!(lock(&path).await.is_ok());
+    }
+}
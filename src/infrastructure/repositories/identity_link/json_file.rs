@@ -4,6 +4,7 @@ use crate::domain::aggregates::identity_link::{
 };
 use crate::domain::common::EmailAddress;
 use crate::domain::ports::repositories::{IdentityLinkRepository, RepositoryError};
+use crate::infrastructure::repositories::file_store;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -22,7 +23,13 @@ use tokio::sync::RwLock;
 ///       {
 ///         "system": "slack",
 ///         "user_id": "U12345678",
+///         "workspace_id": "T12345678",
 ///         "linked_at": "2024-01-01T00:00:00Z"
+///       },
+///       {
+///         "system": "github",
+///         "user_id": "octocat",
+///         "linked_at": "2024-01-02T00:00:00Z"
 ///       }
 ///     ],
 ///     "created_at": "2024-01-01T00:00:00Z",
@@ -47,6 +54,8 @@ struct IdentityLinkDto {
 struct ExternalIdentityDto {
     system: String,
     user_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    workspace_id: Option<String>,
     linked_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -58,6 +67,7 @@ impl IdentityLinkDto {
             .map(|id| ExternalIdentityDto {
                 system: id.system().as_str().to_string(),
                 user_id: id.user_id().to_string(),
+                workspace_id: id.workspace_id().map(str::to_string),
                 linked_at: id.linked_at(),
             })
             .collect();
@@ -79,7 +89,12 @@ impl IdentityLinkDto {
             .filter_map(|dto| {
                 // 現在サポートしているシステムのみ復元
                 ExternalSystem::from_str(&dto.system).ok().map(|system| {
-                    ExternalIdentity::reconstitute(system, dto.user_id.clone(), dto.linked_at)
+                    ExternalIdentity::reconstitute(
+                        system,
+                        dto.user_id.clone(),
+                        dto.workspace_id.clone(),
+                        dto.linked_at,
+                    )
                 })
             })
             .collect();
@@ -107,23 +122,13 @@ impl JsonFileIdentityLinkRepository {
         }
     }
 
+    /// ファイルの内容をキャッシュに読み込む
+    ///
+    /// ファイルが壊れている場合は、直前の内容から復旧する。
     async fn load(&self) -> Result<(), RepositoryError> {
-        let content = match tokio::fs::read_to_string(&self.file_path).await {
-            Ok(content) => content,
-            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-                // ファイルが存在しない場合は空の状態として扱う
-                return Ok(());
-            }
-            Err(e) => {
-                return Err(RepositoryError::Unknown(format!(
-                    "ファイルの読み込みに失敗: {}",
-                    e
-                )));
-            }
-        };
-
-        let data: HashMap<String, IdentityLinkDto> = serde_json::from_str(&content)
-            .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))?;
+        let data: HashMap<String, IdentityLinkDto> = file_store::read_json(&self.file_path)
+            .await?
+            .unwrap_or_default();
 
         let mut cache = self.cache.write().await;
         *cache = data;
@@ -139,24 +144,19 @@ impl JsonFileIdentityLinkRepository {
         Ok(())
     }
 
-    async fn save_to_file(&self) -> Result<(), RepositoryError> {
-        let cache = self.cache.read().await;
-
-        let content = serde_json::to_string_pretty(&*cache)
-            .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
-
-        // 親ディレクトリが存在しない場合は作成
-        if let Some(parent) = self.file_path.parent() {
-            tokio::fs::create_dir_all(parent).await.map_err(|e| {
-                RepositoryError::Unknown(format!("ディレクトリの作成に失敗: {}", e))
-            })?;
-        }
+    /// すべてのIdentityLinkを取得
+    ///
+    /// 他の保存先への移行に使用する。
+    pub async fn find_all(&self) -> Result<Vec<IdentityLink>, RepositoryError> {
+        self.ensure_loaded().await?;
 
-        tokio::fs::write(&self.file_path, content)
-            .await
-            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))?;
+        let cache = self.cache.read().await;
+        cache.values().map(IdentityLinkDto::to_entity).collect()
+    }
 
-        Ok(())
+    async fn save_to_file(&self) -> Result<(), RepositoryError> {
+        let cache = self.cache.read().await;
+        file_store::write_json(&self.file_path, &*cache).await
     }
 }
 
@@ -195,7 +195,9 @@ impl IdentityLinkRepository for JsonFileIdentityLinkRepository {
     }
 
     async fn save(&self, identity: IdentityLink) -> Result<(), RepositoryError> {
-        self.ensure_loaded().await?;
+        // 他のプロセス（手作業のスクリプトなど）の変更を上書きしないよう、ロックを取って読み直してから書き込む
+        let _lock = file_store::lock(&self.file_path).await?;
+        self.load().await?;
 
         let dto = IdentityLinkDto::from_entity(&identity);
         let email_key = identity.email().as_str().to_string();
@@ -209,4 +211,16 @@ impl IdentityLinkRepository for JsonFileIdentityLinkRepository {
 
         Ok(())
     }
+
+    async fn delete(&self, email: &EmailAddress) -> Result<(), RepositoryError> {
+        let _lock = file_store::lock(&self.file_path).await?;
+        self.load().await?;
+
+        let removed = self.cache.write().await.remove(email.as_str()).is_some();
+        if removed {
+            self.save_to_file().await?;
+        }
+
+        Ok(())
+    }
 }
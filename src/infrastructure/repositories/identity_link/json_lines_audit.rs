@@ -0,0 +1,216 @@
+//! JSON LinesによるIdentityLink監査記録リポジトリ実装
+//!
+//! 1行に1件の監査記録を追記していく。既存の行を書き換えることはない。
+
+use crate::domain::aggregates::identity_link::value_objects::{
+    ExternalSystem, IdentityLinkAction, IdentityLinkAuditEntry,
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{IdentityLinkAuditRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+/// JSON Lines file storage for IdentityLink audit entries
+///
+/// ファイルフォーマット（1行1件）:
+/// ```json
+/// {"action":"linked","email":"user@example.com","system":"slack","external_user_id":"U12345678","actor_id":"U87654321","occurred_at":"2024-01-01T00:00:00Z"}
+/// ```
+pub struct JsonLinesIdentityLinkAuditRepository {
+    file_path: PathBuf,
+    /// 追記が混ざらないように書き込みを直列化する
+    write_lock: Mutex<()>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AuditEntryDto {
+    action: String,
+    email: String,
+    system: String,
+    external_user_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    actor_id: Option<String>,
+    occurred_at: DateTime<Utc>,
+}
+
+impl AuditEntryDto {
+    fn from_entry(entry: &IdentityLinkAuditEntry) -> Self {
+        Self {
+            action: entry.action().as_str().to_string(),
+            email: entry.email().as_str().to_string(),
+            system: entry.system().as_str().to_string(),
+            external_user_id: entry.external_user_id().to_string(),
+            actor_id: entry.actor_id().map(str::to_string),
+            occurred_at: entry.occurred_at(),
+        }
+    }
+
+    /// エンティティに変換（未知の操作・外部システムの記録は `None`）
+    fn to_entry(&self) -> Result<Option<IdentityLinkAuditEntry>, RepositoryError> {
+        let (Ok(action), Ok(system)) = (
+            IdentityLinkAction::from_str(&self.action),
+            ExternalSystem::from_str(&self.system),
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(IdentityLinkAuditEntry::reconstitute(
+            action,
+            EmailAddress::new(self.email.clone())?,
+            system,
+            self.external_user_id.clone(),
+            self.actor_id.clone(),
+            self.occurred_at,
+        )))
+    }
+}
+
+impl JsonLinesIdentityLinkAuditRepository {
+    /// 新しいJSON Linesファイルベースのリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `file_path` - JSON Linesファイルのパス
+    pub fn new(file_path: PathBuf) -> Self {
+        Self {
+            file_path,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// 条件に一致する記録を古い順に読み込む
+    async fn find(
+        &self,
+        predicate: impl Fn(&AuditEntryDto) -> bool,
+    ) -> Result<Vec<IdentityLinkAuditEntry>, RepositoryError> {
+        let content = match tokio::fs::read_to_string(&self.file_path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(RepositoryError::Unknown(format!(
+                    "ファイルの読み込みに失敗: {}",
+                    e
+                )));
+            }
+        };
+
+        let mut entries = Vec::new();
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            let dto: AuditEntryDto = serde_json::from_str(line)
+                .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))?;
+            if predicate(&dto)
+                && let Some(entry) = dto.to_entry()?
+            {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl IdentityLinkAuditRepository for JsonLinesIdentityLinkAuditRepository {
+    async fn append(&self, entry: IdentityLinkAuditEntry) -> Result<(), RepositoryError> {
+        let mut line = serde_json::to_string(&AuditEntryDto::from_entry(&entry))
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
+        line.push('\n');
+
+        let _guard = self.write_lock.lock().await;
+
+        // 親ディレクトリが存在しない場合は作成
+        if let Some(parent) = self.file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::Unknown(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.file_path)
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルのオープンに失敗: {}", e)))?;
+        file.write_all(line.as_bytes())
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))?;
+        file.flush()
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))
+    }
+
+    async fn find_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Vec<IdentityLinkAuditEntry>, RepositoryError> {
+        self.find(|dto| dto.email == email.as_str()).await
+    }
+
+    async fn find_by_external_user_id(
+        &self,
+        system: &ExternalSystem,
+        user_id: &str,
+    ) -> Result<Vec<IdentityLinkAuditEntry>, RepositoryError> {
+        self.find(|dto| dto.system == system.as_str() && dto.external_user_id == user_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_append_and_find() {
+        let file_path = std::env::temp_dir()
+            .join(format!("lrm_identity_audit_{}", uuid::Uuid::new_v4()))
+            .join("identity_link_audit.jsonl");
+        let repo = JsonLinesIdentityLinkAuditRepository::new(file_path);
+        let email = EmailAddress::new("user@example.com".to_string()).unwrap();
+
+        repo.append(IdentityLinkAuditEntry::new(
+            IdentityLinkAction::Linked,
+            email.clone(),
+            ExternalSystem::Slack,
+            "U001".to_string(),
+            Some("U999".to_string()),
+        ))
+        .await
+        .unwrap();
+        repo.append(IdentityLinkAuditEntry::new(
+            IdentityLinkAction::Unlinked,
+            email.clone(),
+            ExternalSystem::Slack,
+            "U001".to_string(),
+            None,
+        ))
+        .await
+        .unwrap();
+        repo.append(IdentityLinkAuditEntry::new(
+            IdentityLinkAction::Linked,
+            EmailAddress::new("other@example.com".to_string()).unwrap(),
+            ExternalSystem::Slack,
+            "U002".to_string(),
+            None,
+        ))
+        .await
+        .unwrap();
+
+        let history = repo.find_by_email(&email).await.unwrap();
+        assert_eq!(
+            history.iter().map(|e| e.action()).collect::<Vec<_>>(),
+            vec![IdentityLinkAction::Linked, IdentityLinkAction::Unlinked]
+        );
+        assert_eq!(history[0].actor_id(), Some("U999"));
+
+        let history = repo
+            .find_by_external_user_id(&ExternalSystem::Slack, "U002")
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].email().as_str(), "other@example.com");
+    }
+}
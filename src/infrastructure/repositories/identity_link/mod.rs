@@ -1,10 +1,62 @@
 //! # IdentityLink Repository Implementations
 //!
 //! IdentityLinkRepositoryポートの具象実装を提供します。
+//! 保存先はファイルの拡張子によって切り替わります。
 //!
 //! - `json_file`: JSONファイルを使用した永続化実装
+//! - `sqlite`: SQLiteを使用した永続化実装（`.db` / `.sqlite` / `.sqlite3`）
+//! - `json_lines_audit`: 監査記録をJSON Linesファイルに追記する実装
 
 /// JSONファイルベースのIdentityLinkリポジトリ実装
 pub mod json_file;
+/// JSON LinesファイルベースのIdentityLink監査記録リポジトリ実装
+pub mod json_lines_audit;
+/// SQLiteベースのIdentityLinkリポジトリ実装
+pub mod sqlite;
 
 pub use json_file::JsonFileIdentityLinkRepository;
+pub use json_lines_audit::JsonLinesIdentityLinkAuditRepository;
+pub use sqlite::SqliteIdentityLinkRepository;
+
+use crate::domain::ports::repositories::{IdentityLinkRepository, RepositoryError};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// ファイルの拡張子に応じたIdentityLinkリポジトリを開く
+///
+/// SQLiteのデータベースが空で、同じディレクトリに同名の `.json` ファイルがある場合は
+/// 既存のJSONファイルを一度だけ取り込む。
+///
+/// # Arguments
+/// * `file_path` - IdentityLinkの保存先パス
+pub async fn open(file_path: PathBuf) -> Result<Arc<dyn IdentityLinkRepository>, RepositoryError> {
+    if !is_sqlite_path(&file_path) {
+        return Ok(Arc::new(JsonFileIdentityLinkRepository::new(file_path)));
+    }
+
+    let legacy_json_path = file_path.with_extension("json");
+    let repository = SqliteIdentityLinkRepository::open(file_path).await?;
+
+    if repository.is_empty().await?
+        && tokio::fs::try_exists(&legacy_json_path)
+            .await
+            .unwrap_or(false)
+    {
+        let imported = repository.import_json(&legacy_json_path).await?;
+        tracing::info!(
+            "📦 ID紐付けをSQLiteへ移行しました: {}件 ({})",
+            imported,
+            legacy_json_path.display()
+        );
+    }
+
+    Ok(Arc::new(repository))
+}
+
+/// SQLiteで保存するパスかどうか
+pub fn is_sqlite_path(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("db" | "sqlite" | "sqlite3")
+    )
+}
@@ -0,0 +1,472 @@
+//! SQLiteによるIdentityLinkリポジトリ実装
+//!
+//! 書き込みはトランザクション内で行われるため、複数の書き込みが競合してもデータが壊れない。
+//! 外部システムのユーザーIDには一意インデックスを張り、
+//! 同じユーザーが複数のメールアドレスに紐付けられることをデータベース側でも防ぐ。
+
+use super::JsonFileIdentityLinkRepository;
+use crate::domain::aggregates::identity_link::{
+    entity::IdentityLink,
+    value_objects::{ExternalIdentity, ExternalSystem},
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{IdentityLinkRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, ErrorCode, OptionalExtension, Transaction, params};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS identity_links (
+    email      TEXT PRIMARY KEY NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS external_identities (
+    email            TEXT NOT NULL REFERENCES identity_links (email) ON DELETE CASCADE,
+    external_system  TEXT NOT NULL,
+    external_user_id TEXT NOT NULL,
+    workspace_id     TEXT,
+    linked_at        TEXT NOT NULL
+);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_external_identities_user
+    ON external_identities (external_system, external_user_id, IFNULL(workspace_id, ''));
+CREATE INDEX IF NOT EXISTS idx_external_identities_email ON external_identities (email);
+";
+
+/// IdentityLinkの行（外部アイデンティティを含む）
+struct IdentityLinkRow {
+    email: String,
+    created_at: String,
+    updated_at: String,
+    identities: Vec<ExternalIdentityRow>,
+}
+
+/// 外部アイデンティティの行
+struct ExternalIdentityRow {
+    system: String,
+    user_id: String,
+    workspace_id: Option<String>,
+    linked_at: String,
+}
+
+/// SQLiteでIdentityLinkを永続化するリポジトリ
+///
+/// 外部システムのユーザーID（Enterprise Gridの場合はワークスペースごと）は
+/// 1つのメールアドレスにしか紐付けられない。
+pub struct SqliteIdentityLinkRepository {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteIdentityLinkRepository {
+    /// データベースを開き、必要であればスキーマを作成する
+    ///
+    /// # Arguments
+    /// * `file_path` - SQLiteデータベースファイルのパス
+    pub async fn open(file_path: PathBuf) -> Result<Self, RepositoryError> {
+        if let Some(parent) = file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::ConnectionError(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        let connection = tokio::task::spawn_blocking(move || {
+            let connection = Connection::open(&file_path)?;
+            connection.pragma_update(None, "journal_mode", "WAL")?;
+            connection.pragma_update(None, "foreign_keys", "ON")?;
+            connection.execute_batch(SCHEMA)?;
+            Ok::<_, rusqlite::Error>(connection)
+        })
+        .await
+        .map_err(|e| RepositoryError::Unknown(format!("SQLiteタスクの実行に失敗: {}", e)))?
+        .map_err(|e| {
+            RepositoryError::ConnectionError(format!("SQLiteデータベースのオープンに失敗: {}", e))
+        })?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// IdentityLinkが1件も登録されていないかどうか
+    pub async fn is_empty(&self) -> Result<bool, RepositoryError> {
+        let count: i64 = self
+            .with_connection(|conn| {
+                conn.query_row("SELECT COUNT(*) FROM identity_links", [], |row| row.get(0))
+            })
+            .await?;
+        Ok(count == 0)
+    }
+
+    /// 既存のJSONファイル（`JsonFileIdentityLinkRepository`の形式）を取り込む
+    ///
+    /// 取り込みは1トランザクションで行われ、途中で失敗した場合（一意制約違反を含む）は何も反映されない。
+    /// 同じメールアドレスのIdentityLinkが既にある場合は上書きする。
+    ///
+    /// # Returns
+    /// 取り込んだIdentityLinkの件数
+    pub async fn import_json(&self, json_path: &Path) -> Result<usize, RepositoryError> {
+        let links = JsonFileIdentityLinkRepository::new(json_path.to_path_buf())
+            .find_all()
+            .await?;
+
+        self.with_connection(move |conn| {
+            let tx = conn.transaction()?;
+            for link in &links {
+                Self::upsert(&tx, link)?;
+            }
+            tx.commit()?;
+            Ok(links.len())
+        })
+        .await
+    }
+
+    /// IdentityLinkを追加または更新する
+    fn upsert(tx: &Transaction, identity_link: &IdentityLink) -> Result<(), rusqlite::Error> {
+        let email = identity_link.email().as_str();
+        tx.execute(
+            "INSERT INTO identity_links (email, created_at, updated_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(email) DO UPDATE SET
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at",
+            params![
+                email,
+                identity_link.created_at().to_rfc3339(),
+                identity_link.updated_at().to_rfc3339()
+            ],
+        )?;
+        tx.execute(
+            "DELETE FROM external_identities WHERE email = ?1",
+            params![email],
+        )?;
+        for identity in identity_link.external_identities() {
+            tx.execute(
+                "INSERT INTO external_identities
+                    (email, external_system, external_user_id, workspace_id, linked_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    email,
+                    identity.system().as_str(),
+                    identity.user_id(),
+                    identity.workspace_id(),
+                    identity.linked_at().to_rfc3339()
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// メールアドレスでIdentityLinkを読み込む
+    fn load(conn: &Connection, email: &str) -> Result<Option<IdentityLinkRow>, rusqlite::Error> {
+        let Some((created_at, updated_at)) = conn
+            .query_row(
+                "SELECT created_at, updated_at FROM identity_links WHERE email = ?1",
+                params![email],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+            )
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let mut statement = conn.prepare(
+            "SELECT external_system, external_user_id, workspace_id, linked_at
+             FROM external_identities WHERE email = ?1 ORDER BY rowid",
+        )?;
+        let identities = statement
+            .query_map(params![email], |row| {
+                Ok(ExternalIdentityRow {
+                    system: row.get(0)?,
+                    user_id: row.get(1)?,
+                    workspace_id: row.get(2)?,
+                    linked_at: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(IdentityLinkRow {
+            email: email.to_string(),
+            created_at,
+            updated_at,
+            identities,
+        }))
+    }
+
+    /// 読み込んだ行からエンティティを復元する
+    fn to_entity(row: IdentityLinkRow) -> Result<IdentityLink, RepositoryError> {
+        let external_identities = row
+            .identities
+            .into_iter()
+            // 現在サポートしているシステムのみ復元
+            .filter_map(|row| {
+                ExternalSystem::from_str(&row.system)
+                    .ok()
+                    .map(|system| (system, row))
+            })
+            .map(|(system, row)| {
+                Ok(ExternalIdentity::reconstitute(
+                    system,
+                    row.user_id,
+                    row.workspace_id,
+                    parse_timestamp(&row.linked_at)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, RepositoryError>>()?;
+
+        Ok(IdentityLink::reconstitute(
+            EmailAddress::new(row.email)?,
+            external_identities,
+            parse_timestamp(&row.created_at)?,
+            parse_timestamp(&row.updated_at)?,
+        ))
+    }
+
+    /// ブロッキングスレッド上でコネクションを使った処理を実行する
+    async fn with_connection<T, F>(&self, f: F) -> Result<T, RepositoryError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<T, rusqlite::Error> + Send + 'static,
+    {
+        let connection = Arc::clone(&self.connection);
+        tokio::task::spawn_blocking(move || {
+            let mut conn = connection
+                .lock()
+                .map_err(|_| RepositoryError::Unknown("SQLiteコネクションのロックに失敗".into()))?;
+            f(&mut conn).map_err(map_sqlite_error)
+        })
+        .await
+        .map_err(|e| RepositoryError::Unknown(format!("SQLiteタスクの実行に失敗: {}", e)))?
+    }
+}
+
+/// SQLiteのエラーをリポジトリエラーに変換
+fn map_sqlite_error(e: rusqlite::Error) -> RepositoryError {
+    match e.sqlite_error_code() {
+        Some(ErrorCode::ConstraintViolation) => RepositoryError::Unknown(format!(
+            "外部ユーザーIDは既に別のメールアドレスに紐付けられています: {}",
+            e
+        )),
+        _ => RepositoryError::ConnectionError(format!("SQLiteエラー: {}", e)),
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, RepositoryError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| RepositoryError::Unknown(format!("日時のパースに失敗: {} ({})", value, e)))
+}
+
+#[async_trait]
+impl IdentityLinkRepository for SqliteIdentityLinkRepository {
+    async fn find_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Option<IdentityLink>, RepositoryError> {
+        let email = email.as_str().to_string();
+        self.with_connection(move |conn| Self::load(conn, &email))
+            .await?
+            .map(Self::to_entity)
+            .transpose()
+    }
+
+    async fn find_by_external_user_id(
+        &self,
+        system: &ExternalSystem,
+        user_id: &str,
+    ) -> Result<Option<IdentityLink>, RepositoryError> {
+        let system = system.as_str().to_string();
+        let user_id = user_id.to_string();
+        self.with_connection(move |conn| {
+            let email: Option<String> = conn
+                .query_row(
+                    "SELECT email FROM external_identities
+                     WHERE external_system = ?1 AND external_user_id = ?2
+                     LIMIT 1",
+                    params![system, user_id],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            match email {
+                Some(email) => Self::load(conn, &email),
+                None => Ok(None),
+            }
+        })
+        .await?
+        .map(Self::to_entity)
+        .transpose()
+    }
+
+    async fn save(&self, identity_link: IdentityLink) -> Result<(), RepositoryError> {
+        self.with_connection(move |conn| {
+            let tx = conn.transaction()?;
+            Self::upsert(&tx, &identity_link)?;
+            tx.commit()
+        })
+        .await
+    }
+
+    async fn delete(&self, email: &EmailAddress) -> Result<(), RepositoryError> {
+        let email = email.as_str().to_string();
+        self.with_connection(move |conn| {
+            conn.execute(
+                "DELETE FROM identity_links WHERE email = ?1",
+                params![email],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "lrm_sqlite_identity_{}_{}",
+            name,
+            uuid::Uuid::new_v4()
+        ))
+    }
+
+    fn link(email: &str, slack_user_id: &str) -> IdentityLink {
+        let mut link = IdentityLink::new(EmailAddress::new(email.to_string()).unwrap());
+        link.link_external_identity(ExternalIdentity::new(
+            ExternalSystem::Slack,
+            slack_user_id.to_string(),
+        ))
+        .unwrap();
+        link
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find() {
+        let repo = SqliteIdentityLinkRepository::open(temp_dir("find").join("links.db"))
+            .await
+            .unwrap();
+        assert!(repo.is_empty().await.unwrap());
+
+        repo.save(link("user@example.com", "U001")).await.unwrap();
+
+        let email = EmailAddress::new("user@example.com".to_string()).unwrap();
+        let found = repo.find_by_email(&email).await.unwrap().unwrap();
+        assert!(found.has_identity_for_system(&ExternalSystem::Slack));
+
+        let found = repo
+            .find_by_external_user_id(&ExternalSystem::Slack, "U001")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.email(), &email);
+        assert!(
+            repo.find_by_external_user_id(&ExternalSystem::Slack, "U999")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_by_each_external_system() {
+        let repo = SqliteIdentityLinkRepository::open(temp_dir("systems").join("links.db"))
+            .await
+            .unwrap();
+
+        let mut identity_link = link("user@example.com", "U001");
+        identity_link
+            .link_external_identity(ExternalIdentity::new(
+                ExternalSystem::Discord,
+                "123456789012345678".to_string(),
+            ))
+            .unwrap();
+        identity_link
+            .link_external_identity(ExternalIdentity::new(
+                ExternalSystem::GitHub,
+                "U001".to_string(),
+            ))
+            .unwrap();
+        repo.save(identity_link).await.unwrap();
+
+        let email = EmailAddress::new("user@example.com".to_string()).unwrap();
+        let found = repo.find_by_email(&email).await.unwrap().unwrap();
+        assert_eq!(found.external_identities().len(), 3);
+
+        for (system, user_id) in [
+            (ExternalSystem::Slack, "U001"),
+            (ExternalSystem::Discord, "123456789012345678"),
+            (ExternalSystem::GitHub, "U001"),
+        ] {
+            let found = repo
+                .find_by_external_user_id(&system, user_id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(found.email(), &email);
+        }
+        assert!(
+            repo.find_by_external_user_id(&ExternalSystem::Discord, "U001")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_external_identities() {
+        let repo = SqliteIdentityLinkRepository::open(temp_dir("delete").join("links.db"))
+            .await
+            .unwrap();
+
+        repo.save(link("a@example.com", "U001")).await.unwrap();
+        let email = EmailAddress::new("a@example.com".to_string()).unwrap();
+        repo.delete(&email).await.unwrap();
+
+        assert!(repo.is_empty().await.unwrap());
+        assert!(
+            repo.find_by_external_user_id(&ExternalSystem::Slack, "U001")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        // 削除後は同じ外部ユーザーを別のメールアドレスに紐付けられる
+        repo.save(link("b@example.com", "U001")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_same_external_user_cannot_link_to_two_emails() {
+        let repo = SqliteIdentityLinkRepository::open(temp_dir("unique").join("links.db"))
+            .await
+            .unwrap();
+
+        repo.save(link("a@example.com", "U001")).await.unwrap();
+        let result = repo.save(link("b@example.com", "U001")).await;
+
+        assert!(result.is_err());
+        let email = EmailAddress::new("b@example.com".to_string()).unwrap();
+        assert!(repo.find_by_email(&email).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_import_json() {
+        let dir = temp_dir("import");
+        let json_path = dir.join("identity_links.json");
+        let json_repo = JsonFileIdentityLinkRepository::new(json_path.clone());
+        json_repo.save(link("a@example.com", "U001")).await.unwrap();
+        json_repo.save(link("b@example.com", "U002")).await.unwrap();
+
+        let repo = SqliteIdentityLinkRepository::open(dir.join("identity_links.db"))
+            .await
+            .unwrap();
+        assert_eq!(repo.import_json(&json_path).await.unwrap(), 2);
+
+        let found = repo
+            .find_by_external_user_id(&ExternalSystem::Slack, "U002")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.email().as_str(), "b@example.com");
+    }
+}
@@ -2,5 +2,12 @@
 //!
 //! リポジトリポートの具象実装を提供します。
 //! 各集約に対応するリポジトリの実装をサブモジュールとして含みます。
+pub mod device_health;
+pub mod file_store;
 pub mod identity_link;
+pub mod resource_freeze;
 pub mod resource_usage;
+pub mod sent_reminder;
+pub mod usage_snapshot;
+pub mod waitlist;
+pub mod workspace_token;
@@ -0,0 +1,171 @@
+use crate::domain::aggregates::resource_freeze::ResourceFreeze;
+use crate::domain::ports::repositories::{RepositoryError, ResourceFreezeRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+/// JSON file storage for ResourceFreeze
+///
+/// ファイルフォーマット:
+/// ```json
+/// {
+///   "Thalys": {
+///     "starts_at": "2024-03-01T00:00:00Z",
+///     "reason": "クラスタ移行"
+///   }
+/// }
+/// ```
+pub struct JsonFileResourceFreezeRepository {
+    file_path: PathBuf,
+    /// リソース名をキーとした予約停止（未読み込みの場合は `None`）
+    cache: RwLock<Option<BTreeMap<String, ResourceFreezeDto>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ResourceFreezeDto {
+    starts_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+impl JsonFileResourceFreezeRepository {
+    /// 新しいJSONファイルベースのリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `file_path` - JSONファイルのパス
+    pub fn new(file_path: PathBuf) -> Self {
+        Self {
+            file_path,
+            cache: RwLock::new(None),
+        }
+    }
+
+    async fn load(&self) -> Result<BTreeMap<String, ResourceFreezeDto>, RepositoryError> {
+        let content = match tokio::fs::read_to_string(&self.file_path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // ファイルが存在しない場合は予約停止なしとして扱う
+                return Ok(BTreeMap::new());
+            }
+            Err(e) => {
+                return Err(RepositoryError::Unknown(format!(
+                    "ファイルの読み込みに失敗: {}",
+                    e
+                )));
+            }
+        };
+
+        serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))
+    }
+
+    /// キャッシュが未読み込みの場合、ファイルから読み込む
+    async fn ensure_loaded(&self) -> Result<(), RepositoryError> {
+        if self.cache.read().await.is_some() {
+            return Ok(());
+        }
+        let data = self.load().await?;
+        self.cache.write().await.get_or_insert(data);
+        Ok(())
+    }
+
+    async fn save_to_file(
+        &self,
+        data: &BTreeMap<String, ResourceFreezeDto>,
+    ) -> Result<(), RepositoryError> {
+        let content = serde_json::to_string_pretty(data)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
+
+        // 親ディレクトリが存在しない場合は作成
+        if let Some(parent) = self.file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::Unknown(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        tokio::fs::write(&self.file_path, content)
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))
+    }
+}
+
+#[async_trait]
+impl ResourceFreezeRepository for JsonFileResourceFreezeRepository {
+    async fn find_all(&self) -> Result<Vec<ResourceFreeze>, RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let cache = self.cache.read().await;
+        Ok(cache
+            .iter()
+            .flatten()
+            .map(|(resource_name, dto)| {
+                ResourceFreeze::reconstitute(
+                    resource_name.clone(),
+                    dto.starts_at,
+                    dto.reason.clone(),
+                )
+            })
+            .collect())
+    }
+
+    async fn save(&self, freeze: ResourceFreeze) -> Result<(), RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(BTreeMap::new);
+        data.insert(
+            freeze.resource_name().to_string(),
+            ResourceFreezeDto {
+                starts_at: freeze.starts_at(),
+                reason: freeze.reason().map(str::to_string),
+            },
+        );
+        self.save_to_file(data).await
+    }
+
+    async fn delete(&self, resource_name: &str) -> Result<bool, RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(BTreeMap::new);
+        if data.remove(resource_name).is_none() {
+            return Ok(false);
+        }
+        self.save_to_file(data).await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_save_find_and_delete() {
+        let file_path = std::env::temp_dir()
+            .join(format!("lrm_resource_freeze_{}", uuid::Uuid::new_v4()))
+            .join("resource_freezes.json");
+        let repo = JsonFileResourceFreezeRepository::new(file_path.clone());
+        assert!(repo.find_all().await.unwrap().is_empty());
+
+        let freeze = ResourceFreeze::new(
+            "Thalys".to_string(),
+            Utc::now() + Duration::days(7),
+            Some("クラスタ移行".to_string()),
+        )
+        .unwrap();
+        repo.save(freeze.clone()).await.unwrap();
+
+        // 別のインスタンスからも読み込める
+        let reopened = JsonFileResourceFreezeRepository::new(file_path);
+        assert_eq!(reopened.find_all().await.unwrap(), vec![freeze]);
+
+        assert!(reopened.delete("Thalys").await.unwrap());
+        assert!(!reopened.delete("Thalys").await.unwrap());
+        assert!(reopened.find_all().await.unwrap().is_empty());
+    }
+}
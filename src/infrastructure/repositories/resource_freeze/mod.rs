@@ -0,0 +1,10 @@
+//! # ResourceFreeze Repository Implementations
+//!
+//! ResourceFreezeRepositoryポートの具象実装を提供します。
+//!
+//! - `json_file`: JSONファイルを使用した永続化実装
+
+/// JSONファイルベースのResourceFreezeリポジトリ実装
+pub mod json_file;
+
+pub use json_file::JsonFileResourceFreezeRepository;
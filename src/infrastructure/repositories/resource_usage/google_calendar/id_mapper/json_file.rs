@@ -0,0 +1,345 @@
+//! JSONファイルによるIdMapper実装
+//!
+//! 参照はメモリ上のキャッシュから行う。変更のたびにファイルロックを取ってファイルを読み直し、
+//! その内容に変更を反映して書き込むため、他のプロセス（手作業のスクリプトなど）の変更を上書きしない。
+
+use super::{ExternalId, IdMapper};
+use crate::domain::ports::repositories::RepositoryError;
+use crate::infrastructure::repositories::file_store;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::sync::{Mutex, RwLock};
+
+/// メモリ上のマッピング状態
+struct MappingState {
+    mappings: HashMap<String, ExternalId>,
+    /// 逆引きマップ: event_id -> domain_id (O(1)検索用)
+    reverse_mappings: HashMap<String, String>,
+}
+
+impl MappingState {
+    fn new(mappings: HashMap<String, ExternalId>) -> Self {
+        // 逆引きマップを構築
+        let reverse_mappings = mappings
+            .iter()
+            .map(|(domain_id, external_id)| (external_id.event_id.clone(), domain_id.clone()))
+            .collect();
+        Self {
+            mappings,
+            reverse_mappings,
+        }
+    }
+}
+
+/// JSONファイルでマッピングを永続化するIdMapper
+pub(super) struct JsonFileIdMapper {
+    file_path: PathBuf,
+    state: RwLock<MappingState>,
+    /// プロセス内の書き込みの直列化
+    write_lock: Mutex<()>,
+}
+
+impl JsonFileIdMapper {
+    /// 新しいJsonFileIdMapperを作成
+    ///
+    /// # Arguments
+    /// * `file_path` - マッピングを永続化するJSONファイルのパス
+    pub(super) async fn new(file_path: PathBuf) -> Result<Self, RepositoryError> {
+        let mappings = Self::load_from_file(&file_path).await?;
+
+        Ok(Self {
+            file_path,
+            state: RwLock::new(MappingState::new(mappings)),
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    /// ファイルの最新の内容に変更を反映して書き込む
+    ///
+    /// 読み直しから書き込みまでファイルロックを取り、書き込み後はキャッシュを書き込んだ内容に置き換える。
+    /// 書き込みは一時ファイル経由で行うため、途中でプロセスが停止してもマッピングファイルは壊れない。
+    async fn update(
+        &self,
+        change: impl FnOnce(&mut HashMap<String, ExternalId>),
+    ) -> Result<(), RepositoryError> {
+        let _write_lock = self.write_lock.lock().await;
+        let _lock = file_store::lock(&self.file_path).await?;
+
+        let mut mappings = Self::load_from_file(&self.file_path).await?;
+        change(&mut mappings);
+        file_store::write_json(&self.file_path, &mappings).await?;
+
+        *self.state.write().await = MappingState::new(mappings);
+        Ok(())
+    }
+
+    /// ファイルから全データを読み込み
+    ///
+    /// ファイルが壊れている場合は、直前の内容から復旧する。
+    async fn load_from_file(
+        file_path: &Path,
+    ) -> Result<HashMap<String, ExternalId>, RepositoryError> {
+        Ok(file_store::read_json(file_path).await?.unwrap_or_default())
+    }
+}
+
+#[async_trait]
+impl IdMapper for JsonFileIdMapper {
+    /// マッピングを保存
+    async fn save_mapping(
+        &self,
+        domain_id: &str,
+        external_id: ExternalId,
+    ) -> Result<(), RepositoryError> {
+        self.update(|mappings| {
+            mappings.insert(domain_id.to_string(), external_id);
+        })
+        .await
+    }
+
+    /// Domain ID から外部ID を取得
+    async fn get_external_id(
+        &self,
+        domain_id: &str,
+    ) -> Result<Option<ExternalId>, RepositoryError> {
+        let state = self.state.read().await;
+        Ok(state.mappings.get(domain_id).cloned())
+    }
+
+    /// Event ID から Domain ID を取得（逆引き）
+    async fn get_domain_id(&self, event_id: &str) -> Result<Option<String>, RepositoryError> {
+        let state = self.state.read().await;
+        Ok(state.reverse_mappings.get(event_id).cloned())
+    }
+
+    /// マッピングを削除
+    async fn delete_mapping(&self, domain_id: &str) -> Result<(), RepositoryError> {
+        self.update(|mappings| {
+            mappings.remove(domain_id);
+        })
+        .await
+    }
+
+    /// すべてのマッピングを取得（Domain ID順）
+    async fn all_mappings(&self) -> Result<Vec<(String, ExternalId)>, RepositoryError> {
+        let state = self.state.read().await;
+        let mut mappings: Vec<(String, ExternalId)> = state
+            .mappings
+            .iter()
+            .map(|(domain_id, external_id)| (domain_id.clone(), external_id.clone()))
+            .collect();
+        mappings.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(mappings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn temp_file_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!(
+                "lrm_json_id_mapper_{}_{}",
+                name,
+                uuid::Uuid::new_v4()
+            ))
+            .join("mappings.json")
+    }
+
+    fn external_id(calendar_id: &str, event_id: &str) -> ExternalId {
+        ExternalId {
+            calendar_id: calendar_id.to_string(),
+            event_id: event_id.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_with_missing_file_starts_empty() {
+        let path = temp_file_path("missing");
+        let mapper = JsonFileIdMapper::new(path.clone()).await.unwrap();
+
+        assert!(mapper.get_external_id("domain-1").await.unwrap().is_none());
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_save_and_lookup_both_directions() {
+        let mapper = JsonFileIdMapper::new(temp_file_path("lookup"))
+            .await
+            .unwrap();
+        mapper
+            .save_mapping("domain-1", external_id("cal", "event-1"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            mapper.get_external_id("domain-1").await.unwrap(),
+            Some(external_id("cal", "event-1"))
+        );
+        assert_eq!(
+            mapper.get_domain_id("event-1").await.unwrap(),
+            Some("domain-1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_overwrite_removes_old_reverse_mapping() {
+        let mapper = JsonFileIdMapper::new(temp_file_path("overwrite"))
+            .await
+            .unwrap();
+        mapper
+            .save_mapping("domain-1", external_id("cal", "event-1"))
+            .await
+            .unwrap();
+        mapper
+            .save_mapping("domain-1", external_id("cal2", "event-2"))
+            .await
+            .unwrap();
+
+        assert!(mapper.get_domain_id("event-1").await.unwrap().is_none());
+        assert_eq!(
+            mapper.get_domain_id("event-2").await.unwrap(),
+            Some("domain-1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_persisted_mappings_are_reloaded() {
+        let path = temp_file_path("reload");
+        {
+            let mapper = JsonFileIdMapper::new(path.clone()).await.unwrap();
+            mapper
+                .save_mapping("domain-1", external_id("cal", "event-1"))
+                .await
+                .unwrap();
+            mapper
+                .save_mapping("domain-2", external_id("cal", "event-2"))
+                .await
+                .unwrap();
+            mapper.delete_mapping("domain-1").await.unwrap();
+        }
+
+        let reloaded = JsonFileIdMapper::new(path).await.unwrap();
+        assert!(
+            reloaded
+                .get_external_id("domain-1")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            reloaded.get_domain_id("event-2").await.unwrap(),
+            Some("domain-2".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_keeps_mappings_written_by_another_process() {
+        let path = temp_file_path("external");
+        let mapper = JsonFileIdMapper::new(path.clone()).await.unwrap();
+        mapper
+            .save_mapping("domain-1", external_id("cal", "event-1"))
+            .await
+            .unwrap();
+
+        // 起動後に他のプロセスがファイルへ追加したマッピング
+        let other = JsonFileIdMapper::new(path.clone()).await.unwrap();
+        other
+            .save_mapping("domain-2", external_id("cal", "event-2"))
+            .await
+            .unwrap();
+
+        mapper
+            .save_mapping("domain-3", external_id("cal", "event-3"))
+            .await
+            .unwrap();
+
+        let reloaded = JsonFileIdMapper::new(path).await.unwrap();
+        for i in 1..=3 {
+            assert_eq!(
+                reloaded
+                    .get_domain_id(&format!("event-{}", i))
+                    .await
+                    .unwrap(),
+                Some(format!("domain-{}", i))
+            );
+        }
+        assert_eq!(
+            mapper.get_domain_id("event-2").await.unwrap(),
+            Some("domain-2".to_string())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_saves_are_all_persisted() {
+        let path = temp_file_path("concurrent");
+        let mapper = Arc::new(JsonFileIdMapper::new(path.clone()).await.unwrap());
+
+        let handles: Vec<_> = (0..50)
+            .map(|i| {
+                let mapper = Arc::clone(&mapper);
+                tokio::spawn(async move {
+                    mapper
+                        .save_mapping(
+                            &format!("domain-{}", i),
+                            external_id("cal", &format!("event-{}", i)),
+                        )
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let reloaded = JsonFileIdMapper::new(path).await.unwrap();
+        for i in 0..50 {
+            assert_eq!(
+                reloaded
+                    .get_domain_id(&format!("event-{}", i))
+                    .await
+                    .unwrap(),
+                Some(format!("domain-{}", i))
+            );
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_save_and_delete_keep_file_consistent() {
+        let path = temp_file_path("save_delete");
+        let mapper = Arc::new(JsonFileIdMapper::new(path.clone()).await.unwrap());
+
+        let handles: Vec<_> = (0..20)
+            .map(|i| {
+                let mapper = Arc::clone(&mapper);
+                tokio::spawn(async move {
+                    let domain_id = format!("domain-{}", i);
+                    mapper
+                        .save_mapping(&domain_id, external_id("cal", &format!("event-{}", i)))
+                        .await?;
+                    if i % 2 == 0 {
+                        mapper.delete_mapping(&domain_id).await?;
+                    }
+                    Ok::<_, RepositoryError>(())
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let reloaded = JsonFileIdMapper::new(path).await.unwrap();
+        for i in 0..20 {
+            let found = reloaded
+                .get_external_id(&format!("domain-{}", i))
+                .await
+                .unwrap();
+            assert_eq!(found.is_some(), i % 2 == 1);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Google Calendar Event ID マッピング (内部実装)
+//!
+//! Domain ID と Google Calendar の外部ID (calendar_id + event_id) の対応を永続化する。
+//! 保存先はファイルの拡張子によって切り替わる。
+//!
+//! - `.db` / `.sqlite` / `.sqlite3`: SQLite
+//! - それ以外: JSONファイル
+
+mod json_file;
+mod sqlite;
+
+use json_file::JsonFileIdMapper;
+use sqlite::SqliteIdMapper;
+
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Google Calendar の外部ID (calendar_id + event_id)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct ExternalId {
+    /// Calendar ID
+    pub calendar_id: String,
+    /// Event ID
+    pub event_id: String,
+}
+
+/// Domain ID と Google Calendar Event ID のマッピングを管理
+#[async_trait]
+pub(super) trait IdMapper: Send + Sync {
+    /// マッピングを保存（既存のマッピングがあれば上書き）
+    async fn save_mapping(
+        &self,
+        domain_id: &str,
+        external_id: ExternalId,
+    ) -> Result<(), RepositoryError>;
+
+    /// Domain ID から外部ID を取得
+    async fn get_external_id(&self, domain_id: &str)
+    -> Result<Option<ExternalId>, RepositoryError>;
+
+    /// Event ID から Domain ID を取得（逆引き）
+    async fn get_domain_id(&self, event_id: &str) -> Result<Option<String>, RepositoryError>;
+
+    /// マッピングを削除
+    async fn delete_mapping(&self, domain_id: &str) -> Result<(), RepositoryError>;
+
+    /// すべてのマッピングを取得（Domain ID順）
+    async fn all_mappings(&self) -> Result<Vec<(String, ExternalId)>, RepositoryError>;
+}
+
+/// ファイルの拡張子に応じたIdMapperを開く
+///
+/// SQLiteのデータベースが空で、同じディレクトリに同名の `.json` ファイルがある場合は
+/// 既存のJSONマッピングを一度だけ取り込む。
+///
+/// # Arguments
+/// * `file_path` - マッピングの保存先パス
+pub(super) async fn open(file_path: PathBuf) -> Result<Box<dyn IdMapper>, RepositoryError> {
+    if !is_sqlite_path(&file_path) {
+        return Ok(Box::new(JsonFileIdMapper::new(file_path).await?));
+    }
+
+    let legacy_json_path = file_path.with_extension("json");
+    let mapper = SqliteIdMapper::open(file_path).await?;
+
+    if mapper.is_empty().await?
+        && tokio::fs::try_exists(&legacy_json_path)
+            .await
+            .unwrap_or(false)
+    {
+        let imported = mapper.import_json(&legacy_json_path).await?;
+        tracing::info!(
+            "📦 JSONマッピングをSQLiteへ移行しました: {}件 ({})",
+            imported,
+            legacy_json_path.display()
+        );
+    }
+
+    Ok(Box::new(mapper))
+}
+
+/// SQLiteで保存するパスかどうか
+fn is_sqlite_path(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("db" | "sqlite" | "sqlite3")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "lrm_id_mapper_open_{}_{}",
+            name,
+            uuid::Uuid::new_v4()
+        ))
+    }
+
+    #[test]
+    fn test_is_sqlite_path() {
+        assert!(is_sqlite_path(Path::new("/var/lib/mappings.db")));
+        assert!(is_sqlite_path(Path::new("mappings.sqlite3")));
+        assert!(!is_sqlite_path(Path::new("mappings.json")));
+        assert!(!is_sqlite_path(Path::new("mappings")));
+    }
+
+    #[tokio::test]
+    async fn test_open_sqlite_migrates_sibling_json_once() {
+        let dir = temp_dir("migrate");
+        let json_path = dir.join("mappings.json");
+
+        let json_mapper = JsonFileIdMapper::new(json_path).await.unwrap();
+        json_mapper
+            .save_mapping(
+                "domain-1",
+                ExternalId {
+                    calendar_id: "cal".to_string(),
+                    event_id: "event-1".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let mapper = open(dir.join("mappings.db")).await.unwrap();
+        assert_eq!(
+            mapper.get_domain_id("event-1").await.unwrap(),
+            Some("domain-1".to_string())
+        );
+
+        // 移行後の変更は再オープン時に上書きされない
+        mapper.delete_mapping("domain-1").await.unwrap();
+        mapper
+            .save_mapping(
+                "domain-2",
+                ExternalId {
+                    calendar_id: "cal".to_string(),
+                    event_id: "event-2".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        drop(mapper);
+
+        let reopened = open(dir.join("mappings.db")).await.unwrap();
+        assert!(
+            reopened
+                .get_external_id("domain-1")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            reopened
+                .get_external_id("domain-2")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
@@ -0,0 +1,392 @@
+//! SQLiteによるIdMapper実装
+//!
+//! 書き込みはトランザクション内で行われるため、複数の書き込みが競合してもファイルが壊れない。
+//! event_id には一意インデックスを張り、逆引きもインデックス経由で行う。
+
+use super::{ExternalId, IdMapper};
+use crate::domain::ports::repositories::RepositoryError;
+use async_trait::async_trait;
+use rusqlite::{Connection, OptionalExtension, params};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS id_mappings (
+    domain_id   TEXT PRIMARY KEY NOT NULL,
+    calendar_id TEXT NOT NULL,
+    event_id    TEXT NOT NULL
+);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_id_mappings_event_id ON id_mappings (event_id);
+";
+
+/// SQLiteでマッピングを永続化するIdMapper
+pub(super) struct SqliteIdMapper {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteIdMapper {
+    /// データベースを開き、必要であればスキーマを作成する
+    ///
+    /// # Arguments
+    /// * `file_path` - SQLiteデータベースファイルのパス
+    pub(super) async fn open(file_path: PathBuf) -> Result<Self, RepositoryError> {
+        if let Some(parent) = file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::ConnectionError(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        let connection = tokio::task::spawn_blocking(move || {
+            let connection = Connection::open(&file_path)?;
+            connection.pragma_update(None, "journal_mode", "WAL")?;
+            connection.execute_batch(SCHEMA)?;
+            Ok::<_, rusqlite::Error>(connection)
+        })
+        .await
+        .map_err(|e| RepositoryError::Unknown(format!("SQLiteタスクの実行に失敗: {}", e)))?
+        .map_err(|e| {
+            RepositoryError::ConnectionError(format!("SQLiteデータベースのオープンに失敗: {}", e))
+        })?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// マッピングが1件も登録されていないかどうか
+    pub(super) async fn is_empty(&self) -> Result<bool, RepositoryError> {
+        let count: i64 = self
+            .with_connection(|conn| {
+                conn.query_row("SELECT COUNT(*) FROM id_mappings", [], |row| row.get(0))
+            })
+            .await?;
+        Ok(count == 0)
+    }
+
+    /// 既存のJSONマッピングファイルを取り込む
+    ///
+    /// 取り込みは1トランザクションで行われ、途中で失敗した場合は何も反映されない。
+    ///
+    /// # Returns
+    /// 取り込んだマッピングの件数
+    pub(super) async fn import_json(&self, json_path: &Path) -> Result<usize, RepositoryError> {
+        let content = tokio::fs::read_to_string(json_path).await.map_err(|e| {
+            RepositoryError::ConnectionError(format!("マッピングファイルの読み込みに失敗: {}", e))
+        })?;
+
+        let mappings: HashMap<String, ExternalId> =
+            serde_json::from_str(&content).map_err(|e| {
+                RepositoryError::Unknown(format!("マッピングファイルのパースに失敗: {}", e))
+            })?;
+
+        self.with_connection(move |conn| {
+            let tx = conn.transaction()?;
+            for (domain_id, external_id) in &mappings {
+                Self::upsert(&tx, domain_id, external_id)?;
+            }
+            tx.commit()?;
+            Ok(mappings.len())
+        })
+        .await
+    }
+
+    /// マッピングを追加または更新する
+    ///
+    /// 同じevent_idが別のdomain_idに紐付いている場合は、古い紐付けを削除する。
+    fn upsert(
+        conn: &Connection,
+        domain_id: &str,
+        external_id: &ExternalId,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "DELETE FROM id_mappings WHERE event_id = ?1 AND domain_id <> ?2",
+            params![external_id.event_id, domain_id],
+        )?;
+        conn.execute(
+            "INSERT INTO id_mappings (domain_id, calendar_id, event_id) VALUES (?1, ?2, ?3)
+             ON CONFLICT(domain_id) DO UPDATE SET
+                calendar_id = excluded.calendar_id,
+                event_id = excluded.event_id",
+            params![domain_id, external_id.calendar_id, external_id.event_id],
+        )?;
+        Ok(())
+    }
+
+    /// ブロッキングスレッド上でコネクションを使った処理を実行する
+    async fn with_connection<T, F>(&self, f: F) -> Result<T, RepositoryError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<T, rusqlite::Error> + Send + 'static,
+    {
+        let connection = Arc::clone(&self.connection);
+        tokio::task::spawn_blocking(move || {
+            let mut conn = connection
+                .lock()
+                .map_err(|_| RepositoryError::Unknown("SQLiteコネクションのロックに失敗".into()))?;
+            f(&mut conn)
+                .map_err(|e| RepositoryError::ConnectionError(format!("SQLiteエラー: {}", e)))
+        })
+        .await
+        .map_err(|e| RepositoryError::Unknown(format!("SQLiteタスクの実行に失敗: {}", e)))?
+    }
+}
+
+#[async_trait]
+impl IdMapper for SqliteIdMapper {
+    async fn save_mapping(
+        &self,
+        domain_id: &str,
+        external_id: ExternalId,
+    ) -> Result<(), RepositoryError> {
+        let domain_id = domain_id.to_string();
+        self.with_connection(move |conn| {
+            let tx = conn.transaction()?;
+            Self::upsert(&tx, &domain_id, &external_id)?;
+            tx.commit()
+        })
+        .await
+    }
+
+    async fn get_external_id(
+        &self,
+        domain_id: &str,
+    ) -> Result<Option<ExternalId>, RepositoryError> {
+        let domain_id = domain_id.to_string();
+        self.with_connection(move |conn| {
+            conn.query_row(
+                "SELECT calendar_id, event_id FROM id_mappings WHERE domain_id = ?1",
+                params![domain_id],
+                |row| {
+                    Ok(ExternalId {
+                        calendar_id: row.get(0)?,
+                        event_id: row.get(1)?,
+                    })
+                },
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn get_domain_id(&self, event_id: &str) -> Result<Option<String>, RepositoryError> {
+        let event_id = event_id.to_string();
+        self.with_connection(move |conn| {
+            conn.query_row(
+                "SELECT domain_id FROM id_mappings WHERE event_id = ?1",
+                params![event_id],
+                |row| row.get(0),
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn delete_mapping(&self, domain_id: &str) -> Result<(), RepositoryError> {
+        let domain_id = domain_id.to_string();
+        self.with_connection(move |conn| {
+            conn.execute(
+                "DELETE FROM id_mappings WHERE domain_id = ?1",
+                params![domain_id],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn all_mappings(&self) -> Result<Vec<(String, ExternalId)>, RepositoryError> {
+        self.with_connection(|conn| {
+            let mut statement = conn.prepare(
+                "SELECT domain_id, calendar_id, event_id FROM id_mappings ORDER BY domain_id",
+            )?;
+            statement
+                .query_map([], |row| {
+                    Ok((
+                        row.get(0)?,
+                        ExternalId {
+                            calendar_id: row.get(1)?,
+                            event_id: row.get(2)?,
+                        },
+                    ))
+                })?
+                .collect()
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_db_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!(
+                "lrm_sqlite_id_mapper_{}_{}",
+                name,
+                uuid::Uuid::new_v4()
+            ))
+            .join("mappings.db")
+    }
+
+    fn external_id(calendar_id: &str, event_id: &str) -> ExternalId {
+        ExternalId {
+            calendar_id: calendar_id.to_string(),
+            event_id: event_id.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upsert_and_lookup_both_directions() {
+        let mapper = SqliteIdMapper::open(temp_db_path("upsert")).await.unwrap();
+        assert!(mapper.is_empty().await.unwrap());
+
+        mapper
+            .save_mapping("domain-1", external_id("cal", "event-1"))
+            .await
+            .unwrap();
+        mapper
+            .save_mapping("domain-1", external_id("cal2", "event-2"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            mapper.get_external_id("domain-1").await.unwrap(),
+            Some(external_id("cal2", "event-2"))
+        );
+        assert!(mapper.get_domain_id("event-1").await.unwrap().is_none());
+        assert_eq!(
+            mapper.get_domain_id("event-2").await.unwrap(),
+            Some("domain-1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_event_id_reassigned_to_new_domain_id() {
+        let mapper = SqliteIdMapper::open(temp_db_path("reassign"))
+            .await
+            .unwrap();
+        mapper
+            .save_mapping("domain-1", external_id("cal", "event-1"))
+            .await
+            .unwrap();
+        mapper
+            .save_mapping("domain-2", external_id("cal", "event-1"))
+            .await
+            .unwrap();
+
+        assert!(mapper.get_external_id("domain-1").await.unwrap().is_none());
+        assert_eq!(
+            mapper.get_domain_id("event-1").await.unwrap(),
+            Some("domain-2".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_and_reopen() {
+        let path = temp_db_path("reopen");
+        {
+            let mapper = SqliteIdMapper::open(path.clone()).await.unwrap();
+            mapper
+                .save_mapping("domain-1", external_id("cal", "event-1"))
+                .await
+                .unwrap();
+            mapper
+                .save_mapping("domain-2", external_id("cal", "event-2"))
+                .await
+                .unwrap();
+            mapper.delete_mapping("domain-1").await.unwrap();
+        }
+
+        let reopened = SqliteIdMapper::open(path).await.unwrap();
+        assert!(
+            reopened
+                .get_external_id("domain-1")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            reopened.get_domain_id("event-2").await.unwrap(),
+            Some("domain-2".to_string())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_saves() {
+        let mapper = Arc::new(
+            SqliteIdMapper::open(temp_db_path("concurrent"))
+                .await
+                .unwrap(),
+        );
+
+        let handles: Vec<_> = (0..50)
+            .map(|i| {
+                let mapper = Arc::clone(&mapper);
+                tokio::spawn(async move {
+                    mapper
+                        .save_mapping(
+                            &format!("domain-{}", i),
+                            external_id("cal", &format!("event-{}", i)),
+                        )
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        for i in 0..50 {
+            assert_eq!(
+                mapper.get_domain_id(&format!("event-{}", i)).await.unwrap(),
+                Some(format!("domain-{}", i))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_json() {
+        let dir = std::env::temp_dir().join(format!("lrm_sqlite_import_{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let json_path = dir.join("mappings.json");
+        tokio::fs::write(
+            &json_path,
+            r#"{
+                "domain-1": {"calendar_id": "cal", "event_id": "event-1"},
+                "domain-2": {"calendar_id": "cal", "event_id": "event-2"}
+            }"#,
+        )
+        .await
+        .unwrap();
+
+        let mapper = SqliteIdMapper::open(dir.join("mappings.db")).await.unwrap();
+        assert_eq!(mapper.import_json(&json_path).await.unwrap(), 2);
+        assert_eq!(
+            mapper.get_external_id("domain-2").await.unwrap(),
+            Some(external_id("cal", "event-2"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_all_mappings() {
+        let mapper = SqliteIdMapper::open(temp_db_path("all")).await.unwrap();
+        mapper
+            .save_mapping("domain-2", external_id("cal", "event-2"))
+            .await
+            .unwrap();
+        mapper
+            .save_mapping("domain-1", external_id("cal", "event-1"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            mapper.all_mappings().await.unwrap(),
+            vec![
+                ("domain-1".to_string(), external_id("cal", "event-1")),
+                ("domain-2".to_string(), external_id("cal", "event-2")),
+            ]
+        );
+    }
+}
@@ -1,32 +1,93 @@
-use super::id_mapper::{ExternalId, IdMapper};
+use super::id_mapper::{self, ExternalId, IdMapper};
+use crate::domain::aggregates::group::GroupId;
 use crate::domain::aggregates::resource_usage::{
     entity::ResourceUsage,
     factory::ResourceFactory,
-    value_objects::{Resource, TimePeriod, UsageId},
+    value_objects::{
+        ApprovalStatus, Gpu, Priority, RecurrenceRule, ReservationMetadata, Resource, SeriesId,
+        TimePeriod, UsageId, Visibility,
+    },
 };
 use crate::domain::common::EmailAddress;
+use crate::domain::ports::mapping_reconciliation::{
+    DiscrepancyKind, MappingDiscrepancy, MappingReconciler,
+};
 use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
 use crate::infrastructure::config::ResourceConfig;
+use crate::infrastructure::google_auth::{GoogleCalendarHub, GoogleCredentials};
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
 use google_calendar3::{
-    CalendarHub,
-    api::Event,
-    hyper_rustls::{HttpsConnector, HttpsConnectorBuilder},
-    hyper_util::{
-        client::legacy::{Client, connect::HttpConnector},
-        rt::TokioExecutor,
-    },
+    api::{Event, EventAttendee, EventDateTime, EventExtendedProperties},
     yup_oauth2,
 };
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+/// 定期イベントを個別の予約に展開する期間（現在時刻からの日数）
+///
+/// 終了日のない定期イベントを無制限に展開しないための上限。
+const RECURRENCE_EXPANSION_DAYS: i64 = 365;
+
+/// 複数のカレンダーに分割して登録したイベントに付与する、Domain IDのプライベート拡張プロパティ名
+///
+/// 同じ値を持つイベントは1つのResourceUsageとしてまとめて扱う。
+const USAGE_ID_PROPERTY: &str = "labResourceManagerUsageId";
+
+/// 繰り返し予約から作成したイベントに付与する、シリーズIDのプライベート拡張プロパティ名
+const SERIES_ID_PROPERTY: &str = "labResourceManagerSeriesId";
+
+/// 繰り返し予約から作成したイベントに付与する、繰り返し規則（RRULE形式）のプライベート拡張プロパティ名
+///
+/// 各回は独立したイベントとして登録するため、Google Calendarの定期イベントの `recurrence` は使わない。
+const RECURRENCE_PROPERTY: &str = "labResourceManagerRecurrence";
+
+/// 通常以外の優先度の予約のイベントに付与する、優先度のプライベート拡張プロパティ名
+const PRIORITY_PROPERTY: &str = "labResourceManagerPriority";
+
+/// チームが所有する予約のイベントに付与する、チームIDのプライベート拡張プロパティ名
+const GROUP_PROPERTY: &str = "labResourceManagerGroup";
+
+/// イベントの説明（description）の予約者の行の見出し
+const DESCRIPTION_OWNER_LABEL: &str = "予約者: ";
+
+/// イベントの説明のプロジェクト名の行の見出し
+const DESCRIPTION_PROJECT_LABEL: &str = "プロジェクト: ";
+
+/// イベントの説明の実験IDの行の見出し
+const DESCRIPTION_EXPERIMENT_LABEL: &str = "実験ID: ";
+
+/// イベントの説明の想定使用率の行の見出し（値は「80%」の形式）
+const DESCRIPTION_UTILIZATION_LABEL: &str = "想定使用率: ";
+
+/// 非公開イベントを表すGoogle Calendarの `visibility` の値
+const EVENT_VISIBILITY_PRIVATE: &str = "private";
+
+/// カレンダーの既定の公開設定に従うイベントを表す `visibility` の値
+const EVENT_VISIBILITY_DEFAULT: &str = "default";
+
+/// 仮の予定（承認待ちの予約）を表すGoogle Calendarの `status` の値
+const EVENT_STATUS_TENTATIVE: &str = "tentative";
+
+/// 確定した予定を表す `status` の値
+const EVENT_STATUS_CONFIRMED: &str = "confirmed";
+
+/// 削除された予定を表す `status` の値
+const EVENT_STATUS_CANCELLED: &str = "cancelled";
+
+/// 予約者を参加者として追加するときの出欠の回答（承諾済み）
+const ATTENDEE_RESPONSE_ACCEPTED: &str = "accepted";
+
 /// Google Calendar APIを使用したResourceUsageリポジトリ実装
 pub struct GoogleCalendarUsageRepository {
-    hub: CalendarHub<HttpsConnector<HttpConnector>>,
+    hub: GoogleCalendarHub,
     config: ResourceConfig,
-    service_account_email: String,
-    id_mapper: Arc<IdMapper>,
+    /// 認証したアカウント（サービスアカウントまたはOAuthで同意したユーザー）のメールアドレス
+    account_email: String,
+    /// 予約者をイベントの参加者として追加するか（ドメイン全体の委任が有効な場合）
+    owner_as_attendee: bool,
+    id_mapper: Arc<dyn IdMapper>,
 }
 
 impl GoogleCalendarUsageRepository {
@@ -35,108 +96,198 @@ impl GoogleCalendarUsageRepository {
     /// # Arguments
     /// * `service_account_key` - サービスアカウントキーファイルのパス
     /// * `config` - リソース設定
-    /// * `id_mappings_path` - IDマッピングファイルのパス
+    /// * `id_mappings_path` - IDマッピングファイルのパス（拡張子が `.db` / `.sqlite` / `.sqlite3` の場合はSQLite）
     pub async fn new(
         service_account_key: &str,
         config: ResourceConfig,
         id_mappings_path: std::path::PathBuf,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let secret = yup_oauth2::read_service_account_key(service_account_key).await?;
-        let service_account_email = secret.client_email.clone();
-
-        let auth = yup_oauth2::ServiceAccountAuthenticator::builder(secret)
-            .build()
-            .await?;
-
-        let connector = HttpsConnectorBuilder::new()
-            .with_native_roots()?
-            .https_or_http()
-            .enable_http1()
-            .build();
+        Self::from_key(secret, config, id_mappings_path).await
+    }
 
-        let client = Client::builder(TokioExecutor::new()).build(connector);
+    /// 読み込み済みのサービスアカウントキーから新しいGoogle Calendarリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `secret` - サービスアカウントキー
+    /// * `config` - リソース設定
+    /// * `id_mappings_path` - IDマッピングファイルのパス
+    pub async fn from_key(
+        secret: yup_oauth2::ServiceAccountKey,
+        config: ResourceConfig,
+        id_mappings_path: std::path::PathBuf,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_credentials(
+            GoogleCredentials::ServiceAccount(secret),
+            config,
+            id_mappings_path,
+        )
+        .await
+    }
 
-        let hub = CalendarHub::new(client, auth);
+    /// 認証情報（サービスアカウントまたはOAuth）から新しいGoogle Calendarリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `credentials` - Google Calendar APIの認証情報
+    /// * `config` - リソース設定
+    /// * `id_mappings_path` - IDマッピングファイルのパス
+    pub async fn from_credentials(
+        credentials: GoogleCredentials,
+        config: ResourceConfig,
+        id_mappings_path: std::path::PathBuf,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let hub = credentials.calendar_hub().await?;
+        let account_email = credentials.account_email(&hub).await?;
+        let owner_as_attendee = credentials.can_invite_attendees();
 
-        let id_mapper = IdMapper::new(id_mappings_path)?;
+        let id_mapper = id_mapper::open(id_mappings_path).await?;
 
         Ok(Self {
             hub,
             config,
-            service_account_email,
-            id_mapper: Arc::new(id_mapper),
+            account_email,
+            owner_as_attendee,
+            id_mapper: Arc::from(id_mapper),
         })
     }
 
+    /// 認証したアカウントでカレンダーを参照できるか確認
+    ///
+    /// `calendars.get` を呼び出し、カレンダーが存在しない場合やサービスアカウント（OAuthの場合は同意したユーザー）と
+    /// 共有されていない場合はエラーを返す。
+    pub async fn check_calendar_access(&self, calendar_id: &str) -> Result<(), RepositoryError> {
+        self.hub
+            .calendars()
+            .get(calendar_id)
+            .doit()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                RepositoryError::ConnectionError(format!(
+                    "カレンダー {} を参照できません（{} と共有されているか確認してください）: {}",
+                    calendar_id, self.account_email, e
+                ))
+            })
+    }
+
     /// すべてのカレンダーから未来のイベントを取得
+    ///
+    /// サーバー・デバイス専用・部屋の各カレンダーから取得する。
     /// 戻り値: (Event, calendar_id, resource_name)
     async fn fetch_future_events(&self) -> Result<Vec<(Event, String, String)>, RepositoryError> {
-        let mut all_events = Vec::new();
+        // 過去24時間分も取得して、終了時刻でフィルタリングする
+        // time_minを開始時刻で制限すると、現在進行中のイベント（開始時刻が過去）が除外されてしまう
+        let now = Utc::now();
+        let events = self
+            .fetch_events(
+                now - Duration::hours(24),
+                now + Duration::days(RECURRENCE_EXPANSION_DAYS),
+            )
+            .await?;
 
-        // 各サーバーカレンダーから取得
-        for server in &self.config.servers {
-            let events = self.fetch_events_from_calendar(&server.calendar_id).await?;
-            all_events.extend(
-                events
-                    .into_iter()
-                    .map(|e| (e, server.calendar_id.clone(), server.name.clone())),
-            );
-        }
+        // 終了時刻が現在時刻より後のイベントのみを返す
+        // これにより、進行中または未来のイベントのみが対象となり、
+        // 完了したイベントが誤って削除通知されるのを防ぐ
+        Ok(events
+            .into_iter()
+            .filter(|(event, _, _)| {
+                event
+                    .end
+                    .as_ref()
+                    .and_then(|e| resolve_event_time(e, self.config.tz()))
+                    .map(|end_time| end_time > now)
+                    .unwrap_or(false)
+            })
+            .collect())
+    }
 
-        // 部屋カレンダーから取得
-        for room in &self.config.rooms {
-            let events = self.fetch_events_from_calendar(&room.calendar_id).await?;
+    /// すべてのカレンダーから指定範囲と重なるイベントを取得
+    ///
+    /// サーバー・デバイス専用・部屋の各カレンダーから取得する。
+    /// 戻り値: (Event, calendar_id, resource_name)
+    async fn fetch_events(
+        &self,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Result<Vec<(Event, String, String)>, RepositoryError> {
+        let mut all_events = Vec::new();
+
+        for calendar_id in self.config.calendar_ids() {
+            let resource_context = self.get_resource_context(&calendar_id)?;
+            let events = self
+                .fetch_events_from_calendar(&calendar_id, time_min, time_max)
+                .await?;
             all_events.extend(
                 events
                     .into_iter()
-                    .map(|e| (e, room.calendar_id.clone(), room.name.clone())),
+                    .map(|e| (e, calendar_id.clone(), resource_context.clone())),
             );
         }
 
         Ok(all_events)
     }
 
-    /// 特定のカレンダーから未来のイベント（進行中および今後予定されているもの）を取得
+    /// 特定のカレンダーから指定範囲と重なるイベントを取得
+    ///
+    /// 定期イベントは `singleEvents=true` で個別の発生（インスタンス）に展開して取得する。
     async fn fetch_events_from_calendar(
         &self,
         calendar_id: &str,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
     ) -> Result<Vec<Event>, RepositoryError> {
-        // 過去24時間分も取得して、終了時刻でフィルタリングする
-        // time_minを開始時刻で制限すると、現在進行中のイベント（開始時刻が過去）が除外されてしまう
-        let time_min = Utc::now() - Duration::hours(24);
+        let mut events = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut call = self
+                .hub
+                .events()
+                .list(calendar_id)
+                .time_min(time_min)
+                .time_max(time_max)
+                .single_events(true)
+                .order_by("startTime");
+            if let Some(token) = &page_token {
+                call = call.page_token(token);
+            }
 
-        let result = self
-            .hub
-            .events()
-            .list(calendar_id)
-            .time_min(time_min)
-            .doit()
-            .await
-            .map_err(|e| RepositoryError::ConnectionError(format!("Calendar API error: {}", e)))?;
+            let (_response, result) = call.doit().await.map_err(|e| {
+                RepositoryError::ConnectionError(format!("Calendar API error: {}", e))
+            })?;
 
-        let now = Utc::now();
-        let events = result.1.items.unwrap_or_default();
+            events.extend(result.items.unwrap_or_default());
+            page_token = result.next_page_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
 
-        // 終了時刻が現在時刻より後のイベントのみを返す
-        // これにより、進行中または未来のイベントのみが対象となり、
-        // 完了したイベントが誤って削除通知されるのを防ぐ
-        let filtered_events: Vec<Event> = events
-            .into_iter()
-            .filter(|event| {
-                event
-                    .end
-                    .as_ref()
-                    .and_then(|e| e.date_time.as_ref())
-                    .map(|end_time| *end_time > now)
-                    .unwrap_or(false)
-            })
-            .collect();
+        Ok(events)
+    }
+
+    /// イベントをResourceUsageに変換し、複数のカレンダーに分割された予約を1つにまとめる
+    ///
+    /// 変換できないイベントは警告を出して読み飛ばす。
+    async fn parse_events(
+        &self,
+        events: Vec<(Event, String, String)>,
+    ) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        let mut usages = Vec::new();
+        for (event, calendar_id, context) in events {
+            match self.parse_event(event, &calendar_id, &context).await {
+                Ok(usage) => usages.push(usage),
+                Err(e) => {
+                    tracing::warn!("⚠️ イベントパースエラー: {}", e); // TODO@KinjiKawaguchi: エラーハンドリングの改善
+                }
+            }
+        }
 
-        Ok(filtered_events)
+        // 複数のカレンダーに分割して登録された予約を1つにまとめる
+        merge_linked_usages(usages)
     }
 
     /// イベントをResourceUsageに変換
-    fn parse_event(
+    async fn parse_event(
         &self,
         event: Event,
         calendar_id: &str,
@@ -145,20 +296,29 @@ impl GoogleCalendarUsageRepository {
         // Event ID から Domain ID を取得
         let event_id = event.id.clone().unwrap_or_default();
 
-        let domain_id = match self.id_mapper.get_domain_id(&event_id)? {
+        // デバイス専用カレンダーに分割して登録したイベントは、付与したDomain IDをそのまま使う
+        let existing_domain_id = match linked_usage_id(&event) {
+            Some(linked_domain_id) => Some(linked_domain_id),
+            None => self.id_mapper.get_domain_id(&event_id).await?,
+        };
+
+        let domain_id = match existing_domain_id {
             Some(existing_domain_id) => existing_domain_id,
             None => {
                 // マッピングが見つからない場合、新しいdomain_idを生成してマッピングを作成
-                let new_domain_id = UsageId::new();
+                // 定期イベントの発生は、シリーズと元の開始時刻から決まる固定のIDを使う
+                let new_domain_id = occurrence_usage_id(&event).unwrap_or_default();
 
                 // 新しいマッピングを保存
-                self.id_mapper.save_mapping(
-                    new_domain_id.as_str(),
-                    ExternalId {
-                        calendar_id: calendar_id.to_string(),
-                        event_id: event_id.clone(),
-                    },
-                )?;
+                self.id_mapper
+                    .save_mapping(
+                        new_domain_id.as_str(),
+                        ExternalId {
+                            calendar_id: calendar_id.to_string(),
+                            event_id: event_id.clone(),
+                        },
+                    )
+                    .await?;
 
                 new_domain_id.as_str().to_string()
             }
@@ -167,62 +327,94 @@ impl GoogleCalendarUsageRepository {
         let id = UsageId::from_string(domain_id);
 
         // owner_emailの決定ロジック
-        let owner_email = event
-            .creator
-            .as_ref()
-            .and_then(|c| c.email.as_ref())
-            .ok_or_else(|| RepositoryError::Unknown("作成者情報がありません".to_string()))?;
-
-        // creatorがサービスアカウントの場合はdescriptionから実際のユーザーを取得
-        let owner_email = if owner_email == &self.service_account_email {
-            event
-                .description
-                .as_ref()
-                .and_then(|desc| {
-                    // "予約者: user@example.com" の形式から抽出
-                    desc.lines()
-                        .next()
-                        .and_then(|line| line.strip_prefix("予約者: "))
-                })
-                .ok_or_else(|| {
-                    RepositoryError::Unknown(
+        // Botが参加者付きで作成したイベントは参加者を、それ以外はdescriptionの "予約者: user@example.com" を優先する
+        // （サービスアカウントで作成したイベントや、引き継がれた予約は作成者と予約者が異なる）
+        let creator_email = event.creator.as_ref().and_then(|c| c.email.as_deref());
+        let attendee_owner = if creator_email == Some(self.account_email.as_str()) {
+            attendee_owner(&event, &self.account_email)
+        } else {
+            None
+        };
+        let described_owner = event.description.as_ref().and_then(|desc| {
+            desc.lines()
+                .next()
+                .and_then(|line| line.strip_prefix(DESCRIPTION_OWNER_LABEL))
+        });
+        let owner_email = match attendee_owner.or(described_owner) {
+            Some(owner_email) => owner_email,
+            None => {
+                let creator_email = creator_email.ok_or_else(|| {
+                    RepositoryError::Unknown("作成者情報がありません".to_string())
+                })?;
+                if creator_email == self.account_email {
+                    return Err(RepositoryError::Unknown(
                         "サービスアカウントで作成されたイベントのdescriptionにユーザー情報がありません"
                             .to_string(),
-                    )
-                })?
-        } else {
-            owner_email
+                    ));
+                }
+                creator_email
+            }
         };
 
         let user = self.parse_user(owner_email)?;
 
+        // 終日イベントは date のみを持つため、設定されたタイムゾーンの0:00に変換する
+        let timezone = self.config.tz();
         let start = event
             .start
             .as_ref()
-            .and_then(|s| s.date_time.as_ref())
+            .and_then(|s| resolve_event_time(s, timezone))
             .ok_or_else(|| RepositoryError::Unknown("開始時刻がありません".to_string()))?;
 
         let end = event
             .end
             .as_ref()
-            .and_then(|e| e.date_time.as_ref())
+            .and_then(|e| resolve_event_time(e, timezone))
             .ok_or_else(|| RepositoryError::Unknown("終了時刻がありません".to_string()))?;
 
-        let time_period = TimePeriod::new(*start, *end)
+        let time_period = TimePeriod::new(start, end)
             .map_err(|e| RepositoryError::Unknown(format!("時間枠エラー: {}", e)))?;
 
         // タイトルから資源をパース
         let default_title = String::new();
         let title = event.summary.as_ref().unwrap_or(&default_title);
-        let items = self.parse_resources(title, resource_context)?;
+        let items = self.parse_resources(title, resource_context, calendar_id)?;
+
+        // descriptionから備考とメタデータを抽出
+        let (notes, metadata) = event
+            .description
+            .as_deref()
+            .map(parse_description)
+            .unwrap_or_default();
+
+        // 非公開の予約はイベントの公開設定（visibility）で表す
+        let visibility = match event.visibility.as_deref() {
+            Some(EVENT_VISIBILITY_PRIVATE) => Visibility::Private,
+            _ => Visibility::Public,
+        };
 
-        // descriptionから備考を抽出（"予約者: xxx"の行を除外）
-        let notes = event.description.as_ref().and_then(|desc| {
-            // "予約者: xxx\n\n備考" の形式から備考部分を抽出
-            desc.split_once("\n\n").map(|(_, notes)| notes.to_string())
-        });
+        // 承認待ちの予約はイベントの状態（status）を仮の予定にして表す
+        let approval_status = match event.status.as_deref() {
+            Some(EVENT_STATUS_TENTATIVE) => ApprovalStatus::Pending,
+            _ => ApprovalStatus::Approved,
+        };
+
+        let series_id = linked_series_id(&event);
+        let recurrence = linked_recurrence(&event);
+        let priority = linked_priority(&event);
+        let group = linked_group(&event);
 
         ResourceUsage::reconstruct(id, user, time_period, items, notes)
+            .map(|usage| {
+                usage
+                    .with_visibility(visibility)
+                    .with_series_id(series_id)
+                    .with_recurrence(recurrence)
+                    .with_approval_status(approval_status)
+                    .with_priority(priority)
+                    .with_group(group)
+                    .with_metadata(metadata)
+            })
             .map_err(RepositoryError::from)
     }
 
@@ -232,11 +424,47 @@ impl GoogleCalendarUsageRepository {
     }
 
     /// タイトルから資源をパース
+    ///
+    /// デバイス専用カレンダーのイベントは、そのカレンダーに属するデバイスのみを対象とする。
+    /// タイトルからデバイスを特定できない場合は、カレンダーに属するすべてのデバイスとみなす。
     fn parse_resources(
         &self,
         title: &str,
         resource_context: &str,
+        calendar_id: &str,
     ) -> Result<Vec<Resource>, RepositoryError> {
+        // 機器の場合: 複数の機器が同じカレンダーを使う場合はタイトルの機器名で判別する
+        let instruments = self.config.instruments_in_calendar(calendar_id);
+        if let [instrument] = instruments.as_slice() {
+            return Ok(vec![Resource::Instrument {
+                name: instrument.name.clone(),
+            }]);
+        }
+        if !instruments.is_empty() {
+            let instrument = instruments
+                .iter()
+                .find(|i| i.name.eq_ignore_ascii_case(title.trim()))
+                .ok_or_else(|| {
+                    RepositoryError::Unknown(format!(
+                        "機器のカレンダーの予定のタイトルに機器名がありません: {}",
+                        title
+                    ))
+                })?;
+            return Ok(vec![Resource::Instrument {
+                name: instrument.name.clone(),
+            }]);
+        }
+
+        // 設定で定義した種別のリソースの場合
+        if let Some((resource_type, resource)) =
+            self.config.custom_resource_for_calendar(calendar_id)
+        {
+            return Ok(vec![Resource::Custom {
+                kind: resource_type.id.clone(),
+                name: resource.name.clone(),
+            }]);
+        }
+
         // 部屋の場合
         if let Some(room) = self
             .config
@@ -249,19 +477,69 @@ impl GoogleCalendarUsageRepository {
             }]);
         }
 
+        // ストレージの場合: タイトルの "scratch:500G" から確保する容量を取得
+        if let Some(storage) = self.config.get_storage(resource_context) {
+            let resource = ResourceFactory::create_storage_from_spec(title)
+                .map_err(|e| RepositoryError::Unknown(e.to_string()))?;
+            if resource.name() != storage.name {
+                return Err(RepositoryError::Unknown(format!(
+                    "ボリューム {} のカレンダーに別のボリュームの予定があります: {}",
+                    storage.name, title
+                )));
+            }
+            return Ok(vec![resource]);
+        }
+
+        // ライセンスの場合: タイトルの "matlab:2" から確保する席数を取得
+        if let Some(license) = self.config.get_license(resource_context) {
+            let resource = ResourceFactory::create_license_from_spec(title)
+                .map_err(|e| RepositoryError::Unknown(e.to_string()))?;
+            if resource.name() != license.name {
+                return Err(RepositoryError::Unknown(format!(
+                    "ライセンス {} のカレンダーに別のライセンスの予定があります: {}",
+                    license.name, title
+                )));
+            }
+            return Ok(vec![resource]);
+        }
+
         // GPU（サーバー）の場合: ResourceFactoryを使用
         let server = self.config.get_server(resource_context).ok_or_else(|| {
             RepositoryError::Unknown(format!("サーバーが見つかりません: {}", resource_context))
         })?;
 
-        ResourceFactory::create_gpus_from_spec(title, &server.name, |device_id| {
-            server
-                .devices
+        if calendar_id == server.calendar_id {
+            return ResourceFactory::create_gpus_from_spec(title, &server.name, |device_id| {
+                server
+                    .devices
+                    .iter()
+                    .find(|d| d.id == device_id)
+                    .map(|d| d.model.clone())
+            })
+            .map_err(|e| RepositoryError::Unknown(e.to_string()));
+        }
+
+        // デバイス専用カレンダー
+        let devices: Vec<_> = server
+            .devices
+            .iter()
+            .filter(|d| d.calendar_id.as_deref() == Some(calendar_id))
+            .collect();
+
+        let from_title = ResourceFactory::create_gpus_from_spec(title, &server.name, |device_id| {
+            devices
                 .iter()
                 .find(|d| d.id == device_id)
                 .map(|d| d.model.clone())
-        })
-        .map_err(|e| RepositoryError::Unknown(e.to_string()))
+        });
+
+        match from_title {
+            Ok(resources) if !resources.is_empty() => Ok(resources),
+            _ => Ok(devices
+                .iter()
+                .map(|d| Resource::Gpu(Gpu::new(server.name.clone(), d.id, d.model.clone())))
+                .collect()),
+        }
     }
 
     /// ResourcesからGPUデバイス仕様文字列を生成
@@ -296,8 +574,9 @@ impl GoogleCalendarUsageRepository {
     ///
     /// # 前提条件
     /// このメソッドは、ResourceUsage内のすべてのリソースが同一のカレンダーに属することを前提としています。
-    /// （例: すべてGPU、またはすべて部屋）
+    /// （例: すべて同じサーバーのGPU、またはすべて同じ部屋。ストレージは1つの予約に1つまで）
     /// 混在している場合はエラーを返します。
+    /// 複数のサーバーにまたがるGPUの予約は、`save` で `save_across_calendars` に振り分けられます。
     fn get_calendar_id_for_usage(&self, usage: &ResourceUsage) -> Result<String, RepositoryError> {
         let resources = usage.resources();
         if resources.is_empty() {
@@ -320,6 +599,18 @@ impl GoogleCalendarUsageRepository {
                     .iter()
                     .all(|r| matches!(r, Resource::Room { name } if name == first_name))
             }
+            Resource::Instrument { name: first_name } => {
+                // すべてのリソースが同じ機器であることを確認
+                resources
+                    .iter()
+                    .all(|r| matches!(r, Resource::Instrument { name } if name == first_name))
+            }
+            Resource::Custom { .. } => {
+                // すべてのリソースが同じリソースであることを確認
+                resources.iter().all(|r| r == first_resource)
+            }
+            // 予定のタイトルに容量・席数を1つだけ書くため、ストレージとライセンスは単独でのみ予約できる
+            Resource::Storage { .. } | Resource::License { .. } => resources.len() == 1,
         };
 
         if !all_same_type {
@@ -347,6 +638,38 @@ impl GoogleCalendarUsageRepository {
                     })?;
                 Ok(room.calendar_id.clone())
             }
+            Resource::Instrument { name } => self
+                .config
+                .get_calendar_id_for_resource(first_resource)
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    RepositoryError::Unknown(format!(
+                        "機器 {} のカレンダーが設定されていません",
+                        name
+                    ))
+                }),
+            Resource::Custom { kind, name } => self
+                .config
+                .get_calendar_id_for_resource(first_resource)
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    RepositoryError::Unknown(format!(
+                        "リソースが見つかりません: {} ({})",
+                        name, kind
+                    ))
+                }),
+            Resource::Storage { volume, .. } => {
+                let storage = self.config.get_storage(volume).ok_or_else(|| {
+                    RepositoryError::Unknown(format!("ボリュームが見つかりません: {}", volume))
+                })?;
+                Ok(storage.calendar_id.clone())
+            }
+            Resource::License { name, .. } => {
+                let license = self.config.get_license(name).ok_or_else(|| {
+                    RepositoryError::Unknown(format!("ライセンスが見つかりません: {}", name))
+                })?;
+                Ok(license.calendar_id.clone())
+            }
         }
     }
 
@@ -356,22 +679,32 @@ impl GoogleCalendarUsageRepository {
     /// このメソッドは、get_calendar_id_for_usageで検証済みのResourceUsageを受け取ることを前提としています。
     /// すなわち、すべてのリソースが同一のカレンダーに属していることが保証されています。
     fn create_event_from_usage(&self, usage: &ResourceUsage) -> Result<Event, RepositoryError> {
-        // 注: get_calendar_id_for_usageで検証済みのため、resources()[0]は安全に使用できる
-        let summary = match &usage.resources()[0] {
-            Resource::Gpu(_) => self.format_gpu_spec(usage.resources()).ok_or_else(|| {
+        self.create_event_for_resources(usage, usage.resources())
+    }
+
+    /// ResourceUsageのうち指定したリソース分のGoogle Calendar Eventを作成
+    ///
+    /// 複数のカレンダーに分割して登録する場合に、カレンダーごとのリソースを指定する。
+    /// 繰り返し予約の場合はシリーズIDを拡張プロパティに付与する。
+    fn create_event_for_resources(
+        &self,
+        usage: &ResourceUsage,
+        resources: &[Resource],
+    ) -> Result<Event, RepositoryError> {
+        // 注: get_calendar_id_for_usageで検証済みのため、resources[0]は安全に使用できる
+        let summary = match &resources[0] {
+            Resource::Gpu(_) => self.format_gpu_spec(resources).ok_or_else(|| {
                 RepositoryError::Unknown("GPUデバイス仕様の生成に失敗しました".to_string())
             })?,
-            Resource::Room { name } => name.clone(),
+            Resource::Room { name }
+            | Resource::Instrument { name }
+            | Resource::Custom { name, .. } => name.clone(),
+            Resource::Storage { volume, gigabytes } => format!("{}:{}G", volume, gigabytes),
+            Resource::License { name, seats } => format!("{}:{}", name, seats),
         };
 
-        // descriptionに予約者情報と備考を含める
-        let description = {
-            let mut desc = format!("予約者: {}", usage.owner_email().as_str());
-            if let Some(notes) = usage.notes() {
-                desc.push_str(&format!("\n\n{}", notes));
-            }
-            desc
-        };
+        // descriptionに予約者情報・メタデータと備考を含める
+        let description = format_description(usage);
 
         Ok(Event {
             summary: Some(summary),
@@ -384,8 +717,34 @@ impl GoogleCalendarUsageRepository {
                 date_time: Some(usage.time_period().end()),
                 ..Default::default()
             }),
-            // NOTE: attendeesを追加するとDomain-Wide Delegationが必要になるため、
-            // 予約者情報はdescriptionに含めています
+            // 非公開の予約は、カレンダー上でも他のユーザーには「予定あり」とだけ表示させる
+            visibility: Some(
+                if usage.visibility().is_private() {
+                    EVENT_VISIBILITY_PRIVATE
+                } else {
+                    EVENT_VISIBILITY_DEFAULT
+                }
+                .to_string(),
+            ),
+            // 承認待ちの予約は、承認されるまでカレンダー上で仮の予定として表示させる
+            status: Some(
+                if usage.approval_status().is_pending() {
+                    EVENT_STATUS_TENTATIVE
+                } else {
+                    EVENT_STATUS_CONFIRMED
+                }
+                .to_string(),
+            ),
+            extended_properties: event_properties(usage),
+            // NOTE: attendeesの追加にはDomain-Wide Delegationが必要なため、有効な場合のみ予約者を参加者にする。
+            // 委任を無効に戻しても予約者が分かるよう、descriptionにも予約者情報を含めている
+            attendees: self.owner_as_attendee.then(|| {
+                vec![EventAttendee {
+                    email: Some(usage.owner_email().as_str().to_string()),
+                    response_status: Some(ATTENDEE_RESPONSE_ACCEPTED.to_string()),
+                    ..Default::default()
+                }]
+            }),
             // NOTE: Event IDはGoogle Calendar側で自動生成され、id_mapperで管理されます
             ..Default::default()
         })
@@ -419,9 +778,14 @@ impl GoogleCalendarUsageRepository {
 
     /// カレンダーIDからリソースコンテキスト（サーバー名または部屋名）を取得
     fn get_resource_context(&self, calendar_id: &str) -> Result<String, RepositoryError> {
-        // サーバーカレンダーから検索
+        // サーバーカレンダー（デバイス専用カレンダーを含む）から検索
         for server in &self.config.servers {
-            if server.calendar_id == calendar_id {
+            if server.calendar_id == calendar_id
+                || server
+                    .devices
+                    .iter()
+                    .any(|d| d.calendar_id.as_deref() == Some(calendar_id))
+            {
                 return Ok(server.name.clone());
             }
         }
@@ -433,6 +797,30 @@ impl GoogleCalendarUsageRepository {
             }
         }
 
+        // 機器カレンダーから検索（共有カレンダーの場合は最初の機器名）
+        if let Some(instrument) = self.config.instruments_in_calendar(calendar_id).first() {
+            return Ok(instrument.name.clone());
+        }
+
+        // 設定で定義した種別のリソースのカレンダーから検索
+        if let Some((_, resource)) = self.config.custom_resource_for_calendar(calendar_id) {
+            return Ok(resource.name.clone());
+        }
+
+        // ストレージカレンダーから検索
+        for storage in &self.config.storage {
+            if storage.calendar_id == calendar_id {
+                return Ok(storage.name.clone());
+            }
+        }
+
+        // ライセンスカレンダーから検索
+        for license in &self.config.licenses {
+            if license.calendar_id == calendar_id {
+                return Ok(license.name.clone());
+            }
+        }
+
         Err(RepositoryError::Unknown(format!(
             "カレンダーIDに対応するリソースが見つかりません: {}",
             calendar_id
@@ -447,17 +835,7 @@ impl GoogleCalendarUsageRepository {
         event_id: &str,
     ) -> Result<Option<ResourceUsage>, RepositoryError> {
         // すべてのカレンダーIDを取得
-        let mut calendar_ids: Vec<String> = self
-            .config
-            .servers
-            .iter()
-            .map(|server| server.calendar_id.clone())
-            .collect();
-
-        // 部屋のカレンダーも追加
-        for room in &self.config.rooms {
-            calendar_ids.push(room.calendar_id.clone());
-        }
+        let calendar_ids = self.config.calendar_ids();
 
         // 各カレンダーでイベントの検索を試みる
         for calendar_id in calendar_ids {
@@ -465,11 +843,17 @@ impl GoogleCalendarUsageRepository {
                 .fetch_event_from_calendar(&calendar_id, event_id)
                 .await?
             {
+                Some(event) if event.status.as_deref() == Some(EVENT_STATUS_CANCELLED) => {
+                    // 削除されたイベントは見つからなかったものとする
+                    return Ok(None);
+                }
                 Some(event) => {
                     // リソースコンテキストを取得
                     let resource_context = self.get_resource_context(&calendar_id)?;
                     // イベントをパース（この時点で新しいマッピングが作成される）
-                    let usage = self.parse_event(event, &calendar_id, &resource_context)?;
+                    let usage = self
+                        .parse_event(event, &calendar_id, &resource_context)
+                        .await?;
                     return Ok(Some(usage));
                 }
                 None => {
@@ -488,17 +872,7 @@ impl GoogleCalendarUsageRepository {
     /// 全カレンダーから該当するイベントを検索して削除します。
     async fn delete_by_event_id(&self, event_id: &str) -> Result<(), RepositoryError> {
         // すべてのカレンダーIDを取得
-        let mut calendar_ids: Vec<String> = self
-            .config
-            .servers
-            .iter()
-            .map(|server| server.calendar_id.clone())
-            .collect();
-
-        // 部屋のカレンダーも追加
-        for room in &self.config.rooms {
-            calendar_ids.push(room.calendar_id.clone());
-        }
+        let calendar_ids = self.config.calendar_ids();
 
         // 各カレンダーでイベントの削除を試みる
         for calendar_id in calendar_ids {
@@ -537,51 +911,693 @@ impl GoogleCalendarUsageRepository {
         );
         Err(RepositoryError::NotFound)
     }
-}
 
-#[async_trait]
-impl ResourceUsageRepository for GoogleCalendarUsageRepository {
-    async fn find_by_id(&self, id: &UsageId) -> Result<Option<ResourceUsage>, RepositoryError> {
-        let input_id = id.as_str();
+    /// 後続の処理に失敗したときに、作成済みのイベントを削除する（補償処理）
+    ///
+    /// 孤立したイベントが残らないようにするためのもの。
+    /// 削除にも失敗した場合はログに記録し、呼び出し元には元のエラーを返させる。
+    async fn compensate_created_event(&self, external_id: &ExternalId) {
+        compensate_created_events(self, std::slice::from_ref(external_id)).await;
+    }
 
-        // まずdomain_idとして外部IDを取得を試みる
-        let external_id = match self.id_mapper.get_external_id(input_id)? {
-            Some(ext_id) => ext_id,
-            None => {
-                // 見つからない場合、input_idがevent_idの可能性がある
-                // 逆引きマッピングを試みる
-                match self.id_mapper.get_domain_id(input_id)? {
-                    Some(domain_id) => {
-                        // domain_idが見つかったので、それで外部IDを取得
-                        match self.id_mapper.get_external_id(&domain_id)? {
-                            Some(ext_id) => ext_id,
-                            None => {
-                                return Ok(None);
-                            }
-                        }
-                    }
-                    None => {
-                        // それでも見つからない場合、event_idとして全カレンダーから検索
-                        return self.find_by_event_id(input_id).await;
-                    }
-                }
+    /// ResourceUsageを複数のカレンダーに分割して保存する必要があるか
+    ///
+    /// GPUが複数のサーバーにまたがる場合や、デバイス専用カレンダーを持つサーバーのGPUを含む場合に分割する。
+    fn splits_across_calendars(&self, usage: &ResourceUsage) -> bool {
+        let mut servers: Vec<&str> = Vec::new();
+        for resource in usage.resources() {
+            if let Resource::Gpu(gpu) = resource
+                && !servers.contains(&gpu.server())
+            {
+                servers.push(gpu.server());
             }
-        };
-
-        // 特定のカレンダーから直接イベントを取得
-        let event = match self
-            .fetch_event_from_calendar(&external_id.calendar_id, &external_id.event_id)
-            .await?
-        {
-            Some(event) => event,
-            None => return Ok(None), // イベントが見つからない場合はNone
-        };
-
-        // リソースコンテキストを取得
-        let resource_context = self.get_resource_context(&external_id.calendar_id)?;
-
-        // イベントをパース（ただし、domain_idは元のinput_idを使用）
-        let mut usage = self.parse_event(event, &external_id.calendar_id, &resource_context)?;
+        }
+        servers.len() > 1
+            || servers.iter().any(|name| {
+                self.config
+                    .get_server(name)
+                    .is_some_and(|server| server.has_device_calendars())
+            })
+    }
+
+    /// GPUのカレンダー（サーバーおよびデバイス専用カレンダー）から、指定したDomain IDを付与したイベントを取得
+    ///
+    /// 戻り値: (calendar_id, Event)
+    async fn fetch_linked_events(
+        &self,
+        domain_id: &str,
+    ) -> Result<Vec<(String, Event)>, RepositoryError> {
+        let property = format!("{}={}", USAGE_ID_PROPERTY, domain_id);
+        let mut linked = Vec::new();
+        for calendar_id in self.config.gpu_calendar_ids() {
+            let (_response, result) = self
+                .hub
+                .events()
+                .list(&calendar_id)
+                .add_private_extended_property(&property)
+                .doit()
+                .await
+                .map_err(|e| {
+                    RepositoryError::ConnectionError(format!("Calendar API error: {}", e))
+                })?;
+            linked.extend(
+                result
+                    .items
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|event| (calendar_id.clone(), event)),
+            );
+        }
+
+        Ok(linked)
+    }
+
+    /// 分割して登録された他のイベントのリソースをResourceUsageにまとめる
+    async fn merge_linked_events(
+        &self,
+        usage: ResourceUsage,
+        primary: &ExternalId,
+    ) -> Result<ResourceUsage, RepositoryError> {
+        let mut usages = vec![usage];
+        for (calendar_id, event) in self.fetch_linked_events(usages[0].id().as_str()).await? {
+            if event.id.as_deref() == Some(primary.event_id.as_str()) {
+                continue;
+            }
+            let resource_context = self.get_resource_context(&calendar_id)?;
+            usages.push(
+                self.parse_event(event, &calendar_id, &resource_context)
+                    .await?,
+            );
+        }
+
+        merge_linked_usages(usages)?
+            .into_iter()
+            .next()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    /// ResourceUsageをカレンダーごとに分割して保存
+    ///
+    /// サーバーおよびデバイス専用のカレンダーごとに1つのイベントを作成・更新し、
+    /// すべてのイベントにDomain IDを付与する。
+    /// 不要になったカレンダーのイベントは削除する。
+    /// IdMapperには最初のカレンダーのイベントを代表として登録する。
+    async fn save_across_calendars(&self, usage: &ResourceUsage) -> Result<(), RepositoryError> {
+        let domain_id = usage.id().as_str();
+
+        // リソースをカレンダーごとに分ける
+        let mut groups: Vec<(String, Vec<Resource>)> = Vec::new();
+        for resource in usage.resources() {
+            let calendar_id = match resource {
+                Resource::Gpu(gpu) => self
+                    .config
+                    .get_calendar_id_for_resource(resource)
+                    .ok_or_else(|| {
+                        RepositoryError::Unknown(format!(
+                            "サーバーが見つかりません: {}",
+                            gpu.server()
+                        ))
+                    })?,
+                Resource::Room { .. }
+                | Resource::Instrument { .. }
+                | Resource::Custom { .. }
+                | Resource::Storage { .. }
+                | Resource::License { .. } => {
+                    return Err(RepositoryError::Unknown(
+                        "GPUと部屋・機器・ストレージ・ライセンスを同じ予約に含めることはできません"
+                            .to_string(),
+                    ));
+                }
+            };
+            match groups.iter_mut().find(|(id, _)| id == calendar_id) {
+                Some((_, resources)) => resources.push(resource.clone()),
+                None => groups.push((calendar_id.to_string(), vec![resource.clone()])),
+            }
+        }
+
+        // 既存のイベント（calendar_id → event_id）
+        let mut existing: HashMap<String, String> = self
+            .fetch_linked_events(domain_id)
+            .await?
+            .into_iter()
+            .filter_map(|(calendar_id, event)| event.id.map(|id| (calendar_id, id)))
+            .collect();
+        if let Some(external_id) = self.id_mapper.get_external_id(domain_id).await? {
+            existing
+                .entry(external_id.calendar_id)
+                .or_insert(external_id.event_id);
+        }
+
+        let mut events = Vec::with_capacity(groups.len());
+        for (calendar_id, resources) in groups {
+            let mut event = self.create_event_for_resources(usage, &resources)?;
+            event
+                .extended_properties
+                .get_or_insert_with(Default::default)
+                .private
+                .get_or_insert_with(HashMap::new)
+                .insert(USAGE_ID_PROPERTY.to_string(), domain_id.to_string());
+            events.push((calendar_id, event));
+        }
+
+        let written = write_linked_events(self, events, existing).await?;
+        if let Err(e) = self
+            .id_mapper
+            .save_mapping(domain_id, written.primary)
+            .await
+        {
+            compensate_created_events(self, &written.created).await;
+            return Err(e);
+        }
+
+        // 保存に成功してから、予約から外れたカレンダーのイベントを削除
+        delete_events(self, &written.stale).await
+    }
+
+    /// 代表イベント以外に分割して登録されたイベントを削除
+    ///
+    /// 代表イベントにDomain IDが付与されていない（分割されていない）場合は何もしない。
+    async fn delete_linked_events(
+        &self,
+        primary: &ExternalId,
+        domain_id: &str,
+    ) -> Result<(), RepositoryError> {
+        let linked = self.find_other_linked_events(primary, domain_id).await?;
+        delete_events(self, &linked).await
+    }
+
+    /// 代表イベント以外に分割して登録されたイベントを取得
+    ///
+    /// 代表イベントにDomain IDが付与されていない（分割されていない）場合は空を返す。
+    async fn find_other_linked_events(
+        &self,
+        primary: &ExternalId,
+        domain_id: &str,
+    ) -> Result<Vec<ExternalId>, RepositoryError> {
+        let is_linked = self
+            .fetch_event_from_calendar(&primary.calendar_id, &primary.event_id)
+            .await?
+            .is_some_and(|event| linked_usage_id(&event).is_some());
+        if !is_linked {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .fetch_linked_events(domain_id)
+            .await?
+            .into_iter()
+            .filter_map(|(calendar_id, event)| {
+                Some(ExternalId {
+                    calendar_id,
+                    event_id: event.id?,
+                })
+            })
+            .filter(|external_id| external_id != primary)
+            .collect())
+    }
+}
+
+/// カレンダーのイベントの作成・更新・削除
+///
+/// 複数のカレンダーに分割して保存する処理を、Calendar APIに接続せずに確かめられるようにする。
+#[async_trait]
+trait EventWriter: Sync {
+    /// イベントを作成し、作成されたEvent IDを返す
+    async fn insert_event(
+        &self,
+        event: Event,
+        calendar_id: &str,
+    ) -> Result<String, RepositoryError>;
+
+    /// イベントを更新する
+    async fn update_event(
+        &self,
+        event: Event,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> Result<(), RepositoryError>;
+
+    /// イベントを削除する
+    async fn delete_event(&self, calendar_id: &str, event_id: &str) -> Result<(), RepositoryError>;
+}
+
+#[async_trait]
+impl EventWriter for GoogleCalendarUsageRepository {
+    async fn insert_event(
+        &self,
+        event: Event,
+        calendar_id: &str,
+    ) -> Result<String, RepositoryError> {
+        let (_response, created_event) = self
+            .hub
+            .events()
+            .insert(event, calendar_id)
+            .doit()
+            .await
+            .map_err(|e| RepositoryError::ConnectionError(format!("イベント作成に失敗: {}", e)))?;
+
+        created_event.id.ok_or_else(|| {
+            RepositoryError::Unknown("作成されたイベントにIDがありません".to_string())
+        })
+    }
+
+    async fn update_event(
+        &self,
+        event: Event,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> Result<(), RepositoryError> {
+        self.hub
+            .events()
+            .update(event, calendar_id, event_id)
+            .doit()
+            .await
+            .map_err(|e| RepositoryError::ConnectionError(format!("イベント更新に失敗: {}", e)))?;
+        Ok(())
+    }
+
+    async fn delete_event(&self, calendar_id: &str, event_id: &str) -> Result<(), RepositoryError> {
+        self.hub
+            .events()
+            .delete(calendar_id, event_id)
+            .doit()
+            .await
+            .map_err(|e| RepositoryError::ConnectionError(format!("イベント削除に失敗: {}", e)))?;
+        Ok(())
+    }
+}
+
+/// カレンダーごとに書き込んだイベント
+struct WrittenEvents {
+    /// 代表のイベント
+    primary: ExternalId,
+    /// 新たに作成したイベント
+    created: Vec<ExternalId>,
+    /// 予約から外れたカレンダーの既存のイベント（まだ削除していない）
+    stale: Vec<ExternalId>,
+}
+
+/// カレンダーごとのイベントを作成・更新する
+///
+/// どの段階で失敗しても、このときに作成したイベントを削除してからエラーを返す。
+/// 予約から外れたカレンダーのイベントは削除せずに返す。保存に失敗したときに予約が失われないよう、
+/// 呼び出し側はマッピングを保存してから [`delete_events`] で削除する。
+///
+/// # Arguments
+/// * `events` - カレンダーIDと保存するイベント（最初のものを代表とする）
+/// * `existing` - 既存のイベント（calendar_id → event_id）
+async fn write_linked_events<W: EventWriter + ?Sized>(
+    writer: &W,
+    events: Vec<(String, Event)>,
+    existing: HashMap<String, String>,
+) -> Result<WrittenEvents, RepositoryError> {
+    let mut created: Vec<ExternalId> = Vec::new();
+    match write_events(writer, events, existing, &mut created).await {
+        Ok((primary, stale)) => Ok(WrittenEvents {
+            primary,
+            created,
+            stale,
+        }),
+        Err(e) => {
+            compensate_created_events(writer, &created).await;
+            Err(e)
+        }
+    }
+}
+
+/// イベントを書き込み、代表のイベントと予約から外れたイベントを返す（作成したイベントは `created` に加える）
+async fn write_events<W: EventWriter + ?Sized>(
+    writer: &W,
+    events: Vec<(String, Event)>,
+    mut existing: HashMap<String, String>,
+    created: &mut Vec<ExternalId>,
+) -> Result<(ExternalId, Vec<ExternalId>), RepositoryError> {
+    let mut primary: Option<ExternalId> = None;
+    for (calendar_id, mut event) in events {
+        let event_id = match existing.remove(&calendar_id) {
+            Some(event_id) => {
+                event.id = Some(event_id.clone());
+                writer.update_event(event, &calendar_id, &event_id).await?;
+                event_id
+            }
+            None => {
+                let event_id = writer.insert_event(event, &calendar_id).await?;
+                created.push(ExternalId {
+                    calendar_id: calendar_id.clone(),
+                    event_id: event_id.clone(),
+                });
+                event_id
+            }
+        };
+        primary.get_or_insert(ExternalId {
+            calendar_id,
+            event_id,
+        });
+    }
+
+    let primary =
+        primary.ok_or_else(|| RepositoryError::Unknown("リソースが空です".to_string()))?;
+    let stale = existing
+        .into_iter()
+        .map(|(calendar_id, event_id)| ExternalId {
+            calendar_id,
+            event_id,
+        })
+        .collect();
+    Ok((primary, stale))
+}
+
+/// 指定したイベントをすべて削除する
+async fn delete_events<W: EventWriter + ?Sized>(
+    writer: &W,
+    events: &[ExternalId],
+) -> Result<(), RepositoryError> {
+    for external_id in events {
+        writer
+            .delete_event(&external_id.calendar_id, &external_id.event_id)
+            .await?;
+    }
+    Ok(())
+}
+
+/// 後続の処理に失敗したときに、作成済みのイベントを削除する（補償処理）
+///
+/// 削除にも失敗した場合はログに記録するだけにとどめる。
+async fn compensate_created_events<W: EventWriter + ?Sized>(writer: &W, created: &[ExternalId]) {
+    for external_id in created {
+        match writer
+            .delete_event(&external_id.calendar_id, &external_id.event_id)
+            .await
+        {
+            Ok(()) => tracing::warn!(
+                "↩️ 保存に失敗したため作成したイベントを削除しました: event_id={}, calendar_id={}",
+                external_id.event_id,
+                external_id.calendar_id
+            ),
+            Err(e) => tracing::error!(
+                "❌ 孤立したイベントの削除に失敗: event_id={}, calendar_id={}, error={}",
+                external_id.event_id,
+                external_id.calendar_id,
+                e
+            ),
+        }
+    }
+}
+
+/// イベントに付与されたDomain IDを取得
+fn linked_usage_id(event: &Event) -> Option<String> {
+    event
+        .extended_properties
+        .as_ref()?
+        .private
+        .as_ref()?
+        .get(USAGE_ID_PROPERTY)
+        .cloned()
+}
+
+/// イベントに付与された繰り返し予約のシリーズIDを取得
+fn linked_series_id(event: &Event) -> Option<SeriesId> {
+    event
+        .extended_properties
+        .as_ref()?
+        .private
+        .as_ref()?
+        .get(SERIES_ID_PROPERTY)
+        .map(|id| SeriesId::from_string(id.clone()))
+}
+
+/// イベントに付与された繰り返し予約の規則を取得
+fn linked_recurrence(event: &Event) -> Option<RecurrenceRule> {
+    event
+        .extended_properties
+        .as_ref()?
+        .private
+        .as_ref()?
+        .get(RECURRENCE_PROPERTY)
+        .and_then(|rule| RecurrenceRule::from_rrule(rule))
+}
+
+/// イベントに付与された優先度を取得（付与されていない場合は通常）
+fn linked_priority(event: &Event) -> Priority {
+    event
+        .extended_properties
+        .as_ref()
+        .and_then(|properties| properties.private.as_ref())
+        .and_then(|private| private.get(PRIORITY_PROPERTY))
+        .and_then(|priority| priority.parse().ok())
+        .unwrap_or_default()
+}
+
+/// イベントに付与された予約を所有するチームを取得
+fn linked_group(event: &Event) -> Option<GroupId> {
+    event
+        .extended_properties
+        .as_ref()?
+        .private
+        .as_ref()?
+        .get(GROUP_PROPERTY)
+        .map(|id| GroupId::new(id.clone()))
+}
+
+/// 予約のシリーズID・繰り返し規則・優先度と所有するチームをイベントのプライベート拡張プロパティにする
+///
+/// いずれもない（シリーズに属さない通常の優先度の個人の）予約は `None`
+fn event_properties(usage: &ResourceUsage) -> Option<EventExtendedProperties> {
+    let mut private = HashMap::new();
+    if let Some(series_id) = usage.series_id() {
+        private.insert(
+            SERIES_ID_PROPERTY.to_string(),
+            series_id.as_str().to_string(),
+        );
+    }
+    if let Some(recurrence) = usage.recurrence() {
+        private.insert(RECURRENCE_PROPERTY.to_string(), recurrence.to_rrule());
+    }
+    if usage.priority() != Priority::Normal {
+        private.insert(
+            PRIORITY_PROPERTY.to_string(),
+            usage.priority().as_str().to_string(),
+        );
+    }
+    if let Some(group) = usage.group() {
+        private.insert(GROUP_PROPERTY.to_string(), group.as_str().to_string());
+    }
+    if private.is_empty() {
+        return None;
+    }
+    Some(EventExtendedProperties {
+        private: Some(private),
+        shared: None,
+    })
+}
+
+/// イベントの説明（description）を作成する
+///
+/// 1段落目に予約者と指定されたメタデータを1項目1行の「見出し: 値」の形式で、
+/// 空行のあとに備考を書く。
+fn format_description(usage: &ResourceUsage) -> String {
+    let mut desc = format!(
+        "{}{}",
+        DESCRIPTION_OWNER_LABEL,
+        usage.owner_email().as_str()
+    );
+    let metadata = usage.metadata();
+    if let Some(project) = metadata.project() {
+        desc.push_str(&format!("\n{}{}", DESCRIPTION_PROJECT_LABEL, project));
+    }
+    if let Some(experiment_id) = metadata.experiment_id() {
+        desc.push_str(&format!(
+            "\n{}{}",
+            DESCRIPTION_EXPERIMENT_LABEL, experiment_id
+        ));
+    }
+    if let Some(utilization) = metadata.expected_utilization() {
+        desc.push_str(&format!(
+            "\n{}{}%",
+            DESCRIPTION_UTILIZATION_LABEL, utilization
+        ));
+    }
+    if let Some(notes) = usage.notes() {
+        desc.push_str(&format!("\n\n{}", notes));
+    }
+    desc
+}
+
+/// イベントの説明から備考とメタデータを取り出す
+///
+/// `format_description` の形式を前提とし、1段落目の解釈できない行や不正な値は無視する。
+/// メタデータの行がない（以前の形式の）説明は、メタデータ未指定として扱う。
+fn parse_description(description: &str) -> (Option<String>, ReservationMetadata) {
+    let (header, notes) = match description.split_once("\n\n") {
+        Some((header, notes)) => (header, Some(notes.to_string())),
+        None => (description, None),
+    };
+
+    let mut project = None;
+    let mut experiment_id = None;
+    let mut utilization = None;
+    for line in header.lines() {
+        if let Some(value) = line.strip_prefix(DESCRIPTION_PROJECT_LABEL) {
+            project = Some(value.to_string());
+        } else if let Some(value) = line.strip_prefix(DESCRIPTION_EXPERIMENT_LABEL) {
+            experiment_id = Some(value.to_string());
+        } else if let Some(value) = line.strip_prefix(DESCRIPTION_UTILIZATION_LABEL) {
+            utilization = value.trim().trim_end_matches('%').parse().ok();
+        }
+    }
+    let metadata = ReservationMetadata::new(project.clone(), experiment_id.clone(), utilization)
+        .or_else(|_| ReservationMetadata::new(project, experiment_id, None))
+        .unwrap_or_default();
+    (notes, metadata)
+}
+
+/// Botが追加した参加者（予約者）のメールアドレス
+///
+/// 主催者・会議室などのリソース・Bot自身は除く。
+fn attendee_owner<'a>(event: &'a Event, account_email: &str) -> Option<&'a str> {
+    event
+        .attendees
+        .as_ref()?
+        .iter()
+        .filter(|a| {
+            !a.organizer.unwrap_or(false)
+                && !a.resource.unwrap_or(false)
+                && !a.self_.unwrap_or(false)
+        })
+        .filter_map(|a| a.email.as_deref())
+        .find(|email| *email != account_email)
+}
+
+/// 同じIDを持つResourceUsage（複数のカレンダーに分割されたもの）を1つにまとめる
+///
+/// 順序は最初に現れた位置を保つ。時間帯・予約者・備考は最初のものを使う。
+fn merge_linked_usages(usages: Vec<ResourceUsage>) -> Result<Vec<ResourceUsage>, RepositoryError> {
+    let mut merged: Vec<ResourceUsage> = Vec::new();
+    for usage in usages {
+        match merged.iter_mut().find(|m| m.id() == usage.id()) {
+            Some(existing) => {
+                let mut resources = existing.resources().to_vec();
+                for resource in usage.resources() {
+                    if !resources.contains(resource) {
+                        resources.push(resource.clone());
+                    }
+                }
+                *existing = ResourceUsage::reconstruct(
+                    existing.id().clone(),
+                    existing.owner_email().clone(),
+                    existing.time_period().clone(),
+                    resources,
+                    existing.notes().cloned(),
+                )?
+                .with_visibility(existing.visibility())
+                .with_series_id(existing.series_id().cloned())
+                .with_recurrence(existing.recurrence().cloned())
+                .with_approval_status(existing.approval_status())
+                .with_priority(existing.priority())
+                .with_group(existing.group().cloned())
+                .with_metadata(existing.metadata().clone());
+            }
+            None => merged.push(usage),
+        }
+    }
+    Ok(merged)
+}
+
+/// イベントの日時をUTCに変換
+///
+/// 終日イベント（`date` のみ）は、指定タイムゾーン（未指定ならシステムのローカルタイムゾーン）の
+/// その日の0:00として扱う。Google Calendarの終日イベントの終了日は翌日（排他的）のため、
+/// 開始・終了ともにこの変換を行うと丸1日分の期間になる。
+fn resolve_event_time(event_time: &EventDateTime, timezone: Option<Tz>) -> Option<DateTime<Utc>> {
+    if let Some(date_time) = event_time.date_time {
+        return Some(date_time);
+    }
+
+    let date = event_time.date?;
+    match timezone {
+        Some(tz) => start_of_day(&tz, date),
+        None => start_of_day(&Local, date),
+    }
+}
+
+/// 指定タイムゾーンにおける日付の0:00をUTCで取得
+fn start_of_day<T: TimeZone>(tz: &T, date: NaiveDate) -> Option<DateTime<Utc>> {
+    tz.from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// 定期イベントの発生（インスタンス）に対応するUsageIdを生成
+///
+/// `{シリーズのevent_id}_{元の開始時刻(UTC)}` の形式で、同じ発生に対して常に同じIDになる。
+/// 定期イベントのインスタンスでない場合は `None` を返す。
+fn occurrence_usage_id(event: &Event) -> Option<UsageId> {
+    let series_id = event.recurring_event_id.as_ref()?;
+    let original_start = event.original_start_time.as_ref()?;
+
+    let occurrence_key = match (original_start.date_time, original_start.date) {
+        (Some(date_time), _) => date_time.format("%Y%m%dT%H%M%SZ").to_string(),
+        (None, Some(date)) => date.format("%Y%m%d").to_string(),
+        (None, None) => return None,
+    };
+
+    Some(UsageId::from_string(format!(
+        "{}_{}",
+        series_id, occurrence_key
+    )))
+}
+
+#[async_trait]
+impl ResourceUsageRepository for GoogleCalendarUsageRepository {
+    async fn find_by_id(&self, id: &UsageId) -> Result<Option<ResourceUsage>, RepositoryError> {
+        let input_id = id.as_str();
+
+        // まずdomain_idとして外部IDを取得を試みる
+        let external_id = match self.id_mapper.get_external_id(input_id).await? {
+            Some(ext_id) => ext_id,
+            None => {
+                // 見つからない場合、input_idがevent_idの可能性がある
+                // 逆引きマッピングを試みる
+                match self.id_mapper.get_domain_id(input_id).await? {
+                    Some(domain_id) => {
+                        // domain_idが見つかったので、それで外部IDを取得
+                        match self.id_mapper.get_external_id(&domain_id).await? {
+                            Some(ext_id) => ext_id,
+                            None => {
+                                return Ok(None);
+                            }
+                        }
+                    }
+                    None => {
+                        // それでも見つからない場合、event_idとして全カレンダーから検索
+                        return self.find_by_event_id(input_id).await;
+                    }
+                }
+            }
+        };
+
+        // 特定のカレンダーから直接イベントを取得
+        let event = match self
+            .fetch_event_from_calendar(&external_id.calendar_id, &external_id.event_id)
+            .await?
+        {
+            // 削除されたイベントも取得できるため、見つからない場合と同じく扱う
+            Some(event) if event.status.as_deref() != Some(EVENT_STATUS_CANCELLED) => event,
+            _ => return Ok(None), // イベントが見つからない場合はNone
+        };
+
+        // リソースコンテキストを取得
+        let resource_context = self.get_resource_context(&external_id.calendar_id)?;
+        let is_linked = linked_usage_id(&event).is_some();
+
+        // イベントをパース（ただし、domain_idは元のinput_idを使用）
+        let mut usage = self
+            .parse_event(event, &external_id.calendar_id, &resource_context)
+            .await?;
+        // 複数のカレンダーに分割して登録された予約は、他のイベントのリソースもまとめる
+        if is_linked {
+            usage = self.merge_linked_events(usage, &external_id).await?;
+        }
 
         // IMPORTANT: find_by_id() で検索した場合、取得したResourceUsageのIDは
         // 必ず元のinput_idであるべき。parse_event()が別のdomain_idを生成した場合、
@@ -599,7 +1615,14 @@ impl ResourceUsageRepository for GoogleCalendarUsageRepository {
                 usage.time_period().clone(),
                 usage.resources().to_vec(),
                 usage.notes().cloned(),
-            )?;
+            )?
+            .with_visibility(usage.visibility())
+            .with_series_id(usage.series_id().cloned())
+            .with_recurrence(usage.recurrence().cloned())
+            .with_approval_status(usage.approval_status())
+            .with_priority(usage.priority())
+            .with_group(usage.group().cloned())
+            .with_metadata(usage.metadata().clone());
         }
 
         Ok(Some(usage))
@@ -607,26 +1630,15 @@ impl ResourceUsageRepository for GoogleCalendarUsageRepository {
 
     async fn find_future(&self) -> Result<Vec<ResourceUsage>, RepositoryError> {
         let events = self.fetch_future_events().await?;
-
-        let mut usages = Vec::new();
-        for (event, calendar_id, context) in events {
-            match self.parse_event(event, &calendar_id, &context) {
-                Ok(usage) => usages.push(usage),
-                Err(e) => {
-                    eprintln!("⚠️  イベントパースエラー: {}", e); // TODO@KinjiKawaguchi: エラーハンドリングの改善
-                }
-            }
-        }
-
-        Ok(usages)
+        self.parse_events(events).await
     }
 
     /// 指定期間と重複するResourceUsageを検索
     ///
+    /// 各カレンダーに期間を指定して問い合わせるため、終了済みの予約も含まれる。
+    ///
     /// # パフォーマンスに関する注意
-    /// 現在の実装では、すべての未来のイベントを取得してからメモリ上でフィルタリングしています。
-    /// Google Calendar APIには時間範囲での検索機能がありますが、複数カレンダーにまたがる
-    /// 検索を効率的に行うための十分なクエリ機能がないため、この実装を採用しています。
+    /// カレンダーごとに順に問い合わせています。
     ///
     /// 将来的な改善案:
     /// - 各カレンダーに対して時間範囲クエリを並列実行
@@ -635,8 +1647,12 @@ impl ResourceUsageRepository for GoogleCalendarUsageRepository {
         &self,
         time_period: &TimePeriod,
     ) -> Result<Vec<ResourceUsage>, RepositoryError> {
-        let all_usages = self.find_future().await?;
-        Ok(all_usages
+        let events = self
+            .fetch_events(time_period.start(), time_period.end())
+            .await?;
+        Ok(self
+            .parse_events(events)
+            .await?
             .into_iter()
             .filter(|usage| usage.time_period().overlaps_with(time_period))
             .collect())
@@ -664,11 +1680,22 @@ impl ResourceUsageRepository for GoogleCalendarUsageRepository {
     }
 
     async fn save(&self, usage: &ResourceUsage) -> Result<(), RepositoryError> {
+        // 複数のサーバーやデバイス専用カレンダーにまたがる予約は、カレンダーごとにイベントを分けて保存
+        if self.splits_across_calendars(usage) {
+            return self.save_across_calendars(usage).await;
+        }
+
         let new_calendar_id = self.get_calendar_id_for_usage(usage)?;
         let domain_id = usage.id().as_str();
 
         // Domain IDから外部IDを検索
-        if let Some(external_id) = self.id_mapper.get_external_id(domain_id)? {
+        if let Some(external_id) = self.id_mapper.get_external_id(domain_id).await? {
+            // 以前は分割して登録していた場合の代表以外のイベント
+            // 保存に失敗したときに予約が失われないよう、削除は保存に成功してから行う
+            let stale_linked = self
+                .find_other_linked_events(&external_id, domain_id)
+                .await?;
+
             // 既存イベント
             if external_id.calendar_id == new_calendar_id {
                 // 同じカレンダー → 更新
@@ -685,70 +1712,70 @@ impl ResourceUsageRepository for GoogleCalendarUsageRepository {
                         RepositoryError::ConnectionError(format!("イベント更新に失敗: {}", e))
                     })?;
             } else {
-                // カレンダーが変更された → 古いカレンダーから削除し、新しいカレンダーに作成
-                // 古いイベントを削除
-                self.hub
-                    .events()
-                    .delete(&external_id.calendar_id, &external_id.event_id)
-                    .doit()
+                // カレンダーが変更された → 新しいカレンダーに作成し、マッピング更新後に古いイベントを削除
+                // 途中で失敗した場合は、元のイベントとマッピングが残るように補償する
+                let event = self.create_event_from_usage(usage)?;
+                let new_event_id = self.insert_event(event, &new_calendar_id).await?;
+
+                let new_external_id = ExternalId {
+                    calendar_id: new_calendar_id,
+                    event_id: new_event_id,
+                };
+                if let Err(e) = self
+                    .id_mapper
+                    .save_mapping(domain_id, new_external_id.clone())
                     .await
-                    .map_err(|e| {
-                        RepositoryError::ConnectionError(format!("古いイベントの削除に失敗: {}", e))
-                    })?;
+                {
+                    self.compensate_created_event(&new_external_id).await;
+                    return Err(e);
+                }
 
-                // 新しいカレンダーにイベントを作成
-                let event = self.create_event_from_usage(usage)?;
-                let (_response, created_event) = self
+                if let Err(e) = self
                     .hub
                     .events()
-                    .insert(event, &new_calendar_id)
+                    .delete(&external_id.calendar_id, &external_id.event_id)
                     .doit()
                     .await
-                    .map_err(|e| {
-                        RepositoryError::ConnectionError(format!(
-                            "新しいカレンダーへのイベント作成に失敗: {}",
-                            e
-                        ))
-                    })?;
-
-                // 新しいEvent IDを取得してマッピングを更新
-                let new_event_id = created_event.id.ok_or_else(|| {
-                    RepositoryError::Unknown("作成されたイベントにIDがありません".to_string())
-                })?;
-
-                self.id_mapper.save_mapping(
-                    domain_id,
-                    ExternalId {
-                        calendar_id: new_calendar_id,
-                        event_id: new_event_id,
-                    },
-                )?;
+                {
+                    // 古いイベントが残っているので、マッピングを戻して新しいイベントを削除
+                    if let Err(restore_err) = self
+                        .id_mapper
+                        .save_mapping(domain_id, external_id.clone())
+                        .await
+                    {
+                        tracing::error!(
+                            "❌ マッピングの復元に失敗: domain_id={}, error={}",
+                            domain_id,
+                            restore_err
+                        );
+                    }
+                    self.compensate_created_event(&new_external_id).await;
+                    return Err(RepositoryError::ConnectionError(format!(
+                        "古いイベントの削除に失敗: {}",
+                        e
+                    )));
+                }
             }
+
+            delete_events(self, &stale_linked).await?;
         } else {
             // 新規 → 作成
             let event = self.create_event_from_usage(usage)?;
-            let (_response, created_event) = self
-                .hub
-                .events()
-                .insert(event, &new_calendar_id)
-                .doit()
+            let event_id = self.insert_event(event, &new_calendar_id).await?;
+
+            // マッピングを保存（失敗した場合は作成したイベントを削除）
+            let external_id = ExternalId {
+                calendar_id: new_calendar_id,
+                event_id,
+            };
+            if let Err(e) = self
+                .id_mapper
+                .save_mapping(domain_id, external_id.clone())
                 .await
-                .map_err(|e| {
-                    RepositoryError::ConnectionError(format!("イベント作成に失敗: {}", e))
-                })?;
-
-            // Event IDを取得してマッピングを保存
-            let event_id = created_event.id.ok_or_else(|| {
-                RepositoryError::Unknown("作成されたイベントにIDがありません".to_string())
-            })?;
-
-            self.id_mapper.save_mapping(
-                domain_id,
-                ExternalId {
-                    calendar_id: new_calendar_id.clone(),
-                    event_id: event_id.clone(),
-                },
-            )?;
+            {
+                self.compensate_created_event(&external_id).await;
+                return Err(e);
+            }
         }
 
         Ok(())
@@ -758,17 +1785,19 @@ impl ResourceUsageRepository for GoogleCalendarUsageRepository {
         let input_id = id.as_str();
 
         // まずdomain_idとして外部IDを取得を試みる
-        let (external_id, actual_domain_id) = match self.id_mapper.get_external_id(input_id)? {
+        let (external_id, actual_domain_id) = match self.id_mapper.get_external_id(input_id).await?
+        {
             Some(ext_id) => (ext_id, input_id.to_string()),
             None => {
                 // 見つからない場合、input_idがevent_idの可能性がある
                 // 逆引きマッピングを試みる
-                match self.id_mapper.get_domain_id(input_id)? {
+                match self.id_mapper.get_domain_id(input_id).await? {
                     Some(domain_id) => {
                         // domain_idが見つかったので、それで外部IDを取得
                         let ext_id = self
                             .id_mapper
-                            .get_external_id(&domain_id)?
+                            .get_external_id(&domain_id)
+                            .await?
                             .ok_or(RepositoryError::NotFound)?;
                         (ext_id, domain_id)
                     }
@@ -782,6 +1811,10 @@ impl ResourceUsageRepository for GoogleCalendarUsageRepository {
             }
         };
 
+        // 複数のカレンダーに分割して登録したイベントを先に削除（代表イベントの拡張プロパティで判定するため）
+        self.delete_linked_events(&external_id, &actual_domain_id)
+            .await?;
+
         // イベントを削除
         self.hub
             .events()
@@ -791,8 +1824,549 @@ impl ResourceUsageRepository for GoogleCalendarUsageRepository {
             .map_err(|e| RepositoryError::ConnectionError(format!("イベント削除に失敗: {}", e)))?;
 
         // マッピングを削除
-        self.id_mapper.delete_mapping(&actual_domain_id)?;
+        self.id_mapper.delete_mapping(&actual_domain_id).await?;
 
         Ok(())
     }
 }
+
+#[async_trait]
+impl MappingReconciler for GoogleCalendarUsageRepository {
+    async fn find_discrepancies(
+        &self,
+        period: &TimePeriod,
+    ) -> Result<Vec<MappingDiscrepancy>, RepositoryError> {
+        let mut discrepancies = Vec::new();
+
+        // 対応先のイベントが削除された対応
+        let mappings = self.id_mapper.all_mappings().await?;
+        let mut mapped_usage_ids = HashSet::new();
+        for (domain_id, external_id) in mappings {
+            let event = self
+                .fetch_event_from_calendar(&external_id.calendar_id, &external_id.event_id)
+                .await?;
+            let exists =
+                event.is_some_and(|event| event.status.as_deref() != Some(EVENT_STATUS_CANCELLED));
+            if exists {
+                mapped_usage_ids.insert(domain_id);
+            } else {
+                discrepancies.push(MappingDiscrepancy {
+                    kind: DiscrepancyKind::StaleMapping,
+                    usage_id: UsageId::from_string(domain_id),
+                    calendar_id: external_id.calendar_id,
+                    event_id: external_id.event_id,
+                });
+            }
+        }
+
+        // 対応の記録がないイベント
+        // 分割して登録したイベントは、同じDomain IDのうち最初のイベントだけを対応させる
+        // （対応先が削除された予約も、残っているイベントに対応させ直す）
+        let events = self.fetch_events(period.start(), period.end()).await?;
+        for (event, calendar_id, _) in events {
+            let Some(event_id) = event.id.clone() else {
+                continue;
+            };
+            let usage_id = match linked_usage_id(&event) {
+                Some(linked_domain_id) => linked_domain_id,
+                None => {
+                    if self.id_mapper.get_domain_id(&event_id).await?.is_some() {
+                        continue;
+                    }
+                    occurrence_usage_id(&event)
+                        .unwrap_or_default()
+                        .as_str()
+                        .to_string()
+                }
+            };
+            if !mapped_usage_ids.insert(usage_id.clone()) {
+                continue;
+            }
+            discrepancies.push(MappingDiscrepancy {
+                kind: DiscrepancyKind::UnmappedEvent,
+                usage_id: UsageId::from_string(usage_id),
+                calendar_id,
+                event_id,
+            });
+        }
+
+        Ok(discrepancies)
+    }
+
+    async fn resolve(&self, discrepancy: &MappingDiscrepancy) -> Result<(), RepositoryError> {
+        match discrepancy.kind {
+            DiscrepancyKind::StaleMapping => {
+                self.id_mapper
+                    .delete_mapping(discrepancy.usage_id.as_str())
+                    .await
+            }
+            DiscrepancyKind::UnmappedEvent => {
+                self.id_mapper
+                    .save_mapping(
+                        discrepancy.usage_id.as_str(),
+                        ExternalId {
+                            calendar_id: discrepancy.calendar_id.clone(),
+                            event_id: discrepancy.event_id.clone(),
+                        },
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::RecurrenceFrequency;
+    use std::sync::Mutex;
+
+    /// 書き込みを記録し、指定した操作を失敗させるEventWriter
+    #[derive(Default)]
+    struct RecordingWriter {
+        /// 存在するイベント（calendar_id, event_id）
+        events: Mutex<Vec<(String, String)>>,
+        fail_update: bool,
+        fail_delete_of: Option<String>,
+    }
+
+    #[async_trait]
+    impl EventWriter for RecordingWriter {
+        async fn insert_event(
+            &self,
+            _event: Event,
+            calendar_id: &str,
+        ) -> Result<String, RepositoryError> {
+            let mut events = self.events.lock().unwrap();
+            let event_id = format!("new{}", events.len());
+            events.push((calendar_id.to_string(), event_id.clone()));
+            Ok(event_id)
+        }
+
+        async fn update_event(
+            &self,
+            _event: Event,
+            _calendar_id: &str,
+            _event_id: &str,
+        ) -> Result<(), RepositoryError> {
+            if self.fail_update {
+                return Err(RepositoryError::ConnectionError("update".to_string()));
+            }
+            Ok(())
+        }
+
+        async fn delete_event(
+            &self,
+            calendar_id: &str,
+            event_id: &str,
+        ) -> Result<(), RepositoryError> {
+            if self.fail_delete_of.as_deref() == Some(event_id) {
+                return Err(RepositoryError::ConnectionError("delete".to_string()));
+            }
+            self.events
+                .lock()
+                .unwrap()
+                .retain(|(c, e)| !(c == calendar_id && e == event_id));
+            Ok(())
+        }
+    }
+
+    fn linked_events(calendar_ids: &[&str]) -> Vec<(String, Event)> {
+        calendar_ids
+            .iter()
+            .map(|id| (id.to_string(), Event::default()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_write_linked_events_removes_created_events_when_update_fails() {
+        let writer = RecordingWriter {
+            events: Mutex::new(vec![("b".to_string(), "old-b".to_string())]),
+            fail_update: true,
+            ..Default::default()
+        };
+        let existing = HashMap::from([("b".to_string(), "old-b".to_string())]);
+
+        // aには新たに作成し、bの既存のイベントの更新に失敗する
+        let result = write_linked_events(&writer, linked_events(&["a", "b"]), existing).await;
+
+        assert!(matches!(result, Err(RepositoryError::ConnectionError(_))));
+        assert_eq!(
+            *writer.events.lock().unwrap(),
+            vec![("b".to_string(), "old-b".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_linked_events_keeps_stale_events_when_update_fails() {
+        let writer = RecordingWriter {
+            events: Mutex::new(vec![
+                ("b".to_string(), "old-b".to_string()),
+                ("c".to_string(), "old-c".to_string()),
+            ]),
+            fail_update: true,
+            ..Default::default()
+        };
+        let existing = HashMap::from([
+            ("b".to_string(), "old-b".to_string()),
+            ("c".to_string(), "old-c".to_string()),
+        ]);
+
+        // 予約から外れたcのイベントは、書き込みに失敗しても残る
+        let result = write_linked_events(&writer, linked_events(&["a", "b"]), existing).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            *writer.events.lock().unwrap(),
+            vec![
+                ("b".to_string(), "old-b".to_string()),
+                ("c".to_string(), "old-c".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_events_reports_failure() {
+        let writer = RecordingWriter {
+            events: Mutex::new(vec![("c".to_string(), "old-c".to_string())]),
+            fail_delete_of: Some("old-c".to_string()),
+            ..Default::default()
+        };
+        let stale = vec![ExternalId {
+            calendar_id: "c".to_string(),
+            event_id: "old-c".to_string(),
+        }];
+
+        assert!(delete_events(&writer, &stale).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_write_linked_events_returns_first_event_as_primary() {
+        let writer = RecordingWriter {
+            events: Mutex::new(vec![
+                ("b".to_string(), "old-b".to_string()),
+                ("c".to_string(), "old-c".to_string()),
+            ]),
+            ..Default::default()
+        };
+        let existing = HashMap::from([
+            ("b".to_string(), "old-b".to_string()),
+            ("c".to_string(), "old-c".to_string()),
+        ]);
+
+        let written = write_linked_events(&writer, linked_events(&["b", "a"]), existing)
+            .await
+            .unwrap();
+
+        assert_eq!(written.primary.calendar_id, "b");
+        assert_eq!(written.primary.event_id, "old-b");
+        assert_eq!(written.created.len(), 1);
+        assert_eq!(written.created[0].calendar_id, "a");
+        // 予約から外れたcのイベントは、呼び出し側が保存に成功してから削除する
+        assert_eq!(written.stale.len(), 1);
+        assert_eq!(written.stale[0].event_id, "old-c");
+        assert!(
+            writer
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|(calendar_id, _)| calendar_id == "c")
+        );
+
+        delete_events(&writer, &written.stale).await.unwrap();
+        let events = writer.events.lock().unwrap();
+        assert!(!events.iter().any(|(calendar_id, _)| calendar_id == "c"));
+    }
+
+    fn instance(series_id: &str, original_start: EventDateTime) -> Event {
+        Event {
+            id: Some(format!("{}_instance", series_id)),
+            recurring_event_id: Some(series_id.to_string()),
+            original_start_time: Some(original_start),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_occurrence_usage_id_is_stable_per_occurrence() {
+        let first = EventDateTime {
+            date_time: Some(Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap()),
+            ..Default::default()
+        };
+        let second = EventDateTime {
+            date_time: Some(Utc.with_ymd_and_hms(2024, 1, 22, 10, 0, 0).unwrap()),
+            ..Default::default()
+        };
+
+        let id1 = occurrence_usage_id(&instance("series", first.clone())).unwrap();
+        let id1_again = occurrence_usage_id(&instance("series", first)).unwrap();
+        let id2 = occurrence_usage_id(&instance("series", second)).unwrap();
+
+        assert_eq!(id1.as_str(), "series_20240115T100000Z");
+        assert_eq!(id1, id1_again);
+        assert_ne!(id1, id2);
+    }
+
+    #[test]
+    fn test_occurrence_usage_id_for_all_day_occurrence() {
+        let original_start = EventDateTime {
+            date: NaiveDate::from_ymd_opt(2024, 1, 15),
+            ..Default::default()
+        };
+
+        let id = occurrence_usage_id(&instance("series", original_start)).unwrap();
+
+        assert_eq!(id.as_str(), "series_20240115");
+    }
+
+    #[test]
+    fn test_resolve_event_time_all_day_in_configured_timezone() {
+        let start = EventDateTime {
+            date: NaiveDate::from_ymd_opt(2024, 1, 15),
+            ..Default::default()
+        };
+        let end = EventDateTime {
+            date: NaiveDate::from_ymd_opt(2024, 1, 16),
+            ..Default::default()
+        };
+
+        let start = resolve_event_time(&start, Some(Tz::Asia__Tokyo)).unwrap();
+        let end = resolve_event_time(&end, Some(Tz::Asia__Tokyo)).unwrap();
+
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 1, 14, 15, 0, 0).unwrap());
+        assert_eq!(end - start, Duration::days(1));
+    }
+
+    #[test]
+    fn test_resolve_event_time_prefers_date_time() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let event_time = EventDateTime {
+            date_time: Some(date_time),
+            date: NaiveDate::from_ymd_opt(2024, 1, 20),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            resolve_event_time(&event_time, Some(Tz::Asia__Tokyo)),
+            Some(date_time)
+        );
+    }
+
+    #[test]
+    fn test_linked_usage_id_reads_private_property() {
+        let event = Event {
+            extended_properties: Some(EventExtendedProperties {
+                private: Some(HashMap::from([(
+                    USAGE_ID_PROPERTY.to_string(),
+                    "usage-1".to_string(),
+                )])),
+                shared: None,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(linked_usage_id(&event), Some("usage-1".to_string()));
+        assert_eq!(linked_usage_id(&Event::default()), None);
+    }
+
+    #[test]
+    fn test_priority_round_trips_through_private_property() {
+        let usage = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            None,
+        )
+        .unwrap();
+        assert!(event_properties(&usage).is_none());
+
+        let event = Event {
+            extended_properties: event_properties(&usage.with_priority(Priority::Deadline)),
+            ..Default::default()
+        };
+
+        assert_eq!(linked_priority(&event), Priority::Deadline);
+        assert_eq!(linked_priority(&Event::default()), Priority::Normal);
+    }
+
+    #[test]
+    fn test_group_round_trips_through_private_property() {
+        let usage = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            None,
+        )
+        .unwrap()
+        .with_group(Some(GroupId::new("nlp".to_string())));
+
+        let event = Event {
+            extended_properties: event_properties(&usage),
+            ..Default::default()
+        };
+
+        assert_eq!(linked_group(&event).as_ref(), usage.group());
+        assert_eq!(linked_group(&Event::default()), None);
+    }
+
+    #[test]
+    fn test_metadata_round_trips_through_description() {
+        let metadata = ReservationMetadata::new(
+            Some("LLM事前学習".to_string()),
+            Some("exp-042".to_string()),
+            Some(80),
+        )
+        .unwrap();
+        let usage = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            Some("学習の続き\n\n2行目".to_string()),
+        )
+        .unwrap()
+        .with_metadata(metadata.clone());
+
+        let description = format_description(&usage);
+        assert_eq!(
+            description,
+            "予約者: user@example.com\nプロジェクト: LLM事前学習\n実験ID: exp-042\n想定使用率: 80%\n\n学習の続き\n\n2行目"
+        );
+        assert_eq!(
+            parse_description(&description),
+            (usage.notes().cloned(), metadata)
+        );
+    }
+
+    #[test]
+    fn test_parse_description_without_metadata() {
+        assert_eq!(
+            parse_description("予約者: user@example.com\n\nメモ"),
+            (Some("メモ".to_string()), ReservationMetadata::default())
+        );
+        assert_eq!(
+            parse_description("予約者: user@example.com\n想定使用率: 250%"),
+            (None, ReservationMetadata::default())
+        );
+    }
+
+    #[test]
+    fn test_recurrence_round_trips_through_private_property() {
+        let recurrence = RecurrenceRule::count(RecurrenceFrequency::Biweekly, 6);
+        let usage = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            None,
+        )
+        .unwrap()
+        .with_series_id(Some(SeriesId::new()))
+        .with_recurrence(Some(recurrence.clone()));
+
+        let event = Event {
+            extended_properties: event_properties(&usage),
+            ..Default::default()
+        };
+
+        assert_eq!(linked_recurrence(&event), Some(recurrence));
+        assert_eq!(linked_series_id(&event).as_ref(), usage.series_id());
+        assert_eq!(linked_recurrence(&Event::default()), None);
+    }
+
+    #[test]
+    fn test_merge_linked_usages_combines_resources() {
+        let gpu =
+            |device| Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()));
+        let owner = EmailAddress::new("user@example.com".to_string()).unwrap();
+        let period = TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let usage = |id: &str, resources| {
+            ResourceUsage::reconstruct(
+                UsageId::from_string(id.to_string()),
+                owner.clone(),
+                period.clone(),
+                resources,
+                None,
+            )
+            .unwrap()
+        };
+
+        let merged = merge_linked_usages(vec![
+            usage("linked", vec![gpu(0)]),
+            usage("other", vec![gpu(2)]),
+            usage("linked", vec![gpu(1), gpu(0)]),
+        ])
+        .unwrap();
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].id().as_str(), "linked");
+        assert_eq!(merged[0].resources(), &[gpu(0), gpu(1)]);
+        assert_eq!(merged[1].id().as_str(), "other");
+    }
+
+    #[test]
+    fn test_occurrence_usage_id_none_for_single_event() {
+        let event = Event {
+            id: Some("single".to_string()),
+            ..Default::default()
+        };
+
+        assert!(occurrence_usage_id(&event).is_none());
+    }
+
+    #[test]
+    fn test_attendee_owner_skips_organizer_resources_and_bot() {
+        let attendee = |email: &str| EventAttendee {
+            email: Some(email.to_string()),
+            ..Default::default()
+        };
+        let event = Event {
+            attendees: Some(vec![
+                EventAttendee {
+                    organizer: Some(true),
+                    ..attendee("admin@example.com")
+                },
+                EventAttendee {
+                    resource: Some(true),
+                    ..attendee("room@resource.calendar.google.com")
+                },
+                attendee("bot@example.com"),
+                attendee("user@example.com"),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            attendee_owner(&event, "bot@example.com"),
+            Some("user@example.com")
+        );
+        assert_eq!(attendee_owner(&Event::default(), "bot@example.com"), None);
+    }
+}
@@ -6,13 +6,20 @@ use crate::domain::{
     ports::repositories::{RepositoryError, ResourceUsageRepository},
 };
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
 /// テスト用のインメモリResourceUsageリポジトリ実装
 #[derive(Clone)]
 pub struct MockUsageRepository {
     storage: Arc<Mutex<HashMap<String, ResourceUsage>>>,
+    /// 保存を失敗させるかどうか
+    failing_saves: Arc<AtomicBool>,
+    /// 検索を失敗させるかどうか
+    failing_finds: Arc<AtomicBool>,
+    /// 削除を失敗させるID
+    failing_deletes: Arc<Mutex<HashSet<String>>>,
 }
 
 impl Default for MockUsageRepository {
@@ -26,8 +33,39 @@ impl MockUsageRepository {
     pub fn new() -> Self {
         Self {
             storage: Arc::new(Mutex::new(HashMap::new())),
+            failing_saves: Arc::new(AtomicBool::new(false)),
+            failing_finds: Arc::new(AtomicBool::new(false)),
+            failing_deletes: Arc::new(Mutex::new(HashSet::new())),
         }
     }
+
+    /// 以降の保存を失敗させる（`false` で元に戻す）
+    pub fn fail_saves(&self, fail: bool) {
+        self.failing_saves.store(fail, Ordering::SeqCst);
+    }
+
+    /// 以降の予約の一覧の取得を失敗させる（`false` で元に戻す）
+    pub fn fail_finds(&self, fail: bool) {
+        self.failing_finds.store(fail, Ordering::SeqCst);
+    }
+
+    /// 検索を失敗させる設定の場合はエラーを返す
+    fn check_find(&self) -> Result<(), RepositoryError> {
+        if self.failing_finds.load(Ordering::SeqCst) {
+            return Err(RepositoryError::ConnectionError(
+                "検索の失敗（テスト用）".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 指定したIDの予約の削除を失敗させる
+    pub fn fail_delete_of(&self, id: &UsageId) {
+        self.failing_deletes
+            .lock()
+            .unwrap()
+            .insert(id.as_str().to_string());
+    }
 }
 
 #[async_trait]
@@ -38,14 +76,22 @@ impl ResourceUsageRepository for MockUsageRepository {
     }
 
     async fn find_future(&self) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        self.check_find()?;
         let storage = self.storage.lock().unwrap();
-        Ok(storage.values().cloned().collect())
+        // 実装と同じく、終了した予約は含めない
+        let now = chrono::Utc::now();
+        Ok(storage
+            .values()
+            .filter(|usage| usage.time_period().end() > now)
+            .cloned()
+            .collect())
     }
 
     async fn find_overlapping(
         &self,
         time_period: &TimePeriod,
     ) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        self.check_find()?;
         let storage = self.storage.lock().unwrap();
         let overlapping: Vec<ResourceUsage> = storage
             .values()
@@ -59,6 +105,7 @@ impl ResourceUsageRepository for MockUsageRepository {
         &self,
         owner_email: &crate::domain::common::EmailAddress,
     ) -> Result<Vec<ResourceUsage>, RepositoryError> {
+        self.check_find()?;
         let storage = self.storage.lock().unwrap();
         let owned: Vec<ResourceUsage> = storage
             .values()
@@ -69,12 +116,22 @@ impl ResourceUsageRepository for MockUsageRepository {
     }
 
     async fn save(&self, usage: &ResourceUsage) -> Result<(), RepositoryError> {
+        if self.failing_saves.load(Ordering::SeqCst) {
+            return Err(RepositoryError::ConnectionError(
+                "保存の失敗（テスト用）".to_string(),
+            ));
+        }
         let mut storage = self.storage.lock().unwrap();
         storage.insert(usage.id().as_str().to_string(), usage.clone());
         Ok(())
     }
 
     async fn delete(&self, id: &UsageId) -> Result<(), RepositoryError> {
+        if self.failing_deletes.lock().unwrap().contains(id.as_str()) {
+            return Err(RepositoryError::ConnectionError(
+                "削除の失敗（テスト用）".to_string(),
+            ));
+        }
         let mut storage = self.storage.lock().unwrap();
         storage
             .remove(id.as_str())
@@ -0,0 +1,209 @@
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::ports::notifier::ReminderKind;
+use crate::domain::ports::repositories::{RepositoryError, SentReminderRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+/// JSON file storage for sent reminders
+///
+/// ファイルフォーマット（種類ごとに、予約ID → リマインダー送信時の基準時刻）:
+/// ```json
+/// {
+///   "start": {
+///     "c6b1f3e2-...": "2024-03-01T10:00:00Z"
+///   },
+///   "end": {
+///     "c6b1f3e2-...": "2024-03-01T12:00:00Z"
+///   }
+/// }
+/// ```
+pub struct JsonFileSentReminderRepository {
+    file_path: PathBuf,
+    /// 送信済みの記録（未読み込みの場合は `None`）
+    cache: RwLock<Option<SentRemindersDto>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SentRemindersDto {
+    #[serde(default)]
+    start: BTreeMap<String, DateTime<Utc>>,
+    #[serde(default)]
+    end: BTreeMap<String, DateTime<Utc>>,
+}
+
+impl SentRemindersDto {
+    fn of_kind(&self, kind: ReminderKind) -> &BTreeMap<String, DateTime<Utc>> {
+        match kind {
+            ReminderKind::Start => &self.start,
+            ReminderKind::End => &self.end,
+        }
+    }
+
+    fn of_kind_mut(&mut self, kind: ReminderKind) -> &mut BTreeMap<String, DateTime<Utc>> {
+        match kind {
+            ReminderKind::Start => &mut self.start,
+            ReminderKind::End => &mut self.end,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.start.len() + self.end.len()
+    }
+}
+
+impl JsonFileSentReminderRepository {
+    /// 新しいJSONファイルベースのリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `file_path` - JSONファイルのパス
+    pub fn new(file_path: PathBuf) -> Self {
+        Self {
+            file_path,
+            cache: RwLock::new(None),
+        }
+    }
+
+    async fn load(&self) -> Result<SentRemindersDto, RepositoryError> {
+        let content = match tokio::fs::read_to_string(&self.file_path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // ファイルが存在しない場合は送信済みなしとして扱う
+                return Ok(SentRemindersDto::default());
+            }
+            Err(e) => {
+                return Err(RepositoryError::Unknown(format!(
+                    "ファイルの読み込みに失敗: {}",
+                    e
+                )));
+            }
+        };
+
+        serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))
+    }
+
+    /// キャッシュが未読み込みの場合、ファイルから読み込む
+    async fn ensure_loaded(&self) -> Result<(), RepositoryError> {
+        if self.cache.read().await.is_some() {
+            return Ok(());
+        }
+        let data = self.load().await?;
+        self.cache.write().await.get_or_insert(data);
+        Ok(())
+    }
+
+    async fn save_to_file(&self, data: &SentRemindersDto) -> Result<(), RepositoryError> {
+        let content = serde_json::to_string_pretty(data)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
+
+        // 親ディレクトリが存在しない場合は作成
+        if let Some(parent) = self.file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::Unknown(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        tokio::fs::write(&self.file_path, content)
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))
+    }
+}
+
+#[async_trait]
+impl SentReminderRepository for JsonFileSentReminderRepository {
+    async fn find_all(
+        &self,
+        kind: ReminderKind,
+    ) -> Result<HashMap<UsageId, DateTime<Utc>>, RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let cache = self.cache.read().await;
+        Ok(cache
+            .iter()
+            .flat_map(|data| data.of_kind(kind))
+            .map(|(usage_id, at)| (UsageId::from_string(usage_id.clone()), *at))
+            .collect())
+    }
+
+    async fn save(
+        &self,
+        kind: ReminderKind,
+        usage_id: &UsageId,
+        at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(SentRemindersDto::default);
+        data.of_kind_mut(kind)
+            .insert(usage_id.as_str().to_string(), at);
+        self.save_to_file(data).await
+    }
+
+    async fn retain(&self, usage_ids: &HashSet<UsageId>) -> Result<(), RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(SentRemindersDto::default);
+        let before = data.len();
+        for kind in [ReminderKind::Start, ReminderKind::End] {
+            data.of_kind_mut(kind)
+                .retain(|usage_id, _| usage_ids.contains(&UsageId::from_string(usage_id.clone())));
+        }
+        if data.len() == before {
+            return Ok(());
+        }
+        self.save_to_file(data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_find_and_retain() {
+        let file_path = std::env::temp_dir()
+            .join(format!("lrm_sent_reminder_{}", uuid::Uuid::new_v4()))
+            .join("sent_reminders.json");
+        let repo = JsonFileSentReminderRepository::new(file_path.clone());
+        assert!(repo.find_all(ReminderKind::Start).await.unwrap().is_empty());
+
+        let kept = UsageId::from_string("kept".to_string());
+        let dropped = UsageId::from_string("dropped".to_string());
+        let start = Utc::now();
+        repo.save(ReminderKind::Start, &kept, start).await.unwrap();
+        repo.save(ReminderKind::Start, &dropped, start)
+            .await
+            .unwrap();
+        repo.save(ReminderKind::End, &dropped, start).await.unwrap();
+
+        // 別のインスタンスからも読み込める
+        let reopened = JsonFileSentReminderRepository::new(file_path);
+        assert_eq!(
+            reopened.find_all(ReminderKind::Start).await.unwrap().len(),
+            2
+        );
+        assert_eq!(reopened.find_all(ReminderKind::End).await.unwrap().len(), 1);
+
+        reopened
+            .retain(&HashSet::from([kept.clone()]))
+            .await
+            .unwrap();
+        assert_eq!(
+            reopened.find_all(ReminderKind::Start).await.unwrap(),
+            HashMap::from([(kept, start)])
+        );
+        assert!(
+            reopened
+                .find_all(ReminderKind::End)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! # SentReminder Repository Implementations
+//!
+//! SentReminderRepositoryポートの具象実装を提供します。
+//!
+//! - `json_file`: JSONファイルを使用した永続化実装
+
+/// JSONファイルベースのSentReminderリポジトリ実装
+pub mod json_file;
+
+pub use json_file::JsonFileSentReminderRepository;
@@ -0,0 +1,383 @@
+use crate::domain::aggregates::group::GroupId;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    ApprovalStatus, Gpu, RecurrenceRule, ReservationMetadata, Resource, SeriesId, TimePeriod,
+    UsageId, Visibility,
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{
+    RepositoryError, UsageSnapshotEntry, UsageSnapshotRepository,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use tracing::warn;
+
+/// ファイル形式のバージョン
+///
+/// 以前の形式（予約ID → フィンガープリントのみ）のファイルは、記録がないものとして扱う。
+const FORMAT_VERSION: u32 = 2;
+
+/// JSON file storage for the last seen reservations
+///
+/// ファイルフォーマット（予約ID → 予約内容とそのフィンガープリント）:
+/// ```json
+/// {
+///   "version": 2,
+///   "usages": {
+///     "c6b1f3e2-...": {
+///       "fingerprint": "v1:9f86d081884c7d65...",
+///       "owner_email": "user@example.com",
+///       "start": "2024-01-01T09:00:00Z",
+///       "end": "2024-01-01T12:00:00Z",
+///       "resources": [{ "type": "gpu", "server": "Thalys", "device_number": 0, "model": "A100" }],
+///       "priority": "normal"
+///     }
+///   }
+/// }
+/// ```
+pub struct JsonFileUsageSnapshotRepository {
+    file_path: PathBuf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotFileDto {
+    version: u32,
+    usages: BTreeMap<String, UsageDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UsageDto {
+    fingerprint: String,
+    owner_email: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    resources: Vec<ResourceDto>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    experiment_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expected_utilization: Option<u8>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    private: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pending_approval: bool,
+    priority: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    series_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    recurrence: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ResourceDto {
+    Gpu {
+        server: String,
+        device_number: u32,
+        model: String,
+    },
+    Room {
+        name: String,
+    },
+    Instrument {
+        name: String,
+    },
+    Custom {
+        kind: String,
+        name: String,
+    },
+    Storage {
+        volume: String,
+        gigabytes: u64,
+    },
+    License {
+        name: String,
+        seats: u32,
+    },
+}
+
+impl ResourceDto {
+    fn from_entity(resource: &Resource) -> Self {
+        match resource {
+            Resource::Gpu(gpu) => Self::Gpu {
+                server: gpu.server().to_string(),
+                device_number: gpu.device_number(),
+                model: gpu.model().to_string(),
+            },
+            Resource::Room { name } => Self::Room { name: name.clone() },
+            Resource::Instrument { name } => Self::Instrument { name: name.clone() },
+            Resource::Custom { kind, name } => Self::Custom {
+                kind: kind.clone(),
+                name: name.clone(),
+            },
+            Resource::Storage { volume, gigabytes } => Self::Storage {
+                volume: volume.clone(),
+                gigabytes: *gigabytes,
+            },
+            Resource::License { name, seats } => Self::License {
+                name: name.clone(),
+                seats: *seats,
+            },
+        }
+    }
+
+    fn into_entity(self) -> Resource {
+        match self {
+            Self::Gpu {
+                server,
+                device_number,
+                model,
+            } => Resource::Gpu(Gpu::new(server, device_number, model)),
+            Self::Room { name } => Resource::Room { name },
+            Self::Instrument { name } => Resource::Instrument { name },
+            Self::Custom { kind, name } => Resource::Custom { kind, name },
+            Self::Storage { volume, gigabytes } => Resource::Storage { volume, gigabytes },
+            Self::License { name, seats } => Resource::License { name, seats },
+        }
+    }
+}
+
+impl UsageDto {
+    fn from_entry(entry: &UsageSnapshotEntry) -> Self {
+        let usage = &entry.usage;
+        let metadata = usage.metadata();
+        Self {
+            fingerprint: entry.fingerprint.clone(),
+            owner_email: usage.owner_email().as_str().to_string(),
+            start: usage.time_period().start(),
+            end: usage.time_period().end(),
+            resources: usage
+                .resources()
+                .iter()
+                .map(ResourceDto::from_entity)
+                .collect(),
+            notes: usage.notes().cloned(),
+            project: metadata.project().map(str::to_string),
+            experiment_id: metadata.experiment_id().map(str::to_string),
+            expected_utilization: metadata.expected_utilization(),
+            private: usage.visibility().is_private(),
+            pending_approval: usage.approval_status().is_pending(),
+            priority: usage.priority().as_str().to_string(),
+            series_id: usage.series_id().map(|id| id.as_str().to_string()),
+            recurrence: usage.recurrence().map(RecurrenceRule::to_rrule),
+            group: usage.group().map(|group| group.as_str().to_string()),
+        }
+    }
+
+    fn into_entry(self, id: UsageId) -> Result<UsageSnapshotEntry, RepositoryError> {
+        let usage = ResourceUsage::reconstruct(
+            id,
+            EmailAddress::new(self.owner_email)?,
+            TimePeriod::new(self.start, self.end)?,
+            self.resources
+                .into_iter()
+                .map(ResourceDto::into_entity)
+                .collect(),
+            self.notes,
+        )?
+        .with_metadata(ReservationMetadata::new(
+            self.project,
+            self.experiment_id,
+            self.expected_utilization,
+        )?)
+        .with_visibility(if self.private {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        })
+        .with_approval_status(if self.pending_approval {
+            ApprovalStatus::Pending
+        } else {
+            ApprovalStatus::Approved
+        })
+        .with_priority(self.priority.parse().unwrap_or_default())
+        .with_series_id(self.series_id.map(SeriesId::from_string))
+        .with_recurrence(
+            self.recurrence
+                .as_deref()
+                .and_then(RecurrenceRule::from_rrule),
+        )
+        .with_group(self.group.map(GroupId::new));
+
+        Ok(UsageSnapshotEntry {
+            fingerprint: self.fingerprint,
+            usage,
+        })
+    }
+}
+
+impl JsonFileUsageSnapshotRepository {
+    /// 新しいJSONファイルベースのリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `file_path` - JSONファイルのパス
+    pub fn new(file_path: PathBuf) -> Self {
+        Self { file_path }
+    }
+}
+
+#[async_trait]
+impl UsageSnapshotRepository for JsonFileUsageSnapshotRepository {
+    async fn load(&self) -> Result<Option<HashMap<UsageId, UsageSnapshotEntry>>, RepositoryError> {
+        let content = match tokio::fs::read_to_string(&self.file_path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // ファイルが存在しない場合はまだ記録がないものとして扱う
+                return Ok(None);
+            }
+            Err(e) => {
+                return Err(RepositoryError::Unknown(format!(
+                    "ファイルの読み込みに失敗: {}",
+                    e
+                )));
+            }
+        };
+
+        let value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))?;
+        if value.get("version").and_then(serde_json::Value::as_u64) != Some(FORMAT_VERSION.into()) {
+            warn!(
+                "⚠️  {} は以前の形式のため、記録がないものとして扱います",
+                self.file_path.display()
+            );
+            return Ok(None);
+        }
+        let data: SnapshotFileDto = serde_json::from_value(value)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))?;
+
+        let mut snapshot = HashMap::with_capacity(data.usages.len());
+        for (usage_id, dto) in data.usages {
+            let id = UsageId::from_string(usage_id);
+            match dto.into_entry(id.clone()) {
+                Ok(entry) => {
+                    snapshot.insert(id, entry);
+                }
+                Err(e) => warn!(
+                    "⚠️  記録した予約 {} を読み込めないため、無視します: {}",
+                    id.as_str(),
+                    e
+                ),
+            }
+        }
+        Ok(Some(snapshot))
+    }
+
+    async fn save(
+        &self,
+        snapshot: &HashMap<UsageId, UsageSnapshotEntry>,
+    ) -> Result<(), RepositoryError> {
+        let data = SnapshotFileDto {
+            version: FORMAT_VERSION,
+            usages: snapshot
+                .iter()
+                .map(|(usage_id, entry)| {
+                    (usage_id.as_str().to_string(), UsageDto::from_entry(entry))
+                })
+                .collect(),
+        };
+        let content = serde_json::to_string_pretty(&data)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
+
+        // 親ディレクトリが存在しない場合は作成
+        if let Some(parent) = self.file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::Unknown(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        // 書き込み途中で停止しても壊れたファイルが残らないよう、一時ファイルから置き換える
+        let temp_path = self.file_path.with_extension("json.tmp");
+        tokio::fs::write(&temp_path, content)
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))?;
+        tokio::fs::rename(&temp_path, &self.file_path)
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの置き換えに失敗: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Priority, RecurrenceFrequency};
+    use chrono::TimeZone;
+
+    fn temp_file_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("lrm_usage_snapshot_{}", uuid::Uuid::new_v4()))
+            .join("usage_snapshot.json")
+    }
+
+    fn entry(id: &str, fingerprint: &str) -> (UsageId, UsageSnapshotEntry) {
+        let start = Utc.with_ymd_and_hms(2025, 4, 1, 9, 0, 0).unwrap();
+        let usage = ResourceUsage::reconstruct(
+            UsageId::from_string(id.to_string()),
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, start + chrono::Duration::hours(3)).unwrap(),
+            vec![
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string())),
+                Resource::Storage {
+                    volume: "scratch".to_string(),
+                    gigabytes: 500,
+                },
+            ],
+            Some("学習".to_string()),
+        )
+        .unwrap();
+        (
+            usage.id().clone(),
+            UsageSnapshotEntry {
+                fingerprint: fingerprint.to_string(),
+                usage,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let file_path = temp_file_path();
+        let repo = JsonFileUsageSnapshotRepository::new(file_path.clone());
+        assert_eq!(repo.load().await.unwrap(), None);
+
+        let (id_b, mut entry_b) = entry("b", "hash-b");
+        entry_b.usage = entry_b
+            .usage
+            .with_visibility(Visibility::Private)
+            .with_approval_status(ApprovalStatus::Pending)
+            .with_priority(Priority::Deadline)
+            .with_series_id(Some(SeriesId::from_string("series".to_string())))
+            .with_recurrence(Some(RecurrenceRule::count(RecurrenceFrequency::Weekly, 4)))
+            .with_group(Some(GroupId::new("nlp".to_string())));
+        let snapshot = HashMap::from([entry("a", "hash-a"), (id_b, entry_b)]);
+        repo.save(&snapshot).await.unwrap();
+
+        // 別のインスタンスからも読み込める
+        let reopened = JsonFileUsageSnapshotRepository::new(file_path);
+        assert_eq!(reopened.load().await.unwrap(), Some(snapshot));
+
+        reopened.save(&HashMap::new()).await.unwrap();
+        assert_eq!(reopened.load().await.unwrap(), Some(HashMap::new()));
+    }
+
+    #[tokio::test]
+    async fn test_previous_format_is_treated_as_missing() {
+        let file_path = temp_file_path();
+        tokio::fs::create_dir_all(file_path.parent().unwrap())
+            .await
+            .unwrap();
+        tokio::fs::write(&file_path, r#"{ "c6b1f3e2": "9f86d081" }"#)
+            .await
+            .unwrap();
+
+        let repo = JsonFileUsageSnapshotRepository::new(file_path);
+        assert_eq!(repo.load().await.unwrap(), None);
+    }
+}
@@ -0,0 +1,10 @@
+//! # UsageSnapshot Repository Implementations
+//!
+//! UsageSnapshotRepositoryポートの具象実装を提供します。
+//!
+//! - `json_file`: JSONファイルを使用した永続化実装
+
+/// JSONファイルベースのUsageSnapshotリポジトリ実装
+pub mod json_file;
+
+pub use json_file::JsonFileUsageSnapshotRepository;
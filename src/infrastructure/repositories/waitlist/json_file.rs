@@ -0,0 +1,277 @@
+use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource, TimePeriod};
+use crate::domain::aggregates::waitlist::WaitlistEntry;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::{RepositoryError, WaitlistRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+/// JSON file storage for Waitlist
+///
+/// ファイルフォーマット:
+/// ```json
+/// {
+///   "3f2b6c1e-...": {
+///     "owner_email": "user@example.com",
+///     "start": "2024-03-01T10:00:00Z",
+///     "end": "2024-03-01T18:00:00Z",
+///     "resources": [
+///       { "type": "gpu", "server": "Thalys", "device": 0, "model": "A100" }
+///     ],
+///     "joined_at": "2024-02-28T09:00:00Z"
+///   }
+/// }
+/// ```
+pub struct JsonFileWaitlistRepository {
+    file_path: PathBuf,
+    /// 識別子をキーとした空き待ち（未読み込みの場合は `None`）
+    cache: RwLock<Option<BTreeMap<String, WaitlistEntryDto>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WaitlistEntryDto {
+    owner_email: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    resources: Vec<ResourceDto>,
+    joined_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    notified_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum ResourceDto {
+    Gpu {
+        server: String,
+        device: u32,
+        model: String,
+    },
+    Room {
+        name: String,
+    },
+    Instrument {
+        name: String,
+    },
+    Custom {
+        kind: String,
+        name: String,
+    },
+    Storage {
+        volume: String,
+        gigabytes: u64,
+    },
+    License {
+        name: String,
+        seats: u32,
+    },
+}
+
+impl From<&Resource> for ResourceDto {
+    fn from(resource: &Resource) -> Self {
+        match resource {
+            Resource::Gpu(gpu) => ResourceDto::Gpu {
+                server: gpu.server().to_string(),
+                device: gpu.device_number(),
+                model: gpu.model().to_string(),
+            },
+            Resource::Room { name } => ResourceDto::Room { name: name.clone() },
+            Resource::Instrument { name } => ResourceDto::Instrument { name: name.clone() },
+            Resource::Custom { kind, name } => ResourceDto::Custom {
+                kind: kind.clone(),
+                name: name.clone(),
+            },
+            Resource::Storage { volume, gigabytes } => ResourceDto::Storage {
+                volume: volume.clone(),
+                gigabytes: *gigabytes,
+            },
+            Resource::License { name, seats } => ResourceDto::License {
+                name: name.clone(),
+                seats: *seats,
+            },
+        }
+    }
+}
+
+impl From<ResourceDto> for Resource {
+    fn from(dto: ResourceDto) -> Self {
+        match dto {
+            ResourceDto::Gpu {
+                server,
+                device,
+                model,
+            } => Resource::Gpu(Gpu::new(server, device, model)),
+            ResourceDto::Room { name } => Resource::Room { name },
+            ResourceDto::Instrument { name } => Resource::Instrument { name },
+            ResourceDto::Custom { kind, name } => Resource::Custom { kind, name },
+            ResourceDto::Storage { volume, gigabytes } => Resource::Storage { volume, gigabytes },
+            ResourceDto::License { name, seats } => Resource::License { name, seats },
+        }
+    }
+}
+
+impl WaitlistEntryDto {
+    fn from_entry(entry: &WaitlistEntry) -> Self {
+        Self {
+            owner_email: entry.owner_email().as_str().to_string(),
+            start: entry.time_period().start(),
+            end: entry.time_period().end(),
+            resources: entry.resources().iter().map(Into::into).collect(),
+            joined_at: entry.joined_at(),
+            notified_at: entry.notified_at(),
+        }
+    }
+
+    fn into_entry(self, id: String) -> Result<WaitlistEntry, RepositoryError> {
+        let owner_email = EmailAddress::new(self.owner_email)
+            .map_err(|e| RepositoryError::Unknown(format!("不正なメールアドレス: {}", e)))?;
+        let time_period = TimePeriod::new(self.start, self.end)
+            .map_err(|e| RepositoryError::Unknown(format!("不正な時間帯: {}", e)))?;
+        Ok(WaitlistEntry::reconstitute(
+            id,
+            owner_email,
+            time_period,
+            self.resources.into_iter().map(Into::into).collect(),
+            self.joined_at,
+            self.notified_at,
+        ))
+    }
+}
+
+impl JsonFileWaitlistRepository {
+    /// 新しいJSONファイルベースのリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `file_path` - JSONファイルのパス
+    pub fn new(file_path: PathBuf) -> Self {
+        Self {
+            file_path,
+            cache: RwLock::new(None),
+        }
+    }
+
+    async fn load(&self) -> Result<BTreeMap<String, WaitlistEntryDto>, RepositoryError> {
+        let content = match tokio::fs::read_to_string(&self.file_path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // ファイルが存在しない場合は空き待ちなしとして扱う
+                return Ok(BTreeMap::new());
+            }
+            Err(e) => {
+                return Err(RepositoryError::Unknown(format!(
+                    "ファイルの読み込みに失敗: {}",
+                    e
+                )));
+            }
+        };
+
+        serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))
+    }
+
+    /// キャッシュが未読み込みの場合、ファイルから読み込む
+    async fn ensure_loaded(&self) -> Result<(), RepositoryError> {
+        if self.cache.read().await.is_some() {
+            return Ok(());
+        }
+        let data = self.load().await?;
+        self.cache.write().await.get_or_insert(data);
+        Ok(())
+    }
+
+    async fn save_to_file(
+        &self,
+        data: &BTreeMap<String, WaitlistEntryDto>,
+    ) -> Result<(), RepositoryError> {
+        let content = serde_json::to_string_pretty(data)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
+
+        // 親ディレクトリが存在しない場合は作成
+        if let Some(parent) = self.file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::Unknown(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        tokio::fs::write(&self.file_path, content)
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))
+    }
+}
+
+#[async_trait]
+impl WaitlistRepository for JsonFileWaitlistRepository {
+    async fn find_all(&self) -> Result<Vec<WaitlistEntry>, RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let cache = self.cache.read().await;
+        let mut entries = cache
+            .iter()
+            .flatten()
+            .map(|(id, dto)| dto.clone().into_entry(id.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort_by_key(|entry| entry.joined_at());
+        Ok(entries)
+    }
+
+    async fn save(&self, entry: WaitlistEntry) -> Result<(), RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(BTreeMap::new);
+        data.insert(entry.id().to_string(), WaitlistEntryDto::from_entry(&entry));
+        self.save_to_file(data).await
+    }
+
+    async fn delete(&self, id: &str) -> Result<bool, RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(BTreeMap::new);
+        if data.remove(id).is_none() {
+            return Ok(false);
+        }
+        self.save_to_file(data).await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_save_find_and_delete() {
+        let file_path = std::env::temp_dir()
+            .join(format!("lrm_waitlist_{}", uuid::Uuid::new_v4()))
+            .join("waitlist.json");
+        let repo = JsonFileWaitlistRepository::new(file_path.clone());
+        assert!(repo.find_all().await.unwrap().is_empty());
+
+        let now = Utc::now();
+        let entry = WaitlistEntry::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(now + Duration::hours(1), now + Duration::hours(3)).unwrap(),
+            vec![
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string())),
+                Resource::Room {
+                    name: "会議室A".to_string(),
+                },
+            ],
+        )
+        .unwrap();
+        repo.save(entry.clone()).await.unwrap();
+
+        // 別のインスタンスからも読み込める
+        let reopened = JsonFileWaitlistRepository::new(file_path);
+        assert_eq!(reopened.find_all().await.unwrap(), vec![entry.clone()]);
+
+        assert!(reopened.delete(entry.id()).await.unwrap());
+        assert!(!reopened.delete(entry.id()).await.unwrap());
+        assert!(reopened.find_all().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+use crate::domain::ports::repositories::{RepositoryError, WorkspaceTokenRepository};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+/// JSON file storage for workspace bot tokens
+///
+/// ファイルフォーマット（Team ID → Bot Token）:
+/// ```json
+/// {
+///   "T01234567": "xoxb-..."
+/// }
+/// ```
+///
+/// Bot Tokenを含むため、ファイルは所有者のみが読み書きできる権限（0600）で作成する。
+pub struct JsonFileWorkspaceTokenRepository {
+    file_path: PathBuf,
+    /// ワークスペースごとのBot Token（未読み込みの場合は `None`）
+    cache: RwLock<Option<BTreeMap<String, String>>>,
+}
+
+impl JsonFileWorkspaceTokenRepository {
+    /// 新しいJSONファイルベースのリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `file_path` - JSONファイルのパス
+    pub fn new(file_path: PathBuf) -> Self {
+        Self {
+            file_path,
+            cache: RwLock::new(None),
+        }
+    }
+
+    async fn load(&self) -> Result<BTreeMap<String, String>, RepositoryError> {
+        let content = match tokio::fs::read_to_string(&self.file_path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // ファイルが存在しない場合はインストールなしとして扱う
+                return Ok(BTreeMap::new());
+            }
+            Err(e) => {
+                return Err(RepositoryError::Unknown(format!(
+                    "ファイルの読み込みに失敗: {}",
+                    e
+                )));
+            }
+        };
+
+        serde_json::from_str(&content)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのパースに失敗: {}", e)))
+    }
+
+    /// キャッシュが未読み込みの場合、ファイルから読み込む
+    async fn ensure_loaded(&self) -> Result<(), RepositoryError> {
+        if self.cache.read().await.is_some() {
+            return Ok(());
+        }
+        let data = self.load().await?;
+        self.cache.write().await.get_or_insert(data);
+        Ok(())
+    }
+
+    async fn save_to_file(&self, data: &BTreeMap<String, String>) -> Result<(), RepositoryError> {
+        let content = serde_json::to_string_pretty(data)
+            .map_err(|e| RepositoryError::Unknown(format!("JSONのシリアライズに失敗: {}", e)))?;
+
+        // 親ディレクトリが存在しない場合は作成
+        if let Some(parent) = self.file_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                RepositoryError::Unknown(format!("ディレクトリの作成に失敗: {}", e))
+            })?;
+        }
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options
+            .open(&self.file_path)
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルのオープンに失敗: {}", e)))?;
+
+        tokio::io::AsyncWriteExt::write_all(&mut file, content.as_bytes())
+            .await
+            .map_err(|e| RepositoryError::Unknown(format!("ファイルの書き込みに失敗: {}", e)))
+    }
+}
+
+#[async_trait]
+impl WorkspaceTokenRepository for JsonFileWorkspaceTokenRepository {
+    async fn find_by_team_id(&self, team_id: &str) -> Result<Option<String>, RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let cache = self.cache.read().await;
+        Ok(cache.as_ref().and_then(|data| data.get(team_id).cloned()))
+    }
+
+    async fn save(&self, team_id: &str, bot_token: &str) -> Result<(), RepositoryError> {
+        self.ensure_loaded().await?;
+
+        let mut cache = self.cache.write().await;
+        let data = cache.get_or_insert_with(BTreeMap::new);
+        data.insert(team_id.to_string(), bot_token.to_string());
+        self.save_to_file(data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_and_find_by_team_id() {
+        let file_path = std::env::temp_dir()
+            .join(format!("lrm_workspace_token_{}", uuid::Uuid::new_v4()))
+            .join("workspace_tokens.json");
+        let repo = JsonFileWorkspaceTokenRepository::new(file_path.clone());
+        assert_eq!(repo.find_by_team_id("T01").await.unwrap(), None);
+
+        repo.save("T01", "xoxb-old").await.unwrap();
+        repo.save("T02", "xoxb-other").await.unwrap();
+        repo.save("T01", "xoxb-new").await.unwrap();
+
+        // 別のインスタンスからも読み込める
+        let reopened = JsonFileWorkspaceTokenRepository::new(file_path.clone());
+        assert_eq!(
+            reopened.find_by_team_id("T01").await.unwrap().as_deref(),
+            Some("xoxb-new")
+        );
+        assert_eq!(
+            reopened.find_by_team_id("T02").await.unwrap().as_deref(),
+            Some("xoxb-other")
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&file_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! # WorkspaceToken Repository Implementations
+//!
+//! WorkspaceTokenRepositoryポートの具象実装を提供します。
+//!
+//! - `json_file`: JSONファイルを使用した永続化実装
+
+/// JSONファイルベースのWorkspaceTokenリポジトリ実装
+pub mod json_file;
+
+pub use json_file::JsonFileWorkspaceTokenRepository;
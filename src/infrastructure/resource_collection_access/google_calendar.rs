@@ -2,15 +2,10 @@ use crate::domain::common::EmailAddress;
 use crate::domain::ports::resource_collection_access::{
     ResourceCollectionAccessError, ResourceCollectionAccessService,
 };
+use crate::infrastructure::google_auth::{GoogleCalendarHub, GoogleCredentials};
 use async_trait::async_trait;
 use google_calendar3::{
-    CalendarHub,
     api::{AclRule, AclRuleScope},
-    hyper_rustls::{HttpsConnector, HttpsConnectorBuilder},
-    hyper_util::{
-        client::legacy::{Client, connect::HttpConnector},
-        rt::TokioExecutor,
-    },
     yup_oauth2,
 };
 
@@ -19,7 +14,7 @@ use google_calendar3::{
 /// GoogleカレンダーをResourceUsageのコレクションとして利用し、
 /// ACL（Access Control List）を通じてユーザーのアクセス権限を管理する。
 pub struct GoogleCalendarAccessService {
-    hub: CalendarHub<HttpsConnector<HttpConnector>>,
+    hub: GoogleCalendarHub,
 }
 
 impl GoogleCalendarAccessService {
@@ -29,21 +24,27 @@ impl GoogleCalendarAccessService {
     /// * `service_account_key` - サービスアカウントキーのJSONファイルパス
     pub async fn new(service_account_key: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let secret = yup_oauth2::read_service_account_key(service_account_key).await?;
+        Self::from_key(secret).await
+    }
 
-        let auth = yup_oauth2::ServiceAccountAuthenticator::builder(secret)
-            .build()
-            .await?;
-
-        let connector = HttpsConnectorBuilder::new()
-            .with_native_roots()?
-            .https_or_http()
-            .enable_http1()
-            .build();
-
-        let client = Client::builder(TokioExecutor::new()).build(connector);
-
-        let hub = CalendarHub::new(client, auth);
+    /// 読み込み済みのサービスアカウントキーから新しいインスタンスを作成
+    ///
+    /// # 引数
+    /// * `secret` - サービスアカウントキー
+    pub async fn from_key(
+        secret: yup_oauth2::ServiceAccountKey,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_credentials(GoogleCredentials::ServiceAccount(secret)).await
+    }
 
+    /// 認証情報（サービスアカウントまたはOAuth）から新しいインスタンスを作成
+    ///
+    /// # 引数
+    /// * `credentials` - Google Calendar APIの認証情報
+    pub async fn from_credentials(
+        credentials: GoogleCredentials,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let hub = credentials.calendar_hub().await?;
         Ok(Self { hub })
     }
 }
@@ -0,0 +1,251 @@
+use super::field;
+use crate::domain::ports::secret_provider::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+use sha2::{Digest, Sha256};
+use tokio::sync::OnceCell;
+
+/// 署名に使うサービス名
+const SERVICE: &str = "secretsmanager";
+
+/// GetSecretValue のリクエストの Content-Type
+const CONTENT_TYPE: &str = "application/x-amz-json-1.1";
+
+/// AWSの認証情報
+#[derive(Debug, Clone)]
+pub struct AwsCredentials {
+    /// アクセスキーID
+    pub access_key_id: String,
+    /// シークレットアクセスキー
+    pub secret_access_key: String,
+    /// 一時的な認証情報のセッショントークン（オプション）
+    pub session_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GetSecretValueResponse {
+    #[serde(rename = "SecretString")]
+    secret_string: Option<String>,
+}
+
+/// AWS Secrets Managerからシークレットを取得する実装
+///
+/// 1つのシークレットに、シークレットの名前をキーとしたJSONを登録しておく
+/// （例: `{"SLACK_BOT_TOKEN": "xoxb-...", "SLACK_APP_TOKEN": "xapp-..."}`）。
+/// シークレットの内容は最初の取得時に1回だけ読み込む。
+pub struct AwsSecretsManagerProvider {
+    http_client: reqwest::Client,
+    region: String,
+    secret_id: String,
+    credentials: AwsCredentials,
+    document: OnceCell<Map<String, Value>>,
+}
+
+impl AwsSecretsManagerProvider {
+    /// 新しいプロバイダーを作成
+    ///
+    /// # Arguments
+    /// * `region` - リージョン（例: `ap-northeast-1`）
+    /// * `secret_id` - シークレットの名前またはARN
+    /// * `credentials` - AWSの認証情報
+    pub fn new(region: &str, secret_id: &str, credentials: AwsCredentials) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            region: region.to_string(),
+            secret_id: secret_id.to_string(),
+            credentials,
+            document: OnceCell::new(),
+        }
+    }
+
+    fn host(&self) -> String {
+        format!("{}.{}.amazonaws.com", SERVICE, self.region)
+    }
+
+    async fn fetch(&self) -> Result<Map<String, Value>, SecretError> {
+        let body = json!({ "SecretId": self.secret_id }).to_string();
+        let host = self.host();
+        let now = Utc::now();
+        let headers = signed_headers(&self.credentials, &self.region, &host, &body, now);
+
+        let mut request = self
+            .http_client
+            .post(format!("https://{}/", host))
+            .body(body);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| SecretError::ConnectionError(format!("AWS Secrets Manager: {}", e)))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let detail = response.text().await.unwrap_or_default();
+            return Err(SecretError::ConnectionError(format!(
+                "AWS Secrets Manager: {} {}",
+                status, detail
+            )));
+        }
+
+        let response: GetSecretValueResponse = response.json().await.map_err(|e| {
+            SecretError::Invalid(format!("AWS Secrets Managerの応答を解釈できません: {}", e))
+        })?;
+        let secret_string = response.secret_string.ok_or_else(|| {
+            SecretError::Invalid(format!(
+                "シークレット {} に文字列の値がありません",
+                self.secret_id
+            ))
+        })?;
+
+        match serde_json::from_str(&secret_string) {
+            Ok(Value::Object(map)) => Ok(map),
+            _ => Err(SecretError::Invalid(format!(
+                "シークレット {} の値がJSONのオブジェクトではありません",
+                self.secret_id
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for AwsSecretsManagerProvider {
+    async fn get_secret(&self, name: &str) -> Result<String, SecretError> {
+        let document = self.document.get_or_try_init(|| self.fetch()).await?;
+        field(document, name)
+    }
+}
+
+/// GetSecretValue のリクエストに付けるヘッダー（署名バージョン4の `Authorization` を含む）
+fn signed_headers(
+    credentials: &AwsCredentials,
+    region: &str,
+    host: &str,
+    body: &str,
+    now: DateTime<Utc>,
+) -> Vec<(&'static str, String)> {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+
+    // 署名対象のヘッダー（名前の順）
+    let mut headers = vec![
+        ("content-type", CONTENT_TYPE.to_string()),
+        ("host", host.to_string()),
+        ("x-amz-date", amz_date.clone()),
+    ];
+    if let Some(token) = &credentials.session_token {
+        headers.push(("x-amz-security-token", token.clone()));
+    }
+    headers.push(("x-amz-target", "secretsmanager.GetSecretValue".to_string()));
+
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+        .collect();
+    let signed_header_names = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_request = format!(
+        "POST\n/\n\n{}\n{}\n{}",
+        canonical_headers,
+        signed_header_names,
+        hex::encode(Sha256::digest(body.as_bytes()))
+    );
+
+    let scope = format!("{}/{}/{}/aws4_request", date, region, SERVICE);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+    let signature = hex::encode(hmac_sha256(
+        &signing_key(&credentials.secret_access_key, &date, region, SERVICE),
+        string_to_sign.as_bytes(),
+    ));
+
+    headers.retain(|(name, _)| *name != "host");
+    headers.push((
+        "authorization",
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            credentials.access_key_id, scope, signed_header_names, signature
+        ),
+    ));
+    headers
+}
+
+/// 署名バージョン4の署名鍵を求める
+fn signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let key = hmac_sha256(
+        format!("AWS4{}", secret_access_key).as_bytes(),
+        date.as_bytes(),
+    );
+    let key = hmac_sha256(&key, region.as_bytes());
+    let key = hmac_sha256(&key, service.as_bytes());
+    hmac_sha256(&key, b"aws4_request")
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_signing_key_matches_aws_example() {
+        // AWSのドキュメントにある署名鍵の計算例
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20120215",
+            "us-east-1",
+            "iam",
+        );
+
+        assert_eq!(
+            hex::encode(key),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[test]
+    fn test_signed_headers_include_session_token() {
+        let credentials = AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "secret".to_string(),
+            session_token: Some("token".to_string()),
+        };
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 9, 30, 0).unwrap();
+
+        let headers = signed_headers(
+            &credentials,
+            "ap-northeast-1",
+            "secretsmanager.ap-northeast-1.amazonaws.com",
+            "{}",
+            now,
+        );
+
+        let authorization = &headers
+            .iter()
+            .find(|(n, _)| *n == "authorization")
+            .unwrap()
+            .1;
+        assert!(authorization.starts_with(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20250401/ap-northeast-1/secretsmanager/aws4_request, \
+             SignedHeaders=content-type;host;x-amz-date;x-amz-security-token;x-amz-target, Signature="
+        ));
+        assert!(headers.contains(&("x-amz-security-token", "token".to_string())));
+        assert!(!headers.iter().any(|(n, _)| *n == "host"));
+    }
+}
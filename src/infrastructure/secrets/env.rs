@@ -0,0 +1,23 @@
+use crate::domain::ports::secret_provider::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use std::env;
+
+/// 環境変数からシークレットを取得する実装
+///
+/// シークレットの名前をそのまま環境変数名として読み込む。
+#[derive(Debug, Default)]
+pub struct EnvSecretProvider;
+
+impl EnvSecretProvider {
+    /// 新しいプロバイダーを作成
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl SecretProvider for EnvSecretProvider {
+    async fn get_secret(&self, name: &str) -> Result<String, SecretError> {
+        env::var(name).map_err(|_| SecretError::NotFound(name.to_string()))
+    }
+}
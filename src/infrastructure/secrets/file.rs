@@ -0,0 +1,62 @@
+use crate::domain::ports::secret_provider::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// ディレクトリ内のファイルからシークレットを取得する実装
+///
+/// シークレットの名前をファイル名として読み込み、末尾の改行は取り除く。
+/// Docker / Kubernetesのシークレットのように、1つのシークレットを1つのファイルとしてマウントする場合に使う。
+pub struct FileSecretProvider {
+    dir: PathBuf,
+}
+
+impl FileSecretProvider {
+    /// 新しいプロバイダーを作成
+    ///
+    /// # Arguments
+    /// * `dir` - シークレットのファイルを置くディレクトリ
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for FileSecretProvider {
+    async fn get_secret(&self, name: &str) -> Result<String, SecretError> {
+        let path = self.dir.join(name);
+        match tokio::fs::read_to_string(&path).await {
+            Ok(content) => Ok(content.trim_end_matches(['\r', '\n']).to_string()),
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                Err(SecretError::NotFound(name.to_string()))
+            }
+            Err(e) => Err(SecretError::Invalid(format!(
+                "{} を読み込めません: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_secret_trims_trailing_newline() {
+        let dir = std::env::temp_dir().join(format!("lrm_secrets_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("SLACK_BOT_TOKEN"), "xoxb-secret\n").unwrap();
+        let provider = FileSecretProvider::new(dir);
+
+        assert_eq!(
+            provider.get_secret("SLACK_BOT_TOKEN").await.unwrap(),
+            "xoxb-secret"
+        );
+        assert!(matches!(
+            provider.get_secret("SLACK_APP_TOKEN").await,
+            Err(SecretError::NotFound(_))
+        ));
+    }
+}
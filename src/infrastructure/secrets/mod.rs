@@ -0,0 +1,80 @@
+//! # Secret Provider Implementations
+//!
+//! SecretProviderポートの具象実装を提供します。
+//!
+//! - `env`: 環境変数から取得する実装（デフォルト）
+//! - `file`: ディレクトリ内のファイルから取得する実装
+//! - `vault`: HashiCorp VaultのKVシークレットから取得する実装
+//! - `aws`: AWS Secrets Managerから取得する実装
+//!
+//! どの実装を使うかは環境変数 `SECRET_PROVIDER` で選ぶ。
+
+/// AWS Secrets Managerから取得する実装
+pub mod aws;
+/// 環境変数から取得する実装
+pub mod env;
+/// ディレクトリ内のファイルから取得する実装
+pub mod file;
+/// HashiCorp Vaultから取得する実装
+pub mod vault;
+
+pub use aws::{AwsCredentials, AwsSecretsManagerProvider};
+pub use env::EnvSecretProvider;
+pub use file::FileSecretProvider;
+pub use vault::VaultSecretProvider;
+
+use crate::domain::ports::secret_provider::{SecretError, SecretProvider};
+use crate::infrastructure::config::{ConfigLoadError, defaults};
+use serde_json::{Map, Value};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// 環境変数 `SECRET_PROVIDER`（env, file, vault, aws）からシークレットの取得元を作成する
+///
+/// - `file`: `SECRETS_DIR`（デフォルト: /run/secrets）
+/// - `vault`: `VAULT_ADDR`, `VAULT_TOKEN`, `VAULT_SECRET_PATH`
+/// - `aws`: `AWS_REGION`, `AWS_SECRET_ID`, `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`, `AWS_SESSION_TOKEN`（オプション）
+pub fn provider_from_env() -> Result<Arc<dyn SecretProvider>, ConfigLoadError> {
+    let kind = std::env::var("SECRET_PROVIDER").unwrap_or_default();
+    match kind.trim().to_lowercase().as_str() {
+        "" | "env" => Ok(Arc::new(EnvSecretProvider::new())),
+        "file" => Ok(Arc::new(FileSecretProvider::new(
+            std::env::var("SECRETS_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from(defaults::SECRETS_DIR)),
+        ))),
+        "vault" => Ok(Arc::new(VaultSecretProvider::new(
+            &required("VAULT_ADDR")?,
+            &required("VAULT_TOKEN")?,
+            &required("VAULT_SECRET_PATH")?,
+        ))),
+        "aws" => Ok(Arc::new(AwsSecretsManagerProvider::new(
+            &required("AWS_REGION")?,
+            &required("AWS_SECRET_ID")?,
+            AwsCredentials {
+                access_key_id: required("AWS_ACCESS_KEY_ID")?,
+                secret_access_key: required("AWS_SECRET_ACCESS_KEY")?,
+                session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+            },
+        ))),
+        _ => Err(ConfigLoadError::InvalidEnvVar {
+            name: "SECRET_PROVIDER",
+            reason: "env, file, vault, aws のいずれかである必要があります".to_string(),
+        }),
+    }
+}
+
+fn required(name: &'static str) -> Result<String, ConfigLoadError> {
+    std::env::var(name).map_err(|_| ConfigLoadError::MissingEnvVar(name))
+}
+
+/// キーと値の組からシークレットを取り出す
+///
+/// 文字列以外の値（サービスアカウントキーをJSONのまま登録した場合など）はJSONの文字列にする。
+fn field(document: &Map<String, Value>, name: &str) -> Result<String, SecretError> {
+    match document.get(name) {
+        Some(Value::String(value)) => Ok(value.clone()),
+        Some(value) => Ok(value.to_string()),
+        None => Err(SecretError::NotFound(name.to_string())),
+    }
+}
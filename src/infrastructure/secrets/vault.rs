@@ -0,0 +1,111 @@
+use super::field;
+use crate::domain::ports::secret_provider::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use tokio::sync::OnceCell;
+
+/// HashiCorp Vaultからシークレットを取得する実装
+///
+/// KVシークレットエンジンの1つのパスに、シークレットの名前をキーとして値を登録しておく。
+/// KV v2（`secret/data/...`）とKV v1のどちらの応答にも対応する。
+/// パスの内容は最初の取得時に1回だけ読み込む。
+pub struct VaultSecretProvider {
+    http_client: reqwest::Client,
+    url: String,
+    token: String,
+    document: OnceCell<Map<String, Value>>,
+}
+
+impl VaultSecretProvider {
+    /// 新しいプロバイダーを作成
+    ///
+    /// # Arguments
+    /// * `address` - VaultのURL（例: `https://vault.example.ac.jp:8200`）
+    /// * `token` - Vaultのトークン
+    /// * `path` - シークレットのパス（例: `secret/data/lab-resource-manager`）
+    pub fn new(address: &str, token: &str, path: &str) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            url: format!(
+                "{}/v1/{}",
+                address.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            ),
+            token: token.to_string(),
+            document: OnceCell::new(),
+        }
+    }
+
+    async fn fetch(&self) -> Result<Map<String, Value>, SecretError> {
+        let response: Value = self
+            .http_client
+            .get(&self.url)
+            .header("X-Vault-Token", &self.token)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| SecretError::ConnectionError(format!("Vault: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| SecretError::Invalid(format!("Vaultの応答を解釈できません: {}", e)))?;
+
+        parse_response(response)
+    }
+}
+
+/// Vaultの応答からシークレットの値を取り出す
+fn parse_response(mut response: Value) -> Result<Map<String, Value>, SecretError> {
+    let data = response
+        .get_mut("data")
+        .map(Value::take)
+        .ok_or_else(|| SecretError::Invalid("Vaultの応答に data がありません".to_string()))?;
+
+    // KV v2 は data.data に値、data.metadata にバージョン情報を持つ
+    let data = match data {
+        Value::Object(mut map) if map.contains_key("metadata") => {
+            map.remove("data").unwrap_or(Value::Null)
+        }
+        data => data,
+    };
+
+    match data {
+        Value::Object(map) => Ok(map),
+        _ => Err(SecretError::Invalid(
+            "Vaultのシークレットがキーと値の組ではありません".to_string(),
+        )),
+    }
+}
+
+#[async_trait]
+impl SecretProvider for VaultSecretProvider {
+    async fn get_secret(&self, name: &str) -> Result<String, SecretError> {
+        let document = self.document.get_or_try_init(|| self.fetch()).await?;
+        field(document, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_response_supports_kv_v1_and_v2() {
+        let v2 = json!({
+            "data": {
+                "data": { "SLACK_BOT_TOKEN": "xoxb-v2" },
+                "metadata": { "version": 3 }
+            }
+        });
+        let v1 = json!({ "data": { "SLACK_BOT_TOKEN": "xoxb-v1" } });
+
+        assert_eq!(
+            field(&parse_response(v2).unwrap(), "SLACK_BOT_TOKEN").unwrap(),
+            "xoxb-v2"
+        );
+        assert_eq!(
+            field(&parse_response(v1).unwrap(), "SLACK_BOT_TOKEN").unwrap(),
+            "xoxb-v1"
+        );
+    }
+}
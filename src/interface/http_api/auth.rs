@@ -0,0 +1,151 @@
+//! APIトークンによる認証
+//!
+//! トークンファイルには1行に1つ、`<メールアドレス> <トークンのSHA-256（16進）>` を書く。
+//! `#` から始まる行と空行は無視する。トークンそのものは保存せず、発行時に一度だけ表示する。
+//! ファイルはリクエストのたびに読み込むため、行を削除すればBotを再起動せずにトークンを無効にできる。
+
+use crate::domain::common::EmailAddress;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+
+/// 発行するトークンの接頭辞（ログや設定ファイルに紛れたときに見分けられるようにする）
+const TOKEN_PREFIX: &str = "lrm_";
+
+/// トークンファイルの内容（トークンのハッシュ → トークンの持ち主）
+#[derive(Debug, Default)]
+pub struct ApiTokens {
+    owners: HashMap<String, EmailAddress>,
+}
+
+impl ApiTokens {
+    /// トークンファイルを読み込む（ファイルが無い場合はトークンなし）
+    pub async fn load(path: &Path) -> Result<Self, String> {
+        match tokio::fs::read_to_string(path).await {
+            Ok(content) => Self::parse(&content).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{} を読み込めません: {}", path.display(), e)),
+        }
+    }
+
+    /// トークンファイルの内容を解析する
+    fn parse(content: &str) -> Result<Self, String> {
+        let mut owners = HashMap::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let (Some(email), Some(hash), None) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!(
+                    "{}行目: `<メールアドレス> <トークンのSHA-256>` の形式で書いてください",
+                    index + 1
+                ));
+            };
+            let email = EmailAddress::new(email.to_string())
+                .map_err(|e| format!("{}行目: {}", index + 1, e))?;
+            owners.insert(hash.to_ascii_lowercase(), email);
+        }
+        Ok(Self { owners })
+    }
+
+    /// トークンの持ち主を取得
+    pub fn owner_of(&self, token: &str) -> Option<&EmailAddress> {
+        self.owners.get(&hash_token(token))
+    }
+}
+
+/// `Authorization: Bearer <トークン>` ヘッダーの値からトークンを取り出す
+pub fn bearer_token(authorization: &str) -> Option<&str> {
+    let (scheme, token) = authorization.trim().split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+/// トークンのハッシュ（トークンファイルに保存する値）
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// トークンを発行し、トークンファイルに追記する
+///
+/// 発行したトークンを返す（ファイルにはハッシュのみを保存するため、再表示はできない）。
+pub fn issue_token(path: &Path, email: &EmailAddress) -> std::io::Result<String> {
+    let token = format!(
+        "{}{}{}",
+        TOKEN_PREFIX,
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    );
+
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(file, "{} {}", email.as_str(), hash_token(&token))?;
+    file.sync_all()?;
+
+    Ok(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_are_looked_up_by_hash() {
+        let content = format!(
+            "# スクリプト用\n\nalice@example.com {}\nbob@example.com {}\n",
+            hash_token("token-a"),
+            hash_token("token-b").to_uppercase()
+        );
+        let tokens = ApiTokens::parse(&content).unwrap();
+
+        assert_eq!(
+            tokens.owner_of("token-a").map(EmailAddress::as_str),
+            Some("alice@example.com")
+        );
+        assert_eq!(
+            tokens.owner_of("token-b").map(EmailAddress::as_str),
+            Some("bob@example.com")
+        );
+        assert!(tokens.owner_of("token-c").is_none());
+        assert!(ApiTokens::parse("alice@example.com").is_err());
+        assert!(ApiTokens::parse("not-an-email abc").is_err());
+    }
+
+    #[test]
+    fn test_bearer_token() {
+        assert_eq!(bearer_token("Bearer lrm_abc"), Some("lrm_abc"));
+        assert_eq!(bearer_token("bearer  lrm_abc "), Some("lrm_abc"));
+        assert_eq!(bearer_token("Basic dXNlcjpwYXNz"), None);
+        assert_eq!(bearer_token("Bearer "), None);
+    }
+
+    #[test]
+    fn test_issue_token_appends_hash() {
+        let path = std::env::temp_dir()
+            .join(format!("lrm_api_tokens_{}", uuid::Uuid::new_v4()))
+            .join("api_tokens");
+        let email = EmailAddress::new("alice@example.com".to_string()).unwrap();
+
+        let first = issue_token(&path, &email).unwrap();
+        let second = issue_token(&path, &email).unwrap();
+
+        assert!(first.starts_with(TOKEN_PREFIX));
+        assert_ne!(first, second);
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(!content.contains(&first));
+        let tokens = ApiTokens::parse(&content).unwrap();
+        assert_eq!(tokens.owner_of(&first), Some(&email));
+        assert_eq!(tokens.owner_of(&second), Some(&email));
+    }
+}
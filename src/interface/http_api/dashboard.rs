@@ -0,0 +1,348 @@
+//! 廊下のモニターなどに表示する読み取り専用のダッシュボード
+//!
+//! サーバー・部屋・機器ごとに、今日から7日間の予約をタイムラインで表示するHTMLを、リポジトリの予約から生成する。
+//! JavaScriptは使わず、1分ごとに再読み込みする。ブラウザはヘッダーを指定できないため、
+//! トークンは `?access_token=<トークン>` でも受け付ける。
+
+use super::feeds::FeedKind;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::Resource;
+use crate::domain::common::EmailAddress;
+use crate::infrastructure::config::ResourceConfig;
+use chrono::{DateTime, Days, NaiveTime, TimeZone, Utc};
+use std::fmt::Write;
+
+/// ダッシュボードのパス
+pub const DASHBOARD_PATH: &str = "/dashboard";
+
+/// HTMLのContent-Type
+pub const CONTENT_TYPE: &str = "text/html; charset=utf-8";
+
+/// 表示する日数
+const DAYS: u64 = 7;
+
+/// 再読み込みの間隔（秒）
+const REFRESH_SECS: u32 = 60;
+
+/// 予約1件の帯の高さ（px）
+const BAR_HEIGHT_PX: usize = 22;
+
+const STYLE: &str = "
+body { margin: 0; padding: 16px; font-family: sans-serif; background: #111; color: #eee; }
+header { display: flex; justify-content: space-between; align-items: baseline; }
+h1 { margin: 0 0 12px; font-size: 24px; }
+.row { display: flex; border-bottom: 1px solid #333; }
+.label { width: 140px; flex: none; padding: 4px 8px; font-weight: bold; }
+.lane { position: relative; flex: 1; min-height: 26px; }
+.days .lane { height: 20px; }
+.day { position: absolute; top: 0; font-size: 12px; color: #aaa; padding-left: 4px; }
+.grid { position: absolute; top: 0; bottom: 0; border-left: 1px solid #333; }
+.now { position: absolute; top: 0; bottom: 0; border-left: 2px solid #e55; }
+.bar { position: absolute; height: 20px; overflow: hidden; white-space: nowrap; font-size: 12px;
+  line-height: 20px; padding: 0 4px; box-sizing: border-box; background: #2a6; border-radius: 3px; }
+.bar.private { background: #666; }
+.bar.pending { background: #a82; }
+";
+
+/// ダッシュボードの1行（1つのリソース）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DashboardRow {
+    kind: FeedKind,
+    name: String,
+}
+
+impl DashboardRow {
+    /// 設定にあるサーバー・部屋・機器の順に行を作る
+    pub fn from_config(config: &ResourceConfig) -> Vec<Self> {
+        let row = |kind, name: &String| Self {
+            kind,
+            name: name.clone(),
+        };
+        config
+            .servers
+            .iter()
+            .map(|server| row(FeedKind::Server, &server.name))
+            .chain(
+                config
+                    .rooms
+                    .iter()
+                    .map(|room| row(FeedKind::Room, &room.name)),
+            )
+            .chain(
+                config
+                    .instruments
+                    .iter()
+                    .map(|instrument| row(FeedKind::Instrument, &instrument.name)),
+            )
+            .collect()
+    }
+}
+
+/// ダッシュボードのHTMLを生成する
+///
+/// # Arguments
+/// * `rows` - 表示するリソース
+/// * `usages` - 予約（表示する期間と重ならないものは無視する）
+/// * `now` - 現在時刻
+/// * `tz` - 日付の区切りと時刻の表示に使うタイムゾーン
+/// * `viewer` / `is_admin` - 非公開の予約の詳細を伏せるかどうかの判定に使う
+pub fn render<T: TimeZone>(
+    rows: &[DashboardRow],
+    usages: &[ResourceUsage],
+    now: DateTime<Utc>,
+    tz: &T,
+    viewer: &EmailAddress,
+    is_admin: bool,
+) -> String
+where
+    T::Offset: std::fmt::Display,
+{
+    let today = now.with_timezone(tz).date_naive();
+    let day_start = |offset: u64| {
+        today
+            .checked_add_days(Days::new(offset))
+            .and_then(|day| {
+                tz.from_local_datetime(&day.and_time(NaiveTime::MIN))
+                    .earliest()
+            })
+            .map(|start| start.with_timezone(&Utc))
+    };
+    let (Some(window_start), Some(window_end)) = (day_start(0), day_start(DAYS)) else {
+        return String::new();
+    };
+    let position = |time: DateTime<Utc>| {
+        let total = (window_end - window_start).num_seconds().max(1) as f64;
+        ((time - window_start).num_seconds() as f64 / total * 100.0).clamp(0.0, 100.0)
+    };
+
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"ja\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta http-equiv=\"refresh\" content=\"{}\">\n<title>予約状況</title>\n<style>{}</style>\n</head>\n<body>\n\
+         <header><h1>予約状況</h1><span>{} 更新</span></header>\n<div class=\"timeline\">\n",
+        REFRESH_SECS,
+        STYLE,
+        now.with_timezone(tz).format("%m/%d %H:%M")
+    );
+
+    // 日付の見出し
+    html.push_str("<div class=\"row days\"><div class=\"label\"></div><div class=\"lane\">");
+    for offset in 0..DAYS {
+        if let Some(start) = day_start(offset) {
+            let _ = write!(
+                html,
+                "<div class=\"day\" style=\"left:{:.3}%\">{}</div>",
+                position(start),
+                start.with_timezone(tz).format("%m/%d (%a)")
+            );
+        }
+    }
+    html.push_str("</div></div>\n");
+
+    for row in rows {
+        let mut bars: Vec<&ResourceUsage> = usages
+            .iter()
+            .filter(|usage| {
+                usage.time_period().end() > window_start
+                    && usage.time_period().start() < window_end
+                    && usage
+                        .resources()
+                        .iter()
+                        .any(|resource| row.kind.matches(resource, &row.name))
+            })
+            .collect();
+        bars.sort_by_key(|usage| usage.time_period().start());
+        let lanes = assign_lanes(&bars);
+        let height = lanes.iter().max().map_or(1, |max| max + 1) * BAR_HEIGHT_PX + 4;
+
+        let _ = write!(
+            html,
+            "<div class=\"row\"><div class=\"label\">{}</div><div class=\"lane\" style=\"height:{}px\">",
+            escape(&row.name),
+            height
+        );
+        for offset in 1..DAYS {
+            if let Some(start) = day_start(offset) {
+                let _ = write!(
+                    html,
+                    "<div class=\"grid\" style=\"left:{:.3}%\"></div>",
+                    position(start)
+                );
+            }
+        }
+        for (usage, lane) in bars.iter().zip(&lanes) {
+            let show_details = usage.details_visible_to(Some(viewer), is_admin);
+            let left = position(usage.time_period().start());
+            let width = position(usage.time_period().end()) - left;
+            let mut class = String::from("bar");
+            if !show_details {
+                class.push_str(" private");
+            }
+            if usage.approval_status().is_pending() {
+                class.push_str(" pending");
+            }
+            let label = bar_label(usage, row, show_details);
+            let _ = write!(
+                html,
+                "<div class=\"{}\" style=\"left:{:.3}%;width:{:.3}%;top:{}px\" title=\"{} - {} {}\">{}</div>",
+                class,
+                left,
+                width,
+                lane * BAR_HEIGHT_PX + 2,
+                usage
+                    .time_period()
+                    .start()
+                    .with_timezone(tz)
+                    .format("%m/%d %H:%M"),
+                usage
+                    .time_period()
+                    .end()
+                    .with_timezone(tz)
+                    .format("%m/%d %H:%M"),
+                escape(&label),
+                escape(&label)
+            );
+        }
+        let _ = writeln!(
+            html,
+            "<div class=\"now\" style=\"left:{:.3}%\"></div></div></div>",
+            position(now)
+        );
+    }
+
+    html.push_str("</div>\n</body>\n</html>\n");
+    html
+}
+
+/// 重なる予約が別の段に表示されるよう、予約ごとの段を決める（開始順に並べた予約を渡す）
+fn assign_lanes(usages: &[&ResourceUsage]) -> Vec<usize> {
+    let mut lane_ends: Vec<DateTime<Utc>> = Vec::new();
+    usages
+        .iter()
+        .map(|usage| {
+            let period = usage.time_period();
+            match lane_ends.iter().position(|end| *end <= period.start()) {
+                Some(lane) => {
+                    lane_ends[lane] = period.end();
+                    lane
+                }
+                None => {
+                    lane_ends.push(period.end());
+                    lane_ends.len() - 1
+                }
+            }
+        })
+        .collect()
+}
+
+/// 帯に表示する文字列（GPUの番号と予約者のメールアドレスの@より前）
+fn bar_label(usage: &ResourceUsage, row: &DashboardRow, show_details: bool) -> String {
+    let owner = if show_details {
+        let email = usage.owner_email().as_str();
+        email.split('@').next().unwrap_or(email).to_string()
+    } else {
+        "非公開".to_string()
+    };
+    let devices: Vec<String> = usage
+        .resources()
+        .iter()
+        .filter_map(|resource| match resource {
+            Resource::Gpu(gpu) if row.kind.matches(resource, &row.name) => {
+                Some(gpu.device_number().to_string())
+            }
+            _ => None,
+        })
+        .collect();
+    if devices.is_empty() {
+        owner
+    } else {
+        format!("GPU:{} {}", devices.join(","), owner)
+    }
+}
+
+/// HTMLのエスケープ
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, TimePeriod, Visibility};
+
+    fn email(address: &str) -> EmailAddress {
+        EmailAddress::new(address.to_string()).unwrap()
+    }
+
+    fn usage(owner: &str, start_hour: u32, end_hour: u32, device: u32) -> ResourceUsage {
+        ResourceUsage::new(
+            email(owner),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2025, 4, 2, start_hour, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 2, end_hour, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                device,
+                "A100".to_string(),
+            ))],
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_render_places_bars_and_redacts_private() {
+        let rows = vec![
+            DashboardRow {
+                kind: FeedKind::Server,
+                name: "Thalys".to_string(),
+            },
+            DashboardRow {
+                kind: FeedKind::Room,
+                name: "<会議室>".to_string(),
+            },
+        ];
+        let usages = vec![
+            usage("alice@example.com", 0, 12, 0),
+            usage("bob@example.com", 6, 18, 1).with_visibility(Visibility::Private),
+        ];
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 12, 0, 0).unwrap();
+
+        let html = render(
+            &rows,
+            &usages,
+            now,
+            &Utc,
+            &email("carol@example.com"),
+            false,
+        );
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(">GPU:0 alice</div>"));
+        assert!(html.contains(">GPU:1 非公開</div>"));
+        assert!(!html.contains("bob"));
+        assert!(html.contains("&lt;会議室&gt;"));
+        // 4/1 0:00からの7日間で、4/2 0:00は1/7の位置、重なる2件目は2段目
+        assert!(html.contains("left:14.286%;width:7.143%;top:2px"));
+        assert!(html.contains("top:24px"));
+        assert!(html.contains("<div class=\"now\" style=\"left:7.143%\">"));
+    }
+
+    #[test]
+    fn test_assign_lanes_reuses_free_lanes() {
+        let usages = [
+            usage("a@example.com", 0, 6, 0),
+            usage("b@example.com", 3, 9, 1),
+            usage("c@example.com", 6, 12, 2),
+        ];
+        let refs: Vec<&ResourceUsage> = usages.iter().collect();
+        assert_eq!(assign_lanes(&refs), vec![0, 1, 0]);
+    }
+}
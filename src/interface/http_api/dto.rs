@@ -0,0 +1,300 @@
+//! APIのリクエスト・レスポンスの形式
+//!
+//! 時刻はRFC 3339で受け取り、UTCで返す。リソースはCSVの取り込みと同じ形式
+//! （`Thalys:0-1; 会議室A` のように `;` 区切り）で指定する。
+
+use crate::application::usecases::AvailabilityReport;
+use crate::domain::aggregates::group::GroupId;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::service::format_resource_item;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    Priority, ReservationMetadata, Resource, TimePeriod, Visibility,
+};
+use crate::infrastructure::config::ResourceConfig;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 予約1件分のレスポンス
+///
+/// 閲覧者に詳細を見せない非公開の予約は、予約者・備考・メタデータを `null` にする。
+#[derive(Debug, Serialize)]
+pub struct UsageBody {
+    id: String,
+    owner_email: Option<String>,
+    start: String,
+    end: String,
+    resources: Vec<String>,
+    notes: Option<String>,
+    project: Option<String>,
+    experiment_id: Option<String>,
+    expected_utilization: Option<u8>,
+    private: bool,
+    redacted: bool,
+    pending_approval: bool,
+    priority: String,
+    group: Option<String>,
+}
+
+impl UsageBody {
+    /// 予約をレスポンスに変換する
+    ///
+    /// # Arguments
+    /// * `usage` - 予約
+    /// * `show_details` - 予約者・備考・メタデータを返すかどうか
+    pub fn new(usage: &ResourceUsage, show_details: bool) -> Self {
+        let metadata = usage.metadata();
+        Self {
+            id: usage.id().as_str().to_string(),
+            owner_email: show_details.then(|| usage.owner_email().as_str().to_string()),
+            start: rfc3339(usage.time_period().start()),
+            end: rfc3339(usage.time_period().end()),
+            resources: usage.resources().iter().map(format_resource_item).collect(),
+            notes: usage.notes().filter(|_| show_details).cloned(),
+            project: metadata
+                .project()
+                .filter(|_| show_details)
+                .map(str::to_string),
+            experiment_id: metadata
+                .experiment_id()
+                .filter(|_| show_details)
+                .map(str::to_string),
+            expected_utilization: metadata.expected_utilization().filter(|_| show_details),
+            private: usage.visibility() == Visibility::Private,
+            redacted: !show_details,
+            pending_approval: usage.approval_status().is_pending(),
+            priority: usage.priority().as_str().to_string(),
+            group: usage.group().map(|group| group.as_str().to_string()),
+        }
+    }
+}
+
+/// 空き状況のレスポンス
+#[derive(Debug, Serialize)]
+pub struct AvailabilityBody {
+    start: String,
+    end: String,
+    resources: Vec<ResourceAvailabilityBody>,
+}
+
+/// リソースごとの空き状況
+#[derive(Debug, Serialize)]
+struct ResourceAvailabilityBody {
+    resource: String,
+    free: bool,
+    busy: Vec<PeriodBody>,
+    free_periods: Vec<PeriodBody>,
+}
+
+/// 期間
+#[derive(Debug, Serialize)]
+pub struct PeriodBody {
+    start: String,
+    end: String,
+}
+
+impl PeriodBody {
+    /// 期間をレスポンスに変換する
+    pub fn new(period: &TimePeriod) -> Self {
+        Self {
+            start: rfc3339(period.start()),
+            end: rfc3339(period.end()),
+        }
+    }
+}
+
+impl AvailabilityBody {
+    /// 空き状況をレスポンスに変換する
+    pub fn new(report: &AvailabilityReport) -> Self {
+        Self {
+            start: rfc3339(report.period().start()),
+            end: rfc3339(report.period().end()),
+            resources: report
+                .resources()
+                .iter()
+                .map(|availability| ResourceAvailabilityBody {
+                    resource: format_resource_item(availability.resource()),
+                    free: availability.is_free(),
+                    busy: availability
+                        .busy_periods()
+                        .iter()
+                        .map(PeriodBody::new)
+                        .collect(),
+                    free_periods: availability
+                        .free_periods()
+                        .iter()
+                        .map(PeriodBody::new)
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// エラーのレスポンス
+#[derive(Debug, Serialize)]
+pub struct ErrorBody<'a> {
+    pub error: &'a str,
+}
+
+/// 予約作成のリクエスト
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateUsageRequest {
+    pub resources: String,
+    pub start: String,
+    pub end: String,
+    pub notes: Option<String>,
+    pub project: Option<String>,
+    pub experiment_id: Option<String>,
+    pub expected_utilization: Option<u8>,
+    #[serde(default)]
+    pub private: bool,
+    pub priority: Option<String>,
+    pub group: Option<String>,
+}
+
+impl CreateUsageRequest {
+    /// リクエストを検証し、予約の作成に必要な値に変換する
+    pub fn into_new_usage(self, resource_config: &ResourceConfig) -> Result<NewUsage, String> {
+        let time_period = TimePeriod::new(
+            parse_time("start", &self.start)?,
+            parse_time("end", &self.end)?,
+        )
+        .map_err(|e| e.to_string())?;
+        let resources = resource_config.resolve_resources(&self.resources)?;
+        let metadata =
+            ReservationMetadata::new(self.project, self.experiment_id, self.expected_utilization)
+                .map_err(|e| e.to_string())?;
+        let priority = match self.priority {
+            Some(priority) => priority.parse::<Priority>().map_err(|_| {
+                format!(
+                    "priority は background, normal, deadline のいずれかで指定してください: {}",
+                    priority
+                )
+            })?,
+            None => Priority::default(),
+        };
+        Ok(NewUsage {
+            time_period,
+            resources,
+            notes: self.notes.filter(|notes| !notes.trim().is_empty()),
+            metadata,
+            visibility: if self.private {
+                Visibility::Private
+            } else {
+                Visibility::Public
+            },
+            priority,
+            group: self.group.map(GroupId::new),
+        })
+    }
+}
+
+/// 検証済みの予約作成のリクエスト
+#[derive(Debug)]
+pub struct NewUsage {
+    pub time_period: TimePeriod,
+    pub resources: Vec<Resource>,
+    pub notes: Option<String>,
+    pub metadata: ReservationMetadata,
+    pub visibility: Visibility,
+    pub priority: Priority,
+    pub group: Option<GroupId>,
+}
+
+/// 予約更新のリクエスト（指定した項目だけを変更する）
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateUsageRequest {
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub notes: Option<String>,
+    pub project: Option<String>,
+    pub experiment_id: Option<String>,
+    pub expected_utilization: Option<u8>,
+    pub private: Option<bool>,
+}
+
+impl UpdateUsageRequest {
+    /// メタデータ（プロジェクト・実験ID・想定使用率）のいずれかを変更するかどうか
+    pub fn changes_metadata(&self) -> bool {
+        self.project.is_some()
+            || self.experiment_id.is_some()
+            || self.expected_utilization.is_some()
+    }
+}
+
+/// RFC 3339の時刻を解釈する
+pub fn parse_time(field: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .map_err(|_| {
+            format!(
+                "{} はRFC 3339形式（例: 2025-04-01T09:00:00+09:00）で指定してください: {}",
+                field, value
+            )
+        })
+}
+
+fn rfc3339(datetime: DateTime<Utc>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{
+        Gpu, ReservationMetadata, Resource,
+    };
+    use crate::domain::common::EmailAddress;
+    use chrono::TimeZone;
+
+    fn private_usage() -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 1, 3, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                0,
+                "A100".to_string(),
+            ))],
+            Some("実験".to_string()),
+        )
+        .unwrap()
+        .with_metadata(ReservationMetadata::new(Some("LLM".to_string()), None, Some(80)).unwrap())
+        .with_visibility(Visibility::Private)
+    }
+
+    #[test]
+    fn test_usage_body_redacts_details() {
+        let usage = private_usage();
+
+        let shown = serde_json::to_value(UsageBody::new(&usage, true)).unwrap();
+        assert_eq!(shown["owner_email"], "alice@example.com");
+        assert_eq!(shown["start"], "2025-04-01T00:00:00Z");
+        assert_eq!(shown["resources"][0], "Thalys / A100 / GPU:0");
+        assert_eq!(shown["project"], "LLM");
+        assert_eq!(shown["redacted"], false);
+
+        let hidden = serde_json::to_value(UsageBody::new(&usage, false)).unwrap();
+        assert!(hidden["owner_email"].is_null());
+        assert!(hidden["notes"].is_null());
+        assert!(hidden["project"].is_null());
+        assert!(hidden["expected_utilization"].is_null());
+        assert_eq!(hidden["private"], true);
+        assert_eq!(hidden["redacted"], true);
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(
+            parse_time("start", "2025-04-01T09:00:00+09:00").unwrap(),
+            Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap()
+        );
+        assert!(parse_time("start", "2025-04-01 09:00").is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//! 予約の変更のイベントストリーム（Server-Sent Events）
+//!
+//! 通知と同じ変更検知で見つけた `NotificationEvent` を、接続しているクライアントへそのまま配信する。
+//! 壁掛けのダッシュボードなどで、部屋やGPUの状態をリアルタイムに表示するために使う。
+//!
+//! 1つのイベントは `event: <種類>` と `data: <JSON>` の2行で送る。種類は `created`、`updated`、
+//! `deleted`、`series_created`、`started`、`ended` のいずれか。接続が遅れてイベントを取りこぼした場合は
+//! `lagged` を送る（クライアントは一覧を取得し直す）。
+
+use super::dto::{PeriodBody, UsageBody};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::NotificationEvent;
+use hyper::body::{Body, Bytes, Frame};
+use serde::Serialize;
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+
+/// 配信待ちにできるイベントの上限（超えると遅れている接続は古いイベントを取りこぼす）
+pub const EVENT_BUFFER: usize = 256;
+
+/// 接続を保つためにコメント行を送る間隔（プロキシのタイムアウト対策）
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Server-Sent EventsのContent-Type
+pub const CONTENT_TYPE: &str = "text/event-stream";
+
+/// イベントストリームのレスポンスボディ
+///
+/// クライアントが切断してボディが破棄されると、配信するタスクも終了する。
+pub struct EventStreamBody {
+    receiver: mpsc::Receiver<Bytes>,
+}
+
+impl Body for EventStreamBody {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, Infallible>>> {
+        self.receiver
+            .poll_recv(cx)
+            .map(|chunk| chunk.map(|chunk| Ok(Frame::data(chunk))))
+    }
+}
+
+/// 通知イベントを閲覧者に応じて伏せてから配信するボディを作成
+///
+/// # Arguments
+/// * `events` - 通知イベントの受信側
+/// * `viewer` - 接続した利用者（非公開の予約の詳細を伏せるかどうかの判定に使う）
+/// * `is_admin` - 接続した利用者が管理者かどうか
+pub fn stream(
+    mut events: broadcast::Receiver<NotificationEvent>,
+    viewer: EmailAddress,
+    is_admin: bool,
+) -> EventStreamBody {
+    let (sender, receiver) = mpsc::channel(16);
+    tokio::spawn(async move {
+        let mut keepalive = tokio::time::interval(KEEPALIVE_INTERVAL);
+        loop {
+            let chunk = tokio::select! {
+                event = events.recv() => match event {
+                    Ok(event) => format_event(&event, &viewer, is_admin),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        format!("event: lagged\ndata: {{\"skipped\":{}}}\n\n", skipped)
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                _ = keepalive.tick() => ": keepalive\n\n".to_string(),
+            };
+            if sender.send(Bytes::from(chunk)).await.is_err() {
+                break;
+            }
+        }
+    });
+    EventStreamBody { receiver }
+}
+
+/// 配信するイベントのデータ
+#[derive(Serialize)]
+struct EventData {
+    usage: UsageBody,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    periods: Option<Vec<PeriodBody>>,
+}
+
+/// 通知イベントをServer-Sent Eventsの1件に変換する
+fn format_event(event: &NotificationEvent, viewer: &EmailAddress, is_admin: bool) -> String {
+    let (name, periods) = match event {
+        NotificationEvent::ResourceUsageCreated(_) => ("created", None),
+        NotificationEvent::ResourceUsageUpdated(_) => ("updated", None),
+        NotificationEvent::ResourceUsageDeleted(_) => ("deleted", None),
+        NotificationEvent::ResourceUsageSeriesCreated { periods, .. } => (
+            "series_created",
+            Some(periods.iter().map(PeriodBody::new).collect()),
+        ),
+        NotificationEvent::ResourceUsageStarted(_) => ("started", None),
+        NotificationEvent::ResourceUsageEnded(_) => ("ended", None),
+    };
+    let usage = event.usage();
+    let data = EventData {
+        usage: UsageBody::new(usage, usage.details_visible_to(Some(viewer), is_admin)),
+        periods,
+    };
+    let json = serde_json::to_string(&data).unwrap_or_else(|_| "{}".to_string());
+    format!("event: {}\ndata: {}\n\n", name, json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+    use crate::domain::aggregates::resource_usage::value_objects::Resource;
+    use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, Visibility};
+    use chrono::{TimeZone, Utc};
+    use http_body_util::BodyExt;
+
+    fn email(address: &str) -> EmailAddress {
+        EmailAddress::new(address.to_string()).unwrap()
+    }
+
+    fn private_usage() -> ResourceUsage {
+        ResourceUsage::new(
+            email("alice@example.com"),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 1, 3, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            Some("面談".to_string()),
+        )
+        .unwrap()
+        .with_visibility(Visibility::Private)
+    }
+
+    #[test]
+    fn test_format_event_redacts_private_details() {
+        let event = NotificationEvent::ResourceUsageStarted(private_usage());
+
+        let shown = format_event(&event, &email("alice@example.com"), false);
+        assert!(shown.starts_with("event: started\ndata: {"));
+        assert!(shown.ends_with("}\n\n"));
+        assert!(shown.contains("\"owner_email\":\"alice@example.com\""));
+        assert!(!shown.contains("periods"));
+
+        let hidden = format_event(&event, &email("bob@example.com"), false);
+        assert!(hidden.contains("\"owner_email\":null"));
+        assert!(!hidden.contains("面談"));
+        assert!(hidden.contains("会議室A"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_forwards_events_until_closed() {
+        let (sender, receiver) = broadcast::channel(EVENT_BUFFER);
+        let mut body = stream(receiver, email("alice@example.com"), false);
+
+        // 接続直後の接続維持のコメント
+        let first = body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(first, Bytes::from(": keepalive\n\n"));
+
+        sender
+            .send(NotificationEvent::ResourceUsageCreated(private_usage()))
+            .unwrap();
+        let chunk = body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert!(chunk.starts_with(b"event: created\n"));
+
+        drop(sender);
+        assert!(body.frame().await.is_none());
+    }
+}
@@ -0,0 +1,115 @@
+//! 予約のiCalendarフィード
+//!
+//! カレンダーアプリから購読できる読み取り専用の `.ics` を、リポジトリの予約から生成する。
+//! 予約の保存先がGoogleカレンダーでなくても、個人のカレンダーアプリでGPUの予定を確認できる。
+//!
+//! - `/feeds/server/{サーバー名}.ics`
+//! - `/feeds/room/{部屋名}.ics`
+//! - `/feeds/instrument/{機器名}.ics`
+//! - `/feeds/user/{メールアドレス}.ics`
+//!
+//! リソース名は大文字・小文字を区別しない。カレンダーアプリはヘッダーを指定できないため、
+//! トークンは `?access_token=<トークン>` で受け付ける。
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::Resource;
+use crate::domain::common::EmailAddress;
+use crate::infrastructure::config::ResourceConfig;
+use crate::infrastructure::export::formats::to_icalendar_feed;
+use chrono::Utc;
+
+/// フィードのパスの接頭辞
+pub const FEED_PREFIX: &str = "/feeds";
+
+/// iCalendarのContent-Type
+pub const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+/// フィードの拡張子
+const FEED_EXTENSION: &str = ".ics";
+
+/// リソースごとのフィードの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedKind {
+    Server,
+    Room,
+    Instrument,
+}
+
+impl FeedKind {
+    /// パスの種類の部分（`server` など）を解釈する
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "server" => Some(Self::Server),
+            "room" => Some(Self::Room),
+            "instrument" => Some(Self::Instrument),
+            _ => None,
+        }
+    }
+
+    /// 設定にあるリソース名を探す（大文字・小文字を区別せず、表記は設定に合わせる）
+    pub fn resolve_name(self, config: &ResourceConfig, name: &str) -> Option<String> {
+        let mut names: Box<dyn Iterator<Item = &String>> = match self {
+            Self::Server => Box::new(config.servers.iter().map(|server| &server.name)),
+            Self::Room => Box::new(config.rooms.iter().map(|room| &room.name)),
+            Self::Instrument => {
+                Box::new(config.instruments.iter().map(|instrument| &instrument.name))
+            }
+        };
+        names
+            .find(|candidate| candidate.eq_ignore_ascii_case(name))
+            .cloned()
+    }
+
+    /// リソースがこの種類の指定した名前のリソースかどうか
+    pub fn matches(self, resource: &Resource, name: &str) -> bool {
+        match (self, resource) {
+            (Self::Server, Resource::Gpu(gpu)) => gpu.server() == name,
+            (Self::Room, Resource::Room { name: room }) => room == name,
+            (Self::Instrument, Resource::Instrument { name: instrument }) => instrument == name,
+            _ => false,
+        }
+    }
+}
+
+/// パスのファイル名部分（`thalys.ics`）から名前を取り出す
+pub fn feed_name(file: &str) -> Option<&str> {
+    file.strip_suffix(FEED_EXTENSION)
+        .filter(|name| !name.is_empty())
+}
+
+/// 閲覧者に応じて非公開の予約の詳細を伏せたフィードを生成する
+pub fn render(
+    usages: &[ResourceUsage],
+    calendar_name: &str,
+    viewer: &EmailAddress,
+    is_admin: bool,
+) -> String {
+    to_icalendar_feed(usages, Utc::now(), calendar_name, |usage| {
+        usage.details_visible_to(Some(viewer), is_admin)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+
+    #[test]
+    fn test_feed_kind_matches_resources() {
+        let gpu = Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string()));
+        let room = Resource::Room {
+            name: "会議室A".to_string(),
+        };
+
+        assert_eq!(FeedKind::parse("server"), Some(FeedKind::Server));
+        assert_eq!(FeedKind::parse("gpu"), None);
+        assert!(FeedKind::Server.matches(&gpu, "Thalys"));
+        assert!(!FeedKind::Server.matches(&gpu, "Freccia"));
+        assert!(!FeedKind::Server.matches(&room, "会議室A"));
+        assert!(FeedKind::Room.matches(&room, "会議室A"));
+
+        assert_eq!(feed_name("thalys.ics"), Some("thalys"));
+        assert_eq!(feed_name(".ics"), None);
+        assert_eq!(feed_name("thalys"), None);
+    }
+}
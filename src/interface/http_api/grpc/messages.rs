@@ -0,0 +1,277 @@
+//! `proto/lab_resource_manager/v1/reservations.proto` のメッセージの変換
+//!
+//! フィールド番号はprotoファイルと一致させること。
+
+use super::wire::{Encoder, fields};
+use crate::application::usecases::AvailabilityReport;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::service::format_resource_item;
+use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, Visibility};
+use crate::interface::http_api::dto::CreateUsageRequest;
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// `GetAvailabilityRequest`
+#[derive(Debug)]
+pub struct AvailabilityQuery {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub resource: Option<String>,
+    pub tag: Option<String>,
+}
+
+/// `CreateUsageRequest` を復号する（HTTP APIと同じリクエストに変換する）
+pub fn decode_create_usage(buf: &[u8]) -> Result<CreateUsageRequest, String> {
+    let mut request = CreateUsageRequest {
+        resources: String::new(),
+        start: String::new(),
+        end: String::new(),
+        notes: None,
+        project: None,
+        experiment_id: None,
+        expected_utilization: None,
+        private: false,
+        priority: None,
+        group: None,
+    };
+    for (field, value) in fields(buf)? {
+        match field {
+            1 => request.resources = value.string()?,
+            2 => request.start = rfc3339(value.timestamp()?),
+            3 => request.end = rfc3339(value.timestamp()?),
+            4 => request.notes = non_empty(value.string()?),
+            5 => request.project = non_empty(value.string()?),
+            6 => request.experiment_id = non_empty(value.string()?),
+            7 => {
+                request.expected_utilization = Some(
+                    u8::try_from(value.varint()?)
+                        .map_err(|_| "expected_utilization は0〜100で指定してください")?,
+                )
+            }
+            8 => request.private = value.varint()? != 0,
+            9 => request.priority = non_empty(value.string()?),
+            10 => request.group = non_empty(value.string()?),
+            _ => {}
+        }
+    }
+    for (name, value) in [
+        ("resources", &request.resources),
+        ("start", &request.start),
+        ("end", &request.end),
+    ] {
+        if value.is_empty() {
+            return Err(format!("{} を指定してください", name));
+        }
+    }
+    Ok(request)
+}
+
+/// `ListUsagesRequest` を復号し、絞り込む利用者のメールアドレスを返す
+pub fn decode_list_usages(buf: &[u8]) -> Result<Option<String>, String> {
+    let mut owner_email = None;
+    for (field, value) in fields(buf)? {
+        if field == 1 {
+            owner_email = non_empty(value.string()?);
+        }
+    }
+    Ok(owner_email)
+}
+
+/// `GetAvailabilityRequest` を復号する
+pub fn decode_get_availability(buf: &[u8]) -> Result<AvailabilityQuery, String> {
+    let mut query = AvailabilityQuery {
+        start: None,
+        end: None,
+        resource: None,
+        tag: None,
+    };
+    for (field, value) in fields(buf)? {
+        match field {
+            1 => query.start = Some(value.timestamp()?),
+            2 => query.end = Some(value.timestamp()?),
+            3 => query.resource = non_empty(value.string()?),
+            4 => query.tag = non_empty(value.string()?),
+            _ => {}
+        }
+    }
+    Ok(query)
+}
+
+/// `CancelUsageRequest` を復号し、予約IDを返す
+pub fn decode_cancel_usage(buf: &[u8]) -> Result<String, String> {
+    let mut id = None;
+    for (field, value) in fields(buf)? {
+        if field == 1 {
+            id = non_empty(value.string()?);
+        }
+    }
+    id.ok_or_else(|| "id を指定してください".to_string())
+}
+
+/// `Usage` を符号化する
+///
+/// `show_details` が `false` の場合は、予約者・備考・メタデータを書き出さない。
+pub fn encode_usage(usage: &ResourceUsage, show_details: bool) -> Encoder {
+    let metadata = usage.metadata();
+    let mut message = Encoder::new();
+    message
+        .string(1, usage.id().as_str())
+        .timestamp(3, usage.time_period().start())
+        .timestamp(4, usage.time_period().end());
+    for resource in usage.resources() {
+        message.string(5, &format_resource_item(resource));
+    }
+    if show_details {
+        message
+            .string(2, usage.owner_email().as_str())
+            .string(6, usage.notes().map(String::as_str).unwrap_or_default())
+            .string(7, metadata.project().unwrap_or_default())
+            .string(8, metadata.experiment_id().unwrap_or_default())
+            .optional_uint32(9, metadata.expected_utilization().map(u32::from));
+    }
+    message
+        .bool(10, usage.visibility() == Visibility::Private)
+        .bool(11, !show_details)
+        .bool(12, usage.approval_status().is_pending())
+        .string(13, usage.priority().as_str())
+        .string(
+            14,
+            usage
+                .group()
+                .map(|group| group.as_str())
+                .unwrap_or_default(),
+        );
+    message
+}
+
+/// `ListUsagesResponse` を符号化する
+pub fn encode_list_usages(
+    usages: &[ResourceUsage],
+    show_details: impl Fn(&ResourceUsage) -> bool,
+) -> Vec<u8> {
+    let mut response = Encoder::new();
+    for usage in usages {
+        response.message(1, &encode_usage(usage, show_details(usage)));
+    }
+    response.into_bytes()
+}
+
+/// `GetAvailabilityResponse` を符号化する
+pub fn encode_availability(report: &AvailabilityReport) -> Vec<u8> {
+    let mut response = Encoder::new();
+    for availability in report.resources() {
+        let mut resource = Encoder::new();
+        resource
+            .string(1, &format_resource_item(availability.resource()))
+            .bool(2, availability.is_free());
+        for period in availability.busy_periods() {
+            resource.message(3, &encode_period(period));
+        }
+        for period in availability.free_periods() {
+            resource.message(4, &encode_period(period));
+        }
+        response.message(1, &resource);
+    }
+    response.into_bytes()
+}
+
+fn encode_period(period: &TimePeriod) -> Encoder {
+    let mut message = Encoder::new();
+    message
+        .timestamp(1, period.start())
+        .timestamp(2, period.end());
+    message
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.trim().is_empty()).then_some(value)
+}
+
+fn rfc3339(datetime: DateTime<Utc>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{
+        Gpu, ReservationMetadata, Resource,
+    };
+    use crate::domain::common::EmailAddress;
+    use crate::interface::http_api::grpc::wire::Value;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_decode_create_usage() {
+        let start = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let mut message = Encoder::new();
+        message
+            .string(1, "Thalys:0-1")
+            .timestamp(2, start)
+            .timestamp(3, start + chrono::Duration::hours(3))
+            .string(5, "LLM")
+            .optional_uint32(7, Some(80))
+            .bool(8, true);
+
+        let request = decode_create_usage(&message.into_bytes()).unwrap();
+        assert_eq!(request.resources, "Thalys:0-1");
+        assert_eq!(request.start, "2025-04-01T00:00:00Z");
+        assert_eq!(request.end, "2025-04-01T03:00:00Z");
+        assert_eq!(request.project.as_deref(), Some("LLM"));
+        assert_eq!(request.expected_utilization, Some(80));
+        assert!(request.private);
+        assert!(request.notes.is_none());
+
+        let mut missing = Encoder::new();
+        missing.string(1, "Thalys:0");
+        assert!(decode_create_usage(&missing.into_bytes()).is_err());
+    }
+
+    fn field<'a>(fields: &[(u32, Value<'a>)], number: u32) -> Option<Value<'a>> {
+        fields
+            .iter()
+            .find(|(field, _)| *field == number)
+            .map(|(_, value)| *value)
+    }
+
+    #[test]
+    fn test_encode_usage_redacts_details() {
+        let usage = ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 1, 3, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                0,
+                "A100".to_string(),
+            ))],
+            Some("実験".to_string()),
+        )
+        .unwrap()
+        .with_metadata(ReservationMetadata::new(Some("LLM".to_string()), None, Some(80)).unwrap())
+        .with_visibility(Visibility::Private);
+
+        let shown = encode_usage(&usage, true).into_bytes();
+        let shown = fields(&shown).unwrap();
+        assert_eq!(
+            field(&shown, 2).unwrap().string().unwrap(),
+            "alice@example.com"
+        );
+        assert_eq!(
+            field(&shown, 5).unwrap().string().unwrap(),
+            "Thalys / A100 / GPU:0"
+        );
+        assert_eq!(field(&shown, 9), Some(Value::Varint(80)));
+        assert!(field(&shown, 11).is_none());
+
+        let hidden = encode_usage(&usage, false).into_bytes();
+        let hidden = fields(&hidden).unwrap();
+        assert!(field(&hidden, 2).is_none());
+        assert!(field(&hidden, 6).is_none());
+        assert!(field(&hidden, 7).is_none());
+        assert_eq!(field(&hidden, 10), Some(Value::Varint(1)));
+        assert_eq!(field(&hidden, 11), Some(Value::Varint(1)));
+    }
+}
@@ -0,0 +1,351 @@
+//! gRPCサービス（`lab_resource_manager.v1.ReservationService`）
+//!
+//! サービスの定義は `proto/lab_resource_manager/v1/reservations.proto` にある。HTTP APIと同じポートで、
+//! `content-type: application/grpc` のHTTP/2リクエストとして受け付け、HTTP APIと同じトークン・
+//! ユースケース・認可の規則で処理する。圧縮したメッセージとストリーミングには対応しない。
+//!
+//! tonic・prostは使わず、メッセージの符号化（[`wire`]）とフレームの読み書きをこのモジュールで行っている。
+//! 単項RPCが4つだけで、HTTP APIと同じhyperのサーバーで受け付けるため、コード生成のビルド手順
+//! （protoc）を持ち込まないことを優先した。protoファイルを変更した場合は [`messages`] のフィールド番号も
+//! 合わせて変更すること。protocと同じ規則で符号化したメッセージをHTTP/2で送るテストで互換性を確認している。
+
+mod messages;
+mod wire;
+
+use super::{ApiError, ApiResponse, ApiServer, MAX_BODY_BYTES};
+use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, UsageId};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{CONTENT_TYPE, HeaderMap, HeaderValue};
+use hyper::{Request, Response, StatusCode};
+use tracing::{error, info};
+
+/// gRPCのContent-Type
+const GRPC_CONTENT_TYPE: &str = "application/grpc";
+
+/// サービスのパスの接頭辞
+const SERVICE_PATH: &str = "/lab_resource_manager.v1.ReservationService/";
+
+/// gRPCのステータスコード
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Code {
+    Ok = 0,
+    InvalidArgument = 3,
+    NotFound = 5,
+    PermissionDenied = 7,
+    ResourceExhausted = 8,
+    FailedPrecondition = 9,
+    Unimplemented = 12,
+    Internal = 13,
+    Unauthenticated = 16,
+}
+
+/// gRPCのエラー（ステータスコードとメッセージ）
+#[derive(Debug)]
+struct Status {
+    code: Code,
+    message: String,
+}
+
+impl Status {
+    fn new(code: Code, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn invalid_argument(message: impl Into<String>) -> Self {
+        Self::new(Code::InvalidArgument, message)
+    }
+}
+
+impl From<ApiError> for Status {
+    fn from(e: ApiError) -> Self {
+        let code = match e.status {
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Code::InvalidArgument,
+            StatusCode::UNAUTHORIZED => Code::Unauthenticated,
+            StatusCode::FORBIDDEN => Code::PermissionDenied,
+            StatusCode::NOT_FOUND => Code::NotFound,
+            StatusCode::CONFLICT => Code::FailedPrecondition,
+            StatusCode::PAYLOAD_TOO_LARGE => Code::ResourceExhausted,
+            _ => Code::Internal,
+        };
+        Self::new(code, e.message)
+    }
+}
+
+/// gRPCのリクエストかどうか（`application/grpc` と `application/grpc+proto`）
+pub(super) fn is_grpc<B>(req: &Request<B>) -> bool {
+    req.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with(GRPC_CONTENT_TYPE))
+}
+
+impl<R: ResourceUsageRepository + Send + Sync + 'static> ApiServer<R> {
+    /// gRPCのリクエストを処理する
+    pub(super) async fn handle_grpc(&self, req: Request<Incoming>) -> ApiResponse {
+        let path = req.uri().path().to_string();
+        match self.dispatch_grpc(req).await {
+            Ok(message) => grpc_response(message),
+            Err(status) => {
+                if status.code == Code::Internal {
+                    error!("❌ gRPCのエラー: {}: {}", path, status.message);
+                }
+                status_response(&status)
+            }
+        }
+    }
+
+    async fn dispatch_grpc(&self, req: Request<Incoming>) -> Result<Vec<u8>, Status> {
+        let method = req
+            .uri()
+            .path()
+            .strip_prefix(SERVICE_PATH)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                Status::new(
+                    Code::Unimplemented,
+                    format!("未対応のサービスです: {}", req.uri().path()),
+                )
+            })?;
+        let actor = self.authenticate(&req, None).await?;
+        let is_admin = self.resource_config.is_admin(&actor);
+        let message = read_message(req).await?;
+
+        match method.as_str() {
+            "CreateUsage" => {
+                let request =
+                    messages::decode_create_usage(&message).map_err(Status::invalid_argument)?;
+                let usage = self.create(&actor, request).await?;
+                Ok(messages::encode_usage(&usage, true).into_bytes())
+            }
+            "ListUsages" => {
+                let usages = match messages::decode_list_usages(&message)
+                    .map_err(Status::invalid_argument)?
+                {
+                    Some(owner_email) => {
+                        let owner_email = EmailAddress::new(owner_email)
+                            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+                        self.list_user_usecase
+                            .execute(&owner_email)
+                            .await
+                            .map_err(ApiError::from)?
+                    }
+                    None => self.list_usecase.execute().await.map_err(ApiError::from)?,
+                };
+                Ok(messages::encode_list_usages(&usages, |usage| {
+                    usage.details_visible_to(Some(&actor), is_admin)
+                }))
+            }
+            "GetAvailability" => {
+                let query = messages::decode_get_availability(&message)
+                    .map_err(Status::invalid_argument)?;
+                let (Some(start), Some(end)) = (query.start, query.end) else {
+                    return Err(Status::invalid_argument("start と end を指定してください"));
+                };
+                let period = TimePeriod::new(start, end)
+                    .map_err(|e| Status::invalid_argument(e.to_string()))?;
+                let report = self
+                    .availability_usecase
+                    .execute(&period, query.resource.as_deref(), query.tag.as_deref())
+                    .await
+                    .map_err(ApiError::from)?;
+                Ok(messages::encode_availability(&report))
+            }
+            "CancelUsage" => {
+                let id =
+                    messages::decode_cancel_usage(&message).map_err(Status::invalid_argument)?;
+                self.delete_usecase
+                    .execute(&UsageId::from_string(id.clone()), &actor)
+                    .await
+                    .map_err(ApiError::from)?;
+                info!("🗑️ gRPCで予約を取り消しました: {} ({})", id, actor.as_str());
+                Ok(Vec::new())
+            }
+            _ => Err(Status::new(
+                Code::Unimplemented,
+                format!("未対応のメソッドです: {}", method),
+            )),
+        }
+    }
+}
+
+/// リクエストのボディから1件のメッセージを取り出す
+///
+/// gRPCのメッセージは、圧縮の有無（1バイト）と長さ（4バイト、ビッグエンディアン）に続けて送られる。
+async fn read_message(req: Request<Incoming>) -> Result<Vec<u8>, Status> {
+    let body = Limited::new(req.into_body(), MAX_BODY_BYTES)
+        .collect()
+        .await
+        .map_err(|_| {
+            Status::new(
+                Code::ResourceExhausted,
+                format!("リクエストが大きすぎます（上限 {} バイト）", MAX_BODY_BYTES),
+            )
+        })?
+        .to_bytes();
+    decode_frame(&body)
+}
+
+fn decode_frame(body: &[u8]) -> Result<Vec<u8>, Status> {
+    let Some((&[compressed, a, b, c, d], message)) = body.split_first_chunk::<5>() else {
+        return Err(Status::invalid_argument("メッセージがありません"));
+    };
+    if compressed != 0 {
+        return Err(Status::new(
+            Code::Unimplemented,
+            "圧縮したメッセージには対応していません",
+        ));
+    }
+    let len = u32::from_be_bytes([a, b, c, d]) as usize;
+    if message.len() != len {
+        return Err(Status::invalid_argument(
+            "メッセージの長さが一致しません（ストリーミングには対応していません）",
+        ));
+    }
+    Ok(message.to_vec())
+}
+
+fn encode_frame(message: &[u8]) -> Bytes {
+    let mut frame = Vec::with_capacity(5 + message.len());
+    frame.push(0);
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(message);
+    Bytes::from(frame)
+}
+
+/// 成功時のレスポンス（メッセージに続けて、トレーラーで `grpc-status: 0` を送る）
+fn grpc_response(message: Vec<u8>) -> ApiResponse {
+    let mut trailers = HeaderMap::new();
+    trailers.insert("grpc-status", HeaderValue::from(Code::Ok as i32));
+    let body = Full::new(encode_frame(&message))
+        .with_trailers(std::future::ready(Some(Ok(trailers))))
+        .boxed();
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, GRPC_CONTENT_TYPE)
+        .body(body)
+        .expect("valid response")
+}
+
+/// エラー時のレスポンス（ボディを持たず、ヘッダーにステータスを入れる）
+fn status_response(status: &Status) -> ApiResponse {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, GRPC_CONTENT_TYPE)
+        .header("grpc-status", status.code as i32)
+        .header("grpc-message", percent_encode(&status.message))
+        .body(Full::new(Bytes::new()).boxed())
+        .expect("valid response")
+}
+
+/// `grpc-message` のパーセントエンコード（ASCIIの表示可能文字以外と `%` を符号化する）
+fn percent_encode(message: &str) -> String {
+    message
+        .bytes()
+        .map(|byte| match byte {
+            b' '..=b'~' if byte != b'%' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::http_api::tests::spawn_server;
+    use hyper_util::rt::{TokioExecutor, TokioIo};
+    use std::net::SocketAddr;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// HTTP/2（TLSなし）でメソッドを呼び出し、ヘッダー・メッセージ・トレーラーを返す
+    async fn call(
+        addr: SocketAddr,
+        token: &str,
+        method: &str,
+        message: &[u8],
+    ) -> (HeaderMap, Bytes, Option<HeaderMap>) {
+        let stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let (mut sender, connection) =
+            hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream))
+                .await
+                .unwrap();
+        tokio::spawn(connection);
+        let request = Request::post(format!("http://{}{}{}", addr, SERVICE_PATH, method))
+            .header(CONTENT_TYPE, "application/grpc+proto")
+            .header("te", "trailers")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Full::new(encode_frame(message)))
+            .unwrap();
+        let response = sender.send_request(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers().clone();
+        let body = response.into_body().collect().await.unwrap();
+        let trailers = body.trailers().cloned();
+        (headers, body.to_bytes(), trailers)
+    }
+
+    #[tokio::test]
+    async fn test_serves_protobuf_encoded_calls_over_http2() {
+        let (addr, token) = spawn_server().await;
+
+        // protocと同じ規則で符号化した GetAvailabilityRequest
+        // {start: 2026-01-01T00:00:00Z, end: 2026-01-01T01:00:00Z, resource: "会議室A"}
+        let request = hex("0a060880f2d6ca06120608908ed7ca061a0ae4bc9ae8adb0e5aea441");
+        let (headers, body, trailers) = call(addr, &token, "GetAvailability", &request).await;
+
+        assert_eq!(headers[CONTENT_TYPE], GRPC_CONTENT_TYPE);
+        assert_eq!(trailers.unwrap()["grpc-status"], "0");
+        // GetAvailabilityResponse {resources: [{resource: "会議室A", free: true, free_periods: [期間全体]}]}
+        assert_eq!(
+            decode_frame(&body).unwrap(),
+            hex("0a200a0ae4bc9ae8adb0e5aea441100122100a060880f2d6ca06120608908ed7ca06")
+        );
+
+        // CancelUsageRequest {id: "missing"}
+        let (headers, body, _) = call(addr, &token, "CancelUsage", b"\x0a\x07missing").await;
+        assert_eq!(headers["grpc-status"], "5");
+        assert!(body.is_empty());
+
+        let (headers, _, _) = call(addr, "lrm_invalid", "ListUsages", b"").await;
+        assert_eq!(headers["grpc-status"], "16");
+    }
+
+    #[test]
+    fn test_frame_round_trip() {
+        let frame = encode_frame(b"\x0a\x01a");
+        assert_eq!(&frame[..5], &[0, 0, 0, 0, 3]);
+        assert_eq!(decode_frame(&frame).unwrap(), b"\x0a\x01a");
+
+        assert_eq!(
+            decode_frame(&[1, 0, 0, 0, 0]).unwrap_err().code,
+            Code::Unimplemented
+        );
+        assert_eq!(
+            decode_frame(&[0, 0, 0, 0, 2, 0]).unwrap_err().code,
+            Code::InvalidArgument
+        );
+        assert_eq!(decode_frame(&[0]).unwrap_err().code, Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_status_from_api_error() {
+        let status = |http: StatusCode| Status::from(ApiError::new(http, "")).code;
+        assert_eq!(status(StatusCode::NOT_FOUND), Code::NotFound);
+        assert_eq!(status(StatusCode::CONFLICT), Code::FailedPrecondition);
+        assert_eq!(status(StatusCode::UNAUTHORIZED), Code::Unauthenticated);
+        assert_eq!(status(StatusCode::INTERNAL_SERVER_ERROR), Code::Internal);
+        assert_eq!(percent_encode("予約 100%"), "%E4%BA%88%E7%B4%84 100%25");
+    }
+}
@@ -0,0 +1,235 @@
+//! Protocol Buffersのワイヤー形式の最小限の符号化・復号
+//!
+//! サービスで使う型（varint・文字列・入れ子のメッセージ）のみを扱う。
+//! 知らないフィールドは読み飛ばし、古いクライアントや新しいクライアントとも通信できるようにする。
+
+use chrono::{DateTime, Utc};
+
+/// varintのワイヤータイプ
+const WIRE_VARINT: u8 = 0;
+/// 64ビット固定長のワイヤータイプ
+const WIRE_FIXED64: u8 = 1;
+/// 長さ付きのワイヤータイプ（文字列・バイト列・メッセージ）
+const WIRE_LEN: u8 = 2;
+/// 32ビット固定長のワイヤータイプ
+const WIRE_FIXED32: u8 = 5;
+
+/// メッセージの符号化
+#[derive(Debug, Default)]
+pub struct Encoder {
+    buf: Vec<u8>,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 文字列のフィールド（proto3の既定値である空文字列は書き出さない）
+    pub fn string(&mut self, field: u32, value: &str) -> &mut Self {
+        if !value.is_empty() {
+            self.len_delimited(field, value.as_bytes());
+        }
+        self
+    }
+
+    /// boolのフィールド（falseは書き出さない）
+    pub fn bool(&mut self, field: u32, value: bool) -> &mut Self {
+        if value {
+            self.key(field, WIRE_VARINT);
+            self.varint(1);
+        }
+        self
+    }
+
+    /// int64のフィールド（0は書き出さない）
+    pub fn int64(&mut self, field: u32, value: i64) -> &mut Self {
+        if value != 0 {
+            self.key(field, WIRE_VARINT);
+            self.varint(value as u64);
+        }
+        self
+    }
+
+    /// `optional` のuint32のフィールド（指定した場合は0も書き出す）
+    pub fn optional_uint32(&mut self, field: u32, value: Option<u32>) -> &mut Self {
+        if let Some(value) = value {
+            self.key(field, WIRE_VARINT);
+            self.varint(u64::from(value));
+        }
+        self
+    }
+
+    /// 入れ子のメッセージのフィールド
+    pub fn message(&mut self, field: u32, message: &Encoder) -> &mut Self {
+        self.len_delimited(field, &message.buf);
+        self
+    }
+
+    /// `google.protobuf.Timestamp` のフィールド
+    pub fn timestamp(&mut self, field: u32, value: DateTime<Utc>) -> &mut Self {
+        let mut timestamp = Encoder::new();
+        timestamp
+            .int64(1, value.timestamp())
+            .int64(2, i64::from(value.timestamp_subsec_nanos()));
+        self.message(field, &timestamp)
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.varint((u64::from(field) << 3) | u64::from(wire_type));
+    }
+
+    fn len_delimited(&mut self, field: u32, bytes: &[u8]) {
+        self.key(field, WIRE_LEN);
+        self.varint(bytes.len() as u64);
+        self.buf.extend_from_slice(bytes);
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+}
+
+/// 復号したフィールドの値
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+impl<'a> Value<'a> {
+    /// 文字列として読む
+    pub fn string(self) -> Result<String, String> {
+        match self {
+            Value::Bytes(bytes) => std::str::from_utf8(bytes)
+                .map(str::to_string)
+                .map_err(|_| "文字列がUTF-8ではありません".to_string()),
+            Value::Varint(_) => Err("文字列のフィールドの型が一致しません".to_string()),
+        }
+    }
+
+    /// 入れ子のメッセージとして読む
+    pub fn message(self) -> Result<&'a [u8], String> {
+        match self {
+            Value::Bytes(bytes) => Ok(bytes),
+            Value::Varint(_) => Err("メッセージのフィールドの型が一致しません".to_string()),
+        }
+    }
+
+    /// varintとして読む
+    pub fn varint(self) -> Result<u64, String> {
+        match self {
+            Value::Varint(value) => Ok(value),
+            Value::Bytes(_) => Err("数値のフィールドの型が一致しません".to_string()),
+        }
+    }
+
+    /// `google.protobuf.Timestamp` として読む
+    pub fn timestamp(self) -> Result<DateTime<Utc>, String> {
+        let (mut seconds, mut nanos) = (0i64, 0u32);
+        for (field, value) in fields(self.message()?)? {
+            match field {
+                1 => seconds = value.varint()? as i64,
+                2 => nanos = value.varint()? as u32,
+                _ => {}
+            }
+        }
+        DateTime::from_timestamp(seconds, nanos).ok_or_else(|| "時刻が範囲外です".to_string())
+    }
+}
+
+/// メッセージをフィールド番号と値の組に分ける
+///
+/// 固定長のフィールドは使わないため読み飛ばす。
+pub fn fields(mut buf: &[u8]) -> Result<Vec<(u32, Value<'_>)>, String> {
+    let mut fields = Vec::new();
+    while !buf.is_empty() {
+        let key = read_varint(&mut buf)?;
+        let field = u32::try_from(key >> 3).map_err(|_| "フィールド番号が不正です".to_string())?;
+        match (key & 0x7) as u8 {
+            WIRE_VARINT => fields.push((field, Value::Varint(read_varint(&mut buf)?))),
+            WIRE_LEN => {
+                let len = usize::try_from(read_varint(&mut buf)?)
+                    .map_err(|_| "長さが不正です".to_string())?;
+                if buf.len() < len {
+                    return Err("メッセージが途中で終わっています".to_string());
+                }
+                let (bytes, rest) = buf.split_at(len);
+                fields.push((field, Value::Bytes(bytes)));
+                buf = rest;
+            }
+            WIRE_FIXED64 => buf = skip(buf, 8)?,
+            WIRE_FIXED32 => buf = skip(buf, 4)?,
+            wire_type => return Err(format!("未対応のワイヤータイプです: {}", wire_type)),
+        }
+    }
+    Ok(fields)
+}
+
+fn read_varint(buf: &mut &[u8]) -> Result<u64, String> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = buf
+            .split_first()
+            .ok_or_else(|| "メッセージが途中で終わっています".to_string())?;
+        *buf = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            return Ok(value);
+        }
+    }
+    Err("varintが長すぎます".to_string())
+}
+
+fn skip(buf: &[u8], len: usize) -> Result<&[u8], String> {
+    buf.get(len..)
+        .ok_or_else(|| "メッセージが途中で終わっています".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_round_trip() {
+        let start = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let mut encoder = Encoder::new();
+        encoder
+            .string(1, "Thalys:0-1")
+            .string(2, "")
+            .timestamp(3, start)
+            .bool(4, true)
+            .optional_uint32(5, Some(0))
+            .int64(6, -1);
+        let bytes = encoder.into_bytes();
+
+        let fields = fields(&bytes).unwrap();
+        assert_eq!(fields.len(), 5);
+        assert_eq!(fields[0].0, 1);
+        assert_eq!(fields[0].1.string().unwrap(), "Thalys:0-1");
+        assert_eq!(fields[1].1.timestamp().unwrap(), start);
+        assert_eq!(fields[2].1.varint().unwrap(), 1);
+        assert_eq!(fields[3], (5, Value::Varint(0)));
+        assert_eq!(fields[4].1.varint().unwrap() as i64, -1);
+    }
+
+    #[test]
+    fn test_fields_skips_fixed_and_rejects_truncated() {
+        // フィールド1: fixed64、フィールド2: 文字列 "a"
+        let bytes = [0x09, 0, 0, 0, 0, 0, 0, 0, 0, 0x12, 0x01, b'a'];
+        let fields = fields(&bytes).unwrap();
+        assert_eq!(fields, vec![(2, Value::Bytes(b"a"))]);
+
+        assert!(super::fields(&[0x12, 0x05, b'a']).is_err());
+        assert!(super::fields(&[0x08]).is_err());
+    }
+}
@@ -0,0 +1,985 @@
+//! # HTTP API
+//!
+//! Slackを使わずに予約を操作するためのHTTP API（研究室内のスクリプトやWeb画面向け）。
+//! Slackと同じユースケースを使うため、競合・上限・受付期間の確認や、予約者本人・管理者だけが
+//! 更新・削除できるといった認可の規則も同じになる。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/usages`: 今後の予約の一覧
+//! - `POST /api/v1/usages`: 予約の作成（予約者はトークンの持ち主）
+//! - `GET /api/v1/usages/{id}`: 予約の取得
+//! - `PATCH /api/v1/usages/{id}`: 予約の更新（指定した項目だけを変更する）
+//! - `DELETE /api/v1/usages/{id}`: 予約の削除
+//! - `GET /api/v1/availability?start=...&end=...[&resource=...][&tag=...]`: 期間内の空き状況
+//! - `GET /api/v1/users/{email}/usages`: 利用者の予約の一覧
+//! - `GET /api/v1/events`: 予約の変更のイベントストリーム（[`events`] を参照）
+//! - `GET /feeds/{server|room|instrument|user}/{名前}.ics`: iCalendarフィード（[`feeds`] を参照）
+//! - `GET /dashboard`: 今後7日間の予約のダッシュボード（[`dashboard`] を参照）
+//!
+//! 同じポートでgRPCのサービスも提供する（[`grpc`] を参照）。
+//!
+//! すべてのリクエストに `Authorization: Bearer <トークン>` が必要（[`auth`] を参照）。
+//! ブラウザの `EventSource` やカレンダーアプリはヘッダーを指定できないため、イベントストリーム・
+//! フィード・ダッシュボードに限り `?access_token=<トークン>` でも受け付ける。
+//! 非公開の予約は、予約者本人と管理者以外には予約者・備考・メタデータを返さない。
+//!
+//! ## 実装について
+//!
+//! axumなどのWebフレームワークは使わず、hyperの上でパスを [`Route`] に振り分けている。
+//! エンドポイントが少なく、同じポートでgRPC（HTTP/2）も受け付けるため、依存するクレートを
+//! 増やさないことを優先した。一般的なHTTPクライアントから使えることは、サーバーを起動して
+//! reqwestでリクエストするテストで確認している。
+
+pub mod auth;
+mod dashboard;
+pub mod dto;
+pub mod events;
+mod feeds;
+mod grpc;
+
+use crate::application::error::ApplicationError;
+use crate::application::usecases::{
+    CreateResourceUsageUseCase, DeleteResourceUsageUseCase, GetResourceAvailabilityUseCase,
+    GetResourceUsageByIdUseCase, ListAllFutureResourceUsagesUseCase, ListUserResourceUsagesUseCase,
+    UpdateResourceUsageUseCase,
+};
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    ReservationMetadata, TimePeriod, UsageId, Visibility,
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::NotificationEvent;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::config::{HttpApiConfig, ResourceConfig};
+use auth::ApiTokens;
+use chrono::{Local, Utc};
+use chrono_tz::Tz;
+use dto::{
+    AvailabilityBody, CreateUsageRequest, ErrorBody, UpdateUsageRequest, UsageBody, parse_time,
+};
+use feeds::FeedKind;
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tracing::{error, info};
+
+/// APIのパスの接頭辞
+pub const API_PREFIX: &str = "/api/v1";
+
+/// 受け付けるリクエストボディの上限（バイト）
+const MAX_BODY_BYTES: usize = 64 * 1024;
+
+type ApiResponse = Response<BoxBody<Bytes, Infallible>>;
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// 予約を操作するHTTP APIのサーバー
+pub struct ApiServer<R: ResourceUsageRepository> {
+    config: HttpApiConfig,
+    tokens_file: PathBuf,
+    resource_config: Arc<ResourceConfig>,
+    create_usecase: Arc<CreateResourceUsageUseCase<R>>,
+    update_usecase: Arc<UpdateResourceUsageUseCase<R>>,
+    delete_usecase: Arc<DeleteResourceUsageUseCase<R>>,
+    get_usage_usecase: Arc<GetResourceUsageByIdUseCase<R>>,
+    availability_usecase: Arc<GetResourceAvailabilityUseCase<R>>,
+    list_usecase: Arc<ListAllFutureResourceUsagesUseCase<R>>,
+    list_user_usecase: Arc<ListUserResourceUsagesUseCase<R>>,
+    event_stream: Option<broadcast::Sender<NotificationEvent>>,
+}
+
+impl<R> ApiServer<R>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+{
+    /// 新しいApiServerを作成
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        config: HttpApiConfig,
+        tokens_file: PathBuf,
+        resource_config: Arc<ResourceConfig>,
+        create_usecase: Arc<CreateResourceUsageUseCase<R>>,
+        update_usecase: Arc<UpdateResourceUsageUseCase<R>>,
+        delete_usecase: Arc<DeleteResourceUsageUseCase<R>>,
+        get_usage_usecase: Arc<GetResourceUsageByIdUseCase<R>>,
+        availability_usecase: Arc<GetResourceAvailabilityUseCase<R>>,
+        list_usecase: Arc<ListAllFutureResourceUsagesUseCase<R>>,
+        list_user_usecase: Arc<ListUserResourceUsagesUseCase<R>>,
+    ) -> Self {
+        Self {
+            config,
+            tokens_file,
+            resource_config,
+            create_usecase,
+            update_usecase,
+            delete_usecase,
+            get_usage_usecase,
+            availability_usecase,
+            list_usecase,
+            list_user_usecase,
+            event_stream: None,
+        }
+    }
+
+    /// 予約の変更を配信するチャンネルを設定
+    ///
+    /// 設定した場合、`/api/v1/events` でイベントストリームを提供する。
+    pub fn with_event_stream(mut self, event_stream: broadcast::Sender<NotificationEvent>) -> Self {
+        self.event_stream = Some(event_stream);
+        self
+    }
+
+    /// 待ち受けアドレス
+    pub fn listen_addr(&self) -> &str {
+        &self.config.listen_addr
+    }
+
+    /// APIサーバーを実行
+    ///
+    /// 待ち受けに失敗した場合のみ終了する。
+    pub async fn serve(self: Arc<Self>) -> Result<(), BoxError> {
+        let listener = TcpListener::bind(&self.config.listen_addr).await?;
+        info!(
+            "🌐 HTTP APIを起動しました: {}{}",
+            self.config.listen_addr, API_PREFIX
+        );
+        self.accept(listener).await
+    }
+
+    /// 待ち受けたソケットで接続を受け付ける
+    async fn accept(self: Arc<Self>, listener: TcpListener) -> Result<(), BoxError> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |req| {
+                    let server = server.clone();
+                    async move { Ok::<_, Infallible>(server.handle(req).await) }
+                });
+                // gRPCのためにHTTP/2（TLSなし）も受け付ける
+                if let Err(e) = auto::Builder::new(TokioExecutor::new())
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    error!("❌ HTTP APIの接続のエラー: {}", e);
+                }
+            });
+        }
+    }
+
+    async fn handle(&self, req: Request<Incoming>) -> ApiResponse {
+        if grpc::is_grpc(&req) {
+            return self.handle_grpc(req).await;
+        }
+        let method = req.method().clone();
+        let path = req.uri().path().to_string();
+        match self.dispatch(req).await {
+            Ok(response) => response,
+            Err(e) => {
+                if e.status.is_server_error() {
+                    error!("❌ HTTP APIのエラー: {} {}: {}", method, path, e.message);
+                }
+                json_response(e.status, &ErrorBody { error: &e.message })
+            }
+        }
+    }
+
+    async fn dispatch(&self, req: Request<Incoming>) -> Result<ApiResponse, ApiError> {
+        let route = Route::parse(req.method(), req.uri().path())?;
+        let query = query_params(req.uri().query().unwrap_or_default());
+        let query_token = match route {
+            Route::Events | Route::ResourceFeed(..) | Route::UserFeed(_) | Route::Dashboard => {
+                query.get("access_token").map(String::as_str)
+            }
+            _ => None,
+        };
+        let actor = self.authenticate(&req, query_token).await?;
+        let is_admin = self.resource_config.is_admin(&actor);
+
+        match route {
+            Route::ListUsages => {
+                let usages = self.list_usecase.execute().await?;
+                Ok(json_response(
+                    StatusCode::OK,
+                    &usage_bodies(&usages, &actor, is_admin),
+                ))
+            }
+            Route::CreateUsage => {
+                let request: CreateUsageRequest = read_json(req).await?;
+                let usage = self.create(&actor, request).await?;
+                Ok(json_response(
+                    StatusCode::CREATED,
+                    &UsageBody::new(&usage, true),
+                ))
+            }
+            Route::GetUsage(id) => {
+                let usage = self
+                    .get_usage_usecase
+                    .execute(&UsageId::from_string(id))
+                    .await?;
+                let show_details = usage.details_visible_to(Some(&actor), is_admin);
+                Ok(json_response(
+                    StatusCode::OK,
+                    &UsageBody::new(&usage, show_details),
+                ))
+            }
+            Route::UpdateUsage(id) => {
+                let request: UpdateUsageRequest = read_json(req).await?;
+                let usage = self
+                    .update(&UsageId::from_string(id), &actor, request)
+                    .await?;
+                let show_details = usage.details_visible_to(Some(&actor), is_admin);
+                Ok(json_response(
+                    StatusCode::OK,
+                    &UsageBody::new(&usage, show_details),
+                ))
+            }
+            Route::DeleteUsage(id) => {
+                self.delete_usecase
+                    .execute(&UsageId::from_string(id), &actor)
+                    .await?;
+                Ok(Response::builder()
+                    .status(StatusCode::NO_CONTENT)
+                    .body(Full::new(Bytes::new()).boxed())
+                    .expect("valid response"))
+            }
+            Route::Availability => {
+                let param = |name: &'static str| {
+                    query.get(name).ok_or_else(|| {
+                        ApiError::bad_request(format!("{} を指定してください", name))
+                    })
+                };
+                let period = TimePeriod::new(
+                    parse_time("start", param("start")?).map_err(ApiError::bad_request)?,
+                    parse_time("end", param("end")?).map_err(ApiError::bad_request)?,
+                )
+                .map_err(|e| ApiError::bad_request(e.to_string()))?;
+                let report = self
+                    .availability_usecase
+                    .execute(
+                        &period,
+                        query.get("resource").map(String::as_str),
+                        query.get("tag").map(String::as_str),
+                    )
+                    .await?;
+                Ok(json_response(
+                    StatusCode::OK,
+                    &AvailabilityBody::new(&report),
+                ))
+            }
+            Route::UserUsages(email) => {
+                let email =
+                    EmailAddress::new(email).map_err(|e| ApiError::bad_request(e.to_string()))?;
+                let usages = self.list_user_usecase.execute(&email).await?;
+                Ok(json_response(
+                    StatusCode::OK,
+                    &usage_bodies(&usages, &actor, is_admin),
+                ))
+            }
+            Route::Events => {
+                let event_stream = self
+                    .event_stream
+                    .as_ref()
+                    .ok_or_else(|| ApiError::new(StatusCode::NOT_FOUND, "Not Found"))?;
+                let body = events::stream(event_stream.subscribe(), actor, is_admin);
+                Ok(Response::builder()
+                    .status(StatusCode::OK)
+                    .header(hyper::header::CONTENT_TYPE, events::CONTENT_TYPE)
+                    .header(hyper::header::CACHE_CONTROL, "no-cache")
+                    .body(body.boxed())
+                    .expect("valid response"))
+            }
+            Route::ResourceFeed(kind, name) => {
+                let name = kind
+                    .resolve_name(&self.resource_config, &name)
+                    .ok_or_else(|| {
+                        ApiError::new(
+                            StatusCode::NOT_FOUND,
+                            format!("リソースが見つかりません: {}", name),
+                        )
+                    })?;
+                let usages: Vec<ResourceUsage> = self
+                    .list_usecase
+                    .execute()
+                    .await?
+                    .into_iter()
+                    .filter(|usage| {
+                        usage
+                            .resources()
+                            .iter()
+                            .any(|resource| kind.matches(resource, &name))
+                    })
+                    .collect();
+                Ok(calendar_response(feeds::render(
+                    &usages, &name, &actor, is_admin,
+                )))
+            }
+            Route::UserFeed(email) => {
+                let email =
+                    EmailAddress::new(email).map_err(|e| ApiError::bad_request(e.to_string()))?;
+                let usages = self.list_user_usecase.execute(&email).await?;
+                Ok(calendar_response(feeds::render(
+                    &usages,
+                    email.as_str(),
+                    &actor,
+                    is_admin,
+                )))
+            }
+            Route::Dashboard => {
+                let usages = self.list_usecase.execute().await?;
+                let rows = dashboard::DashboardRow::from_config(&self.resource_config);
+                let now = Utc::now();
+                let html = match self
+                    .resource_config
+                    .timezone
+                    .as_deref()
+                    .and_then(|tz| tz.parse::<Tz>().ok())
+                {
+                    Some(tz) => dashboard::render(&rows, &usages, now, &tz, &actor, is_admin),
+                    None => dashboard::render(&rows, &usages, now, &Local, &actor, is_admin),
+                };
+                Ok(Response::builder()
+                    .status(StatusCode::OK)
+                    .header(hyper::header::CONTENT_TYPE, dashboard::CONTENT_TYPE)
+                    .header(hyper::header::CACHE_CONTROL, "no-cache")
+                    .body(Full::new(Bytes::from(html)).boxed())
+                    .expect("valid response"))
+            }
+        }
+    }
+
+    /// `Authorization` ヘッダー（なければクエリ文字列）のトークンから操作する利用者を特定する
+    async fn authenticate(
+        &self,
+        req: &Request<Incoming>,
+        query_token: Option<&str>,
+    ) -> Result<EmailAddress, ApiError> {
+        let token = req
+            .headers()
+            .get(hyper::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(auth::bearer_token)
+            .or(query_token)
+            .ok_or_else(|| {
+                ApiError::unauthorized("Authorization: Bearer <トークン> を指定してください")
+            })?;
+
+        let tokens = ApiTokens::load(&self.tokens_file)
+            .await
+            .map_err(ApiError::internal)?;
+        tokens
+            .owner_of(token)
+            .cloned()
+            .ok_or_else(|| ApiError::unauthorized("トークンが無効です"))
+    }
+
+    /// 予約を作成し、作成した予約を返す
+    async fn create(
+        &self,
+        actor: &EmailAddress,
+        request: CreateUsageRequest,
+    ) -> Result<ResourceUsage, ApiError> {
+        let new_usage = request
+            .into_new_usage(&self.resource_config)
+            .map_err(ApiError::bad_request)?;
+        let usage_id = self
+            .create_usecase
+            .execute(
+                actor.clone(),
+                new_usage.time_period,
+                new_usage.resources,
+                new_usage.notes,
+                new_usage.metadata,
+                new_usage.visibility,
+                new_usage.priority,
+                new_usage.group,
+            )
+            .await?;
+        info!(
+            "✅ HTTP APIで予約を作成しました: {} ({})",
+            usage_id.as_str(),
+            actor.as_str()
+        );
+        Ok(self.get_usage_usecase.execute(&usage_id).await?)
+    }
+
+    /// 予約を更新し、更新後の予約を返す
+    ///
+    /// 開始・終了の片方だけ、メタデータの一部だけを指定した場合は、残りは現在の値のままにする。
+    async fn update(
+        &self,
+        id: &UsageId,
+        actor: &EmailAddress,
+        request: UpdateUsageRequest,
+    ) -> Result<ResourceUsage, ApiError> {
+        let current = self.get_usage_usecase.execute(id).await?;
+
+        let time_period = match (&request.start, &request.end) {
+            (None, None) => None,
+            (start, end) => {
+                let start = match start {
+                    Some(start) => parse_time("start", start).map_err(ApiError::bad_request)?,
+                    None => current.time_period().start(),
+                };
+                let end = match end {
+                    Some(end) => parse_time("end", end).map_err(ApiError::bad_request)?,
+                    None => current.time_period().end(),
+                };
+                Some(
+                    TimePeriod::new(start, end)
+                        .map_err(|e| ApiError::bad_request(e.to_string()))?,
+                )
+            }
+        };
+        let metadata = if request.changes_metadata() {
+            let existing = current.metadata();
+            Some(
+                ReservationMetadata::new(
+                    request
+                        .project
+                        .or_else(|| existing.project().map(str::to_string)),
+                    request
+                        .experiment_id
+                        .or_else(|| existing.experiment_id().map(str::to_string)),
+                    request
+                        .expected_utilization
+                        .or(existing.expected_utilization()),
+                )
+                .map_err(|e| ApiError::bad_request(e.to_string()))?,
+            )
+        } else {
+            None
+        };
+
+        self.update_usecase
+            .execute(
+                id,
+                actor,
+                time_period,
+                request.notes,
+                metadata,
+                request.private.map(visibility),
+            )
+            .await?;
+        info!(
+            "✏️ HTTP APIで予約を更新しました: {} ({})",
+            id.as_str(),
+            actor.as_str()
+        );
+        Ok(self.get_usage_usecase.execute(id).await?)
+    }
+}
+
+fn visibility(private: bool) -> Visibility {
+    if private {
+        Visibility::Private
+    } else {
+        Visibility::Public
+    }
+}
+
+fn usage_bodies(usages: &[ResourceUsage], actor: &EmailAddress, is_admin: bool) -> Vec<UsageBody> {
+    usages
+        .iter()
+        .map(|usage| UsageBody::new(usage, usage.details_visible_to(Some(actor), is_admin)))
+        .collect()
+}
+
+/// APIのルート
+#[derive(Debug, PartialEq, Eq)]
+enum Route {
+    ListUsages,
+    CreateUsage,
+    GetUsage(String),
+    UpdateUsage(String),
+    DeleteUsage(String),
+    Availability,
+    UserUsages(String),
+    Events,
+    ResourceFeed(FeedKind, String),
+    UserFeed(String),
+    Dashboard,
+}
+
+impl Route {
+    /// メソッドとパスからルートを決める
+    fn parse(method: &Method, path: &str) -> Result<Self, ApiError> {
+        let not_found = || ApiError::new(StatusCode::NOT_FOUND, "Not Found");
+        if path.trim_end_matches('/') == dashboard::DASHBOARD_PATH {
+            if method != Method::GET {
+                return Err(ApiError::new(
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    "Method Not Allowed",
+                ));
+            }
+            return Ok(Self::Dashboard);
+        }
+        if let Some(rest) = path
+            .strip_prefix(feeds::FEED_PREFIX)
+            .and_then(|rest| rest.strip_prefix('/'))
+        {
+            return Self::parse_feed(method, rest);
+        }
+        let rest = path
+            .strip_prefix(API_PREFIX)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .ok_or_else(not_found)?;
+        let segments = path_segments(rest).ok_or_else(not_found)?;
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+        let route = match (method, segments.as_slice()) {
+            (&Method::GET, ["usages"]) => Self::ListUsages,
+            (&Method::POST, ["usages"]) => Self::CreateUsage,
+            (&Method::GET, ["usages", id]) => Self::GetUsage(id.to_string()),
+            (&Method::PATCH, ["usages", id]) => Self::UpdateUsage(id.to_string()),
+            (&Method::DELETE, ["usages", id]) => Self::DeleteUsage(id.to_string()),
+            (&Method::GET, ["availability"]) => Self::Availability,
+            (&Method::GET, ["users", email, "usages"]) => Self::UserUsages(email.to_string()),
+            (&Method::GET, ["events"]) => Self::Events,
+            (
+                _,
+                ["usages"] | ["usages", _] | ["availability"] | ["users", _, "usages"] | ["events"],
+            ) => {
+                return Err(ApiError::new(
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    "Method Not Allowed",
+                ));
+            }
+            _ => return Err(not_found()),
+        };
+        Ok(route)
+    }
+
+    /// フィードのパス（`/feeds/` より後）からルートを決める
+    fn parse_feed(method: &Method, rest: &str) -> Result<Self, ApiError> {
+        let not_found = || ApiError::new(StatusCode::NOT_FOUND, "Not Found");
+        let segments = path_segments(rest).ok_or_else(not_found)?;
+        let [kind, file] = segments.as_slice() else {
+            return Err(not_found());
+        };
+        let name = feeds::feed_name(file).ok_or_else(not_found)?.to_string();
+        let route = match kind.as_str() {
+            "user" => Self::UserFeed(name),
+            kind => Self::ResourceFeed(FeedKind::parse(kind).ok_or_else(not_found)?, name),
+        };
+        if method != Method::GET {
+            return Err(ApiError::new(
+                StatusCode::METHOD_NOT_ALLOWED,
+                "Method Not Allowed",
+            ));
+        }
+        Ok(route)
+    }
+}
+
+/// パスを `/` で区切り、それぞれをパーセントデコードする
+fn path_segments(rest: &str) -> Option<Vec<String>> {
+    rest.trim_end_matches('/')
+        .split('/')
+        .map(percent_decode)
+        .collect()
+}
+
+/// APIのエラー（ステータスコードとメッセージ）
+#[derive(Debug)]
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, message)
+    }
+
+    fn internal(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, message)
+    }
+}
+
+impl From<ApplicationError> for ApiError {
+    fn from(e: ApplicationError) -> Self {
+        let status = match &e {
+            ApplicationError::Repository(RepositoryError::NotFound) => StatusCode::NOT_FOUND,
+            ApplicationError::Unauthorized(_) => StatusCode::FORBIDDEN,
+            ApplicationError::ResourceConflict { .. }
+            | ApplicationError::ReservationLimit(_)
+            | ApplicationError::BookingWindow(_)
+            | ApplicationError::ResourceFreeze(_)
+            | ApplicationError::DeviceHealth(_)
+            | ApplicationError::Preemption(_) => StatusCode::CONFLICT,
+            ApplicationError::ResourceUsage(_)
+            | ApplicationError::Group(_)
+            | ApplicationError::CapacityExceeded(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self::new(status, e.to_string())
+    }
+}
+
+/// リクエストボディをJSONとして読み込む
+async fn read_json<T: DeserializeOwned>(req: Request<Incoming>) -> Result<T, ApiError> {
+    let body = Limited::new(req.into_body(), MAX_BODY_BYTES)
+        .collect()
+        .await
+        .map_err(|_| {
+            ApiError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!(
+                    "リクエストボディは{}バイト以下にしてください",
+                    MAX_BODY_BYTES
+                ),
+            )
+        })?
+        .to_bytes();
+    serde_json::from_slice(&body)
+        .map_err(|e| ApiError::bad_request(format!("リクエストボディが不正です: {}", e)))
+}
+
+fn json_response<T: Serialize>(status: StatusCode, body: &T) -> ApiResponse {
+    let json = serde_json::to_vec(body).unwrap_or_else(|_| b"{}".to_vec());
+    Response::builder()
+        .status(status)
+        .header(
+            hyper::header::CONTENT_TYPE,
+            "application/json; charset=utf-8",
+        )
+        .body(Full::new(Bytes::from(json)).boxed())
+        .expect("valid response")
+}
+
+/// iCalendarフィードのレスポンスを作成
+fn calendar_response(calendar: String) -> ApiResponse {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(hyper::header::CONTENT_TYPE, feeds::CONTENT_TYPE)
+        .body(Full::new(Bytes::from(calendar)).boxed())
+        .expect("valid response")
+}
+
+/// クエリ文字列を名前と値に分ける（同じ名前が複数ある場合は最後の値）
+fn query_params(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter_map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Some((
+                percent_decode(&name.replace('+', " "))?,
+                percent_decode(&value.replace('+', " "))?,
+            ))
+        })
+        .collect()
+}
+
+/// パーセントエンコーディングを復号する（不正な場合は `None`）
+fn percent_decode(s: &str) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            // `from_str_radix` は先頭の `+` を受け付けるため、2文字とも16進数字か確かめる
+            let hex = s.get(i + 1..i + 3)?;
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use std::net::SocketAddr;
+
+    /// テスト用のAPIサーバーを起動し、アドレスと `alice@example.com` のトークンを返す
+    pub(super) async fn spawn_server() -> (SocketAddr, String) {
+        let resource_config: ResourceConfig = toml::from_str(
+            r#"
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers.devices]]
+id = 1
+model = "A100"
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+notifications = []
+"#,
+        )
+        .unwrap();
+        let tokens_file =
+            std::env::temp_dir().join(format!("lrm_api_tokens_{}", uuid::Uuid::new_v4()));
+        let token = auth::issue_token(
+            &tokens_file,
+            &EmailAddress::new("alice@example.com".to_string()).unwrap(),
+        )
+        .unwrap();
+
+        let repo = Arc::new(MockUsageRepository::new());
+        let server = Arc::new(ApiServer::new(
+            HttpApiConfig {
+                listen_addr: "127.0.0.1:0".to_string(),
+            },
+            tokens_file,
+            Arc::new(resource_config.clone()),
+            Arc::new(CreateResourceUsageUseCase::new(repo.clone())),
+            Arc::new(UpdateResourceUsageUseCase::new(repo.clone())),
+            Arc::new(DeleteResourceUsageUseCase::new(repo.clone())),
+            Arc::new(GetResourceUsageByIdUseCase::new(repo.clone())),
+            Arc::new(GetResourceAvailabilityUseCase::new(
+                repo.clone(),
+                resource_config.resources(),
+            )),
+            Arc::new(ListAllFutureResourceUsagesUseCase::new(repo.clone())),
+            Arc::new(ListUserResourceUsagesUseCase::new(repo)),
+        ));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(server.accept(listener));
+        (addr, token)
+    }
+
+    #[tokio::test]
+    async fn test_serves_standard_http_clients() {
+        rustls::crypto::ring::default_provider()
+            .install_default()
+            .ok();
+        let (addr, token) = spawn_server().await;
+        let client = reqwest::Client::new();
+        let url = |path: &str| format!("http://{}{}{}", addr, API_PREFIX, path);
+        let start = (Utc::now() + chrono::Duration::days(1)).to_rfc3339();
+        let end =
+            (Utc::now() + chrono::Duration::days(1) + chrono::Duration::hours(2)).to_rfc3339();
+
+        let response = client.get(url("/usages")).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+        let response = client
+            .post(url("/usages"))
+            .bearer_auth(&token)
+            .json(&serde_json::json!({
+                "resources": "Thalys:0",
+                "start": start,
+                "end": end,
+                "notes": "学習ジョブ",
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::CREATED);
+        let created: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(created["owner_email"], "alice@example.com");
+        assert_eq!(created["notes"], "学習ジョブ");
+        let id = created["id"].as_str().unwrap().to_string();
+
+        let usages: serde_json::Value = client
+            .get(url("/users/alice%40example.com/usages"))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(usages[0]["id"], id.as_str());
+
+        let availability: serde_json::Value = client
+            .get(url("/availability"))
+            .query(&[("start", start.as_str()), ("end", end.as_str())])
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let free: Vec<bool> = availability["resources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|resource| resource["free"].as_bool().unwrap())
+            .collect();
+        assert_eq!(free, vec![false, true, true]);
+
+        let response = client
+            .delete(url(&format!("/usages/{}", id)))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+        let response = client
+            .get(url(&format!("/usages/{}", id)))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_route_parse() {
+        assert_eq!(
+            Route::parse(&Method::GET, "/api/v1/usages").unwrap(),
+            Route::ListUsages
+        );
+        assert_eq!(
+            Route::parse(&Method::POST, "/api/v1/usages/").unwrap(),
+            Route::CreateUsage
+        );
+        assert_eq!(
+            Route::parse(&Method::PATCH, "/api/v1/usages/abc-123").unwrap(),
+            Route::UpdateUsage("abc-123".to_string())
+        );
+        assert_eq!(
+            Route::parse(&Method::GET, "/api/v1/users/alice%40example.com/usages").unwrap(),
+            Route::UserUsages("alice@example.com".to_string())
+        );
+        assert_eq!(
+            Route::parse(&Method::GET, "/api/v1/events").unwrap(),
+            Route::Events
+        );
+        assert_eq!(
+            Route::parse(&Method::POST, "/api/v1/events")
+                .unwrap_err()
+                .status,
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+        assert_eq!(
+            Route::parse(&Method::GET, "/feeds/server/thalys.ics").unwrap(),
+            Route::ResourceFeed(FeedKind::Server, "thalys".to_string())
+        );
+        assert_eq!(
+            Route::parse(&Method::GET, "/feeds/user/alice%40example.com.ics").unwrap(),
+            Route::UserFeed("alice@example.com".to_string())
+        );
+        assert_eq!(
+            Route::parse(&Method::GET, "/dashboard").unwrap(),
+            Route::Dashboard
+        );
+        assert_eq!(
+            Route::parse(&Method::POST, "/dashboard")
+                .unwrap_err()
+                .status,
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+        assert_eq!(
+            Route::parse(&Method::GET, "/feeds/gpu/thalys.ics")
+                .unwrap_err()
+                .status,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            Route::parse(&Method::DELETE, "/feeds/room/lab.ics")
+                .unwrap_err()
+                .status,
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+        assert_eq!(
+            Route::parse(&Method::PUT, "/api/v1/usages/abc-123")
+                .unwrap_err()
+                .status,
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+        assert_eq!(
+            Route::parse(&Method::GET, "/api/v2/usages")
+                .unwrap_err()
+                .status,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            Route::parse(&Method::GET, "/api/v1/usages/a/b")
+                .unwrap_err()
+                .status,
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn test_query_params() {
+        let params = query_params(
+            "start=2025-04-01T09%3A00%3A00%2B09%3A00&resource=%E4%BC%9A%E8%AD%B0%E5%AE%A4A&tag=a100+gpu",
+        );
+
+        assert_eq!(params["start"], "2025-04-01T09:00:00+09:00");
+        assert_eq!(params["resource"], "会議室A");
+        assert_eq!(params["tag"], "a100 gpu");
+        assert_eq!(percent_decode("%zz"), None);
+    }
+
+    #[test]
+    fn test_percent_decode_rejects_non_hex_digits() {
+        assert_eq!(percent_decode("%41%2b").as_deref(), Some("A+"));
+        assert_eq!(percent_decode("%+A"), None);
+        assert_eq!(percent_decode("%-1"), None);
+        assert_eq!(percent_decode("%4"), None);
+        assert_eq!(percent_decode("%%41"), None);
+    }
+
+    #[test]
+    fn test_application_errors_map_to_status() {
+        let status = |e: ApplicationError| ApiError::from(e).status;
+
+        assert_eq!(
+            status(ApplicationError::Repository(RepositoryError::NotFound)),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(ApplicationError::Unauthorized("他人の予約".to_string())),
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            status(ApplicationError::ResourceConflict {
+                resource_description: "Thalys / GPU:0".to_string(),
+                conflicting_usage_id: "abc".to_string(),
+            }),
+            StatusCode::CONFLICT
+        );
+        assert_eq!(
+            status(ApplicationError::Repository(RepositoryError::Unknown(
+                "timeout".to_string()
+            ))),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! # MCPサーバー
+//!
+//! AIアシスタントから予約を操作するためのMCP（Model Context Protocol）サーバー。
+//! 標準入出力でJSON-RPCを送受信し、予約の一覧・空き状況の確認・作成・取り消しをツールとして公開する。
+//!
+//! 操作する利用者はAPIトークン（[`crate::interface::http_api::auth`] を参照）で決まり、
+//! Slack・HTTP APIと同じユースケースを使うため、競合・上限の確認や認可の規則も同じになる。
+
+mod protocol;
+mod tools;
+
+use crate::application::usecases::{
+    CreateResourceUsageUseCase, DeleteResourceUsageUseCase, GetResourceAvailabilityUseCase,
+    GetResourceUsageByIdUseCase, ListAllFutureResourceUsagesUseCase, ListUserResourceUsagesUseCase,
+};
+use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, UsageId};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::config::ResourceConfig;
+use crate::interface::http_api::dto::{
+    AvailabilityBody, CreateUsageRequest, UsageBody, parse_time,
+};
+use protocol::{RpcError, error_response, result_response, tool_result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tracing::info;
+
+/// 予約を操作するMCPサーバー
+pub struct McpServer<R: ResourceUsageRepository> {
+    actor: EmailAddress,
+    is_admin: bool,
+    resource_config: Arc<ResourceConfig>,
+    create_usecase: Arc<CreateResourceUsageUseCase<R>>,
+    delete_usecase: Arc<DeleteResourceUsageUseCase<R>>,
+    get_usage_usecase: Arc<GetResourceUsageByIdUseCase<R>>,
+    availability_usecase: Arc<GetResourceAvailabilityUseCase<R>>,
+    list_usecase: Arc<ListAllFutureResourceUsagesUseCase<R>>,
+    list_user_usecase: Arc<ListUserResourceUsagesUseCase<R>>,
+}
+
+impl<R> McpServer<R>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+{
+    /// 新しいMcpServerを作成
+    ///
+    /// # Arguments
+    /// * `actor` - ツールで操作する利用者（APIトークンの持ち主）
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        actor: EmailAddress,
+        resource_config: Arc<ResourceConfig>,
+        create_usecase: Arc<CreateResourceUsageUseCase<R>>,
+        delete_usecase: Arc<DeleteResourceUsageUseCase<R>>,
+        get_usage_usecase: Arc<GetResourceUsageByIdUseCase<R>>,
+        availability_usecase: Arc<GetResourceAvailabilityUseCase<R>>,
+        list_usecase: Arc<ListAllFutureResourceUsagesUseCase<R>>,
+        list_user_usecase: Arc<ListUserResourceUsagesUseCase<R>>,
+    ) -> Self {
+        Self {
+            is_admin: resource_config.is_admin(&actor),
+            actor,
+            resource_config,
+            create_usecase,
+            delete_usecase,
+            get_usage_usecase,
+            availability_usecase,
+            list_usecase,
+            list_user_usecase,
+        }
+    }
+
+    /// 標準入出力でMCPサーバーを実行
+    ///
+    /// 標準入力が閉じられると終了する。標準出力はプロトコルに使うため、ログは標準エラー出力に書くこと。
+    pub async fn serve_stdio(&self) -> std::io::Result<()> {
+        let mut lines = BufReader::new(tokio::io::stdin()).lines();
+        let mut stdout = tokio::io::stdout();
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = self.handle_message(&line).await {
+                stdout.write_all(response.as_bytes()).await?;
+                stdout.write_all(b"\n").await?;
+                stdout.flush().await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 1行のメッセージを処理し、応答を返す（通知の場合は `None`）
+    async fn handle_message(&self, line: &str) -> Option<String> {
+        let request = match protocol::parse(line) {
+            Ok(request) => request,
+            Err(response) => return Some(response),
+        };
+        let id = request.id?;
+        let response = match self.dispatch(&request.method, request.params).await {
+            Ok(result) => result_response(&id, result),
+            Err(error) => error_response(&id, &error),
+        };
+        Some(response)
+    }
+
+    async fn dispatch(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "initialize" => Ok(protocol::initialize_result(&params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tools::definitions() })),
+            "tools/call" => {
+                let name = params
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| RpcError::new(protocol::INVALID_PARAMS, "name がありません"))?;
+                let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
+                Ok(match self.call_tool(name, arguments).await {
+                    Ok(text) => tool_result(text, false),
+                    Err(message) => tool_result(message, true),
+                })
+            }
+            _ => Err(RpcError::new(
+                protocol::METHOD_NOT_FOUND,
+                format!("未対応のメソッドです: {}", method),
+            )),
+        }
+    }
+
+    /// ツールを実行し、結果のJSON（エラーの場合はメッセージ）を返す
+    async fn call_tool(&self, name: &str, arguments: Value) -> Result<String, String> {
+        match name {
+            tools::LIST_RESERVATIONS => {
+                let args: tools::ListReservationsArgs = parse_args(arguments)?;
+                let owner = match (args.mine, args.owner_email) {
+                    (true, _) => Some(self.actor.clone()),
+                    (false, Some(owner_email)) => {
+                        Some(EmailAddress::new(owner_email).map_err(|e| e.to_string())?)
+                    }
+                    (false, None) => None,
+                };
+                let usages = match owner {
+                    Some(owner) => self.list_user_usecase.execute(&owner).await,
+                    None => self.list_usecase.execute().await,
+                }
+                .map_err(|e| e.to_string())?;
+                let bodies: Vec<UsageBody> = usages
+                    .iter()
+                    .map(|usage| {
+                        UsageBody::new(
+                            usage,
+                            usage.details_visible_to(Some(&self.actor), self.is_admin),
+                        )
+                    })
+                    .collect();
+                to_json(&bodies)
+            }
+            tools::CHECK_AVAILABILITY => {
+                let args: tools::CheckAvailabilityArgs = parse_args(arguments)?;
+                let period = TimePeriod::new(
+                    parse_time("start", &args.start)?,
+                    parse_time("end", &args.end)?,
+                )
+                .map_err(|e| e.to_string())?;
+                let report = self
+                    .availability_usecase
+                    .execute(&period, args.resource.as_deref(), args.tag.as_deref())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                to_json(&AvailabilityBody::new(&report))
+            }
+            tools::CREATE_RESERVATION => {
+                let request: CreateUsageRequest = parse_args(arguments)?;
+                let new_usage = request.into_new_usage(&self.resource_config)?;
+                let usage_id = self
+                    .create_usecase
+                    .execute(
+                        self.actor.clone(),
+                        new_usage.time_period,
+                        new_usage.resources,
+                        new_usage.notes,
+                        new_usage.metadata,
+                        new_usage.visibility,
+                        new_usage.priority,
+                        new_usage.group,
+                    )
+                    .await
+                    .map_err(|e| e.to_string())?;
+                info!(
+                    "✅ MCPで予約を作成しました: {} ({})",
+                    usage_id.as_str(),
+                    self.actor.as_str()
+                );
+                let usage = self
+                    .get_usage_usecase
+                    .execute(&usage_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                to_json(&UsageBody::new(&usage, true))
+            }
+            tools::CANCEL_RESERVATION => {
+                let args: tools::CancelReservationArgs = parse_args(arguments)?;
+                self.delete_usecase
+                    .execute(&UsageId::from_string(args.id.clone()), &self.actor)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                info!(
+                    "🗑️ MCPで予約を取り消しました: {} ({})",
+                    args.id,
+                    self.actor.as_str()
+                );
+                to_json(&json!({ "cancelled": args.id }))
+            }
+            _ => Err(format!("未対応のツールです: {}", name)),
+        }
+    }
+}
+
+fn parse_args<T: DeserializeOwned>(arguments: Value) -> Result<T, String> {
+    serde_json::from_value(arguments).map_err(|e| format!("引数が不正です: {}", e))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, String> {
+    serde_json::to_string_pretty(value).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+    use chrono::{Duration, SecondsFormat, Utc};
+
+    const CONFIG: &str = r#"
+rooms = []
+
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers.devices]]
+id = 1
+model = "A100"
+"#;
+
+    /// 同じリポジトリの予約を操作する、利用者ごとのMCPサーバー
+    fn server(repo: &Arc<MockUsageRepository>, actor: &str) -> McpServer<MockUsageRepository> {
+        let resource_config: Arc<ResourceConfig> = Arc::new(toml::from_str(CONFIG).unwrap());
+        McpServer::new(
+            EmailAddress::new(format!("{}@example.com", actor)).unwrap(),
+            resource_config.clone(),
+            Arc::new(CreateResourceUsageUseCase::new(repo.clone())),
+            Arc::new(DeleteResourceUsageUseCase::new(repo.clone())),
+            Arc::new(GetResourceUsageByIdUseCase::new(repo.clone())),
+            Arc::new(GetResourceAvailabilityUseCase::new(
+                repo.clone(),
+                resource_config.resources(),
+            )),
+            Arc::new(ListAllFutureResourceUsagesUseCase::new(repo.clone())),
+            Arc::new(ListUserResourceUsagesUseCase::new(repo.clone())),
+        )
+    }
+
+    async fn request(
+        server: &McpServer<MockUsageRepository>,
+        method: &str,
+        params: Value,
+    ) -> Value {
+        let line = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response = server
+            .handle_message(&line.to_string())
+            .await
+            .expect("リクエストには応答するはず");
+        serde_json::from_str(&response).unwrap()
+    }
+
+    /// ツールを呼び出し、結果のテキストをJSONとして（エラーの場合は文字列として）返す
+    async fn call(
+        server: &McpServer<MockUsageRepository>,
+        name: &str,
+        arguments: Value,
+    ) -> Result<Value, String> {
+        let response = request(
+            server,
+            "tools/call",
+            json!({ "name": name, "arguments": arguments }),
+        )
+        .await;
+        let text = response["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        if response["result"]["isError"] == true {
+            Err(text)
+        } else {
+            Ok(serde_json::from_str(&text).unwrap())
+        }
+    }
+
+    fn rfc3339(hours_from_now: i64) -> String {
+        (Utc::now() + Duration::hours(hours_from_now)).to_rfc3339_opts(SecondsFormat::Secs, true)
+    }
+
+    #[tokio::test]
+    async fn test_protocol_messages() {
+        let server = server(&Arc::new(MockUsageRepository::new()), "alice");
+
+        let notification = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        assert!(
+            server
+                .handle_message(&notification.to_string())
+                .await
+                .is_none()
+        );
+
+        let tools = request(&server, "tools/list", json!({})).await;
+        let names: Vec<&str> = tools["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|tool| tool["name"].as_str())
+            .collect();
+        assert!(names.contains(&tools::CREATE_RESERVATION));
+        assert!(names.contains(&tools::CANCEL_RESERVATION));
+
+        let unknown = request(&server, "resources/list", json!({})).await;
+        assert_eq!(unknown["error"]["code"], protocol::METHOD_NOT_FOUND);
+
+        let missing_name = request(&server, "tools/call", json!({})).await;
+        assert_eq!(missing_name["error"]["code"], protocol::INVALID_PARAMS);
+
+        assert!(call(&server, "delete_everything", json!({})).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_list_and_cancel_as_actor() {
+        let repo = Arc::new(MockUsageRepository::new());
+        let alice = server(&repo, "alice");
+        let bob = server(&repo, "bob");
+        let (start, end) = (rfc3339(24), rfc3339(26));
+
+        let created = call(
+            &alice,
+            tools::CREATE_RESERVATION,
+            json!({ "resources": "Thalys:0", "start": start, "end": end, "private": true }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(created["owner_email"], "alice@example.com");
+        let id = created["id"].as_str().unwrap().to_string();
+
+        // 同じデバイスは予約できない
+        assert!(
+            call(
+                &bob,
+                tools::CREATE_RESERVATION,
+                json!({ "resources": "Thalys:0", "start": start, "end": end }),
+            )
+            .await
+            .is_err()
+        );
+
+        let availability = call(
+            &bob,
+            tools::CHECK_AVAILABILITY,
+            json!({ "start": start, "end": end }),
+        )
+        .await
+        .unwrap();
+        let free: Vec<bool> = availability["resources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|resource| resource["free"].as_bool().unwrap())
+            .collect();
+        assert_eq!(free, vec![false, true]);
+
+        // 非公開の予約の予約者は本人以外には見せない
+        let listed = call(&bob, tools::LIST_RESERVATIONS, json!({}))
+            .await
+            .unwrap();
+        assert_eq!(listed[0]["owner_email"], Value::Null);
+        let mine = call(&bob, tools::LIST_RESERVATIONS, json!({ "mine": true }))
+            .await
+            .unwrap();
+        assert!(mine.as_array().unwrap().is_empty());
+
+        assert!(
+            call(&bob, tools::CANCEL_RESERVATION, json!({ "id": id }))
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            call(&alice, tools::CANCEL_RESERVATION, json!({ "id": id }))
+                .await
+                .unwrap(),
+            json!({ "cancelled": id })
+        );
+        let listed = call(&alice, tools::LIST_RESERVATIONS, json!({}))
+            .await
+            .unwrap();
+        assert!(listed.as_array().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! MCPのJSON-RPC 2.0のメッセージ
+//!
+//! 標準入出力では1行に1つのメッセージを送受信する。`id` のないメッセージは通知で、応答しない。
+
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// このサーバーが対応するMCPのプロトコルのバージョン（新しい順）
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// JSONとして解釈できない
+pub const PARSE_ERROR: i64 = -32700;
+/// JSON-RPCのリクエストではない
+pub const INVALID_REQUEST: i64 = -32600;
+/// メソッドがない
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// パラメーターが不正
+pub const INVALID_PARAMS: i64 = -32602;
+
+/// 受け取ったリクエスト（または通知）
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    /// 通知の場合は `None`
+    #[serde(default)]
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// JSON-RPCのエラー
+#[derive(Debug)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// 1行のメッセージを解釈する
+///
+/// 解釈できない場合は、返すべきエラー応答を返す。
+pub fn parse(line: &str) -> Result<Request, String> {
+    let value: Value = serde_json::from_str(line)
+        .map_err(|e| error_response(&Value::Null, &RpcError::new(PARSE_ERROR, e.to_string())))?;
+    let id = value.get("id").cloned().unwrap_or(Value::Null);
+    serde_json::from_value(value)
+        .map_err(|e| error_response(&id, &RpcError::new(INVALID_REQUEST, e.to_string())))
+}
+
+/// 成功の応答
+pub fn result_response(id: &Value, result: Value) -> String {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string()
+}
+
+/// エラーの応答
+pub fn error_response(id: &Value, error: &RpcError) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+    .to_string()
+}
+
+/// `initialize` の応答
+///
+/// クライアントが求めたバージョンに対応していればそれを、していなければ最新のバージョンを返す。
+pub fn initialize_result(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = SUPPORTED_PROTOCOL_VERSIONS
+        .iter()
+        .find(|version| Some(**version) == requested)
+        .unwrap_or(&SUPPORTED_PROTOCOL_VERSIONS[0]);
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": { "listChanged": false } },
+        "serverInfo": {
+            "name": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "instructions": "研究室のGPU・部屋などの予約を確認・作成・取り消すツールです。時刻はRFC 3339（例: 2025-04-01T09:00:00+09:00）で指定してください。",
+    })
+}
+
+/// `tools/call` の結果（ツールのエラーはJSON-RPCのエラーではなく `isError` で返す）
+pub fn tool_result(text: String, is_error: bool) -> Value {
+    json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": is_error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let request =
+            parse(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list","params":{}}"#).unwrap();
+        assert_eq!(request.id, Some(json!(1)));
+        assert_eq!(request.method, "tools/list");
+
+        let notification =
+            parse(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#).unwrap();
+        assert!(notification.id.is_none());
+
+        let error: Value = serde_json::from_str(&parse("{").unwrap_err()).unwrap();
+        assert_eq!(error["error"]["code"], PARSE_ERROR);
+        let error: Value = serde_json::from_str(&parse(r#"{"id":2}"#).unwrap_err()).unwrap();
+        assert_eq!(error["id"], 2);
+        assert_eq!(error["error"]["code"], INVALID_REQUEST);
+    }
+
+    #[test]
+    fn test_initialize_negotiates_version() {
+        let result = initialize_result(&json!({ "protocolVersion": "2024-11-05" }));
+        assert_eq!(result["protocolVersion"], "2024-11-05");
+        assert!(result["capabilities"]["tools"].is_object());
+
+        let result = initialize_result(&json!({ "protocolVersion": "1999-01-01" }));
+        assert_eq!(result["protocolVersion"], SUPPORTED_PROTOCOL_VERSIONS[0]);
+    }
+}
@@ -0,0 +1,139 @@
+//! MCPで公開するツールの定義と引数
+
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+/// 予約の一覧
+pub const LIST_RESERVATIONS: &str = "list_reservations";
+/// 空き状況の確認
+pub const CHECK_AVAILABILITY: &str = "check_availability";
+/// 予約の作成
+pub const CREATE_RESERVATION: &str = "create_reservation";
+/// 予約の取り消し
+pub const CANCEL_RESERVATION: &str = "cancel_reservation";
+
+/// `list_reservations` の引数
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListReservationsArgs {
+    /// 絞り込む予約者（省略時はすべての予約者）
+    pub owner_email: Option<String>,
+    /// trueの場合は自分の予約のみ
+    #[serde(default)]
+    pub mine: bool,
+}
+
+/// `check_availability` の引数
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckAvailabilityArgs {
+    pub start: String,
+    pub end: String,
+    pub resource: Option<String>,
+    pub tag: Option<String>,
+}
+
+/// `cancel_reservation` の引数
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CancelReservationArgs {
+    pub id: String,
+}
+
+/// `tools/list` で返すツールの一覧
+pub fn definitions() -> Value {
+    json!([
+        {
+            "name": LIST_RESERVATIONS,
+            "description": "今後の予約の一覧を取得します。他の利用者の非公開の予約は、予約者・備考が伏せられます。",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "owner_email": { "type": "string", "description": "この予約者の予約のみを取得する" },
+                    "mine": { "type": "boolean", "description": "trueの場合は自分の予約のみを取得する" },
+                },
+                "additionalProperties": false,
+            },
+            "annotations": { "readOnlyHint": true },
+        },
+        {
+            "name": CHECK_AVAILABILITY,
+            "description": "期間内のリソースごとの使用中・空きの期間を取得します。",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "start": { "type": "string", "description": "期間の開始（RFC 3339）" },
+                    "end": { "type": "string", "description": "期間の終了（RFC 3339）" },
+                    "resource": { "type": "string", "description": "リソース名で絞り込む（例: Thalys）" },
+                    "tag": { "type": "string", "description": "タグで絞り込む（例: a100）" },
+                },
+                "required": ["start", "end"],
+                "additionalProperties": false,
+            },
+            "annotations": { "readOnlyHint": true },
+        },
+        {
+            "name": CREATE_RESERVATION,
+            "description": "自分を予約者として予約を作成します。Slackの /reserve と同じく、競合・同時予約の上限・受付期間・予約停止を確認します。",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "resources": { "type": "string", "description": "予約するリソース（例: \"Thalys:0-1; 会議室A\"）" },
+                    "start": { "type": "string", "description": "開始（RFC 3339）" },
+                    "end": { "type": "string", "description": "終了（RFC 3339）" },
+                    "notes": { "type": "string", "description": "備考" },
+                    "project": { "type": "string" },
+                    "experiment_id": { "type": "string" },
+                    "expected_utilization": { "type": "integer", "minimum": 0, "maximum": 100 },
+                    "private": { "type": "boolean", "description": "trueの場合は他の利用者に詳細を見せない" },
+                    "priority": { "type": "string", "enum": ["background", "normal", "deadline"] },
+                    "group": { "type": "string", "description": "グループの予約にする場合のグループID" },
+                },
+                "required": ["resources", "start", "end"],
+                "additionalProperties": false,
+            },
+        },
+        {
+            "name": CANCEL_RESERVATION,
+            "description": "予約を取り消します。取り消せるのは予約者本人・管理者・モデレーターのみです。",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string", "description": "予約ID" },
+                },
+                "required": ["id"],
+                "additionalProperties": false,
+            },
+            "annotations": { "destructiveHint": true },
+        },
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_definitions_have_object_schemas() {
+        let definitions = definitions();
+        let tools = definitions.as_array().unwrap();
+        let names: Vec<&str> = tools
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                LIST_RESERVATIONS,
+                CHECK_AVAILABILITY,
+                CREATE_RESERVATION,
+                CANCEL_RESERVATION
+            ]
+        );
+        assert!(
+            tools
+                .iter()
+                .all(|tool| tool["inputSchema"]["type"] == "object")
+        );
+    }
+}
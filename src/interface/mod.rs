@@ -11,4 +11,7 @@
 //!
 //! Interface層はApplication層とDomain層に依存できる。
 //! Infrastructure層には直接依存しない（DIコンテナ経由で注入）。
+pub mod http_api;
+pub mod mcp;
 pub mod slack;
+pub mod tui;
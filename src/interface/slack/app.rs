@@ -2,19 +2,63 @@
 //!
 //! 依存関係を管理し、Slackインタラクションのメインエントリポイントを提供
 
+use crate::application::read_model::ReservationReadModel;
+use crate::application::usecases::approve_reservation::ApproveReservationUseCase;
+use crate::application::usecases::auto_release_idle_reservations::AutoReleaseIdleReservationsUseCase;
+use crate::application::usecases::bulk_delete_resource_usages::BulkDeleteResourceUsagesUseCase;
+use crate::application::usecases::clone_reservation::CloneReservationUseCase;
+use crate::application::usecases::cost_report::CostReportUseCase;
 use crate::application::usecases::create_resource_usage::CreateResourceUsageUseCase;
 use crate::application::usecases::delete_resource_usage::DeleteResourceUsageUseCase;
+use crate::application::usecases::detect_unreserved_usage::DetectUnreservedUsageUseCase;
+use crate::application::usecases::extend_resource_usage::ExtendResourceUsageUseCase;
+use crate::application::usecases::find_next_available_slot::FindNextAvailableSlotUseCase;
+use crate::application::usecases::freeze_resource::FreezeResourceUseCase;
+use crate::application::usecases::get_current_occupants::GetCurrentOccupantsUseCase;
+use crate::application::usecases::get_identity_link_history::GetIdentityLinkHistoryUseCase;
+use crate::application::usecases::get_resource_availability::GetResourceAvailabilityUseCase;
+use crate::application::usecases::get_resource_usage_by_id::GetResourceUsageByIdUseCase;
 use crate::application::usecases::grant_user_resource_access::GrantUserResourceAccessUseCase;
+use crate::application::usecases::join_waitlist::JoinWaitlistUseCase;
 use crate::application::usecases::notify_future_resource_usage_changes::NotifyFutureResourceUsageChangesUseCase;
+use crate::application::usecases::notify_waitlist::NotifyWaitlistUseCase;
+use crate::application::usecases::rebuild_reservation_read_model::RebuildReservationReadModelUseCase;
+use crate::application::usecases::release_resource_usage::ReleaseResourceUsageUseCase;
+use crate::application::usecases::revoke_user_resource_access::RevokeUserResourceAccessUseCase;
+use crate::application::usecases::send_upcoming_reminders::SendUpcomingRemindersUseCase;
+use crate::application::usecases::set_device_status::SetDeviceStatusUseCase;
+use crate::application::usecases::sync_directory_members::SyncDirectoryMembersUseCase;
+use crate::application::usecases::transfer_ownership::TransferOwnershipUseCase;
 use crate::application::usecases::update_resource_usage::UpdateResourceUsageUseCase;
+use crate::application::usecases::usage_report::UsageReportUseCase;
+use crate::application::usecases::wake_reserved_servers::WakeReservedServersUseCase;
+use crate::application::usecases::weekly_digest::WeeklyDigestUseCase;
+use crate::domain::aggregates::device_health::DeviceHealth;
+use crate::domain::ports::leader_election::LeaderElection;
 use crate::domain::ports::notifier::Notifier;
-use crate::domain::ports::repositories::{IdentityLinkRepository, ResourceUsageRepository};
+use crate::domain::ports::repositories::{
+    IdentityLinkRepository, ResourceUsageRepository, WorkspaceTokenRepository,
+};
 use crate::infrastructure::config::{AppConfig, ResourceConfig};
+use crate::infrastructure::i18n::fill;
+use crate::infrastructure::logging;
+#[cfg(feature = "metrics")]
+use crate::infrastructure::metrics::metrics;
+use crate::interface::http_api::{self, ApiServer};
+use crate::interface::slack::oauth;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::user_resolver::{self, UserPreferences};
+use crate::interface::slack::views::messages::error;
+use crate::interface::slack::views::modals::field_errors;
 use slack_morphism::prelude::*;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 use tokio_util::task::TaskTracker;
+use tracing::{Instrument, error, info, warn};
 
 /// 依存性注入を備えたSlackアプリケーション
 ///
@@ -31,13 +75,45 @@ where
 
     // UseCases
     grant_access_usecase: Arc<GrantUserResourceAccessUseCase>,
+    revoke_access_usecase: Arc<RevokeUserResourceAccessUseCase>,
+    link_history_usecase: Arc<GetIdentityLinkHistoryUseCase>,
     create_resource_usage_usecase: Arc<CreateResourceUsageUseCase<R>>,
     update_resource_usage_usecase: Arc<UpdateResourceUsageUseCase<R>>,
+    extend_usage_usecase: Arc<ExtendResourceUsageUseCase<R>>,
+    release_usage_usecase: Arc<ReleaseResourceUsageUseCase<R>>,
+    transfer_ownership_usecase: Arc<TransferOwnershipUseCase<R>>,
+    approve_reservation_usecase: Arc<ApproveReservationUseCase<R>>,
+    get_usage_usecase: Arc<GetResourceUsageByIdUseCase<R>>,
     delete_usage_usecase: Arc<DeleteResourceUsageUseCase<R>>,
+    bulk_delete_usecase: Arc<BulkDeleteResourceUsagesUseCase<R>>,
+    freeze_usecase: Arc<FreezeResourceUseCase<R>>,
+    device_status_usecase: Arc<SetDeviceStatusUseCase<R>>,
+    availability_usecase: Arc<GetResourceAvailabilityUseCase<R>>,
+    next_slot_usecase: Arc<FindNextAvailableSlotUseCase<R>>,
+    clone_reservation_usecase: Arc<CloneReservationUseCase<R>>,
+    current_occupants_usecase: Arc<GetCurrentOccupantsUseCase<R>>,
+    usage_report_usecase: Arc<UsageReportUseCase<R>>,
+    cost_report_usecase: Option<Arc<CostReportUseCase<R>>>,
     notify_usecase: Arc<NotifyFutureResourceUsageChangesUseCase<R, N>>,
+    rebuild_read_model_usecase: Arc<RebuildReservationReadModelUseCase<R>>,
+    wake_servers_usecase: Option<Arc<WakeReservedServersUseCase<R>>>,
+    detect_unreserved_usecase: Option<Arc<DetectUnreservedUsageUseCase<R>>>,
+    auto_release_usecase: Option<Arc<AutoReleaseIdleReservationsUseCase<R>>>,
+    reminders_usecase: Option<Arc<SendUpcomingRemindersUseCase<R>>>,
+    weekly_digest_usecase: Option<Arc<WeeklyDigestUseCase<R>>>,
+    join_waitlist_usecase: Option<Arc<JoinWaitlistUseCase>>,
+    notify_waitlist_usecase: Option<Arc<NotifyWaitlistUseCase<R>>>,
+    /// 名簿同期ユースケースと同期間隔
+    sync_members: Option<(Arc<SyncDirectoryMembersUseCase>, Duration)>,
+    /// 複数インスタンスで運用する場合のリーダー選出
+    leader_election: Option<Arc<dyn LeaderElection>>,
+    /// 予約を操作するHTTP API
+    api_server: Option<Arc<ApiServer<R>>>,
 
     // リポジトリ
     identity_repo: Arc<dyn IdentityLinkRepository>,
+    /// OAuthでインストールされたワークスペースごとのBotトークン
+    workspace_token_repo: Option<Arc<dyn WorkspaceTokenRepository>>,
 
     // Slackインフラストラクチャ
     slack_client: Arc<SlackHyperClient>,
@@ -64,10 +140,26 @@ where
         resource_config: Arc<ResourceConfig>,
         identity_repo: Arc<dyn IdentityLinkRepository>,
         grant_access_usecase: Arc<GrantUserResourceAccessUseCase>,
+        revoke_access_usecase: Arc<RevokeUserResourceAccessUseCase>,
+        link_history_usecase: Arc<GetIdentityLinkHistoryUseCase>,
         create_resource_usage_usecase: Arc<CreateResourceUsageUseCase<R>>,
         update_resource_usage_usecase: Arc<UpdateResourceUsageUseCase<R>>,
+        extend_usage_usecase: Arc<ExtendResourceUsageUseCase<R>>,
+        release_usage_usecase: Arc<ReleaseResourceUsageUseCase<R>>,
+        transfer_ownership_usecase: Arc<TransferOwnershipUseCase<R>>,
+        approve_reservation_usecase: Arc<ApproveReservationUseCase<R>>,
+        get_usage_usecase: Arc<GetResourceUsageByIdUseCase<R>>,
         delete_usage_usecase: Arc<DeleteResourceUsageUseCase<R>>,
+        bulk_delete_usecase: Arc<BulkDeleteResourceUsagesUseCase<R>>,
+        freeze_usecase: Arc<FreezeResourceUseCase<R>>,
+        device_status_usecase: Arc<SetDeviceStatusUseCase<R>>,
+        availability_usecase: Arc<GetResourceAvailabilityUseCase<R>>,
+        next_slot_usecase: Arc<FindNextAvailableSlotUseCase<R>>,
+        clone_reservation_usecase: Arc<CloneReservationUseCase<R>>,
+        current_occupants_usecase: Arc<GetCurrentOccupantsUseCase<R>>,
+        usage_report_usecase: Arc<UsageReportUseCase<R>>,
         notify_usecase: Arc<NotifyFutureResourceUsageChangesUseCase<R, N>>,
+        rebuild_read_model_usecase: Arc<RebuildReservationReadModelUseCase<R>>,
         slack_client: Arc<SlackHyperClient>,
         bot_token: SlackApiToken,
     ) -> Self {
@@ -76,10 +168,38 @@ where
             resource_config,
             identity_repo,
             grant_access_usecase,
+            revoke_access_usecase,
+            link_history_usecase,
             create_resource_usage_usecase,
             update_resource_usage_usecase,
+            extend_usage_usecase,
+            release_usage_usecase,
+            transfer_ownership_usecase,
+            approve_reservation_usecase,
+            get_usage_usecase,
             delete_usage_usecase,
+            bulk_delete_usecase,
+            freeze_usecase,
+            device_status_usecase,
+            availability_usecase,
+            next_slot_usecase,
+            clone_reservation_usecase,
+            current_occupants_usecase,
+            usage_report_usecase,
+            cost_report_usecase: None,
             notify_usecase,
+            rebuild_read_model_usecase,
+            wake_servers_usecase: None,
+            detect_unreserved_usecase: None,
+            auto_release_usecase: None,
+            reminders_usecase: None,
+            weekly_digest_usecase: None,
+            join_waitlist_usecase: None,
+            notify_waitlist_usecase: None,
+            sync_members: None,
+            leader_election: None,
+            api_server: None,
+            workspace_token_repo: None,
             slack_client,
             bot_token,
             user_channel_map: Arc::new(RwLock::new(HashMap::new())),
@@ -88,32 +208,164 @@ where
         }
     }
 
+    /// 予約開始前にサーバーの電源を入れるユースケースを設定
+    ///
+    /// 設定した場合、ポーリングのたびに起動が必要なサーバーの電源を入れる。
+    pub fn with_wake_servers_usecase(
+        mut self,
+        wake_servers_usecase: Arc<WakeReservedServersUseCase<R>>,
+    ) -> Self {
+        self.wake_servers_usecase = Some(wake_servers_usecase);
+        self
+    }
+
+    /// 予約なしでのGPUの使用を検出するユースケースを設定
+    ///
+    /// 設定した場合、ポーリングのたびにGPUの使用状況と予約を突き合わせて警告する。
+    pub fn with_detect_unreserved_usecase(
+        mut self,
+        detect_unreserved_usecase: Arc<DetectUnreservedUsageUseCase<R>>,
+    ) -> Self {
+        self.detect_unreserved_usecase = Some(detect_unreserved_usecase);
+        self
+    }
+
+    /// 使われていない予約を確認・解放するユースケースを設定
+    ///
+    /// 設定した場合、ポーリングのたびに予約したGPUの使用状況を確認し、使われていない予約を予約者に確認・解放する。
+    pub fn with_auto_release_usecase(
+        mut self,
+        auto_release_usecase: Arc<AutoReleaseIdleReservationsUseCase<R>>,
+    ) -> Self {
+        self.auto_release_usecase = Some(auto_release_usecase);
+        self
+    }
+
+    /// 予約開始前に予約者へリマインダーを送るユースケースを設定
+    ///
+    /// 設定した場合、ポーリングのたびに開始が近い予約のリマインダーを送る。
+    pub fn with_reminders_usecase(
+        mut self,
+        reminders_usecase: Arc<SendUpcomingRemindersUseCase<R>>,
+    ) -> Self {
+        self.reminders_usecase = Some(reminders_usecase);
+        self
+    }
+
+    /// 週間の利用状況のまとめを投稿するユースケースを設定
+    ///
+    /// 設定した場合、ポーリングのたびに投稿時刻を過ぎていないか確認し、週に一度まとめを投稿する。
+    pub fn with_weekly_digest_usecase(
+        mut self,
+        weekly_digest_usecase: Arc<WeeklyDigestUseCase<R>>,
+    ) -> Self {
+        self.weekly_digest_usecase = Some(weekly_digest_usecase);
+        self
+    }
+
+    /// 空き待ちのユースケースを設定
+    ///
+    /// 設定した場合、予約が競合したときに空き待ちへの登録を提案し、
+    /// ポーリングのたびに空きが出た空き待ちの希望者に知らせる。
+    pub fn with_waitlist_usecases(
+        mut self,
+        join_waitlist_usecase: Arc<JoinWaitlistUseCase>,
+        notify_waitlist_usecase: Arc<NotifyWaitlistUseCase<R>>,
+    ) -> Self {
+        self.join_waitlist_usecase = Some(join_waitlist_usecase);
+        self.notify_waitlist_usecase = Some(notify_waitlist_usecase);
+        self
+    }
+
+    /// 費用を集計するユースケースを設定
+    ///
+    /// 設定した場合、管理者が `/cost-report` でユーザー・プロジェクトごとの費用を集計できる。
+    pub fn with_cost_report_usecase(
+        mut self,
+        cost_report_usecase: Arc<CostReportUseCase<R>>,
+    ) -> Self {
+        self.cost_report_usecase = Some(cost_report_usecase);
+        self
+    }
+
+    /// 研究室の名簿からID紐付けを同期するユースケースを設定
+    ///
+    /// 設定した場合、指定した間隔で名簿を同期する。
+    pub fn with_sync_members_usecase(
+        mut self,
+        sync_members_usecase: Arc<SyncDirectoryMembersUseCase>,
+        interval: Duration,
+    ) -> Self {
+        self.sync_members = Some((sync_members_usecase, interval));
+        self
+    }
+
+    /// 複数インスタンスで運用する場合のリーダー選出を設定
+    ///
+    /// 設定した場合、リーダーのインスタンスだけがポーリングと通知を行う。
+    /// 他のインスタンスは通知せずに予約の状態だけを取り込み、リーダーが停止すると引き継ぐ。
+    pub fn with_leader_election(mut self, leader_election: Arc<dyn LeaderElection>) -> Self {
+        self.leader_election = Some(leader_election);
+        self
+    }
+
+    /// 予約を操作するHTTP APIを設定
+    ///
+    /// 設定した場合、Socket Modeのリスナーと並行してAPIサーバーを起動する。
+    pub fn with_api_server(mut self, api_server: Arc<ApiServer<R>>) -> Self {
+        self.api_server = Some(api_server);
+        self
+    }
+
+    /// ワークスペースごとのBotトークンを保持するリポジトリを設定
+    ///
+    /// 設定した場合、イベントを受信したワークスペースのトークンで応答する。
+    /// トークンが見つからないワークスペースには既定のBotトークンを使う。
+    pub fn with_workspace_token_repository(
+        mut self,
+        workspace_token_repo: Arc<dyn WorkspaceTokenRepository>,
+    ) -> Self {
+        self.workspace_token_repo = Some(workspace_token_repo);
+        self
+    }
+
     /// アプリケーションを実行
     ///
     /// Socket Modeリスナーとポーリングタスクを起動し、
     /// Ctrl+Cシグナルまで実行を継続します。
     pub async fn run(self: Arc<Self>) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        println!("🤖 Slack Bot を起動しています...");
-        println!(
+        info!("🤖 Slack Bot を起動しています...");
+        info!(
             "📁 リソース設定ファイル: {}",
             self.app_config.resource_config_path.display()
         );
-        println!(
+        info!(
             "📁 ID紐付けファイル: {}",
             self.app_config.identity_links_file.display()
         );
-        println!(
-            "✅ 設定を読み込みました: {} サーバー, {} 部屋",
+        info!(
+            "✅ 設定を読み込みました: {} サーバー, {} 部屋, {} 機器",
             self.resource_config.servers.len(),
-            self.resource_config.rooms.len()
+            self.resource_config.rooms.len(),
+            self.resource_config.instruments.len()
         );
-        println!("✅ Slack App を初期化しました");
-        println!("✅ 通知機能を初期化しました");
+        info!("✅ Slack App を初期化しました");
+        info!("✅ 通知機能を初期化しました");
 
-        println!("🚀 Bot の準備ができました！");
-        println!("   /register-calendar <your-email@gmail.com>");
-        println!("   /link-user <@slack_user> <email@gmail.com>");
-        println!();
+        info!("🚀 Bot の準備ができました！");
+        info!("   /register-calendar <your-email@gmail.com>");
+        info!("   /link-user <@slack_user> <email@gmail.com>");
+        info!("   /availability [server] [YYYY-MM-DD]");
+        info!("   /next-free <server|room> [devices] <hours>");
+        info!("   /whois-using <server|room>");
+        info!("   /cancel-all");
+        info!("   /usage-stats [week|month]");
+        info!("   /cost-report [week|month]");
+        info!("   /unlink-user [<@slack_user>]");
+        info!("   /link-history <@slack_user|email>");
+        info!("   /freeze-resource <resource> <YYYY-MM-DD> [HH:MM] [reason]");
+        info!("   /device-status <server> <device> <available|degraded|out-of-service> [note]");
+        info!("   /admin-cancel <usage-id|@user>");
 
         // Socket Mode リスナーの設定
         let socket_mode_callbacks = SlackSocketModeListenerCallbacks::new()
@@ -132,54 +384,114 @@ where
             socket_mode_callbacks,
         );
 
-        println!("🔌 Slack Socket Mode に接続しています...");
+        info!("🔌 Slack Socket Mode に接続しています...");
 
         let app_token = SlackApiToken::new(self.app_config.slack_app_token.clone().into());
         socket_mode_listener.listen_for(&app_token).await?;
 
-        println!("✅ Slack Socket Mode に接続しました！");
-        println!("🎉 Bot がスラッシュコマンドを待機しています");
-        println!();
+        info!("✅ Slack Socket Mode に接続しました！");
+        info!("🎉 Bot がスラッシュコマンドを待機しています");
 
-        println!(
+        info!(
             "🔍 カレンダー監視を開始します（間隔: {}秒）",
             self.app_config.polling_interval_secs
         );
-        println!();
-        println!("Bot を停止するには Ctrl+C を押してください");
+        info!("Bot を停止するには Ctrl+C を押してください");
+
+        // 停止の合図（ポーリングと名簿の同期は実行中の回を終えてから止まる）
+        let stop = CancellationToken::new();
 
         // バックグラウンドでポーリングタスクを実行
         let polling_handle = {
-            let notify_usecase = self.notify_usecase.clone();
+            let app = self.clone();
+            let stop = stop.clone();
+            let leading = Arc::new(AtomicBool::new(false));
             let polling_interval = Duration::from_secs(self.app_config.polling_interval_secs);
             tokio::spawn(async move {
-                loop {
-                    match notify_usecase.poll_once().await {
-                        Ok(_) => {}
-                        Err(e) => {
-                            eprintln!("❌ ポーリングエラー: {}", e);
-                        }
+                repeat_until_stopped(&stop, polling_interval, || {
+                    let app = app.clone();
+                    let leading = leading.clone();
+                    async move {
+                        // ポーリング1回分のログを相関IDでまとめる
+                        let span = tracing::info_span!(
+                            "poll",
+                            correlation_id = %logging::correlation_id()
+                        );
+                        app.poll_round(&leading).instrument(span).await;
                     }
-                    tokio::time::sleep(polling_interval).await;
-                }
+                })
+                .await;
             })
         };
 
-        // Socket Mode リスナーとポーリングタスクを並行実行
-        tokio::select! {
-            _ = socket_mode_listener.serve() => {
-                println!("\n🔌 Socket Mode リスナーが終了しました");
-            }
-            _ = tokio::signal::ctrl_c() => {
-                println!("\n👋 シャットダウンシグナルを受信しました");
+        // バックグラウンドで名簿の同期を実行
+        let sync_members_handle = self.sync_members.clone().map(|(usecase, interval)| {
+            info!(
+                "👥 名簿の同期を開始します（間隔: {}秒）",
+                interval.as_secs()
+            );
+            let stop = stop.clone();
+            tokio::spawn(async move {
+                repeat_until_stopped(&stop, interval, async || match usecase.execute().await {
+                    Ok(linked) => {
+                        for email in linked {
+                            info!("👥 名簿のメンバーを紐付けました: {}", email.as_str());
+                        }
+                    }
+                    Err(e) => error!("❌ 名簿の同期エラー: {}", e),
+                })
+                .await;
+            })
+        });
+
+        // 複数ワークスペースへのインストールを受け付けるHTTPサーバー
+        let oauth_handle = match (&self.app_config.slack_oauth, &self.workspace_token_repo) {
+            (Some(oauth_config), Some(workspace_token_repo)) => {
+                info!(
+                    "🔑 インストール用のHTTPサーバーを起動します: {}{}",
+                    oauth_config.listen_addr,
+                    oauth::INSTALL_PATH
+                );
+                let oauth_config = oauth_config.clone();
+                let workspace_token_repo = workspace_token_repo.clone();
+                let messages = self.resource_config.i18n.locale.messages();
+                let stop = stop.clone();
+                Some(tokio::spawn(async move {
+                    if let Err(e) =
+                        oauth::serve(&oauth_config, workspace_token_repo, messages, stop).await
+                    {
+                        error!("❌ インストール用HTTPサーバーのエラー: {}", e);
+                    }
+                }))
             }
-        }
+            _ => None,
+        };
 
-        // ポーリングタスクを停止
-        polling_handle.abort();
+        // 予約を操作するHTTP API
+        let api_handle = self.api_server.clone().map(|api_server| {
+            info!(
+                "🌐 HTTP APIを起動します: {}{}",
+                api_server.listen_addr(),
+                http_api::API_PREFIX
+            );
+            tokio::spawn(async move {
+                if let Err(e) = api_server.serve().await {
+                    error!("❌ HTTP APIのエラー: {}", e);
+                }
+            })
+        });
+
+        // Socket Mode リスナーを実行
+        // 終了シグナル（Ctrl+C, SIGTERM）を受けると、新しいイベントの受信を止めてから戻る
+        socket_mode_listener.serve().await;
+        info!("👋 シャットダウンシグナルを受信しました");
 
-        println!("👋 シャットダウンしています...");
-        self.shutdown().await;
+        info!("👋 シャットダウンしています...");
+        if let Some(handle) = api_handle {
+            handle.abort();
+        }
+        self.shutdown(stop, polling_handle, sync_members_handle, oauth_handle)
+            .await;
 
         Ok(())
     }
@@ -190,7 +502,23 @@ where
         _client: Arc<SlackHyperClient>,
         state: SlackClientEventsUserState,
     ) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>> {
-        println!("📩 コマンドを受信しました: {}", event.command);
+        // コマンド1件分のログ（後続のバックグラウンド処理を含む）を相関IDでまとめる
+        let span = tracing::info_span!(
+            "slack_command",
+            correlation_id = %logging::correlation_id(),
+            command = %event.command
+        );
+        Self::process_command_event(event, state)
+            .instrument(span)
+            .await
+    }
+
+    /// コマンドイベントを処理
+    async fn process_command_event(
+        event: SlackCommandEvent,
+        state: SlackClientEventsUserState,
+    ) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>> {
+        info!("📩 コマンドを受信しました: {}", event.command);
 
         let app = state
             .read()
@@ -199,15 +527,18 @@ where
             .ok_or("App の状態が見つかりません")?
             .clone();
 
+        let (team_id, user_id) = (event.team_id.clone(), event.user_id.clone());
         match app.route_slash_command(event).await {
             Ok(response) => {
-                println!("✅ コマンドを正常に処理しました");
+                info!("✅ コマンドを正常に処理しました");
                 Ok(response)
             }
             Err(e) => {
-                eprintln!("❌ コマンド処理エラー: {}", e);
+                error!("❌ コマンド処理エラー: {}", e);
+                let messages = app.user_preferences(&team_id, &user_id).await.messages();
                 Ok(SlackCommandEventResponse::new(
-                    SlackMessageContent::new().with_text(format!("エラー: {}", e)),
+                    SlackMessageContent::new()
+                        .with_text(fill(messages.command_failed, &[("error", &e.to_string())])),
                 ))
             }
         }
@@ -219,7 +550,12 @@ where
         client: Arc<SlackHyperClient>,
         state: SlackClientEventsUserState,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        println!("🔘 インタラクションを受信しました");
+        // インタラクション1件分のログを相関IDでまとめる
+        let span = tracing::info_span!(
+            "slack_interaction",
+            correlation_id = %logging::correlation_id()
+        );
+        info!(parent: &span, "🔘 インタラクションを受信しました");
 
         let app = state
             .read()
@@ -229,84 +565,359 @@ where
             .clone();
 
         // Socket Modeには即座に応答を返すため、処理を非同期タスクでspawn
-        tokio::spawn(async move {
-            let result = app.route_interaction(event.clone()).await;
-
-            match result {
-                Ok(Some(response)) => {
-                    println!("📤 ビュー応答を送信中...");
-
-                    let token = &app.bot_token;
-                    let session = client.open_session(token);
-
-                    match response {
-                        SlackViewSubmissionResponse::Update(update_response) => {
-                            if let SlackInteractionEvent::ViewSubmission(vs) = &event {
-                                let view_id = &vs.view.state_params.id;
-                                let hash = if let SlackView::Modal(modal) = &vs.view.view {
-                                    modal.hash.clone()
-                                } else {
-                                    None
-                                };
-
-                                let mut request =
-                                    SlackApiViewsUpdateRequest::new(update_response.view);
-                                request.view_id = Some(view_id.clone());
-                                request.hash = hash;
-
-                                match session.views_update(&request).await {
-                                    Ok(_) => println!("✅ ビューを更新しました"),
-                                    Err(e) => eprintln!("❌ ビュー更新エラー: {}", e),
+        // （シャットダウン時に処理の完了を待てるようTaskTrackerで追跡する）
+        let task_tracker = app.task_tracker.clone();
+        task_tracker.spawn(
+            async move {
+                let result = app.route_interaction(event.clone()).await;
+
+                match result {
+                    Ok(Some(response)) => {
+                        info!("📤 ビュー応答を送信中...");
+
+                        // ビュー応答を返すのはビュー送信イベントのみ
+                        let token = match &event {
+                            SlackInteractionEvent::ViewSubmission(vs) => {
+                                app.bot_token_for(&vs.team.id).await
+                            }
+                            _ => app.bot_token.clone(),
+                        };
+                        let session = client.open_session(&token);
+
+                        match response {
+                            SlackViewSubmissionResponse::Update(update_response) => {
+                                if let SlackInteractionEvent::ViewSubmission(vs) = &event {
+                                    let view_id = &vs.view.state_params.id;
+                                    let hash = if let SlackView::Modal(modal) = &vs.view.view {
+                                        modal.hash.clone()
+                                    } else {
+                                        None
+                                    };
+
+                                    let mut request =
+                                        SlackApiViewsUpdateRequest::new(update_response.view);
+                                    request.view_id = Some(view_id.clone());
+                                    request.hash = hash;
+
+                                    match session.views_update(&request).await {
+                                        Ok(_) => info!("✅ ビューを更新しました"),
+                                        Err(e) => error!("❌ ビュー更新エラー: {}", e),
+                                    }
                                 }
                             }
-                        }
-                        SlackViewSubmissionResponse::Push(push_response) => {
-                            if let SlackInteractionEvent::ViewSubmission(vs) = &event
-                                && let Some(trigger_id) = &vs.trigger_id
-                            {
-                                match session
-                                    .views_push(&SlackApiViewsPushRequest::new(
-                                        trigger_id.clone(),
-                                        push_response.view,
-                                    ))
-                                    .await
+                            SlackViewSubmissionResponse::Push(push_response) => {
+                                if let SlackInteractionEvent::ViewSubmission(vs) = &event
+                                    && let Some(trigger_id) = &vs.trigger_id
                                 {
-                                    Ok(_) => println!("✅ ビューをpushしました"),
-                                    Err(e) => eprintln!("❌ ビューpushエラー: {}", e),
+                                    match session
+                                        .views_push(&SlackApiViewsPushRequest::new(
+                                            trigger_id.clone(),
+                                            push_response.view,
+                                        ))
+                                        .await
+                                    {
+                                        Ok(_) => info!("✅ ビューをpushしました"),
+                                        Err(e) => error!("❌ ビューpushエラー: {}", e),
+                                    }
                                 }
                             }
+                            SlackViewSubmissionResponse::Errors(errors_response) => {
+                                // Socket Modeの応答ではエラーを返せず、送信したモーダルは閉じてしまう。
+                                // 入力した値のままモーダルを開き直し、入力欄ごとにエラーを表示する
+                                let mut reopened = false;
+                                if let SlackInteractionEvent::ViewSubmission(vs) = &event
+                                    && let Some(trigger_id) = &vs.trigger_id
+                                    && let Some(view) = field_errors::reopen_with_errors(
+                                        &vs.view,
+                                        &errors_response.errors,
+                                    )
+                                {
+                                    match session
+                                        .views_open(&SlackApiViewsOpenRequest::new(
+                                            trigger_id.clone(),
+                                            view,
+                                        ))
+                                        .await
+                                    {
+                                        Ok(_) => {
+                                            info!(
+                                                "✅ 入力エラーを表示したモーダルを開き直しました"
+                                            );
+                                            reopened = true;
+                                        }
+                                        Err(e) => error!("❌ モーダルを開き直せません: {}", e),
+                                    }
+                                }
+
+                                // 開き直せない場合は、エフェメラルメッセージ（送れなければDM）で代替
+                                if !reopened
+                                    && let SlackInteractionEvent::ViewSubmission(vs) = &event
+                                {
+                                    let channel_id = app
+                                        .user_channel_map
+                                        .read()
+                                        .unwrap()
+                                        .get(&vs.user.id)
+                                        .cloned();
+                                    let messages = app
+                                        .user_preferences(&vs.team.id, &vs.user.id)
+                                        .await
+                                        .messages();
+                                    let content = error::create_field_errors_message(
+                                        messages,
+                                        &errors_response.errors,
+                                    );
+                                    let delivery = messages::ResultDelivery::for_user(
+                                        channel_id,
+                                        &vs.user.id,
+                                        content,
+                                    );
+                                    match messages::deliver_result(&session, delivery).await {
+                                        Ok(_) => info!("✅ 入力エラーを送信しました"),
+                                        Err(e) => error!("❌ 入力エラーの送信エラー: {}", e),
+                                    }
+                                }
+                            }
+                            SlackViewSubmissionResponse::Clear(_) => {
+                                warn!("⚠️ Clear responseは未実装です");
+                            }
                         }
-                        SlackViewSubmissionResponse::Clear(_) => {
-                            println!("⚠️ Clear responseは未実装です");
-                        }
-                        _ => {}
+
+                        info!("✅ インタラクションを正常に処理しました");
+                    }
+                    Ok(None) => {
+                        info!("✅ インタラクションを正常に処理しました（応答なし）");
+                    }
+                    Err(e) => {
+                        error!("❌ インタラクション処理エラー: {}", e);
                     }
+                }
+            }
+            .instrument(span),
+        );
 
-                    println!("✅ インタラクションを正常に処理しました");
+        Ok(())
+    }
+
+    /// ポーリング1回分の処理（予約の変更の通知と、予約に応じた定期処理）
+    ///
+    /// `leading` は前回の回でリーダーだったかどうか（リーダーの交代をログに残すために使う）。
+    async fn poll_round(&self, leading: &AtomicBool) {
+        // 複数インスタンスで運用する場合、リーダーだけがポーリングと通知を行う
+        if let Some(leader_election) = &self.leader_election {
+            let acquired = match leader_election.try_acquire().await {
+                Ok(acquired) => acquired,
+                Err(e) => {
+                    error!("❌ リーダーの確認エラー: {}", e);
+                    false
                 }
-                Ok(None) => {
-                    println!("✅ インタラクションを正常に処理しました（応答なし）");
+            };
+            if leading.swap(acquired, Ordering::Relaxed) != acquired {
+                if acquired {
+                    info!("👑 リーダーになりました。ポーリングと通知を開始します");
+                } else {
+                    info!("💤 リーダーではなくなりました。待機します");
                 }
-                Err(e) => {
-                    eprintln!("❌ インタラクション処理エラー: {}", e);
+            }
+            if !acquired {
+                if let Err(e) = self.notify_usecase.sync_state().await {
+                    error!("❌ 予約の状態の取り込みエラー: {}", e);
                 }
+                return;
             }
-        });
-
-        Ok(())
+        }
+        #[cfg(feature = "metrics")]
+        let poll_started = std::time::Instant::now();
+        let polled = self.notify_usecase.poll_once().await;
+        #[cfg(feature = "metrics")]
+        metrics().record_poll(poll_started.elapsed(), polled.as_ref().ok().copied());
+        if let Err(e) = polled {
+            error!("❌ ポーリングエラー: {}", e);
+        }
+        // 読み取りモデルの再構築（失敗時は前回のプロジェクションを使い続ける）
+        if let Err(e) = self.rebuild_read_model_usecase.execute().await {
+            error!("❌ 読み取りモデルの再構築エラー: {}", e);
+        }
+        // 予約開始前のサーバー起動
+        if let Some(wake_servers_usecase) = &self.wake_servers_usecase {
+            match wake_servers_usecase.execute().await {
+                Ok(woken) => {
+                    for server in woken {
+                        info!("⚡ 予約に備えてサーバーを起動しました: {}", server);
+                    }
+                }
+                Err(e) => error!("❌ サーバー起動処理エラー: {}", e),
+            }
+        }
+        // 予約なしでのGPUの使用の検出
+        if let Some(detect_unreserved_usecase) = &self.detect_unreserved_usecase {
+            match detect_unreserved_usecase.execute().await {
+                Ok(detected) => {
+                    for usage in detected {
+                        info!(
+                            "⚠️ 予約なしでのGPUの使用を警告しました: {} GPU:{} ({})",
+                            usage.server, usage.device_number, usage.unix_user
+                        );
+                    }
+                }
+                Err(e) => error!("❌ 予約なしでのGPUの使用の検出エラー: {}", e),
+            }
+        }
+        // 使われていない予約の確認・解放
+        if let Some(auto_release_usecase) = &self.auto_release_usecase {
+            match auto_release_usecase.execute().await {
+                Ok(handled) => {
+                    for (usage_id, action) in handled {
+                        info!(
+                            "💤 使われていない予約を処理しました（{:?}）: {}",
+                            action,
+                            usage_id.as_str()
+                        );
+                    }
+                }
+                Err(e) => error!("❌ 使われていない予約の確認・解放エラー: {}", e),
+            }
+        }
+        // 予約開始前のリマインダー
+        if let Some(reminders_usecase) = &self.reminders_usecase {
+            match reminders_usecase.execute().await {
+                Ok(reminded) => {
+                    for (email, kind) in reminded {
+                        info!(
+                            "⏰ リマインダーを送信しました（{:?}）: {}",
+                            kind,
+                            email.as_str()
+                        );
+                    }
+                }
+                Err(e) => error!("❌ リマインダー送信処理エラー: {}", e),
+            }
+        }
+        // 週間の利用状況のまとめ
+        if let Some(weekly_digest_usecase) = &self.weekly_digest_usecase {
+            match weekly_digest_usecase.execute(chrono::Utc::now()).await {
+                Ok(posted) => {
+                    for channel_id in posted {
+                        info!("📊 週間の利用状況を投稿しました: {}", channel_id);
+                    }
+                }
+                Err(e) => error!("❌ 週間の利用状況の投稿エラー: {}", e),
+            }
+        }
+        // 空きが出た空き待ちの通知
+        if let Some(notify_waitlist_usecase) = &self.notify_waitlist_usecase {
+            match notify_waitlist_usecase.execute().await {
+                Ok(notified) => {
+                    for email in notified {
+                        info!(
+                            "🔔 空き待ちの希望者に空きを知らせました: {}",
+                            email.as_str()
+                        );
+                    }
+                }
+                Err(e) => error!("❌ 空き待ちの通知処理エラー: {}", e),
+            }
+        }
     }
 
-    /// すべてのバックグラウンドタスクの完了を待機
-    async fn shutdown(&self) {
+    /// 実行中の処理を終えてから停止する
+    ///
+    /// ポーリングと名簿の同期は実行中の回を最後まで終えてから止め、インストール用のHTTPサーバーは
+    /// 処理中のリクエストを終えてから止める。
+    /// その後バックグラウンドタスク（コマンドの後続処理・インタラクションの処理）の完了を待つ。
+    /// 各処理は永続化を終えてから完了するため、書き込み途中のファイルを残さない。
+    async fn shutdown(
+        &self,
+        stop: CancellationToken,
+        polling_handle: JoinHandle<()>,
+        sync_members_handle: Option<JoinHandle<()>>,
+        oauth_handle: Option<JoinHandle<()>>,
+    ) {
+        stop.cancel();
+        info!("⏳ 実行中のポーリングの完了を待っています...");
+        if let Err(e) = polling_handle.await {
+            error!("❌ ポーリングタスクのエラー: {}", e);
+        }
+        if let Some(handle) = sync_members_handle
+            && let Err(e) = handle.await
+        {
+            error!("❌ 名簿の同期タスクのエラー: {}", e);
+        }
+        if let Some(handle) = oauth_handle
+            && let Err(e) = handle.await
+        {
+            error!("❌ インストール用HTTPサーバーのタスクのエラー: {}", e);
+        }
+        // 待機中のインスタンスがすぐに引き継げるよう、リーダーのロックを手放す
+        if let Some(leader_election) = &self.leader_election
+            && let Err(e) = leader_election.release().await
+        {
+            error!("❌ リーダーのロックの解放エラー: {}", e);
+        }
+
         self.task_tracker.close();
+        if !self.task_tracker.is_empty() {
+            info!(
+                "⏳ 実行中のバックグラウンドタスク（{}件）の完了を待っています...",
+                self.task_tracker.len()
+            );
+        }
         self.task_tracker.wait().await;
+        info!("✅ すべての処理が完了しました");
     }
 
-    // 以下、既存のメソッドで使用されるフィールドへのアクセサ
-    pub fn bot_token(&self) -> &SlackApiToken {
-        &self.bot_token
+    /// ID紐付けに使うワークスペースID
+    ///
+    /// Enterprise Gridで運用している場合のみ、イベントのTeam IDを返す。
+    pub fn identity_workspace_id(&self, team_id: &SlackTeamId) -> Option<String> {
+        self.app_config
+            .slack_enterprise_grid
+            .then(|| team_id.to_string())
+    }
+
+    /// ワークスペースに応じたBotトークン
+    ///
+    /// OAuthでインストールされたワークスペースであればそのトークンを、
+    /// それ以外は既定のBotトークンを返す。
+    pub async fn bot_token_for(&self, team_id: &SlackTeamId) -> SlackApiToken {
+        if let Some(repo) = &self.workspace_token_repo {
+            match repo.find_by_team_id(team_id.as_ref()).await {
+                Ok(Some(token)) => return SlackApiToken::new(token.into()),
+                Ok(None) => {}
+                Err(e) => error!("❌ ワークスペースのトークン取得エラー: {}", e),
+            }
+        }
+        self.bot_token.clone()
     }
 
+    /// 利用者のSlackプロフィールに基づく表示設定（タイムゾーン・表示言語）
+    pub async fn user_preferences(
+        &self,
+        team_id: &SlackTeamId,
+        user_id: &SlackUserId,
+    ) -> UserPreferences {
+        let bot_token = self.bot_token_for(team_id).await;
+        user_resolver::fetch_user_preferences(
+            &self.slack_client,
+            &bot_token,
+            user_id,
+            &self.resource_config.i18n,
+        )
+        .await
+    }
+
+    /// 状態が登録されているデバイス（取得に失敗した場合は空）
+    ///
+    /// 予約モーダルで、使用停止中のデバイスを隠し、性能低下中のデバイスに印を付けるために使う。
+    pub async fn device_healths(&self) -> Vec<DeviceHealth> {
+        self.device_status_usecase.list().await.unwrap_or_else(|e| {
+            error!("❌ デバイスの状態の取得エラー: {}", e);
+            Vec::new()
+        })
+    }
+
+    // 以下、既存のメソッドで使用されるフィールドへのアクセサ
+
     pub fn slack_client(&self) -> &Arc<SlackHyperClient> {
         &self.slack_client
     }
@@ -323,6 +934,14 @@ where
         &self.grant_access_usecase
     }
 
+    pub fn revoke_access_usecase(&self) -> &Arc<RevokeUserResourceAccessUseCase> {
+        &self.revoke_access_usecase
+    }
+
+    pub fn link_history_usecase(&self) -> &Arc<GetIdentityLinkHistoryUseCase> {
+        &self.link_history_usecase
+    }
+
     pub fn create_resource_usage_usecase(&self) -> &Arc<CreateResourceUsageUseCase<R>> {
         &self.create_resource_usage_usecase
     }
@@ -331,10 +950,78 @@ where
         &self.update_resource_usage_usecase
     }
 
+    pub fn extend_usage_usecase(&self) -> &Arc<ExtendResourceUsageUseCase<R>> {
+        &self.extend_usage_usecase
+    }
+
+    pub fn release_usage_usecase(&self) -> &Arc<ReleaseResourceUsageUseCase<R>> {
+        &self.release_usage_usecase
+    }
+
+    pub fn transfer_ownership_usecase(&self) -> &Arc<TransferOwnershipUseCase<R>> {
+        &self.transfer_ownership_usecase
+    }
+
+    pub fn approve_reservation_usecase(&self) -> &Arc<ApproveReservationUseCase<R>> {
+        &self.approve_reservation_usecase
+    }
+
+    pub fn get_usage_usecase(&self) -> &Arc<GetResourceUsageByIdUseCase<R>> {
+        &self.get_usage_usecase
+    }
+
     pub fn delete_usage_usecase(&self) -> &Arc<DeleteResourceUsageUseCase<R>> {
         &self.delete_usage_usecase
     }
 
+    pub fn bulk_delete_usecase(&self) -> &Arc<BulkDeleteResourceUsagesUseCase<R>> {
+        &self.bulk_delete_usecase
+    }
+
+    pub fn freeze_usecase(&self) -> &Arc<FreezeResourceUseCase<R>> {
+        &self.freeze_usecase
+    }
+
+    pub fn device_status_usecase(&self) -> &Arc<SetDeviceStatusUseCase<R>> {
+        &self.device_status_usecase
+    }
+
+    pub fn availability_usecase(&self) -> &Arc<GetResourceAvailabilityUseCase<R>> {
+        &self.availability_usecase
+    }
+
+    pub fn next_slot_usecase(&self) -> &Arc<FindNextAvailableSlotUseCase<R>> {
+        &self.next_slot_usecase
+    }
+
+    pub fn clone_reservation_usecase(&self) -> &Arc<CloneReservationUseCase<R>> {
+        &self.clone_reservation_usecase
+    }
+
+    pub fn current_occupants_usecase(&self) -> &Arc<GetCurrentOccupantsUseCase<R>> {
+        &self.current_occupants_usecase
+    }
+
+    pub fn usage_report_usecase(&self) -> &Arc<UsageReportUseCase<R>> {
+        &self.usage_report_usecase
+    }
+
+    pub fn cost_report_usecase(&self) -> Option<&Arc<CostReportUseCase<R>>> {
+        self.cost_report_usecase.as_ref()
+    }
+
+    pub fn auto_release_usecase(&self) -> Option<&Arc<AutoReleaseIdleReservationsUseCase<R>>> {
+        self.auto_release_usecase.as_ref()
+    }
+
+    pub fn join_waitlist_usecase(&self) -> Option<&Arc<JoinWaitlistUseCase>> {
+        self.join_waitlist_usecase.as_ref()
+    }
+
+    pub fn reservation_read_model(&self) -> Arc<ReservationReadModel> {
+        self.rebuild_read_model_usecase.read_model()
+    }
+
     pub fn user_channel_map(&self) -> &Arc<RwLock<HashMap<SlackUserId, SlackChannelId>>> {
         &self.user_channel_map
     }
@@ -347,3 +1034,77 @@ where
         &self.http_client
     }
 }
+
+/// 停止の合図があるまで、`interval` ごとに `round` を繰り返す
+///
+/// 実行中の回は中断せず、最後まで終えてから止まる。
+async fn repeat_until_stopped<F: Future<Output = ()>>(
+    stop: &CancellationToken,
+    interval: Duration,
+    mut round: impl FnMut() -> F,
+) {
+    loop {
+        round().await;
+        tokio::select! {
+            _ = stop.cancelled() => break,
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use tokio::sync::Notify;
+
+    #[tokio::test]
+    async fn test_round_in_progress_completes_before_stop() {
+        let stop = CancellationToken::new();
+        let started = Arc::new(Notify::new());
+        let completed = Arc::new(AtomicUsize::new(0));
+        let handle = tokio::spawn({
+            let stop = stop.clone();
+            let started = started.clone();
+            let completed = completed.clone();
+            async move {
+                repeat_until_stopped(&stop, Duration::from_secs(60), || {
+                    let started = started.clone();
+                    let completed = completed.clone();
+                    async move {
+                        started.notify_one();
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        completed.fetch_add(1, Ordering::SeqCst);
+                    }
+                })
+                .await;
+            }
+        });
+
+        // ポーリングの途中で停止の合図を送る
+        started.notified().await;
+        stop.cancel();
+        handle.await.unwrap();
+
+        assert_eq!(completed.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stops_while_waiting_for_next_round() {
+        let stop = CancellationToken::new();
+        let rounds = AtomicUsize::new(0);
+        stop.cancel();
+
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            repeat_until_stopped(&stop, Duration::from_secs(60), || {
+                rounds.fetch_add(1, Ordering::SeqCst);
+                async {}
+            }),
+        )
+        .await
+        .expect("次の回を待たずに止まるはず");
+
+        assert_eq!(rounds.load(Ordering::SeqCst), 1);
+    }
+}
@@ -2,15 +2,19 @@
 //!
 //! レスポンス追跡付きでバックグラウンドでタスクを実行するユーティリティ
 
+use crate::infrastructure::i18n::Messages;
 use crate::interface::slack::slack_client::messages;
 use slack_morphism::prelude::*;
 use tokio_util::task::TaskTracker;
+use tracing::Instrument;
 
 /// 操作をバックグラウンドで実行し、response URL経由で結果を送信
 ///
 /// 結果はエフェメラルメッセージ（コマンド実行ユーザーのみ表示）として送信されます。
+/// バックグラウンドの処理のログは、呼び出し元のスパン（コマンドの相関ID）に含まれます。
 ///
 /// # 引数
+/// * `catalog` - 即時レスポンスの表示言語のメッセージカタログ
 /// * `task_tracker` - TaskTracker for managing background tasks
 /// * `http_client` - HTTP client for sending follow-up messages
 /// * `response_url` - Slack response URL to send the result to
@@ -19,6 +23,7 @@ use tokio_util::task::TaskTracker;
 /// # 戻り値
 /// 処理開始を示す即時レスポンス
 pub async fn execute_with_response<F, Fut>(
+    catalog: &Messages,
     task_tracker: &TaskTracker,
     http_client: reqwest::Client,
     response_url: SlackResponseUrl,
@@ -28,14 +33,19 @@ where
     F: FnOnce() -> Fut + Send + 'static,
     Fut: std::future::Future<Output = Result<String, String>> + Send + 'static,
 {
-    task_tracker.spawn(async move {
-        let message = match operation().await {
-            Ok(msg) => msg,
-            Err(err) => err,
-        };
+    task_tracker.spawn(
+        async move {
+            let message = match operation().await {
+                Ok(msg) => msg,
+                Err(err) => err,
+            };
 
-        messages::send_ephemeral(&http_client, &response_url, message).await;
-    });
+            messages::send_ephemeral(&http_client, &response_url, message).await;
+        }
+        .in_current_span(),
+    );
 
-    SlackCommandEventResponse::new(SlackMessageContent::new().with_text("⏳ 処理中...".to_string()))
+    SlackCommandEventResponse::new(
+        SlackMessageContent::new().with_text(catalog.processing.to_string()),
+    )
 }
@@ -0,0 +1,145 @@
+//! 承認依頼の承認・却下ボタンハンドラ
+
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::errors::ResourceUsageError;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::config::ResourceStyle;
+use crate::infrastructure::i18n::fill;
+use crate::infrastructure::notifier::formatter::format_resources_styled;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::user_resolver;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 承認ボタンのクリックを処理
+///
+/// 承認待ちの予約を確定させ、承認依頼メッセージを結果で置き換える。
+/// 予約者には通常の更新通知で共有される。
+pub async fn handle_approve<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    handle(app, block_actions, action, true).await
+}
+
+/// 却下ボタンのクリックを処理
+///
+/// 承認待ちの予約を削除し、承認依頼メッセージを結果で置き換える。
+/// 予約者には通常の削除通知で共有される。
+pub async fn handle_reject<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    handle(app, block_actions, action, false).await
+}
+
+async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+    approve: bool,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(usage_id_str) = &action.value else {
+        error!("❌ usage_idが取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(response_url) = &block_actions.response_url else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+        return Ok(());
+    };
+
+    let usage_id = UsageId::from_string(usage_id_str.clone());
+    info!(
+        "📝 予約の{}: usage_id={}, approver={}",
+        if approve { "承認" } else { "却下" },
+        usage_id.as_str(),
+        user.id
+    );
+
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+    let Ok(approver) = user_resolver::resolve_user_email(&user.id, app.identity_repo())
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|email| EmailAddress::new(email).map_err(|e| e.to_string()))
+    else {
+        messages::send_ephemeral(
+            app.http_client(),
+            response_url,
+            messages.approval_email_required.to_string(),
+        )
+        .await;
+        return Ok(());
+    };
+
+    let usecase = app.approve_reservation_usecase();
+    let result = if approve {
+        usecase.approve(&usage_id, &approver).await
+    } else {
+        usecase.reject(&usage_id, &approver).await
+    };
+
+    match result {
+        Ok(usage) => {
+            let message = fill(
+                if approve {
+                    messages.approved_by
+                } else {
+                    messages.rejected_by
+                },
+                &[
+                    ("approver", &format!("<@{}>", user.id)),
+                    (
+                        "resources",
+                        &format_resources_styled(usage.resources(), ResourceStyle::Compact)
+                            .replace('\n', ", "),
+                    ),
+                    ("owner", usage.owner_email().as_str()),
+                ],
+            );
+            messages::replace_original(app.http_client(), response_url, message).await;
+        }
+        Err(e) => {
+            let message = match e {
+                ApplicationError::ResourceUsage(ResourceUsageError::NotPendingApproval) => {
+                    messages.already_approved.to_string()
+                }
+                ApplicationError::Repository(RepositoryError::NotFound) => {
+                    messages.approval_not_found.to_string()
+                }
+                ApplicationError::Unauthorized(_) => messages.approval_forbidden.to_string(),
+                e => {
+                    error!("❌ 予約の承認・却下に失敗: {}", e);
+                    fill(messages.approval_failed, &[("error", &e.to_string())])
+                }
+            };
+            messages::send_ephemeral(app.http_client(), response_url, message).await;
+        }
+    }
+
+    Ok(())
+}
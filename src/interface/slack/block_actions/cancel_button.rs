@@ -1,9 +1,11 @@
 //! 予約キャンセルボタンハンドラ
 
+use crate::application::error::ApplicationError;
 use crate::domain::aggregates::resource_usage::value_objects::UsageId;
 use crate::domain::common::EmailAddress;
 use crate::domain::ports::notifier::Notifier;
-use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::i18n::fill;
 use crate::interface::slack::app::SlackApp;
 use crate::interface::slack::utility::user_resolver;
 use slack_morphism::prelude::*;
@@ -75,23 +77,23 @@ where
 
     // ユーザーにフィードバックメッセージを送信
     if let Some(ch_id) = channel_id {
+        let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+        let messages = preferences.messages();
         let message_text = match &result {
             Ok(_) => {
                 info!("✅ 削除成功: {}", usage_id.as_str());
-                "✅ 予約をキャンセルしました".to_string()
+                messages.cancelled.to_string()
             }
             Err(e) => {
                 error!("❌ 削除失敗: usage_id={}, error={}", usage_id.as_str(), e);
 
                 // エラーの種類に応じてユーザーフレンドリーなメッセージを返す
-                let error_msg = e.to_string();
-                if error_msg.contains("見つかりません") || error_msg.contains("NotFound") {
-                    "❌ 申し訳ございません。この予約は既に削除されているか、見つかりませんでした。"
-                        .to_string()
-                } else if error_msg.contains("権限") || error_msg.contains("Unauthorized") {
-                    "❌ この予約を削除する権限がありません。".to_string()
-                } else {
-                    format!("❌ 予約の削除に失敗しました: {}", error_msg)
+                match e {
+                    ApplicationError::Repository(RepositoryError::NotFound) => {
+                        messages.usage_not_found.to_string()
+                    }
+                    ApplicationError::Unauthorized(_) => messages.cancel_forbidden.to_string(),
+                    e => fill(messages.cancel_failed, &[("error", &e.to_string())]),
                 }
             }
         };
@@ -103,7 +105,8 @@ where
             SlackMessageContent::new().with_text(message_text),
         );
 
-        let session = app.slack_client().open_session(app.bot_token());
+        let bot_token = app.bot_token_for(&block_actions.team.id).await;
+        let session = app.slack_client().open_session(&bot_token);
         if let Err(e) = session.chat_post_ephemeral(&ephemeral_req).await {
             error!("❌ エフェメラルメッセージ送信失敗: {}", e);
         }
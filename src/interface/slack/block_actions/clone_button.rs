@@ -0,0 +1,153 @@
+//! もう一度予約ボタンハンドラ
+
+use crate::application::usecases::find_next_available_slot::SEARCH_DAYS;
+use crate::domain::aggregates::resource_usage::service::format_time_period;
+use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, UsageId};
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::{messages, modals};
+use crate::interface::slack::utility::device_availability;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::modals::registration;
+use crate::interface::slack::views::modals::reserve::{self, DateTimeInputs};
+use chrono::Utc;
+use slack_morphism::prelude::*;
+use tracing::{error, info, warn};
+
+/// もう一度予約ボタンのクリックを処理
+///
+/// 元の予約と同じリソースを選択し、同じ長さだけ空いている次の時間帯を日時に入力した予約モーダルを開く。
+/// 空いている時間帯が見つからない場合は、現在時刻から同じ長さを入力してその旨を表示する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(usage_id_str) = &action.value else {
+        error!("❌ usage_idが取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let slack_client = app.slack_client();
+    let bot_token = &app.bot_token_for(&block_actions.team.id).await;
+    let identity_repo = app.identity_repo();
+    let config = app.resource_config();
+    let trigger_id = &block_actions.trigger_id;
+
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+
+    // 未リンク: メールアドレス登録モーダルを表示
+    if !user_resolver::is_user_linked(&user.id, identity_repo).await {
+        let modal = registration::create(messages);
+        modals::open(slack_client, bot_token, trigger_id, modal).await?;
+        return Ok(());
+    }
+
+    // channel_idを取得してuser_channel_mapに登録（エフェメラルメッセージ送信用）
+    if let SlackInteractionActionContainer::Message(msg) = &block_actions.container
+        && let Some(channel_id) = &msg.channel_id
+    {
+        app.user_channel_map()
+            .write()
+            .unwrap()
+            .insert(user.id.clone(), channel_id.clone());
+    }
+
+    let now = Utc::now();
+    let usage_id = UsageId::from_string(usage_id_str.clone());
+    let cloned = match app
+        .clone_reservation_usecase()
+        .execute(&usage_id, now)
+        .await
+    {
+        Ok(cloned) => cloned,
+        Err(e) => {
+            warn!("⚠️ もう一度予約する予約を取得できませんでした: {}", e);
+            reply(app, block_actions, messages.usage_not_found).await;
+            return Ok(());
+        }
+    };
+
+    let datetime = match cloned.period {
+        Some(period) => {
+            info!(
+                "🔁 同じリソースの空いている時間帯: {} ~ {}",
+                period.start(),
+                period.end()
+            );
+            let time = format_time_period(&period, preferences.timezone.map(|tz| tz.name()));
+            DateTimeInputs {
+                revision: 0,
+                period: Some(period),
+                note: Some(fill(messages.suggest_time_found, &[("time", &time)])),
+            }
+        }
+        None => DateTimeInputs {
+            revision: 0,
+            period: TimePeriod::new(now, now + cloned.duration).ok(),
+            note: Some(fill(
+                messages.suggest_time_none,
+                &[("days", &SEARCH_DAYS.to_string())],
+            )),
+        },
+    };
+
+    let busy_devices = match datetime
+        .period
+        .clone()
+        .or_else(device_availability::new_reservation_period)
+    {
+        Some(period) => {
+            device_availability::busy_devices(
+                &app.reservation_read_model().snapshot(),
+                config,
+                identity_repo,
+                &user.id,
+                &period,
+                None,
+            )
+            .await
+        }
+        None => Vec::new(),
+    };
+
+    let modal = reserve::create_clone_modal(
+        config,
+        &cloned.resources,
+        &preferences,
+        &busy_devices,
+        &app.device_healths().await,
+        &datetime,
+    );
+    modals::open(slack_client, bot_token, trigger_id, modal).await?;
+
+    Ok(())
+}
+
+/// ボタンを押したユーザーにエフェメラルメッセージで結果を通知
+async fn reply<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    message: &str,
+) where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message.to_string()).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+}
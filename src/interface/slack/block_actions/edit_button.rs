@@ -1,16 +1,20 @@
 //! 予約編集ボタンハンドラ
 
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
 use crate::domain::ports::notifier::Notifier;
 use crate::domain::ports::repositories::ResourceUsageRepository;
 use crate::interface::slack::app::SlackApp;
-use crate::interface::slack::constants::CALLBACK_RESERVE_UPDATE;
-use crate::interface::slack::slack_client::modals;
+use crate::interface::slack::slack_client::{messages, modals};
+use crate::interface::slack::utility::device_availability;
 use crate::interface::slack::utility::user_resolver;
 use crate::interface::slack::views::modals::{registration, reserve};
 use slack_morphism::prelude::*;
-use tracing::error;
+use tracing::{error, warn};
 
 /// 予約編集ボタンのクリックを処理
+///
+/// 既存の予約の内容を入力した状態で更新モーダルを開く。
 pub async fn handle<R, N>(
     app: &SlackApp<R, N>,
     block_actions: &SlackInteractionBlockActionsEvent,
@@ -32,18 +36,21 @@ where
 
     // 依存性を取得
     let slack_client = app.slack_client();
-    let bot_token = app.bot_token();
+    let bot_token = &app.bot_token_for(&block_actions.team.id).await;
     let identity_repo = app.identity_repo();
     let config = app.resource_config();
 
     let trigger_id = &block_actions.trigger_id;
 
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+
     // ユーザーがリンクされているかチェック
     let is_linked = user_resolver::is_user_linked(&user.id, identity_repo).await;
 
     if !is_linked {
         // 未リンク: メールアドレス登録モーダルを表示
-        let modal = registration::create();
+        let modal = registration::create(messages);
         modals::open(slack_client, bot_token, trigger_id, modal).await?;
 
         return Ok(());
@@ -60,19 +67,68 @@ where
             .insert(user.id.clone(), channel_id.clone());
     }
 
-    // 予約モーダルを作成（更新用のパラメータを渡す）
-    let initial_server = config.servers.first().map(|s| s.name.as_str());
-    let modal_view = reserve::create_reserve_modal(
+    // 既存の予約を取得（非公開の備考を他のユーザーに見せないよう、予約者か管理者に限る）
+    let usage_id = UsageId::from_string(usage_id_str.clone());
+    let usage = match app.get_usage_usecase().execute(&usage_id).await {
+        Ok(usage) => usage,
+        Err(e) => {
+            warn!("⚠️ 編集する予約を取得できませんでした: {}", e);
+            reply(app, block_actions, messages.usage_not_found).await;
+            return Ok(());
+        }
+    };
+
+    // 予約者本人か、予約を所有するチームのメンバーに限る
+    let is_owner = user_resolver::resolve_user_email(&user.id, identity_repo)
+        .await
+        .ok()
+        .and_then(|email| EmailAddress::new(email).ok())
+        .is_some_and(|email| {
+            &email == usage.owner_email()
+                || usage
+                    .group()
+                    .and_then(|group_id| app.create_resource_usage_usecase().group(group_id))
+                    .is_some_and(|group| group.is_member(&email))
+        });
+    if !is_owner && !user_resolver::is_admin(&user.id, identity_repo, config).await {
+        reply(app, block_actions, messages.update_forbidden).await;
+        return Ok(());
+    }
+
+    // 編集中の予約自体は使用中として表示しない
+    let busy_devices = device_availability::busy_devices(
+        &app.reservation_read_model().snapshot(),
         config,
-        None,
-        initial_server,
-        Some(usage_id_str),
-        Some(CALLBACK_RESERVE_UPDATE), // callback_id
-        Some("予約更新"),              // title
-        Some("更新"),                  // submit_text
+        identity_repo,
+        &user.id,
+        usage.time_period(),
+        Some(usage.id()),
+    )
+    .await;
+    let modal_view = reserve::create_edit_modal(
+        config,
+        &usage,
+        &preferences,
+        &busy_devices,
+        &app.device_healths().await,
     );
-
     modals::open(slack_client, bot_token, trigger_id, modal_view).await?;
 
     Ok(())
 }
+
+/// ボタンを押したユーザーにエフェメラルメッセージで結果を通知
+async fn reply<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    message: &str,
+) where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message.to_string()).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+}
@@ -0,0 +1,113 @@
+//! 予約延長ボタンハンドラ
+
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::{messages, modals};
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::modals::{extend, registration};
+use slack_morphism::prelude::*;
+use tracing::{error, warn};
+
+/// 予約延長ボタンのクリックを処理
+///
+/// 延長する時間を選ぶモーダルを開く。延長できるのは予約者本人のみ。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(usage_id_str) = &action.value else {
+        error!("❌ usage_idが取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let slack_client = app.slack_client();
+    let bot_token = &app.bot_token_for(&block_actions.team.id).await;
+    let identity_repo = app.identity_repo();
+    let trigger_id = &block_actions.trigger_id;
+
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+
+    // 未リンク: メールアドレス登録モーダルを表示
+    if !user_resolver::is_user_linked(&user.id, identity_repo).await {
+        let modal = registration::create(messages);
+        modals::open(slack_client, bot_token, trigger_id, modal).await?;
+        return Ok(());
+    }
+
+    // channel_idを取得してuser_channel_mapに登録（エフェメラルメッセージ送信用）
+    if let SlackInteractionActionContainer::Message(msg) = &block_actions.container
+        && let Some(channel_id) = &msg.channel_id
+    {
+        app.user_channel_map()
+            .write()
+            .unwrap()
+            .insert(user.id.clone(), channel_id.clone());
+    }
+
+    let usage_id = UsageId::from_string(usage_id_str.clone());
+    let usage = match app.get_usage_usecase().execute(&usage_id).await {
+        Ok(usage) => usage,
+        Err(e) => {
+            warn!("⚠️ 延長する予約を取得できませんでした: {}", e);
+            reply(app, block_actions, messages.usage_not_found).await;
+            return Ok(());
+        }
+    };
+
+    // 予約者本人か、予約を所有するチームのメンバーに限る
+    let is_owner = user_resolver::resolve_user_email(&user.id, identity_repo)
+        .await
+        .ok()
+        .and_then(|email| EmailAddress::new(email).ok())
+        .is_some_and(|email| {
+            &email == usage.owner_email()
+                || usage
+                    .group()
+                    .and_then(|group_id| app.create_resource_usage_usecase().group(group_id))
+                    .is_some_and(|group| group.is_member(&email))
+        });
+    if !is_owner && !user_resolver::is_admin(&user.id, identity_repo, app.resource_config()).await {
+        reply(app, block_actions, messages.extend_forbidden).await;
+        return Ok(());
+    }
+
+    modals::open(
+        slack_client,
+        bot_token,
+        trigger_id,
+        extend::create(&usage, &preferences),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// ボタンを押したユーザーにエフェメラルメッセージで結果を通知
+async fn reply<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    message: &str,
+) where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message.to_string()).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+}
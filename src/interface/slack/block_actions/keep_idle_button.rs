@@ -0,0 +1,73 @@
+//! 使われていない予約の確認の使用中ボタンハンドラ
+
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::user_resolver;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 使用中ボタンのクリックを処理
+///
+/// 予約者が使用を続けると応答した予約を、この予約期間中は自動で解放しないようにする。
+/// 応答できるのは予約者本人のみ。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(usage_id_str) = &action.value else {
+        error!("❌ usage_idが取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(auto_release_usecase) = app.auto_release_usecase() else {
+        error!("❌ 使われていない予約の自動解放が設定されていません");
+        return Ok(());
+    };
+
+    let owner_email =
+        EmailAddress::new(user_resolver::resolve_user_email(&user.id, app.identity_repo()).await?)?;
+
+    let usage_id = UsageId::from_string(usage_id_str.clone());
+    info!(
+        "▶️ 使われていない予約の継続: usage_id={}",
+        usage_id.as_str()
+    );
+
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+    let message = match auto_release_usecase.keep(&usage_id, &owner_email).await {
+        Ok(()) => messages.kept_idle.to_string(),
+        Err(ApplicationError::Repository(RepositoryError::NotFound)) => {
+            messages.usage_not_found.to_string()
+        }
+        Err(ApplicationError::Unauthorized(_)) => messages.keep_idle_forbidden.to_string(),
+        Err(e) => {
+            error!("❌ 予約の継続に失敗: {}", e);
+            fill(messages.keep_idle_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+
+    Ok(())
+}
@@ -10,9 +10,33 @@
 //! ## モジュール
 //!
 //! - `modal_state_change`: モーダル状態変更（リソースタイプ、サーバー選択）
+//! - `approval_button`: 承認依頼の承認・却下ボタンハンドラ
 //! - `cancel_button`: 予約キャンセルボタンハンドラ
+//! - `clone_button`: もう一度予約ボタンハンドラ
 //! - `edit_button`: 予約編集ボタンハンドラ
+//! - `extend_button`: 予約延長ボタンハンドラ
+//! - `keep_idle_button`: 使われていない予約の確認の使用中ボタンハンドラ
+//! - `quick_extend_button`: 終了前リマインダーの延長ボタンハンドラ
+//! - `release_button`: 予約の早期終了（今すぐ解放）ボタンハンドラ
+//! - `split_reservation_button`: 分割予約ボタンハンドラ
+//! - `suggest_time_button`: 予約モーダルの空いている時間の提案ボタンハンドラ
+//! - `transfer_button`: 予約引き継ぎボタンハンドラ
+//! - `profile_email_button`: プロフィールのメールアドレス連携ボタンハンドラ
+//! - `unlink_button`: 連携解除ボタンハンドラ
+//! - `waitlist_button`: 空き待ちへの登録ボタンハンドラ
 
+pub mod approval_button;
 pub mod cancel_button;
+pub mod clone_button;
 pub mod edit_button;
+pub mod extend_button;
+pub mod keep_idle_button;
 pub mod modal_state_change;
+pub mod profile_email_button;
+pub mod quick_extend_button;
+pub mod release_button;
+pub mod split_reservation_button;
+pub mod suggest_time_button;
+pub mod transfer_button;
+pub mod unlink_button;
+pub mod waitlist_button;
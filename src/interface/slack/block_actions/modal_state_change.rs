@@ -1,15 +1,18 @@
-//! モーダル状態変更ハンドラ（リソースタイプ、サーバー選択）
+//! モーダル状態変更ハンドラ（リソースタイプ、タグ、サーバー選択）
 
 use crate::domain::ports::notifier::Notifier;
 use crate::domain::ports::repositories::ResourceUsageRepository;
 use crate::interface::slack::app::SlackApp;
 use crate::interface::slack::constants::*;
 use crate::interface::slack::slack_client::modals;
+use crate::interface::slack::utility::device_availability;
+use crate::interface::slack::utility::form_validation;
+use crate::interface::slack::utility::user_resolver::UserPreferences;
 use crate::interface::slack::views::modals::reserve;
 use slack_morphism::prelude::*;
 use tracing::{error, info};
 
-/// モーダル状態変更を処理（リソースタイプ選択、サーバー選択）
+/// モーダル状態変更を処理（リソースタイプ選択、タグ選択、サーバー選択）
 ///
 /// 適切なフィールドを表示するようモーダルを動的に更新
 pub async fn handle<R, N>(
@@ -27,7 +30,7 @@ where
     // Get dependencies
     let config = app.resource_config();
     let slack_client = app.slack_client();
-    let bot_token = app.bot_token();
+    let bot_token = &app.bot_token_for(&block_actions.team.id).await;
 
     // Determine new values based on action
     let new_resource_type = if action_id == ACTION_RESERVE_RESOURCE_TYPE {
@@ -39,18 +42,37 @@ where
         None
     };
 
-    // サーバー選択の決定
-    let new_selected_server = if action_id == ACTION_RESERVE_SERVER_SELECT {
-        // サーバーが明示的に選択された場合
+    // デバイスを絞り込むタグ（タグ以外の変更では選択中のタグを保つ）
+    let tag = if action_id == ACTION_RESERVE_TAG {
         action
             .selected_option
             .as_ref()
             .map(|opt| opt.value.as_str())
-    } else if new_resource_type.is_some() && new_resource_type == Some("gpu") {
-        // リソースタイプがGPUに変更された場合、デフォルトのサーバーを選択
-        config.servers.first().map(|s| s.name.as_str())
+            .filter(|value| *value != RESERVE_TAG_ANY_VALUE)
     } else {
-        None
+        block_actions.state.as_ref().and_then(reserve::selected_tag)
+    };
+
+    // デバイス選択を開くサーバーの決定
+    let open_servers: Vec<&str> = if action_id == ACTION_RESERVE_SERVER_SELECT {
+        // サーバーが選択された場合、開いていたサーバーに加えて選択したサーバーを開く
+        let mut servers = block_actions
+            .view
+            .as_ref()
+            .map(|view| reserve::open_servers(config, view))
+            .unwrap_or_default();
+        if let Some(server) = action
+            .selected_option
+            .as_ref()
+            .map(|opt| opt.value.as_str())
+        {
+            servers.retain(|name| *name != server);
+            servers.push(server);
+        }
+        servers
+    } else {
+        // リソースタイプ・タグが変更された場合は、デフォルトのサーバーだけを開く
+        Vec::new()
     };
 
     // Get view_id from container
@@ -69,20 +91,59 @@ where
     };
 
     info!(
-        "📝 選択値: type={:?}, server={:?}",
-        new_resource_type, new_selected_server
+        "📝 選択値: type={:?}, tag={:?}, servers={:?}",
+        new_resource_type, tag, open_servers
     );
 
     // Create updated modal
     info!("🔨 新しいモーダルを作成中...");
-    let updated_modal = reserve::create_reserve_modal(
+    let preferences = match &block_actions.user {
+        Some(user) => app.user_preferences(&block_actions.team.id, &user.id).await,
+        None => UserPreferences {
+            locale: config.i18n.locale,
+            ..Default::default()
+        },
+    };
+    // 入力中の期間（未入力・不正な場合は日時の初期値の期間）に使用中のデバイス
+    let busy_devices = match (
+        &block_actions.user,
+        block_actions
+            .state
+            .as_ref()
+            .and_then(|state| form_validation::time_period_from_state(state, preferences.timezone))
+            .or_else(device_availability::new_reservation_period),
+    ) {
+        (Some(user), Some(period)) => {
+            device_availability::busy_devices(
+                &app.reservation_read_model().snapshot(),
+                config,
+                app.identity_repo(),
+                &user.id,
+                &period,
+                None,
+            )
+            .await
+        }
+        _ => Vec::new(),
+    };
+    // 利用者が入力した日時を保つため、日時の入力欄は同じ版のまま作り直す
+    let datetime = reserve::DateTimeInputs {
+        revision: block_actions
+            .view
+            .as_ref()
+            .map(reserve::datetime_revision)
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+    let updated_modal = reserve::create_reserve_modal_with_datetime(
         config,
         new_resource_type,
-        new_selected_server,
-        None, // No usage_id for modal updates
-        None, // Use default callback_id
-        None, // Use default title
-        None, // Use default submit_text
+        &open_servers,
+        &preferences,
+        &busy_devices,
+        &app.device_healths().await,
+        &datetime,
+        tag,
     );
 
     // Update modal
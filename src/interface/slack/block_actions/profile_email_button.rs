@@ -0,0 +1,167 @@
+//! プロフィールのメールアドレス連携ボタンハンドラ
+
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::{messages, modals};
+use crate::interface::slack::utility::device_availability;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::modals::{registration, reserve};
+use slack_morphism::prelude::*;
+use tracing::{error, info, warn};
+
+/// 「このアドレスで連携」ボタンのクリックを処理
+///
+/// Slackプロフィールのメールアドレスを改めて取得して紐付け、成功したら予約モーダルを開く。
+/// プロフィールからメールアドレスを取得できなくなっていた場合はメール登録モーダルを開く。
+pub async fn handle_link<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+
+    // ボタンの表示後にプロフィールが変更されている可能性があるため、改めて取得する
+    let Some(email) = user_resolver::fetch_profile_email(
+        app.slack_client(),
+        &app.bot_token_for(&block_actions.team.id).await,
+        &user.id,
+    )
+    .await
+    else {
+        info!("プロフィールのメールアドレスを取得できないため、メール登録モーダルを表示します");
+        let modal = registration::create(messages);
+        modals::open(
+            app.slack_client(),
+            &app.bot_token_for(&block_actions.team.id).await,
+            &block_actions.trigger_id,
+            modal,
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let result = app
+        .grant_access_usecase()
+        .execute(
+            ExternalSystem::Slack,
+            user.id.to_string(),
+            app.identity_workspace_id(&block_actions.team.id),
+            email.clone(),
+            Some(user.id.to_string()),
+        )
+        .await;
+
+    let message = match result {
+        Ok(()) => {
+            info!("✅ プロフィールのメールアドレスで連携: {}", email.as_str());
+            let config = app.resource_config();
+            let busy_devices = match device_availability::new_reservation_period() {
+                Some(period) => {
+                    device_availability::busy_devices(
+                        &app.reservation_read_model().snapshot(),
+                        config,
+                        app.identity_repo(),
+                        &user.id,
+                        &period,
+                        None,
+                    )
+                    .await
+                }
+                None => Vec::new(),
+            };
+            let modal = reserve::create_reserve_modal(
+                config,
+                None,
+                &[],
+                None,
+                None,
+                None,
+                None,
+                &preferences,
+                &busy_devices,
+                &app.device_healths().await,
+            );
+            match modals::open(
+                app.slack_client(),
+                &app.bot_token_for(&block_actions.team.id).await,
+                &block_actions.trigger_id,
+                modal,
+            )
+            .await
+            {
+                Ok(_) => fill(messages.email_registered, &[("email", email.as_str())]),
+                Err(e) => {
+                    // アクセス権の付与に時間がかかり trigger_id が失効した場合など
+                    warn!("⚠️ 予約モーダルを開けませんでした: {}", e);
+                    fill(
+                        messages.email_registered_retry,
+                        &[("email", email.as_str())],
+                    )
+                }
+            }
+        }
+        Err(e) => {
+            error!("❌ ユーザー登録に失敗: {}", e);
+            fill(messages.register_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+
+    Ok(())
+}
+
+/// 「別のアドレスを入力」ボタンのクリックを処理
+///
+/// メール登録モーダルを開く
+pub async fn handle_manual<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    if let (Some(user), Some(channel)) = (&block_actions.user, &block_actions.channel) {
+        // 登録結果のエフェメラルメッセージ送信用
+        app.user_channel_map()
+            .write()
+            .unwrap()
+            .insert(user.id.clone(), channel.id.clone());
+    }
+
+    let messages = match &block_actions.user {
+        Some(user) => app
+            .user_preferences(&block_actions.team.id, &user.id)
+            .await
+            .messages(),
+        None => app.resource_config().i18n.locale.messages(),
+    };
+    let modal = registration::create(messages);
+    modals::open(
+        app.slack_client(),
+        &app.bot_token_for(&block_actions.team.id).await,
+        &block_actions.trigger_id,
+        modal,
+    )
+    .await?;
+
+    info!("✅ メールアドレス登録モーダルを開きました");
+    Ok(())
+}
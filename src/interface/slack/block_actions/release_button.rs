@@ -0,0 +1,99 @@
+//! 予約の早期終了（今すぐ解放）ボタンハンドラ
+
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::errors::ResourceUsageError;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::{messages, modals};
+use crate::interface::slack::utility::datetime_parser::to_user_time;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::modals::registration;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 今すぐ解放ボタンのクリックを処理
+///
+/// 使用中の予約の終了時刻を現在時刻に切り詰め、リソースを解放する。
+/// 解放できるのは予約者本人のみ。更新された予約は通常の更新通知で共有される。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(usage_id_str) = &action.value else {
+        error!("❌ usage_idが取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let identity_repo = app.identity_repo();
+
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+
+    // 未リンク: メールアドレス登録モーダルを表示
+    if !user_resolver::is_user_linked(&user.id, identity_repo).await {
+        let modal = registration::create(messages);
+        modals::open(
+            app.slack_client(),
+            &app.bot_token_for(&block_actions.team.id).await,
+            &block_actions.trigger_id,
+            modal,
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let owner_email =
+        EmailAddress::new(user_resolver::resolve_user_email(&user.id, identity_repo).await?)?;
+
+    let usage_id = UsageId::from_string(usage_id_str.clone());
+    info!("⏹ 予約の早期終了要求: usage_id={}", usage_id.as_str());
+
+    let message = match app
+        .release_usage_usecase()
+        .execute(&usage_id, &owner_email)
+        .await
+    {
+        Ok(released) => fill(
+            messages.released,
+            &[(
+                "end",
+                &to_user_time(released.end(), preferences.timezone)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            )],
+        ),
+        Err(ApplicationError::ResourceUsage(ResourceUsageError::NotInProgress)) => {
+            messages.release_not_in_use.to_string()
+        }
+        Err(ApplicationError::Repository(RepositoryError::NotFound)) => {
+            messages.usage_not_found.to_string()
+        }
+        Err(ApplicationError::Unauthorized(_)) => messages.release_forbidden.to_string(),
+        Err(e) => {
+            error!("❌ 予約の早期終了に失敗: {}", e);
+            fill(messages.release_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+
+    Ok(())
+}
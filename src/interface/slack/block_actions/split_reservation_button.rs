@@ -0,0 +1,97 @@
+//! 分割予約ボタンハンドラ
+
+use crate::domain::aggregates::resource_usage::value_objects::Priority;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::split_proposal::SplitReservationPayload;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 「分割して予約する」ボタンのクリックを処理
+///
+/// ボタンに埋め込まれた分割案の各区画をそれぞれ予約し、結果をエフェメラルメッセージで通知する
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(value) = &action.value else {
+        error!("❌ 分割案が取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let payload: SplitReservationPayload = serde_json::from_str(value)?;
+    let notes = payload.notes.clone();
+    let metadata = payload.metadata();
+    let visibility = payload.visibility();
+    let allocations = payload.into_allocations()?;
+    info!("✂️ 分割予約要求: {}区画", allocations.len());
+
+    let owner_email = user_resolver::resolve_user_email(&user.id, app.identity_repo()).await?;
+    let owner_email = EmailAddress::new(owner_email)?;
+
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+    let mut lines = Vec::new();
+    for allocation in allocations {
+        let resources = allocation
+            .resources
+            .iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        match app
+            .create_resource_usage_usecase()
+            .execute(
+                owner_email.clone(),
+                allocation.time_period,
+                allocation.resources,
+                notes.clone(),
+                metadata.clone(),
+                visibility,
+                Priority::Normal,
+                None,
+            )
+            .await
+        {
+            Ok(usage_id) => {
+                info!("✅ 分割予約を作成しました: {}", usage_id.as_str());
+                lines.push(fill(
+                    messages.split_reserved,
+                    &[("resources", &resources), ("usage_id", usage_id.as_str())],
+                ));
+            }
+            Err(e) => {
+                error!("❌ 分割予約の作成に失敗: {}", e);
+                lines.push(fill(
+                    messages.split_failed,
+                    &[("resources", &resources), ("error", &e.to_string())],
+                ));
+            }
+        }
+    }
+
+    let message = format!("{}\n{}", messages.split_result_header, lines.join("\n"));
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+
+    Ok(())
+}
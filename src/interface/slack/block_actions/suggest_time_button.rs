@@ -0,0 +1,245 @@
+//! 空いている時間の提案ボタンハンドラ
+
+use crate::application::usecases::find_next_available_slot::SEARCH_DAYS;
+use crate::domain::aggregates::resource_usage::service::format_time_period;
+use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Resource};
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::config::ResourceConfig;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::constants::*;
+use crate::interface::slack::slack_client::modals;
+use crate::interface::slack::utility::device_availability;
+use crate::interface::slack::utility::form_validation;
+use crate::interface::slack::views::modals::reserve::{self, DateTimeInputs};
+use chrono::{Duration, Utc};
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 空いている時間の提案ボタンを処理
+///
+/// 選択中のリソースが、入力中の期間と同じ長さだけ続けて空いている最も早い時間帯を探し、
+/// 見つかった場合はモーダルの日時に入力する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let (Some(user), Some(view), SlackInteractionActionContainer::View(view_container)) = (
+        &block_actions.user,
+        &block_actions.view,
+        &block_actions.container,
+    ) else {
+        error!("❌ モーダル外のインタラクションです");
+        return Ok(());
+    };
+
+    let config = app.resource_config();
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+    let state = block_actions.state.as_ref();
+
+    let resource_type = state
+        .and_then(|state| selected_value(state, ACTION_RESERVE_RESOURCE_TYPE))
+        .unwrap_or("gpu");
+    let open_servers = reserve::open_servers(config, view);
+    let resources = state
+        .map(|state| resources_from_state(state, resource_type, config))
+        .unwrap_or_default();
+
+    // 入力中の期間と同じ長さ（未入力・不正な場合は1時間）で、入力中の開始時刻以降を探す
+    let entered = state
+        .and_then(|state| form_validation::time_period_from_state(state, preferences.timezone));
+    let duration = entered
+        .as_ref()
+        .map(|period| period.end() - period.start())
+        .unwrap_or_else(|| Duration::hours(1));
+    let not_before = entered
+        .as_ref()
+        .map(|period| period.start())
+        .unwrap_or_default()
+        .max(Utc::now());
+
+    let revision = reserve::datetime_revision(view);
+    let found = if resources.is_empty() {
+        // サーバー・部屋が未選択
+        Ok(None)
+    } else {
+        app.next_slot_usecase()
+            .execute(&resources, duration, not_before)
+            .await
+    };
+    let datetime = match found {
+        Ok(Some(period)) => {
+            info!(
+                "💡 空いている時間帯を提案: {} ~ {}",
+                period.start(),
+                period.end()
+            );
+            let time = format_time_period(&period, preferences.timezone.map(|tz| tz.name()));
+            DateTimeInputs {
+                // 入力済みの日時を置き換えるため、入力欄の版を上げる
+                revision: revision + 1,
+                period: Some(period),
+                note: Some(fill(messages.suggest_time_found, &[("time", &time)])),
+            }
+        }
+        Ok(None) => DateTimeInputs {
+            revision,
+            period: None,
+            note: Some(fill(
+                messages.suggest_time_none,
+                &[("days", &SEARCH_DAYS.to_string())],
+            )),
+        },
+        Err(e) => {
+            error!("❌ 空いている時間帯の検索に失敗: {}", e);
+            DateTimeInputs {
+                revision,
+                period: None,
+                note: Some(fill(
+                    messages.next_free_failed,
+                    &[("error", &e.to_string())],
+                )),
+            }
+        }
+    };
+
+    let busy_devices = match datetime
+        .period
+        .clone()
+        .or(entered)
+        .or_else(device_availability::new_reservation_period)
+    {
+        Some(period) => {
+            device_availability::busy_devices(
+                &app.reservation_read_model().snapshot(),
+                config,
+                app.identity_repo(),
+                &user.id,
+                &period,
+                None,
+            )
+            .await
+        }
+        None => Vec::new(),
+    };
+
+    let modal = reserve::create_reserve_modal_with_datetime(
+        config,
+        Some(resource_type),
+        &open_servers,
+        &preferences,
+        &busy_devices,
+        &app.device_healths().await,
+        &datetime,
+        state.and_then(reserve::selected_tag),
+    );
+    modals::update(
+        app.slack_client(),
+        &app.bot_token_for(&block_actions.team.id).await,
+        &view_container.view_id,
+        modal,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// 入力途中のモーダルで選択されているオプションの値を取得
+fn selected_value<'a>(state: &'a SlackViewState, action_id: &str) -> Option<&'a str> {
+    let action_id = SlackActionId::new(action_id.to_string());
+    state
+        .values
+        .values()
+        .find_map(|actions| actions.get(&action_id))
+        .and_then(|value| value.selected_option.as_ref())
+        .map(|option| option.value.as_str())
+}
+
+/// 入力途中のモーダルで選択されているリソースを取得
+///
+/// 予約の送信時と同じく、デバイスを1つも選択していない場合は選択中のサーバーのすべてのデバイス
+/// （タグで絞り込んでいる場合はタグの付いたデバイス）とする。
+fn resources_from_state(
+    state: &SlackViewState,
+    resource_type: &str,
+    config: &ResourceConfig,
+) -> Vec<Resource> {
+    match resource_type {
+        "gpu" => {
+            let mut resources = Vec::new();
+            for server in &config.servers {
+                let selected = state
+                    .values
+                    .get(&SlackBlockId::new(reserve::devices_block_id(&server.name)))
+                    .and_then(|actions| {
+                        actions.get(&SlackActionId::new(ACTION_RESERVE_DEVICES.to_string()))
+                    })
+                    .and_then(|value| value.selected_options.as_ref());
+                for option in selected.into_iter().flatten() {
+                    if let Some(device) = server
+                        .devices
+                        .iter()
+                        .find(|device| device.id.to_string() == option.value)
+                    {
+                        resources.push(Resource::Gpu(Gpu::new(
+                            server.name.clone(),
+                            device.id,
+                            device.model.clone(),
+                        )));
+                    }
+                }
+            }
+            if !resources.is_empty() {
+                return resources;
+            }
+
+            selected_value(state, ACTION_RESERVE_SERVER_SELECT)
+                .and_then(|name| config.servers.iter().find(|server| server.name == name))
+                .map(|server| {
+                    let tag = reserve::selected_tag(state);
+                    server
+                        .devices
+                        .iter()
+                        .filter(|device| tag.is_none_or(|tag| server.device_has_tag(device, tag)))
+                        .map(|device| {
+                            Resource::Gpu(Gpu::new(
+                                server.name.clone(),
+                                device.id,
+                                device.model.clone(),
+                            ))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+        "room" => selected_value(state, ACTION_RESERVE_ROOM_SELECT)
+            .map(|name| {
+                vec![Resource::Room {
+                    name: name.to_string(),
+                }]
+            })
+            .unwrap_or_default(),
+        "instrument" => selected_value(state, ACTION_RESERVE_INSTRUMENT_SELECT)
+            .map(|name| {
+                vec![Resource::Instrument {
+                    name: name.to_string(),
+                }]
+            })
+            .unwrap_or_default(),
+        other => reserve::custom_kind(other)
+            .zip(selected_value(state, ACTION_RESERVE_CUSTOM_SELECT))
+            .map(|(kind, name)| {
+                vec![Resource::Custom {
+                    kind: kind.to_string(),
+                    name: name.to_string(),
+                }]
+            })
+            .unwrap_or_default(),
+    }
+}
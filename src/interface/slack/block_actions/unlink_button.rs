@@ -0,0 +1,56 @@
+//! 連携解除ボタンハンドラ
+
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::messages;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 「連携を解除する」ボタンのクリックを処理
+///
+/// ボタンを押したユーザー自身の紐付けを解除し、結果をエフェメラルメッセージで通知する
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let preferences = app.user_preferences(&block_actions.team.id, &user.id).await;
+    let messages = preferences.messages();
+    let message = match app
+        .revoke_access_usecase()
+        .execute(
+            ExternalSystem::Slack,
+            user.id.as_ref(),
+            Some(user.id.to_string()),
+        )
+        .await
+    {
+        Ok(email) => {
+            info!("✅ 連携を解除しました: {} ({})", user.id, email.as_str());
+            fill(messages.unlinked, &[("email", email.as_str())])
+        }
+        Err(e) => {
+            error!("❌ 連携の解除に失敗: {}", e);
+            fill(messages.unlink_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! 空き待ちへの登録ボタンハンドラ
+
+use crate::domain::aggregates::waitlist::WaitlistEntry;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::waitlist::WaitlistPayload;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 「空いたら知らせる」ボタンのクリックを処理
+///
+/// ボタンに埋め込まれた時間帯・リソースで空き待ちに登録し、結果をエフェメラルメッセージで通知する
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    block_actions: &SlackInteractionBlockActionsEvent,
+    action: &SlackInteractionActionInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let Some(value) = &action.value else {
+        error!("❌ 空き待ちの希望が取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(user) = &block_actions.user else {
+        error!("❌ ユーザー情報が取得できませんでした");
+        return Ok(());
+    };
+
+    let Some(join_waitlist_usecase) = app.join_waitlist_usecase() else {
+        error!("❌ 空き待ちが設定されていません");
+        return Ok(());
+    };
+
+    let payload: WaitlistPayload = serde_json::from_str(value)?;
+    let (time_period, resources) = payload.into_request()?;
+    info!("🔔 空き待ちへの登録要求: {:?}", resources);
+
+    let owner_email = user_resolver::resolve_user_email(&user.id, app.identity_repo()).await?;
+    let owner_email = EmailAddress::new(owner_email)?;
+
+    let messages = app
+        .user_preferences(&block_actions.team.id, &user.id)
+        .await
+        .messages();
+    let result = match WaitlistEntry::new(owner_email, time_period, resources) {
+        Ok(entry) => join_waitlist_usecase.execute(entry).await,
+        Err(e) => Err(e.into()),
+    };
+    let message = match result {
+        Ok(true) => {
+            info!("✅ 空き待ちに登録しました");
+            messages.waitlist_joined.to_string()
+        }
+        Ok(false) => messages.waitlist_already_joined.to_string(),
+        Err(e) => {
+            error!("❌ 空き待ちへの登録に失敗: {}", e);
+            fill(messages.waitlist_join_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    if let Some(response_url) = &block_actions.response_url {
+        messages::send_ephemeral(app.http_client(), response_url, message).await;
+    } else {
+        error!("❌ response_urlが取得できないため、結果を通知できませんでした");
+    }
+
+    Ok(())
+}
@@ -3,12 +3,18 @@
 //! Slackインタラクションで使用されるアクションID、コールバックID、その他の定数
 
 // アクションID - 予約フォーム
-/// リソースタイプ選択（GPU/部屋）のラジオボタンアクション
+/// リソースタイプ選択（GPU/部屋/機器/設定で定義した種別）のラジオボタンアクション
 pub const ACTION_RESERVE_RESOURCE_TYPE: &str = "reserve_resource_type";
 /// サーバー選択のセレクトメニューアクション
 pub const ACTION_RESERVE_SERVER_SELECT: &str = "reserve_server_select";
 /// 部屋選択のセレクトメニューアクション
 pub const ACTION_RESERVE_ROOM_SELECT: &str = "reserve_room_select";
+/// 機器選択のセレクトメニューアクション
+pub const ACTION_RESERVE_INSTRUMENT_SELECT: &str = "reserve_instrument_select";
+/// 設定で定義した種別のリソース選択のセレクトメニューアクション
+pub const ACTION_RESERVE_CUSTOM_SELECT: &str = "reserve_custom_select";
+/// 設定で定義したリソース種別のリソースタイプの値の接頭辞（`custom:<種別ID>`）
+pub const RESERVE_CUSTOM_TYPE_PREFIX: &str = "custom:";
 /// デバイス（GPU）選択のチェックボックスアクション
 pub const ACTION_RESERVE_DEVICES: &str = "reserve_devices";
 /// 予約開始日の日付ピッカーアクション
@@ -21,6 +27,36 @@ pub const ACTION_RESERVE_END_DATE: &str = "reserve_end_date";
 pub const ACTION_RESERVE_END_TIME: &str = "reserve_end_time";
 /// 備考入力のテキストエリアアクション
 pub const ACTION_RESERVE_NOTES: &str = "reserve_notes";
+/// プロジェクト名の入力アクション
+pub const ACTION_RESERVE_PROJECT: &str = "reserve_project";
+/// 実験IDの入力アクション
+pub const ACTION_RESERVE_EXPERIMENT: &str = "reserve_experiment";
+/// 想定使用率の数値入力アクション
+pub const ACTION_RESERVE_UTILIZATION: &str = "reserve_utilization";
+/// 非公開予約のチェックボックスアクション
+pub const ACTION_RESERVE_PRIVATE: &str = "reserve_private";
+/// 非公開予約チェックボックスの選択肢の値
+pub const RESERVE_PRIVATE_OPTION_VALUE: &str = "private";
+/// 優先度のセレクトメニューアクション
+pub const ACTION_RESERVE_PRIORITY: &str = "reserve_priority";
+/// デバイスをタグで絞り込むセレクトメニューアクション
+pub const ACTION_RESERVE_TAG: &str = "reserve_tag";
+/// 予約を所有するチームのセレクトメニューアクション
+pub const ACTION_RESERVE_GROUP: &str = "reserve_group";
+/// 横取りのチェックボックスアクション
+pub const ACTION_RESERVE_PREEMPT: &str = "reserve_preempt";
+/// 横取りチェックボックスの選択肢の値
+pub const RESERVE_PREEMPT_OPTION_VALUE: &str = "preempt";
+/// 繰り返し（毎週/隔週）のセレクトメニューアクション
+pub const ACTION_RESERVE_REPEAT: &str = "reserve_repeat";
+/// 繰り返しの終了日の日付ピッカーアクション
+pub const ACTION_RESERVE_REPEAT_UNTIL: &str = "reserve_repeat_until";
+/// 繰り返さない場合の選択肢の値
+pub const RESERVE_REPEAT_NONE_VALUE: &str = "none";
+/// タグで絞り込まない場合の選択肢の値
+pub const RESERVE_TAG_ANY_VALUE: &str = "*";
+/// 空いている時間を提案するボタンのアクション
+pub const ACTION_RESERVE_SUGGEST_TIME: &str = "reserve_suggest_time";
 
 // モーダルコールバックID
 /// メールアドレス登録モーダルのコールバックID
@@ -31,11 +67,27 @@ pub const CALLBACK_LINK_USER: &str = "link_user";
 pub const CALLBACK_RESERVE_SUBMIT: &str = "reserve_submit";
 /// 予約更新モーダルのコールバックID
 pub const CALLBACK_RESERVE_UPDATE: &str = "reserve_update";
+/// 予約延長モーダルのコールバックID
+pub const CALLBACK_EXTEND_RESERVATION: &str = "extend_reservation_submit";
+/// 予約引き継ぎモーダルのコールバックID
+pub const CALLBACK_TRANSFER_RESERVATION: &str = "transfer_reservation_submit";
+/// 予約一括キャンセルモーダルのコールバックID
+pub const CALLBACK_CANCEL_ALL: &str = "cancel_all_submit";
+
+// アクションID - 予約一括キャンセルモーダル
+/// キャンセルする予約のチェックボックスのアクション
+pub const ACTION_CANCEL_ALL_SELECT: &str = "cancel_all_select";
 
 // アクションID - メールアドレス登録モーダル
 /// メールアドレス入力フィールドのアクション
 pub const ACTION_EMAIL_INPUT: &str = "email_input";
 
+// アクションID - プロフィールのメールアドレス連携メッセージ
+/// プロフィールのメールアドレスで連携するボタンのアクション
+pub const ACTION_LINK_PROFILE_EMAIL: &str = "link_profile_email";
+/// 別のメールアドレスを入力するボタンのアクション
+pub const ACTION_ENTER_EMAIL_MANUALLY: &str = "enter_email_manually";
+
 // アクションID - ユーザーリンクモーダル
 /// ユーザー選択フィールドのアクション
 pub const ACTION_USER_SELECT: &str = "user_select";
@@ -47,3 +99,47 @@ pub const ACTION_LINK_EMAIL_INPUT: &str = "link_email_input";
 pub const ACTION_EDIT_RESERVATION: &str = "edit_reservation";
 /// 予約キャンセルボタンのアクション
 pub const ACTION_CANCEL_RESERVATION: &str = "cancel_reservation";
+/// 予約延長ボタンのアクション
+pub const ACTION_EXTEND_RESERVATION: &str = "extend_reservation";
+/// 使用中の予約を今すぐ終了する（解放する）ボタンのアクション
+pub const ACTION_RELEASE_RESERVATION: &str = "release_reservation";
+/// 予約を別のユーザーに引き継ぐボタンのアクション
+pub const ACTION_TRANSFER_RESERVATION: &str = "transfer_reservation";
+/// 同じリソース・長さでもう一度予約するボタンのアクション
+pub const ACTION_CLONE_RESERVATION: &str = "clone_reservation";
+/// 終了前リマインダーの、予約をその場で延長するボタンのアクション
+pub const ACTION_QUICK_EXTEND_RESERVATION: &str = "quick_extend_reservation";
+/// 終了前リマインダーの延長ボタンで延長する時間（分）
+pub const QUICK_EXTEND_MINUTES: i64 = 60;
+/// 使われていない予約の確認の、使用を続けるボタンのアクション
+pub const ACTION_KEEP_IDLE_RESERVATION: &str = "keep_idle_reservation";
+
+// アクションID - 承認依頼メッセージ
+/// 承認待ちの予約を承認するボタンのアクション
+pub const ACTION_APPROVE_RESERVATION: &str = "approve_reservation";
+/// 承認待ちの予約を却下するボタンのアクション
+pub const ACTION_REJECT_RESERVATION: &str = "reject_reservation";
+
+// アクションID - 予約延長モーダル
+/// 延長する時間（+1時間/+2時間/その他）のラジオボタンアクション
+pub const ACTION_EXTEND_DURATION: &str = "extend_duration";
+/// 「その他」を選んだときの延長時間（分）の入力アクション
+pub const ACTION_EXTEND_CUSTOM_MINUTES: &str = "extend_custom_minutes";
+/// 延長時間を直接入力する選択肢の値
+pub const EXTEND_CUSTOM_VALUE: &str = "custom";
+
+// アクションID - 予約引き継ぎモーダル
+/// 引き継ぎ先のユーザー選択のアクション
+pub const ACTION_TRANSFER_NEW_OWNER: &str = "transfer_new_owner";
+
+// アクションID - 分割予約の提案メッセージ
+/// 分割予約確定ボタンのアクション
+pub const ACTION_CONFIRM_SPLIT_RESERVATION: &str = "confirm_split_reservation";
+
+// アクションID - 空き待ちの案内メッセージ
+/// 空き待ちへの登録ボタンのアクション
+pub const ACTION_JOIN_WAITLIST: &str = "join_waitlist";
+
+// アクションID - 連携解除の確認メッセージ
+/// 自分の連携を解除するボタンのアクション
+pub const ACTION_CONFIRM_UNLINK_SELF: &str = "confirm_unlink_self";
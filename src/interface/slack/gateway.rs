@@ -4,8 +4,13 @@
 
 use crate::domain::ports::notifier::Notifier;
 use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+#[cfg(feature = "metrics")]
+use crate::infrastructure::metrics::metrics;
 use crate::interface::slack::app::SlackApp;
 use crate::interface::slack::constants::*;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::views::messages::error;
 use slack_morphism::prelude::*;
 use tracing::error;
 
@@ -24,6 +29,19 @@ where
     pub async fn route_slash_command(
         &self,
         event: SlackCommandEvent,
+    ) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>> {
+        #[cfg(feature = "metrics")]
+        let (command, started) = (event.command.0.clone(), std::time::Instant::now());
+        let result = self.dispatch_slash_command(event).await;
+        #[cfg(feature = "metrics")]
+        metrics().record_command(&command, started.elapsed());
+        result
+    }
+
+    /// スラッシュコマンドをコマンド名に対応するハンドラに振り分ける
+    async fn dispatch_slash_command(
+        &self,
+        event: SlackCommandEvent,
     ) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>> {
         let command = event.command.0.as_str();
 
@@ -41,12 +59,52 @@ where
                 crate::interface::slack::slash_commands::register_calendar::handle(self, event)
                     .await
             }
+            "/link-history" => {
+                crate::interface::slack::slash_commands::link_history::handle(self, event).await
+            }
             "/link-user" => {
                 crate::interface::slack::slash_commands::link_user::handle(self, event).await
             }
-            _ => Ok(SlackCommandEventResponse::new(
-                SlackMessageContent::new().with_text(format!("不明なコマンド: {}", command)),
-            )),
+            "/availability" => {
+                crate::interface::slack::slash_commands::availability::handle(self, event).await
+            }
+            "/cancel-all" => {
+                crate::interface::slack::slash_commands::cancel_all::handle(self, event).await
+            }
+            "/admin-cancel" => {
+                crate::interface::slack::slash_commands::admin_cancel::handle(self, event).await
+            }
+            "/freeze-resource" => {
+                crate::interface::slack::slash_commands::freeze_resource::handle(self, event).await
+            }
+            "/device-status" => {
+                crate::interface::slack::slash_commands::device_status::handle(self, event).await
+            }
+            "/unlink-user" => {
+                crate::interface::slack::slash_commands::unlink_user::handle(self, event).await
+            }
+            "/next-free" => {
+                crate::interface::slack::slash_commands::next_free::handle(self, event).await
+            }
+            "/whois-using" => {
+                crate::interface::slack::slash_commands::whois_using::handle(self, event).await
+            }
+            "/usage-stats" => {
+                crate::interface::slack::slash_commands::usage_stats::handle(self, event).await
+            }
+            "/cost-report" => {
+                crate::interface::slack::slash_commands::cost_report::handle(self, event).await
+            }
+            _ => {
+                let messages = self
+                    .user_preferences(&event.team_id, &event.user_id)
+                    .await
+                    .messages();
+                Ok(SlackCommandEventResponse::new(
+                    SlackMessageContent::new()
+                        .with_text(fill(messages.unknown_command, &[("command", command)])),
+                ))
+            }
         }
     }
 
@@ -85,7 +143,7 @@ where
             _ => None,
         };
 
-        match callback_id.as_deref() {
+        let result = match callback_id.as_deref() {
             Some(CALLBACK_REGISTER_EMAIL) => {
                 crate::interface::slack::view_submissions::registration::handle(
                     self,
@@ -105,10 +163,62 @@ where
                 crate::interface::slack::view_submissions::update::handle(self, view_submission)
                     .await
             }
+            Some(CALLBACK_EXTEND_RESERVATION) => {
+                crate::interface::slack::view_submissions::extend::handle(self, view_submission)
+                    .await
+            }
+            Some(CALLBACK_TRANSFER_RESERVATION) => {
+                crate::interface::slack::view_submissions::transfer::handle(self, view_submission)
+                    .await
+            }
+            Some(CALLBACK_CANCEL_ALL) => {
+                crate::interface::slack::view_submissions::cancel_all::handle(self, view_submission)
+                    .await
+            }
             _ => {
                 error!("❌ 不明なcallback_id: {:?}", callback_id);
                 Ok(None)
             }
+        };
+
+        if let Err(e) = &result {
+            self.notify_submission_failure(view_submission, &e.to_string())
+                .await;
+        }
+        result
+    }
+
+    /// モーダル送信の処理に失敗したことを送信者に伝える
+    ///
+    /// Socket Modeではモーダルが先に閉じるため、エラーの理由を
+    /// エフェメラルメッセージ（送れなければDM）で送る。
+    async fn notify_submission_failure(
+        &self,
+        view_submission: &SlackInteractionViewSubmissionEvent,
+        reason: &str,
+    ) {
+        let user_id = &view_submission.user.id;
+        let messages = self
+            .user_preferences(&view_submission.team.id, user_id)
+            .await
+            .messages();
+        // 理由がカタログのエラーメッセージの場合は、見出しと記号が重ならないようにする
+        let content = error::create_with_details(
+            messages.invalid_submission,
+            reason.trim_start_matches("❌ "),
+        );
+        let channel_id = self
+            .user_channel_map()
+            .read()
+            .unwrap()
+            .get(user_id)
+            .cloned();
+
+        let bot_token = self.bot_token_for(&view_submission.team.id).await;
+        let session = self.slack_client().open_session(&bot_token);
+        let delivery = messages::ResultDelivery::for_user(channel_id, user_id, content);
+        if let Err(e) = messages::deliver_result(&session, delivery).await {
+            error!("❌ 送信失敗の通知に失敗: {}", e);
         }
     }
 
@@ -148,6 +258,70 @@ where
                     )
                     .await?
                 }
+                ACTION_EXTEND_RESERVATION => {
+                    crate::interface::slack::block_actions::extend_button::handle(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
+                ACTION_CLONE_RESERVATION => {
+                    crate::interface::slack::block_actions::clone_button::handle(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
+                ACTION_TRANSFER_RESERVATION => {
+                    crate::interface::slack::block_actions::transfer_button::handle(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
+                ACTION_QUICK_EXTEND_RESERVATION => {
+                    crate::interface::slack::block_actions::quick_extend_button::handle(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
+                ACTION_KEEP_IDLE_RESERVATION => {
+                    crate::interface::slack::block_actions::keep_idle_button::handle(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
+                ACTION_RELEASE_RESERVATION => {
+                    crate::interface::slack::block_actions::release_button::handle(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
+                ACTION_APPROVE_RESERVATION => {
+                    crate::interface::slack::block_actions::approval_button::handle_approve(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
+                ACTION_REJECT_RESERVATION => {
+                    crate::interface::slack::block_actions::approval_button::handle_reject(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
                 ACTION_CANCEL_RESERVATION => {
                     crate::interface::slack::block_actions::cancel_button::handle(
                         self,
@@ -156,6 +330,43 @@ where
                     )
                     .await?
                 }
+                ACTION_LINK_PROFILE_EMAIL => {
+                    crate::interface::slack::block_actions::profile_email_button::handle_link(
+                        self,
+                        block_actions,
+                    )
+                    .await?
+                }
+                ACTION_ENTER_EMAIL_MANUALLY => {
+                    crate::interface::slack::block_actions::profile_email_button::handle_manual(
+                        self,
+                        block_actions,
+                    )
+                    .await?
+                }
+                ACTION_CONFIRM_UNLINK_SELF => {
+                    crate::interface::slack::block_actions::unlink_button::handle(
+                        self,
+                        block_actions,
+                    )
+                    .await?
+                }
+                ACTION_CONFIRM_SPLIT_RESERVATION => {
+                    crate::interface::slack::block_actions::split_reservation_button::handle(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
+                ACTION_JOIN_WAITLIST => {
+                    crate::interface::slack::block_actions::waitlist_button::handle(
+                        self,
+                        block_actions,
+                        action,
+                    )
+                    .await?
+                }
                 _ => {}
             }
         }
@@ -176,7 +387,9 @@ where
             let action_id = action.action_id.to_string();
 
             match action_id.as_str() {
-                ACTION_RESERVE_RESOURCE_TYPE | ACTION_RESERVE_SERVER_SELECT => {
+                ACTION_RESERVE_RESOURCE_TYPE
+                | ACTION_RESERVE_TAG
+                | ACTION_RESERVE_SERVER_SELECT => {
                     crate::interface::slack::block_actions::modal_state_change::handle(
                         self,
                         block_actions,
@@ -184,6 +397,13 @@ where
                     )
                     .await?
                 }
+                ACTION_RESERVE_SUGGEST_TIME => {
+                    crate::interface::slack::block_actions::suggest_time_button::handle(
+                        self,
+                        block_actions,
+                    )
+                    .await?
+                }
                 _ => {
                     // その他のモーダルアクションは送信時に処理
                 }
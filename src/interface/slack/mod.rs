@@ -20,6 +20,7 @@
 //! - `async_execution`: バックグラウンドタスク管理（非同期処理）
 //! - `views`: UIコンポーネント定義（モーダル、メッセージのビルダー）
 //! - `constants`: アクションID、コールバックIDなどの定数
+//! - `oauth`: 複数ワークスペースへのインストール（OAuth）用HTTPサーバー
 //!
 //! ## Slack APIとの対応
 //!
@@ -36,6 +37,7 @@ pub mod async_execution;
 pub mod block_actions;
 pub mod constants;
 pub mod gateway;
+pub mod oauth;
 pub mod slack_client;
 pub mod slash_commands;
 pub mod utility;
@@ -0,0 +1,229 @@
+//! 複数ワークスペースへのインストール（Slack OAuth）
+//!
+//! インストール用のHTTPサーバーを起動し、`/slack/install` からSlackの認可画面へ誘導する。
+//! 認可後のコールバックで発行されたBot Tokenを、ワークスペース（Team ID）ごとに保存する。
+
+use crate::domain::ports::repositories::WorkspaceTokenRepository;
+use crate::infrastructure::config::SlackOAuthConfig;
+use crate::infrastructure::i18n::Messages;
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use hyper_util::server::graceful::GracefulShutdown;
+use slack_morphism::prelude::*;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// インストールを開始するパス
+pub const INSTALL_PATH: &str = "/slack/install";
+/// インストール完了後のリダイレクト先
+const INSTALLED_PATH: &str = "/slack/installed";
+/// インストールが取り消されたときのリダイレクト先
+const CANCELLED_PATH: &str = "/slack/cancelled";
+/// インストールに失敗したときのリダイレクト先
+const ERROR_PATH: &str = "/slack/install-error";
+
+type ServiceResult =
+    Result<Response<http_body_util::combinators::BoxBody<Bytes, Infallible>>, BoxError>;
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// OAuthのインストール用HTTPサーバーを実行
+///
+/// `stop` が取り消されると新しい接続の受け付けをやめ、処理中のリクエストを終えてから戻る。
+/// それ以外では、待ち受けに失敗した場合のみ終了する。
+/// インストール結果のページは `messages` の言語で表示する。
+pub async fn serve(
+    config: &SlackOAuthConfig,
+    workspace_token_repo: Arc<dyn WorkspaceTokenRepository>,
+    messages: &'static Messages,
+    stop: CancellationToken,
+) -> Result<(), BoxError> {
+    let oauth_config = Arc::new(listener_config(config)?);
+
+    let client = Arc::new(SlackClient::new(SlackClientHyperConnector::new()?));
+    let environment = Arc::new(
+        SlackClientEventsListenerEnvironment::new(client).with_user_state(workspace_token_repo),
+    );
+    let routes = chain_service_routes_fn(
+        SlackClientEventsHyperListener::new(environment)
+            .oauth_service_fn(oauth_config, on_installed),
+        move |req| result_page(req, messages),
+    );
+
+    let listener = TcpListener::bind(&config.listen_addr).await?;
+    info!(
+        "🔑 インストール用のHTTPサーバーを起動しました: {}{}",
+        config.listen_addr, INSTALL_PATH
+    );
+
+    let graceful = GracefulShutdown::new();
+    loop {
+        let stream = tokio::select! {
+            accepted = listener.accept() => accepted?.0,
+            _ = stop.cancelled() => break,
+        };
+        let connection = graceful.watch(
+            http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service_fn(routes.clone())),
+        );
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                error!("❌ インストール用HTTP接続のエラー: {}", e);
+            }
+        });
+    }
+    graceful.shutdown().await;
+    info!("🔑 インストール用のHTTPサーバーを停止しました");
+    Ok(())
+}
+
+/// slack-morphismのOAuth設定を作成
+///
+/// リダイレクトURLをホスト部分とパス部分に分け、パス部分でコールバックを受け付ける。
+fn listener_config(config: &SlackOAuthConfig) -> Result<SlackOAuthListenerConfig, BoxError> {
+    let redirect_url = reqwest::Url::parse(&config.redirect_url)?;
+
+    Ok(SlackOAuthListenerConfig::new(
+        config.client_id.clone().into(),
+        config.client_secret.clone().into(),
+        config.scopes.clone(),
+        redirect_url.origin().ascii_serialization(),
+    )
+    .with_install_path(INSTALL_PATH.to_string())
+    .with_redirect_callback_path(redirect_url.path().to_string())
+    .with_redirect_installed_url(INSTALLED_PATH.to_string())
+    .with_redirect_cancelled_url(CANCELLED_PATH.to_string())
+    .with_redirect_error_redirect_url(ERROR_PATH.to_string()))
+}
+
+/// インストール完了時にワークスペースのBot Tokenを保存
+async fn on_installed(
+    response: SlackOAuthV2AccessTokenResponse,
+    _client: Arc<SlackHyperClient>,
+    state: SlackClientEventsUserState,
+) {
+    let Some(repo) = state
+        .read()
+        .await
+        .get_user_state::<Arc<dyn WorkspaceTokenRepository>>()
+        .cloned()
+    else {
+        error!("❌ ワークスペースのトークン保存先が見つかりません");
+        return;
+    };
+
+    let team_id = response.team.id.to_string();
+    match repo.save(&team_id, response.access_token.as_ref()).await {
+        Ok(()) => info!(
+            "✅ ワークスペースにインストールされました: {} ({})",
+            response.team.name.as_deref().unwrap_or("名前なし"),
+            team_id
+        ),
+        Err(e) => error!("❌ ワークスペースのトークン保存に失敗: {}", e),
+    }
+}
+
+/// インストール結果のページ（OAuth以外のパスへのリクエスト）
+async fn result_page(req: Request<Incoming>, messages: &'static Messages) -> ServiceResult {
+    let (status, message) = match req.uri().path() {
+        INSTALLED_PATH => (StatusCode::OK, messages.install_completed),
+        CANCELLED_PATH => (StatusCode::OK, messages.install_cancelled),
+        ERROR_PATH => (StatusCode::OK, messages.install_failed),
+        _ => (StatusCode::NOT_FOUND, "Not Found"),
+    };
+
+    Ok(Response::builder()
+        .status(status)
+        .header(hyper::header::CONTENT_TYPE, "text/plain; charset=utf-8")
+        .body(Full::new(Bytes::from(message)).boxed())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::repositories::workspace_token::JsonFileWorkspaceTokenRepository;
+
+    #[tokio::test]
+    async fn test_serve_stops_gracefully_with_open_connections() {
+        rustls::crypto::ring::default_provider()
+            .install_default()
+            .ok();
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let config = SlackOAuthConfig {
+            client_id: "123.456".to_string(),
+            client_secret: "secret".to_string(),
+            redirect_url: format!("http://{}/slack/oauth/callback", addr),
+            listen_addr: addr.to_string(),
+            scopes: "commands".to_string(),
+        };
+        let stop = CancellationToken::new();
+        let server = tokio::spawn({
+            let stop = stop.clone();
+            async move {
+                serve(
+                    &config,
+                    Arc::new(JsonFileWorkspaceTokenRepository::new(
+                        std::env::temp_dir()
+                            .join(format!("lrm_oauth_tokens_{}.json", uuid::Uuid::new_v4())),
+                    )),
+                    crate::infrastructure::i18n::Locale::Ja.messages(),
+                    stop,
+                )
+                .await
+                .map_err(|e| e.to_string())
+            }
+        });
+
+        // 接続を開いたまま（keep-alive）にするクライアント
+        let client = reqwest::Client::new();
+        let url = format!("http://{}{}", addr, INSTALLED_PATH);
+        let mut response = client.get(&url).send().await;
+        for _ in 0..50 {
+            if response.is_ok() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            response = client.get(&url).send().await;
+        }
+        assert!(response.unwrap().status().is_success());
+
+        stop.cancel();
+        let stopped = tokio::time::timeout(std::time::Duration::from_secs(5), server)
+            .await
+            .expect("停止の合図から5秒以内に終了するはず");
+        assert_eq!(stopped.unwrap(), Ok(()));
+    }
+
+    #[test]
+    fn test_listener_config_splits_redirect_url() {
+        let config = SlackOAuthConfig {
+            client_id: "123.456".to_string(),
+            client_secret: "secret".to_string(),
+            redirect_url: "https://lrm.example.com:8443/slack/oauth/callback".to_string(),
+            listen_addr: "0.0.0.0:3000".to_string(),
+            scopes: "commands,chat:write".to_string(),
+        };
+
+        let oauth_config = listener_config(&config).unwrap();
+
+        assert_eq!(
+            oauth_config.redirect_callback_host,
+            "https://lrm.example.com:8443"
+        );
+        assert_eq!(oauth_config.redirect_callback_path, "/slack/oauth/callback");
+        assert_eq!(oauth_config.install_path, INSTALL_PATH);
+        assert_eq!(
+            oauth_config.to_redirect_url().unwrap().as_str(),
+            config.redirect_url
+        );
+    }
+}
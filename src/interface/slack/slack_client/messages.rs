@@ -1,9 +1,12 @@
 //! Slack message operations
 //!
 //! Wrappers around Slack API for message operations
+//!
+//! All calls go through the shared Slack rate limiter.
 
+use crate::infrastructure::rate_limit::RateLimiter;
 use slack_morphism::prelude::*;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 /// response URL経由でフォローアップメッセージを送信
 ///
@@ -21,10 +24,8 @@ pub async fn send_followup(
         "response_type": "in_channel"
     });
 
-    match http_client
-        .post(response_url.0.as_str())
-        .json(&payload)
-        .send()
+    match RateLimiter::slack()
+        .send(http_client.post(response_url.0.as_str()).json(&payload))
         .await
     {
         Ok(_) => info!("✅ Follow-up message sent successfully"),
@@ -48,13 +49,183 @@ pub async fn send_ephemeral(
         "response_type": "ephemeral"
     });
 
-    match http_client
-        .post(response_url.0.as_str())
-        .json(&payload)
-        .send()
+    match RateLimiter::slack()
+        .send(http_client.post(response_url.0.as_str()).json(&payload))
         .await
     {
         Ok(_) => info!("✅ Ephemeral message sent successfully"),
         Err(e) => error!("❌ Failed to send ephemeral message: {}", e),
     }
 }
+
+/// response URL経由で、ボタンを含む元のメッセージを置き換える
+///
+/// # 引数
+/// * `http_client` - HTTP client
+/// * `response_url` - Slack response URL from the event
+/// * `message` - Message text to replace the original with
+pub async fn replace_original(
+    http_client: &reqwest::Client,
+    response_url: &SlackResponseUrl,
+    message: String,
+) {
+    let payload = serde_json::json!({
+        "text": message,
+        "replace_original": true
+    });
+
+    match RateLimiter::slack()
+        .send(http_client.post(response_url.0.as_str()).json(&payload))
+        .await
+    {
+        Ok(_) => info!("✅ Original message replaced successfully"),
+        Err(e) => error!("❌ Failed to replace original message: {}", e),
+    }
+}
+
+/// エフェメラルメッセージを送信し、送れなければDMで送る
+///
+/// Botが参加していないチャンネルなどでエフェメラルメッセージを送れない場合も、
+/// 結果が利用者に届くようにする。
+///
+/// # 引数
+/// * `session` - Slack API session
+/// * `request` - Ephemeral message request (channel, user and content)
+pub async fn post_ephemeral_or_dm(
+    session: &SlackClientSession<'_, SlackClientHyperHttpsConnector>,
+    request: &SlackApiChatPostEphemeralRequest,
+) -> ClientResult<()> {
+    match RateLimiter::slack()
+        .call(|| session.chat_post_ephemeral(request))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            warn!(
+                "⚠️ エフェメラルメッセージを送信できないため、DMで送信します: {}",
+                e
+            );
+            send_direct_message(session, &request.user, request.content.clone()).await
+        }
+    }
+}
+
+/// ユーザーにDMを送信
+///
+/// # 引数
+/// * `session` - Slack API session
+/// * `user_id` - Recipient user
+/// * `content` - Message content to send
+pub async fn send_direct_message(
+    session: &SlackClientSession<'_, SlackClientHyperHttpsConnector>,
+    user_id: &SlackUserId,
+    content: SlackMessageContent,
+) -> ClientResult<()> {
+    // ユーザーIDを宛先にすると、BotとのDMに投稿される
+    send_message(session, SlackChannelId::new(user_id.to_string()), content).await
+}
+
+/// チャンネルにメッセージを送信
+///
+/// # 引数
+/// * `session` - Slack API session
+/// * `channel_id` - Destination channel
+/// * `content` - Message content to send
+pub async fn send_message(
+    session: &SlackClientSession<'_, SlackClientHyperHttpsConnector>,
+    channel_id: SlackChannelId,
+    content: SlackMessageContent,
+) -> ClientResult<()> {
+    let request = SlackApiChatPostMessageRequest::new(channel_id, content);
+    RateLimiter::slack()
+        .call(|| session.chat_post_message(&request))
+        .await?;
+    Ok(())
+}
+
+/// 結果を利用者に届ける方法
+#[derive(Debug)]
+pub enum ResultDelivery {
+    /// 操作したチャンネルへのエフェメラルメッセージ（送れなければDM）
+    Ephemeral(SlackApiChatPostEphemeralRequest),
+    /// BotとのDM
+    DirectMessage(SlackUserId, SlackMessageContent),
+}
+
+impl ResultDelivery {
+    /// 操作したチャンネルがわかればエフェメラルメッセージ、わからなければDMを選ぶ
+    ///
+    /// モーダルはチャンネルを持たないため、コマンドを実行したチャンネルを
+    /// 覚えていない場合（再起動後など）はDMで送る。
+    pub fn for_user(
+        channel_id: Option<SlackChannelId>,
+        user_id: &SlackUserId,
+        content: SlackMessageContent,
+    ) -> Self {
+        match channel_id {
+            Some(channel_id) => Self::Ephemeral(SlackApiChatPostEphemeralRequest::new(
+                channel_id,
+                user_id.clone(),
+                content,
+            )),
+            None => Self::DirectMessage(user_id.clone(), content),
+        }
+    }
+}
+
+/// 結果をエフェメラルメッセージかDMで利用者に送信
+///
+/// # 引数
+/// * `session` - Slack API session
+/// * `delivery` - How to deliver the result
+pub async fn deliver_result(
+    session: &SlackClientSession<'_, SlackClientHyperHttpsConnector>,
+    delivery: ResultDelivery,
+) -> ClientResult<()> {
+    match delivery {
+        ResultDelivery::Ephemeral(request) => post_ephemeral_or_dm(session, &request).await,
+        ResultDelivery::DirectMessage(user_id, content) => {
+            send_direct_message(session, &user_id, content).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content() -> SlackMessageContent {
+        SlackMessageContent::new().with_text("❌ 予約できませんでした".to_string())
+    }
+
+    #[test]
+    fn test_delivers_ephemeral_in_known_channel() {
+        let user_id = SlackUserId::new("U123".to_string());
+
+        let delivery = ResultDelivery::for_user(
+            Some(SlackChannelId::new("C456".to_string())),
+            &user_id,
+            content(),
+        );
+
+        let ResultDelivery::Ephemeral(request) = delivery else {
+            panic!("エフェメラルメッセージではありません: {:?}", delivery);
+        };
+        assert_eq!(request.channel, SlackChannelId::new("C456".to_string()));
+        assert_eq!(request.user, user_id);
+        assert_eq!(request.content, content());
+    }
+
+    #[test]
+    fn test_delivers_by_dm_without_channel() {
+        let user_id = SlackUserId::new("U123".to_string());
+
+        let delivery = ResultDelivery::for_user(None, &user_id, content());
+
+        let ResultDelivery::DirectMessage(recipient, sent) = delivery else {
+            panic!("DMではありません: {:?}", delivery);
+        };
+        assert_eq!(recipient, user_id);
+        assert_eq!(sent, content());
+    }
+}
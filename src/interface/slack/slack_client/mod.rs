@@ -16,8 +16,9 @@
 //! |-----------|---------------|
 //! | `views.open` | `modals::open()` |
 //! | `views.update` | `modals::update()` |
-//! | `chat.postMessage` | `messages::send_followup()` |
-//! | `chat.postEphemeral` | `messages::send_ephemeral()` |
+//! | `chat.postMessage` | `messages::send_message()`, `messages::send_direct_message()` |
+//! | response URL | `messages::send_followup()`, `messages::send_ephemeral()`, `messages::replace_original()` |
+//! | `chat.postEphemeral` | `messages::post_ephemeral_or_dm()`, `messages::deliver_result()` |
 //!
 //! ## モジュール
 //!
@@ -26,3 +27,18 @@
 
 pub mod messages;
 pub mod modals;
+
+use slack_morphism::prelude::*;
+use std::sync::OnceLock;
+
+/// 通知の送信で共有するSlackクライアント
+///
+/// 通知ごとにHTTPコネクターを作らないよう、プロセス内で1つのクライアントを使い回す。
+pub fn shared() -> &'static SlackHyperClient {
+    static CLIENT: OnceLock<SlackHyperClient> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        SlackClient::new(
+            SlackClientHyperConnector::new().expect("Failed to initialize Slack HTTP connector"),
+        )
+    })
+}
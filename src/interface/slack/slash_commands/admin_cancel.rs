@@ -0,0 +1,167 @@
+//! /admin-cancel コマンドハンドラ
+
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::admin_cancel;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// キャンセルの対象
+#[derive(Debug, PartialEq)]
+enum CancelTarget<'a> {
+    /// 予約ID
+    Usage(&'a str),
+    /// SlackユーザーID
+    User(&'a str),
+}
+
+/// /admin-cancel スラッシュコマンドを処理
+///
+/// 放置された予約や不適切な予約を、管理者またはモデレーターが予約者に代わってキャンセルする。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    let identity_repo = app.identity_repo();
+    if !user_resolver::role(&event.user_id, identity_repo, app.resource_config())
+        .await
+        .can_cancel_others()
+    {
+        info!(
+            "管理者・モデレーターではないユーザー {} が予約のキャンセルを試みました",
+            event.user_id
+        );
+        return Ok(text_response(messages.admin_cancel_forbidden.to_string()));
+    }
+
+    let Some(target) = parse_target(event.text.as_deref().unwrap_or("")) else {
+        return Ok(text_response(messages.admin_cancel_usage.to_string()));
+    };
+
+    let actor_email = match user_resolver::resolve_user_email(&event.user_id, identity_repo).await {
+        Ok(email) => EmailAddress::new(email)?,
+        Err(_) => {
+            return Ok(text_response(messages.email_not_registered.to_string()));
+        }
+    };
+
+    let delete_usecase = app.delete_usage_usecase();
+    let response = match target {
+        CancelTarget::Usage(usage_id) => {
+            let usage_id = UsageId::from_string(usage_id.to_string());
+            let usage = match app.get_usage_usecase().execute(&usage_id).await {
+                Ok(usage) => usage,
+                Err(ApplicationError::Repository(RepositoryError::NotFound)) => {
+                    return Ok(text_response(fill(
+                        messages.reservation_not_found,
+                        &[("usage_id", usage_id.as_str())],
+                    )));
+                }
+                Err(e) => return Ok(failure(messages, &e)),
+            };
+            match delete_usecase.execute(&usage_id, &actor_email).await {
+                Ok(()) => {
+                    info!(
+                        "🗑️ {} が予約をキャンセルしました: {}",
+                        actor_email.as_str(),
+                        usage_id.as_str()
+                    );
+                    let target = fill(
+                        messages.reservation_target,
+                        &[("usage_id", usage_id.as_str())],
+                    );
+                    admin_cancel::create_cancelled(messages, &target, &[usage])
+                }
+                Err(e) => return Ok(failure(messages, &e)),
+            }
+        }
+        CancelTarget::User(user_id) => {
+            let owner_email = match identity_repo
+                .find_by_external_user_id(&ExternalSystem::Slack, user_id)
+                .await
+            {
+                Ok(Some(identity_link)) => identity_link.email().clone(),
+                Ok(None) => {
+                    return Ok(text_response(fill(
+                        messages.user_not_linked,
+                        &[("user", &format!("<@{}>", user_id))],
+                    )));
+                }
+                Err(e) => return Ok(failure(messages, &e)),
+            };
+            match delete_usecase
+                .execute_for_owner(&owner_email, &actor_email)
+                .await
+            {
+                Ok(cancelled) => {
+                    info!(
+                        "🗑️ {} が {} の予約を{}件キャンセルしました",
+                        actor_email.as_str(),
+                        owner_email.as_str(),
+                        cancelled.len()
+                    );
+                    admin_cancel::create_cancelled(messages, &format!("<@{}>", user_id), &cancelled)
+                }
+                Err(e) => return Ok(failure(messages, &e)),
+            }
+        }
+    };
+
+    Ok(SlackCommandEventResponse::new(response))
+}
+
+/// コマンド引数を解釈する
+fn parse_target(text: &str) -> Option<CancelTarget<'_>> {
+    let mut args = text.split_whitespace();
+    let target = args.next()?;
+    if args.next().is_some() {
+        return None;
+    }
+    Some(match user_resolver::parse_user_mention(target) {
+        Some(user_id) => CancelTarget::User(user_id),
+        None => CancelTarget::Usage(target),
+    })
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
+
+fn failure(messages: &Messages, e: &dyn std::error::Error) -> SlackCommandEventResponse {
+    error!("❌ 予約のキャンセルに失敗: {}", e);
+    text_response(fill(messages.cancel_failed, &[("error", &e.to_string())]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!(parse_target(""), None);
+        assert_eq!(
+            parse_target("<@U01234567|alice>"),
+            Some(CancelTarget::User("U01234567"))
+        );
+        assert_eq!(
+            parse_target("3f2b9c1e-7d4a-4e2b-9a8f-1c2d3e4f5a6b"),
+            Some(CancelTarget::Usage("3f2b9c1e-7d4a-4e2b-9a8f-1c2d3e4f5a6b"))
+        );
+        assert_eq!(parse_target("<@U01234567> extra"), None);
+    }
+}
@@ -0,0 +1,187 @@
+//! /availability コマンドハンドラ
+
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::utility::datetime_parser::parse_datetime;
+use crate::interface::slack::views::messages::availability;
+use chrono::{Days, Local, NaiveDate};
+use slack_morphism::prelude::*;
+use tracing::error;
+
+/// タグで絞り込む引数の接頭辞
+const TAG_PREFIX: &str = "tag:";
+
+/// /availability スラッシュコマンドを処理
+///
+/// 指定日（省略時は今日）のGPU・部屋の空き状況を1時間単位で表示する。
+/// サーバー名または部屋名を指定した場合は、そのリソースのみを表示する。
+/// `tag:a100` のようにタグを指定した場合は、そのタグの付いたデバイスのみを表示する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    let Some(Args {
+        resource_name,
+        tag,
+        date,
+    }) = parse_args(
+        event.text.as_deref().unwrap_or(""),
+        Local::now().date_naive(),
+    )
+    else {
+        return Ok(text_response(messages.availability_usage.to_string()));
+    };
+
+    if let Some(name) = resource_name {
+        let config = app.resource_config();
+        let exists = config.servers.iter().any(|s| s.name == name)
+            || config.rooms.iter().any(|r| r.name == name)
+            || config.get_instrument(name).is_some()
+            || config.has_custom_resource(name);
+        if !exists {
+            return Ok(text_response(format!(
+                "❌ {}",
+                fill(messages.unknown_resource, &[("name", name)])
+            )));
+        }
+    }
+
+    if let Some(tag) = tag
+        && !app
+            .resource_config()
+            .tags()
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(tag))
+    {
+        return Ok(text_response(format!(
+            "❌ {}",
+            fill(messages.unknown_tag, &[("tag", tag)])
+        )));
+    }
+
+    let day = day_period(date, messages)?;
+    let response = match app
+        .availability_usecase()
+        .execute(&day, resource_name, tag)
+        .await
+    {
+        Ok(report) => availability::create(messages, app.resource_config(), date, &report),
+        Err(e) => {
+            error!("❌ 空き状況の取得に失敗: {}", e);
+            SlackMessageContent::new().with_text(fill(
+                messages.availability_failed,
+                &[("error", &e.to_string())],
+            ))
+        }
+    };
+
+    Ok(SlackCommandEventResponse::new(response))
+}
+
+/// コマンド引数
+#[derive(Debug, PartialEq)]
+struct Args<'a> {
+    /// 絞り込むサーバー名または部屋名
+    resource_name: Option<&'a str>,
+    /// 絞り込むタグ
+    tag: Option<&'a str>,
+    /// 表示する日
+    date: NaiveDate,
+}
+
+/// コマンド引数を解釈する
+///
+/// 引数は順不同で、`tag:` で始まるものをタグ、日付として解釈できるものを日付、それ以外をリソース名とする。
+fn parse_args(text: &str, today: NaiveDate) -> Option<Args<'_>> {
+    let mut resource_name = None;
+    let mut tag = None;
+    let mut date = None;
+    for arg in text.split_whitespace() {
+        if let Some(value) = arg.strip_prefix(TAG_PREFIX) {
+            if tag.is_some() || value.is_empty() {
+                return None;
+            }
+            tag = Some(value);
+            continue;
+        }
+        match NaiveDate::parse_from_str(arg, "%Y-%m-%d") {
+            Ok(parsed) if date.is_none() => date = Some(parsed),
+            Err(_) if resource_name.is_none() => resource_name = Some(arg),
+            _ => return None,
+        }
+    }
+    Some(Args {
+        resource_name,
+        tag,
+        date: date.unwrap_or(today),
+    })
+}
+
+/// 指定日の0:00から翌日0:00までの期間
+fn day_period(
+    date: NaiveDate,
+    messages: &Messages,
+) -> Result<TimePeriod, Box<dyn std::error::Error + Send + Sync>> {
+    let next = date.checked_add_days(Days::new(1)).ok_or_else(|| {
+        fill(
+            messages.invalid_datetime,
+            &[("datetime", &date.to_string())],
+        )
+    })?;
+    let start = parse_datetime(&date.format("%Y-%m-%d").to_string(), "00:00", None)?;
+    let end = parse_datetime(&next.format("%Y-%m-%d").to_string(), "00:00", None)?;
+    Ok(TimePeriod::new(start, end)?)
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let today = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 4, 3).unwrap();
+
+        let args = |resource_name, tag, date| {
+            Some(Args {
+                resource_name,
+                tag,
+                date,
+            })
+        };
+
+        assert_eq!(parse_args("", today), args(None, None, today));
+        assert_eq!(
+            parse_args("Thalys", today),
+            args(Some("Thalys"), None, today)
+        );
+        assert_eq!(parse_args("2025-04-03", today), args(None, None, date));
+        assert_eq!(
+            parse_args("2025-04-03 Thalys", today),
+            args(Some("Thalys"), None, date)
+        );
+        assert_eq!(
+            parse_args("tag:a100 2025-04-03", today),
+            args(None, Some("a100"), date)
+        );
+        assert_eq!(parse_args("Thalys Freccia", today), None);
+        assert_eq!(parse_args("2025-04-03 2025-04-04", today), None);
+        assert_eq!(parse_args("tag:a100 tag:h100", today), None);
+        assert_eq!(parse_args("tag:", today), None);
+    }
+}
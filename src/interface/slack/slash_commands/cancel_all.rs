@@ -0,0 +1,65 @@
+//! /cancel-all コマンドハンドラ
+
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slack_client::modals;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// /cancel-all スラッシュコマンドを処理
+///
+/// 自分の開始前の予約を一覧にしたモーダルを開き、選んだ予約をまとめてキャンセルできるようにする。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let preferences = app.user_preferences(&event.team_id, &event.user_id).await;
+    let messages = preferences.messages();
+
+    let owner_email = EmailAddress::new(
+        user_resolver::resolve_user_email(&event.user_id, app.identity_repo()).await?,
+    )?;
+    let usages = match app.bulk_delete_usecase().list_upcoming(&owner_email).await {
+        Ok(usages) => usages,
+        Err(e) => {
+            error!("❌ 開始前の予約の取得に失敗: {}", e);
+            return Ok(text_response(fill(
+                messages.cancel_all_failed,
+                &[("error", &e.to_string())],
+            )));
+        }
+    };
+    if usages.is_empty() {
+        return Ok(text_response(messages.cancel_all_none.to_string()));
+    }
+
+    info!(
+        "🗑️ 一括キャンセルモーダルを開きます: {} ({}件)",
+        owner_email.as_str(),
+        usages.len()
+    );
+    let modal = views::modals::cancel_all::create(messages, &usages, preferences.timezone);
+    modals::open(
+        app.slack_client(),
+        &app.bot_token_for(&event.team_id).await,
+        &event.trigger_id,
+        modal,
+    )
+    .await?;
+
+    // 空のレスポンスを返す（モーダルが開かれたことをSlackに伝える）
+    Ok(SlackCommandEventResponse::new(SlackMessageContent::new()))
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
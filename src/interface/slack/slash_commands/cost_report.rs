@@ -0,0 +1,65 @@
+//! /cost-report コマンドハンドラ
+
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slash_commands::usage_stats::parse_window;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::cost_report;
+use chrono::Utc;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// /cost-report スラッシュコマンドを処理
+///
+/// 現在時刻までの指定期間（省略時は過去7日間）のGPUの予約の費用を、
+/// ユーザー・プロジェクトごとに多い順で表示する（管理者コマンド）。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    if !user_resolver::is_admin(&event.user_id, app.identity_repo(), app.resource_config()).await {
+        info!(
+            "管理者ではないユーザー {} が費用の集計を試みました",
+            event.user_id
+        );
+        return Ok(text_response(messages.admin_only.to_string()));
+    }
+
+    let Some(usecase) = app.cost_report_usecase() else {
+        return Ok(text_response(messages.cost_model_missing.to_string()));
+    };
+
+    let Some(window) = parse_window(event.text.as_deref().unwrap_or("")) else {
+        return Ok(text_response(messages.cost_report_usage.to_string()));
+    };
+
+    let now = Utc::now();
+    let period = TimePeriod::new(now - window.duration(), now)?;
+    let response = match usecase.execute(&period).await {
+        Ok(report) => cost_report::create(messages, window.label(messages), &report),
+        Err(e) => {
+            error!("❌ 費用の集計に失敗: {}", e);
+            SlackMessageContent::new().with_text(fill(
+                messages.cost_report_failed,
+                &[("error", &e.to_string())],
+            ))
+        }
+    };
+
+    Ok(SlackCommandEventResponse::new(response))
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
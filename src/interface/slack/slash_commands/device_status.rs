@@ -0,0 +1,158 @@
+//! /device-status コマンドハンドラ
+
+use crate::domain::aggregates::device_health::{DeviceHealth, DeviceStatus};
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::device_status;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// /device-status のサブコマンド
+#[derive(Debug, PartialEq)]
+enum DeviceStatusCommand<'a> {
+    /// 状態が登録されているデバイスの一覧
+    List,
+    /// デバイスの状態の設定
+    Set {
+        server_name: &'a str,
+        device_id: u32,
+        status: DeviceStatus,
+        note: Option<String>,
+    },
+}
+
+/// /device-status スラッシュコマンドを処理
+///
+/// GPUデバイスの状態（使用可能・性能低下・使用停止）を設定する（管理者コマンド）。
+/// 使用停止にした場合は、影響を受ける既存の予約を一覧表示する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    if !user_resolver::is_admin(&event.user_id, app.identity_repo(), app.resource_config()).await {
+        info!(
+            "管理者ではないユーザー {} がデバイスの状態の変更を試みました",
+            event.user_id
+        );
+        return Ok(text_response(messages.admin_only.to_string()));
+    }
+
+    let Some(command) = parse_command(event.text.as_deref().unwrap_or("")) else {
+        return Ok(text_response(messages.device_status_usage.to_string()));
+    };
+
+    let usecase = app.device_status_usecase();
+    let response = match command {
+        DeviceStatusCommand::List => match usecase.list().await {
+            Ok(healths) => device_status::create_list(messages, &healths),
+            Err(e) => failure(messages.device_status_list_failed, &e),
+        },
+        DeviceStatusCommand::Set {
+            server_name,
+            device_id,
+            status,
+            note,
+        } => {
+            let exists = app
+                .resource_config()
+                .get_server(server_name)
+                .is_some_and(|server| server.devices.iter().any(|d| d.id == device_id));
+            if !exists {
+                return Ok(text_response(fill(
+                    messages.unknown_device,
+                    &[("server", server_name), ("device", &device_id.to_string())],
+                )));
+            }
+
+            let health = DeviceHealth::new(server_name.to_string(), device_id, status, note);
+            match usecase.execute(health.clone()).await {
+                Ok(affected) => {
+                    info!(
+                        "🔧 デバイスの状態を設定しました: {} のデバイス {} → {} ({}件の予約が影響)",
+                        server_name,
+                        device_id,
+                        status.as_str(),
+                        affected.len()
+                    );
+                    device_status::create_updated(messages, &health, &affected)
+                }
+                Err(e) => failure(messages.device_status_set_failed, &e),
+            }
+        }
+    };
+
+    Ok(SlackCommandEventResponse::new(response))
+}
+
+/// コマンド引数を解釈する
+fn parse_command(text: &str) -> Option<DeviceStatusCommand<'_>> {
+    let mut args = text.split_whitespace();
+    match args.next() {
+        None | Some("list") => Some(DeviceStatusCommand::List),
+        Some(server_name) => {
+            let device_id = args.next()?.parse().ok()?;
+            let status = args.next()?.parse().ok()?;
+            let rest: Vec<&str> = args.collect();
+            let note = (!rest.is_empty()).then(|| rest.join(" "));
+            Some(DeviceStatusCommand::Set {
+                server_name,
+                device_id,
+                status,
+                note,
+            })
+        }
+    }
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
+
+/// `template` の `{error}` にエラーを埋め込んだ失敗メッセージ
+fn failure(template: &str, e: &dyn std::error::Error) -> SlackMessageContent {
+    error!("❌ デバイスの状態の操作に失敗: {}", e);
+    SlackMessageContent::new().with_text(fill(template, &[("error", &e.to_string())]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command(""), Some(DeviceStatusCommand::List));
+        assert_eq!(parse_command("list"), Some(DeviceStatusCommand::List));
+        assert_eq!(parse_command("Thalys"), None);
+        assert_eq!(parse_command("Thalys x out-of-service"), None);
+        assert_eq!(parse_command("Thalys 1 broken"), None);
+        assert_eq!(
+            parse_command("Thalys 1 out-of-service ファン 故障"),
+            Some(DeviceStatusCommand::Set {
+                server_name: "Thalys",
+                device_id: 1,
+                status: DeviceStatus::OutOfService,
+                note: Some("ファン 故障".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_command("Thalys 1 available"),
+            Some(DeviceStatusCommand::Set {
+                server_name: "Thalys",
+                device_id: 1,
+                status: DeviceStatus::Available,
+                note: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! /freeze-resource コマンドハンドラ
+
+use crate::domain::aggregates::resource_freeze::{ResourceFreeze, ResourceFreezeError};
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::utility::{datetime_parser::parse_datetime, user_resolver};
+use crate::interface::slack::views::messages::resource_freeze;
+use chrono::Local;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// /freeze-resource のサブコマンド
+#[derive(Debug, PartialEq)]
+enum FreezeCommand<'a> {
+    /// 予約停止の一覧
+    List,
+    /// 予約停止の解除
+    Cancel { resource_name: &'a str },
+    /// 予約停止の登録
+    Freeze {
+        resource_name: &'a str,
+        date: &'a str,
+        time: &'a str,
+        reason: Option<String>,
+    },
+}
+
+/// /freeze-resource スラッシュコマンドを処理
+///
+/// リソースの新規予約を指定時刻以降停止する（管理者コマンド）。
+/// 登録時には、影響を受ける既存の予約を一覧表示する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    if !user_resolver::is_admin(&event.user_id, app.identity_repo(), app.resource_config()).await {
+        info!(
+            "管理者ではないユーザー {} が予約停止を試みました",
+            event.user_id
+        );
+        return Ok(text_response(messages.admin_only.to_string()));
+    }
+
+    let Some(command) = parse_command(event.text.as_deref().unwrap_or("")) else {
+        return Ok(text_response(messages.freeze_usage.to_string()));
+    };
+
+    let usecase = app.freeze_usecase();
+    let response = match command {
+        FreezeCommand::List => match usecase.list().await {
+            Ok(freezes) => resource_freeze::create_list(messages, &freezes),
+            Err(e) => failure(messages.freeze_list_failed, &e),
+        },
+        FreezeCommand::Cancel { resource_name } => match usecase.unfreeze(resource_name).await {
+            Ok(true) => {
+                info!("✅ 予約停止を解除しました: {}", resource_name);
+                SlackMessageContent::new()
+                    .with_text(fill(messages.unfrozen, &[("resource", resource_name)]))
+            }
+            Ok(false) => SlackMessageContent::new()
+                .with_text(fill(messages.not_frozen, &[("resource", resource_name)])),
+            Err(e) => failure(messages.unfreeze_failed, &e),
+        },
+        FreezeCommand::Freeze {
+            resource_name,
+            date,
+            time,
+            reason,
+        } => {
+            let config = app.resource_config();
+            let exists = config.servers.iter().any(|s| s.name == resource_name)
+                || config.rooms.iter().any(|r| r.name == resource_name)
+                || config.get_instrument(resource_name).is_some()
+                || config.has_custom_resource(resource_name);
+            if !exists {
+                return Ok(text_response(format!(
+                    "❌ {}",
+                    fill(messages.unknown_resource, &[("name", resource_name)])
+                )));
+            }
+
+            let freeze = match parse_datetime(date, time, None) {
+                Ok(starts_at) => ResourceFreeze::new(resource_name.to_string(), starts_at, reason),
+                Err(_) => {
+                    return Ok(text_response(format!(
+                        "❌ {}\n\n{}",
+                        fill(
+                            messages.invalid_datetime,
+                            &[("datetime", &format!("{} {}", date, time))]
+                        ),
+                        messages.freeze_usage
+                    )));
+                }
+            };
+            let freeze = match freeze {
+                Ok(freeze) => freeze,
+                Err(ResourceFreezeError::StartsInPast { starts_at }) => {
+                    return Ok(text_response(fill(
+                        messages.freeze_starts_in_past,
+                        &[(
+                            "time",
+                            &starts_at
+                                .with_timezone(&Local)
+                                .format("%Y-%m-%d %H:%M")
+                                .to_string(),
+                        )],
+                    )));
+                }
+                Err(e) => return Ok(text_response(format!("❌ {}", e))),
+            };
+
+            match usecase.execute(freeze.clone()).await {
+                Ok(affected) => {
+                    info!(
+                        "🧊 予約停止を登録しました: {} ({}件の予約が影響)",
+                        resource_name,
+                        affected.len()
+                    );
+                    resource_freeze::create_frozen(messages, &freeze, &affected)
+                }
+                Err(e) => failure(messages.freeze_failed, &e),
+            }
+        }
+    };
+
+    Ok(SlackCommandEventResponse::new(response))
+}
+
+/// コマンド引数を解釈する
+fn parse_command(text: &str) -> Option<FreezeCommand<'_>> {
+    let mut args = text.split_whitespace();
+    match args.next() {
+        None | Some("list") => Some(FreezeCommand::List),
+        Some("cancel") => {
+            let resource_name = args.next()?;
+            args.next()
+                .is_none()
+                .then_some(FreezeCommand::Cancel { resource_name })
+        }
+        Some(resource_name) => {
+            let date = args.next()?;
+            let mut rest: Vec<&str> = args.collect();
+            let time = match rest.first() {
+                Some(time) if is_time(time) => rest.remove(0),
+                _ => "00:00",
+            };
+            let reason = (!rest.is_empty()).then(|| rest.join(" "));
+            Some(FreezeCommand::Freeze {
+                resource_name,
+                date,
+                time,
+                reason,
+            })
+        }
+    }
+}
+
+/// HH:MM 形式かどうか
+fn is_time(value: &str) -> bool {
+    value
+        .split_once(':')
+        .is_some_and(|(h, m)| h.parse::<u32>().is_ok() && m.len() == 2 && m.parse::<u32>().is_ok())
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
+
+/// `template` の `{error}` にエラーを埋め込んだ失敗メッセージ
+fn failure(template: &str, e: &dyn std::error::Error) -> SlackMessageContent {
+    error!("❌ 予約停止の操作に失敗: {}", e);
+    SlackMessageContent::new().with_text(fill(template, &[("error", &e.to_string())]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command(""), Some(FreezeCommand::List));
+        assert_eq!(parse_command("list"), Some(FreezeCommand::List));
+        assert_eq!(
+            parse_command("cancel Thalys"),
+            Some(FreezeCommand::Cancel {
+                resource_name: "Thalys"
+            })
+        );
+        assert_eq!(parse_command("cancel"), None);
+        assert_eq!(parse_command("Thalys"), None);
+        assert_eq!(
+            parse_command("Thalys 2024-03-01"),
+            Some(FreezeCommand::Freeze {
+                resource_name: "Thalys",
+                date: "2024-03-01",
+                time: "00:00",
+                reason: None,
+            })
+        );
+        assert_eq!(
+            parse_command("Thalys 2024-03-01 18:30 クラスタ 移行"),
+            Some(FreezeCommand::Freeze {
+                resource_name: "Thalys",
+                date: "2024-03-01",
+                time: "18:30",
+                reason: Some("クラスタ 移行".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_command("Thalys 2024-03-01 クラスタ移行"),
+            Some(FreezeCommand::Freeze {
+                resource_name: "Thalys",
+                date: "2024-03-01",
+                time: "00:00",
+                reason: Some("クラスタ移行".to_string()),
+            })
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! /link-history コマンドハンドラ
+
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::link_history;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// /link-history スラッシュコマンドを処理
+///
+/// `<@slack_user>` またはメールアドレスを指定し、紐付け・解除の履歴を表示する（管理者コマンド）。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    if !user_resolver::is_admin(&event.user_id, app.identity_repo(), app.resource_config()).await {
+        info!(
+            "管理者ではないユーザー {} が紐付けの履歴の参照を試みました",
+            event.user_id
+        );
+        return Ok(text_response(messages.admin_only.to_string()));
+    }
+
+    let text = event.text.as_deref().unwrap_or("").trim();
+    let usecase = app.link_history_usecase();
+    let (subject, result) = if let Some(user_id) = user_resolver::parse_user_mention(text) {
+        (
+            format!("<@{}>", user_id),
+            usecase
+                .by_external_user(&ExternalSystem::Slack, user_id)
+                .await,
+        )
+    } else if let Ok(email) = EmailAddress::new(text.to_string()) {
+        (email.as_str().to_string(), usecase.by_email(&email).await)
+    } else {
+        return Ok(text_response(messages.link_history_usage.to_string()));
+    };
+
+    let response = match result {
+        Ok(entries) => link_history::create(messages, &subject, &entries),
+        Err(e) => {
+            error!("❌ 紐付けの履歴の取得に失敗: {}", e);
+            SlackMessageContent::new().with_text(fill(
+                messages.link_history_failed,
+                &[("error", &e.to_string())],
+            ))
+        }
+    };
+
+    Ok(SlackCommandEventResponse::new(response))
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
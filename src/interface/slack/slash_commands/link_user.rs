@@ -22,12 +22,16 @@ where
     info!("🔗 ユーザーリンクモーダルを開きます");
 
     // ユーザーリンクモーダルを作成
-    let modal = views::modals::link_user::create();
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    let modal = views::modals::link_user::create(messages);
 
     // モーダルを開く
     modals::open(
         app.slack_client(),
-        app.bot_token(),
+        &app.bot_token_for(&event.team_id).await,
         &event.trigger_id,
         modal,
     )
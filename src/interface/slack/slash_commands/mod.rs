@@ -15,10 +15,32 @@
 //!
 //! ## モジュール
 //!
+//! - `admin_cancel`: `/admin-cancel` - 他のユーザーの予約のキャンセル（管理者・モデレーター用）
+//! - `availability`: `/availability` - GPU・部屋の空き状況
+//! - `cancel_all`: `/cancel-all` - 自分の開始前の予約の一括キャンセル（モーダルベース）
+//! - `cost_report`: `/cost-report` - ユーザー・プロジェクトごとのGPUの予約の費用の集計（管理者用）
+//! - `device_status`: `/device-status` - GPUデバイスの状態の設定・一覧（管理者用）
+//! - `freeze_resource`: `/freeze-resource` - リソースの予約停止の登録・解除・一覧（管理者用）
+//! - `link_history`: `/link-history` - メールアドレスとの紐付けの履歴（管理者用）
 //! - `link_user`: `/link-user` - ユーザーとメールアドレスの紐付け（管理者用、モーダルベース）
+//! - `next_free`: `/next-free` - 指定した時間数だけ続けて空いている最も早い時間帯
 //! - `register_calendar`: `/register-calendar` - メールアドレス登録（モーダルベース）
 //! - `reserve`: `/reserve` - リソース予約（モーダルベース）
+//! - `unlink_user`: `/unlink-user` - メールアドレスとの紐付けの解除（他のユーザーは管理者のみ）
+//! - `whois_using`: `/whois-using` - サーバー・部屋を現在使用中のユーザー
+//! - `usage_stats`: `/usage-stats` - ユーザー・サーバー・デバイスごとの予約時間の集計
 
+pub mod admin_cancel;
+pub mod availability;
+pub mod cancel_all;
+pub mod cost_report;
+pub mod device_status;
+pub mod freeze_resource;
+pub mod link_history;
 pub mod link_user;
+pub mod next_free;
 pub mod register_calendar;
 pub mod reserve;
+pub mod unlink_user;
+pub mod usage_stats;
+pub mod whois_using;
@@ -0,0 +1,174 @@
+//! /next-free コマンドハンドラ
+
+use crate::application::usecases::find_next_available_slot::SEARCH_DAYS;
+use crate::domain::aggregates::resource_usage::service::format_time_period;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slash_commands::reserve::resolve_resources;
+use crate::interface::slack::utility::datetime_parser::to_user_time;
+use chrono::{Duration, Utc};
+use slack_morphism::prelude::*;
+use tracing::error;
+
+/// /next-free スラッシュコマンドを処理
+///
+/// 指定したサーバー（デバイス指定も可）または部屋が、指定した時間数だけ続けて空いている
+/// 最も早い時間帯を表示する。その日のうちに終わる場合は、そのまま予約できる `/reserve` のコマンドも添える。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let preferences = app.user_preferences(&event.team_id, &event.user_id).await;
+    let messages = preferences.messages();
+    let Some(args) = parse_args(event.text.as_deref().unwrap_or("")) else {
+        return Ok(text_response(messages.next_free_usage.to_string()));
+    };
+
+    let resources = match resolve_resources(
+        messages,
+        app.resource_config(),
+        args.resource_name,
+        args.device_spec,
+    ) {
+        Ok(resources) => resources,
+        Err(message) => return Ok(text_response(format!("❌ {}", message))),
+    };
+    // 表示には設定どおりの大文字・小文字のリソース名を使う
+    let resource = match (resources.first(), args.device_spec) {
+        (Some(first), Some(spec)) => format!("{} {}", first.name(), spec),
+        (Some(first), None) => first.name().to_string(),
+        (None, _) => args.resource_name.to_string(),
+    };
+
+    let text = match app
+        .next_slot_usecase()
+        .execute(&resources, args.duration, Utc::now())
+        .await
+    {
+        Ok(Some(period)) => {
+            let timezone = preferences.timezone;
+            let mut text = fill(
+                messages.next_free_found,
+                &[
+                    ("resource", &resource),
+                    (
+                        "time",
+                        &format_time_period(&period, timezone.map(|tz| tz.name())),
+                    ),
+                ],
+            );
+            // `/reserve` の引数では日をまたぐ時間帯を指定できない
+            let start = to_user_time(period.start(), timezone);
+            let end = to_user_time(period.end(), timezone);
+            if start.date_naive() == end.date_naive() {
+                let command = format!(
+                    "/reserve {} {}-{} {}",
+                    start.format("%Y-%m-%d"),
+                    start.format("%H:%M"),
+                    end.format("%H:%M"),
+                    resource
+                );
+                text.push_str("\n\n");
+                text.push_str(&fill(
+                    messages.next_free_reserve_hint,
+                    &[("command", &command)],
+                ));
+            }
+            text
+        }
+        Ok(None) => fill(
+            messages.next_free_none,
+            &[
+                ("resource", &resource),
+                ("hours", args.hours),
+                ("days", &SEARCH_DAYS.to_string()),
+            ],
+        ),
+        Err(e) => {
+            error!("❌ 空いている時間帯の検索に失敗: {}", e);
+            fill(messages.next_free_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    Ok(text_response(text))
+}
+
+/// `/next-free` の引数
+#[derive(Debug, PartialEq)]
+struct NextFreeArgs<'a> {
+    resource_name: &'a str,
+    device_spec: Option<&'a str>,
+    /// 入力された時間数（表示用）
+    hours: &'a str,
+    duration: Duration,
+}
+
+/// コマンド引数を解釈する
+///
+/// `<サーバー名または部屋名> [デバイス指定] <時間数>` の形式。時間数は小数（例: 1.5）も使える。
+fn parse_args(text: &str) -> Option<NextFreeArgs<'_>> {
+    let args: Vec<&str> = text.split_whitespace().collect();
+    let (resource_name, device_spec, hours) = match args.as_slice() {
+        [name, hours] => (*name, None, *hours),
+        [name, spec, hours] => (*name, Some(*spec), *hours),
+        _ => return None,
+    };
+
+    let value: f64 = hours.parse().ok()?;
+    if !value.is_finite() || value <= 0.0 || value > (SEARCH_DAYS * 24) as f64 {
+        return None;
+    }
+    let minutes = (value * 60.0).round() as i64;
+    if minutes == 0 {
+        return None;
+    }
+
+    Some(NextFreeArgs {
+        resource_name,
+        device_spec,
+        hours,
+        duration: Duration::minutes(minutes),
+    })
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args("Thalys 4"),
+            Some(NextFreeArgs {
+                resource_name: "Thalys",
+                device_spec: None,
+                hours: "4",
+                duration: Duration::hours(4),
+            })
+        );
+        assert_eq!(
+            parse_args("Thalys 0-1 1.5"),
+            Some(NextFreeArgs {
+                resource_name: "Thalys",
+                device_spec: Some("0-1"),
+                hours: "1.5",
+                duration: Duration::minutes(90),
+            })
+        );
+        assert_eq!(parse_args("Thalys"), None);
+        assert_eq!(parse_args("Thalys 0"), None);
+        assert_eq!(parse_args("Thalys -1"), None);
+        assert_eq!(parse_args("Thalys four"), None);
+        assert_eq!(parse_args("Thalys 0-1 2 extra"), None);
+    }
+}
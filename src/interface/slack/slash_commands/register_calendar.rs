@@ -23,12 +23,16 @@ where
     info!("📧 メールアドレス登録モーダルを開きます: user={}", user_id);
 
     // メールアドレス登録モーダルを作成
-    let modal = views::modals::registration::create();
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    let modal = views::modals::registration::create(messages);
 
     // モーダルを開く
     modals::open(
         app.slack_client(),
-        app.bot_token(),
+        &app.bot_token_for(&event.team_id).await,
         &event.trigger_id,
         modal,
     )
@@ -1,17 +1,34 @@
 //! /reserve コマンドハンドラ
 
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::factory::ResourceFactory;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    Gpu, Priority, ReservationMetadata, Resource, TimePeriod, Visibility,
+};
+use crate::domain::common::EmailAddress;
 use crate::domain::ports::notifier::Notifier;
 use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::config::ResourceConfig;
+use crate::infrastructure::i18n::{Messages, fill};
 use crate::interface::slack::app::SlackApp;
 use crate::interface::slack::slack_client::modals;
-use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::utility::datetime_parser::{parse_datetime, to_user_time};
+use crate::interface::slack::utility::device_availability;
+use crate::interface::slack::utility::user_resolver::{self, UserPreferences};
+use crate::interface::slack::views::messages::{
+    booking_window, confirmation, profile_email, reservation_limit,
+};
 use crate::interface::slack::views::modals::{registration, reserve};
+use chrono::{Days, NaiveDate, NaiveTime, Utc};
 use slack_morphism::prelude::*;
-use tracing::info;
+use tracing::{error, info};
 
 /// /reserve スラッシュコマンドを処理
 ///
-/// ユーザーが紐付け済みの場合は予約モーダルを表示、未紐付けの場合はメール登録モーダルを表示
+/// ユーザーが紐付け済みの場合は予約モーダルを表示する。
+/// 引数（日付・時間帯・リソース・デバイス指定）を付けた場合は、モーダルを開かずにその場で予約する。
+/// 未紐付けの場合は、Slackプロフィールにメールアドレスがあればそのアドレスでの連携を提案し、
+/// 無ければメール登録モーダルを表示する。
 pub async fn handle<R, N>(
     app: &SlackApp<R, N>,
     event: SlackCommandEvent,
@@ -26,24 +43,46 @@ where
     // Get dependencies
     let config = app.resource_config();
     let slack_client = app.slack_client();
-    let bot_token = app.bot_token();
+    let bot_token = &app.bot_token_for(&event.team_id).await;
     let identity_repo = app.identity_repo();
 
+    // 日時はSlackプロフィールのタイムゾーンで扱う
+    let preferences =
+        user_resolver::fetch_user_preferences(slack_client, bot_token, user_id, &config.i18n).await;
+
     // Check if user is linked
     let is_linked = user_resolver::is_user_linked(user_id, identity_repo).await;
 
     if !is_linked {
-        // Unlinked: Show email registration modal
-        info!(
-            "ユーザー {} は未リンク。メールアドレス登録モーダルを表示します",
-            user_id
-        );
+        let email = user_resolver::fetch_profile_email(slack_client, bot_token, user_id).await;
+        match link_prompt(preferences.messages(), email.as_ref()) {
+            // Unlinked with profile email: Offer one-click linking
+            LinkPrompt::ProfileEmail(content) => {
+                info!(
+                    "ユーザー {} は未リンク。プロフィールのメールアドレスでの連携を提案します",
+                    user_id
+                );
+                return Ok(SlackCommandEventResponse::new(content));
+            }
+            // Unlinked without profile email: Show email registration modal
+            LinkPrompt::RegistrationModal(modal) => {
+                info!(
+                    "ユーザー {} は未リンク。メールアドレス登録モーダルを表示します",
+                    user_id
+                );
+
+                modals::open(slack_client, bot_token, trigger_id, modal).await?;
 
-        let modal = registration::create();
-        modals::open(slack_client, bot_token, trigger_id, modal).await?;
+                info!("✅ メールアドレス登録モーダルを開きました");
+                return Ok(SlackCommandEventResponse::new(SlackMessageContent::new()));
+            }
+        }
+    }
 
-        info!("✅ メールアドレス登録モーダルを開きました");
-        return Ok(SlackCommandEventResponse::new(SlackMessageContent::new()));
+    // Linked with arguments: Reserve directly without the modal
+    let text = event.text.as_deref().unwrap_or("").trim();
+    if !text.is_empty() {
+        return quick_reserve(app, user_id, text, &preferences).await;
     }
 
     // Linked: Show reservation modal
@@ -53,11 +92,518 @@ where
     );
 
     // Create and open reservation modal
-    let initial_server = config.servers.first().map(|s| s.name.as_str());
-    let modal = reserve::create_reserve_modal(config, None, initial_server, None, None, None, None);
+    let busy_devices = match device_availability::new_reservation_period() {
+        Some(period) => {
+            device_availability::busy_devices(
+                &app.reservation_read_model().snapshot(),
+                config,
+                app.identity_repo(),
+                user_id,
+                &period,
+                None,
+            )
+            .await
+        }
+        None => Vec::new(),
+    };
+    let modal = reserve::create_reserve_modal(
+        config,
+        None,
+        &[],
+        None,
+        None,
+        None,
+        None,
+        &preferences,
+        &busy_devices,
+        &app.device_healths().await,
+    );
 
     modals::open(slack_client, bot_token, trigger_id, modal).await?;
 
     info!("✅ 予約モーダルを開きました");
     Ok(SlackCommandEventResponse::new(SlackMessageContent::new()))
 }
+
+/// 引数で指定された内容でそのまま予約する
+async fn quick_reserve<R, N>(
+    app: &SlackApp<R, N>,
+    user_id: &SlackUserId,
+    text: &str,
+    preferences: &UserPreferences,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = preferences.messages();
+    let timezone = preferences.timezone;
+    let Some(args) = parse_quick_args(text, to_user_time(Utc::now(), timezone).date_naive()) else {
+        return Ok(text_response(messages.quick_reserve_usage.to_string()));
+    };
+
+    let config = app.resource_config();
+    let resources = match resolve_resources(messages, config, args.resource_name, args.device_spec)
+    {
+        Ok(resources) => resources,
+        Err(message) => return Ok(text_response(format!("❌ {}", message))),
+    };
+
+    let date = args.date.format("%Y-%m-%d").to_string();
+    let start = parse_datetime(&date, &args.start.format("%H:%M").to_string(), timezone)?;
+    let end = parse_datetime(&date, &args.end.format("%H:%M").to_string(), timezone)?;
+    let time_period = match TimePeriod::new(start, end) {
+        Ok(time_period) => time_period,
+        Err(_) => {
+            return Ok(text_response(messages.end_before_start.to_string()));
+        }
+    };
+
+    let owner_email =
+        EmailAddress::new(user_resolver::resolve_user_email(user_id, app.identity_repo()).await?)?;
+
+    info!(
+        "⚡ 引数から予約を作成中: {:?} {} ~ {}",
+        resources,
+        time_period.start(),
+        time_period.end()
+    );
+    let requires_approval = app
+        .create_resource_usage_usecase()
+        .requires_approval(&resources);
+    // 利用実績の集計に失敗しても予約は行う
+    let fair_share = app
+        .create_resource_usage_usecase()
+        .fair_share_advice(&owner_email, &time_period, &resources)
+        .await
+        .unwrap_or_else(|e| {
+            error!("❌ 利用実績の集計に失敗: {}", e);
+            None
+        });
+    let message = match app
+        .create_resource_usage_usecase()
+        .execute(
+            owner_email,
+            time_period.clone(),
+            resources,
+            None,
+            ReservationMetadata::default(),
+            Visibility::Public,
+            Priority::Normal,
+            None,
+        )
+        .await
+    {
+        Ok(usage_id) => {
+            info!("✅ 予約を作成しました: {}", usage_id.as_str());
+            let mut message = fill(messages.reserved, &[("usage_id", usage_id.as_str())]);
+            if requires_approval {
+                message.push_str(&format!("\n\n{}", messages.approval_pending));
+            }
+            // 週末・休業日にかかる場合は注意書きを添える（予約自体は行う）
+            if let Some(advisory) = config.holiday_advisory_policy().and_then(|policy| {
+                confirmation::closure_advisory(messages, &policy.closed_days(&time_period))
+            }) {
+                message.push_str(&format!("\n\n{}", advisory));
+            }
+            if let Some(advice) = &fair_share {
+                message.push_str(&format!(
+                    "\n\n{}",
+                    confirmation::fair_share_advisory(messages, advice)
+                ));
+            }
+            message
+        }
+        Err(ApplicationError::ReservationLimit(e)) => {
+            info!("⚠️ 同時予約の上限を超えています: {}", e);
+            fill(
+                messages.reserve_failed,
+                &[(
+                    "error",
+                    &reservation_limit::limit_exceeded(messages, &e, timezone),
+                )],
+            )
+        }
+        Err(ApplicationError::BookingWindow(e)) => {
+            info!("⚠️ 予約の受付期間外です: {}", e);
+            fill(
+                messages.reserve_failed,
+                &[("error", &booking_window::outside_window(messages, &e))],
+            )
+        }
+        Err(e) => {
+            error!("❌ 予約作成に失敗: {}", e);
+            fill(messages.reserve_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    Ok(text_response(message))
+}
+
+/// `/reserve` の引数
+#[derive(Debug, PartialEq)]
+struct QuickReserveArgs<'a> {
+    date: NaiveDate,
+    start: NaiveTime,
+    end: NaiveTime,
+    resource_name: &'a str,
+    device_spec: Option<&'a str>,
+}
+
+/// コマンド引数を解釈する
+///
+/// 日付（today / tomorrow / 今日 / 明日 / 明後日 / YYYY-MM-DD、省略時は今日）と時間帯（HH:MM-HH:MM）は順不同。
+/// それ以外の1つ目をリソース名、2つ目をデバイス指定とする。
+fn parse_quick_args(text: &str, today: NaiveDate) -> Option<QuickReserveArgs<'_>> {
+    let mut date = None;
+    let mut time_range = None;
+    let mut resource_name = None;
+    let mut device_spec = None;
+    for arg in text.split_whitespace() {
+        if let Some(parsed) = parse_date_word(arg, today) {
+            if date.replace(parsed).is_some() {
+                return None;
+            }
+        } else if let Some(parsed) = parse_time_range(arg) {
+            if time_range.replace(parsed).is_some() {
+                return None;
+            }
+        } else if resource_name.is_none() {
+            resource_name = Some(arg);
+        } else if device_spec.is_none() {
+            device_spec = Some(arg);
+        } else {
+            return None;
+        }
+    }
+
+    let (start, end) = time_range?;
+    Some(QuickReserveArgs {
+        date: date.unwrap_or(today),
+        start,
+        end,
+        resource_name: resource_name?,
+        device_spec,
+    })
+}
+
+fn parse_date_word(arg: &str, today: NaiveDate) -> Option<NaiveDate> {
+    match arg.to_lowercase().as_str() {
+        "today" | "今日" => Some(today),
+        "tomorrow" | "明日" => today.checked_add_days(Days::new(1)),
+        "明後日" => today.checked_add_days(Days::new(2)),
+        _ => NaiveDate::parse_from_str(arg, "%Y-%m-%d").ok(),
+    }
+}
+
+fn parse_time_range(arg: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (start, end) = arg.split_once('-')?;
+    Some((
+        NaiveTime::parse_from_str(start, "%H:%M").ok()?,
+        NaiveTime::parse_from_str(end, "%H:%M").ok()?,
+    ))
+}
+
+/// リソース名（大文字・小文字は区別しない）とデバイス指定から予約するリソースを決める
+///
+/// サーバーでデバイス指定を省略した場合は、そのサーバーのすべてのデバイスを予約する。
+pub(super) fn resolve_resources(
+    messages: &Messages,
+    config: &ResourceConfig,
+    name: &str,
+    device_spec: Option<&str>,
+) -> Result<Vec<Resource>, String> {
+    if let Some(server) = config
+        .servers
+        .iter()
+        .find(|server| server.name.eq_ignore_ascii_case(name))
+    {
+        return match device_spec {
+            Some(spec) => ResourceFactory::create_gpus_from_spec(spec, &server.name, |id| {
+                server
+                    .devices
+                    .iter()
+                    .find(|device| device.id == id)
+                    .map(|device| device.model.clone())
+            })
+            .map_err(|e| e.to_string()),
+            None => Ok(server
+                .devices
+                .iter()
+                .map(|device| {
+                    Resource::Gpu(Gpu::new(
+                        server.name.clone(),
+                        device.id,
+                        device.model.clone(),
+                    ))
+                })
+                .collect()),
+        };
+    }
+
+    if let Some(room) = config
+        .rooms
+        .iter()
+        .find(|room| room.name.eq_ignore_ascii_case(name))
+    {
+        if device_spec.is_some() {
+            return Err(fill(messages.room_has_no_devices, &[("room", &room.name)]));
+        }
+        return Ok(vec![Resource::Room {
+            name: room.name.clone(),
+        }]);
+    }
+
+    if let Some(instrument) = config
+        .instruments
+        .iter()
+        .find(|instrument| instrument.name.eq_ignore_ascii_case(name))
+    {
+        if device_spec.is_some() {
+            return Err(fill(
+                messages.instrument_has_no_devices,
+                &[("instrument", &instrument.name)],
+            ));
+        }
+        return Ok(vec![Resource::Instrument {
+            name: instrument.name.clone(),
+        }]);
+    }
+
+    for resource_type in &config.resource_types {
+        if let Some(resource) = resource_type
+            .resources
+            .iter()
+            .find(|resource| resource.name.eq_ignore_ascii_case(name))
+        {
+            if device_spec.is_some() {
+                return Err(fill(
+                    messages.custom_has_no_devices,
+                    &[("type", &resource_type.label), ("name", &resource.name)],
+                ));
+            }
+            return Ok(vec![Resource::Custom {
+                kind: resource_type.id.clone(),
+                name: resource.name.clone(),
+            }]);
+        }
+    }
+
+    Err(fill(messages.unknown_resource, &[("name", name)]))
+}
+
+/// 未紐付けの利用者への案内
+#[derive(Debug)]
+enum LinkPrompt {
+    /// プロフィールのメールアドレスでのワンクリック連携を提案するメッセージ
+    ProfileEmail(SlackMessageContent),
+    /// メール登録モーダル
+    RegistrationModal(SlackView),
+}
+
+/// Slackプロフィールのメールアドレスの有無から、未紐付けの利用者への案内を選ぶ
+fn link_prompt(messages: &Messages, email: Option<&EmailAddress>) -> LinkPrompt {
+    match email {
+        Some(email) => LinkPrompt::ProfileEmail(profile_email::create(messages, email)),
+        None => LinkPrompt::RegistrationModal(registration::create(messages)),
+    }
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::i18n::Locale;
+    use crate::interface::slack::constants::*;
+
+    /// `users.info` の応答からプロフィールのユーザーを取り出す
+    fn users_info_user(profile: serde_json::Value) -> SlackUser {
+        let response: SlackApiUsersInfoResponse = serde_json::from_value(serde_json::json!({
+            "ok": true,
+            "user": {
+                "id": "U012AB3CD",
+                "team_id": "T012AB3CD",
+                "name": "spengler",
+                "profile": profile
+            }
+        }))
+        .unwrap();
+        response.user
+    }
+
+    #[test]
+    fn test_link_prompt_opens_registration_modal_without_profile_email() {
+        let messages = Locale::Ja.messages();
+        let user = users_info_user(serde_json::json!({
+            "real_name": "Egon Spengler",
+            "display_name": "spengler"
+        }));
+
+        let email = user_resolver::profile_email(&user);
+        assert_eq!(email, None);
+
+        let LinkPrompt::RegistrationModal(SlackView::Modal(modal)) =
+            link_prompt(messages, email.as_ref())
+        else {
+            panic!("メール登録モーダルではありません");
+        };
+        assert_eq!(
+            modal.callback_id.map(|id| id.to_string()).as_deref(),
+            Some(CALLBACK_REGISTER_EMAIL)
+        );
+    }
+
+    #[test]
+    fn test_link_prompt_offers_profile_email() {
+        let messages = Locale::Ja.messages();
+        let user = users_info_user(serde_json::json!({
+            "real_name": "Egon Spengler",
+            "email": " spengler@example.com "
+        }));
+
+        let email = user_resolver::profile_email(&user);
+        assert_eq!(
+            email.as_ref().map(|email| email.as_str()),
+            Some("spengler@example.com")
+        );
+
+        let LinkPrompt::ProfileEmail(content) = link_prompt(messages, email.as_ref()) else {
+            panic!("ワンクリック連携の提案ではありません");
+        };
+        assert!(content.text.unwrap().contains("spengler@example.com"));
+        let action_ids: Vec<String> = content
+            .blocks
+            .unwrap()
+            .iter()
+            .filter_map(|block| match block {
+                SlackBlock::Actions(actions) => Some(actions),
+                _ => None,
+            })
+            .flat_map(|actions| actions.elements.iter())
+            .filter_map(|element| match element {
+                SlackActionBlockElement::Button(button) => Some(button.action_id.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            action_ids,
+            vec![ACTION_LINK_PROFILE_EMAIL, ACTION_ENTER_EMAIL_MANUALLY]
+        );
+    }
+
+    #[test]
+    fn test_parse_quick_args() {
+        let today = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+        let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+
+        assert_eq!(
+            parse_quick_args("tomorrow 14:00-18:00 thalys 0-1", today),
+            Some(QuickReserveArgs {
+                date: NaiveDate::from_ymd_opt(2025, 4, 2).unwrap(),
+                start: time(14),
+                end: time(18),
+                resource_name: "thalys",
+                device_spec: Some("0-1"),
+            })
+        );
+        assert_eq!(
+            parse_quick_args("会議室A 9:00-10:00", today),
+            Some(QuickReserveArgs {
+                date: today,
+                start: time(9),
+                end: time(10),
+                resource_name: "会議室A",
+                device_spec: None,
+            })
+        );
+
+        // 時間帯・リソース名が無い、または余分な引数がある
+        assert_eq!(parse_quick_args("tomorrow Thalys", today), None);
+        assert_eq!(parse_quick_args("14:00-18:00", today), None);
+        assert_eq!(parse_quick_args("14:00-18:00 Thalys 0 1", today), None);
+        assert_eq!(
+            parse_quick_args("today tomorrow 14:00-18:00 Thalys", today),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve_resources() {
+        let messages = Locale::Ja.messages();
+        let config: ResourceConfig = toml::from_str(
+            r#"
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers.devices]]
+id = 1
+model = "A100"
+
+[[rooms]]
+name = "会議室A"
+calendar_id = "room@example.com"
+notifications = []
+
+[[instruments]]
+name = "Oscilloscope"
+calendar_id = "scope@example.com"
+notifications = []
+
+[[resource_types]]
+id = "camera"
+label = "カメラ"
+
+[[resource_types.resources]]
+name = "EOS R5"
+calendar_id = "camera@example.com"
+notifications = []
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolve_resources(messages, &config, "thalys", Some("1"))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            resolve_resources(messages, &config, "Thalys", None)
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(resolve_resources(messages, &config, "Thalys", Some("5")).is_err());
+        assert_eq!(
+            resolve_resources(messages, &config, "会議室A", None).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string()
+            }]
+        );
+        assert!(resolve_resources(messages, &config, "会議室A", Some("0")).is_err());
+        assert_eq!(
+            resolve_resources(messages, &config, "oscilloscope", None).unwrap(),
+            vec![Resource::Instrument {
+                name: "Oscilloscope".to_string()
+            }]
+        );
+        assert!(resolve_resources(messages, &config, "Oscilloscope", Some("0")).is_err());
+        assert_eq!(
+            resolve_resources(messages, &config, "eos r5", None).unwrap(),
+            vec![Resource::Custom {
+                kind: "camera".to_string(),
+                name: "EOS R5".to_string()
+            }]
+        );
+        assert!(resolve_resources(messages, &config, "Freccia", None).is_err());
+    }
+}
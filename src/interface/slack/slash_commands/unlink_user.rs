@@ -0,0 +1,93 @@
+//! /unlink-user コマンドハンドラ
+
+use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::unlink_confirmation;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// /unlink-user スラッシュコマンドを処理
+///
+/// 引数なしの場合は自分の連携を解除する確認メッセージを返す。
+/// `<@slack_user>` を指定した場合は、そのユーザーの連携を解除する（管理者のみ）。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    let user_id = &event.user_id;
+    let text = event.text.as_deref().unwrap_or("").trim();
+
+    // 引数なし: 自分の連携を解除する確認メッセージを表示
+    if text.is_empty() {
+        let response = match user_resolver::resolve_user_email(user_id, app.identity_repo()).await {
+            Ok(email) => unlink_confirmation::create(messages, &EmailAddress::new(email)?),
+            Err(_) => SlackMessageContent::new().with_text(messages.not_linked_yet.to_string()),
+        };
+        return Ok(SlackCommandEventResponse::new(response));
+    }
+
+    let Some(target_user_id) = user_resolver::parse_user_mention(text) else {
+        return Ok(SlackCommandEventResponse::new(
+            SlackMessageContent::new().with_text(messages.unlink_usage.to_string()),
+        ));
+    };
+
+    // 他のユーザーの連携解除は管理者のみ
+    let is_admin =
+        user_resolver::is_admin(user_id, app.identity_repo(), app.resource_config()).await;
+    if !is_admin && target_user_id != user_id.as_ref() {
+        info!(
+            "管理者ではないユーザー {} が他のユーザーの連携解除を試みました",
+            user_id
+        );
+        return Ok(SlackCommandEventResponse::new(
+            SlackMessageContent::new().with_text(messages.unlink_others_forbidden.to_string()),
+        ));
+    }
+
+    let message = match app
+        .revoke_access_usecase()
+        .execute(
+            ExternalSystem::Slack,
+            target_user_id,
+            Some(user_id.to_string()),
+        )
+        .await
+    {
+        Ok(email) => {
+            info!(
+                "✅ 連携を解除しました: {} ({})",
+                target_user_id,
+                email.as_str()
+            );
+            fill(
+                messages.user_unlinked,
+                &[
+                    ("user", &format!("<@{}>", target_user_id)),
+                    ("email", email.as_str()),
+                ],
+            )
+        }
+        Err(e) => {
+            error!("❌ 連携の解除に失敗: {}", e);
+            fill(messages.unlink_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    Ok(SlackCommandEventResponse::new(
+        SlackMessageContent::new().with_text(message),
+    ))
+}
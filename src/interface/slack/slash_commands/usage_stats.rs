@@ -0,0 +1,98 @@
+//! /usage-stats コマンドハンドラ
+
+use crate::domain::aggregates::resource_usage::value_objects::TimePeriod;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::views::messages::usage_stats;
+use chrono::{Duration, Utc};
+use slack_morphism::prelude::*;
+use tracing::error;
+
+/// 集計する期間
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Window {
+    /// 過去7日間
+    Week,
+    /// 過去30日間
+    Month,
+}
+
+impl Window {
+    /// 集計期間の呼び名
+    pub(super) fn label(&self, messages: &Messages) -> &'static str {
+        match self {
+            Window::Week => messages.window_week,
+            Window::Month => messages.window_month,
+        }
+    }
+
+    /// 集計期間の長さ
+    pub(super) fn duration(&self) -> Duration {
+        match self {
+            Window::Week => Duration::days(7),
+            Window::Month => Duration::days(30),
+        }
+    }
+}
+
+/// /usage-stats スラッシュコマンドを処理
+///
+/// 現在時刻までの指定期間（省略時は過去7日間）の予約時間を、
+/// ユーザー・サーバー・デバイスごとに多い順で表示する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let messages = app
+        .user_preferences(&event.team_id, &event.user_id)
+        .await
+        .messages();
+    let Some(window) = parse_window(event.text.as_deref().unwrap_or("")) else {
+        return Ok(SlackCommandEventResponse::new(
+            SlackMessageContent::new().with_text(messages.usage_stats_usage.to_string()),
+        ));
+    };
+
+    let now = Utc::now();
+    let period = TimePeriod::new(now - window.duration(), now)?;
+    let response = match app.usage_report_usecase().execute(&period).await {
+        Ok(report) => usage_stats::create(messages, window.label(messages), &report),
+        Err(e) => {
+            error!("❌ 予約時間の集計に失敗: {}", e);
+            SlackMessageContent::new().with_text(fill(
+                messages.usage_stats_failed,
+                &[("error", &e.to_string())],
+            ))
+        }
+    };
+
+    Ok(SlackCommandEventResponse::new(response))
+}
+
+/// コマンド引数から集計期間を解釈する（省略時は過去7日間）
+pub(super) fn parse_window(text: &str) -> Option<Window> {
+    match text.trim().to_lowercase().as_str() {
+        "" | "week" => Some(Window::Week),
+        "month" => Some(Window::Month),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_window() {
+        assert_eq!(parse_window(""), Some(Window::Week));
+        assert_eq!(parse_window(" week "), Some(Window::Week));
+        assert_eq!(parse_window("Month"), Some(Window::Month));
+        assert_eq!(parse_window("year"), None);
+    }
+}
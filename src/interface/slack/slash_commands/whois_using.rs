@@ -0,0 +1,104 @@
+//! /whois-using コマンドハンドラ
+
+use crate::application::read_model::ReservationView;
+use crate::domain::aggregates::resource_usage::value_objects::Resource;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::slash_commands::reserve::resolve_resources;
+use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::whois_using::{self, Occupant};
+use chrono::Utc;
+use slack_morphism::prelude::*;
+use std::sync::Arc;
+use tracing::error;
+
+/// /whois-using スラッシュコマンドを処理
+///
+/// 指定したサーバー・部屋を現在使用中のユーザーと、いつまで使うかを表示する。
+/// 非公開の予約は、予約者本人と管理者以外には予約者を伏せる。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    event: SlackCommandEvent,
+) -> Result<SlackCommandEventResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let preferences = app.user_preferences(&event.team_id, &event.user_id).await;
+    let messages = preferences.messages();
+    let text = event.text.as_deref().unwrap_or("").trim();
+    if text.is_empty() || text.split_whitespace().count() > 1 {
+        return Ok(text_response(messages.whois_using_usage.to_string()));
+    }
+
+    // 大文字・小文字を区別せず、設定どおりのリソース名にそろえる
+    let config = app.resource_config();
+    let resource_name = match resolve_resources(messages, config, text, None) {
+        Ok(resources) => resources
+            .first()
+            .map(|resource| resource.name().to_string())
+            .unwrap_or_else(|| text.to_string()),
+        Err(message) => return Ok(text_response(format!("❌ {}", message))),
+    };
+
+    let now = Utc::now();
+    let usages = match app
+        .current_occupants_usecase()
+        .execute(&resource_name, now)
+        .await
+    {
+        Ok(usages) => usages,
+        Err(e) => {
+            error!("❌ 使用中の予約の取得に失敗: {}", e);
+            return Ok(text_response(fill(
+                messages.whois_using_failed,
+                &[("error", &e.to_string())],
+            )));
+        }
+    };
+
+    let identity_repo = app.identity_repo();
+    let viewer = user_resolver::resolve_user_email(&event.user_id, identity_repo)
+        .await
+        .ok()
+        .and_then(|email| EmailAddress::new(email).ok());
+    let is_admin = user_resolver::is_admin(&event.user_id, identity_repo, config).await;
+
+    let mut occupants = Vec::new();
+    for usage in usages {
+        let view = ReservationView::new(Arc::new(usage), viewer.as_ref(), is_admin);
+        let owner = match view.owner_email() {
+            Some(email) => user_resolver::mention_for_email(email, identity_repo).await,
+            None => messages.redacted_owner.to_string(),
+        };
+        occupants.push(Occupant {
+            owner,
+            devices: view
+                .resources()
+                .iter()
+                .filter_map(|resource| match resource {
+                    Resource::Gpu(gpu) if gpu.server() == resource_name => {
+                        Some(gpu.device_number())
+                    }
+                    _ => None,
+                })
+                .collect(),
+            until: view.time_period().end(),
+        });
+    }
+
+    Ok(SlackCommandEventResponse::new(whois_using::create(
+        messages,
+        &resource_name,
+        &occupants,
+        now,
+        preferences.timezone,
+    )))
+}
+
+fn text_response(text: String) -> SlackCommandEventResponse {
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text(text))
+}
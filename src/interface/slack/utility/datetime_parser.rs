@@ -1,12 +1,14 @@
 //! 日付・時刻パースユーティリティ
 
-use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 
 /// 日付文字列と時刻文字列をUTC DateTimeにパース
 ///
 /// # 引数
 /// * `date_str` - 日付文字列 (YYYY-MM-DD形式)
 /// * `time_str` - 時刻文字列 (HH:MM形式)
+/// * `timezone` - 入力された日時のタイムゾーン（`None` の場合はシステムのローカルタイムゾーン）
 ///
 /// # 戻り値
 /// パースされたUTC DateTime
@@ -17,6 +19,7 @@ use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
 pub fn parse_datetime(
     date_str: &str,
     time_str: &str,
+    timezone: Option<Tz>,
 ) -> Result<DateTime<Utc>, Box<dyn std::error::Error + Send + Sync>> {
     // 日付をパース (YYYY-MM-DD形式)
     let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
@@ -29,16 +32,55 @@ pub fn parse_datetime(
     // 日付と時刻を結合
     let naive_datetime = date.and_time(time);
 
-    // ローカルタイムゾーンでDateTime<Local>を作成してからUTCに変換
-    let local_datetime = Local
-        .from_local_datetime(&naive_datetime)
-        .single()
-        .ok_or_else(|| {
-            format!(
-                "無効な日時: {} {} (夏時間の切り替え時刻の可能性があります)",
-                date_str, time_str
-            )
-        })?;
-
-    Ok(local_datetime.with_timezone(&Utc))
+    // 指定のタイムゾーン（なければローカルタイムゾーン）の日時としてからUTCに変換
+    let datetime = match timezone {
+        Some(tz) => tz
+            .from_local_datetime(&naive_datetime)
+            .single()
+            .map(|datetime| datetime.with_timezone(&Utc)),
+        None => Local
+            .from_local_datetime(&naive_datetime)
+            .single()
+            .map(|datetime| datetime.with_timezone(&Utc)),
+    };
+
+    datetime.ok_or_else(|| {
+        format!(
+            "無効な日時: {} {} (夏時間の切り替え時刻の可能性があります)",
+            date_str, time_str
+        )
+        .into()
+    })
+}
+
+/// UTC DateTimeを利用者のタイムゾーンの日時に変換
+///
+/// # 引数
+/// * `datetime` - 変換する日時
+/// * `timezone` - 利用者のタイムゾーン（`None` の場合はシステムのローカルタイムゾーン）
+pub fn to_user_time(datetime: DateTime<Utc>, timezone: Option<Tz>) -> DateTime<FixedOffset> {
+    match timezone {
+        Some(tz) => datetime.with_timezone(&tz).fixed_offset(),
+        None => datetime.with_timezone(&Local).fixed_offset(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_datetime_in_user_timezone() {
+        let tokyo = parse_datetime("2025-04-01", "10:00", Some(Tz::Asia__Tokyo)).unwrap();
+        let berlin = parse_datetime("2025-04-01", "10:00", Some(Tz::Europe__Berlin)).unwrap();
+
+        assert_eq!(tokyo, Utc.with_ymd_and_hms(2025, 4, 1, 1, 0, 0).unwrap());
+        assert_eq!(berlin, Utc.with_ymd_and_hms(2025, 4, 1, 8, 0, 0).unwrap());
+        assert_eq!(
+            to_user_time(berlin, Some(Tz::Europe__Berlin))
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            "2025-04-01 10:00"
+        );
+    }
 }
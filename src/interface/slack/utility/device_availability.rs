@@ -0,0 +1,101 @@
+//! デバイスの使用状況
+//!
+//! 予約モーダルのデバイス選択に、選択中の期間の使用状況を表示するために使います。
+//! 外部のカレンダーAPIへはアクセスせず、定期的に再構築される予約の読み取りモデルを参照します。
+
+use crate::application::read_model::{ReservationProjection, ReservationView};
+use crate::domain::aggregates::resource_usage::value_objects::{
+    Gpu, Resource, TimePeriod, UsageId,
+};
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::repositories::IdentityLinkRepository;
+use crate::infrastructure::config::ResourceConfig;
+use crate::interface::slack::utility::user_resolver;
+use chrono::{DateTime, Duration, Utc};
+use slack_morphism::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 指定期間に使用中のデバイス
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusyDevice {
+    /// サーバー名
+    pub server: String,
+    /// デバイス番号
+    pub device: u32,
+    /// 使用中の予約が終わる時刻（複数ある場合は最も遅いもの）
+    pub until: DateTime<Utc>,
+    /// 予約者（Slackのメンション、連携していない場合はメールアドレス。非公開で伏せる場合は `None`）
+    pub owner: Option<String>,
+}
+
+/// 新規予約モーダルの日時の初期値と同じ期間（現在時刻から1時間）
+pub fn new_reservation_period() -> Option<TimePeriod> {
+    let now = Utc::now();
+    TimePeriod::new(now, now + Duration::hours(1)).ok()
+}
+
+/// 指定期間に使用中のデバイスを取得
+///
+/// 非公開の予約は、閲覧者が予約者本人か管理者の場合だけ予約者を表示する。
+///
+/// # 引数
+/// * `projection` - 予約の読み取りモデルのスナップショット
+/// * `config` - リソース設定
+/// * `identity_repo` - ID紐付けリポジトリ
+/// * `viewer` - モーダルを開いたSlackユーザー
+/// * `time_period` - 使用状況を調べる期間
+/// * `exclude` - 除外する予約（編集中の予約）
+pub async fn busy_devices(
+    projection: &ReservationProjection,
+    config: &ResourceConfig,
+    identity_repo: &Arc<dyn IdentityLinkRepository>,
+    viewer: &SlackUserId,
+    time_period: &TimePeriod,
+    exclude: Option<&UsageId>,
+) -> Vec<BusyDevice> {
+    let viewer_email = user_resolver::resolve_user_email(viewer, identity_repo)
+        .await
+        .ok()
+        .and_then(|email| EmailAddress::new(email).ok());
+    let is_admin = user_resolver::is_admin(viewer, identity_repo, config).await;
+
+    let mut mentions: HashMap<EmailAddress, String> = HashMap::new();
+    let mut busy = Vec::new();
+    for server in &config.servers {
+        for device in &server.devices {
+            let resource = Resource::Gpu(Gpu::new(
+                server.name.clone(),
+                device.id,
+                device.model.clone(),
+            ));
+            let Some(usage) = projection
+                .overlapping(&resource, time_period)
+                .into_iter()
+                .filter(|usage| Some(usage.id()) != exclude)
+                .max_by_key(|usage| usage.time_period().end())
+            else {
+                continue;
+            };
+
+            let view = ReservationView::new(usage, viewer_email.as_ref(), is_admin);
+            let owner = match view.owner_email() {
+                Some(email) => {
+                    if !mentions.contains_key(email) {
+                        let mention = user_resolver::mention_for_email(email, identity_repo).await;
+                        mentions.insert(email.clone(), mention);
+                    }
+                    mentions.get(email).cloned()
+                }
+                None => None,
+            };
+            busy.push(BusyDevice {
+                server: server.name.clone(),
+                device: device.id,
+                until: view.time_period().end(),
+                owner,
+            });
+        }
+    }
+    busy
+}
@@ -2,6 +2,12 @@
 //!
 //! Slackモーダルからフォーム値を抽出するユーティリティ
 
+use crate::domain::aggregates::group::GroupId;
+use crate::domain::aggregates::resource_usage::value_objects::{Priority, Visibility};
+use crate::interface::slack::constants::{
+    ACTION_RESERVE_GROUP, ACTION_RESERVE_PREEMPT, ACTION_RESERVE_PRIORITY, ACTION_RESERVE_PRIVATE,
+    RESERVE_PREEMPT_OPTION_VALUE, RESERVE_PRIVATE_OPTION_VALUE,
+};
 use slack_morphism::prelude::*;
 
 /// ビュー送信からプレーンテキスト入力値を取得
@@ -148,6 +154,109 @@ pub fn get_selected_options(
     Vec::new()
 }
 
+/// すべてのブロックのチェックボックスまたはマルチセレクトから、選択されたオプションの値を取得
+///
+/// 選択肢が多く、同じアクションIDの入力欄を複数のブロックに分けている場合に使う。
+///
+/// # 引数
+/// * `view_submission` - ビュー送信イベント
+/// * `action_id_str` - アクションID文字列
+pub fn get_selected_options_in_all_blocks(
+    view_submission: &SlackInteractionViewSubmissionEvent,
+    action_id_str: &str,
+) -> Vec<String> {
+    let Some(state) = &view_submission.view.state_params.state else {
+        return Vec::new();
+    };
+    let action_id = SlackActionId::new(action_id_str.to_string());
+
+    state
+        .values
+        .values()
+        .filter_map(|actions_map| actions_map.get(&action_id))
+        .filter_map(|value| value.selected_options.as_ref())
+        .flat_map(|options| options.iter().map(|opt| opt.value.clone()))
+        .collect()
+}
+
+/// 指定したブロック内のチェックボックスまたはマルチセレクトから、選択されたオプションの値を取得
+///
+/// 同じアクションIDの入力欄が複数のブロックにある場合（サーバーごとのデバイス選択など）に使う。
+///
+/// # 引数
+/// * `view_submission` - ビュー送信イベント
+/// * `block_id_str` - ブロックID文字列
+/// * `action_id_str` - アクションID文字列
+pub fn get_selected_options_in_block(
+    view_submission: &SlackInteractionViewSubmissionEvent,
+    block_id_str: &str,
+    action_id_str: &str,
+) -> Vec<String> {
+    let Some(state) = &view_submission.view.state_params.state else {
+        return Vec::new();
+    };
+
+    state
+        .values
+        .get(&SlackBlockId::new(block_id_str.to_string()))
+        .and_then(|actions_map| actions_map.get(&SlackActionId::new(action_id_str.to_string())))
+        .and_then(|value| value.selected_options.as_ref())
+        .map(|options| options.iter().map(|opt| opt.value.clone()).collect())
+        .unwrap_or_default()
+}
+
+/// 公開範囲のチェックボックスから予約の公開範囲を取得
+///
+/// # 引数
+/// * `view_submission` - ビュー送信イベント
+///
+/// # 戻り値
+/// 「非公開にする」が選択されていれば `Visibility::Private`、そうでなければ `Visibility::Public`
+pub fn get_visibility(view_submission: &SlackInteractionViewSubmissionEvent) -> Visibility {
+    if get_selected_options(view_submission, ACTION_RESERVE_PRIVATE)
+        .iter()
+        .any(|value| value == RESERVE_PRIVATE_OPTION_VALUE)
+    {
+        Visibility::Private
+    } else {
+        Visibility::Public
+    }
+}
+
+/// 優先度のセレクトメニューから予約の優先度を取得
+///
+/// # 引数
+/// * `view_submission` - ビュー送信イベント
+///
+/// # 戻り値
+/// 選択されていない場合は `Priority::Normal`
+pub fn get_priority(view_submission: &SlackInteractionViewSubmissionEvent) -> Priority {
+    get_selected_option_value(view_submission, ACTION_RESERVE_PRIORITY)
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+/// チームのセレクトメニューから予約を所有するチームを取得
+///
+/// # 引数
+/// * `view_submission` - ビュー送信イベント
+///
+/// # 戻り値
+/// 選択されていない場合は `None`（予約者個人の予約）
+pub fn get_group(view_submission: &SlackInteractionViewSubmissionEvent) -> Option<GroupId> {
+    get_selected_option_value(view_submission, ACTION_RESERVE_GROUP).map(GroupId::new)
+}
+
+/// 横取りのチェックボックスが選択されているかどうか
+///
+/// # 引数
+/// * `view_submission` - ビュー送信イベント
+pub fn get_preempt(view_submission: &SlackInteractionViewSubmissionEvent) -> bool {
+    get_selected_options(view_submission, ACTION_RESERVE_PREEMPT)
+        .iter()
+        .any(|value| value == RESERVE_PREEMPT_OPTION_VALUE)
+}
+
 /// モーダルビューからprivate_metadataを取得
 ///
 /// # 引数
@@ -0,0 +1,372 @@
+//! 予約フォームの入力検証
+//!
+//! 予約・予約更新モーダルの入力値（日時・繰り返し・メタデータ）を検証し、問題のある入力欄ごとにエラーを返す。
+//! エラーのキーは入力欄のブロックIDで、`SlackViewSubmissionResponse::Errors` にそのまま使える。
+
+use crate::domain::aggregates::resource_usage::errors::ResourceUsageError;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    RecurrenceFrequency, RecurrenceRule, ReservationMetadata, TimePeriod,
+};
+use crate::infrastructure::i18n::{Locale, Messages, fill};
+use crate::interface::slack::constants::*;
+use crate::interface::slack::utility::datetime_parser::parse_datetime;
+use crate::interface::slack::utility::extract_form_data;
+use chrono_tz::Tz;
+use slack_morphism::prelude::*;
+use std::collections::HashMap;
+
+/// 入力欄ごとのエラー（ブロックID → エラーメッセージ）
+pub type FieldErrors = HashMap<String, String>;
+
+/// 入力欄ごとのエラーからビュー送信の応答を作成
+pub fn errors_response(errors: FieldErrors) -> SlackViewSubmissionResponse {
+    SlackViewSubmissionResponse::Errors(SlackViewSubmissionErrorsResponse::new(errors))
+}
+
+/// 1つの入力欄だけのエラーを作成
+pub fn errors_at(block_id: &str, message: String) -> FieldErrors {
+    FieldErrors::from([(block_id.to_string(), message)])
+}
+
+/// 予約フォームから使用期間を取得
+///
+/// 入力された日時は `timezone`（`None` の場合はシステムのローカルタイムゾーン）の日時として扱う。
+///
+/// # エラー
+/// 未入力・不正な日時や、終了が開始以前の場合は入力欄ごとのエラーを返す
+pub fn time_period_from_form(
+    view_submission: &SlackInteractionViewSubmissionEvent,
+    timezone: Option<Tz>,
+    messages: &Messages,
+) -> Result<TimePeriod, FieldErrors> {
+    validate_time_period(
+        extract_form_data::get_selected_date(view_submission, ACTION_RESERVE_START_DATE).as_deref(),
+        extract_form_data::get_selected_time(view_submission, ACTION_RESERVE_START_TIME).as_deref(),
+        extract_form_data::get_selected_date(view_submission, ACTION_RESERVE_END_DATE).as_deref(),
+        extract_form_data::get_selected_time(view_submission, ACTION_RESERVE_END_TIME).as_deref(),
+        timezone,
+        messages,
+    )
+}
+
+/// 入力途中の予約フォームの状態から使用期間を取得
+///
+/// 送信前のモーダルを作り直すときに使う。未入力・不正な日時の場合は `None` を返す
+/// （エラーの内容は使わないため、メッセージは既定の言語で作る）。
+pub fn time_period_from_state(state: &SlackViewState, timezone: Option<Tz>) -> Option<TimePeriod> {
+    let value = |action_id: &str| {
+        let action_id = SlackActionId::new(action_id.to_string());
+        state
+            .values
+            .values()
+            .find_map(|actions| actions.get(&action_id))
+    };
+    let date = |id: &str| value(id).and_then(|v| v.selected_date.as_ref().map(|d| d.to_string()));
+    let time = |id: &str| value(id).and_then(|v| v.selected_time.as_ref().map(|t| t.to_string()));
+
+    validate_time_period(
+        date(ACTION_RESERVE_START_DATE).as_deref(),
+        time(ACTION_RESERVE_START_TIME).as_deref(),
+        date(ACTION_RESERVE_END_DATE).as_deref(),
+        time(ACTION_RESERVE_END_TIME).as_deref(),
+        timezone,
+        Locale::default().messages(),
+    )
+    .ok()
+}
+
+/// 予約フォームから繰り返し規則を取得
+///
+/// 繰り返さない場合は `None` を返す。
+///
+/// # エラー
+/// 終了日が未入力・不正な場合や、回数が多すぎる場合は入力欄ごとのエラーを返す
+pub fn recurrence_from_form(
+    view_submission: &SlackInteractionViewSubmissionEvent,
+    first: &TimePeriod,
+    timezone: Option<Tz>,
+    messages: &Messages,
+) -> Result<Option<RecurrenceRule>, FieldErrors> {
+    validate_recurrence(
+        extract_form_data::get_selected_option_value(view_submission, ACTION_RESERVE_REPEAT)
+            .as_deref(),
+        extract_form_data::get_selected_date(view_submission, ACTION_RESERVE_REPEAT_UNTIL)
+            .as_deref(),
+        first,
+        timezone,
+        messages,
+    )
+}
+
+/// 予約フォームからメタデータ（プロジェクト名・実験ID・想定使用率）を取得
+///
+/// # エラー
+/// 想定使用率が0〜100の整数でない場合は入力欄のエラーを返す
+pub fn metadata_from_form(
+    view_submission: &SlackInteractionViewSubmissionEvent,
+    messages: &Messages,
+) -> Result<ReservationMetadata, FieldErrors> {
+    validate_metadata(
+        extract_form_data::get_plain_text_input(view_submission, ACTION_RESERVE_PROJECT),
+        extract_form_data::get_plain_text_input(view_submission, ACTION_RESERVE_EXPERIMENT),
+        extract_form_data::get_plain_text_input(view_submission, ACTION_RESERVE_UTILIZATION)
+            .as_deref(),
+        messages,
+    )
+}
+
+/// メタデータの入力値を検証する
+fn validate_metadata(
+    project: Option<String>,
+    experiment_id: Option<String>,
+    utilization: Option<&str>,
+    messages: &Messages,
+) -> Result<ReservationMetadata, FieldErrors> {
+    let invalid = || {
+        errors_at(
+            ACTION_RESERVE_UTILIZATION,
+            messages.invalid_utilization.to_string(),
+        )
+    };
+    let utilization = match utilization.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => Some(value.parse::<u8>().map_err(|_| invalid())?),
+        None => None,
+    };
+    ReservationMetadata::new(project, experiment_id, utilization).map_err(|_| invalid())
+}
+
+/// 繰り返しの入力値を検証して繰り返し規則にする
+fn validate_recurrence(
+    repeat: Option<&str>,
+    until_date: Option<&str>,
+    first: &TimePeriod,
+    timezone: Option<Tz>,
+    messages: &Messages,
+) -> Result<Option<RecurrenceRule>, FieldErrors> {
+    let Some(repeat) = repeat.filter(|value| *value != RESERVE_REPEAT_NONE_VALUE) else {
+        return Ok(None);
+    };
+    let frequency = RecurrenceFrequency::parse(repeat).ok_or_else(|| {
+        errors_at(
+            ACTION_RESERVE_REPEAT,
+            fill(messages.unknown_repeat, &[("repeat", repeat)]),
+        )
+    })?;
+    let until_date = until_date.ok_or_else(|| {
+        errors_at(
+            ACTION_RESERVE_REPEAT_UNTIL,
+            messages.repeat_until_required.to_string(),
+        )
+    })?;
+
+    // 終了日の終わりまでに開始する回を含める
+    let until = parse_datetime(until_date, "23:59", timezone).map_err(|_| {
+        invalid_datetime(ACTION_RESERVE_REPEAT_UNTIL, until_date, "23:59", messages)
+    })?;
+    let recurrence = RecurrenceRule::until(frequency, until);
+    match recurrence.occurrences(first) {
+        Ok(_) => Ok(Some(recurrence)),
+        Err(ResourceUsageError::InvalidRecurrence(reason)) => {
+            Err(errors_at(ACTION_RESERVE_REPEAT_UNTIL, reason))
+        }
+        Err(e) => Err(errors_at(ACTION_RESERVE_REPEAT_UNTIL, e.to_string())),
+    }
+}
+
+/// 日付・時刻の入力値を検証して使用期間にする
+fn validate_time_period(
+    start_date: Option<&str>,
+    start_time: Option<&str>,
+    end_date: Option<&str>,
+    end_time: Option<&str>,
+    timezone: Option<Tz>,
+    messages: &Messages,
+) -> Result<TimePeriod, FieldErrors> {
+    let mut errors = FieldErrors::new();
+    for (value, block_id, message) in [
+        (
+            start_date,
+            ACTION_RESERVE_START_DATE,
+            messages.start_date_required,
+        ),
+        (
+            start_time,
+            ACTION_RESERVE_START_TIME,
+            messages.start_time_required,
+        ),
+        (
+            end_date,
+            ACTION_RESERVE_END_DATE,
+            messages.end_date_required,
+        ),
+        (
+            end_time,
+            ACTION_RESERVE_END_TIME,
+            messages.end_time_required,
+        ),
+    ] {
+        if value.is_none() {
+            errors.insert(block_id.to_string(), message.to_string());
+        }
+    }
+
+    let (Some(start_date), Some(start_time), Some(end_date), Some(end_time)) =
+        (start_date, start_time, end_date, end_time)
+    else {
+        return Err(errors);
+    };
+
+    let start = parse_datetime(start_date, start_time, timezone).map_err(|_| {
+        invalid_datetime(ACTION_RESERVE_START_TIME, start_date, start_time, messages)
+    })?;
+    let end = parse_datetime(end_date, end_time, timezone)
+        .map_err(|_| invalid_datetime(ACTION_RESERVE_END_TIME, end_date, end_time, messages))?;
+
+    TimePeriod::new(start, end).map_err(|_| {
+        // 日付が逆転している場合は終了日、同じ日なら終了時刻の欄に表示する
+        let block_id = if end_date < start_date {
+            ACTION_RESERVE_END_DATE
+        } else {
+            ACTION_RESERVE_END_TIME
+        };
+        errors_at(block_id, messages.end_not_after_start.to_string())
+    })
+}
+
+/// 日時として解釈できない入力のエラー
+fn invalid_datetime(block_id: &str, date: &str, time: &str, messages: &Messages) -> FieldErrors {
+    errors_at(
+        block_id,
+        fill(
+            messages.invalid_datetime,
+            &[("datetime", &format!("{} {}", date, time))],
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_time_period_accepts_valid_input() {
+        let period = validate_time_period(
+            Some("2025-04-01"),
+            Some("10:00"),
+            Some("2025-04-01"),
+            Some("12:00"),
+            None,
+            Locale::Ja.messages(),
+        )
+        .unwrap();
+
+        assert_eq!((period.end() - period.start()).num_hours(), 2);
+    }
+
+    #[test]
+    fn test_validate_time_period_reports_missing_fields() {
+        let errors = validate_time_period(
+            None,
+            Some("10:00"),
+            Some("2025-04-01"),
+            None,
+            None,
+            Locale::Ja.messages(),
+        )
+        .unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors.contains_key(ACTION_RESERVE_START_DATE));
+        assert!(errors.contains_key(ACTION_RESERVE_END_TIME));
+    }
+
+    #[test]
+    fn test_validate_time_period_reports_end_before_start() {
+        let same_day = validate_time_period(
+            Some("2025-04-01"),
+            Some("10:00"),
+            Some("2025-04-01"),
+            Some("09:00"),
+            None,
+            Locale::Ja.messages(),
+        )
+        .unwrap_err();
+        assert!(same_day.contains_key(ACTION_RESERVE_END_TIME));
+
+        let earlier_day = validate_time_period(
+            Some("2025-04-02"),
+            Some("10:00"),
+            Some("2025-04-01"),
+            Some("12:00"),
+            None,
+            Locale::Ja.messages(),
+        )
+        .unwrap_err();
+        assert!(earlier_day.contains_key(ACTION_RESERVE_END_DATE));
+    }
+
+    #[test]
+    fn test_validate_recurrence() {
+        let first = validate_time_period(
+            Some("2025-04-01"),
+            Some("10:00"),
+            Some("2025-04-01"),
+            Some("12:00"),
+            None,
+            Locale::Ja.messages(),
+        )
+        .unwrap();
+        let messages = Locale::Ja.messages();
+
+        assert_eq!(
+            validate_recurrence(None, None, &first, None, messages).unwrap(),
+            None
+        );
+        assert_eq!(
+            validate_recurrence(
+                Some(RESERVE_REPEAT_NONE_VALUE),
+                None,
+                &first,
+                None,
+                messages
+            )
+            .unwrap(),
+            None
+        );
+
+        let recurrence =
+            validate_recurrence(Some("weekly"), Some("2025-04-15"), &first, None, messages)
+                .unwrap()
+                .unwrap();
+        assert_eq!(recurrence.occurrences(&first).unwrap().len(), 3);
+
+        let missing_until =
+            validate_recurrence(Some("biweekly"), None, &first, None, messages).unwrap_err();
+        assert!(missing_until.contains_key(ACTION_RESERVE_REPEAT_UNTIL));
+
+        let until_before_start =
+            validate_recurrence(Some("weekly"), Some("2025-03-31"), &first, None, messages)
+                .unwrap_err();
+        assert!(until_before_start.contains_key(ACTION_RESERVE_REPEAT_UNTIL));
+    }
+
+    #[test]
+    fn test_validate_metadata() {
+        let messages = Locale::Ja.messages();
+
+        let metadata =
+            validate_metadata(Some("LLM".to_string()), None, Some(" 80 "), messages).unwrap();
+        assert_eq!(metadata.project(), Some("LLM"));
+        assert_eq!(metadata.expected_utilization(), Some(80));
+        assert!(
+            validate_metadata(None, None, None, messages)
+                .unwrap()
+                .is_empty()
+        );
+
+        for invalid in ["101", "-1", "50.5", "half"] {
+            let errors = validate_metadata(None, None, Some(invalid), messages).unwrap_err();
+            assert!(errors.contains_key(ACTION_RESERVE_UTILIZATION));
+        }
+    }
+}
@@ -12,7 +12,11 @@
 //! - `extract_form_data`: Slackフォームデータの抽出
 //! - `user_resolver`: SlackユーザーIDからメールアドレスへの解決
 //! - `datetime_parser`: 日付・時刻のパース
+//! - `form_validation`: 予約フォームの入力検証
+//! - `device_availability`: 予約モーダルに表示するデバイスの使用状況
 
 pub mod datetime_parser;
+pub mod device_availability;
 pub mod extract_form_data;
+pub mod form_validation;
 pub mod user_resolver;
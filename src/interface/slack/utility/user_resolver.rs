@@ -3,9 +3,15 @@
 //! SlackユーザーIDからメールアドレスへの解決を行います
 
 use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
+use crate::domain::common::EmailAddress;
 use crate::domain::ports::repositories::IdentityLinkRepository;
+use crate::domain::services::Role;
+use crate::infrastructure::config::{I18nConfig, ResourceConfig};
+use crate::infrastructure::i18n::{Locale, Messages};
+use chrono_tz::Tz;
 use slack_morphism::prelude::*;
 use std::sync::Arc;
+use tracing::warn;
 
 /// SlackユーザーIDをメールアドレスに解決
 ///
@@ -51,3 +57,206 @@ pub async fn is_user_linked(
         .flatten()
         .is_some()
 }
+
+/// ユーザーの役割を求める
+///
+/// SlackユーザーIDと紐付けられたメールアドレスのうち、リソース設定の `admins`・`moderators` で
+/// より強い役割に登録されている方を使う。
+///
+/// # 引数
+/// * `slack_user_id` - SlackユーザーID
+/// * `identity_repo` - ID紐付けリポジトリ
+/// * `resource_config` - リソース設定
+pub async fn role(
+    slack_user_id: &SlackUserId,
+    identity_repo: &Arc<dyn IdentityLinkRepository>,
+    resource_config: &ResourceConfig,
+) -> Role {
+    let by_user_id = resource_config.role_of_user_id(slack_user_id.as_ref());
+    if by_user_id == Role::Admin {
+        return by_user_id;
+    }
+    let by_email = identity_repo
+        .find_by_external_user_id(&ExternalSystem::Slack, slack_user_id.as_ref())
+        .await
+        .ok()
+        .flatten()
+        .map(|identity_link| resource_config.role_of(identity_link.email()))
+        .unwrap_or_default();
+    by_user_id.max(by_email)
+}
+
+/// ユーザーが管理者かどうかチェック
+///
+/// SlackユーザーID、または紐付けられたメールアドレスがリソース設定の `admins` に含まれている場合に
+/// 管理者とみなす。
+///
+/// # 引数
+/// * `slack_user_id` - SlackユーザーID
+/// * `identity_repo` - ID紐付けリポジトリ
+/// * `resource_config` - リソース設定
+pub async fn is_admin(
+    slack_user_id: &SlackUserId,
+    identity_repo: &Arc<dyn IdentityLinkRepository>,
+    resource_config: &ResourceConfig,
+) -> bool {
+    role(slack_user_id, identity_repo, resource_config).await == Role::Admin
+}
+
+/// メールアドレスのユーザーの表示を取得
+///
+/// Slackと連携していればメンション（`<@U...>`）、連携していなければメールアドレスを返す。
+pub async fn mention_for_email(
+    email: &EmailAddress,
+    identity_repo: &Arc<dyn IdentityLinkRepository>,
+) -> String {
+    identity_repo
+        .find_by_email(email)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|identity_link| {
+            identity_link
+                .get_identity_for_system(&ExternalSystem::Slack)
+                .map(|identity| format!("<@{}>", identity.user_id()))
+        })
+        .unwrap_or_else(|| email.as_str().to_string())
+}
+
+/// Slackプロフィールに登録されたメールアドレスを取得
+///
+/// `users.info` を呼び出す。Botトークンに `users:read.email` スコープが必要。
+///
+/// # 引数
+/// * `slack_client` - Slackクライアント
+/// * `bot_token` - Botトークン
+/// * `slack_user_id` - SlackユーザーID
+///
+/// # 戻り値
+/// プロフィールにメールアドレスが無い場合や取得に失敗した場合は `None`
+pub async fn fetch_profile_email(
+    slack_client: &SlackHyperClient,
+    bot_token: &SlackApiToken,
+    slack_user_id: &SlackUserId,
+) -> Option<EmailAddress> {
+    let session = slack_client.open_session(bot_token);
+    let response = match session
+        .users_info(&SlackApiUsersInfoRequest::new(slack_user_id.clone()))
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            warn!("⚠️ Slackプロフィールの取得に失敗: {}", e);
+            return None;
+        }
+    };
+
+    profile_email(&response.user)
+}
+
+/// `users.info` で得たユーザーのプロフィールからメールアドレスを取り出す
+///
+/// Botトークンにスコープが無い場合や、ゲストなどでプロフィールにメールアドレスが無い場合は `None`
+pub fn profile_email(user: &SlackUser) -> Option<EmailAddress> {
+    let email = user.profile.as_ref()?.email.as_ref()?;
+    EmailAddress::new(email.0.trim().to_string()).ok()
+}
+
+/// 利用者ごとの表示設定
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UserPreferences {
+    /// 日時を扱うタイムゾーン（`None` の場合はシステムのローカルタイムゾーン）
+    pub timezone: Option<Tz>,
+    /// 表示言語
+    pub locale: Locale,
+}
+
+impl UserPreferences {
+    /// 表示言語のメッセージカタログ
+    pub fn messages(&self) -> &'static Messages {
+        self.locale.messages()
+    }
+}
+
+/// Slackプロフィールから利用者の表示設定を取得
+///
+/// `users.info` を呼び出し、プロフィールの `tz`（IANA形式）をタイムゾーンとする。
+/// 表示言語は設定の `locale` を使い、`use_slack_locale` が有効な場合は利用者のSlackの言語設定を優先する。
+///
+/// # 引数
+/// * `slack_client` - Slackクライアント
+/// * `bot_token` - Botトークン
+/// * `slack_user_id` - SlackユーザーID
+/// * `i18n` - 表示言語の設定
+///
+/// # 戻り値
+/// 取得に失敗した場合はタイムゾーンを `None`、表示言語を設定の `locale` とする
+pub async fn fetch_user_preferences(
+    slack_client: &SlackHyperClient,
+    bot_token: &SlackApiToken,
+    slack_user_id: &SlackUserId,
+    i18n: &I18nConfig,
+) -> UserPreferences {
+    let session = slack_client.open_session(bot_token);
+    let user = match session
+        .users_info(
+            &SlackApiUsersInfoRequest::new(slack_user_id.clone())
+                .with_include_locale(i18n.use_slack_locale),
+        )
+        .await
+    {
+        Ok(response) => response.user,
+        Err(e) => {
+            warn!("⚠️ Slackプロフィールの取得に失敗: {}", e);
+            return UserPreferences {
+                timezone: None,
+                locale: i18n.locale,
+            };
+        }
+    };
+
+    let locale = user
+        .locale
+        .filter(|_| i18n.use_slack_locale)
+        .and_then(|locale| Locale::from_slack_locale(&locale.0))
+        .unwrap_or(i18n.locale);
+
+    UserPreferences {
+        timezone: user.tz.and_then(|tz| tz.parse().ok()),
+        locale,
+    }
+}
+
+/// コマンド引数からSlackユーザーIDを取り出す
+///
+/// `<@U123>`、`<@U123|name>` 形式のメンション、またはユーザーIDそのものを受け付ける。
+pub fn parse_user_mention(text: &str) -> Option<&str> {
+    let id = match text.strip_prefix("<@") {
+        Some(rest) => {
+            let rest = rest.strip_suffix('>')?;
+            rest.split('|').next()?
+        }
+        None => text,
+    };
+    let is_user_id = id.starts_with(['U', 'W'])
+        && id.len() > 1
+        && id
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+    is_user_id.then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user_mention() {
+        assert_eq!(parse_user_mention("<@U012AB3CD>"), Some("U012AB3CD"));
+        assert_eq!(parse_user_mention("<@W012AB3CD|bob>"), Some("W012AB3CD"));
+        assert_eq!(parse_user_mention("U012AB3CD"), Some("U012AB3CD"));
+        assert_eq!(parse_user_mention("@bob"), None);
+        assert_eq!(parse_user_mention("<@U012AB3CD"), None);
+        assert_eq!(parse_user_mention("<#C012AB3CD|general>"), None);
+    }
+}
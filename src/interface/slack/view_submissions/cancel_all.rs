@@ -0,0 +1,107 @@
+//! 予約一括キャンセルモーダル送信ハンドラ
+
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::constants::*;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::{extract_form_data, form_validation, user_resolver};
+use crate::interface::slack::views::modals::cancel_all::option_label;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 予約一括キャンセルモーダル送信を処理
+///
+/// 選択された予約をまとめてキャンセルし、エフェメラルメッセージで結果を通知する。
+/// 予約が選択されていない場合は、モーダルを閉じずにエラーを表示する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    view_submission: &SlackInteractionViewSubmissionEvent,
+) -> Result<Option<SlackViewSubmissionResponse>, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let user_id = view_submission.user.id.clone();
+    let preferences = app
+        .user_preferences(&view_submission.team.id, &user_id)
+        .await;
+    let messages = preferences.messages();
+
+    let usage_ids: Vec<UsageId> = extract_form_data::get_selected_options_in_all_blocks(
+        view_submission,
+        ACTION_CANCEL_ALL_SELECT,
+    )
+    .into_iter()
+    .map(UsageId::from_string)
+    .collect();
+    if usage_ids.is_empty() {
+        return Ok(Some(form_validation::errors_response(
+            form_validation::errors_at(
+                &format!("{}_0", ACTION_CANCEL_ALL_SELECT),
+                messages.cancel_all_nothing_selected.to_string(),
+            ),
+        )));
+    }
+
+    let actor_email = EmailAddress::new(
+        user_resolver::resolve_user_email(&user_id, app.identity_repo())
+            .await
+            .map_err(|_| messages.email_not_registered)?,
+    )?;
+
+    let message_text = match app
+        .bulk_delete_usecase()
+        .execute(&usage_ids, &actor_email)
+        .await
+    {
+        Ok(cancelled) => {
+            info!(
+                "🗑️ {} が予約を{}件まとめてキャンセルしました",
+                actor_email.as_str(),
+                cancelled.len()
+            );
+            let mut lines = vec![fill(
+                messages.cancel_all_done,
+                &[("count", &cancelled.len().to_string())],
+            )];
+            lines.extend(
+                cancelled
+                    .iter()
+                    .map(|usage| format!("• {}", option_label(usage, preferences.timezone))),
+            );
+            lines.join("\n")
+        }
+        Err(ApplicationError::Repository(RepositoryError::NotFound)) => {
+            messages.cancel_all_not_found.to_string()
+        }
+        Err(e) => {
+            error!("❌ 予約の一括キャンセルに失敗: {}", e);
+            fill(messages.cancel_all_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    let channel_id = app
+        .user_channel_map()
+        .read()
+        .unwrap()
+        .get(&user_id)
+        .cloned()
+        .ok_or(messages.session_expired)?;
+
+    let ephemeral_req = SlackApiChatPostEphemeralRequest::new(
+        channel_id,
+        user_id.clone(),
+        SlackMessageContent::new().with_text(message_text),
+    );
+    let bot_token = app.bot_token_for(&view_submission.team.id).await;
+    let session = app.slack_client().open_session(&bot_token);
+    messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
+
+    // モーダルを閉じる
+    Ok(None)
+}
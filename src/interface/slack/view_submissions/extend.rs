@@ -0,0 +1,219 @@
+//! 予約延長モーダル送信ハンドラ
+
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::constants::*;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::datetime_parser::to_user_time;
+use crate::interface::slack::utility::form_validation::{self, FieldErrors};
+use crate::interface::slack::utility::{extract_form_data, user_resolver};
+use crate::interface::slack::views::messages::{booking_window, reservation_limit};
+use chrono::Duration;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 予約延長モーダル送信を処理
+///
+/// 延長する時間帯が後に続く予約と競合する場合は、モーダルを閉じずに入力欄にエラーを表示する。
+/// 延長できた場合は新しい終了時刻をエフェメラルメッセージで通知する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    view_submission: &SlackInteractionViewSubmissionEvent,
+) -> Result<Option<SlackViewSubmissionResponse>, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let user_id = view_submission.user.id.clone();
+
+    let preferences = app
+        .user_preferences(&view_submission.team.id, &user_id)
+        .await;
+    let messages = preferences.messages();
+
+    let usage_id = UsageId::from_string(
+        extract_form_data::get_private_metadata(view_submission).ok_or(messages.invalid_request)?,
+    );
+
+    let extension = match extension_from_input(
+        extract_form_data::get_selected_option_value(view_submission, ACTION_EXTEND_DURATION)
+            .as_deref(),
+        extract_form_data::get_plain_text_input(view_submission, ACTION_EXTEND_CUSTOM_MINUTES)
+            .as_deref(),
+        messages,
+    ) {
+        Ok(extension) => extension,
+        Err(errors) => return Ok(Some(form_validation::errors_response(errors))),
+    };
+
+    let owner_email = EmailAddress::new(
+        user_resolver::resolve_user_email(&user_id, app.identity_repo())
+            .await
+            .map_err(|_| messages.email_not_registered)?,
+    )?;
+
+    info!(
+        "⏩ 予約を延長中: {} (+{}分)",
+        usage_id.as_str(),
+        extension.num_minutes()
+    );
+    let message_text = match app
+        .extend_usage_usecase()
+        .execute(&usage_id, &owner_email, extension)
+        .await
+    {
+        Ok(extended) => fill(
+            messages.extended,
+            &[(
+                "end",
+                &to_user_time(extended.end(), preferences.timezone)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            )],
+        ),
+        // 延長できない理由は入力欄に表示し、別の時間を選び直せるようにする
+        Err(ApplicationError::ResourceConflict {
+            resource_description,
+            ..
+        }) => {
+            return Ok(Some(form_validation::errors_response(
+                form_validation::errors_at(
+                    ACTION_EXTEND_DURATION,
+                    fill(
+                        messages.extend_conflict_field,
+                        &[("resource", &resource_description)],
+                    ),
+                ),
+            )));
+        }
+        Err(ApplicationError::ResourceFreeze(e)) => {
+            return Ok(Some(form_validation::errors_response(
+                form_validation::errors_at(ACTION_EXTEND_DURATION, e.to_string()),
+            )));
+        }
+        Err(ApplicationError::ReservationLimit(e)) => {
+            return Ok(Some(form_validation::errors_response(
+                form_validation::errors_at(
+                    ACTION_EXTEND_DURATION,
+                    reservation_limit::limit_exceeded(messages, &e, preferences.timezone),
+                ),
+            )));
+        }
+        Err(ApplicationError::BookingWindow(e)) => {
+            return Ok(Some(form_validation::errors_response(
+                form_validation::errors_at(
+                    ACTION_EXTEND_DURATION,
+                    booking_window::outside_window(messages, &e),
+                ),
+            )));
+        }
+        Err(ApplicationError::Repository(RepositoryError::NotFound)) => {
+            messages.usage_not_found.to_string()
+        }
+        Err(ApplicationError::Unauthorized(_)) => messages.extend_forbidden.to_string(),
+        Err(e) => {
+            error!("❌ 予約の延長に失敗: {}", e);
+            fill(messages.extend_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    let channel_id = app
+        .user_channel_map()
+        .read()
+        .unwrap()
+        .get(&user_id)
+        .cloned()
+        .ok_or(messages.session_expired_button)?;
+
+    let ephemeral_req = SlackApiChatPostEphemeralRequest::new(
+        channel_id,
+        user_id.clone(),
+        SlackMessageContent::new().with_text(message_text),
+    );
+    let bot_token = app.bot_token_for(&view_submission.team.id).await;
+    let session = app.slack_client().open_session(&bot_token);
+    messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
+
+    // モーダルを閉じる
+    Ok(None)
+}
+
+/// 選択肢と入力値から延長する時間を求める
+///
+/// 「その他」を選んだ場合は、入力された分数（1以上の整数）を使う。
+fn extension_from_input(
+    choice: Option<&str>,
+    custom_minutes: Option<&str>,
+    messages: &Messages,
+) -> Result<Duration, FieldErrors> {
+    let minutes = match choice {
+        Some(EXTEND_CUSTOM_VALUE) => custom_minutes
+            .ok_or_else(|| {
+                form_validation::errors_at(
+                    ACTION_EXTEND_CUSTOM_MINUTES,
+                    messages.extend_minutes_required.to_string(),
+                )
+            })?
+            .parse::<i64>()
+            .ok()
+            .filter(|minutes| *minutes > 0)
+            .ok_or_else(|| {
+                form_validation::errors_at(
+                    ACTION_EXTEND_CUSTOM_MINUTES,
+                    messages.positive_integer_required.to_string(),
+                )
+            })?,
+        Some(preset) => preset.parse::<i64>().map_err(|_| {
+            form_validation::errors_at(
+                ACTION_EXTEND_DURATION,
+                fill(messages.unknown_extend_preset, &[("preset", preset)]),
+            )
+        })?,
+        None => {
+            return Err(form_validation::errors_at(
+                ACTION_EXTEND_DURATION,
+                messages.extend_duration_required.to_string(),
+            ));
+        }
+    };
+    Ok(Duration::minutes(minutes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::i18n::Locale;
+
+    #[test]
+    fn test_extension_from_input() {
+        let messages = Locale::En.messages();
+        assert_eq!(
+            extension_from_input(Some("120"), None, messages).unwrap(),
+            Duration::hours(2)
+        );
+        assert_eq!(
+            extension_from_input(Some(EXTEND_CUSTOM_VALUE), Some("45"), messages).unwrap(),
+            Duration::minutes(45)
+        );
+
+        assert!(
+            extension_from_input(Some(EXTEND_CUSTOM_VALUE), None, messages)
+                .unwrap_err()
+                .contains_key(ACTION_EXTEND_CUSTOM_MINUTES)
+        );
+        assert!(
+            extension_from_input(Some(EXTEND_CUSTOM_VALUE), Some("0"), messages)
+                .unwrap_err()
+                .contains_key(ACTION_EXTEND_CUSTOM_MINUTES)
+        );
+        assert_eq!(
+            extension_from_input(None, None, messages).unwrap_err()[ACTION_EXTEND_DURATION],
+            messages.extend_duration_required
+        );
+    }
+}
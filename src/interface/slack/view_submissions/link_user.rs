@@ -4,8 +4,10 @@ use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
 use crate::domain::common::EmailAddress;
 use crate::domain::ports::notifier::Notifier;
 use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
 use crate::interface::slack::app::SlackApp;
 use crate::interface::slack::constants::{ACTION_LINK_EMAIL_INPUT, ACTION_USER_SELECT};
+use crate::interface::slack::slack_client::messages;
 use crate::interface::slack::utility::extract_form_data;
 use slack_morphism::prelude::*;
 use tracing::{error, info};
@@ -24,15 +26,19 @@ where
     info!("ユーザーリンクを処理中...");
 
     let user_id = view_submission.user.id.clone();
+    let preferences = app
+        .user_preferences(&view_submission.team.id, &user_id)
+        .await;
+    let messages = preferences.messages();
 
     // ユーザーIDを抽出
     let target_user_id = extract_form_data::get_user_select(view_submission, ACTION_USER_SELECT)
-        .ok_or("ユーザーが選択されていません")?;
+        .ok_or(messages.user_required)?;
 
     // メールアドレスを抽出
     let email_value =
         extract_form_data::get_plain_text_input(view_submission, ACTION_LINK_EMAIL_INPUT)
-            .ok_or("メールアドレスが入力されていません")?;
+            .ok_or(messages.email_required)?;
 
     // メールアドレスのバリデーション
     let email_result = EmailAddress::new(email_value.trim().to_string());
@@ -41,7 +47,13 @@ where
     let link_result = match &email_result {
         Ok(email) => app
             .grant_access_usecase()
-            .execute(ExternalSystem::Slack, target_user_id.clone(), email.clone())
+            .execute(
+                ExternalSystem::Slack,
+                target_user_id.clone(),
+                app.identity_workspace_id(&view_submission.team.id),
+                email.clone(),
+                Some(user_id.to_string()),
+            )
             .await
             .map_err(|e| e.into()),
         Err(e) => Err(Box::new(e.clone()) as Box<dyn std::error::Error + Send + Sync>),
@@ -54,7 +66,7 @@ where
         .unwrap()
         .get(&user_id)
         .cloned()
-        .ok_or("セッションの有効期限が切れました。もう一度コマンドを実行してください。")?;
+        .ok_or(messages.session_expired)?;
 
     // エフェメラルメッセージで結果を送信
     let message_text = match link_result {
@@ -64,15 +76,17 @@ where
                 target_user_id,
                 email_result.as_ref().unwrap().as_str()
             );
-            format!(
-                "✅ ユーザー <@{}> をメールアドレス {} に紐付けました",
-                target_user_id,
-                email_result.as_ref().unwrap().as_str()
+            fill(
+                messages.linked,
+                &[
+                    ("user", &format!("<@{}>", target_user_id)),
+                    ("email", email_result.as_ref().unwrap().as_str()),
+                ],
             )
         }
         Err(e) => {
             error!("❌ ユーザーリンクに失敗: {}", e);
-            format!("❌ 紐付けに失敗しました: {}", e)
+            fill(messages.link_failed, &[("error", &e.to_string())])
         }
     };
 
@@ -82,8 +96,9 @@ where
         SlackMessageContent::new().with_text(message_text),
     );
 
-    let session = app.slack_client().open_session(app.bot_token());
-    session.chat_post_ephemeral(&ephemeral_req).await?;
+    let bot_token = app.bot_token_for(&view_submission.team.id).await;
+    let session = app.slack_client().open_session(&bot_token);
+    messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
 
     // モーダルを閉じる
     Ok(None)
@@ -19,14 +19,23 @@
 //! | `register_email` | `registration` | メールアドレス登録 |
 //! | `link_user` | `link_user` | ユーザーリンク（管理者用） |
 //! | `reserve_submit` | `reserve` | リソース予約作成 |
+//! | `extend_reservation_submit` | `extend` | 予約延長 |
+//! | `cancel_all_submit` | `cancel_all` | 予約の一括キャンセル |
+//! | `transfer_reservation_submit` | `transfer` | 予約の引き継ぎ |
 //!
 //! ## モジュール
 //!
 //! - `registration`: メールアドレス登録モーダルの送信処理
 //! - `link_user`: ユーザーリンクモーダルの送信処理
 //! - `reserve`: リソース予約作成モーダルの送信処理
+//! - `extend`: 予約延長モーダルの送信処理
+//! - `cancel_all`: 予約一括キャンセルモーダルの送信処理
+//! - `transfer`: 予約引き継ぎモーダルの送信処理
 
+pub mod cancel_all;
+pub mod extend;
 pub mod link_user;
 pub mod registration;
 pub mod reserve;
+pub mod transfer;
 pub mod update;
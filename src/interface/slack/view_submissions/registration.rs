@@ -4,8 +4,10 @@ use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
 use crate::domain::common::EmailAddress;
 use crate::domain::ports::notifier::Notifier;
 use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
 use crate::interface::slack::app::SlackApp;
 use crate::interface::slack::constants::ACTION_EMAIL_INPUT;
+use crate::interface::slack::slack_client::messages;
 use crate::interface::slack::utility::extract_form_data;
 use slack_morphism::prelude::*;
 use tracing::{error, info};
@@ -24,16 +26,26 @@ where
     info!("メールアドレス登録を処理中...");
 
     let user_id = view_submission.user.id.clone();
+    let preferences = app
+        .user_preferences(&view_submission.team.id, &user_id)
+        .await;
+    let messages = preferences.messages();
 
     let email_value = extract_form_data::get_plain_text_input(view_submission, ACTION_EMAIL_INPUT)
-        .ok_or("メールアドレスが入力されていません")?;
+        .ok_or(messages.email_required)?;
 
     let email_result = EmailAddress::new(email_value.trim().to_string());
 
     let registration_result = match &email_result {
         Ok(email) => app
             .grant_access_usecase()
-            .execute(ExternalSystem::Slack, user_id.to_string(), email.clone())
+            .execute(
+                ExternalSystem::Slack,
+                user_id.to_string(),
+                app.identity_workspace_id(&view_submission.team.id),
+                email.clone(),
+                Some(user_id.to_string()),
+            )
             .await
             .map_err(|e| e.into()),
         Err(e) => Err(Box::new(e.clone()) as Box<dyn std::error::Error + Send + Sync>),
@@ -46,7 +58,7 @@ where
         .unwrap()
         .get(&user_id)
         .cloned()
-        .ok_or("セッションの有効期限が切れました。もう一度コマンドを実行してください。")?;
+        .ok_or(messages.session_expired)?;
 
     // エフェメラルメッセージで結果を送信
     let message_text = match registration_result {
@@ -55,14 +67,14 @@ where
                 "✅ ユーザー登録成功: {}",
                 email_result.as_ref().unwrap().as_str()
             );
-            format!(
-                "✅ メールアドレス {} を登録しました",
-                email_result.as_ref().unwrap().as_str()
+            fill(
+                messages.email_registered,
+                &[("email", email_result.as_ref().unwrap().as_str())],
             )
         }
         Err(e) => {
             error!("❌ ユーザー登録に失敗: {}", e);
-            format!("❌ 登録に失敗しました: {}", e)
+            fill(messages.register_failed, &[("error", &e.to_string())])
         }
     };
 
@@ -72,8 +84,9 @@ where
         SlackMessageContent::new().with_text(message_text),
     );
 
-    let session = app.slack_client().open_session(app.bot_token());
-    session.chat_post_ephemeral(&ephemeral_req).await?;
+    let bot_token = app.bot_token_for(&view_submission.team.id).await;
+    let session = app.slack_client().open_session(&bot_token);
+    messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
 
     // モーダルを閉じる
     Ok(None)
@@ -1,13 +1,28 @@
 //! リソース予約モーダル送信ハンドラ
 
-use crate::domain::aggregates::resource_usage::value_objects::resource::{Gpu, Resource};
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::group::GroupId;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    Gpu, Priority, RecurrenceRule, ReservationMetadata, Resource, TimePeriod, Visibility,
+};
+use crate::domain::common::EmailAddress;
 use crate::domain::ports::notifier::Notifier;
 use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::domain::services::resource_usage::ResourceConflict;
+use crate::infrastructure::config::ResourceConfig;
+use crate::infrastructure::i18n::{Messages, fill};
 use crate::interface::slack::app::SlackApp;
 use crate::interface::slack::constants::*;
-use crate::interface::slack::utility::datetime_parser::parse_datetime;
+use crate::interface::slack::slack_client::messages;
 use crate::interface::slack::utility::extract_form_data;
+use crate::interface::slack::utility::form_validation::{self, FieldErrors};
 use crate::interface::slack::utility::user_resolver;
+use crate::interface::slack::views::messages::{
+    booking_window, confirmation, conflict, reservation_limit, split_proposal, waitlist,
+};
+use crate::interface::slack::views::modals::cancel_all::option_label;
+use crate::interface::slack::views::modals::reserve;
+use chrono_tz::Tz;
 use slack_morphism::prelude::*;
 use tracing::{error, info};
 
@@ -29,119 +44,97 @@ where
     let identity_repo = app.identity_repo();
     let config = app.resource_config();
 
+    // 日時はSlackプロフィールのタイムゾーンで入力されたものとして扱う
+    let preferences = app
+        .user_preferences(&view_submission.team.id, &user_id)
+        .await;
+    let timezone = preferences.timezone;
+    let messages = preferences.messages();
+
     // Extract form values
-    let resource_type =
+    let Some(resource_type) =
         extract_form_data::get_selected_option_value(view_submission, ACTION_RESERVE_RESOURCE_TYPE)
-            .ok_or("リソースタイプが選択されていません")?;
+    else {
+        return Ok(Some(form_validation::errors_response(
+            form_validation::errors_at(
+                ACTION_RESERVE_RESOURCE_TYPE,
+                messages.resource_type_required.to_string(),
+            ),
+        )));
+    };
     info!("  → リソースタイプ: {}", resource_type);
 
-    let start_date =
-        extract_form_data::get_selected_date(view_submission, ACTION_RESERVE_START_DATE)
-            .ok_or("開始日が選択されていません")?;
-    let start_time =
-        extract_form_data::get_selected_time(view_submission, ACTION_RESERVE_START_TIME)
-            .ok_or("開始時刻が選択されていません")?;
-
-    let end_date = extract_form_data::get_selected_date(view_submission, ACTION_RESERVE_END_DATE)
-        .ok_or("終了日が選択されていません")?;
-    let end_time = extract_form_data::get_selected_time(view_submission, ACTION_RESERVE_END_TIME)
-        .ok_or("終了時刻が選択されていません")?;
-
     let notes = extract_form_data::get_plain_text_input(view_submission, ACTION_RESERVE_NOTES);
 
-    // Parse datetime
-    let start_datetime = parse_datetime(&start_date, &start_time)?;
-    let end_datetime = parse_datetime(&end_date, &end_time)?;
-    let time_period = crate::domain::aggregates::resource_usage::value_objects::TimePeriod::new(
-        start_datetime,
-        end_datetime,
-    )?;
-    info!("  → 期間: {} ~ {}", start_datetime, end_datetime);
+    // 日時の入力に問題がある場合は入力欄にエラーを表示する
+    let time_period =
+        match form_validation::time_period_from_form(view_submission, timezone, messages) {
+            Ok(time_period) => time_period,
+            Err(errors) => return Ok(Some(form_validation::errors_response(errors))),
+        };
+    info!("  → 期間: {} ~ {}", time_period.start(), time_period.end());
+
+    let recurrence = match form_validation::recurrence_from_form(
+        view_submission,
+        &time_period,
+        timezone,
+        messages,
+    ) {
+        Ok(recurrence) => recurrence,
+        Err(errors) => return Ok(Some(form_validation::errors_response(errors))),
+    };
 
     // Get owner email from user_id
-    let owner_email = user_resolver::resolve_user_email(&user_id, identity_repo).await?;
+    let owner_email = user_resolver::resolve_user_email(&user_id, identity_repo)
+        .await
+        .map_err(|_| messages.email_not_registered)?;
     info!("  → オーナー: {}", owner_email);
 
     // Extract resources based on type
     let resource_type_val = resource_type.as_str();
-    let resources: Vec<Resource> = if resource_type_val == "gpu" {
-        // Get server name
-        let server_name = extract_form_data::get_selected_option_text(
-            view_submission,
-            ACTION_RESERVE_SERVER_SELECT,
-        )
-        .ok_or("サーバーが選択されていません")?;
-        info!("  → サーバー: {}", server_name);
-
-        // Get server config
-        let server_config = config
-            .servers
-            .iter()
-            .find(|s| s.name == server_name)
-            .ok_or_else(|| format!("サーバー {} が見つかりません", server_name))?;
-
-        // Get selected devices (optional)
-        let device_id_values =
-            extract_form_data::get_selected_options(view_submission, ACTION_RESERVE_DEVICES);
-        info!("  → 選択デバイス数: {}", device_id_values.len());
-
-        if device_id_values.is_empty() {
-            // No specific devices selected - reserve entire server (all devices)
-            server_config
-                .devices
-                .iter()
-                .map(|device| {
-                    Resource::Gpu(Gpu::new(
-                        server_name.clone(),
-                        device.id,
-                        device.model.clone(),
-                    ))
-                })
-                .collect()
-        } else {
-            // Parse device IDs from values
-            let mut gpu_resources = Vec::new();
-            for id_str in device_id_values {
-                let device_id = id_str
-                    .parse::<u32>()
-                    .map_err(|e| format!("デバイスIDのパースに失敗: {} ({})", id_str, e))?;
-                let device = server_config
-                    .devices
-                    .iter()
-                    .find(|d| d.id == device_id)
-                    .ok_or_else(|| format!("デバイス {} が見つかりません", device_id))?;
-                gpu_resources.push(Resource::Gpu(Gpu::new(
-                    server_name.clone(),
-                    device.id,
-                    device.model.clone(),
-                )));
-            }
-            gpu_resources
-        }
-    } else if resource_type_val == "room" {
-        let room_name = extract_form_data::get_selected_option_text(
-            view_submission,
-            ACTION_RESERVE_ROOM_SELECT,
-        )
-        .ok_or("部屋が選択されていません")?;
-        info!("  → 部屋: {}", room_name);
-        vec![Resource::Room { name: room_name }]
-    } else {
-        return Err(format!("不明なリソースタイプ: {}", resource_type_val).into());
+    let resources = match resources_from_form(view_submission, resource_type_val, config, messages)
+    {
+        Ok(resources) => resources,
+        Err(errors) => return Ok(Some(form_validation::errors_response(errors))),
     };
 
     info!("  → リソース: {:?}", resources);
 
-    // Create reservation
-    info!("📝 予約を作成中...");
-    let reservation_result = create_usage_usecase
-        .execute(
-            crate::domain::common::EmailAddress::new(owner_email)?,
-            time_period,
-            resources,
-            notes,
-        )
-        .await;
+    let metadata = match form_validation::metadata_from_form(view_submission, messages) {
+        Ok(metadata) => metadata,
+        Err(errors) => return Ok(Some(form_validation::errors_response(errors))),
+    };
+    let visibility = extract_form_data::get_visibility(view_submission);
+    let priority = extract_form_data::get_priority(view_submission);
+    let preempt = extract_form_data::get_preempt(view_submission);
+    let group = extract_form_data::get_group(view_submission);
+
+    let owner_email = EmailAddress::new(owner_email)?;
+
+    // メンバーでないチームを選んだ場合は入力欄にエラーを表示する
+    if let Some(group) = group
+        .as_ref()
+        .and_then(|group_id| create_usage_usecase.group(group_id))
+        && !group.is_member(&owner_email)
+    {
+        return Ok(Some(form_validation::errors_response(
+            form_validation::errors_at(
+                ACTION_RESERVE_GROUP,
+                fill(messages.group_not_member, &[("group", group.name())]),
+            ),
+        )));
+    }
+
+    // 受付期間外の場合は日時の入力欄にエラーを表示する（繰り返し予約の2回目以降は作成時に確認する）
+    if let Err(e) = create_usage_usecase.check_booking_window(&time_period, &resources) {
+        info!("⚠️ 予約の受付期間外です: {}", e);
+        return Ok(Some(form_validation::errors_response(
+            form_validation::errors_at(
+                ACTION_RESERVE_START_DATE,
+                booking_window::outside_window(messages, &e),
+            ),
+        )));
+    }
 
     // channel_id を取得
     let channel_id = app
@@ -150,20 +143,177 @@ where
         .unwrap()
         .get(&user_id)
         .cloned()
-        .ok_or("セッションの有効期限が切れました。もう一度コマンドを実行してください。")?;
+        .ok_or(messages.session_expired)?;
+
+    if let Some(recurrence) = recurrence {
+        return handle_series(
+            app,
+            &user_id,
+            &view_submission.team.id,
+            messages,
+            timezone,
+            channel_id,
+            owner_email,
+            time_period,
+            &recurrence,
+            resources,
+            notes,
+            metadata,
+            visibility,
+            priority,
+            group,
+        )
+        .await;
+    }
+
+    // 既存の予約と競合する場合は予約せずに知らせる
+    let conflicts = create_usage_usecase
+        .find_conflicts(&time_period, &resources)
+        .await?;
+    // 横取りを選んだ場合は、競合する予約をすべて取り消せるときだけ予約に進む
+    let preempting = preempt
+        && !conflicts.is_empty()
+        && match create_usage_usecase.preemptable(&owner_email, priority, &conflicts) {
+            Ok(_) => true,
+            Err(e) => {
+                info!("⚠️ 競合する予約を横取りできません: {}", e);
+                false
+            }
+        };
+    if !conflicts.is_empty() && !preempting {
+        info!("⚠️ 既存の予約と競合しています: {}件", conflicts.len());
+        let errors = conflict_errors(app, messages, &user_id, &owner_email, &conflicts).await;
+        let mut reasons: Vec<&str> = errors.values().map(String::as_str).collect();
+        reasons.sort_unstable();
+        let reasons = reasons.join("\n");
+        let bot_token = app.bot_token_for(&view_submission.team.id).await;
+        let session = app.slack_client().open_session(&bot_token);
+
+        // 部分的な競合の場合は分割案を提示
+        match create_usage_usecase
+            .propose_split(&time_period, &resources)
+            .await
+        {
+            Ok(Some(proposal)) if !proposal.is_empty() => {
+                info!("✂️ 分割案を提示します: {}区画", proposal.allocations.len());
+                let mut content = split_proposal::create(
+                    messages, &reasons, &proposal, notes, &metadata, visibility,
+                );
+                // 全体が空くのを待つこともできるように、空き待ちのボタンを添える
+                if app.join_waitlist_usecase().is_some()
+                    && let Some(button) = waitlist::join_button(messages, &time_period, &resources)
+                {
+                    content
+                        .blocks
+                        .get_or_insert_with(Vec::new)
+                        .push(SlackBlock::Actions(SlackActionsBlock::new(vec![button])));
+                }
+                let ephemeral_req =
+                    SlackApiChatPostEphemeralRequest::new(channel_id, user_id.clone(), content);
+                messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
+                return Ok(None);
+            }
+            Ok(_) => {}
+            Err(split_err) => error!("❌ 分割案の計算に失敗: {}", split_err),
+        }
+
+        // 入力欄のエラーとは別に、空き待ちへの登録を提案する
+        if app.join_waitlist_usecase().is_some()
+            && let Some(content) = waitlist::offer(messages, &reasons, &time_period, &resources)
+        {
+            let ephemeral_req =
+                SlackApiChatPostEphemeralRequest::new(channel_id, user_id.clone(), content);
+            if let Err(e) = messages::post_ephemeral_or_dm(&session, &ephemeral_req).await {
+                error!("❌ 空き待ちの案内の送信に失敗: {}", e);
+            }
+        }
+
+        return Ok(Some(form_validation::errors_response(errors)));
+    }
+
+    // Create reservation
+    info!("📝 予約を作成中...");
+    let requires_approval = create_usage_usecase.requires_approval(&resources);
+    // 利用実績の集計に失敗しても予約は行う
+    let fair_share = create_usage_usecase
+        .fair_share_advice(&owner_email, &time_period, &resources)
+        .await
+        .unwrap_or_else(|e| {
+            error!("❌ 利用実績の集計に失敗: {}", e);
+            None
+        });
+    let reservation_result = if preempting {
+        create_usage_usecase
+            .execute_preempting(
+                owner_email,
+                time_period.clone(),
+                resources,
+                notes,
+                metadata,
+                visibility,
+                priority,
+                group,
+            )
+            .await
+            .map(|reservation| (reservation.usage_id, reservation.preempted))
+    } else {
+        create_usage_usecase
+            .execute(
+                owner_email,
+                time_period.clone(),
+                resources,
+                notes,
+                metadata,
+                visibility,
+                priority,
+                group,
+            )
+            .await
+            .map(|usage_id| (usage_id, Vec::new()))
+    };
 
     // エフェメラルメッセージで結果を送信
     let message_text = match reservation_result {
-        Ok(ref usage_id) => {
+        Ok((ref usage_id, ref preempted)) => {
             info!("✅ 予約を作成しました: {}", usage_id.as_str());
-            format!(
-                "✅ リソースの予約が完了しました\n予約ID: {}",
-                usage_id.as_str()
+            let mut message = fill(messages.reserved, &[("usage_id", usage_id.as_str())]);
+            if !preempted.is_empty() {
+                info!("⚡ 予約を{}件横取りしました", preempted.len());
+                message.push_str(&format!("\n\n{}", messages.preempted_others));
+                for usage in preempted {
+                    message.push_str(&format!("\n• {}", option_label(usage, timezone)));
+                }
+            }
+            if requires_approval {
+                message.push_str(&format!("\n\n{}", messages.approval_pending));
+            }
+            // 週末・休業日にかかる場合は注意書きを添える（予約自体は行う）
+            if let Some(advisory) = config.holiday_advisory_policy().and_then(|policy| {
+                confirmation::closure_advisory(messages, &policy.closed_days(&time_period))
+            }) {
+                message.push_str(&format!("\n\n{}", advisory));
+            }
+            if let Some(advice) = &fair_share {
+                message.push_str(&format!(
+                    "\n\n{}",
+                    confirmation::fair_share_advisory(messages, advice)
+                ));
+            }
+            message
+        }
+        Err(ApplicationError::ReservationLimit(ref e)) => {
+            info!("⚠️ 同時予約の上限を超えています: {}", e);
+            fill(
+                messages.reserve_failed,
+                &[(
+                    "error",
+                    &reservation_limit::limit_exceeded(messages, e, timezone),
+                )],
             )
         }
         Err(ref e) => {
             error!("❌ 予約作成に失敗: {}", e);
-            format!("❌ 予約の作成に失敗しました\n\n{}", e)
+            fill(messages.reserve_failed, &[("error", &e.to_string())])
         }
     };
 
@@ -173,9 +323,344 @@ where
         SlackMessageContent::new().with_text(message_text),
     );
 
-    let session = app.slack_client().open_session(app.bot_token());
-    session.chat_post_ephemeral(&ephemeral_req).await?;
+    let bot_token = app.bot_token_for(&view_submission.team.id).await;
+    let session = app.slack_client().open_session(&bot_token);
+    messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
 
     // モーダルを閉じる
     Ok(None)
 }
+
+/// 繰り返し予約を作成
+///
+/// いずれかの回が既存の予約と競合する場合は、どの回も予約せずに入力欄にエラーを表示する。
+#[allow(clippy::too_many_arguments)]
+async fn handle_series<R, N>(
+    app: &SlackApp<R, N>,
+    user_id: &SlackUserId,
+    team_id: &SlackTeamId,
+    messages: &Messages,
+    timezone: Option<Tz>,
+    channel_id: SlackChannelId,
+    owner_email: EmailAddress,
+    first_period: TimePeriod,
+    recurrence: &RecurrenceRule,
+    resources: Vec<Resource>,
+    notes: Option<String>,
+    metadata: ReservationMetadata,
+    visibility: Visibility,
+    priority: Priority,
+    group: Option<GroupId>,
+) -> Result<Option<SlackViewSubmissionResponse>, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let create_usage_usecase = app.create_resource_usage_usecase();
+    let periods = recurrence.occurrences(&first_period)?;
+    info!(
+        "🔁 繰り返し予約: {} {}回",
+        recurrence.frequency(),
+        periods.len()
+    );
+
+    // すべての回の競合をまとめて知らせる
+    let conflicts = create_usage_usecase
+        .find_series_conflicts(&first_period, recurrence, &resources)
+        .await?;
+    if !conflicts.is_empty() {
+        info!("⚠️ 既存の予約と競合しています: {}件", conflicts.len());
+        let errors = conflict_errors(app, messages, user_id, &owner_email, &conflicts).await;
+        return Ok(Some(form_validation::errors_response(errors)));
+    }
+
+    info!("📝 繰り返し予約を作成中...");
+    let requires_approval = create_usage_usecase.requires_approval(&resources);
+    // 最初の回について助言する（利用実績の集計に失敗しても予約は行う）
+    let fair_share = create_usage_usecase
+        .fair_share_advice(&owner_email, &first_period, &resources)
+        .await
+        .unwrap_or_else(|e| {
+            error!("❌ 利用実績の集計に失敗: {}", e);
+            None
+        });
+    let message_text = match create_usage_usecase
+        .execute_series(
+            owner_email,
+            first_period,
+            recurrence,
+            resources,
+            notes,
+            metadata,
+            visibility,
+            priority,
+            group,
+        )
+        .await
+    {
+        Ok(usage_ids) => {
+            info!("✅ 繰り返し予約を作成しました: {}件", usage_ids.len());
+            let mut message = fill(
+                messages.series_reserved,
+                &[
+                    ("frequency", messages.frequency(recurrence.frequency())),
+                    ("count", &usage_ids.len().to_string()),
+                    (
+                        "usage_id",
+                        usage_ids.first().map(|id| id.as_str()).unwrap_or_default(),
+                    ),
+                ],
+            );
+            if requires_approval {
+                message.push_str(&format!("\n\n{}", messages.approval_pending));
+            }
+            // 週末・休業日にかかる回がある場合は注意書きを添える（予約自体は行う）
+            let advisory = app
+                .resource_config()
+                .holiday_advisory_policy()
+                .and_then(|policy| {
+                    let closed_days: Vec<_> = periods
+                        .iter()
+                        .flat_map(|period| policy.closed_days(period))
+                        .collect();
+                    confirmation::closure_advisory(messages, &closed_days)
+                });
+            if let Some(advisory) = advisory {
+                message.push_str(&format!("\n\n{}", advisory));
+            }
+            if let Some(advice) = &fair_share {
+                message.push_str(&format!(
+                    "\n\n{}",
+                    confirmation::fair_share_advisory(messages, advice)
+                ));
+            }
+            message
+        }
+        Err(ApplicationError::ReservationLimit(e)) => {
+            info!("⚠️ 同時予約の上限を超えています: {}", e);
+            fill(
+                messages.series_failed,
+                &[(
+                    "error",
+                    &reservation_limit::limit_exceeded(messages, &e, timezone),
+                )],
+            )
+        }
+        Err(ApplicationError::BookingWindow(e)) => {
+            info!("⚠️ 予約の受付期間外です: {}", e);
+            fill(
+                messages.series_failed,
+                &[("error", &booking_window::outside_window(messages, &e))],
+            )
+        }
+        Err(e) => {
+            error!("❌ 繰り返し予約の作成に失敗: {}", e);
+            fill(messages.series_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    let ephemeral_req = SlackApiChatPostEphemeralRequest::new(
+        channel_id,
+        user_id.clone(),
+        SlackMessageContent::new().with_text(message_text),
+    );
+
+    let bot_token = app.bot_token_for(team_id).await;
+    let session = app.slack_client().open_session(&bot_token);
+    messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
+
+    Ok(None)
+}
+
+/// 競合を入力欄ごとのエラーにする
+async fn conflict_errors<R, N>(
+    app: &SlackApp<R, N>,
+    messages: &Messages,
+    user_id: &SlackUserId,
+    owner_email: &EmailAddress,
+    conflicts: &[ResourceConflict],
+) -> FieldErrors
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let is_admin =
+        user_resolver::is_admin(user_id, app.identity_repo(), app.resource_config()).await;
+    conflict::field_errors(
+        messages,
+        conflicts,
+        |resource| match resource {
+            Resource::Gpu(gpu) => reserve::devices_block_id(gpu.server()),
+            Resource::Instrument { .. } => ACTION_RESERVE_INSTRUMENT_SELECT.to_string(),
+            Resource::Custom { .. } => ACTION_RESERVE_CUSTOM_SELECT.to_string(),
+            Resource::Room { .. } | Resource::Storage { .. } | Resource::License { .. } => {
+                ACTION_RESERVE_ROOM_SELECT.to_string()
+            }
+        },
+        owner_email,
+        is_admin,
+    )
+}
+
+/// 予約フォームから予約するリソースを取得
+///
+/// # エラー
+/// サーバー・部屋が未選択、または設定に存在しない場合は入力欄ごとのエラーを返す
+fn resources_from_form(
+    view_submission: &SlackInteractionViewSubmissionEvent,
+    resource_type: &str,
+    config: &ResourceConfig,
+    messages: &Messages,
+) -> Result<Vec<Resource>, FieldErrors> {
+    match resource_type {
+        "gpu" => {
+            let server_name = extract_form_data::get_selected_option_text(
+                view_submission,
+                ACTION_RESERVE_SERVER_SELECT,
+            )
+            .ok_or_else(|| {
+                form_validation::errors_at(
+                    ACTION_RESERVE_SERVER_SELECT,
+                    messages.server_required.to_string(),
+                )
+            })?;
+            info!("  → サーバー: {}", server_name);
+
+            let server_config = config
+                .servers
+                .iter()
+                .find(|s| s.name == server_name)
+                .ok_or_else(|| {
+                    form_validation::errors_at(
+                        ACTION_RESERVE_SERVER_SELECT,
+                        fill(messages.server_not_found, &[("server", &server_name)]),
+                    )
+                })?;
+
+            // Get selected devices on every open server (optional)
+            let mut resources = Vec::new();
+            for server in &config.servers {
+                let block_id = reserve::devices_block_id(&server.name);
+                for id_str in extract_form_data::get_selected_options_in_block(
+                    view_submission,
+                    &block_id,
+                    ACTION_RESERVE_DEVICES,
+                ) {
+                    let device = id_str
+                        .parse::<u32>()
+                        .ok()
+                        .and_then(|device_id| server.devices.iter().find(|d| d.id == device_id))
+                        .ok_or_else(|| {
+                            form_validation::errors_at(
+                                &block_id,
+                                fill(
+                                    messages.device_not_found,
+                                    &[("device", &id_str), ("server", &server.name)],
+                                ),
+                            )
+                        })?;
+                    resources.push(Resource::Gpu(Gpu::new(
+                        server.name.clone(),
+                        device.id,
+                        device.model.clone(),
+                    )));
+                }
+            }
+            info!("  → 選択デバイス数: {}", resources.len());
+
+            if resources.is_empty() {
+                // No specific devices selected - reserve entire server (all devices, or the tagged ones)
+                let tag = extract_form_data::get_selected_option_value(
+                    view_submission,
+                    ACTION_RESERVE_TAG,
+                )
+                .filter(|tag| tag != RESERVE_TAG_ANY_VALUE);
+                let resources: Vec<Resource> = server_config
+                    .devices
+                    .iter()
+                    .filter(|device| {
+                        tag.as_deref()
+                            .is_none_or(|tag| server_config.device_has_tag(device, tag))
+                    })
+                    .map(|device| {
+                        Resource::Gpu(Gpu::new(
+                            server_name.clone(),
+                            device.id,
+                            device.model.clone(),
+                        ))
+                    })
+                    .collect();
+                if resources.is_empty() {
+                    return Err(form_validation::errors_at(
+                        ACTION_RESERVE_SERVER_SELECT,
+                        fill(
+                            messages.no_tagged_devices,
+                            &[
+                                ("server", &server_name),
+                                ("tag", tag.as_deref().unwrap_or_default()),
+                            ],
+                        ),
+                    ));
+                }
+                return Ok(resources);
+            }
+
+            Ok(resources)
+        }
+        "room" => {
+            let room_name = extract_form_data::get_selected_option_text(
+                view_submission,
+                ACTION_RESERVE_ROOM_SELECT,
+            )
+            .ok_or_else(|| {
+                form_validation::errors_at(
+                    ACTION_RESERVE_ROOM_SELECT,
+                    messages.room_required.to_string(),
+                )
+            })?;
+            info!("  → 部屋: {}", room_name);
+            Ok(vec![Resource::Room { name: room_name }])
+        }
+        "instrument" => {
+            let instrument_name = extract_form_data::get_selected_option_text(
+                view_submission,
+                ACTION_RESERVE_INSTRUMENT_SELECT,
+            )
+            .ok_or_else(|| {
+                form_validation::errors_at(
+                    ACTION_RESERVE_INSTRUMENT_SELECT,
+                    messages.instrument_required.to_string(),
+                )
+            })?;
+            info!("  → 機器: {}", instrument_name);
+            Ok(vec![Resource::Instrument {
+                name: instrument_name,
+            }])
+        }
+        other => {
+            let resource_type = reserve::custom_kind(other)
+                .and_then(|kind| config.get_resource_type(kind))
+                .ok_or_else(|| {
+                    form_validation::errors_at(
+                        ACTION_RESERVE_RESOURCE_TYPE,
+                        fill(messages.unknown_resource_type, &[("type", other)]),
+                    )
+                })?;
+            let name = extract_form_data::get_selected_option_value(
+                view_submission,
+                ACTION_RESERVE_CUSTOM_SELECT,
+            )
+            .ok_or_else(|| {
+                form_validation::errors_at(
+                    ACTION_RESERVE_CUSTOM_SELECT,
+                    fill(messages.custom_required, &[("type", &resource_type.label)]),
+                )
+            })?;
+            info!("  → {}: {}", resource_type.label, name);
+            Ok(vec![Resource::Custom {
+                kind: resource_type.id.clone(),
+                name,
+            }])
+        }
+    }
+}
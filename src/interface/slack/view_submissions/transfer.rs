@@ -0,0 +1,128 @@
+//! 予約引き継ぎモーダル送信ハンドラ
+
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::resource_usage::errors::ResourceUsageError;
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
+use crate::domain::common::EmailAddress;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::ports::repositories::{RepositoryError, ResourceUsageRepository};
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::app::SlackApp;
+use crate::interface::slack::constants::*;
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::form_validation;
+use crate::interface::slack::utility::{extract_form_data, user_resolver};
+use crate::interface::slack::views::messages::reservation_limit;
+use slack_morphism::prelude::*;
+use tracing::{error, info};
+
+/// 予約引き継ぎモーダル送信を処理
+///
+/// 引き継ぎ先がメールアドレスを登録していない場合や、引き継ぎ先の同時予約の上限を超える場合は、
+/// モーダルを閉じずに入力欄にエラーを表示する。
+/// 引き継げた場合は結果をエフェメラルメッセージで通知する。
+pub async fn handle<R, N>(
+    app: &SlackApp<R, N>,
+    view_submission: &SlackInteractionViewSubmissionEvent,
+) -> Result<Option<SlackViewSubmissionResponse>, Box<dyn std::error::Error + Send + Sync>>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+    N: Notifier + Send + Sync + 'static,
+{
+    let user_id = view_submission.user.id.clone();
+
+    let preferences = app
+        .user_preferences(&view_submission.team.id, &user_id)
+        .await;
+    let messages = preferences.messages();
+
+    let usage_id = UsageId::from_string(
+        extract_form_data::get_private_metadata(view_submission).ok_or(messages.invalid_request)?,
+    );
+
+    let new_owner_id =
+        extract_form_data::get_user_select(view_submission, ACTION_TRANSFER_NEW_OWNER)
+            .ok_or(messages.transfer_owner_required)?;
+    let new_owner = match user_resolver::resolve_user_email(
+        &SlackUserId::new(new_owner_id),
+        app.identity_repo(),
+    )
+    .await
+    .ok()
+    .and_then(|email| EmailAddress::new(email).ok())
+    {
+        Some(email) => email,
+        None => {
+            return Ok(Some(form_validation::errors_response(
+                form_validation::errors_at(
+                    ACTION_TRANSFER_NEW_OWNER,
+                    messages.transfer_not_linked.to_string(),
+                ),
+            )));
+        }
+    };
+
+    let actor_email = EmailAddress::new(
+        user_resolver::resolve_user_email(&user_id, app.identity_repo())
+            .await
+            .map_err(|_| messages.email_not_registered)?,
+    )?;
+
+    info!(
+        "👥 予約を引き継ぎ中: {} → {}",
+        usage_id.as_str(),
+        new_owner.as_str()
+    );
+    let message_text = match app
+        .transfer_ownership_usecase()
+        .execute(&usage_id, &actor_email, new_owner.clone())
+        .await
+    {
+        Ok(()) => fill(messages.transferred, &[("owner", new_owner.as_str())]),
+        // 引き継ぎ先の問題は入力欄に表示し、別のメンバーを選び直せるようにする
+        Err(ApplicationError::ResourceUsage(ResourceUsageError::SameOwner)) => {
+            return Ok(Some(form_validation::errors_response(
+                form_validation::errors_at(
+                    ACTION_TRANSFER_NEW_OWNER,
+                    messages.transfer_same_owner.to_string(),
+                ),
+            )));
+        }
+        Err(ApplicationError::ReservationLimit(e)) => {
+            return Ok(Some(form_validation::errors_response(
+                form_validation::errors_at(
+                    ACTION_TRANSFER_NEW_OWNER,
+                    reservation_limit::limit_exceeded(messages, &e, preferences.timezone),
+                ),
+            )));
+        }
+        Err(ApplicationError::Repository(RepositoryError::NotFound)) => {
+            messages.usage_not_found.to_string()
+        }
+        Err(ApplicationError::Unauthorized(_)) => messages.transfer_forbidden.to_string(),
+        Err(e) => {
+            error!("❌ 予約の引き継ぎに失敗: {}", e);
+            fill(messages.transfer_failed, &[("error", &e.to_string())])
+        }
+    };
+
+    let channel_id = app
+        .user_channel_map()
+        .read()
+        .unwrap()
+        .get(&user_id)
+        .cloned()
+        .ok_or(messages.session_expired_button)?;
+
+    let ephemeral_req = SlackApiChatPostEphemeralRequest::new(
+        channel_id,
+        user_id.clone(),
+        SlackMessageContent::new().with_text(message_text),
+    );
+    let bot_token = app.bot_token_for(&view_submission.team.id).await;
+    let session = app.slack_client().open_session(&bot_token);
+    messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
+
+    // モーダルを閉じる
+    Ok(None)
+}
@@ -1,12 +1,16 @@
 //! リソース予約更新モーダル送信ハンドラ
 
+use crate::application::error::ApplicationError;
 use crate::domain::aggregates::identity_link::value_objects::ExternalSystem;
-use crate::domain::aggregates::resource_usage::value_objects::{TimePeriod, UsageId};
+use crate::domain::aggregates::resource_usage::value_objects::UsageId;
 use crate::domain::ports::notifier::Notifier;
 use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::i18n::fill;
 use crate::interface::slack::app::SlackApp;
 use crate::interface::slack::constants::*;
-use crate::interface::slack::utility::{datetime_parser::parse_datetime, extract_form_data};
+use crate::interface::slack::slack_client::messages;
+use crate::interface::slack::utility::{extract_form_data, form_validation};
+use crate::interface::slack::views::messages::{booking_window, confirmation, reservation_limit};
 use slack_morphism::prelude::*;
 
 /// リソース予約更新モーダル送信を処理
@@ -22,46 +26,59 @@ where
 {
     let user_id = view_submission.user.id.clone();
 
+    let preferences = app
+        .user_preferences(&view_submission.team.id, &user_id)
+        .await;
+    let messages = preferences.messages();
+
     // private_metadataからusage_idを取得
-    let usage_id_str = extract_form_data::get_private_metadata(view_submission)
-        .ok_or("usage_idがprivate_metadataに設定されていません")?;
+    let usage_id_str =
+        extract_form_data::get_private_metadata(view_submission).ok_or(messages.invalid_request)?;
 
     let usage_id = UsageId::from_string(usage_id_str.clone());
 
-    // 開始・終了日時を取得
-    let start_date =
-        extract_form_data::get_selected_date(view_submission, ACTION_RESERVE_START_DATE)
-            .ok_or("開始日が選択されていません")?;
-    let start_time =
-        extract_form_data::get_selected_time(view_submission, ACTION_RESERVE_START_TIME)
-            .ok_or("開始時刻が選択されていません")?;
-
-    let end_date = extract_form_data::get_selected_date(view_submission, ACTION_RESERVE_END_DATE)
-        .ok_or("終了日が選択されていません")?;
-    let end_time = extract_form_data::get_selected_time(view_submission, ACTION_RESERVE_END_TIME)
-        .ok_or("終了時刻が選択されていません")?;
-
-    // Parse datetime
-    let start_datetime = parse_datetime(&start_date, &start_time)?;
-    let end_datetime = parse_datetime(&end_date, &end_time)?;
-    let time_period = TimePeriod::new(start_datetime, end_datetime)?;
+    // 開始・終了日時を取得（Slackプロフィールのタイムゾーンで扱い、入力に問題がある場合は入力欄にエラーを表示する）
+    let time_period = match form_validation::time_period_from_form(
+        view_submission,
+        preferences.timezone,
+        messages,
+    ) {
+        Ok(time_period) => time_period,
+        Err(errors) => return Ok(Some(form_validation::errors_response(errors))),
+    };
 
     // 備考を取得（オプション）
     let notes = extract_form_data::get_plain_text_input(view_submission, ACTION_RESERVE_NOTES);
 
+    // プロジェクト名・実験ID・想定使用率を取得（想定使用率が不正な場合は入力欄にエラーを表示する）
+    let metadata = match form_validation::metadata_from_form(view_submission, messages) {
+        Ok(metadata) => metadata,
+        Err(errors) => return Ok(Some(form_validation::errors_response(errors))),
+    };
+
+    // 公開範囲を取得
+    let visibility = extract_form_data::get_visibility(view_submission);
+
     // ユーザーのメールアドレスを取得
     let identity_link = app
         .identity_repo()
         .find_by_external_user_id(&ExternalSystem::Slack, user_id.as_ref())
         .await?
-        .ok_or("ユーザーが登録されていません。まず /register-calendar を実行してください")?;
+        .ok_or(messages.email_not_registered)?;
 
     let owner_email = identity_link.email().clone();
 
     // 予約を更新
     let update_result = app
         .update_resource_usage_usecase()
-        .execute(&usage_id, &owner_email, Some(time_period), notes)
+        .execute(
+            &usage_id,
+            &owner_email,
+            Some(time_period.clone()),
+            notes,
+            Some(metadata),
+            Some(visibility),
+        )
         .await;
 
     // channel_id を取得
@@ -71,23 +88,44 @@ where
         .unwrap()
         .get(&user_id)
         .cloned()
-        .ok_or("セッションの有効期限が切れました。もう一度コマンドを実行してください。")?;
+        .ok_or(messages.session_expired)?;
 
     // エフェメラルメッセージで結果を送信
     let message_text = match update_result {
-        Ok(_) => "✅ 予約を更新しました".to_string(),
+        Ok(_) => {
+            // 週末・休業日にかかる場合は注意書きを添える（更新自体は行う）
+            match app
+                .resource_config()
+                .holiday_advisory_policy()
+                .and_then(|policy| {
+                    confirmation::closure_advisory(messages, &policy.closed_days(&time_period))
+                }) {
+                Some(advisory) => format!("{}\n\n{}", messages.updated, advisory),
+                None => messages.updated.to_string(),
+            }
+        }
+        Err(ApplicationError::ReservationLimit(e)) => fill(
+            messages.update_failed,
+            &[(
+                "error",
+                &reservation_limit::limit_exceeded(messages, &e, preferences.timezone),
+            )],
+        ),
+        Err(ApplicationError::BookingWindow(e)) => fill(
+            messages.update_failed,
+            &[("error", &booking_window::outside_window(messages, &e))],
+        ),
         Err(e) => {
             // エラーの種類に応じてユーザーフレンドリーなメッセージを返す
             let error_msg = e.to_string();
             if error_msg.contains("見つかりません") || error_msg.contains("NotFound") {
-                "❌ 申し訳ございません。この予約は既に削除されているか、見つかりませんでした。"
-                    .to_string()
+                messages.usage_not_found.to_string()
             } else if error_msg.contains("権限") || error_msg.contains("Unauthorized") {
-                "❌ この予約を更新する権限がありません。".to_string()
+                messages.update_forbidden.to_string()
             } else if error_msg.contains("重複") || error_msg.contains("Conflict") {
-                "❌ 指定された時間帯は既に予約されています。".to_string()
+                messages.time_slot_taken.to_string()
             } else {
-                format!("❌ 予約の更新に失敗しました: {}", error_msg)
+                fill(messages.update_failed, &[("error", &error_msg)])
             }
         }
     };
@@ -98,8 +136,9 @@ where
         SlackMessageContent::new().with_text(message_text),
     );
 
-    let session = app.slack_client().open_session(app.bot_token());
-    session.chat_post_ephemeral(&ephemeral_req).await?;
+    let bot_token = app.bot_token_for(&view_submission.team.id).await;
+    let session = app.slack_client().open_session(&bot_token);
+    messages::post_ephemeral_or_dm(&session, &ephemeral_req).await?;
 
     // モーダルを閉じる
     Ok(None)
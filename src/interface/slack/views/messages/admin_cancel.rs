@@ -0,0 +1,75 @@
+//! 管理者・モデレーターによる予約キャンセルメッセージ
+//!
+//! `/admin-cancel` の結果として、キャンセルした予約を一覧表示する。
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::infrastructure::i18n::{Messages, fill};
+use chrono::{DateTime, Local, Utc};
+use slack_morphism::prelude::*;
+
+/// 予約のキャンセル結果メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `target` - キャンセルの対象（予約IDやユーザーのメンション）
+/// * `cancelled` - キャンセルした予約
+pub fn create_cancelled(
+    messages: &Messages,
+    target: &str,
+    cancelled: &[ResourceUsage],
+) -> SlackMessageContent {
+    if cancelled.is_empty() {
+        return SlackMessageContent::new()
+            .with_text(fill(messages.admin_cancel_none, &[("target", target)]));
+    }
+
+    let mut lines = vec![fill(
+        messages.admin_cancel_done,
+        &[("target", target), ("count", &cancelled.len().to_string())],
+    )];
+    for usage in cancelled {
+        lines.push(format!(
+            "• {} - {} {} ({})",
+            format_timestamp(usage.time_period().start()),
+            format_timestamp(usage.time_period().end()),
+            usage
+                .resources()
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            usage.owner_email().as_str()
+        ));
+    }
+    lines.push(format!("\n{}", messages.admin_cancel_notice));
+
+    SlackMessageContent::new().with_text(lines.join("\n"))
+}
+
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::i18n::Locale;
+
+    #[test]
+    fn test_create_cancelled_uses_the_locale() {
+        let ja = create_cancelled(Locale::Ja.messages(), "<@U01234567>", &[]);
+        let en = create_cancelled(Locale::En.messages(), "<@U01234567>", &[]);
+
+        assert_eq!(
+            ja.text.as_deref(),
+            Some("<@U01234567> にキャンセルできる予約はありません")
+        );
+        assert_eq!(
+            en.text.as_deref(),
+            Some("<@U01234567> has no reservations that can be cancelled")
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! 空き状況メッセージ
+//!
+//! `/availability` の結果として、GPU・部屋ごとの1日の空き状況を1時間単位のグリッドで表示する。
+
+use crate::application::usecases::{AvailabilityReport, ResourceAvailability};
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::infrastructure::config::ResourceConfig;
+use crate::infrastructure::i18n::{Messages, fill};
+use chrono::{Duration, NaiveDate};
+use slack_morphism::prelude::*;
+
+/// 予約ありの時間帯
+const BUSY: char = '█';
+/// 空いている時間帯
+const FREE: char = '░';
+
+/// 空き状況メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `config` - リソース種別の表示名の取得に使うリソース設定
+/// * `date` - 表示する日付
+/// * `report` - 表示する日（0:00から翌日0:00まで）の空き状況
+pub fn create(
+    messages: &Messages,
+    config: &ResourceConfig,
+    date: NaiveDate,
+    report: &AvailabilityReport,
+) -> SlackMessageContent {
+    let day = report.period();
+    let text = fill(
+        messages.availability_header,
+        &[
+            ("date", &date.format("%Y-%m-%d").to_string()),
+            ("free", &report.free_count().to_string()),
+            ("total", &report.resources().len().to_string()),
+        ],
+    );
+    let legend = fill(
+        messages.availability_legend,
+        &[("busy", &BUSY.to_string()), ("free", &FREE.to_string())],
+    );
+
+    let mut blocks = vec![SlackBlock::Section(
+        SlackSectionBlock::new().with_text(md!(format!("*{}*\n{}", text, legend))),
+    )];
+
+    // サーバー・部屋ごとにまとめて表示
+    let mut groups: Vec<(String, Vec<&ResourceAvailability>)> = Vec::new();
+    for availability in report.resources() {
+        let group = group_name(messages, config, availability.resource());
+        match groups.iter_mut().find(|(name, _)| *name == group) {
+            Some((_, members)) => members.push(availability),
+            None => groups.push((group, vec![availability])),
+        }
+    }
+
+    for (group, members) in groups {
+        let mut lines = vec![hour_header(day)];
+        lines.extend(
+            members
+                .iter()
+                .map(|availability| format!("{} {}", grid(availability, day), label(availability))),
+        );
+        blocks.push(SlackBlock::Section(
+            SlackSectionBlock::new().with_text(md!(format!(
+                "*{}*\n```{}```",
+                group,
+                lines.join("\n")
+            ))),
+        ));
+    }
+
+    SlackMessageContent::new()
+        .with_text(text)
+        .with_blocks(blocks)
+}
+
+/// 1時間ごとの予約状況を1文字ずつ並べたグリッド
+fn grid(availability: &ResourceAvailability, day: &TimePeriod) -> String {
+    hourly_slots(day)
+        .map(|slot| {
+            if availability.is_busy_during(&slot) {
+                BUSY
+            } else {
+                FREE
+            }
+        })
+        .collect()
+}
+
+/// グリッドの上に表示する時刻の目盛り（6時間ごと）
+fn hour_header(day: &TimePeriod) -> String {
+    let hours = hourly_slots(day).count();
+    (0..hours)
+        .step_by(6)
+        .map(|hour| format!("{:<6}", hour))
+        .collect::<String>()
+        .chars()
+        .take(hours)
+        .collect()
+}
+
+fn hourly_slots(day: &TimePeriod) -> impl Iterator<Item = TimePeriod> + '_ {
+    let hours = (day.end() - day.start()).num_hours();
+    (0..hours).filter_map(move |hour| {
+        let start = day.start() + Duration::hours(hour);
+        TimePeriod::new(start, (start + Duration::hours(1)).min(day.end())).ok()
+    })
+}
+
+fn label(availability: &ResourceAvailability) -> String {
+    match availability.resource() {
+        Resource::Gpu(gpu) => format!("GPU#{} ({})", gpu.device_number(), gpu.model()),
+        Resource::Room { name } | Resource::Instrument { name } | Resource::Custom { name, .. } => {
+            name.clone()
+        }
+        Resource::Storage { volume, gigabytes } => format!("{} {}GB", volume, gigabytes),
+        Resource::License { name, seats } => format!("{} x{}", name, seats),
+    }
+}
+
+/// リソースをまとめて表示するグループ名（GPUはサーバー名、それ以外は種別の表示名）
+fn group_name(messages: &Messages, config: &ResourceConfig, resource: &Resource) -> String {
+    match resource {
+        Resource::Gpu(gpu) => gpu.server().to_string(),
+        Resource::Room { .. } => messages.kind_room.to_string(),
+        Resource::Instrument { .. } => messages.kind_instrument.to_string(),
+        Resource::Custom { kind, .. } => config.resource_type_label(kind).to_string(),
+        Resource::Storage { .. } => messages.kind_storage.to_string(),
+        Resource::License { .. } => messages.kind_license.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::i18n::Locale;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_hour_header_marks_every_six_hours() {
+        let start = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let day = TimePeriod::new(start, start + Duration::hours(24)).unwrap();
+
+        assert_eq!(hour_header(&day), "0     6     12    18    ");
+        assert_eq!(hourly_slots(&day).count(), 24);
+    }
+
+    #[test]
+    fn test_custom_resources_are_grouped_by_configured_label() {
+        let config: ResourceConfig = toml::from_str(
+            r#"
+rooms = []
+servers = []
+
+[[resource_types]]
+id = "camera"
+label = "カメラ"
+
+[[resource_types.resources]]
+name = "EOS R5"
+calendar_id = "camera@example.com"
+notifications = []
+"#,
+        )
+        .unwrap();
+        let messages = Locale::Ja.messages();
+        let custom = |kind: &str| Resource::Custom {
+            kind: kind.to_string(),
+            name: "EOS R5".to_string(),
+        };
+
+        assert_eq!(group_name(messages, &config, &custom("camera")), "カメラ");
+        assert_eq!(group_name(messages, &config, &custom("drone")), "drone");
+    }
+}
@@ -0,0 +1,30 @@
+//! 予約の受付期間外のメッセージ
+//!
+//! 予約の作成・更新がリソースの受付期間（何分前まで・何日先まで）の外の場合に、その理由を表示する。
+
+use crate::domain::services::resource_usage::{BookingWindowError, BookingWindowViolation};
+use crate::infrastructure::i18n::{Messages, fill};
+
+/// 受付期間外の理由を作成
+///
+/// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
+/// * `error` - 受付期間外のエラー
+pub fn outside_window(messages: &Messages, error: &BookingWindowError) -> String {
+    match error.violation {
+        BookingWindowViolation::NoticeTooShort(min_notice) => fill(
+            messages.booking_notice_required,
+            &[
+                ("resource", &error.resource),
+                ("minutes", &min_notice.num_minutes().to_string()),
+            ],
+        ),
+        BookingWindowViolation::TooFarAhead(max_advance) => fill(
+            messages.booking_too_far_ahead,
+            &[
+                ("resource", &error.resource),
+                ("days", &max_advance.num_days().to_string()),
+            ],
+        ),
+    }
+}
@@ -1,5 +1,8 @@
 //! 確認メッセージブロック
 
+use crate::domain::services::resource_usage::{ClosedDay, ClosureReason, FairShareAdvice};
+use crate::infrastructure::i18n::{Messages, fill};
+use chrono::Datelike;
 use slack_morphism::prelude::*;
 
 /// シンプルな確認メッセージを作成
@@ -35,9 +38,11 @@ pub fn create_with_details(
 /// 確認メッセージを表示するモーダルを作成
 ///
 /// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
 /// * `title` - モーダルのタイトル
 /// * `message` - 確認メッセージ
 pub fn create_confirmation_modal(
+    messages: &Messages,
     title: impl Into<String>,
     message: impl Into<String>,
 ) -> SlackView {
@@ -45,5 +50,64 @@ pub fn create_confirmation_modal(
         SlackSectionBlock::new().with_text(md!(format!("✅ {}", message.into()))),
     )];
 
-    SlackView::Modal(SlackModalView::new(pt!(title.into()), blocks).with_close(pt!("閉じる")))
+    SlackView::Modal(
+        SlackModalView::new(pt!(title.into()), blocks).with_close(pt!(messages.close.to_string())),
+    )
+}
+
+/// 休業日にかかる予約への注意書きを作成
+///
+/// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
+/// * `closed_days` - 予約期間に含まれる休業日
+///
+/// # 戻り値
+/// 休業日がない場合は `None`
+pub fn closure_advisory(messages: &Messages, closed_days: &[ClosedDay]) -> Option<String> {
+    if closed_days.is_empty() {
+        return None;
+    }
+
+    let lines: Vec<String> = closed_days
+        .iter()
+        .map(|day| {
+            let reason = match &day.reason {
+                ClosureReason::Weekend(_) => messages.closed_weekday,
+                ClosureReason::Holiday(name) => name.as_str(),
+            };
+            format!(
+                "• {} ({}) {}",
+                day.date.format("%m/%d"),
+                messages.weekday(day.date.weekday()),
+                reason
+            )
+        })
+        .collect();
+
+    Some(format!("{}\n{}", messages.closure_header, lines.join("\n")))
+}
+
+/// 直近の使用量が公平な配分を超えている予約への注意書きを作成
+///
+/// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
+/// * `advice` - 公平な利用のための助言
+pub fn fair_share_advisory(messages: &Messages, advice: &FairShareAdvice) -> String {
+    let mut text = fill(
+        messages.fair_share_advisory,
+        &[
+            ("days", &advice.window.num_days().to_string()),
+            ("model", &advice.model),
+            ("hours", &advice.used.num_hours().to_string()),
+            ("ratio", &format!("{:.1}", advice.score)),
+        ],
+    );
+    if let Some(server) = &advice.alternative {
+        text.push('\n');
+        text.push_str(&fill(
+            messages.fair_share_alternative,
+            &[("server", server)],
+        ));
+    }
+    text
 }
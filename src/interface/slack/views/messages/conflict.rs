@@ -0,0 +1,136 @@
+//! 予約の競合メッセージ
+//!
+//! 予約フォームの送信時に、希望したリソースと競合している既存の予約を
+//! 入力欄ごとのエラーとして示す。
+
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::domain::common::EmailAddress;
+use crate::domain::services::resource_usage::ResourceConflict;
+use crate::infrastructure::i18n::{Messages, fill};
+use chrono::Local;
+use std::collections::HashMap;
+
+/// 競合を入力欄ごとのエラーメッセージにまとめる
+///
+/// 同じ入力欄に複数の競合がある場合は改行でつなげる。
+/// 非公開の予約は、閲覧者が予約者本人か管理者でない限り予約者を伏せる。
+///
+/// # Arguments
+/// * `messages` - 表示言語のメッセージカタログ
+/// * `conflicts` - 競合したリソースと既存の予約の組
+/// * `block_id_of` - リソースに対応する入力欄のブロックID
+/// * `viewer` - 予約しようとしたユーザーのメールアドレス
+/// * `is_admin` - 予約しようとしたユーザーが管理者かどうか
+pub fn field_errors(
+    messages: &Messages,
+    conflicts: &[ResourceConflict],
+    block_id_of: impl Fn(&Resource) -> String,
+    viewer: &EmailAddress,
+    is_admin: bool,
+) -> HashMap<String, String> {
+    let mut errors: HashMap<String, String> = HashMap::new();
+    for conflict in conflicts {
+        let line = describe(messages, conflict, viewer, is_admin);
+        errors
+            .entry(block_id_of(&conflict.resource))
+            .and_modify(|text| {
+                text.push('\n');
+                text.push_str(&line);
+            })
+            .or_insert(line);
+    }
+    errors
+}
+
+fn describe(
+    messages: &Messages,
+    conflict: &ResourceConflict,
+    viewer: &EmailAddress,
+    is_admin: bool,
+) -> String {
+    let existing = &conflict.existing_usage;
+    let resource = conflict.resource.to_string();
+    let period = format_period(existing.time_period());
+    if existing.details_visible_to(Some(viewer), is_admin) {
+        fill(
+            messages.conflict_with_owner,
+            &[
+                ("resource", &resource),
+                ("owner", existing.owner_email().as_str()),
+                ("time", &period),
+            ],
+        )
+    } else {
+        fill(
+            messages.conflict_private,
+            &[("resource", &resource), ("time", &period)],
+        )
+    }
+}
+
+fn format_period(period: &TimePeriod) -> String {
+    let start = period.start().with_timezone(&Local);
+    let end = period.end().with_timezone(&Local);
+    if start.date_naive() == end.date_naive() {
+        format!("{} - {}", start.format("%m/%d %H:%M"), end.format("%H:%M"))
+    } else {
+        format!(
+            "{} - {}",
+            start.format("%m/%d %H:%M"),
+            end.format("%m/%d %H:%M")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, Visibility};
+    use crate::infrastructure::i18n::Locale;
+    use chrono::{Duration, Utc};
+
+    fn email(value: &str) -> EmailAddress {
+        EmailAddress::new(value.to_string()).unwrap()
+    }
+
+    fn conflict(device: u32, visibility: Visibility) -> ResourceConflict {
+        let resource = Resource::Gpu(Gpu::new("Thalys".to_string(), device, "A100".to_string()));
+        let start = Utc::now() + Duration::hours(1);
+        let existing_usage = ResourceUsage::new(
+            email("owner@example.com"),
+            TimePeriod::new(start, start + Duration::hours(2)).unwrap(),
+            vec![resource.clone()],
+            None,
+        )
+        .unwrap()
+        .with_visibility(visibility);
+        ResourceConflict {
+            resource,
+            existing_usage,
+        }
+    }
+
+    #[test]
+    fn test_field_errors_group_by_block_and_hide_private_owner() {
+        let conflicts = vec![
+            conflict(0, Visibility::Public),
+            conflict(1, Visibility::Private),
+        ];
+
+        let errors = field_errors(
+            Locale::Ja.messages(),
+            &conflicts,
+            |_| "devices".to_string(),
+            &email("viewer@example.com"),
+            false,
+        );
+
+        assert_eq!(errors.len(), 1);
+        let lines: Vec<&str> = errors["devices"].lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("owner@example.com"));
+        assert!(!lines[1].contains("owner@example.com"));
+        assert!(lines[1].contains("非公開の予約"));
+    }
+}
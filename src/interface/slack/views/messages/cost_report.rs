@@ -0,0 +1,88 @@
+//! 費用の集計メッセージ
+//!
+//! `/cost-report` の結果として、期間内のGPUの予約の費用をユーザー・プロジェクトごとに順位付けして表示する。
+
+use crate::application::usecases::CostReport;
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::views::messages::usage_stats::{
+    format_hours, period_header, ranked_rows, table_block,
+};
+use slack_morphism::prelude::*;
+
+/// 費用の集計メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `label` - 集計期間の呼び名（例: "過去7日間"）
+/// * `report` - 集計結果
+pub fn create(messages: &Messages, label: &str, report: &CostReport) -> SlackMessageContent {
+    let text = period_header(messages.cost_report_header, label, report.period());
+
+    if report.users().is_empty() {
+        return SlackMessageContent::new()
+            .with_text(format!("{}\n{}", text, messages.cost_report_none));
+    }
+
+    let currency = report.currency();
+    let users = ranked_rows(report.users().iter().map(|user| {
+        format!(
+            "{}  {}  {}",
+            format_cost(currency, user.cost()),
+            format_hours(user.gpu_time()),
+            user.owner_email().as_str()
+        )
+    }));
+    let projects = ranked_rows(report.projects().iter().map(|project| {
+        format!(
+            "{}  {}  {}",
+            format_cost(currency, project.cost()),
+            format_hours(project.gpu_time()),
+            project.project().unwrap_or(messages.no_project)
+        )
+    }));
+
+    let blocks = vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(format!(
+            "*{}*\n{}",
+            text,
+            fill(
+                messages.cost_report_total,
+                &[(
+                    "total",
+                    format_cost(currency, report.total_cost()).trim_start()
+                )],
+            )
+        )))),
+        table_block(messages.by_user, &users),
+        table_block(messages.by_project, &projects),
+    ];
+
+    SlackMessageContent::new()
+        .with_text(text)
+        .with_blocks(blocks)
+}
+
+/// 費用を「¥12,345」の形式で表す（1未満は四捨五入、右寄せ）
+fn format_cost(currency: &str, cost: f64) -> String {
+    let digits = (cost.round() as i64).to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    format!("{:>10}", format!("{}{}", currency, grouped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_cost() {
+        assert_eq!(format_cost("¥", 1234567.4).trim_start(), "¥1,234,567");
+        assert_eq!(format_cost("¥", 999.5).trim_start(), "¥1,000");
+        assert_eq!(format_cost("$", 0.0).trim_start(), "$0");
+    }
+}
@@ -0,0 +1,108 @@
+//! デバイスの状態メッセージ
+//!
+//! `/device-status` の結果として、設定したデバイスの状態と影響を受ける既存の予約、
+//! または状態が登録されているデバイスの一覧を表示する。
+
+use crate::domain::aggregates::device_health::{DeviceHealth, DeviceStatus};
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::infrastructure::i18n::{Messages, fill};
+use chrono::{DateTime, Local, Utc};
+use slack_morphism::prelude::*;
+
+/// デバイスの状態の設定結果メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `health` - 設定したデバイスの状態
+/// * `affected` - 使用停止にしたデバイスを含む今後の予約
+pub fn create_updated(
+    messages: &Messages,
+    health: &DeviceHealth,
+    affected: &[ResourceUsage],
+) -> SlackMessageContent {
+    let mut lines = vec![format!(
+        "{} {}",
+        icon(health.status()),
+        describe(messages, health)
+    )];
+    if health.status() == DeviceStatus::OutOfService {
+        if affected.is_empty() {
+            lines.push(messages.affected_none.to_string());
+        } else {
+            lines.push(format!(
+                "\n{}",
+                fill(
+                    messages.affected_header,
+                    &[("count", &affected.len().to_string())]
+                )
+            ));
+            for usage in affected {
+                lines.push(format!(
+                    "• {} - {} {} ({})",
+                    format_timestamp(usage.time_period().start()),
+                    format_timestamp(usage.time_period().end()),
+                    usage
+                        .resources()
+                        .iter()
+                        .map(|r| r.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    usage.owner_email().as_str()
+                ));
+            }
+            lines.push(format!("\n{}", messages.affected_notice));
+        }
+    }
+
+    SlackMessageContent::new().with_text(lines.join("\n"))
+}
+
+/// 状態が登録されているデバイスの一覧メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `healths` - 状態が登録されているデバイス
+pub fn create_list(messages: &Messages, healths: &[DeviceHealth]) -> SlackMessageContent {
+    let text =
+        if healths.is_empty() {
+            messages.device_status_none.to_string()
+        } else {
+            let mut lines = vec![messages.device_status_header.to_string()];
+            lines.extend(healths.iter().map(|health| {
+                format!("• {} {}", icon(health.status()), describe(messages, health))
+            }));
+            lines.join("\n")
+        };
+
+    SlackMessageContent::new().with_text(text)
+}
+
+fn describe(messages: &Messages, health: &DeviceHealth) -> String {
+    let mut text = fill(
+        messages.device_status_entry,
+        &[
+            ("server", health.server_name()),
+            ("device", &health.device_id().to_string()),
+            ("status", messages.device_status(health.status())),
+        ],
+    );
+    if let Some(note) = health.note() {
+        text.push_str(&format!("（{}）", note));
+    }
+    text
+}
+
+fn icon(status: DeviceStatus) -> &'static str {
+    match status {
+        DeviceStatus::Available => "✅",
+        DeviceStatus::Degraded => "⚠️",
+        DeviceStatus::OutOfService => "🚫",
+    }
+}
+
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
@@ -1,6 +1,8 @@
 //! エラーメッセージブロック
 
+use crate::infrastructure::i18n::Messages;
 use slack_morphism::prelude::*;
+use std::collections::HashMap;
 
 /// シンプルなエラーメッセージを作成
 ///
@@ -32,15 +34,46 @@ pub fn create_with_details(
         ])
 }
 
+/// 入力欄ごとのエラーをエフェメラルメッセージにする
+///
+/// Socket Modeではview_submissionの応答でエラーを返せないため、
+/// エラーを表示したモーダルを開き直せなかった場合に代わりに送信する。
+pub fn create_field_errors_message(
+    messages: &Messages,
+    errors: &HashMap<String, String>,
+) -> SlackMessageContent {
+    let mut lines: Vec<&str> = errors.values().map(String::as_str).collect();
+    lines.sort_unstable();
+    let text = format!(
+        "{}\n\n{}",
+        messages.check_input,
+        lines
+            .iter()
+            .flat_map(|text| text.lines())
+            .map(|line| format!("• {}", line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+
+    SlackMessageContent::new().with_text(text)
+}
+
 /// エラーメッセージを表示するモーダルを作成
 ///
 /// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
 /// * `title` - モーダルのタイトル
 /// * `message` - エラーメッセージ
-pub fn create_error_modal(title: impl Into<String>, message: impl Into<String>) -> SlackView {
+pub fn create_error_modal(
+    messages: &Messages,
+    title: impl Into<String>,
+    message: impl Into<String>,
+) -> SlackView {
     let blocks = vec![SlackBlock::Section(
         SlackSectionBlock::new().with_text(md!(format!("❌ {}", message.into()))),
     )];
 
-    SlackView::Modal(SlackModalView::new(pt!(title.into()), blocks).with_close(pt!("閉じる")))
+    SlackView::Modal(
+        SlackModalView::new(pt!(title.into()), blocks).with_close(pt!(messages.close.to_string())),
+    )
 }
@@ -0,0 +1,60 @@
+//! 紐付け履歴メッセージ
+//!
+//! `/link-history` の結果として、誰が・いつ・どのメールアドレスを紐付け（解除）したかを表示する。
+
+use crate::domain::aggregates::identity_link::value_objects::{
+    ExternalSystem, IdentityLinkAuditEntry,
+};
+use crate::infrastructure::i18n::{Messages, fill};
+use chrono::Local;
+use slack_morphism::prelude::*;
+
+/// 紐付け履歴メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `subject` - 履歴の対象（Slackメンションまたはメールアドレス）
+/// * `entries` - 監査記録（古い順）
+pub fn create(
+    messages: &Messages,
+    subject: &str,
+    entries: &[IdentityLinkAuditEntry],
+) -> SlackMessageContent {
+    let text = if entries.is_empty() {
+        fill(messages.link_history_none, &[("subject", subject)])
+    } else {
+        let mut lines = vec![fill(messages.link_history_header, &[("subject", subject)])];
+        lines.extend(entries.iter().map(|entry| describe(messages, entry)));
+        lines.join("\n")
+    };
+
+    SlackMessageContent::new().with_text(text)
+}
+
+fn describe(messages: &Messages, entry: &IdentityLinkAuditEntry) -> String {
+    let user = match entry.system() {
+        ExternalSystem::Slack => format!("<@{}>", entry.external_user_id()),
+        system => format!("{}: {}", system.as_str(), entry.external_user_id()),
+    };
+    let actor = match entry.actor_id() {
+        Some(actor_id) => format!("<@{}>", actor_id),
+        None => messages.link_history_automatic.to_string(),
+    };
+    fill(
+        messages.link_history_entry,
+        &[
+            (
+                "time",
+                &entry
+                    .occurred_at()
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            ),
+            ("action", messages.link_action(entry.action())),
+            ("email", entry.email().as_str()),
+            ("user", &user),
+            ("actor", &actor),
+        ],
+    )
+}
@@ -9,8 +9,38 @@
 //!
 //! ## モジュール
 //!
+//! - `admin_cancel`: 管理者・モデレーターによる予約キャンセルの結果
+//! - `availability`: GPU・部屋の空き状況
+//! - `booking_window`: 予約の受付期間外の理由
 //! - `confirmation`: 成功メッセージ（予約作成/更新/削除の完了通知）
+//! - `conflict`: 予約の競合（入力欄ごとのエラー）
+//! - `cost_report`: ユーザー・プロジェクトごとのGPUの予約の費用
+//! - `device_status`: デバイスの状態の設定結果と一覧
 //! - `error`: エラーメッセージ（操作失敗時の通知）
+//! - `link_history`: メールアドレスとの紐付けの履歴
+//! - `profile_email`: Slackプロフィールのメールアドレスでの連携の提案
+//! - `reservation_limit`: 同時予約の上限を超えた理由と現在の予約
+//! - `resource_freeze`: 予約停止の登録結果と一覧
+//! - `split_proposal`: 分割予約の提案（予約が部分的に競合した場合）
+//! - `unlink_confirmation`: 自分の連携解除の確認
+//! - `usage_stats`: ユーザー・サーバー・デバイスごとの予約時間の集計
+//! - `waitlist`: 空き待ちの案内（予約が競合した場合）
+//! - `whois_using`: サーバー・部屋を現在使用中のユーザー
 
+pub mod admin_cancel;
+pub mod availability;
+pub mod booking_window;
 pub mod confirmation;
+pub mod conflict;
+pub mod cost_report;
+pub mod device_status;
 pub mod error;
+pub mod link_history;
+pub mod profile_email;
+pub mod reservation_limit;
+pub mod resource_freeze;
+pub mod split_proposal;
+pub mod unlink_confirmation;
+pub mod usage_stats;
+pub mod waitlist;
+pub mod whois_using;
@@ -0,0 +1,38 @@
+//! プロフィールのメールアドレス連携メッセージブロック
+//!
+//! 未連携のユーザーに、Slackプロフィールのメールアドレスでの連携を提案する。
+
+use crate::domain::common::EmailAddress;
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::constants::{ACTION_ENTER_EMAIL_MANUALLY, ACTION_LINK_PROFILE_EMAIL};
+use slack_morphism::prelude::*;
+
+/// プロフィールのメールアドレスでの連携を提案するメッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `email` - Slackプロフィールに登録されたメールアドレス
+pub fn create(messages: &Messages, email: &EmailAddress) -> SlackMessageContent {
+    let text = fill(messages.profile_email_offer, &[("email", email.as_str())]);
+
+    let blocks = vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(text.clone()))),
+        SlackBlock::Actions(SlackActionsBlock::new(vec![
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(
+                    SlackActionId::new(ACTION_LINK_PROFILE_EMAIL.to_string()),
+                    pt!(messages.link_profile_email),
+                )
+                .with_style("primary".to_string()),
+            ),
+            SlackActionBlockElement::Button(SlackBlockButtonElement::new(
+                SlackActionId::new(ACTION_ENTER_EMAIL_MANUALLY.to_string()),
+                pt!(messages.enter_email_manually),
+            )),
+        ])),
+    ];
+
+    SlackMessageContent::new()
+        .with_text(text)
+        .with_blocks(blocks)
+}
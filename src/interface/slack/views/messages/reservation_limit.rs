@@ -0,0 +1,50 @@
+//! 同時予約の上限メッセージ
+//!
+//! 予約の作成・更新が利用者ごとの同時予約の上限を超えた場合に、
+//! 超えた上限と、上限の計算に数えた利用者の予約を表示する。
+
+use crate::domain::services::resource_usage::{LimitKind, ReservationLimitError};
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::views::modals::cancel_all::option_label;
+use chrono_tz::Tz;
+
+/// 上限を超えた理由と現在の予約の一覧を作成
+///
+/// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
+/// * `error` - 上限を超えたエラー
+/// * `timezone` - 予約の期間の表示に使うタイムゾーン
+pub fn limit_exceeded(
+    messages: &Messages,
+    error: &ReservationLimitError,
+    timezone: Option<Tz>,
+) -> String {
+    let limit = error.limit.to_string();
+    let current = error.current.to_string();
+    let mut text = match (&error.kind, &error.server) {
+        (LimitKind::Reservations, None) => fill(
+            messages.limit_reservations,
+            &[("limit", &limit), ("current", &current)],
+        ),
+        (LimitKind::Reservations, Some(server)) => fill(
+            messages.limit_reservations_on_server,
+            &[("server", server), ("limit", &limit), ("current", &current)],
+        ),
+        (LimitKind::Devices, None) => fill(
+            messages.limit_devices,
+            &[("limit", &limit), ("current", &current)],
+        ),
+        (LimitKind::Devices, Some(server)) => fill(
+            messages.limit_devices_on_server,
+            &[("server", server), ("limit", &limit), ("current", &current)],
+        ),
+    };
+
+    if !error.holdings.is_empty() {
+        text.push_str(&format!("\n\n{}", messages.limit_holdings));
+        for usage in &error.holdings {
+            text.push_str(&format!("\n• {}", option_label(usage, timezone)));
+        }
+    }
+    text
+}
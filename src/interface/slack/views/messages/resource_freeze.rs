@@ -0,0 +1,94 @@
+//! 予約停止メッセージ
+//!
+//! `/freeze-resource` の結果として、登録した予約停止と影響を受ける既存の予約、
+//! または予約停止の一覧を表示する。
+
+use crate::domain::aggregates::resource_freeze::ResourceFreeze;
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::infrastructure::i18n::{Messages, fill};
+use chrono::{DateTime, Local, Utc};
+use slack_morphism::prelude::*;
+
+/// 予約停止の登録結果メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `freeze` - 登録した予約停止
+/// * `affected` - 予約停止の開始以降にかかる既存の予約
+pub fn create_frozen(
+    messages: &Messages,
+    freeze: &ResourceFreeze,
+    affected: &[ResourceUsage],
+) -> SlackMessageContent {
+    let mut lines = vec![format!("🧊 {}", describe(messages, freeze))];
+    if affected.is_empty() {
+        lines.push(messages.affected_none.to_string());
+    } else {
+        lines.push(format!(
+            "\n{}",
+            fill(
+                messages.affected_header,
+                &[("count", &affected.len().to_string())]
+            )
+        ));
+        for usage in affected {
+            lines.push(format!(
+                "• {} - {} {} ({})",
+                format_timestamp(usage.time_period().start()),
+                format_timestamp(usage.time_period().end()),
+                usage
+                    .resources()
+                    .iter()
+                    .map(|r| r.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                usage.owner_email().as_str()
+            ));
+        }
+        lines.push(format!("\n{}", messages.affected_notice));
+    }
+
+    SlackMessageContent::new().with_text(lines.join("\n"))
+}
+
+/// 予約停止の一覧メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `freezes` - 登録されている予約停止
+pub fn create_list(messages: &Messages, freezes: &[ResourceFreeze]) -> SlackMessageContent {
+    let text = if freezes.is_empty() {
+        messages.freeze_none.to_string()
+    } else {
+        let mut lines = vec![messages.freeze_header.to_string()];
+        lines.extend(
+            freezes
+                .iter()
+                .map(|freeze| format!("• {}", describe(messages, freeze))),
+        );
+        lines.join("\n")
+    };
+
+    SlackMessageContent::new().with_text(text)
+}
+
+fn describe(messages: &Messages, freeze: &ResourceFreeze) -> String {
+    let mut text = fill(
+        messages.frozen_entry,
+        &[
+            ("resource", freeze.resource_name()),
+            ("time", &format_timestamp(freeze.starts_at())),
+        ],
+    );
+    if let Some(reason) = freeze.reason() {
+        text.push_str(&fill(messages.freeze_reason, &[("reason", reason)]));
+    }
+    text
+}
+
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
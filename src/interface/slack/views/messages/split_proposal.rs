@@ -0,0 +1,311 @@
+//! 分割予約の提案メッセージブロック
+//!
+//! 予約が部分的に競合した場合に、空いている部分だけを予約する分割案を提示する。
+
+use crate::domain::aggregates::resource_usage::value_objects::{
+    Gpu, ReservationMetadata, Resource, TimePeriod, Visibility,
+};
+use crate::domain::services::resource_usage::{ResourceAllocation, SplitProposal};
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::constants::ACTION_CONFIRM_SPLIT_RESERVATION;
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use slack_morphism::prelude::*;
+
+/// Slackボタンのvalueに格納できる最大文字数
+const MAX_BUTTON_VALUE_LEN: usize = 2000;
+
+/// 分割予約ボタンに埋め込むペイロード
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SplitReservationPayload {
+    /// 予約する区画
+    pub parts: Vec<SplitPartPayload>,
+    /// 備考
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// プロジェクト名
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// 実験ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub experiment_id: Option<String>,
+    /// 想定使用率（%）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_utilization: Option<u8>,
+    /// 非公開の予約かどうか
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub private: bool,
+}
+
+/// 分割予約の1区画
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SplitPartPayload {
+    /// 開始時刻
+    pub start: DateTime<Utc>,
+    /// 終了時刻
+    pub end: DateTime<Utc>,
+    /// 予約するリソース
+    pub resources: Vec<ResourcePayload>,
+}
+
+/// リソースのシリアライズ表現
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ResourcePayload {
+    /// GPU
+    Gpu {
+        server: String,
+        device: u32,
+        model: String,
+    },
+    /// 部屋
+    Room { name: String },
+    /// 機器
+    Instrument { name: String },
+    /// 設定で定義した種別のリソース
+    Custom { kind: String, name: String },
+    /// ストレージ
+    Storage { volume: String, gigabytes: u64 },
+    /// ライセンス
+    License { name: String, seats: u32 },
+}
+
+impl From<&Resource> for ResourcePayload {
+    fn from(resource: &Resource) -> Self {
+        match resource {
+            Resource::Gpu(gpu) => ResourcePayload::Gpu {
+                server: gpu.server().to_string(),
+                device: gpu.device_number(),
+                model: gpu.model().to_string(),
+            },
+            Resource::Room { name } => ResourcePayload::Room { name: name.clone() },
+            Resource::Instrument { name } => ResourcePayload::Instrument { name: name.clone() },
+            Resource::Custom { kind, name } => ResourcePayload::Custom {
+                kind: kind.clone(),
+                name: name.clone(),
+            },
+            Resource::Storage { volume, gigabytes } => ResourcePayload::Storage {
+                volume: volume.clone(),
+                gigabytes: *gigabytes,
+            },
+            Resource::License { name, seats } => ResourcePayload::License {
+                name: name.clone(),
+                seats: *seats,
+            },
+        }
+    }
+}
+
+impl From<ResourcePayload> for Resource {
+    fn from(payload: ResourcePayload) -> Self {
+        match payload {
+            ResourcePayload::Gpu {
+                server,
+                device,
+                model,
+            } => Resource::Gpu(Gpu::new(server, device, model)),
+            ResourcePayload::Room { name } => Resource::Room { name },
+            ResourcePayload::Instrument { name } => Resource::Instrument { name },
+            ResourcePayload::Custom { kind, name } => Resource::Custom { kind, name },
+            ResourcePayload::Storage { volume, gigabytes } => {
+                Resource::Storage { volume, gigabytes }
+            }
+            ResourcePayload::License { name, seats } => Resource::License { name, seats },
+        }
+    }
+}
+
+impl SplitReservationPayload {
+    /// 分割案からペイロードを作成
+    pub fn new(
+        proposal: &SplitProposal,
+        notes: Option<String>,
+        metadata: &ReservationMetadata,
+        visibility: Visibility,
+    ) -> Self {
+        Self {
+            parts: proposal
+                .allocations
+                .iter()
+                .map(|allocation| SplitPartPayload {
+                    start: allocation.time_period.start(),
+                    end: allocation.time_period.end(),
+                    resources: allocation.resources.iter().map(Into::into).collect(),
+                })
+                .collect(),
+            notes,
+            project: metadata.project().map(str::to_string),
+            experiment_id: metadata.experiment_id().map(str::to_string),
+            expected_utilization: metadata.expected_utilization(),
+            private: visibility.is_private(),
+        }
+    }
+
+    /// 予約のメタデータ（不正な値の場合は未指定）
+    pub fn metadata(&self) -> ReservationMetadata {
+        ReservationMetadata::new(
+            self.project.clone(),
+            self.experiment_id.clone(),
+            self.expected_utilization,
+        )
+        .unwrap_or_default()
+    }
+
+    /// 予約の公開範囲
+    pub fn visibility(&self) -> Visibility {
+        if self.private {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        }
+    }
+
+    /// ペイロードから予約する区画を復元
+    pub fn into_allocations(self) -> Result<Vec<ResourceAllocation>, String> {
+        self.parts
+            .into_iter()
+            .map(|part| {
+                Ok(ResourceAllocation {
+                    time_period: TimePeriod::new(part.start, part.end)
+                        .map_err(|e| e.to_string())?,
+                    resources: part.resources.into_iter().map(Into::into).collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 分割予約の提案メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `conflict_reason` - 元の予約が失敗した理由
+/// * `proposal` - 分割案
+/// * `notes` - 元の予約の備考
+/// * `metadata` - 元の予約のメタデータ
+/// * `visibility` - 元の予約の公開範囲
+pub fn create(
+    messages: &Messages,
+    conflict_reason: &str,
+    proposal: &SplitProposal,
+    notes: Option<String>,
+    metadata: &ReservationMetadata,
+    visibility: Visibility,
+) -> SlackMessageContent {
+    let mut lines = vec![fill(
+        messages.split_partial_conflict,
+        &[("reasons", conflict_reason)],
+    )];
+    lines.push(messages.split_available.to_string());
+    for allocation in &proposal.allocations {
+        lines.push(format!(
+            "• {} — {}",
+            format_period(&allocation.time_period),
+            format_resources(&allocation.resources)
+        ));
+    }
+    if !proposal.unavailable.is_empty() {
+        lines.push(fill(
+            messages.split_unavailable,
+            &[("resources", &format_resources(&proposal.unavailable))],
+        ));
+    }
+
+    let text = lines.join("\n");
+    let mut blocks = vec![SlackBlock::Section(
+        SlackSectionBlock::new().with_text(md!(text.clone())),
+    )];
+
+    let payload = serde_json::to_string(&SplitReservationPayload::new(
+        proposal, notes, metadata, visibility,
+    ))
+    .ok();
+    match payload {
+        Some(value) if value.len() <= MAX_BUTTON_VALUE_LEN => {
+            blocks.push(SlackBlock::Actions(SlackActionsBlock::new(vec![
+                SlackActionBlockElement::Button(
+                    SlackBlockButtonElement::new(
+                        SlackActionId::new(ACTION_CONFIRM_SPLIT_RESERVATION.to_string()),
+                        pt!(messages.split_reserve),
+                    )
+                    .with_style("primary".to_string())
+                    .with_value(value),
+                ),
+            ])));
+        }
+        _ => {
+            blocks.push(SlackBlock::Context(SlackContextBlock::new(vec![
+                SlackContextBlockElement::MarkDown(SlackBlockMarkDownText::new(
+                    messages.split_too_large.to_string(),
+                )),
+            ])));
+        }
+    }
+
+    SlackMessageContent::new()
+        .with_text(text)
+        .with_blocks(blocks)
+}
+
+fn format_period(period: &TimePeriod) -> String {
+    let start = period.start().with_timezone(&Local);
+    let end = period.end().with_timezone(&Local);
+    if start.date_naive() == end.date_naive() {
+        format!("{} - {}", start.format("%m/%d %H:%M"), end.format("%H:%M"))
+    } else {
+        format!(
+            "{} - {}",
+            start.format("%m/%d %H:%M"),
+            end.format("%m/%d %H:%M")
+        )
+    }
+}
+
+fn format_resources(resources: &[Resource]) -> String {
+    resources
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_payload_round_trip() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 15, 18, 0, 0).unwrap();
+        let proposal = SplitProposal {
+            allocations: vec![ResourceAllocation {
+                time_period: TimePeriod::new(start, end).unwrap(),
+                resources: vec![
+                    Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string())),
+                    Resource::Room {
+                        name: "会議室A".to_string(),
+                    },
+                ],
+            }],
+            unavailable: vec![],
+        };
+
+        let metadata =
+            ReservationMetadata::new(Some("LLM".to_string()), Some("exp-1".to_string()), None)
+                .unwrap();
+        let payload = SplitReservationPayload::new(
+            &proposal,
+            Some("メモ".to_string()),
+            &metadata,
+            Visibility::Private,
+        );
+        let json = serde_json::to_string(&payload).unwrap();
+        let restored: SplitReservationPayload = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.notes.as_deref(), Some("メモ"));
+        assert_eq!(restored.metadata(), metadata);
+        assert_eq!(restored.visibility(), Visibility::Private);
+        assert_eq!(restored.into_allocations().unwrap(), proposal.allocations);
+    }
+}
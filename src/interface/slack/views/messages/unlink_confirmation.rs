@@ -0,0 +1,34 @@
+//! 連携解除の確認メッセージブロック
+//!
+//! `/unlink-user` を引数なしで実行したユーザーに、自分の連携を解除するか確認する。
+
+use crate::domain::common::EmailAddress;
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::constants::ACTION_CONFIRM_UNLINK_SELF;
+use slack_morphism::prelude::*;
+
+/// 自分の連携解除を確認するメッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `email` - 現在紐付けられているメールアドレス
+pub fn create(messages: &Messages, email: &EmailAddress) -> SlackMessageContent {
+    let text = fill(messages.unlink_confirm, &[("email", email.as_str())]);
+
+    let blocks = vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(text.clone()))),
+        SlackBlock::Actions(SlackActionsBlock::new(vec![
+            SlackActionBlockElement::Button(
+                SlackBlockButtonElement::new(
+                    SlackActionId::new(ACTION_CONFIRM_UNLINK_SELF.to_string()),
+                    pt!(messages.unlink_button),
+                )
+                .with_style("danger".to_string()),
+            ),
+        ])),
+    ];
+
+    SlackMessageContent::new()
+        .with_text(text)
+        .with_blocks(blocks)
+}
@@ -0,0 +1,178 @@
+//! 利用状況の集計メッセージ
+//!
+//! `/usage-stats` の結果として、期間内の予約時間をユーザー・サーバー・デバイス・プロジェクトごとに順位付けして表示する。
+//! 複数の週にまたがる場合は週ごとの推移も表示する。
+
+use crate::application::usecases::UsageReport;
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::infrastructure::i18n::{Messages, fill};
+use chrono::{DateTime, Duration, Local, Utc};
+use slack_morphism::prelude::*;
+
+/// 各表に表示する最大の行数
+const MAX_ROWS: usize = 10;
+
+/// 利用状況の集計メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+/// * `label` - 集計期間の呼び名（例: "過去7日間"）
+/// * `report` - 集計結果
+pub fn create(messages: &Messages, label: &str, report: &UsageReport) -> SlackMessageContent {
+    let text = period_header(messages.usage_stats_header, label, report.period());
+
+    if report.users().is_empty() {
+        return SlackMessageContent::new()
+            .with_text(format!("{}\n{}", text, messages.usage_stats_none));
+    }
+    let room = |time| {
+        fill(
+            messages.usage_stats_room,
+            &[("hours", format_hours(time).trim_start())],
+        )
+    };
+
+    let users = ranked_rows(report.users().iter().map(|user| {
+        let mut row = format!(
+            "GPU {}  {}",
+            format_hours(user.gpu_time()),
+            user.owner_email().as_str()
+        );
+        if user.room_time() > Duration::zero() {
+            row.push_str(&room(user.room_time()));
+        }
+        row
+    }));
+    let servers = ranked_rows(
+        report
+            .servers()
+            .into_iter()
+            .map(|(server, time)| format!("{}  {}", format_hours(time), server)),
+    );
+    let projects = ranked_rows(report.projects().iter().map(|project| {
+        let mut row = format!(
+            "GPU {}  {}",
+            format_hours(project.gpu_time()),
+            project.project()
+        );
+        if project.room_time() > Duration::zero() {
+            row.push_str(&room(project.room_time()));
+        }
+        if let Some(utilization) = project.expected_utilization() {
+            row.push_str(&fill(
+                messages.usage_stats_utilization,
+                &[("utilization", &utilization.to_string())],
+            ));
+        }
+        row
+    }));
+    let resources = ranked_rows(report.resources().iter().map(|total| {
+        format!(
+            "{}  {}",
+            format_hours(total.time()),
+            label_of(total.resource())
+        )
+    }));
+
+    let mut blocks = vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(format!("*{}*", text)))),
+        table_block(messages.by_user, &users),
+    ];
+    if !servers.is_empty() {
+        blocks.push(table_block(messages.by_server, &servers));
+    }
+    blocks.push(table_block(messages.by_resource, &resources));
+    if !projects.is_empty() {
+        blocks.push(table_block(messages.by_project, &projects));
+    }
+    // 複数の週にまたがる場合は週ごとの推移も表示する
+    if report.weeks().len() > 1 {
+        let weeks: Vec<String> = report
+            .weeks()
+            .iter()
+            .map(|week| {
+                format!(
+                    "{}〜  GPU {}",
+                    week.period().start().with_timezone(&Local).format("%m/%d"),
+                    format_hours(week.gpu_time())
+                )
+            })
+            .collect();
+        blocks.push(table_block(messages.by_week, &weeks));
+    }
+
+    SlackMessageContent::new()
+        .with_text(text)
+        .with_blocks(blocks)
+}
+
+/// 集計の見出し（`template` の `{window}`・`{start}`・`{end}` に集計期間を埋め込む）
+pub(super) fn period_header(template: &str, label: &str, period: &TimePeriod) -> String {
+    let format = |time: DateTime<Utc>| {
+        time.with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    };
+    fill(
+        template,
+        &[
+            ("window", label),
+            ("start", &format(period.start())),
+            ("end", &format(period.end())),
+        ],
+    )
+}
+
+/// 見出しと等幅の表からなるブロック
+pub(super) fn table_block(title: &str, rows: &[String]) -> SlackBlock {
+    SlackBlock::Section(SlackSectionBlock::new().with_text(md!(format!(
+        "*{}*\n```{}```",
+        title,
+        rows.join("\n")
+    ))))
+}
+
+/// 順位を付けた行（上位 `MAX_ROWS` 件）
+pub(super) fn ranked_rows(rows: impl Iterator<Item = String>) -> Vec<String> {
+    rows.take(MAX_ROWS)
+        .enumerate()
+        .map(|(index, row)| format!("{:>2}. {}", index + 1, row))
+        .collect()
+}
+
+/// 時間を「12.5h」の形式で表す（右寄せ）
+pub(super) fn format_hours(time: Duration) -> String {
+    format!("{:>6.1}h", time.num_minutes() as f64 / 60.0)
+}
+
+fn label_of(resource: &Resource) -> String {
+    match resource {
+        Resource::Gpu(gpu) => format!(
+            "{} GPU#{} ({})",
+            gpu.server(),
+            gpu.device_number(),
+            gpu.model()
+        ),
+        Resource::Room { name } | Resource::Instrument { name } | Resource::Custom { name, .. } => {
+            name.clone()
+        }
+        Resource::Storage { volume, gigabytes } => format!("{} {}GB", volume, gigabytes),
+        Resource::License { name, seats } => format!("{} x{}", name, seats),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranked_rows_are_numbered_and_truncated() {
+        let rows = ranked_rows(
+            (0..12).map(|i| format!("{}  user{}", format_hours(Duration::minutes(90)), i)),
+        );
+
+        assert_eq!(rows.len(), MAX_ROWS);
+        assert_eq!(rows[0], " 1.    1.5h  user0");
+        assert_eq!(rows[9], "10.    1.5h  user9");
+    }
+}
@@ -0,0 +1,117 @@
+//! 空き待ちの案内メッセージブロック
+//!
+//! 予約が既存の予約と競合した場合に、空いたら知らせる空き待ちへの登録を提案する。
+
+use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::constants::ACTION_JOIN_WAITLIST;
+use crate::interface::slack::views::messages::split_proposal::ResourcePayload;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use slack_morphism::prelude::*;
+
+/// Slackボタンのvalueに格納できる最大文字数
+const MAX_BUTTON_VALUE_LEN: usize = 2000;
+
+/// 空き待ちボタンに埋め込むペイロード
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaitlistPayload {
+    /// 希望する時間帯の開始時刻
+    pub start: DateTime<Utc>,
+    /// 希望する時間帯の終了時刻
+    pub end: DateTime<Utc>,
+    /// 希望するリソース
+    pub resources: Vec<ResourcePayload>,
+}
+
+impl WaitlistPayload {
+    /// 希望する時間帯・リソースからペイロードを作成
+    pub fn new(time_period: &TimePeriod, resources: &[Resource]) -> Self {
+        Self {
+            start: time_period.start(),
+            end: time_period.end(),
+            resources: resources.iter().map(Into::into).collect(),
+        }
+    }
+
+    /// ペイロードから希望する時間帯・リソースを復元
+    pub fn into_request(self) -> Result<(TimePeriod, Vec<Resource>), String> {
+        let time_period = TimePeriod::new(self.start, self.end).map_err(|e| e.to_string())?;
+        Ok((
+            time_period,
+            self.resources.into_iter().map(Into::into).collect(),
+        ))
+    }
+}
+
+/// 空き待ちへの登録ボタンを作成
+///
+/// 希望するリソースが多すぎてボタンに収まらない場合は `None`
+pub fn join_button(
+    messages: &Messages,
+    time_period: &TimePeriod,
+    resources: &[Resource],
+) -> Option<SlackActionBlockElement> {
+    let value = serde_json::to_string(&WaitlistPayload::new(time_period, resources)).ok()?;
+    if value.len() > MAX_BUTTON_VALUE_LEN {
+        return None;
+    }
+    Some(SlackActionBlockElement::Button(
+        SlackBlockButtonElement::new(
+            SlackActionId::new(ACTION_JOIN_WAITLIST.to_string()),
+            pt!(messages.join_waitlist.to_string()),
+        )
+        .with_value(value),
+    ))
+}
+
+/// 予約が競合した場合の空き待ちの案内メッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
+/// * `conflict_reason` - 予約できなかった理由
+/// * `time_period` - 希望した時間帯
+/// * `resources` - 希望したリソース
+pub fn offer(
+    messages: &Messages,
+    conflict_reason: &str,
+    time_period: &TimePeriod,
+    resources: &[Resource],
+) -> Option<SlackMessageContent> {
+    let button = join_button(messages, time_period, resources)?;
+    let text = fill(messages.waitlist_offer, &[("reasons", conflict_reason)]);
+    Some(
+        SlackMessageContent::new()
+            .with_text(text.clone())
+            .with_blocks(vec![
+                SlackBlock::Section(SlackSectionBlock::new().with_text(md!(text))),
+                SlackBlock::Actions(SlackActionsBlock::new(vec![button])),
+            ]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_payload_round_trip() {
+        let time_period = TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 15, 18, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let resources = vec![Resource::Gpu(Gpu::new(
+            "Thalys".to_string(),
+            0,
+            "A100".to_string(),
+        ))];
+
+        let json = serde_json::to_string(&WaitlistPayload::new(&time_period, &resources)).unwrap();
+        let restored: WaitlistPayload = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.into_request().unwrap(), (time_period, resources));
+    }
+}
@@ -0,0 +1,134 @@
+//! 使用中のユーザーのメッセージ
+//!
+//! `/whois-using` の結果として、サーバー・部屋を現在使用中のユーザーと終了時刻を表示する。
+
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::utility::datetime_parser::to_user_time;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use slack_morphism::prelude::*;
+
+/// 使用中の予約1件分の表示内容
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occupant {
+    /// 予約者（Slackのメンションかメールアドレス。非公開で伏せる場合は「予約済み」等）
+    pub owner: String,
+    /// 使用中のデバイス番号（部屋の場合は空）
+    pub devices: Vec<u32>,
+    /// 予約の終了時刻
+    pub until: DateTime<Utc>,
+}
+
+/// 使用中のユーザーのメッセージを作成
+///
+/// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
+/// * `resource` - サーバー名または部屋名
+/// * `occupants` - 使用中の予約（終了時刻順）
+/// * `now` - 基準の時刻（終了が今日でない場合は日付も表示する）
+/// * `timezone` - 時刻を表示するタイムゾーン
+pub fn create(
+    messages: &Messages,
+    resource: &str,
+    occupants: &[Occupant],
+    now: DateTime<Utc>,
+    timezone: Option<Tz>,
+) -> SlackMessageContent {
+    if occupants.is_empty() {
+        return SlackMessageContent::new()
+            .with_text(fill(messages.whois_using_none, &[("resource", resource)]));
+    }
+
+    let today = to_user_time(now, timezone).date_naive();
+    let lines: Vec<String> = occupants
+        .iter()
+        .map(|occupant| {
+            let until = to_user_time(occupant.until, timezone);
+            let until = if until.date_naive() == today {
+                until.format("%H:%M").to_string()
+            } else {
+                until.format("%m/%d %H:%M").to_string()
+            };
+            let devices = if occupant.devices.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    " — GPU {}",
+                    occupant
+                        .devices
+                        .iter()
+                        .map(|device| device.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            };
+            fill(
+                messages.whois_using_entry,
+                &[
+                    ("owner", &occupant.owner),
+                    ("devices", &devices),
+                    ("until", &until),
+                ],
+            )
+        })
+        .collect();
+
+    let text = format!(
+        "*{}*\n{}",
+        fill(messages.whois_using_header, &[("resource", resource)]),
+        lines.join("\n")
+    );
+    SlackMessageContent::new()
+        .with_text(text.clone())
+        .with_blocks(vec![SlackBlock::Section(
+            SlackSectionBlock::new().with_text(md!(text)),
+        )])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::i18n::Locale;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_create_lists_occupants() {
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 3, 0, 0).unwrap();
+        let occupants = vec![
+            Occupant {
+                owner: "<@U123>".to_string(),
+                devices: vec![0, 1],
+                until: Utc.with_ymd_and_hms(2025, 4, 1, 9, 0, 0).unwrap(),
+            },
+            Occupant {
+                owner: "Reserved".to_string(),
+                devices: vec![2],
+                until: Utc.with_ymd_and_hms(2025, 4, 2, 1, 30, 0).unwrap(),
+            },
+        ];
+
+        let content = create(
+            Locale::En.messages(),
+            "Thalys",
+            &occupants,
+            now,
+            Some(chrono_tz::Asia::Tokyo),
+        );
+
+        assert_eq!(
+            content.text.as_deref(),
+            Some(
+                "*👥 Currently using Thalys*\n\
+                 • <@U123> — GPU 0, 1 (until 18:00)\n\
+                 • Reserved — GPU 2 (until 04/02 10:30)"
+            )
+        );
+    }
+
+    #[test]
+    fn test_create_without_occupants() {
+        let content = create(Locale::En.messages(), "Room A", &[], Utc::now(), None);
+
+        assert_eq!(content.text.as_deref(), Some("✅ Room A is free right now"));
+    }
+}
@@ -0,0 +1,208 @@
+//! 予約一括キャンセルモーダルビルダー
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::Resource;
+use crate::infrastructure::i18n::{Messages, fill};
+use crate::interface::slack::constants::{ACTION_CANCEL_ALL_SELECT, CALLBACK_CANCEL_ALL};
+use crate::interface::slack::utility::datetime_parser::to_user_time;
+use chrono_tz::Tz;
+use slack_morphism::prelude::*;
+
+/// モーダルに表示する予約の最大数（モーダルのブロック数の上限に収めるため）
+pub const MAX_LISTED: usize = 50;
+
+/// 1つのチェックボックスに含める選択肢の最大数（Slackの上限）
+const OPTIONS_PER_BLOCK: usize = 10;
+
+/// 選択肢のテキストの最大文字数（Slackの上限）
+const MAX_OPTION_CHARS: usize = 75;
+
+/// 予約一括キャンセルモーダルを作成
+///
+/// `/cancel-all` コマンドで使用される、開始前の予約を選んでまとめてキャンセルするモーダル。
+/// 予約は開始時刻の早い順に `MAX_LISTED` 件まで表示する。
+///
+/// # 引数
+/// * `messages` - 表示言語のメッセージカタログ
+/// * `usages` - キャンセルできる予約（開始時刻の早い順）
+/// * `timezone` - 日時を表示するタイムゾーン
+pub fn create(messages: &Messages, usages: &[ResourceUsage], timezone: Option<Tz>) -> SlackView {
+    let mut blocks = vec![SlackBlock::Section(
+        SlackSectionBlock::new().with_text(md!(messages.cancel_all_intro)),
+    )];
+    if usages.len() > MAX_LISTED {
+        blocks.push(SlackBlock::Context(SlackContextBlock::new(vec![
+            SlackContextBlockElement::MarkDown(SlackBlockMarkDownText::new(fill(
+                messages.cancel_all_truncated,
+                &[("count", &MAX_LISTED.to_string())],
+            ))),
+        ])));
+    }
+
+    let listed = &usages[..usages.len().min(MAX_LISTED)];
+    for (index, chunk) in listed.chunks(OPTIONS_PER_BLOCK).enumerate() {
+        let options: Vec<SlackBlockChoiceItem<SlackBlockText>> = chunk
+            .iter()
+            .map(|usage| {
+                SlackBlockChoiceItem::new(
+                    pt!(option_label(usage, timezone)),
+                    usage.id().as_str().to_string(),
+                )
+            })
+            .collect();
+        blocks.push(SlackBlock::Input(
+            SlackInputBlock::new(
+                pt!(messages.cancel_all_label),
+                SlackInputBlockElement::Checkboxes(SlackBlockCheckboxesElement::new(
+                    SlackActionId::new(ACTION_CANCEL_ALL_SELECT.to_string()),
+                    options,
+                )),
+            )
+            .with_block_id(SlackBlockId::new(format!(
+                "{}_{}",
+                ACTION_CANCEL_ALL_SELECT, index
+            )))
+            .with_optional(true),
+        ));
+    }
+
+    SlackView::Modal(
+        SlackModalView::new(pt!(messages.cancel_all_title), blocks)
+            .with_callback_id(CALLBACK_CANCEL_ALL.into())
+            .with_submit(pt!(messages.cancel_all_submit))
+            .with_close(pt!(messages.cancel)),
+    )
+}
+
+/// 予約の選択肢のテキスト（期間とリソース）
+///
+/// キャンセル後の結果メッセージでも同じ表記を使う。
+pub fn option_label(usage: &ResourceUsage, timezone: Option<Tz>) -> String {
+    let start = to_user_time(usage.time_period().start(), timezone);
+    let end = to_user_time(usage.time_period().end(), timezone);
+    let period = if start.date_naive() == end.date_naive() {
+        format!("{}-{}", start.format("%Y-%m-%d %H:%M"), end.format("%H:%M"))
+    } else {
+        format!(
+            "{} - {}",
+            start.format("%Y-%m-%d %H:%M"),
+            end.format("%m-%d %H:%M")
+        )
+    };
+
+    let mut servers: Vec<(&str, Vec<String>)> = Vec::new();
+    let mut others: Vec<String> = Vec::new();
+    for resource in usage.resources() {
+        match resource {
+            Resource::Gpu(gpu) => {
+                let device = gpu.device_number().to_string();
+                match servers.iter_mut().find(|(name, _)| *name == gpu.server()) {
+                    Some((_, devices)) => devices.push(device),
+                    None => servers.push((gpu.server(), vec![device])),
+                }
+            }
+            Resource::Room { name }
+            | Resource::Instrument { name }
+            | Resource::Custom { name, .. } => others.push(name.clone()),
+            Resource::Storage { volume, gigabytes } => {
+                others.push(format!("{} {}GB", volume, gigabytes))
+            }
+            Resource::License { name, seats } => others.push(format!("{} x{}", name, seats)),
+        }
+    }
+    let resources: Vec<String> = servers
+        .into_iter()
+        .map(|(server, devices)| format!("{} GPU {}", server, devices.join(",")))
+        .chain(others)
+        .collect();
+
+    let label = format!("{}  {}", period, resources.join(" / "));
+    if label.chars().count() <= MAX_OPTION_CHARS {
+        label
+    } else {
+        let truncated: String = label.chars().take(MAX_OPTION_CHARS - 1).collect();
+        format!("{}…", truncated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, TimePeriod};
+    use crate::domain::common::EmailAddress;
+    use chrono::{TimeZone, Utc};
+
+    fn usage(start_hour: u32, end_day: u32, resources: Vec<Resource>) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2025, 4, 1, start_hour, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, end_day, 9, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            resources,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_option_label() {
+        let gpus = usage(
+            1,
+            1,
+            vec![
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 0, "A100".to_string())),
+                Resource::Gpu(Gpu::new("Thalys".to_string(), 1, "A100".to_string())),
+                Resource::Room {
+                    name: "Room A".to_string(),
+                },
+            ],
+        );
+        assert_eq!(
+            option_label(&gpus, Some(chrono_tz::UTC)),
+            "2025-04-01 01:00-09:00  Thalys GPU 0,1 / Room A"
+        );
+
+        let overnight = usage(
+            20,
+            2,
+            vec![Resource::Room {
+                name: "Room A".to_string(),
+            }],
+        );
+        assert_eq!(
+            option_label(&overnight, Some(chrono_tz::UTC)),
+            "2025-04-01 20:00 - 04-02 09:00  Room A"
+        );
+    }
+
+    #[test]
+    fn test_create_splits_options_into_blocks() {
+        let usages: Vec<ResourceUsage> = (0..MAX_LISTED + 3)
+            .map(|_| {
+                usage(
+                    1,
+                    1,
+                    vec![Resource::Room {
+                        name: "Room A".to_string(),
+                    }],
+                )
+            })
+            .collect();
+
+        let SlackView::Modal(modal) = create(
+            crate::infrastructure::i18n::Locale::En.messages(),
+            &usages,
+            None,
+        ) else {
+            panic!("モーダルではありません");
+        };
+        let inputs = modal
+            .blocks
+            .iter()
+            .filter(|block| matches!(block, SlackBlock::Input(_)))
+            .count();
+        assert_eq!(inputs, MAX_LISTED / OPTIONS_PER_BLOCK);
+    }
+}
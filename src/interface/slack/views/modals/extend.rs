@@ -0,0 +1,74 @@
+//! 予約延長モーダルビルダー
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::constants::*;
+use crate::interface::slack::utility::datetime_parser::to_user_time;
+use crate::interface::slack::utility::user_resolver::UserPreferences;
+use slack_morphism::prelude::*;
+
+/// 予約を延長するモーダルを作成
+///
+/// +1時間・+2時間・その他（分単位で入力）から延長する時間を選ぶ。
+///
+/// # 引数
+/// * `usage` - 延長する予約
+/// * `preferences` - 予約者の表示設定（終了時刻を表示するタイムゾーン・表示言語）
+///
+/// # 戻り値
+/// 予約延長フォームのモーダルビュー（usage_idをprivate_metadataに設定）
+pub fn create(usage: &ResourceUsage, preferences: &UserPreferences) -> SlackView {
+    let messages = preferences.messages();
+    let end = to_user_time(usage.time_period().end(), preferences.timezone);
+
+    let one_hour: SlackBlockChoiceItem<SlackBlockText> =
+        SlackBlockChoiceItem::new(pt!(messages.plus_one_hour), "60".into());
+    let options = vec![
+        one_hour.clone(),
+        SlackBlockChoiceItem::new(pt!(messages.plus_two_hours), "120".into()),
+        SlackBlockChoiceItem::new(pt!(messages.other), EXTEND_CUSTOM_VALUE.into()),
+    ];
+
+    let blocks = vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(fill(
+            messages.current_end,
+            &[("end", &end.format("%Y-%m-%d %H:%M").to_string())]
+        )))),
+        SlackBlock::Input(
+            SlackInputBlock::new(
+                pt!(messages.extend_duration),
+                SlackInputBlockElement::RadioButtons(
+                    SlackBlockRadioButtonsElement::new(
+                        SlackActionId::new(ACTION_EXTEND_DURATION.to_string()),
+                        options,
+                    )
+                    .with_initial_option(one_hour),
+                ),
+            )
+            .with_block_id(SlackBlockId::new(ACTION_EXTEND_DURATION.to_string())),
+        ),
+        SlackBlock::Input(
+            SlackInputBlock::new(
+                pt!(messages.extend_minutes),
+                SlackInputBlockElement::NumberInput(
+                    SlackBlockNumberInputElement::new(
+                        SlackActionId::new(ACTION_EXTEND_CUSTOM_MINUTES.to_string()),
+                        false,
+                    )
+                    .with_min_value("1".to_string()),
+                ),
+            )
+            .with_block_id(SlackBlockId::new(ACTION_EXTEND_CUSTOM_MINUTES.to_string()))
+            .with_hint(pt!(messages.extend_minutes_hint))
+            .with_optional(true),
+        ),
+    ];
+
+    SlackView::Modal(
+        SlackModalView::new(pt!(messages.extend_title), blocks)
+            .with_callback_id(CALLBACK_EXTEND_RESERVATION.into())
+            .with_submit(pt!(messages.extend_submit))
+            .with_close(pt!(messages.cancel))
+            .with_private_metadata(usage.id().as_str().to_string()),
+    )
+}
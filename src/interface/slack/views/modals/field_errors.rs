@@ -0,0 +1,269 @@
+//! 入力エラーを表示するために開き直すモーダル
+//!
+//! Socket Modeではview_submissionの応答（`response_action: errors`）を返せず、送信したモーダルは閉じてしまう。
+//! そのため、送信されたモーダルを入力した値のまま開き直し、エラーのある入力欄のヒントにエラーを表示する。
+
+use serde_json::{Map, Value, json};
+use slack_morphism::prelude::*;
+use std::collections::HashMap;
+
+/// 送信された値を入力済みにし、入力欄ごとのエラーを表示したモーダルを作成
+///
+/// # 引数
+/// * `submitted` - 送信されたモーダル（入力された値を含む）
+/// * `errors` - ブロックIDごとのエラーメッセージ
+///
+/// # 戻り値
+/// 開き直すモーダル。モーダル以外のビューの場合は `None`
+pub fn reopen_with_errors(
+    submitted: &SlackStatefulView,
+    errors: &HashMap<String, String>,
+) -> Option<SlackView> {
+    let SlackView::Modal(_) = &submitted.view else {
+        return None;
+    };
+    let mut view = serde_json::to_value(&submitted.view).ok()?;
+    let state = submitted
+        .state_params
+        .state
+        .as_ref()
+        .and_then(|state| serde_json::to_value(&state.values).ok())
+        .unwrap_or(Value::Null);
+
+    let blocks = view.get_mut("blocks")?.as_array_mut()?;
+    for block in blocks {
+        if block.get("type").and_then(Value::as_str) != Some("input") {
+            continue;
+        }
+        let Some(block_id) = block
+            .get("block_id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        let block = block.as_object_mut()?;
+
+        if let Some(element) = block.get_mut("element").and_then(Value::as_object_mut) {
+            let action_id = element.get("action_id").and_then(Value::as_str);
+            let submitted_value = match action_id {
+                Some(action_id) => state
+                    .get(&block_id)
+                    .and_then(|values| values.get(action_id)),
+                None => None,
+            };
+            if let Some(submitted_value) = submitted_value {
+                prefill(element, submitted_value);
+            }
+        }
+
+        match errors.get(&block_id) {
+            Some(error) => {
+                block.insert(
+                    "hint".to_string(),
+                    json!({ "type": "plain_text", "text": format!("⚠️ {}", error) }),
+                );
+            }
+            None => {
+                block.remove("hint");
+            }
+        }
+    }
+
+    serde_json::from_value(view).ok()
+}
+
+/// 入力欄に送信された値を設定する
+fn prefill(element: &mut Map<String, Value>, submitted: &Value) {
+    const INITIAL_KEYS: [&str; 9] = [
+        "initial_value",
+        "initial_option",
+        "initial_options",
+        "initial_date",
+        "initial_time",
+        "initial_user",
+        "initial_users",
+        "initial_conversation",
+        "initial_channel",
+    ];
+    // 未入力にした欄は、元の初期値に戻らないよう空にする
+    for key in INITIAL_KEYS {
+        element.remove(key);
+    }
+
+    let field = |name: &str| {
+        submitted
+            .get(name)
+            .filter(|value| !value.is_null())
+            .cloned()
+    };
+    let element_type = element
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let initial = match element_type.as_str() {
+        "plain_text_input" | "number_input" | "email_text_input" | "url_text_input" => {
+            field("value").map(|value| ("initial_value", value))
+        }
+        "static_select" | "radio_buttons" => field("selected_option")
+            .and_then(|selected| find_option(element, &selected))
+            .map(|option| ("initial_option", option)),
+        "multi_static_select" | "checkboxes" => field("selected_options")
+            .and_then(|selected| selected.as_array().cloned())
+            .map(|selected| {
+                let options: Vec<Value> = selected
+                    .iter()
+                    .filter_map(|option| find_option(element, option))
+                    .collect();
+                ("initial_options", Value::Array(options))
+            })
+            .filter(|(_, options)| {
+                options
+                    .as_array()
+                    .is_some_and(|options| !options.is_empty())
+            }),
+        "datepicker" => field("selected_date").map(|date| ("initial_date", date)),
+        "timepicker" => field("selected_time").map(|time| ("initial_time", time)),
+        "users_select" => field("selected_user").map(|user| ("initial_user", user)),
+        "multi_users_select" => field("selected_users").map(|users| ("initial_users", users)),
+        "conversations_select" => field("selected_conversation")
+            .map(|conversation| ("initial_conversation", conversation)),
+        "channels_select" => field("selected_channel").map(|channel| ("initial_channel", channel)),
+        _ => None,
+    };
+    if let Some((key, value)) = initial {
+        element.insert(key.to_string(), value);
+    }
+}
+
+/// 選択肢の一覧から、送信された選択肢と同じ値のものを探す
+///
+/// 初期値は選択肢と完全に一致している必要があるため、送信された選択肢ではなく元の選択肢を使う。
+fn find_option(element: &Map<String, Value>, selected: &Value) -> Option<Value> {
+    let value = selected.get("value")?;
+    let options = element
+        .get("options")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten();
+    let grouped = element
+        .get("option_groups")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|group| group.get("options").and_then(Value::as_array))
+        .flatten();
+    options
+        .chain(grouped)
+        .find(|option| option.get("value") == Some(value))
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submitted(view: Value, state: Value) -> SlackStatefulView {
+        let mut payload = view;
+        let fields = payload.as_object_mut().unwrap();
+        fields.insert("id".to_string(), json!("V123"));
+        fields.insert("team_id".to_string(), json!("T123"));
+        fields.insert("hash".to_string(), json!("hash"));
+        fields.insert("state".to_string(), json!({ "values": state }));
+        serde_json::from_value(payload).unwrap()
+    }
+
+    fn reserve_form() -> Value {
+        json!({
+            "type": "modal",
+            "callback_id": "reserve",
+            "private_metadata": "meta",
+            "title": { "type": "plain_text", "text": "予約" },
+            "submit": { "type": "plain_text", "text": "送信" },
+            "blocks": [
+                {
+                    "type": "input",
+                    "block_id": "start_date",
+                    "label": { "type": "plain_text", "text": "開始日" },
+                    "element": { "type": "datepicker", "action_id": "start_date", "initial_date": "2024-01-15" }
+                },
+                {
+                    "type": "input",
+                    "block_id": "server",
+                    "label": { "type": "plain_text", "text": "サーバー" },
+                    "element": {
+                        "type": "static_select",
+                        "action_id": "server",
+                        "options": [
+                            { "text": { "type": "plain_text", "text": "Thalys" }, "value": "Thalys" },
+                            { "text": { "type": "plain_text", "text": "Luna" }, "value": "Luna" }
+                        ]
+                    }
+                },
+                {
+                    "type": "input",
+                    "block_id": "notes",
+                    "label": { "type": "plain_text", "text": "備考" },
+                    "element": { "type": "plain_text_input", "action_id": "notes", "initial_value": "元の備考" },
+                    "optional": true
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_reopen_prefills_submitted_values_and_shows_errors() {
+        let view = submitted(
+            reserve_form(),
+            json!({
+                "start_date": { "start_date": { "type": "datepicker", "selected_date": "2024-02-01" } },
+                "server": { "server": { "type": "static_select", "selected_option": { "text": { "type": "plain_text", "text": "Thalys" }, "value": "Thalys" } } },
+                "notes": { "notes": { "type": "plain_text_input", "value": "学習ジョブ" } }
+            }),
+        );
+        let errors = HashMap::from([(
+            "start_date".to_string(),
+            "開始日は過去にできません".to_string(),
+        )]);
+
+        let reopened = serde_json::to_value(reopen_with_errors(&view, &errors).unwrap()).unwrap();
+
+        assert_eq!(reopened["callback_id"], "reserve");
+        assert_eq!(reopened["private_metadata"], "meta");
+        let blocks = &reopened["blocks"];
+        assert_eq!(blocks[0]["element"]["initial_date"], "2024-02-01");
+        assert_eq!(blocks[0]["hint"]["text"], "⚠️ 開始日は過去にできません");
+        assert_eq!(blocks[1]["element"]["initial_option"]["value"], "Thalys");
+        assert_eq!(
+            blocks[1]["element"]["initial_option"]["text"]["text"],
+            "Thalys"
+        );
+        assert!(blocks[1].get("hint").is_none());
+        assert_eq!(blocks[2]["element"]["initial_value"], "学習ジョブ");
+    }
+
+    #[test]
+    fn test_reopen_clears_initial_value_of_emptied_field() {
+        let view = submitted(
+            reserve_form(),
+            json!({
+                "notes": { "notes": { "type": "plain_text_input", "value": null } }
+            }),
+        );
+
+        let reopened =
+            serde_json::to_value(reopen_with_errors(&view, &HashMap::new()).unwrap()).unwrap();
+
+        assert!(
+            reopened["blocks"][2]["element"]
+                .get("initial_value")
+                .is_none()
+        );
+        // 送信されなかった欄は元の初期値のまま
+        assert_eq!(
+            reopened["blocks"][0]["element"]["initial_date"],
+            "2024-01-15"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! ユーザーリンクモーダルビルダー
 
+use crate::infrastructure::i18n::Messages;
 use crate::interface::slack::constants::{
     ACTION_LINK_EMAIL_INPUT, ACTION_USER_SELECT, CALLBACK_LINK_USER,
 };
@@ -9,28 +10,27 @@ use slack_morphism::prelude::*;
 ///
 /// `/link-user` コマンドで使用される、
 /// 他のユーザーをメールアドレスに紐付けるモーダル（管理者用）
-pub fn create() -> SlackView {
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+pub fn create(messages: &Messages) -> SlackView {
     let blocks = vec![
-        SlackBlock::Section(
-            SlackSectionBlock::new().with_text(md!(
-                "他のユーザーをGoogleカレンダーのメールアドレスに紐付けます。\n紐付けられたユーザーに、カレンダーへのアクセス権が自動的に付与されます。"
-            )),
-        ),
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(messages.link_user_intro))),
         SlackBlock::Input(
             SlackInputBlock::new(
-                pt!("紐付けるユーザー"),
+                pt!(messages.link_user_target),
                 SlackInputBlockElement::UsersSelect(
                     SlackBlockUsersSelectElement::new(SlackActionId::new(
                         ACTION_USER_SELECT.to_string(),
                     ))
-                    .with_placeholder(pt!("ユーザーを選択")),
+                    .with_placeholder(pt!(messages.select_user)),
                 ),
             )
             .with_block_id(SlackBlockId::new(ACTION_USER_SELECT.to_string())),
         ),
         SlackBlock::Input(
             SlackInputBlock::new(
-                pt!("メールアドレス"),
+                pt!(messages.email_address),
                 SlackInputBlockElement::PlainTextInput(
                     SlackBlockPlainTextInputElement::new(SlackActionId::new(
                         ACTION_LINK_EMAIL_INPUT.to_string(),
@@ -43,9 +43,9 @@ pub fn create() -> SlackView {
     ];
 
     SlackView::Modal(
-        SlackModalView::new(pt!("ユーザーをメールアドレスに紐付け"), blocks)
+        SlackModalView::new(pt!(messages.link_user_title), blocks)
             .with_callback_id(CALLBACK_LINK_USER.into())
-            .with_submit(pt!("紐付け"))
-            .with_close(pt!("キャンセル")),
+            .with_submit(pt!(messages.link_user_submit))
+            .with_close(pt!(messages.cancel)),
     )
 }
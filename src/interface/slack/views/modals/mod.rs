@@ -10,10 +10,18 @@
 //!
 //! ## モジュール
 //!
+//! - `cancel_all`: 予約一括キャンセルモーダル（`/cancel-all`コマンドに対応）
+//! - `extend`: 予約延長モーダル
+//! - `field_errors`: 入力エラーを表示するために開き直すモーダル
 //! - `registration`: メールアドレス登録モーダル
 //! - `link_user`: ユーザーリンクモーダル（管理者用）
 //! - `reserve`: リソース予約モーダル（`/reserve`コマンドに対応）
+//! - `transfer`: 予約引き継ぎモーダル
 
+pub mod cancel_all;
+pub mod extend;
+pub mod field_errors;
 pub mod link_user;
 pub mod registration;
 pub mod reserve;
+pub mod transfer;
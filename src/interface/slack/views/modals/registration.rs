@@ -1,5 +1,6 @@
 //! メールアドレス登録モーダルビルダー
 
+use crate::infrastructure::i18n::Messages;
 use crate::interface::slack::constants::{ACTION_EMAIL_INPUT, CALLBACK_REGISTER_EMAIL};
 use slack_morphism::prelude::*;
 
@@ -7,30 +8,30 @@ use slack_morphism::prelude::*;
 ///
 /// `/register-calendar` コマンドなどで使用される、
 /// Google Calendarメールアドレスを登録するモーダル
-pub fn create() -> SlackView {
+///
+/// # 引数
+/// * `messages` - 表示に使う言語の文言
+pub fn create(messages: &Messages) -> SlackView {
     let blocks = vec![
-        SlackBlock::Section(
-            SlackSectionBlock::new()
-                .with_text(md!("Googleカレンダーと連携するためのメールアドレスを登録してください。\n登録されたアドレスに、カレンダーへのアクセス権が自動的に付与されます。"))
-        ),
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(messages.register_intro))),
         SlackBlock::Input(
             SlackInputBlock::new(
-                pt!("メールアドレス"),
+                pt!(messages.email_address),
                 SlackInputBlockElement::PlainTextInput(
-                    SlackBlockPlainTextInputElement::new(
-                        SlackActionId::new(ACTION_EMAIL_INPUT.to_string())
-                    )
-                    .with_placeholder(pt!("your-email@gmail.com"))
-                )
+                    SlackBlockPlainTextInputElement::new(SlackActionId::new(
+                        ACTION_EMAIL_INPUT.to_string(),
+                    ))
+                    .with_placeholder(pt!("your-email@gmail.com")),
+                ),
             )
-            .with_block_id(SlackBlockId::new(ACTION_EMAIL_INPUT.to_string()))
+            .with_block_id(SlackBlockId::new(ACTION_EMAIL_INPUT.to_string())),
         ),
     ];
 
     SlackView::Modal(
-        SlackModalView::new(pt!("メールアドレスの登録"), blocks)
+        SlackModalView::new(pt!(messages.register_title), blocks)
             .with_callback_id(CALLBACK_REGISTER_EMAIL.into())
-            .with_submit(pt!("登録"))
-            .with_close(pt!("キャンセル")),
+            .with_submit(pt!(messages.register_submit))
+            .with_close(pt!(messages.cancel)),
     )
 }
@@ -1,56 +1,383 @@
 //! リソース予約モーダルビルダー
 
-use crate::infrastructure::config::ResourceConfig;
+use crate::domain::aggregates::device_health::{DeviceHealth, DeviceStatus};
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::{
+    Priority, RecurrenceFrequency, RecurrenceRule, ReservationMetadata, Resource, TimePeriod,
+    Visibility,
+};
+use crate::infrastructure::config::resource_config::ServerConfig;
+use crate::infrastructure::config::{ResourceConfig, ResourceTypeConfig};
+use crate::infrastructure::i18n::{Messages, fill};
 use crate::interface::slack::constants::*;
-use chrono::{Local, Timelike};
+use crate::interface::slack::utility::datetime_parser::to_user_time;
+use crate::interface::slack::utility::device_availability::BusyDevice;
+use crate::interface::slack::utility::user_resolver::UserPreferences;
+use chrono::{DateTime, FixedOffset, Utc};
+use chrono_tz::Tz;
 use slack_morphism::prelude::*;
 
+/// 日時の入力欄の状態
+///
+/// Slackはモーダルを更新しても、ブロックIDが同じ入力欄では利用者の入力値を保つ。
+/// 入力済みの日時を置き換えるときは版を上げて、日時の入力欄のブロックIDを変える。
+#[derive(Debug, Clone, Default)]
+pub struct DateTimeInputs {
+    /// 入力欄のブロックIDの版
+    pub revision: u32,
+    /// 初期値にする期間（`None` の場合は現在時刻から1時間）
+    pub period: Option<TimePeriod>,
+    /// 日時の入力欄の下に表示する案内
+    pub note: Option<String>,
+}
+
+/// モーダルの入力欄に表示する初期値
+struct InitialValues {
+    /// 利用者のタイムゾーンでの開始日時
+    start: DateTime<FixedOffset>,
+    /// 利用者のタイムゾーンでの終了日時
+    end: DateTime<FixedOffset>,
+    /// 日時を表示するタイムゾーン（`None` の場合はシステムのローカルタイムゾーン）
+    timezone: Option<Tz>,
+    /// 表示言語のメッセージカタログ
+    messages: &'static Messages,
+    /// チェック済みにするGPU（サーバー名, デバイス番号）
+    gpus: Vec<(String, u32)>,
+    /// 選択済みにする部屋名
+    room: Option<String>,
+    /// 選択済みにする機器名
+    instrument: Option<String>,
+    /// 選択済みにする設定で定義した種別のリソース名
+    custom: Option<String>,
+    notes: Option<String>,
+    /// 入力済みにするメタデータ（プロジェクト名・実験ID・想定使用率）
+    metadata: ReservationMetadata,
+    private: bool,
+    /// 日時の入力欄のブロックIDの版
+    datetime_revision: u32,
+    /// 日時の入力欄の下に表示する案内
+    datetime_note: Option<String>,
+    /// デバイスを絞り込むタグ
+    tag: Option<String>,
+}
+
+impl InitialValues {
+    /// 新規予約用（現在時刻から1時間）
+    fn for_new_reservation(preferences: &UserPreferences) -> Self {
+        let start = to_user_time(Utc::now(), preferences.timezone);
+        Self {
+            start,
+            end: start + chrono::Duration::hours(1),
+            timezone: preferences.timezone,
+            messages: preferences.messages(),
+            gpus: Vec::new(),
+            room: None,
+            instrument: None,
+            custom: None,
+            notes: None,
+            metadata: ReservationMetadata::default(),
+            private: false,
+            datetime_revision: 0,
+            datetime_note: None,
+            tag: None,
+        }
+    }
+
+    /// 日時の入力欄の状態を反映
+    fn with_datetime(mut self, datetime: &DateTimeInputs) -> Self {
+        if let Some(period) = &datetime.period {
+            self.start = to_user_time(period.start(), self.timezone);
+            self.end = to_user_time(period.end(), self.timezone);
+        }
+        self.datetime_revision = datetime.revision;
+        self.datetime_note = datetime.note.clone();
+        self
+    }
+
+    /// デバイスを絞り込むタグを反映
+    fn with_tag(mut self, tag: Option<&str>) -> Self {
+        self.tag = tag.map(str::to_string);
+        self
+    }
+
+    /// 選択済みにするリソースを反映
+    fn with_resources(mut self, resources: &[Resource]) -> Self {
+        self.gpus = resources
+            .iter()
+            .filter_map(|resource| match resource {
+                Resource::Gpu(gpu) => Some((gpu.server().to_string(), gpu.device_number())),
+                _ => None,
+            })
+            .collect();
+        self.room = resources.iter().find_map(|resource| match resource {
+            Resource::Room { name } => Some(name.clone()),
+            _ => None,
+        });
+        self.instrument = resources.iter().find_map(|resource| match resource {
+            Resource::Instrument { name } => Some(name.clone()),
+            _ => None,
+        });
+        self.custom = resources.iter().find_map(|resource| match resource {
+            Resource::Custom { name, .. } => Some(name.clone()),
+            _ => None,
+        });
+        self
+    }
+
+    /// 既存の予約の内容
+    fn from_usage(usage: &ResourceUsage, preferences: &UserPreferences) -> Self {
+        Self {
+            start: to_user_time(usage.time_period().start(), preferences.timezone),
+            end: to_user_time(usage.time_period().end(), preferences.timezone),
+            notes: usage.notes().cloned(),
+            metadata: usage.metadata().clone(),
+            private: usage.visibility() == Visibility::Private,
+            ..Self::for_new_reservation(preferences)
+        }
+        .with_resources(usage.resources())
+    }
+}
+
+/// 既存の予約を編集するモーダルを作成
+///
+/// 日時・サーバー・デバイス・部屋・プロジェクト情報・備考・公開範囲に予約の現在の内容を入力した状態で開く。
+/// 複数のサーバーのGPUを含む予約は、それぞれのサーバーのデバイス選択を開いた状態にする。
+///
+/// # 引数
+/// * `config` - リソース設定
+/// * `usage` - 編集する予約
+/// * `preferences` - 予約者の表示設定（日時を表示するタイムゾーン・表示言語）
+/// * `busy_devices` - 予約の期間に別の予約で使用中のデバイス
+/// * `device_healths` - 状態が登録されているデバイス
+///
+/// # 戻り値
+/// 予約更新フォームのモーダルビュー
+pub fn create_edit_modal(
+    config: &ResourceConfig,
+    usage: &ResourceUsage,
+    preferences: &UserPreferences,
+    busy_devices: &[BusyDevice],
+    device_healths: &[DeviceHealth],
+) -> SlackView {
+    let messages = preferences.messages();
+    let resource_type = resource_type_of(usage.resources());
+    let open_servers = gpu_servers_of(usage.resources());
+
+    SlackView::Modal(
+        build_modal(
+            config,
+            &resource_type,
+            &open_servers,
+            &InitialValues::from_usage(usage, preferences),
+            busy_devices,
+            device_healths,
+            Some(usage.id().as_str()),
+        )
+        .with_callback_id(CALLBACK_RESERVE_UPDATE.into())
+        .with_title(pt!(messages.update_title))
+        .with_submit(pt!(messages.update_submit)),
+    )
+}
+
+/// 予約と同じリソースを選択した状態で新規予約用のモーダルを作成
+///
+/// 「もう一度予約」ボタンから開く。日時は空いている次の時間帯を入力し、備考などは空にする。
+///
+/// # 引数
+/// * `config` - リソース設定
+/// * `resources` - 選択済みにするリソース
+/// * `preferences` - 利用者の表示設定
+/// * `busy_devices` - 入力する期間に使用中のデバイス
+/// * `device_healths` - 状態が登録されているデバイス
+/// * `datetime` - 日時の入力欄の状態（空いている時間帯とその案内）
+pub fn create_clone_modal(
+    config: &ResourceConfig,
+    resources: &[Resource],
+    preferences: &UserPreferences,
+    busy_devices: &[BusyDevice],
+    device_healths: &[DeviceHealth],
+    datetime: &DateTimeInputs,
+) -> SlackView {
+    let messages = preferences.messages();
+    SlackView::Modal(
+        build_modal(
+            config,
+            &resource_type_of(resources),
+            &gpu_servers_of(resources),
+            &InitialValues::for_new_reservation(preferences)
+                .with_resources(resources)
+                .with_datetime(datetime),
+            busy_devices,
+            device_healths,
+            None,
+        )
+        .with_callback_id(CALLBACK_RESERVE_SUBMIT.into())
+        .with_title(pt!(messages.reserve_title))
+        .with_submit(pt!(messages.reserve_submit)),
+    )
+}
+
+/// リソースに対応する予約フォームのリソースタイプ（最初のリソースで決める）
+fn resource_type_of(resources: &[Resource]) -> String {
+    match resources.first() {
+        Some(Resource::Room { .. }) => "room".to_string(),
+        Some(Resource::Instrument { .. }) => "instrument".to_string(),
+        Some(Resource::Custom { kind, .. }) => custom_resource_type(kind),
+        Some(Resource::Gpu(_))
+        | Some(Resource::Storage { .. })
+        | Some(Resource::License { .. })
+        | None => "gpu".to_string(),
+    }
+}
+
+/// デバイス選択を開くサーバー名（GPUを含むサーバーを重複なく順に）
+fn gpu_servers_of(resources: &[Resource]) -> Vec<&str> {
+    let mut servers: Vec<&str> = Vec::new();
+    for resource in resources {
+        if let Resource::Gpu(gpu) = resource
+            && !servers.contains(&gpu.server())
+        {
+            servers.push(gpu.server());
+        }
+    }
+    servers
+}
+
 /// 予約作成・更新用のモーダルを作成
 ///
 /// # 引数
 /// * `config` - リソース設定
-/// * `resource_type` - 選択されたリソースタイプ ("gpu" or "room")
-/// * `selected_server` - 選択されたサーバー名（GPU選択時のみ）
+/// * `resource_type` - 選択されたリソースタイプ ("gpu", "room", "instrument" or "custom:<種別ID>")
+/// * `open_servers` - デバイス選択を開くサーバー名（GPU選択時のみ。最後のものをサーバー選択の初期値にし、空の場合は最初のサーバーを開く）
 /// * `usage_id` - 更新対象の予約ID（Noneの場合は新規作成）
 /// * `callback_id` - モーダルのコールバックID（デフォルト: "reserve_submit"）
 /// * `title` - モーダルのタイトル（デフォルト: "リソース予約"）
 /// * `submit_text` - 送信ボタンのテキスト（デフォルト: "予約する"）
+/// * `preferences` - 利用者の表示設定（日時の初期値に使うタイムゾーン・表示言語）
+/// * `busy_devices` - 入力中の期間に使用中のデバイス（デバイスの選択肢に使用状況を表示する）
+/// * `device_healths` - 状態が登録されているデバイス（使用停止中のデバイスは選択肢に表示しない）
 ///
 /// # 戻り値
 /// 予約フォームのモーダルビュー
+#[allow(clippy::too_many_arguments)]
 pub fn create_reserve_modal(
     config: &ResourceConfig,
     resource_type: Option<&str>,
-    selected_server: Option<&str>,
+    open_servers: &[&str],
     usage_id: Option<&str>,
     callback_id: Option<&str>,
     title: Option<&str>,
     submit_text: Option<&str>,
+    preferences: &UserPreferences,
+    busy_devices: &[BusyDevice],
+    device_healths: &[DeviceHealth],
 ) -> SlackView {
-    // 現在時刻を取得してデフォルト値を設定
-    let now = Local::now();
-    let start_date = now.format("%Y-%m-%d").to_string();
-    let start_time = format!("{:02}:{:02}", now.hour(), now.minute());
-
-    let end = now + chrono::Duration::hours(1);
-    let end_date = end.format("%Y-%m-%d").to_string();
-    let end_time = format!("{:02}:{:02}", end.hour(), end.minute());
-
     // 現在選択中のリソースタイプ (デフォルトは "gpu")
     let current_resource_type = resource_type.unwrap_or("gpu");
 
-    // リソースタイプ選択肢（GPU or Room）
-    let resource_type_options = vec![
+    let modal_view = build_modal(
+        config,
+        current_resource_type,
+        open_servers,
+        &InitialValues::for_new_reservation(preferences),
+        busy_devices,
+        device_healths,
+        usage_id,
+    );
+
+    // モーダルの設定
+    let callback_id = callback_id.unwrap_or(CALLBACK_RESERVE_SUBMIT);
+    let messages = preferences.messages();
+    let title = title.unwrap_or(messages.reserve_title);
+    let submit_text = submit_text.unwrap_or(messages.reserve_submit);
+
+    SlackView::Modal(
+        modal_view
+            .with_callback_id(callback_id.into())
+            .with_title(pt!(title))
+            .with_submit(pt!(submit_text)),
+    )
+}
+
+/// 日時の入力欄の状態を指定して新規予約用のモーダルを作成
+///
+/// 入力途中のモーダルを作り直すときに使う。
+///
+/// # 引数
+/// * `config` - リソース設定
+/// * `resource_type` - 選択されたリソースタイプ ("gpu", "room", "instrument" or "custom:<種別ID>")
+/// * `open_servers` - デバイス選択を開くサーバー名
+/// * `preferences` - 利用者の表示設定
+/// * `busy_devices` - 入力中の期間に使用中のデバイス
+/// * `device_healths` - 状態が登録されているデバイス
+/// * `datetime` - 日時の入力欄の状態
+/// * `tag` - デバイスを絞り込むタグ
+#[allow(clippy::too_many_arguments)]
+pub fn create_reserve_modal_with_datetime(
+    config: &ResourceConfig,
+    resource_type: Option<&str>,
+    open_servers: &[&str],
+    preferences: &UserPreferences,
+    busy_devices: &[BusyDevice],
+    device_healths: &[DeviceHealth],
+    datetime: &DateTimeInputs,
+    tag: Option<&str>,
+) -> SlackView {
+    let messages = preferences.messages();
+    SlackView::Modal(
+        build_modal(
+            config,
+            resource_type.unwrap_or("gpu"),
+            open_servers,
+            &InitialValues::for_new_reservation(preferences)
+                .with_datetime(datetime)
+                .with_tag(tag),
+            busy_devices,
+            device_healths,
+            None,
+        )
+        .with_callback_id(CALLBACK_RESERVE_SUBMIT.into())
+        .with_title(pt!(messages.reserve_title))
+        .with_submit(pt!(messages.reserve_submit)),
+    )
+}
+
+/// 予約フォームのモーダルを組み立てる（コールバックID・タイトル・送信ボタンは呼び出し側で設定）
+fn build_modal(
+    config: &ResourceConfig,
+    current_resource_type: &str,
+    open_servers: &[&str],
+    initial: &InitialValues,
+    busy_devices: &[BusyDevice],
+    device_healths: &[DeviceHealth],
+    usage_id: Option<&str>,
+) -> SlackModalView {
+    let messages = initial.messages;
+
+    // リソースタイプ選択肢（GPU or Room、機器が設定されている場合は Instrument も）
+    let mut resource_type_options = vec![
         SlackBlockChoiceItem::new(pt!("GPU Server"), "gpu".into()),
         SlackBlockChoiceItem::new(pt!("Room"), "room".into()),
     ];
+    if !config.instruments.is_empty() {
+        resource_type_options.push(SlackBlockChoiceItem::new(
+            pt!("Instrument"),
+            "instrument".into(),
+        ));
+    }
+    for resource_type in &config.resource_types {
+        resource_type_options.push(SlackBlockChoiceItem::new(
+            pt!(resource_type.label.clone()),
+            custom_resource_type(&resource_type.id),
+        ));
+    }
 
     // 初期選択値を決定
-    let initial_resource_type = if current_resource_type == "room" {
-        SlackBlockChoiceItem::new(pt!("Room"), "room".into())
-    } else {
-        SlackBlockChoiceItem::new(pt!("GPU Server"), "gpu".into())
-    };
+    let initial_resource_type = resource_type_options
+        .iter()
+        .find(|option| option.value == current_resource_type)
+        .unwrap_or(&resource_type_options[0])
+        .clone();
 
     // モーダルのブロックを動的に構築
     let mut blocks: Vec<SlackBlock> = vec![];
@@ -58,7 +385,7 @@ pub fn create_reserve_modal(
     // リソースタイプ選択（常に表示）
     blocks.push(SlackBlock::Input(
         SlackInputBlock::new(
-            pt!("リソースタイプ"),
+            pt!(messages.resource_type),
             SlackInputBlockElement::RadioButtons(
                 SlackBlockRadioButtonsElement::new(
                     SlackActionId::new(ACTION_RESERVE_RESOURCE_TYPE.to_string()),
@@ -72,82 +399,245 @@ pub fn create_reserve_modal(
 
     // リソースタイプに応じて条件分岐
     if current_resource_type == "gpu" {
-        add_gpu_blocks(&mut blocks, config, selected_server);
+        add_gpu_blocks(
+            &mut blocks,
+            config,
+            open_servers,
+            initial,
+            busy_devices,
+            device_healths,
+        );
     } else if current_resource_type == "room" {
-        add_room_blocks(&mut blocks, config);
+        add_room_blocks(&mut blocks, messages, config, initial.room.as_deref());
+    } else if current_resource_type == "instrument" {
+        add_instrument_blocks(&mut blocks, messages, config, initial.instrument.as_deref());
+    } else if let Some(resource_type) =
+        custom_kind(current_resource_type).and_then(|kind| config.get_resource_type(kind))
+    {
+        add_custom_blocks(
+            &mut blocks,
+            messages,
+            resource_type,
+            initial.custom.as_deref(),
+        );
     }
 
     // 日時フィールド（常に表示）
-    add_datetime_blocks(&mut blocks, &start_date, &start_time, &end_date, &end_time);
+    add_datetime_blocks(&mut blocks, initial);
+
+    // 空いている時間の提案（新規作成時のみ）
+    if usage_id.is_none() {
+        add_suggest_time_blocks(&mut blocks, initial);
+    }
+
+    // 繰り返し（新規作成時のみ）
+    if usage_id.is_none() {
+        add_repeat_blocks(&mut blocks, messages);
+    }
+
+    // 予約を所有するチーム（新規作成時のみ）
+    if usage_id.is_none() && !config.groups.is_empty() {
+        add_group_blocks(&mut blocks, messages, config);
+    }
+
+    // 優先度と横取り（新規作成時のみ）
+    if usage_id.is_none() {
+        add_priority_blocks(&mut blocks, messages);
+    }
+
+    // プロジェクト名・実験ID・想定使用率（常に表示、オプション）
+    add_metadata_blocks(&mut blocks, messages, &initial.metadata);
 
     // 備考（常に表示、オプション）
+    let mut notes_element =
+        SlackBlockPlainTextInputElement::new(SlackActionId::new(ACTION_RESERVE_NOTES.to_string()))
+            .with_multiline(true);
+    if let Some(notes) = &initial.notes {
+        notes_element = notes_element.with_initial_value(notes.clone());
+    }
     blocks.push(SlackBlock::Input(
         SlackInputBlock::new(
-            pt!("備考"),
-            SlackInputBlockElement::PlainTextInput(
-                SlackBlockPlainTextInputElement::new(SlackActionId::new(
-                    ACTION_RESERVE_NOTES.to_string(),
-                ))
-                .with_multiline(true),
-            ),
+            pt!(messages.notes),
+            SlackInputBlockElement::PlainTextInput(notes_element),
         )
         .with_optional(true),
     ));
 
-    // モーダルの作成
-    let callback_id = callback_id.unwrap_or(CALLBACK_RESERVE_SUBMIT);
-    let title = title.unwrap_or("リソース予約");
-    let submit_text = submit_text.unwrap_or("予約する");
+    // 公開範囲（常に表示、オプション）
+    let private_option: SlackBlockChoiceItem<SlackBlockText> = SlackBlockChoiceItem::new(
+        pt!(messages.make_private),
+        RESERVE_PRIVATE_OPTION_VALUE.into(),
+    );
+    let mut private_element = SlackBlockCheckboxesElement::new(
+        SlackActionId::new(ACTION_RESERVE_PRIVATE.to_string()),
+        vec![private_option.clone()],
+    );
+    if initial.private {
+        private_element = private_element.with_initial_options(vec![private_option]);
+    }
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.visibility),
+            SlackInputBlockElement::Checkboxes(private_element),
+        )
+        .with_hint(pt!(messages.private_hint))
+        .with_optional(true),
+    ));
 
-    let mut modal_view = SlackModalView::new(pt!(title), blocks)
-        .with_callback_id(callback_id.into())
-        .with_submit(pt!(submit_text))
-        .with_close(pt!("キャンセル"));
+    let mut modal_view =
+        SlackModalView::new(pt!(messages.reserve_title), blocks).with_close(pt!(messages.cancel));
 
     // usage_idがあればprivate_metadataに設定
     if let Some(id) = usage_id {
         modal_view = modal_view.with_private_metadata(id.into());
     }
 
-    SlackView::Modal(modal_view)
+    modal_view
 }
 
 /// サーバーのデバイスリストから選択肢を生成
+///
+/// 使用中のデバイスには、いつまで誰が使っているかを併記する。
+/// 使用停止中のデバイスとタグの付いていないデバイスは表示せず（編集中の予約に含まれるものを除く）、
+/// 性能低下中のデバイスには印を付ける。
 fn create_device_options(
-    server: &crate::infrastructure::config::resource_config::ServerConfig,
+    server: &ServerConfig,
+    initial: &InitialValues,
+    tag: Option<&str>,
+    busy_devices: &[BusyDevice],
+    device_healths: &[DeviceHealth],
 ) -> Vec<SlackBlockChoiceItem<SlackBlockText>> {
+    let status_of = |device_id: u32| {
+        device_healths
+            .iter()
+            .find(|health| health.server_name() == server.name && health.device_id() == device_id)
+            .map_or(DeviceStatus::Available, DeviceHealth::status)
+    };
     server
         .devices
         .iter()
+        .filter(|device| {
+            let selectable = status_of(device.id) != DeviceStatus::OutOfService
+                && tag.is_none_or(|tag| server.device_has_tag(device, tag));
+            selectable
+                || initial
+                    .gpus
+                    .iter()
+                    .any(|(name, id)| *name == server.name && *id == device.id)
+        })
         .map(|device| {
-            SlackBlockChoiceItem::new(
-                SlackBlockText::Plain(SlackBlockPlainText::from(format!(
-                    "Device {} ({})",
-                    device.id, device.model
+            let mut label = format!("Device {} ({})", device.id, device.model);
+            if status_of(device.id) == DeviceStatus::Degraded {
+                label = fill(initial.messages.device_degraded, &[("device", &label)]);
+            }
+            let busy = busy_devices
+                .iter()
+                .find(|busy| busy.server == server.name && busy.device == device.id);
+            let text = match busy {
+                Some(busy) => SlackBlockText::MarkDown(SlackBlockMarkDownText::new(busy_label(
+                    &label, busy, initial,
                 ))),
-                device.id.to_string(),
-            )
+                None => SlackBlockText::Plain(SlackBlockPlainText::from(label)),
+            };
+            SlackBlockChoiceItem::new(text, device.id.to_string())
         })
         .collect()
 }
 
-/// GPUサーバー選択ブロックを追加
+/// 使用中のデバイスの表示（例: "Device 0 (A100) — busy until 18:00 by @taro"）
+///
+/// 終了時刻が今日でない場合は日付も表示する。
+fn busy_label(label: &str, busy: &BusyDevice, initial: &InitialValues) -> String {
+    let until = to_user_time(busy.until, initial.timezone);
+    let today = to_user_time(Utc::now(), initial.timezone).date_naive();
+    let until = if until.date_naive() == today {
+        until.format("%H:%M").to_string()
+    } else {
+        until.format("%m/%d %H:%M").to_string()
+    };
+
+    match &busy.owner {
+        Some(owner) => fill(
+            initial.messages.device_busy,
+            &[("device", label), ("until", &until), ("owner", owner)],
+        ),
+        None => fill(
+            initial.messages.device_busy_private,
+            &[("device", label), ("until", &until)],
+        ),
+    }
+}
+
+/// GPUデバイス選択ブロックのブロックID
+///
+/// サーバーごとにデバイス選択ブロックを分けるため、サーバー名を含める。
+pub fn devices_block_id(server_name: &str) -> String {
+    format!("{}_{}", ACTION_RESERVE_DEVICES, server_name)
+}
+
+/// モーダルでデバイス選択が開かれているサーバー名を取得（設定の順）
+///
+/// サーバー選択の変更でモーダルを作り直すときに、それまでに開いていたサーバーを引き継ぐために使う。
+pub fn open_servers<'a>(config: &'a ResourceConfig, view: &SlackView) -> Vec<&'a str> {
+    let SlackView::Modal(modal_view) = view else {
+        return Vec::new();
+    };
+    let block_ids: Vec<String> = modal_view
+        .blocks
+        .iter()
+        .filter_map(|block| match block {
+            SlackBlock::Input(input) => input.block_id.as_ref().map(|id| id.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    config
+        .servers
+        .iter()
+        .map(|server| server.name.as_str())
+        .filter(|name| block_ids.contains(&devices_block_id(name)))
+        .collect()
+}
+
+/// GPUサーバー選択ブロックと、開いているサーバーごとのデバイス選択ブロックを追加
+///
+/// サーバーを選択するたびにそのサーバーのデバイス選択が追加され、複数のサーバーのGPUをまとめて予約できる。
 fn add_gpu_blocks(
     blocks: &mut Vec<SlackBlock>,
     config: &ResourceConfig,
-    selected_server: Option<&str>,
+    open_servers: &[&str],
+    initial: &InitialValues,
+    busy_devices: &[BusyDevice],
+    device_healths: &[DeviceHealth],
 ) {
+    let messages = initial.messages;
+    let checked_gpus = &initial.gpus;
+
     // サーバー設定が空の場合はエラーメッセージを表示
     if config.servers.is_empty() {
-        blocks.push(SlackBlock::Section(SlackSectionBlock::new().with_text(
-            md!("⚠️ サーバー設定が見つかりません。管理者に問い合わせてください。"),
-        )));
+        blocks.push(SlackBlock::Section(
+            SlackSectionBlock::new().with_text(md!(messages.no_servers)),
+        ));
         return;
     }
 
-    // GPU Server選択肢
-    let server_options: Vec<SlackBlockChoiceItem<SlackBlockPlainTextOnly>> = config
+    // タグで絞り込む（タグが設定されている場合のみ）
+    let tags = config.tags();
+    let tag = initial
+        .tag
+        .as_deref()
+        .filter(|tag| tags.iter().any(|t| t.eq_ignore_ascii_case(tag)));
+    if !tags.is_empty() {
+        add_tag_blocks(blocks, messages, &tags, tag);
+    }
+    let servers: Vec<&ServerConfig> = config
         .servers
+        .iter()
+        .filter(|server| tag.is_none_or(|tag| server.has_tag(tag)))
+        .collect();
+
+    // GPU Server選択肢
+    let server_options: Vec<SlackBlockChoiceItem<SlackBlockPlainTextOnly>> = servers
         .iter()
         .map(|server| SlackBlockChoiceItem::new(pt!(server.name.clone()), server.name.clone()))
         .collect();
@@ -156,13 +646,15 @@ fn add_gpu_blocks(
     let mut server_select_element = SlackBlockStaticSelectElement::new(SlackActionId::new(
         ACTION_RESERVE_SERVER_SELECT.to_string(),
     ))
-    .with_placeholder(pt!("サーバーを選択"))
+    .with_placeholder(pt!(messages.select_server))
     .with_options(server_options.clone());
 
     // デフォルト値を設定
-    // 既に選択されている場合はそれを、そうでない場合は最初のサーバーを選択
-    let default_server_name =
-        selected_server.or_else(|| config.servers.first().map(|s| s.name.as_str()));
+    // 最後に開いたサーバーを、開いていない場合は最初のサーバーを選択
+    let default_server_name = open_servers
+        .last()
+        .copied()
+        .or_else(|| servers.first().map(|s| s.name.as_str()));
 
     if let Some(server_name) = default_server_name {
         let initial_server =
@@ -175,50 +667,119 @@ fn add_gpu_blocks(
             pt!("GPU Server"),
             SlackInputBlockElement::StaticSelect(server_select_element),
         )
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_SERVER_SELECT.to_string()))
+        .with_hint(pt!(messages.server_hint))
         .with_dispatch_action(true),
     ));
 
-    // デバイス選択肢（選択されたサーバーに応じて変更）
-    let device_options: Vec<SlackBlockChoiceItem<SlackBlockText>> =
-        if let Some(server_name) = selected_server {
-            // 特定のサーバーのデバイスを表示
-            config
-                .servers
-                .iter()
-                .find(|s| s.name == server_name)
-                .map(create_device_options)
-                .unwrap_or_default()
-        } else {
-            // サーバー未選択の場合は最初のサーバーのデバイスを表示
-            config
-                .servers
-                .first()
-                .map(create_device_options)
-                .unwrap_or_default()
-        };
+    // デバイス選択（開いているサーバーごとのチェックボックス）
+    for server in servers.iter().filter(|server| {
+        open_servers.contains(&server.name.as_str())
+            || (open_servers.is_empty() && default_server_name == Some(server.name.as_str()))
+    }) {
+        let device_options =
+            create_device_options(server, initial, tag, busy_devices, device_healths);
+        if device_options.is_empty() {
+            continue;
+        }
 
-    // GPU Device選択（チェックボックス）
-    if !device_options.is_empty() {
+        let initial_options: Vec<SlackBlockChoiceItem<SlackBlockText>> = device_options
+            .iter()
+            .filter(|option| {
+                option.value.parse::<u32>().is_ok_and(|id| {
+                    checked_gpus
+                        .iter()
+                        .any(|(name, device)| *name == server.name && *device == id)
+                })
+            })
+            .cloned()
+            .collect();
+        let mut devices_element = SlackBlockCheckboxesElement::new(
+            SlackActionId::new(ACTION_RESERVE_DEVICES.to_string()),
+            device_options,
+        );
+        if !initial_options.is_empty() {
+            devices_element = devices_element.with_initial_options(initial_options);
+        }
         blocks.push(SlackBlock::Input(
             SlackInputBlock::new(
-                pt!("GPU Devices"),
-                SlackInputBlockElement::Checkboxes(SlackBlockCheckboxesElement::new(
-                    SlackActionId::new(ACTION_RESERVE_DEVICES.to_string()),
-                    device_options,
-                )),
+                pt!(format!("GPU Devices ({})", server.name)),
+                SlackInputBlockElement::Checkboxes(devices_element),
             )
+            .with_block_id(SlackBlockId::new(devices_block_id(&server.name)))
             .with_optional(true),
         ));
     }
 }
 
+/// デバイスをタグで絞り込む入力欄を追加
+///
+/// タグを選ぶとモーダルを作り直し、タグの付いたデバイスを持つサーバーとデバイスだけを表示する。
+fn add_tag_blocks(
+    blocks: &mut Vec<SlackBlock>,
+    messages: &Messages,
+    tags: &[&str],
+    selected: Option<&str>,
+) {
+    let any = SlackBlockChoiceItem::new(pt!(messages.tag_any), RESERVE_TAG_ANY_VALUE.to_string());
+    let mut options = vec![any.clone()];
+    options.extend(
+        tags.iter()
+            .map(|tag| SlackBlockChoiceItem::new(pt!(tag.to_string()), tag.to_string())),
+    );
+    let initial = selected
+        .and_then(|selected| {
+            options
+                .iter()
+                .find(|option| option.value.eq_ignore_ascii_case(selected))
+                .cloned()
+        })
+        .unwrap_or(any);
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.tag_filter),
+            SlackInputBlockElement::StaticSelect(
+                SlackBlockStaticSelectElement::new(SlackActionId::new(
+                    ACTION_RESERVE_TAG.to_string(),
+                ))
+                .with_options(options)
+                .with_initial_option(initial),
+            ),
+        )
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_TAG.to_string()))
+        .with_hint(pt!(messages.tag_filter_hint))
+        .with_dispatch_action(true)
+        .with_optional(true),
+    ));
+}
+
+/// 入力途中のモーダルで選ばれているデバイスを絞り込むタグを取得
+///
+/// タグで絞り込まない場合は `None` を返す。
+pub fn selected_tag(state: &SlackViewState) -> Option<&str> {
+    let action_id = SlackActionId::new(ACTION_RESERVE_TAG.to_string());
+    state
+        .values
+        .values()
+        .find_map(|actions| actions.get(&action_id))
+        .and_then(|value| value.selected_option.as_ref())
+        .map(|option| option.value.as_str())
+        .filter(|value| *value != RESERVE_TAG_ANY_VALUE)
+}
+
 /// Room選択ブロックを追加
-fn add_room_blocks(blocks: &mut Vec<SlackBlock>, config: &ResourceConfig) {
+fn add_room_blocks(
+    blocks: &mut Vec<SlackBlock>,
+    messages: &Messages,
+    config: &ResourceConfig,
+    selected_room: Option<&str>,
+) {
     // 部屋設定が空の場合はエラーメッセージを表示
     if config.rooms.is_empty() {
-        blocks.push(SlackBlock::Section(SlackSectionBlock::new().with_text(
-            md!("⚠️ 部屋設定が見つかりません。管理者に問い合わせてください。"),
-        )));
+        blocks.push(SlackBlock::Section(
+            SlackSectionBlock::new().with_text(md!(messages.no_rooms)),
+        ));
         return;
     }
 
@@ -233,67 +794,777 @@ fn add_room_blocks(blocks: &mut Vec<SlackBlock>, config: &ResourceConfig) {
     let mut room_select_element = SlackBlockStaticSelectElement::new(SlackActionId::new(
         ACTION_RESERVE_ROOM_SELECT.to_string(),
     ))
-    .with_placeholder(pt!("部屋を選択"))
+    .with_placeholder(pt!(messages.select_room))
     .with_options(room_options.clone());
 
-    // デフォルト値を設定（最初の部屋を選択）
-    if let Some(first_room) = config.rooms.first() {
-        let initial_room =
-            SlackBlockChoiceItem::new(pt!(first_room.name.clone()), first_room.name.clone());
+    // デフォルト値を設定（選択済みの部屋、なければ最初の部屋を選択）
+    let initial_room = selected_room
+        .and_then(|name| config.rooms.iter().find(|room| room.name == name))
+        .or_else(|| config.rooms.first());
+    if let Some(room) = initial_room {
+        let initial_room = SlackBlockChoiceItem::new(pt!(room.name.clone()), room.name.clone());
         room_select_element = room_select_element.with_initial_option(initial_room);
     }
 
-    blocks.push(SlackBlock::Input(SlackInputBlock::new(
-        pt!("Room"),
-        SlackInputBlockElement::StaticSelect(room_select_element),
-    )));
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!("Room"),
+            SlackInputBlockElement::StaticSelect(room_select_element),
+        )
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_ROOM_SELECT.to_string())),
+    ));
 }
 
-/// 日時選択ブロックを追加
-fn add_datetime_blocks(
+/// 機器選択ブロックを追加
+fn add_instrument_blocks(
     blocks: &mut Vec<SlackBlock>,
-    start_date: &str,
-    start_time: &str,
-    end_date: &str,
-    end_time: &str,
+    messages: &Messages,
+    config: &ResourceConfig,
+    selected_instrument: Option<&str>,
 ) {
-    blocks.push(SlackBlock::Input(SlackInputBlock::new(
-        pt!("開始日"),
-        SlackInputBlockElement::DatePicker(
-            SlackBlockDatePickerElement::new(SlackActionId::new(
-                ACTION_RESERVE_START_DATE.to_string(),
-            ))
-            .with_initial_date(start_date.to_string()),
-        ),
-    )));
-
-    blocks.push(SlackBlock::Input(SlackInputBlock::new(
-        pt!("開始時刻"),
-        SlackInputBlockElement::TimePicker(
-            SlackBlockTimePickerElement::new(SlackActionId::new(
-                ACTION_RESERVE_START_TIME.to_string(),
-            ))
-            .with_initial_time(start_time.to_string()),
-        ),
-    )));
-
-    blocks.push(SlackBlock::Input(SlackInputBlock::new(
-        pt!("終了日"),
-        SlackInputBlockElement::DatePicker(
-            SlackBlockDatePickerElement::new(SlackActionId::new(
-                ACTION_RESERVE_END_DATE.to_string(),
-            ))
-            .with_initial_date(end_date.to_string()),
-        ),
-    )));
-
-    blocks.push(SlackBlock::Input(SlackInputBlock::new(
-        pt!("終了時刻"),
-        SlackInputBlockElement::TimePicker(
-            SlackBlockTimePickerElement::new(SlackActionId::new(
-                ACTION_RESERVE_END_TIME.to_string(),
-            ))
-            .with_initial_time(end_time.to_string()),
+    let instrument_options: Vec<SlackBlockChoiceItem<SlackBlockPlainTextOnly>> = config
+        .instruments
+        .iter()
+        .map(|instrument| {
+            SlackBlockChoiceItem::new(pt!(instrument.name.clone()), instrument.name.clone())
+        })
+        .collect();
+
+    let mut instrument_select_element = SlackBlockStaticSelectElement::new(SlackActionId::new(
+        ACTION_RESERVE_INSTRUMENT_SELECT.to_string(),
+    ))
+    .with_placeholder(pt!(messages.select_instrument))
+    .with_options(instrument_options);
+
+    // デフォルト値を設定（選択済みの機器、なければ最初の機器を選択）
+    let initial_instrument = selected_instrument
+        .and_then(|name| config.get_instrument(name))
+        .or_else(|| config.instruments.first());
+    if let Some(instrument) = initial_instrument {
+        instrument_select_element = instrument_select_element.with_initial_option(
+            SlackBlockChoiceItem::new(pt!(instrument.name.clone()), instrument.name.clone()),
+        );
+    }
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!("Instrument"),
+            SlackInputBlockElement::StaticSelect(instrument_select_element),
+        )
+        .with_block_id(SlackBlockId::new(
+            ACTION_RESERVE_INSTRUMENT_SELECT.to_string(),
+        )),
+    ));
+}
+
+/// 設定で定義した種別のリソース選択ブロックを追加
+fn add_custom_blocks(
+    blocks: &mut Vec<SlackBlock>,
+    messages: &Messages,
+    resource_type: &ResourceTypeConfig,
+    selected: Option<&str>,
+) {
+    let options: Vec<SlackBlockChoiceItem<SlackBlockPlainTextOnly>> = resource_type
+        .resources
+        .iter()
+        .map(|resource| {
+            SlackBlockChoiceItem::new(pt!(resource.name.clone()), resource.name.clone())
+        })
+        .collect();
+
+    let mut select_element = SlackBlockStaticSelectElement::new(SlackActionId::new(
+        ACTION_RESERVE_CUSTOM_SELECT.to_string(),
+    ))
+    .with_placeholder(pt!(fill(
+        messages.select_custom,
+        &[("type", &resource_type.label)]
+    )))
+    .with_options(options);
+
+    // デフォルト値を設定（選択済みのリソース、なければ最初のリソースを選択）
+    let initial = selected
+        .and_then(|name| resource_type.resources.iter().find(|r| r.name == name))
+        .or_else(|| resource_type.resources.first());
+    if let Some(resource) = initial {
+        select_element = select_element.with_initial_option(SlackBlockChoiceItem::new(
+            pt!(resource.name.clone()),
+            resource.name.clone(),
+        ));
+    }
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(resource_type.label.clone()),
+            SlackInputBlockElement::StaticSelect(select_element),
+        )
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_CUSTOM_SELECT.to_string())),
+    ));
+}
+
+/// 設定で定義したリソース種別を表すリソースタイプの値
+pub fn custom_resource_type(kind: &str) -> String {
+    format!("{}{}", RESERVE_CUSTOM_TYPE_PREFIX, kind)
+}
+
+/// リソースタイプの値から設定で定義したリソース種別のIDを取得（それ以外のリソースタイプは `None`）
+pub fn custom_kind(resource_type: &str) -> Option<&str> {
+    resource_type.strip_prefix(RESERVE_CUSTOM_TYPE_PREFIX)
+}
+
+/// 繰り返し設定ブロックを追加
+fn add_repeat_blocks(blocks: &mut Vec<SlackBlock>, messages: &Messages) {
+    let none_option = SlackBlockChoiceItem::new(
+        pt!(messages.repeat_none),
+        RESERVE_REPEAT_NONE_VALUE.to_string(),
+    );
+    let options: Vec<SlackBlockChoiceItem<SlackBlockPlainTextOnly>> =
+        std::iter::once(none_option.clone())
+            .chain(
+                [RecurrenceFrequency::Weekly, RecurrenceFrequency::Biweekly]
+                    .into_iter()
+                    .map(|frequency| {
+                        SlackBlockChoiceItem::new(
+                            pt!(messages.frequency(frequency)),
+                            frequency.as_str().to_string(),
+                        )
+                    }),
+            )
+            .collect();
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.repeat),
+            SlackInputBlockElement::StaticSelect(
+                SlackBlockStaticSelectElement::new(SlackActionId::new(
+                    ACTION_RESERVE_REPEAT.to_string(),
+                ))
+                .with_options(options)
+                .with_initial_option(none_option),
+            ),
+        )
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_REPEAT.to_string()))
+        .with_optional(true),
+    ));
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.repeat_until),
+            SlackInputBlockElement::DatePicker(SlackBlockDatePickerElement::new(
+                SlackActionId::new(ACTION_RESERVE_REPEAT_UNTIL.to_string()),
+            )),
+        )
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_REPEAT_UNTIL.to_string()))
+        .with_hint(pt!(fill(
+            messages.repeat_until_hint,
+            &[("max", &RecurrenceRule::MAX_OCCURRENCES.to_string())]
+        )))
+        .with_optional(true),
+    ));
+}
+
+/// 予約を所有するチームの入力欄を追加
+///
+/// 選ばなければ予約者個人の予約になる。
+fn add_group_blocks(blocks: &mut Vec<SlackBlock>, messages: &Messages, config: &ResourceConfig) {
+    let options: Vec<SlackBlockChoiceItem<SlackBlockPlainTextOnly>> = config
+        .groups
+        .iter()
+        .map(|group| SlackBlockChoiceItem::new(pt!(group.name.clone()), group.id.clone()))
+        .collect();
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.group),
+            SlackInputBlockElement::StaticSelect(
+                SlackBlockStaticSelectElement::new(SlackActionId::new(
+                    ACTION_RESERVE_GROUP.to_string(),
+                ))
+                .with_options(options),
+            ),
+        )
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_GROUP.to_string()))
+        .with_hint(pt!(messages.group_hint))
+        .with_optional(true),
+    ));
+}
+
+/// プロジェクト名・実験ID・想定使用率の入力欄を追加
+fn add_metadata_blocks(
+    blocks: &mut Vec<SlackBlock>,
+    messages: &Messages,
+    metadata: &ReservationMetadata,
+) {
+    for (label, action_id, value) in [
+        (messages.project, ACTION_RESERVE_PROJECT, metadata.project()),
+        (
+            messages.experiment_id,
+            ACTION_RESERVE_EXPERIMENT,
+            metadata.experiment_id(),
         ),
-    )));
+    ] {
+        let mut element =
+            SlackBlockPlainTextInputElement::new(SlackActionId::new(action_id.to_string()));
+        if let Some(value) = value {
+            element = element.with_initial_value(value.to_string());
+        }
+        blocks.push(SlackBlock::Input(
+            SlackInputBlock::new(pt!(label), SlackInputBlockElement::PlainTextInput(element))
+                .with_optional(true),
+        ));
+    }
+
+    let mut utilization = SlackBlockNumberInputElement::new(
+        SlackActionId::new(ACTION_RESERVE_UTILIZATION.to_string()),
+        false,
+    )
+    .with_min_value("0".to_string())
+    .with_max_value(ReservationMetadata::MAX_UTILIZATION.to_string());
+    if let Some(value) = metadata.expected_utilization() {
+        utilization = utilization.with_initial_value(value.to_string());
+    }
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.utilization),
+            SlackInputBlockElement::NumberInput(utilization),
+        )
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_UTILIZATION.to_string()))
+        .with_hint(pt!(messages.utilization_hint))
+        .with_optional(true),
+    ));
+}
+
+/// 優先度と横取りの入力欄を追加
+fn add_priority_blocks(blocks: &mut Vec<SlackBlock>, messages: &Messages) {
+    let options: Vec<SlackBlockChoiceItem<SlackBlockPlainTextOnly>> = Priority::ALL
+        .into_iter()
+        .map(|priority| {
+            SlackBlockChoiceItem::new(
+                pt!(messages.priority(priority)),
+                priority.as_str().to_string(),
+            )
+        })
+        .collect();
+    let normal = SlackBlockChoiceItem::new(
+        pt!(messages.priority(Priority::Normal)),
+        Priority::Normal.as_str().to_string(),
+    );
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.priority),
+            SlackInputBlockElement::StaticSelect(
+                SlackBlockStaticSelectElement::new(SlackActionId::new(
+                    ACTION_RESERVE_PRIORITY.to_string(),
+                ))
+                .with_options(options)
+                .with_initial_option(normal),
+            ),
+        )
+        .with_hint(pt!(messages.priority_hint))
+        .with_optional(true),
+    ));
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.preemption),
+            SlackInputBlockElement::Checkboxes(SlackBlockCheckboxesElement::new(
+                SlackActionId::new(ACTION_RESERVE_PREEMPT.to_string()),
+                vec![SlackBlockChoiceItem::new(
+                    pt!(messages.preempt),
+                    RESERVE_PREEMPT_OPTION_VALUE.into(),
+                )],
+            )),
+        )
+        .with_hint(pt!(messages.preempt_hint))
+        .with_optional(true),
+    ));
+}
+
+/// 日時の入力欄のブロックID
+///
+/// 版が0の場合はアクションIDと同じにする。
+fn datetime_block_id(action_id: &str, revision: u32) -> String {
+    if revision == 0 {
+        action_id.to_string()
+    } else {
+        format!("{}_{}", action_id, revision)
+    }
+}
+
+/// モーダルの日時の入力欄のブロックIDの版を取得
+///
+/// モーダルを作り直すときに同じ版を使うと、利用者が入力した日時が保たれる。
+pub fn datetime_revision(view: &SlackView) -> u32 {
+    let SlackView::Modal(modal_view) = view else {
+        return 0;
+    };
+    modal_view
+        .blocks
+        .iter()
+        .find_map(|block| match block {
+            SlackBlock::Input(input) => input.block_id.as_ref().and_then(|id| {
+                id.to_string()
+                    .strip_prefix(ACTION_RESERVE_START_DATE)
+                    .map(str::to_string)
+            }),
+            _ => None,
+        })
+        .and_then(|suffix| suffix.strip_prefix('_').and_then(|n| n.parse().ok()))
+        .unwrap_or(0)
+}
+
+/// 空いている時間を提案するボタンと、提案の結果を追加
+fn add_suggest_time_blocks(blocks: &mut Vec<SlackBlock>, initial: &InitialValues) {
+    blocks.push(SlackBlock::Actions(
+        SlackActionsBlock::new(vec![SlackActionBlockElement::Button(
+            SlackBlockButtonElement::new(
+                SlackActionId::new(ACTION_RESERVE_SUGGEST_TIME.to_string()),
+                pt!(initial.messages.suggest_time),
+            ),
+        )])
+        .with_block_id(SlackBlockId::new(ACTION_RESERVE_SUGGEST_TIME.to_string())),
+    ));
+
+    if let Some(note) = &initial.datetime_note {
+        blocks.push(SlackBlock::Context(SlackContextBlock::new(vec![
+            SlackContextBlockElement::MarkDown(SlackBlockMarkDownText::new(note.clone())),
+        ])));
+    }
+}
+
+/// 日時選択ブロックを追加
+///
+/// 利用者のタイムゾーンが分かっている場合は、どのタイムゾーンで入力するかを併せて表示する。
+fn add_datetime_blocks(blocks: &mut Vec<SlackBlock>, initial: &InitialValues) {
+    let messages = initial.messages;
+    let (start, end) = (initial.start, initial.end);
+    let start_date = start.format("%Y-%m-%d").to_string();
+    let start_time = start.format("%H:%M").to_string();
+    let end_date = end.format("%Y-%m-%d").to_string();
+    let end_time = end.format("%H:%M").to_string();
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.start_date),
+            SlackInputBlockElement::DatePicker(
+                SlackBlockDatePickerElement::new(SlackActionId::new(
+                    ACTION_RESERVE_START_DATE.to_string(),
+                ))
+                .with_initial_date(start_date),
+            ),
+        )
+        .with_block_id(SlackBlockId::new(datetime_block_id(
+            ACTION_RESERVE_START_DATE,
+            initial.datetime_revision,
+        ))),
+    ));
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.start_time),
+            SlackInputBlockElement::TimePicker(
+                SlackBlockTimePickerElement::new(SlackActionId::new(
+                    ACTION_RESERVE_START_TIME.to_string(),
+                ))
+                .with_initial_time(start_time),
+            ),
+        )
+        .with_block_id(SlackBlockId::new(datetime_block_id(
+            ACTION_RESERVE_START_TIME,
+            initial.datetime_revision,
+        ))),
+    ));
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.end_date),
+            SlackInputBlockElement::DatePicker(
+                SlackBlockDatePickerElement::new(SlackActionId::new(
+                    ACTION_RESERVE_END_DATE.to_string(),
+                ))
+                .with_initial_date(end_date),
+            ),
+        )
+        .with_block_id(SlackBlockId::new(datetime_block_id(
+            ACTION_RESERVE_END_DATE,
+            initial.datetime_revision,
+        ))),
+    ));
+
+    blocks.push(SlackBlock::Input(
+        SlackInputBlock::new(
+            pt!(messages.end_time),
+            SlackInputBlockElement::TimePicker(
+                SlackBlockTimePickerElement::new(SlackActionId::new(
+                    ACTION_RESERVE_END_TIME.to_string(),
+                ))
+                .with_initial_time(end_time),
+            ),
+        )
+        .with_block_id(SlackBlockId::new(datetime_block_id(
+            ACTION_RESERVE_END_TIME,
+            initial.datetime_revision,
+        ))),
+    ));
+
+    if let Some(timezone) = initial.timezone {
+        blocks.push(SlackBlock::Context(SlackContextBlock::new(vec![
+            SlackContextBlockElement::MarkDown(SlackBlockMarkDownText::new(fill(
+                messages.timezone_hint,
+                &[("timezone", timezone.name())],
+            ))),
+        ])));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::Gpu;
+
+    const CONFIG: &str = r#"
+rooms = []
+
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+
+[[servers]]
+name = "Freccia"
+calendar_id = "freccia@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "RTX 4090"
+
+[[servers]]
+name = "Eurostar"
+calendar_id = "eurostar@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "V100"
+"#;
+
+    #[test]
+    fn test_open_servers_round_trip() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+
+        let modal = create_reserve_modal(
+            &config,
+            None,
+            &["Eurostar", "Thalys"],
+            None,
+            None,
+            None,
+            None,
+            &UserPreferences::default(),
+            &[],
+            &[],
+        );
+
+        // 設定の順に並ぶ
+        assert_eq!(open_servers(&config, &modal), vec!["Thalys", "Eurostar"]);
+
+        let default_modal = create_reserve_modal(
+            &config,
+            None,
+            &[],
+            None,
+            None,
+            None,
+            None,
+            &UserPreferences::default(),
+            &[],
+            &[],
+        );
+        assert_eq!(open_servers(&config, &default_modal), vec!["Thalys"]);
+    }
+
+    #[test]
+    fn test_clone_modal_opens_servers_of_resources() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        let resources = vec![
+            Resource::Gpu(Gpu::new("Eurostar".to_string(), 0, "V100".to_string())),
+            Resource::Gpu(Gpu::new("Freccia".to_string(), 0, "RTX 4090".to_string())),
+        ];
+
+        let modal = create_clone_modal(
+            &config,
+            &resources,
+            &UserPreferences::default(),
+            &[],
+            &[],
+            &DateTimeInputs::default(),
+        );
+
+        assert_eq!(open_servers(&config, &modal), vec!["Freccia", "Eurostar"]);
+        let SlackView::Modal(modal) = modal else {
+            panic!("モーダルではありません");
+        };
+        assert_eq!(
+            modal.callback_id.map(|id| id.to_string()).as_deref(),
+            Some(CALLBACK_RESERVE_SUBMIT)
+        );
+        assert_eq!(modal.private_metadata, None);
+    }
+
+    #[test]
+    fn test_edit_modal_prefills_existing_reservation() {
+        use crate::domain::common::EmailAddress;
+        use chrono::TimeZone;
+
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        let usage = ResourceUsage::new(
+            EmailAddress::new("user@example.com".to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 2, 2, 30, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Freccia".to_string(),
+                0,
+                "RTX 4090".to_string(),
+            ))],
+            Some("学習ジョブ".to_string()),
+        )
+        .unwrap();
+        let preferences = UserPreferences {
+            timezone: Some(chrono_tz::Asia::Tokyo),
+            ..UserPreferences::default()
+        };
+
+        let modal = create_edit_modal(&config, &usage, &preferences, &[], &[]);
+
+        assert_eq!(open_servers(&config, &modal), vec!["Freccia"]);
+        let SlackView::Modal(modal) = modal else {
+            panic!("モーダルではありません");
+        };
+        assert_eq!(
+            modal.callback_id.map(|id| id.to_string()).as_deref(),
+            Some(CALLBACK_RESERVE_UPDATE)
+        );
+        assert_eq!(modal.private_metadata.as_deref(), Some(usage.id().as_str()));
+
+        let input = |block_id: &str| {
+            modal
+                .blocks
+                .iter()
+                .find_map(|block| match block {
+                    SlackBlock::Input(input)
+                        if input.block_id.as_ref().map(|id| id.to_string()).as_deref()
+                            == Some(block_id) =>
+                    {
+                        Some(&input.element)
+                    }
+                    _ => None,
+                })
+                .unwrap_or_else(|| panic!("{} がありません", block_id))
+        };
+        let date = |block_id: &str| match input(block_id) {
+            SlackInputBlockElement::DatePicker(picker) => picker.initial_date.clone(),
+            _ => panic!("日付の入力ではありません"),
+        };
+        let time = |block_id: &str| match input(block_id) {
+            SlackInputBlockElement::TimePicker(picker) => picker.initial_time.clone(),
+            _ => panic!("時刻の入力ではありません"),
+        };
+        assert_eq!(
+            date(ACTION_RESERVE_START_DATE).as_deref(),
+            Some("2025-04-01")
+        );
+        assert_eq!(time(ACTION_RESERVE_START_TIME).as_deref(), Some("09:00"));
+        assert_eq!(date(ACTION_RESERVE_END_DATE).as_deref(), Some("2025-04-02"));
+        assert_eq!(time(ACTION_RESERVE_END_TIME).as_deref(), Some("11:30"));
+
+        let SlackInputBlockElement::StaticSelect(server) = input(ACTION_RESERVE_SERVER_SELECT)
+        else {
+            panic!("静的な選択ではありません");
+        };
+        assert_eq!(
+            server
+                .initial_option
+                .as_ref()
+                .map(|option| option.value.as_str()),
+            Some("Freccia")
+        );
+        let SlackInputBlockElement::Checkboxes(devices) = input(&devices_block_id("Freccia"))
+        else {
+            panic!("チェックボックスではありません");
+        };
+        let checked: Vec<&str> = devices
+            .initial_options
+            .iter()
+            .flatten()
+            .map(|option| option.value.as_str())
+            .collect();
+        assert_eq!(checked, vec!["0"]);
+
+        let notes = modal.blocks.iter().find_map(|block| match block {
+            SlackBlock::Input(SlackInputBlock {
+                element: SlackInputBlockElement::PlainTextInput(element),
+                ..
+            }) if element.action_id.to_string() == ACTION_RESERVE_NOTES => {
+                element.initial_value.as_deref()
+            }
+            _ => None,
+        });
+        assert_eq!(notes, Some("学習ジョブ"));
+    }
+
+    #[test]
+    fn test_clone_modal_selects_custom_resource() {
+        let content = format!(
+            r#"{}
+[[resource_types]]
+id = "camera"
+label = "カメラ"
+
+[[resource_types.resources]]
+name = "EOS R5"
+calendar_id = "eos@example.com"
+notifications = []
+
+[[resource_types.resources]]
+name = "α7 IV"
+calendar_id = "alpha@example.com"
+notifications = []
+"#,
+            CONFIG
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let resources = vec![Resource::Custom {
+            kind: "camera".to_string(),
+            name: "α7 IV".to_string(),
+        }];
+
+        let SlackView::Modal(modal) = create_clone_modal(
+            &config,
+            &resources,
+            &UserPreferences::default(),
+            &[],
+            &[],
+            &DateTimeInputs::default(),
+        ) else {
+            panic!("モーダルではありません");
+        };
+
+        let custom = modal
+            .blocks
+            .iter()
+            .find_map(|block| match block {
+                SlackBlock::Input(input)
+                    if input.block_id.as_ref().map(|id| id.to_string()).as_deref()
+                        == Some(ACTION_RESERVE_CUSTOM_SELECT) =>
+                {
+                    Some(input)
+                }
+                _ => None,
+            })
+            .expect("種別のリソース選択がありません");
+        assert_eq!(custom.label, pt!("カメラ".to_string()));
+        let SlackInputBlockElement::StaticSelect(select) = &custom.element else {
+            panic!("静的な選択ではありません");
+        };
+        assert_eq!(
+            select
+                .initial_option
+                .as_ref()
+                .map(|option| option.value.as_str()),
+            Some("α7 IV")
+        );
+    }
+
+    #[test]
+    fn test_busy_device_label() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        let preferences = UserPreferences {
+            timezone: Some(chrono_tz::Asia::Tokyo),
+            locale: crate::infrastructure::i18n::Locale::En,
+        };
+        let initial = InitialValues::for_new_reservation(&preferences);
+        let until = Utc::now() + chrono::Duration::days(2);
+        let busy = vec![BusyDevice {
+            server: "Thalys".to_string(),
+            device: 0,
+            until,
+            owner: Some("<@U123>".to_string()),
+        }];
+
+        let thalys = create_device_options(&config.servers[0], &initial, None, &busy, &[]);
+        let freccia = create_device_options(&config.servers[1], &initial, None, &busy, &[]);
+
+        let expected = format!(
+            "Device 0 (A100) — busy until {} by <@U123>",
+            to_user_time(until, initial.timezone).format("%m/%d %H:%M")
+        );
+        assert!(matches!(&thalys[0].text, SlackBlockText::MarkDown(text) if text.text == expected));
+        assert!(
+            matches!(&freccia[0].text, SlackBlockText::Plain(text) if text.text == "Device 0 (RTX 4090)")
+        );
+    }
+
+    #[test]
+    fn test_tag_filter_opens_first_tagged_server() {
+        let content = CONFIG.replace(
+            "model = \"RTX 4090\"",
+            "model = \"RTX 4090\"\ntags = [\"teaching-only\"]",
+        );
+        let config: ResourceConfig = toml::from_str(&content).unwrap();
+        let preferences = UserPreferences::default();
+
+        let modal = create_reserve_modal_with_datetime(
+            &config,
+            None,
+            &[],
+            &preferences,
+            &[],
+            &[],
+            &DateTimeInputs::default(),
+            Some("Teaching-Only"),
+        );
+        assert_eq!(open_servers(&config, &modal), vec!["Freccia"]);
+
+        let initial = InitialValues::for_new_reservation(&preferences);
+        assert!(
+            create_device_options(
+                &config.servers[0],
+                &initial,
+                Some("teaching-only"),
+                &[],
+                &[]
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_device_options_reflect_device_health() {
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        let preferences = UserPreferences {
+            timezone: None,
+            locale: crate::infrastructure::i18n::Locale::En,
+        };
+        let initial = InitialValues::for_new_reservation(&preferences);
+        let healths = vec![
+            DeviceHealth::new("Thalys".to_string(), 0, DeviceStatus::OutOfService, None),
+            DeviceHealth::new("Freccia".to_string(), 0, DeviceStatus::Degraded, None),
+        ];
+
+        assert!(
+            create_device_options(&config.servers[0], &initial, None, &[], &healths).is_empty()
+        );
+        let freccia = create_device_options(&config.servers[1], &initial, None, &[], &healths);
+        assert!(
+            matches!(&freccia[0].text, SlackBlockText::Plain(text) if text.text == "Device 0 (RTX 4090) ⚠️ degraded")
+        );
+    }
 }
@@ -0,0 +1,46 @@
+//! 予約引き継ぎモーダルビルダー
+
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::infrastructure::i18n::fill;
+use crate::interface::slack::constants::*;
+use crate::interface::slack::utility::user_resolver::UserPreferences;
+use slack_morphism::prelude::*;
+
+/// 予約を別のユーザーに引き継ぐモーダルを作成
+///
+/// 引き継ぎ先をSlackのユーザー選択で選ぶ。
+///
+/// # 引数
+/// * `usage` - 引き継ぐ予約
+/// * `preferences` - 操作するユーザーの表示設定（表示言語）
+///
+/// # 戻り値
+/// 予約引き継ぎフォームのモーダルビュー（usage_idをprivate_metadataに設定）
+pub fn create(usage: &ResourceUsage, preferences: &UserPreferences) -> SlackView {
+    let messages = preferences.messages();
+
+    let blocks = vec![
+        SlackBlock::Section(SlackSectionBlock::new().with_text(md!(fill(
+            messages.current_owner,
+            &[("owner", usage.owner_email().as_str())]
+        )))),
+        SlackBlock::Input(
+            SlackInputBlock::new(
+                pt!(messages.transfer_new_owner),
+                SlackInputBlockElement::UsersSelect(SlackBlockUsersSelectElement::new(
+                    SlackActionId::new(ACTION_TRANSFER_NEW_OWNER.to_string()),
+                )),
+            )
+            .with_block_id(SlackBlockId::new(ACTION_TRANSFER_NEW_OWNER.to_string()))
+            .with_hint(pt!(messages.transfer_new_owner_hint)),
+        ),
+    ];
+
+    SlackView::Modal(
+        SlackModalView::new(pt!(messages.transfer_title), blocks)
+            .with_callback_id(CALLBACK_TRANSFER_RESERVATION.into())
+            .with_submit(pt!(messages.transfer_submit))
+            .with_close(pt!(messages.cancel))
+            .with_private_metadata(usage.id().as_str().to_string()),
+    )
+}
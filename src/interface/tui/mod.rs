@@ -0,0 +1,347 @@
+//! # 端末のダッシュボード
+//!
+//! 端末で予約の状況を確認するためのダッシュボード（管理者向け）。
+//! デバイスごとの予約のタイムライン、予約の変更の通知ログ、ポーリングの状態を表示し、
+//! ポーリングの間隔ごとに更新する。
+//!
+//! 通知ログは、Botと同じ変更の検知をこのプロセスでも行い、検知した変更を記録したもの
+//! （Slackなどへは送らない）。画面はANSIエスケープシーケンスで描画する。
+//!
+//! ratatui・crosstermは使わず、表示だけの画面を [`render`] で組み立てている。入力は `q` + Enter だけで
+//! 生モードやマウス操作を必要としないため、依存するクレートを増やさないことを優先した。
+//! 代替スクリーンの切り替えと画面の消去のほかは文字色（SGR）だけを使い、端末の大きさを超えないように
+//! 描画することをテストで確認している。
+
+mod render;
+
+use crate::application::usecases::{
+    ListAllFutureResourceUsagesUseCase, NotifyFutureResourceUsageChangesUseCase,
+};
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::ports::notifier::{NotificationError, NotificationEvent, Notifier};
+use crate::domain::ports::repositories::ResourceUsageRepository;
+use crate::infrastructure::config::ResourceConfig;
+use async_trait::async_trait;
+use chrono::{DateTime, Local, Utc};
+use chrono_tz::Tz;
+use render::{TimelineRow, View};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+/// 通知ログに残す件数
+const MAX_LOG_ENTRIES: usize = 200;
+
+/// 端末の大きさが分からない場合の桁数・行数
+const DEFAULT_SIZE: (usize, usize) = (120, 40);
+
+/// 代替スクリーンに切り替え、カーソルを隠す
+const ENTER_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+/// 元のスクリーンに戻し、カーソルを表示する
+const LEAVE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+/// 画面を消去し、カーソルを左上に移動する
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// 通知ログの1件
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// 検知した時刻
+    pub at: DateTime<Utc>,
+    pub event: NotificationEvent,
+}
+
+/// ポーリングの状態
+#[derive(Debug, Clone, Default)]
+pub struct PollHealth {
+    /// 最後に成功した時刻
+    pub last_success: Option<DateTime<Utc>>,
+    /// 最後に成功したポーリングにかかった時間
+    pub last_duration: Duration,
+    /// 最後に取得した終了していない予約の件数
+    pub fetched: usize,
+    /// 続けて失敗した回数
+    pub consecutive_failures: u32,
+    /// 最後に失敗した時刻とエラー
+    pub last_error: Option<(DateTime<Utc>, String)>,
+}
+
+/// 検知した変更を通知ログに記録する通知サービス
+struct LogNotifier {
+    log: Arc<Mutex<VecDeque<LogEntry>>>,
+}
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, event: NotificationEvent) -> Result<(), NotificationError> {
+        let mut log = self.log.lock().unwrap_or_else(|e| e.into_inner());
+        log.push_back(LogEntry {
+            at: Utc::now(),
+            event,
+        });
+        while log.len() > MAX_LOG_ENTRIES {
+            log.pop_front();
+        }
+        Ok(())
+    }
+}
+
+/// 端末のダッシュボード
+pub struct TuiDashboard<R: ResourceUsageRepository> {
+    repository: Arc<R>,
+    rows: Vec<TimelineRow>,
+    timezone: Option<Tz>,
+    polling_interval: Duration,
+    hours: u32,
+    list_usecase: ListAllFutureResourceUsagesUseCase<R>,
+}
+
+impl<R> TuiDashboard<R>
+where
+    R: ResourceUsageRepository + Send + Sync + 'static,
+{
+    /// 新しいTuiDashboardを作成
+    ///
+    /// # Arguments
+    /// * `polling_interval` - 予約を取得し直す間隔
+    /// * `hours` - タイムラインに表示する時間数
+    pub fn new(
+        repository: Arc<R>,
+        resource_config: &ResourceConfig,
+        polling_interval: Duration,
+        hours: u32,
+    ) -> Self {
+        Self {
+            list_usecase: ListAllFutureResourceUsagesUseCase::new(repository.clone()),
+            repository,
+            rows: TimelineRow::from_config(resource_config),
+            timezone: resource_config
+                .timezone
+                .as_deref()
+                .and_then(|tz| tz.parse::<Tz>().ok()),
+            polling_interval,
+            hours,
+        }
+    }
+
+    /// ダッシュボードを表示する
+    ///
+    /// `q` と Enter、または Ctrl-C で終了する。標準出力は画面の描画に使うため、ログは標準エラー出力に書くこと。
+    pub async fn run(&self) -> std::io::Result<()> {
+        let log = Arc::new(Mutex::new(VecDeque::new()));
+        let watcher = NotifyFutureResourceUsageChangesUseCase::new(
+            self.repository.clone(),
+            LogNotifier { log: log.clone() },
+        )
+        .await
+        .map_err(std::io::Error::other)?;
+
+        let mut stdout = tokio::io::stdout();
+        stdout.write_all(ENTER_SCREEN.as_bytes()).await?;
+        let result = self.event_loop(&watcher, &log, &mut stdout).await;
+        stdout.write_all(LEAVE_SCREEN.as_bytes()).await?;
+        stdout.flush().await?;
+        result
+    }
+
+    async fn event_loop(
+        &self,
+        watcher: &NotifyFutureResourceUsageChangesUseCase<R, LogNotifier>,
+        log: &Mutex<VecDeque<LogEntry>>,
+        stdout: &mut tokio::io::Stdout,
+    ) -> std::io::Result<()> {
+        let mut input = BufReader::new(tokio::io::stdin()).lines();
+        let mut poll_timer = tokio::time::interval(self.polling_interval);
+        let mut redraw_timer = tokio::time::interval(Duration::from_secs(1));
+        let mut usages = Vec::new();
+        let mut health = PollHealth::default();
+
+        loop {
+            tokio::select! {
+                _ = poll_timer.tick() => {
+                    self.poll(watcher, &mut usages, &mut health).await;
+                }
+                _ = redraw_timer.tick() => {}
+                line = input.next_line() => match line? {
+                    Some(line) if line.trim() == "q" => return Ok(()),
+                    None => return Ok(()),
+                    Some(_) => {}
+                },
+                _ = tokio::signal::ctrl_c() => return Ok(()),
+            }
+
+            let (width, height) = terminal_size();
+            let lines = {
+                let log = log.lock().unwrap_or_else(|e| e.into_inner());
+                let view = View {
+                    rows: &self.rows,
+                    usages: &usages,
+                    log: &log,
+                    health: &health,
+                    hours: self.hours,
+                    polling_interval_secs: self.polling_interval.as_secs(),
+                };
+                let now = Utc::now();
+                match &self.timezone {
+                    Some(tz) => render::render(&view, now, tz, width, height),
+                    None => render::render(&view, now, &Local, width, height),
+                }
+            };
+            let mut screen = String::from(CLEAR);
+            screen.push_str(&lines.join("\n"));
+            stdout.write_all(screen.as_bytes()).await?;
+            stdout.flush().await?;
+        }
+    }
+
+    /// 変更を検知して予約を取得し直し、ポーリングの状態を更新する
+    async fn poll(
+        &self,
+        watcher: &NotifyFutureResourceUsageChangesUseCase<R, LogNotifier>,
+        usages: &mut Vec<ResourceUsage>,
+        health: &mut PollHealth,
+    ) {
+        let started = Instant::now();
+        let result = match watcher.poll_once().await {
+            Ok(fetched) => self
+                .list_usecase
+                .execute()
+                .await
+                .map(|listed| (fetched, listed)),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok((fetched, listed)) => {
+                *usages = listed;
+                health.last_success = Some(Utc::now());
+                health.last_duration = started.elapsed();
+                health.fetched = fetched;
+                health.consecutive_failures = 0;
+            }
+            Err(e) => {
+                health.consecutive_failures += 1;
+                health.last_error = Some((Utc::now(), e.to_string()));
+            }
+        }
+    }
+}
+
+/// 端末の桁数・行数（`stty size`、環境変数 `COLUMNS` / `LINES` の順に調べる）
+fn terminal_size() -> (usize, usize) {
+    let from_stty = std::fs::File::open("/dev/tty").ok().and_then(|tty| {
+        let output = std::process::Command::new("stty")
+            .arg("size")
+            .stdin(tty)
+            .output()
+            .ok()?;
+        let size = String::from_utf8(output.stdout).ok()?;
+        let (rows, columns) = size.trim().split_once(' ')?;
+        Some((columns.parse().ok()?, rows.parse().ok()?))
+    });
+    from_stty.unwrap_or_else(|| {
+        let env = |name: &str, default: usize| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(default)
+        };
+        (env("COLUMNS", DEFAULT_SIZE.0), env("LINES", DEFAULT_SIZE.1))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Resource, TimePeriod};
+    use crate::domain::common::EmailAddress;
+    use crate::infrastructure::repositories::resource_usage::mock::MockUsageRepository;
+
+    const CONFIG: &str = r#"
+rooms = []
+
+[[servers]]
+name = "Thalys"
+calendar_id = "thalys@example.com"
+notifications = []
+
+[[servers.devices]]
+id = 0
+model = "A100"
+"#;
+
+    fn usage(notes: &str) -> ResourceUsage {
+        let start = Utc::now() + chrono::Duration::hours(1);
+        ResourceUsage::new(
+            EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            TimePeriod::new(start, start + chrono::Duration::hours(2)).unwrap(),
+            vec![Resource::Room {
+                name: "会議室A".to_string(),
+            }],
+            Some(notes.to_string()),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_log_keeps_latest_entries() {
+        let log = Arc::new(Mutex::new(VecDeque::new()));
+        let notifier = LogNotifier { log: log.clone() };
+        for i in 0..MAX_LOG_ENTRIES + 5 {
+            notifier
+                .notify(NotificationEvent::ResourceUsageCreated(usage(
+                    &i.to_string(),
+                )))
+                .await
+                .unwrap();
+        }
+
+        let log = log.lock().unwrap();
+        assert_eq!(log.len(), MAX_LOG_ENTRIES);
+        let NotificationEvent::ResourceUsageCreated(oldest) = &log[0].event else {
+            panic!("作成の通知のはず: {:?}", log[0].event);
+        };
+        assert_eq!(oldest.notes().map(String::as_str), Some("5"));
+    }
+
+    #[tokio::test]
+    async fn test_poll_tracks_health_and_logs_changes() {
+        let repo = Arc::new(MockUsageRepository::new());
+        repo.save(&usage("first")).await.unwrap();
+        let config: ResourceConfig = toml::from_str(CONFIG).unwrap();
+        let dashboard = TuiDashboard::new(repo.clone(), &config, Duration::from_secs(60), 12);
+        let log = Arc::new(Mutex::new(VecDeque::new()));
+        let watcher = NotifyFutureResourceUsageChangesUseCase::new(
+            repo.clone(),
+            LogNotifier { log: log.clone() },
+        )
+        .await
+        .unwrap();
+        let mut usages = Vec::new();
+        let mut health = PollHealth::default();
+
+        dashboard.poll(&watcher, &mut usages, &mut health).await;
+        assert_eq!(usages.len(), 1);
+        assert_eq!(health.fetched, 1);
+        assert!(health.last_success.is_some());
+
+        // 失敗しても直前に取得した予約は表示したままにする
+        repo.fail_finds(true);
+        dashboard.poll(&watcher, &mut usages, &mut health).await;
+        dashboard.poll(&watcher, &mut usages, &mut health).await;
+        assert_eq!(health.consecutive_failures, 2);
+        assert!(health.last_error.is_some());
+        assert_eq!(usages.len(), 1);
+
+        repo.fail_finds(false);
+        repo.save(&usage("second")).await.unwrap();
+        dashboard.poll(&watcher, &mut usages, &mut health).await;
+        assert_eq!(health.consecutive_failures, 0);
+        assert_eq!(usages.len(), 2);
+        let log = log.lock().unwrap();
+        assert!(matches!(
+            log.back().map(|entry| &entry.event),
+            Some(NotificationEvent::ResourceUsageCreated(created))
+                if created.notes().map(String::as_str) == Some("second")
+        ));
+    }
+}
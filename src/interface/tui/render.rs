@@ -0,0 +1,504 @@
+//! ダッシュボードの画面の描画
+//!
+//! 画面の内容をANSIエスケープシーケンス付きの行として組み立てる（端末への出力は [`super`] が行う）。
+
+use super::{LogEntry, PollHealth};
+use crate::domain::aggregates::resource_usage::entity::ResourceUsage;
+use crate::domain::aggregates::resource_usage::value_objects::Resource;
+use crate::domain::ports::notifier::NotificationEvent;
+use crate::infrastructure::config::{ResourceConfig, ResourceStyle};
+use crate::infrastructure::notifier::formatter::format_resources_styled;
+use chrono::{DateTime, Duration, TimeZone, Timelike, Utc};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+
+/// 予約者ごとに使い分ける文字色
+const OWNER_COLORS: [&str; 5] = ["\x1b[36m", "\x1b[35m", "\x1b[34m", "\x1b[32m", "\x1b[96m"];
+
+/// ラベルの列の最大幅
+const MAX_LABEL_WIDTH: usize = 20;
+
+/// タイムラインの1行（GPUは1デバイスごと、部屋・機器は1つごと）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimelineRow {
+    Device { server: String, device: u32 },
+    Room(String),
+    Instrument(String),
+}
+
+impl TimelineRow {
+    /// 設定にあるサーバーのデバイス・部屋・機器の順に行を作る
+    pub fn from_config(config: &ResourceConfig) -> Vec<Self> {
+        let devices = config.servers.iter().flat_map(|server| {
+            server.devices.iter().map(|device| Self::Device {
+                server: server.name.clone(),
+                device: device.id,
+            })
+        });
+        let rooms = config
+            .rooms
+            .iter()
+            .map(|room| Self::Room(room.name.clone()));
+        let instruments = config
+            .instruments
+            .iter()
+            .map(|instrument| Self::Instrument(instrument.name.clone()));
+        devices.chain(rooms).chain(instruments).collect()
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Device { server, device } => format!("{}:{}", server, device),
+            Self::Room(name) | Self::Instrument(name) => name.clone(),
+        }
+    }
+
+    fn matches(&self, resource: &Resource) -> bool {
+        match (self, resource) {
+            (Self::Device { server, device }, Resource::Gpu(gpu)) => {
+                gpu.server() == server && gpu.device_number() == *device
+            }
+            (Self::Room(name), Resource::Room { name: room }) => name == room,
+            (Self::Instrument(name), Resource::Instrument { name: instrument }) => {
+                name == instrument
+            }
+            _ => false,
+        }
+    }
+}
+
+/// 描画する内容
+pub struct View<'a> {
+    pub rows: &'a [TimelineRow],
+    pub usages: &'a [ResourceUsage],
+    pub log: &'a VecDeque<LogEntry>,
+    pub health: &'a PollHealth,
+    /// タイムラインに表示する時間数
+    pub hours: u32,
+    /// ポーリングの間隔（秒）
+    pub polling_interval_secs: u64,
+}
+
+/// 画面を行ごとに描画する
+///
+/// 端末からはみ出して画面がスクロールしないよう、行数と各行の表示幅を端末の大きさで切り詰める。
+///
+/// # Arguments
+/// * `now` - 現在時刻（タイムラインは現在の時の0分から始まる）
+/// * `tz` - 時刻の表示に使うタイムゾーン
+/// * `width` / `height` - 端末の桁数・行数
+pub fn render<T: TimeZone>(
+    view: &View,
+    now: DateTime<Utc>,
+    tz: &T,
+    width: usize,
+    height: usize,
+) -> Vec<String>
+where
+    T::Offset: Display,
+{
+    let time = |datetime: DateTime<Utc>, format: &str| {
+        datetime.with_timezone(tz).format(format).to_string()
+    };
+    let mut lines = vec![
+        format!(
+            "{}lab-resource-manager{}  {}  {}（q + Enter で終了）{}",
+            BOLD,
+            RESET,
+            time(now, "%Y-%m-%d %H:%M:%S"),
+            DIM,
+            RESET
+        ),
+        String::new(),
+        format!("{}予約（今後{}時間）{}", BOLD, view.hours, RESET),
+    ];
+
+    // タイムライン
+    let label_width = view
+        .rows
+        .iter()
+        .map(|row| display_width(&row.label()))
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LABEL_WIDTH);
+    let cells = width.saturating_sub(label_width + 3).max(10);
+    let start = start_of_hour(now, tz);
+    let cell_secs = (i64::from(view.hours.max(1)) * 3600) as f64 / cells as f64;
+    let cell_start = |cell: usize| start + Duration::seconds((cell as f64 * cell_secs) as i64);
+
+    let mut ticks = vec![' '; cells];
+    let mut next_free = 0;
+    for hour in 0..view.hours {
+        let column = (f64::from(hour) * 3600.0 / cell_secs) as usize;
+        let text = time(start + Duration::hours(i64::from(hour)), "%H");
+        if column >= next_free && column + text.len() <= cells {
+            for (i, c) in text.chars().enumerate() {
+                ticks[column + i] = c;
+            }
+            next_free = column + text.len() + 1;
+        }
+    }
+    lines.push(format!(
+        "{} | {}{}{}",
+        " ".repeat(label_width),
+        DIM,
+        ticks.iter().collect::<String>(),
+        RESET
+    ));
+
+    for row in view.rows {
+        let usages: Vec<&ResourceUsage> = view
+            .usages
+            .iter()
+            .filter(|usage| usage.resources().iter().any(|r| row.matches(r)))
+            .collect();
+        let mut line = format!("{} | ", pad(&row.label(), label_width));
+        for cell in 0..cells {
+            let (from, to) = (cell_start(cell), cell_start(cell + 1));
+            let usage = usages
+                .iter()
+                .find(|usage| usage.time_period().start() < to && usage.time_period().end() > from);
+            match usage {
+                Some(usage) => {
+                    let color = if usage.approval_status().is_pending() {
+                        YELLOW
+                    } else {
+                        owner_color(usage)
+                    };
+                    line.push_str(&format!("{}{}{}", color, owner_initial(usage), RESET));
+                }
+                None => line.push_str(&format!("{}·{}", DIM, RESET)),
+            }
+        }
+        lines.push(line);
+    }
+
+    // ポーリングの状態
+    lines.push(String::new());
+    let last_success = view.health.last_success.map_or("なし".to_string(), |at| {
+        format!(
+            "{}（{:.1}秒、予約{}件）",
+            time(at, "%H:%M:%S"),
+            view.health.last_duration.as_secs_f64(),
+            view.health.fetched
+        )
+    });
+    let status = if view.health.consecutive_failures == 0 {
+        format!("{}正常{}", GREEN, RESET)
+    } else {
+        format!(
+            "{}失敗が{}回続いています{}",
+            RED, view.health.consecutive_failures, RESET
+        )
+    };
+    lines.push(format!(
+        "{}ポーリング{}  {}  最終成功 {}  間隔 {}秒",
+        BOLD, RESET, status, last_success, view.polling_interval_secs
+    ));
+    if let Some((at, message)) = &view.health.last_error {
+        lines.push(format!(
+            "  {}最後のエラー {}: {}{}",
+            RED,
+            time(*at, "%m/%d %H:%M:%S"),
+            message,
+            RESET
+        ));
+    }
+
+    // 通知ログ（新しい順）
+    lines.push(String::new());
+    lines.push(format!("{}通知ログ{}", BOLD, RESET));
+    let remaining = height.saturating_sub(lines.len()).max(1);
+    if view.log.is_empty() {
+        lines.push(format!("{}まだ変更はありません{}", DIM, RESET));
+    }
+    for entry in view.log.iter().rev().take(remaining) {
+        let usage = entry.event.usage();
+        lines.push(format!(
+            "{}  {}  {}  {}  {} - {}",
+            time(entry.at, "%H:%M:%S"),
+            event_label(&entry.event),
+            usage.owner_email().as_str(),
+            format_resources_styled(usage.resources(), ResourceStyle::Compact),
+            time(usage.time_period().start(), "%m/%d %H:%M"),
+            time(usage.time_period().end(), "%m/%d %H:%M")
+        ));
+    }
+    lines.truncate(height);
+    lines.iter().map(|line| clip(line, width)).collect()
+}
+
+/// 表示幅が `width` を超える部分を切り詰める（エスケープシーケンスは幅に数えない）
+fn clip(line: &str, width: usize) -> String {
+    let mut clipped = String::new();
+    let mut used = 0;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            clipped.push(c);
+            for c in chars.by_ref() {
+                clipped.push(c);
+                if c == 'm' {
+                    break;
+                }
+            }
+            continue;
+        }
+        let w = display_width(c.encode_utf8(&mut [0; 4]));
+        if used + w > width {
+            // 切り詰めた後ろの色が次の行に残らないようにする
+            clipped.push_str(RESET);
+            break;
+        }
+        clipped.push(c);
+        used += w;
+    }
+    clipped
+}
+
+/// 現在の時の0分
+fn start_of_hour<T: TimeZone>(now: DateTime<Utc>, tz: &T) -> DateTime<Utc> {
+    let local = now.with_timezone(tz);
+    local
+        .with_minute(0)
+        .and_then(|local| local.with_second(0))
+        .and_then(|local| local.with_nanosecond(0))
+        .map_or(now, |local| local.with_timezone(&Utc))
+}
+
+fn event_label(event: &NotificationEvent) -> &'static str {
+    match event {
+        NotificationEvent::ResourceUsageCreated(_) => "作成",
+        NotificationEvent::ResourceUsageUpdated(_) => "更新",
+        NotificationEvent::ResourceUsageDeleted(_) => "削除",
+        NotificationEvent::ResourceUsageSeriesCreated { .. } => "繰返",
+        NotificationEvent::ResourceUsageStarted(_) => "開始",
+        NotificationEvent::ResourceUsageEnded(_) => "終了",
+    }
+}
+
+/// タイムラインに表示する予約者の頭文字
+fn owner_initial(usage: &ResourceUsage) -> char {
+    usage
+        .owner_email()
+        .as_str()
+        .chars()
+        .next()
+        .map_or('#', |c| c.to_ascii_uppercase())
+}
+
+fn owner_color(usage: &ResourceUsage) -> &'static str {
+    let hash = usage
+        .owner_email()
+        .as_str()
+        .bytes()
+        .fold(0usize, |hash, byte| {
+            hash.wrapping_mul(31).wrapping_add(byte as usize)
+        });
+    OWNER_COLORS[hash % OWNER_COLORS.len()]
+}
+
+/// 端末での表示幅（全角文字は2桁として数える）
+fn display_width(s: &str) -> usize {
+    s.chars().map(|c| if c >= '\u{1100}' { 2 } else { 1 }).sum()
+}
+
+/// 表示幅が `width` になるよう、空白で埋めるか切り詰める
+fn pad(s: &str, width: usize) -> String {
+    let mut padded = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = display_width(c.encode_utf8(&mut [0; 4]));
+        if used + w > width {
+            break;
+        }
+        padded.push(c);
+        used += w;
+    }
+    padded.push_str(&" ".repeat(width - used));
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::resource_usage::value_objects::{Gpu, TimePeriod};
+    use crate::domain::common::EmailAddress;
+
+    fn usage(owner: &str, device: u32, start_hour: u32, end_hour: u32) -> ResourceUsage {
+        ResourceUsage::new(
+            EmailAddress::new(owner.to_string()).unwrap(),
+            TimePeriod::new(
+                Utc.with_ymd_and_hms(2025, 4, 1, start_hour, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 1, end_hour, 0, 0).unwrap(),
+            )
+            .unwrap(),
+            vec![Resource::Gpu(Gpu::new(
+                "Thalys".to_string(),
+                device,
+                "A100".to_string(),
+            ))],
+            None,
+        )
+        .unwrap()
+    }
+
+    fn strip_ansi(line: &str) -> String {
+        let mut stripped = String::new();
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| *c == 'm');
+            } else {
+                stripped.push(c);
+            }
+        }
+        stripped
+    }
+
+    #[test]
+    fn test_render_timeline_and_log() {
+        let rows = vec![
+            TimelineRow::Device {
+                server: "Thalys".to_string(),
+                device: 0,
+            },
+            TimelineRow::Room("会議室".to_string()),
+        ];
+        let created = usage("alice@example.com", 0, 12, 14);
+        let usages = vec![created.clone(), usage("bob@example.com", 1, 10, 11)];
+        let log = VecDeque::from([LogEntry {
+            at: Utc.with_ymd_and_hms(2025, 4, 1, 10, 5, 0).unwrap(),
+            event: NotificationEvent::ResourceUsageCreated(created),
+        }]);
+        let health = PollHealth {
+            consecutive_failures: 2,
+            last_error: Some((
+                Utc.with_ymd_and_hms(2025, 4, 1, 10, 29, 0).unwrap(),
+                "timeout".to_string(),
+            )),
+            ..PollHealth::default()
+        };
+        let view = View {
+            rows: &rows,
+            usages: &usages,
+            log: &log,
+            health: &health,
+            hours: 4,
+            polling_interval_secs: 60,
+        };
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 10, 30, 0).unwrap();
+
+        // ラベル8桁 + " | " + 16セル（1セル15分）
+        let lines: Vec<String> = render(&view, now, &Utc, 27, 30)
+            .iter()
+            .map(|line| strip_ansi(line))
+            .collect();
+
+        assert_eq!(lines[3], "         | 10  11  12  13  ");
+        assert_eq!(lines[4], "Thalys:0 | ········AAAAAAAA");
+        assert_eq!(lines[5], "会議室   | ················");
+        // 端末の幅を超える部分は切り詰める
+        assert_eq!(lines[11], "10:05:00  作成  alice@examp");
+
+        let lines: Vec<String> = render(&view, now, &Utc, 200, 30)
+            .iter()
+            .map(|line| strip_ansi(line))
+            .collect();
+        assert!(lines[7].contains("失敗が2回続いています"));
+        assert!(lines[7].contains("最終成功 なし"));
+        assert!(lines[8].contains("最後のエラー 04/01 10:29:00: timeout"));
+        assert!(lines[11].starts_with("10:05:00  作成  alice@example.com  Thalys 0"));
+    }
+
+    /// 行に含まれるエスケープシーケンスがすべてSGR（`ESC [ 数字;… m`）か
+    fn only_sgr_sequences(line: &str) -> bool {
+        line.split('\x1b').skip(1).all(|rest| {
+            rest.strip_prefix('[')
+                .and_then(|rest| rest.split_once('m'))
+                .is_some_and(|(params, _)| params.chars().all(|c| c.is_ascii_digit() || c == ';'))
+        })
+    }
+
+    #[test]
+    fn test_frame_fits_terminal_and_uses_only_sgr() {
+        let rows: Vec<TimelineRow> = (0..8)
+            .map(|device| TimelineRow::Device {
+                server: "Thalys".to_string(),
+                device,
+            })
+            .chain([TimelineRow::Room("とても長い名前の会議室".to_string())])
+            .collect();
+        let usages: Vec<ResourceUsage> = (0..8)
+            .map(|device| usage("alice@example.com", device, 10, 12 + device % 3))
+            .collect();
+        let log: VecDeque<LogEntry> = usages
+            .iter()
+            .map(|usage| LogEntry {
+                at: Utc.with_ymd_and_hms(2025, 4, 1, 10, 5, 0).unwrap(),
+                event: NotificationEvent::ResourceUsageCreated(usage.clone()),
+            })
+            .collect();
+        let health = PollHealth {
+            consecutive_failures: 1,
+            last_error: Some((
+                Utc.with_ymd_and_hms(2025, 4, 1, 10, 29, 0).unwrap(),
+                "接続がタイムアウトしました".repeat(10),
+            )),
+            ..PollHealth::default()
+        };
+        let view = View {
+            rows: &rows,
+            usages: &usages,
+            log: &log,
+            health: &health,
+            hours: 12,
+            polling_interval_secs: 60,
+        };
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 10, 30, 0).unwrap();
+
+        for (width, height) in [(40, 12), (80, 24), (120, 40)] {
+            let lines = render(&view, now, &Utc, width, height);
+            assert!(
+                lines.len() <= height,
+                "{}x{}: {}行",
+                width,
+                height,
+                lines.len()
+            );
+            for line in &lines {
+                assert!(only_sgr_sequences(line), "{:?}", line);
+                let stripped = strip_ansi(line);
+                assert!(
+                    display_width(&stripped) <= width,
+                    "{}桁を超えています: {:?}",
+                    width,
+                    stripped
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_clip_keeps_escape_sequences() {
+        assert_eq!(
+            clip(&format!("{}会議室{}", BOLD, RESET), 5),
+            format!("{}会議{}", BOLD, RESET)
+        );
+        assert_eq!(clip("Thalys", 10), "Thalys");
+    }
+
+    #[test]
+    fn test_pad_counts_wide_characters() {
+        assert_eq!(pad("会議室A", 6), "会議室");
+        assert_eq!(pad("Thalys:0", 10), "Thalys:0  ");
+        assert_eq!(display_width("会議室A"), 7);
+    }
+}
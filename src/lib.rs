@@ -69,6 +69,26 @@
 //! # }
 //! ```
 //!
+//! To run the complete Slack bot, let [`LabResourceManagerBuilder`] wire everything from the
+//! configuration. Every dependency has a default implementation and can be overridden:
+//!
+//! ```rust,no_run
+//! use lab_resource_manager::LabResourceManagerBuilder;
+//! use std::sync::Arc;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Reads the same environment variables and resources.toml as the binary
+//! let app = LabResourceManagerBuilder::from_env().await?
+//!     // .with_identity_repository(...)
+//!     // .with_power_management(...)
+//!     .build()
+//!     .await?;
+//!
+//! Arc::new(app).run().await.map_err(|e| -> Box<dyn std::error::Error> { e })?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Device Specification Format
 //!
 //! Calendar event titles support flexible device specification:
@@ -92,6 +112,7 @@
 
 // Module declarations
 pub mod application;
+pub mod builder;
 pub mod domain;
 pub mod infrastructure;
 pub mod interface;
@@ -108,6 +129,9 @@ pub mod prelude {
     // Use cases
     pub use crate::application::usecases::NotifyFutureResourceUsageChangesUseCase;
 
+    // Wiring
+    pub use crate::builder::LabResourceManagerBuilder;
+
     // Application errors
     pub use crate::application::error::ApplicationError;
 
@@ -143,6 +167,7 @@ pub mod prelude {
 
 // Convenience re-exports at crate root
 pub use application::{error::ApplicationError, usecases::NotifyFutureResourceUsageChangesUseCase};
+pub use builder::LabResourceManagerBuilder;
 pub use domain::ports::{
     notifier::{NotificationError, NotificationEvent, Notifier},
     repositories::{RepositoryError, ResourceUsageRepository},